@@ -0,0 +1,391 @@
+//! Command-line front end for behavioral-pathways scenarios.
+//!
+//! Loads a TOML scenario (see the `scenario` module for the format) and
+//! runs one command against it through the crate's public API:
+//!
+//! ```text
+//! bp-cli run FILE [--query ID@DATE]... [--paths PATH,...] [--json]
+//! bp-cli series FILE [--entity ID] [--from DATE] [--to DATE] [--step-days N]
+//!                    [--paths PATH,...] [--json]
+//! bp-cli validate FILE [--json]
+//! bp-cli diff A B [--json]
+//! ```
+//!
+//! `run` prints each queried entity's state at each date (every entity at
+//! the reference date by default). `series` samples one entity from
+//! `--from` (default: reference) to `--to` (default: a year later) every
+//! `--step-days` (default 30). Paths are written like
+//! `mood.valence,mental_health.depression`; the default is the golden
+//! path set. Output is a table unless `--json` is given.
+//!
+//! Exit codes: 0 on success, 1 when `validate` reports issues or `diff`
+//! finds differences, 2 on usage or scenario errors.
+
+mod scenario;
+
+use behavioral_pathways::enums::StatePath;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::test_scenarios::GOLDEN_PATHS;
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+use scenario::ScenarioError;
+use serde_json::json;
+use std::fmt;
+use std::path::Path;
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+usage:
+  bp-cli run FILE [--query ID@DATE]... [--paths PATH,...] [--json]
+  bp-cli series FILE [--entity ID] [--from DATE] [--to DATE] [--step-days N] \
+[--paths PATH,...] [--json]
+  bp-cli validate FILE [--json]
+  bp-cli diff A B [--json]";
+
+const DEFAULT_SERIES_DAYS: u64 = 365;
+const DEFAULT_STEP_DAYS: u64 = 30;
+
+/// Errors that stop a command before it produces output.
+#[derive(Debug)]
+enum CliError {
+    /// The arguments do not form a valid command.
+    Usage(String),
+    /// A scenario or date could not be loaded.
+    Scenario(ScenarioError),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Usage(message) => write!(f, "{}\n\n{}", message, USAGE),
+            CliError::Scenario(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl From<ScenarioError> for CliError {
+    fn from(err: ScenarioError) -> Self {
+        CliError::Scenario(err)
+    }
+}
+
+/// Flags and positional arguments after the command name.
+#[derive(Debug, Default)]
+struct Args {
+    positional: Vec<String>,
+    queries: Vec<String>,
+    paths: Option<String>,
+    entity: Option<String>,
+    from: Option<String>,
+    to: Option<String>,
+    step_days: Option<String>,
+    json: bool,
+}
+
+impl Args {
+    fn parse(raw: impl IntoIterator<Item = String>) -> Result<Self, CliError> {
+        let mut args = Args::default();
+        let mut raw = raw.into_iter();
+        while let Some(arg) = raw.next() {
+            let mut value = |flag: &str| {
+                raw.next()
+                    .ok_or_else(|| CliError::Usage(format!("{} needs a value", flag)))
+            };
+            match arg.as_str() {
+                "--json" => args.json = true,
+                "--query" => args.queries.push(value("--query")?),
+                "--paths" => args.paths = Some(value("--paths")?),
+                "--entity" => args.entity = Some(value("--entity")?),
+                "--from" => args.from = Some(value("--from")?),
+                "--to" => args.to = Some(value("--to")?),
+                "--step-days" => args.step_days = Some(value("--step-days")?),
+                flag if flag.starts_with("--") => {
+                    return Err(CliError::Usage(format!("unknown option {}", flag)));
+                }
+                _ => args.positional.push(arg),
+            }
+        }
+        Ok(args)
+    }
+
+    /// Returns the positional arguments, requiring exactly `count`.
+    fn files(&self, count: usize) -> Result<&[String], CliError> {
+        if self.positional.len() == count {
+            Ok(&self.positional)
+        } else {
+            Err(CliError::Usage(format!(
+                "expected {} scenario file(s), got {}",
+                count,
+                self.positional.len()
+            )))
+        }
+    }
+
+    fn state_paths(&self) -> Result<Vec<StatePath>, CliError> {
+        match &self.paths {
+            Some(list) => list.split(',').map(parse_path).collect(),
+            None => Ok(GOLDEN_PATHS.to_vec()),
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let mut raw = std::env::args().skip(1);
+    let command = raw.next();
+    let result = Args::parse(raw).and_then(|args| match command.as_deref() {
+        Some("run") => run(&args),
+        Some("series") => series(&args),
+        Some("validate") => validate(&args),
+        Some("diff") => diff(&args),
+        Some("help" | "--help" | "-h") => {
+            println!("{}", USAGE);
+            Ok(true)
+        }
+        Some(other) => Err(CliError::Usage(format!("unknown command '{}'", other))),
+        None => Err(CliError::Usage(String::from("missing command"))),
+    });
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(err) => {
+            eprintln!("bp-cli: {}", err);
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Prints states at each queried date. Always succeeds once loaded.
+fn run(args: &Args) -> Result<bool, CliError> {
+    let sim = scenario::load(Path::new(&args.files(1)?[0]))?;
+    let paths = args.state_paths()?;
+    let queries = if args.queries.is_empty() {
+        entity_ids(&sim)
+            .into_iter()
+            .map(|id| (id, sim.reference_date()))
+            .collect()
+    } else {
+        args.queries
+            .iter()
+            .map(|query| parse_query(query))
+            .collect::<Result<Vec<_>, _>>()?
+    };
+
+    let mut rows = Vec::new();
+    for (id, at) in queries {
+        let handle = sim
+            .entity(&id)
+            .ok_or_else(|| CliError::Usage(format!("no entity '{}' in scenario", id)))?;
+        let state = handle.state_at(at);
+        for path in &paths {
+            rows.push((id.clone(), at, *path, state.get_effective(*path)));
+        }
+    }
+    print_rows(&rows, args.json);
+    Ok(true)
+}
+
+/// Prints one entity's sampled trajectory. Always succeeds once loaded.
+fn series(args: &Args) -> Result<bool, CliError> {
+    let sim = scenario::load(Path::new(&args.files(1)?[0]))?;
+    let paths = args.state_paths()?;
+    let id = match &args.entity {
+        Some(id) => entity_id(id)?,
+        None => match entity_ids(&sim).as_slice() {
+            [only] => only.clone(),
+            _ => {
+                return Err(CliError::Usage(String::from(
+                    "--entity is required when the scenario has several entities",
+                )));
+            }
+        },
+    };
+    let from = match &args.from {
+        Some(date) => scenario::parse_timestamp(date)?,
+        None => sim.reference_date(),
+    };
+    let to = match &args.to {
+        Some(date) => scenario::parse_timestamp(date)?,
+        None => from + Duration::days(DEFAULT_SERIES_DAYS),
+    };
+    let step_days = match &args.step_days {
+        Some(days) => days
+            .parse::<u64>()
+            .ok()
+            .filter(|days| *days > 0)
+            .ok_or_else(|| CliError::Usage(format!("invalid --step-days '{}'", days)))?,
+        None => DEFAULT_STEP_DAYS,
+    };
+    let handle = sim
+        .entity(&id)
+        .ok_or_else(|| CliError::Usage(format!("no entity '{}' in scenario", id)))?;
+
+    let mut rows = Vec::new();
+    for path in paths {
+        for (at, value) in handle.series(path, from, to, Duration::days(step_days)) {
+            rows.push((id.clone(), at, path, value));
+        }
+    }
+    rows.sort_by_key(|(_, at, _, _)| *at);
+    print_rows(&rows, args.json);
+    Ok(true)
+}
+
+/// Prints validation issues. Fails if there are any.
+fn validate(args: &Args) -> Result<bool, CliError> {
+    let sim = scenario::load(Path::new(&args.files(1)?[0]))?;
+    let issues: Vec<String> = sim.validate().iter().map(ToString::to_string).collect();
+    if args.json {
+        let report = json!({ "ok": issues.is_empty(), "issues": issues });
+        println!("{}", report);
+    } else if issues.is_empty() {
+        println!("ok");
+    } else {
+        for issue in &issues {
+            println!("{}", issue);
+        }
+    }
+    Ok(issues.is_empty())
+}
+
+/// Prints the changes from the first scenario to the second. Fails if
+/// they differ.
+fn diff(args: &Args) -> Result<bool, CliError> {
+    let files = args.files(2)?;
+    let a = scenario::load(Path::new(&files[0]))?;
+    let b = scenario::load(Path::new(&files[1]))?;
+    let diff = a.diff(&b);
+    if args.json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&diff).expect("a diff always serializes")
+        );
+    } else {
+        print!("{}", diff);
+    }
+    Ok(diff.is_empty())
+}
+
+/// Returns every entity's ID in sorted order.
+fn entity_ids(sim: &Simulation) -> Vec<EntityId> {
+    let mut ids: Vec<EntityId> = sim.entities().map(|e| e.entity().id().clone()).collect();
+    ids.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+    ids
+}
+
+fn entity_id(value: &str) -> Result<EntityId, CliError> {
+    EntityId::new(value).map_err(|_| CliError::Usage(format!("invalid entity ID '{}'", value)))
+}
+
+/// Parses `ID@DATE`.
+fn parse_query(query: &str) -> Result<(EntityId, Timestamp), CliError> {
+    let (id, date) = query
+        .rsplit_once('@')
+        .ok_or_else(|| CliError::Usage(format!("query '{}' is not ID@DATE", query)))?;
+    Ok((entity_id(id)?, scenario::parse_timestamp(date)?))
+}
+
+/// Parses a path like `mood.valence` or `MentalHealth::Depression`.
+///
+/// Matching ignores case and any characters other than letters, digits,
+/// and the separator, so `social_cognition.loneliness` finds
+/// `SocialCognition::Loneliness`.
+fn parse_path(name: &str) -> Result<StatePath, CliError> {
+    let wanted = normalize_path(name);
+    StatePath::all()
+        .into_iter()
+        .find(|path| normalize_path(&path.to_string()) == wanted)
+        .ok_or_else(|| CliError::Usage(format!("unknown state path '{}'", name.trim())))
+}
+
+fn normalize_path(name: &str) -> String {
+    name.trim()
+        .replace("::", ".")
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '.')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Prints `(entity, timestamp, path, value)` rows as a table or JSON array.
+fn print_rows(rows: &[(EntityId, Timestamp, StatePath, f64)], as_json: bool) {
+    if as_json {
+        let rows: Vec<_> = rows
+            .iter()
+            .map(|(id, at, path, value)| {
+                json!({
+                    "entity": id.as_str(),
+                    "timestamp": at.to_string(),
+                    "path": path.to_string(),
+                    "value": value,
+                })
+            })
+            .collect();
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&rows).expect("rows always serialize")
+        );
+        return;
+    }
+
+    let cells: Vec<[String; 4]> = rows
+        .iter()
+        .map(|(id, at, path, value)| {
+            [
+                id.to_string(),
+                at.to_string(),
+                path.to_string(),
+                format!("{:.4}", value),
+            ]
+        })
+        .collect();
+    let header = ["entity", "timestamp", "path", "value"].map(String::from);
+    let mut widths = header.clone().map(|h| h.len());
+    for row in &cells {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+    for row in std::iter::once(&header).chain(&cells) {
+        println!(
+            "{:<w0$}  {:<w1$}  {:<w2$}  {:>w3$}",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use behavioral_pathways::enums::{MentalHealthPath, MoodPath, SocialCognitionPath};
+
+    #[test]
+    fn paths_parse_in_dotted_and_display_forms() {
+        assert_eq!(
+            parse_path("mood.valence").unwrap(),
+            StatePath::Mood(MoodPath::Valence)
+        );
+        assert_eq!(
+            parse_path("mental_health.depression").unwrap(),
+            StatePath::MentalHealth(MentalHealthPath::Depression)
+        );
+        assert_eq!(
+            parse_path("SocialCognition::Loneliness").unwrap(),
+            StatePath::SocialCognition(SocialCognitionPath::Loneliness)
+        );
+        assert!(parse_path("mood.happiness").is_err());
+    }
+
+    #[test]
+    fn queries_split_on_the_last_at_sign() {
+        let (id, at) = parse_query("person_001@2024-06-01").unwrap();
+        assert_eq!(id.as_str(), "person_001");
+        assert_eq!(at, Timestamp::from_ymd_hms(2024, 6, 1, 0, 0, 0));
+        assert!(parse_query("person_001").is_err());
+    }
+}
@@ -0,0 +1,347 @@
+//! TOML scenario files.
+//!
+//! A scenario declares a reference date, entities, events, and
+//! relationships, and is built into a `Simulation` with
+//! `SimulationBuilder`, so unknown entity references fail the load:
+//!
+//! ```toml
+//! reference = "2024-01-01"
+//! external = ["grandmother"]
+//!
+//! [[entity]]
+//! id = "person_001"
+//! species = "Human"          # default Human
+//! age_years = 34             # optional
+//! birth_date = "1990-01-01"  # optional
+//! anchor = "2024-01-01"      # default: reference
+//!
+//! [[event]]
+//! id = "layoff"              # default: event_<index>
+//! type = "JobLoss"
+//! target = "person_001"
+//! source = "person_002"      # optional
+//! severity = 0.7             # optional
+//! objective_severity = 0.5   # optional
+//! at = "2024-03-01"
+//!
+//! [[relationship]]
+//! a = "person_001"
+//! b = "person_002"
+//! schema = "Peer"            # default Peer; e.g. "Estranged Parent"
+//! formed = "2020-01-01"      # default: reference
+//! ```
+//!
+//! Dates are `YYYY-MM-DD` or `YYYY-MM-DD HH:MM:SS`. Event types and
+//! species use their Rust variant names. Events without an `id` are
+//! numbered by position, so the same file always yields the same IDs and
+//! two versions of a scenario diff cleanly.
+
+use behavioral_pathways::entity::{EntityBuildError, EntityBuilder};
+use behavioral_pathways::enums::{EventType, RelationshipSchema, Species};
+use behavioral_pathways::event::{EventBuildError, EventBuilder};
+use behavioral_pathways::simulation::{Simulation, SimulationBuildError, SimulationBuilder};
+use behavioral_pathways::types::{Duration, EntityId, EventId, Timestamp};
+use serde::Deserialize;
+use std::fmt;
+use std::path::Path;
+
+/// Errors from loading a scenario file.
+#[derive(Debug)]
+pub enum ScenarioError {
+    /// The file could not be read.
+    Io {
+        /// The file's path.
+        path: String,
+        /// The underlying error.
+        source: std::io::Error,
+    },
+    /// The file is not valid TOML or does not match the scenario schema.
+    Parse {
+        /// The file's path.
+        path: String,
+        /// The parser's message.
+        message: String,
+    },
+    /// A date is not `YYYY-MM-DD` or `YYYY-MM-DD HH:MM:SS`.
+    InvalidTimestamp(String),
+    /// An entity or event ID is empty.
+    InvalidId(String),
+    /// A relationship names a schema that does not exist.
+    UnknownSchema(String),
+    /// An entity failed to build.
+    Entity(EntityBuildError),
+    /// An event failed to build.
+    Event(EventBuildError),
+    /// The simulation failed to build.
+    Simulation(SimulationBuildError),
+}
+
+impl fmt::Display for ScenarioError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScenarioError::Io { path, source } => write!(f, "cannot read {}: {}", path, source),
+            ScenarioError::Parse { path, message } => {
+                write!(f, "invalid scenario {}: {}", path, message.trim_end())
+            }
+            ScenarioError::InvalidTimestamp(value) => write!(
+                f,
+                "invalid date '{}' (expected YYYY-MM-DD or YYYY-MM-DD HH:MM:SS)",
+                value
+            ),
+            ScenarioError::InvalidId(value) => write!(f, "invalid ID '{}'", value),
+            ScenarioError::UnknownSchema(name) => {
+                write!(f, "unknown relationship schema '{}'", name)
+            }
+            ScenarioError::Entity(err) => write!(f, "invalid entity: {}", err),
+            ScenarioError::Event(err) => write!(f, "invalid event: {}", err),
+            ScenarioError::Simulation(err) => write!(f, "invalid scenario: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ScenarioError {}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ScenarioFile {
+    reference: String,
+    #[serde(default)]
+    external: Vec<String>,
+    #[serde(default)]
+    entity: Vec<EntitySpec>,
+    #[serde(default)]
+    event: Vec<EventSpec>,
+    #[serde(default)]
+    relationship: Vec<RelationshipSpec>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EntitySpec {
+    id: String,
+    #[serde(default)]
+    species: Species,
+    age_years: Option<u64>,
+    birth_date: Option<String>,
+    anchor: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EventSpec {
+    id: Option<String>,
+    #[serde(rename = "type")]
+    event_type: EventType,
+    target: Option<String>,
+    source: Option<String>,
+    severity: Option<f64>,
+    objective_severity: Option<f64>,
+    at: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RelationshipSpec {
+    a: String,
+    b: String,
+    schema: Option<String>,
+    formed: Option<String>,
+}
+
+/// Reads and builds the scenario at `path`.
+pub fn load(path: &Path) -> Result<Simulation, ScenarioError> {
+    let display = path.display().to_string();
+    let text = std::fs::read_to_string(path).map_err(|source| ScenarioError::Io {
+        path: display.clone(),
+        source,
+    })?;
+    parse(&text).map_err(|err| match err {
+        ScenarioError::Parse { message, .. } => ScenarioError::Parse {
+            path: display,
+            message,
+        },
+        other => other,
+    })
+}
+
+/// Builds a scenario from TOML text.
+pub fn parse(text: &str) -> Result<Simulation, ScenarioError> {
+    let file: ScenarioFile = toml::from_str(text).map_err(|err| ScenarioError::Parse {
+        path: String::from("<input>"),
+        message: err.to_string(),
+    })?;
+    let reference = parse_timestamp(&file.reference)?;
+    let mut builder = SimulationBuilder::new(reference);
+
+    for id in &file.external {
+        builder = builder.declare_external_entity(entity_id(id)?);
+    }
+    for spec in file.entity {
+        let mut entity = EntityBuilder::new().id(spec.id).species(spec.species);
+        if let Some(years) = spec.age_years {
+            entity = entity.age(Duration::years(years));
+        }
+        if let Some(birth_date) = &spec.birth_date {
+            entity = entity.birth_date(parse_timestamp(birth_date)?);
+        }
+        let anchor = match &spec.anchor {
+            Some(anchor) => parse_timestamp(anchor)?,
+            None => reference,
+        };
+        builder = builder.add_entity(entity.build().map_err(ScenarioError::Entity)?, anchor);
+    }
+    for (index, spec) in file.event.into_iter().enumerate() {
+        let id = spec.id.unwrap_or_else(|| format!("event_{}", index));
+        let id = EventId::new(id.clone()).map_err(|_| ScenarioError::InvalidId(id))?;
+        let mut event = EventBuilder::new(spec.event_type).id(id);
+        if let Some(target) = &spec.target {
+            event = event.target(entity_id(target)?);
+        }
+        if let Some(source) = &spec.source {
+            event = event.source(entity_id(source)?);
+        }
+        if let Some(severity) = spec.severity {
+            event = event.severity(severity);
+        }
+        if let Some(objective) = spec.objective_severity {
+            event = event.objective_severity(objective);
+        }
+        let event = event.build().map_err(ScenarioError::Event)?;
+        builder = builder.add_event(event, parse_timestamp(&spec.at)?);
+    }
+    for spec in file.relationship {
+        let schema = match &spec.schema {
+            Some(name) => schema_named(name)?,
+            None => RelationshipSchema::default(),
+        };
+        let formed = match &spec.formed {
+            Some(formed) => parse_timestamp(formed)?,
+            None => reference,
+        };
+        builder =
+            builder.add_relationship(entity_id(&spec.a)?, entity_id(&spec.b)?, schema, formed);
+    }
+
+    builder.build().map_err(ScenarioError::Simulation)
+}
+
+/// Parses `YYYY-MM-DD` (midnight) or `YYYY-MM-DD HH:MM:SS`.
+pub fn parse_timestamp(value: &str) -> Result<Timestamp, ScenarioError> {
+    let value = value.trim();
+    let full = if value.len() == 10 {
+        format!("{} 00:00:00", value)
+    } else {
+        value.to_string()
+    };
+    Timestamp::from_str(&full).map_err(|_| ScenarioError::InvalidTimestamp(value.to_string()))
+}
+
+/// Built-in schemas a scenario can name; custom templates are code-only.
+const SCHEMAS: [RelationshipSchema; 11] = [
+    RelationshipSchema::Peer,
+    RelationshipSchema::Mentor,
+    RelationshipSchema::Subordinate,
+    RelationshipSchema::Romantic,
+    RelationshipSchema::Family,
+    RelationshipSchema::Nuclear,
+    RelationshipSchema::Extended,
+    RelationshipSchema::Rival,
+    RelationshipSchema::Sibling,
+    RelationshipSchema::Coworker,
+    RelationshipSchema::EstrangedParent,
+];
+
+/// Finds a schema by its variant name or display name, ignoring case and
+/// spaces, so `EstrangedParent` and `Estranged Parent` both work.
+fn schema_named(name: &str) -> Result<RelationshipSchema, ScenarioError> {
+    let wanted = normalize(name);
+    SCHEMAS
+        .into_iter()
+        .find(|schema| {
+            normalize(schema.name()) == wanted || normalize(&format!("{:?}", schema)) == wanted
+        })
+        .ok_or_else(|| ScenarioError::UnknownSchema(name.to_string()))
+}
+
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| !c.is_whitespace() && *c != '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn entity_id(value: &str) -> Result<EntityId, ScenarioError> {
+    EntityId::new(value).map_err(|_| ScenarioError::InvalidId(value.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_dates_with_and_without_time() {
+        assert_eq!(
+            parse_timestamp("2024-06-01").unwrap(),
+            Timestamp::from_ymd_hms(2024, 6, 1, 0, 0, 0)
+        );
+        assert_eq!(
+            parse_timestamp("2024-06-01 12:30:00").unwrap(),
+            Timestamp::from_ymd_hms(2024, 6, 1, 12, 30, 0)
+        );
+        assert!(parse_timestamp("June 1st").is_err());
+    }
+
+    #[test]
+    fn unnamed_events_are_numbered_by_position() {
+        let sim = parse(
+            r#"
+            reference = "2024-01-01"
+            [[entity]]
+            id = "ana"
+            [[event]]
+            type = "Conflict"
+            target = "ana"
+            at = "2024-02-01"
+            "#,
+        )
+        .unwrap();
+        let ids: Vec<_> = sim
+            .all_events()
+            .map(|te| te.event().id().as_str())
+            .collect();
+        assert_eq!(ids, ["event_0"]);
+    }
+
+    #[test]
+    fn unknown_schema_and_target_are_errors() {
+        let unknown_schema = parse(
+            r#"
+            reference = "2024-01-01"
+            [[relationship]]
+            a = "ana"
+            b = "ben"
+            schema = "Nemesis"
+            "#,
+        );
+        assert!(matches!(
+            unknown_schema,
+            Err(ScenarioError::UnknownSchema(_))
+        ));
+
+        let unknown_target = parse(
+            r#"
+            reference = "2024-01-01"
+            [[event]]
+            type = "Conflict"
+            target = "nobody"
+            at = "2024-02-01"
+            "#,
+        );
+        assert!(matches!(
+            unknown_target,
+            Err(ScenarioError::Simulation(
+                SimulationBuildError::EventReferencesUnknownEntity(_, _)
+            ))
+        ));
+    }
+}
@@ -0,0 +1,178 @@
+//! Integration tests for the `bp-cli` binary.
+//!
+//! Tests that each command runs against the fixture scenarios in
+//! `tests/fixtures/`, prints tables or JSON to stdout, and exits nonzero
+//! on validation failures, differences, and bad input.
+
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+fn fixture(name: &str) -> String {
+    let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "tests", "fixtures", name]
+        .iter()
+        .collect();
+    path.display().to_string()
+}
+
+fn bp_cli(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_bp-cli"))
+        .args(args)
+        .output()
+        .expect("bp-cli runs")
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).expect("stdout is UTF-8")
+}
+
+/// Tests that `run` prints the queried paths for one entity and date.
+///
+/// Validates: `ID@DATE` queries, dotted path names, and the table layout.
+#[test]
+fn run_prints_queried_paths_as_a_table() {
+    let output = bp_cli(&[
+        "run",
+        &fixture("baseline.toml"),
+        "--query",
+        "person_001@2024-06-01",
+        "--paths",
+        "mood.valence,mental_health.depression",
+    ]);
+    assert!(output.status.success());
+
+    let text = stdout(&output);
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("entity"));
+    assert!(lines[1].starts_with("person_001  2024-06-01 00:00:00  Mood::Valence"));
+    assert!(lines[2].contains("MentalHealth::Depression"));
+}
+
+/// Tests that `run --json` reports the layoff's effect on mood.
+///
+/// Validates: JSON rows carry entity, timestamp, path, and value, and the
+/// state reflects events before the queried date.
+#[test]
+fn run_json_reflects_events_before_the_query() {
+    let output = bp_cli(&[
+        "run",
+        &fixture("baseline.toml"),
+        "--query",
+        "person_001@2024-02-01",
+        "--query",
+        "person_001@2024-03-02",
+        "--paths",
+        "mood.valence",
+        "--json",
+    ]);
+    assert!(output.status.success());
+
+    let rows: serde_json::Value = serde_json::from_str(&stdout(&output)).unwrap();
+    let rows = rows.as_array().unwrap();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0]["entity"], "person_001");
+    assert_eq!(rows[0]["path"], "Mood::Valence");
+    assert_eq!(rows[1]["timestamp"], "2024-03-02 00:00:00");
+    let before = rows[0]["value"].as_f64().unwrap();
+    let after = rows[1]["value"].as_f64().unwrap();
+    assert!(after < before);
+}
+
+/// Tests that `series` samples one entity at the requested step.
+///
+/// Validates: `--from`, `--to`, and `--step-days` bound the samples.
+#[test]
+fn series_samples_between_dates() {
+    let output = bp_cli(&[
+        "series",
+        &fixture("baseline.toml"),
+        "--entity",
+        "person_001",
+        "--from",
+        "2024-01-01",
+        "--to",
+        "2024-03-31",
+        "--step-days",
+        "30",
+        "--paths",
+        "needs.stress",
+        "--json",
+    ]);
+    assert!(output.status.success());
+
+    let rows: serde_json::Value = serde_json::from_str(&stdout(&output)).unwrap();
+    let timestamps: Vec<&str> = rows
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|row| row["timestamp"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        timestamps,
+        [
+            "2024-01-01 00:00:00",
+            "2024-01-31 00:00:00",
+            "2024-03-01 00:00:00",
+            "2024-03-31 00:00:00",
+        ]
+    );
+}
+
+/// Tests that `validate` passes a plausible scenario and fails an
+/// implausible one.
+///
+/// Validates: "ok" with exit 0, and issues on stdout with exit 1.
+#[test]
+fn validate_exits_nonzero_on_issues() {
+    let clean = bp_cli(&["validate", &fixture("baseline.toml")]);
+    assert!(clean.status.success());
+    assert_eq!(stdout(&clean).trim(), "ok");
+
+    let flagged = bp_cli(&["validate", &fixture("implausible.toml")]);
+    assert_eq!(flagged.status.code(), Some(1));
+    assert!(stdout(&flagged).contains("Event 'lost_keys'"));
+
+    let flagged_json = bp_cli(&["validate", &fixture("implausible.toml"), "--json"]);
+    assert_eq!(flagged_json.status.code(), Some(1));
+    let report: serde_json::Value = serde_json::from_str(&stdout(&flagged_json)).unwrap();
+    assert_eq!(report["ok"], false);
+    assert_eq!(report["issues"].as_array().unwrap().len(), 1);
+}
+
+/// Tests that `diff` lists changed and removed events.
+///
+/// Validates: the diff's text form, and exit 0 only for identical
+/// scenarios.
+#[test]
+fn diff_reports_event_changes() {
+    let same = bp_cli(&["diff", &fixture("baseline.toml"), &fixture("baseline.toml")]);
+    assert!(same.status.success());
+    assert_eq!(stdout(&same).trim(), "no differences");
+
+    let changed = bp_cli(&["diff", &fixture("baseline.toml"), &fixture("variant.toml")]);
+    assert_eq!(changed.status.code(), Some(1));
+    let text = stdout(&changed);
+    assert!(text.contains("- event argument"));
+    assert!(text.contains("~ event layoff"));
+}
+
+/// Tests that usage and scenario errors exit with status 2.
+///
+/// Validates: errors go to stderr, leaving stdout empty.
+#[test]
+fn bad_input_exits_with_status_two() {
+    let missing = bp_cli(&["run", &fixture("missing.toml")]);
+    assert_eq!(missing.status.code(), Some(2));
+    assert!(stdout(&missing).is_empty());
+
+    let bad_path = bp_cli(&[
+        "run",
+        &fixture("baseline.toml"),
+        "--paths",
+        "mood.happiness",
+    ]);
+    assert_eq!(bad_path.status.code(), Some(2));
+
+    let unknown_command = bp_cli(&["simulate"]);
+    assert_eq!(unknown_command.status.code(), Some(2));
+}
@@ -0,0 +1,102 @@
+//! Dyad trust evolution.
+//!
+//! Two colleagues form a relationship. Bob helps Alice through a hard
+//! month, then betrays a confidence. `relationship_at()` reconstructs the
+//! relationship at each point so Alice's trust in Bob can be read as it
+//! stood then, and `predict_interaction()` shows how willing she is to
+//! lean on him for support.
+//!
+//! Run with `cargo run --example dyad_trust_evolution`. The assertions also
+//! run under `cargo test` via `tests/examples.rs`.
+
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{
+    Audience, Direction, EventPayload, EventType, RelationshipSchema, Species, SupportType,
+    TrustDomain,
+};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+pub fn main() {
+    let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let mut sim = Simulation::new(reference);
+    for name in ["alice", "bob"] {
+        let entity = EntityBuilder::new()
+            .id(name)
+            .species(Species::Human)
+            .build()
+            .unwrap();
+        sim.add_entity(entity, reference);
+    }
+    let alice = EntityId::new("alice").unwrap();
+    let bob = EntityId::new("bob").unwrap();
+
+    let formed = reference + Duration::days(10);
+    let peer = RelationshipSchema::Peer;
+    let rel_id = sim.add_relationship(alice.clone(), bob.clone(), peer, formed);
+
+    // Bob helps Alice weekly for a month
+    for week in 1..=4 {
+        let help = EventBuilder::new(EventType::Support)
+            .source(bob.clone())
+            .target(alice.clone())
+            .severity(0.7)
+            .payload(EventPayload::Support {
+                support_type: SupportType::Emotional,
+                effectiveness: 0.9,
+                audience: Audience::Private,
+            })
+            .build()
+            .unwrap();
+        sim.add_event(help, formed + Duration::weeks(week));
+    }
+
+    // Then shares something she told him in confidence
+    let betrayed_at = formed + Duration::days(60);
+    let betrayal = EventBuilder::new(EventType::Betrayal)
+        .source(bob.clone())
+        .target(alice.clone())
+        .severity(0.8)
+        .payload(EventPayload::Betrayal {
+            confidence_violated: 0.9,
+        })
+        .build()
+        .unwrap();
+    sim.add_event(betrayal, betrayed_at);
+
+    assert!(sim.relationship_at(&rel_id, reference).is_none());
+
+    let checkpoints = [
+        ("formed", formed),
+        ("after support", formed + Duration::days(35)),
+        ("after betrayal", betrayed_at + Duration::days(1)),
+    ];
+    let mut benevolence = Vec::new();
+    let mut integrity = Vec::new();
+    let mut willingness = Vec::new();
+    for (label, at) in checkpoints {
+        let relationship = sim.relationship_at(&rel_id, at).unwrap();
+        let trust = relationship.trustworthiness(Direction::AToB);
+        let decision = sim
+            .predict_interaction(&alice, &bob, TrustDomain::Support, at)
+            .unwrap();
+        println!(
+            "{label}: benevolence {:.3}, integrity {:.3}, support willingness {:.3}",
+            trust.benevolence_effective(),
+            trust.integrity_effective(),
+            decision.support_willingness()
+        );
+        benevolence.push(trust.benevolence_effective());
+        integrity.push(trust.integrity_effective());
+        willingness.push(decision.support_willingness());
+    }
+
+    // Support builds perceived benevolence and willingness to rely on Bob
+    assert!(benevolence[1] > benevolence[0]);
+    assert!(willingness[1] > willingness[0]);
+
+    // The betrayal damages integrity and erases the gain in willingness
+    assert!(integrity[2] < integrity[1]);
+    assert!(willingness[2] < willingness[0]);
+}
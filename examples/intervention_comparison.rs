@@ -0,0 +1,88 @@
+//! Intervention comparison.
+//!
+//! A person is repeatedly excluded over a week. Before committing to an
+//! intervention, `preview_event()` projects each candidate against the same
+//! history without modifying the simulation, so the options can be ranked
+//! by their effect on thwarted belongingness the next day.
+//!
+//! Run with `cargo run --example intervention_comparison`. The assertions
+//! also run under `cargo test` via `tests/examples.rs`.
+
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{
+    Audience, EventPayload, EventType, MentalHealthPath, Species, StatePath, SupportType,
+};
+use behavioral_pathways::event::{Event, EventBuilder};
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+fn candidates(target: &EntityId) -> Vec<(&'static str, Event)> {
+    let emotional_support = EventBuilder::new(EventType::Support)
+        .target(target.clone())
+        .severity(0.7)
+        .payload(EventPayload::Support {
+            support_type: SupportType::Emotional,
+            effectiveness: 0.8,
+            audience: Audience::Private,
+        })
+        .build()
+        .unwrap();
+    let inclusion = EventBuilder::new(EventType::SocialInclusion)
+        .target(target.clone())
+        .severity(0.7)
+        .build()
+        .unwrap();
+    vec![
+        ("emotional support", emotional_support),
+        ("group inclusion", inclusion),
+    ]
+}
+
+pub fn main() {
+    let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let mut sim = Simulation::new(reference);
+    let person = EntityBuilder::new()
+        .id("sam")
+        .species(Species::Human)
+        .build()
+        .unwrap();
+    let id = sim.add_entity(person, reference);
+
+    for day in [1, 3, 5, 7] {
+        let exclusion = EventBuilder::new(EventType::SocialExclusion)
+            .target(id.clone())
+            .severity(0.7)
+            .build()
+            .unwrap();
+        sim.add_event(exclusion, reference + Duration::days(day));
+    }
+
+    let intervene_at = reference + Duration::days(8);
+    let check_at = intervene_at + Duration::days(1);
+    let tb = StatePath::MentalHealth(MentalHealthPath::ThwartedBelongingness);
+
+    let handle = sim.entity(&id).unwrap();
+    let untreated = handle.state_at(check_at).get_effective(tb);
+    println!("no intervention: TB {untreated:.3}");
+
+    let mut ranked: Vec<(&str, f64)> = candidates(&id)
+        .iter()
+        .map(|(name, event)| {
+            let treated = handle.preview_event(event, intervene_at, check_at);
+            (*name, treated.get_effective(tb))
+        })
+        .collect();
+    ranked.sort_by(|a, b| a.1.total_cmp(&b.1));
+    for (name, value) in &ranked {
+        println!("{name}: TB {value:.3}");
+    }
+
+    // Every candidate helps, and inclusion in the group that excluded them
+    // restores belonging more than support from outside it
+    assert!(ranked.iter().all(|(_, value)| *value < untreated));
+    assert_eq!(ranked[0].0, "group inclusion");
+
+    // Previews never touch the simulation
+    assert_eq!(handle.event_count(), 4);
+    assert!((handle.state_at(check_at).get_effective(tb) - untreated).abs() < 1e-12);
+}
@@ -0,0 +1,101 @@
+//! Single-entity lifetime projection.
+//!
+//! Anchors one person in 2024, schedules a season of weekly social
+//! exclusion followed by an achievement, and samples their state across the
+//! following years with `series()` and `state_series()`.
+//!
+//! Run with `cargo run --example lifetime_projection`. The assertions also
+//! run under `cargo test` via `tests/examples.rs`.
+
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{
+    EventType, LifeStage, MentalHealthPath, SocialCognitionPath, Species, StatePath,
+};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, Timestamp};
+
+pub fn main() {
+    let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let mut sim = Simulation::new(reference);
+    let person = EntityBuilder::new()
+        .id("dana")
+        .species(Species::Human)
+        .birth_date(Timestamp::from_ymd_hms(1990, 6, 15, 0, 0, 0))
+        .build()
+        .unwrap();
+    let id = sim.add_entity(person, reference);
+
+    // Twelve weeks of being left out by a new team
+    let exclusion_start = reference + Duration::days(400);
+    for week in 0..12 {
+        let exclusion = EventBuilder::new(EventType::SocialExclusion)
+            .target(id.clone())
+            .severity(0.6)
+            .build()
+            .unwrap();
+        sim.add_event(exclusion, exclusion_start + Duration::weeks(week));
+    }
+
+    let promotion = EventBuilder::new(EventType::Achievement)
+        .target(id.clone())
+        .severity(0.6)
+        .build()
+        .unwrap();
+    sim.add_event(promotion, reference + Duration::days(900));
+
+    let handle = sim.entity(&id).unwrap();
+
+    // Yearly snapshots: age advances and the life stage follows it
+    let horizon = reference + Duration::years(30);
+    let yearly = handle.state_series(reference, horizon, Duration::years(1));
+    assert_eq!(yearly.len(), 31);
+    for window in yearly.windows(2) {
+        let (_, earlier) = &window[0];
+        let (_, later) = &window[1];
+        assert!(later.age_at_timestamp > earlier.age_at_timestamp);
+    }
+    assert_eq!(yearly[0].1.life_stage, LifeStage::Adult);
+    assert_eq!(yearly[30].1.life_stage, LifeStage::MatureAdult);
+
+    // Daily loneliness around the exclusion season: it rises while the
+    // exclusions continue and settles back toward baseline afterwards
+    let loneliness = StatePath::SocialCognition(SocialCognitionPath::Loneliness);
+    let daily = handle.series(
+        loneliness,
+        exclusion_start - Duration::weeks(2),
+        exclusion_start + Duration::weeks(40),
+        Duration::days(1),
+    );
+    let before = daily[0].1;
+    let (peak_at, peak) =
+        daily
+            .iter()
+            .copied()
+            .fold((exclusion_start, f64::MIN), |best, sample| {
+                if sample.1 > best.1 {
+                    sample
+                } else {
+                    best
+                }
+            });
+    let months_later = daily.last().unwrap().1;
+    assert!(peak > before + 0.1, "Exclusion should raise loneliness");
+    assert!(peak_at >= exclusion_start);
+    assert!(peak_at < exclusion_start + Duration::weeks(12));
+    assert!(
+        (months_later - before).abs() < 0.05,
+        "Loneliness should settle once the exclusions stop"
+    );
+
+    let depression = StatePath::MentalHealth(MentalHealthPath::Depression);
+    let final_depression = handle
+        .state_at(reference + Duration::years(5))
+        .get_effective(depression);
+    assert!((0.0..=1.0).contains(&final_depression));
+
+    println!("Loneliness before exclusion: {before:.3}");
+    println!("Peak loneliness:             {peak:.3}");
+    println!("Six months after:            {months_later:.3}");
+    println!("Depression in 2029:          {final_depression:.3}");
+}
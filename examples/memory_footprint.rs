@@ -0,0 +1,141 @@
+//! Memory footprint of a large synthetic simulation.
+//!
+//! Builds a population where every entity receives the same number of
+//! events, then reports the heap bytes still allocated once the
+//! simulation is built. A counting allocator wraps the system allocator,
+//! so the figure covers every live allocation made by the crate. On Linux
+//! the resident set size is printed as well.
+//!
+//! The run ends with a fingerprint of the state of a sample of entities,
+//! so storage changes can be checked for behavioral drift by comparing
+//! fingerprints before and after.
+//!
+//! Run with `cargo run --release --example memory_footprint`. Pass
+//! entity and event counts to change the scale; the reference run is
+//! 50,000 entities and 2,000,000 events:
+//!
+//! ```text
+//! cargo run --release --example memory_footprint -- 50000 2000000
+//! ```
+//!
+//! Reference run (Linux, release build, 50,000 entities, 2,000,000 events):
+//!
+//! | Storage | Live heap | VmRSS |
+//! |---------|-----------|-------|
+//! | `String` ids, events in one `Vec` scanned per query | 832.8 MiB | 935 MiB |
+//! | Shared `Arc<str>` ids, one event arena with per-entity `u32` indexes, shared payloads, rare event fields boxed, shared default context and config | 473.8 MiB | 488 MiB |
+//!
+//! The second layout uses 43% less heap, and both runs print the same
+//! fingerprint.
+
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{EventType, MoodPath, NeedsPath, SocialCognitionPath};
+use behavioral_pathways::enums::{Species, StatePath};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EntityId, MicrosystemId, Timestamp};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct CountingAllocator;
+
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        LIVE_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        LIVE_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const EVENT_TYPES: [EventType; 4] = [
+    EventType::Interaction,
+    EventType::Support,
+    EventType::Conflict,
+    EventType::Achievement,
+];
+
+fn build(entities: usize, events: usize) -> Simulation {
+    let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let mut sim = Simulation::new(reference);
+    let workplace = MicrosystemId::new("workplace").unwrap();
+
+    let ids: Vec<EntityId> = (0..entities)
+        .map(|index| {
+            let entity = EntityBuilder::new()
+                .id(format!("person_{index:06}"))
+                .species(Species::Human)
+                .build()
+                .unwrap();
+            sim.add_entity(entity, reference)
+        })
+        .collect();
+
+    for index in 0..events {
+        let target = &ids[index % entities];
+        let source = &ids[(index * 7 + 1) % entities];
+        let event = EventBuilder::new(EVENT_TYPES[index % EVENT_TYPES.len()])
+            .target(target.clone())
+            .source(source.clone())
+            .context(workplace.clone())
+            .severity(0.5)
+            .build()
+            .unwrap();
+        let day = (index / entities) as u64;
+        sim.add_event(event, reference + Duration::days(day));
+    }
+    sim
+}
+
+/// Hashes the exact state of the first `sample` entities at `at`.
+fn fingerprint(sim: &Simulation, sample: usize, at: Timestamp) -> u64 {
+    let paths = [
+        StatePath::Mood(MoodPath::Valence),
+        StatePath::Mood(MoodPath::Arousal),
+        StatePath::Needs(NeedsPath::Stress),
+        StatePath::SocialCognition(SocialCognitionPath::Loneliness),
+    ];
+    let mut hasher = DefaultHasher::new();
+    for index in 0..sample.min(sim.entity_count()) {
+        let id = EntityId::new(format!("person_{index:06}")).unwrap();
+        let state = sim.entity(&id).unwrap().state_at(at);
+        for path in paths {
+            state.get_effective(path).to_bits().hash(&mut hasher);
+        }
+    }
+    hasher.finish()
+}
+
+pub fn main() {
+    let mut args = std::env::args().skip(1);
+    let entities: usize = args.next().map_or(5_000, |arg| arg.parse().unwrap());
+    let events: usize = args.next().map_or(200_000, |arg| arg.parse().unwrap());
+
+    let before = LIVE_BYTES.load(Ordering::Relaxed);
+    let sim = build(entities, events);
+    let live = LIVE_BYTES.load(Ordering::Relaxed) - before;
+
+    println!("entities: {}", sim.entity_count());
+    println!("events:   {}", sim.all_events().count());
+    println!("heap:     {:.1} MiB", live as f64 / (1024.0 * 1024.0));
+    if let Ok(status) = std::fs::read_to_string("/proc/self/status") {
+        if let Some(line) = status.lines().find(|line| line.starts_with("VmRSS")) {
+            println!("{line}");
+        }
+    }
+    println!("per event (incl. entities): {} bytes", live / events.max(1));
+
+    let days = (events / entities.max(1)) as u64 + 1;
+    let at = sim.reference_date() + Duration::days(days);
+    println!("fingerprint: {:016x}", fingerprint(&sim, 100, at));
+}
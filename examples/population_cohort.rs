@@ -0,0 +1,103 @@
+//! Population cohort run.
+//!
+//! A plant closure lays off a cohort of workers on the same day. The
+//! workers differ only in neuroticism, so the run shows how one shared
+//! event spreads into different individual responses, and how the cohort
+//! as a whole recovers over the following week.
+//!
+//! Run with `cargo run --example population_cohort`. The assertions also
+//! run under `cargo test` via `tests/examples.rs`.
+
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{EventType, MoodPath, Species, StatePath};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::state::Hexaco;
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+const COHORT_SIZE: usize = 20;
+
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+pub fn main() {
+    let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let closure = reference + Duration::days(1);
+    let mut sim = Simulation::new(reference);
+
+    // Neuroticism evenly spread from -0.95 to 0.95 across the cohort
+    let mut cohort: Vec<(f32, EntityId)> = Vec::with_capacity(COHORT_SIZE);
+    for index in 0..COHORT_SIZE {
+        let neuroticism = -0.95 + 1.9 * index as f32 / (COHORT_SIZE - 1) as f32;
+        let worker = EntityBuilder::new()
+            .id(format!("worker_{index:02}"))
+            .species(Species::Human)
+            .hexaco(Hexaco::new().with_neuroticism(neuroticism))
+            .build()
+            .unwrap();
+        let id = sim.add_entity(worker, reference);
+        let layoff = EventBuilder::new(EventType::JobLoss)
+            .target(id.clone())
+            .severity(0.7)
+            .build()
+            .unwrap();
+        sim.add_event(layoff, closure);
+        cohort.push((neuroticism, id));
+    }
+    assert_eq!(sim.entity_count(), COHORT_SIZE);
+
+    // Valence for every worker from the day before to a week after, every
+    // six hours
+    let valence = StatePath::Mood(MoodPath::Valence);
+    let trajectories: Vec<Vec<(Timestamp, f64)>> = cohort
+        .iter()
+        .map(|(_, id)| {
+            sim.entity(id).unwrap().series(
+                valence,
+                closure - Duration::days(1),
+                closure + Duration::days(7),
+                Duration::hours(6),
+            )
+        })
+        .collect();
+    let samples = trajectories[0].len();
+    assert!(trajectories.iter().all(|t| t.len() == samples));
+
+    let cohort_mean_at = |index: usize| {
+        let values: Vec<f64> = trajectories.iter().map(|t| t[index].1).collect();
+        mean(&values)
+    };
+    let before_index = 0;
+    let shortly_after_index = 5;
+    let week_after_index = samples - 1;
+    assert_eq!(
+        trajectories[0][shortly_after_index].0,
+        closure + Duration::hours(6)
+    );
+
+    let before = cohort_mean_at(before_index);
+    let shortly_after = cohort_mean_at(shortly_after_index);
+    let week_after = cohort_mean_at(week_after_index);
+    println!("cohort valence before:    {before:.3}");
+    println!("cohort valence +6h:       {shortly_after:.3}");
+    println!("cohort valence +1 week:   {week_after:.3}");
+
+    // The closure hits the whole cohort, which recovers within the week
+    assert!(shortly_after < before - 0.1);
+    assert!((week_after - before).abs() < 0.01);
+
+    // Responses order by neuroticism: more neurotic workers are hit harder
+    let responses: Vec<f64> = trajectories
+        .iter()
+        .map(|t| t[shortly_after_index].1 - t[before_index].1)
+        .collect();
+    assert!(responses.windows(2).all(|pair| pair[1] <= pair[0]));
+
+    let half = COHORT_SIZE / 2;
+    let low = mean(&responses[..half]);
+    let high = mean(&responses[half..]);
+    println!("mean drop, low neuroticism:  {:.3}", -low);
+    println!("mean drop, high neuroticism: {:.3}", -high);
+    assert!(-high > -low + 0.05);
+}
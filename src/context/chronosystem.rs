@@ -9,11 +9,14 @@
 //! - Off-time penalties for transitions occurring outside expected windows
 //! - Developmental plasticity modifiers
 
-use crate::enums::{BirthEra, ChronosystemPath};
-use crate::types::{EventId, Timestamp};
+use crate::enums::{
+    BirthEra, ChronosystemPath, EventCategory, EventPayload, EventType, LifeDomain,
+};
+use crate::types::{EventId, Timestamp, UnitInterval};
 
 /// Domain of a turning point in life.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TurningPointDomain {
     /// Job/profession change.
     Career,
@@ -29,8 +32,62 @@ pub enum TurningPointDomain {
     Loss,
 }
 
+impl TurningPointDomain {
+    /// Returns the turning point domain for a life domain.
+    #[must_use]
+    pub const fn from_life_domain(domain: LifeDomain) -> Self {
+        match domain {
+            LifeDomain::Work | LifeDomain::Academic | LifeDomain::Financial => {
+                TurningPointDomain::Career
+            }
+            LifeDomain::Social | LifeDomain::Relationship => TurningPointDomain::Relationship,
+            LifeDomain::Health | LifeDomain::Athletic => TurningPointDomain::Health,
+            LifeDomain::Creative => TurningPointDomain::Identity,
+        }
+    }
+
+    /// Infers the area of life an event changes.
+    ///
+    /// A payload naming a `LifeDomain` decides first, then event types
+    /// with an obvious domain (bereavement is a loss, job loss a career
+    /// change), then the event's category: trauma is a health event,
+    /// social and burden events are relational, achievement events are
+    /// career events, and control and contextual events shift identity.
+    #[must_use]
+    pub fn for_event(event_type: EventType, payload: &EventPayload) -> Self {
+        match payload {
+            EventPayload::Empowerment { domain, .. }
+            | EventPayload::Achievement { domain, .. }
+            | EventPayload::Failure { domain, .. }
+            | EventPayload::RoleExit { domain, .. }
+            | EventPayload::Concealment { domain, .. }
+            | EventPayload::Disclosure { domain, .. } => {
+                return TurningPointDomain::from_life_domain(*domain);
+            }
+            _ => {}
+        }
+        match event_type {
+            EventType::Bereavement | EventType::SuicidalLoss | EventType::Loss => {
+                TurningPointDomain::Loss
+            }
+            EventType::JobLoss | EventType::FinancialBurden => TurningPointDomain::Career,
+            EventType::ChronicIllnessOnset | EventType::HealthChange => TurningPointDomain::Health,
+            EventType::ContextTransition => TurningPointDomain::Location,
+            _ => match event_type.category() {
+                EventCategory::Trauma => TurningPointDomain::Health,
+                EventCategory::SocialBelonging
+                | EventCategory::BurdenPerception
+                | EventCategory::Social => TurningPointDomain::Relationship,
+                EventCategory::Achievement => TurningPointDomain::Career,
+                EventCategory::Control | EventCategory::Contextual => TurningPointDomain::Identity,
+            },
+        }
+    }
+}
+
 /// A significant turning point in an entity's life.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TurningPoint {
     /// Reference to triggering event.
     pub event_id: EventId,
@@ -47,6 +104,7 @@ pub struct TurningPoint {
 
 /// A critical period where developmental effects are amplified.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CriticalPeriod {
     /// What domain is sensitive (attachment, identity, etc.).
     pub domain: String,
@@ -111,6 +169,7 @@ impl CriticalPeriod {
 /// - `early_transition_multiplier` - Stress increase for early transitions (default 1.3)
 /// - `late_transition_multiplier` - Stress increase for late transitions (default 1.5)
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NormativeTransition {
     /// Name of the transition (e.g., "marriage", "parenthood", "retirement").
     pub name: String,
@@ -280,6 +339,7 @@ impl NormativeTransition {
 
 /// A non-normative (historical) event affecting a cohort.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NonNormativeEvent {
     /// Name of the event (e.g., "great_recession", "pandemic").
     pub name: String,
@@ -331,6 +391,7 @@ impl NonNormativeEvent {
 
 /// Historical period context.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HistoricalPeriod {
     /// Name of current era.
     pub era_name: String,
@@ -358,6 +419,7 @@ impl Default for HistoricalPeriod {
 
 /// Cohort effects based on birth era and formative events.
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CohortEffects {
     /// Era when born.
     pub birth_era: BirthEra,
@@ -374,6 +436,7 @@ pub struct CohortEffects {
 /// Represents the dimension of time in ecological context, including
 /// historical events, life transitions, and developmental timing.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChronosystemContext {
     /// Historical period context.
     historical_period: HistoricalPeriod,
@@ -432,7 +495,7 @@ impl ChronosystemContext {
 
     /// Sets a value by chronosystem path.
     pub fn set_value(&mut self, path: &ChronosystemPath, value: f64) {
-        let clamped = value.clamp(0.0, 1.0);
+        let clamped = UnitInterval::clamped(value).get();
         match path {
             ChronosystemPath::StabilityLevel => self.historical_period.stability_level = clamped,
             ChronosystemPath::ResourceScarcity => {
@@ -647,7 +710,7 @@ impl ChronosystemContext {
 
     /// Sets the plasticity modifier.
     pub fn set_plasticity_modifier(&mut self, value: f64) {
-        self.plasticity_modifier = value.clamp(0.0, 1.0);
+        self.plasticity_modifier = UnitInterval::clamped(value).get();
     }
 
 }
@@ -696,6 +759,33 @@ mod tests {
         assert!((no_match - 1.0).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn turning_point_domain_for_event() {
+        let promotion = EventPayload::Empowerment {
+            domain: LifeDomain::Work,
+        };
+        assert_eq!(
+            TurningPointDomain::for_event(EventType::Empowerment, &promotion),
+            TurningPointDomain::Career
+        );
+        assert_eq!(
+            TurningPointDomain::for_event(EventType::Violence, &EventPayload::Empty),
+            TurningPointDomain::Health
+        );
+        assert_eq!(
+            TurningPointDomain::for_event(EventType::Bereavement, &EventPayload::Empty),
+            TurningPointDomain::Loss
+        );
+        assert_eq!(
+            TurningPointDomain::for_event(EventType::Betrayal, &EventPayload::Empty),
+            TurningPointDomain::Relationship
+        );
+        assert_eq!(
+            TurningPointDomain::for_event(EventType::ContextTransition, &EventPayload::Empty),
+            TurningPointDomain::Location
+        );
+    }
+
     #[test]
     fn turning_point_domain_eq() {
         assert_eq!(TurningPointDomain::Career, TurningPointDomain::Career);
@@ -0,0 +1,343 @@
+//! Concealment burden modeling.
+//!
+//! Keeping a stigmatized identity or secret hidden is a chronic stressor
+//! distinct from whatever is concealed. While a concealment is ongoing it
+//! adds a sustained load on top of event effects:
+//!
+//! - **Maintenance load**: stress and self-hate that build up over the
+//!   first weeks of concealment, hold while it lasts, and fade after it
+//!   ends.
+//! - **Suppressed buffering**: relationships in which the secret is kept
+//!   buffer loneliness less, because the entity cannot be fully known in
+//!   them.
+//!
+//! Concealments are declared by an event carrying an ongoing
+//! `EventPayload::Concealment`. The maintenance load ends at the payload's
+//! `until` timestamp or at the first later `EventPayload::Disclosure` in
+//! the same life domain. A disclosure lifts the suppression only for the
+//! relationship disclosed to; the secret is still kept in the others until
+//! `until`. The partner's reaction to a disclosure is applied once by event
+//! interpretation.
+
+use crate::enums::{EventPayload, LifeDomain};
+use crate::event::Event;
+#[cfg(feature = "context")]
+use crate::state::IndividualState;
+use crate::types::{RelationshipId, Timestamp};
+
+/// Stress added by a fully built-up concealment at full intensity.
+pub const CONCEALMENT_STRESS_LOAD: f32 = 0.12;
+
+/// Self-hate added by a fully built-up concealment at full intensity.
+pub const CONCEALMENT_SELF_HATE_LOAD: f32 = 0.1;
+
+/// Share of a relationship's loneliness buffering lost while a secret is
+/// kept in it at full intensity.
+pub const CONCEALMENT_SUPPORT_SUPPRESSION: f64 = 0.6;
+
+/// Days for the maintenance load to build halfway, and to fade halfway
+/// after concealment ends.
+const LOAD_HALF_LIFE_DAYS: f64 = 30.0;
+
+/// An ongoing concealment of a secret within a life domain.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::context::Concealment;
+/// use behavioral_pathways::enums::LifeDomain;
+/// use behavioral_pathways::types::{Duration, RelationshipId, Timestamp};
+///
+/// let onset = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+/// let partner = RelationshipId::new("rel_partner").unwrap();
+/// let concealment =
+///     Concealment::new(LifeDomain::Relationship, 0.8, onset, vec![partner.clone()])
+///         .with_disclosure(partner.clone(), onset + Duration::days(60));
+///
+/// assert!(concealment.is_active_at(onset + Duration::days(30)));
+/// assert!(!concealment.is_active_at(onset + Duration::days(90)));
+/// assert!(!concealment.conceals_from(&partner, onset + Duration::days(90)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Concealment {
+    /// Life domain of what is concealed.
+    pub domain: LifeDomain,
+
+    /// How much effort concealment takes (0-1).
+    pub intensity: f32,
+
+    /// When concealment began.
+    pub onset: Timestamp,
+
+    /// When concealment was planned to end, if it was.
+    pub until: Option<Timestamp>,
+
+    /// Relationships in which the secret is kept.
+    pub kept_from: Vec<RelationshipId>,
+
+    /// Disclosures made since onset, with the relationship disclosed to.
+    pub disclosures: Vec<(RelationshipId, Timestamp)>,
+}
+
+impl Concealment {
+    /// Creates an open-ended concealment with no disclosures.
+    #[must_use]
+    pub fn new(
+        domain: LifeDomain,
+        intensity: f32,
+        onset: Timestamp,
+        kept_from: Vec<RelationshipId>,
+    ) -> Self {
+        Concealment {
+            domain,
+            intensity: intensity.clamp(0.0, 1.0),
+            onset,
+            until: None,
+            kept_from,
+            disclosures: Vec::new(),
+        }
+    }
+
+    /// Sets when the concealment ends.
+    #[must_use]
+    pub fn with_until(mut self, timestamp: Timestamp) -> Self {
+        self.until = Some(timestamp);
+        self
+    }
+
+    /// Records a disclosure to the given relationship.
+    #[must_use]
+    pub fn with_disclosure(mut self, relationship: RelationshipId, timestamp: Timestamp) -> Self {
+        self.disclosures.push((relationship, timestamp));
+        self
+    }
+
+    /// Builds a concealment from an event carrying an ongoing
+    /// `EventPayload::Concealment`.
+    ///
+    /// Returns `None` for one-off concealments and any other payload.
+    #[must_use]
+    pub fn from_event(event: &Event, timestamp: Timestamp) -> Option<Self> {
+        match event.payload() {
+            EventPayload::Concealment {
+                domain,
+                intensity,
+                ongoing: true,
+                kept_from,
+                until,
+            } => {
+                let concealment =
+                    Concealment::new(*domain, *intensity as f32, timestamp, kept_from.clone());
+                Some(match until {
+                    Some(until) => concealment.with_until(*until),
+                    None => concealment,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns when the maintenance load ends: at `until` or the first
+    /// disclosure, whichever comes first.
+    #[must_use]
+    pub fn ends_at(&self) -> Option<Timestamp> {
+        self.disclosures
+            .iter()
+            .map(|(_, timestamp)| *timestamp)
+            .chain(self.until)
+            .min()
+    }
+
+    /// Returns true if the concealment has begun and not yet ended.
+    #[must_use]
+    pub fn is_active_at(&self, timestamp: Timestamp) -> bool {
+        timestamp >= self.onset && self.ends_at().is_none_or(|end| timestamp < end)
+    }
+
+    /// Returns true if the secret is still kept in `relationship` at
+    /// `timestamp`.
+    ///
+    /// Disclosing to other partners does not lift the suppression; only
+    /// `until` or a disclosure to this relationship does.
+    #[must_use]
+    pub fn conceals_from(&self, relationship: &RelationshipId, timestamp: Timestamp) -> bool {
+        timestamp >= self.onset
+            && self.until.is_none_or(|until| timestamp < until)
+            && self.kept_from.contains(relationship)
+            && !self
+                .disclosures
+                .iter()
+                .any(|(disclosed, at)| disclosed == relationship && *at <= timestamp)
+    }
+
+    /// Returns the maintenance load at `timestamp` (0-1).
+    ///
+    /// The load builds toward the intensity while concealment lasts and
+    /// fades after it ends, both with a 30-day half-life.
+    #[must_use]
+    pub fn load_at(&self, timestamp: Timestamp) -> f32 {
+        if timestamp <= self.onset {
+            return 0.0;
+        }
+        let end = self
+            .ends_at()
+            .map_or(timestamp, |end| end.clamp(self.onset, timestamp));
+        let built = 1.0 - 0.5_f64.powf((end - self.onset).as_days_f64() / LOAD_HALF_LIFE_DAYS);
+        let faded = 0.5_f64.powf((timestamp - end).as_days_f64() / LOAD_HALF_LIFE_DAYS);
+        self.intensity * (built * faded) as f32
+    }
+}
+
+/// Applies the maintenance load of concealments to state.
+///
+/// Called by `state_at()` after context, role exit, and health condition
+/// effects. Concealments with onset after `current_timestamp` are ignored.
+///
+/// # Arguments
+///
+/// * `state` - The current state after decay, events, and context effects
+/// * `concealments` - Concealments that began in the queried range
+/// * `current_timestamp` - Absolute timestamp for the current state
+#[cfg(feature = "context")]
+#[must_use]
+pub(crate) fn apply_concealment_effects(
+    mut state: IndividualState,
+    concealments: &[Concealment],
+    current_timestamp: Timestamp,
+) -> IndividualState {
+    for concealment in concealments {
+        let load = concealment.load_at(current_timestamp);
+        state
+            .needs_mut()
+            .add_stress_delta(CONCEALMENT_STRESS_LOAD * load);
+        state
+            .social_cognition_mut()
+            .add_self_hate_delta(CONCEALMENT_SELF_HATE_LOAD * load);
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::EventType;
+    use crate::event::EventBuilder;
+    use crate::types::Duration;
+
+    fn onset() -> Timestamp {
+        Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+    }
+
+    fn rel(id: &str) -> RelationshipId {
+        RelationshipId::new(id).unwrap()
+    }
+
+    fn concealment() -> Concealment {
+        Concealment::new(
+            LifeDomain::Relationship,
+            0.8,
+            onset(),
+            vec![rel("rel_partner"), rel("rel_parent")],
+        )
+    }
+
+    #[test]
+    fn from_event_reads_ongoing_payload() {
+        let until = onset() + Duration::days(200);
+        let event = EventBuilder::new(EventType::Interaction)
+            .payload(EventPayload::Concealment {
+                domain: LifeDomain::Health,
+                intensity: 0.6,
+                ongoing: true,
+                kept_from: vec![rel("rel_partner")],
+                until: Some(until),
+            })
+            .build()
+            .unwrap();
+
+        let concealment = Concealment::from_event(&event, onset()).unwrap();
+        assert_eq!(concealment.domain, LifeDomain::Health);
+        assert!((concealment.intensity - 0.6).abs() < 1e-6);
+        assert_eq!(concealment.kept_from, vec![rel("rel_partner")]);
+        assert_eq!(concealment.ends_at(), Some(until));
+    }
+
+    #[test]
+    fn from_event_ignores_one_off_and_other_payloads() {
+        let one_off = EventBuilder::new(EventType::Interaction)
+            .payload(EventPayload::Concealment {
+                domain: LifeDomain::Work,
+                intensity: 0.5,
+                ongoing: false,
+                kept_from: Vec::new(),
+                until: None,
+            })
+            .build()
+            .unwrap();
+        assert!(Concealment::from_event(&one_off, onset()).is_none());
+
+        let other = EventBuilder::new(EventType::Interaction).build().unwrap();
+        assert!(Concealment::from_event(&other, onset()).is_none());
+    }
+
+    #[test]
+    fn first_disclosure_or_until_ends_activity() {
+        let disclosed = concealment()
+            .with_until(onset() + Duration::days(120))
+            .with_disclosure(rel("rel_partner"), onset() + Duration::days(60));
+        assert!(!disclosed.is_active_at(onset() - Duration::days(1)));
+        assert!(disclosed.is_active_at(onset() + Duration::days(59)));
+        assert!(!disclosed.is_active_at(onset() + Duration::days(60)));
+
+        let planned = concealment().with_until(onset() + Duration::days(120));
+        assert!(planned.is_active_at(onset() + Duration::days(119)));
+        assert!(!planned.is_active_at(onset() + Duration::days(120)));
+    }
+
+    #[test]
+    fn disclosure_lifts_suppression_only_for_its_relationship() {
+        let disclosed =
+            concealment().with_disclosure(rel("rel_partner"), onset() + Duration::days(60));
+        let later = onset() + Duration::days(90);
+
+        assert!(disclosed.conceals_from(&rel("rel_partner"), onset() + Duration::days(30)));
+        assert!(!disclosed.conceals_from(&rel("rel_partner"), later));
+        assert!(disclosed.conceals_from(&rel("rel_parent"), later));
+        assert!(!disclosed.conceals_from(&rel("rel_friend"), later));
+
+        let ended = concealment().with_until(onset() + Duration::days(60));
+        assert!(!ended.conceals_from(&rel("rel_parent"), later));
+    }
+
+    #[test]
+    fn load_builds_while_active_and_fades_after() {
+        let open = concealment();
+        let month = open.load_at(onset() + Duration::days(30));
+        let year = open.load_at(onset() + Duration::days(365));
+        assert!(open.load_at(onset()).abs() < f32::EPSILON);
+        assert!((month - 0.4).abs() < 1e-3);
+        assert!(year > month && year <= 0.8);
+
+        let disclosed =
+            concealment().with_disclosure(rel("rel_partner"), onset() + Duration::days(30));
+        let faded = disclosed.load_at(onset() + Duration::days(60));
+        assert!((faded - 0.2).abs() < 1e-3);
+    }
+
+    #[cfg(feature = "context")]
+    #[test]
+    fn active_concealment_adds_stress_and_self_hate() {
+        let at = onset() + Duration::days(365);
+        let state = apply_concealment_effects(IndividualState::new(), &[concealment()], at);
+        let baseline = IndividualState::new();
+
+        assert!(state.needs().stress().delta() > baseline.needs().stress().delta());
+        assert!(
+            state.social_cognition().self_hate().delta()
+                > baseline.social_cognition().self_hate().delta()
+        );
+
+        let future = apply_concealment_effects(IndividualState::new(), &[concealment()], onset());
+        assert_eq!(future, baseline);
+    }
+}
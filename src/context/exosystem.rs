@@ -10,12 +10,14 @@
 //! - Healthcare system access affects available care
 
 use crate::enums::ExosystemPath;
+use crate::types::UnitInterval;
 
 /// Parent work environment quality.
 ///
 /// For children, this captures how the parent's workplace affects
 /// their availability and emotional state.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ParentWorkQuality {
     /// Parent's work stress level (0-1).
     pub stress_level: f64,
@@ -55,6 +57,7 @@ impl ParentWorkQuality {
 /// These settings affect the individual through their influence on
 /// microsystems rather than through direct interaction.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExosystemContext {
     /// Quality of healthcare access (0-1).
     pub health_system_access: f64,
@@ -121,7 +124,7 @@ impl ExosystemContext {
 
     /// Sets a value by exosystem path.
     pub fn set_value(&mut self, path: &ExosystemPath, value: f64) {
-        let clamped = value.clamp(0.0, 1.0);
+        let clamped = UnitInterval::clamped(value).get();
         match path {
             ExosystemPath::HealthSystemAccess => self.health_system_access = clamped,
             ExosystemPath::EducationalSystemQuality => self.educational_system_quality = clamped,
@@ -254,6 +257,14 @@ mod tests {
         assert!((exo.resource_availability - 0.0).abs() < f64::EPSILON);
     }
 
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "non-finite input")]
+    fn exosystem_set_value_asserts_finite_in_debug_builds() {
+        let mut exo = ExosystemContext::default();
+        exo.set_value(&ExosystemPath::ResourceAvailability, f64::NAN);
+    }
+
     #[test]
     fn exosystem_parent_work_stress_creates_environment() {
         let mut exo = ExosystemContext::default();
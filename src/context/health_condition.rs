@@ -0,0 +1,523 @@
+//! Chronic health condition modeling.
+//!
+//! Acute illness is handled entirely by event interpretation: an
+//! `EventPayload::HealthCondition` event with `chronic: false` ramps in a
+//! time-limited fatigue and mood hit that decays like any other event.
+//!
+//! Chronic conditions persist until remission and add a sustained load on
+//! top of event effects:
+//!
+//! - **Persistent burden**: fatigue, stress, and depression that stay
+//!   elevated while the condition is active. Progressive courses worsen
+//!   over the first years.
+//! - **Flares**: relapsing-remitting conditions flare on a deterministic
+//!   schedule derived from the condition kind, each leaving a residual
+//!   that fades over a few weeks.
+//! - **Caregiving**: instrumental support received while ill raises
+//!   perceived liability (feeling like a burden).
+//!
+//! Healthcare microsystem quality determines how well the condition is
+//! managed. Good access to a responsive, warm provider reduces the
+//! persistent burden and flare residuals; no healthcare microsystem leaves
+//! the condition unmanaged.
+//!
+//! Conditions are declared by an event carrying
+//! `EventPayload::HealthCondition` with `chronic: true` and end at the next
+//! `EventPayload::HealthRemission` for the same condition kind.
+
+use crate::context::EcologicalContext;
+use crate::enums::{ConditionKind, CourseShape, EventPayload};
+use crate::event::Event;
+#[cfg(feature = "context")]
+use crate::state::IndividualState;
+use crate::types::Timestamp;
+
+/// Fatigue added by an active chronic condition at full severity.
+pub const CHRONIC_FATIGUE_LOAD: f32 = 0.2;
+
+/// Stress added by an active chronic condition at full severity.
+pub const CHRONIC_STRESS_LOAD: f32 = 0.1;
+
+/// Depression added by an active chronic condition at full severity.
+pub const CHRONIC_DEPRESSION_LOAD: f32 = 0.15;
+
+/// Fatigue added by a flare at full severity, before fading.
+pub const FLARE_FATIGUE: f32 = 0.2;
+
+/// Depression added by a flare at full severity, before fading.
+pub const FLARE_DEPRESSION: f32 = 0.05;
+
+/// Perceived liability added by caregiving received while ill.
+pub const CAREGIVING_LIABILITY: f32 = 0.06;
+
+/// Share of the unmanaged burden removed by fully effective management.
+pub const MAX_MANAGEMENT_RELIEF: f64 = 0.7;
+
+/// Days for half of a flare's residual to fade.
+#[cfg(feature = "context")]
+const FLARE_HALF_LIFE_DAYS: f64 = 14.0;
+
+/// Days for half of the liability from one caregiving episode to fade.
+#[cfg(feature = "context")]
+const CAREGIVING_HALF_LIFE_DAYS: f64 = 30.0;
+
+/// Years over which a progressive condition reaches its peak burden.
+const PROGRESSION_YEARS: f64 = 2.0;
+
+/// Additional burden of a progressive condition at its peak.
+const PROGRESSION_PEAK: f64 = 0.5;
+
+/// Valence lost to a flare at full severity, before fading.
+#[cfg(feature = "context")]
+const FLARE_VALENCE_LOSS: f32 = 0.1;
+
+/// A chronic health condition with an onset and optional remission.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::context::HealthCondition;
+/// use behavioral_pathways::enums::{ConditionKind, CourseShape};
+/// use behavioral_pathways::types::{Duration, Timestamp};
+///
+/// let onset = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+/// let condition = HealthCondition::new(
+///     ConditionKind::Autoimmune,
+///     CourseShape::RelapsingRemitting,
+///     0.6,
+///     onset,
+/// );
+/// assert!(condition.is_active_at(onset + Duration::days(30)));
+/// assert_eq!(condition.flare_times(onset + Duration::days(365)).len(), 4);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealthCondition {
+    /// Kind of condition.
+    pub kind: ConditionKind,
+
+    /// How severity develops over the course of the condition.
+    pub course: CourseShape,
+
+    /// Severity of the condition (0-1).
+    pub severity: f32,
+
+    /// When the condition began.
+    pub onset: Timestamp,
+
+    /// When the condition went into remission, if it has.
+    pub remission: Option<Timestamp>,
+}
+
+impl HealthCondition {
+    /// Creates an active chronic condition.
+    #[must_use]
+    pub fn new(kind: ConditionKind, course: CourseShape, severity: f32, onset: Timestamp) -> Self {
+        HealthCondition {
+            kind,
+            course,
+            severity: severity.clamp(0.0, 1.0),
+            onset,
+            remission: None,
+        }
+    }
+
+    /// Marks the condition as remitted at the given time.
+    #[must_use]
+    pub fn with_remission(mut self, timestamp: Timestamp) -> Self {
+        self.remission = Some(timestamp);
+        self
+    }
+
+    /// Builds a chronic condition from an event carrying
+    /// `EventPayload::HealthCondition`.
+    ///
+    /// Severity is taken from the event. Returns `None` for acute conditions
+    /// and any other payload.
+    #[must_use]
+    pub fn from_event(event: &Event, timestamp: Timestamp) -> Option<Self> {
+        match event.payload() {
+            EventPayload::HealthCondition {
+                condition,
+                chronic: true,
+                severity_course,
+            } => Some(HealthCondition::new(
+                *condition,
+                *severity_course,
+                event.severity() as f32,
+                timestamp,
+            )),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the condition has begun and not yet remitted.
+    #[must_use]
+    pub fn is_active_at(&self, timestamp: Timestamp) -> bool {
+        timestamp >= self.onset && self.remission.is_none_or(|r| timestamp < r)
+    }
+
+    /// Returns the times of flares up to and including `until`.
+    ///
+    /// Only relapsing-remitting conditions flare. Flares recur every
+    /// `ConditionKind::flare_interval()` after onset and stop at remission.
+    #[must_use]
+    pub fn flare_times(&self, until: Timestamp) -> Vec<Timestamp> {
+        if self.course != CourseShape::RelapsingRemitting {
+            return Vec::new();
+        }
+        let interval = self.kind.flare_interval();
+        let mut flares = Vec::new();
+        let mut next = self.onset + interval;
+        while next <= until && self.is_active_at(next) {
+            flares.push(next);
+            next = next + interval;
+        }
+        flares
+    }
+
+    /// Returns the burden multiplier from disease progression.
+    ///
+    /// Progressive conditions grow linearly to 1.5x over their first two
+    /// years. Other courses stay at 1.0.
+    #[must_use]
+    pub fn progression_factor(&self, timestamp: Timestamp) -> f32 {
+        if self.course != CourseShape::Progressive || timestamp <= self.onset {
+            return 1.0;
+        }
+        let years = (timestamp - self.onset).as_days_f64() / 365.0;
+        (1.0 + PROGRESSION_PEAK * (years / PROGRESSION_YEARS).min(1.0)) as f32
+    }
+}
+
+/// Returns how effectively the context's healthcare manages a chronic
+/// condition (0-1).
+///
+/// Uses the best Healthcare microsystem: access frequency matters most,
+/// then provider responsiveness and warmth, while hostility undermines
+/// management. Returns 0.0 without a Healthcare microsystem.
+#[must_use]
+pub fn management_effectiveness(context: &EcologicalContext) -> f64 {
+    context
+        .microsystems_iter()
+        .filter_map(|(_, microsystem)| microsystem.healthcare())
+        .map(|care| {
+            (0.5 * care.access_frequency + 0.3 * care.responsiveness + 0.2 * care.warmth
+                - 0.2 * care.hostility)
+                .clamp(0.0, 1.0)
+        })
+        .fold(0.0, f64::max)
+}
+
+/// Applies the sustained effects of chronic health conditions to state.
+///
+/// Called by `state_at()` after context and role exit effects. Conditions
+/// with onset after `current_timestamp` are ignored. `caregiving` holds the
+/// times instrumental support was received; only episodes that fall while
+/// a condition was active add perceived liability.
+///
+/// # Arguments
+///
+/// * `state` - The current state after decay, events, and context effects
+/// * `context` - The ecological context providing healthcare quality
+/// * `conditions` - Chronic conditions that began in the queried range
+/// * `caregiving` - Times instrumental support was received
+/// * `current_timestamp` - Absolute timestamp for the current state
+#[cfg(feature = "context")]
+#[must_use]
+pub(crate) fn apply_health_condition_effects(
+    mut state: IndividualState,
+    context: &EcologicalContext,
+    conditions: &[HealthCondition],
+    caregiving: &[Timestamp],
+    current_timestamp: Timestamp,
+) -> IndividualState {
+    if conditions.is_empty() {
+        return state;
+    }
+    let unmanaged = (1.0 - MAX_MANAGEMENT_RELIEF * management_effectiveness(context)) as f32;
+
+    for condition in conditions.iter().filter(|c| c.onset <= current_timestamp) {
+        if condition.is_active_at(current_timestamp) {
+            let burden =
+                condition.severity * condition.progression_factor(current_timestamp) * unmanaged;
+            state
+                .needs_mut()
+                .add_fatigue_delta(CHRONIC_FATIGUE_LOAD * burden);
+            state
+                .needs_mut()
+                .add_stress_delta(CHRONIC_STRESS_LOAD * burden);
+            state
+                .mental_health_mut()
+                .add_depression_delta(CHRONIC_DEPRESSION_LOAD * burden);
+        }
+
+        let flare_residual: f64 = condition
+            .flare_times(current_timestamp)
+            .iter()
+            .map(|flare| {
+                0.5_f64.powf((current_timestamp - *flare).as_days_f64() / FLARE_HALF_LIFE_DAYS)
+            })
+            .sum();
+        let flare = condition.severity * unmanaged * flare_residual as f32;
+        state.needs_mut().add_fatigue_delta(FLARE_FATIGUE * flare);
+        state
+            .mood_mut()
+            .add_valence_delta(-FLARE_VALENCE_LOSS * flare);
+        state
+            .mental_health_mut()
+            .add_depression_delta(FLARE_DEPRESSION * flare);
+    }
+
+    for received in caregiving.iter().filter(|ts| **ts <= current_timestamp) {
+        let severity = conditions
+            .iter()
+            .filter(|c| c.is_active_at(*received))
+            .map(|c| c.severity)
+            .fold(0.0, f32::max);
+        if severity > 0.0 {
+            let days = (current_timestamp - *received).as_days_f64();
+            let residual = 0.5_f64.powf(days / CAREGIVING_HALF_LIFE_DAYS) as f32;
+            state
+                .social_cognition_mut()
+                .add_perceived_liability_delta(CAREGIVING_LIABILITY * severity * residual);
+        }
+    }
+
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::{HealthcareContext, Microsystem};
+    use crate::enums::EventType;
+    use crate::event::EventBuilder;
+    use crate::types::{Duration, MicrosystemId};
+
+    fn onset() -> Timestamp {
+        Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+    }
+
+    fn condition(course: CourseShape) -> HealthCondition {
+        HealthCondition::new(ConditionKind::Autoimmune, course, 0.8, onset())
+    }
+
+    fn healthcare_context(access: f64, responsiveness: f64) -> EcologicalContext {
+        let mut context = EcologicalContext::default();
+        let care = HealthcareContext {
+            access_frequency: access,
+            responsiveness,
+            warmth: 0.8,
+            hostility: 0.0,
+            ..Default::default()
+        };
+        context.add_microsystem(
+            MicrosystemId::new("clinic").unwrap(),
+            Microsystem::new_healthcare(care),
+        );
+        context
+    }
+
+    #[test]
+    fn from_event_reads_chronic_payload() {
+        let event = EventBuilder::new(EventType::ChronicIllnessOnset)
+            .severity(0.7)
+            .payload(EventPayload::HealthCondition {
+                condition: ConditionKind::ChronicPain,
+                chronic: true,
+                severity_course: CourseShape::Progressive,
+            })
+            .build()
+            .unwrap();
+
+        let condition = HealthCondition::from_event(&event, onset()).unwrap();
+        assert_eq!(condition.kind, ConditionKind::ChronicPain);
+        assert_eq!(condition.course, CourseShape::Progressive);
+        assert!((condition.severity - 0.7).abs() < 1e-6);
+        assert_eq!(condition.remission, None);
+    }
+
+    #[test]
+    fn from_event_ignores_acute_and_other_payloads() {
+        let acute = EventBuilder::new(EventType::HealthChange)
+            .payload(EventPayload::HealthCondition {
+                condition: ConditionKind::Infection,
+                chronic: false,
+                severity_course: CourseShape::Sudden,
+            })
+            .build()
+            .unwrap();
+        assert!(HealthCondition::from_event(&acute, onset()).is_none());
+
+        let other = EventBuilder::new(EventType::HealthChange).build().unwrap();
+        assert!(HealthCondition::from_event(&other, onset()).is_none());
+    }
+
+    #[test]
+    fn remission_ends_activity() {
+        let remitted = condition(CourseShape::Gradual).with_remission(onset() + Duration::days(90));
+        assert!(!remitted.is_active_at(onset() - Duration::days(1)));
+        assert!(remitted.is_active_at(onset() + Duration::days(89)));
+        assert!(!remitted.is_active_at(onset() + Duration::days(90)));
+    }
+
+    #[test]
+    fn only_relapsing_remitting_conditions_flare() {
+        let year_later = onset() + Duration::days(365);
+        assert!(condition(CourseShape::Gradual)
+            .flare_times(year_later)
+            .is_empty());
+
+        let flares = condition(CourseShape::RelapsingRemitting).flare_times(year_later);
+        assert_eq!(flares.len(), 4);
+        assert_eq!(
+            flares[0],
+            onset() + ConditionKind::Autoimmune.flare_interval()
+        );
+    }
+
+    #[test]
+    fn flares_stop_at_remission() {
+        let remitted = condition(CourseShape::RelapsingRemitting)
+            .with_remission(onset() + Duration::days(100));
+        let flares = remitted.flare_times(onset() + Duration::days(365));
+        assert_eq!(flares.len(), 1);
+    }
+
+    #[test]
+    fn progressive_conditions_worsen_then_plateau() {
+        let progressive = condition(CourseShape::Progressive);
+        let one_year = progressive.progression_factor(onset() + Duration::days(365));
+        let two_years = progressive.progression_factor(onset() + Duration::days(730));
+        let five_years = progressive.progression_factor(onset() + Duration::days(1825));
+        assert!((one_year - 1.25).abs() < 1e-3);
+        assert!((two_years - 1.5).abs() < 1e-3);
+        assert!((five_years - 1.5).abs() < 1e-3);
+
+        let gradual = condition(CourseShape::Gradual);
+        assert!((gradual.progression_factor(onset() + Duration::days(730)) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn management_effectiveness_orders_healthcare_quality() {
+        let none = management_effectiveness(&EcologicalContext::default());
+        let poor = management_effectiveness(&healthcare_context(0.1, 0.2));
+        let good = management_effectiveness(&healthcare_context(0.9, 0.9));
+        assert!(none.abs() < f64::EPSILON);
+        assert!(poor > none);
+        assert!(good > poor);
+        assert!(good <= 1.0);
+    }
+
+    #[cfg(feature = "context")]
+    #[test]
+    fn active_condition_adds_persistent_burden() {
+        let conditions = [condition(CourseShape::Gradual)];
+        let state = apply_health_condition_effects(
+            IndividualState::new(),
+            &EcologicalContext::default(),
+            &conditions,
+            &[],
+            onset() + Duration::days(30),
+        );
+        assert!((state.needs().fatigue().delta() - CHRONIC_FATIGUE_LOAD * 0.8).abs() < 1e-6);
+        assert!((state.needs().stress().delta() - CHRONIC_STRESS_LOAD * 0.8).abs() < 1e-6);
+        let depression = state.mental_health().depression().delta();
+        assert!((depression - CHRONIC_DEPRESSION_LOAD * 0.8).abs() < 1e-6);
+    }
+
+    #[cfg(feature = "context")]
+    #[test]
+    fn good_healthcare_reduces_burden() {
+        let conditions = [condition(CourseShape::RelapsingRemitting)];
+        let at = onset() + Duration::days(200);
+        let unmanaged = apply_health_condition_effects(
+            IndividualState::new(),
+            &EcologicalContext::default(),
+            &conditions,
+            &[],
+            at,
+        );
+        let managed = apply_health_condition_effects(
+            IndividualState::new(),
+            &healthcare_context(0.9, 0.9),
+            &conditions,
+            &[],
+            at,
+        );
+        assert!(
+            managed.mental_health().depression().delta()
+                < unmanaged.mental_health().depression().delta()
+        );
+        assert!(managed.needs().fatigue().delta() < unmanaged.needs().fatigue().delta());
+    }
+
+    #[cfg(feature = "context")]
+    #[test]
+    fn flare_residual_fades() {
+        let conditions = [condition(CourseShape::RelapsingRemitting)];
+        let flare = onset() + ConditionKind::Autoimmune.flare_interval();
+        let fatigue_at = |days: u64| {
+            apply_health_condition_effects(
+                IndividualState::new(),
+                &EcologicalContext::default(),
+                &conditions,
+                &[],
+                flare + Duration::days(days),
+            )
+            .needs()
+            .fatigue()
+            .delta()
+        };
+        let baseline = CHRONIC_FATIGUE_LOAD * 0.8;
+        assert!((fatigue_at(0) - baseline - FLARE_FATIGUE * 0.8).abs() < 1e-5);
+        assert!((fatigue_at(14) - baseline - FLARE_FATIGUE * 0.4).abs() < 1e-5);
+    }
+
+    #[cfg(feature = "context")]
+    #[test]
+    fn remitted_condition_adds_no_persistent_burden() {
+        let conditions =
+            [condition(CourseShape::Gradual).with_remission(onset() + Duration::days(60))];
+        let state = apply_health_condition_effects(
+            IndividualState::new(),
+            &EcologicalContext::default(),
+            &conditions,
+            &[],
+            onset() + Duration::days(90),
+        );
+        assert!(state.needs().fatigue().delta().abs() < f32::EPSILON);
+        assert!(state.mental_health().depression().delta().abs() < f32::EPSILON);
+    }
+
+    #[cfg(feature = "context")]
+    #[test]
+    fn caregiving_while_ill_raises_perceived_liability() {
+        let conditions = [condition(CourseShape::Gradual)];
+        let received = onset() + Duration::days(10);
+        let before_onset = onset() - Duration::days(10);
+        let state = apply_health_condition_effects(
+            IndividualState::new(),
+            &EcologicalContext::default(),
+            &conditions,
+            &[received, before_onset],
+            received,
+        );
+        let liability = state.social_cognition().perceived_liability().delta();
+        assert!((liability - CAREGIVING_LIABILITY * 0.8).abs() < 1e-6);
+    }
+
+    #[cfg(feature = "context")]
+    #[test]
+    fn future_conditions_are_ignored() {
+        let conditions = [condition(CourseShape::Gradual)];
+        let state = apply_health_condition_effects(
+            IndividualState::new(),
+            &EcologicalContext::default(),
+            &conditions,
+            &[],
+            onset() - Duration::days(1),
+        );
+        assert!(state.needs().fatigue().delta().abs() < f32::EPSILON);
+    }
+}
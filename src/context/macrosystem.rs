@@ -8,10 +8,12 @@
 //! - Society-wide stressors (cultural stress, collective trauma)
 
 use crate::enums::{MacrosystemPath, RelationshipSchema};
+use crate::types::{SignedUnit, UnitInterval};
 use std::collections::HashMap;
 
 /// Cultural orientation dimensions (Hofstede-inspired).
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CulturalOrientation {
     /// Individualism vs collectivism (-1 collectivist to +1 individualist).
     pub individualism_collectivism: f64,
@@ -35,6 +37,7 @@ impl Default for CulturalOrientation {
 
 /// Institutional structure dimensions.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InstitutionalStructure {
     /// Legal system reliability (0-1).
     pub rule_of_law: f64,
@@ -85,6 +88,7 @@ impl Default for MacrosystemConstraintSet {
 
 /// Overrides applied for subcultures or distinct entity groups.
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MacrosystemModifier {
     /// Individualism vs collectivism (-1 to 1).
     pub individualism_collectivism: Option<f64>,
@@ -158,6 +162,7 @@ impl MacrosystemModifier {
 /// Macrosystem values typically remain stable over long periods but can shift
 /// due to major societal events.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MacrosystemContext {
     /// Cultural orientation (Hofstede dimensions).
     pub cultural_orientation: CulturalOrientation,
@@ -175,6 +180,7 @@ pub struct MacrosystemContext {
     pub economic_inequality: f64,
 
     /// Subculture-specific overrides keyed by group label.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::types::sorted_serde::map"))]
     pub subculture_overrides: HashMap<String, MacrosystemModifier>,
 }
 
@@ -232,31 +238,33 @@ impl MacrosystemContext {
         // Most values are clamped 0-1, except individualism_collectivism which is -1 to 1
         match path {
             MacrosystemPath::IndividualismCollectivism => {
-                self.cultural_orientation.individualism_collectivism = value.clamp(-1.0, 1.0);
+                self.cultural_orientation.individualism_collectivism =
+                    SignedUnit::clamped(value).get();
             }
             MacrosystemPath::PowerDistance => {
-                self.cultural_orientation.power_distance = value.clamp(0.0, 1.0);
+                self.cultural_orientation.power_distance = UnitInterval::clamped(value).get();
             }
             MacrosystemPath::UncertaintyAvoidance => {
-                self.cultural_orientation.uncertainty_avoidance = value.clamp(0.0, 1.0);
+                self.cultural_orientation.uncertainty_avoidance =
+                    UnitInterval::clamped(value).get();
             }
             MacrosystemPath::CulturalStress => {
-                self.cultural_stress = value.clamp(0.0, 1.0);
+                self.cultural_stress = UnitInterval::clamped(value).get();
             }
             MacrosystemPath::CollectiveTrauma => {
-                self.collective_trauma = value.clamp(0.0, 1.0);
+                self.collective_trauma = UnitInterval::clamped(value).get();
             }
             MacrosystemPath::EconomicInequality => {
-                self.economic_inequality = value.clamp(0.0, 1.0);
+                self.economic_inequality = UnitInterval::clamped(value).get();
             }
             MacrosystemPath::RuleOfLaw => {
-                self.institutional_structure.rule_of_law = value.clamp(0.0, 1.0);
+                self.institutional_structure.rule_of_law = UnitInterval::clamped(value).get();
             }
             MacrosystemPath::SocialMobility => {
-                self.institutional_structure.social_mobility = value.clamp(0.0, 1.0);
+                self.institutional_structure.social_mobility = UnitInterval::clamped(value).get();
             }
             MacrosystemPath::CorruptionLevel => {
-                self.institutional_structure.corruption_level = value.clamp(0.0, 1.0);
+                self.institutional_structure.corruption_level = UnitInterval::clamped(value).get();
             }
         }
     }
@@ -297,6 +305,8 @@ impl MacrosystemContext {
                     RelationshipSchema::Family,
                     RelationshipSchema::Nuclear,
                     RelationshipSchema::Extended,
+                    RelationshipSchema::Sibling,
+                    RelationshipSchema::EstrangedParent,
                 ],
                 hierarchy_violation_penalty: power_distance * 0.2,
             }
@@ -309,6 +319,9 @@ impl MacrosystemContext {
                     RelationshipSchema::Family,
                     RelationshipSchema::Nuclear,
                     RelationshipSchema::Extended,
+                    RelationshipSchema::Sibling,
+                    RelationshipSchema::Coworker,
+                    RelationshipSchema::EstrangedParent,
                 ],
                 hierarchy_violation_penalty: 0.0,
             }
@@ -157,6 +157,7 @@ pub struct MesosystemLinkage {
 
 /// Persisted mesosystem state computed from microsystems.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MesosystemState {
     /// Competing demands between work and family contexts.
     pub work_family_conflict: f64,
@@ -282,6 +283,16 @@ impl MesosystemState {
     }
 }
 
+/// Returns how much `target` resists stress spilling into it, from -0.5
+/// to 0.5 and zero at the defaults.
+fn spillover_resistance(target: &Microsystem) -> f64 {
+    match target {
+        Microsystem::Family(family) => family.cohesion - 0.5,
+        Microsystem::Work(work) => work.autonomy - 0.5,
+        _ => 0.0,
+    }
+}
+
 fn average(values: &[f64]) -> Option<f64> {
     if values.is_empty() {
         None
@@ -356,6 +367,10 @@ impl MesosystemCache {
     ///
     /// Formula: spillover = if source_stress > 0.5 { (source_stress - 0.5) * 0.3 } else { 0.0 }
     ///
+    /// The target's resistance then scales it: family cohesion or work
+    /// autonomy above their defaults shields the target, below them
+    /// exposes it further.
+    ///
     /// # Arguments
     ///
     /// * `from` - Source microsystem ID
@@ -383,9 +398,10 @@ impl MesosystemCache {
             None => return 0.0,
         };
 
-        if microsystems.get(to).is_none() {
-            return 0.0;
-        }
+        let target = match microsystems.get(to) {
+            Some(m) => m,
+            None => return 0.0,
+        };
 
         // Threshold-based spillover: only stress above 0.5 leaks across contexts.
         let source_stress = source.stress_level();
@@ -394,7 +410,7 @@ impl MesosystemCache {
         } else {
             0.0
         };
-        spillover.clamp(0.0, 1.0)
+        (spillover * (1.0 - spillover_resistance(target))).clamp(0.0, 1.0)
     }
 
     /// Gets or computes role conflict between two microsystems.
@@ -443,6 +459,7 @@ impl MesosystemCache {
         // 1. Both contexts having high demands (stress)
         // 2. Different warmth levels (inconsistent treatment)
         // 3. High interaction frequency in both (time conflict)
+        // 4. Rigid work hours against unsupported caregiving (schedule conflict)
 
         let stress_a = micro_a.stress_level();
         let stress_b = micro_b.stress_level();
@@ -468,7 +485,20 @@ impl MesosystemCache {
         let hostility_diff = (micro_a.hostility() - micro_b.hostility()).abs();
         let treatment_conflict = (warmth_diff + hostility_diff) * 0.3;
 
-        let total = time_conflict + stress_conflict + treatment_conflict;
+        // Schedule conflict: work hours that cannot bend around caregiving
+        // the family does not help with. Zero at default flexibility, and
+        // flexibility above it relieves the other conflicts.
+        let schedule_conflict = match (micro_a, micro_b) {
+            (Microsystem::Work(work), Microsystem::Family(family))
+            | (Microsystem::Family(family), Microsystem::Work(work)) => {
+                (0.5 - work.schedule_flexibility)
+                    * family.caregiving_burden
+                    * (1.0 - family.caregiving_support)
+            }
+            _ => 0.0,
+        };
+
+        let total = time_conflict + stress_conflict + treatment_conflict + schedule_conflict;
         total.clamp(0.0, 1.0)
     }
 
@@ -1787,4 +1817,58 @@ mod tests {
         let spillover = cache.get_spillover(&work_id, &family_id, &microsystems);
         assert!((spillover - 0.0).abs() < f64::EPSILON);
     }
+
+    #[test]
+    fn cohesive_family_resists_spillover() {
+        let cache = MesosystemCache::new();
+        let work_id = MicrosystemId::new("work").unwrap();
+        let family_id = MicrosystemId::new("family").unwrap();
+        let spillover_into = |cohesion: f64| {
+            let mut microsystems = HashMap::new();
+            let work = WorkContext {
+                workload_stress: 0.9,
+                ..Default::default()
+            };
+            microsystems.insert(work_id.clone(), Microsystem::new_work(work));
+            let family = FamilyContext {
+                cohesion,
+                ..Default::default()
+            };
+            microsystems.insert(family_id.clone(), Microsystem::new_family(family));
+            cache.get_spillover(&work_id, &family_id, &microsystems)
+        };
+
+        // (0.9 - 0.5) * 0.3 at the default cohesion
+        assert!((spillover_into(0.5) - 0.12).abs() < 1e-12);
+        assert!(spillover_into(0.9) < spillover_into(0.5));
+        assert!(spillover_into(0.1) > spillover_into(0.5));
+    }
+
+    #[test]
+    fn rigid_schedule_against_unsupported_caregiving_adds_role_conflict() {
+        let cache = MesosystemCache::new();
+        let work_id = MicrosystemId::new("work").unwrap();
+        let family_id = MicrosystemId::new("family").unwrap();
+        let conflict = |schedule_flexibility: f64, caregiving_support: f64| {
+            let mut microsystems = HashMap::new();
+            let work = WorkContext {
+                schedule_flexibility,
+                ..Default::default()
+            };
+            microsystems.insert(work_id.clone(), Microsystem::new_work(work));
+            let family = FamilyContext {
+                caregiving_burden: 0.8,
+                caregiving_support,
+                ..Default::default()
+            };
+            microsystems.insert(family_id.clone(), Microsystem::new_family(family));
+            cache.get_role_conflict(&work_id, &family_id, &microsystems)
+        };
+
+        let neutral = conflict(0.5, 0.5);
+        assert!(conflict(0.1, 0.1) > neutral + 0.2);
+        // Help with caregiving removes the clash with rigid hours
+        assert!((conflict(0.1, 1.0) - neutral).abs() < 1e-12);
+        assert!(conflict(0.9, 0.1) < neutral);
+    }
 }
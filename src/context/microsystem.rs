@@ -14,7 +14,7 @@ use crate::enums::{
     EducationPath, FamilyPath, HealthcarePath, MicrosystemPath, NeighborhoodPath, ReligiousPath,
     SocialPath, WorkPath,
 };
-use crate::types::EntityId;
+use crate::types::{EntityId, UnitInterval};
 
 /// Type of microsystem environment.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -37,6 +37,7 @@ pub enum MicrosystemType {
 
 /// Role within a family context.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FamilyRole {
     /// Child role in family.
     Child,
@@ -76,6 +77,7 @@ impl FamilyRole {
 /// Tracks the frequency and complexity of interactions, which determine
 /// whether proximal processes can occur.
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InteractionProfile {
     /// Interaction frequency (0-1, normalized).
     pub interaction_frequency: f64,
@@ -111,6 +113,7 @@ impl InteractionProfile {
 
 /// Work context dimensions.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WorkContext {
     /// Current work pressure (0-1).
     pub workload_stress: f64,
@@ -136,6 +139,18 @@ pub struct WorkContext {
     /// Security and constancy of position (0-1).
     pub stability: f64,
 
+    /// Control over how and when work is done (0-1).
+    pub autonomy: f64,
+
+    /// Freedom to arrange working hours (0-1).
+    pub schedule_flexibility: f64,
+
+    /// Acknowledgement of contributions (0-1).
+    pub recognition: f64,
+
+    /// Fear of losing the job (0-1).
+    pub job_insecurity: f64,
+
     /// Interaction profile for this context.
     pub interaction_profile: InteractionProfile,
 
@@ -157,6 +172,10 @@ impl Default for WorkContext {
             predictability: 0.5,
             cognitive_stimulation: 0.5,
             stability: 0.6,
+            autonomy: 0.5,
+            schedule_flexibility: 0.5,
+            recognition: 0.5,
+            job_insecurity: 0.0,
             interaction_profile: InteractionProfile::new(),
             supervisor_id: None,
             peer_ids: Vec::new(),
@@ -165,6 +184,21 @@ impl Default for WorkContext {
 }
 
 impl WorkContext {
+    /// Returns the stress this workplace puts on the person (0-1).
+    ///
+    /// Workload stress, raised by job insecurity and by autonomy,
+    /// schedule flexibility, and recognition below their defaults, and
+    /// eased by them above. At the defaults this is the workload stress.
+    #[must_use]
+    pub fn stress_level(&self) -> f64 {
+        let stress = self.workload_stress
+            + (0.5 - self.autonomy) * 0.2
+            + (0.5 - self.schedule_flexibility) * 0.1
+            + (0.5 - self.recognition) * 0.1
+            + self.job_insecurity * 0.3;
+        stress.clamp(0.0, 1.0)
+    }
+
     /// Gets a value by path.
     #[must_use]
     pub fn get_value(&self, path: WorkPath) -> f64 {
@@ -177,6 +211,10 @@ impl WorkContext {
             WorkPath::Predictability => self.predictability,
             WorkPath::CognitiveStimulation => self.cognitive_stimulation,
             WorkPath::Stability => self.stability,
+            WorkPath::Autonomy => self.autonomy,
+            WorkPath::ScheduleFlexibility => self.schedule_flexibility,
+            WorkPath::Recognition => self.recognition,
+            WorkPath::JobInsecurity => self.job_insecurity,
             WorkPath::InteractionFrequency => self.interaction_profile.interaction_frequency,
             WorkPath::InteractionComplexity => self.interaction_profile.interaction_complexity,
         }
@@ -184,7 +222,7 @@ impl WorkContext {
 
     /// Sets a value by path.
     pub fn set_value(&mut self, path: WorkPath, value: f64) {
-        let clamped = value.clamp(0.0, 1.0);
+        let clamped = UnitInterval::clamped(value).get();
         match path {
             WorkPath::WorkloadStress => self.workload_stress = clamped,
             WorkPath::RoleSatisfaction => self.role_satisfaction = clamped,
@@ -194,6 +232,10 @@ impl WorkContext {
             WorkPath::Predictability => self.predictability = clamped,
             WorkPath::CognitiveStimulation => self.cognitive_stimulation = clamped,
             WorkPath::Stability => self.stability = clamped,
+            WorkPath::Autonomy => self.autonomy = clamped,
+            WorkPath::ScheduleFlexibility => self.schedule_flexibility = clamped,
+            WorkPath::Recognition => self.recognition = clamped,
+            WorkPath::JobInsecurity => self.job_insecurity = clamped,
             WorkPath::InteractionFrequency => {
                 self.interaction_profile.interaction_frequency = clamped
             }
@@ -206,6 +248,7 @@ impl WorkContext {
 
 /// Family context dimensions.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FamilyContext {
     /// Overall family satisfaction (0-1).
     pub family_satisfaction: f64,
@@ -228,6 +271,15 @@ pub struct FamilyContext {
     /// Family stability (0-1).
     pub stability: f64,
 
+    /// Help available with caregiving (0-1).
+    pub caregiving_support: f64,
+
+    /// Strain from household finances (0-1).
+    pub financial_strain: f64,
+
+    /// Closeness and sense of togetherness (0-1).
+    pub cohesion: f64,
+
     /// Role within family.
     pub family_role: FamilyRole,
 
@@ -248,6 +300,9 @@ impl Default for FamilyContext {
             role_clarity: 0.5,
             predictability: 0.5,
             stability: 0.6,
+            caregiving_support: 0.5,
+            financial_strain: 0.0,
+            cohesion: 0.5,
             family_role: FamilyRole::None,
             interaction_profile: InteractionProfile::new(),
             family_unit: Vec::new(),
@@ -256,6 +311,23 @@ impl Default for FamilyContext {
 }
 
 impl FamilyContext {
+    /// Returns the stress this family puts on the person (0-1).
+    ///
+    /// Caregiving burden and hostility, with caregiving support above or
+    /// below its default easing or compounding the burden. Financial
+    /// strain adds to it, and cohesion below its default raises it while
+    /// cohesion above eases it. At the defaults this is the burden and
+    /// hostility alone.
+    #[must_use]
+    pub fn stress_level(&self) -> f64 {
+        let burden = self.caregiving_burden * (1.0 + (0.5 - self.caregiving_support) * 0.6);
+        let stress = burden * 0.7
+            + self.hostility * 0.3
+            + self.financial_strain * 0.3
+            + (0.5 - self.cohesion) * 0.2;
+        stress.clamp(0.0, 1.0)
+    }
+
     /// Gets a value by path.
     #[must_use]
     pub fn get_value(&self, path: FamilyPath) -> f64 {
@@ -267,6 +339,9 @@ impl FamilyContext {
             FamilyPath::RoleClarity => self.role_clarity,
             FamilyPath::Predictability => self.predictability,
             FamilyPath::Stability => self.stability,
+            FamilyPath::CaregivingSupport => self.caregiving_support,
+            FamilyPath::FinancialStrain => self.financial_strain,
+            FamilyPath::Cohesion => self.cohesion,
             FamilyPath::InteractionFrequency => self.interaction_profile.interaction_frequency,
             FamilyPath::InteractionComplexity => self.interaction_profile.interaction_complexity,
         }
@@ -274,7 +349,7 @@ impl FamilyContext {
 
     /// Sets a value by path.
     pub fn set_value(&mut self, path: FamilyPath, value: f64) {
-        let clamped = value.clamp(0.0, 1.0);
+        let clamped = UnitInterval::clamped(value).get();
         match path {
             FamilyPath::FamilySatisfaction => self.family_satisfaction = clamped,
             FamilyPath::CaregivingBurden => self.caregiving_burden = clamped,
@@ -283,6 +358,9 @@ impl FamilyContext {
             FamilyPath::RoleClarity => self.role_clarity = clamped,
             FamilyPath::Predictability => self.predictability = clamped,
             FamilyPath::Stability => self.stability = clamped,
+            FamilyPath::CaregivingSupport => self.caregiving_support = clamped,
+            FamilyPath::FinancialStrain => self.financial_strain = clamped,
+            FamilyPath::Cohesion => self.cohesion = clamped,
             FamilyPath::InteractionFrequency => {
                 self.interaction_profile.interaction_frequency = clamped
             }
@@ -295,6 +373,7 @@ impl FamilyContext {
 
 /// Social context dimensions.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SocialContext {
     /// Standing in social group (0-1).
     pub group_standing: f64,
@@ -344,7 +423,7 @@ impl SocialContext {
 
     /// Sets a value by path.
     pub fn set_value(&mut self, path: SocialPath, value: f64) {
-        let clamped = value.clamp(0.0, 1.0);
+        let clamped = UnitInterval::clamped(value).get();
         match path {
             SocialPath::GroupStanding => self.group_standing = clamped,
             SocialPath::Warmth => self.warmth = clamped,
@@ -362,6 +441,7 @@ impl SocialContext {
 
 /// Education context dimensions.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EducationContext {
     /// Intellectual challenge level (0-1).
     pub cognitive_demand: f64,
@@ -415,7 +495,7 @@ impl EducationContext {
 
     /// Sets a value by path.
     pub fn set_value(&mut self, path: EducationPath, value: f64) {
-        let clamped = value.clamp(0.0, 1.0);
+        let clamped = UnitInterval::clamped(value).get();
         match path {
             EducationPath::CognitiveDemand => self.cognitive_demand = clamped,
             EducationPath::CompetenceSupport => self.competence_support = clamped,
@@ -433,6 +513,7 @@ impl EducationContext {
 
 /// Healthcare context dimensions.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HealthcareContext {
     /// Healthcare access frequency (0-1).
     pub access_frequency: f64,
@@ -484,7 +565,7 @@ impl HealthcareContext {
 
     /// Sets a value by path.
     pub fn set_value(&mut self, path: HealthcarePath, value: f64) {
-        let clamped = value.clamp(0.0, 1.0);
+        let clamped = UnitInterval::clamped(value).get();
         match path {
             HealthcarePath::AccessFrequency => self.access_frequency = clamped,
             HealthcarePath::Responsiveness => self.responsiveness = clamped,
@@ -502,6 +583,7 @@ impl HealthcareContext {
 
 /// Religious context dimensions.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReligiousContext {
     /// Ritual participation frequency (0-1).
     pub ritual_frequency: f64,
@@ -546,7 +628,7 @@ impl ReligiousContext {
 
     /// Sets a value by path.
     pub fn set_value(&mut self, path: ReligiousPath, value: f64) {
-        let clamped = value.clamp(0.0, 1.0);
+        let clamped = UnitInterval::clamped(value).get();
         match path {
             ReligiousPath::RitualFrequency => self.ritual_frequency = clamped,
             ReligiousPath::Warmth => self.warmth = clamped,
@@ -563,6 +645,7 @@ impl ReligiousContext {
 
 /// Neighborhood context dimensions.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NeighborhoodContext {
     /// Neighborhood safety (0-1).
     pub safety: f64,
@@ -616,7 +699,7 @@ impl NeighborhoodContext {
 
     /// Sets a value by path.
     pub fn set_value(&mut self, path: NeighborhoodPath, value: f64) {
-        let clamped = value.clamp(0.0, 1.0);
+        let clamped = UnitInterval::clamped(value).get();
         match path {
             NeighborhoodPath::Safety => self.safety = clamped,
             NeighborhoodPath::Cohesion => self.cohesion = clamped,
@@ -634,6 +717,7 @@ impl NeighborhoodContext {
 
 /// A microsystem instance containing one of the context types.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Microsystem {
     /// Work environment context.
     Work(WorkContext),
@@ -942,8 +1026,8 @@ impl Microsystem {
     #[must_use]
     pub fn stress_level(&self) -> f64 {
         match self {
-            Microsystem::Work(w) => w.workload_stress,
-            Microsystem::Family(f) => f.caregiving_burden * 0.7 + f.hostility * 0.3,
+            Microsystem::Work(w) => w.stress_level(),
+            Microsystem::Family(f) => f.stress_level(),
             Microsystem::Social(s) => s.hostility * 0.6 + (1.0 - s.group_standing) * 0.4,
             Microsystem::Education(e) => e.cognitive_demand * 0.5 + e.hostility * 0.5,
             Microsystem::Healthcare(h) => h.hostility * 0.5 + (1.0 - h.responsiveness) * 0.5,
@@ -1050,6 +1134,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn work_stress_rises_with_low_autonomy_and_insecurity() {
+        let default = WorkContext::default();
+        assert!((default.stress_level() - default.workload_stress).abs() < f64::EPSILON);
+
+        let precarious = WorkContext {
+            autonomy: 0.1,
+            schedule_flexibility: 0.2,
+            recognition: 0.2,
+            job_insecurity: 0.8,
+            ..Default::default()
+        };
+        let empowered = WorkContext {
+            autonomy: 0.9,
+            schedule_flexibility: 0.8,
+            recognition: 0.8,
+            ..Default::default()
+        };
+        assert!(precarious.stress_level() > default.stress_level());
+        assert!(empowered.stress_level() < default.stress_level());
+    }
+
     // --- FamilyContext tests ---
 
     #[test]
@@ -1059,6 +1165,28 @@ mod tests {
         assert_eq!(family.family_role, FamilyRole::None);
     }
 
+    #[test]
+    fn family_stress_follows_support_strain_and_cohesion() {
+        let default = FamilyContext::default();
+        let expected = default.caregiving_burden * 0.7 + default.hostility * 0.3;
+        assert!((default.stress_level() - expected).abs() < f64::EPSILON);
+
+        let strained = FamilyContext {
+            caregiving_burden: 0.6,
+            caregiving_support: 0.1,
+            financial_strain: 0.7,
+            cohesion: 0.2,
+            ..Default::default()
+        };
+        let supported = FamilyContext {
+            caregiving_burden: 0.6,
+            caregiving_support: 0.9,
+            cohesion: 0.8,
+            ..Default::default()
+        };
+        assert!(strained.stress_level() > supported.stress_level() + 0.3);
+    }
+
     #[test]
     fn family_context_get_value() {
         let family = FamilyContext::default();
@@ -16,18 +16,37 @@
 //! not gradual decay. Mesosystem values are always computed from microsystem data.
 
 mod chronosystem;
+mod concealment;
+#[cfg(feature = "context")]
 mod effects;
 mod exosystem;
+mod health_condition;
 mod macrosystem;
 mod mesosystem;
 mod microsystem;
+mod role_exit;
+mod shaping;
 
 pub use chronosystem::{
     ChronosystemContext, CohortEffects, CriticalPeriod, HistoricalPeriod, NonNormativeEvent,
     NormativeTransition, TurningPoint, TurningPointDomain,
 };
+#[cfg(feature = "context")]
 pub(crate) use effects::apply_context_effects;
+#[cfg(feature = "context")]
+pub(crate) use concealment::apply_concealment_effects;
+pub use concealment::{
+    Concealment, CONCEALMENT_SELF_HATE_LOAD, CONCEALMENT_STRESS_LOAD,
+    CONCEALMENT_SUPPORT_SUPPRESSION,
+};
 pub use exosystem::{ExosystemContext, ParentWorkQuality};
+#[cfg(feature = "context")]
+pub(crate) use health_condition::apply_health_condition_effects;
+pub use health_condition::{
+    management_effectiveness, HealthCondition, CAREGIVING_LIABILITY, CHRONIC_DEPRESSION_LOAD,
+    CHRONIC_FATIGUE_LOAD, CHRONIC_STRESS_LOAD, FLARE_DEPRESSION, FLARE_FATIGUE,
+    MAX_MANAGEMENT_RELIEF,
+};
 pub use macrosystem::{
     CulturalOrientation, InstitutionalStructure, MacrosystemConstraintSet, MacrosystemContext,
 };
@@ -40,9 +59,20 @@ pub use microsystem::{
     Microsystem, MicrosystemType, NeighborhoodContext, ReligiousContext, SocialContext,
     WorkContext,
 };
+#[cfg(feature = "context")]
+pub(crate) use role_exit::apply_role_exit_effects;
+pub use role_exit::{
+    has_alternative_purpose_sources, RoleExit, INVOLUNTARY_EXIT_GRIEVANCE,
+    INVOLUNTARY_EXIT_SELF_WORTH_LOSS, RETIREMENT_TRANSITION, ROLE_EXIT_PURPOSE_LOSS,
+};
+pub(crate) use shaping::shape_context;
+#[cfg(feature = "context")]
+pub(crate) use shaping::shape_context_over;
+pub use shaping::{person_shaping_fraction, PERSON_SHAPING_HALF_LIFE_DAYS};
 
 use crate::enums::ContextPath;
 use crate::types::MicrosystemId;
+use shaping::PersonShaping;
 use std::collections::HashMap;
 
 /// Aggregate container for all ecological context layers.
@@ -80,8 +110,10 @@ use std::collections::HashMap;
 /// assert_eq!(context.microsystem_count(), 1);
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EcologicalContext {
     /// Microsystem instances keyed by ID.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::types::sorted_serde::map"))]
     microsystems: HashMap<MicrosystemId, Microsystem>,
 
     /// Exosystem context (indirect influences).
@@ -94,6 +126,7 @@ pub struct EcologicalContext {
     chronosystem: ChronosystemContext,
 
     /// Cached mesosystem linkages (computed from microsystems).
+    #[cfg_attr(feature = "serde", serde(skip))]
     mesosystem_cache: MesosystemCache,
 
     /// Stored mesosystem state computed from microsystems.
@@ -161,6 +194,23 @@ impl EcologicalContext {
             .collect()
     }
 
+    /// Ends the microsystem membership carried by a role.
+    ///
+    /// Removes the exit's named microsystem if it has one, otherwise every
+    /// microsystem of the role's type (e.g., all Work microsystems on
+    /// retirement). Returns the number of microsystems removed.
+    pub fn end_role(&mut self, exit: &RoleExit) -> usize {
+        let ids = match (&exit.microsystem, exit.microsystem_type()) {
+            (Some(id), _) => vec![id.clone()],
+            (None, Some(microsystem_type)) => self.list_microsystems(microsystem_type),
+            (None, None) => Vec::new(),
+        };
+
+        ids.iter()
+            .filter(|id| self.remove_microsystem(id).is_some())
+            .count()
+    }
+
     /// Returns the total number of microsystems.
     #[must_use]
     pub fn microsystem_count(&self) -> usize {
@@ -347,9 +397,15 @@ impl EcologicalContext {
 
     /// Applies person-to-context shaping effects.
     ///
-    /// High extraversion increases warmth in social microsystems.
+    /// High extraversion increases warmth in social microsystems, and high
+    /// conscientiousness increases role clarity, autonomy, and recognition
+    /// in work microsystems.
     /// This is called SECOND in the bidirectional processing order.
     ///
+    /// This is the fully developed shaping a personality exerts on its
+    /// contexts. Simulation queries and `Entity::advance` approach it over
+    /// time along `person_shaping_fraction`.
+    ///
     /// # Arguments
     ///
     /// * `extraversion` - The entity's extraversion level (-1.0 to 1.0)
@@ -365,72 +421,14 @@ impl EcologicalContext {
         neuroticism: f32,
         grievance: f32,
     ) {
-        // High extraversion (> 0.3) increases social warmth
-        if extraversion > 0.3 {
-            let boost = f64::from(extraversion - 0.3) * 0.1; // Max ~0.07 boost
-
-            for microsystem in self.microsystems.values_mut() {
-                if let Some(social) = microsystem.social_mut() {
-                    social.warmth = (social.warmth + boost).min(1.0);
-                }
-            }
-        }
-
-        // High conscientiousness (> 0.3) increases work structure
-        if conscientiousness > 0.3 {
-            let boost = f64::from(conscientiousness - 0.3) * 0.1;
-
-            for microsystem in self.microsystems.values_mut() {
-                if let Some(work) = microsystem.work_mut() {
-                    work.role_clarity = (work.role_clarity + boost).min(1.0);
-                    work.predictability = (work.predictability + boost * 0.5).min(1.0);
-                }
-            }
-        }
-
-        // High agreeableness (> 0.3) increases family warmth
-        if agreeableness > 0.3 {
-            let boost = f64::from(agreeableness - 0.3) * 0.1;
-
-            for microsystem in self.microsystems.values_mut() {
-                if let Some(family) = microsystem.family_mut() {
-                    family.warmth = (family.warmth + boost).min(1.0);
-                }
-            }
-        }
-
-        // High neuroticism (> 0.3) reduces tolerance for instability
-        if neuroticism > 0.3 {
-            let penalty = f64::from(neuroticism - 0.3) * 0.1;
-
-            for microsystem in self.microsystems.values_mut() {
-                if let Some(work) = microsystem.work_mut() {
-                    work.stability = (work.stability - penalty).max(0.0);
-                    work.predictability = (work.predictability - penalty).max(0.0);
-                }
-                if let Some(family) = microsystem.family_mut() {
-                    family.stability = (family.stability - penalty).max(0.0);
-                    family.predictability = (family.predictability - penalty).max(0.0);
-                }
-            }
-        }
-
-        // High grievance (> 0.3) increases perceived hostility
-        if grievance > 0.3 {
-            let boost = f64::from(grievance - 0.3) * 0.1;
-
-            for microsystem in self.microsystems.values_mut() {
-                if let Some(work) = microsystem.work_mut() {
-                    work.hostility = (work.hostility + boost).min(1.0);
-                }
-                if let Some(family) = microsystem.family_mut() {
-                    family.hostility = (family.hostility + boost).min(1.0);
-                }
-                if let Some(social) = microsystem.social_mut() {
-                    social.hostility = (social.hostility + boost).min(1.0);
-                }
-            }
-        }
+        PersonShaping::new(
+            extraversion,
+            conscientiousness,
+            agreeableness,
+            neuroticism,
+            grievance,
+        )
+        .apply(self, 1.0);
     }
 
     /// Computes context effects on person state.
@@ -611,6 +609,82 @@ mod tests {
         assert!(removed.is_none());
     }
 
+    #[test]
+    fn end_role_removes_all_microsystems_of_role_type() {
+        use crate::enums::LifeDomain;
+        use crate::types::Timestamp;
+
+        let mut context = EcologicalContext::default();
+        context.add_microsystem(
+            MicrosystemId::new("day_job").unwrap(),
+            Microsystem::new_work(WorkContext::default()),
+        );
+        context.add_microsystem(
+            MicrosystemId::new("night_job").unwrap(),
+            Microsystem::new_work(WorkContext::default()),
+        );
+        context.add_microsystem(
+            MicrosystemId::new("family_primary").unwrap(),
+            Microsystem::new_family(FamilyContext::default()),
+        );
+
+        let exit = RoleExit::new(
+            LifeDomain::Work,
+            true,
+            Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0),
+        );
+        assert_eq!(context.end_role(&exit), 2);
+        assert!(context.list_microsystems(MicrosystemType::Work).is_empty());
+        assert_eq!(context.microsystem_count(), 1);
+    }
+
+    #[test]
+    fn end_role_with_named_microsystem_removes_only_that_one() {
+        use crate::enums::LifeDomain;
+        use crate::types::Timestamp;
+
+        let mut context = EcologicalContext::default();
+        let day_job = MicrosystemId::new("day_job").unwrap();
+        context.add_microsystem(
+            day_job.clone(),
+            Microsystem::new_work(WorkContext::default()),
+        );
+        context.add_microsystem(
+            MicrosystemId::new("night_job").unwrap(),
+            Microsystem::new_work(WorkContext::default()),
+        );
+
+        let exit = RoleExit::new(
+            LifeDomain::Work,
+            false,
+            Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0),
+        )
+        .with_microsystem(day_job.clone());
+        assert_eq!(context.end_role(&exit), 1);
+        assert!(context.get_microsystem(&day_job).is_none());
+        assert_eq!(context.microsystem_count(), 1);
+    }
+
+    #[test]
+    fn end_role_without_microsystem_type_removes_nothing() {
+        use crate::enums::LifeDomain;
+        use crate::types::Timestamp;
+
+        let mut context = EcologicalContext::default();
+        context.add_microsystem(
+            MicrosystemId::new("team").unwrap(),
+            Microsystem::new_social(SocialContext::default()),
+        );
+
+        let exit = RoleExit::new(
+            LifeDomain::Athletic,
+            true,
+            Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0),
+        );
+        assert_eq!(context.end_role(&exit), 0);
+        assert_eq!(context.microsystem_count(), 1);
+    }
+
     #[test]
     fn list_microsystems_by_type() {
         let mut context = EcologicalContext::default();
@@ -829,6 +903,64 @@ mod tests {
         assert!((value - 0.9).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn work_and_family_structural_paths_round_trip() {
+        use crate::enums::{FamilyPath, WorkPath};
+
+        let mut context = EcologicalContext::default();
+        let work_id = MicrosystemId::new("work_acme").unwrap();
+        let family_id = MicrosystemId::new("family_home").unwrap();
+        context.add_microsystem(
+            work_id.clone(),
+            Microsystem::new_work(WorkContext::default()),
+        );
+        context.add_microsystem(
+            family_id.clone(),
+            Microsystem::new_family(FamilyContext::default()),
+        );
+
+        let work_paths = [
+            WorkPath::Autonomy,
+            WorkPath::ScheduleFlexibility,
+            WorkPath::Recognition,
+            WorkPath::JobInsecurity,
+        ]
+        .map(|path| ContextPath::Microsystem(work_id.clone(), MicrosystemPath::Work(path)));
+        let family_paths = [
+            FamilyPath::CaregivingSupport,
+            FamilyPath::FinancialStrain,
+            FamilyPath::Cohesion,
+        ]
+        .map(|path| ContextPath::Microsystem(family_id.clone(), MicrosystemPath::Family(path)));
+        for (i, path) in work_paths.iter().chain(&family_paths).enumerate() {
+            let value = 0.1 + 0.1 * i as f64;
+            assert!(context.set(path, value));
+            assert_eq!(context.get(path), Some(value));
+        }
+    }
+
+    #[test]
+    fn low_autonomy_and_job_insecurity_raise_aggregate_stress() {
+        let work_id = MicrosystemId::new("work").unwrap();
+        let mut secure = EcologicalContext::default();
+        secure.add_microsystem(
+            work_id.clone(),
+            Microsystem::new_work(WorkContext::default()),
+        );
+        let mut precarious = EcologicalContext::default();
+        precarious.add_microsystem(
+            work_id,
+            Microsystem::new_work(WorkContext {
+                autonomy: 0.1,
+                job_insecurity: 0.8,
+                ..Default::default()
+            }),
+        );
+
+        assert!((secure.aggregate_stress() - 0.3).abs() < f64::EPSILON);
+        assert!(precarious.aggregate_stress() > secure.aggregate_stress() + 0.2);
+    }
+
     #[test]
     fn context_set_microsystem_nonexistent() {
         let mut context = EcologicalContext::default();
@@ -1135,6 +1267,23 @@ mod tests {
         assert!(updated.role_clarity > 0.4);
     }
 
+    #[test]
+    fn high_conscientiousness_increases_work_autonomy_and_recognition() {
+        let mut context = EcologicalContext::default();
+        let work_id = MicrosystemId::new("work").unwrap();
+        context.add_microsystem(
+            work_id.clone(),
+            Microsystem::new_work(WorkContext::default()),
+        );
+
+        context.apply_person_to_context_shaping(0.0, 0.7, 0.0, 0.0, 0.0);
+
+        let updated = context.get_microsystem(&work_id).unwrap().work().unwrap();
+        assert!(updated.autonomy > 0.5);
+        assert!(updated.recognition > 0.5);
+        assert!((updated.schedule_flexibility - 0.5).abs() < f64::EPSILON);
+    }
+
     #[test]
     fn conscientiousness_ignores_non_work_microsystems() {
         let mut context = EcologicalContext::default();
@@ -0,0 +1,472 @@
+//! Role exit modeling for retirement and other departures from a life role.
+//!
+//! A role exit ends membership in the microsystem that carried the role
+//! (e.g., the Work microsystem on retirement). Leaving removes that
+//! microsystem's stress spillover, but also the purpose the role provided.
+//!
+//! Whether purpose recovers depends on two factors:
+//!
+//! - **Voluntariness**: chosen exits (planned retirement) recover more fully
+//!   than imposed ones (forced retirement, layoff).
+//! - **Alternative purpose sources**: volunteering or community microsystems
+//!   and strong family roles give purpose somewhere else to come from.
+//!
+//! Involuntary exits additionally leave a grievance and a self-worth hit
+//! that fade slowly over the following years.
+//!
+//! Role exits are declared either by an event carrying
+//! `EventPayload::RoleExit` or by a completed "retirement" normative
+//! transition in the chronosystem.
+
+use crate::context::{EcologicalContext, FamilyRole, MicrosystemType, NormativeTransition};
+use crate::enums::{EventPayload, LifeDomain};
+use crate::event::Event;
+#[cfg(feature = "context")]
+use crate::state::IndividualState;
+use crate::types::{Duration, MicrosystemId, Timestamp};
+
+/// Name of the chronosystem normative transition treated as retirement.
+pub const RETIREMENT_TRANSITION: &str = "retirement";
+
+/// Purpose lost when a role ends, before any recovery.
+pub const ROLE_EXIT_PURPOSE_LOSS: f32 = 0.25;
+
+/// Grievance added by an involuntary exit.
+pub const INVOLUNTARY_EXIT_GRIEVANCE: f32 = 0.15;
+
+/// Self-worth lost to an involuntary exit.
+pub const INVOLUNTARY_EXIT_SELF_WORTH_LOSS: f32 = 0.12;
+
+/// Days for half of the attainable purpose recovery to occur.
+#[cfg(feature = "context")]
+const PURPOSE_RECOVERY_HALF_LIFE_DAYS: f64 = 180.0;
+
+/// Days for the grievance and self-worth hit of an involuntary exit to halve.
+#[cfg(feature = "context")]
+const INVOLUNTARY_EXIT_HALF_LIFE_DAYS: f64 = 730.0;
+
+/// Minimum interaction frequency for a community microsystem to count as
+/// an alternative purpose source (e.g., regular volunteering).
+const COMMUNITY_ROLE_FREQUENCY_THRESHOLD: f64 = 0.5;
+
+/// Minimum family warmth for a parent or spouse role to count as an
+/// alternative purpose source.
+const FAMILY_ROLE_WARMTH_THRESHOLD: f64 = 0.6;
+
+/// A departure from a life role at a specific time.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::context::{MicrosystemType, RoleExit};
+/// use behavioral_pathways::enums::LifeDomain;
+/// use behavioral_pathways::types::Timestamp;
+///
+/// let exit = RoleExit::new(
+///     LifeDomain::Work,
+///     true,
+///     Timestamp::from_ymd_hms(2024, 6, 1, 0, 0, 0),
+/// );
+/// assert_eq!(exit.microsystem_type(), Some(MicrosystemType::Work));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoleExit {
+    /// Life domain of the role being exited.
+    pub domain: LifeDomain,
+
+    /// Whether the exit was chosen (true) or imposed (false).
+    pub voluntary: bool,
+
+    /// When the exit occurred.
+    pub timestamp: Timestamp,
+
+    /// Specific microsystem being left, if known.
+    ///
+    /// When `None`, every microsystem of the domain's type is ended.
+    pub microsystem: Option<MicrosystemId>,
+}
+
+impl RoleExit {
+    /// Creates a role exit for the given domain.
+    #[must_use]
+    pub fn new(domain: LifeDomain, voluntary: bool, timestamp: Timestamp) -> Self {
+        RoleExit {
+            domain,
+            voluntary,
+            timestamp,
+            microsystem: None,
+        }
+    }
+
+    /// Restricts the exit to a specific microsystem.
+    #[must_use]
+    pub fn with_microsystem(mut self, id: MicrosystemId) -> Self {
+        self.microsystem = Some(id);
+        self
+    }
+
+    /// Builds a role exit from an event carrying `EventPayload::RoleExit`.
+    ///
+    /// The event's microsystem context, if set, names the microsystem left.
+    /// Returns `None` for any other payload.
+    #[must_use]
+    pub fn from_event(event: &Event, timestamp: Timestamp) -> Option<Self> {
+        match event.payload() {
+            EventPayload::RoleExit { domain, voluntary } => Some(RoleExit {
+                domain: *domain,
+                voluntary: *voluntary,
+                timestamp,
+                microsystem: event.microsystem_context().cloned(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Builds a voluntary Work exit from a completed retirement transition.
+    ///
+    /// Returns `None` if the transition is not retirement, has not been
+    /// completed, or the birth date is unknown.
+    #[must_use]
+    pub fn from_retirement_transition(
+        transition: &NormativeTransition,
+        birth_date: Option<Timestamp>,
+    ) -> Option<Self> {
+        if !transition.name.eq_ignore_ascii_case(RETIREMENT_TRANSITION) {
+            return None;
+        }
+        let actual_age = transition.actual_age?;
+        let birth_date = birth_date?;
+        let age_days = (actual_age.max(0.0) * 365.0).round() as u64;
+        Some(RoleExit::new(
+            LifeDomain::Work,
+            true,
+            birth_date + Duration::days(age_days),
+        ))
+    }
+
+    /// Returns the microsystem type carrying this role, if any.
+    ///
+    /// Work maps to the Work microsystem and Academic to Education. Other
+    /// domains have no dedicated microsystem.
+    #[must_use]
+    pub const fn microsystem_type(&self) -> Option<MicrosystemType> {
+        match self.domain {
+            LifeDomain::Work => Some(MicrosystemType::Work),
+            LifeDomain::Academic => Some(MicrosystemType::Education),
+            _ => None,
+        }
+    }
+
+    /// Returns the fraction of lost purpose that can eventually be regained.
+    ///
+    /// | Voluntary | Alternatives | Ceiling |
+    /// |-----------|--------------|---------|
+    /// | yes       | yes          | 0.9     |
+    /// | no        | yes          | 0.6     |
+    /// | yes       | no           | 0.4     |
+    /// | no        | no           | 0.0     |
+    #[must_use]
+    pub const fn purpose_recovery_ceiling(&self, has_alternatives: bool) -> f32 {
+        match (self.voluntary, has_alternatives) {
+            (true, true) => 0.9,
+            (false, true) => 0.6,
+            (true, false) => 0.4,
+            (false, false) => 0.0,
+        }
+    }
+}
+
+/// Returns true if the context offers purpose outside a paid or academic role.
+///
+/// Alternatives are regular community involvement (a Social, Religious, or
+/// Neighborhood microsystem with frequent interaction, such as volunteering)
+/// or a warm parent or spouse role in a Family microsystem.
+#[must_use]
+pub fn has_alternative_purpose_sources(context: &EcologicalContext) -> bool {
+    context.microsystems_iter().any(|(_, microsystem)| {
+        if let Some(family) = microsystem.family() {
+            return matches!(family.family_role, FamilyRole::Parent | FamilyRole::Spouse)
+                && family.warmth >= FAMILY_ROLE_WARMTH_THRESHOLD;
+        }
+        matches!(
+            microsystem.microsystem_type(),
+            MicrosystemType::Social | MicrosystemType::Religious | MicrosystemType::Neighborhood
+        ) && microsystem.interaction_frequency() >= COMMUNITY_ROLE_FREQUENCY_THRESHOLD
+    })
+}
+
+/// Applies the sustained effects of role exits to state.
+///
+/// Called by `state_at()` after context effects, with the context as it
+/// stands after the exits. Exits later than `current_timestamp` are ignored.
+///
+/// # Arguments
+///
+/// * `state` - The current state after decay, events, and context effects
+/// * `context` - The ecological context with exited roles already removed
+/// * `exits` - Role exits that occurred in the queried range
+/// * `current_timestamp` - Absolute timestamp for the current state
+#[cfg(feature = "context")]
+#[must_use]
+pub(crate) fn apply_role_exit_effects(
+    mut state: IndividualState,
+    context: &EcologicalContext,
+    exits: &[RoleExit],
+    current_timestamp: Timestamp,
+) -> IndividualState {
+    let has_alternatives = has_alternative_purpose_sources(context);
+
+    for exit in exits.iter().filter(|e| e.timestamp <= current_timestamp) {
+        let days = (current_timestamp - exit.timestamp).as_days_f64();
+
+        let ceiling = exit.purpose_recovery_ceiling(has_alternatives);
+        let progress = 1.0 - 0.5_f64.powf(days / PURPOSE_RECOVERY_HALF_LIFE_DAYS);
+        let recovered = ceiling * progress as f32;
+        state
+            .needs_mut()
+            .add_purpose_delta(-ROLE_EXIT_PURPOSE_LOSS * (1.0 - recovered));
+
+        if !exit.voluntary {
+            let residual = 0.5_f64.powf(days / INVOLUNTARY_EXIT_HALF_LIFE_DAYS) as f32;
+            state
+                .disposition_mut()
+                .add_grievance_delta(INVOLUNTARY_EXIT_GRIEVANCE * residual);
+            state
+                .mental_health_mut()
+                .add_self_worth_delta(-INVOLUNTARY_EXIT_SELF_WORTH_LOSS * residual);
+        }
+    }
+
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::{FamilyContext, InteractionProfile, Microsystem, SocialContext, WorkContext};
+    use crate::enums::EventType;
+    use crate::event::EventBuilder;
+
+    fn exit_time() -> Timestamp {
+        Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+    }
+
+    fn volunteering_context() -> EcologicalContext {
+        let mut context = EcologicalContext::default();
+        let social = SocialContext {
+            interaction_profile: InteractionProfile::with_values(0.7, 0.6),
+            ..Default::default()
+        };
+        context.add_microsystem(
+            MicrosystemId::new("food_bank").unwrap(),
+            Microsystem::new_social(social),
+        );
+        context
+    }
+
+    #[test]
+    fn from_event_reads_role_exit_payload() {
+        let work_id = MicrosystemId::new("acme").unwrap();
+        let event = EventBuilder::new(EventType::ContextTransition)
+            .payload(EventPayload::RoleExit {
+                domain: LifeDomain::Work,
+                voluntary: false,
+            })
+            .context(work_id.clone())
+            .build()
+            .unwrap();
+
+        let exit = RoleExit::from_event(&event, exit_time()).unwrap();
+        assert_eq!(exit.domain, LifeDomain::Work);
+        assert!(!exit.voluntary);
+        assert_eq!(exit.microsystem, Some(work_id));
+    }
+
+    #[test]
+    fn from_event_ignores_other_payloads() {
+        let event = EventBuilder::new(EventType::ContextTransition)
+            .build()
+            .unwrap();
+        assert!(RoleExit::from_event(&event, exit_time()).is_none());
+    }
+
+    #[test]
+    fn retirement_transition_converts_to_voluntary_work_exit() {
+        let birth = Timestamp::from_ymd_hms(1960, 1, 1, 0, 0, 0);
+        let mut transition = NormativeTransition::new("retirement", 65.0, 3.0, false);
+        assert!(RoleExit::from_retirement_transition(&transition, Some(birth)).is_none());
+
+        transition.complete(64.0);
+        let exit = RoleExit::from_retirement_transition(&transition, Some(birth)).unwrap();
+        assert_eq!(exit.domain, LifeDomain::Work);
+        assert!(exit.voluntary);
+        assert_eq!(exit.timestamp, birth + Duration::days(64 * 365));
+
+        assert!(RoleExit::from_retirement_transition(&transition, None).is_none());
+    }
+
+    #[test]
+    fn non_retirement_transition_is_not_an_exit() {
+        let birth = Timestamp::from_ymd_hms(1960, 1, 1, 0, 0, 0);
+        let mut transition = NormativeTransition::new("marriage", 28.0, 4.0, false);
+        transition.complete(28.0);
+        assert!(RoleExit::from_retirement_transition(&transition, Some(birth)).is_none());
+    }
+
+    #[test]
+    fn domain_maps_to_microsystem_type() {
+        let ts = exit_time();
+        assert_eq!(
+            RoleExit::new(LifeDomain::Work, true, ts).microsystem_type(),
+            Some(MicrosystemType::Work)
+        );
+        assert_eq!(
+            RoleExit::new(LifeDomain::Academic, true, ts).microsystem_type(),
+            Some(MicrosystemType::Education)
+        );
+        assert_eq!(
+            RoleExit::new(LifeDomain::Athletic, true, ts).microsystem_type(),
+            None
+        );
+    }
+
+    #[test]
+    fn recovery_ceiling_orders_quadrants() {
+        let voluntary = RoleExit::new(LifeDomain::Work, true, exit_time());
+        let involuntary = RoleExit::new(LifeDomain::Work, false, exit_time());
+
+        assert!(voluntary.purpose_recovery_ceiling(true) > involuntary.purpose_recovery_ceiling(true));
+        assert!(involuntary.purpose_recovery_ceiling(true) > voluntary.purpose_recovery_ceiling(false));
+        assert!(voluntary.purpose_recovery_ceiling(false) > involuntary.purpose_recovery_ceiling(false));
+    }
+
+    #[test]
+    fn volunteering_counts_as_alternative() {
+        assert!(has_alternative_purpose_sources(&volunteering_context()));
+    }
+
+    #[test]
+    fn infrequent_social_contact_is_not_alternative() {
+        let mut context = EcologicalContext::default();
+        let social = SocialContext {
+            interaction_profile: InteractionProfile::with_values(0.2, 0.6),
+            ..Default::default()
+        };
+        context.add_microsystem(
+            MicrosystemId::new("book_club").unwrap(),
+            Microsystem::new_social(social),
+        );
+        assert!(!has_alternative_purpose_sources(&context));
+    }
+
+    #[test]
+    fn warm_parent_role_counts_as_alternative() {
+        let mut context = EcologicalContext::default();
+        let family = FamilyContext {
+            family_role: FamilyRole::Parent,
+            warmth: 0.8,
+            ..Default::default()
+        };
+        context.add_microsystem(
+            MicrosystemId::new("home").unwrap(),
+            Microsystem::new_family(family),
+        );
+        assert!(has_alternative_purpose_sources(&context));
+    }
+
+    #[test]
+    fn cold_or_child_family_role_is_not_alternative() {
+        let mut context = EcologicalContext::default();
+        let family = FamilyContext {
+            family_role: FamilyRole::Child,
+            warmth: 0.9,
+            ..Default::default()
+        };
+        context.add_microsystem(
+            MicrosystemId::new("home").unwrap(),
+            Microsystem::new_family(family),
+        );
+        assert!(!has_alternative_purpose_sources(&context));
+    }
+
+    #[test]
+    fn work_microsystem_is_not_alternative() {
+        let mut context = EcologicalContext::default();
+        context.add_microsystem(
+            MicrosystemId::new("acme").unwrap(),
+            Microsystem::new_work(WorkContext::default()),
+        );
+        assert!(!has_alternative_purpose_sources(&context));
+    }
+
+    #[cfg(feature = "context")]
+    #[test]
+    fn exit_drops_purpose_immediately() {
+        let exits = [RoleExit::new(LifeDomain::Work, true, exit_time())];
+        let state = apply_role_exit_effects(
+            IndividualState::new(),
+            &EcologicalContext::default(),
+            &exits,
+            exit_time(),
+        );
+        assert!((state.needs().purpose().delta() + ROLE_EXIT_PURPOSE_LOSS).abs() < 1e-6);
+    }
+
+    #[cfg(feature = "context")]
+    #[test]
+    fn voluntary_exit_with_alternatives_recovers_most_purpose() {
+        let exits = [RoleExit::new(LifeDomain::Work, true, exit_time())];
+        let later = exit_time() + Duration::days(730);
+        let state = apply_role_exit_effects(
+            IndividualState::new(),
+            &volunteering_context(),
+            &exits,
+            later,
+        );
+        let remaining_loss = -state.needs().purpose().delta();
+        assert!(remaining_loss > 0.0);
+        assert!(remaining_loss < ROLE_EXIT_PURPOSE_LOSS * 0.2);
+        assert!(state.disposition().grievance().delta().abs() < f32::EPSILON);
+    }
+
+    #[cfg(feature = "context")]
+    #[test]
+    fn involuntary_exit_without_alternatives_keeps_full_loss() {
+        let exits = [RoleExit::new(LifeDomain::Work, false, exit_time())];
+        let later = exit_time() + Duration::days(730);
+        let state = apply_role_exit_effects(
+            IndividualState::new(),
+            &EcologicalContext::default(),
+            &exits,
+            later,
+        );
+        assert!((state.needs().purpose().delta() + ROLE_EXIT_PURPOSE_LOSS).abs() < 1e-6);
+        // Two years is one half-life for the grievance and self-worth hit
+        let grievance = state.disposition().grievance().delta();
+        assert!((grievance - INVOLUNTARY_EXIT_GRIEVANCE * 0.5).abs() < 1e-4);
+        let self_worth = state.mental_health().self_worth().delta();
+        assert!((self_worth + INVOLUNTARY_EXIT_SELF_WORTH_LOSS * 0.5).abs() < 1e-4);
+    }
+
+    #[cfg(feature = "context")]
+    #[test]
+    fn future_exits_are_ignored() {
+        let exits = [RoleExit::new(LifeDomain::Work, false, exit_time())];
+        let before = exit_time() - Duration::days(1);
+        let state = apply_role_exit_effects(
+            IndividualState::new(),
+            &EcologicalContext::default(),
+            &exits,
+            before,
+        );
+        assert!(state.needs().purpose().delta().abs() < f32::EPSILON);
+        assert!(state.disposition().grievance().delta().abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn with_microsystem_sets_target() {
+        let id = MicrosystemId::new("acme").unwrap();
+        let exit = RoleExit::new(LifeDomain::Work, true, exit_time()).with_microsystem(id.clone());
+        assert_eq!(exit.microsystem, Some(id));
+    }
+}
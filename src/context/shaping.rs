@@ -0,0 +1,319 @@
+//! Time-integrated person-to-context shaping.
+//!
+//! People shape the settings they spend time in: an extravert warms their
+//! social circle, a conscientious worker brings structure to their job and
+//! earns autonomy and recognition in it, and a person holding a grievance
+//! reads hostility into every setting.
+//! `EcologicalContext::apply_person_to_context_shaping` gives the fully
+//! developed effect of a personality on a context. Here that effect builds
+//! up with the time the person spends in the context.
+//!
+//! Each shaped field moves toward its fully shaped value along
+//!
+//! ```text
+//! fraction(t) = 1 - 0.5^(t / PERSON_SHAPING_HALF_LIFE_DAYS)
+//! ```
+//!
+//! which is 0 at `t = 0`, one half after a half-life, and saturates at 1.
+//! A span from `t1` to `t2` delivers `fraction(t2) - fraction(t1)` of the
+//! full shift, so shaping a context over consecutive spans gives the same
+//! result as shaping it once over their total.
+//!
+//! Shaping returns a new context. Queries shape a per-query copy, so the
+//! context stored on an entity is never changed by a query.
+
+use crate::context::{EcologicalContext, Microsystem};
+use crate::state::IndividualState;
+use crate::types::Duration;
+
+/// Days for half of the fully developed person-to-context shaping to occur.
+pub const PERSON_SHAPING_HALF_LIFE_DAYS: f64 = 180.0;
+
+/// Trait threshold above which a trait starts shaping its contexts.
+const SHAPING_THRESHOLD: f32 = 0.3;
+
+/// Shift per unit of trait above `SHAPING_THRESHOLD`.
+const SHAPING_RATE: f64 = 0.1;
+
+/// Returns the share of the fully developed shaping reached after `elapsed`.
+///
+/// Rises from 0.0 and saturates at 1.0, reaching 0.5 after
+/// `PERSON_SHAPING_HALF_LIFE_DAYS`.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::context::{person_shaping_fraction, PERSON_SHAPING_HALF_LIFE_DAYS};
+/// use behavioral_pathways::types::Duration;
+///
+/// assert_eq!(person_shaping_fraction(Duration::zero()), 0.0);
+/// let half_life = Duration::days(PERSON_SHAPING_HALF_LIFE_DAYS as u64);
+/// assert!((person_shaping_fraction(half_life) - 0.5).abs() < 1e-12);
+/// assert!(person_shaping_fraction(Duration::years(50)) > 0.99);
+/// ```
+#[must_use]
+pub fn person_shaping_fraction(elapsed: Duration) -> f64 {
+    1.0 - 0.5_f64.powf(elapsed.as_days_f64() / PERSON_SHAPING_HALF_LIFE_DAYS)
+}
+
+/// Fully developed shifts a personality makes to its contexts.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct PersonShaping {
+    /// Extraversion warms social microsystems.
+    social_warmth: f64,
+    /// Conscientiousness clarifies work roles.
+    role_clarity: f64,
+    /// Conscientiousness makes work more predictable.
+    work_predictability: f64,
+    /// Conscientiousness earns autonomy and recognition at work.
+    work_standing: f64,
+    /// Agreeableness warms family microsystems.
+    family_warmth: f64,
+    /// Neuroticism lowers tolerance for instability at work and home.
+    instability: f64,
+    /// Grievance raises perceived hostility everywhere.
+    hostility: f64,
+}
+
+impl PersonShaping {
+    /// Computes the shifts for the given trait levels.
+    pub(crate) fn new(
+        extraversion: f32,
+        conscientiousness: f32,
+        agreeableness: f32,
+        neuroticism: f32,
+        grievance: f32,
+    ) -> Self {
+        let above = |level: f32| f64::from((level - SHAPING_THRESHOLD).max(0.0)) * SHAPING_RATE;
+        PersonShaping {
+            social_warmth: above(extraversion),
+            role_clarity: above(conscientiousness),
+            work_predictability: above(conscientiousness) * 0.5,
+            work_standing: above(conscientiousness),
+            family_warmth: above(agreeableness),
+            instability: above(neuroticism),
+            hostility: above(grievance),
+        }
+    }
+
+    /// Computes the shifts for the person in `state`.
+    pub(crate) fn from_state(state: &IndividualState) -> Self {
+        let hexaco = state.hexaco();
+        PersonShaping::new(
+            hexaco.extraversion(),
+            hexaco.conscientiousness(),
+            hexaco.agreeableness(),
+            hexaco.neuroticism(),
+            state.disposition().grievance_effective(),
+        )
+    }
+
+    /// Applies `fraction` of the shifts to every microsystem in `context`.
+    pub(crate) fn apply(&self, context: &mut EcologicalContext, fraction: f64) {
+        if fraction == 0.0 || *self == PersonShaping::default() {
+            return;
+        }
+        for microsystem in context.microsystems.values_mut() {
+            self.apply_to(microsystem, fraction);
+        }
+        context.invalidate_mesosystem_cache();
+    }
+
+    fn apply_to(&self, microsystem: &mut Microsystem, fraction: f64) {
+        let shift = |value: &mut f64, delta: f64| {
+            *value = (*value + delta * fraction).clamp(0.0, 1.0);
+        };
+        if let Some(social) = microsystem.social_mut() {
+            shift(&mut social.warmth, self.social_warmth);
+            shift(&mut social.hostility, self.hostility);
+        }
+        if let Some(work) = microsystem.work_mut() {
+            shift(&mut work.role_clarity, self.role_clarity);
+            shift(&mut work.autonomy, self.work_standing);
+            shift(&mut work.recognition, self.work_standing);
+            shift(
+                &mut work.predictability,
+                self.work_predictability - self.instability,
+            );
+            shift(&mut work.stability, -self.instability);
+            shift(&mut work.hostility, self.hostility);
+        }
+        if let Some(family) = microsystem.family_mut() {
+            shift(&mut family.warmth, self.family_warmth);
+            shift(&mut family.stability, -self.instability);
+            shift(&mut family.predictability, -self.instability);
+            shift(&mut family.hostility, self.hostility);
+        }
+    }
+}
+
+/// Returns `context` after the person in `state` has spent `elapsed` in it.
+pub(crate) fn shape_context(
+    context: &EcologicalContext,
+    state: &IndividualState,
+    elapsed: Duration,
+) -> EcologicalContext {
+    let mut shaped = context.clone();
+    PersonShaping::from_state(state).apply(&mut shaped, person_shaping_fraction(elapsed));
+    shaped
+}
+
+/// Returns `context` as shaped on average over a span of `elapsed`.
+///
+/// Effects that accrue over a span at a rate set by the context see the
+/// context as it develops. With shaping linear in the fraction, the
+/// average context is the one shaped by the fraction's mean over the span.
+#[cfg(feature = "context")]
+pub(crate) fn shape_context_over(
+    context: &EcologicalContext,
+    state: &IndividualState,
+    elapsed: Duration,
+) -> EcologicalContext {
+    let half_lives = elapsed.as_days_f64() / PERSON_SHAPING_HALF_LIFE_DAYS;
+    let mean_fraction = if half_lives > 0.0 {
+        1.0 - person_shaping_fraction(elapsed) / (half_lives * std::f64::consts::LN_2)
+    } else {
+        0.0
+    };
+    let mut shaped = context.clone();
+    PersonShaping::from_state(state).apply(&mut shaped, mean_fraction);
+    shaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::{FamilyContext, SocialContext, WorkContext};
+    use crate::state::Hexaco;
+    use crate::types::MicrosystemId;
+
+    fn context() -> EcologicalContext {
+        let mut context = EcologicalContext::default();
+        context.add_microsystem(
+            MicrosystemId::new("friends").unwrap(),
+            Microsystem::new_social(SocialContext {
+                warmth: 0.4,
+                ..Default::default()
+            }),
+        );
+        context.add_microsystem(
+            MicrosystemId::new("office").unwrap(),
+            Microsystem::new_work(WorkContext::default()),
+        );
+        context.add_microsystem(
+            MicrosystemId::new("home").unwrap(),
+            Microsystem::new_family(FamilyContext::default()),
+        );
+        context
+    }
+
+    fn person() -> IndividualState {
+        let mut state = IndividualState::new();
+        *state.hexaco_mut() = Hexaco::new()
+            .with_extraversion(0.9)
+            .with_conscientiousness(0.8)
+            .with_agreeableness(0.7)
+            .with_neuroticism(0.6);
+        state
+    }
+
+    fn fields(context: &EcologicalContext) -> Vec<f64> {
+        let mut ids: Vec<_> = context.microsystems_iter().map(|(id, _)| id).collect();
+        ids.sort();
+        let mut fields = Vec::new();
+        for id in ids {
+            let microsystem = context.get_microsystem(id).unwrap();
+            if let Some(social) = microsystem.social() {
+                fields.extend([social.warmth, social.hostility]);
+            }
+            if let Some(work) = microsystem.work() {
+                fields.extend([
+                    work.role_clarity,
+                    work.predictability,
+                    work.stability,
+                    work.hostility,
+                ]);
+            }
+            if let Some(family) = microsystem.family() {
+                fields.extend([
+                    family.warmth,
+                    family.stability,
+                    family.predictability,
+                    family.hostility,
+                ]);
+            }
+        }
+        fields
+    }
+
+    fn warmth(context: &EcologicalContext) -> f64 {
+        context
+            .get_microsystem(&MicrosystemId::new("friends").unwrap())
+            .unwrap()
+            .social()
+            .unwrap()
+            .warmth
+    }
+
+    #[test]
+    fn shaping_saturates_at_the_fully_developed_shaping() {
+        let (context, state) = (context(), person());
+        let mut full = context.clone();
+        let hexaco = state.hexaco();
+        full.apply_person_to_context_shaping(
+            hexaco.extraversion(),
+            hexaco.conscientiousness(),
+            hexaco.agreeableness(),
+            hexaco.neuroticism(),
+            0.0,
+        );
+
+        let day = warmth(&shape_context(&context, &state, Duration::days(1)));
+        let year = warmth(&shape_context(&context, &state, Duration::years(1)));
+        let century = warmth(&shape_context(&context, &state, Duration::years(100)));
+        assert!(warmth(&context) < day && day < year && year < century);
+        assert!((century - warmth(&full)).abs() < 1e-9);
+        assert_eq!(shape_context(&context, &state, Duration::zero()), context);
+    }
+
+    #[test]
+    fn consecutive_spans_match_a_single_span() {
+        let (context, state) = (context(), person());
+        let shaping = PersonShaping::from_state(&state);
+        for total_days in [2, 30, 180, 365, 2000, 10_000] {
+            let total = Duration::days(total_days);
+            let whole = fields(&shape_context(&context, &state, total));
+            for split_days in [1, total_days / 3, total_days / 2, total_days - 1] {
+                let split = Duration::days(split_days);
+                let mut segmented = shape_context(&context, &state, split);
+                let remaining = person_shaping_fraction(total) - person_shaping_fraction(split);
+                shaping.apply(&mut segmented, remaining);
+                for (a, b) in whole.iter().zip(fields(&segmented)) {
+                    assert!(
+                        (a - b).abs() < 1e-12,
+                        "{total_days} days split at {split_days}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn shaping_leaves_the_original_context_unchanged() {
+        let (context, state) = (context(), person());
+        let before = context.clone();
+        let shaped = shape_context(&context, &state, Duration::years(5));
+        assert_ne!(fields(&shaped), fields(&before));
+        assert_eq!(context, before);
+    }
+
+    #[test]
+    #[cfg(feature = "context")]
+    fn span_average_lies_between_start_and_end() {
+        let (context, state) = (context(), person());
+        let span = Duration::days(365);
+        let average = warmth(&shape_context_over(&context, &state, span));
+        assert!(warmth(&context) < average);
+        assert!(average < warmth(&shape_context(&context, &state, span)));
+    }
+}
@@ -0,0 +1,422 @@
+//! Reusable contract checks for downstream model extensions.
+//!
+//! Crates that add their own species, event blueprints, or simulations can
+//! call these from their test suites to check their extensions against the
+//! semantics this crate relies on. The crate's own tests call the same
+//! functions, so a change to a contract fails here first.
+//!
+//! Each function panics with a message naming the broken rule:
+//!
+//! - [`assert_boundary_rules`] - which events a query at, after, or before
+//!   the anchor includes
+//! - [`assert_decay_roundtrip`] - decay toward the base, and regression
+//!   undoing it, on a species' psychological time
+//! - [`assert_blueprint_bounds`] - which paths a blueprint may touch, and
+//!   with what signs and magnitudes
+//! - [`assert_path_ranges`] - the effective range of every state path
+//!
+//! Available with the `contract-tests` feature.
+//!
+//! # Examples
+//!
+//! Validating a custom blueprint:
+//!
+//! ```
+//! use behavioral_pathways::contract_tests::assert_blueprint_bounds;
+//! use behavioral_pathways::enums::{
+//!     EventType, MoodPath, ReversibilityResult, SocialCognitionPath, StatePath,
+//! };
+//! use behavioral_pathways::event::BlueprintSummary;
+//!
+//! let ostracism = BlueprintSummary {
+//!     deltas: vec![
+//!         (StatePath::Mood(MoodPath::Valence), -0.25),
+//!         (StatePath::SocialCognition(SocialCognitionPath::Loneliness), 0.3),
+//!     ],
+//!     reversibility: ReversibilityResult::Reversible,
+//!     ..BlueprintSummary::for_event_type(EventType::SocialExclusion)
+//! };
+//! assert_blueprint_bounds(&ostracism);
+//! ```
+
+use crate::enums::{EventType, MentalHealthPath, Species, StatePath};
+use crate::event::{BlueprintSummary, EventBuilder};
+use crate::processor::{
+    apply_event_delta, decay_processor_for, is_reversible_event_path, regress_state,
+    EVENT_DELTA_PATHS,
+};
+use crate::simulation::{effective_value, RegressionQuality, Simulation};
+use crate::state::IndividualState;
+use crate::types::{Duration, Timestamp};
+
+/// How far from the anchor `assert_boundary_rules` places its probe
+/// events.
+pub const PROBE_OFFSET: Duration = Duration::hours(1);
+
+/// Largest round-trip error `assert_decay_roundtrip` accepts.
+pub const ROUNDTRIP_TOLERANCE: f32 = 1e-4;
+
+/// Smallest decayed delta `assert_decay_roundtrip` expects regression to
+/// restore; smaller ones have lost too much precision.
+pub const ROUNDTRIP_FLOOR: f32 = 0.01;
+
+/// Asserts the event boundary rules for every entity in `simulation`.
+///
+/// For each entity, probes with a severe social exclusion previewed at
+/// and around its anchor and checks that:
+///
+/// - a query at the anchor returns the anchor state exactly, whatever
+///   happens at the anchor
+/// - projecting forward skips an event at the anchor, which the anchor
+///   state already reflects, and includes one at the queried time
+/// - regressing backward reverses an event at the anchor and between, but
+///   not one at the queried time, which the state then already reflects
+///
+/// Entities should have no observation within `PROBE_OFFSET` of their
+/// anchor, since an observation replaces the projection.
+///
+/// # Panics
+///
+/// Panics if any rule is broken.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::contract_tests::assert_boundary_rules;
+/// use behavioral_pathways::entity::EntityBuilder;
+/// use behavioral_pathways::enums::Species;
+/// use behavioral_pathways::simulation::Simulation;
+/// use behavioral_pathways::types::Timestamp;
+///
+/// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+/// let mut sim = Simulation::new(reference);
+/// let otter = Species::custom("otter", 20, 3, 0.6);
+/// let entity = EntityBuilder::new().species(otter).build().unwrap();
+/// sim.add_entity(entity, reference);
+/// assert_boundary_rules(&sim);
+/// ```
+pub fn assert_boundary_rules(simulation: &Simulation) {
+    for anchored in simulation.entities() {
+        let id = anchored.entity().id();
+        let anchor = anchored.anchor_timestamp();
+        let handle = simulation
+            .entity(id)
+            .unwrap_or_else(|| panic!("entity '{id}' has no query handle"));
+        let probe = EventBuilder::new(EventType::SocialExclusion)
+            .target(id.clone())
+            .severity(0.8)
+            .build()
+            .unwrap_or_else(|err| panic!("probe event for '{id}' failed to build: {err}"));
+        let with_probe = |at: Timestamp, query: Timestamp| {
+            handle.preview_event(&probe, at, query).individual_state
+        };
+
+        let at_anchor = handle.state_at(anchor);
+        assert_eq!(
+            &at_anchor.individual_state,
+            anchored.entity().individual_state(),
+            "entity '{id}': state at the anchor differs from the anchor state"
+        );
+        assert_eq!(
+            at_anchor.regression_quality(),
+            RegressionQuality::Exact,
+            "entity '{id}': state at the anchor is not exact"
+        );
+        assert_eq!(
+            with_probe(anchor, anchor),
+            at_anchor.individual_state,
+            "entity '{id}': an event at the anchor changed the state at the anchor"
+        );
+
+        let later = anchor + PROBE_OFFSET;
+        let projected = handle.state_at(later).individual_state;
+        assert_eq!(
+            with_probe(anchor, later),
+            projected,
+            "entity '{id}': forward projection applied an event at the anchor"
+        );
+        assert_ne!(
+            with_probe(later, later),
+            projected,
+            "entity '{id}': forward projection skipped an event at the queried time"
+        );
+
+        let earlier = anchor - PROBE_OFFSET;
+        let regressed = handle.state_at(earlier).individual_state;
+        assert_eq!(
+            with_probe(earlier, earlier),
+            regressed,
+            "entity '{id}': regression reversed an event at the queried time"
+        );
+        assert_ne!(
+            with_probe(earlier + PROBE_OFFSET / 2, earlier),
+            regressed,
+            "entity '{id}': regression skipped an event before the anchor"
+        );
+        assert_ne!(
+            with_probe(anchor, earlier),
+            regressed,
+            "entity '{id}': regression skipped an event at the anchor"
+        );
+    }
+}
+
+/// Asserts that decay on `species`' psychological time moves every event
+/// delta toward its base and that regression undoes it.
+///
+/// Starts from a state with a positive and a negative delta on the event
+/// delta paths, then checks over several spans that:
+///
+/// - each decaying delta shrinks in magnitude without changing sign
+/// - Acquired Capability, which never decays, keeps its delta
+/// - regressing by the same span restores, within `ROUNDTRIP_TOLERANCE`,
+///   every delta that has not decayed below `ROUNDTRIP_FLOOR`
+///
+/// # Panics
+///
+/// Panics if any rule is broken.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::contract_tests::assert_decay_roundtrip;
+/// use behavioral_pathways::enums::Species;
+///
+/// assert_decay_roundtrip(&Species::custom("otter", 20, 3, 0.6));
+/// ```
+pub fn assert_decay_roundtrip(species: &Species) {
+    for sign in [1.0_f32, -1.0] {
+        let mut original = IndividualState::new();
+        for (path, _) in EVENT_DELTA_PATHS {
+            // Acquired Capability only accumulates
+            let delta = if is_reversible_event_path(path) {
+                0.3 * sign
+            } else {
+                0.3
+            };
+            apply_event_delta(&mut original, path, delta, false);
+        }
+
+        for elapsed in [Duration::hours(6), Duration::days(3), Duration::days(30)] {
+            let mut decayed = original.clone();
+            decayed.apply_species_decay(elapsed, species);
+            for (path, _) in EVENT_DELTA_PATHS {
+                let before = delta_at(&original, path);
+                let after = delta_at(&decayed, path);
+                if is_reversible_event_path(path) {
+                    assert!(
+                        after.abs() <= before.abs() && after * before >= 0.0,
+                        "{species:?}: {path:?} delta {before} decayed to {after} over {elapsed:?}"
+                    );
+                } else {
+                    assert_eq!(
+                        after, before,
+                        "{species:?}: {path:?} decayed over {elapsed:?}, but never decays"
+                    );
+                }
+            }
+
+            let restored = regress_state(
+                decayed.clone(),
+                species.psychological_duration(elapsed),
+                decay_processor_for(species),
+            );
+            for (path, _) in EVENT_DELTA_PATHS {
+                if delta_at(&decayed, path).abs() < ROUNDTRIP_FLOOR {
+                    continue;
+                }
+                let before = delta_at(&original, path);
+                let after = delta_at(&restored, path);
+                assert!(
+                    (after - before).abs() <= ROUNDTRIP_TOLERANCE,
+                    "{species:?}: {path:?} delta {before} came back as {after} after {elapsed:?}"
+                );
+            }
+        }
+    }
+}
+
+/// Asserts that a blueprint only uses deltas the processor applies.
+///
+/// Checks that the blueprint:
+///
+/// - touches each path at most once, and only event delta paths (any
+///   other delta would be silently dropped)
+/// - has finite, non-zero per-unit-severity deltas of at most 1
+/// - never lowers Acquired Capability
+/// - leaves every path within its range, after clamping, when applied at
+///   full severity to a neutral state
+/// - is non-reversible exactly when it touches a path regression cannot
+///   undo
+///
+/// # Panics
+///
+/// Panics if any rule is broken.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::contract_tests::assert_blueprint_bounds;
+/// use behavioral_pathways::enums::EventType;
+/// use behavioral_pathways::event::BlueprintSummary;
+///
+/// assert_blueprint_bounds(&BlueprintSummary::for_event_type(EventType::Violence));
+/// ```
+pub fn assert_blueprint_bounds(blueprint: &BlueprintSummary) {
+    let event_type = blueprint.event_type;
+    let mut applied = IndividualState::new();
+    for (index, &(path, delta)) in blueprint.deltas.iter().enumerate() {
+        assert!(
+            !blueprint.deltas[..index]
+                .iter()
+                .any(|(earlier, _)| *earlier == path),
+            "{event_type:?}: {path:?} has more than one delta"
+        );
+        assert!(
+            delta.is_finite() && delta != 0.0 && delta.abs() <= 1.0,
+            "{event_type:?}: {path:?} delta {delta} is not a finite, non-zero value within 1"
+        );
+        if path == StatePath::MentalHealth(MentalHealthPath::AcquiredCapability) {
+            assert!(
+                delta > 0.0,
+                "{event_type:?}: Acquired Capability delta {delta} is not positive"
+            );
+        }
+        assert!(
+            apply_event_delta(&mut applied, path, delta as f32, false),
+            "{event_type:?}: {path:?} is not an event delta path"
+        );
+        let (min, max) = path.bounds();
+        let value = effective_value(&applied, path);
+        assert!(
+            (min..=max).contains(&value),
+            "{event_type:?}: {path:?} reaches {value} at full severity, outside {min} to {max}"
+        );
+    }
+
+    let irreversible = blueprint
+        .deltas
+        .iter()
+        .any(|(path, _)| !is_reversible_event_path(*path));
+    assert_eq!(
+        blueprint.reversibility.is_non_reversible(),
+        irreversible,
+        "{event_type:?}: reversibility {:?} does not match its paths",
+        blueprint.reversibility
+    );
+}
+
+/// Asserts the effective range of every state path.
+///
+/// Checks that HEXACO factors and mood span -1 to 1 and every other path
+/// 0 to 1, that a neutral state is within range on every path, and that
+/// event deltas far past either end clamp to the range.
+///
+/// # Panics
+///
+/// Panics if any rule is broken.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::contract_tests::assert_path_ranges;
+///
+/// assert_path_ranges();
+/// ```
+pub fn assert_path_ranges() {
+    let neutral = IndividualState::new();
+    for path in StatePath::all() {
+        let expected = match path {
+            StatePath::Hexaco(_) | StatePath::Mood(_) => (-1.0, 1.0),
+            _ => (0.0, 1.0),
+        };
+        assert_eq!(path.bounds(), expected, "{path:?} has unexpected bounds");
+        let (min, max) = expected;
+        let value = effective_value(&neutral, path);
+        assert!(
+            (min..=max).contains(&value),
+            "{path:?} is {value} in a neutral state, outside {min} to {max}"
+        );
+    }
+
+    for push in [10.0_f32, -10.0] {
+        let mut pushed = IndividualState::new();
+        for (path, _) in EVENT_DELTA_PATHS {
+            apply_event_delta(&mut pushed, path, push, false);
+        }
+        for (path, _) in EVENT_DELTA_PATHS {
+            let (min, max) = path.bounds();
+            let value = effective_value(&pushed, path);
+            assert!(
+                (min..=max).contains(&value),
+                "{path:?} is {value} after a delta of {push}, outside {min} to {max}"
+            );
+        }
+    }
+}
+
+/// Returns the delta stored behind an event delta path.
+fn delta_at(state: &IndividualState, path: StatePath) -> f32 {
+    state
+        .state_value(path)
+        .map_or(0.0, |value| value.delta() + value.chronic_delta())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::EntityBuilder;
+
+    #[test]
+    fn crate_simulation_keeps_boundary_rules() {
+        let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let mut sim = Simulation::new(reference);
+        for species in [Species::Human, Species::Dog, Species::Mouse] {
+            let entity = EntityBuilder::new()
+                .species(species)
+                .age(Duration::years(1))
+                .build()
+                .unwrap();
+            sim.add_entity(entity, reference);
+        }
+        assert_boundary_rules(&sim);
+    }
+
+    #[test]
+    fn crate_species_round_trip_decay() {
+        for species in [
+            Species::Human,
+            Species::Dog,
+            Species::Cat,
+            Species::Dolphin,
+            Species::Horse,
+            Species::Elephant,
+            Species::Chimpanzee,
+            Species::Crow,
+            Species::Mouse,
+            Species::Robotic,
+        ] {
+            assert_decay_roundtrip(&species);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "is not an event delta path")]
+    fn blueprint_outside_event_paths_fails() {
+        let blueprint = BlueprintSummary {
+            deltas: vec![(StatePath::Needs(crate::enums::NeedsPath::Stress), 0.2)],
+            ..BlueprintSummary::for_event_type(EventType::Failure)
+        };
+        assert_blueprint_bounds(&blueprint);
+    }
+
+    #[test]
+    #[should_panic(expected = "reversibility")]
+    fn blueprint_with_wrong_reversibility_fails() {
+        let blueprint = BlueprintSummary {
+            reversibility: crate::enums::ReversibilityResult::Reversible,
+            ..BlueprintSummary::for_event_type(EventType::Violence)
+        };
+        assert_blueprint_bounds(&blueprint);
+    }
+}
@@ -0,0 +1,40 @@
+//! Reference data for documentation generation.
+//!
+//! These functions expose library tables in machine-readable form so doc
+//! sites can be generated from code rather than maintained by hand.
+
+use crate::enums::EventType;
+use crate::event::BlueprintSummary;
+
+/// Returns the default-effect summary of every event type.
+///
+/// Entries follow the order of `EventType::all()`.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::docs::event_reference;
+/// use behavioral_pathways::enums::EventType;
+///
+/// let reference = event_reference();
+/// assert_eq!(reference.len(), EventType::all().len());
+/// ```
+#[must_use]
+pub fn event_reference() -> Vec<BlueprintSummary> {
+    EventType::all()
+        .iter()
+        .map(EventType::blueprint_summary)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_reference_covers_all_event_types_in_order() {
+        let reference = event_reference();
+        let types: Vec<EventType> = reference.iter().map(|s| s.event_type).collect();
+        assert_eq!(types, EventType::all().to_vec());
+    }
+}
@@ -3,6 +3,15 @@
 //! Following the theoretical purity of the PAD (Pleasure-Arousal-Dominance) model,
 //! affective state is strictly limited to the three core dimensions. Physiological
 //! states like fatigue and stress are handled separately.
+//!
+//! Both structs are snapshots of `IndividualState`, not separate state. The
+//! supported way to read them at a timestamp is `ComputedState::affective_state()`
+//! and `ComputedState::physiological_state()` (or the equivalent
+//! `AffectiveState::from_computed` and `PhysiologicalState::snapshot`), which
+//! read the same effective values as `ComputedState::get_effective()`.
+
+use crate::simulation::ComputedState;
+use crate::state::IndividualState;
 
 /// Pure PAD affective state containing only the three core dimensions.
 ///
@@ -24,6 +33,23 @@ pub struct AffectiveState {
     pub dominance: f32,
 }
 
+impl AffectiveState {
+    /// Returns the effective PAD state at a queried timestamp.
+    #[must_use]
+    pub fn from_computed(state: &ComputedState) -> Self {
+        Self::from_individual(state.individual_state())
+    }
+
+    /// Returns the effective PAD state of an individual state.
+    pub(crate) fn from_individual(state: &IndividualState) -> Self {
+        AffectiveState {
+            valence: state.mood().valence_effective(),
+            arousal: state.mood().arousal_effective(),
+            dominance: state.mood().dominance_effective(),
+        }
+    }
+}
+
 /// Physiological state containing fatigue and stress.
 ///
 /// These are NOT part of the PAD affective model but are often relevant
@@ -36,3 +62,19 @@ pub struct PhysiologicalState {
     /// Stress: tension (0 to 1).
     pub stress: f32,
 }
+
+impl PhysiologicalState {
+    /// Returns the effective fatigue and stress at a queried timestamp.
+    #[must_use]
+    pub fn snapshot(state: &ComputedState) -> Self {
+        Self::from_individual(state.individual_state())
+    }
+
+    /// Returns the effective fatigue and stress of an individual state.
+    pub(crate) fn from_individual(state: &IndividualState) -> Self {
+        PhysiologicalState {
+            fatigue: state.needs().fatigue_effective(),
+            stress: state.needs().stress_effective(),
+        }
+    }
+}
@@ -0,0 +1,190 @@
+//! Input completeness for partially specified entities.
+//!
+//! Registries rarely record everything the model reads. The entity builder's
+//! `build_partial` fills each missing key input with a documented population
+//! default and lists what it filled in a [`CompletenessReport`]:
+//!
+//! | Input | Default |
+//! |-------|---------|
+//! | Species | `Species::Human` |
+//! | Personality | `PersonalityProfile::Balanced` (every HEXACO factor at the population mean, 0.0) |
+//! | Age | Half the species lifespan (40 years for humans) |
+//! | Birth date | None; age stays fixed at the anchor age |
+//! | Person characteristics | Neutral (0.5) |
+//! | Context | `EcologicalContext::default()` |
+//!
+//! The report is stored on the entity and lowers `ComputedState::confidence`
+//! by each imputed input's weight. Defaults that `build()` applies are
+//! treated as deliberate and not reported.
+
+use std::fmt;
+
+/// A key input that `build_partial` filled with a population default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ImputedField {
+    /// No species; the entity is modelled as human.
+    Species,
+    /// No personality profile, HEXACO, or trait values; traits are at the
+    /// population mean.
+    Personality,
+    /// Neither an age nor a birth date; the age is half the species lifespan.
+    Age,
+    /// No birth date; age and life stage do not advance with time.
+    BirthDate,
+    /// No person characteristics; they are neutral.
+    PersonCharacteristics,
+    /// No ecological context; every context dimension is at its default.
+    Context,
+}
+
+impl ImputedField {
+    /// Returns how much imputing this input lowers confidence.
+    ///
+    /// Personality shapes every event interpretation and weighs most; the
+    /// context and person characteristics only modulate.
+    #[must_use]
+    pub const fn confidence_weight(&self) -> f64 {
+        match self {
+            ImputedField::Species => 0.2,
+            ImputedField::Personality => 0.35,
+            ImputedField::Age => 0.15,
+            ImputedField::BirthDate => 0.1,
+            ImputedField::PersonCharacteristics => 0.05,
+            ImputedField::Context => 0.1,
+        }
+    }
+
+    /// Returns a human-readable name for this input.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            ImputedField::Species => "Species",
+            ImputedField::Personality => "Personality",
+            ImputedField::Age => "Age",
+            ImputedField::BirthDate => "Birth Date",
+            ImputedField::PersonCharacteristics => "Person Characteristics",
+            ImputedField::Context => "Context",
+        }
+    }
+}
+
+impl fmt::Display for ImputedField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Which key inputs of an entity were imputed.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::entity::{EntityBuilder, ImputedField};
+/// use behavioral_pathways::enums::Species;
+///
+/// let (entity, report) = EntityBuilder::new().species(Species::Human).build_partial();
+///
+/// assert!(report.is_imputed(ImputedField::Personality));
+/// assert!(!report.is_imputed(ImputedField::Species));
+/// assert!(report.confidence() < 1.0);
+/// assert_eq!(entity.completeness(), &report);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CompletenessReport {
+    /// Imputed inputs, in `ImputedField` order.
+    imputed: Vec<ImputedField>,
+}
+
+impl CompletenessReport {
+    /// Creates a report for the given imputed inputs.
+    #[must_use]
+    pub(crate) fn new(mut imputed: Vec<ImputedField>) -> Self {
+        imputed.sort();
+        imputed.dedup();
+        CompletenessReport { imputed }
+    }
+
+    /// Returns the imputed inputs.
+    #[must_use]
+    pub fn imputed(&self) -> &[ImputedField] {
+        &self.imputed
+    }
+
+    /// Returns true if `field` was imputed.
+    #[must_use]
+    pub fn is_imputed(&self, field: ImputedField) -> bool {
+        self.imputed.contains(&field)
+    }
+
+    /// Returns true if no input was imputed.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.imputed.is_empty()
+    }
+
+    /// Returns confidence in states computed from these inputs (0.0 to
+    /// 1.0): 1.0 less the weight of each imputed input.
+    #[must_use]
+    pub fn confidence(&self) -> f64 {
+        let lost: f64 = self
+            .imputed
+            .iter()
+            .map(ImputedField::confidence_weight)
+            .sum();
+        (1.0 - lost).max(0.0)
+    }
+}
+
+impl fmt::Display for CompletenessReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_complete() {
+            return write!(f, "complete");
+        }
+        let names: Vec<&str> = self.imputed.iter().map(ImputedField::name).collect();
+        write!(f, "imputed: {}", names.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn complete_report_has_full_confidence() {
+        let report = CompletenessReport::default();
+        assert!(report.is_complete());
+        assert_eq!(report.confidence(), 1.0);
+        assert_eq!(report.to_string(), "complete");
+    }
+
+    #[test]
+    fn report_is_sorted_and_deduplicated() {
+        let report = CompletenessReport::new(vec![
+            ImputedField::Context,
+            ImputedField::Personality,
+            ImputedField::Context,
+        ]);
+        assert_eq!(
+            report.imputed(),
+            &[ImputedField::Personality, ImputedField::Context]
+        );
+        assert_eq!(report.to_string(), "imputed: Personality, Context");
+        assert!((report.confidence() - 0.55).abs() < 1e-12);
+    }
+
+    #[test]
+    fn weights_sum_to_at_most_one() {
+        let all = CompletenessReport::new(vec![
+            ImputedField::Species,
+            ImputedField::Personality,
+            ImputedField::Age,
+            ImputedField::BirthDate,
+            ImputedField::PersonCharacteristics,
+            ImputedField::Context,
+        ]);
+        assert!(all.confidence() >= 0.0);
+        assert!(all.confidence() < 0.1);
+    }
+}
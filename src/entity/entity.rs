@@ -9,20 +9,46 @@
 //! - Relationship slots for connections to other entities
 //! - Memory storage for episodic memories
 
-use crate::context::EcologicalContext;
-use crate::entity::{AffectiveState, PhysiologicalState};
+use crate::context::{shape_context, EcologicalContext};
+use crate::entity::{AffectiveState, CompletenessReport, PhysiologicalState};
 use crate::enums::{
-    ContextPath, DispositionPath, HexacoPath, LifeStage, MentalHealthPath, MoodPath, NeedsPath,
-    PersonCharacteristicsPath, SocialCognitionPath, Species, StatePath,
+    ContextPath, DispositionPath, HexacoPath, InterpretationRole, LifeStage, MentalHealthPath,
+    MoodPath, NeedsPath, PersonCharacteristicsPath, PersonalityProfile, SocialCognitionPath,
+    Species, StatePath,
 };
 use crate::memory::{EmotionalSnapshot, MemoryEntry, MemoryLayer, MemoryLayers, MemoryTag};
-use crate::processor::DecayProcessor;
+use crate::event::Event;
+use crate::processor::{interpret_event, interpret_event_as, DecayProcessor, InterpretedEvent};
 use crate::state::{EntityModelConfig, IndividualState};
-use crate::types::{Alert, Duration, EntityId, MicrosystemId, RelationshipSlot, Timestamp};
+use crate::types::{
+    Alert, Duration, EntityId, MicrosystemId, RelationshipId, RelationshipSlot, Timestamp,
+};
+use std::sync::{Arc, OnceLock};
 
 /// The maximum number of relationship slots an entity can have.
 pub const MAX_RELATIONSHIP_SLOTS: usize = 8;
 
+/// Returns the default ecological context, shared by every entity built
+/// without one until it is modified.
+fn default_context() -> Arc<EcologicalContext> {
+    static DEFAULT: OnceLock<Arc<EcologicalContext>> = OnceLock::new();
+    DEFAULT
+        .get_or_init(|| Arc::new(EcologicalContext::default()))
+        .clone()
+}
+
+/// Returns the default model configuration for `species`, shared by every
+/// human entity until it is modified.
+fn species_config(species: &Species) -> Arc<EntityModelConfig> {
+    static HUMAN: OnceLock<Arc<EntityModelConfig>> = OnceLock::new();
+    match species {
+        Species::Human => HUMAN
+            .get_or_init(|| Arc::new(EntityModelConfig::human_default()))
+            .clone(),
+        _ => Arc::new(EntityModelConfig::for_species(species)),
+    }
+}
+
 /// Core entity representing a living individual with psychological state.
 ///
 /// Entity is the primary container for simulation. It holds identity,
@@ -61,6 +87,7 @@ pub const MAX_RELATIONSHIP_SLOTS: usize = 8;
 ///
 /// [`EntityBuilder`]: crate::entity::EntityBuilder
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Entity {
     /// Unique identifier for this entity.
     id: EntityId,
@@ -84,7 +111,10 @@ pub struct Entity {
     life_stage: LifeStage,
 
     /// Aggregate individual psychological state.
-    individual_state: IndividualState,
+    ///
+    /// Shared with entities spawned from the same template until one of
+    /// them changes it.
+    individual_state: Arc<IndividualState>,
 
     /// Relationship slots for connections to other entities.
     relationship_slots: [RelationshipSlot; MAX_RELATIONSHIP_SLOTS],
@@ -101,8 +131,9 @@ pub struct Entity {
     /// Ecological context (Bronfenbrenner's five layers).
     ///
     /// Contains microsystem instances, exosystem, macrosystem, and chronosystem
-    /// context values that influence individual development.
-    context: EcologicalContext,
+    /// context values that influence individual development. Shared like
+    /// `individual_state`.
+    context: Arc<EcologicalContext>,
 
     /// Pending alerts generated by threshold checks and spiral detection.
     ///
@@ -111,7 +142,11 @@ pub struct Entity {
     pending_alerts: Vec<Alert>,
 
     /// Configuration for this entity model (subsystem activation, thresholds, etc.).
-    config: EntityModelConfig,
+    /// Shared like `individual_state`.
+    config: Arc<EntityModelConfig>,
+
+    /// Key inputs imputed by `EntityBuilder::build_partial` (empty otherwise).
+    completeness: CompletenessReport,
 }
 
 impl Entity {
@@ -126,19 +161,20 @@ impl Entity {
         life_stage: LifeStage,
         individual_state: IndividualState,
     ) -> Self {
-        let config = EntityModelConfig::for_species(&species);
+        let config = species_config(&species);
         Entity {
             id,
             species,
             age,
             birth_date,
             life_stage,
-            individual_state,
+            individual_state: Arc::new(individual_state),
             relationship_slots: Default::default(),
             memories: MemoryLayers::new(),
-            context: EcologicalContext::default(),
+            context: default_context(),
             pending_alerts: Vec::new(),
             config,
+            completeness: CompletenessReport::default(),
         }
     }
 
@@ -154,19 +190,38 @@ impl Entity {
         individual_state: IndividualState,
         context: EcologicalContext,
     ) -> Self {
-        let config = EntityModelConfig::for_species(&species);
+        let config = species_config(&species);
         Entity {
             id,
             species,
             age,
             birth_date,
             life_stage,
-            individual_state,
+            individual_state: Arc::new(individual_state),
             relationship_slots: Default::default(),
             memories: MemoryLayers::new(),
-            context,
+            context: Arc::new(context),
             pending_alerts: Vec::new(),
             config,
+            completeness: CompletenessReport::default(),
+        }
+    }
+
+    /// Returns a copy of this entity under a different identifier.
+    pub(crate) fn clone_with_id(&self, id: EntityId) -> Self {
+        Entity {
+            id,
+            species: self.species.clone(),
+            age: self.age,
+            birth_date: self.birth_date,
+            life_stage: self.life_stage,
+            individual_state: self.individual_state.clone(),
+            relationship_slots: self.relationship_slots.clone(),
+            memories: self.memories.clone(),
+            context: self.context.clone(),
+            pending_alerts: self.pending_alerts.clone(),
+            config: self.config.clone(),
+            completeness: self.completeness.clone(),
         }
     }
 
@@ -237,6 +292,18 @@ impl Entity {
         self.life_stage
     }
 
+    /// Returns which key inputs were imputed when the entity was built.
+    ///
+    /// Empty unless the entity came from `EntityBuilder::build_partial`.
+    #[must_use]
+    pub fn completeness(&self) -> &CompletenessReport {
+        &self.completeness
+    }
+
+    pub(crate) fn set_completeness(&mut self, completeness: CompletenessReport) {
+        self.completeness = completeness;
+    }
+
     // --- State Access ---
 
     /// Returns a reference to the entity's individual state.
@@ -245,9 +312,34 @@ impl Entity {
         &self.individual_state
     }
 
+    /// Returns the preset profile closest to the entity's current HEXACO
+    /// values.
+    ///
+    /// An entity built from a profile with no trait overrides returns that
+    /// profile. See `Hexaco::closest_profile`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::enums::{PersonalityProfile, Species};
+    ///
+    /// let entity = EntityBuilder::new()
+    ///     .species(Species::Human)
+    ///     .personality_profile(PersonalityProfile::Introverted)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(entity.personality_profile(), PersonalityProfile::Introverted);
+    /// ```
+    #[must_use]
+    pub fn personality_profile(&self) -> PersonalityProfile {
+        self.individual_state.hexaco().closest_profile()
+    }
+
     /// Returns a mutable reference to the entity's individual state.
     pub fn individual_state_mut(&mut self) -> &mut IndividualState {
-        &mut self.individual_state
+        Arc::make_mut(&mut self.individual_state)
     }
 
     /// Gets the effective value (base + delta) for a state path.
@@ -377,12 +469,7 @@ impl Entity {
     /// are NOT part of affect - use `query_physiological_state()` for those.
     #[must_use]
     pub fn query_affective_state(&self) -> AffectiveState {
-        let state = self.individual_state();
-        AffectiveState {
-            valence: state.mood().valence_effective(),
-            arousal: state.mood().arousal_effective(),
-            dominance: state.mood().dominance_effective(),
-        }
+        AffectiveState::from_individual(self.individual_state())
     }
 
     /// Returns the baseline PAD affective state (no deltas).
@@ -405,11 +492,68 @@ impl Entity {
     /// These track bodily needs rather than emotional experience.
     #[must_use]
     pub fn query_physiological_state(&self) -> PhysiologicalState {
-        let state = self.individual_state();
-        PhysiologicalState {
-            fatigue: state.needs().fatigue_effective(),
-            stress: state.needs().stress_effective(),
-        }
+        PhysiologicalState::from_individual(self.individual_state())
+    }
+
+    /// Previews how this entity would interpret an event, without applying it.
+    ///
+    /// The returned deltas are the immediate, personality-modulated effect
+    /// before developmental scaling and decay. To see the resulting state
+    /// at a timestamp, use `EntityQueryHandle::preview_event()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::enums::{EventType, Species};
+    /// use behavioral_pathways::event::EventBuilder;
+    ///
+    /// let entity = EntityBuilder::new().species(Species::Human).build().unwrap();
+    /// let exclusion = EventBuilder::new(EventType::SocialExclusion)
+    ///     .severity(0.7)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let interpreted = entity.interpret_event(&exclusion);
+    /// assert!(interpreted.valence_delta < 0.0);
+    /// assert!(interpreted.loneliness_delta > 0.0);
+    /// ```
+    #[must_use]
+    pub fn interpret_event(&self, event: &Event) -> InterpretedEvent {
+        interpret_event(event, self)
+    }
+
+    /// Interprets an event as this entity would in the given role, without
+    /// applying it.
+    ///
+    /// `interpret_event` takes the role from the event: the entity is a
+    /// witness if the event lists it as one, and the target otherwise. A
+    /// witness or someone who heard about the event secondhand feels a
+    /// share of the impact that grows with empathy, and attributes the
+    /// event to someone else.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::enums::{EventType, InterpretationRole, Species};
+    /// use behavioral_pathways::event::EventBuilder;
+    ///
+    /// let entity = EntityBuilder::new().species(Species::Human).build().unwrap();
+    /// let violence = EventBuilder::new(EventType::Violence)
+    ///     .severity(0.8)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let direct = entity.interpret_event_as(&violence, InterpretationRole::Target);
+    /// let witnessed = entity.interpret_event_as(&violence, InterpretationRole::Witness);
+    /// assert!(witnessed.valence_delta < 0.0);
+    /// assert!(witnessed.valence_delta > direct.valence_delta);
+    /// assert!(!witnessed.attribution.is_self_caused());
+    /// ```
+    #[must_use]
+    pub fn interpret_event_as(&self, event: &Event, role: InterpretationRole) -> InterpretedEvent {
+        interpret_event_as(event, self, role)
     }
 
     /// Returns the baseline physiological state (no deltas).
@@ -475,12 +619,12 @@ impl Entity {
 
             // Apply microsystem effects to person state
             if stress_adj.abs() > f32::EPSILON {
-                self.individual_state
+                self.individual_state_mut()
                     .needs_mut()
                     .add_stress_delta(stress_adj);
             }
             if loneliness_adj.abs() > f32::EPSILON {
-                self.individual_state
+                self.individual_state_mut()
                     .social_cognition_mut()
                     .add_loneliness_delta(loneliness_adj);
             }
@@ -492,7 +636,7 @@ impl Entity {
             // Scale: 0.5 support = neutral, 1.0 = max buffer (-0.05), 0.0 = no buffer
             let exo_stress_buffer = ((institutional_support - 0.5) * 0.1) as f32;
             if exo_stress_buffer.abs() > f32::EPSILON {
-                self.individual_state
+                self.individual_state_mut()
                     .needs_mut()
                     .add_stress_delta(-exo_stress_buffer);
             }
@@ -505,7 +649,7 @@ impl Entity {
             // High belonging weight (collectivist) = reduces loneliness
             let macro_loneliness_adj = ((1.0 - belonging_weight) * 0.05) as f32;
             if macro_loneliness_adj.abs() > f32::EPSILON {
-                self.individual_state
+                self.individual_state_mut()
                     .social_cognition_mut()
                     .add_loneliness_delta(macro_loneliness_adj);
             }
@@ -514,7 +658,7 @@ impl Entity {
             let cultural_stress = self.context.macrosystem().cultural_stress;
             let macro_stress_adj = (cultural_stress * 0.05) as f32;
             if macro_stress_adj.abs() > f32::EPSILON {
-                self.individual_state
+                self.individual_state_mut()
                     .needs_mut()
                     .add_stress_delta(macro_stress_adj);
             }
@@ -524,7 +668,7 @@ impl Entity {
             let off_time_stress = self.context.chronosystem().total_off_time_stress();
             let chrono_stress_adj = (off_time_stress * 0.1) as f32;
             if chrono_stress_adj.abs() > f32::EPSILON {
-                self.individual_state
+                self.individual_state_mut()
                     .needs_mut()
                     .add_stress_delta(chrono_stress_adj);
             }
@@ -537,37 +681,27 @@ impl Entity {
                 .stability_level;
             let instability_stress = ((1.0 - stability) * 0.05) as f32;
             if instability_stress.abs() > f32::EPSILON {
-                self.individual_state
+                self.individual_state_mut()
                     .needs_mut()
                     .add_stress_delta(instability_stress);
             }
         }
 
-        // 2. PERSON-TO-CONTEXT: Modify context based on updated state SECOND
-        let hexaco = self.individual_state.hexaco();
-        let extraversion = hexaco.extraversion();
-        let conscientiousness = hexaco.conscientiousness();
-        let agreeableness = hexaco.agreeableness();
-        let neuroticism = hexaco.neuroticism();
-        let grievance = self.individual_state.disposition().grievance_effective();
-        self.context.apply_person_to_context_shaping(
-            extraversion,
-            conscientiousness,
-            agreeableness,
-            neuroticism,
-            grievance,
-        );
+        // 2. PERSON-TO-CONTEXT: Modify context based on updated state SECOND,
+        // by the share of the fully developed shaping `duration` delivers
+        let shaped = shape_context(self.context(), &self.individual_state, duration);
+        *self.context_mut() = shaped;
 
         // === STANDARD PROCESSING ===
 
         // Apply decay via processor
-        processor.apply_decay(&mut self.individual_state, duration, time_scale);
+        processor.apply_decay(self.individual_state_mut(), duration, time_scale);
 
         // Advance age
         self.age = self.age + duration;
 
         // Invalidate mesosystem cache so values are recomputed next access
-        self.context.invalidate_mesosystem_cache();
+        self.context_mut().invalidate_mesosystem_cache();
     }
 
     /// Computes aggregate interaction frequency and complexity across all microsystems.
@@ -604,13 +738,42 @@ impl Entity {
     /// Returns a mutable reference to the relationship slots.
     ///
     /// This is crate-internal for Phase 5 relationship management.
-    #[allow(dead_code)]
     pub(crate) fn relationship_slots_mut(
         &mut self,
     ) -> &mut [RelationshipSlot; MAX_RELATIONSHIP_SLOTS] {
         &mut self.relationship_slots
     }
 
+    /// Attaches a relationship to the first empty slot.
+    ///
+    /// Returns false if every slot is taken. A relationship already
+    /// attached is left where it is.
+    pub(crate) fn attach_relationship(&mut self, id: &RelationshipId) -> bool {
+        let slots = self.relationship_slots_mut();
+        if slots
+            .iter()
+            .any(|slot| slot.get_attached().as_ref() == Some(id))
+        {
+            return true;
+        }
+        match slots.iter_mut().find(|slot| slot.is_empty()) {
+            Some(slot) => {
+                slot.attach(id.clone());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Detaches a relationship from whichever slot holds it.
+    pub(crate) fn detach_relationship(&mut self, id: &RelationshipId) {
+        for slot in self.relationship_slots_mut() {
+            if slot.get_attached().as_ref() == Some(id) {
+                slot.detach();
+            }
+        }
+    }
+
     // --- Context ---
 
     /// Returns a reference to the entity's ecological context.
@@ -661,7 +824,7 @@ impl Entity {
     /// assert_eq!(entity.context().microsystem_count(), 1);
     /// ```
     pub fn context_mut(&mut self) -> &mut EcologicalContext {
-        &mut self.context
+        Arc::make_mut(&mut self.context)
     }
 
     // --- Configuration ---
@@ -710,7 +873,7 @@ impl Entity {
     /// assert!(!entity.config().mental_health_enabled());
     /// ```
     pub fn config_mut(&mut self) -> &mut EntityModelConfig {
-        &mut self.config
+        Arc::make_mut(&mut self.config)
     }
 
     /// Gets a context value by path.
@@ -781,7 +944,7 @@ impl Entity {
     /// assert!((stress - 0.8).abs() < 0.01);
     /// ```
     pub fn set_context(&mut self, path: &ContextPath, value: f64) -> bool {
-        self.context.set(path, value)
+        self.context_mut().set(path, value)
     }
 
     // --- Memory ---
@@ -977,6 +1140,7 @@ impl Entity {
             entry = entry.with_microsystem_context(context);
         }
 
+        entry.set_layer(layer);
         let result = entry.clone();
         self.memories.add(layer, entry);
         result
@@ -990,7 +1154,7 @@ impl Entity {
     /// - 0.5-0.7: Long-term (significant events)
     /// - 0.7-1.0: Legacy (major/defining events)
     #[must_use]
-    fn layer_for_salience(salience: f32) -> MemoryLayer {
+    pub(crate) fn layer_for_salience(salience: f32) -> MemoryLayer {
         if salience < 0.3 {
             MemoryLayer::Immediate
         } else if salience < 0.5 {
@@ -1139,6 +1303,8 @@ impl Entity {
             NeedsPath::Fatigue => n.fatigue_effective(),
             NeedsPath::Stress => n.stress_effective(),
             NeedsPath::Purpose => n.purpose_effective(),
+            NeedsPath::Satiation => n.satiation_effective(),
+            NeedsPath::Safety => n.safety_effective(),
         }
     }
 
@@ -1148,6 +1314,8 @@ impl Entity {
             NeedsPath::Fatigue => n.fatigue_base(),
             NeedsPath::Stress => n.stress_base(),
             NeedsPath::Purpose => n.purpose_base(),
+            NeedsPath::Satiation => n.satiation_base(),
+            NeedsPath::Safety => n.safety_base(),
         }
     }
 
@@ -1157,6 +1325,8 @@ impl Entity {
             NeedsPath::Fatigue => n.fatigue().delta(),
             NeedsPath::Stress => n.stress().delta(),
             NeedsPath::Purpose => n.purpose().delta(),
+            NeedsPath::Satiation => n.satiation().delta(),
+            NeedsPath::Safety => n.safety().delta(),
         }
     }
 
@@ -2026,6 +2196,8 @@ mod tests {
             NeedsPath::Fatigue,
             NeedsPath::Stress,
             NeedsPath::Purpose,
+            NeedsPath::Satiation,
+            NeedsPath::Safety,
         ];
 
         for path in paths {
@@ -2383,6 +2555,37 @@ mod tests {
         assert_eq!(entity.relationship_slots()[0].get_attached(), Some(id));
     }
 
+    #[test]
+    fn attach_relationship_fills_first_empty_slot() {
+        let mut entity = EntityBuilder::new()
+            .species(Species::Human)
+            .build()
+            .unwrap();
+        let first = RelationshipId::new("rel_first").unwrap();
+        let second = RelationshipId::new("rel_second").unwrap();
+
+        assert!(entity.attach_relationship(&first));
+        assert!(entity.attach_relationship(&first));
+        assert!(entity.attach_relationship(&second));
+        assert_eq!(
+            entity.relationship_slots()[0].get_attached(),
+            Some(first.clone())
+        );
+        assert_eq!(
+            entity.relationship_slots()[1].get_attached(),
+            Some(second.clone())
+        );
+        assert!(entity.relationship_slots()[2].is_empty());
+
+        entity.detach_relationship(&first);
+        assert!(entity.relationship_slots()[0].is_empty());
+        for index in 0..MAX_RELATIONSHIP_SLOTS - 1 {
+            let id = RelationshipId::new(format!("rel_{index}")).unwrap();
+            assert!(entity.attach_relationship(&id));
+        }
+        assert!(!entity.attach_relationship(&first));
+    }
+
     // --- Memory Tests ---
 
     #[test]
@@ -4,15 +4,15 @@
 //! with proper validation. Species is required; other fields have defaults.
 
 use crate::context::EcologicalContext;
-use crate::enums::{LifeStage, PersonalityProfile, Species};
+use crate::enums::{HexacoPath, LifeStage, PersonalityProfile, Species};
 use crate::state::{
-    Disposition, Hexaco, IndividualState, MentalHealth, Mood, Needs, PersonCharacteristics,
-    SocialCognition,
+    DecayProfile, Disposition, Hexaco, IndividualState, LifeStageTable, MentalHealth, Mood, Needs,
+    PersonCharacteristics, SocialCognition,
 };
 // Note: Mood::from_personality is used below to derive baseline affect from HEXACO
-use crate::types::{Duration, EntityId, Timestamp};
+use crate::types::{BoundedValueError, Duration, EntityId, IntoBounded, SignedUnit, Timestamp};
 
-use super::Entity;
+use super::{CompletenessReport, Entity, ImputedField};
 
 /// Error type for entity build failures.
 ///
@@ -27,13 +27,21 @@ use super::Entity;
 /// let result = EntityBuilder::new().build();
 /// assert!(matches!(result, Err(EntityBuildError::MissingSpecies)));
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum EntityBuildError {
     /// Species is required but was not set.
     MissingSpecies,
 
     /// The entity ID is invalid (empty string).
     InvalidId(String),
+
+    /// A numeric input is outside its scale or not finite.
+    OutOfRange {
+        /// The input that was rejected.
+        field: String,
+        /// The rejected value and its scale.
+        error: BoundedValueError,
+    },
 }
 
 impl std::fmt::Display for EntityBuildError {
@@ -41,6 +49,7 @@ impl std::fmt::Display for EntityBuildError {
         match self {
             EntityBuildError::MissingSpecies => write!(f, "Species is required but was not set"),
             EntityBuildError::InvalidId(reason) => write!(f, "Invalid entity ID: {}", reason),
+            EntityBuildError::OutOfRange { field, error } => write!(f, "{}: {}", field, error),
         }
     }
 }
@@ -63,6 +72,12 @@ impl std::error::Error for EntityBuildError {}
 /// - `life_stage` - Derived from age and species if not set
 /// - `personality` - Neutral HEXACO (Balanced profile) if not set
 /// - `person_characteristics` - Neutral (0.5, 0.5, 0.5) if not set
+/// - `disposition` - Healthy defaults if not set
+/// - `decay_profile` - Default half-lives for every dimension if not set
+/// - `life_stage_table` - Species-scaled stage boundaries if not set
+///
+/// A `personality_profile()` preset fills the personality, person
+/// characteristics, and disposition that are not set explicitly.
 ///
 /// # Examples
 ///
@@ -95,7 +110,9 @@ pub struct EntityBuilder {
     birth_date: Option<Timestamp>,
     life_stage: Option<LifeStage>,
     personality: Option<PersonalityProfile>,
+    preset: Option<PersonalityProfile>,
     hexaco: Option<Hexaco>,
+    trait_values: Vec<(HexacoPath, Result<SignedUnit, BoundedValueError>)>,
     person_characteristics: Option<PersonCharacteristics>,
     mood: Option<Mood>,
     needs: Option<Needs>,
@@ -103,6 +120,8 @@ pub struct EntityBuilder {
     social_cognition: Option<SocialCognition>,
     disposition: Option<Disposition>,
     context: Option<EcologicalContext>,
+    decay_profile: Option<DecayProfile>,
+    life_stage_table: Option<LifeStageTable>,
 }
 
 impl EntityBuilder {
@@ -273,6 +292,40 @@ impl EntityBuilder {
         self
     }
 
+    /// Seeds the entity from a preset profile.
+    ///
+    /// Unlike `personality()`, which sets HEXACO only, the preset also
+    /// supplies the profile's disposition baselines (empathy, aggression,
+    /// trust propensity) and person characteristics
+    /// (`PersonCharacteristics::from_profile`). It is applied first:
+    /// `personality()`, `hexaco()`, `trait_value()`, `disposition()`, and
+    /// `person_characteristics()` override it whether they are called
+    /// before or after.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::enums::{HexacoPath, PersonalityProfile, Species};
+    ///
+    /// let entity = EntityBuilder::new()
+    ///     .species(Species::Human)
+    ///     .personality_profile(PersonalityProfile::Agreeable)
+    ///     .trait_value(HexacoPath::Extraversion, -0.4)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let state = entity.individual_state();
+    /// assert!((state.hexaco().agreeableness() - 0.6).abs() < 1e-6);
+    /// assert!((state.hexaco().extraversion() + 0.4).abs() < 1e-6);
+    /// assert!(state.disposition().trust_propensity_effective() > 0.6);
+    /// ```
+    #[must_use]
+    pub fn personality_profile(mut self, profile: PersonalityProfile) -> Self {
+        self.preset = Some(profile);
+        self
+    }
+
     /// Sets HEXACO personality values directly.
     ///
     /// This allows fine-grained control over personality dimensions.
@@ -302,6 +355,37 @@ impl EntityBuilder {
         self
     }
 
+    /// Sets a single HEXACO trait.
+    ///
+    /// Accepts a raw `f32`/`f64`, validated when `build()` is called, or a
+    /// [`SignedUnit`]. Trait values are applied on top of the profile or
+    /// explicit HEXACO, whichever is set, in the order they were given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::entity::{EntityBuildError, EntityBuilder};
+    /// use behavioral_pathways::enums::{HexacoPath, Species};
+    ///
+    /// let entity = EntityBuilder::new()
+    ///     .species(Species::Human)
+    ///     .trait_value(HexacoPath::Extraversion, 0.6)
+    ///     .build()
+    ///     .unwrap();
+    /// assert!((entity.individual_state().hexaco().extraversion() - 0.6).abs() < 1e-6);
+    ///
+    /// let result = EntityBuilder::new()
+    ///     .species(Species::Human)
+    ///     .trait_value(HexacoPath::Extraversion, 1.6)
+    ///     .build();
+    /// assert!(matches!(result, Err(EntityBuildError::OutOfRange { .. })));
+    /// ```
+    #[must_use]
+    pub fn trait_value(mut self, path: HexacoPath, value: impl IntoBounded<SignedUnit>) -> Self {
+        self.trait_values.push((path, value.into_bounded()));
+        self
+    }
+
     /// Sets person characteristics (PPCT model).
     ///
     /// Person characteristics include demand, resource, and force factors
@@ -499,12 +583,152 @@ impl EntityBuilder {
         self
     }
 
+    /// Overrides decay half-lives for this entity.
+    ///
+    /// The profile is stored in the entity's `EntityModelConfig` and in its
+    /// state's, and both forward decay and backward regression use it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::enums::{MoodPath, Species, StatePath};
+    /// use behavioral_pathways::state::DecayProfile;
+    /// use behavioral_pathways::types::Duration;
+    ///
+    /// let valence = StatePath::Mood(MoodPath::Valence);
+    /// let entity = EntityBuilder::new()
+    ///     .species(Species::Dog)
+    ///     .decay_profile(DecayProfile::new().with_half_life(valence, Duration::hours(1)))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let profile = entity.config().decay_profile().unwrap();
+    /// assert_eq!(profile.half_life(valence), Some(Duration::hours(1)));
+    /// ```
+    #[must_use]
+    pub fn decay_profile(mut self, profile: DecayProfile) -> Self {
+        self.decay_profile = Some(profile);
+        self
+    }
+
+    /// Overrides the species' life stage boundaries.
+    ///
+    /// The table decides the derived life stage, the life stage reported
+    /// by state queries, and the age formative plasticity reads.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::enums::{LifeStage, Species};
+    /// use behavioral_pathways::state::LifeStageTable;
+    /// use behavioral_pathways::types::Duration;
+    ///
+    /// let entity = EntityBuilder::new()
+    ///     .species(Species::Dog)
+    ///     .age(Duration::years(2))
+    ///     .life_stage_table(
+    ///         LifeStageTable::for_species(&Species::Dog)
+    ///             .with_stage_entry(LifeStage::YoungAdult, 3.0),
+    ///     )
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(entity.life_stage(), LifeStage::Adolescent);
+    /// ```
+    #[must_use]
+    pub fn life_stage_table(mut self, table: LifeStageTable) -> Self {
+        self.life_stage_table = Some(table);
+        self
+    }
+
+    /// Builds the entity, filling missing key inputs with population
+    /// defaults instead of failing.
+    ///
+    /// Each input filled this way is listed in the returned report, which is
+    /// also stored on the entity (see `Entity::completeness`) and lowers
+    /// `ComputedState::confidence`. The defaults are documented in the
+    /// `ImputedField` variants: a human species, a balanced personality,
+    /// half the species lifespan as age, neutral person characteristics,
+    /// and a default context. A missing birth date cannot be imputed and is
+    /// reported so the fixed age is visible.
+    ///
+    /// An invalid ID is replaced with a generated one, and out-of-range
+    /// trait values are dropped as missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::entity::{EntityBuilder, ImputedField};
+    /// use behavioral_pathways::enums::PersonalityProfile;
+    ///
+    /// let (entity, report) = EntityBuilder::new()
+    ///     .id("registry_0042")
+    ///     .personality(PersonalityProfile::Anxious)
+    ///     .build_partial();
+    ///
+    /// assert!(report.is_imputed(ImputedField::Species));
+    /// assert!(report.is_imputed(ImputedField::Age));
+    /// assert!(!report.is_imputed(ImputedField::Personality));
+    /// assert_eq!(entity.age().as_years(), 40);
+    /// ```
+    #[must_use]
+    pub fn build_partial(mut self) -> (Entity, CompletenessReport) {
+        let mut imputed = Vec::new();
+        if self
+            .id
+            .as_ref()
+            .is_some_and(|id| EntityId::new(id.as_str()).is_err())
+        {
+            self.id = None;
+        }
+        let species = self.species.get_or_insert_with(|| {
+            imputed.push(ImputedField::Species);
+            Species::Human
+        });
+        if self.age.is_none() && self.birth_date.is_none() {
+            self.age = Some(Duration::years(u64::from(species.lifespan_years() / 2)));
+            imputed.push(ImputedField::Age);
+        }
+        if self.birth_date.is_none() {
+            imputed.push(ImputedField::BirthDate);
+        }
+        self.trait_values.retain(|(_, value)| value.is_ok());
+        if self.hexaco.is_none()
+            && self.personality.is_none()
+            && self.preset.is_none()
+            && self.trait_values.is_empty()
+        {
+            imputed.push(ImputedField::Personality);
+        }
+        if self.person_characteristics.is_none() && self.preset.is_none() {
+            imputed.push(ImputedField::PersonCharacteristics);
+        }
+        if self.context.is_none() {
+            imputed.push(ImputedField::Context);
+        }
+
+        let report = CompletenessReport::new(imputed);
+        let mut entity = self
+            .build()
+            .expect("every input that can fail is filled or dropped");
+        entity.set_completeness(report.clone());
+        (entity, report)
+    }
+
     /// Builds the entity.
     ///
+    /// Unset optional inputs take their defaults, which are treated as
+    /// deliberate: the entity's completeness report is empty. Use
+    /// `build_partial` to record them as imputed.
+    ///
     /// # Errors
     ///
     /// Returns `EntityBuildError::MissingSpecies` if species was not set.
     /// Returns `EntityBuildError::InvalidId` if the ID is empty.
+    /// Returns `EntityBuildError::OutOfRange` if a trait value is outside
+    /// -1.0 to 1.0 or not finite.
     ///
     /// # Examples
     ///
@@ -542,19 +766,34 @@ impl EntityBuilder {
         // Determine life stage: explicit > derived from age
         let life_stage = self
             .life_stage
-            .unwrap_or_else(|| LifeStage::from_age_years_for_species(&species, age.as_years_f64()));
+            .unwrap_or_else(|| match &self.life_stage_table {
+                Some(table) => table.stage_at(age.as_years_f64()),
+                None => LifeStage::from_age_years_for_species(&species, age.as_years_f64()),
+            });
 
-        // Build HEXACO: explicit hexaco > profile > default
-        let hexaco = if let Some(h) = self.hexaco {
+        // Build HEXACO: explicit hexaco > profile > preset > default
+        let mut hexaco = if let Some(h) = self.hexaco {
             h
-        } else if let Some(profile) = self.personality {
+        } else if let Some(profile) = self.personality.or(self.preset) {
             Hexaco::from_profile(profile)
         } else {
             Hexaco::from_profile(PersonalityProfile::Balanced)
         };
 
-        // Build person characteristics
-        let person_characteristics = self.person_characteristics.unwrap_or_default();
+        // Individual trait values override the chosen HEXACO
+        for (path, value) in self.trait_values {
+            let value = value.map_err(|error| EntityBuildError::OutOfRange {
+                field: path.name().to_string(),
+                error,
+            })?;
+            set_trait(&mut hexaco, path, value);
+        }
+
+        // Build person characteristics: explicit > preset > default
+        let person_characteristics = self
+            .person_characteristics
+            .or_else(|| self.preset.map(PersonCharacteristics::from_profile))
+            .unwrap_or_default();
 
         // Build individual state with required components
         let mut individual_state = IndividualState::new()
@@ -577,13 +816,31 @@ impl EntityBuilder {
         if let Some(social_cognition) = self.social_cognition {
             individual_state = individual_state.with_social_cognition(social_cognition);
         }
-        if let Some(disposition) = self.disposition {
+        if let Some(disposition) = self
+            .disposition
+            .or_else(|| self.preset.map(Disposition::from_profile))
+        {
             individual_state = individual_state.with_disposition(disposition);
         }
 
+        if let Some(profile) = &self.decay_profile {
+            let config = individual_state
+                .config()
+                .clone()
+                .with_decay_profile(profile.clone());
+            individual_state = individual_state.with_config(config);
+        }
+        if let Some(table) = &self.life_stage_table {
+            let config = individual_state
+                .config()
+                .clone()
+                .with_life_stage_table(table.clone());
+            individual_state = individual_state.with_config(config);
+        }
+
         // Build entity with or without custom context
-        if let Some(context) = self.context {
-            Ok(Entity::new_with_context(
+        let mut entity = if let Some(context) = self.context {
+            Entity::new_with_context(
                 id,
                 species,
                 age,
@@ -591,17 +848,37 @@ impl EntityBuilder {
                 life_stage,
                 individual_state,
                 context,
-            ))
+            )
         } else {
-            Ok(Entity::new(
+            Entity::new(
                 id,
                 species,
                 age,
                 birth_date,
                 life_stage,
                 individual_state,
-            ))
+            )
+        };
+        if let Some(profile) = self.decay_profile {
+            entity.config_mut().set_decay_profile(Some(profile));
+        }
+        if let Some(table) = self.life_stage_table {
+            entity.config_mut().set_life_stage_table(Some(table));
         }
+        Ok(entity)
+    }
+}
+
+/// Sets the HEXACO trait at `path`.
+fn set_trait(hexaco: &mut Hexaco, path: HexacoPath, value: SignedUnit) {
+    let value = f32::from(value);
+    match path {
+        HexacoPath::Openness => hexaco.set_openness(value),
+        HexacoPath::Conscientiousness => hexaco.set_conscientiousness(value),
+        HexacoPath::Extraversion => hexaco.set_extraversion(value),
+        HexacoPath::Agreeableness => hexaco.set_agreeableness(value),
+        HexacoPath::Neuroticism => hexaco.set_neuroticism(value),
+        HexacoPath::HonestyHumility => hexaco.set_honesty_humility(value),
     }
 }
 
@@ -807,6 +1084,90 @@ mod tests {
         assert!(entity.individual_state().hexaco().extraversion() > 0.5);
     }
 
+    #[test]
+    fn personality_profile_seeds_hexaco_disposition_and_characteristics() {
+        let entity = EntityBuilder::new()
+            .species(Species::Human)
+            .personality_profile(PersonalityProfile::Rebel)
+            .build()
+            .unwrap();
+
+        let state = entity.individual_state();
+        assert_eq!(
+            state.hexaco(),
+            &Hexaco::from_profile(PersonalityProfile::Rebel)
+        );
+        assert_eq!(
+            state.disposition(),
+            &Disposition::from_profile(PersonalityProfile::Rebel)
+        );
+        assert_eq!(
+            state.person_characteristics(),
+            &PersonCharacteristics::from_profile(PersonalityProfile::Rebel)
+        );
+        assert_eq!(entity.personality_profile(), PersonalityProfile::Rebel);
+    }
+
+    #[test]
+    fn overrides_win_over_personality_profile_in_either_order() {
+        let disposition = Disposition::new().with_aggression_base(0.9);
+        let before = EntityBuilder::new()
+            .species(Species::Human)
+            .trait_value(HexacoPath::Agreeableness, 0.9)
+            .disposition(disposition.clone())
+            .personality_profile(PersonalityProfile::Rebel)
+            .build()
+            .unwrap();
+        let after = EntityBuilder::new()
+            .species(Species::Human)
+            .personality_profile(PersonalityProfile::Rebel)
+            .trait_value(HexacoPath::Agreeableness, 0.9)
+            .disposition(disposition.clone())
+            .build()
+            .unwrap();
+
+        for entity in [&before, &after] {
+            let state = entity.individual_state();
+            assert!((state.hexaco().agreeableness() - 0.9).abs() < f32::EPSILON);
+            // Untouched traits keep the profile's values
+            let rebel = Hexaco::from_profile(PersonalityProfile::Rebel);
+            assert!((state.hexaco().honesty_humility() - rebel.honesty_humility()).abs() < 1e-6);
+            assert_eq!(state.disposition(), &disposition);
+            assert_eq!(
+                state.person_characteristics(),
+                &PersonCharacteristics::from_profile(PersonalityProfile::Rebel)
+            );
+        }
+        assert_eq!(before.individual_state(), after.individual_state());
+    }
+
+    #[test]
+    fn hexaco_overrides_personality_profile() {
+        let entity = EntityBuilder::new()
+            .species(Species::Human)
+            .hexaco(Hexaco::uniform(0.3))
+            .personality_profile(PersonalityProfile::Leader)
+            .build()
+            .unwrap();
+
+        let state = entity.individual_state();
+        assert_eq!(state.hexaco(), &Hexaco::uniform(0.3));
+        assert_eq!(
+            state.disposition(),
+            &Disposition::from_profile(PersonalityProfile::Leader)
+        );
+    }
+
+    #[test]
+    fn personality_profile_is_not_imputed() {
+        let (_, report) = EntityBuilder::new()
+            .personality_profile(PersonalityProfile::Anxious)
+            .build_partial();
+
+        assert!(!report.is_imputed(ImputedField::Personality));
+        assert!(!report.is_imputed(ImputedField::PersonCharacteristics));
+    }
+
     #[test]
     fn builder_clone() {
         let builder = EntityBuilder::new()
@@ -850,6 +1211,54 @@ mod tests {
         assert!(display2.contains("test reason"));
     }
 
+    #[test]
+    fn trait_value_applies_over_the_profile() {
+        let entity = EntityBuilder::new()
+            .species(Species::Human)
+            .personality(PersonalityProfile::Leader)
+            .trait_value(HexacoPath::Neuroticism, 0.5_f32)
+            .trait_value(HexacoPath::Openness, SignedUnit::new(-0.25).unwrap())
+            .build()
+            .unwrap();
+        let hexaco = entity.individual_state().hexaco();
+        let leader = Hexaco::from_profile(PersonalityProfile::Leader);
+
+        assert!((hexaco.neuroticism() - 0.5).abs() < f32::EPSILON);
+        assert!((hexaco.openness() - (-0.25)).abs() < f32::EPSILON);
+        assert!((hexaco.extraversion() - leader.extraversion()).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn trait_value_out_of_range_fails_build() {
+        let result = EntityBuilder::new()
+            .species(Species::Human)
+            .trait_value(HexacoPath::Agreeableness, 1.5)
+            .build();
+
+        assert_eq!(
+            result.unwrap_err(),
+            EntityBuildError::OutOfRange {
+                field: "Agreeableness".to_string(),
+                error: SignedUnit::new(1.5).unwrap_err(),
+            }
+        );
+
+        let nan = EntityBuilder::new()
+            .species(Species::Human)
+            .trait_value(HexacoPath::Openness, f64::NAN)
+            .build();
+        assert!(matches!(nan, Err(EntityBuildError::OutOfRange { .. })));
+    }
+
+    #[test]
+    fn out_of_range_error_display() {
+        let err = EntityBuildError::OutOfRange {
+            field: "Openness".to_string(),
+            error: SignedUnit::new(2.0).unwrap_err(),
+        };
+        assert_eq!(err.to_string(), "Openness: Value 2 is outside [-1, 1]");
+    }
+
     #[test]
     fn error_debug() {
         let err = EntityBuildError::MissingSpecies;
@@ -1159,4 +1568,41 @@ mod tests {
         assert_eq!(entity.individual_state().social_cognition(), &sc);
         assert_eq!(entity.individual_state().disposition(), &disp);
     }
+
+    #[test]
+    fn build_partial_imputes_missing_inputs() {
+        let (entity, report) = EntityBuilder::new().id("").build_partial();
+
+        assert_eq!(entity.species(), &Species::Human);
+        assert_eq!(entity.age(), Duration::years(40));
+        assert!(report.is_imputed(ImputedField::Species));
+        assert!(report.is_imputed(ImputedField::Age));
+        assert!(report.is_imputed(ImputedField::Personality));
+        assert_eq!(report.imputed().len(), 6);
+        assert_eq!(entity.completeness(), &report);
+    }
+
+    #[test]
+    fn build_partial_drops_invalid_trait_values() {
+        let (entity, report) = EntityBuilder::new()
+            .species(Species::Human)
+            .trait_value(HexacoPath::Extraversion, 3.0)
+            .birth_date(Timestamp::from_ymd_hms(1990, 1, 1, 0, 0, 0))
+            .build_partial();
+
+        assert!(report.is_imputed(ImputedField::Personality));
+        assert!(!report.is_imputed(ImputedField::Age));
+        assert!(!report.is_imputed(ImputedField::BirthDate));
+        assert_eq!(entity.individual_state().hexaco().extraversion(), 0.0);
+    }
+
+    #[test]
+    fn build_records_no_imputed_inputs() {
+        let entity = EntityBuilder::new()
+            .species(Species::Human)
+            .build()
+            .unwrap();
+
+        assert!(entity.completeness().is_complete());
+    }
 }
@@ -0,0 +1,126 @@
+//! Entity templates for spawning many similar entities.
+//!
+//! An `EntityTemplate` builds an entity once and keeps it as a prototype.
+//! Spawning clones the prototype under a new identifier. The clone shares
+//! the prototype's individual state, ecological context, and model
+//! configuration until the spawned entity changes them; memories and
+//! relationship slots are copied. Templates carry no timestamp: the anchor
+//! is supplied when a spawned entity is added to a simulation.
+
+use crate::types::EntityId;
+
+use super::{Entity, EntityBuildError, EntityBuilder};
+
+/// A built prototype entity that spawns entities by cloning.
+///
+/// Spawned entities are equal to entities built from the same builder
+/// with the spawned identifier. Use `Simulation::add_from_template` to
+/// spawn and anchor a batch in one call.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::entity::{EntityBuilder, EntityTemplate};
+/// use behavioral_pathways::enums::{PersonalityProfile, Species};
+/// use behavioral_pathways::types::Duration;
+///
+/// let guard = EntityTemplate::compile(
+///     EntityBuilder::new()
+///         .species(Species::Human)
+///         .age(Duration::years(30))
+///         .personality(PersonalityProfile::Leader),
+/// )
+/// .unwrap();
+///
+/// let entity = guard.spawn("guard_001").unwrap();
+/// assert_eq!(entity.id().as_str(), "guard_001");
+/// assert_eq!(entity.age().as_years(), 30);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntityTemplate {
+    /// The entity every spawn copies.
+    prototype: Entity,
+}
+
+impl EntityTemplate {
+    /// Builds the prototype from `builder`.
+    ///
+    /// An identifier set on the builder is ignored; each spawn supplies its
+    /// own.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error `EntityBuilder::build()` would return.
+    pub fn compile(builder: EntityBuilder) -> Result<Self, EntityBuildError> {
+        let prototype = builder.build()?;
+        Ok(EntityTemplate { prototype })
+    }
+
+    /// Returns the entity every spawn copies.
+    #[must_use]
+    pub fn prototype(&self) -> &Entity {
+        &self.prototype
+    }
+
+    /// Clones the prototype under the given identifier.
+    ///
+    /// The spawned entity is not anchored. `spawn` takes no anchor
+    /// timestamp because an `Entity` has none of its own: the anchor is
+    /// the one passed to `Simulation::add_entity` or `add_from_template`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EntityBuildError::InvalidId` if `id` is empty.
+    pub fn spawn(&self, id: &str) -> Result<Entity, EntityBuildError> {
+        let id = EntityId::new(id).map_err(|err| EntityBuildError::InvalidId(err.reason))?;
+        Ok(self.spawn_with_id(id))
+    }
+
+    /// Clones the prototype under an already validated identifier.
+    pub(crate) fn spawn_with_id(&self, id: EntityId) -> Entity {
+        self.prototype.clone_with_id(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::{PersonalityProfile, Species};
+    use crate::types::Duration;
+
+    fn builder() -> EntityBuilder {
+        EntityBuilder::new()
+            .species(Species::Human)
+            .age(Duration::years(30))
+            .personality(PersonalityProfile::Anxious)
+    }
+
+    #[test]
+    fn spawn_matches_builder() {
+        let template = EntityTemplate::compile(builder()).unwrap();
+        let built = builder().id("npc_001").build().unwrap();
+
+        assert_eq!(template.spawn("npc_001").unwrap(), built);
+        assert_eq!(template.prototype().species(), &Species::Human);
+    }
+
+    #[test]
+    fn builder_id_is_ignored() {
+        let template = EntityTemplate::compile(builder().id("archetype")).unwrap();
+        let entity = template.spawn("npc_002").unwrap();
+        assert_eq!(entity.id().as_str(), "npc_002");
+    }
+
+    #[test]
+    fn compile_and_spawn_report_build_errors() {
+        assert_eq!(
+            EntityTemplate::compile(EntityBuilder::new()).unwrap_err(),
+            EntityBuildError::MissingSpecies
+        );
+        let template = EntityTemplate::compile(builder()).unwrap();
+        assert!(matches!(
+            template.spawn(""),
+            Err(EntityBuildError::InvalidId(_))
+        ));
+    }
+}
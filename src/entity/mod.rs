@@ -8,12 +8,18 @@
 //! - [`Entity`] - Core agent container holding individual state
 //! - [`EntityBuilder`] - Fluent builder for Entity construction
 //! - [`EntityBuildError`] - Error type for build validation failures
+//! - [`EntityTemplate`] - Compiled builder for spawning many entities
+//! - [`CompletenessReport`] - Inputs imputed by `EntityBuilder::build_partial`
 
 #[allow(clippy::module_inception)]
 mod entity;
 mod affective_state;
+mod completeness;
 mod entity_builder;
+mod entity_template;
 
 pub use affective_state::{AffectiveState, PhysiologicalState};
+pub use completeness::{CompletenessReport, ImputedField};
 pub use entity::Entity;
 pub use entity_builder::{EntityBuildError, EntityBuilder};
+pub use entity_template::EntityTemplate;
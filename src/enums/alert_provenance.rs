@@ -0,0 +1,39 @@
+//! Alert provenance for distinguishing model and external alerts.
+
+/// Where an alert came from.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::enums::AlertProvenance;
+///
+/// assert_eq!(AlertProvenance::default(), AlertProvenance::Model);
+/// assert_eq!(AlertProvenance::External.to_string(), "External");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlertProvenance {
+    /// Generated by the model from computed state.
+    #[default]
+    Model,
+
+    /// Supplied by an outside system, such as a clinician's risk flag.
+    External,
+}
+
+impl AlertProvenance {
+    /// Returns a human-readable name for this provenance.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            AlertProvenance::Model => "Model",
+            AlertProvenance::External => "External",
+        }
+    }
+}
+
+impl std::fmt::Display for AlertProvenance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
@@ -17,6 +17,7 @@
 /// assert!(severity < AlertSeverity::Critical);
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AlertSeverity {
     /// Informational alert - noteworthy but not concerning.
     Info,
@@ -19,10 +19,10 @@
 
 use crate::enums::StatePath;
 use crate::processor::{ConvergenceStatus, ItsProximalFactor};
-use serde::{Deserialize, Serialize};
 
 /// Type of feedback spiral detected.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SpiralType {
     /// Stress-fatigue-impulse control spiral.
     Stress,
@@ -58,7 +58,8 @@ impl std::fmt::Display for SpiralType {
 /// - Single factor elevations: Generally low risk, passive ideation possible
 /// - Two-factor combinations: Moderate risk, intervention recommended
 /// - Three-factor convergence: HIGH RISK, immediate intervention needed
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ItsAlert {
     /// Only Thwarted Belongingness is elevated.
     /// Low risk - monitor for belonging needs, social connection.
@@ -243,6 +244,7 @@ impl std::fmt::Display for ItsAlert {
 /// );
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AlertTrigger {
     /// A state dimension exceeded a threshold value.
     ThresholdExceeded(StatePath, f64),
@@ -762,6 +764,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "serde")]
     fn its_alert_serialize_deserialize() {
         let alert = ItsAlert::ThreeFactorConvergence;
         let json = serde_json::to_string(&alert).unwrap();
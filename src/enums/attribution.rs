@@ -21,6 +21,7 @@ use crate::types::EntityId;
 /// assert!(stability.is_stable());
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AttributionStability {
     /// Perceived as permanent/unchangeable.
     Stable,
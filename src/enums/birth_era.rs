@@ -2,6 +2,7 @@
 
 /// Era when an entity was born.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BirthEra {
     /// Born during a crisis period.
     Crisis,
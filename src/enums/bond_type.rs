@@ -17,6 +17,7 @@
 /// assert!(bonds.contains(&BondType::Family));
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BondType {
     /// Equal standing relationship with no hierarchy.
     Peer,
@@ -137,6 +138,32 @@ impl BondType {
         }
     }
 
+    /// Returns how strongly this bond ties one party to the other.
+    ///
+    /// Scales the grief of losing the other party, following the
+    /// attachment hierarchy: partners and immediate family first, then
+    /// friends, then role-based ties.
+    ///
+    /// - Romantic, Parent, Child: 1.0
+    /// - Family: 0.9
+    /// - Sibling: 0.8
+    /// - Friend: 0.7
+    /// - Peer, Mentor, Mentee: 0.4
+    /// - Colleague, Authority, Subordinate: 0.2
+    /// - Rival: 0.0
+    #[must_use]
+    pub const fn attachment_weight(&self) -> f64 {
+        match self {
+            BondType::Romantic | BondType::Parent | BondType::Child => 1.0,
+            BondType::Family => 0.9,
+            BondType::Sibling => 0.8,
+            BondType::Friend => 0.7,
+            BondType::Peer | BondType::Mentor | BondType::Mentee => 0.4,
+            BondType::Colleague | BondType::Authority | BondType::Subordinate => 0.2,
+            BondType::Rival => 0.0,
+        }
+    }
+
     /// Returns all bond types.
     #[must_use]
     pub const fn all() -> [BondType; 13] {
@@ -226,6 +253,21 @@ mod tests {
         assert_eq!(BondType::Peer.reciprocal(), BondType::Peer);
     }
 
+    #[test]
+    fn attachment_weight_follows_attachment_hierarchy() {
+        assert!((BondType::Romantic.attachment_weight() - 1.0).abs() < f64::EPSILON);
+        assert!((BondType::Parent.attachment_weight() - 1.0).abs() < f64::EPSILON);
+        assert!(BondType::Sibling.attachment_weight() > BondType::Friend.attachment_weight());
+        assert!(BondType::Friend.attachment_weight() > BondType::Peer.attachment_weight());
+        assert!(BondType::Peer.attachment_weight() > BondType::Colleague.attachment_weight());
+        assert!(BondType::Rival.attachment_weight().abs() < f64::EPSILON);
+
+        for bond in BondType::all() {
+            let weight = bond.attachment_weight();
+            assert!((0.0..=1.0).contains(&weight));
+        }
+    }
+
     #[test]
     fn display_format() {
         assert_eq!(format!("{}", BondType::Parent), "Parent");
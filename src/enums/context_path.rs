@@ -20,8 +20,15 @@
 //! let work_path = ContextPath::Microsystem(work_id, MicrosystemPath::Work(WorkPath::WorkloadStress));
 //! ```
 //!
+//!
+//! Context paths also have dotted keys for use in scenario files, with the
+//! microsystem ID embedded: `microsystem.work_acme.work.workload_stress`.
+//!
 //! [`StatePath`]: crate::enums::StatePath
 
+use crate::enums::path_key::{
+    name_display, path_segments, segment, single_field, unknown_field, ParsePathError,
+};
 use crate::types::MicrosystemId;
 
 /// Top-level context access path.
@@ -35,6 +42,7 @@ use crate::types::MicrosystemId;
 /// multiple microsystems of the same type (e.g., two jobs). Single-instance
 /// contexts (Macrosystem, Exosystem, Chronosystem) do not require an ID.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ContextPath {
     /// Path to a microsystem dimension.
     /// Requires MicrosystemId to identify which instance.
@@ -55,6 +63,7 @@ pub enum ContextPath {
 /// Microsystems are immediate environments with face-to-face interactions.
 /// Each entity can have multiple microsystems of each type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MicrosystemPath {
     /// Work context dimensions.
     Work(WorkPath),
@@ -80,6 +89,7 @@ pub enum MicrosystemPath {
 
 /// Path to work context dimensions.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WorkPath {
     /// Current work pressure (0-1).
     WorkloadStress,
@@ -105,6 +115,18 @@ pub enum WorkPath {
     /// Security and constancy of position (0-1).
     Stability,
 
+    /// Control over how and when work is done (0-1).
+    Autonomy,
+
+    /// Freedom to arrange working hours (0-1).
+    ScheduleFlexibility,
+
+    /// Acknowledgement of contributions (0-1).
+    Recognition,
+
+    /// Fear of losing the job (0-1).
+    JobInsecurity,
+
     /// Interaction frequency normalized (0-1).
     InteractionFrequency,
 
@@ -114,6 +136,7 @@ pub enum WorkPath {
 
 /// Path to family context dimensions.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FamilyPath {
     /// Overall family satisfaction (0-1).
     FamilySatisfaction,
@@ -136,6 +159,15 @@ pub enum FamilyPath {
     /// Family stability (0-1).
     Stability,
 
+    /// Help available with caregiving (0-1).
+    CaregivingSupport,
+
+    /// Strain from household finances (0-1).
+    FinancialStrain,
+
+    /// Closeness and sense of togetherness (0-1).
+    Cohesion,
+
     /// Interaction frequency normalized (0-1).
     InteractionFrequency,
 
@@ -145,6 +177,7 @@ pub enum FamilyPath {
 
 /// Path to social context dimensions.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SocialPath {
     /// Standing in social group (0-1).
     GroupStanding,
@@ -167,6 +200,7 @@ pub enum SocialPath {
 
 /// Path to education context dimensions.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EducationPath {
     /// Intellectual challenge level (0-1).
     CognitiveDemand,
@@ -189,6 +223,7 @@ pub enum EducationPath {
 
 /// Path to healthcare context dimensions.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HealthcarePath {
     /// Healthcare access frequency (0-1).
     AccessFrequency,
@@ -211,6 +246,7 @@ pub enum HealthcarePath {
 
 /// Path to religious context dimensions.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ReligiousPath {
     /// Ritual participation frequency (0-1).
     RitualFrequency,
@@ -230,6 +266,7 @@ pub enum ReligiousPath {
 
 /// Path to neighborhood context dimensions.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NeighborhoodPath {
     /// Neighborhood safety (0-1).
     Safety,
@@ -255,6 +292,7 @@ pub enum NeighborhoodPath {
 /// Exosystem settings affect the individual indirectly through
 /// their influence on microsystems.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ExosystemPath {
     /// Quality of healthcare access (0-1).
     HealthSystemAccess,
@@ -286,6 +324,7 @@ pub enum ExosystemPath {
 /// Macrosystem represents overarching cultural, societal, and
 /// ideological patterns.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MacrosystemPath {
     /// Individualism vs collectivism (-1 collectivist to 1 individualist).
     IndividualismCollectivism,
@@ -320,6 +359,7 @@ pub enum MacrosystemPath {
 /// Chronosystem represents the temporal dimension including
 /// life transitions and historical events.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ChronosystemPath {
     /// Societal stability level (0-1).
     StabilityLevel,
@@ -339,7 +379,7 @@ pub enum ChronosystemPath {
 impl WorkPath {
     /// Returns all WorkPath variants.
     #[must_use]
-    pub const fn all() -> [WorkPath; 10] {
+    pub const fn all() -> [WorkPath; 14] {
         [
             WorkPath::WorkloadStress,
             WorkPath::RoleSatisfaction,
@@ -349,6 +389,10 @@ impl WorkPath {
             WorkPath::Predictability,
             WorkPath::CognitiveStimulation,
             WorkPath::Stability,
+            WorkPath::Autonomy,
+            WorkPath::ScheduleFlexibility,
+            WorkPath::Recognition,
+            WorkPath::JobInsecurity,
             WorkPath::InteractionFrequency,
             WorkPath::InteractionComplexity,
         ]
@@ -366,6 +410,10 @@ impl WorkPath {
             WorkPath::Predictability => "Predictability",
             WorkPath::CognitiveStimulation => "Cognitive Stimulation",
             WorkPath::Stability => "Stability",
+            WorkPath::Autonomy => "Autonomy",
+            WorkPath::ScheduleFlexibility => "Schedule Flexibility",
+            WorkPath::Recognition => "Recognition",
+            WorkPath::JobInsecurity => "Job Insecurity",
             WorkPath::InteractionFrequency => "Interaction Frequency",
             WorkPath::InteractionComplexity => "Interaction Complexity",
         }
@@ -375,7 +423,7 @@ impl WorkPath {
 impl FamilyPath {
     /// Returns all FamilyPath variants.
     #[must_use]
-    pub const fn all() -> [FamilyPath; 9] {
+    pub const fn all() -> [FamilyPath; 12] {
         [
             FamilyPath::FamilySatisfaction,
             FamilyPath::CaregivingBurden,
@@ -384,6 +432,9 @@ impl FamilyPath {
             FamilyPath::RoleClarity,
             FamilyPath::Predictability,
             FamilyPath::Stability,
+            FamilyPath::CaregivingSupport,
+            FamilyPath::FinancialStrain,
+            FamilyPath::Cohesion,
             FamilyPath::InteractionFrequency,
             FamilyPath::InteractionComplexity,
         ]
@@ -400,6 +451,9 @@ impl FamilyPath {
             FamilyPath::RoleClarity => "Role Clarity",
             FamilyPath::Predictability => "Predictability",
             FamilyPath::Stability => "Stability",
+            FamilyPath::CaregivingSupport => "Caregiving Support",
+            FamilyPath::FinancialStrain => "Financial Strain",
+            FamilyPath::Cohesion => "Cohesion",
             FamilyPath::InteractionFrequency => "Interaction Frequency",
             FamilyPath::InteractionComplexity => "Interaction Complexity",
         }
@@ -545,6 +599,69 @@ impl NeighborhoodPath {
 }
 
 impl MicrosystemPath {
+    /// Returns every microsystem path, grouped by microsystem type.
+    #[must_use]
+    pub fn all() -> Vec<MicrosystemPath> {
+        let mut paths = Vec::new();
+        paths.extend(WorkPath::all().map(MicrosystemPath::Work));
+        paths.extend(FamilyPath::all().map(MicrosystemPath::Family));
+        paths.extend(SocialPath::all().map(MicrosystemPath::Social));
+        paths.extend(EducationPath::all().map(MicrosystemPath::Education));
+        paths.extend(HealthcarePath::all().map(MicrosystemPath::Healthcare));
+        paths.extend(ReligiousPath::all().map(MicrosystemPath::Religious));
+        paths.extend(NeighborhoodPath::all().map(MicrosystemPath::Neighborhood));
+        paths
+    }
+
+    /// Returns the dotted key for this path, such as `work.workload_stress`.
+    #[must_use]
+    pub fn key(&self) -> String {
+        format!("{}.{}", segment(self.type_name()), segment(self.name()))
+    }
+
+    /// Returns true if `key` is the key segment of a microsystem type.
+    fn is_type_key(key: &str) -> bool {
+        MicrosystemPath::all()
+            .iter()
+            .any(|path| segment(path.type_name()) == key)
+    }
+
+    /// Parses `type.field` or, when the field belongs to one microsystem
+    /// type only, a bare `field`. `path` is the full string, for errors.
+    fn from_segments(path: &str, segments: &[&str]) -> Result<MicrosystemPath, ParsePathError> {
+        match segments {
+            [type_key, field] => {
+                if !MicrosystemPath::is_type_key(type_key) {
+                    return Err(ParsePathError::UnknownGroup {
+                        path: path.to_string(),
+                        group: type_key.to_string(),
+                    });
+                }
+                MicrosystemPath::all()
+                    .into_iter()
+                    .find(|p| segment(p.type_name()) == *type_key && segment(p.name()) == *field)
+                    .ok_or_else(|| unknown_field(path, field))
+            }
+            [field] => {
+                let matches: Vec<MicrosystemPath> = MicrosystemPath::all()
+                    .into_iter()
+                    .filter(|p| segment(p.name()) == *field)
+                    .collect();
+                match matches.as_slice() {
+                    [] => Err(unknown_field(path, field)),
+                    [only] => Ok(*only),
+                    _ => Err(ParsePathError::AmbiguousField {
+                        path: path.to_string(),
+                        field: field.to_string(),
+                    }),
+                }
+            }
+            _ => Err(ParsePathError::TrailingSegments {
+                path: path.to_string(),
+            }),
+        }
+    }
+
     /// Returns a human-readable name for this path.
     #[must_use]
     pub fn name(&self) -> &'static str {
@@ -664,18 +781,72 @@ impl ChronosystemPath {
     }
 }
 
+impl ContextPath {
+    /// Returns every context path, with microsystem paths for the
+    /// `microsystem` instance.
+    #[must_use]
+    pub fn all(microsystem: &MicrosystemId) -> Vec<ContextPath> {
+        let mut paths: Vec<ContextPath> = MicrosystemPath::all()
+            .into_iter()
+            .map(|path| ContextPath::Microsystem(microsystem.clone(), path))
+            .collect();
+        paths.extend(ExosystemPath::all().map(ContextPath::Exosystem));
+        paths.extend(MacrosystemPath::all().map(ContextPath::Macrosystem));
+        paths.extend(ChronosystemPath::all().map(ContextPath::Chronosystem));
+        paths
+    }
+
+    /// Returns the dotted key for this path, such as
+    /// `microsystem.work_acme.work.workload_stress` or
+    /// `macrosystem.power_distance`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::enums::{ContextPath, MicrosystemPath, WorkPath};
+    /// use behavioral_pathways::types::MicrosystemId;
+    ///
+    /// let work_id = MicrosystemId::new("work_acme").unwrap();
+    /// let path = ContextPath::Microsystem(work_id, MicrosystemPath::Work(WorkPath::WorkloadStress));
+    /// assert_eq!(path.key(), "microsystem.work_acme.work.workload_stress");
+    ///
+    /// // The type segment may be left out when the field is unique to one type
+    /// assert_eq!("microsystem.work_acme.workload_stress".parse::<ContextPath>(), Ok(path));
+    /// ```
+    #[must_use]
+    pub fn key(&self) -> String {
+        match self {
+            ContextPath::Microsystem(id, path) => format!("microsystem.{}.{}", id, path.key()),
+            ContextPath::Exosystem(p) => format!("exosystem.{}", p.key()),
+            ContextPath::Macrosystem(p) => format!("macrosystem.{}", p.key()),
+            ContextPath::Chronosystem(p) => format!("chronosystem.{}", p.key()),
+        }
+    }
+
+    /// Returns the path whose `Display` text is `s`.
+    fn from_display(s: &str) -> Option<ContextPath> {
+        if let Some(rest) = s.strip_prefix("Microsystem[") {
+            let (id, name) = rest.rsplit_once("]::")?;
+            let path = MicrosystemPath::all()
+                .into_iter()
+                .find(|path| path.to_string() == name)?;
+            return MicrosystemId::new(id)
+                .ok()
+                .map(|id| ContextPath::Microsystem(id, path));
+        }
+        ExosystemPath::all()
+            .map(ContextPath::Exosystem)
+            .into_iter()
+            .chain(MacrosystemPath::all().map(ContextPath::Macrosystem))
+            .chain(ChronosystemPath::all().map(ContextPath::Chronosystem))
+            .find(|path| path.to_string() == s)
+    }
+}
+
 impl std::fmt::Display for ContextPath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            ContextPath::Microsystem(id, path) => {
-                write!(
-                    f,
-                    "Microsystem[{}]::{}::{}",
-                    id,
-                    path.type_name(),
-                    path.name()
-                )
-            }
+            ContextPath::Microsystem(id, path) => write!(f, "Microsystem[{}]::{}", id, path),
             ContextPath::Exosystem(p) => write!(f, "Exosystem::{}", p.name()),
             ContextPath::Macrosystem(p) => write!(f, "Macrosystem::{}", p.name()),
             ContextPath::Chronosystem(p) => write!(f, "Chronosystem::{}", p.name()),
@@ -683,6 +854,114 @@ impl std::fmt::Display for ContextPath {
     }
 }
 
+/// Parses a dotted key or the text `Display` writes.
+///
+/// A microsystem key is `microsystem.<id>.<type>.<field>`. The ID may
+/// contain dots, and the type may be left out when the field belongs to
+/// only one microsystem type (`microsystem.work_acme.workload_stress`).
+impl std::str::FromStr for ContextPath {
+    type Err = ParsePathError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParsePathError::Empty);
+        }
+        if let Some(path) = ContextPath::from_display(s) {
+            return Ok(path);
+        }
+
+        let segments: Vec<&str> = s.split('.').collect();
+        let lookup: fn(&str) -> Option<ContextPath> = match segments[0] {
+            "microsystem" => {
+                let rest = &segments[1..];
+                if rest.len() < 2 {
+                    return Err(ParsePathError::MissingSegment {
+                        path: s.to_string(),
+                    });
+                }
+                let n = rest.len();
+                let split = if n >= 3 && MicrosystemPath::is_type_key(rest[n - 2]) {
+                    n - 2
+                } else {
+                    n - 1
+                };
+                let path = MicrosystemPath::from_segments(s, &rest[split..])?;
+                let id = MicrosystemId::new(rest[..split].join(".")).map_err(|error| {
+                    ParsePathError::InvalidId {
+                        path: s.to_string(),
+                        reason: error.reason,
+                    }
+                })?;
+                return Ok(ContextPath::Microsystem(id, path));
+            }
+            "exosystem" => |f| ExosystemPath::from_key(f).map(ContextPath::Exosystem),
+            "macrosystem" => |f| MacrosystemPath::from_key(f).map(ContextPath::Macrosystem),
+            "chronosystem" => |f| ChronosystemPath::from_key(f).map(ContextPath::Chronosystem),
+            group => {
+                return Err(ParsePathError::UnknownGroup {
+                    path: s.to_string(),
+                    group: group.to_string(),
+                })
+            }
+        };
+        let field = single_field(s, &segments[1..])?;
+        lookup(field).ok_or_else(|| unknown_field(s, field))
+    }
+}
+
+/// Displays the microsystem type and field, such as `Work::Workload Stress`.
+impl std::fmt::Display for MicrosystemPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}::{}", self.type_name(), self.name())
+    }
+}
+
+/// Parses `type.field`, a bare field unique to one microsystem type, or
+/// the text `Display` writes.
+impl std::str::FromStr for MicrosystemPath {
+    type Err = ParsePathError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParsePathError::Empty);
+        }
+        if let Some(path) = MicrosystemPath::all()
+            .into_iter()
+            .find(|path| path.to_string() == s)
+        {
+            return Ok(path);
+        }
+        let segments: Vec<&str> = s.split('.').collect();
+        MicrosystemPath::from_segments(s, &segments)
+    }
+}
+
+path_segments!(
+    WorkPath => WorkPath::all(),
+    FamilyPath => FamilyPath::all(),
+    SocialPath => SocialPath::all(),
+    EducationPath => EducationPath::all(),
+    HealthcarePath => HealthcarePath::all(),
+    ReligiousPath => ReligiousPath::all(),
+    NeighborhoodPath => NeighborhoodPath::all(),
+    ExosystemPath => ExosystemPath::all(),
+    MacrosystemPath => MacrosystemPath::all(),
+    ChronosystemPath => ChronosystemPath::all(),
+);
+
+name_display!(
+    WorkPath,
+    FamilyPath,
+    SocialPath,
+    EducationPath,
+    HealthcarePath,
+    ReligiousPath,
+    NeighborhoodPath,
+    ExosystemPath,
+    MacrosystemPath,
+    ChronosystemPath,
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -719,13 +998,13 @@ mod tests {
     #[test]
     fn work_path_all() {
         let all = WorkPath::all();
-        assert_eq!(all.len(), 10);
+        assert_eq!(all.len(), 14);
     }
 
     #[test]
     fn family_path_all() {
         let all = FamilyPath::all();
-        assert_eq!(all.len(), 9);
+        assert_eq!(all.len(), 12);
     }
 
     #[test]
@@ -1018,6 +1297,10 @@ mod tests {
                 WorkPath::Predictability => assert_eq!(p.name(), "Predictability"),
                 WorkPath::CognitiveStimulation => assert_eq!(p.name(), "Cognitive Stimulation"),
                 WorkPath::Stability => assert_eq!(p.name(), "Stability"),
+                WorkPath::Autonomy => assert_eq!(p.name(), "Autonomy"),
+                WorkPath::ScheduleFlexibility => assert_eq!(p.name(), "Schedule Flexibility"),
+                WorkPath::Recognition => assert_eq!(p.name(), "Recognition"),
+                WorkPath::JobInsecurity => assert_eq!(p.name(), "Job Insecurity"),
                 WorkPath::InteractionFrequency => assert_eq!(p.name(), "Interaction Frequency"),
                 WorkPath::InteractionComplexity => assert_eq!(p.name(), "Interaction Complexity"),
             }
@@ -1035,6 +1318,9 @@ mod tests {
                 FamilyPath::RoleClarity => assert_eq!(p.name(), "Role Clarity"),
                 FamilyPath::Predictability => assert_eq!(p.name(), "Predictability"),
                 FamilyPath::Stability => assert_eq!(p.name(), "Stability"),
+                FamilyPath::CaregivingSupport => assert_eq!(p.name(), "Caregiving Support"),
+                FamilyPath::FinancialStrain => assert_eq!(p.name(), "Financial Strain"),
+                FamilyPath::Cohesion => assert_eq!(p.name(), "Cohesion"),
                 FamilyPath::InteractionFrequency => assert_eq!(p.name(), "Interaction Frequency"),
                 FamilyPath::InteractionComplexity => assert_eq!(p.name(), "Interaction Complexity"),
             }
@@ -1186,4 +1472,72 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn every_context_path_parses_from_its_key_and_display() {
+        for id in ["work_acme", "acme.work"] {
+            let id = MicrosystemId::new(id).unwrap();
+            for path in ContextPath::all(&id) {
+                assert_eq!(path.key().parse::<ContextPath>(), Ok(path.clone()));
+                assert_eq!(path.to_string().parse::<ContextPath>(), Ok(path));
+            }
+        }
+        for path in MicrosystemPath::all() {
+            assert_eq!(path.key().parse::<MicrosystemPath>(), Ok(path));
+            assert_eq!(path.to_string().parse::<MicrosystemPath>(), Ok(path));
+        }
+        assert_eq!(
+            "rule_of_law".parse::<MacrosystemPath>(),
+            Ok(MacrosystemPath::RuleOfLaw)
+        );
+    }
+
+    #[test]
+    fn microsystem_key_may_omit_a_unique_type() {
+        let id = MicrosystemId::new("work_acme").unwrap();
+        let stress = ContextPath::Microsystem(id, MicrosystemPath::Work(WorkPath::WorkloadStress));
+        assert_eq!(stress.key(), "microsystem.work_acme.work.workload_stress");
+        assert_eq!(
+            "microsystem.work_acme.workload_stress".parse::<ContextPath>(),
+            Ok(stress)
+        );
+        assert_eq!(
+            "microsystem.work_acme.warmth".parse::<ContextPath>(),
+            Err(ParsePathError::AmbiguousField {
+                path: "microsystem.work_acme.warmth".to_string(),
+                field: "warmth".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn malformed_context_keys_are_described() {
+        assert_eq!(
+            "microsystem.work_acme".parse::<ContextPath>(),
+            Err(ParsePathError::MissingSegment {
+                path: "microsystem.work_acme".to_string(),
+            })
+        );
+        assert_eq!(
+            "microsystem..work.warmth".parse::<ContextPath>(),
+            Err(ParsePathError::InvalidId {
+                path: "microsystem..work.warmth".to_string(),
+                reason: "ID cannot be empty".to_string(),
+            })
+        );
+        assert_eq!(
+            "microsystem.home.family.workload_stress".parse::<ContextPath>(),
+            Err(ParsePathError::UnknownField {
+                path: "microsystem.home.family.workload_stress".to_string(),
+                field: "workload_stress".to_string(),
+            })
+        );
+        assert_eq!(
+            "mesosystem.links".parse::<ContextPath>(),
+            Err(ParsePathError::UnknownGroup {
+                path: "mesosystem.links".to_string(),
+                group: "mesosystem".to_string(),
+            })
+        );
+    }
 }
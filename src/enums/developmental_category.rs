@@ -145,6 +145,11 @@ impl From<&EventType> for DevelopmentalCategory {
             EventType::Loss => DevelopmentalCategory::Neutral,
             EventType::Interaction => DevelopmentalCategory::Neutral,
             EventType::PolicyChange => DevelopmentalCategory::Neutral,
+            EventType::HealthChange => DevelopmentalCategory::Neutral,
+            EventType::SleepDeprivation => DevelopmentalCategory::Neutral,
+            EventType::Rest => DevelopmentalCategory::Neutral,
+            EventType::Feeding => DevelopmentalCategory::Neutral,
+            EventType::ThreatExposure => DevelopmentalCategory::Neutral,
             // AC pathway events are generally cross-stage (trauma/violence)
             EventType::NonSuicidalSelfInjury => DevelopmentalCategory::Neutral,
             EventType::ChildhoodAbuse => DevelopmentalCategory::Neutral,
@@ -34,6 +34,7 @@
 /// assert!(!emotion.is_positive());
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Emotion {
     /// V+ A+ D+ - Joyful, energetic, in-control.
     Exuberant,
@@ -4,25 +4,31 @@
 //! specific details about the event. All field types use
 //! typed enums (no magic strings).
 
-use crate::types::{EntityId, GroupId, MicrosystemId, RelationshipId};
-use serde::{Deserialize, Serialize};
+use crate::enums::RampShape;
+use crate::types::{
+    BoundedValueError, Duration, EntityId, GroupId, MicrosystemId, RelationshipId, SignedUnit,
+    Timestamp, UnitInterval,
+};
 
 /// Type-specific event data.
 ///
 /// Each variant corresponds to an `EventType` and contains the
-/// specific details for that event.
+/// specific details for that event. `EventType::allowed_payloads` lists
+/// the variants each event type accepts besides `Empty`.
 ///
 /// # Examples
 ///
 /// ```
-/// use behavioral_pathways::enums::{EventPayload, LifeDomain, SupportType};
+/// use behavioral_pathways::enums::{Audience, EventPayload, LifeDomain, SupportType};
 ///
 /// let payload = EventPayload::Support {
 ///     support_type: SupportType::Emotional,
 ///     effectiveness: 0.8,
+///     audience: Audience::Private,
 /// };
 /// ```
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EventPayload {
     /// Empty payload for events without specific data.
     ///
@@ -67,6 +73,10 @@ pub enum EventPayload {
         support_type: SupportType,
         /// How effective the support was (0.0-1.0).
         effectiveness: f64,
+        /// Who witnessed the support or praise. Missing in serialized
+        /// events written before audiences existed, and read as private.
+        #[cfg_attr(feature = "serde", serde(default))]
+        audience: Audience,
     },
     /// Interpersonal disagreement.
     Conflict {
@@ -106,6 +116,10 @@ pub enum EventPayload {
         domain: LifeDomain,
         /// Magnitude of achievement (0.0-1.0).
         magnitude: f64,
+        /// Who recognized the achievement. Missing in serialized events
+        /// written before audiences existed, and read as private.
+        #[cfg_attr(feature = "serde", serde(default))]
+        audience: Audience,
     },
     /// Goal failure.
     Failure {
@@ -118,6 +132,10 @@ pub enum EventPayload {
     Loss {
         /// Type of loss.
         loss_type: LossType,
+        /// The person lost, for a `LossType::Person` loss. Missing in
+        /// serialized events written before it existed, and read as none.
+        #[cfg_attr(feature = "serde", serde(default))]
+        lost_entity: Option<EntityId>,
     },
 
     // Environmental events
@@ -135,6 +153,17 @@ pub enum EventPayload {
         /// Microsystem transitioning to.
         to: MicrosystemId,
     },
+    /// Exit from a life role (retirement, job exit, leaving school).
+    ///
+    /// Ends the microsystem carrying the role. Use with
+    /// `EventType::ContextTransition`; set the event's microsystem context
+    /// to name the specific microsystem being left.
+    RoleExit {
+        /// Life domain of the role being exited.
+        domain: LifeDomain,
+        /// Whether the exit was chosen (true) or imposed (false).
+        voluntary: bool,
+    },
     /// Chronosystem-level event.
     HistoricalEvent {
         /// Type of historical event.
@@ -143,6 +172,82 @@ pub enum EventPayload {
         scope: HistoricalScope,
     },
 
+    // Health events
+    /// Onset of a somatic illness or health condition.
+    ///
+    /// Use with `EventType::HealthChange`, or `EventType::ChronicIllnessOnset`
+    /// to also apply that event type's burden blueprint. Acute conditions
+    /// produce time-limited fatigue and low mood delivered over the
+    /// condition's onset window. Chronic conditions add a persistent load
+    /// that depends on how well the condition is managed.
+    HealthCondition {
+        /// The kind of condition.
+        condition: ConditionKind,
+        /// Whether the condition persists (true) or resolves (false).
+        chronic: bool,
+        /// How symptoms develop over time.
+        severity_course: CourseShape,
+    },
+    /// Remission of a previously declared chronic condition.
+    ///
+    /// Use with `EventType::HealthChange`. Ends the persistent load and
+    /// flare schedule of the matching condition.
+    HealthRemission {
+        /// The kind of condition entering remission.
+        condition: ConditionKind,
+    },
+
+    // Physiological events
+    /// A night's sleep.
+    ///
+    /// Use with `EventType::SleepDeprivation` or `EventType::Rest`. Sleep
+    /// restores in proportion to hours times quality, with a full night
+    /// (8 hours at full quality) restoring completely. A deprivation event
+    /// applies the part of its effect that was not restored; a rest event
+    /// applies the part that was.
+    Sleep {
+        /// Hours slept (0.0-24.0).
+        hours: f64,
+        /// How restful the sleep was (0.0-1.0).
+        quality: f64,
+    },
+
+    // Concealment events
+    /// Keeping a stigmatized identity or secret hidden.
+    ///
+    /// Use with `EventType::Interaction`. While ongoing, concealment adds a
+    /// stress and self-hate maintenance load and weakens the loneliness
+    /// buffering of every relationship the secret is kept in. It ends at
+    /// `until` or at the first `EventPayload::Disclosure` in the same life
+    /// domain. A one-off concealment (`ongoing: false`) has no sustained
+    /// effect.
+    Concealment {
+        /// Life domain of what is concealed.
+        domain: LifeDomain,
+        /// How much effort concealment takes (0.0-1.0).
+        intensity: f64,
+        /// Whether concealment continues after the event.
+        ongoing: bool,
+        /// Relationships in which the secret is kept.
+        kept_from: Vec<RelationshipId>,
+        /// When concealment ends, if known in advance.
+        until: Option<Timestamp>,
+    },
+    /// Revealing a concealed identity or secret to a partner.
+    ///
+    /// Use with `EventType::Interaction`. Ends the maintenance load of an
+    /// ongoing concealment in the same life domain and lifts its suppression
+    /// of the partner's relationship. The partner's reaction drives a
+    /// one-time valence and perceived reciprocal caring response.
+    Disclosure {
+        /// Life domain of what is disclosed.
+        domain: LifeDomain,
+        /// Relationship with the partner disclosed to.
+        relationship: RelationshipId,
+        /// Partner's reaction, from rejection (-1.0) to full acceptance (1.0).
+        reaction: f64,
+    },
+
     // Internal events
     /// Insight or realization.
     Realization {
@@ -158,8 +263,420 @@ pub enum EventPayload {
     },
 }
 
+impl EventPayload {
+    /// Returns the variant of this payload, without its fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::enums::{EventPayload, PayloadKind};
+    ///
+    /// let payload = EventPayload::Betrayal {
+    ///     confidence_violated: 0.8,
+    /// };
+    /// assert_eq!(payload.kind(), PayloadKind::Betrayal);
+    /// ```
+    #[must_use]
+    pub const fn kind(&self) -> PayloadKind {
+        match self {
+            EventPayload::Empty => PayloadKind::Empty,
+            EventPayload::Interaction { .. } => PayloadKind::Interaction,
+            EventPayload::SocialExclusion { .. } => PayloadKind::SocialExclusion,
+            EventPayload::SocialInclusion { .. } => PayloadKind::SocialInclusion,
+            EventPayload::BurdenFeedback { .. } => PayloadKind::BurdenFeedback,
+            EventPayload::Betrayal { .. } => PayloadKind::Betrayal,
+            EventPayload::Support { .. } => PayloadKind::Support,
+            EventPayload::Conflict { .. } => PayloadKind::Conflict,
+            EventPayload::Violence { .. } => PayloadKind::Violence,
+            EventPayload::Humiliation { .. } => PayloadKind::Humiliation,
+            EventPayload::Empowerment { .. } => PayloadKind::Empowerment,
+            EventPayload::Achievement { .. } => PayloadKind::Achievement,
+            EventPayload::Failure { .. } => PayloadKind::Failure,
+            EventPayload::Loss { .. } => PayloadKind::Loss,
+            EventPayload::PolicyChange { .. } => PayloadKind::PolicyChange,
+            EventPayload::ContextTransition { .. } => PayloadKind::ContextTransition,
+            EventPayload::RoleExit { .. } => PayloadKind::RoleExit,
+            EventPayload::HistoricalEvent { .. } => PayloadKind::HistoricalEvent,
+            EventPayload::HealthCondition { .. } => PayloadKind::HealthCondition,
+            EventPayload::HealthRemission { .. } => PayloadKind::HealthRemission,
+            EventPayload::Sleep { .. } => PayloadKind::Sleep,
+            EventPayload::Concealment { .. } => PayloadKind::Concealment,
+            EventPayload::Disclosure { .. } => PayloadKind::Disclosure,
+            EventPayload::Realization { .. } => PayloadKind::Realization,
+            EventPayload::TraumaticExposure { .. } => PayloadKind::TraumaticExposure,
+        }
+    }
+
+    /// Returns a human-readable name for this payload variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::enums::EventPayload;
+    ///
+    /// assert_eq!(EventPayload::Empty.name(), "Empty");
+    /// ```
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.kind().name()
+    }
+
+    /// Returns who witnessed the recognition this payload carries.
+    ///
+    /// Achievement and Support payloads carry an audience; every other
+    /// payload is private.
+    #[must_use]
+    pub fn audience(&self) -> Audience {
+        match self {
+            EventPayload::Achievement { audience, .. } | EventPayload::Support { audience, .. } => {
+                *audience
+            }
+            _ => Audience::Private,
+        }
+    }
+
+    /// Checks that every numeric field is on its documented scale.
+    ///
+    /// `EventBuilder::build` rejects a payload that fails this check.
+    ///
+    /// # Errors
+    ///
+    /// Returns the name of the first out-of-range or non-finite field with
+    /// the reason it was rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::enums::{EventPayload, LifeDomain};
+    ///
+    /// let payload = EventPayload::Achievement {
+    ///     domain: LifeDomain::Work,
+    ///     magnitude: 1.4,
+    ///     audience: Default::default(),
+    /// };
+    /// let (field, _) = payload.validate_ranges().unwrap_err();
+    /// assert_eq!(field, "magnitude");
+    /// ```
+    pub fn validate_ranges(&self) -> Result<(), (&'static str, BoundedValueError)> {
+        let unit = |field, value| UnitInterval::new(value).map(drop).map_err(|e| (field, e));
+        let signed = |field, value| SignedUnit::new(value).map(drop).map_err(|e| (field, e));
+        match self {
+            EventPayload::Betrayal {
+                confidence_violated,
+            } => unit("confidence_violated", *confidence_violated),
+            EventPayload::Support { effectiveness, .. } => unit("effectiveness", *effectiveness),
+            EventPayload::Violence {
+                injury_severity, ..
+            } => unit("injury_severity", *injury_severity),
+            EventPayload::Achievement { magnitude, .. } => unit("magnitude", *magnitude),
+            EventPayload::PolicyChange { favorability, .. } => {
+                signed("favorability", *favorability)
+            }
+            EventPayload::Concealment { intensity, .. } => unit("intensity", *intensity),
+            EventPayload::Disclosure { reaction, .. } => signed("reaction", *reaction),
+            EventPayload::TraumaticExposure { proximity, .. } => unit("proximity", *proximity),
+            EventPayload::Sleep { hours, quality } => {
+                if !(hours.is_finite() && (0.0..=24.0).contains(hours)) {
+                    return Err((
+                        "hours",
+                        BoundedValueError {
+                            value: *hours,
+                            min: 0.0,
+                            max: 24.0,
+                        },
+                    ));
+                }
+                unit("quality", *quality)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The variant of an `EventPayload`, without its fields.
+///
+/// `EventType::allowed_payloads` lists the kinds each event type accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PayloadKind {
+    /// `EventPayload::Empty`.
+    Empty,
+    /// `EventPayload::Interaction`.
+    Interaction,
+    /// `EventPayload::SocialExclusion`.
+    SocialExclusion,
+    /// `EventPayload::SocialInclusion`.
+    SocialInclusion,
+    /// `EventPayload::BurdenFeedback`.
+    BurdenFeedback,
+    /// `EventPayload::Betrayal`.
+    Betrayal,
+    /// `EventPayload::Support`.
+    Support,
+    /// `EventPayload::Conflict`.
+    Conflict,
+    /// `EventPayload::Violence`.
+    Violence,
+    /// `EventPayload::Humiliation`.
+    Humiliation,
+    /// `EventPayload::Empowerment`.
+    Empowerment,
+    /// `EventPayload::Achievement`.
+    Achievement,
+    /// `EventPayload::Failure`.
+    Failure,
+    /// `EventPayload::Loss`.
+    Loss,
+    /// `EventPayload::PolicyChange`.
+    PolicyChange,
+    /// `EventPayload::ContextTransition`.
+    ContextTransition,
+    /// `EventPayload::RoleExit`.
+    RoleExit,
+    /// `EventPayload::HistoricalEvent`.
+    HistoricalEvent,
+    /// `EventPayload::HealthCondition`.
+    HealthCondition,
+    /// `EventPayload::HealthRemission`.
+    HealthRemission,
+    /// `EventPayload::Sleep`.
+    Sleep,
+    /// `EventPayload::Concealment`.
+    Concealment,
+    /// `EventPayload::Disclosure`.
+    Disclosure,
+    /// `EventPayload::Realization`.
+    Realization,
+    /// `EventPayload::TraumaticExposure`.
+    TraumaticExposure,
+}
+
+impl PayloadKind {
+    /// Returns a human-readable name for this payload kind.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            PayloadKind::Empty => "Empty",
+            PayloadKind::Interaction => "Interaction",
+            PayloadKind::SocialExclusion => "Social Exclusion",
+            PayloadKind::SocialInclusion => "Social Inclusion",
+            PayloadKind::BurdenFeedback => "Burden Feedback",
+            PayloadKind::Betrayal => "Betrayal",
+            PayloadKind::Support => "Support",
+            PayloadKind::Conflict => "Conflict",
+            PayloadKind::Violence => "Violence",
+            PayloadKind::Humiliation => "Humiliation",
+            PayloadKind::Empowerment => "Empowerment",
+            PayloadKind::Achievement => "Achievement",
+            PayloadKind::Failure => "Failure",
+            PayloadKind::Loss => "Loss",
+            PayloadKind::PolicyChange => "Policy Change",
+            PayloadKind::ContextTransition => "Context Transition",
+            PayloadKind::RoleExit => "Role Exit",
+            PayloadKind::HistoricalEvent => "Historical Event",
+            PayloadKind::HealthCondition => "Health Condition",
+            PayloadKind::HealthRemission => "Health Remission",
+            PayloadKind::Sleep => "Sleep",
+            PayloadKind::Concealment => "Concealment",
+            PayloadKind::Disclosure => "Disclosure",
+            PayloadKind::Realization => "Realization",
+            PayloadKind::TraumaticExposure => "Traumatic Exposure",
+        }
+    }
+}
+
+/// Who witnesses an achievement or an act of support or praise.
+///
+/// Recognition in front of others boosts dominance and self-worth more
+/// than private praise, and makes a contradicting failure or humiliation
+/// soon after hurt more. The multipliers grow with the audience and are
+/// bounded by the public values:
+///
+/// | Audience | Recognition | Fall |
+/// |----------|-------------|------|
+/// | `Private` | 1.0 | 1.0 |
+/// | `SmallGroup` | 1.25 | 1.2 |
+/// | `Public` | 1.5 | 1.4 |
+///
+/// Audiences are ordered from smallest to largest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Audience {
+    /// Only the entity and the giver.
+    #[default]
+    Private,
+    /// A handful of peers, such as a team.
+    SmallGroup,
+    /// An open audience, such as an award ceremony or social media.
+    Public,
+}
+
+impl Audience {
+    /// Returns a human-readable name for this audience.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Audience::Private => "Private",
+            Audience::SmallGroup => "SmallGroup",
+            Audience::Public => "Public",
+        }
+    }
+
+    /// Returns all audience variants, from smallest to largest.
+    #[must_use]
+    pub const fn all() -> [Audience; 3] {
+        [Audience::Private, Audience::SmallGroup, Audience::Public]
+    }
+
+    /// Returns the factor applied to the dominance and self-worth gains of
+    /// recognition given before this audience.
+    #[must_use]
+    pub const fn recognition_multiplier(&self) -> f64 {
+        match self {
+            Audience::Private => 1.0,
+            Audience::SmallGroup => 1.25,
+            Audience::Public => 1.5,
+        }
+    }
+
+    /// Returns the factor applied to the losses of a failure or humiliation
+    /// that contradicts recognition given before this audience.
+    #[must_use]
+    pub const fn fall_multiplier(&self) -> f64 {
+        match self {
+            Audience::Private => 1.0,
+            Audience::SmallGroup => 1.2,
+            Audience::Public => 1.4,
+        }
+    }
+}
+
+/// Kind of somatic health condition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConditionKind {
+    /// Short-lived infection (flu, viral illness).
+    Infection,
+    /// Respiratory illness (asthma, COPD).
+    Respiratory,
+    /// Persistent pain condition.
+    ChronicPain,
+    /// Autoimmune disease (lupus, rheumatoid arthritis, MS).
+    Autoimmune,
+    /// Cardiovascular or metabolic disease (heart disease, diabetes).
+    Cardiometabolic,
+    /// Cancer.
+    Cancer,
+    /// Neurological condition (epilepsy, migraine, Parkinson's).
+    Neurological,
+}
+
+impl ConditionKind {
+    /// Returns a human-readable name for this condition kind.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            ConditionKind::Infection => "Infection",
+            ConditionKind::Respiratory => "Respiratory",
+            ConditionKind::ChronicPain => "Chronic Pain",
+            ConditionKind::Autoimmune => "Autoimmune",
+            ConditionKind::Cardiometabolic => "Cardiometabolic",
+            ConditionKind::Cancer => "Cancer",
+            ConditionKind::Neurological => "Neurological",
+        }
+    }
+
+    /// Returns the window over which acute symptoms build to their peak.
+    #[must_use]
+    pub const fn typical_onset(&self) -> Duration {
+        match self {
+            ConditionKind::Infection => Duration::days(3),
+            ConditionKind::Respiratory => Duration::days(5),
+            ConditionKind::ChronicPain
+            | ConditionKind::Autoimmune
+            | ConditionKind::Cardiometabolic
+            | ConditionKind::Neurological => Duration::days(14),
+            ConditionKind::Cancer => Duration::days(30),
+        }
+    }
+
+    /// Returns the typical interval between flares of a relapsing course.
+    #[must_use]
+    pub const fn flare_interval(&self) -> Duration {
+        match self {
+            ConditionKind::Infection | ConditionKind::Respiratory => Duration::days(60),
+            ConditionKind::ChronicPain | ConditionKind::Neurological => Duration::days(45),
+            ConditionKind::Autoimmune => Duration::days(90),
+            ConditionKind::Cardiometabolic | ConditionKind::Cancer => Duration::days(120),
+        }
+    }
+
+    /// Returns all condition kinds.
+    #[must_use]
+    pub const fn all() -> [ConditionKind; 7] {
+        [
+            ConditionKind::Infection,
+            ConditionKind::Respiratory,
+            ConditionKind::ChronicPain,
+            ConditionKind::Autoimmune,
+            ConditionKind::Cardiometabolic,
+            ConditionKind::Cancer,
+            ConditionKind::Neurological,
+        ]
+    }
+}
+
+/// How the severity of a health condition develops over time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CourseShape {
+    /// Symptoms appear at once and then hold steady.
+    Sudden,
+    /// Symptoms build steadily, then hold steady.
+    Gradual,
+    /// Symptoms build slowly at first and keep worsening.
+    Progressive,
+    /// Symptoms flare periodically between quieter stretches.
+    RelapsingRemitting,
+}
+
+impl CourseShape {
+    /// Returns a human-readable name for this course.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            CourseShape::Sudden => "Sudden",
+            CourseShape::Gradual => "Gradual",
+            CourseShape::Progressive => "Progressive",
+            CourseShape::RelapsingRemitting => "Relapsing-Remitting",
+        }
+    }
+
+    /// Returns the ramp profile used to deliver acute symptoms.
+    #[must_use]
+    pub const fn ramp_shape(&self) -> RampShape {
+        match self {
+            CourseShape::Sudden => RampShape::Step,
+            CourseShape::Gradual | CourseShape::RelapsingRemitting => RampShape::Linear,
+            CourseShape::Progressive => RampShape::EaseIn,
+        }
+    }
+
+    /// Returns all course variants.
+    #[must_use]
+    pub const fn all() -> [CourseShape; 4] {
+        [
+            CourseShape::Sudden,
+            CourseShape::Gradual,
+            CourseShape::Progressive,
+            CourseShape::RelapsingRemitting,
+        ]
+    }
+}
+
 /// Topic of a social interaction.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InteractionTopic {
     /// Work-related discussion.
     Work,
@@ -204,7 +721,8 @@ impl InteractionTopic {
 }
 
 /// Life domain for achievements, empowerment, and failures.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LifeDomain {
     /// Professional work.
     Work,
@@ -257,7 +775,8 @@ impl LifeDomain {
 }
 
 /// Policy area affected by a policy change.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PolicyArea {
     /// Economic policy.
     Economic,
@@ -302,7 +821,8 @@ impl PolicyArea {
 }
 
 /// Type of historical/chronosystem event.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HistoricalEventType {
     /// Armed conflict.
     War,
@@ -347,7 +867,8 @@ impl HistoricalEventType {
 }
 
 /// Type of insight or realization.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RealizationType {
     /// Insight about oneself.
     SelfInsight,
@@ -384,7 +905,8 @@ impl RealizationType {
 }
 
 /// Type of support received.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SupportType {
     /// Emotional support (listening, empathy).
     Emotional,
@@ -421,7 +943,8 @@ impl SupportType {
 }
 
 /// Type of weapon involved in violence.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WeaponType {
     /// No weapon (unarmed).
     None,
@@ -458,7 +981,8 @@ impl WeaponType {
 }
 
 /// Type of loss experienced.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LossType {
     /// Loss of a person (death, separation).
     Person,
@@ -495,7 +1019,8 @@ impl LossType {
 }
 
 /// Type of trauma experienced.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TraumaType {
     /// Physical trauma (injury, pain).
     Physical,
@@ -528,7 +1053,8 @@ impl TraumaType {
 }
 
 /// Geographic/social scope of a historical event.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HistoricalScope {
     /// Local community impact.
     Local,
@@ -590,6 +1116,7 @@ mod tests {
             EventPayload::Support {
                 support_type,
                 effectiveness,
+                ..
             } => (support_type, effectiveness),
             _ => panic!("Expected Support payload"),
         }
@@ -623,6 +1150,7 @@ mod tests {
         let payload = EventPayload::Support {
             support_type: SupportType::Emotional,
             effectiveness: 0.4,
+            audience: Audience::Private,
         };
         let _ = unpack_interaction(payload);
     }
@@ -655,6 +1183,7 @@ mod tests {
         let payload = EventPayload::Support {
             support_type: SupportType::Emotional,
             effectiveness: 0.8,
+            audience: Audience::Private,
         };
 
         let (support_type, effectiveness) = unpack_support(payload);
@@ -690,6 +1219,7 @@ mod tests {
         let payload = EventPayload::Support {
             support_type: SupportType::Instrumental,
             effectiveness: 0.6,
+            audience: Audience::Private,
         };
         let _ = unpack_violence(payload);
     }
@@ -716,6 +1246,7 @@ mod tests {
         let _ = EventPayload::Support {
             support_type: SupportType::Emotional,
             effectiveness: 0.5,
+            audience: Audience::Private,
         };
         let _ = EventPayload::Conflict {
             verbal: true,
@@ -736,6 +1267,7 @@ mod tests {
         let _ = EventPayload::Achievement {
             domain: LifeDomain::Academic,
             magnitude: 0.5,
+            audience: Audience::Private,
         };
         let _ = EventPayload::Failure {
             domain: LifeDomain::Financial,
@@ -743,6 +1275,7 @@ mod tests {
         };
         let _ = EventPayload::Loss {
             loss_type: LossType::Person,
+            lost_entity: Some(EntityId::new("partner").unwrap()),
         };
         let _ = EventPayload::PolicyChange {
             policy_area: PolicyArea::Healthcare,
@@ -751,6 +1284,10 @@ mod tests {
         let from = MicrosystemId::new("home").unwrap();
         let to = MicrosystemId::new("work").unwrap();
         let _ = EventPayload::ContextTransition { from, to };
+        let _ = EventPayload::RoleExit {
+            domain: LifeDomain::Work,
+            voluntary: true,
+        };
         let _ = EventPayload::HistoricalEvent {
             event_type: HistoricalEventType::Pandemic,
             scope: HistoricalScope::Global,
@@ -762,6 +1299,49 @@ mod tests {
             trauma_type: TraumaType::Witnessing,
             proximity: 0.5,
         };
+        let _ = EventPayload::HealthCondition {
+            condition: ConditionKind::Autoimmune,
+            chronic: true,
+            severity_course: CourseShape::RelapsingRemitting,
+        };
+        let _ = EventPayload::HealthRemission {
+            condition: ConditionKind::Autoimmune,
+        };
+        let _ = EventPayload::Sleep {
+            hours: 4.0,
+            quality: 0.6,
+        };
+        let relationship = RelationshipId::new("rel_001").unwrap();
+        let _ = EventPayload::Concealment {
+            domain: LifeDomain::Relationship,
+            intensity: 0.7,
+            ongoing: true,
+            kept_from: vec![relationship.clone()],
+            until: None,
+        };
+        let _ = EventPayload::Disclosure {
+            domain: LifeDomain::Relationship,
+            relationship,
+            reaction: 0.8,
+        };
+    }
+
+    #[test]
+    fn condition_kind_all() {
+        let all = ConditionKind::all();
+        assert_eq!(all.len(), 7);
+        for kind in all {
+            assert!(!kind.typical_onset().is_zero());
+            assert!(kind.flare_interval() > kind.typical_onset());
+        }
+    }
+
+    #[test]
+    fn course_shape_maps_to_ramp_shape() {
+        assert_eq!(CourseShape::Sudden.ramp_shape(), RampShape::Step);
+        assert_eq!(CourseShape::Gradual.ramp_shape(), RampShape::Linear);
+        assert_eq!(CourseShape::Progressive.ramp_shape(), RampShape::EaseIn);
+        assert_eq!(CourseShape::all().len(), 4);
     }
 
     #[test]
@@ -856,6 +1436,12 @@ mod tests {
         for s in HistoricalScope::all() {
             assert!(!s.name().is_empty());
         }
+        for c in ConditionKind::all() {
+            assert!(!c.name().is_empty());
+        }
+        for c in CourseShape::all() {
+            assert!(!c.name().is_empty());
+        }
     }
 
     #[test]
@@ -874,6 +1460,7 @@ mod tests {
         let payload = EventPayload::Support {
             support_type: SupportType::Emotional,
             effectiveness: 0.8,
+            audience: Audience::Private,
         };
         let debug = format!("{:?}", payload);
         assert!(debug.contains("Support"));
@@ -885,6 +1472,7 @@ mod tests {
         let payload = EventPayload::Achievement {
             domain: LifeDomain::Work,
             magnitude: 0.9,
+            audience: Audience::Private,
         };
         let cloned = payload.clone();
         assert_eq!(payload, cloned);
@@ -899,4 +1487,38 @@ mod tests {
         let cloned = payload.clone();
         assert_eq!(payload, cloned);
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn missing_audience_deserializes_as_private() {
+        let json = r#"{"Achievement":{"domain":"Work","magnitude":0.9}}"#;
+        let payload: EventPayload = serde_json::from_str(json).unwrap();
+        assert_eq!(payload.audience(), Audience::Private);
+
+        let payload = EventPayload::Support {
+            support_type: SupportType::Emotional,
+            effectiveness: 0.8,
+            audience: Audience::Public,
+        };
+        let json = serde_json::to_string(&payload).unwrap();
+        let deserialized: EventPayload = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, payload);
+        assert_eq!(EventPayload::Empty.audience(), Audience::Private);
+    }
+
+    #[test]
+    fn audience_multipliers_grow_with_audience() {
+        let all = Audience::all();
+        assert_eq!(Audience::default(), Audience::Private);
+        assert_eq!(
+            all.map(|audience| audience.name()),
+            ["Private", "SmallGroup", "Public"]
+        );
+        assert!(all.windows(2).all(|pair| {
+            pair[0] < pair[1]
+                && pair[0].recognition_multiplier() < pair[1].recognition_multiplier()
+                && pair[0].fall_multiplier() < pair[1].fall_multiplier()
+        }));
+        assert!((Audience::Private.fall_multiplier() - 1.0).abs() < f64::EPSILON);
+    }
 }
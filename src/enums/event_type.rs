@@ -4,7 +4,7 @@
 //! `EventType` is the primary classification, `EventCategory` maps to theoretical
 //! domains, and `EventTag` provides additional categorization.
 
-use serde::{Deserialize, Serialize};
+use crate::enums::PayloadKind;
 
 /// Primary event classification for compile-time validation.
 ///
@@ -19,7 +19,8 @@ use serde::{Deserialize, Serialize};
 /// let event_type = EventType::SocialExclusion;
 /// assert_eq!(event_type.category(), EventCategory::SocialBelonging);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EventType {
     // Social/Interpersonal
     /// General social exchange.
@@ -61,6 +62,21 @@ pub enum EventType {
     /// Chronosystem-level event.
     HistoricalEvent,
 
+    // Health
+    /// Change in somatic health (illness onset or remission).
+    HealthChange,
+
+    // Physiological
+    /// Lost or poor sleep (raises fatigue, lowers arousal).
+    SleepDeprivation,
+    /// Restorative sleep or rest (lowers fatigue).
+    Rest,
+    /// A meal (raises satiation, calms in proportion to hunger).
+    Feeding,
+    /// Exposure to a predator, attack, or other danger (lowers safety,
+    /// raises arousal).
+    ThreatExposure,
+
     // Internal
     /// Insight or realization.
     Realization,
@@ -169,6 +185,11 @@ impl EventType {
             EventType::PolicyChange => EventCategory::Contextual,
             EventType::ContextTransition => EventCategory::Contextual,
             EventType::HistoricalEvent => EventCategory::Contextual,
+            EventType::HealthChange => EventCategory::Contextual,
+            EventType::SleepDeprivation => EventCategory::Contextual,
+            EventType::Rest => EventCategory::Contextual,
+            EventType::Feeding => EventCategory::Contextual,
+            EventType::ThreatExposure => EventCategory::Contextual,
             EventType::Realization => EventCategory::Contextual,
         }
     }
@@ -243,6 +264,99 @@ impl EventType {
         (tb as u8 + pb as u8 + ac as u8) > 1
     }
 
+    /// Returns a machine-readable summary of this event type's default effects.
+    ///
+    /// The summary is built from the same blueprint table event
+    /// interpretation uses, so it always matches processing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::enums::{EventType, SocialCognitionPath, StatePath};
+    ///
+    /// let summary = EventType::SocialExclusion.blueprint_summary();
+    /// let loneliness = StatePath::SocialCognition(SocialCognitionPath::Loneliness);
+    /// assert!(summary.affected_paths().contains(&loneliness));
+    /// ```
+    #[must_use]
+    pub fn blueprint_summary(&self) -> crate::event::BlueprintSummary {
+        crate::event::BlueprintSummary::for_event_type(*self)
+    }
+
+    /// Returns the payload kinds this event type accepts.
+    ///
+    /// `EventPayload::Empty` is accepted by every event type and is not
+    /// listed. `EventBuilder::build` rejects any other payload whose
+    /// `EventPayload::kind()` is not in this list. Most types accept the
+    /// payload of the same name. A general Interaction also accepts the
+    /// social payloads its effects are read from, and the ITS pathway types
+    /// accept the payloads of the events they specialize (a Bereavement
+    /// carries a Loss).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::enums::{EventType, PayloadKind};
+    ///
+    /// assert_eq!(EventType::Support.allowed_payloads(), [PayloadKind::Support]);
+    /// assert!(EventType::JobLoss
+    ///     .allowed_payloads()
+    ///     .contains(&PayloadKind::RoleExit));
+    /// assert!(EventType::PriorSuicideAttempt.allowed_payloads().is_empty());
+    /// ```
+    #[must_use]
+    pub const fn allowed_payloads(&self) -> &'static [PayloadKind] {
+        match self {
+            EventType::Interaction => &[
+                PayloadKind::Interaction,
+                PayloadKind::Support,
+                PayloadKind::Betrayal,
+                PayloadKind::Conflict,
+                PayloadKind::Concealment,
+                PayloadKind::Disclosure,
+            ],
+            EventType::SocialExclusion
+            | EventType::Rejection
+            | EventType::SocialIsolation
+            | EventType::GroupExclusion => &[PayloadKind::SocialExclusion],
+            EventType::SocialInclusion => &[PayloadKind::SocialInclusion],
+            EventType::BurdenFeedback => &[PayloadKind::BurdenFeedback],
+            EventType::Betrayal => &[PayloadKind::Betrayal],
+            EventType::Support => &[PayloadKind::Support],
+            EventType::Conflict => &[PayloadKind::Conflict],
+            EventType::Violence | EventType::PhysicalInjury => &[PayloadKind::Violence],
+            EventType::Humiliation => &[PayloadKind::Humiliation],
+            EventType::Empowerment => &[PayloadKind::Empowerment],
+            EventType::Achievement => &[PayloadKind::Achievement],
+            EventType::Failure => &[PayloadKind::Failure],
+            EventType::Loss
+            | EventType::Bereavement
+            | EventType::RelationshipEnd
+            | EventType::SuicidalLoss => &[PayloadKind::Loss],
+            EventType::PolicyChange => &[PayloadKind::PolicyChange],
+            EventType::ContextTransition => {
+                &[PayloadKind::ContextTransition, PayloadKind::RoleExit]
+            }
+            EventType::HistoricalEvent => &[PayloadKind::HistoricalEvent],
+            EventType::HealthChange => {
+                &[PayloadKind::HealthCondition, PayloadKind::HealthRemission]
+            }
+            EventType::SleepDeprivation | EventType::Rest => &[PayloadKind::Sleep],
+            EventType::Feeding | EventType::ThreatExposure => &[],
+            EventType::Realization => &[PayloadKind::Realization],
+            EventType::TraumaticExposure => &[PayloadKind::TraumaticExposure],
+            EventType::ShamingEvent => &[PayloadKind::Humiliation, PayloadKind::BurdenFeedback],
+            EventType::FinancialBurden => &[PayloadKind::Loss, PayloadKind::BurdenFeedback],
+            EventType::JobLoss => &[PayloadKind::Loss, PayloadKind::RoleExit],
+            EventType::ChronicIllnessOnset => &[PayloadKind::HealthCondition],
+            EventType::FamilyDiscord => &[PayloadKind::Conflict, PayloadKind::BurdenFeedback],
+            EventType::ChildhoodAbuse | EventType::CombatExposure | EventType::ViolenceExposure => {
+                &[PayloadKind::Violence, PayloadKind::TraumaticExposure]
+            }
+            EventType::NonSuicidalSelfInjury | EventType::PriorSuicideAttempt => &[],
+        }
+    }
+
     /// Returns a human-readable name for this event type.
     #[must_use]
     pub const fn name(&self) -> &'static str {
@@ -263,6 +377,11 @@ impl EventType {
             EventType::PolicyChange => "Policy Change",
             EventType::ContextTransition => "Context Transition",
             EventType::HistoricalEvent => "Historical Event",
+            EventType::HealthChange => "Health Change",
+            EventType::SleepDeprivation => "Sleep Deprivation",
+            EventType::Rest => "Rest",
+            EventType::Feeding => "Feeding",
+            EventType::ThreatExposure => "Threat Exposure",
             EventType::Realization => "Realization",
             EventType::TraumaticExposure => "Traumatic Exposure",
             // TB pathway events
@@ -290,7 +409,7 @@ impl EventType {
 
     /// Returns all event type variants.
     #[must_use]
-    pub const fn all() -> [EventType; 35] {
+    pub const fn all() -> [EventType; 40] {
         [
             EventType::Interaction,
             EventType::SocialExclusion,
@@ -308,6 +427,11 @@ impl EventType {
             EventType::PolicyChange,
             EventType::ContextTransition,
             EventType::HistoricalEvent,
+            EventType::HealthChange,
+            EventType::SleepDeprivation,
+            EventType::Rest,
+            EventType::Feeding,
+            EventType::ThreatExposure,
             EventType::Realization,
             EventType::TraumaticExposure,
             // TB pathway events
@@ -339,7 +463,8 @@ impl EventType {
 /// Maps events to ITS pathways, PAD dimensions, and other theoretical
 /// constructs. This enables compile-time linkage between events and
 /// their effects on psychological state.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EventCategory {
     /// Events affecting belonging (maps to TB - Thwarted Belongingness).
     SocialBelonging,
@@ -391,7 +516,8 @@ impl EventCategory {
 ///
 /// Multiple tags can be applied to a single event for fine-grained
 /// filtering and retrieval.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EventTag {
     /// Event relates to personal matters.
     Personal,
@@ -421,6 +547,15 @@ pub enum EventTag {
     AcuteEvent,
     /// Chronic pattern with slow-decaying impact.
     ChronicPattern,
+    /// Event is known in advance, so arousal and stress build across its
+    /// anticipation window before it happens.
+    Anticipated,
+    /// Event marks a turning point in the entity's life, whatever its
+    /// formative shifts.
+    TurningPoint,
+    /// Event's source holds authority over the target, such as an
+    /// employer, officer, or official.
+    Authority,
 }
 
 impl EventTag {
@@ -442,12 +577,15 @@ impl EventTag {
             EventTag::LowStakes => "Low Stakes",
             EventTag::AcuteEvent => "Acute Event",
             EventTag::ChronicPattern => "Chronic Pattern",
+            EventTag::Anticipated => "Anticipated",
+            EventTag::TurningPoint => "Turning Point",
+            EventTag::Authority => "Authority",
         }
     }
 
     /// Returns all tag variants.
     #[must_use]
-    pub const fn all() -> [EventTag; 14] {
+    pub const fn all() -> [EventTag; 17] {
         [
             EventTag::Personal,
             EventTag::Social,
@@ -463,6 +601,9 @@ impl EventTag {
             EventTag::LowStakes,
             EventTag::AcuteEvent,
             EventTag::ChronicPattern,
+            EventTag::Anticipated,
+            EventTag::TurningPoint,
+            EventTag::Authority,
         ]
     }
 }
@@ -502,7 +643,7 @@ mod tests {
     #[test]
     fn event_type_all_returns_all_variants() {
         let all = EventType::all();
-        assert_eq!(all.len(), 35);
+        assert_eq!(all.len(), 40);
     }
 
     #[test]
@@ -528,7 +669,7 @@ mod tests {
     #[test]
     fn event_tag_all_returns_all_variants() {
         let all = EventTag::all();
-        assert_eq!(all.len(), 14);
+        assert_eq!(all.len(), 17);
     }
 
     #[test]
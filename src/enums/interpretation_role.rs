@@ -0,0 +1,101 @@
+//! How an entity took part in an event it interprets.
+//!
+//! An event lands hardest on its target. Entities listed as witnesses saw
+//! it happen to someone else and feel a fraction of it, scaled by their
+//! empathy, and entities who only heard about it feel less still.
+
+use crate::event::Event;
+use crate::memory::MemorySource;
+use crate::types::EntityId;
+use std::fmt;
+
+/// How an entity took part in the event it is interpreting.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::enums::{EventType, InterpretationRole};
+/// use behavioral_pathways::event::EventBuilder;
+/// use behavioral_pathways::memory::MemorySource;
+/// use behavioral_pathways::types::EntityId;
+///
+/// let victim = EntityId::new("victim").unwrap();
+/// let bystander = EntityId::new("bystander").unwrap();
+/// let assault = EventBuilder::new(EventType::Violence)
+///     .target(victim.clone())
+///     .witness(bystander.clone())
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(InterpretationRole::of(&assault, &victim), InterpretationRole::Target);
+/// assert_eq!(InterpretationRole::of(&assault, &bystander), InterpretationRole::Witness);
+/// assert_eq!(InterpretationRole::Witness.memory_source(), MemorySource::Witness);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InterpretationRole {
+    /// The event happened to the entity.
+    #[default]
+    Target,
+    /// The entity saw the event happen to someone else.
+    Witness,
+    /// The entity heard about the event from someone else.
+    Secondhand,
+}
+
+impl InterpretationRole {
+    /// Returns the role `entity_id` has in `event`.
+    ///
+    /// An entity listed as a witness is a `Witness` unless it is also the
+    /// target. Any other entity interprets the event as its `Target`.
+    #[must_use]
+    pub fn of(event: &Event, entity_id: &EntityId) -> Self {
+        if event.target() != Some(entity_id) && event.is_witnessed_by(entity_id) {
+            InterpretationRole::Witness
+        } else {
+            InterpretationRole::Target
+        }
+    }
+
+    /// Returns true if the entity did not experience the event directly.
+    #[must_use]
+    pub const fn is_observer(&self) -> bool {
+        !matches!(self, InterpretationRole::Target)
+    }
+
+    /// Returns the source of the memories the entity forms of the event.
+    #[must_use]
+    pub const fn memory_source(&self) -> MemorySource {
+        match self {
+            InterpretationRole::Target => MemorySource::Self_,
+            InterpretationRole::Witness => MemorySource::Witness,
+            InterpretationRole::Secondhand => MemorySource::Rumor,
+        }
+    }
+
+    /// Returns the name of this role.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            InterpretationRole::Target => "Target",
+            InterpretationRole::Witness => "Witness",
+            InterpretationRole::Secondhand => "Secondhand",
+        }
+    }
+
+    /// Returns all roles.
+    #[must_use]
+    pub const fn all() -> [InterpretationRole; 3] {
+        [
+            InterpretationRole::Target,
+            InterpretationRole::Witness,
+            InterpretationRole::Secondhand,
+        ]
+    }
+}
+
+impl fmt::Display for InterpretationRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
@@ -4,6 +4,13 @@
 //! (how strongly events affect the entity).
 
 use crate::enums::Species;
+use crate::types::Duration;
+
+/// Human maturity age in years, the anchor for scaling early development.
+const HUMAN_MATURITY: f64 = 25.0;
+
+/// Human lifespan in years, the anchor for scaling senescence.
+pub(crate) const HUMAN_LIFESPAN: f64 = 80.0;
 
 /// Developmental life stage based on age.
 ///
@@ -24,6 +31,7 @@ use crate::enums::Species;
 /// assert_eq!(stage.name(), "Child");
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LifeStage {
     /// Ages 0-12: Highest plasticity, greatest event impact.
     Child,
@@ -64,20 +72,23 @@ impl LifeStage {
 
     /// Determines the life stage from age in years, scaled for the given species.
     ///
-    /// This function scales the human-centric thresholds based on the species'
-    /// maturity age relative to humans (25 years). For example, a dog matures
-    /// at 2 years, so a 2-year-old dog is equivalent to a 25-year-old human
-    /// (a Young Adult), and a 1-year-old dog is roughly equivalent to a
-    /// 12.5-year-old human (a Child).
+    /// Ages are converted to "human-equivalent years" before applying the
+    /// human thresholds. Development up to maturity scales by the species'
+    /// maturity age relative to humans (25 years), so a 2-year-old dog is
+    /// equivalent to a 25-year-old human (a Young Adult). After maturity,
+    /// ages scale by the rest of the species' lifespan relative to the human
+    /// 25-80, so senescence tracks how long the species actually lives.
     ///
     /// # Scaling Formula
     ///
-    /// The age is converted to "human-equivalent years" using:
     /// ```text
-    /// human_equivalent = age_years * (human_maturity / species_maturity)
-    ///                  = age_years * (25 / species.maturity_age_years())
+    /// before maturity: human_equivalent = age * 25 / maturity
+    /// after maturity:  human_equivalent = 25 + (age - maturity) * 55 / (lifespan - maturity)
     /// ```
     ///
+    /// Species whose lifespan does not exceed their maturity age use the
+    /// maturity scaling throughout.
+    ///
     /// # Arguments
     ///
     /// * `species` - The species to scale thresholds for
@@ -97,27 +108,15 @@ impl LifeStage {
     /// // A 1.1-year-old dog = 13.75 human years (Adolescent)
     /// assert_eq!(LifeStage::from_age_years_for_species(&Species::Dog, 1.1), LifeStage::Adolescent);
     ///
+    /// // An 11-year-old dog is near the end of its 12-year lifespan (Elder)
+    /// assert_eq!(LifeStage::from_age_years_for_species(&Species::Dog, 11.0), LifeStage::Elder);
+    ///
     /// // Human uses standard thresholds
     /// assert_eq!(LifeStage::from_age_years_for_species(&Species::Human, 25.0), LifeStage::YoungAdult);
     /// ```
     #[must_use]
     pub fn from_age_years_for_species(species: &Species, age_years: f64) -> Self {
-        const HUMAN_MATURITY: f64 = 25.0;
-
-        let species_maturity = f64::from(species.maturity_age_years());
-
-        // Handle species with zero maturity age (e.g., Mouse with 6-week maturity stored as 0)
-        // Use a small default to avoid division by zero while still giving fast development
-        let effective_maturity = if species_maturity > 0.0 {
-            species_maturity
-        } else {
-            // For species like Mouse where maturity is sub-year, use 0.12 (about 6 weeks)
-            0.12
-        };
-
-        // Scale the age to human-equivalent years
-        let scale_factor = HUMAN_MATURITY / effective_maturity;
-        let human_equivalent_age = age_years * scale_factor;
+        let human_equivalent_age = human_equivalent_age(species, age_years);
 
         // Convert to u16 for the internal helper, clamping to valid range
         let clamped_age = human_equivalent_age.clamp(0.0, f64::from(u16::MAX)) as u16;
@@ -125,6 +124,84 @@ impl LifeStage {
         Self::from_human_equivalent_age(clamped_age)
     }
 
+    /// Returns how far through its life stage an entity of this age is.
+    ///
+    /// Progress is 0.0 at stage entry and approaches 1.0 at the next stage,
+    /// measured in the species' own time. Elder progress runs from Elder
+    /// entry to the species' lifespan and stays at 1.0 beyond it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::enums::{LifeStage, Species};
+    ///
+    /// // Adolescence runs from 13 to 18 for humans
+    /// let progress = LifeStage::progress_for_species(&Species::Human, 15.5);
+    /// assert!((progress - 0.5).abs() < 1e-9);
+    /// ```
+    #[must_use]
+    pub fn progress_for_species(species: &Species, age_years: f64) -> f64 {
+        let stage = Self::from_age_years_for_species(species, age_years);
+        let entry = species_age(species, f64::from(stage.age_range().0));
+        let exit = match stage.next() {
+            Some(next) => species_age(species, f64::from(next.age_range().0)),
+            None => f64::from(species.lifespan_years()),
+        };
+        if exit <= entry {
+            return 1.0;
+        }
+        ((age_years - entry) / (exit - entry)).clamp(0.0, 1.0)
+    }
+
+    /// Returns the time until an entity of this age enters its next stage.
+    ///
+    /// Returns `None` for Elders, which have no next stage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::enums::{LifeStage, Species};
+    /// use behavioral_pathways::types::Duration;
+    ///
+    /// let remaining = LifeStage::time_until_next_stage_for_species(&Species::Human, 16.0);
+    /// assert_eq!(remaining, Some(Duration::years(2)));
+    /// assert_eq!(LifeStage::time_until_next_stage_for_species(&Species::Human, 90.0), None);
+    /// ```
+    #[must_use]
+    pub fn time_until_next_stage_for_species(
+        species: &Species,
+        age_years: f64,
+    ) -> Option<Duration> {
+        let stage = Self::from_age_years_for_species(species, age_years);
+        let next = stage.next()?;
+        let next_entry = species_age(species, f64::from(next.age_range().0));
+        let remaining_years = (next_entry - age_years).max(0.0);
+        let seconds_per_year = Duration::years(1).as_seconds() as f64;
+        Some(Duration::seconds((remaining_years * seconds_per_year).round() as u64))
+    }
+
+    /// Returns the stage after this one, or `None` for Elder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::enums::LifeStage;
+    ///
+    /// assert_eq!(LifeStage::Child.next(), Some(LifeStage::Adolescent));
+    /// assert_eq!(LifeStage::Elder.next(), None);
+    /// ```
+    #[must_use]
+    pub const fn next(&self) -> Option<LifeStage> {
+        match self {
+            LifeStage::Child => Some(LifeStage::Adolescent),
+            LifeStage::Adolescent => Some(LifeStage::YoungAdult),
+            LifeStage::YoungAdult => Some(LifeStage::Adult),
+            LifeStage::Adult => Some(LifeStage::MatureAdult),
+            LifeStage::MatureAdult => Some(LifeStage::Elder),
+            LifeStage::Elder => None,
+        }
+    }
+
     /// Returns the event impact multiplier for this life stage.
     ///
     /// This multiplier affects how strongly events affect the entity.
@@ -232,6 +309,43 @@ impl LifeStage {
     }
 }
 
+/// Returns the species' maturity age in years.
+///
+/// Species like Mouse mature in under a year and store 0; they use 0.12
+/// (about 6 weeks) to avoid division by zero.
+fn effective_maturity(species: &Species) -> f64 {
+    let maturity = f64::from(species.maturity_age_years());
+    if maturity > 0.0 {
+        maturity
+    } else {
+        0.12
+    }
+}
+
+/// Converts a species age to human-equivalent years.
+pub(crate) fn human_equivalent_age(species: &Species, age_years: f64) -> f64 {
+    let maturity = effective_maturity(species);
+    let lifespan = f64::from(species.lifespan_years());
+    if age_years <= maturity || lifespan <= maturity {
+        age_years * HUMAN_MATURITY / maturity
+    } else {
+        HUMAN_MATURITY
+            + (age_years - maturity) * (HUMAN_LIFESPAN - HUMAN_MATURITY) / (lifespan - maturity)
+    }
+}
+
+/// Converts human-equivalent years back to a species age.
+pub(crate) fn species_age(species: &Species, human_years: f64) -> f64 {
+    let maturity = effective_maturity(species);
+    let lifespan = f64::from(species.lifespan_years());
+    if human_years <= HUMAN_MATURITY || lifespan <= maturity {
+        human_years * maturity / HUMAN_MATURITY
+    } else {
+        let adult_span = HUMAN_LIFESPAN - HUMAN_MATURITY;
+        maturity + (human_years - HUMAN_MATURITY) * (lifespan - maturity) / adult_span
+    }
+}
+
 impl std::fmt::Display for LifeStage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.name())
@@ -456,11 +570,81 @@ mod tests {
     }
 
     #[test]
-    fn dog_eight_years_is_elder() {
+    fn dog_eight_years_is_mature_adult() {
         // Dog lifespan is 12 years, maturity at 2
-        // 8-year-old dog = 100 human-equivalent years = Elder
+        // 8-year-old dog = 25 + 6 * 55 / 10 = 58 human-equivalent years
         let stage = LifeStage::from_age_years_for_species(&Species::Dog, 8.0);
-        assert_eq!(stage, LifeStage::Elder);
+        assert_eq!(stage, LifeStage::MatureAdult);
+    }
+
+    #[test]
+    fn senescence_scales_with_lifespan() {
+        // Dog Elder begins at 2 + 46 * 10 / 55 = 10.36 years
+        assert_eq!(
+            LifeStage::from_age_years_for_species(&Species::Dog, 10.3),
+            LifeStage::MatureAdult
+        );
+        assert_eq!(
+            LifeStage::from_age_years_for_species(&Species::Dog, 10.4),
+            LifeStage::Elder
+        );
+        // Mouse Elder begins at 0.12 + 46 * 1.88 / 55 = 1.69 years
+        assert_eq!(
+            LifeStage::from_age_years_for_species(&Species::Mouse, 1.6),
+            LifeStage::MatureAdult
+        );
+        assert_eq!(
+            LifeStage::from_age_years_for_species(&Species::Mouse, 1.7),
+            LifeStage::Elder
+        );
+    }
+
+    #[test]
+    fn species_age_inverts_human_equivalent_age() {
+        for species in [Species::Human, Species::Dog, Species::Mouse, Species::Elephant] {
+            for age in [0.05, 0.5, 1.0, 3.0, 9.0, 40.0] {
+                let round_trip = species_age(&species, human_equivalent_age(&species, age));
+                assert!((round_trip - age).abs() < 1e-9, "{species:?} at {age}");
+            }
+        }
+    }
+
+    #[test]
+    fn progress_resets_at_each_human_boundary() {
+        for stage in LifeStage::all() {
+            let entry = f64::from(stage.age_range().0);
+            assert!(LifeStage::progress_for_species(&Species::Human, entry).abs() < 1e-9);
+            if let Some(next) = stage.next() {
+                let exit = f64::from(next.age_range().0);
+                let near_exit = LifeStage::progress_for_species(&Species::Human, exit - 0.01);
+                assert!(near_exit > 0.99 && near_exit < 1.0, "{stage} at {near_exit}");
+            }
+        }
+        assert!((LifeStage::progress_for_species(&Species::Human, 75.5) - 0.5).abs() < 1e-9);
+        assert!((LifeStage::progress_for_species(&Species::Human, 95.0) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn progress_near_short_lifespan_boundaries() {
+        // Mouse Adolescent spans 13-18 human years = 0.0624-0.0864 mouse years
+        let entry = 13.0 * 0.12 / 25.0;
+        let exit = 18.0 * 0.12 / 25.0;
+        let midway = (entry + exit) / 2.0;
+        assert!((LifeStage::progress_for_species(&Species::Mouse, midway) - 0.5).abs() < 1e-6);
+        let remaining = LifeStage::time_until_next_stage_for_species(&Species::Mouse, midway)
+            .unwrap()
+            .as_years_f64();
+        assert!((remaining - (exit - midway)).abs() < 1e-6);
+
+        // Just before the dog's Elder boundary, little time remains
+        let remaining = LifeStage::time_until_next_stage_for_species(&Species::Dog, 10.3)
+            .unwrap()
+            .as_days_f64();
+        assert!(remaining > 0.0 && remaining < 30.0);
+        assert_eq!(
+            LifeStage::time_until_next_stage_for_species(&Species::Dog, 10.4),
+            None
+        );
     }
 
     #[test]
@@ -577,7 +761,7 @@ mod tests {
     #[test]
     fn very_old_age_is_elder() {
         // Very old ages should be Elder
-        // Dog: 12 years * 12.5 = 150 human years -> Elder
+        // Dog: 12 years = end of lifespan = 80 human years -> Elder
         assert_eq!(
             LifeStage::from_age_years_for_species(&Species::Dog, 12.0),
             LifeStage::Elder
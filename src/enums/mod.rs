@@ -2,8 +2,10 @@
 //!
 //! This module contains enumerations for species, life stages,
 //! personality profiles, relationship types, state access paths,
-//! context access paths, emotions, alerts, reversibility, and events.
+//! context access paths, query paths, emotions, alerts, reversibility,
+//! and events.
 
+mod alert_provenance;
 mod alert_severity;
 mod alert_trigger;
 mod attribution;
@@ -15,8 +17,12 @@ mod emotion;
 mod event_payload;
 mod event_scope;
 mod event_type;
+mod interpretation_role;
 mod life_stage;
+mod path_key;
 mod personality_profile;
+mod query_path;
+mod ramp_shape;
 mod rel_path;
 mod relationship_schema;
 mod reversibility;
@@ -25,6 +31,7 @@ mod state_path;
 mod subsystem_id;
 mod trust_domain;
 
+pub use alert_provenance::AlertProvenance;
 pub use alert_severity::AlertSeverity;
 pub use alert_trigger::{AlertTrigger, ItsAlert, SpiralType};
 pub use attribution::{Attribution, AttributionStability};
@@ -37,13 +44,19 @@ pub use context_path::{
 pub(crate) use developmental_category::DevelopmentalCategory;
 pub use emotion::Emotion;
 pub use event_payload::{
-    EventPayload, HistoricalEventType, HistoricalScope, InteractionTopic, LifeDomain, LossType,
-    PolicyArea, RealizationType, SupportType, TraumaType, WeaponType,
+    Audience, ConditionKind, CourseShape, EventPayload, HistoricalEventType, HistoricalScope,
+    InteractionTopic, LifeDomain, LossType, PayloadKind, PolicyArea, RealizationType, SupportType,
+    TraumaType, WeaponType,
 };
 pub use event_scope::EventScope;
 pub use event_type::{EventCategory, EventTag, EventType};
+pub use interpretation_role::InterpretationRole;
 pub use life_stage::LifeStage;
+pub(crate) use life_stage::{human_equivalent_age, species_age, HUMAN_LIFESPAN};
+pub use path_key::ParsePathError;
 pub use personality_profile::PersonalityProfile;
+pub use query_path::QueryPath;
+pub use ramp_shape::RampShape;
 pub use rel_path::{Direction, DirectionalPath, RelPath, SharedPath, TrustPath};
 pub use relationship_schema::RelationshipSchema;
 pub use reversibility::{ReversibilityError, ReversibilityResult};
@@ -0,0 +1,220 @@
+//! Dotted string keys for typed paths.
+//!
+//! Every state, context, and relationship path has a key made of
+//! snake_case segments joined by dots, such as `mood.valence`,
+//! `microsystem.work_acme.work.workload_stress`, or
+//! `a_to_b.trust.competence`. Keys suit configuration files where a path
+//! must be written as a string; `str::parse` reads a key back, and also
+//! accepts the path's `Display` text.
+
+use std::fmt;
+
+/// Returns the key segment for a human-readable name.
+///
+/// Spaces and hyphens become underscores, a lowercase-to-uppercase step
+/// starts a new word, and the result is lowercase, so `Honesty-Humility`
+/// becomes `honesty_humility` and `SupportWillingness` becomes
+/// `support_willingness`.
+pub(crate) fn segment(name: &str) -> String {
+    let mut key = String::with_capacity(name.len() + 4);
+    let mut previous_lowercase = false;
+    for c in name.chars() {
+        if c == ' ' || c == '-' {
+            key.push('_');
+        } else {
+            if c.is_uppercase() && previous_lowercase {
+                key.push('_');
+            }
+            key.extend(c.to_lowercase());
+        }
+        previous_lowercase = c.is_lowercase();
+    }
+    key
+}
+
+/// Implements `key()`, `from_key()`, and `FromStr` for leaf path enums.
+///
+/// Each listed type needs a `name()` method, and `$all` must list every
+/// variant.
+macro_rules! path_segments {
+    ($($path:ident => $all:expr),* $(,)?) => {
+        $(
+            impl $path {
+                /// Returns the key segment for this path, such as
+                /// `workload_stress`.
+                #[must_use]
+                pub fn key(&self) -> String {
+                    crate::enums::path_key::segment(self.name())
+                }
+
+                /// Returns the path whose key segment is `key`.
+                #[must_use]
+                pub fn from_key(key: &str) -> Option<Self> {
+                    $all.into_iter().find(|path| path.key() == key)
+                }
+            }
+
+            /// Parses the key segment or the human-readable name.
+            impl std::str::FromStr for $path {
+                type Err = crate::enums::ParsePathError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    if s.is_empty() {
+                        return Err(crate::enums::ParsePathError::Empty);
+                    }
+                    Self::from_key(s)
+                        .or_else(|| $all.into_iter().find(|path| path.name() == s))
+                        .ok_or_else(|| crate::enums::ParsePathError::UnknownField {
+                            path: s.to_string(),
+                            field: s.to_string(),
+                        })
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use path_segments;
+
+/// Implements `Display` as the human-readable `name()` for path enums.
+macro_rules! name_display {
+    ($($path:ident),* $(,)?) => {
+        $(
+            impl std::fmt::Display for $path {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    write!(f, "{}", self.name())
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use name_display;
+
+/// Error returned when a string is not a path key or display name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParsePathError {
+    /// The string is empty.
+    Empty,
+    /// The first segment does not name a path group.
+    UnknownGroup {
+        /// The full string parsed.
+        path: String,
+        /// The unrecognized segment.
+        group: String,
+    },
+    /// A segment does not name a dimension of its group.
+    UnknownField {
+        /// The full string parsed.
+        path: String,
+        /// The unrecognized segment.
+        field: String,
+    },
+    /// The path ends before naming a dimension.
+    MissingSegment {
+        /// The full string parsed.
+        path: String,
+    },
+    /// Segments follow a complete path.
+    TrailingSegments {
+        /// The full string parsed.
+        path: String,
+    },
+    /// A microsystem path omits the type and its field exists in more than
+    /// one microsystem type.
+    AmbiguousField {
+        /// The full string parsed.
+        path: String,
+        /// The field shared by several types.
+        field: String,
+    },
+    /// The microsystem ID is invalid.
+    InvalidId {
+        /// The full string parsed.
+        path: String,
+        /// Why the ID was rejected.
+        reason: String,
+    },
+}
+
+impl fmt::Display for ParsePathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParsePathError::Empty => write!(f, "Path is empty"),
+            ParsePathError::UnknownGroup { path, group } => {
+                write!(f, "Unknown path group '{}' in '{}'", group, path)
+            }
+            ParsePathError::UnknownField { path, field } => {
+                write!(f, "Unknown field '{}' in '{}'", field, path)
+            }
+            ParsePathError::MissingSegment { path } => {
+                write!(f, "Path '{}' ends before naming a dimension", path)
+            }
+            ParsePathError::TrailingSegments { path } => {
+                write!(f, "Path '{}' has segments after its dimension", path)
+            }
+            ParsePathError::AmbiguousField { path, field } => write!(
+                f,
+                "Field '{}' in '{}' exists in several microsystem types; name the type",
+                field, path
+            ),
+            ParsePathError::InvalidId { path, reason } => {
+                write!(f, "Invalid microsystem ID in '{}': {}", path, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParsePathError {}
+
+/// Returns the dimension segment of `path`, given the segments after its
+/// group, which must be exactly one.
+pub(crate) fn single_field<'a>(path: &str, rest: &[&'a str]) -> Result<&'a str, ParsePathError> {
+    match rest {
+        [] => Err(ParsePathError::MissingSegment {
+            path: path.to_string(),
+        }),
+        [field] => Ok(field),
+        _ => Err(ParsePathError::TrailingSegments {
+            path: path.to_string(),
+        }),
+    }
+}
+
+/// Returns an `UnknownField` error for `field` in `path`.
+pub(crate) fn unknown_field(path: &str, field: &str) -> ParsePathError {
+    ParsePathError::UnknownField {
+        path: path.to_string(),
+        field: field.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn segments_are_snake_case() {
+        assert_eq!(segment("Valence"), "valence");
+        assert_eq!(
+            segment("Perceived Reciprocal Caring"),
+            "perceived_reciprocal_caring"
+        );
+        assert_eq!(segment("Honesty-Humility"), "honesty_humility");
+        assert_eq!(segment("SupportWillingness"), "support_willingness");
+        assert_eq!(segment("A to B"), "a_to_b");
+    }
+
+    #[test]
+    fn errors_describe_the_failure() {
+        let error = ParsePathError::UnknownField {
+            path: "mood.valance".to_string(),
+            field: "valance".to_string(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Unknown field 'valance' in 'mood.valance'"
+        );
+        assert_eq!(ParsePathError::Empty.to_string(), "Path is empty");
+    }
+}
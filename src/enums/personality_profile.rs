@@ -4,8 +4,6 @@
 //! that can be used to quickly create entities with consistent
 //! behavioral patterns.
 
-use serde::{Deserialize, Serialize};
-
 /// Preset personality archetypes for quick entity creation.
 ///
 /// Each profile represents a common personality pattern with
@@ -23,7 +21,8 @@ use serde::{Deserialize, Serialize};
 /// let profile = PersonalityProfile::Anxious;
 /// assert!(profile.neuroticism() > 0.5);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PersonalityProfile {
     /// Average on all dimensions. A neutral baseline.
     #[default]
@@ -182,6 +181,63 @@ impl PersonalityProfile {
         }
     }
 
+    /// Returns the empathy baseline for this profile (0.0-1.0).
+    ///
+    /// Higher values indicate more concern for others' feelings.
+    #[must_use]
+    pub const fn empathy(&self) -> f32 {
+        match self {
+            PersonalityProfile::Balanced => 0.7,
+            PersonalityProfile::Anxious => 0.7,
+            PersonalityProfile::Avoidant => 0.5,
+            PersonalityProfile::Agreeable => 0.85,
+            PersonalityProfile::Conscientious => 0.7,
+            PersonalityProfile::Neurotic => 0.6,
+            PersonalityProfile::Extraverted => 0.7,
+            PersonalityProfile::Introverted => 0.65,
+            PersonalityProfile::Leader => 0.65,
+            PersonalityProfile::Rebel => 0.5,
+        }
+    }
+
+    /// Returns the aggression baseline for this profile (0.0-1.0).
+    ///
+    /// Higher values indicate a stronger tendency toward hostile responses.
+    #[must_use]
+    pub const fn aggression(&self) -> f32 {
+        match self {
+            PersonalityProfile::Balanced => 0.2,
+            PersonalityProfile::Anxious => 0.2,
+            PersonalityProfile::Avoidant => 0.2,
+            PersonalityProfile::Agreeable => 0.1,
+            PersonalityProfile::Conscientious => 0.15,
+            PersonalityProfile::Neurotic => 0.3,
+            PersonalityProfile::Extraverted => 0.2,
+            PersonalityProfile::Introverted => 0.15,
+            PersonalityProfile::Leader => 0.25,
+            PersonalityProfile::Rebel => 0.4,
+        }
+    }
+
+    /// Returns the trust propensity baseline for this profile (0.0-1.0).
+    ///
+    /// Higher values indicate a greater general willingness to trust others.
+    #[must_use]
+    pub const fn trust_propensity(&self) -> f32 {
+        match self {
+            PersonalityProfile::Balanced => 0.5,
+            PersonalityProfile::Anxious => 0.35,
+            PersonalityProfile::Avoidant => 0.3,
+            PersonalityProfile::Agreeable => 0.7,
+            PersonalityProfile::Conscientious => 0.5,
+            PersonalityProfile::Neurotic => 0.35,
+            PersonalityProfile::Extraverted => 0.6,
+            PersonalityProfile::Introverted => 0.45,
+            PersonalityProfile::Leader => 0.55,
+            PersonalityProfile::Rebel => 0.3,
+        }
+    }
+
     /// Returns a human-readable name for this profile.
     #[must_use]
     pub const fn name(&self) -> &'static str {
@@ -255,6 +311,21 @@ mod tests {
         assert!((profile.honesty_humility() - 0.5).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn balanced_dispositions_match_defaults() {
+        let profile = PersonalityProfile::Balanced;
+        assert!((profile.empathy() - 0.7).abs() < f32::EPSILON);
+        assert!((profile.aggression() - 0.2).abs() < f32::EPSILON);
+        assert!((profile.trust_propensity() - 0.5).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn rebel_is_less_trusting_and_more_aggressive() {
+        let profile = PersonalityProfile::Rebel;
+        assert!(profile.trust_propensity() < PersonalityProfile::Agreeable.trust_propensity());
+        assert!(profile.aggression() > PersonalityProfile::Balanced.aggression());
+    }
+
     #[test]
     fn anxious_has_high_neuroticism() {
         let profile = PersonalityProfile::Anxious;
@@ -291,6 +362,9 @@ mod tests {
             assert!(profile.neuroticism() >= 0.0 && profile.neuroticism() <= 1.0);
             assert!(profile.openness() >= 0.0 && profile.openness() <= 1.0);
             assert!(profile.honesty_humility() >= 0.0 && profile.honesty_humility() <= 1.0);
+            assert!(profile.empathy() >= 0.0 && profile.empathy() <= 1.0);
+            assert!(profile.aggression() >= 0.0 && profile.aggression() <= 1.0);
+            assert!(profile.trust_propensity() >= 0.0 && profile.trust_propensity() <= 1.0);
         }
     }
 
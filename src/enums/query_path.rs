@@ -0,0 +1,125 @@
+//! One path type for every dimension a state query can report.
+//!
+//! `StatePath` names stored and computed state, `Emotion` the octant
+//! emotions derived from mood, and `RelPath` relationship dimensions. A
+//! `QueryPath` wraps any of the three, so a generic consumer such as a
+//! dashboard can list and fetch every dimension through
+//! `ComputedState::query` without knowing where each one lives.
+
+use crate::enums::{Emotion, RelPath, StatePath};
+use std::fmt;
+
+/// A dimension that can be queried by path.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::enums::{Emotion, MoodPath, QueryPath, StatePath};
+///
+/// let valence: QueryPath = StatePath::Mood(MoodPath::Valence).into();
+/// assert_eq!(valence, QueryPath::State(StatePath::Mood(MoodPath::Valence)));
+///
+/// let all = QueryPath::all();
+/// assert!(all.contains(&QueryPath::Emotion(Emotion::Anxious)));
+/// assert!(!all.contains(&QueryPath::Emotion(Emotion::Neutral)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QueryPath {
+    /// A stored or computed state dimension.
+    State(StatePath),
+    /// The intensity (0-1) of an octant emotion derived from mood.
+    Emotion(Emotion),
+    /// A relationship dimension, which needs a relationship to query.
+    Relationship(RelPath),
+}
+
+impl QueryPath {
+    /// Returns every queryable dimension: state paths, then emotions, then
+    /// relationship paths.
+    ///
+    /// `Emotion::Neutral` is left out, since it is a placeholder with no
+    /// intensity.
+    #[must_use]
+    pub fn all() -> Vec<QueryPath> {
+        let mut paths: Vec<QueryPath> =
+            StatePath::all().into_iter().map(QueryPath::State).collect();
+        paths.extend(
+            Emotion::all()
+                .into_iter()
+                .filter(|emotion| !emotion.is_neutral())
+                .map(QueryPath::Emotion),
+        );
+        paths.extend(RelPath::all().into_iter().map(QueryPath::Relationship));
+        paths
+    }
+
+    /// Returns true if the path needs a relationship to query, so
+    /// `ComputedState::query` returns `None` for it.
+    #[must_use]
+    pub const fn is_relationship(&self) -> bool {
+        matches!(self, QueryPath::Relationship(_))
+    }
+}
+
+impl From<StatePath> for QueryPath {
+    fn from(path: StatePath) -> Self {
+        QueryPath::State(path)
+    }
+}
+
+impl From<Emotion> for QueryPath {
+    fn from(emotion: Emotion) -> Self {
+        QueryPath::Emotion(emotion)
+    }
+}
+
+impl From<RelPath> for QueryPath {
+    fn from(path: RelPath) -> Self {
+        QueryPath::Relationship(path)
+    }
+}
+
+impl fmt::Display for QueryPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QueryPath::State(path) => write!(f, "{}", path),
+            QueryPath::Emotion(emotion) => write!(f, "Emotion::{}", emotion),
+            QueryPath::Relationship(path) => write!(f, "Relationship::{}", path),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::{MoodPath, SharedPath};
+    use std::collections::HashSet;
+
+    #[test]
+    fn all_lists_each_dimension_once() {
+        let all = QueryPath::all();
+        let expected = StatePath::all().len() + Emotion::all().len() - 1 + RelPath::all().len();
+        assert_eq!(all.len(), expected);
+        assert_eq!(all.iter().collect::<HashSet<_>>().len(), all.len());
+        assert_eq!(
+            all.iter().filter(|path| path.is_relationship()).count(),
+            RelPath::all().len()
+        );
+    }
+
+    #[test]
+    fn display_names_the_kind_of_dimension() {
+        assert_eq!(
+            QueryPath::from(StatePath::Mood(MoodPath::Valence)).to_string(),
+            StatePath::Mood(MoodPath::Valence).to_string()
+        );
+        assert_eq!(
+            QueryPath::from(Emotion::Anxious).to_string(),
+            "Emotion::Anxious"
+        );
+        assert_eq!(
+            QueryPath::from(RelPath::Shared(SharedPath::Affinity)).to_string(),
+            "Relationship::Shared.Affinity"
+        );
+    }
+}
@@ -0,0 +1,121 @@
+//! Onset profiles for gradual-onset events.
+//!
+//! Some stressors build over weeks or months rather than landing at once
+//! (a deteriorating marriage, a progressive illness). A ramp shape describes
+//! how much of an event's effect has been delivered at each point of its
+//! ramp window.
+
+/// How an event's effect is delivered across its ramp window.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::enums::RampShape;
+///
+/// assert!((RampShape::Linear.delivered_fraction(0.5) - 0.5).abs() < 1e-9);
+/// assert!((RampShape::EaseIn.delivered_fraction(0.5) - 0.25).abs() < 1e-9);
+/// assert!((RampShape::Step.delivered_fraction(0.0) - 1.0).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RampShape {
+    /// The full effect lands at onset, as for an instant event.
+    #[default]
+    Step,
+    /// The effect is delivered at a constant rate across the window.
+    Linear,
+    /// Delivery starts slowly and accelerates toward the end of the window.
+    EaseIn,
+}
+
+impl RampShape {
+    /// Returns the cumulative fraction of the effect delivered at the given
+    /// progress through the ramp window.
+    ///
+    /// Progress is clamped to 0-1. Every shape delivers the full effect by
+    /// the end of the window.
+    #[must_use]
+    pub fn delivered_fraction(&self, progress: f64) -> f64 {
+        let progress = progress.clamp(0.0, 1.0);
+        match self {
+            RampShape::Step => 1.0,
+            RampShape::Linear => progress,
+            RampShape::EaseIn => progress * progress,
+        }
+    }
+
+    /// Returns true if this shape delivers the whole effect at onset.
+    #[must_use]
+    pub const fn is_instant(&self) -> bool {
+        matches!(self, RampShape::Step)
+    }
+
+    /// Returns a human-readable name for this shape.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            RampShape::Step => "Step",
+            RampShape::Linear => "Linear",
+            RampShape::EaseIn => "Ease In",
+        }
+    }
+
+    /// Returns all ramp shapes.
+    #[must_use]
+    pub const fn all() -> [RampShape; 3] {
+        [RampShape::Step, RampShape::Linear, RampShape::EaseIn]
+    }
+}
+
+impl std::fmt::Display for RampShape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_is_step() {
+        assert_eq!(RampShape::default(), RampShape::Step);
+        assert!(RampShape::Step.is_instant());
+        assert!(!RampShape::Linear.is_instant());
+    }
+
+    #[test]
+    fn every_shape_delivers_full_effect_at_end() {
+        for shape in RampShape::all() {
+            assert!((shape.delivered_fraction(1.0) - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn gradual_shapes_start_at_zero() {
+        assert!(RampShape::Linear.delivered_fraction(0.0).abs() < 1e-12);
+        assert!(RampShape::EaseIn.delivered_fraction(0.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn ease_in_lags_linear() {
+        for progress in [0.1, 0.3, 0.5, 0.9] {
+            assert!(
+                RampShape::EaseIn.delivered_fraction(progress)
+                    < RampShape::Linear.delivered_fraction(progress)
+            );
+        }
+    }
+
+    #[test]
+    fn progress_is_clamped() {
+        assert!((RampShape::Linear.delivered_fraction(1.5) - 1.0).abs() < 1e-12);
+        assert!(RampShape::Linear.delivered_fraction(-0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn display_uses_name() {
+        assert_eq!(RampShape::EaseIn.to_string(), "Ease In");
+        assert_eq!(RampShape::Step.to_string(), "Step");
+    }
+}
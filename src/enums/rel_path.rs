@@ -2,6 +2,11 @@
 //!
 //! These enums provide compile-time safety for accessing relationship dimensions.
 //! No magic strings - all paths are typed enums.
+//!
+//! Where a path must be written as a string, use its dotted key, such as
+//! `shared.affinity` or `a_to_b.trust.competence`.
+
+use crate::enums::path_key::{path_segments, segment, single_field, unknown_field, ParsePathError};
 
 /// Direction of a relationship dimension from one entity to another.
 ///
@@ -26,6 +31,12 @@ pub enum Direction {
 }
 
 impl Direction {
+    /// Returns both directions.
+    #[must_use]
+    pub const fn all() -> [Direction; 2] {
+        [Direction::AToB, Direction::BToA]
+    }
+
     /// Returns the opposite direction.
     ///
     /// # Examples
@@ -175,6 +186,59 @@ pub enum DirectionalPath {
 }
 
 impl DirectionalPath {
+    /// Returns every directional path, including computed trust outputs.
+    #[must_use]
+    pub fn all() -> Vec<DirectionalPath> {
+        let mut paths: Vec<DirectionalPath> = TrustPath::all_with_computed()
+            .map(DirectionalPath::Trust)
+            .to_vec();
+        paths.extend([
+            DirectionalPath::Warmth,
+            DirectionalPath::Resentment,
+            DirectionalPath::Dependence,
+            DirectionalPath::Attraction,
+            DirectionalPath::Attachment,
+            DirectionalPath::Jealousy,
+            DirectionalPath::Fear,
+            DirectionalPath::Obligation,
+            DirectionalPath::PerceivedRisk,
+        ]);
+        paths
+    }
+
+    /// Returns the dotted key for this path, such as `warmth` or
+    /// `trust.competence`.
+    #[must_use]
+    pub fn key(&self) -> String {
+        match self {
+            DirectionalPath::Trust(tp) => format!("trust.{}", tp.key()),
+            _ => segment(self.name()),
+        }
+    }
+
+    /// Parses the segments of a directional key. `path` is the full
+    /// string, for errors.
+    fn from_segments(path: &str, segments: &[&str]) -> Result<DirectionalPath, ParsePathError> {
+        match segments {
+            [] => Err(ParsePathError::MissingSegment {
+                path: path.to_string(),
+            }),
+            ["trust", rest @ ..] => {
+                let field = single_field(path, rest)?;
+                TrustPath::from_key(field)
+                    .map(DirectionalPath::Trust)
+                    .ok_or_else(|| unknown_field(path, field))
+            }
+            [field] => DirectionalPath::all()
+                .into_iter()
+                .find(|p| !matches!(p, DirectionalPath::Trust(_)) && p.key() == *field)
+                .ok_or_else(|| unknown_field(path, field)),
+            _ => Err(ParsePathError::TrailingSegments {
+                path: path.to_string(),
+            }),
+        }
+    }
+
     /// Returns a human-readable name for this path.
     #[must_use]
     pub const fn name(&self) -> &'static str {
@@ -286,6 +350,43 @@ pub enum RelPath {
 }
 
 impl RelPath {
+    /// Returns every relationship path: shared paths, each directional path
+    /// in both directions, then the stage.
+    #[must_use]
+    pub fn all() -> Vec<RelPath> {
+        let mut paths: Vec<RelPath> = SharedPath::all().map(RelPath::Shared).to_vec();
+        for direction in Direction::all() {
+            paths.extend(
+                DirectionalPath::all()
+                    .into_iter()
+                    .map(|path| RelPath::Directional(direction, path)),
+            );
+        }
+        paths.push(RelPath::Stage);
+        paths
+    }
+
+    /// Returns the dotted key for this path, such as `shared.affinity`,
+    /// `a_to_b.trust.competence`, or `stage`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::enums::{Direction, DirectionalPath, RelPath};
+    ///
+    /// let path = RelPath::Directional(Direction::BToA, DirectionalPath::Warmth);
+    /// assert_eq!(path.key(), "b_to_a.warmth");
+    /// assert_eq!("b_to_a.warmth".parse::<RelPath>(), Ok(path));
+    /// ```
+    #[must_use]
+    pub fn key(&self) -> String {
+        match self {
+            RelPath::Shared(sp) => format!("shared.{}", sp.key()),
+            RelPath::Directional(dir, dp) => format!("{}.{}", dir.key(), dp.key()),
+            RelPath::Stage => "stage".to_string(),
+        }
+    }
+
     /// Returns a human-readable description of this path.
     #[must_use]
     pub fn description(&self) -> String {
@@ -303,6 +404,71 @@ impl std::fmt::Display for RelPath {
     }
 }
 
+/// Parses a dotted key or the text `Display` writes.
+impl std::str::FromStr for RelPath {
+    type Err = ParsePathError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParsePathError::Empty);
+        }
+        if let Some(path) = RelPath::all()
+            .into_iter()
+            .find(|path| path.to_string() == s)
+        {
+            return Ok(path);
+        }
+
+        let segments: Vec<&str> = s.split('.').collect();
+        match segments[0] {
+            "stage" if segments.len() == 1 => Ok(RelPath::Stage),
+            "stage" => Err(ParsePathError::TrailingSegments {
+                path: s.to_string(),
+            }),
+            "shared" => {
+                let field = single_field(s, &segments[1..])?;
+                SharedPath::from_key(field)
+                    .map(RelPath::Shared)
+                    .ok_or_else(|| unknown_field(s, field))
+            }
+            group => match Direction::from_key(group) {
+                Some(direction) => DirectionalPath::from_segments(s, &segments[1..])
+                    .map(|path| RelPath::Directional(direction, path)),
+                None => Err(ParsePathError::UnknownGroup {
+                    path: s.to_string(),
+                    group: group.to_string(),
+                }),
+            },
+        }
+    }
+}
+
+/// Parses a dotted key such as `trust.competence`, or the human-readable
+/// name.
+impl std::str::FromStr for DirectionalPath {
+    type Err = ParsePathError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParsePathError::Empty);
+        }
+        if let Some(path) = DirectionalPath::all()
+            .into_iter()
+            .find(|path| path.name() == s)
+        {
+            return Ok(path);
+        }
+        let segments: Vec<&str> = s.split('.').collect();
+        DirectionalPath::from_segments(s, &segments)
+    }
+}
+
+path_segments!(
+    Direction => Direction::all(),
+    TrustPath => TrustPath::all_with_computed(),
+    SharedPath => SharedPath::all(),
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -643,4 +809,72 @@ mod tests {
         let debug = format!("{:?}", RelPath::Stage);
         assert!(debug.contains("Stage"));
     }
+
+    #[test]
+    fn every_rel_path_parses_from_its_key_and_display() {
+        let all = RelPath::all();
+        assert_eq!(all.len(), 5 + 2 * 13 + 1);
+        for path in all {
+            assert_eq!(path.key().parse::<RelPath>(), Ok(path));
+            assert_eq!(path.to_string().parse::<RelPath>(), Ok(path));
+        }
+        for path in DirectionalPath::all() {
+            assert_eq!(path.key().parse::<DirectionalPath>(), Ok(path));
+            assert_eq!(path.to_string().parse::<DirectionalPath>(), Ok(path));
+        }
+        for direction in Direction::all() {
+            assert_eq!(direction.to_string().parse::<Direction>(), Ok(direction));
+        }
+    }
+
+    #[test]
+    fn rel_path_keys() {
+        assert_eq!(
+            RelPath::Shared(SharedPath::Affinity).key(),
+            "shared.affinity"
+        );
+        assert_eq!(
+            RelPath::Directional(
+                Direction::AToB,
+                DirectionalPath::Trust(TrustPath::SupportWillingness)
+            )
+            .key(),
+            "a_to_b.trust.support_willingness"
+        );
+        assert_eq!(
+            RelPath::Directional(Direction::BToA, DirectionalPath::PerceivedRisk).key(),
+            "b_to_a.perceived_risk"
+        );
+        assert_eq!(RelPath::Stage.key(), "stage");
+    }
+
+    #[test]
+    fn malformed_rel_keys_are_described() {
+        assert_eq!(
+            "a_to_b.trust".parse::<RelPath>(),
+            Err(ParsePathError::MissingSegment {
+                path: "a_to_b.trust".to_string(),
+            })
+        );
+        assert_eq!(
+            "a_to_b.competence".parse::<RelPath>(),
+            Err(ParsePathError::UnknownField {
+                path: "a_to_b.competence".to_string(),
+                field: "competence".to_string(),
+            })
+        );
+        assert_eq!(
+            "a_to_c.warmth".parse::<RelPath>(),
+            Err(ParsePathError::UnknownGroup {
+                path: "a_to_c.warmth".to_string(),
+                group: "a_to_c".to_string(),
+            })
+        );
+        assert_eq!(
+            "stage.early".parse::<RelPath>(),
+            Err(ParsePathError::TrailingSegments {
+                path: "stage.early".to_string(),
+            })
+        );
+    }
 }
@@ -1,7 +1,11 @@
 //! Relationship schema definitions for structural relationship types.
 //!
 //! Schemas define the overall structure and expected patterns
-//! of relationships, particularly for macrosystem constraints.
+//! of relationships, particularly for macrosystem constraints. Each schema
+//! also has a `RelationshipTemplate` that `Relationship::from_schema` uses
+//! to pre-fill a new relationship.
+
+use crate::relationship::RelationshipTemplate;
 
 /// The structural schema of a relationship.
 ///
@@ -17,6 +21,7 @@
 /// assert!(!schema.is_hierarchical());
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RelationshipSchema {
     /// Equal standing relationship with no hierarchy.
     #[default]
@@ -42,6 +47,18 @@ pub enum RelationshipSchema {
 
     /// Competitive or adversarial relationship.
     Rival,
+
+    /// Brothers or sisters with a long shared history.
+    Sibling,
+
+    /// Colleagues who work together but are not yet close.
+    Coworker,
+
+    /// An adult child (A) and a parent (B) who have grown apart.
+    EstrangedParent,
+
+    /// A user-defined schema with its own template.
+    Custom(RelationshipTemplate),
 }
 
 impl RelationshipSchema {
@@ -57,6 +74,10 @@ impl RelationshipSchema {
             RelationshipSchema::Nuclear => "Nuclear Family",
             RelationshipSchema::Extended => "Extended Family",
             RelationshipSchema::Rival => "Rival",
+            RelationshipSchema::Sibling => "Sibling",
+            RelationshipSchema::Coworker => "Coworker",
+            RelationshipSchema::EstrangedParent => "Estranged Parent",
+            RelationshipSchema::Custom(_) => "Custom",
         }
     }
 
@@ -74,7 +95,11 @@ impl RelationshipSchema {
     pub const fn is_family(&self) -> bool {
         matches!(
             self,
-            RelationshipSchema::Family | RelationshipSchema::Nuclear | RelationshipSchema::Extended
+            RelationshipSchema::Family
+                | RelationshipSchema::Nuclear
+                | RelationshipSchema::Extended
+                | RelationshipSchema::Sibling
+                | RelationshipSchema::EstrangedParent
         )
     }
 
@@ -90,12 +115,16 @@ impl RelationshipSchema {
             RelationshipSchema::Nuclear => "Core family unit (parents and children)",
             RelationshipSchema::Extended => "Extended family network",
             RelationshipSchema::Rival => "Competitive or adversarial dynamic",
+            RelationshipSchema::Sibling => "Siblings with a long shared history",
+            RelationshipSchema::Coworker => "Working relationship between new colleagues",
+            RelationshipSchema::EstrangedParent => "Adult child and estranged parent",
+            RelationshipSchema::Custom(_) => "User-defined relationship template",
         }
     }
 
-    /// Returns all relationship schemas.
+    /// Returns all built-in relationship schemas.
     #[must_use]
-    pub const fn all() -> [RelationshipSchema; 8] {
+    pub const fn all() -> [RelationshipSchema; 11] {
         [
             RelationshipSchema::Peer,
             RelationshipSchema::Mentor,
@@ -105,6 +134,9 @@ impl RelationshipSchema {
             RelationshipSchema::Nuclear,
             RelationshipSchema::Extended,
             RelationshipSchema::Rival,
+            RelationshipSchema::Sibling,
+            RelationshipSchema::Coworker,
+            RelationshipSchema::EstrangedParent,
         ]
     }
 }
@@ -132,7 +164,8 @@ mod tests {
     #[test]
     fn all_schemas() {
         let all = RelationshipSchema::all();
-        assert_eq!(all.len(), 8);
+        assert_eq!(all.len(), 11);
+        assert!(!all.iter().any(|schema| matches!(schema, RelationshipSchema::Custom(_))));
     }
 
     #[test]
@@ -150,9 +183,13 @@ mod tests {
         assert!(RelationshipSchema::Family.is_family());
         assert!(RelationshipSchema::Nuclear.is_family());
         assert!(RelationshipSchema::Extended.is_family());
+        assert!(RelationshipSchema::Sibling.is_family());
+        assert!(RelationshipSchema::EstrangedParent.is_family());
 
         assert!(!RelationshipSchema::Peer.is_family());
         assert!(!RelationshipSchema::Romantic.is_family());
+        assert!(!RelationshipSchema::Coworker.is_family());
+        assert!(!RelationshipSchema::Custom(RelationshipTemplate::default()).is_family());
     }
 
     #[test]
@@ -4,7 +4,7 @@
 //! that determine how quickly psychological processes occur relative to
 //! real time.
 
-use serde::{Deserialize, Serialize};
+use crate::types::Duration;
 
 /// Species type defining biological characteristics that affect psychological processing.
 ///
@@ -32,7 +32,8 @@ use serde::{Deserialize, Serialize};
 /// assert_eq!(dog.lifespan_years(), 12);
 /// assert!((dog.time_scale() - 6.67).abs() < 0.1);
 /// ```
-#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Species {
     /// Standard human psychology with full model support.
     /// Lifespan: 80 years, Maturity: 25 years, Time scale: 1.0x
@@ -71,6 +72,13 @@ pub enum Species {
     /// Lifespan: 2 years, Maturity: 6 weeks, Time scale: 40x
     Mouse,
 
+    /// Robotic entity whose state holds until an event changes it.
+    /// Lifespan: 80 years, Maturity: 0 years, Time scale: 1.0x
+    ///
+    /// Deltas never decay, so a robot's state is exactly what its events
+    /// made it.
+    Robotic,
+
     /// Custom species with user-defined parameters.
     Custom {
         /// Species identifier name.
@@ -108,6 +116,7 @@ impl Species {
             Species::Chimpanzee => 50,
             Species::Crow => 15,
             Species::Mouse => 2,
+            Species::Robotic => 80,
             Species::Custom { lifespan_years, .. } => *lifespan_years,
         }
     }
@@ -137,6 +146,7 @@ impl Species {
             Species::Chimpanzee => 13,
             Species::Crow => 2,
             Species::Mouse => 0, // 6 weeks, represented as 0 years
+            Species::Robotic => 0,
             Species::Custom {
                 maturity_age_years, ..
             } => *maturity_age_years,
@@ -174,6 +184,51 @@ impl Species {
         }
     }
 
+    /// Converts real elapsed time to the psychological time this species
+    /// experiences, scaling it by `time_scale()`.
+    ///
+    /// This is the single conversion behind every species-scaled process:
+    /// state decay, memory consolidation windows and priming, spiral
+    /// accumulation, and the settling of formative shifts. Human time is
+    /// returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::enums::Species;
+    /// use behavioral_pathways::types::Duration;
+    ///
+    /// let week = Duration::weeks(1);
+    /// assert_eq!(Species::Human.psychological_duration(week), week);
+    /// assert_eq!(Species::Mouse.psychological_duration(week), Duration::weeks(40));
+    /// ```
+    #[must_use]
+    pub fn psychological_duration(&self, real: Duration) -> Duration {
+        let time_scale = f64::from(self.time_scale());
+        if time_scale == 1.0 {
+            return real;
+        }
+        Duration::from_millis((real.as_millis() as f64 * time_scale) as u64)
+    }
+
+    /// Returns true if this species' state deltas decay over time.
+    ///
+    /// Only robotic entities hold their state until an event changes it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::enums::Species;
+    ///
+    /// assert!(Species::Human.decays());
+    /// assert!(Species::Mouse.decays());
+    /// assert!(!Species::Robotic.decays());
+    /// ```
+    #[must_use]
+    pub fn decays(&self) -> bool {
+        !matches!(self, Species::Robotic)
+    }
+
     /// Returns the social complexity rating for this species.
     ///
     /// Social complexity affects which subsystems are active:
@@ -204,6 +259,7 @@ impl Species {
             Species::Chimpanzee => 0.9,
             Species::Crow => 0.7,
             Species::Mouse => 0.1,
+            Species::Robotic => 0.0,
             Species::Custom {
                 social_complexity, ..
             } => *social_complexity,
@@ -302,6 +358,7 @@ mod tests {
             Species::Chimpanzee,
             Species::Crow,
             Species::Mouse,
+            Species::Robotic,
         ];
 
         for s in species {
@@ -309,6 +366,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn robotic_species_parameters() {
+        let robot = Species::Robotic;
+        assert_eq!(robot.lifespan_years(), 80);
+        assert_eq!(robot.maturity_age_years(), 0);
+        assert!((robot.time_scale() - 1.0).abs() < f32::EPSILON);
+        assert!(robot.social_complexity().abs() < f32::EPSILON);
+        assert!(!robot.decays());
+    }
+
     #[test]
     fn default_species_is_human() {
         assert_eq!(Species::default(), Species::Human);
@@ -407,4 +474,14 @@ mod tests {
         assert!(custom_debug.contains("Custom"));
         assert!(custom_debug.contains("Parrot"));
     }
+
+    #[test]
+    fn psychological_duration_scales_by_time_scale() {
+        let week = Duration::weeks(1);
+        assert_eq!(Species::Human.psychological_duration(week), week);
+        assert_eq!(Species::Mouse.psychological_duration(week), Duration::weeks(40));
+
+        let dog_hours = Species::Dog.psychological_duration(Duration::days(3)).as_hours();
+        assert!((479..=480).contains(&dog_hours));
+    }
 }
@@ -15,12 +15,20 @@
 //!
 //! // These would be caught at compile time if misspelled
 //! ```
+//!
+//! Where a path must be written as a string, such as in a scenario file,
+//! use its dotted key (`mood.valence`); `str::parse` reads it back.
+
+use crate::enums::path_key::{
+    name_display, path_segments, single_field, unknown_field, ParsePathError,
+};
 
 /// Top-level state access path.
 ///
 /// This is the root enum for accessing any state dimension. Use this
 /// when you need to specify a path to any part of an entity's state.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StatePath {
     /// Path to a HEXACO personality dimension.
     Hexaco(HexacoPath),
@@ -45,7 +53,8 @@ pub enum StatePath {
 }
 
 /// Path to HEXACO personality dimensions.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HexacoPath {
     /// Openness to experience.
     Openness,
@@ -71,6 +80,7 @@ pub enum HexacoPath {
 /// Note: Mood contains ONLY PAD dimensions (valence, arousal, dominance).
 /// Fatigue and stress are physiological states in [`NeedsPath`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MoodPath {
     /// Valence: pleasantness (-1 to +1).
     Valence,
@@ -84,8 +94,10 @@ pub enum MoodPath {
 
 /// Path to needs dimensions.
 ///
-/// These include physiological states (fatigue, stress) and purpose.
+/// These include physiological states (fatigue, stress, satiation,
+/// safety) and purpose.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NeedsPath {
     /// Physical and mental tiredness.
     Fatigue,
@@ -95,12 +107,19 @@ pub enum NeedsPath {
 
     /// Sense of meaning and direction.
     Purpose,
+
+    /// Fullness after eating, the inverse of hunger.
+    Satiation,
+
+    /// Perceived safety from threat.
+    Safety,
 }
 
 /// Path to social cognition dimensions.
 ///
 /// These include beliefs that feed into ITS computations.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SocialCognitionPath {
     /// Social isolation - feeling disconnected.
     Loneliness,
@@ -123,6 +142,7 @@ pub enum SocialCognitionPath {
 /// These include ITS (Interpersonal Theory of Suicide) factors
 /// and other mental health indicators.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MentalHealthPath {
     /// Depression severity.
     Depression,
@@ -160,6 +180,7 @@ pub enum MentalHealthPath {
 
 /// Path to disposition dimensions.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DispositionPath {
     /// Self-regulation capacity.
     ImpulseControl,
@@ -182,6 +203,7 @@ pub enum DispositionPath {
 
 /// Path to person characteristics (PPCT model).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PersonCharacteristicsPath {
     // Resource characteristics
     /// Reasoning and problem-solving ability.
@@ -217,6 +239,34 @@ pub enum PersonCharacteristicsPath {
     Force,
 }
 
+impl StatePath {
+    /// Returns every state path, grouped by top-level dimension.
+    #[must_use]
+    pub fn all() -> Vec<StatePath> {
+        let mut paths = Vec::new();
+        paths.extend(HexacoPath::all().map(StatePath::Hexaco));
+        paths.extend(MoodPath::all().map(StatePath::Mood));
+        paths.extend(NeedsPath::all().map(StatePath::Needs));
+        paths.extend(SocialCognitionPath::all().map(StatePath::SocialCognition));
+        paths.extend(MentalHealthPath::all().map(StatePath::MentalHealth));
+        paths.extend(DispositionPath::all().map(StatePath::Disposition));
+        paths.extend(PersonCharacteristicsPath::all().map(StatePath::PersonCharacteristics));
+        paths
+    }
+
+    /// Returns the (min, max) bounds of this path's effective value.
+    ///
+    /// HEXACO factors and mood are bipolar (-1 to 1); every other
+    /// dimension is unipolar (0 to 1).
+    #[must_use]
+    pub const fn bounds(&self) -> (f64, f64) {
+        match self {
+            StatePath::Hexaco(_) | StatePath::Mood(_) => (-1.0, 1.0),
+            _ => (0.0, 1.0),
+        }
+    }
+}
+
 // Implement name methods for each path enum
 
 impl HexacoPath {
@@ -268,8 +318,14 @@ impl MoodPath {
 impl NeedsPath {
     /// Returns all Needs path variants.
     #[must_use]
-    pub const fn all() -> [NeedsPath; 3] {
-        [NeedsPath::Fatigue, NeedsPath::Stress, NeedsPath::Purpose]
+    pub const fn all() -> [NeedsPath; 5] {
+        [
+            NeedsPath::Fatigue,
+            NeedsPath::Stress,
+            NeedsPath::Purpose,
+            NeedsPath::Satiation,
+            NeedsPath::Safety,
+        ]
     }
 
     /// Returns a human-readable name for this path.
@@ -279,6 +335,8 @@ impl NeedsPath {
             NeedsPath::Fatigue => "Fatigue",
             NeedsPath::Stress => "Stress",
             NeedsPath::Purpose => "Purpose",
+            NeedsPath::Satiation => "Satiation",
+            NeedsPath::Safety => "Safety",
         }
     }
 }
@@ -428,6 +486,11 @@ impl PersonCharacteristicsPath {
     }
 }
 
+/// Displays the canonical name of the path, such as `Mood::Valence` or
+/// `SocialCognition::Perceived Reciprocal Caring`.
+///
+/// The canonical name is stable across runs and releases, so it can key
+/// exported columns and saved files. `str::parse` reads it back.
 impl std::fmt::Display for StatePath {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -442,10 +505,127 @@ impl std::fmt::Display for StatePath {
     }
 }
 
+impl StatePath {
+    /// Returns the dotted key for this path, such as `mood.valence` or
+    /// `social_cognition.perceived_reciprocal_caring`.
+    ///
+    /// Keys are meant for configuration files; `str::parse` reads them back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::enums::{MoodPath, StatePath};
+    ///
+    /// let path = StatePath::Mood(MoodPath::Valence);
+    /// assert_eq!(path.key(), "mood.valence");
+    /// assert_eq!("mood.valence".parse::<StatePath>(), Ok(path));
+    /// ```
+    #[must_use]
+    pub fn key(&self) -> String {
+        match self {
+            StatePath::Hexaco(p) => format!("hexaco.{}", p.key()),
+            StatePath::Mood(p) => format!("mood.{}", p.key()),
+            StatePath::Needs(p) => format!("needs.{}", p.key()),
+            StatePath::SocialCognition(p) => format!("social_cognition.{}", p.key()),
+            StatePath::MentalHealth(p) => format!("mental_health.{}", p.key()),
+            StatePath::Disposition(p) => format!("disposition.{}", p.key()),
+            StatePath::PersonCharacteristics(p) => format!("person_characteristics.{}", p.key()),
+        }
+    }
+}
+
+/// Parses a dotted key such as `mood.valence`, or the canonical name
+/// `Display` writes.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::enums::{MoodPath, ParsePathError, StatePath};
+///
+/// let path: StatePath = "Mood::Valence".parse().unwrap();
+/// assert_eq!(path, StatePath::Mood(MoodPath::Valence));
+/// assert_eq!(
+///     "mood.valance".parse::<StatePath>(),
+///     Err(ParsePathError::UnknownField {
+///         path: "mood.valance".to_string(),
+///         field: "valance".to_string(),
+///     })
+/// );
+/// ```
+impl std::str::FromStr for StatePath {
+    type Err = ParsePathError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParsePathError::Empty);
+        }
+        if let Some(path) = StatePath::all()
+            .into_iter()
+            .find(|path| path.to_string() == s)
+        {
+            return Ok(path);
+        }
+
+        let segments: Vec<&str> = s.split('.').collect();
+        let lookup: fn(&str) -> Option<StatePath> = match segments[0] {
+            "hexaco" => |f| HexacoPath::from_key(f).map(StatePath::Hexaco),
+            "mood" => |f| MoodPath::from_key(f).map(StatePath::Mood),
+            "needs" => |f| NeedsPath::from_key(f).map(StatePath::Needs),
+            "social_cognition" => {
+                |f| SocialCognitionPath::from_key(f).map(StatePath::SocialCognition)
+            }
+            "mental_health" => |f| MentalHealthPath::from_key(f).map(StatePath::MentalHealth),
+            "disposition" => |f| DispositionPath::from_key(f).map(StatePath::Disposition),
+            "person_characteristics" => {
+                |f| PersonCharacteristicsPath::from_key(f).map(StatePath::PersonCharacteristics)
+            }
+            group => {
+                return Err(ParsePathError::UnknownGroup {
+                    path: s.to_string(),
+                    group: group.to_string(),
+                })
+            }
+        };
+        let field = single_field(s, &segments[1..])?;
+        lookup(field).ok_or_else(|| unknown_field(s, field))
+    }
+}
+
+path_segments!(
+    HexacoPath => HexacoPath::all(),
+    MoodPath => MoodPath::all(),
+    NeedsPath => NeedsPath::all(),
+    SocialCognitionPath => SocialCognitionPath::all(),
+    MentalHealthPath => MentalHealthPath::all(),
+    DispositionPath => DispositionPath::all(),
+    PersonCharacteristicsPath => PersonCharacteristicsPath::all(),
+);
+
+name_display!(
+    HexacoPath,
+    MoodPath,
+    NeedsPath,
+    SocialCognitionPath,
+    MentalHealthPath,
+    DispositionPath,
+    PersonCharacteristicsPath,
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn state_path_bounds_match_polarity() {
+        assert_eq!(StatePath::Mood(MoodPath::Valence).bounds(), (-1.0, 1.0));
+        assert_eq!(StatePath::Hexaco(HexacoPath::Openness).bounds(), (-1.0, 1.0));
+        assert_eq!(
+            StatePath::SocialCognition(SocialCognitionPath::Loneliness).bounds(),
+            (0.0, 1.0)
+        );
+        crate::contract_tests::assert_path_ranges();
+    }
+
     #[test]
     fn state_path_mood_variants_exist() {
         // Verify MoodPath variants compile
@@ -463,6 +643,8 @@ mod tests {
         let _ = NeedsPath::Stress;
         let _ = NeedsPath::Fatigue;
         let _ = NeedsPath::Purpose;
+        let _ = NeedsPath::Satiation;
+        let _ = NeedsPath::Safety;
 
         // Verify they can be wrapped in StatePath
         let _ = StatePath::Needs(NeedsPath::Stress);
@@ -511,7 +693,7 @@ mod tests {
     #[test]
     fn needs_path_all() {
         let all = NeedsPath::all();
-        assert_eq!(all.len(), 3);
+        assert_eq!(all.len(), 5);
     }
 
     #[test]
@@ -587,6 +769,69 @@ mod tests {
         assert!(display.contains("Valence"));
     }
 
+    #[test]
+    fn every_path_parses_from_its_key_and_display_name() {
+        for path in StatePath::all() {
+            assert_eq!(path.key().parse::<StatePath>(), Ok(path));
+            assert_eq!(path.to_string().parse::<StatePath>(), Ok(path));
+        }
+        let caring = StatePath::SocialCognition(SocialCognitionPath::PerceivedReciprocalCaring);
+        assert_eq!(
+            caring.to_string(),
+            "SocialCognition::Perceived Reciprocal Caring"
+        );
+        assert_eq!(caring.key(), "social_cognition.perceived_reciprocal_caring");
+        assert_eq!(
+            StatePath::Hexaco(HexacoPath::HonestyHumility).key(),
+            "hexaco.honesty_humility"
+        );
+    }
+
+    #[test]
+    fn nested_paths_parse_from_key_and_name() {
+        for path in MentalHealthPath::all() {
+            assert_eq!(path.key().parse::<MentalHealthPath>(), Ok(path));
+            assert_eq!(path.to_string().parse::<MentalHealthPath>(), Ok(path));
+        }
+        assert_eq!(
+            "self_worth".parse::<MentalHealthPath>(),
+            Ok(MentalHealthPath::SelfWorth)
+        );
+        assert_eq!(MoodPath::Arousal.to_string(), "Arousal");
+        assert_eq!("".parse::<MoodPath>(), Err(ParsePathError::Empty));
+    }
+
+    #[test]
+    fn malformed_state_keys_are_described() {
+        assert_eq!("".parse::<StatePath>(), Err(ParsePathError::Empty));
+        assert_eq!(
+            "moods.valence".parse::<StatePath>(),
+            Err(ParsePathError::UnknownGroup {
+                path: "moods.valence".to_string(),
+                group: "moods".to_string(),
+            })
+        );
+        assert_eq!(
+            "mood".parse::<StatePath>(),
+            Err(ParsePathError::MissingSegment {
+                path: "mood".to_string(),
+            })
+        );
+        assert_eq!(
+            "mood.valence.x".parse::<StatePath>(),
+            Err(ParsePathError::TrailingSegments {
+                path: "mood.valence.x".to_string(),
+            })
+        );
+        assert_eq!(
+            "mood.Valence".parse::<StatePath>(),
+            Err(ParsePathError::UnknownField {
+                path: "mood.Valence".to_string(),
+                field: "Valence".to_string(),
+            })
+        );
+    }
+
     #[test]
     fn paths_are_copy() {
         let p1 = MoodPath::Valence;
@@ -649,6 +894,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn state_path_all_covers_every_dimension() {
+        let all = StatePath::all();
+        assert_eq!(all.len(), 6 + 3 + 5 + 5 + 9 + 6 + 10);
+        assert!(all.contains(&StatePath::Needs(NeedsPath::Purpose)));
+        assert!(all.contains(&StatePath::Needs(NeedsPath::Safety)));
+        assert!(all.contains(&StatePath::PersonCharacteristics(
+            PersonCharacteristicsPath::Force
+        )));
+    }
+
     #[test]
     fn all_hexaco_names() {
         for p in HexacoPath::all() {
@@ -681,6 +937,8 @@ mod tests {
                 NeedsPath::Fatigue => assert_eq!(p.name(), "Fatigue"),
                 NeedsPath::Stress => assert_eq!(p.name(), "Stress"),
                 NeedsPath::Purpose => assert_eq!(p.name(), "Purpose"),
+                NeedsPath::Satiation => assert_eq!(p.name(), "Satiation"),
+                NeedsPath::Safety => assert_eq!(p.name(), "Safety"),
             }
         }
     }
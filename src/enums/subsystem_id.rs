@@ -4,8 +4,6 @@
 //! Different entity types may have different active subsystems based on
 //! their psychological complexity.
 
-use serde::{Deserialize, Serialize};
-
 /// Identifies a subsystem that can be active or inactive for an entity.
 ///
 /// Subsystems are modular components that handle specific aspects of
@@ -19,7 +17,8 @@ use serde::{Deserialize, Serialize};
 /// let active = vec![SubsystemId::State, SubsystemId::Memory, SubsystemId::Relationship];
 /// assert!(active.contains(&SubsystemId::State));
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SubsystemId {
     /// State system: decay, drift, feedback loops, threshold checks.
     ///
@@ -5,6 +5,7 @@
 
 /// Trust domains for willingness decisions.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TrustDomain {
     /// Willingness to delegate tasks.
     Task,
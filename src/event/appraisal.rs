@@ -0,0 +1,217 @@
+//! Subjective appraisal of event severity.
+//!
+//! An event's objective severity is how severe it is to an outside
+//! observer; its subjective severity is how severe the target takes it to
+//! be. Interpretation always uses the subjective value. When an event
+//! carries only an objective severity, the subjective value is appraised
+//! from personality:
+//!
+//! - Emotionality amplifies threatening (negative) events
+//! - Extraversion, the HEXACO factor closest to dispositional optimism,
+//!   softens negative events and amplifies positive ones
+//!
+//! The gap between the two values is informative in itself, so both are
+//! kept for inspection.
+
+use crate::entity::Entity;
+use crate::event::{base_impact, Event};
+
+/// Largest gap between subjective and objective severity considered
+/// plausible.
+///
+/// Personality-based appraisal stays well inside this bound, so an
+/// explicit pair of values further apart is more likely a data-entry
+/// error than a real appraisal difference.
+pub const IMPLAUSIBLE_APPRAISAL_GAP: f64 = 0.5;
+
+/// How strongly Emotionality amplifies the appraisal of negative events.
+const EMOTIONALITY_WEIGHT: f64 = 0.25;
+
+/// How strongly optimism (Extraversion) shifts the appraisal.
+const OPTIMISM_WEIGHT: f64 = 0.25;
+
+/// The objective and subjective severity of an event for one entity.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::simulation::Simulation;
+/// use behavioral_pathways::entity::EntityBuilder;
+/// use behavioral_pathways::event::EventBuilder;
+/// use behavioral_pathways::enums::{EventType, Species};
+/// use behavioral_pathways::state::Hexaco;
+/// use behavioral_pathways::types::Timestamp;
+///
+/// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+/// let mut sim = Simulation::new(reference);
+/// let anxious = EntityBuilder::new()
+///     .id("person_001")
+///     .species(Species::Human)
+///     .hexaco(Hexaco::new().with_neuroticism(0.8))
+///     .build()
+///     .unwrap();
+/// let id = sim.add_entity(anxious, reference);
+///
+/// let layoff = EventBuilder::new(EventType::Loss).objective_severity(0.5).build().unwrap();
+/// let appraisal = sim.entity(&id).unwrap().severity_appraisal(&layoff);
+/// assert!(appraisal.is_appraised());
+/// assert!(appraisal.gap().unwrap() > 0.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SeverityAppraisal {
+    /// Objective severity, if the event carries one.
+    objective: Option<f64>,
+    /// Severity as experienced by the entity.
+    subjective: f64,
+    /// Whether the subjective severity was derived from the objective one.
+    appraised: bool,
+}
+
+impl SeverityAppraisal {
+    /// Returns the objective severity, if the event carries one.
+    #[must_use]
+    pub fn objective(&self) -> Option<f64> {
+        self.objective
+    }
+
+    /// Returns the subjective severity used for interpretation.
+    #[must_use]
+    pub fn subjective(&self) -> f64 {
+        self.subjective
+    }
+
+    /// Returns true if the subjective severity was appraised from the
+    /// objective severity and the entity's personality.
+    #[must_use]
+    pub fn is_appraised(&self) -> bool {
+        self.appraised
+    }
+
+    /// Returns subjective minus objective severity, if the event carries
+    /// an objective severity.
+    ///
+    /// Positive values mean the entity experiences the event as more
+    /// severe than it objectively is.
+    #[must_use]
+    pub fn gap(&self) -> Option<f64> {
+        self.objective.map(|objective| self.subjective - objective)
+    }
+
+    /// Returns true if the gap exceeds `IMPLAUSIBLE_APPRAISAL_GAP`.
+    #[must_use]
+    pub fn is_implausible(&self) -> bool {
+        self.gap()
+            .is_some_and(|gap| gap.abs() > IMPLAUSIBLE_APPRAISAL_GAP)
+    }
+}
+
+/// Computes the entity's appraisal of an event's severity.
+///
+/// An explicit subjective severity is used as given. Otherwise the
+/// objective severity is scaled by the entity's appraisal tendency.
+pub(crate) fn appraise_severity(event: &Event, entity: &Entity) -> SeverityAppraisal {
+    let objective = event.objective_severity();
+    let subjective = match objective {
+        Some(objective) if event.is_severity_appraised() => {
+            (objective * appraisal_factor(event, entity)).clamp(0.0, 1.0)
+        }
+        _ => event.severity(),
+    };
+
+    SeverityAppraisal {
+        objective,
+        subjective,
+        appraised: event.is_severity_appraised(),
+    }
+}
+
+/// Returns the multiplier from objective to subjective severity.
+fn appraisal_factor(event: &Event, entity: &Entity) -> f64 {
+    let hexaco = entity.individual_state().hexaco();
+    let emotionality = f64::from(hexaco.emotionality());
+    let optimism = f64::from(hexaco.extraversion());
+    let valence = base_impact(event.category(), event.event_type()).valence;
+
+    if valence > 0.0 {
+        1.0 + OPTIMISM_WEIGHT * optimism
+    } else {
+        1.0 + EMOTIONALITY_WEIGHT * emotionality - OPTIMISM_WEIGHT * optimism
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::EntityBuilder;
+    use crate::enums::{EventType, Species};
+    use crate::event::EventBuilder;
+    use crate::state::Hexaco;
+
+    fn person(hexaco: Hexaco) -> Entity {
+        EntityBuilder::new()
+            .species(Species::Human)
+            .hexaco(hexaco)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn subjective_only_is_used_as_given() {
+        let event = EventBuilder::new(EventType::Loss)
+            .severity(0.6)
+            .build()
+            .unwrap();
+        let appraisal = appraise_severity(&event, &person(Hexaco::new().with_neuroticism(0.9)));
+
+        assert!((appraisal.subjective() - 0.6).abs() < f64::EPSILON);
+        assert_eq!(appraisal.objective(), None);
+        assert_eq!(appraisal.gap(), None);
+        assert!(!appraisal.is_appraised());
+        assert!(!appraisal.is_implausible());
+    }
+
+    #[test]
+    fn objective_only_is_appraised_through_personality() {
+        let event = EventBuilder::new(EventType::Loss)
+            .objective_severity(0.5)
+            .build()
+            .unwrap();
+        let anxious = appraise_severity(&event, &person(Hexaco::new().with_neuroticism(0.8)));
+        let optimist = appraise_severity(&event, &person(Hexaco::new().with_extraversion(0.8)));
+        let neutral = appraise_severity(&event, &person(Hexaco::new()));
+
+        assert!(anxious.is_appraised());
+        assert!(anxious.subjective() > 0.5);
+        assert!(optimist.subjective() < 0.5);
+        assert!((neutral.subjective() - 0.5).abs() < 1e-9);
+        assert!(anxious.gap().unwrap() > 0.0);
+        assert!(optimist.gap().unwrap() < 0.0);
+        assert!(!anxious.is_implausible());
+    }
+
+    #[test]
+    fn optimism_amplifies_positive_events() {
+        let event = EventBuilder::new(EventType::Achievement)
+            .objective_severity(0.5)
+            .build()
+            .unwrap();
+        let optimist = appraise_severity(&event, &person(Hexaco::new().with_extraversion(0.8)));
+
+        assert!(optimist.subjective() > 0.5);
+    }
+
+    #[test]
+    fn both_values_keep_the_given_gap() {
+        let event = EventBuilder::new(EventType::Loss)
+            .objective_severity(0.2)
+            .severity(0.9)
+            .build()
+            .unwrap();
+        let appraisal = appraise_severity(&event, &person(Hexaco::new()));
+
+        assert!(!appraisal.is_appraised());
+        assert!((appraisal.subjective() - 0.9).abs() < f64::EPSILON);
+        assert!((appraisal.gap().unwrap() - 0.7).abs() < 1e-9);
+        assert!(appraisal.is_implausible());
+    }
+}
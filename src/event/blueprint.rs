@@ -0,0 +1,373 @@
+//! Default state-effect blueprints for event types.
+//!
+//! Each event type has a blueprint: the per-unit-severity deltas it applies
+//! to a neutral entity when it carries no payload. Event interpretation reads
+//! its base impacts from this table, and [`BlueprintSummary`] exposes the
+//! same values publicly, so the documented effects cannot drift from the
+//! processed ones.
+//!
+//! Blueprints describe the unmodulated baseline only. Personality (HEXACO
+//! Emotionality and Agreeableness), attribution, payload details, and
+//! developmental stage all scale or extend these deltas at interpretation time.
+
+use crate::enums::{
+    DispositionPath, EventCategory, EventType, MentalHealthPath, MoodPath, NeedsPath,
+    ReversibilityResult, SocialCognitionPath, StatePath,
+};
+use crate::processor::impact::{
+    BURDEN_LIABILITY, CONTROL_DOMINANCE, EXCLUSION_LONELINESS, FEEDING_AROUSAL, FEEDING_SATIATION,
+    FEEDING_VALENCE, HIGH_AROUSAL, INCLUSION_LONELINESS, NEGATIVE_VALENCE, POSITIVE_VALENCE,
+    REST_AROUSAL, REST_FATIGUE, SELF_HATE, SLEEP_LOSS_AROUSAL, SLEEP_LOSS_FATIGUE, THREAT_SAFETY,
+    TRAUMA_AC,
+};
+
+/// Per-unit-severity base impact of an event before modulation.
+///
+/// `self_worth` and `grievance` are the event-specific secondary effects
+/// (Loss and Conflict) that scale with Emotionality but not with the
+/// payload.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) struct BaseImpact {
+    pub valence: f32,
+    pub arousal: f32,
+    pub dominance: f32,
+    pub loneliness: f32,
+    pub prc: f32,
+    pub perceived_liability: f32,
+    pub self_worth: f32,
+    pub grievance: f32,
+    pub acquired_capability: f32,
+    pub self_hate: f32,
+    pub fatigue: f32,
+    pub satiation: f32,
+    pub safety: f32,
+}
+
+/// Returns the blueprint for an event type within a processing category.
+///
+/// The category is normally `event_type.category()`. Event types that are
+/// not handled by their category's blueprint (such as `Interaction`, whose
+/// effects come entirely from its payload) return a zero impact.
+pub(crate) const fn base_impact(category: EventCategory, event_type: EventType) -> BaseImpact {
+    let zero = BaseImpact {
+        valence: 0.0,
+        arousal: 0.0,
+        dominance: 0.0,
+        loneliness: 0.0,
+        prc: 0.0,
+        perceived_liability: 0.0,
+        self_worth: 0.0,
+        grievance: 0.0,
+        acquired_capability: 0.0,
+        self_hate: 0.0,
+        fatigue: 0.0,
+        satiation: 0.0,
+        safety: 0.0,
+    };
+    match category {
+        // TB pathway
+        EventCategory::SocialBelonging => match event_type {
+            EventType::SocialExclusion => BaseImpact {
+                valence: NEGATIVE_VALENCE,
+                loneliness: EXCLUSION_LONELINESS,
+                prc: -0.1,
+                ..zero
+            },
+            EventType::SocialInclusion => BaseImpact {
+                valence: POSITIVE_VALENCE,
+                loneliness: INCLUSION_LONELINESS,
+                prc: 0.1,
+                ..zero
+            },
+            _ => zero,
+        },
+        // PB pathway
+        EventCategory::BurdenPerception => BaseImpact {
+            valence: NEGATIVE_VALENCE,
+            perceived_liability: BURDEN_LIABILITY,
+            self_hate: SELF_HATE * 0.5,
+            ..zero
+        },
+        // AC pathway - NEVER decays
+        EventCategory::Trauma => BaseImpact {
+            valence: NEGATIVE_VALENCE,
+            arousal: HIGH_AROUSAL,
+            acquired_capability: TRAUMA_AC,
+            ..zero
+        },
+        EventCategory::Control => match event_type {
+            EventType::Humiliation => BaseImpact {
+                valence: NEGATIVE_VALENCE,
+                dominance: -CONTROL_DOMINANCE,
+                ..zero
+            },
+            EventType::Empowerment => BaseImpact {
+                valence: POSITIVE_VALENCE,
+                dominance: CONTROL_DOMINANCE,
+                ..zero
+            },
+            _ => zero,
+        },
+        EventCategory::Achievement => match event_type {
+            EventType::Achievement => BaseImpact {
+                valence: POSITIVE_VALENCE,
+                dominance: 0.1,
+                ..zero
+            },
+            EventType::Failure => BaseImpact {
+                valence: NEGATIVE_VALENCE,
+                dominance: -0.1,
+                ..zero
+            },
+            // Loss events (job loss, significant loss, death, etc.)
+            // Spec: spec/subsystems/event-system.md Loss entry
+            EventType::Loss => BaseImpact {
+                valence: -0.15,
+                arousal: 0.10,
+                dominance: -0.10,
+                self_worth: -0.15,
+                grievance: 0.05,
+                ..zero
+            },
+            _ => zero,
+        },
+        EventCategory::Social => match event_type {
+            // Spec: spec/subsystems/event-system.md Conflict entry
+            EventType::Conflict => BaseImpact {
+                valence: -0.10,
+                arousal: 0.12,
+                dominance: -0.12,
+                loneliness: 0.08,
+                perceived_liability: 0.04,
+                self_worth: -0.06,
+                grievance: 0.04,
+                ..zero
+            },
+            // Support without payload uses the blueprint
+            // Spec: spec/subsystems/event-system.md Support entry
+            EventType::Support => BaseImpact {
+                valence: 0.08,
+                loneliness: -0.20,
+                perceived_liability: -0.10,
+                ..zero
+            },
+            _ => zero,
+        },
+        EventCategory::Contextual => match event_type {
+            // Physiological events act on fatigue, satiation, and safety
+            EventType::SleepDeprivation => BaseImpact {
+                arousal: SLEEP_LOSS_AROUSAL,
+                fatigue: SLEEP_LOSS_FATIGUE,
+                ..zero
+            },
+            EventType::Rest => BaseImpact {
+                arousal: REST_AROUSAL,
+                fatigue: REST_FATIGUE,
+                ..zero
+            },
+            EventType::Feeding => BaseImpact {
+                valence: FEEDING_VALENCE,
+                arousal: FEEDING_AROUSAL,
+                satiation: FEEDING_SATIATION,
+                ..zero
+            },
+            EventType::ThreatExposure => BaseImpact {
+                valence: NEGATIVE_VALENCE,
+                arousal: HIGH_AROUSAL,
+                safety: THREAT_SAFETY,
+                ..zero
+            },
+            // Environmental events - minimal direct state impact
+            _ => BaseImpact {
+                arousal: 0.1,
+                ..zero
+            },
+        },
+    }
+}
+
+impl BaseImpact {
+    /// Returns the non-zero deltas as state paths, in interpretation order.
+    fn state_deltas(&self) -> Vec<(StatePath, f64)> {
+        let entries = [
+            (StatePath::Mood(MoodPath::Valence), self.valence),
+            (StatePath::Mood(MoodPath::Arousal), self.arousal),
+            (StatePath::Mood(MoodPath::Dominance), self.dominance),
+            (
+                StatePath::SocialCognition(SocialCognitionPath::Loneliness),
+                self.loneliness,
+            ),
+            (
+                StatePath::SocialCognition(SocialCognitionPath::PerceivedReciprocalCaring),
+                self.prc,
+            ),
+            (
+                StatePath::SocialCognition(SocialCognitionPath::PerceivedLiability),
+                self.perceived_liability,
+            ),
+            (
+                StatePath::SocialCognition(SocialCognitionPath::SelfHate),
+                self.self_hate,
+            ),
+            (
+                StatePath::MentalHealth(MentalHealthPath::AcquiredCapability),
+                self.acquired_capability,
+            ),
+            (
+                StatePath::MentalHealth(MentalHealthPath::SelfWorth),
+                self.self_worth,
+            ),
+            (
+                StatePath::Disposition(DispositionPath::Grievance),
+                self.grievance,
+            ),
+            (StatePath::Needs(NeedsPath::Fatigue), self.fatigue),
+            (StatePath::Needs(NeedsPath::Satiation), self.satiation),
+            (StatePath::Needs(NeedsPath::Safety), self.safety),
+        ];
+        entries
+            .into_iter()
+            .filter(|(_, delta)| delta.abs() > f32::EPSILON)
+            .map(|(path, delta)| (path, f64::from(delta)))
+            .collect()
+    }
+}
+
+/// Machine-readable summary of an event type's default effects.
+///
+/// Deltas are per unit of severity for a neutral entity (all HEXACO traits
+/// at 0) and an event without payload. Multiply by severity to get the
+/// baseline effect of a specific event.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::enums::{EventType, MoodPath, StatePath};
+///
+/// let summary = EventType::Humiliation.blueprint_summary();
+/// assert!(summary.delta_for(StatePath::Mood(MoodPath::Dominance)).unwrap() < 0.0);
+/// assert!(summary.reversibility.is_reversible());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlueprintSummary {
+    /// The event type summarized.
+    pub event_type: EventType,
+    /// The processing category of the event type.
+    pub category: EventCategory,
+    /// Default per-unit-severity deltas, in interpretation order.
+    pub deltas: Vec<(StatePath, f64)>,
+    /// Whether the effects can be undone by backward regression.
+    ///
+    /// Events that touch Acquired Capability are non-reversible because AC
+    /// never decays.
+    pub reversibility: ReversibilityResult,
+}
+
+impl BlueprintSummary {
+    /// Builds the summary for an event type.
+    #[must_use]
+    pub fn for_event_type(event_type: EventType) -> Self {
+        let category = event_type.category();
+        let deltas = base_impact(category, event_type).state_deltas();
+        let reversibility = if deltas
+            .iter()
+            .any(|(path, _)| *path == StatePath::MentalHealth(MentalHealthPath::AcquiredCapability))
+        {
+            ReversibilityResult::NonReversible
+        } else {
+            ReversibilityResult::Reversible
+        };
+        BlueprintSummary {
+            event_type,
+            category,
+            deltas,
+            reversibility,
+        }
+    }
+
+    /// Returns the state paths this event type affects by default.
+    #[must_use]
+    pub fn affected_paths(&self) -> Vec<StatePath> {
+        self.deltas.iter().map(|(path, _)| *path).collect()
+    }
+
+    /// Returns the default delta for a path, if the event type affects it.
+    #[must_use]
+    pub fn delta_for(&self, path: StatePath) -> Option<f64> {
+        self.deltas
+            .iter()
+            .find(|(candidate, _)| *candidate == path)
+            .map(|(_, delta)| *delta)
+    }
+
+    /// Returns true if the event type has no default effect without a payload.
+    #[must_use]
+    pub fn is_payload_driven(&self) -> bool {
+        self.deltas.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_event_type_has_summary() {
+        for event_type in EventType::all() {
+            let summary = BlueprintSummary::for_event_type(event_type);
+            assert_eq!(summary.event_type, event_type);
+            assert_eq!(summary.category, event_type.category());
+        }
+    }
+
+    #[test]
+    fn every_blueprint_keeps_contract() {
+        for event_type in EventType::all() {
+            crate::contract_tests::assert_blueprint_bounds(&BlueprintSummary::for_event_type(
+                event_type,
+            ));
+        }
+    }
+
+    #[test]
+    fn trauma_summary_is_non_reversible() {
+        let summary = BlueprintSummary::for_event_type(EventType::Violence);
+        assert!(summary.reversibility.is_non_reversible());
+        assert!(summary.affected_paths().contains(&StatePath::MentalHealth(
+            MentalHealthPath::AcquiredCapability
+        )));
+    }
+
+    #[test]
+    fn loss_summary_includes_secondary_effects() {
+        let summary = BlueprintSummary::for_event_type(EventType::Loss);
+        let self_worth = summary
+            .delta_for(StatePath::MentalHealth(MentalHealthPath::SelfWorth))
+            .unwrap();
+        let grievance = summary
+            .delta_for(StatePath::Disposition(DispositionPath::Grievance))
+            .unwrap();
+        assert!((self_worth + 0.15).abs() < 1e-6);
+        assert!((grievance - 0.05).abs() < 1e-6);
+        assert!(summary.reversibility.is_reversible());
+    }
+
+    #[test]
+    fn interaction_summary_is_payload_driven() {
+        let summary = BlueprintSummary::for_event_type(EventType::Interaction);
+        assert!(summary.is_payload_driven());
+        assert!(summary
+            .delta_for(StatePath::Mood(MoodPath::Valence))
+            .is_none());
+    }
+
+    #[test]
+    fn unhandled_event_type_in_category_has_zero_impact() {
+        let impact = base_impact(EventCategory::Control, EventType::Interaction);
+        assert_eq!(impact, BaseImpact::default());
+        let impact = base_impact(EventCategory::SocialBelonging, EventType::Interaction);
+        assert_eq!(impact, BaseImpact::default());
+        let impact = base_impact(EventCategory::Achievement, EventType::Interaction);
+        assert_eq!(impact, BaseImpact::default());
+    }
+}
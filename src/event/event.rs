@@ -2,11 +2,21 @@
 //!
 //! Events are occurrences that affect entity state. Each event has a type,
 //! optional source and target, severity, tags, and type-specific payload.
-
-use crate::enums::{EventCategory, EventPayload, EventTag, EventType, HexacoPath};
-use crate::types::{Duration, EntityId, EventId, MicrosystemId};
+//!
+//! Simulations hold millions of events, so `Event` keeps only the fields
+//! nearly every event sets inline. The payload is shared between copies of
+//! an event, such as the per-recipient copies of a broadcast, and the
+//! rarely set properties live in one box that most events never allocate.
+
+use crate::enums::{EventCategory, EventPayload, EventTag, EventType, HexacoPath, RampShape};
+use crate::event::Provenance;
+use crate::types::{Duration, EntityId, EventId, GroupId, MicrosystemId};
+use std::sync::Arc;
 use uuid::Uuid;
 
+/// The payload of events without one, so `payload()` can lend a reference.
+static EMPTY_PAYLOAD: EventPayload = EventPayload::Empty;
+
 /// Generates a unique event ID using UUID v4.
 fn generate_event_id() -> EventId {
     let uuid = Uuid::new_v4();
@@ -43,6 +53,11 @@ fn generate_event_id() -> EventId {
 /// assert_eq!(event.category(), EventCategory::SocialBelonging);
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "EventRepr", into = "EventRepr")
+)]
 pub struct Event {
     /// Unique identifier for this event.
     id: EventId,
@@ -54,21 +69,62 @@ pub struct Event {
     source: Option<EntityId>,
     /// Entity affected by the event (None for broadcast).
     target: Option<EntityId>,
-    /// Intensity of the event (0.0 to 1.0).
+    /// Intensity of the event (0.0 to 1.0), as appraised by the target.
     severity: f64,
+    /// Type-specific event data, shared between copies of the event (None
+    /// for `EventPayload::Empty`).
+    payload: Option<Arc<EventPayload>>,
+    /// Microsystem context where event occurred.
+    microsystem_context: Option<MicrosystemId>,
+    /// Witnesses, tags, shaping, and the other rarely set properties (None
+    /// when all are at their defaults).
+    details: Option<Box<EventDetails>>,
+}
+
+/// Rarely set event properties, stored out of line to keep `Event` small.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct EventDetails {
+    /// Entities who saw the event happen to its target.
+    witnesses: Box<[EntityId]>,
     /// Additional categorization tags.
-    tags: Vec<EventTag>,
-    /// Type-specific event data (required, use EventPayload::Empty if none).
-    payload: EventPayload,
+    tags: Box<[EventTag]>,
+    /// Objective severity, base shifts, ramp, and anticipation.
+    shaping: EventShaping,
     /// When the event occurred (entity age at event time).
     timestamp: Duration,
-    /// Microsystem context where event occurred.
-    microsystem_context: Option<MicrosystemId>,
+    /// Group the event was delivered to, for group events.
+    group: Option<GroupId>,
+    /// Source-system metadata.
+    provenance: Option<Provenance>,
+}
+
+/// How an event's effect is appraised and delivered over time.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct EventShaping {
+    /// Intensity of the event as an outside observer would rate it.
+    objective_severity: Option<f64>,
+    /// Whether the target's appraisal must be derived from the objective
+    /// severity, because no subjective severity was given.
+    severity_appraised: bool,
     /// Personality base shifts triggered by this event.
     /// Each entry is (trait, shift_amount) to be processed during simulation.
     base_shifts: Vec<(HexacoPath, f32)>,
+    /// Window over which the effect is delivered (zero for instant events).
+    ramp_duration: Duration,
+    /// How the effect is distributed across the ramp window.
+    ramp_shape: RampShape,
+    /// Window before the event over which an anticipated event builds
+    /// arousal and stress.
+    anticipation_window: Duration,
 }
 
+/// Upper bound on the number of increments a ramped event is split into.
+///
+/// Ramps are delivered in daily increments; longer ramps use proportionally
+/// wider increments so traversal cost stays bounded.
+const MAX_RAMP_INCREMENTS: u64 = 365;
+
 impl Event {
     /// Creates a new event with the given type.
     ///
@@ -82,11 +138,9 @@ impl Event {
             source: None,
             target: None,
             severity: 0.5,
-            tags: Vec::new(),
-            payload: EventPayload::Empty,
-            timestamp: Duration::zero(),
+            payload: None,
             microsystem_context: None,
-            base_shifts: Vec::new(),
+            details: None,
         }
     }
 
@@ -100,11 +154,9 @@ impl Event {
             source: None,
             target: None,
             severity: 0.5,
-            tags: Vec::new(),
-            payload: EventPayload::Empty,
-            timestamp: Duration::zero(),
+            payload: None,
             microsystem_context: None,
-            base_shifts: Vec::new(),
+            details: None,
         }
     }
 
@@ -140,34 +192,69 @@ impl Event {
         self.target.as_ref()
     }
 
+    /// Returns the entities who witnessed the event.
+    #[must_use]
+    pub fn witnesses(&self) -> &[EntityId] {
+        self.details
+            .as_ref()
+            .map_or(&[], |details| &details.witnesses)
+    }
+
+    /// Returns true if the entity is listed as a witness of the event.
+    #[must_use]
+    pub fn is_witnessed_by(&self, entity_id: &EntityId) -> bool {
+        self.witnesses().contains(entity_id)
+    }
+
     /// Returns the severity (0.0 to 1.0).
+    ///
+    /// This is the subjective severity when one was given. For an event
+    /// built with only an objective severity it is the objective value;
+    /// the target's appraisal of it is computed during interpretation.
     #[must_use]
     pub fn severity(&self) -> f64 {
         self.severity
     }
 
+    /// Returns the objective severity (0.0 to 1.0), if one was given.
+    #[must_use]
+    pub fn objective_severity(&self) -> Option<f64> {
+        self.shaping()
+            .and_then(|shaping| shaping.objective_severity)
+    }
+
+    /// Returns true if the subjective severity is appraised from the
+    /// objective severity rather than given explicitly.
+    #[must_use]
+    pub fn is_severity_appraised(&self) -> bool {
+        self.shaping()
+            .is_some_and(|shaping| shaping.severity_appraised)
+    }
+
     /// Returns the tags.
     #[must_use]
     pub fn tags(&self) -> &[EventTag] {
-        &self.tags
+        self.details.as_ref().map_or(&[], |details| &details.tags)
     }
 
     /// Returns the payload.
     #[must_use]
     pub fn payload(&self) -> &EventPayload {
-        &self.payload
+        self.payload.as_deref().unwrap_or(&EMPTY_PAYLOAD)
     }
 
     /// Returns whether the payload has data (not Empty).
     #[must_use]
     pub fn has_payload_data(&self) -> bool {
-        !matches!(self.payload, EventPayload::Empty)
+        self.payload.is_some()
     }
 
     /// Returns the timestamp.
     #[must_use]
     pub fn timestamp(&self) -> Duration {
-        self.timestamp
+        self.details
+            .as_ref()
+            .map_or_else(Duration::zero, |details| details.timestamp)
     }
 
     /// Returns the microsystem context, if any.
@@ -176,25 +263,137 @@ impl Event {
         self.microsystem_context.as_ref()
     }
 
+    /// Returns the group the event was delivered to, if any.
+    ///
+    /// Set for each member's copy of a group event, so subscribers can
+    /// filter on group membership.
+    #[must_use]
+    pub fn group(&self) -> Option<&GroupId> {
+        self.details
+            .as_ref()
+            .and_then(|details| details.group.as_ref())
+    }
+
+    /// Returns where the event was ingested from, if recorded.
+    ///
+    /// Provenance is metadata only and never affects interpretation.
+    #[must_use]
+    pub fn provenance(&self) -> Option<&Provenance> {
+        self.details
+            .as_ref()
+            .and_then(|details| details.provenance.as_ref())
+    }
+
     /// Returns the personality base shifts for this event.
     ///
     /// Each entry is a (trait, shift_amount) pair representing a permanent
     /// personality change to apply when this event is processed.
     #[must_use]
     pub fn base_shifts(&self) -> &[(HexacoPath, f32)] {
-        &self.base_shifts
+        self.shaping()
+            .map_or(&[], |shaping| shaping.base_shifts.as_slice())
     }
 
     /// Returns true if this event has any personality base shifts.
     #[must_use]
     pub fn has_base_shifts(&self) -> bool {
-        !self.base_shifts.is_empty()
+        !self.base_shifts().is_empty()
+    }
+
+    /// Returns the window over which the event's effect is delivered.
+    #[must_use]
+    pub fn ramp_duration(&self) -> Duration {
+        self.shaping()
+            .map_or_else(Duration::zero, |shaping| shaping.ramp_duration)
+    }
+
+    /// Returns the onset profile of the event.
+    #[must_use]
+    pub fn ramp_shape(&self) -> RampShape {
+        self.shaping()
+            .map_or(RampShape::Step, |shaping| shaping.ramp_shape)
+    }
+
+    /// Returns true if the effect builds up over a ramp window instead of
+    /// landing at once.
+    #[must_use]
+    pub fn is_gradual(&self) -> bool {
+        !self.ramp_duration().is_zero() && !self.ramp_shape().is_instant()
+    }
+
+    /// Splits the event's effect into increments across its ramp window.
+    ///
+    /// Each entry is (offset from onset, fraction of the full effect). The
+    /// fractions sum to 1.0 and the last increment lands at the end of the
+    /// window. Instant events yield a single increment at onset.
+    #[must_use]
+    pub(crate) fn onset_increments(&self) -> Vec<(Duration, f64)> {
+        if !self.is_gradual() {
+            return vec![(Duration::zero(), 1.0)];
+        }
+        let ramp_duration = self.ramp_duration();
+        let ramp_shape = self.ramp_shape();
+        let total_seconds = ramp_duration.as_seconds();
+        let count = ramp_duration.as_days().clamp(1, MAX_RAMP_INCREMENTS);
+        let mut delivered = 0.0;
+        (1..=count)
+            .map(|k| {
+                let offset = Duration::seconds(total_seconds * k / count);
+                let cumulative = ramp_shape.delivered_fraction(k as f64 / count as f64);
+                let fraction = cumulative - delivered;
+                delivered = cumulative;
+                (offset, fraction)
+            })
+            .collect()
+    }
+
+    /// Returns the window before the event over which anticipation builds.
+    #[must_use]
+    pub fn anticipation_window(&self) -> Duration {
+        self.shaping()
+            .map_or_else(Duration::zero, |shaping| shaping.anticipation_window)
+    }
+
+    /// Returns true if the event affects state before it happens: it is
+    /// tagged `EventTag::Anticipated` and has an anticipation window.
+    #[must_use]
+    pub fn is_anticipated(&self) -> bool {
+        self.has_tag(EventTag::Anticipated) && !self.anticipation_window().is_zero()
+    }
+
+    /// Splits the anticipatory effect into increments across the
+    /// anticipation window.
+    ///
+    /// Each entry is (lead before the event, fraction of the anticipatory
+    /// effect). Increments are daily, capped like ramps, and ease in so
+    /// anticipation builds fastest as the event approaches; the last lands
+    /// one increment before the event. The fractions sum to 1.0. Events
+    /// that are not anticipated yield none.
+    #[must_use]
+    pub(crate) fn anticipation_increments(&self) -> Vec<(Duration, f64)> {
+        if !self.is_anticipated() {
+            return Vec::new();
+        }
+        let window = self.anticipation_window();
+        let total_seconds = window.as_seconds();
+        let count = window.as_days().clamp(1, MAX_RAMP_INCREMENTS);
+        let mut delivered = 0.0;
+        (0..count)
+            .map(|k| {
+                let lead = Duration::seconds(total_seconds * (count - k) / count);
+                let cumulative =
+                    RampShape::EaseIn.delivered_fraction((k + 1) as f64 / count as f64);
+                let fraction = cumulative - delivered;
+                delivered = cumulative;
+                (lead, fraction)
+            })
+            .collect()
     }
 
     /// Returns whether this event has the specified tag.
     #[must_use]
     pub fn has_tag(&self, tag: EventTag) -> bool {
-        self.tags.contains(&tag)
+        self.tags().contains(&tag)
     }
 
     /// Returns true if this is a trauma-category event.
@@ -235,35 +434,158 @@ impl Event {
         self.target = target;
     }
 
+    pub(crate) fn set_witnesses(&mut self, witnesses: Vec<EntityId>) {
+        self.update_details(|details| details.witnesses = witnesses.into_boxed_slice());
+    }
+
     pub(crate) fn set_severity(&mut self, severity: f64) {
         self.severity = severity.clamp(0.0, 1.0);
     }
 
+    pub(crate) fn set_objective_severity(&mut self, objective: Option<f64>, appraised: bool) {
+        self.update_shaping(|shaping| {
+            shaping.objective_severity = objective.map(|value| value.clamp(0.0, 1.0));
+            shaping.severity_appraised = appraised && objective.is_some();
+        });
+    }
+
     pub(crate) fn set_tags(&mut self, tags: Vec<EventTag>) {
-        self.tags = tags;
+        self.update_details(|details| details.tags = tags.into_boxed_slice());
     }
 
     #[allow(dead_code)]
     pub(crate) fn add_tag(&mut self, tag: EventTag) {
-        if !self.tags.contains(&tag) {
-            self.tags.push(tag);
+        if !self.has_tag(tag) {
+            let mut tags = self.tags().to_vec();
+            tags.push(tag);
+            self.set_tags(tags);
         }
     }
 
     pub(crate) fn set_payload(&mut self, payload: EventPayload) {
-        self.payload = payload;
+        self.payload = (payload != EventPayload::Empty).then(|| Arc::new(payload));
     }
 
     pub(crate) fn set_timestamp(&mut self, timestamp: Duration) {
-        self.timestamp = timestamp;
+        self.update_details(|details| details.timestamp = timestamp);
     }
 
     pub(crate) fn set_microsystem_context(&mut self, context: Option<MicrosystemId>) {
         self.microsystem_context = context;
     }
 
+    pub(crate) fn set_group(&mut self, group: Option<GroupId>) {
+        self.update_details(|details| details.group = group);
+    }
+
+    pub(crate) fn set_provenance(&mut self, provenance: Option<Provenance>) {
+        self.update_details(|details| details.provenance = provenance);
+    }
+
     pub(crate) fn set_base_shifts(&mut self, shifts: Vec<(HexacoPath, f32)>) {
-        self.base_shifts = shifts;
+        self.update_shaping(|shaping| shaping.base_shifts = shifts);
+    }
+
+    pub(crate) fn set_anticipation_window(&mut self, window: Duration) {
+        self.update_shaping(|shaping| shaping.anticipation_window = window);
+    }
+
+    pub(crate) fn set_ramp(&mut self, duration: Duration, shape: RampShape) {
+        self.update_shaping(|shaping| {
+            shaping.ramp_duration = duration;
+            shaping.ramp_shape = shape;
+        });
+    }
+
+    /// Edits the out-of-line properties, dropping the box when they are
+    /// all back at their defaults so equal events compare equal.
+    fn shaping(&self) -> Option<&EventShaping> {
+        self.details.as_ref().map(|details| &details.shaping)
+    }
+
+    fn update_shaping(&mut self, edit: impl FnOnce(&mut EventShaping)) {
+        self.update_details(|details| edit(&mut details.shaping));
+    }
+
+    /// Edits the out-of-line properties, dropping the box once they are
+    /// all back at their defaults.
+    fn update_details(&mut self, edit: impl FnOnce(&mut EventDetails)) {
+        let mut details = self.details.take().unwrap_or_default();
+        edit(&mut details);
+        if *details != EventDetails::default() {
+            self.details = Some(details);
+        }
+    }
+}
+
+/// The serialized form of an event, which keeps every property at the top
+/// level whatever the in-memory layout.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct EventRepr {
+    id: EventId,
+    event_type: EventType,
+    category: EventCategory,
+    source: Option<EntityId>,
+    target: Option<EntityId>,
+    #[serde(default)]
+    witnesses: Vec<EntityId>,
+    severity: f64,
+    shaping: Option<EventShaping>,
+    tags: Vec<EventTag>,
+    payload: EventPayload,
+    timestamp: Duration,
+    microsystem_context: Option<MicrosystemId>,
+    group: Option<GroupId>,
+    provenance: Option<Provenance>,
+}
+
+#[cfg(feature = "serde")]
+impl From<Event> for EventRepr {
+    fn from(event: Event) -> Self {
+        let details = event.details.map(|details| *details).unwrap_or_default();
+        EventRepr {
+            id: event.id,
+            event_type: event.event_type,
+            category: event.category,
+            source: event.source,
+            target: event.target,
+            witnesses: details.witnesses.into_vec(),
+            severity: event.severity,
+            shaping: (details.shaping != EventShaping::default()).then_some(details.shaping),
+            tags: details.tags.into_vec(),
+            payload: event
+                .payload
+                .map_or(EventPayload::Empty, |payload| (*payload).clone()),
+            timestamp: details.timestamp,
+            microsystem_context: event.microsystem_context,
+            group: details.group,
+            provenance: details.provenance,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<EventRepr> for Event {
+    fn from(repr: EventRepr) -> Self {
+        let mut event = Event::with_id(repr.id, repr.event_type);
+        event.category = repr.category;
+        event.source = repr.source;
+        event.target = repr.target;
+        event.severity = repr.severity;
+        event.set_payload(repr.payload);
+        event.microsystem_context = repr.microsystem_context;
+        event.update_details(|details| {
+            *details = EventDetails {
+                witnesses: repr.witnesses.into_boxed_slice(),
+                tags: repr.tags.into_boxed_slice(),
+                shaping: repr.shaping.unwrap_or_default(),
+                timestamp: repr.timestamp,
+                group: repr.group,
+                provenance: repr.provenance,
+            }
+        });
+        event
     }
 }
 
@@ -470,6 +792,23 @@ mod tests {
         assert!(event.has_tag(EventTag::HighStakes));
     }
 
+    #[test]
+    fn clearing_shaping_restores_equality() {
+        let id = EventId::new("evt_1").unwrap();
+        let plain = Event::with_id(id.clone(), EventType::Conflict);
+        let mut event = Event::with_id(id, EventType::Conflict);
+
+        event.set_ramp(Duration::days(10), RampShape::Linear);
+        event.set_objective_severity(Some(0.4), true);
+        assert_eq!(event.ramp_duration(), Duration::days(10));
+        assert_eq!(event.objective_severity(), Some(0.4));
+        assert_ne!(event, plain);
+
+        event.set_ramp(Duration::zero(), RampShape::Step);
+        event.set_objective_severity(None, false);
+        assert_eq!(event, plain);
+    }
+
     #[test]
     fn event_default_has_no_base_shifts() {
         let event = Event::new(EventType::Violence);
@@ -490,4 +829,86 @@ mod tests {
         assert_eq!(event.base_shifts()[0], (HexacoPath::Neuroticism, 0.25));
         assert_eq!(event.base_shifts()[1], (HexacoPath::Agreeableness, -0.15));
     }
+
+    #[test]
+    fn instant_event_has_single_increment_at_onset() {
+        let event = Event::new(EventType::Conflict);
+        assert!(!event.is_gradual());
+        assert_eq!(event.onset_increments(), vec![(Duration::zero(), 1.0)]);
+    }
+
+    #[test]
+    fn step_ramp_is_instant() {
+        let mut event = Event::new(EventType::Conflict);
+        event.set_ramp(Duration::days(30), RampShape::Step);
+        assert!(!event.is_gradual());
+        assert_eq!(event.onset_increments().len(), 1);
+    }
+
+    #[test]
+    fn ramp_increments_sum_to_full_effect() {
+        for shape in [RampShape::Linear, RampShape::EaseIn] {
+            let mut event = Event::new(EventType::Conflict);
+            event.set_ramp(Duration::days(30), shape);
+            let increments = event.onset_increments();
+            assert_eq!(increments.len(), 30);
+            let total: f64 = increments.iter().map(|(_, fraction)| fraction).sum();
+            assert!((total - 1.0).abs() < 1e-9);
+            assert_eq!(increments.last().unwrap().0, Duration::days(30));
+            assert!(increments.iter().all(|(offset, _)| !offset.is_zero()));
+        }
+    }
+
+    #[test]
+    fn ease_in_increments_grow_over_window() {
+        let mut event = Event::new(EventType::Conflict);
+        event.set_ramp(Duration::days(10), RampShape::EaseIn);
+        let increments = event.onset_increments();
+        assert!(increments[0].1 < increments[9].1);
+    }
+
+    #[test]
+    fn long_ramp_increment_count_is_capped() {
+        let mut event = Event::new(EventType::Conflict);
+        event.set_ramp(Duration::years(3), RampShape::Linear);
+        let increments = event.onset_increments();
+        assert_eq!(increments.len() as u64, MAX_RAMP_INCREMENTS);
+        assert_eq!(increments.last().unwrap().0, Duration::years(3));
+    }
+
+    #[test]
+    fn anticipation_requires_tag_and_window() {
+        let mut event = Event::new(EventType::JobLoss);
+        event.set_anticipation_window(Duration::days(7));
+        assert!(!event.is_anticipated());
+        assert!(event.anticipation_increments().is_empty());
+
+        event.set_tags(vec![EventTag::Anticipated]);
+        assert!(event.is_anticipated());
+
+        event.set_anticipation_window(Duration::zero());
+        assert!(!event.is_anticipated());
+    }
+
+    #[test]
+    fn anticipation_increments_build_toward_the_event() {
+        let mut event = Event::new(EventType::JobLoss);
+        event.set_tags(vec![EventTag::Anticipated]);
+        event.set_anticipation_window(Duration::days(7));
+        let increments = event.anticipation_increments();
+
+        assert_eq!(increments.len(), 7);
+        assert_eq!(increments[0].0, Duration::days(7));
+        assert_eq!(increments[6].0, Duration::days(1));
+        let total: f64 = increments.iter().map(|(_, fraction)| fraction).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+        assert!(increments[6].1 > increments[0].1);
+    }
+
+    #[test]
+    fn sub_day_ramp_lands_once_at_window_end() {
+        let mut event = Event::new(EventType::Conflict);
+        event.set_ramp(Duration::hours(6), RampShape::Linear);
+        assert_eq!(event.onset_increments(), vec![(Duration::hours(6), 1.0)]);
+    }
 }
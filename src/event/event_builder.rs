@@ -3,21 +3,51 @@
 //! The builder pattern allows clean construction of events with many
 //! optional fields. Category is auto-derived from EventType.
 
-use crate::enums::{EventPayload, EventTag, EventType, HexacoPath};
-use crate::event::Event;
-use crate::types::{Duration, EntityId, EventId, MicrosystemId};
+use crate::enums::{EventPayload, EventTag, EventType, HexacoPath, PayloadKind, RampShape};
+use crate::event::{Event, Provenance};
+use crate::types::{
+    BoundedValueError, Duration, EntityId, EventId, GroupId, IntoBounded, MicrosystemId,
+    SignedUnit, UnitInterval,
+};
 use std::fmt;
 
 /// Error returned when event building fails.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct EventBuildError {
-    /// Description of what went wrong.
-    pub reason: String,
+pub enum EventBuildError {
+    /// A severity, base shift, or payload field is outside its scale or
+    /// not finite.
+    OutOfRange {
+        /// Name of the rejected field.
+        field: String,
+        /// Why the value was rejected.
+        reason: String,
+    },
+    /// The payload variant does not belong to the event type.
+    PayloadMismatch {
+        /// Payload kinds the event type accepts besides `Empty`, from
+        /// `EventType::allowed_payloads`.
+        expected: &'static [PayloadKind],
+        /// The kind of payload that was set, from `EventPayload::kind`.
+        found: PayloadKind,
+    },
 }
 
 impl fmt::Display for EventBuildError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "Event build error: {}", self.reason)
+        match self {
+            EventBuildError::OutOfRange { field, reason } => {
+                write!(f, "Event build error: {}: {}", field, reason)
+            }
+            EventBuildError::PayloadMismatch { expected, found } => write!(
+                f,
+                "Event build error: payload '{}' does not match the event type (expected Empty{})",
+                found.name(),
+                expected
+                    .iter()
+                    .map(|kind| format!(" or {}", kind.name()))
+                    .collect::<String>()
+            ),
+        }
     }
 }
 
@@ -33,7 +63,7 @@ impl std::error::Error for EventBuildError {}
 ///
 /// ```
 /// use behavioral_pathways::event::EventBuilder;
-/// use behavioral_pathways::enums::{EventType, EventTag, EventPayload, SupportType};
+/// use behavioral_pathways::enums::{Audience, EventType, EventTag, EventPayload, SupportType};
 /// use behavioral_pathways::types::{EntityId, Duration};
 ///
 /// let helper = EntityId::new("helper_001").unwrap();
@@ -48,6 +78,7 @@ impl std::error::Error for EventBuildError {}
 ///     .payload(EventPayload::Support {
 ///         support_type: SupportType::Emotional,
 ///         effectiveness: 0.9,
+///         audience: Audience::Private,
 ///     })
 ///     .build()
 ///     .unwrap();
@@ -61,12 +92,20 @@ pub struct EventBuilder {
     id: Option<EventId>,
     source: Option<EntityId>,
     target: Option<EntityId>,
-    severity: f64,
+    witnesses: Vec<EntityId>,
+    severity: Option<Result<UnitInterval, BoundedValueError>>,
+    objective_severity: Option<Result<UnitInterval, BoundedValueError>>,
     tags: Vec<EventTag>,
     payload: Option<EventPayload>,
     timestamp: Duration,
     microsystem_context: Option<MicrosystemId>,
-    base_shifts: Vec<(HexacoPath, f32)>,
+    group: Option<GroupId>,
+    provenance: Option<Provenance>,
+    base_shifts: Vec<(HexacoPath, Result<SignedUnit, BoundedValueError>)>,
+    ramp_duration: Duration,
+    ramp_shape: RampShape,
+    anticipation_window: Duration,
+    strict: bool,
 }
 
 impl EventBuilder {
@@ -84,12 +123,20 @@ impl EventBuilder {
             id: None,
             source: None,
             target: None,
-            severity: 0.5,
+            witnesses: Vec::new(),
+            severity: None,
+            objective_severity: None,
             tags: Vec::new(),
             payload: None,
             timestamp: Duration::zero(),
             microsystem_context: None,
+            group: None,
+            provenance: None,
             base_shifts: Vec::new(),
+            ramp_duration: Duration::zero(),
+            ramp_shape: RampShape::Step,
+            anticipation_window: Duration::zero(),
+            strict: true,
         }
     }
 
@@ -116,12 +163,39 @@ impl EventBuilder {
         self
     }
 
+    /// Adds an entity who saw the event happen to its target.
+    ///
+    /// Witnesses feel a share of the target's impact, scaled by their
+    /// empathy, and attribute the event to someone other than themselves.
+    /// Adding the same witness twice has no further effect.
+    #[must_use]
+    pub fn witness(mut self, entity_id: EntityId) -> Self {
+        if !self.witnesses.contains(&entity_id) {
+            self.witnesses.push(entity_id);
+        }
+        self
+    }
+
     /// Sets the severity (0.0 to 1.0).
     ///
-    /// Values are clamped to the valid range.
+    /// This is the subjective severity: how strongly the target experiences
+    /// the event. Accepts a raw float, validated by `build()`, or a
+    /// [`UnitInterval`].
+    #[must_use]
+    pub fn severity(mut self, severity: impl IntoBounded<UnitInterval>) -> Self {
+        self.severity = Some(severity.into_bounded());
+        self
+    }
+
+    /// Sets the objective severity (0.0 to 1.0).
+    ///
+    /// This is how severe the event is to an outside observer. If no
+    /// subjective severity is set, the target's appraisal is computed from
+    /// this value and their personality when the event is interpreted.
+    /// Accepts a raw float, validated by `build()`, or a [`UnitInterval`].
     #[must_use]
-    pub fn severity(mut self, severity: f64) -> Self {
-        self.severity = severity.clamp(0.0, 1.0);
+    pub fn objective_severity(mut self, severity: impl IntoBounded<UnitInterval>) -> Self {
+        self.objective_severity = Some(severity.into_bounded());
         self
     }
 
@@ -162,6 +236,23 @@ impl EventBuilder {
         self
     }
 
+    /// Sets the group the event is delivered to.
+    #[must_use]
+    pub fn group(mut self, group: GroupId) -> Self {
+        self.group = Some(group);
+        self
+    }
+
+    /// Records the source system the event was ingested from.
+    ///
+    /// Provenance is carried with the event for auditing and filtering and
+    /// has no effect on interpretation.
+    #[must_use]
+    pub fn provenance(mut self, provenance: Provenance) -> Self {
+        self.provenance = Some(provenance);
+        self
+    }
+
     /// Adds a personality base shift to this event.
     ///
     /// Base shifts represent permanent personality changes triggered by
@@ -170,7 +261,7 @@ impl EventBuilder {
     /// # Arguments
     ///
     /// * `trait_path` - Which HEXACO trait to shift
-    /// * `amount` - Shift amount (clamped to -1.0 to 1.0)
+    /// * `amount` - Shift amount (-1.0 to 1.0), validated by `build()`
     ///
     /// # Examples
     ///
@@ -189,9 +280,116 @@ impl EventBuilder {
     /// assert_eq!(event.base_shifts().len(), 2);
     /// ```
     #[must_use]
-    pub fn with_base_shift(mut self, trait_path: HexacoPath, amount: f32) -> Self {
-        let clamped = amount.clamp(-1.0, 1.0);
-        self.base_shifts.push((trait_path, clamped));
+    pub fn with_base_shift(
+        mut self,
+        trait_path: HexacoPath,
+        amount: impl IntoBounded<SignedUnit>,
+    ) -> Self {
+        self.base_shifts.push((trait_path, amount.into_bounded()));
+        self
+    }
+
+    /// Spreads the event's effect across a ramp window.
+    ///
+    /// Gradual stressors such as a deteriorating marriage or a progressive
+    /// illness build over time. With a ramp, the interpreted deltas are
+    /// delivered progressively from the event's timestamp to the end of the
+    /// window, and each increment starts decaying as it lands. The total
+    /// delivered effect equals that of the same event without a ramp.
+    ///
+    /// `RampShape::Step` (the default) delivers everything at onset. Acute
+    /// `EventPayload::HealthCondition` events without an explicit ramp use
+    /// the condition's typical onset window and the course's ramp shape.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::enums::{EventType, RampShape};
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::types::Duration;
+    ///
+    /// let event = EventBuilder::new(EventType::Conflict)
+    ///     .severity(0.6)
+    ///     .ramp(Duration::days(90), RampShape::Linear)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert!(event.is_gradual());
+    /// assert_eq!(event.ramp_duration(), Duration::days(90));
+    /// ```
+    #[must_use]
+    pub fn ramp(mut self, duration: Duration, shape: RampShape) -> Self {
+        self.ramp_duration = duration;
+        self.ramp_shape = shape;
+        self
+    }
+
+    /// Sets the window before the event over which it is anticipated.
+    ///
+    /// People react to a scheduled surgery or announced layoff before it
+    /// happens. When the event is also tagged `EventTag::Anticipated`,
+    /// state queries inside the window apply a share of its interpreted
+    /// arousal, and stress if the event is unpleasant, building as the
+    /// event approaches. Without the tag the window has no effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::enums::{EventTag, EventType};
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::types::Duration;
+    ///
+    /// let event = EventBuilder::new(EventType::JobLoss)
+    ///     .severity(0.8)
+    ///     .tag(EventTag::Anticipated)
+    ///     .anticipation_window(Duration::weeks(1))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert!(event.is_anticipated());
+    /// assert_eq!(event.anticipation_window(), Duration::weeks(1));
+    /// ```
+    #[must_use]
+    pub fn anticipation_window(mut self, window: Duration) -> Self {
+        self.anticipation_window = window;
+        self
+    }
+
+    /// Sets whether `build` rejects a payload that does not belong to the
+    /// event type. Defaults to true.
+    ///
+    /// With strict validation off, a mismatched payload is kept and the
+    /// interpreter ignores whatever the event type does not read from it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::enums::{EventPayload, EventType, PayloadKind};
+    /// use behavioral_pathways::event::{EventBuildError, EventBuilder};
+    ///
+    /// let betrayal = EventPayload::Betrayal {
+    ///     confidence_violated: 0.8,
+    /// };
+    /// let strict = EventBuilder::new(EventType::Support)
+    ///     .payload(betrayal.clone())
+    ///     .build();
+    /// assert_eq!(
+    ///     strict.unwrap_err(),
+    ///     EventBuildError::PayloadMismatch {
+    ///         expected: &[PayloadKind::Support],
+    ///         found: PayloadKind::Betrayal,
+    ///     }
+    /// );
+    ///
+    /// let lenient = EventBuilder::new(EventType::Support)
+    ///     .payload(betrayal)
+    ///     .validate_strict(false)
+    ///     .build();
+    /// assert!(lenient.is_ok());
+    /// ```
+    #[must_use]
+    pub fn validate_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
         self
     }
 
@@ -201,10 +399,59 @@ impl EventBuilder {
     ///
     /// The constructed event, or an error if validation fails.
     ///
+    /// # Errors
+    ///
+    /// Returns `EventBuildError::OutOfRange` if a severity, base shift, or
+    /// payload field is outside its scale or not finite, and
+    /// `EventBuildError::PayloadMismatch` if the payload is not `Empty` or
+    /// one of `EventType::allowed_payloads` (unless `validate_strict` is
+    /// off).
+    ///
     /// # Notes
     ///
     /// If no payload was set, defaults to `EventPayload::Empty`.
     pub fn build(self) -> Result<Event, EventBuildError> {
+        let severity = self
+            .severity
+            .transpose()
+            .map_err(|error| range_error("severity", error))?
+            .map(UnitInterval::get);
+        let objective_severity = self
+            .objective_severity
+            .transpose()
+            .map_err(|error| range_error("objective_severity", error))?
+            .map(UnitInterval::get);
+        let base_shifts = self
+            .base_shifts
+            .into_iter()
+            .map(|(path, amount)| {
+                amount
+                    .map(|amount| (path, f32::from(amount)))
+                    .map_err(|error| range_error(path.name(), error))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let payload = self.payload.unwrap_or(EventPayload::Empty);
+        let expected = self.event_type.allowed_payloads();
+        if self.strict && payload != EventPayload::Empty && !expected.contains(&payload.kind()) {
+            return Err(EventBuildError::PayloadMismatch {
+                expected,
+                found: payload.kind(),
+            });
+        }
+        payload
+            .validate_ranges()
+            .map_err(|(field, error)| range_error(field, error))?;
+        let (ramp_duration, ramp_shape) = match &payload {
+            EventPayload::HealthCondition {
+                condition,
+                chronic: false,
+                severity_course,
+            } if self.ramp_duration.is_zero() => {
+                (condition.typical_onset(), severity_course.ramp_shape())
+            }
+            _ => (self.ramp_duration, self.ramp_shape),
+        };
+
         let mut event = if let Some(id) = self.id {
             Event::with_id(id, self.event_type)
         } else {
@@ -213,22 +460,143 @@ impl EventBuilder {
 
         event.set_source(self.source);
         event.set_target(self.target);
-        event.set_severity(self.severity);
+        event.set_witnesses(self.witnesses);
+        event.set_severity(severity.or(objective_severity).unwrap_or(0.5));
+        event.set_objective_severity(objective_severity, severity.is_none());
         event.set_tags(self.tags);
         // Use provided payload or default to Empty
-        event.set_payload(self.payload.unwrap_or(EventPayload::Empty));
+        event.set_payload(payload);
         event.set_timestamp(self.timestamp);
         event.set_microsystem_context(self.microsystem_context);
-        event.set_base_shifts(self.base_shifts);
+        event.set_group(self.group);
+        event.set_provenance(self.provenance);
+        event.set_base_shifts(base_shifts);
+        event.set_ramp(ramp_duration, ramp_shape);
+        event.set_anticipation_window(self.anticipation_window);
 
         Ok(event)
     }
 }
 
+/// Returns the build error for an out-of-range input.
+fn range_error(field: &str, error: BoundedValueError) -> EventBuildError {
+    EventBuildError::OutOfRange {
+        field: field.to_string(),
+        reason: error.to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::enums::{EventCategory, SupportType};
+    use crate::enums::{
+        Audience, ConditionKind, CourseShape, EventCategory, HistoricalEventType, HistoricalScope,
+        LifeDomain, LossType, PolicyArea, RealizationType, SupportType, TraumaType,
+    };
+
+    /// One payload of every variant except `Empty`.
+    fn sample_payloads() -> Vec<EventPayload> {
+        let microsystem = MicrosystemId::new("work").unwrap();
+        vec![
+            EventPayload::Interaction {
+                topic: None,
+                duration_minutes: 30,
+            },
+            EventPayload::SocialExclusion {
+                group_id: None,
+                explicit: true,
+            },
+            EventPayload::SocialInclusion { group_id: None },
+            EventPayload::BurdenFeedback {
+                source_relationship: None,
+                verbal: true,
+            },
+            EventPayload::Betrayal {
+                confidence_violated: 0.5,
+            },
+            EventPayload::Support {
+                support_type: SupportType::Emotional,
+                effectiveness: 0.5,
+                audience: Audience::Private,
+            },
+            EventPayload::Conflict {
+                verbal: true,
+                physical: false,
+                resolved: false,
+            },
+            EventPayload::Violence {
+                weapon: None,
+                injury_severity: 0.5,
+            },
+            EventPayload::Humiliation {
+                public: true,
+                perpetrator: None,
+            },
+            EventPayload::Empowerment {
+                domain: LifeDomain::Work,
+            },
+            EventPayload::Achievement {
+                domain: LifeDomain::Work,
+                magnitude: 0.5,
+                audience: Audience::Private,
+            },
+            EventPayload::Failure {
+                domain: LifeDomain::Work,
+                public: false,
+            },
+            EventPayload::Loss {
+                loss_type: LossType::Person,
+                lost_entity: None,
+            },
+            EventPayload::PolicyChange {
+                policy_area: PolicyArea::Housing,
+                favorability: 0.2,
+            },
+            EventPayload::ContextTransition {
+                from: microsystem.clone(),
+                to: microsystem,
+            },
+            EventPayload::RoleExit {
+                domain: LifeDomain::Work,
+                voluntary: true,
+            },
+            EventPayload::HistoricalEvent {
+                event_type: HistoricalEventType::Pandemic,
+                scope: HistoricalScope::Global,
+            },
+            EventPayload::HealthCondition {
+                condition: ConditionKind::Infection,
+                chronic: false,
+                severity_course: CourseShape::Sudden,
+            },
+            EventPayload::HealthRemission {
+                condition: ConditionKind::ChronicPain,
+            },
+            EventPayload::Sleep {
+                hours: 7.0,
+                quality: 0.8,
+            },
+            EventPayload::Concealment {
+                domain: LifeDomain::Relationship,
+                intensity: 0.5,
+                ongoing: false,
+                kept_from: Vec::new(),
+                until: None,
+            },
+            EventPayload::Disclosure {
+                domain: LifeDomain::Relationship,
+                relationship: crate::types::RelationshipId::new("rel").unwrap(),
+                reaction: 0.5,
+            },
+            EventPayload::Realization {
+                realization_type: RealizationType::SelfInsight,
+            },
+            EventPayload::TraumaticExposure {
+                trauma_type: TraumaType::Witnessing,
+                proximity: 0.5,
+            },
+        ]
+    }
 
     #[test]
     fn event_builder_minimal() {
@@ -264,6 +632,7 @@ mod tests {
             .payload(EventPayload::Support {
                 support_type: SupportType::Emotional,
                 effectiveness: 0.85,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -297,18 +666,69 @@ mod tests {
     }
 
     #[test]
-    fn event_builder_severity_clamped() {
-        let event_high = EventBuilder::new(EventType::Violence)
-            .severity(1.5)
+    fn event_builder_severity_out_of_range_errors() {
+        let high = EventBuilder::new(EventType::Violence).severity(1.5).build();
+        assert_eq!(
+            high.unwrap_err().to_string(),
+            "Event build error: severity: Value 1.5 is outside [0, 1]"
+        );
+
+        let low = EventBuilder::new(EventType::Violence)
+            .severity(-0.5_f32)
+            .build();
+        assert!(low.is_err());
+
+        let nan = EventBuilder::new(EventType::Violence)
+            .severity(f64::NAN)
+            .build();
+        assert!(nan.is_err());
+    }
+
+    #[test]
+    fn event_builder_accepts_bounded_values() {
+        let event = EventBuilder::new(EventType::Violence)
+            .severity(UnitInterval::new(1.0).unwrap())
+            .with_base_shift(HexacoPath::Openness, SignedUnit::new(-1.0).unwrap())
+            .build()
+            .unwrap();
+
+        assert!((event.severity() - 1.0).abs() < f64::EPSILON);
+        assert!((event.base_shifts()[0].1 - (-1.0)).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn event_builder_objective_severity_combinations() {
+        let subjective = EventBuilder::new(EventType::Loss)
+            .severity(0.4)
             .build()
             .unwrap();
-        assert!((event_high.severity() - 1.0).abs() < f64::EPSILON);
+        assert_eq!(subjective.objective_severity(), None);
+        assert!(!subjective.is_severity_appraised());
 
-        let event_low = EventBuilder::new(EventType::Violence)
-            .severity(-0.5)
+        let objective = EventBuilder::new(EventType::Loss)
+            .objective_severity(1.0)
+            .build()
+            .unwrap();
+        assert_eq!(objective.objective_severity(), Some(1.0));
+        assert!((objective.severity() - 1.0).abs() < f64::EPSILON);
+        assert!(objective.is_severity_appraised());
+
+        let both = EventBuilder::new(EventType::Loss)
+            .objective_severity(0.3)
+            .severity(0.9)
             .build()
             .unwrap();
-        assert!(event_low.severity().abs() < f64::EPSILON);
+        assert_eq!(both.objective_severity(), Some(0.3));
+        assert!((both.severity() - 0.9).abs() < f64::EPSILON);
+        assert!(!both.is_severity_appraised());
+
+        let out_of_range = EventBuilder::new(EventType::Loss)
+            .objective_severity(1.2)
+            .build();
+        assert!(matches!(
+            out_of_range.unwrap_err(),
+            EventBuildError::OutOfRange { field, .. } if field == "objective_severity"
+        ));
     }
 
     #[test]
@@ -359,11 +779,22 @@ mod tests {
 
     #[test]
     fn event_build_error_display() {
-        let error = EventBuildError {
+        let error = EventBuildError::OutOfRange {
+            field: "severity".to_string(),
             reason: "test error".to_string(),
         };
         let display = format!("{}", error);
-        assert!(display.contains("test error"));
+        assert!(display.contains("severity: test error"));
+
+        let error = EventBuildError::PayloadMismatch {
+            expected: &[PayloadKind::Loss, PayloadKind::RoleExit],
+            found: PayloadKind::Support,
+        };
+        assert_eq!(
+            error.to_string(),
+            "Event build error: payload 'Support' does not match the event type \
+             (expected Empty or Loss or Role Exit)"
+        );
     }
 
     #[test]
@@ -411,14 +842,178 @@ mod tests {
     }
 
     #[test]
-    fn event_builder_base_shift_clamps_values() {
-        let event = EventBuilder::new(EventType::Violence)
-            .with_base_shift(HexacoPath::Openness, 2.0)
+    fn event_builder_ramp_sets_onset_profile() {
+        let event = EventBuilder::new(EventType::Conflict)
+            .ramp(Duration::days(90), RampShape::EaseIn)
+            .build()
+            .unwrap();
+        assert!(event.is_gradual());
+        assert_eq!(event.ramp_duration(), Duration::days(90));
+        assert_eq!(event.ramp_shape(), RampShape::EaseIn);
+    }
+
+    #[test]
+    fn acute_health_condition_defaults_to_course_ramp() {
+        let event = EventBuilder::new(EventType::HealthChange)
+            .payload(EventPayload::HealthCondition {
+                condition: ConditionKind::Infection,
+                chronic: false,
+                severity_course: CourseShape::Gradual,
+            })
+            .build()
+            .unwrap();
+        assert_eq!(event.ramp_duration(), ConditionKind::Infection.typical_onset());
+        assert_eq!(event.ramp_shape(), RampShape::Linear);
+    }
+
+    #[test]
+    fn explicit_ramp_overrides_health_condition_default() {
+        let event = EventBuilder::new(EventType::HealthChange)
+            .payload(EventPayload::HealthCondition {
+                condition: ConditionKind::Infection,
+                chronic: false,
+                severity_course: CourseShape::Gradual,
+            })
+            .ramp(Duration::days(10), RampShape::EaseIn)
+            .build()
+            .unwrap();
+        assert_eq!(event.ramp_duration(), Duration::days(10));
+        assert_eq!(event.ramp_shape(), RampShape::EaseIn);
+    }
+
+    #[test]
+    fn chronic_health_condition_has_no_default_ramp() {
+        let event = EventBuilder::new(EventType::ChronicIllnessOnset)
+            .payload(EventPayload::HealthCondition {
+                condition: ConditionKind::Autoimmune,
+                chronic: true,
+                severity_course: CourseShape::Gradual,
+            })
+            .build()
+            .unwrap();
+        assert!(!event.is_gradual());
+    }
+
+    #[test]
+    fn event_builder_defaults_to_instant_onset() {
+        let event = EventBuilder::new(EventType::Conflict).build().unwrap();
+        assert!(!event.is_gradual());
+        assert!(event.ramp_duration().is_zero());
+        assert_eq!(event.ramp_shape(), RampShape::Step);
+    }
+
+    #[test]
+    fn event_builder_base_shift_out_of_range_errors() {
+        let result = EventBuilder::new(EventType::Violence)
+            .with_base_shift(HexacoPath::Openness, 0.5)
             .with_base_shift(HexacoPath::Extraversion, -2.0)
+            .build();
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Event build error: Extraversion: Value -2 is outside [-1, 1]"
+        );
+    }
+
+    #[test]
+    fn event_builder_payload_out_of_range_errors() {
+        let result = EventBuilder::new(EventType::Support)
+            .payload(EventPayload::Support {
+                support_type: SupportType::Emotional,
+                effectiveness: 1.3,
+                audience: Audience::Private,
+            })
+            .build();
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Event build error: effectiveness: Value 1.3 is outside [0, 1]"
+        );
+
+        let result = EventBuilder::new(EventType::PolicyChange)
+            .payload(EventPayload::PolicyChange {
+                policy_area: crate::enums::PolicyArea::Healthcare,
+                favorability: -0.8,
+            })
+            .build();
+        assert!(result.is_ok());
+
+        let result = EventBuilder::new(EventType::Rest)
+            .payload(EventPayload::Sleep {
+                hours: 30.0,
+                quality: 0.5,
+            })
+            .build();
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Event build error: hours: Value 30 is outside [0, 24]"
+        );
+    }
+
+    #[test]
+    fn mismatched_payloads_are_rejected() {
+        let payloads = sample_payloads();
+        for event_type in EventType::all() {
+            let allowed = event_type.allowed_payloads();
+            for kind in allowed {
+                assert!(
+                    payloads.iter().any(|payload| payload.kind() == *kind),
+                    "{event_type:?} allows {kind:?}, which has no sample payload"
+                );
+            }
+            for payload in &payloads {
+                let result = EventBuilder::new(event_type)
+                    .payload(payload.clone())
+                    .build();
+                if allowed.contains(&payload.kind()) {
+                    assert_eq!(result.unwrap().payload(), payload);
+                } else {
+                    assert_eq!(
+                        result.unwrap_err(),
+                        EventBuildError::PayloadMismatch {
+                            expected: allowed,
+                            found: payload.kind(),
+                        },
+                        "{event_type:?} with {}",
+                        payload.name()
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn empty_payload_is_allowed_for_every_type() {
+        for event_type in EventType::all() {
+            let event = EventBuilder::new(event_type)
+                .payload(EventPayload::Empty)
+                .build()
+                .unwrap();
+            assert_eq!(event.payload(), &EventPayload::Empty);
+        }
+    }
+
+    #[test]
+    fn lenient_builder_keeps_mismatched_payload() {
+        let betrayal = EventPayload::Betrayal {
+            confidence_violated: 0.8,
+        };
+        let event = EventBuilder::new(EventType::Achievement)
+            .payload(betrayal.clone())
+            .validate_strict(false)
             .build()
             .unwrap();
+        assert_eq!(event.payload(), &betrayal);
 
-        assert!((event.base_shifts()[0].1 - 1.0).abs() < f32::EPSILON);
-        assert!((event.base_shifts()[1].1 - (-1.0)).abs() < f32::EPSILON);
+        // Range checks still apply
+        let result = EventBuilder::new(EventType::Achievement)
+            .payload(EventPayload::Betrayal {
+                confidence_violated: 1.5,
+            })
+            .validate_strict(false)
+            .build();
+        assert!(matches!(
+            result.unwrap_err(),
+            EventBuildError::OutOfRange { .. }
+        ));
     }
 }
@@ -6,7 +6,7 @@
 
 use crate::enums::{EventCategory, EventScope, EventTag, EventType};
 use crate::event::Event;
-use crate::types::{EntityId, EventId, MicrosystemId, SubscriptionId};
+use crate::types::{EntityId, EventId, GroupId, MicrosystemId, SubscriptionId};
 use std::collections::{HashMap, VecDeque};
 use uuid::Uuid;
 
@@ -28,10 +28,44 @@ impl std::fmt::Display for EventBusError {
 
 impl std::error::Error for EventBusError {}
 
-/// Filter criteria for event subscriptions.
+/// Filter criteria for event subscriptions and retrospective queries.
 ///
 /// All criteria are optional. If a criterion is set, events must match it.
 /// If not set, any value for that field is accepted.
+///
+/// Filters compose into a tree: `and`, `or` and `not` wrap filters in a
+/// new node whose `all_of`, `any_of` or `none_of` holds them. A filter
+/// matches when its own criteria match, every `all_of` filter matches, at
+/// least one `any_of` filter matches (if there are any), and no `none_of`
+/// filter matches.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::enums::{EventCategory, EventTag, EventType};
+/// use behavioral_pathways::event::{EventBuilder, EventFilter};
+/// use behavioral_pathways::types::EntityId;
+///
+/// let crew = [EntityId::new("ana").unwrap(), EntityId::new("ben").unwrap()];
+/// let filter = EventFilter::category(EventCategory::Trauma)
+///     .and(EventFilter::severity_range(0.6, 1.0))
+///     .and(EventFilter::target_in(&crew))
+///     .or(EventFilter::tag(EventTag::MoralViolation));
+///
+/// let assault = EventBuilder::new(EventType::Violence)
+///     .target(crew[0].clone())
+///     .severity(0.8)
+///     .build()
+///     .unwrap();
+/// assert!(filter.matches(&assault));
+///
+/// let scuffle = EventBuilder::new(EventType::Violence)
+///     .target(crew[0].clone())
+///     .severity(0.3)
+///     .build()
+///     .unwrap();
+/// assert!(!filter.matches(&scuffle));
+/// ```
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct EventFilter {
     /// Match events of specific types.
@@ -46,6 +80,24 @@ pub struct EventFilter {
     pub target: Option<EntityId>,
     /// Match events in a specific microsystem.
     pub microsystem: Option<MicrosystemId>,
+    /// Match events delivered to a specific group.
+    pub group: Option<GroupId>,
+    /// Match events ingested from a specific source system. Events without
+    /// provenance never match.
+    pub source_system: Option<String>,
+    /// Match events with severity within an inclusive `(min, max)` range.
+    pub severity: Option<(f64, f64)>,
+    /// Match events targeting any of these entities. Events without a
+    /// target never match.
+    pub targets: Option<Vec<EntityId>>,
+    /// Match events whose payload has this name (see `EventPayload::name`).
+    pub payload: Option<String>,
+    /// Filters that must all match.
+    pub all_of: Vec<EventFilter>,
+    /// Filters of which at least one must match, if any are given.
+    pub any_of: Vec<EventFilter>,
+    /// Filters none of which may match.
+    pub none_of: Vec<EventFilter>,
 }
 
 impl EventFilter {
@@ -55,6 +107,72 @@ impl EventFilter {
         EventFilter::default()
     }
 
+    /// Creates a filter matching events of one category.
+    #[must_use]
+    pub fn category(category: EventCategory) -> Self {
+        EventFilter::new().with_category(category)
+    }
+
+    /// Creates a filter matching events with severity from `min` to `max`,
+    /// inclusive.
+    #[must_use]
+    pub fn severity_range(min: f64, max: f64) -> Self {
+        EventFilter::new().with_severity_range(min, max)
+    }
+
+    /// Creates a filter matching events with a tag.
+    #[must_use]
+    pub fn tag(tag: EventTag) -> Self {
+        EventFilter::new().with_tag(tag)
+    }
+
+    /// Creates a filter matching events targeting any of `targets`.
+    #[must_use]
+    pub fn target_in(targets: &[EntityId]) -> Self {
+        EventFilter::new().with_targets(targets.to_vec())
+    }
+
+    /// Creates a filter matching events from a source entity.
+    #[must_use]
+    pub fn source(source: EntityId) -> Self {
+        EventFilter::new().with_source(source)
+    }
+
+    /// Creates a filter matching events whose payload has the given name,
+    /// such as `"Violence"` or `"Sleep"`.
+    #[must_use]
+    pub fn payload(name: impl Into<String>) -> Self {
+        EventFilter::new().with_payload(name)
+    }
+
+    /// Returns a filter matching events that match both filters.
+    #[must_use]
+    pub fn and(self, other: EventFilter) -> Self {
+        EventFilter {
+            all_of: vec![self, other],
+            ..EventFilter::default()
+        }
+    }
+
+    /// Returns a filter matching events that match either filter.
+    #[must_use]
+    pub fn or(self, other: EventFilter) -> Self {
+        EventFilter {
+            any_of: vec![self, other],
+            ..EventFilter::default()
+        }
+    }
+
+    /// Returns a filter matching events this filter does not match.
+    #[allow(clippy::should_implement_trait)] // Chains with and/or without importing ops::Not
+    #[must_use]
+    pub fn not(self) -> Self {
+        EventFilter {
+            none_of: vec![self],
+            ..EventFilter::default()
+        }
+    }
+
     /// Filters by event types.
     #[must_use]
     pub fn with_event_types(mut self, types: Vec<EventType>) -> Self {
@@ -118,6 +236,41 @@ impl EventFilter {
         self
     }
 
+    /// Filters by the group the event was delivered to.
+    #[must_use]
+    pub fn with_group(mut self, group: GroupId) -> Self {
+        self.group = Some(group);
+        self
+    }
+
+    /// Filters by the source system recorded in event provenance.
+    #[must_use]
+    pub fn with_source_system(mut self, system: impl Into<String>) -> Self {
+        self.source_system = Some(system.into());
+        self
+    }
+
+    /// Filters by severity, from `min` to `max` inclusive.
+    #[must_use]
+    pub fn with_severity_range(mut self, min: f64, max: f64) -> Self {
+        self.severity = Some((min, max));
+        self
+    }
+
+    /// Filters by target entity, accepting any of `targets`.
+    #[must_use]
+    pub fn with_targets(mut self, targets: Vec<EntityId>) -> Self {
+        self.targets = Some(targets);
+        self
+    }
+
+    /// Filters by payload name.
+    #[must_use]
+    pub fn with_payload(mut self, name: impl Into<String>) -> Self {
+        self.payload = Some(name.into());
+        self
+    }
+
     /// Checks if an event matches this filter.
     #[must_use]
     pub fn matches(&self, event: &Event) -> bool {
@@ -164,7 +317,48 @@ impl EventFilter {
             }
         }
 
-        true
+        // Check group
+        if let Some(ref group) = self.group {
+            if event.group() != Some(group) {
+                return false;
+            }
+        }
+
+        // Check source system
+        if let Some(ref system) = self.source_system {
+            if event.provenance().map(|p| &p.system) != Some(system) {
+                return false;
+            }
+        }
+
+        // Check severity range
+        if let Some((min, max)) = self.severity {
+            if !(min..=max).contains(&event.severity()) {
+                return false;
+            }
+        }
+
+        // Check target set
+        if let Some(ref targets) = self.targets {
+            if !event
+                .target()
+                .is_some_and(|target| targets.contains(target))
+            {
+                return false;
+            }
+        }
+
+        // Check payload
+        if let Some(ref payload) = self.payload {
+            if event.payload().name() != payload {
+                return false;
+            }
+        }
+
+        // Check child filters
+        self.all_of.iter().all(|filter| filter.matches(event))
+            && (self.any_of.is_empty() || self.any_of.iter().any(|filter| filter.matches(event)))
+            && !self.none_of.iter().any(|filter| filter.matches(event))
     }
 }
 
@@ -247,8 +441,7 @@ impl EventBus {
     /// # Returns
     ///
     /// The subscription ID for polling
-    #[allow(dead_code)] // Used in unit tests
-    pub(crate) fn subscribe(&mut self, filter: EventFilter) -> SubscriptionId {
+    pub fn subscribe(&mut self, filter: EventFilter) -> SubscriptionId {
         let id = generate_subscription_id();
         let subscription = Subscription {
             id: id.clone(),
@@ -275,8 +468,9 @@ impl EventBus {
 
     /// Dispatches an event to entities matching a scope.
     ///
-    /// For Individual scope, sets the event's target before delivery.
-    /// For Group/Microsystem/Global, the event is broadcast to all
+    /// For Individual scope, sets the event's target before delivery, and
+    /// for Group scope the event's group, so `EventFilter::with_group`
+    /// matches it. Group/Microsystem/Global events are broadcast to all
     /// matching subscribers.
     ///
     /// # Arguments
@@ -297,9 +491,11 @@ impl EventBus {
     /// bus.dispatch(event, EventScope::Global);
     /// ```
     pub fn dispatch(&mut self, mut event: Event, scope: EventScope) {
-        // For Individual scope, set the target
-        if let EventScope::Individual(entity_id) = scope {
-            event.set_target(Some(entity_id));
+        // For Individual scope, set the target; for Group scope, the group
+        match scope {
+            EventScope::Individual(entity_id) => event.set_target(Some(entity_id)),
+            EventScope::Group(group_id) => event.set_group(Some(group_id)),
+            EventScope::Microsystem(_) | EventScope::Global => {}
         }
 
         // Deliver to matching subscriptions
@@ -526,7 +722,6 @@ impl Default for EventBus {
 }
 
 /// Generates a unique subscription ID.
-#[allow(dead_code)] // Used by subscribe() which is used in unit tests
 fn generate_subscription_id() -> SubscriptionId {
     let uuid = Uuid::new_v4();
     SubscriptionId::new(format!("sub_{uuid}")).unwrap()
@@ -925,6 +1120,29 @@ mod tests {
         assert_eq!(bus.poll(&work_sub).len(), 1);
     }
 
+    #[test]
+    fn event_filter_by_source_system() {
+        let provenance = |system: &str| crate::event::Provenance {
+            system: system.to_string(),
+            external_id: "rec-1".to_string(),
+            ingested_at: crate::types::Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0),
+        };
+        let filter = EventFilter::new().with_source_system("ehr");
+
+        let from_ehr = EventBuilder::new(EventType::Loss)
+            .provenance(provenance("ehr"))
+            .build()
+            .unwrap();
+        let from_survey = EventBuilder::new(EventType::Loss)
+            .provenance(provenance("survey"))
+            .build()
+            .unwrap();
+
+        assert!(filter.matches(&from_ehr));
+        assert!(!filter.matches(&from_survey));
+        assert!(!filter.matches(&create_violence_event()));
+    }
+
     #[test]
     fn event_filter_clone() {
         let filter = EventFilter::new().with_event_type(EventType::Violence);
@@ -979,6 +1197,24 @@ mod tests {
         assert_eq!(bus.poll(&sub_id).len(), 1);
     }
 
+    #[test]
+    fn filter_matches_group() {
+        let mut bus = EventBus::new();
+        let team = GroupId::new("team").unwrap();
+        let team_sub = bus.subscribe(EventFilter::new().with_group(team.clone()));
+
+        bus.dispatch(create_violence_event(), EventScope::Group(team.clone()));
+        bus.dispatch(
+            create_violence_event(),
+            EventScope::Group(GroupId::new("other").unwrap()),
+        );
+        bus.dispatch(create_violence_event(), EventScope::Global);
+
+        let events = bus.poll(&team_sub);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].group(), Some(&team));
+    }
+
     #[test]
     fn dispatch_microsystem_scope() {
         let mut bus = EventBus::new();
@@ -1138,4 +1374,125 @@ mod tests {
         let debug = format!("{:?}", processed);
         assert!(debug.contains("ProcessedEvent"));
     }
+
+    fn event_with(event_type: EventType, severity: f64, target: &str) -> Event {
+        EventBuilder::new(event_type)
+            .severity(severity)
+            .target(EntityId::new(target).unwrap())
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn leaf_constructors_match_their_criterion() {
+        let assault = event_with(EventType::Violence, 0.8, "ana");
+        let support = event_with(EventType::Support, 0.3, "ben");
+        let ana = EntityId::new("ana").unwrap();
+        let crew = [ana.clone(), EntityId::new("cal").unwrap()];
+
+        let trauma = EventFilter::category(EventCategory::Trauma);
+        assert!(trauma.matches(&assault) && !trauma.matches(&support));
+
+        let severe = EventFilter::severity_range(0.6, 1.0);
+        assert!(severe.matches(&assault) && !severe.matches(&support));
+        // Both ends are inclusive
+        assert!(EventFilter::severity_range(0.3, 0.3).matches(&support));
+
+        let crew_filter = EventFilter::target_in(&crew);
+        assert!(crew_filter.matches(&assault) && !crew_filter.matches(&support));
+        assert!(!crew_filter.matches(&create_violence_event()));
+        assert!(!EventFilter::target_in(&[]).matches(&assault));
+
+        let tagged = EventBuilder::new(EventType::Betrayal)
+            .tag(EventTag::MoralViolation)
+            .build()
+            .unwrap();
+        let moral = EventFilter::tag(EventTag::MoralViolation);
+        assert!(moral.matches(&tagged) && !moral.matches(&assault));
+
+        let from_ana = EventBuilder::new(EventType::Support)
+            .source(ana.clone())
+            .build()
+            .unwrap();
+        assert!(EventFilter::source(ana).matches(&from_ana));
+        assert!(!EventFilter::source(crew[1].clone()).matches(&from_ana));
+    }
+
+    #[test]
+    fn payload_filter_matches_payload_name() {
+        let sleep = EventBuilder::new(EventType::Rest)
+            .payload(crate::enums::EventPayload::Sleep {
+                hours: 8.0,
+                quality: 0.9,
+            })
+            .build()
+            .unwrap();
+        assert!(EventFilter::payload("Sleep").matches(&sleep));
+        assert!(!EventFilter::payload("Sleep").matches(&create_violence_event()));
+        assert!(EventFilter::payload("Empty").matches(&create_violence_event()));
+    }
+
+    #[test]
+    fn combinators_nest() {
+        let crew = [EntityId::new("ana").unwrap()];
+        let severe_crew_trauma = EventFilter::category(EventCategory::Trauma)
+            .and(EventFilter::severity_range(0.6, 1.0))
+            .and(EventFilter::target_in(&crew));
+        let filter = severe_crew_trauma
+            .clone()
+            .or(EventFilter::tag(EventTag::MoralViolation));
+
+        let severe = event_with(EventType::Violence, 0.8, "ana");
+        let mild = event_with(EventType::Violence, 0.4, "ana");
+        let elsewhere = event_with(EventType::Violence, 0.8, "ben");
+        let moral = EventBuilder::new(EventType::Betrayal)
+            .severity(0.1)
+            .tag(EventTag::MoralViolation)
+            .build()
+            .unwrap();
+
+        assert!(filter.matches(&severe));
+        assert!(!filter.matches(&mild));
+        assert!(!filter.matches(&elsewhere));
+        assert!(filter.matches(&moral));
+
+        let negated = filter.clone().not();
+        for event in [&severe, &mild, &elsewhere, &moral] {
+            assert_ne!(negated.matches(event), filter.matches(event));
+        }
+        assert_eq!(
+            negated.clone().not().matches(&severe),
+            filter.matches(&severe)
+        );
+
+        // Own criteria combine with child filters
+        let mild_trauma = EventFilter::category(EventCategory::Trauma)
+            .and(EventFilter::severity_range(0.6, 1.0).not());
+        assert!(mild_trauma.matches(&mild));
+        assert!(!mild_trauma.matches(&severe));
+        assert!(!mild_trauma.matches(&moral));
+    }
+
+    #[test]
+    fn subscriptions_dispatch_through_the_tree() {
+        let mut bus = EventBus::new();
+        let crew = [EntityId::new("ana").unwrap()];
+        let sub_id = bus.subscribe(
+            EventFilter::category(EventCategory::Trauma)
+                .and(EventFilter::target_in(&crew))
+                .or(EventFilter::tag(EventTag::MoralViolation)),
+        );
+
+        bus.dispatch(
+            event_with(EventType::Violence, 0.8, "ben"),
+            EventScope::Individual(crew[0].clone()),
+        );
+        bus.dispatch(
+            event_with(EventType::Violence, 0.8, "ben"),
+            EventScope::Global,
+        );
+        bus.dispatch(create_support_event(), EventScope::Global);
+
+        assert_eq!(bus.poll(&sub_id).len(), 1);
+    }
 }
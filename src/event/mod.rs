@@ -10,6 +10,9 @@
 //! - [`EventBuilder`] - Fluent construction
 //! - [`EventBus`] - Dispatch and subscription
 //! - [`EventFilter`] - Subscription filtering
+//! - [`Provenance`] - Source-system metadata for ingested events
+//! - [`BlueprintSummary`] - Default effects of each event type
+//! - [`SeverityAppraisal`] - Objective and subjective severity of an event
 //!
 //! # Usage
 //!
@@ -31,15 +34,23 @@
 //! assert!(bus.has_pending());
 //! ```
 
+mod appraisal;
+mod blueprint;
 #[allow(clippy::module_inception)]
 mod event;
 mod event_builder;
 mod event_bus;
+mod provenance;
 mod salience;
 
+pub(crate) use appraisal::appraise_severity;
+pub use appraisal::{SeverityAppraisal, IMPLAUSIBLE_APPRAISAL_GAP};
+pub(crate) use blueprint::base_impact;
+pub use blueprint::BlueprintSummary;
 pub use event::Event;
 pub use event_builder::{EventBuildError, EventBuilder};
 pub use event_bus::{EventBus, EventBusError, EventFilter, ProcessedEvent, MAX_CASCADE_DEPTH};
+pub use provenance::Provenance;
 pub use salience::{
     arousal_weight_for_species, compute_arousal_modulated_salience, AROUSAL_CEILING,
     AROUSAL_THRESHOLD, AROUSAL_WEIGHT_ANIMAL, AROUSAL_WEIGHT_HUMAN, AROUSAL_WEIGHT_ROBOTIC,
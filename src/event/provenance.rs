@@ -0,0 +1,80 @@
+//! Source-system metadata for ingested events.
+//!
+//! Events imported from an external system (an EHR, a survey platform, game
+//! telemetry) can record where they came from so they can be audited. The
+//! metadata is carried with the event, compared by `Simulation::diff`, and
+//! matched by `EventFilter`, but never read during interpretation: an event
+//! with provenance affects state exactly as the same event without it.
+
+use crate::types::Timestamp;
+use std::fmt;
+
+/// Where an event was ingested from.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::enums::EventType;
+/// use behavioral_pathways::event::{EventBuilder, Provenance};
+/// use behavioral_pathways::types::Timestamp;
+///
+/// let provenance = Provenance {
+///     system: "ehr".to_string(),
+///     external_id: "enc-2291".to_string(),
+///     ingested_at: Timestamp::from_ymd_hms(2024, 3, 2, 9, 0, 0),
+/// };
+/// let event = EventBuilder::new(EventType::Loss)
+///     .severity(0.6)
+///     .provenance(provenance.clone())
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(event.provenance(), Some(&provenance));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Provenance {
+    /// Name of the source system, such as `"ehr"` or `"survey"`.
+    pub system: String,
+    /// Identifier of the record in the source system.
+    pub external_id: String,
+    /// When the record was ingested.
+    pub ingested_at: Timestamp,
+}
+
+impl fmt::Display for Provenance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{} (ingested {})",
+            self.system, self.external_id, self.ingested_at
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn provenance() -> Provenance {
+        Provenance {
+            system: "survey".to_string(),
+            external_id: "resp-17".to_string(),
+            ingested_at: Timestamp::from_ymd_hms(2024, 5, 1, 12, 0, 0),
+        }
+    }
+
+    #[test]
+    fn display_names_system_and_record() {
+        let text = provenance().to_string();
+        assert!(text.starts_with("survey:resp-17 (ingested "));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip() {
+        let json = serde_json::to_string(&provenance()).unwrap();
+        let back: Provenance = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, provenance());
+    }
+}
@@ -50,6 +50,7 @@ pub fn arousal_weight_for_species(species: &Species) -> f32 {
             AROUSAL_WEIGHT_HUMAN
         }
         Species::Horse => AROUSAL_WEIGHT_ANIMAL,
+        Species::Robotic => AROUSAL_WEIGHT_ROBOTIC,
         Species::Custom { .. } => AROUSAL_WEIGHT_HUMAN,
     }
 }
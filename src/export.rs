@@ -0,0 +1,390 @@
+//! Export of state trajectories for analysis outside the crate.
+//!
+//! `TrajectoryExporter` samples an entity's effective state over a range at
+//! a fixed step and returns the samples as `TrajectoryRow`s, or writes them
+//! as CSV or JSON Lines to any `io::Write`. Columns are keyed by the
+//! canonical `StatePath` name (`Mood::Valence`), which `str::parse` reads
+//! back, and values are written in Rust's shortest round-trip form, so
+//! `read_csv()` recovers the exact values written.
+//!
+//! # Examples
+//!
+//! ```
+//! use behavioral_pathways::entity::EntityBuilder;
+//! use behavioral_pathways::enums::{MoodPath, NeedsPath, Species, StatePath};
+//! use behavioral_pathways::export::{read_csv, TrajectoryExporter};
+//! use behavioral_pathways::simulation::Simulation;
+//! use behavioral_pathways::types::{Duration, Timestamp};
+//!
+//! let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+//! let mut sim = Simulation::new(reference);
+//! let entity = EntityBuilder::new().id("person_001").species(Species::Human).build().unwrap();
+//! let id = sim.add_entity(entity, reference);
+//!
+//! let exporter = TrajectoryExporter::new(sim.entity(&id).unwrap())
+//!     .paths(&[StatePath::Mood(MoodPath::Valence), StatePath::Needs(NeedsPath::Stress)])
+//!     .step(Duration::days(1))
+//!     .range(reference, reference + Duration::days(6));
+//!
+//! let mut csv = Vec::new();
+//! exporter.write_csv(&mut csv).unwrap();
+//! assert!(csv.starts_with(b"timestamp,Mood::Valence,Needs::Stress\n"));
+//! assert_eq!(read_csv(csv.as_slice()).unwrap(), exporter.rows());
+//! ```
+
+use crate::enums::StatePath;
+use crate::simulation::EntityQueryHandle;
+use crate::types::{Duration, Timestamp};
+use std::fmt;
+use std::io;
+
+/// Samples an entity's state trajectory for export.
+///
+/// Defaults to every `StatePath`, a one-day step, and a single sample at
+/// the entity's anchor. Sampling follows `EntityQueryHandle::state_range()`:
+/// from the range start to its end inclusive, and a single sample at the
+/// start for a zero step.
+pub struct TrajectoryExporter<'a> {
+    /// The entity sampled.
+    handle: EntityQueryHandle<'a>,
+    /// Exported paths, in column order.
+    paths: Vec<StatePath>,
+    /// Time between samples.
+    step: Duration,
+    /// First and last sample times, if set.
+    range: Option<(Timestamp, Timestamp)>,
+}
+
+impl<'a> TrajectoryExporter<'a> {
+    /// Creates an exporter for the entity behind `handle`.
+    #[must_use]
+    pub fn new(handle: EntityQueryHandle<'a>) -> Self {
+        TrajectoryExporter {
+            handle,
+            paths: StatePath::all(),
+            step: Duration::days(1),
+            range: None,
+        }
+    }
+
+    /// Sets the exported paths, in column order.
+    #[must_use]
+    pub fn paths(mut self, paths: &[StatePath]) -> Self {
+        self.paths = paths.to_vec();
+        self
+    }
+
+    /// Sets the time between samples.
+    #[must_use]
+    pub fn step(mut self, step: Duration) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the first and last sample times.
+    #[must_use]
+    pub fn range(mut self, start: Timestamp, end: Timestamp) -> Self {
+        self.range = Some((start, end));
+        self
+    }
+
+    /// Returns the exported paths, in column order.
+    #[must_use]
+    pub fn exported_paths(&self) -> &[StatePath] {
+        &self.paths
+    }
+
+    /// Samples the trajectory.
+    ///
+    /// Returns no rows if the entity is not in the simulation or the range
+    /// ends before it starts.
+    #[must_use]
+    pub fn rows(&self) -> Vec<TrajectoryRow> {
+        let Some((start, end)) = self.range.or_else(|| {
+            self.handle
+                .anchor_timestamp()
+                .map(|anchor| (anchor, anchor))
+        }) else {
+            return Vec::new();
+        };
+        self.handle
+            .state_range(start, end, self.step)
+            .into_iter()
+            .map(|(timestamp, state)| TrajectoryRow {
+                timestamp,
+                values: self
+                    .paths
+                    .iter()
+                    .map(|path| (*path, state.get_effective(*path)))
+                    .collect(),
+            })
+            .collect()
+    }
+
+    /// Writes the trajectory as CSV.
+    ///
+    /// The header is `timestamp` followed by the canonical name of each
+    /// path. Each row starts with the sample time as `Timestamp` displays.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from `writer`.
+    pub fn write_csv<W: io::Write>(&self, mut writer: W) -> io::Result<()> {
+        write!(writer, "timestamp")?;
+        for path in &self.paths {
+            write!(writer, ",{path}")?;
+        }
+        writeln!(writer)?;
+        for row in self.rows() {
+            write!(writer, "{}", row.timestamp)?;
+            for (_, value) in &row.values {
+                write!(writer, ",{value}")?;
+            }
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the trajectory as JSON Lines, one object per sample.
+    ///
+    /// Each object has a `timestamp` string and one number per path, keyed
+    /// by its canonical name, in column order.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error from `writer`.
+    pub fn write_json_lines<W: io::Write>(&self, mut writer: W) -> io::Result<()> {
+        for row in self.rows() {
+            write!(writer, "{{\"timestamp\":\"{}\"", row.timestamp)?;
+            // Canonical path names need no escaping, and JSON has no
+            // literal for a non-finite number
+            for (path, value) in &row.values {
+                if value.is_finite() {
+                    write!(writer, ",\"{path}\":{value}")?;
+                } else {
+                    write!(writer, ",\"{path}\":null")?;
+                }
+            }
+            writeln!(writer, "}}")?;
+        }
+        Ok(())
+    }
+}
+
+/// One sample of an exported trajectory.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrajectoryRow {
+    /// When the state was sampled.
+    timestamp: Timestamp,
+    /// Effective value of each exported path, in column order.
+    values: Vec<(StatePath, f64)>,
+}
+
+impl TrajectoryRow {
+    /// Creates a row from its sample time and values.
+    #[must_use]
+    pub fn new(timestamp: Timestamp, values: Vec<(StatePath, f64)>) -> Self {
+        TrajectoryRow { timestamp, values }
+    }
+
+    /// Returns when the state was sampled.
+    #[must_use]
+    pub fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+
+    /// Returns each path's effective value, in column order.
+    #[must_use]
+    pub fn values(&self) -> &[(StatePath, f64)] {
+        &self.values
+    }
+
+    /// Returns the value for a path, if it was exported.
+    #[must_use]
+    pub fn get(&self, path: StatePath) -> Option<f64> {
+        self.values
+            .iter()
+            .find(|(exported, _)| *exported == path)
+            .map(|(_, value)| *value)
+    }
+}
+
+/// Reads rows written by `TrajectoryExporter::write_csv()`.
+///
+/// Blank lines are skipped.
+///
+/// # Errors
+///
+/// Returns a `TrajectoryReadError` if reading fails, the header names an
+/// unknown path, or a row is malformed.
+pub fn read_csv<R: io::BufRead>(reader: R) -> Result<Vec<TrajectoryRow>, TrajectoryReadError> {
+    let mut paths = None;
+    let mut rows = Vec::new();
+    for (index, line) in reader.lines().enumerate() {
+        let line = line.map_err(TrajectoryReadError::Io)?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let line_number = index + 1;
+        let mut fields = line.split(',');
+        let first = fields.next().unwrap_or_default();
+
+        let Some(paths) = &paths else {
+            paths = Some(
+                fields
+                    .map(|name| {
+                        name.parse::<StatePath>()
+                            .map_err(|_| TrajectoryReadError::UnknownPath(name.to_string()))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?,
+            );
+            continue;
+        };
+
+        let timestamp =
+            Timestamp::from_str(first).map_err(|_| TrajectoryReadError::InvalidTimestamp {
+                line: line_number,
+                value: first.to_string(),
+            })?;
+        let values = fields
+            .map(|value| {
+                value
+                    .parse::<f64>()
+                    .map_err(|_| TrajectoryReadError::InvalidValue {
+                        line: line_number,
+                        value: value.to_string(),
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if values.len() != paths.len() {
+            return Err(TrajectoryReadError::ColumnCount {
+                line: line_number,
+                expected: paths.len(),
+                found: values.len(),
+            });
+        }
+        rows.push(TrajectoryRow {
+            timestamp,
+            values: paths.iter().copied().zip(values).collect(),
+        });
+    }
+    if paths.is_none() {
+        return Err(TrajectoryReadError::MissingHeader);
+    }
+    Ok(rows)
+}
+
+/// Errors from reading an exported trajectory CSV.
+#[derive(Debug)]
+pub enum TrajectoryReadError {
+    /// Reading the input failed.
+    Io(io::Error),
+    /// The CSV has no header line.
+    MissingHeader,
+    /// The header names a column that is not a state path.
+    UnknownPath(String),
+    /// A sample time is not a timestamp.
+    InvalidTimestamp {
+        /// One-based line number.
+        line: usize,
+        /// The unparseable timestamp.
+        value: String,
+    },
+    /// A value is not a number.
+    InvalidValue {
+        /// One-based line number.
+        line: usize,
+        /// The unparseable value.
+        value: String,
+    },
+    /// A row has the wrong number of values.
+    ColumnCount {
+        /// One-based line number.
+        line: usize,
+        /// Values expected from the header.
+        expected: usize,
+        /// Values found on the line.
+        found: usize,
+    },
+}
+
+impl fmt::Display for TrajectoryReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrajectoryReadError::Io(error) => write!(f, "Failed to read trajectory: {}", error),
+            TrajectoryReadError::MissingHeader => write!(f, "Trajectory CSV has no header"),
+            TrajectoryReadError::UnknownPath(name) => {
+                write!(f, "Unknown state path in header: {}", name)
+            }
+            TrajectoryReadError::InvalidTimestamp { line, value } => {
+                write!(f, "Invalid timestamp on line {}: {}", line, value)
+            }
+            TrajectoryReadError::InvalidValue { line, value } => {
+                write!(f, "Invalid value on line {}: {}", line, value)
+            }
+            TrajectoryReadError::ColumnCount {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Line {} has {} values, expected {}",
+                line, found, expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TrajectoryReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TrajectoryReadError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::MoodPath;
+
+    fn valence() -> StatePath {
+        StatePath::Mood(MoodPath::Valence)
+    }
+
+    #[test]
+    fn malformed_csv_is_rejected() {
+        assert!(matches!(
+            read_csv("".as_bytes()),
+            Err(TrajectoryReadError::MissingHeader)
+        ));
+        assert!(matches!(
+            read_csv("timestamp,Mood::Nope\n".as_bytes()),
+            Err(TrajectoryReadError::UnknownPath(name)) if name == "Mood::Nope"
+        ));
+        let bad_time = "timestamp,Mood::Valence\n2024-01-01,0.1\n";
+        assert!(matches!(
+            read_csv(bad_time.as_bytes()),
+            Err(TrajectoryReadError::InvalidTimestamp { line: 2, .. })
+        ));
+        let short = "timestamp,Mood::Valence\n\n2024-01-01 00:00:00\n";
+        assert!(matches!(
+            read_csv(short.as_bytes()),
+            Err(TrajectoryReadError::ColumnCount {
+                line: 3,
+                expected: 1,
+                found: 0
+            })
+        ));
+    }
+
+    #[test]
+    fn row_values_are_looked_up_by_path() {
+        let timestamp = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let row = TrajectoryRow::new(timestamp, vec![(valence(), -0.25)]);
+        assert_eq!(row.timestamp(), timestamp);
+        assert_eq!(row.get(valence()), Some(-0.25));
+        assert_eq!(row.get(StatePath::Mood(MoodPath::Arousal)), None);
+    }
+}
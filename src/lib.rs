@@ -50,31 +50,81 @@
 //! Internal methods like `advance()`, `regress_by()`, `apply_decay()`, and
 //! `reverse_decay()` are implementation details used by `state_at()` and
 //! should not be called directly by consumers.
+//!
+//! # Stability
+//!
+//! Everything in the rendered docs is stable and follows semver. Working
+//! below the `Simulation` API goes through these supported wrappers:
+//!
+//! - `Entity::interpret_event(&event)` - preview an event's deltas
+//! - `EmotionIntensities::from_pad(v, a, d, flag)` - emotions from explicit PAD values
+//! - `IndividualState::apply_species_decay(elapsed, &species)` - decay a standalone state
+//!
+//! The hidden `unstable` module keeps older processor entry points
+//! (`interpret_event`, `apply_interpreted_event`, `process_event`,
+//! `derive_emotion`) as deprecated shims with no stability guarantee.
+//!
+//! # Feature Flags
+//!
+//! `context`, `memory`, `relationships`, and `its` are on by default.
+//! Consumers that only need mood and needs projection can disable them to
+//! compile less code:
+//!
+//! - `context` - ecological context, role exit, and health condition effects
+//!   in `state_at()`
+//! - `memory` - memory consolidation and priming in `state_at()`, and
+//!   memories formed from events and `record_interaction`
+//! - `relationships` - trust antecedents from events, and relationship
+//!   support in the loneliness buffer (which needs `context` too)
+//! - `its` - ITS contributors from `ComputedState::its_contributors()` and
+//!   model alerts from `ComputedState::alerts()`
+//! - `parallel` - evaluates `Simulation::alert_sweep` and
+//!   `Simulation::states_at` entities, and forecast branches, on scoped
+//!   threads
+//! - `serde` - serde derives on every public type, so a whole
+//!   `Simulation` serializes and deserializes, and `IndividualState`
+//!   JSON snapshots
+//! - `contract-tests` - the `contract_tests` module of reusable checks for
+//!   downstream species, blueprints, and simulations
+//!
+//! Disabling a default feature is non-breaking: every type, `StatePath`, and
+//! `ContextPath` remains available. Context values, memories, and
+//! relationships can still be stored and read, but `state_at()` skips the
+//! matching hook, so those values keep their neutral effect on state,
+//! `alerts()` returns external alerts only, and `its_contributors()` is
+//! empty.
 
 pub mod context;
+#[cfg(any(test, feature = "contract-tests"))]
+pub mod contract_tests;
+pub mod docs;
 pub mod entity;
 pub mod enums;
 pub mod event;
+pub mod export;
 pub mod memory;
 pub(crate) mod processor;
 pub mod relationship;
 pub mod simulation;
 pub mod state;
+pub mod test_scenarios;
 pub mod types;
+#[doc(hidden)]
+pub mod unstable;
 
 // Re-export entity types at crate root
 pub use entity::{AffectiveState, Entity, EntityBuildError, EntityBuilder, PhysiologicalState};
 
 // Re-export commonly used enums at crate root
 pub use enums::{
-    AlertSeverity, AlertTrigger, Attribution, AttributionStability, BirthEra, BondType,
-    ChronosystemPath,
+    AlertProvenance, AlertSeverity, AlertTrigger, Attribution, AttributionStability, BirthEra,
+    BondType, ChronosystemPath,
     ContextPath, Direction, DirectionalPath, DispositionPath, EducationPath, Emotion,
     EventCategory, EventPayload, EventScope, EventTag, EventType, ExosystemPath, FamilyPath,
     HealthcarePath, HexacoPath, HistoricalEventType, HistoricalScope, InteractionTopic, LifeDomain,
     LifeStage, LossType, MacrosystemPath, MentalHealthPath, MicrosystemPath, MoodPath, NeedsPath,
-    NeighborhoodPath, PersonCharacteristicsPath, PersonalityProfile, PolicyArea, RealizationType,
-    RelPath, RelationshipSchema, ReligiousPath, ReversibilityError, ReversibilityResult,
+    NeighborhoodPath, ParsePathError, PersonCharacteristicsPath, PersonalityProfile,
+    PolicyArea, RealizationType, RelPath, RelationshipSchema, ReligiousPath, ReversibilityError, ReversibilityResult,
     SharedPath, SocialCognitionPath, SocialPath, Species, SpiralType, StatePath, SubsystemId,
     SupportType, TraumaType, TrustPath, WeaponType, WorkPath,
 };
@@ -86,52 +136,64 @@ pub use context::{
     FamilyRole, HealthcareContext, HistoricalPeriod, InstitutionalStructure, InteractionProfile,
     MacrosystemConstraintSet, MacrosystemContext, MesosystemCache, MesosystemLinkage, Microsystem,
     MicrosystemType, NeighborhoodContext, NonNormativeEvent, NormativeTransition, ParentWorkQuality,
-    ProximalProcessGateError, ReligiousContext, SocialContext, TurningPoint, TurningPointDomain,
-    WorkContext, INTERACTION_COMPLEXITY_THRESHOLD, INTERACTION_FREQUENCY_THRESHOLD,
+    ProximalProcessGateError, ReligiousContext, RoleExit, SocialContext, TurningPoint,
+    TurningPointDomain, WorkContext, INTERACTION_COMPLEXITY_THRESHOLD,
+    INTERACTION_FREQUENCY_THRESHOLD,
 };
 
 // NOTE: Processor module contains internal implementation details.
 // Consumers should use the Simulation API (state_at) instead of calling
 // processor functions directly.
-// Exception: EmotionIntensities is exported for derived emotion access.
-pub use processor::EmotionIntensities;
+// Exceptions: EmotionIntensities and InterpretedEvent are returned by the
+// stable wrappers (EmotionIntensities::from_pad, Entity::interpret_event),
+// and the ITS types by ComputedState::its_factors and its_contributors.
+pub use processor::{
+    AcContributor, ConvergenceStatus, EmotionIntensities, InterpretedEvent, ItsContributor,
+    ItsFactors, ItsProximalFactor, PbContributor, TbContributor, ACUTE_CONTRIBUTOR_DECAY_HALF_LIFE,
+    CONTRIBUTOR_ACTIVATION_THRESHOLD,
+};
 
 // Re-export simulation types at crate root
 pub use simulation::{
     AnchoredEntity, ComputedState, EntityQueryHandle, RegressionQuality, Simulation,
-    SimulationBuildError, SimulationBuilder, TimestampedEvent, TimestampedRelationship,
+    SimulationBuildError, SimulationBuilder, SimulationDiff, TimestampedAlert, TimestampedEvent,
+    TimestampedRelationship,
 };
 
 // Re-export commonly used state types at crate root
 pub use state::{
-    age_plasticity, apply_formative_modifiers, combined_plasticity, cumulative_in_direction,
-    effective_base_at, saturation_factor, sensitive_period_modifier, species_plasticity_modifier,
-    stability_coefficient, trait_modifier, BaseShiftRecord, DemandCharacteristics, Demographical,
-    Disposition, EntityModelConfig, Hexaco, IndividualState, MentalHealth, Mood, Needs,
-    PersonCharacteristics, SocialCognition, StateValue, CUMULATIVE_CAP, HOPELESSNESS_THRESHOLD,
-    MAX_SINGLE_EVENT_SHIFT, PB_PRESENT_THRESHOLD, SATURATION_CONSTANT, SETTLING_DAYS,
-    SEVERE_SHIFT_RETENTION, SEVERE_SHIFT_THRESHOLD, TB_PRESENT_THRESHOLD,
+    age_plasticity, apply_formative_modifiers, audit_formative_modifiers, combined_plasticity,
+    cumulative_in_direction, effective_base_at, saturation_factor, sensitive_period_modifier,
+    species_plasticity_modifier, stability_coefficient, trait_modifier, BaseShiftRecord,
+    DecayProfile, DeltaBreakdown, DemandCharacteristics, Demographical, Disposition,
+    EntityModelConfig, FormativeShiftAudit, Hexaco, IndividualState, LifeStageTable, MentalHealth,
+    Mood, Needs, PersonCharacteristics, ShiftCap, SocialCognition, StateValue, CUMULATIVE_CAP,
+    HOPELESSNESS_THRESHOLD, MAX_SINGLE_EVENT_SHIFT, PB_PRESENT_THRESHOLD, SATURATION_CONSTANT,
+    SETTLING_DAYS, SEVERE_SHIFT_RETENTION, SEVERE_SHIFT_THRESHOLD, TB_PRESENT_THRESHOLD,
 };
 
 // Re-export relationship types at crate root
 pub use relationship::{
-    AntecedentDirection, AntecedentMapping, AntecedentType, DirectionalDimensions,
-    InteractionPattern, PerceivedRisk, Relationship, RelationshipError, RelationshipStage,
-    SharedDimensions, StakesLevel, TrustAntecedent, TrustContext, TrustDecision,
-    TrustworthinessFactors, Vulnerability, VulnerabilityType, TRUST_ANTECEDENT_TABLE,
+    AntecedentDirection, AntecedentHalfLives, AntecedentMapping, AntecedentType,
+    DirectionalDimensions, DormancyConfig, InteractionPattern, PerceivedRisk, Relationship,
+    RelationshipActivity, RelationshipError, RelationshipStage, SharedDimensions, StakesLevel,
+    TrustAntecedent, TrustContext, TrustDecision, TrustDecisionBreakdown, TrustworthinessFactors,
+    Vulnerability, VulnerabilityType, WeightedEvidence, WeightedTrustworthiness,
+    TRUST_ANTECEDENT_TABLE,
 };
 
 // Re-export event types at crate root
 pub use event::{
-    arousal_weight_for_species, compute_arousal_modulated_salience, Event, EventBuildError,
-    EventBuilder, EventBus, EventBusError, EventFilter, AROUSAL_CEILING, AROUSAL_THRESHOLD,
-    AROUSAL_WEIGHT_ANIMAL, AROUSAL_WEIGHT_HUMAN, AROUSAL_WEIGHT_ROBOTIC,
-    EXTREME_AROUSAL_IMPAIRMENT, MAX_CASCADE_DEPTH, NEGATIVITY_BIAS_MULTIPLIER,
+    arousal_weight_for_species, compute_arousal_modulated_salience, BlueprintSummary, Event,
+    EventBuildError, EventBuilder, EventBus, EventBusError, EventFilter, AROUSAL_CEILING,
+    AROUSAL_THRESHOLD, AROUSAL_WEIGHT_ANIMAL, AROUSAL_WEIGHT_HUMAN, AROUSAL_WEIGHT_ROBOTIC,
+    EXTREME_AROUSAL_IMPAIRMENT, IMPLAUSIBLE_APPRAISAL_GAP, MAX_CASCADE_DEPTH,
+    NEGATIVITY_BIAS_MULTIPLIER, Provenance, SeverityAppraisal,
 };
 
 // Re-export commonly used types at crate root
 pub use types::{
     duration_to_timestamp, timestamp_to_duration, Alert, Duration, EntityId, EventId, GroupId,
-    MemoryId, MicrosystemId, RelationshipId, RelationshipSlot, SubscriptionId, Timestamp,
-    TimestampParseError,
+    MemoryId, MicrosystemId, RelationshipId, RelationshipSlot, SignedUnit, SubscriptionId,
+    Timestamp, TimestampParseError, UnitInterval,
 };
@@ -12,8 +12,9 @@
 //! - Positive mood + positive memories -> positive valence priming delta
 //! - High-salience memory retrieval -> arousal priming delta
 //!
-//! Memory layer mutations (promotion, decay removal) are deferred to Phase 12
-//! as they require entity mutation which contradicts the pure `state_at()` model.
+//! Priming reads the memories as stored at the anchor. Layer promotion and
+//! forgetting over time are computed without mutating the entity by
+//! `maintenance::consolidate_at`, which `memories_at()` uses.
 
 use crate::memory::{MemoryLayers, MemoryTag};
 use crate::state::{IndividualState, Mood};
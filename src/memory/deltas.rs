@@ -23,6 +23,7 @@ use crate::types::EntityId;
 /// assert!((delta.trust_integrity().unwrap() - (-0.15)).abs() < f32::EPSILON);
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RelationshipDelta {
     /// The target entity whose relationship changed.
     target_entity: EntityId,
@@ -164,6 +165,7 @@ impl RelationshipDelta {
 /// assert!(delta.trusted().is_none());
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReputationDelta {
     /// Change to trusted reputation.
     trusted: Option<f32>,
@@ -246,6 +248,7 @@ impl ReputationDelta {
 /// assert!(deltas.relationship_delta().is_some());
 /// ```
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeltasApplied {
     /// Changes to a relationship.
     relationship_delta: Option<RelationshipDelta>,
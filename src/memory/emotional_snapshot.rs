@@ -26,6 +26,7 @@ use crate::state::Mood;
 /// assert!((snapshot.dominance() - (-0.2)).abs() < f32::EPSILON);
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EmotionalSnapshot {
     /// Valence: pleasantness of emotional experience.
     /// Range: -1 (displeasure) to +1 (pleasure)
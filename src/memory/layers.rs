@@ -42,6 +42,7 @@ pub const LONG_TERM_CAPACITY: usize = 50;
 /// assert_eq!(layers.immediate_count(), 1);
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryLayers {
     /// Immediate memories (capacity: 10, horizon: minutes-hours).
     immediate: Vec<MemoryEntry>,
@@ -58,6 +59,7 @@ pub struct MemoryLayers {
 
 /// Specifies which memory layer to operate on.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MemoryLayer {
     /// Immediate memory layer (capacity: 10).
     Immediate,
@@ -133,8 +135,9 @@ impl MemoryLayers {
     /// Adds a memory to the specified layer.
     ///
     /// If the layer is at capacity, evicts the lowest-salience entry first.
-    /// Legacy layer has unlimited capacity.
-    pub fn add(&mut self, layer: MemoryLayer, entry: MemoryEntry) {
+    /// Legacy layer has unlimited capacity. The entry's `layer()` records
+    /// where it was added.
+    pub fn add(&mut self, layer: MemoryLayer, mut entry: MemoryEntry) {
         let (vec, capacity) = match layer {
             MemoryLayer::Immediate => (&mut self.immediate, Some(IMMEDIATE_CAPACITY)),
             MemoryLayer::ShortTerm => (&mut self.short_term, Some(SHORT_TERM_CAPACITY)),
@@ -149,6 +152,7 @@ impl MemoryLayers {
             }
         }
 
+        entry.set_layer(layer);
         vec.push(entry);
     }
 
@@ -9,6 +9,15 @@
 //! NOT during `state_at()` queries. The caller is responsible for invoking
 //! maintenance at appropriate intervals (recommended: once per simulated day).
 //!
+//! `consolidate_at` is the query-time counterpart: without touching the
+//! stored layers, it computes where each memory would be at a given age,
+//! with its salience decayed and faded memories forgotten. `memories_at()`
+//! returns this consolidated view.
+//!
+//! Consolidation windows are in psychological time. Use
+//! `apply_species_memory_maintenance` for non-human entities, whose windows
+//! pass faster in real time.
+//!
 //! # Layer Promotion Rules
 //!
 //! - Immediate -> Short-term: salience >= 0.3, 1 hour base window
@@ -22,7 +31,10 @@
 //! - Optimal arousal (0.0 raw, 0.5 normalized) = base window (fastest)
 //! - Extreme arousal (-1.0 or 1.0 raw) = 2x base window (slowest)
 
-use crate::memory::{MemoryEntry, MemoryLayer, MemoryLayers, MemoryTag};
+use crate::enums::Species;
+use crate::memory::{
+    MemoryEntry, MemoryLayer, MemoryLayers, MemoryTag, DEFAULT_SALIENCE_HALF_LIFE_DAYS,
+};
 use crate::types::{Duration, MemoryId};
 
 /// Salience threshold for Immediate -> Short-term promotion.
@@ -37,6 +49,10 @@ const THRESHOLD_LONG_TO_LEGACY: f32 = 0.9;
 /// Salience threshold below which memories are removed from Short-term.
 const DECAY_THRESHOLD: f32 = 0.2;
 
+/// Salience below which `consolidate_at` forgets a memory, unless it is
+/// in the legacy layer or tagged as trauma.
+pub const FORGETTING_THRESHOLD: f32 = 0.05;
+
 /// Base consolidation window for Immediate -> Short-term (in hours).
 /// Used as the base value for arousal-modulated consolidation.
 const BASE_WINDOW_IMMEDIATE_HOURS: f32 = 1.0;
@@ -474,6 +490,41 @@ pub fn decay_low_salience(
 pub fn apply_memory_maintenance(
     memories: &mut MemoryLayers,
     elapsed: Duration,
+) -> Result<MaintenanceReport, MaintenanceError> {
+    apply_species_memory_maintenance(memories, elapsed, &Species::Human)
+}
+
+/// Runs memory maintenance on the psychological time of `species`.
+///
+/// Consolidation windows are psychological durations, so the time since
+/// each memory was encoded is scaled by `Species::psychological_duration()`
+/// before it is compared with its window. A mouse consolidates a day-long
+/// window in under an hour of real time. Humans behave exactly as with
+/// `apply_memory_maintenance`.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::enums::Species;
+/// use behavioral_pathways::memory::{MemoryLayers, MemoryLayer, MemoryEntry};
+/// use behavioral_pathways::memory::maintenance::apply_species_memory_maintenance;
+/// use behavioral_pathways::types::Duration;
+///
+/// let mut human = MemoryLayers::new();
+/// let walk = MemoryEntry::new(Duration::zero(), "Walk").with_salience(0.7);
+/// human.add(MemoryLayer::ShortTerm, walk);
+/// let mut mouse = human.clone();
+///
+/// let elapsed = Duration::hours(2);
+/// let human_report = apply_species_memory_maintenance(&mut human, elapsed, &Species::Human);
+/// let mouse_report = apply_species_memory_maintenance(&mut mouse, elapsed, &Species::Mouse);
+/// assert_eq!(human_report.unwrap().promoted, 0);
+/// assert_eq!(mouse_report.unwrap().promoted, 1);
+/// ```
+pub fn apply_species_memory_maintenance(
+    memories: &mut MemoryLayers,
+    elapsed: Duration,
+    species: &Species,
 ) -> Result<MaintenanceReport, MaintenanceError> {
     let mut report = MaintenanceReport::new();
 
@@ -508,7 +559,8 @@ pub fn apply_memory_maintenance(
             let base_hours = base_consolidation_window_hours(MemoryLayer::Immediate);
             let arousal = m.emotional_snapshot().arousal();
             let required_window = compute_consolidation_window(base_hours, arousal);
-            let time_since_encoding = elapsed.saturating_sub(m.timestamp());
+            let time_since_encoding =
+                species.psychological_duration(elapsed.saturating_sub(m.timestamp()));
             time_since_encoding >= required_window
         })
         .map(|m| m.id().clone())
@@ -527,7 +579,8 @@ pub fn apply_memory_maintenance(
             let base_hours = base_consolidation_window_hours(MemoryLayer::ShortTerm);
             let arousal = m.emotional_snapshot().arousal();
             let required_window = compute_consolidation_window(base_hours, arousal);
-            let time_since_encoding = elapsed.saturating_sub(m.timestamp());
+            let time_since_encoding =
+                species.psychological_duration(elapsed.saturating_sub(m.timestamp()));
             time_since_encoding >= required_window
         })
         .map(|m| m.id().clone())
@@ -569,6 +622,84 @@ pub fn apply_memory_maintenance(
     Ok(report)
 }
 
+/// Returns the memories as they stand at `age`, consolidated over the
+/// psychological time of `species` since each was encoded.
+///
+/// Memories formed after `age` are left out. Each remaining memory starts
+/// in its stored `layer()` (Immediate if it has none) and is promoted
+/// through every layer whose salience threshold, consolidation window,
+/// and milestone requirement it meets, as `apply_species_memory_maintenance`
+/// would on repeated runs. Outside the legacy layer its salience then
+/// decays over the time since encoding with `DEFAULT_SALIENCE_HALF_LIFE_DAYS`,
+/// and a memory that falls below `FORGETTING_THRESHOLD` is forgotten
+/// unless it carries a trauma tag. Memories are added oldest first, so
+/// layer capacity evicts the lowest salience.
+///
+/// The input is not modified.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::enums::Species;
+/// use behavioral_pathways::memory::maintenance::consolidate_at;
+/// use behavioral_pathways::memory::{MemoryEntry, MemoryLayer, MemoryTag};
+/// use behavioral_pathways::types::Duration;
+///
+/// let chat = MemoryEntry::new(Duration::zero(), "Small talk").with_salience(0.3);
+/// let assault = MemoryEntry::new(Duration::zero(), "Assault")
+///     .add_tag(MemoryTag::Violence)
+///     .with_salience(0.9);
+///
+/// let later = consolidate_at(vec![chat, assault], Duration::years(1), &Species::Human);
+/// assert_eq!(later.total_count(), 1);
+/// assert_eq!(later.long_term()[0].layer(), Some(MemoryLayer::LongTerm));
+/// ```
+#[must_use]
+pub fn consolidate_at<I>(memories: I, age: Duration, species: &Species) -> MemoryLayers
+where
+    I: IntoIterator<Item = MemoryEntry>,
+{
+    let mut memories: Vec<MemoryEntry> = memories
+        .into_iter()
+        .filter(|m| m.timestamp() <= age)
+        .collect();
+    memories.sort_by_key(MemoryEntry::timestamp);
+
+    let mut layers = MemoryLayers::new();
+    for mut memory in memories {
+        let since_encoding = species.psychological_duration(age.saturating_sub(memory.timestamp()));
+        let layer = consolidated_layer(&memory, since_encoding);
+        if layer == MemoryLayer::Legacy {
+            layers.add(layer, memory);
+            continue;
+        }
+        memory.apply_salience_decay(since_encoding, 1.0, DEFAULT_SALIENCE_HALF_LIFE_DAYS);
+        let trauma = memory.tags().iter().any(MemoryTag::is_trauma);
+        if memory.salience() >= FORGETTING_THRESHOLD || trauma {
+            layers.add(layer, memory);
+        }
+    }
+    layers
+}
+
+/// Returns the layer `memory` reaches `since_encoding` after it was
+/// encoded, promoting from its stored layer one step at a time.
+fn consolidated_layer(memory: &MemoryEntry, since_encoding: Duration) -> MemoryLayer {
+    let arousal = memory.emotional_snapshot().arousal();
+    let mut layer = memory.layer().unwrap_or(MemoryLayer::Immediate);
+    while let Some(next) = next_layer(layer) {
+        let window = compute_consolidation_window(base_consolidation_window_hours(layer), arousal);
+        let promoted = memory.salience() >= promotion_threshold(layer)
+            && since_encoding >= window
+            && (next != MemoryLayer::Legacy || memory.has_tag(MemoryTag::Milestone));
+        if !promoted {
+            break;
+        }
+        layer = next;
+    }
+    layer
+}
+
 /// Determines if maintenance should run based on elapsed time since last run.
 ///
 /// Maintenance is recommended to run once per simulated day. This helper function
@@ -1327,4 +1458,101 @@ mod tests {
         assert_eq!(layers.short_term_count(), 1); // Still in short-term
         assert_eq!(layers.long_term_count(), 0); // Not promoted
     }
+
+    #[test]
+    fn species_maintenance_uses_psychological_time() {
+        let mut human = MemoryLayers::new();
+        human.add(MemoryLayer::Immediate, create_memory_at_zero(0.5));
+        let mut mouse = human.clone();
+
+        // Ten real minutes is under the one hour window for a human but
+        // over six psychological hours for a mouse
+        let elapsed = Duration::minutes(10);
+        let human_report =
+            apply_species_memory_maintenance(&mut human, elapsed, &Species::Human).unwrap();
+        let mouse_report =
+            apply_species_memory_maintenance(&mut mouse, elapsed, &Species::Mouse).unwrap();
+
+        assert_eq!(human_report.promoted, 0);
+        assert_eq!(mouse_report.promoted, 1);
+        assert_eq!(mouse.short_term_count(), 1);
+    }
+
+    // === Query-Time Consolidation Tests ===
+
+    #[test]
+    fn consolidate_at_promotes_by_salience_and_window() {
+        let memories = vec![
+            create_memory_at_zero(0.2),
+            create_memory_at_zero(0.4),
+            create_memory_at_zero(0.7),
+            create_memory_at_zero(0.95).add_tag(MemoryTag::Milestone),
+        ];
+
+        // Before the first window nothing has moved
+        let early = consolidate_at(memories.clone(), Duration::minutes(30), &Species::Human);
+        assert_eq!(early.immediate_count(), 4);
+
+        let later = consolidate_at(memories, Duration::days(2), &Species::Human);
+        assert_eq!(later.immediate_count(), 1);
+        assert_eq!(later.short_term_count(), 1);
+        assert_eq!(later.long_term_count(), 1);
+        assert_eq!(later.legacy_count(), 1);
+        for (layer, entries) in [
+            (MemoryLayer::Immediate, later.immediate()),
+            (MemoryLayer::ShortTerm, later.short_term()),
+            (MemoryLayer::LongTerm, later.long_term()),
+            (MemoryLayer::Legacy, later.legacy()),
+        ] {
+            assert_eq!(entries[0].layer(), Some(layer));
+        }
+    }
+
+    #[test]
+    fn consolidate_at_decays_and_forgets() {
+        let mut stored = MemoryLayers::new();
+        stored.add(MemoryLayer::LongTerm, create_memory_at_zero(0.8));
+        stored.add(
+            MemoryLayer::LongTerm,
+            create_memory_at_zero(0.8).add_tag(MemoryTag::Death),
+        );
+        stored.add(
+            MemoryLayer::Legacy,
+            create_memory_at_zero(0.5).add_tag(MemoryTag::Milestone),
+        );
+        let memories = || stored.all_memories().cloned();
+
+        // One half-life halves salience outside the legacy layer
+        let month = consolidate_at(memories(), Duration::days(30), &Species::Human);
+        assert!((month.long_term()[0].salience() - 0.4).abs() < 0.01);
+        assert!((month.legacy()[0].salience() - 0.5).abs() < f32::EPSILON);
+
+        // Years on, only the trauma and legacy memories are left
+        let years = consolidate_at(memories(), Duration::years(2), &Species::Human);
+        assert_eq!(years.long_term_count(), 1);
+        assert!(years.long_term()[0].has_tag(MemoryTag::Death));
+        assert_eq!(years.legacy_count(), 1);
+        // The input is unchanged
+        assert_eq!(stored.total_count(), 3);
+        assert!((stored.long_term()[0].salience() - 0.8).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn consolidate_at_skips_later_memories_and_respects_capacity() {
+        let memories: Vec<MemoryEntry> = (0..15)
+            .map(|hour| {
+                MemoryEntry::new(Duration::hours(hour), "Passing moment")
+                    .with_salience(0.1 + hour as f32 * 0.01)
+            })
+            .collect();
+
+        let layers = consolidate_at(memories, Duration::hours(12), &Species::Human);
+        // Thirteen formed by hour 12; the immediate layer holds ten, evicting
+        // the lowest salience
+        assert_eq!(layers.immediate_count(), crate::memory::IMMEDIATE_CAPACITY);
+        assert!(layers
+            .immediate()
+            .iter()
+            .all(|m| m.timestamp() >= Duration::hours(3)));
+    }
 }
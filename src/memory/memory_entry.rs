@@ -3,7 +3,7 @@
 //! A memory entry captures an event or experience along with its emotional
 //! context, participants, and effects on relationships.
 
-use crate::memory::{DeltasApplied, EmotionalSnapshot, MemorySource, MemoryTag};
+use crate::memory::{DeltasApplied, EmotionalSnapshot, MemoryLayer, MemorySource, MemoryTag};
 use crate::types::{Duration, EntityId, EventId, MemoryId, MicrosystemId};
 use uuid::Uuid;
 
@@ -42,6 +42,7 @@ fn generate_memory_id() -> MemoryId {
 /// assert!((entry.salience() - 0.7).abs() < f32::EPSILON);
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryEntry {
     /// Unique identifier for this memory.
     id: MemoryId,
@@ -78,6 +79,9 @@ pub struct MemoryEntry {
 
     /// Context where the memory was encoded.
     microsystem_context: Option<MicrosystemId>,
+
+    /// Layer the memory is stored in, if any.
+    layer: Option<MemoryLayer>,
 }
 
 impl MemoryEntry {
@@ -105,6 +109,7 @@ impl MemoryEntry {
             deltas_applied: DeltasApplied::default(),
             summary: summary.into(),
             microsystem_context: None,
+            layer: None,
         }
     }
 
@@ -126,6 +131,7 @@ impl MemoryEntry {
             deltas_applied: DeltasApplied::default(),
             summary: summary.into(),
             microsystem_context: None,
+            layer: None,
         }
     }
 
@@ -276,6 +282,13 @@ impl MemoryEntry {
         self.microsystem_context.as_ref()
     }
 
+    /// Returns the layer the memory is stored in, or `None` if it has not
+    /// been added to `MemoryLayers`.
+    #[must_use]
+    pub fn layer(&self) -> Option<MemoryLayer> {
+        self.layer
+    }
+
     /// Returns whether this memory contains the specified tag.
     #[must_use]
     pub fn has_tag(&self, tag: MemoryTag) -> bool {
@@ -332,6 +345,11 @@ impl MemoryEntry {
         self.salience = (self.salience * decay_factor).clamp(0.0, 1.0);
     }
 
+    /// Records the layer the memory is stored in.
+    pub(crate) fn set_layer(&mut self, layer: MemoryLayer) {
+        self.layer = Some(layer);
+    }
+
     /// Sets the salience directly (useful for testing or manual adjustments).
     ///
     /// Value is clamped to [0.0, 1.0].
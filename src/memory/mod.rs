@@ -52,6 +52,7 @@
 //! assert_eq!(personal.len(), 1);
 //! ```
 
+#[cfg(feature = "memory")]
 mod consolidation;
 mod deltas;
 mod emotional_snapshot;
@@ -62,6 +63,7 @@ mod retrieval;
 mod source;
 mod tags;
 
+#[cfg(feature = "memory")]
 pub use consolidation::{apply_memory_consolidation, compute_priming_deltas, PrimingDeltas};
 pub use deltas::{DeltasApplied, RelationshipDelta, ReputationDelta};
 pub use emotional_snapshot::EmotionalSnapshot;
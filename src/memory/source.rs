@@ -21,6 +21,7 @@ use std::fmt;
 /// assert!((witness.confidence() - 0.7).abs() < f32::EPSILON);
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MemorySource {
     /// Entity directly experienced the event.
     /// Confidence: 1.0
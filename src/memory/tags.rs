@@ -18,6 +18,7 @@ use std::fmt;
 /// assert_eq!(tag.name(), "Betrayal");
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MemoryTag {
     /// Mission-related events.
     Mission,
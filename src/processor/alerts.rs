@@ -3,40 +3,32 @@
 //! This module provides functions that check entity state against
 //! dangerous thresholds and generate alerts when crossed.
 //!
-//! Note: All functions in this module are test-only. They are used to test
-//! the alert generation logic but are not part of the public API.
+//! ITS factor, ITS threshold, and spiral checks back
+//! `ComputedState::alerts()`.
 
-#[cfg(test)]
 use crate::enums::{AlertTrigger, MentalHealthPath, SpiralType, StatePath};
-#[cfg(test)]
 use crate::processor::ItsFactors;
-#[cfg(test)]
-use crate::state::IndividualState;
-#[cfg(test)]
+use crate::state::{
+    IndividualState, HOPELESSNESS_THRESHOLD, PB_PRESENT_THRESHOLD, TB_PRESENT_THRESHOLD,
+};
 use crate::types::{Alert, Duration};
 
 /// Threshold for suicidal desire to trigger a warning.
-#[cfg(test)]
 const DESIRE_WARNING_THRESHOLD: f32 = 0.5;
 
 /// Threshold for suicidal desire to trigger a critical alert.
-#[cfg(test)]
 const DESIRE_CRITICAL_THRESHOLD: f32 = 0.7;
 
 /// Threshold for attempt risk to trigger a warning.
-#[cfg(test)]
 const RISK_WARNING_THRESHOLD: f32 = 0.4;
 
 /// Threshold for attempt risk to trigger a critical alert.
-#[cfg(test)]
 const RISK_CRITICAL_THRESHOLD: f32 = 0.6;
 
 /// Threshold for stress spiral to generate an alert.
-#[cfg(test)]
 const STRESS_SPIRAL_ALERT_THRESHOLD: f32 = 0.6;
 
 /// Threshold for depression spiral to generate an alert.
-#[cfg(test)]
 const DEPRESSION_SPIRAL_ALERT_THRESHOLD: f32 = 0.4;
 
 /// Checks ITS factors against thresholds and generates alerts.
@@ -68,7 +60,6 @@ const DEPRESSION_SPIRAL_ALERT_THRESHOLD: f32 = 0.4;
 /// // Healthy state should have no alerts
 /// assert!(alerts.is_empty());
 /// ```
-#[cfg(test)]
 #[must_use]
 pub(crate) fn check_its_thresholds(factors: &ItsFactors, timestamp: Duration) -> Vec<Alert> {
     let mut alerts = Vec::new();
@@ -124,6 +115,65 @@ pub(crate) fn check_its_thresholds(factors: &ItsFactors, timestamp: Duration) ->
     alerts
 }
 
+/// Checks the ITS proximal factors against their presence thresholds and
+/// generates alerts.
+///
+/// This function checks:
+/// - Thwarted belongingness (info at `TB_PRESENT_THRESHOLD`)
+/// - Perceived burdensomeness (info at `PB_PRESENT_THRESHOLD`)
+/// - Interpersonal hopelessness (info at `HOPELESSNESS_THRESHOLD`)
+///
+/// A present factor is informational on its own; desire and risk alerts
+/// from `check_its_thresholds` carry the higher severities.
+///
+/// # Arguments
+///
+/// * `factors` - Computed ITS factors from entity state
+/// * `hopelessness` - Effective interpersonal hopelessness
+/// * `timestamp` - Current simulation time for alert timestamp
+///
+/// # Returns
+///
+/// Vector of alerts generated. May be empty if no factor is present.
+#[must_use]
+pub(crate) fn check_factor_thresholds(
+    factors: &ItsFactors,
+    hopelessness: f32,
+    timestamp: Duration,
+) -> Vec<Alert> {
+    let checks = [
+        (
+            MentalHealthPath::ThwartedBelongingness,
+            factors.thwarted_belongingness,
+            TB_PRESENT_THRESHOLD,
+            "Thwarted belongingness present",
+        ),
+        (
+            MentalHealthPath::PerceivedBurdensomeness,
+            factors.perceived_burdensomeness,
+            PB_PRESENT_THRESHOLD,
+            "Perceived burdensomeness present",
+        ),
+        (
+            MentalHealthPath::InterpersonalHopelessness,
+            hopelessness,
+            HOPELESSNESS_THRESHOLD,
+            "Interpersonal hopelessness present",
+        ),
+    ];
+    checks
+        .into_iter()
+        .filter(|(_, value, threshold, _)| value >= threshold)
+        .map(|(path, value, _, label)| {
+            Alert::info(
+                AlertTrigger::threshold(StatePath::MentalHealth(path), f64::from(value)),
+                timestamp,
+                format!("{label}: {value:.2}"),
+            )
+        })
+        .collect()
+}
+
 /// Checks for active feedback spirals and generates alerts.
 ///
 /// This function checks:
@@ -154,7 +204,6 @@ pub(crate) fn check_its_thresholds(factors: &ItsFactors, timestamp: Duration) ->
 ///
 /// assert!(!alerts.is_empty());
 /// ```
-#[cfg(test)]
 #[must_use]
 pub(crate) fn check_spiral_alerts(
     state: &IndividualState,
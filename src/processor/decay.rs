@@ -7,7 +7,12 @@
 //!
 //! Time scale (from Species) affects how quickly an entity experiences time,
 //! effectively shortening decay half-lives proportionally.
+//!
+//! `decay_processor_for()` picks the processor for a species: robotic
+//! entities get `NoOpDecayProcessor`, every other species the exponential
+//! `StateDecayProcessor`.
 
+use crate::enums::Species;
 use crate::state::IndividualState;
 use crate::types::Duration;
 
@@ -41,6 +46,44 @@ pub trait DecayProcessor {
     /// * `duration` - The real time that has elapsed
     /// * `time_scale` - The entity's time scaling factor (e.g., 6.7 for dogs)
     fn apply_decay(&self, state: &mut IndividualState, duration: Duration, time_scale: f64);
+
+    /// Reverses the decay `apply_decay` would apply over `duration`.
+    ///
+    /// The default inverts exponential decay, as `StateDecayProcessor`
+    /// applies it: each value is reversed with its own half-life, after the
+    /// config's `DecayProfile` overrides are applied, so decaying and then
+    /// reversing by the same duration round-trips. Dimensions with no decay
+    /// are left unchanged.
+    fn reverse_decay(&self, state: &mut IndividualState, duration: Duration, time_scale: f64) {
+        let scaled_duration = scale(duration, time_scale);
+        state.apply_decay_profile();
+        for value in state.decaying_values_mut() {
+            value.reverse_decay(scaled_duration);
+        }
+    }
+}
+
+/// Returns the decay processor for an entity of `species`.
+///
+/// Robotic entities hold their state until an event changes it, so they
+/// get `NoOpDecayProcessor`; every other species decays exponentially.
+/// Species time scaling is applied separately, through the `time_scale`
+/// argument or `Species::psychological_duration()`.
+#[must_use]
+pub fn decay_processor_for(species: &Species) -> &'static (dyn DecayProcessor + Sync) {
+    if species.decays() {
+        &StateDecayProcessor
+    } else {
+        &NoOpDecayProcessor
+    }
+}
+
+/// Scales real time to psychological time by `time_scale`.
+fn scale(duration: Duration, time_scale: f64) -> Duration {
+    if time_scale == 1.0 {
+        return duration;
+    }
+    Duration::from_millis((duration.as_millis() as f64 * time_scale) as u64)
 }
 
 /// No-op decay processor that leaves state unchanged.
@@ -68,7 +111,6 @@ pub trait DecayProcessor {
 /// assert!((delta_before - delta_after).abs() < f32::EPSILON);
 /// ```
 #[derive(Debug, Clone, Copy, Default)]
-#[allow(dead_code)]
 pub struct NoOpDecayProcessor;
 
 impl NoOpDecayProcessor {
@@ -84,6 +126,10 @@ impl DecayProcessor for NoOpDecayProcessor {
     fn apply_decay(&self, _state: &mut IndividualState, _duration: Duration, _time_scale: f64) {
         // No-op - state unchanged
     }
+
+    fn reverse_decay(&self, _state: &mut IndividualState, _duration: Duration, _time_scale: f64) {
+        // No-op - nothing decayed, so nothing to reverse
+    }
 }
 
 /// Real decay processor that applies exponential decay to state deltas.
@@ -121,7 +167,6 @@ impl DecayProcessor for NoOpDecayProcessor {
 /// assert!((state.needs().stress().delta() - 0.2).abs() < 0.01);
 /// ```
 #[derive(Debug, Clone, Copy, Default)]
-#[allow(dead_code)]
 pub struct StateDecayProcessor;
 
 impl StateDecayProcessor {
@@ -137,8 +182,7 @@ impl DecayProcessor for StateDecayProcessor {
     fn apply_decay(&self, state: &mut IndividualState, duration: Duration, time_scale: f64) {
         // Apply time scaling - psychological time passes faster for shorter-lived species
         // For a dog with time_scale 6.7, 1 real day feels like ~7 psychological days
-        let scaled_duration =
-            Duration::from_millis((duration.as_millis() as f64 * time_scale) as u64);
+        let scaled_duration = scale(duration, time_scale);
 
         // Apply decay to all state components
         // Note: IndividualState.apply_decay handles which components decay
@@ -503,6 +547,43 @@ mod tests {
         assert!((state.mood().valence_delta() - 0.8).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn reverse_decay_undoes_state_decay() {
+        let processor = StateDecayProcessor::new();
+        let mut state = IndividualState::new();
+
+        state.mood_mut().add_valence_delta(0.8);
+        processor.apply_decay(&mut state, Duration::hours(6), 2.0);
+        processor.reverse_decay(&mut state, Duration::hours(6), 2.0);
+
+        assert!((state.mood().valence_delta() - 0.8).abs() < 1e-4);
+    }
+
+    #[test]
+    fn no_op_reverse_decay_leaves_state_unchanged() {
+        let processor = NoOpDecayProcessor::new();
+        let mut state = IndividualState::new();
+
+        state.mood_mut().add_valence_delta(0.4);
+        let before = state.clone();
+        processor.reverse_decay(&mut state, Duration::years(1), 1.0);
+
+        assert_eq!(state, before);
+    }
+
+    #[test]
+    fn robotic_species_gets_no_op_processor() {
+        let mut robot = IndividualState::new();
+        robot.mood_mut().add_valence_delta(0.8);
+        let mut human = robot.clone();
+
+        decay_processor_for(&Species::Robotic).apply_decay(&mut robot, Duration::years(1), 1.0);
+        decay_processor_for(&Species::Human).apply_decay(&mut human, Duration::years(1), 1.0);
+
+        assert!((robot.mood().valence_delta() - 0.8).abs() < f32::EPSILON);
+        assert!(human.mood().valence_delta() < 0.01);
+    }
+
     #[test]
     fn multiple_dimensions_decay_independently() {
         let processor = StateDecayProcessor::new();
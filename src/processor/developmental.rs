@@ -82,7 +82,7 @@ pub(crate) fn apply_developmental_effects(
     // Compute life stage at event time (not anchor time)
     // Use raw age in years for species-aware life stage lookup
     let raw_age_years = age_days_f64 / DAYS_PER_YEAR;
-    let life_stage = LifeStage::from_age_years_for_species(species, raw_age_years);
+    let life_stage = entity.config().life_stage_at(species, raw_age_years);
 
     // Compute plasticity modifier
     let plasticity = get_plasticity_modifier(&life_stage, age_years);
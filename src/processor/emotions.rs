@@ -42,6 +42,49 @@ pub struct EmotionIntensities {
 }
 
 impl EmotionIntensities {
+    /// Derives graded emotions from explicit PAD values.
+    ///
+    /// Values outside -1 to 1 are clamped. Disgust is scaled by
+    /// `moral_violation_flag` (0 to 1).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::EmotionIntensities;
+    ///
+    /// let emotions = EmotionIntensities::from_pad(1.0, 1.0, 1.0, 0.0);
+    /// assert_eq!(emotions.exuberant, 1.0);
+    /// assert_eq!(emotions.anxious, 0.0);
+    ///
+    /// let violated = EmotionIntensities::from_pad(-0.8, 0.9, 0.7, 1.0);
+    /// assert!(violated.disgust > 0.0);
+    /// ```
+    #[must_use]
+    pub fn from_pad(valence: f32, arousal: f32, dominance: f32, moral_violation_flag: f32) -> Self {
+        derive_emotion(valence, arousal, dominance, moral_violation_flag)
+    }
+
+    /// Derives graded emotions from a state's effective PAD values and
+    /// moral violation flag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::{EmotionIntensities, IndividualState};
+    ///
+    /// let mut state = IndividualState::new();
+    /// state.mood_mut().add_valence_delta(-0.8);
+    /// state.mood_mut().add_arousal_delta(0.8);
+    /// state.mood_mut().add_dominance_delta(-0.8);
+    ///
+    /// let emotions = EmotionIntensities::from_state(&state);
+    /// assert!(emotions.anxious > emotions.relaxed);
+    /// ```
+    #[must_use]
+    pub fn from_state(state: &IndividualState) -> Self {
+        get_derived_emotion(state)
+    }
+
     /// Returns the intensity for the requested emotion.
     #[allow(dead_code)]
     #[must_use]
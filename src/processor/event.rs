@@ -4,16 +4,26 @@
 //! entity personality, applying state changes, and computing salience.
 
 use crate::entity::Entity;
+#[cfg(feature = "relationships")]
+use crate::enums::Direction;
+#[cfg(feature = "context")]
+use crate::enums::EventTag;
 use crate::enums::{
-    Attribution, AttributionStability, Direction, DispositionPath, EventCategory, EventPayload,
-    EventType, LifeDomain, MentalHealthPath, MoodPath, NeedsPath, RealizationType,
-    SocialCognitionPath, StatePath, SupportType,
+    Attribution, AttributionStability, Audience, DispositionPath, EventCategory, EventPayload,
+    EventType, InterpretationRole, LifeDomain, MentalHealthPath, MoodPath, NeedsPath,
+    RealizationType, SocialCognitionPath, StatePath, SupportType,
 };
-use crate::event::{compute_arousal_modulated_salience, Event};
-#[cfg(test)]
-use crate::memory::MemoryTag;
+use crate::event::{
+    appraise_severity, base_impact, compute_arousal_modulated_salience, Event, SeverityAppraisal,
+};
+#[cfg(feature = "memory")]
+use crate::memory::{MemoryEntry, MemoryTag};
+use crate::processor::state_evolution::apply_event_delta;
+#[cfg(feature = "relationships")]
 use crate::relationship::{get_antecedent_for_event, Relationship, TrustAntecedent};
-use crate::types::{EventId, Timestamp};
+#[cfg(feature = "relationships")]
+use crate::types::Timestamp;
+use crate::types::{EventId, RelationshipId};
 
 /// Interpretation of an event based on entity state and personality.
 ///
@@ -26,6 +36,8 @@ pub struct InterpretedEvent {
     pub event: Event,
     /// ID of the original event for reference.
     pub original_event: EventId,
+    /// How the entity took part in the event.
+    pub role: InterpretationRole,
     /// Computed attribution for this event.
     pub attribution: Attribution,
     /// Valence modifier based on event type and personality.
@@ -48,12 +60,23 @@ pub struct InterpretedEvent {
     pub interpersonal_hopelessness_delta: f32,
     /// Computed salience for memory encoding.
     pub salience: f32,
+    /// Objective and subjective severity of the event for this entity.
+    pub severity_appraisal: SeverityAppraisal,
     /// Perceived severity after personality modulation.
     pub perceived_severity: f64,
     /// Memory salience for encoding.
     pub memory_salience: f64,
     /// State changes to apply, as (path, delta) pairs.
     pub state_deltas: Vec<(StatePath, f64)>,
+    /// The earlier non-private recognition this failure or humiliation
+    /// contradicts, whose audience amplified its losses.
+    pub contradicted_recognition: Option<EventId>,
+    /// The sleep deprivation in the day before this event whose fatigue
+    /// amplified its negative valence.
+    pub preceding_sleep_loss: Option<EventId>,
+    /// The relationship this loss ended, whose closeness magnified its
+    /// loneliness.
+    pub lost_relationship: Option<RelationshipId>,
 }
 
 impl InterpretedEvent {
@@ -76,6 +99,7 @@ impl InterpretedEvent {
         InterpretedEvent {
             event: self.event.clone(),
             original_event: self.original_event.clone(),
+            role: self.role,
             attribution: self.attribution.clone(),
             valence_delta: self.valence_delta * factor_f32,
             arousal_delta: self.arousal_delta * factor_f32,
@@ -87,6 +111,7 @@ impl InterpretedEvent {
             acquired_capability_delta: self.acquired_capability_delta * factor_f32,
             interpersonal_hopelessness_delta: self.interpersonal_hopelessness_delta * factor_f32,
             salience: self.salience, // Salience is not scaled
+            severity_appraisal: self.severity_appraisal,
             perceived_severity: self.perceived_severity * factor,
             memory_salience: self.memory_salience, // Memory salience is not scaled
             state_deltas: self
@@ -94,6 +119,9 @@ impl InterpretedEvent {
                 .iter()
                 .map(|(path, delta)| (*path, delta * factor))
                 .collect(),
+            contradicted_recognition: self.contradicted_recognition.clone(),
+            preceding_sleep_loss: self.preceding_sleep_loss.clone(),
+            lost_relationship: self.lost_relationship.clone(),
         }
     }
 }
@@ -124,6 +152,58 @@ pub mod impact {
     pub const TRAUMA_AC: f32 = 0.15;
     /// Base interpersonal hopelessness impact.
     pub const INTERPERSONAL_HOPELESSNESS: f32 = 0.1;
+    /// Base fatigue impact for acute illness.
+    pub const ILLNESS_FATIGUE: f32 = 0.4;
+    /// Base valence impact for illness onset.
+    pub const ILLNESS_VALENCE: f32 = -0.15;
+    /// Valence response to a fully accepting or rejecting disclosure.
+    pub const DISCLOSURE_VALENCE: f32 = 0.25;
+    /// PRC response to a fully accepting or rejecting disclosure.
+    pub const DISCLOSURE_PRC: f32 = 0.2;
+    /// Base fatigue impact for sleep deprivation.
+    pub const SLEEP_LOSS_FATIGUE: f32 = 0.4;
+    /// Base arousal impact for sleep deprivation.
+    pub const SLEEP_LOSS_AROUSAL: f32 = -0.2;
+    /// Base fatigue reduction for rest.
+    pub const REST_FATIGUE: f32 = -0.4;
+    /// Base arousal impact for rest.
+    pub const REST_AROUSAL: f32 = 0.05;
+    /// Share of the target's impact a fully empathic witness feels.
+    pub const WITNESS_IMPACT: f32 = 0.5;
+    /// Share of the target's impact a fully empathic entity feels on
+    /// hearing about an event secondhand.
+    pub const SECONDHAND_IMPACT: f32 = 0.25;
+    /// Base satiation increase for a meal.
+    pub const FEEDING_SATIATION: f32 = 0.5;
+    /// Base valence impact for a meal.
+    pub const FEEDING_VALENCE: f32 = 0.1;
+    /// Base arousal impact for a meal at resting hunger.
+    pub const FEEDING_AROUSAL: f32 = -0.15;
+    /// Base safety reduction for exposure to a threat.
+    pub const THREAT_SAFETY: f32 = -0.4;
+    /// Hours of full-quality sleep that restore completely.
+    pub const RESTORATIVE_SLEEP_HOURS: f64 = 8.0;
+    /// Extra negative valence per unit of fatigue from recent sleep loss.
+    pub const SLEEP_LOSS_VALENCE_GAIN: f32 = 1.5;
+    /// Loneliness added by losing a fully close, fully attached partner.
+    pub const BEREAVEMENT_LONELINESS: f32 = 0.4;
+    /// Perceived reciprocal caring impact of losing a fully close, fully
+    /// attached partner.
+    pub const BEREAVEMENT_PRC: f32 = -0.3;
+    /// Share by which a fully collectivist culture magnifies the
+    /// loneliness of exclusion and the liability of burden feedback; a
+    /// fully individualist culture softens them by the same share.
+    #[cfg(feature = "context")]
+    pub const COLLECTIVIST_SOCIAL_GAIN: f32 = 0.3;
+    /// Share by which a fully collectivist culture discounts the
+    /// protective effects of individual achievement; a fully individualist
+    /// culture strengthens them by the same share.
+    #[cfg(feature = "context")]
+    pub const COLLECTIVIST_ACHIEVEMENT_DISCOUNT: f32 = 0.3;
+    /// Dominance loss a humiliation by an authority gains per unit of power
+    /// distance above the neutral 0.5.
+    #[cfg(feature = "context")]
+    pub const AUTHORITY_HUMILIATION_GAIN: f32 = 1.0;
 }
 
 /// Interprets an event based on entity state and personality.
@@ -138,6 +218,9 @@ pub mod impact {
 /// - Agreeableness affects social event interpretation
 /// - Honesty-Humility affects attribution patterns
 ///
+/// An entity listed as one of the event's witnesses interprets it as a
+/// witness; see `interpret_event_as`.
+///
 /// # Arguments
 ///
 /// * `event` - The event to interpret
@@ -172,6 +255,22 @@ pub mod impact {
 /// ```
 #[must_use]
 pub(crate) fn interpret_event(event: &Event, entity: &Entity) -> InterpretedEvent {
+    interpret_event_as(event, entity, InterpretationRole::of(event, entity.id()))
+}
+
+/// Interprets an event for an entity that took part in it in `role`.
+///
+/// A target takes the full impact. An observer takes a share of it
+/// proportional to its empathy: up to `WITNESS_IMPACT` for a witness, so
+/// 30-50% at typical empathy, and up to `SECONDHAND_IMPACT` for someone
+/// who heard about it. Observers attribute the event to its source, or
+/// failing that to its target, never to themselves.
+#[must_use]
+pub(crate) fn interpret_event_as(
+    event: &Event,
+    entity: &Entity,
+    role: InterpretationRole,
+) -> InterpretedEvent {
     // Get personality for modulation
     let hexaco = entity.individual_state().hexaco();
     let emotionality = hexaco.emotionality(); // HEXACO Emotionality
@@ -183,7 +282,9 @@ pub(crate) fn interpret_event(event: &Event, entity: &Entity) -> InterpretedEven
         .get_effective(StatePath::Mood(MoodPath::Arousal))
         .unwrap_or(0.0) as f32;
 
-    let severity = event.severity() as f32;
+    // Interpretation works from the entity's appraisal of the event
+    let severity_appraisal = appraise_severity(event, entity);
+    let severity = severity_appraisal.subjective() as f32;
     let category = event.category();
     let event_type = event.event_type();
 
@@ -199,102 +300,46 @@ pub(crate) fn interpret_event(event: &Event, entity: &Entity) -> InterpretedEven
     let mut interpersonal_hopelessness_delta = 0.0;
     let mut purpose_delta = 0.0;
     let mut self_worth_delta = 0.0;
-
-    // Apply base impacts by category
-    match category {
-        EventCategory::SocialBelonging => {
-            // TB pathway
-            match event_type {
-                EventType::SocialExclusion => {
-                    valence_delta = impact::NEGATIVE_VALENCE * severity;
-                    loneliness_delta = impact::EXCLUSION_LONELINESS * severity;
-                    prc_delta = -0.1 * severity;
-                }
-                EventType::SocialInclusion => {
-                    valence_delta = impact::POSITIVE_VALENCE * severity;
-                    loneliness_delta = impact::INCLUSION_LONELINESS * severity;
-                    prc_delta = 0.1 * severity;
-                }
-                _ => {}
-            }
-        }
-        EventCategory::BurdenPerception => {
-            // PB pathway
-            valence_delta = impact::NEGATIVE_VALENCE * severity;
-            perceived_liability_delta = impact::BURDEN_LIABILITY * severity;
-            self_hate_delta = impact::SELF_HATE * severity * 0.5;
-        }
-        EventCategory::Trauma => {
-            // AC pathway - NEVER decays
-            valence_delta = impact::NEGATIVE_VALENCE * severity;
-            arousal_delta = impact::HIGH_AROUSAL * severity;
-            acquired_capability_delta = impact::TRAUMA_AC * severity;
-        }
-        EventCategory::Control => match event_type {
-            EventType::Humiliation => {
-                valence_delta = impact::NEGATIVE_VALENCE * severity;
-                dominance_delta = -impact::CONTROL_DOMINANCE * severity;
-            }
-            EventType::Empowerment => {
-                valence_delta = impact::POSITIVE_VALENCE * severity;
-                dominance_delta = impact::CONTROL_DOMINANCE * severity;
-            }
-            _ => {}
-        },
-        EventCategory::Achievement => match event_type {
-            EventType::Achievement => {
-                valence_delta = impact::POSITIVE_VALENCE * severity;
-                dominance_delta = 0.1 * severity;
-            }
-            EventType::Failure => {
-                valence_delta = impact::NEGATIVE_VALENCE * severity;
-                dominance_delta = -0.1 * severity;
-            }
-            EventType::Loss => {
-                // Loss events (job loss, significant loss, death, etc.)
-                // Spec: spec/subsystems/event-system.md Loss entry
-                valence_delta = -0.15 * severity;
-                dominance_delta = -0.10 * severity;
-                arousal_delta = 0.10 * severity;
-            }
-            _ => {}
-        },
-        EventCategory::Social => {
-            // Conflict has specific blueprint effects
-            if event_type == EventType::Conflict {
-                // Spec: spec/subsystems/event-system.md Conflict entry
-                valence_delta = -0.10 * severity;
-                arousal_delta = 0.12 * severity;
-                dominance_delta = -0.12 * severity;
-                loneliness_delta = 0.08 * severity;
-                perceived_liability_delta = 0.04 * severity;
-                // self_worth and grievance are added later in state_deltas section
-            } else if event_type == EventType::Support && matches!(event.payload(), EventPayload::Empty) {
-                // Support without payload uses blueprint
-                // Spec: spec/subsystems/event-system.md Support entry
-                valence_delta = 0.08 * severity;
-                loneliness_delta = -0.20 * severity;
-                perceived_liability_delta = -0.10 * severity;
-            } else {
-                // General social events - process payload
-                process_social_event_payload(
-                    event,
-                    &mut valence_delta,
-                    &mut arousal_delta,
-                    &mut prc_delta,
-                    &mut loneliness_delta,
-                );
-            }
-        }
-        EventCategory::Contextual => {
-            // Environmental events - minimal direct state impact
-            arousal_delta = 0.1 * severity;
-        }
+    let mut fatigue_delta = 0.0;
+    let mut satiation_delta = 0.0;
+    let mut safety_delta = 0.0;
+
+    // Apply base impacts from the blueprint table. Social events other than
+    // Conflict and payload-less Support take their effects from the payload.
+    let uses_blueprint = category != EventCategory::Social
+        || event_type == EventType::Conflict
+        || (event_type == EventType::Support && matches!(event.payload(), EventPayload::Empty));
+    let base = base_impact(category, event_type);
+    if uses_blueprint {
+        valence_delta = base.valence * severity;
+        arousal_delta = base.arousal * severity;
+        dominance_delta = base.dominance * severity;
+        loneliness_delta = base.loneliness * severity;
+        prc_delta = base.prc * severity;
+        perceived_liability_delta = base.perceived_liability * severity;
+        self_hate_delta = base.self_hate * severity;
+        acquired_capability_delta = base.acquired_capability * severity;
+        fatigue_delta = base.fatigue * severity;
+        satiation_delta = base.satiation * severity;
+        safety_delta = base.safety * severity;
+    } else {
+        // General social events - process payload
+        process_social_event_payload(
+            event,
+            severity,
+            &mut valence_delta,
+            &mut arousal_delta,
+            &mut prc_delta,
+            &mut loneliness_delta,
+        );
     }
 
     // Protective factors lower TB/PB without touching AC.
     if event_type == EventType::Achievement {
-        if let EventPayload::Achievement { domain, magnitude } = event.payload() {
+        if let EventPayload::Achievement {
+            domain, magnitude, ..
+        } = event.payload()
+        {
             let productivity = severity * (*magnitude as f32);
             if matches!(domain, LifeDomain::Work | LifeDomain::Academic | LifeDomain::Financial) {
                 perceived_liability_delta -= 0.12 * productivity;
@@ -307,6 +352,67 @@ pub(crate) fn interpret_event(event: &Event, entity: &Entity) -> InterpretedEven
         }
     }
 
+    // Role exits: the sustained purpose, grievance, and self-worth effects
+    // are applied by the context layer; only the immediate affect lives here.
+    if event_type == EventType::ContextTransition {
+        if let EventPayload::RoleExit { voluntary, .. } = event.payload() {
+            if *voluntary {
+                valence_delta += 0.05 * severity;
+            } else {
+                valence_delta -= 0.2 * severity;
+                dominance_delta -= 0.15 * severity;
+            }
+        }
+    }
+
+    // Health conditions: the immediate symptoms live here; the persistent
+    // load of chronic conditions is applied by the context layer.
+    match event.payload() {
+        EventPayload::HealthCondition { chronic, .. } => {
+            valence_delta += impact::ILLNESS_VALENCE * severity;
+            fatigue_delta += if *chronic {
+                impact::ILLNESS_FATIGUE * 0.5 * severity
+            } else {
+                impact::ILLNESS_FATIGUE * severity
+            };
+        }
+        EventPayload::HealthRemission { .. } => {
+            valence_delta += -impact::ILLNESS_VALENCE * severity;
+        }
+        _ => {}
+    }
+
+    // Sleep: a deprivation applies the part of its effect the night's sleep
+    // did not restore, a rest the part it did.
+    if let EventPayload::Sleep { hours, quality } = event.payload() {
+        let restored = (hours * quality / impact::RESTORATIVE_SLEEP_HOURS).clamp(0.0, 1.0) as f32;
+        let factor = if event_type == EventType::Rest {
+            restored
+        } else {
+            1.0 - restored
+        };
+        arousal_delta *= factor;
+        fatigue_delta *= factor;
+    }
+
+    // Feeding: a meal calms in proportion to hunger, relative to the
+    // hunger of an entity at its resting satiation
+    if event_type == EventType::Feeding {
+        let needs = entity.individual_state().needs();
+        let resting_hunger = 1.0 - needs.satiation_base();
+        if resting_hunger > f32::EPSILON {
+            arousal_delta *= (1.0 - needs.satiation_effective()) / resting_hunger;
+        }
+    }
+
+    // Disclosures: the partner's reaction lives here; ending the
+    // concealment load and suppression is applied by the context layer.
+    if let EventPayload::Disclosure { reaction, .. } = event.payload() {
+        let reaction = reaction.clamp(-1.0, 1.0) as f32;
+        valence_delta += impact::DISCLOSURE_VALENCE * reaction * severity;
+        prc_delta += impact::DISCLOSURE_PRC * reaction * severity;
+    }
+
     if event_type == EventType::SocialInclusion {
         if let EventPayload::SocialInclusion { group_id: Some(_) } = event.payload() {
             loneliness_delta -= 0.08 * severity;
@@ -318,6 +424,7 @@ pub(crate) fn interpret_event(event: &Event, entity: &Entity) -> InterpretedEven
         if let EventPayload::Support {
             support_type,
             effectiveness,
+            ..
         } = event.payload()
         {
             if matches!(
@@ -344,6 +451,14 @@ pub(crate) fn interpret_event(event: &Event, entity: &Entity) -> InterpretedEven
         }
     }
 
+    // Recognition before an audience lifts dominance and self-worth further
+    // than private praise
+    if matches!(event_type, EventType::Achievement | EventType::Support) {
+        let recognition = event.payload().audience().recognition_multiplier() as f32;
+        dominance_delta *= recognition;
+        self_worth_delta *= recognition;
+    }
+
     // Modulate by Emotionality (higher = stronger emotional response)
     let emotionality_factor = 1.0 + (emotionality * 0.3);
     valence_delta *= emotionality_factor;
@@ -359,18 +474,56 @@ pub(crate) fn interpret_event(event: &Event, entity: &Entity) -> InterpretedEven
         prc_delta *= agree_factor;
     }
 
-    // Compute attribution (simplified model)
-    let attribution = compute_attribution(event, honesty_humility);
+    // Modulate by cultural orientation: collectivism magnifies exclusion
+    // and burden and discounts individual achievement, and power distance
+    // magnifies humiliation by an authority. Neutral at the defaults.
+    #[cfg(feature = "context")]
+    {
+        let culture = &entity.context().macrosystem().cultural_orientation;
+        let individualism = culture.individualism_collectivism.clamp(-1.0, 1.0) as f32;
+        match event_type {
+            EventType::SocialExclusion if loneliness_delta > 0.0 => {
+                loneliness_delta *= 1.0 - individualism * impact::COLLECTIVIST_SOCIAL_GAIN;
+            }
+            EventType::BurdenFeedback if perceived_liability_delta > 0.0 => {
+                perceived_liability_delta *= 1.0 - individualism * impact::COLLECTIVIST_SOCIAL_GAIN;
+            }
+            EventType::Achievement => {
+                let factor = 1.0 + individualism * impact::COLLECTIVIST_ACHIEVEMENT_DISCOUNT;
+                perceived_liability_delta *= factor;
+                self_hate_delta *= factor;
+                self_worth_delta *= factor;
+            }
+            EventType::Humiliation
+                if dominance_delta < 0.0 && event.has_tag(EventTag::Authority) =>
+            {
+                let excess = (culture.power_distance.clamp(0.0, 1.0) - 0.5) as f32;
+                dominance_delta *= 1.0 + excess * impact::AUTHORITY_HUMILIATION_GAIN;
+            }
+            _ => {}
+        }
+    }
+
+    // Compute attribution from personality and attributional style
+    let style = AttributionalStyle::of(entity).for_valence(base.valence);
+    let attribution = if role.is_observer() {
+        observer_attribution(event, severity)
+    } else {
+        compute_attribution(event, severity, honesty_humility, style)
+    };
 
-    // If stable self-attribution for negative event, increase self-hate and hopelessness
+    // If stable self-attribution for negative event, increase self-hate and
+    // hopelessness, more so for a self-blaming style
     if attribution.is_self_caused() && attribution.is_stable() && valence_delta < 0.0 {
-        self_hate_delta += impact::SELF_HATE * severity;
-        interpersonal_hopelessness_delta += impact::INTERPERSONAL_HOPELESSNESS * severity;
+        let self_blame = style.self_blame_factor();
+        self_hate_delta += impact::SELF_HATE * severity * self_blame;
+        interpersonal_hopelessness_delta +=
+            impact::INTERPERSONAL_HOPELESSNESS * severity * self_blame;
     }
 
     // Compute salience with arousal modulation
     let valence_for_salience = valence_delta;
-    let base_salience = compute_base_salience(event);
+    let base_salience = compute_base_salience(event, severity);
     let salience = compute_arousal_modulated_salience(
         base_salience,
         current_arousal + arousal_delta,
@@ -442,48 +595,42 @@ pub(crate) fn interpret_event(event: &Event, entity: &Entity) -> InterpretedEven
             purpose_delta as f64,
         ));
     }
-    if self_worth_delta.abs() > f32::EPSILON {
+    if fatigue_delta.abs() > f32::EPSILON {
+        state_deltas.push((StatePath::Needs(NeedsPath::Fatigue), fatigue_delta as f64));
+    }
+    if satiation_delta.abs() > f32::EPSILON {
         state_deltas.push((
-            StatePath::MentalHealth(MentalHealthPath::SelfWorth),
-            self_worth_delta as f64,
+            StatePath::Needs(NeedsPath::Satiation),
+            satiation_delta as f64,
         ));
     }
-
-    // Loss-specific state changes (self_worth and grievance)
-    // Spec: spec/subsystems/event-system.md Loss entry
-    if event_type == EventType::Loss {
-        let self_worth_delta = -0.15 * severity * emotionality_factor;
-        let grievance_delta = 0.05 * severity * emotionality_factor;
-
+    if safety_delta.abs() > f32::EPSILON {
+        state_deltas.push((StatePath::Needs(NeedsPath::Safety), safety_delta as f64));
+    }
+    if self_worth_delta.abs() > f32::EPSILON {
         state_deltas.push((
             StatePath::MentalHealth(MentalHealthPath::SelfWorth),
             self_worth_delta as f64,
         ));
-        state_deltas.push((
-            StatePath::Disposition(DispositionPath::Grievance),
-            grievance_delta as f64,
-        ));
     }
 
-    // Conflict-specific state changes (self_worth and grievance)
-    // Spec: spec/subsystems/event-system.md Conflict entry
-    if event_type == EventType::Conflict {
-        let self_worth_delta = -0.06 * severity * emotionality_factor;
-        let grievance_delta = 0.04 * severity * emotionality_factor;
-
+    // Event-specific secondary effects (self_worth and grievance), used by
+    // Loss and Conflict per spec/subsystems/event-system.md
+    if uses_blueprint && (base.self_worth != 0.0 || base.grievance != 0.0) {
         state_deltas.push((
             StatePath::MentalHealth(MentalHealthPath::SelfWorth),
-            self_worth_delta as f64,
+            (base.self_worth * severity * emotionality_factor) as f64,
         ));
         state_deltas.push((
             StatePath::Disposition(DispositionPath::Grievance),
-            grievance_delta as f64,
+            (base.grievance * severity * emotionality_factor) as f64,
         ));
     }
 
-    InterpretedEvent {
+    let interpreted = InterpretedEvent {
         event: event.clone(),
         original_event: event.id().clone(),
+        role,
         attribution,
         valence_delta,
         arousal_delta,
@@ -495,15 +642,121 @@ pub(crate) fn interpret_event(event: &Event, entity: &Entity) -> InterpretedEven
         acquired_capability_delta,
         interpersonal_hopelessness_delta,
         salience,
+        severity_appraisal,
         perceived_severity,
         memory_salience: salience as f64,
         state_deltas,
+        contradicted_recognition: None,
+        preceding_sleep_loss: None,
+        lost_relationship: None,
+    };
+
+    let observed_share = match role {
+        InterpretationRole::Target => return interpreted,
+        InterpretationRole::Witness => impact::WITNESS_IMPACT,
+        InterpretationRole::Secondhand => impact::SECONDHAND_IMPACT,
+    };
+    let empathy = entity
+        .individual_state()
+        .disposition()
+        .empathy_effective()
+        .clamp(0.0, 1.0);
+    interpreted.scaled_by(f64::from(observed_share * empathy))
+}
+
+/// Amplifies the losses of a failure or humiliation that contradicts
+/// earlier recognition given before `audience`.
+///
+/// Negative valence, dominance, and self-worth deltas are multiplied by
+/// the audience's fall multiplier; gains and other dimensions are left as
+/// they are.
+pub(crate) fn amplify_fall(
+    interpreted: &mut InterpretedEvent,
+    recognition: EventId,
+    audience: Audience,
+) {
+    let factor = audience.fall_multiplier();
+    let amplify = |delta: f32| {
+        if delta < 0.0 {
+            delta * factor as f32
+        } else {
+            delta
+        }
+    };
+    interpreted.valence_delta = amplify(interpreted.valence_delta);
+    interpreted.dominance_delta = amplify(interpreted.dominance_delta);
+    for (path, delta) in &mut interpreted.state_deltas {
+        let amplified = matches!(
+            path,
+            StatePath::Mood(MoodPath::Valence | MoodPath::Dominance)
+                | StatePath::MentalHealth(MentalHealthPath::SelfWorth)
+        );
+        if amplified && *delta < 0.0 {
+            *delta *= factor;
+        }
     }
+    interpreted.contradicted_recognition = Some(recognition);
 }
 
-/// Computes base salience from event properties.
-fn compute_base_salience(event: &Event) -> f32 {
-    let severity = event.severity() as f32;
+/// Amplifies the negative valence of an event that follows a sleep
+/// deprivation.
+///
+/// `fatigue` is the fatigue the sleep loss added. Negative valence deltas
+/// are multiplied by `1 + SLEEP_LOSS_VALENCE_GAIN * fatigue`; positive
+/// valence and other dimensions are left as they are.
+pub(crate) fn amplify_when_tired(
+    interpreted: &mut InterpretedEvent,
+    sleep_loss: EventId,
+    fatigue: f32,
+) {
+    let factor = 1.0 + impact::SLEEP_LOSS_VALENCE_GAIN * fatigue.max(0.0);
+    if interpreted.valence_delta < 0.0 {
+        interpreted.valence_delta *= factor;
+    }
+    for (path, delta) in &mut interpreted.state_deltas {
+        if *path == StatePath::Mood(MoodPath::Valence) && *delta < 0.0 {
+            *delta *= f64::from(factor);
+        }
+    }
+    interpreted.preceding_sleep_loss = Some(sleep_loss);
+}
+
+/// Magnifies the loneliness of a loss that ends `relationship`.
+///
+/// `weight` (0-1) is how close and attached the entity was to the person
+/// lost. Loneliness rises by `BEREAVEMENT_LONELINESS` and perceived
+/// reciprocal caring falls by `BEREAVEMENT_PRC`, both scaled by the
+/// perceived severity and `weight`, so losing a partner leaves the entity
+/// far lonelier than losing a colleague.
+pub(crate) fn amplify_bereavement(
+    interpreted: &mut InterpretedEvent,
+    relationship: RelationshipId,
+    weight: f64,
+) {
+    let scale = (interpreted.perceived_severity * weight.clamp(0.0, 1.0)) as f32;
+    let loneliness = impact::BEREAVEMENT_LONELINESS * scale;
+    let prc = impact::BEREAVEMENT_PRC * scale;
+    interpreted.loneliness_delta += loneliness;
+    interpreted.prc_delta += prc;
+    for (path, delta) in [
+        (SocialCognitionPath::Loneliness, loneliness),
+        (SocialCognitionPath::PerceivedReciprocalCaring, prc),
+    ] {
+        let path = StatePath::SocialCognition(path);
+        match interpreted
+            .state_deltas
+            .iter_mut()
+            .find(|(p, _)| *p == path)
+        {
+            Some((_, existing)) => *existing += f64::from(delta),
+            None => interpreted.state_deltas.push((path, f64::from(delta))),
+        }
+    }
+    interpreted.lost_relationship = Some(relationship);
+}
+
+/// Computes base salience from event properties and subjective severity.
+fn compute_base_salience(event: &Event, severity: f32) -> f32 {
     let category_boost = match event.category() {
         EventCategory::Trauma => 0.2,
         EventCategory::SocialBelonging | EventCategory::BurdenPerception => 0.1,
@@ -513,47 +766,137 @@ fn compute_base_salience(event: &Event) -> f32 {
     (0.3 + severity * 0.5 + category_boost).clamp(0.0, 1.0)
 }
 
-/// Computes attribution based on event and personality.
-fn compute_attribution(event: &Event, honesty_humility: f32) -> Attribution {
-    // Simple model: higher honesty-humility = more internal attribution
-    // Event source affects attribution
-    if let Some(source) = event.source() {
-        // There's a clear external cause
-        let stability = if event.severity() > 0.7 {
-            AttributionStability::Stable
+/// Returns the attribution `entity` makes for `event`, as computed during
+/// interpretation.
+pub(crate) fn attribute_event(event: &Event, entity: &Entity) -> Attribution {
+    let severity = appraise_severity(event, entity).subjective() as f32;
+    let honesty_humility = entity.individual_state().hexaco().honesty_humility();
+    let valence = base_impact(event.category(), event.event_type()).valence;
+    let style = AttributionalStyle::of(entity).for_valence(valence);
+    compute_attribution(event, severity, honesty_humility, style)
+}
+
+/// Self-worth at which attributional style is neutral (the default base).
+const NEUTRAL_SELF_WORTH: f32 = 0.6;
+
+/// Severity above which a neutral attributional style sees a cause as stable.
+const STABLE_SEVERITY: f32 = 0.7;
+
+/// How far a fully biased style moves the stable-severity threshold.
+const STABILITY_BIAS_RANGE: f32 = 0.4;
+
+/// An entity's attributional style for negative events.
+///
+/// Neuroticism and low self-worth push toward internal, stable causes
+/// ("it's me, and it always will be"); grievance pushes blame outward but
+/// also toward stable causes. Both biases are zero at neutral neuroticism,
+/// default self-worth and no grievance, which attributes as before
+/// personality was considered.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct AttributionalStyle {
+    /// Added to honesty-humility when choosing self over circumstance.
+    internal: f32,
+    /// Lowers the severity at which a cause is seen as stable.
+    stable: f32,
+}
+
+impl AttributionalStyle {
+    fn of(entity: &Entity) -> Self {
+        let neuroticism = entity.individual_state().hexaco().neuroticism();
+        let self_worth = entity
+            .get_effective(StatePath::MentalHealth(MentalHealthPath::SelfWorth))
+            .unwrap_or(f64::from(NEUTRAL_SELF_WORTH)) as f32;
+        let grievance = entity
+            .get_effective(StatePath::Disposition(DispositionPath::Grievance))
+            .unwrap_or(0.0) as f32;
+        let unworthiness = NEUTRAL_SELF_WORTH - self_worth;
+        AttributionalStyle {
+            internal: (0.5 * neuroticism + unworthiness - grievance).clamp(-1.0, 1.0),
+            stable: (0.5 * neuroticism + unworthiness + 0.5 * grievance).clamp(-1.0, 1.0),
+        }
+    }
+
+    /// Returns the style applied to an event of base `valence`: as is for
+    /// negative events, reversed for positive ones (a pessimistic style
+    /// credits successes to passing circumstance), and neutral otherwise.
+    fn for_valence(self, valence: f32) -> Self {
+        if valence < 0.0 {
+            self
+        } else if valence > 0.0 {
+            AttributionalStyle {
+                internal: -self.internal,
+                stable: -self.stable,
+            }
         } else {
-            AttributionStability::Unstable
-        };
-        return Attribution::Other(source.clone(), stability);
+            AttributionalStyle::default()
+        }
     }
 
-    // No clear source - attribution based on personality
-    let stability = if event.severity() > 0.7 {
+    /// Multiplier on the self-hate and hopelessness a stable
+    /// self-attribution adds (1.0 for a neutral or outward style).
+    fn self_blame_factor(self) -> f32 {
+        1.0 + self.internal.max(0.0) + self.stable.max(0.0)
+    }
+}
+
+/// Computes attribution based on event, personality and attributional
+/// style.
+fn compute_attribution(
+    event: &Event,
+    severity: f32,
+    honesty_humility: f32,
+    style: AttributionalStyle,
+) -> Attribution {
+    // A biased style sees lesser events as having stable causes
+    let stable_severity = STABLE_SEVERITY - STABILITY_BIAS_RANGE * style.stable;
+    let stability = if severity > stable_severity {
         AttributionStability::Stable
     } else {
         AttributionStability::Unstable
     };
 
-    // Higher honesty-humility = more likely to self-attribute
-    if honesty_humility > 0.3 {
+    // Event source affects attribution
+    if let Some(source) = event.source() {
+        // There's a clear external cause
+        return Attribution::Other(source.clone(), stability);
+    }
+
+    // No clear source - attribution based on personality. Higher
+    // honesty-humility, neuroticism and lower self-worth = more likely to
+    // self-attribute; grievance looks to circumstance
+    let internality = honesty_humility + style.internal;
+    if internality > 0.3 {
         Attribution::SelfCaused(stability)
-    } else if honesty_humility < -0.3 {
+    } else if internality < -0.3 {
         Attribution::Situational(stability)
     } else {
         Attribution::Unknown
     }
 }
 
+/// Computes the attribution of an entity that observed an event happen to
+/// someone else: the event's source, or failing that its target.
+fn observer_attribution(event: &Event, severity: f32) -> Attribution {
+    let stability = if severity > 0.7 {
+        AttributionStability::Stable
+    } else {
+        AttributionStability::Unstable
+    };
+    match event.source().or(event.target()) {
+        Some(other) => Attribution::Other(other.clone(), stability),
+        None => Attribution::Situational(stability),
+    }
+}
+
 /// Processes payload for social events.
 fn process_social_event_payload(
     event: &Event,
+    severity: f32,
     valence_delta: &mut f32,
     arousal_delta: &mut f32,
     prc_delta: &mut f32,
     loneliness_delta: &mut f32,
 ) {
-    let severity = event.severity() as f32;
-
     match event.payload() {
         EventPayload::Support { effectiveness, .. } => {
             let eff = *effectiveness as f32;
@@ -599,7 +942,6 @@ fn process_social_event_payload(
 ///
 /// * `interpreted` - The interpreted event with computed deltas
 /// * `entity` - The entity to modify
-#[cfg(test)]
 pub(crate) fn apply_interpreted_event(interpreted: &InterpretedEvent, entity: &mut Entity) {
     use crate::enums::EventTag;
 
@@ -607,98 +949,11 @@ pub(crate) fn apply_interpreted_event(interpreted: &InterpretedEvent, entity: &m
 
     // Apply state changes by iterating state_deltas
     for (path, delta) in &interpreted.state_deltas {
-        let delta_f32 = *delta as f32;
-        match path {
-            StatePath::Mood(MoodPath::Valence) => {
-                entity
-                    .individual_state_mut()
-                    .mood_mut()
-                    .add_valence_delta(delta_f32);
-            }
-            StatePath::Mood(MoodPath::Arousal) => {
-                entity
-                    .individual_state_mut()
-                    .mood_mut()
-                    .add_arousal_delta(delta_f32);
-            }
-            StatePath::Mood(MoodPath::Dominance) => {
-                entity
-                    .individual_state_mut()
-                    .mood_mut()
-                    .add_dominance_delta(delta_f32);
-            }
-            StatePath::SocialCognition(SocialCognitionPath::Loneliness) => {
-                let social = entity.individual_state_mut().social_cognition_mut();
-                if chronic {
-                    social.loneliness_mut().add_chronic_delta(delta_f32);
-                } else {
-                    social.add_loneliness_delta(delta_f32);
-                }
-            }
-            StatePath::SocialCognition(SocialCognitionPath::PerceivedReciprocalCaring) => {
-                let social = entity.individual_state_mut().social_cognition_mut();
-                if chronic {
-                    social
-                        .perceived_reciprocal_caring_mut()
-                        .add_chronic_delta(delta_f32);
-                } else {
-                    social.add_perceived_reciprocal_caring_delta(delta_f32);
-                }
-            }
-            StatePath::SocialCognition(SocialCognitionPath::PerceivedLiability) => {
-                let social = entity.individual_state_mut().social_cognition_mut();
-                if chronic {
-                    social
-                        .perceived_liability_mut()
-                        .add_chronic_delta(delta_f32);
-                } else {
-                    social.add_perceived_liability_delta(delta_f32);
-                }
-            }
-            StatePath::SocialCognition(SocialCognitionPath::SelfHate) => {
-                let social = entity.individual_state_mut().social_cognition_mut();
-                if chronic {
-                    social.self_hate_mut().add_chronic_delta(delta_f32);
-                } else {
-                    social.add_self_hate_delta(delta_f32);
-                }
-            }
-            StatePath::Needs(NeedsPath::Purpose) => {
-                entity
-                    .individual_state_mut()
-                    .needs_mut()
-                    .add_purpose_delta(delta_f32);
-            }
-            StatePath::MentalHealth(MentalHealthPath::AcquiredCapability) => {
-                entity
-                    .individual_state_mut()
-                    .mental_health_mut()
-                    .add_acquired_capability_delta(delta_f32);
-            }
-            StatePath::MentalHealth(MentalHealthPath::InterpersonalHopelessness) => {
-                entity
-                    .individual_state_mut()
-                    .mental_health_mut()
-                    .add_interpersonal_hopelessness_delta(delta_f32);
-            }
-            StatePath::MentalHealth(MentalHealthPath::SelfWorth) => {
-                entity
-                    .individual_state_mut()
-                    .mental_health_mut()
-                    .add_self_worth_delta(delta_f32);
-            }
-            StatePath::Disposition(DispositionPath::Grievance) => {
-                entity
-                    .individual_state_mut()
-                    .disposition_mut()
-                    .add_grievance_delta(delta_f32);
-            }
-            // Other paths are not processed by event interpretation
-            _ => {}
-        }
+        apply_event_delta(entity.individual_state_mut(), *path, *delta as f32, chronic);
     }
 
     // Create and store memory
+    #[cfg(feature = "memory")]
     store_event_memory(interpreted, entity);
 }
 
@@ -706,6 +961,7 @@ pub(crate) fn apply_interpreted_event(interpreted: &InterpretedEvent, entity: &m
 ///
 /// For events with a source and target, this updates the target's
 /// trustworthiness perceptions of the source.
+#[cfg(feature = "relationships")]
 pub(crate) fn process_event_to_relationships(
     event: &Event,
     timestamp: Timestamp,
@@ -753,6 +1009,39 @@ pub(crate) fn process_event_to_relationships(
     }
 }
 
+/// Removes the trust antecedents `process_event_to_relationships` recorded
+/// for an event, recomputing the affected trustworthiness.
+#[cfg(feature = "relationships")]
+pub(crate) fn retract_event_from_relationships(
+    event: &Event,
+    timestamp: Timestamp,
+    relationships: &mut [Relationship],
+) {
+    let (Some(source), Some(target)) = (event.source(), event.target()) else {
+        return;
+    };
+
+    let mappings = get_antecedent_for_event(event);
+    let severity = event.severity() as f32;
+    for relationship in relationships.iter_mut() {
+        let Some(direction) = direction_for_relationship(relationship, target, source) else {
+            continue;
+        };
+        for mapping in &mappings {
+            if (mapping.base_magnitude * severity).clamp(0.0, 1.0) <= 0.0 {
+                continue;
+            }
+            relationship.remove_antecedent(
+                direction,
+                timestamp,
+                mapping.antecedent_type,
+                mapping.context,
+            );
+        }
+    }
+}
+
+#[cfg(feature = "relationships")]
 fn direction_for_relationship(
     relationship: &Relationship,
     trustor: &crate::types::EntityId,
@@ -768,7 +1057,7 @@ fn direction_for_relationship(
 }
 
 /// Stores a memory of the event.
-#[cfg(test)]
+#[cfg(feature = "memory")]
 fn store_event_memory(interpreted: &InterpretedEvent, entity: &mut Entity) {
     let event = &interpreted.event;
 
@@ -781,23 +1070,26 @@ fn store_event_memory(interpreted: &InterpretedEvent, entity: &mut Entity) {
         _ => tags.push(MemoryTag::Personal),
     }
 
-    // Get participants from event source
+    // Get participants from event source, and the target for an observer
     let mut participants = Vec::new();
     if let Some(source) = event.source() {
         participants.push(source.clone());
     }
+    if interpreted.role.is_observer() {
+        participants.extend(event.target().cloned());
+    }
 
-    // Get microsystem context
-    let microsystem = event.microsystem_context().cloned();
-
-    // Use the entity's create_memory method which handles everything
-    let _ = entity.create_memory(
-        event.event_type().name(),
-        participants,
-        tags,
-        interpreted.salience,
-        microsystem,
-    );
+    let mut entry = MemoryEntry::new(entity.age(), event.event_type().name())
+        .with_participants(participants)
+        .with_tags(tags)
+        .with_salience(interpreted.salience)
+        .with_emotional_snapshot(entity.mood_snapshot())
+        .with_source(interpreted.role.memory_source());
+    if let Some(context) = event.microsystem_context() {
+        entry = entry.with_microsystem_context(context.clone());
+    }
+    let layer = Entity::layer_for_salience(interpreted.salience);
+    entity.memories_mut().add(layer, entry);
 }
 
 /// Processes an event completely: interprets and applies it.
@@ -840,7 +1132,6 @@ fn store_event_memory(interpreted: &InterpretedEvent, entity: &mut Entity) {
 /// let final_valence = entity.get_effective(StatePath::Mood(MoodPath::Valence));
 /// assert!(final_valence < initial_valence); // Exclusion reduced valence
 /// ```
-#[cfg(test)]
 pub(crate) fn process_event(event: &Event, entity: &mut Entity) -> InterpretedEvent {
     let interpreted = interpret_event(event, entity);
     apply_interpreted_event(&interpreted, entity);
@@ -851,14 +1142,18 @@ pub(crate) fn process_event(event: &Event, entity: &mut Entity) -> InterpretedEv
 mod tests {
     use super::*;
     use crate::entity::EntityBuilder;
+    #[cfg(feature = "relationships")]
+    use crate::enums::Direction;
+    #[cfg(feature = "memory")]
+    use crate::enums::WeaponType;
     use crate::enums::{
-        Direction, EventTag, LifeDomain, PersonalityProfile, RealizationType, Species,
-        SupportType, WeaponType,
+        EventTag, LifeDomain, PersonalityProfile, RealizationType, Species, SupportType,
     };
     use crate::event::EventBuilder;
+    #[cfg(feature = "memory")]
     use crate::memory::MemoryTag;
     use crate::state::Hexaco;
-    use crate::types::{EntityId, GroupId};
+    use crate::types::{EntityId, GroupId, RelationshipId};
 
     fn create_human() -> Entity {
         EntityBuilder::new()
@@ -867,6 +1162,44 @@ mod tests {
             .unwrap()
     }
 
+    #[test]
+    fn blueprint_summaries_match_neutral_interpretation() {
+        let entity = EntityBuilder::new()
+            .species(Species::Human)
+            .hexaco(Hexaco::new())
+            .build()
+            .unwrap();
+        let reference = crate::docs::event_reference();
+        assert_eq!(reference.len(), EventType::all().len());
+
+        for (summary, event_type) in reference.iter().zip(EventType::all()) {
+            assert_eq!(summary.event_type, event_type);
+            let event = EventBuilder::new(event_type).severity(1.0).build().unwrap();
+            let interpreted = interpret_event(&event, &entity);
+
+            let interpreted_paths: Vec<StatePath> =
+                interpreted.state_deltas.iter().map(|(path, _)| *path).collect();
+            assert_eq!(
+                summary.affected_paths(),
+                interpreted_paths,
+                "{:?} summary paths disagree with interpretation",
+                event_type
+            );
+            for ((path, expected), (_, actual)) in
+                summary.deltas.iter().zip(&interpreted.state_deltas)
+            {
+                assert!(
+                    (expected - actual).abs() < 1e-6,
+                    "{:?} {:?}: summary {} vs interpreted {}",
+                    event_type,
+                    path,
+                    expected,
+                    actual
+                );
+            }
+        }
+    }
+
     #[test]
     fn interpret_social_exclusion_negative_valence() {
         let entity = create_human();
@@ -916,6 +1249,49 @@ mod tests {
         assert!(interpreted.dominance_delta.abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn interpret_event_uses_subjective_severity() {
+        let calm = create_human();
+        let objective_only = EventBuilder::new(EventType::Loss)
+            .objective_severity(0.2)
+            .build()
+            .unwrap();
+        let felt_as_severe = EventBuilder::new(EventType::Loss)
+            .objective_severity(0.2)
+            .severity(0.8)
+            .build()
+            .unwrap();
+        let subjective_only = EventBuilder::new(EventType::Loss)
+            .severity(0.8)
+            .build()
+            .unwrap();
+
+        let appraised = interpret_event(&objective_only, &calm);
+        let both = interpret_event(&felt_as_severe, &calm);
+        let given = interpret_event(&subjective_only, &calm);
+
+        // Explicit subjective severity drives effects and salience
+        assert!((both.valence_delta - given.valence_delta).abs() < f32::EPSILON);
+        assert!((both.memory_salience - given.memory_salience).abs() < f64::EPSILON);
+        assert!(both.valence_delta < appraised.valence_delta);
+        assert!(both.memory_salience > appraised.memory_salience);
+        assert!((both.severity_appraisal.gap().unwrap() - 0.6).abs() < 1e-9);
+        assert_eq!(given.severity_appraisal.gap(), None);
+
+        // Objective-only severity is appraised through personality
+        let anxious = EntityBuilder::new()
+            .species(Species::Human)
+            .hexaco(Hexaco::new().with_neuroticism(0.9))
+            .build()
+            .unwrap();
+        let anxious_appraisal = interpret_event(&objective_only, &anxious);
+        assert!(anxious_appraisal.severity_appraisal.is_appraised());
+        assert!(
+            anxious_appraisal.severity_appraisal.subjective()
+                > appraised.severity_appraisal.subjective()
+        );
+    }
+
     #[test]
     fn interpret_burden_feedback_increases_liability() {
         let entity = create_human();
@@ -979,6 +1355,7 @@ mod tests {
             .payload(EventPayload::Support {
                 support_type: SupportType::Emotional,
                 effectiveness: 0.9,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -997,6 +1374,7 @@ mod tests {
             .payload(EventPayload::Achievement {
                 domain: LifeDomain::Work,
                 magnitude: 0.7,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -1011,6 +1389,305 @@ mod tests {
             .any(|(path, _)| *path == StatePath::MentalHealth(MentalHealthPath::SelfWorth)));
     }
 
+    #[test]
+    fn public_recognition_scales_dominance_and_self_worth() {
+        let entity = create_human();
+        let achievement = |audience| {
+            let event = EventBuilder::new(EventType::Achievement)
+                .severity(0.8)
+                .payload(EventPayload::Achievement {
+                    domain: LifeDomain::Work,
+                    magnitude: 0.7,
+                    audience,
+                })
+                .build()
+                .unwrap();
+            interpret_event(&event, &entity)
+        };
+        let self_worth = |interpreted: &InterpretedEvent| {
+            interpreted
+                .state_deltas
+                .iter()
+                .find(|(path, _)| *path == StatePath::MentalHealth(MentalHealthPath::SelfWorth))
+                .map(|(_, delta)| *delta)
+                .unwrap()
+        };
+
+        let private = achievement(Audience::Private);
+        let public = achievement(Audience::Public);
+        let ratio = Audience::Public.recognition_multiplier() as f32;
+        assert!((public.dominance_delta - private.dominance_delta * ratio).abs() < 1e-6);
+        assert!((self_worth(&public) - self_worth(&private) * ratio as f64).abs() < 1e-6);
+        assert!((public.valence_delta - private.valence_delta).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn amplify_fall_scales_only_losses() {
+        let entity = create_human();
+        let event = EventBuilder::new(EventType::Humiliation)
+            .severity(0.6)
+            .build()
+            .unwrap();
+        let interpreted = interpret_event(&event, &entity);
+        let recognition = EventId::new("award").unwrap();
+
+        let mut amplified = interpreted.clone();
+        amplify_fall(&mut amplified, recognition.clone(), Audience::SmallGroup);
+        let factor = Audience::SmallGroup.fall_multiplier();
+        assert_eq!(amplified.contradicted_recognition, Some(recognition));
+        assert!(interpreted.dominance_delta < 0.0);
+        assert!(
+            (amplified.dominance_delta - interpreted.dominance_delta * factor as f32).abs() < 1e-6
+        );
+        for ((path, before), (_, after)) in interpreted
+            .state_deltas
+            .iter()
+            .zip(&amplified.state_deltas)
+        {
+            let amplified_path = matches!(
+                path,
+                StatePath::Mood(MoodPath::Valence | MoodPath::Dominance)
+                    | StatePath::MentalHealth(MentalHealthPath::SelfWorth)
+            );
+            let expected = if amplified_path && *before < 0.0 {
+                before * factor
+            } else {
+                *before
+            };
+            assert!((after - expected).abs() < 1e-12, "{:?}", path);
+        }
+    }
+
+    #[test]
+    fn sleep_payload_scales_fatigue_by_restoration() {
+        let entity = create_human();
+        let sleep = |event_type, hours| {
+            let event = EventBuilder::new(event_type)
+                .payload(EventPayload::Sleep {
+                    hours,
+                    quality: 1.0,
+                })
+                .build()
+                .unwrap();
+            let interpreted = interpret_event(&event, &entity);
+            let fatigue = interpreted
+                .state_deltas
+                .iter()
+                .find(|(path, _)| *path == StatePath::Needs(NeedsPath::Fatigue))
+                .map_or(0.0, |(_, delta)| *delta);
+            (fatigue, interpreted)
+        };
+
+        let (no_sleep, deprived) = sleep(EventType::SleepDeprivation, 0.0);
+        let (short_sleep, _) = sleep(EventType::SleepDeprivation, 6.0);
+        assert!(no_sleep > short_sleep && short_sleep > 0.0);
+        assert!(deprived.arousal_delta < 0.0);
+        assert!(deprived.valence_delta.abs() < f32::EPSILON);
+
+        let (full_night, rested) = sleep(EventType::Rest, 8.0);
+        let (nap, _) = sleep(EventType::Rest, 2.0);
+        assert!(full_night < nap && nap < 0.0);
+        assert!(rested.arousal_delta > 0.0);
+
+        // A full night leaves nothing for deprivation to apply
+        let (restored, _) = sleep(EventType::SleepDeprivation, 10.0);
+        assert!(restored.abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn feeding_calms_by_hunger_and_threat_lowers_safety() {
+        let delta = |interpreted: &InterpretedEvent, path| {
+            interpreted
+                .state_deltas
+                .iter()
+                .find(|(candidate, _)| *candidate == path)
+                .map_or(0.0, |(_, delta)| *delta)
+        };
+        let feeding = EventBuilder::new(EventType::Feeding)
+            .severity(0.8)
+            .build()
+            .unwrap();
+        let mut hungry = create_human();
+        let resting = interpret_event(&feeding, &hungry);
+        assert!(delta(&resting, StatePath::Needs(NeedsPath::Satiation)) > 0.3);
+        assert!(resting.arousal_delta < 0.0);
+
+        // Half the way from resting hunger to full halves the calming
+        let room = 1.0 - hungry.individual_state().needs().satiation_base();
+        hungry
+            .individual_state_mut()
+            .needs_mut()
+            .add_satiation_delta(room / 2.0);
+        let half_full = interpret_event(&feeding, &hungry);
+        assert!((half_full.arousal_delta - resting.arousal_delta / 2.0).abs() < 1e-6);
+
+        let threat = EventBuilder::new(EventType::ThreatExposure)
+            .severity(0.8)
+            .build()
+            .unwrap();
+        let threatened = interpret_event(&threat, &create_human());
+        assert!(delta(&threatened, StatePath::Needs(NeedsPath::Safety)) < -0.3);
+        assert!(threatened.arousal_delta > 0.0);
+        assert!(threatened.valence_delta < 0.0);
+    }
+
+    #[test]
+    fn amplify_when_tired_scales_only_negative_valence() {
+        let entity = create_human();
+        let event = EventBuilder::new(EventType::Humiliation)
+            .severity(0.6)
+            .build()
+            .unwrap();
+        let interpreted = interpret_event(&event, &entity);
+        let sleep_loss = EventId::new("all_nighter").unwrap();
+
+        let mut amplified = interpreted.clone();
+        amplify_when_tired(&mut amplified, sleep_loss.clone(), 0.4);
+        let factor = 1.0 + impact::SLEEP_LOSS_VALENCE_GAIN * 0.4;
+        assert_eq!(amplified.preceding_sleep_loss, Some(sleep_loss));
+        assert!((amplified.valence_delta - interpreted.valence_delta * factor).abs() < 1e-6);
+        assert!((amplified.dominance_delta - interpreted.dominance_delta).abs() < f32::EPSILON);
+        for ((path, before), (_, after)) in
+            interpreted.state_deltas.iter().zip(&amplified.state_deltas)
+        {
+            let expected = if *path == StatePath::Mood(MoodPath::Valence) && *before < 0.0 {
+                before * f64::from(factor)
+            } else {
+                *before
+            };
+            assert!((after - expected).abs() < 1e-12, "{:?}", path);
+        }
+    }
+
+    #[test]
+    fn amplify_bereavement_scales_loneliness_by_weight() {
+        let entity = create_human();
+        let event = EventBuilder::new(EventType::Loss)
+            .severity(0.8)
+            .build()
+            .unwrap();
+        let interpreted = interpret_event(&event, &entity);
+        let relationship = RelationshipId::new("rel_partner").unwrap();
+        let loneliness = |interpreted: &InterpretedEvent| {
+            interpreted
+                .state_deltas
+                .iter()
+                .find(|(path, _)| {
+                    *path == StatePath::SocialCognition(SocialCognitionPath::Loneliness)
+                })
+                .map_or(0.0, |(_, delta)| *delta)
+        };
+
+        let mut close = interpreted.clone();
+        amplify_bereavement(&mut close, relationship.clone(), 1.0);
+        let mut distant = interpreted.clone();
+        amplify_bereavement(&mut distant, relationship.clone(), 0.1);
+
+        assert_eq!(close.lost_relationship, Some(relationship));
+        assert!(loneliness(&close) > loneliness(&distant));
+        assert!(loneliness(&distant) > loneliness(&interpreted));
+        assert!(close.prc_delta < distant.prc_delta);
+        assert!((close.valence_delta - interpreted.valence_delta).abs() < f32::EPSILON);
+    }
+
+    #[cfg(feature = "context")]
+    fn create_human_in_culture(individualism: f64, power_distance: f64) -> Entity {
+        let mut entity = create_human();
+        let culture = &mut entity.context_mut().macrosystem_mut().cultural_orientation;
+        culture.individualism_collectivism = individualism;
+        culture.power_distance = power_distance;
+        entity
+    }
+
+    #[test]
+    #[cfg(feature = "context")]
+    fn collectivism_magnifies_exclusion_and_burden() {
+        let collectivist = create_human_in_culture(-1.0, 0.5);
+        let neutral = create_human_in_culture(0.0, 0.5);
+        let individualist = create_human_in_culture(1.0, 0.5);
+
+        let exclusion = EventBuilder::new(EventType::SocialExclusion)
+            .severity(0.7)
+            .build()
+            .unwrap();
+        let loneliness = |entity: &Entity| interpret_event(&exclusion, entity).loneliness_delta;
+        let gain = 1.0 + impact::COLLECTIVIST_SOCIAL_GAIN;
+        assert!((loneliness(&collectivist) - loneliness(&neutral) * gain).abs() < 1e-6);
+        assert!(loneliness(&individualist) < loneliness(&neutral));
+        assert_eq!(
+            loneliness(&neutral),
+            interpret_event(&exclusion, &create_human()).loneliness_delta
+        );
+
+        let burden = EventBuilder::new(EventType::BurdenFeedback)
+            .severity(0.7)
+            .build()
+            .unwrap();
+        let liability =
+            |entity: &Entity| interpret_event(&burden, entity).perceived_liability_delta;
+        assert!(liability(&collectivist) > liability(&neutral));
+        assert!(liability(&individualist) < liability(&neutral));
+    }
+
+    #[test]
+    #[cfg(feature = "context")]
+    fn collectivism_discounts_achievement_protection() {
+        let event = EventBuilder::new(EventType::Achievement)
+            .severity(0.7)
+            .payload(EventPayload::Achievement {
+                domain: LifeDomain::Work,
+                magnitude: 0.6,
+                audience: Audience::Private,
+            })
+            .build()
+            .unwrap();
+        let collectivist = interpret_event(&event, &create_human_in_culture(-1.0, 0.5));
+        let individualist = interpret_event(&event, &create_human_in_culture(1.0, 0.5));
+        let self_worth = |interpreted: &InterpretedEvent| {
+            interpreted
+                .state_deltas
+                .iter()
+                .find(|(path, _)| *path == StatePath::MentalHealth(MentalHealthPath::SelfWorth))
+                .map_or(0.0, |(_, delta)| *delta)
+        };
+
+        assert!(self_worth(&collectivist) > 0.0);
+        assert!(self_worth(&collectivist) < self_worth(&individualist));
+        assert!(collectivist.perceived_liability_delta > individualist.perceived_liability_delta);
+        assert!(collectivist.self_hate_delta > individualist.self_hate_delta);
+        assert!((collectivist.valence_delta - individualist.valence_delta).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    #[cfg(feature = "context")]
+    fn power_distance_magnifies_humiliation_by_authority() {
+        let hierarchical = create_human_in_culture(0.0, 1.0);
+        let egalitarian = create_human_in_culture(0.0, 0.0);
+        let by_authority = EventBuilder::new(EventType::Humiliation)
+            .severity(0.6)
+            .tag(EventTag::Authority)
+            .build()
+            .unwrap();
+        let by_peer = EventBuilder::new(EventType::Humiliation)
+            .severity(0.6)
+            .build()
+            .unwrap();
+        let dominance =
+            |event: &Event, entity: &Entity| interpret_event(event, entity).dominance_delta;
+
+        let factor = 1.0 + 0.5 * impact::AUTHORITY_HUMILIATION_GAIN;
+        assert!(
+            (dominance(&by_authority, &hierarchical) - dominance(&by_peer, &hierarchical) * factor)
+                .abs()
+                < 1e-6
+        );
+        assert!(dominance(&by_authority, &egalitarian) > dominance(&by_peer, &egalitarian));
+        assert_eq!(
+            dominance(&by_peer, &hierarchical),
+            dominance(&by_peer, &egalitarian)
+        );
+    }
+
     #[test]
     fn interpret_financial_achievement_impacts_self_worth_only() {
         let entity = create_human();
@@ -1019,6 +1696,7 @@ mod tests {
             .payload(EventPayload::Achievement {
                 domain: LifeDomain::Financial,
                 magnitude: 0.6,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -1046,6 +1724,7 @@ mod tests {
             .payload(EventPayload::Achievement {
                 domain: LifeDomain::Creative,
                 magnitude: 0.6,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -1096,6 +1775,7 @@ mod tests {
             .payload(EventPayload::Support {
                 support_type: SupportType::Emotional,
                 effectiveness: 0.8,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -1106,6 +1786,29 @@ mod tests {
         assert!(interpreted.self_hate_delta < 0.0);
     }
 
+    #[test]
+    fn disclosure_response_follows_partner_reaction() {
+        let entity = create_human();
+        let disclosure = |reaction| {
+            let event = EventBuilder::new(EventType::Interaction)
+                .severity(0.6)
+                .payload(EventPayload::Disclosure {
+                    domain: LifeDomain::Relationship,
+                    relationship: RelationshipId::new("rel_partner").unwrap(),
+                    reaction,
+                })
+                .build()
+                .unwrap();
+            interpret_event(&event, &entity)
+        };
+
+        let accepted = disclosure(0.9);
+        let rejected = disclosure(-0.9);
+        assert!(accepted.valence_delta > 0.0 && accepted.prc_delta > 0.0);
+        assert!(rejected.valence_delta < 0.0 && rejected.prc_delta < 0.0);
+        assert!(disclosure(0.3).valence_delta < accepted.valence_delta);
+    }
+
     #[test]
     fn interpret_purpose_development_adds_purpose_delta() {
         let entity = create_human();
@@ -1127,6 +1830,28 @@ mod tests {
             .any(|(path, _)| *path == StatePath::Needs(NeedsPath::Purpose)));
     }
 
+    #[test]
+    fn interpret_role_exit_voluntariness_sets_affect() {
+        let entity = create_human();
+        let exit = |voluntary| {
+            EventBuilder::new(EventType::ContextTransition)
+                .severity(0.6)
+                .payload(EventPayload::RoleExit {
+                    domain: LifeDomain::Work,
+                    voluntary,
+                })
+                .build()
+                .unwrap()
+        };
+
+        let chosen = interpret_event(&exit(true), &entity);
+        let imposed = interpret_event(&exit(false), &entity);
+
+        assert!(chosen.valence_delta > 0.0);
+        assert!(imposed.valence_delta < 0.0);
+        assert!(imposed.dominance_delta < chosen.dominance_delta);
+    }
+
     #[test]
     fn apply_interpreted_event_modifies_state() {
         let mut entity = create_human();
@@ -1144,6 +1869,7 @@ mod tests {
         assert!(final_valence < initial_valence);
     }
 
+    #[cfg(feature = "memory")]
     #[test]
     fn apply_interpreted_event_creates_memory() {
         let mut entity = create_human();
@@ -1330,6 +2056,7 @@ mod tests {
         assert!(interpreted.arousal_delta > 0.05);
     }
 
+    #[cfg(feature = "memory")]
     #[test]
     fn memory_layer_based_on_salience() {
         let mut entity = create_human();
@@ -1507,6 +2234,113 @@ mod tests {
         assert!(after > before);
     }
 
+    /// Builds an entity with the given neuroticism, self-worth and grievance
+    /// and neutral honesty-humility.
+    fn styled_entity(neuroticism: f32, self_worth: f32, grievance: f32) -> Entity {
+        let mut entity = EntityBuilder::new()
+            .species(Species::Human)
+            .hexaco(Hexaco::new().with_neuroticism(neuroticism))
+            .build()
+            .unwrap();
+        let state = entity.individual_state_mut();
+        state
+            .mental_health_mut()
+            .self_worth_mut()
+            .set_base(self_worth);
+        state.disposition_mut().grievance_mut().set_base(grievance);
+        entity
+    }
+
+    #[test]
+    fn neutral_style_matches_severity_only_attribution() {
+        let entity = styled_entity(0.0, NEUTRAL_SELF_WORTH, 0.0);
+        assert_eq!(
+            AttributionalStyle::of(&entity),
+            AttributionalStyle::default()
+        );
+        let failure = EventBuilder::new(EventType::Failure)
+            .severity(0.5)
+            .build()
+            .unwrap();
+        assert!(interpret_event(&failure, &entity).attribution.is_unknown());
+    }
+
+    #[test]
+    fn neurotic_low_self_worth_blames_self_stably() {
+        let entity = styled_entity(0.8, 0.2, 0.0);
+        let failure = EventBuilder::new(EventType::Failure)
+            .severity(0.5)
+            .build()
+            .unwrap();
+        let attribution = interpret_event(&failure, &entity).attribution;
+        assert_eq!(
+            attribution,
+            Attribution::SelfCaused(AttributionStability::Stable)
+        );
+
+        // The same style credits success to passing circumstance
+        let achievement = EventBuilder::new(EventType::Achievement)
+            .severity(0.5)
+            .build()
+            .unwrap();
+        assert_eq!(
+            attribute_event(&achievement, &entity),
+            Attribution::Situational(AttributionStability::Unstable)
+        );
+    }
+
+    #[test]
+    fn grievance_blames_circumstance_stably() {
+        let entity = styled_entity(0.0, NEUTRAL_SELF_WORTH, 0.9);
+        let failure = EventBuilder::new(EventType::Failure)
+            .severity(0.65)
+            .build()
+            .unwrap();
+        let interpreted = interpret_event(&failure, &entity);
+        assert_eq!(
+            interpreted.attribution,
+            Attribution::Situational(AttributionStability::Stable)
+        );
+        assert!(interpreted.interpersonal_hopelessness_delta.abs() < f32::EPSILON);
+
+        // Without grievance the same failure has a passing cause
+        let neutral = styled_entity(0.0, NEUTRAL_SELF_WORTH, 0.0);
+        assert!(!interpret_event(&failure, &neutral).attribution.is_stable());
+    }
+
+    #[test]
+    fn self_blaming_style_amplifies_self_hate() {
+        let hexaco = Hexaco::new().with_honesty_humility(0.8);
+        let neutral = EntityBuilder::new()
+            .species(Species::Human)
+            .hexaco(hexaco.clone())
+            .build()
+            .unwrap();
+        let mut anxious = EntityBuilder::new()
+            .species(Species::Human)
+            .hexaco(hexaco.with_neuroticism(0.8))
+            .build()
+            .unwrap();
+        anxious
+            .individual_state_mut()
+            .mental_health_mut()
+            .self_worth_mut()
+            .set_base(0.3);
+        let event = EventBuilder::new(EventType::BurdenFeedback)
+            .severity(0.9)
+            .build()
+            .unwrap();
+
+        let neutral = interpret_event(&event, &neutral);
+        let anxious = interpret_event(&event, &anxious);
+        assert!(neutral.attribution.is_self_caused() && neutral.attribution.is_stable());
+        assert!(anxious.attribution.is_self_caused() && anxious.attribution.is_stable());
+        assert!(
+            anxious.interpersonal_hopelessness_delta > neutral.interpersonal_hopelessness_delta
+        );
+        assert!(anxious.self_hate_delta > neutral.self_hate_delta);
+    }
+
     #[test]
     fn low_honesty_humility_situational_attribution() {
         // Create entity with low honesty-humility (Rebel personality)
@@ -1547,6 +2381,7 @@ mod tests {
         assert!(interpreted.attribution.is_unknown());
     }
 
+    #[cfg(feature = "memory")]
     #[test]
     fn loss_event_uses_personal_tag() {
         let mut entity = create_human();
@@ -1562,6 +2397,7 @@ mod tests {
         assert!(entity.memories().total_count() > 0);
     }
 
+    #[cfg(feature = "memory")]
     #[test]
     fn achievement_event_uses_achievement_tag() {
         let mut entity = create_human();
@@ -1577,6 +2413,7 @@ mod tests {
         assert!(!tagged.is_empty());
     }
 
+    #[cfg(feature = "memory")]
     #[test]
     fn source_event_adds_participant_to_memory() {
         let mut entity = create_human();
@@ -1871,6 +2708,7 @@ mod tests {
             .payload(EventPayload::Support {
                 support_type: crate::enums::SupportType::Emotional,
                 effectiveness: 0.9,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -2043,6 +2881,7 @@ mod tests {
         // Attribution type should match (note: Attribution derives Clone)
     }
 
+    #[cfg(feature = "relationships")]
     #[test]
     fn antecedent_tracks_trust_building_events() {
         use crate::relationship::{AntecedentDirection, AntecedentType, Relationship};
@@ -2074,6 +2913,7 @@ mod tests {
             > 0.0);
     }
 
+    #[cfg(feature = "relationships")]
     #[test]
     fn antecedent_magnitude_scales_with_relationship_consistency() {
         use crate::relationship::{AntecedentType, Relationship};
@@ -2125,6 +2965,7 @@ mod tests {
         assert!((high_mag - high_expected).abs() < 0.01);
     }
 
+    #[cfg(feature = "relationships")]
     #[test]
     fn antecedent_history_provides_trust_narrative() {
         use crate::relationship::Relationship;
@@ -2149,6 +2990,7 @@ mod tests {
             .any(|entry| entry.context().contains("support")));
     }
 
+    #[cfg(feature = "relationships")]
     #[test]
     fn process_event_to_relationships_applies_pattern_consistency_weight() {
         use crate::relationship::{get_antecedent_for_event, Relationship};
@@ -2188,6 +3030,7 @@ mod tests {
         assert!(high_mag > low_mag);
     }
 
+    #[cfg(feature = "relationships")]
     #[test]
     fn process_event_to_relationships_tracks_a_to_b_direction() {
         use crate::relationship::Relationship;
@@ -2211,6 +3054,7 @@ mod tests {
             .is_empty());
     }
 
+    #[cfg(feature = "relationships")]
     #[test]
     fn process_event_to_relationships_requires_source_and_target() {
         use crate::relationship::Relationship;
@@ -2235,6 +3079,7 @@ mod tests {
             .is_empty());
     }
 
+    #[cfg(feature = "relationships")]
     #[test]
     fn process_event_to_relationships_skips_unmapped_events() {
         use crate::relationship::Relationship;
@@ -2261,6 +3106,7 @@ mod tests {
             .is_empty());
     }
 
+    #[cfg(feature = "relationships")]
     #[test]
     fn process_event_to_relationships_skips_unrelated_relationships() {
         use crate::relationship::Relationship;
@@ -2355,6 +3201,7 @@ mod tests {
             .payload(EventPayload::Support {
                 support_type: SupportType::Emotional,
                 effectiveness: 0.9,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -2374,6 +3221,7 @@ mod tests {
             .payload(EventPayload::Support {
                 support_type: SupportType::Companionship,
                 effectiveness: 0.85,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -2393,6 +3241,7 @@ mod tests {
             .payload(EventPayload::Support {
                 support_type: SupportType::Instrumental,
                 effectiveness: 0.9,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -2404,6 +3253,7 @@ mod tests {
         assert!(interpreted.self_hate_delta.abs() < f32::EPSILON);
     }
 
+    #[cfg(feature = "relationships")]
     #[test]
     fn relationship_skips_when_no_matching_direction() {
         use crate::relationship::Relationship;
@@ -2437,6 +3287,7 @@ mod tests {
             .is_empty());
     }
 
+    #[cfg(feature = "relationships")]
     #[test]
     fn relationship_skips_zero_magnitude_antecedents() {
         use crate::relationship::Relationship;
@@ -2661,4 +3512,35 @@ mod tests {
         // Should have base effects but not ExistentialInsight-specific effects
         assert!(!interpreted.state_deltas.is_empty());
     }
+
+    #[test]
+    fn observers_take_a_share_of_the_impact_and_blame_others() {
+        // Self-blaming personality, so a target would self-attribute
+        let hexaco = Hexaco::new().with_honesty_humility(0.8);
+        let entity = EntityBuilder::new()
+            .species(Species::Human)
+            .hexaco(hexaco)
+            .build()
+            .unwrap();
+        let victim = EntityId::new("victim").unwrap();
+        let event = EventBuilder::new(EventType::Humiliation)
+            .target(victim.clone())
+            .severity(0.8)
+            .build()
+            .unwrap();
+
+        let direct = interpret_event_as(&event, &entity, InterpretationRole::Target);
+        let witnessed = interpret_event_as(&event, &entity, InterpretationRole::Witness);
+        let heard = interpret_event_as(&event, &entity, InterpretationRole::Secondhand);
+        assert!(direct.attribution.is_self_caused());
+        assert_eq!(witnessed.attribution.other_entity(), Some(&victim));
+        assert_eq!(heard.attribution.other_entity(), Some(&victim));
+
+        let empathy = entity.individual_state().disposition().empathy_effective();
+        let expected = direct.valence_delta * impact::WITNESS_IMPACT * empathy;
+        assert!((witnessed.valence_delta - expected).abs() < 1e-6);
+        assert!(heard.valence_delta > witnessed.valence_delta);
+        assert!(heard.valence_delta < 0.0);
+        assert_eq!(witnessed.role, InterpretationRole::Witness);
+    }
 }
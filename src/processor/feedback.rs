@@ -19,7 +19,7 @@
 //! cumulative pathological processes that require intervention events
 //! (not automatic reversal).
 
-use crate::enums::Species;
+use crate::enums::{Species, SpiralType};
 use crate::state::IndividualState;
 use crate::types::Duration;
 
@@ -106,13 +106,15 @@ impl SpiralResult {
 /// - if fatigue > 0.5: impulse_control_delta -= 0.01
 /// - if Human and stress > 0.7: depression_delta += 0.005
 ///
-/// Rates are per-tick. When processing time, multiply by duration in days.
+/// Rates are per psychological day: `duration` is scaled by
+/// `Species::psychological_duration()`, so shorter-lived species spiral
+/// faster in real time.
 ///
 /// # Arguments
 ///
 /// * `state` - The individual state to modify
 /// * `species` - The entity's species (affects depression contribution)
-/// * `duration` - The time elapsed (rates are scaled by days)
+/// * `duration` - The real time elapsed (rates are scaled by psychological days)
 ///
 /// # Returns
 ///
@@ -151,7 +153,7 @@ pub fn apply_stress_spiral(
     }
 
     result.triggered = true;
-    let days = duration.as_days() as f32;
+    let days = species.psychological_duration(duration).as_days() as f32;
 
     // Stress causes fatigue
     let fatigue_increase = stress * STRESS_SPIRAL_RATE * days;
@@ -205,7 +207,7 @@ pub fn apply_stress_spiral(
 ///
 /// * `state` - The individual state to modify
 /// * `species` - The entity's species (only affects Human)
-/// * `duration` - The time elapsed (rates are scaled by days)
+/// * `duration` - The real time elapsed (rates are scaled by psychological days)
 ///
 /// # Returns
 ///
@@ -249,7 +251,7 @@ pub fn apply_depression_spiral(
     }
 
     result.triggered = true;
-    let days = duration.as_days() as f32;
+    let days = species.psychological_duration(duration).as_days() as f32;
 
     // Depression increases loneliness passively
     let loneliness_increase = depression * DEPRESSION_SPIRAL_RATE * days;
@@ -279,6 +281,26 @@ pub fn apply_depression_spiral(
     result
 }
 
+/// Returns the feedback spirals active in `state`.
+///
+/// The stress spiral is active above `STRESS_SPIRAL_THRESHOLD`, and is
+/// listed first; the depression spiral is active above
+/// `DEPRESSION_SPIRAL_THRESHOLD`, for humans only. These are the
+/// conditions under which `apply_stress_spiral` and
+/// `apply_depression_spiral` trigger.
+#[must_use]
+pub(crate) fn active_spirals(state: &IndividualState, species: &Species) -> Vec<SpiralType> {
+    let mut spirals = Vec::new();
+    if state.needs().stress_effective() > STRESS_SPIRAL_THRESHOLD {
+        spirals.push(SpiralType::Stress);
+    }
+    let depression = state.mental_health().depression_effective();
+    if *species == Species::Human && depression > DEPRESSION_SPIRAL_THRESHOLD {
+        spirals.push(SpiralType::Depression);
+    }
+    spirals
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -568,6 +590,20 @@ mod tests {
         assert!((result2.fatigue_change - result1.fatigue_change * 2.0).abs() < 0.001);
     }
 
+    #[test]
+    fn shorter_lived_species_accumulate_faster() {
+        let mut human = IndividualState::new();
+        human.needs_mut().stress_mut().set_base(0.8);
+        let mut mouse = human.clone();
+
+        let human_result = apply_stress_spiral(&mut human, &Species::Human, Duration::days(1));
+        let mouse_result = apply_stress_spiral(&mut mouse, &Species::Mouse, Duration::days(1));
+
+        // A mouse day is 40 psychological days
+        let ratio = mouse_result.fatigue_change / human_result.fatigue_change;
+        assert!((ratio - 40.0).abs() < 0.01);
+    }
+
     #[test]
     fn zero_duration_no_effect() {
         let mut state = IndividualState::new();
@@ -722,6 +758,26 @@ mod tests {
             .is_feedback_loop_affected());
     }
 
+    #[test]
+    fn active_spirals_match_the_spiral_thresholds() {
+        let mut state = IndividualState::new();
+        assert!(active_spirals(&state, &Species::Human).is_empty());
+
+        state.mental_health_mut().depression_mut().set_base(0.5);
+        assert_eq!(
+            active_spirals(&state, &Species::Human),
+            [SpiralType::Depression]
+        );
+        assert!(active_spirals(&state, &Species::Dog).is_empty());
+
+        state.needs_mut().stress_mut().set_base(0.7);
+        assert_eq!(
+            active_spirals(&state, &Species::Human),
+            [SpiralType::Stress, SpiralType::Depression]
+        );
+        assert_eq!(active_spirals(&state, &Species::Dog), [SpiralType::Stress]);
+    }
+
     #[test]
     fn feedback_affected_prevents_reversal() {
         use crate::processor::reverse_decay;
@@ -20,7 +20,6 @@
 //! - Three-factor convergence (TB + PB + AC all elevated = highest risk)
 
 use crate::state::{IndividualState, MentalHealth, SocialCognition, TB_PRESENT_THRESHOLD, PB_PRESENT_THRESHOLD};
-use serde::{Deserialize, Serialize};
 
 /// Threshold for Acquired Capability to be considered elevated.
 pub const AC_ELEVATED_THRESHOLD: f32 = 0.3;
@@ -31,7 +30,8 @@ pub const AC_ELEVATED_THRESHOLD: f32 = 0.3;
 /// - TB (Thwarted Belongingness): Unmet need to belong
 /// - PB (Perceived Burdensomeness): Belief of being a burden
 /// - AC (Acquired Capability): Habituation to pain and reduced fear of death
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ItsProximalFactor {
     /// Thwarted Belongingness - feeling disconnected from others.
     ThwartedBelongingness,
@@ -96,7 +96,8 @@ impl std::fmt::Display for ItsProximalFactor {
 /// | X  |    | X  | Moderate - belongingness + capability |
 /// |    | X  | X  | Moderate - burdensomeness + capability |
 /// | X  | X  | X  | HIGH - three-factor convergence |
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConvergenceStatus {
     /// True if all three factors (TB, PB, AC) are elevated.
     pub is_three_factor_convergent: bool,
@@ -206,7 +207,8 @@ impl Default for ConvergenceStatus {
 /// This struct holds the computed values for all ITS components.
 /// These are derived values - they are computed from the underlying
 /// state dimensions, not stored directly.
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(dead_code)]
 pub struct ItsFactors {
     /// Thwarted Belongingness: (loneliness + (1 - reciprocal_caring)) / 2
@@ -218,6 +220,9 @@ pub struct ItsFactors {
     /// Acquired Capability: habituation to pain/death (never decreases)
     pub acquired_capability: f32,
 
+    /// Interpersonal Hopelessness: belief that TB and PB will not change
+    pub interpersonal_hopelessness: f32,
+
     /// Suicidal Desire: TB * PB when hopelessness threshold met
     pub suicidal_desire: f32,
 
@@ -259,6 +264,7 @@ impl Default for ItsFactors {
             thwarted_belongingness: 0.0,
             perceived_burdensomeness: 0.0,
             acquired_capability: 0.0,
+            interpersonal_hopelessness: 0.0,
             suicidal_desire: 0.0,
             attempt_risk: 0.0,
             passive_ideation_present: false,
@@ -323,9 +329,9 @@ pub fn compute_its_factors_from_components(
     let pb = mental_health.compute_perceived_burdensomeness(social);
     let ac = mental_health.acquired_capability_effective();
 
-    // Check hopelessness threshold for active desire
-    // Note: compute_suicidal_desire handles the hopelessness threshold internally
-    let _hopelessness = mental_health.interpersonal_hopelessness_effective();
+    // Reported alongside the factors; compute_suicidal_desire applies
+    // the hopelessness threshold internally
+    let hopelessness = mental_health.interpersonal_hopelessness_effective();
 
     // Suicidal desire requires TB AND PB AND hopelessness > threshold
     // Using Phase 2's compute_suicidal_desire which handles this logic
@@ -344,6 +350,7 @@ pub fn compute_its_factors_from_components(
         thwarted_belongingness: tb,
         perceived_burdensomeness: pb,
         acquired_capability: ac,
+        interpersonal_hopelessness: hopelessness,
         suicidal_desire: desire,
         attempt_risk: risk,
         passive_ideation_present: passive_ideation,
@@ -586,6 +593,7 @@ mod tests {
             thwarted_belongingness: 0.5,
             perceived_burdensomeness: 0.4,
             acquired_capability: 0.3,
+            interpersonal_hopelessness: 0.6,
             suicidal_desire: 0.2,
             attempt_risk: 0.1,
             passive_ideation_present: true,
@@ -26,9 +26,9 @@
 //! - Acute contributors decay over time (e.g., single rejection event)
 //! - Chronic contributors persist until explicitly resolved (e.g., unemployment state)
 
+use crate::enums::EventType;
 use crate::processor::ItsProximalFactor;
 use crate::types::{Duration, Timestamp};
-use serde::{Deserialize, Serialize};
 
 /// Default decay half-life for acute contributors (7 days).
 pub const ACUTE_CONTRIBUTOR_DECAY_HALF_LIFE: Duration = Duration::days(7);
@@ -40,7 +40,8 @@ pub const CONTRIBUTOR_ACTIVATION_THRESHOLD: f32 = 0.1;
 ///
 /// These represent specific circumstances or events that increase
 /// the sense of social disconnection and unmet belonging needs.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TbContributor {
     /// Social rejection from individuals or groups.
     SocialRejection,
@@ -109,7 +110,8 @@ impl std::fmt::Display for TbContributor {
 ///
 /// These represent specific circumstances or events that increase
 /// the belief of being a burden to others.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PbContributor {
     /// Direct feedback that one is a burden.
     DirectBurdenFeedback,
@@ -187,7 +189,8 @@ impl std::fmt::Display for PbContributor {
 ///
 /// AC is the most stable ITS factor - it accumulates and rarely decreases.
 /// These contributors represent permanent increases in capability.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AcContributor {
     /// Non-suicidal self-injury (NSSI) - highest specificity.
     NonSuicidalSelfInjury,
@@ -284,7 +287,8 @@ impl std::fmt::Display for AcContributor {
 }
 
 /// A unified ITS contributor that can be any of TB, PB, or AC type.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ItsContributor {
     /// Contributor to Thwarted Belongingness.
     Tb(TbContributor),
@@ -324,6 +328,70 @@ impl ItsContributor {
             ItsContributor::Ac(c) => c.is_chronic(),
         }
     }
+
+    /// Returns the contributors an event of this type activates.
+    ///
+    /// Multi-pathway events activate one contributor per pathway (job
+    /// loss displaces a social role and strains finances). Event types
+    /// outside the ITS pathways activate none.
+    #[must_use]
+    pub const fn for_event_type(event_type: EventType) -> &'static [ItsContributor] {
+        use AcContributor as Ac;
+        use PbContributor as Pb;
+        use TbContributor as Tb;
+
+        match event_type {
+            EventType::SocialExclusion | EventType::Rejection => {
+                &[ItsContributor::Tb(Tb::SocialRejection)]
+            }
+            EventType::SocialIsolation => &[ItsContributor::Tb(Tb::Isolation)],
+            EventType::RelationshipEnd | EventType::Bereavement => {
+                &[ItsContributor::Tb(Tb::RelationshipLoss)]
+            }
+            EventType::GroupExclusion => &[ItsContributor::Tb(Tb::GroupExclusion)],
+            EventType::Conflict => &[ItsContributor::Tb(Tb::InterpersonalConflict)],
+            EventType::BurdenFeedback => &[ItsContributor::Pb(Pb::DirectBurdenFeedback)],
+            EventType::ShamingEvent | EventType::Humiliation => &[ItsContributor::Pb(Pb::Shame)],
+            EventType::FinancialBurden => &[ItsContributor::Pb(Pb::FinancialStrain)],
+            EventType::JobLoss => &[
+                ItsContributor::Tb(Tb::RoleDisplacement),
+                ItsContributor::Pb(Pb::FinancialStrain),
+            ],
+            EventType::Failure => &[ItsContributor::Pb(Pb::RoleFailure)],
+            EventType::ChronicIllnessOnset => &[ItsContributor::Pb(Pb::IllnessDependent)],
+            EventType::FamilyDiscord => &[ItsContributor::Pb(Pb::FamilyConflict)],
+            EventType::NonSuicidalSelfInjury => &[ItsContributor::Ac(Ac::NonSuicidalSelfInjury)],
+            EventType::PriorSuicideAttempt => &[ItsContributor::Ac(Ac::PriorSuicideAttempt)],
+            EventType::Violence | EventType::ChildhoodAbuse => {
+                &[ItsContributor::Ac(Ac::PhysicalAbuseExposure)]
+            }
+            EventType::CombatExposure => &[ItsContributor::Ac(Ac::CombatExposure)],
+            EventType::PhysicalInjury => &[ItsContributor::Ac(Ac::PhysicalInjury)],
+            EventType::ViolenceExposure | EventType::TraumaticExposure => {
+                &[ItsContributor::Ac(Ac::ViolenceWitnessing)]
+            }
+            EventType::SuicidalLoss => &[
+                ItsContributor::Tb(Tb::RelationshipLoss),
+                ItsContributor::Ac(Ac::SuicideBereavement),
+            ],
+            EventType::SocialInclusion
+            | EventType::Achievement
+            | EventType::Loss
+            | EventType::Empowerment
+            | EventType::Interaction
+            | EventType::Betrayal
+            | EventType::Support
+            | EventType::PolicyChange
+            | EventType::ContextTransition
+            | EventType::HistoricalEvent
+            | EventType::HealthChange
+            | EventType::SleepDeprivation
+            | EventType::Rest
+            | EventType::Feeding
+            | EventType::ThreatExposure
+            | EventType::Realization => &[],
+        }
+    }
 }
 
 impl std::fmt::Display for ItsContributor {
@@ -335,7 +403,8 @@ impl std::fmt::Display for ItsContributor {
 /// Record of a contributor activation.
 ///
 /// Tracks when a contributor was activated and its current intensity.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ContributorActivation {
     /// The contributor that was activated.
     pub contributor: ItsContributor,
@@ -398,7 +467,8 @@ impl ContributorActivation {
 ///
 /// This provides Layer 2 state tracking - which specific contributors
 /// are currently active and feeding into the proximal factors.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ItsContributors {
     /// All contributor activations.
     activations: Vec<ContributorActivation>,
@@ -1392,6 +1462,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "serde")]
     fn contributors_serialize_deserialize() {
         let mut contributors = ItsContributors::new();
         contributors.activate(
@@ -1406,6 +1477,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "serde")]
     fn contributor_activation_serialize_deserialize() {
         let activation = ContributorActivation::new(
             ItsContributor::Pb(PbContributor::Shame),
@@ -1419,6 +1491,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "serde")]
     fn its_contributor_serialize_deserialize() {
         let contributor = ItsContributor::Ac(AcContributor::CombatExposure);
 
@@ -16,8 +16,10 @@
 //! - [`DecayProcessor`] - Trait for applying decay to entity state
 //! - [`StateDecayProcessor`] - Real implementation with exponential decay
 //! - [`NoOpDecayProcessor`] - No-op implementation for testing/robotic entities
+//! - [`decay_processor_for`] - Picks the processor for a species
 //! - [`InterpretedEvent`] - Interpreted event with computed deltas
 //! - [`ItsFactors`] - Computed ITS risk factors
+//! - [`ItsContributor`] - Life circumstances feeding the ITS factors
 //!
 //! # Internal Functions (crate visibility)
 //!
@@ -25,12 +27,22 @@
 //! - State evolution: `advance_state`, `regress_state`, `apply_interpreted_event_to_state`, `reverse_interpreted_event_from_state`
 //! - Event processing: `interpret_event`
 //! - Developmental: `apply_developmental_effects`
+//! - Alerts: `check_factor_thresholds`, `check_its_thresholds`, `check_spiral_alerts`
 //!
 //! The following functions are internal to their modules and used only in tests:
-//! - Alerts: `check_its_thresholds`, `check_spiral_alerts`
-//! - Event: `apply_interpreted_event`, `process_event`
 //! - State evolution: `apply_event_to_state`
+//!
+//! # Public Surface
+//!
+//! Consumers reach this module only through stable wrappers:
+//! `Entity::interpret_event`, `EmotionIntensities::from_pad`,
+//! `EmotionIntensities::from_state`, and `IndividualState::apply_species_decay`.
+//! The ITS types returned by `ComputedState::its_factors` and
+//! `ComputedState::its_contributors` are re-exported at the crate root.
+//! `apply_interpreted_event`, `process_event`, and the raw emotion functions
+//! are re-exported as deprecated shims in the hidden `unstable` module.
 
+#[cfg(feature = "its")]
 mod alerts;
 mod decay;
 mod developmental;
@@ -42,17 +54,22 @@ mod its_contributors;
 mod reversibility;
 mod state_evolution;
 
-// Alert functions are internal to the alerts module and its tests
-pub use decay::DecayProcessor;
+#[cfg(feature = "its")]
+pub(crate) use alerts::{check_factor_thresholds, check_its_thresholds, check_spiral_alerts};
+pub use decay::{decay_processor_for, DecayProcessor};
 #[allow(unused_imports)]
 pub use decay::{NoOpDecayProcessor, StateDecayProcessor};
 pub(crate) use developmental::apply_developmental_effects;
 #[allow(unused_imports)]
 pub use emotions::{derive_emotion, get_derived_emotion, EmotionIntensities};
-pub(crate) use event::interpret_event;
-pub(crate) use event::process_event_to_relationships;
+pub(crate) use event::{
+    amplify_bereavement, amplify_fall, amplify_when_tired, apply_interpreted_event,
+    attribute_event, impact, interpret_event, interpret_event_as, process_event,
+};
+#[cfg(feature = "relationships")]
+pub(crate) use event::{process_event_to_relationships, retract_event_from_relationships};
 pub use event::InterpretedEvent;
-// apply_interpreted_event and process_event are internal to the event module and its tests
+pub(crate) use feedback::active_spirals;
 #[allow(unused_imports)]
 pub use feedback::{
     apply_depression_spiral, apply_stress_spiral, DEPRESSION_SPIRAL_RATE,
@@ -72,7 +89,11 @@ pub use reversibility::{
     REVERSAL_EPSILON,
 };
 pub(crate) use state_evolution::{
-    advance_state, apply_interpreted_event_to_state, regress_state,
-    reverse_interpreted_event_from_state,
+    advance_state, apply_anticipation_to_state, apply_interpreted_event_to_state, regress_state,
+    reverse_anticipation_from_state, reverse_interpreted_event_from_state,
 };
+#[cfg(any(test, feature = "contract-tests"))]
+pub(crate) use state_evolution::{apply_event_delta, is_reversible_event_path, EVENT_DELTA_PATHS};
+#[cfg(any(feature = "context", feature = "memory"))]
+pub(crate) use state_evolution::apply_span_hooks;
 // apply_event_to_state is internal to the state_evolution module and its tests
@@ -7,7 +7,11 @@
 
 #[cfg(test)]
 use crate::event::Event;
-use crate::enums::SocialCognitionPath;
+use crate::enums::{
+    DispositionPath, EventTag, MentalHealthPath, MoodPath, NeedsPath, SocialCognitionPath,
+    StatePath,
+};
+use crate::processor::DecayProcessor;
 use crate::state::{IndividualState, SocialCognition};
 use crate::types::Duration;
 
@@ -19,7 +23,8 @@ use crate::types::Duration;
 /// # Arguments
 ///
 /// * `state` - The starting state
-/// * `duration` - The time to advance
+/// * `duration` - The psychological time to advance
+/// * `processor` - The entity's decay processor, from `decay_processor_for()`
 ///
 /// # Returns
 ///
@@ -28,22 +33,26 @@ use crate::types::Duration;
 /// # Examples
 ///
 /// ```ignore
-/// use behavioral_pathways::processor::advance_state;
+/// use behavioral_pathways::processor::{advance_state, StateDecayProcessor};
 /// use behavioral_pathways::state::IndividualState;
 /// use behavioral_pathways::types::Duration;
 ///
 /// let mut initial = IndividualState::new();
 /// initial.mood_mut().add_valence_delta(0.5);
 ///
-/// let advanced = advance_state(initial, Duration::hours(6));
+/// let advanced = advance_state(initial, Duration::hours(6), &StateDecayProcessor);
 ///
 /// // Delta should have decayed (6-hour half-life for mood)
 /// assert!(advanced.mood().valence_delta() < 0.5);
 /// ```
 #[must_use]
-pub(crate) fn advance_state(state: IndividualState, duration: Duration) -> IndividualState {
+pub(crate) fn advance_state(
+    state: IndividualState,
+    duration: Duration,
+    processor: &dyn DecayProcessor,
+) -> IndividualState {
     let mut new_state = state;
-    new_state.apply_decay(duration);
+    processor.apply_decay(&mut new_state, duration, 1.0);
     new_state
 }
 
@@ -58,7 +67,8 @@ pub(crate) fn advance_state(state: IndividualState, duration: Duration) -> Indiv
 /// # Arguments
 ///
 /// * `state` - The current state
-/// * `duration` - The time to regress
+/// * `duration` - The psychological time to regress
+/// * `processor` - The entity's decay processor, from `decay_processor_for()`
 ///
 /// # Returns
 ///
@@ -67,24 +77,57 @@ pub(crate) fn advance_state(state: IndividualState, duration: Duration) -> Indiv
 /// # Examples
 ///
 /// ```ignore
-/// use behavioral_pathways::processor::regress_state;
+/// use behavioral_pathways::processor::{regress_state, StateDecayProcessor};
 /// use behavioral_pathways::state::IndividualState;
 /// use behavioral_pathways::types::Duration;
 ///
 /// let initial = IndividualState::new();
-/// let regressed = regress_state(initial, Duration::hours(6));
+/// let regressed = regress_state(initial, Duration::hours(6), &StateDecayProcessor);
 /// ```
 #[must_use]
-pub(crate) fn regress_state(state: IndividualState, duration: Duration) -> IndividualState {
+pub(crate) fn regress_state(
+    state: IndividualState,
+    duration: Duration,
+    processor: &dyn DecayProcessor,
+) -> IndividualState {
     let mut new_state = state;
 
     // Reverse decay on all decayable dimensions
     // Note: This is an approximation - we reverse by applying the inverse of decay
-    reverse_decay_on_state(&mut new_state, duration);
+    processor.reverse_decay(&mut new_state, duration, 1.0);
 
     new_state
 }
 
+/// Applies span hooks, such as context effects, to a projected state.
+///
+/// Hooks add deltas in proportion to the span. Each decaying value's
+/// addition is moved into its base, where it persists without decay, so a
+/// projection re-anchored partway does not decay it a second time and
+/// composes with the rest of the span. Backward (`forward` false), the
+/// additions are removed instead.
+#[cfg(any(feature = "context", feature = "memory"))]
+#[must_use]
+pub(crate) fn apply_span_hooks(
+    state: IndividualState,
+    forward: bool,
+    hooks: impl FnOnce(IndividualState) -> IndividualState,
+) -> IndividualState {
+    let mut before = state.clone();
+    let mut after = hooks(state);
+    let decaying = after.decaying_values_mut();
+    for (value, prior) in decaying.into_iter().zip(before.decaying_values_mut()) {
+        let added = value.delta() - prior.delta();
+        if added.abs() < f32::EPSILON {
+            continue;
+        }
+        value.add_delta(-added);
+        let shift = if forward { added } else { -added };
+        value.set_base(value.base() + shift);
+    }
+    after
+}
+
 fn apply_social_cognition_delta(
     social: &mut SocialCognition,
     path: SocialCognitionPath,
@@ -134,124 +177,90 @@ fn apply_social_cognition_delta(
     }
 }
 
-/// Applies decay reversal to all reversible dimensions of a state.
-fn reverse_decay_on_state(state: &mut IndividualState, duration: Duration) {
-    // Mood dimensions have 6-hour half-life
-    reverse_dimension_decay(state.mood_mut().valence_mut(), duration, Duration::hours(6));
-    reverse_dimension_decay(state.mood_mut().arousal_mut(), duration, Duration::hours(6));
-    reverse_dimension_decay(
-        state.mood_mut().dominance_mut(),
-        duration,
-        Duration::hours(6),
-    );
-
-    // Social cognition dimensions have various half-lives
-    reverse_dimension_decay(
-        state.social_cognition_mut().loneliness_mut(),
-        duration,
-        Duration::days(1),
-    );
-    reverse_dimension_decay(
-        state.social_cognition_mut()
-            .perceived_reciprocal_caring_mut(),
-        duration,
-        Duration::days(2),
-    );
-    reverse_dimension_decay(
-        state.social_cognition_mut().perceived_liability_mut(),
-        duration,
-        Duration::days(3),
-    );
-    reverse_dimension_decay(
-        state.social_cognition_mut().self_hate_mut(),
-        duration,
-        Duration::days(3),
-    );
-    reverse_dimension_decay(
-        state.needs_mut().stress_mut(),
-        duration,
-        Duration::hours(12),
-    );
-    reverse_dimension_decay(
-        state.needs_mut().fatigue_mut(),
-        duration,
-        Duration::hours(8),
-    );
-    reverse_dimension_decay(state.needs_mut().purpose_mut(), duration, Duration::days(3));
-
-    // Mental health dimensions (except AC which has no decay)
-    reverse_dimension_decay(
-        state.mental_health_mut().depression_mut(),
-        duration,
-        Duration::weeks(2),
-    );
-    reverse_dimension_decay(
-        state.mental_health_mut().interpersonal_hopelessness_mut(),
-        duration,
-        Duration::weeks(2),
-    );
-    // Acquired Capability has no decay - cannot be reversed
-
-    // Disposition dimensions
-    reverse_dimension_decay(
-        state.disposition_mut().empathy_mut(),
-        duration,
-        Duration::weeks(4),
-    );
-    reverse_dimension_decay(
-        state.disposition_mut().aggression_mut(),
-        duration,
-        Duration::weeks(1),
-    );
-    reverse_dimension_decay(
-        state.disposition_mut().grievance_mut(),
-        duration,
-        Duration::weeks(1),
-    );
-
-    // Person characteristics - social capital
-    reverse_dimension_decay(
-        state.person_characteristics_mut().social_capital_mut(),
-        duration,
-        Duration::weeks(4),
-    );
+/// Every state path `interpret_event` can emit a delta for, and whether
+/// backward regression undoes it.
+///
+/// `apply_event_delta` handles exactly these paths, so applying and
+/// reversing an interpreted event touch the same dimensions. Acquired
+/// Capability is permanent per ITS theory and is never reversed.
+pub(crate) const EVENT_DELTA_PATHS: [(StatePath, bool); 15] = [
+    (StatePath::Mood(MoodPath::Valence), true),
+    (StatePath::Mood(MoodPath::Arousal), true),
+    (StatePath::Mood(MoodPath::Dominance), true),
+    (StatePath::SocialCognition(SocialCognitionPath::Loneliness), true),
+    (
+        StatePath::SocialCognition(SocialCognitionPath::PerceivedReciprocalCaring),
+        true,
+    ),
+    (
+        StatePath::SocialCognition(SocialCognitionPath::PerceivedLiability),
+        true,
+    ),
+    (StatePath::SocialCognition(SocialCognitionPath::SelfHate), true),
+    (StatePath::Needs(NeedsPath::Purpose), true),
+    (StatePath::Needs(NeedsPath::Fatigue), true),
+    (StatePath::Needs(NeedsPath::Satiation), true),
+    (StatePath::Needs(NeedsPath::Safety), true),
+    (
+        StatePath::MentalHealth(MentalHealthPath::AcquiredCapability),
+        false,
+    ),
+    (
+        StatePath::MentalHealth(MentalHealthPath::InterpersonalHopelessness),
+        true,
+    ),
+    (StatePath::MentalHealth(MentalHealthPath::SelfWorth), true),
+    (StatePath::Disposition(DispositionPath::Grievance), true),
+];
+
+/// Returns true if backward regression undoes an event's delta to `path`.
+#[must_use]
+pub(crate) fn is_reversible_event_path(path: StatePath) -> bool {
+    EVENT_DELTA_PATHS
+        .iter()
+        .any(|(event_path, reversible)| *event_path == path && *reversible)
 }
 
-/// Reverses decay on a single state value.
-fn reverse_dimension_decay(
-    state_value: &mut crate::state::StateValue,
-    duration: Duration,
-    half_life: Duration,
-) {
-    let current_delta = state_value.delta();
-
-    // Skip if delta is effectively zero
-    if current_delta.abs() < f32::EPSILON {
-        return;
-    }
-
-    // Compute reversal factor: exp(ln(2) * t / half_life)
-    let elapsed_ms = duration.as_millis() as f64;
-    let half_life_ms = half_life.as_millis() as f64;
-
-    if half_life_ms <= 0.0 {
-        return;
-    }
-
-    let ln2 = std::f64::consts::LN_2;
-    let exponent = ln2 * elapsed_ms / half_life_ms;
-
-    // Guard against overflow
-    if exponent > 700.0 {
-        return;
+/// Adds an interpreted event's delta for `path` to `state`.
+///
+/// Social cognition deltas from chronic-pattern events decay slowly.
+/// Returns false, leaving the state unchanged, for a path outside
+/// `EVENT_DELTA_PATHS`.
+pub(crate) fn apply_event_delta(
+    state: &mut IndividualState,
+    path: StatePath,
+    delta: f32,
+    chronic: bool,
+) -> bool {
+    match path {
+        StatePath::Mood(MoodPath::Valence) => state.mood_mut().add_valence_delta(delta),
+        StatePath::Mood(MoodPath::Arousal) => state.mood_mut().add_arousal_delta(delta),
+        StatePath::Mood(MoodPath::Dominance) => state.mood_mut().add_dominance_delta(delta),
+        StatePath::SocialCognition(
+            path @ (SocialCognitionPath::Loneliness
+            | SocialCognitionPath::PerceivedReciprocalCaring
+            | SocialCognitionPath::PerceivedLiability
+            | SocialCognitionPath::SelfHate),
+        ) => apply_social_cognition_delta(state.social_cognition_mut(), path, delta, chronic),
+        StatePath::Needs(NeedsPath::Purpose) => state.needs_mut().add_purpose_delta(delta),
+        StatePath::Needs(NeedsPath::Fatigue) => state.needs_mut().add_fatigue_delta(delta),
+        StatePath::Needs(NeedsPath::Satiation) => state.needs_mut().add_satiation_delta(delta),
+        StatePath::Needs(NeedsPath::Safety) => state.needs_mut().add_safety_delta(delta),
+        StatePath::MentalHealth(MentalHealthPath::AcquiredCapability) => state
+            .mental_health_mut()
+            .add_acquired_capability_delta(delta),
+        StatePath::MentalHealth(MentalHealthPath::InterpersonalHopelessness) => state
+            .mental_health_mut()
+            .add_interpersonal_hopelessness_delta(delta),
+        StatePath::MentalHealth(MentalHealthPath::SelfWorth) => {
+            state.mental_health_mut().add_self_worth_delta(delta)
+        }
+        StatePath::Disposition(DispositionPath::Grievance) => {
+            state.disposition_mut().add_grievance_delta(delta)
+        }
+        _ => return false,
     }
-
-    let reversal_factor = exponent.exp();
-    let original_delta = (current_delta as f64) * reversal_factor;
-
-    // Clamp to reasonable range to avoid numerical issues
-    let clamped = original_delta.clamp(-100.0, 100.0) as f32;
-    state_value.set_delta(clamped);
+    true
 }
 
 /// Applies an event's effects to state, returning a new state.
@@ -398,7 +407,9 @@ fn apply_protective_factors(state: &mut IndividualState, event: &Event, severity
 
     if event_type == EventType::Achievement {
         match event.payload() {
-            EventPayload::Achievement { domain, magnitude } => {
+            EventPayload::Achievement {
+                domain, magnitude, ..
+            } => {
                 let magnitude = *magnitude as f32;
                 let productivity = severity * magnitude;
 
@@ -474,10 +485,12 @@ fn apply_protective_factors(state: &mut IndividualState, event: &Event, severity
             EventPayload::Support {
                 support_type: SupportType::Emotional,
                 effectiveness,
+                ..
             }
             | EventPayload::Support {
                 support_type: SupportType::Companionship,
                 effectiveness,
+                ..
             } => {
                 let eff = *effectiveness as f32;
                 apply_social_cognition_delta(
@@ -546,49 +559,12 @@ pub(crate) fn apply_interpreted_event_to_state(
     state: IndividualState,
     interpreted: &crate::processor::InterpretedEvent,
 ) -> IndividualState {
-    use crate::enums::{EventTag, MentalHealthPath, MoodPath, NeedsPath, StatePath};
-
     let mut new_state = state;
     let chronic = interpreted.event.has_tag(EventTag::ChronicPattern);
 
     // Apply each delta from the interpreted event
     for (path, delta) in &interpreted.state_deltas {
-        let delta_f32 = *delta as f32;
-
-        match path {
-            StatePath::Mood(MoodPath::Valence) => {
-                new_state.mood_mut().add_valence_delta(delta_f32);
-            }
-            StatePath::Mood(MoodPath::Arousal) => {
-                new_state.mood_mut().add_arousal_delta(delta_f32);
-            }
-            StatePath::Mood(MoodPath::Dominance) => {
-                new_state.mood_mut().add_dominance_delta(delta_f32);
-            }
-            StatePath::SocialCognition(path) => {
-                apply_social_cognition_delta(
-                    new_state.social_cognition_mut(),
-                    *path,
-                    delta_f32,
-                    chronic,
-                );
-            }
-            StatePath::Needs(NeedsPath::Purpose) => {
-                new_state.needs_mut().add_purpose_delta(delta_f32);
-            }
-            StatePath::MentalHealth(MentalHealthPath::AcquiredCapability) => {
-                new_state
-                    .mental_health_mut()
-                    .add_acquired_capability_delta(delta_f32);
-            }
-            StatePath::MentalHealth(MentalHealthPath::InterpersonalHopelessness) => {
-                new_state
-                    .mental_health_mut()
-                    .add_interpersonal_hopelessness_delta(delta_f32);
-            }
-            // Other paths are not typically in interpreted events
-            _ => {}
-        }
+        apply_event_delta(&mut new_state, *path, *delta as f32, chronic);
     }
 
     if interpreted.event.has_tag(EventTag::MoralViolation) {
@@ -604,7 +580,8 @@ pub(crate) fn apply_interpreted_event_to_state(
 /// the event's effects. This is more accurate than `reverse_event_from_state`
 /// because it uses the personality-modulated deltas that were actually applied.
 ///
-/// Note: Acquired Capability increases are NOT reversed (permanent per ITS theory).
+/// Every path in `EVENT_DELTA_PATHS` is reversed except Acquired Capability,
+/// whose increases are permanent per ITS theory.
 ///
 /// # Arguments
 ///
@@ -619,53 +596,78 @@ pub(crate) fn reverse_interpreted_event_from_state(
     state: IndividualState,
     interpreted: &crate::processor::InterpretedEvent,
 ) -> IndividualState {
-    use crate::enums::{EventTag, MentalHealthPath, MoodPath, NeedsPath, StatePath};
-
     let mut new_state = state;
     let chronic = interpreted.event.has_tag(EventTag::ChronicPattern);
 
-    // Reverse each delta from the interpreted event
+    // Reverse each delta from the interpreted event. AC is permanent per
+    // ITS theory, so its delta is skipped intentionally
     for (path, delta) in &interpreted.state_deltas {
-        let neg_delta = -(*delta as f32);
-
-        match path {
-            StatePath::Mood(MoodPath::Valence) => {
-                new_state.mood_mut().add_valence_delta(neg_delta);
-            }
-            StatePath::Mood(MoodPath::Arousal) => {
-                new_state.mood_mut().add_arousal_delta(neg_delta);
-            }
-            StatePath::Mood(MoodPath::Dominance) => {
-                new_state.mood_mut().add_dominance_delta(neg_delta);
-            }
-            StatePath::SocialCognition(path) => {
-                apply_social_cognition_delta(
-                    new_state.social_cognition_mut(),
-                    *path,
-                    neg_delta,
-                    chronic,
-                );
-            }
-            StatePath::Needs(NeedsPath::Purpose) => {
-                new_state.needs_mut().add_purpose_delta(neg_delta);
-            }
-            StatePath::MentalHealth(MentalHealthPath::AcquiredCapability) => {
-                // AC is NOT reversed - it's permanent per ITS theory
-                // Skip this delta intentionally
-            }
-            StatePath::MentalHealth(MentalHealthPath::InterpersonalHopelessness) => {
-                new_state
-                    .mental_health_mut()
-                    .add_interpersonal_hopelessness_delta(neg_delta);
-            }
-            // Other paths are not typically in interpreted events
-            _ => {}
+        if is_reversible_event_path(*path) {
+            apply_event_delta(&mut new_state, *path, -(*delta as f32), chronic);
         }
     }
 
     new_state
 }
 
+/// Share of an anticipated event's emotional intensity that builds
+/// across its anticipation window.
+pub(crate) const ANTICIPATION_SCALE: f32 = 0.5;
+
+/// Returns the arousal and stress deltas an anticipated event delivers
+/// across its whole anticipation window, scaled by `factor`.
+///
+/// Anticipation raises arousal by `ANTICIPATION_SCALE` of the event's
+/// interpreted intensity, the larger of its valence and arousal
+/// magnitudes, since waiting for either good or bad news is activating.
+/// Unpleasant events (negative valence) add the same magnitude of stress;
+/// looking forward to a pleasant event does not.
+fn anticipatory_deltas(
+    interpreted: &crate::processor::InterpretedEvent,
+    factor: f64,
+) -> (f32, f32) {
+    let intensity = interpreted
+        .valence_delta
+        .abs()
+        .max(interpreted.arousal_delta.abs());
+    let arousal = intensity * ANTICIPATION_SCALE * factor as f32;
+    let stress = if interpreted.valence_delta < 0.0 {
+        arousal
+    } else {
+        0.0
+    };
+    (arousal, stress)
+}
+
+/// Applies the share `factor` of an anticipated event's anticipatory
+/// arousal and stress to state.
+#[must_use]
+pub(crate) fn apply_anticipation_to_state(
+    state: IndividualState,
+    interpreted: &crate::processor::InterpretedEvent,
+    factor: f64,
+) -> IndividualState {
+    let mut new_state = state;
+    let (arousal, stress) = anticipatory_deltas(interpreted, factor);
+    new_state.mood_mut().add_arousal_delta(arousal);
+    new_state.needs_mut().add_stress_delta(stress);
+    new_state
+}
+
+/// Reverses what `apply_anticipation_to_state` applied for the same share.
+#[must_use]
+pub(crate) fn reverse_anticipation_from_state(
+    state: IndividualState,
+    interpreted: &crate::processor::InterpretedEvent,
+    factor: f64,
+) -> IndividualState {
+    let mut new_state = state;
+    let (arousal, stress) = anticipatory_deltas(interpreted, factor);
+    new_state.mood_mut().add_arousal_delta(-arousal);
+    new_state.needs_mut().add_stress_delta(-stress);
+    new_state
+}
+
 /// Reverses an event's effects from state, returning a new state.
 ///
 /// This is the inverse of `apply_event_to_state`. It subtracts the
@@ -791,16 +793,146 @@ fn reverse_event_from_state(state: IndividualState, event: &Event) -> Individual
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::enums::{EventPayload, EventTag, EventType, LifeDomain, RealizationType, SupportType};
+    use crate::enums::{
+        Audience, EventPayload, EventTag, EventType, LifeDomain, RealizationType, SupportType,
+    };
     use crate::event::EventBuilder;
+    use crate::processor::StateDecayProcessor;
     use crate::types::GroupId;
 
+    /// Returns events of every type, plus payloads and tags that add
+    /// purpose, self-worth, and chronic social cognition deltas.
+    fn events_of_every_kind() -> Vec<Event> {
+        let mut events: Vec<Event> = EventType::all()
+            .into_iter()
+            .map(|event_type| EventBuilder::new(event_type).severity(0.7).build().unwrap())
+            .collect();
+        let payloads = [
+            (
+                EventType::Support,
+                EventPayload::Support {
+                    support_type: SupportType::Emotional,
+                    effectiveness: 0.9,
+                    audience: Audience::Public,
+                },
+            ),
+            (
+                EventType::Realization,
+                EventPayload::Realization {
+                    realization_type: RealizationType::ExistentialInsight,
+                },
+            ),
+            (
+                EventType::Achievement,
+                EventPayload::Achievement {
+                    domain: LifeDomain::Work,
+                    magnitude: 0.8,
+                    audience: Audience::Private,
+                },
+            ),
+        ];
+        for (event_type, payload) in payloads {
+            events.push(
+                EventBuilder::new(event_type)
+                    .severity(0.7)
+                    .payload(payload)
+                    .build()
+                    .unwrap(),
+            );
+        }
+        events.push(
+            EventBuilder::new(EventType::SocialExclusion)
+                .severity(0.7)
+                .tag(EventTag::ChronicPattern)
+                .build()
+                .unwrap(),
+        );
+        events
+    }
+
+    #[test]
+    fn event_delta_paths_cover_every_interpreted_path() {
+        use crate::entity::EntityBuilder;
+        use crate::processor::interpret_event;
+
+        let entity = EntityBuilder::new()
+            .species(crate::enums::Species::Human)
+            .build()
+            .unwrap();
+        for event in events_of_every_kind() {
+            let interpreted = interpret_event(&event, &entity);
+            for (path, _) in &interpreted.state_deltas {
+                assert!(
+                    EVENT_DELTA_PATHS.iter().any(|(known, _)| known == path),
+                    "{:?} emits {path:?}",
+                    event.event_type()
+                );
+            }
+        }
+
+        for (path, _) in EVENT_DELTA_PATHS {
+            let mut state = IndividualState::new();
+            assert!(apply_event_delta(&mut state, path, 0.1, false), "{path:?}");
+        }
+        let mut state = IndividualState::new();
+        assert!(!apply_event_delta(
+            &mut state,
+            StatePath::Needs(NeedsPath::Stress),
+            0.1,
+            false
+        ));
+    }
+
+    #[test]
+    fn apply_then_reverse_restores_every_reversible_path() {
+        use crate::entity::EntityBuilder;
+        use crate::processor::interpret_event;
+
+        let entity = EntityBuilder::new()
+            .species(crate::enums::Species::Human)
+            .build()
+            .unwrap();
+        let mut self_worth_moved = false;
+        let mut grievance_moved = false;
+        for event in events_of_every_kind() {
+            let interpreted = interpret_event(&event, &entity);
+            let applied =
+                apply_interpreted_event_to_state(entity.individual_state().clone(), &interpreted);
+            for (path, moved) in [
+                (StatePath::MentalHealth(MentalHealthPath::SelfWorth), &mut self_worth_moved),
+                (StatePath::Disposition(DispositionPath::Grievance), &mut grievance_moved),
+            ] {
+                let mut probe = entity.clone();
+                *probe.individual_state_mut() = applied.clone();
+                *moved |= probe.get_effective(path) != entity.get_effective(path);
+            }
+            let reversed = reverse_interpreted_event_from_state(applied, &interpreted);
+
+            let mut restored = entity.clone();
+            *restored.individual_state_mut() = reversed;
+            for path in StatePath::all() {
+                if path == StatePath::MentalHealth(MentalHealthPath::AcquiredCapability) {
+                    continue;
+                }
+                let before = entity.get_effective(path).unwrap_or(0.0);
+                let after = restored.get_effective(path).unwrap_or(0.0);
+                assert!(
+                    (before - after).abs() < 1e-5,
+                    "{:?} left {path:?} at {after}, was {before}",
+                    event.event_type()
+                );
+            }
+        }
+        assert!(self_worth_moved);
+        assert!(grievance_moved);
+    }
+
     #[test]
     fn advance_state_applies_decay() {
         let mut state = IndividualState::new();
         state.mood_mut().add_valence_delta(0.8);
 
-        let advanced = advance_state(state, Duration::weeks(1));
+        let advanced = advance_state(state, Duration::weeks(1), &StateDecayProcessor);
 
         // After 1 week with 6-hour half-life, delta should be nearly zero
         assert!(advanced.mood().valence_delta() < 0.01);
@@ -811,7 +943,7 @@ mod tests {
         let mut state = IndividualState::new();
         state.mood_mut().add_valence_delta(0.5);
 
-        let advanced = advance_state(state, Duration::zero());
+        let advanced = advance_state(state, Duration::zero(), &StateDecayProcessor);
 
         assert!((advanced.mood().valence_delta() - 0.5).abs() < f32::EPSILON);
     }
@@ -822,7 +954,7 @@ mod tests {
         // Set a small delta that would result from decay
         state.mood_mut().add_valence_delta(0.25); // Half of 0.5 after one half-life
 
-        let regressed = regress_state(state, Duration::hours(6));
+        let regressed = regress_state(state, Duration::hours(6), &StateDecayProcessor);
 
         // After reversing 6 hours (one half-life), delta should approximately double
         assert!(regressed.mood().valence_delta() > 0.4);
@@ -833,11 +965,29 @@ mod tests {
         let mut state = IndividualState::new();
         state.mood_mut().add_valence_delta(0.5);
 
-        let regressed = regress_state(state, Duration::zero());
+        let regressed = regress_state(state, Duration::zero(), &StateDecayProcessor);
 
         assert!((regressed.mood().valence_delta() - 0.5).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn decay_profile_round_trips_with_custom_half_life() {
+        use crate::state::{DecayProfile, EntityModelConfig};
+
+        let path = StatePath::SocialCognition(SocialCognitionPath::Loneliness);
+        let profile = DecayProfile::new().with_half_life(path, Duration::hours(5));
+        let mut state = IndividualState::new()
+            .with_config(EntityModelConfig::default().with_decay_profile(profile));
+        state.social_cognition_mut().add_loneliness_delta(0.6);
+
+        let advanced = advance_state(state, Duration::hours(5), &StateDecayProcessor);
+        // Halved at the overridden 5-hour half-life, not the 1-day default
+        assert!((advanced.social_cognition().loneliness().delta() - 0.3).abs() < 1e-4);
+
+        let regressed = regress_state(advanced, Duration::hours(5), &StateDecayProcessor);
+        assert!((regressed.social_cognition().loneliness().delta() - 0.6).abs() < 1e-4);
+    }
+
     #[test]
     fn apply_event_to_state_social_exclusion() {
         let state = IndividualState::new();
@@ -902,6 +1052,7 @@ mod tests {
             .payload(EventPayload::Achievement {
                 domain: LifeDomain::Work,
                 magnitude: 0.9,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -946,6 +1097,7 @@ mod tests {
             .payload(EventPayload::Support {
                 support_type: SupportType::Emotional,
                 effectiveness: 0.9,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -1058,6 +1210,7 @@ mod tests {
             .payload(EventPayload::Achievement {
                 domain: LifeDomain::Work,
                 magnitude: 0.7,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -1102,6 +1255,7 @@ mod tests {
             .payload(EventPayload::Support {
                 support_type: SupportType::Emotional,
                 effectiveness: 0.8,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -1212,7 +1366,7 @@ mod tests {
         state.mood_mut().add_valence_delta(0.5);
 
         let original_delta = state.mood().valence_delta();
-        let _ = advance_state(state.clone(), Duration::hours(6));
+        let _ = advance_state(state.clone(), Duration::hours(6), &StateDecayProcessor);
 
         // Original should be unchanged (it was cloned)
         assert!((state.mood().valence_delta() - original_delta).abs() < f32::EPSILON);
@@ -1224,7 +1378,7 @@ mod tests {
         state.mood_mut().add_valence_delta(0.5);
 
         let original_delta = state.mood().valence_delta();
-        let _ = regress_state(state.clone(), Duration::hours(6));
+        let _ = regress_state(state.clone(), Duration::hours(6), &StateDecayProcessor);
 
         // Original should be unchanged (it was cloned)
         assert!((state.mood().valence_delta() - original_delta).abs() < f32::EPSILON);
@@ -1270,7 +1424,7 @@ mod tests {
         let state = IndividualState::new();
         // All deltas are zero
 
-        let regressed = regress_state(state, Duration::hours(6));
+        let regressed = regress_state(state, Duration::hours(6), &StateDecayProcessor);
 
         // Should still be zero
         assert!(regressed.mood().valence_delta().abs() < f32::EPSILON);
@@ -1418,9 +1572,15 @@ mod tests {
         event: crate::event::Event,
         state_deltas: Vec<(crate::enums::StatePath, f64)>,
     ) -> crate::processor::InterpretedEvent {
+        let entity = crate::entity::EntityBuilder::new()
+            .species(crate::enums::Species::Human)
+            .build()
+            .unwrap();
+        let severity_appraisal = crate::event::appraise_severity(&event, &entity);
         crate::processor::InterpretedEvent {
             event,
             original_event: crate::types::EventId::new("test_event").unwrap(),
+            role: crate::enums::InterpretationRole::Target,
             attribution: crate::enums::Attribution::Unknown,
             valence_delta: 0.0,
             arousal_delta: 0.0,
@@ -1432,9 +1592,13 @@ mod tests {
             acquired_capability_delta: 0.0,
             interpersonal_hopelessness_delta: 0.0,
             salience: 0.5,
+            severity_appraisal,
             perceived_severity: 0.5,
             memory_salience: 0.5,
             state_deltas,
+            contradicted_recognition: None,
+            preceding_sleep_loss: None,
+            lost_relationship: None,
         }
     }
 
@@ -1834,45 +1998,6 @@ mod tests {
         );
     }
 
-    #[test]
-    fn reverse_dimension_decay_handles_zero_half_life() {
-        // Test the half_life <= 0 early return (line 198)
-        // When half_life is zero, the function should return early without modification.
-        use crate::state::StateValue;
-
-        let mut state_value = StateValue::new(0.5).with_delta(0.3);
-        let original_delta = state_value.delta();
-        let duration = Duration::days(1);
-        let half_life = Duration::zero(); // Zero half-life triggers early return
-
-        reverse_dimension_decay(&mut state_value, duration, half_life);
-
-        // Delta should be unchanged because function returned early
-        assert!((state_value.delta() - original_delta).abs() < 0.001);
-    }
-
-    #[test]
-    fn reverse_dimension_decay_handles_extremely_long_duration() {
-        // Test the exponent > 700 early return (line 206)
-        // This guards against exp() overflow with very long durations.
-        // exponent = ln(2) * elapsed_ms / half_life_ms
-        // For exponent > 700, we need elapsed_ms / half_life_ms > 1010
-        // With 1 day half-life, 1010+ days elapsed triggers this.
-        use crate::state::StateValue;
-
-        let mut state_value = StateValue::new(0.5).with_delta(0.3);
-        let original_delta = state_value.delta();
-        let duration = Duration::years(10); // Very long duration
-        let half_life = Duration::days(1); // 1 day half-life
-
-        // exponent = ln(2) * (10*365*24*60*60*1000) / (24*60*60*1000)
-        // exponent = ln(2) * 3650 = 2530 which is > 700
-        reverse_dimension_decay(&mut state_value, duration, half_life);
-
-        // Delta should be unchanged because function returned early due to overflow guard
-        assert!((state_value.delta() - original_delta).abs() < 0.001);
-    }
-
     #[test]
     fn apply_social_cognition_delta_loneliness_acute() {
         let mut state = IndividualState::new();
@@ -2042,6 +2167,7 @@ mod tests {
             .payload(EventPayload::Achievement {
                 domain: LifeDomain::Academic,
                 magnitude: 0.8,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -2060,6 +2186,7 @@ mod tests {
             .payload(EventPayload::Achievement {
                 domain: LifeDomain::Financial,
                 magnitude: 0.85,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -2078,6 +2205,7 @@ mod tests {
             .payload(EventPayload::Achievement {
                 domain: LifeDomain::Creative,
                 magnitude: 0.75,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -2096,6 +2224,7 @@ mod tests {
             .payload(EventPayload::Achievement {
                 domain: LifeDomain::Social,
                 magnitude: 0.7,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -2144,6 +2273,7 @@ mod tests {
             .payload(EventPayload::Support {
                 support_type: SupportType::Companionship,
                 effectiveness: 0.8,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -2162,6 +2292,7 @@ mod tests {
             .payload(EventPayload::Support {
                 support_type: SupportType::Instrumental,
                 effectiveness: 0.8,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -2180,6 +2311,7 @@ mod tests {
             .payload(EventPayload::Support {
                 support_type: SupportType::Informational,
                 effectiveness: 0.8,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -2302,37 +2434,6 @@ mod tests {
         assert!(reversed.needs().purpose().delta().abs() < 0.001);
     }
 
-    #[test]
-    fn reverse_dimension_decay_with_negative_delta() {
-        use crate::state::StateValue;
-
-        let mut state_value = StateValue::new(0.5).with_delta(-0.3);
-        let original_delta = state_value.delta();
-        let duration = Duration::hours(6);
-        let half_life = Duration::hours(6);
-
-        reverse_dimension_decay(&mut state_value, duration, half_life);
-
-        // Negative delta should double in magnitude (reversal)
-        assert!(state_value.delta() < original_delta);
-        assert!(state_value.delta().abs() > original_delta.abs() * 0.9);
-    }
-
-    #[test]
-    fn reverse_dimension_decay_with_extremely_small_delta() {
-        use crate::state::StateValue;
-
-        let mut state_value = StateValue::new(0.5).with_delta(1e-10);
-        let original_delta = state_value.delta();
-        let duration = Duration::hours(6);
-        let half_life = Duration::hours(6);
-
-        reverse_dimension_decay(&mut state_value, duration, half_life);
-
-        // Should skip due to epsilon check
-        assert!((state_value.delta() - original_delta).abs() < 1e-15);
-    }
-
     #[test]
     fn apply_event_chronic_pattern_affects_pb_slower_decay() {
         let state = IndividualState::new();
@@ -2497,21 +2598,6 @@ mod tests {
         assert!(loneliness_delta < -0.2);
     }
 
-    #[test]
-    fn reverse_dimension_decay_clamping_large_reversal() {
-        use crate::state::StateValue;
-
-        let mut state_value = StateValue::new(0.5).with_delta(10.0);
-        let duration = Duration::years(1);
-        let half_life = Duration::hours(6);
-
-        reverse_dimension_decay(&mut state_value, duration, half_life);
-
-        // Should be clamped to 100.0 due to clamp(-100.0, 100.0)
-        assert!(state_value.delta() <= 100.0);
-        assert!(state_value.delta() >= -100.0);
-    }
-
     #[test]
     fn apply_interpreted_event_moral_violation_flag_set() {
         let state = IndividualState::new();
@@ -2540,6 +2626,7 @@ mod tests {
             .payload(EventPayload::Support {
                 support_type: SupportType::Emotional,
                 effectiveness: 1.0,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -2549,6 +2636,7 @@ mod tests {
             .payload(EventPayload::Support {
                 support_type: SupportType::Emotional,
                 effectiveness: 0.3,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -2588,54 +2676,10 @@ mod tests {
         assert!(reversed.social_cognition().perceived_liability().delta() > -0.01);
     }
 
-    #[test]
-    fn reverse_dimension_decay_exponent_overflow_guard() {
-        use crate::state::StateValue;
-
-        let mut state_value = StateValue::new(0.5).with_delta(10.0);
-        let duration = Duration::years(100);
-        let half_life = Duration::hours(1);
-
-        reverse_dimension_decay(&mut state_value, duration, half_life);
-
-        assert!(state_value.delta().is_finite());
-    }
-
     // ========================================================================
     // Coverage tests for state_evolution.rs missed regions
     // ========================================================================
 
-    #[test]
-    fn reverse_dimension_decay_very_long_half_life() {
-        use crate::state::StateValue;
-
-        // Test edge case: very long half_life with short duration should barely change delta
-        let mut state_value = StateValue::new(0.5).with_delta(0.3);
-        let duration = Duration::seconds(1);
-        let half_life = Duration::days(1000); // Very long half-life relative to duration
-
-        // This test primarily verifies the function handles extreme values gracefully
-        reverse_dimension_decay(&mut state_value, duration, half_life);
-        assert!(state_value.delta().is_finite());
-        // Delta should be nearly the same since only 1 second elapsed vs 1000 day half-life
-        assert!(state_value.delta() > 0.29);
-    }
-
-    #[test]
-    fn reverse_dimension_decay_very_small_delta() {
-        use crate::state::StateValue;
-
-        // Test that very small deltas are handled correctly
-        let mut state_value = StateValue::new(0.5).with_delta(1e-10);
-        let duration = Duration::hours(1);
-        let half_life = Duration::hours(6);
-
-        reverse_dimension_decay(&mut state_value, duration, half_life);
-
-        // Should not cause underflow or NaN
-        assert!(state_value.delta().is_finite());
-    }
-
     #[test]
     fn apply_decay_chronically_affected_dimension() {
         use crate::state::IndividualState;
@@ -2659,22 +2703,6 @@ mod tests {
         assert!(final_delta < initial_delta);
     }
 
-    #[test]
-    fn reverse_dimension_decay_overflow_guard() {
-        use crate::state::StateValue;
-
-        // Test that extremely large duration doesn't cause overflow
-        // This tests the guard at line 245: if exponent > 700.0
-        let mut state_value = StateValue::new(0.5).with_delta(0.1);
-        let duration = Duration::days(365 * 1000); // 1000 years - extreme case
-        let half_life = Duration::hours(6);
-
-        reverse_dimension_decay(&mut state_value, duration, half_life);
-
-        // Should not cause overflow - delta should remain unchanged due to guard
-        assert!((state_value.delta() - 0.1).abs() < f32::EPSILON);
-    }
-
     #[test]
     fn support_event_with_instrumental_type_does_not_affect_social_cognition() {
         // This test covers the case where Support event has a non-Emotional/non-Companionship
@@ -2685,6 +2713,7 @@ mod tests {
             .payload(EventPayload::Support {
                 support_type: SupportType::Instrumental,
                 effectiveness: 1.0,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
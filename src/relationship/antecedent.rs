@@ -12,6 +12,7 @@ use crate::types::Timestamp;
 
 /// The trust dimension affected by an antecedent.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AntecedentType {
     /// Perceived ability to perform tasks.
     Ability,
@@ -35,6 +36,7 @@ impl AntecedentType {
 
 /// Whether the antecedent is positive or negative.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AntecedentDirection {
     /// Positive trust-building signal.
     Positive,
@@ -44,6 +46,7 @@ pub enum AntecedentDirection {
 
 /// A single trust antecedent instance.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TrustAntecedent {
     timestamp: Timestamp,
     antecedent_type: AntecedentType,
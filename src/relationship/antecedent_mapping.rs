@@ -289,6 +289,7 @@ pub fn get_antecedent_for_event(event: &Event) -> Vec<AntecedentMapping> {
                 EventPayload::Support {
                     support_type,
                     effectiveness,
+                    ..
                 } => (*support_type, *effectiveness as f32),
                 _ => (SupportType::Emotional, 0.7),
             };
@@ -533,7 +534,7 @@ pub fn get_antecedent_for_event(event: &Event) -> Vec<AntecedentMapping> {
         }
         EventType::Loss => {
             let loss_type = match event.payload() {
-                EventPayload::Loss { loss_type } => *loss_type,
+                EventPayload::Loss { loss_type, .. } => *loss_type,
                 _ => LossType::Resource,
             };
             let loss_factor = match loss_type {
@@ -614,7 +615,9 @@ pub fn get_antecedent_for_event(event: &Event) -> Vec<AntecedentMapping> {
 mod tests {
     use super::*;
     use crate::event::EventBuilder;
-    use crate::enums::{EventPayload, EventTag, InteractionTopic, SupportType, TrustDomain};
+    use crate::enums::{
+        Audience, EventPayload, EventTag, InteractionTopic, SupportType, TrustDomain,
+    };
 
     #[test]
     fn antecedent_mapping_returns_entries_for_known_event() {
@@ -665,6 +668,7 @@ mod tests {
             .payload(EventPayload::Support {
                 support_type: SupportType::Instrumental,
                 effectiveness: 0.9,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -718,6 +722,7 @@ mod tests {
             .payload(EventPayload::Achievement {
                 magnitude: 0.9,
                 domain: LifeDomain::Work,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -734,6 +739,7 @@ mod tests {
             .payload(EventPayload::Achievement {
                 magnitude: 0.8,
                 domain: LifeDomain::Work,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -749,6 +755,7 @@ mod tests {
             .payload(EventPayload::Achievement {
                 magnitude: 0.8,
                 domain: LifeDomain::Work,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -804,6 +811,7 @@ mod tests {
             .payload(EventPayload::Support {
                 support_type: SupportType::Emotional,
                 effectiveness: 0.8,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -818,6 +826,7 @@ mod tests {
             .payload(EventPayload::Support {
                 support_type: SupportType::Instrumental,
                 effectiveness: 0.8,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -837,6 +846,7 @@ mod tests {
             .payload(EventPayload::Support {
                 support_type: SupportType::Informational,
                 effectiveness: 0.7,
+                audience: Audience::Private,
             })
             .build()
             .unwrap();
@@ -1150,6 +1160,7 @@ mod tests {
         let event = EventBuilder::new(EventType::Loss)
             .payload(EventPayload::Loss {
                 loss_type: LossType::Person,
+                lost_entity: None,
             })
             .build()
             .unwrap();
@@ -1163,6 +1174,7 @@ mod tests {
         let event = EventBuilder::new(EventType::Loss)
             .payload(EventPayload::Loss {
                 loss_type: LossType::Status,
+                lost_entity: None,
             })
             .build()
             .unwrap();
@@ -1175,6 +1187,7 @@ mod tests {
         let event = EventBuilder::new(EventType::Loss)
             .payload(EventPayload::Loss {
                 loss_type: LossType::Resource,
+                lost_entity: None,
             })
             .build()
             .unwrap();
@@ -1187,6 +1200,7 @@ mod tests {
         let event = EventBuilder::new(EventType::Loss)
             .payload(EventPayload::Loss {
                 loss_type: LossType::Opportunity,
+                lost_entity: None,
             })
             .build()
             .unwrap();
@@ -24,6 +24,7 @@ const DEFAULT_DECAY_HALF_LIFE: Duration = Duration::days(14);
 /// assert!(dims.warmth_effective() > 0.3);
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DirectionalDimensions {
     /// Positive feeling toward the other entity.
     /// Range: 0 (cold) to 1 (warm)
@@ -0,0 +1,370 @@
+//! Relationship dormancy ("drifting apart").
+//!
+//! Declared closeness does not persist indefinitely without contact. When
+//! the pair stops interacting, the relationship's closeness weight drifts
+//! toward zero, close relationships regress a stage, and trust antecedents
+//! lose freshness. A reconnection restores most of the lost closeness at
+//! once, because relationship memory makes rebuilding faster than forming
+//! the bond originally.
+//!
+//! Contact is inferred from `Interaction` and `Support` events whose source
+//! and target are the two members of the pair, in either direction.
+
+use crate::enums::EventType;
+use crate::event::Event;
+use crate::relationship::RelationshipStage;
+use crate::types::{Duration, EntityId, Timestamp};
+
+/// Half-life for trust antecedent freshness during dormancy, in days.
+///
+/// Matches the antecedent temporal decay used by trustworthiness
+/// recomputation, so freshness reads as "how much recent evidence remains".
+const ANTECEDENT_FRESHNESS_HALF_LIFE_DAYS: f64 = 180.0;
+
+/// Tuning for relationship drift during dormancy.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::relationship::DormancyConfig;
+/// use behavioral_pathways::types::Duration;
+///
+/// let config = DormancyConfig {
+///     stage_regression_after: Duration::days(180),
+///     ..Default::default()
+/// };
+/// assert_eq!(config.dormancy_threshold, Duration::days(90));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DormancyConfig {
+    /// Gap without contact before drift begins.
+    pub dormancy_threshold: Duration,
+    /// Time without contact after which an Intimate relationship
+    /// regresses to Established.
+    pub stage_regression_after: Duration,
+    /// Half-life of closeness once the dormancy threshold is exceeded.
+    pub drift_half_life: Duration,
+    /// Fraction of lost closeness restored by a single reconnection (0-1).
+    pub reconnection_recovery: f64,
+}
+
+impl Default for DormancyConfig {
+    fn default() -> Self {
+        DormancyConfig {
+            dormancy_threshold: Duration::days(90),
+            stage_regression_after: Duration::years(1),
+            drift_half_life: Duration::days(365),
+            reconnection_recovery: 0.75,
+        }
+    }
+}
+
+impl DormancyConfig {
+    /// Applies drift to a closeness weight across a gap without contact.
+    ///
+    /// Gaps at or below the dormancy threshold leave closeness unchanged.
+    #[must_use]
+    pub fn drift(&self, closeness: f64, gap: Duration) -> f64 {
+        let excess_days = gap.saturating_sub(self.dormancy_threshold).as_days_f64();
+        if excess_days <= 0.0 {
+            return closeness;
+        }
+        let half_life = self.drift_half_life.as_days_f64().max(1.0);
+        closeness * 0.5_f64.powf(excess_days / half_life)
+    }
+
+    /// Applies a reconnection to a closeness weight.
+    #[must_use]
+    pub fn reconnect(&self, closeness: f64) -> f64 {
+        let recovery = self.reconnection_recovery.clamp(0.0, 1.0);
+        (closeness + (1.0 - closeness) * recovery).clamp(0.0, 1.0)
+    }
+}
+
+/// Activity summary for a relationship at a point in time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RelationshipActivity {
+    /// Most recent contact at or before the query time, if any.
+    pub last_interaction: Option<Timestamp>,
+    /// Time since the last contact (or formation, if never in contact).
+    pub dormant_for: Duration,
+    /// Closeness weight relative to declared closeness (0-1).
+    pub closeness: f64,
+    /// Stage after dormancy regression is applied.
+    pub stage: RelationshipStage,
+    /// Freshness of trust antecedents given the dormancy (0-1). Trust
+    /// decisions scale the trustworthiness built from antecedents by it.
+    pub antecedent_freshness: f64,
+}
+
+impl RelationshipActivity {
+    /// Computes activity from the relationship's contact history.
+    ///
+    /// Closeness starts at 1.0 (the declared closeness) when the relationship
+    /// is formed. Each gap between contacts applies drift, and each contact
+    /// applies a reconnection. Contacts outside `(formed, at]` are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `declared_stage` - The relationship's declared stage
+    /// * `formed` - When the relationship was formed
+    /// * `contacts` - Contact timestamps between the pair, in any order
+    /// * `at` - The query timestamp
+    /// * `config` - Drift tuning
+    #[must_use]
+    pub fn compute(
+        declared_stage: RelationshipStage,
+        formed: Timestamp,
+        contacts: &[Timestamp],
+        at: Timestamp,
+        config: &DormancyConfig,
+    ) -> Self {
+        let mut in_range: Vec<Timestamp> = contacts
+            .iter()
+            .copied()
+            .filter(|ts| *ts > formed && *ts <= at)
+            .collect();
+        in_range.sort();
+
+        let mut closeness = 1.0;
+        let mut cursor = formed;
+        for contact in &in_range {
+            closeness = config.drift(closeness, *contact - cursor);
+            closeness = config.reconnect(closeness);
+            cursor = *contact;
+        }
+        let dormant_for = at - cursor;
+        closeness = config.drift(closeness, dormant_for);
+
+        let stage = if declared_stage == RelationshipStage::Intimate
+            && dormant_for >= config.stage_regression_after
+        {
+            RelationshipStage::Established
+        } else {
+            declared_stage
+        };
+
+        let stale_days = dormant_for
+            .saturating_sub(config.dormancy_threshold)
+            .as_days_f64();
+        let antecedent_freshness = 0.5_f64.powf(stale_days / ANTECEDENT_FRESHNESS_HALF_LIFE_DAYS);
+
+        RelationshipActivity {
+            last_interaction: in_range.last().copied(),
+            dormant_for,
+            closeness,
+            stage,
+            antecedent_freshness,
+        }
+    }
+
+    /// Returns true if the relationship has gone past the dormancy threshold.
+    #[must_use]
+    pub fn is_dormant(&self, config: &DormancyConfig) -> bool {
+        self.dormant_for > config.dormancy_threshold
+    }
+
+    /// Returns the relationship's contribution to social support.
+    ///
+    /// Combines the stage's support weight with current closeness.
+    #[must_use]
+    pub fn support_weight(&self) -> f64 {
+        self.stage.support_weight() * self.closeness
+    }
+}
+
+/// Returns true if the event is contact between the two entities.
+///
+/// Contact is an `Interaction` or `Support` event with one member of the
+/// pair as source and the other as target.
+#[must_use]
+pub fn is_contact_event(event: &Event, entity_a: &EntityId, entity_b: &EntityId) -> bool {
+    if !matches!(
+        event.event_type(),
+        EventType::Interaction | EventType::Support
+    ) {
+        return false;
+    }
+    match (event.source(), event.target()) {
+        (Some(source), Some(target)) => {
+            (source == entity_a && target == entity_b) || (source == entity_b && target == entity_a)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::EventBuilder;
+
+    fn formed() -> Timestamp {
+        Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+    }
+
+    #[test]
+    fn dormancy_config_defaults() {
+        let config = DormancyConfig::default();
+        assert_eq!(config.dormancy_threshold, Duration::days(90));
+        assert_eq!(config.stage_regression_after, Duration::years(1));
+        assert_eq!(config.drift_half_life, Duration::days(365));
+        assert!((config.reconnection_recovery - 0.75).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn drift_within_threshold_is_noop() {
+        let config = DormancyConfig::default();
+        assert!((config.drift(0.8, Duration::days(60)) - 0.8).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn drift_halves_after_one_half_life_past_threshold() {
+        let config = DormancyConfig::default();
+        let drifted = config.drift(1.0, Duration::days(90 + 365));
+        assert!((drifted - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn reconnect_restores_fraction_of_lost_closeness() {
+        let config = DormancyConfig::default();
+        assert!((config.reconnect(0.2) - 0.8).abs() < 1e-9);
+    }
+
+    #[test]
+    fn activity_without_contact_drifts_from_formation() {
+        let config = DormancyConfig::default();
+        let at = formed() + Duration::years(2);
+        let activity =
+            RelationshipActivity::compute(RelationshipStage::Intimate, formed(), &[], at, &config);
+
+        assert!(activity.last_interaction.is_none());
+        assert_eq!(activity.dormant_for, Duration::years(2));
+        assert!(activity.closeness < 0.4);
+        assert_eq!(activity.stage, RelationshipStage::Established);
+        assert!(activity.antecedent_freshness < 0.1);
+        assert!(activity.is_dormant(&config));
+    }
+
+    #[test]
+    fn activity_with_regular_contact_stays_close() {
+        let config = DormancyConfig::default();
+        let contacts: Vec<Timestamp> = (1..=24)
+            .map(|month| formed() + Duration::days(30 * month))
+            .collect();
+        let at = formed() + Duration::years(2);
+        let activity = RelationshipActivity::compute(
+            RelationshipStage::Intimate,
+            formed(),
+            &contacts,
+            at,
+            &config,
+        );
+
+        assert!((activity.closeness - 1.0).abs() < f64::EPSILON);
+        assert_eq!(activity.stage, RelationshipStage::Intimate);
+        assert!((activity.antecedent_freshness - 1.0).abs() < f64::EPSILON);
+        assert!(!activity.is_dormant(&config));
+    }
+
+    #[test]
+    fn single_reconnection_recovers_most_closeness() {
+        let config = DormancyConfig::default();
+        let at = formed() + Duration::years(2);
+        let reconnection = at - Duration::days(7);
+        let dormant =
+            RelationshipActivity::compute(RelationshipStage::Intimate, formed(), &[], at, &config);
+        let reconnected = RelationshipActivity::compute(
+            RelationshipStage::Intimate,
+            formed(),
+            &[reconnection],
+            at,
+            &config,
+        );
+
+        assert!(reconnected.closeness > 0.75);
+        assert!(reconnected.closeness > dormant.closeness + 0.4);
+        assert_eq!(reconnected.last_interaction, Some(reconnection));
+        assert_eq!(reconnected.stage, RelationshipStage::Intimate);
+    }
+
+    #[test]
+    fn activity_ignores_contacts_outside_range() {
+        let config = DormancyConfig::default();
+        let at = formed() + Duration::days(30);
+        let contacts = [formed() - Duration::days(10), at + Duration::days(10)];
+        let activity = RelationshipActivity::compute(
+            RelationshipStage::Established,
+            formed(),
+            &contacts,
+            at,
+            &config,
+        );
+        assert!(activity.last_interaction.is_none());
+        assert_eq!(activity.dormant_for, Duration::days(30));
+    }
+
+    #[test]
+    fn stage_regression_only_affects_intimate() {
+        let config = DormancyConfig::default();
+        let at = formed() + Duration::years(3);
+        let activity = RelationshipActivity::compute(
+            RelationshipStage::Acquaintance,
+            formed(),
+            &[],
+            at,
+            &config,
+        );
+        assert_eq!(activity.stage, RelationshipStage::Acquaintance);
+    }
+
+    #[test]
+    fn support_weight_scales_stage_weight_by_closeness() {
+        let activity = RelationshipActivity {
+            last_interaction: None,
+            dormant_for: Duration::zero(),
+            closeness: 0.5,
+            stage: RelationshipStage::Intimate,
+            antecedent_freshness: 1.0,
+        };
+        assert!((activity.support_weight() - 0.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn contact_event_requires_pair_in_either_direction() {
+        let alice = EntityId::new("alice").unwrap();
+        let bob = EntityId::new("bob").unwrap();
+        let carol = EntityId::new("carol").unwrap();
+
+        let forward = EventBuilder::new(EventType::Interaction)
+            .source(alice.clone())
+            .target(bob.clone())
+            .build()
+            .unwrap();
+        let reverse = EventBuilder::new(EventType::Support)
+            .source(bob.clone())
+            .target(alice.clone())
+            .build()
+            .unwrap();
+        let other_pair = EventBuilder::new(EventType::Interaction)
+            .source(alice.clone())
+            .target(carol)
+            .build()
+            .unwrap();
+        let wrong_type = EventBuilder::new(EventType::Conflict)
+            .source(alice.clone())
+            .target(bob.clone())
+            .build()
+            .unwrap();
+        let no_source = EventBuilder::new(EventType::Interaction)
+            .target(bob.clone())
+            .build()
+            .unwrap();
+
+        assert!(is_contact_event(&forward, &alice, &bob));
+        assert!(is_contact_event(&reverse, &alice, &bob));
+        assert!(!is_contact_event(&other_pair, &alice, &bob));
+        assert!(!is_contact_event(&wrong_type, &alice, &bob));
+        assert!(!is_contact_event(&no_source, &alice, &bob));
+    }
+}
@@ -1,11 +1,42 @@
 //! Interaction pattern metadata for relationships.
 //!
 //! Captures how frequently and consistently entities interact.
+//!
+//! # Recorded History
+//!
+//! `record_interaction` accumulates a history of individual interactions:
+//! how many, how long they lasted, which topics they covered, and when the
+//! first and last happened. Once any are recorded, `frequency` reflects
+//! their rate since the first, replacing any declared value:
+//!
+//! `frequency = ln(1 + per_week) / ln(8)`
+//!
+//! so weekly contact gives about 0.33 and daily contact 1.0. The rate is
+//! taken over at least `MIN_FREQUENCY_WINDOW`, so a couple of interactions
+//! in quick succession do not read as daily contact. `complexity()` is the
+//! mean of topic diversity and interaction depth (mean duration, up to an
+//! hour). Together they feed the proximal-process gate.
+
+use crate::context::{
+    check_proximal_process_gate, ProximalProcessGateError, INTERACTION_COMPLEXITY_THRESHOLD,
+    INTERACTION_FREQUENCY_THRESHOLD,
+};
+use crate::enums::InteractionTopic;
+use crate::types::{Duration, Timestamp};
+
+/// Shortest window recorded interactions are averaged over for
+/// `InteractionPattern::frequency`.
+pub const MIN_FREQUENCY_WINDOW: Duration = Duration::weeks(4);
 
-use crate::types::Timestamp;
+/// Interactions per week that count as daily contact (frequency 1.0).
+const DAILY_PER_WEEK: f64 = 7.0;
+
+/// Mean interaction length, in minutes, at which depth is full.
+const FULL_DEPTH_MINUTES: f64 = 60.0;
 
 /// Interaction pattern for a relationship.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InteractionPattern {
     /// How often the entities interact (0 = rarely, 1 = daily).
     pub frequency: f32,
@@ -15,6 +46,21 @@ pub struct InteractionPattern {
 
     /// Timestamp of the last interaction, if known.
     pub last_interaction: Option<Timestamp>,
+
+    /// Number of interactions recorded with `record_interaction`.
+    pub interaction_count: u32,
+
+    /// Timestamp of the first recorded interaction.
+    pub first_interaction: Option<Timestamp>,
+
+    /// Total minutes of recorded interactions with a known duration.
+    pub total_minutes: u64,
+
+    /// Number of recorded interactions with a known duration.
+    pub timed_interactions: u32,
+
+    /// Distinct topics of recorded interactions, in the order first seen.
+    pub topics: Vec<InteractionTopic>,
 }
 
 impl Default for InteractionPattern {
@@ -23,6 +69,11 @@ impl Default for InteractionPattern {
             frequency: 0.0,
             consistency: 0.0,
             last_interaction: None,
+            interaction_count: 0,
+            first_interaction: None,
+            total_minutes: 0,
+            timed_interactions: 0,
+            topics: Vec::new(),
         }
     }
 }
@@ -54,18 +105,125 @@ impl InteractionPattern {
         self.last_interaction = Some(last_interaction);
         self
     }
+
+    /// Adds one interaction to the recorded history and updates
+    /// `frequency` to the recorded rate as of `at`.
+    ///
+    /// A `duration_minutes` of 0 means the duration is unknown, and the
+    /// interaction is left out of the mean duration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::enums::InteractionTopic;
+    /// use behavioral_pathways::relationship::InteractionPattern;
+    /// use behavioral_pathways::types::{Duration, Timestamp};
+    ///
+    /// let start = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut pattern = InteractionPattern::new();
+    /// for week in 0..26 {
+    ///     let at = start + Duration::weeks(week);
+    ///     pattern.record_interaction(at, Some(InteractionTopic::Casual), 30);
+    /// }
+    ///
+    /// assert_eq!(pattern.interaction_count, 26);
+    /// assert!((pattern.mean_duration_minutes() - 30.0).abs() < 1e-9);
+    /// assert!(pattern.frequency > 0.3);
+    /// ```
+    pub fn record_interaction(
+        &mut self,
+        at: Timestamp,
+        topic: Option<InteractionTopic>,
+        duration_minutes: u32,
+    ) {
+        self.interaction_count += 1;
+        self.first_interaction = Some(self.first_interaction.map_or(at, |first| first.min(at)));
+        self.last_interaction = Some(self.last_interaction.map_or(at, |last| last.max(at)));
+        if duration_minutes > 0 {
+            self.total_minutes += u64::from(duration_minutes);
+            self.timed_interactions += 1;
+        }
+        if let Some(topic) = topic.filter(|topic| !self.topics.contains(topic)) {
+            self.topics.push(topic);
+        }
+        self.frequency = self.frequency_at(at);
+    }
+
+    /// Returns the recorded interaction rate as a frequency (0-1) as of
+    /// `at`, or the declared `frequency` if nothing has been recorded.
+    ///
+    /// The rate is taken from the first recorded interaction to `at`, over
+    /// at least `MIN_FREQUENCY_WINDOW`, so it falls while contact stops.
+    #[must_use]
+    pub fn frequency_at(&self, at: Timestamp) -> f32 {
+        let Some(first) = self.first_interaction else {
+            return self.frequency;
+        };
+        let window = (at - first).max(MIN_FREQUENCY_WINDOW);
+        let per_week = f64::from(self.interaction_count) / (window.as_days_f64() / 7.0);
+        ((1.0 + per_week).ln() / (1.0 + DAILY_PER_WEEK).ln()).min(1.0) as f32
+    }
+
+    /// Returns the mean length of recorded interactions with a known
+    /// duration, in minutes (0 if there are none).
+    #[must_use]
+    pub fn mean_duration_minutes(&self) -> f64 {
+        if self.timed_interactions == 0 {
+            return 0.0;
+        }
+        self.total_minutes as f64 / f64::from(self.timed_interactions)
+    }
+
+    /// Returns the share of interaction topics the recorded history has
+    /// covered (0-1).
+    #[must_use]
+    pub fn topic_diversity(&self) -> f64 {
+        self.topics.len() as f64 / InteractionTopic::all().len() as f64
+    }
+
+    /// Returns the complexity of the recorded interactions (0-1): the mean
+    /// of topic diversity and depth, where depth is the mean duration as a
+    /// share of an hour.
+    #[must_use]
+    pub fn complexity(&self) -> f64 {
+        let depth = (self.mean_duration_minutes() / FULL_DEPTH_MINUTES).min(1.0);
+        (self.topic_diversity() + depth) / 2.0
+    }
+
+    /// Checks the proximal-process gate against the default thresholds,
+    /// using `frequency` and `complexity()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProximalProcessGateError` naming the measures below
+    /// `INTERACTION_FREQUENCY_THRESHOLD` or
+    /// `INTERACTION_COMPLEXITY_THRESHOLD`.
+    pub fn proximal_process_gate(&self) -> Result<(), ProximalProcessGateError> {
+        check_proximal_process_gate(
+            f64::from(self.frequency),
+            self.complexity(),
+            INTERACTION_FREQUENCY_THRESHOLD,
+            INTERACTION_COMPLEXITY_THRESHOLD,
+        )
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn start() -> Timestamp {
+        Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+    }
+
     #[test]
     fn interaction_pattern_defaults_empty() {
         let pattern = InteractionPattern::default();
         assert!((pattern.frequency - 0.0).abs() < f32::EPSILON);
         assert!((pattern.consistency - 0.0).abs() < f32::EPSILON);
         assert!(pattern.last_interaction.is_none());
+        assert_eq!(pattern.interaction_count, 0);
+        assert!(pattern.topics.is_empty());
     }
 
     #[test]
@@ -80,4 +238,77 @@ mod tests {
         assert!((pattern.consistency - 0.0).abs() < f32::EPSILON);
         assert_eq!(pattern.last_interaction, Some(ts));
     }
+
+    #[test]
+    fn recording_accumulates_history() {
+        let mut pattern = InteractionPattern::new().with_frequency(0.9);
+        pattern.record_interaction(
+            start() + Duration::days(3),
+            Some(InteractionTopic::Work),
+            60,
+        );
+        pattern.record_interaction(start(), Some(InteractionTopic::Work), 0);
+        pattern.record_interaction(
+            start() + Duration::days(1),
+            Some(InteractionTopic::Casual),
+            20,
+        );
+
+        assert_eq!(pattern.interaction_count, 3);
+        assert_eq!(pattern.first_interaction, Some(start()));
+        assert_eq!(pattern.last_interaction, Some(start() + Duration::days(3)));
+        assert!((pattern.mean_duration_minutes() - 40.0).abs() < 1e-9);
+        assert_eq!(
+            pattern.topics,
+            vec![InteractionTopic::Work, InteractionTopic::Casual]
+        );
+        assert!((pattern.topic_diversity() - 2.0 / 6.0).abs() < 1e-9);
+        // Three interactions over the minimum four-week window
+        assert!(pattern.frequency < 0.9);
+    }
+
+    #[test]
+    fn frequency_follows_the_weekly_rate() {
+        let mut daily = InteractionPattern::new();
+        let mut weekly = InteractionPattern::new();
+        for day in 0..56 {
+            daily.record_interaction(start() + Duration::days(day), None, 0);
+            if day % 7 == 0 {
+                weekly.record_interaction(start() + Duration::days(day), None, 0);
+            }
+        }
+        let end = start() + Duration::days(56);
+        assert!(daily.frequency_at(end) > 0.95);
+        assert!((weekly.frequency_at(end) - 1.0 / 3.0).abs() < 0.01);
+        // Contact stopping lets the rate fall
+        assert!(weekly.frequency_at(end + Duration::years(1)) < 0.1);
+        assert!(
+            (InteractionPattern::new()
+                .with_frequency(0.4)
+                .frequency_at(end)
+                - 0.4)
+                .abs()
+                < 1e-6
+        );
+    }
+
+    #[test]
+    fn gate_needs_frequency_and_complexity() {
+        let mut brief = InteractionPattern::new();
+        let mut deep = InteractionPattern::new();
+        for week in 0..12 {
+            let at = start() + Duration::weeks(week);
+            brief.record_interaction(at, Some(InteractionTopic::Casual), 5);
+            deep.record_interaction(at, Some(InteractionTopic::Casual), 45);
+        }
+        assert!(matches!(
+            brief.proximal_process_gate(),
+            Err(ProximalProcessGateError::ComplexityBelowThreshold { .. })
+        ));
+        assert!(deep.proximal_process_gate().is_ok());
+        assert!(matches!(
+            InteractionPattern::new().proximal_process_gate(),
+            Err(ProximalProcessGateError::BothBelowThreshold { .. })
+        ));
+    }
 }
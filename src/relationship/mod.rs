@@ -60,27 +60,37 @@ mod antecedent;
 mod antecedent_mapping;
 mod interaction_pattern;
 mod directional_dimensions;
+mod dormancy;
 mod perceived_risk;
 mod predictions;
 #[allow(clippy::module_inception)]
 mod relationship;
 mod shared_dimensions;
 mod stage;
+mod stage_transition;
+mod template;
 mod trust;
 mod trust_context;
 mod trust_decision;
 mod trustworthiness;
 
 pub use directional_dimensions::DirectionalDimensions;
+pub use dormancy::{is_contact_event, DormancyConfig, RelationshipActivity};
 pub use trust_context::TrustContext;
 pub use perceived_risk::{PerceivedRisk, StakesLevel, Vulnerability, VulnerabilityType};
 pub use predictions::{would_confide, would_help};
-pub use interaction_pattern::InteractionPattern;
+pub use interaction_pattern::{InteractionPattern, MIN_FREQUENCY_WINDOW};
 pub use antecedent::{AntecedentDirection, AntecedentType, TrustAntecedent};
 pub use antecedent_mapping::{get_antecedent_for_event, AntecedentMapping, TRUST_ANTECEDENT_TABLE};
-pub use relationship::{Relationship, RelationshipError, StageTransitionError};
+pub use relationship::{
+    Relationship, RelationshipError, StageTransitionError, INTERACTION_HISTORY_GAIN,
+};
 pub use shared_dimensions::SharedDimensions;
 pub use stage::RelationshipStage;
+pub use stage_transition::{ENGAGEMENT_HALF_LIFE, ESTRANGEMENT_TRUST, STAGE_HYSTERESIS};
+pub use template::{DirectionalTemplate, RelationshipTemplate};
 pub use trust::Trust;
-pub use trust_decision::TrustDecision;
-pub use trustworthiness::TrustworthinessFactors;
+pub use trust_decision::{TrustDecision, TrustDecisionBreakdown};
+pub use trustworthiness::{
+    AntecedentHalfLives, TrustworthinessFactors, WeightedEvidence, WeightedTrustworthiness,
+};
@@ -260,6 +260,7 @@ impl std::fmt::Display for Vulnerability {
 /// let action_risk = risk.compute_for_stakes(StakesLevel::High);
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PerceivedRisk {
     /// The base/accumulated perceived risk.
     risk: StateValue,
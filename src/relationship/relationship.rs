@@ -4,11 +4,17 @@
 //! shared dimensions, directional feelings, trustworthiness perceptions,
 //! and perceived risk.
 
-use crate::enums::{BondType, Direction, DirectionalPath, RelPath, RelationshipSchema};
+use crate::enums::{
+    BondType, Direction, DirectionalPath, InteractionTopic, RelPath, RelationshipSchema,
+};
+use crate::relationship::stage_transition::evaluate_stage_transition;
+#[cfg(feature = "relationships")]
+use crate::relationship::AntecedentType;
 use crate::relationship::{
-    AntecedentDirection, DirectionalDimensions, InteractionPattern, PerceivedRisk,
-    RelationshipStage, SharedDimensions, StakesLevel, TrustAntecedent, TrustDecision,
-    TrustworthinessFactors,
+    AntecedentDirection, AntecedentHalfLives, DirectionalDimensions, InteractionPattern,
+    PerceivedRisk, RelationshipStage, RelationshipTemplate, SharedDimensions, StakesLevel,
+    TrustAntecedent, TrustContext, TrustDecision, TrustDecisionBreakdown, TrustworthinessFactors,
+    WeightedTrustworthiness,
 };
 use crate::state::StateValue;
 use crate::types::{Duration, EntityId, RelationshipId, Timestamp};
@@ -42,9 +48,11 @@ impl std::error::Error for RelationshipError {}
 
 /// Error type for invalid stage transitions.
 ///
-/// This is returned by `Relationship::set_stage()` when the transition
-/// is not allowed. Currently all transitions are allowed for flexibility,
-/// but Phase 8 may add restrictions.
+/// Returned by `Relationship::transition_stage()` and
+/// `Relationship::evaluate_stage_transition()` for a move that
+/// `RelationshipStage::can_transition_to` does not allow, such as skipping
+/// from Stranger to Intimate. `Relationship::set_stage()` currently allows
+/// all transitions for flexibility.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct StageTransitionError {
     /// The stage being transitioned from.
@@ -79,6 +87,11 @@ impl std::error::Error for StageTransitionError {}
 /// have minimal impact anyway, so this cap is primarily for memory safety.
 const MAX_ANTECEDENT_HISTORY: usize = 100;
 
+/// Shared history one recorded interaction adds, for an interaction of
+/// unknown or negligible length; an interaction of an hour or more adds
+/// twice this.
+pub const INTERACTION_HISTORY_GAIN: f32 = 0.004;
+
 /// A relationship between two entities.
 ///
 /// Relationships contain:
@@ -121,6 +134,7 @@ const MAX_ANTECEDENT_HISTORY: usize = 100;
 /// ));
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Relationship {
     /// Unique identifier for this relationship.
     id: RelationshipId,
@@ -175,6 +189,13 @@ pub struct Relationship {
 
     /// Most recent negative antecedent timestamp (B's perspective).
     last_negative_antecedent_b_to_a: Option<Timestamp>,
+
+    /// Evidential half-lives used by `trustworthiness_at`.
+    antecedent_half_lives: AntecedentHalfLives,
+
+    /// When one party lost the other, ending the relationship.
+    #[cfg_attr(feature = "serde", serde(default))]
+    dissolved_at: Option<Timestamp>,
 }
 
 impl Relationship {
@@ -191,6 +212,10 @@ impl Relationship {
     ///
     /// Returns `RelationshipError::SelfRelationship` if both entities are the same.
     ///
+    /// Either side may be a party that is never simulated, such as a
+    /// deceased parent. Declare it with `Simulation::declare_external_entity`
+    /// so the simulation treats it as external rather than dangling.
+    ///
     /// # Examples
     ///
     /// ```
@@ -232,9 +257,49 @@ impl Relationship {
             antecedent_history_b_to_a: Vec::new(),
             last_negative_antecedent_a_to_b: None,
             last_negative_antecedent_b_to_a: None,
+            antecedent_half_lives: AntecedentHalfLives::default(),
+            dissolved_at: None,
         })
     }
 
+    /// Creates a relationship pre-filled from a schema's template.
+    ///
+    /// Bonds, stage, shared dimensions, directional trust and feelings, and
+    /// the interaction pattern come from `RelationshipTemplate::for_schema`;
+    /// all of them can be changed afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RelationshipError::SelfRelationship` if both entities are the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::enums::{BondType, RelationshipSchema};
+    /// use behavioral_pathways::relationship::{Relationship, RelationshipStage};
+    /// use behavioral_pathways::types::EntityId;
+    ///
+    /// let me = EntityId::new("me").unwrap();
+    /// let sister = EntityId::new("sister").unwrap();
+    ///
+    /// let rel = Relationship::from_schema(me, sister, RelationshipSchema::Sibling).unwrap();
+    /// assert!(rel.has_bond(BondType::Sibling));
+    /// assert_eq!(rel.stage(), RelationshipStage::Established);
+    /// ```
+    pub fn from_schema(
+        entity_a: EntityId,
+        entity_b: EntityId,
+        schema: RelationshipSchema,
+    ) -> Result<Self, RelationshipError> {
+        let template = RelationshipTemplate::for_schema(schema);
+        let mut relationship = Self::try_between(entity_a, entity_b)?
+            .with_bonds(template.bond.into_iter().collect())
+            .with_stage(template.stage)
+            .with_schema(schema);
+        template.apply_dimensions(&mut relationship);
+        Ok(relationship)
+    }
+
     /// Sets the bond type, replacing any existing bonds.
     ///
     /// Use `add_bond` to add multiple bond types.
@@ -265,6 +330,20 @@ impl Relationship {
         self
     }
 
+    /// Sets the evidential half-lives used by `trustworthiness_at`.
+    #[must_use]
+    pub fn with_antecedent_half_lives(mut self, half_lives: AntecedentHalfLives) -> Self {
+        self.antecedent_half_lives = half_lives;
+        self
+    }
+
+    /// Marks the relationship as dissolved at the given time.
+    #[must_use]
+    pub fn with_dissolved_at(mut self, at: Timestamp) -> Self {
+        self.dissolved_at = Some(at);
+        self
+    }
+
     // Accessors
 
     /// Returns the relationship ID.
@@ -309,6 +388,19 @@ impl Relationship {
         self.stage
     }
 
+    /// Returns when one party's loss of the other dissolved the
+    /// relationship, if it has been dissolved.
+    #[must_use]
+    pub fn dissolved_at(&self) -> Option<Timestamp> {
+        self.dissolved_at
+    }
+
+    /// Returns true if the relationship has been dissolved.
+    #[must_use]
+    pub fn is_dissolved(&self) -> bool {
+        self.dissolved_at.is_some()
+    }
+
     /// Returns a reference to the shared dimensions.
     #[must_use]
     pub fn shared(&self) -> &SharedDimensions {
@@ -331,6 +423,46 @@ impl Relationship {
         &mut self.pattern
     }
 
+    /// Records an interaction between the pair.
+    ///
+    /// The interaction is added to the pattern's history (see
+    /// `InteractionPattern::record_interaction`), and time together builds
+    /// shared history: `INTERACTION_HISTORY_GAIN`, scaled up to double for
+    /// an hour or more. A `duration_minutes` of 0 means unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::enums::InteractionTopic;
+    /// use behavioral_pathways::relationship::Relationship;
+    /// use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+    ///
+    /// let alice = EntityId::new("alice").unwrap();
+    /// let bob = EntityId::new("bob").unwrap();
+    /// let mut rel = Relationship::try_between(alice, bob).unwrap();
+    ///
+    /// let start = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// for week in 0..26 {
+    ///     let at = start + Duration::weeks(week);
+    ///     rel.record_interaction(at, Some(InteractionTopic::Casual), 45);
+    /// }
+    ///
+    /// assert_eq!(rel.pattern().interaction_count, 26);
+    /// assert!(rel.shared().history_effective() > 0.1);
+    /// assert!(rel.pattern().proximal_process_gate().is_ok());
+    /// ```
+    pub fn record_interaction(
+        &mut self,
+        at: Timestamp,
+        topic: Option<InteractionTopic>,
+        duration_minutes: u32,
+    ) {
+        self.pattern.record_interaction(at, topic, duration_minutes);
+        let depth = (duration_minutes as f32 / 60.0).min(1.0);
+        self.shared
+            .add_history_delta(INTERACTION_HISTORY_GAIN * (1.0 + depth));
+    }
+
     // Bond management
 
     /// Adds a bond type to this relationship.
@@ -383,6 +515,99 @@ impl Relationship {
         Ok(())
     }
 
+    /// Moves the relationship to `stage` if the current stage can reach it
+    /// in one transition.
+    ///
+    /// Unlike `set_stage`, this refuses skipped stages. See
+    /// `RelationshipStage::can_transition_to`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StageTransitionError` if the transition is not allowed; the
+    /// stage is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::relationship::{Relationship, RelationshipStage};
+    /// use behavioral_pathways::types::EntityId;
+    ///
+    /// let alice = EntityId::new("alice").unwrap();
+    /// let bob = EntityId::new("bob").unwrap();
+    /// let mut rel = Relationship::try_between(alice, bob).unwrap();
+    ///
+    /// assert!(rel.transition_stage(RelationshipStage::Intimate).is_err());
+    /// assert!(rel.transition_stage(RelationshipStage::Acquaintance).is_ok());
+    /// assert_eq!(rel.stage(), RelationshipStage::Acquaintance);
+    /// ```
+    pub fn transition_stage(
+        &mut self,
+        stage: RelationshipStage,
+    ) -> Result<(), StageTransitionError> {
+        if !self.stage.can_transition_to(stage) {
+            return Err(StageTransitionError::new(self.stage, stage));
+        }
+        self.stage = stage;
+        Ok(())
+    }
+
+    /// Decays the relationship over `elapsed` and moves its stage by at
+    /// most one step based on the interaction history.
+    ///
+    /// `interactions` are samples of the pair's interaction pattern over the
+    /// `elapsed` window since the last evaluation, such as one per week; an
+    /// empty slice means no contact. They are blended into `pattern()` with
+    /// a half-life of `ENGAGEMENT_HALF_LIFE`, so engagement reflects the
+    /// accumulated history rather than the latest window.
+    ///
+    /// Promotion needs engagement, trust in both directions, and shared
+    /// depth at the next stage's thresholds. Demotion waits until the
+    /// evidence falls `STAGE_HYSTERESIS` below the current stage's
+    /// thresholds, and trust below `ESTRANGEMENT_TRUST` estranges the
+    /// relationship. Engagement is the accumulated frequency, with erratic
+    /// contact counting half; trust is the lower of the two directions'
+    /// overall trustworthiness; depth is the mean of shared intimacy and
+    /// history.
+    ///
+    /// Returns the new stage, or `None` if the stage did not change.
+    ///
+    /// # Errors
+    ///
+    /// Returns `StageTransitionError` if the evidence calls for a move the
+    /// current stage cannot make, such as estranging a Stranger. The decay
+    /// is still applied and the stage is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::relationship::{
+    ///     InteractionPattern, Relationship, RelationshipStage,
+    /// };
+    /// use behavioral_pathways::types::{Duration, EntityId};
+    ///
+    /// let alice = EntityId::new("alice").unwrap();
+    /// let bob = EntityId::new("bob").unwrap();
+    /// let mut rel = Relationship::try_between(alice, bob).unwrap();
+    /// rel.shared_mut().add_history_delta(0.2);
+    ///
+    /// // A quarter of weekly contact
+    /// let weekly = vec![InteractionPattern::new().with_frequency(0.4).with_consistency(0.8); 12];
+    /// let stage = rel
+    ///     .evaluate_stage_transition(&weekly, Duration::weeks(12))
+    ///     .unwrap();
+    /// assert_eq!(stage, Some(RelationshipStage::Acquaintance));
+    ///
+    /// // Evidence supporting Intimate still only moves one stage at a time
+    /// assert!(rel.transition_stage(RelationshipStage::Intimate).is_err());
+    /// ```
+    pub fn evaluate_stage_transition(
+        &mut self,
+        interactions: &[InteractionPattern],
+        elapsed: Duration,
+    ) -> Result<Option<RelationshipStage>, StageTransitionError> {
+        evaluate_stage_transition(self, interactions, elapsed)
+    }
+
     // Trustworthiness access
 
     /// Returns a reference to the trustworthiness factors for a direction.
@@ -471,6 +696,69 @@ impl Relationship {
         }
     }
 
+    /// Returns the evidential half-lives used by `trustworthiness_at`.
+    #[must_use]
+    pub fn antecedent_half_lives(&self) -> &AntecedentHalfLives {
+        &self.antecedent_half_lives
+    }
+
+    /// Sets the evidential half-lives used by `trustworthiness_at`.
+    pub fn set_antecedent_half_lives(&mut self, half_lives: AntecedentHalfLives) {
+        self.antecedent_half_lives = half_lives;
+    }
+
+    /// Returns trustworthiness in one direction at `timestamp`, weighting
+    /// each antecedent by its recency.
+    ///
+    /// Unlike `trustworthiness`, which accumulates antecedents as they are
+    /// appended, each antecedent at or before `timestamp` counts by
+    /// `0.5^(age / half_life)`, with the half-life for its type from
+    /// `antecedent_half_lives`. The result carries the weighted evidence
+    /// behind each factor and the factors themselves, on this direction's
+    /// bases. See `TrustworthinessFactors::weighted_at`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::enums::Direction;
+    /// use behavioral_pathways::relationship::{
+    ///     AntecedentDirection, AntecedentType, Relationship, TrustAntecedent,
+    /// };
+    /// use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+    ///
+    /// let alice = EntityId::new("alice").unwrap();
+    /// let bob = EntityId::new("bob").unwrap();
+    /// let mut rel = Relationship::try_between(alice, bob).unwrap();
+    /// let kept_promise = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// rel.append_antecedent(
+    ///     Direction::AToB,
+    ///     TrustAntecedent::new(
+    ///         kept_promise,
+    ///         AntecedentType::Integrity,
+    ///         AntecedentDirection::Positive,
+    ///         0.6,
+    ///         "kept a promise",
+    ///     ),
+    /// );
+    ///
+    /// let fresh = rel.trustworthiness_at(Direction::AToB, kept_promise);
+    /// let later = rel.trustworthiness_at(Direction::AToB, kept_promise + Duration::years(4));
+    /// assert!(fresh.integrity.delta > later.integrity.delta);
+    /// assert!(later.factors.integrity_effective() > 0.3);
+    /// ```
+    #[must_use]
+    pub fn trustworthiness_at(
+        &self,
+        direction: Direction,
+        timestamp: Timestamp,
+    ) -> WeightedTrustworthiness {
+        self.trustworthiness(direction).weighted_at(
+            self.antecedent_history(direction),
+            timestamp,
+            &self.antecedent_half_lives,
+        )
+    }
+
     /// Returns the last negative antecedent timestamp for the given direction.
     #[must_use]
     pub fn last_negative_antecedent(&self, direction: Direction) -> Option<Timestamp> {
@@ -480,6 +768,98 @@ impl Relationship {
         }
     }
 
+    /// Drops every antecedent in one direction, restoring its declared
+    /// trustworthiness.
+    pub(crate) fn clear_antecedents(&mut self, direction: Direction) {
+        match direction {
+            Direction::AToB => {
+                self.antecedent_history_a_to_b.clear();
+                self.last_negative_antecedent_a_to_b = None;
+            }
+            Direction::BToA => {
+                self.antecedent_history_b_to_a.clear();
+                self.last_negative_antecedent_b_to_a = None;
+            }
+        }
+        self.trustworthiness_mut(direction)
+            .recompute_from_antecedents(&[]);
+    }
+
+    /// Drops antecedents recorded after `timestamp` and recomputes the
+    /// trustworthiness of each direction that lost any.
+    ///
+    /// Used to reconstruct the relationship as it stood at a past time.
+    pub(crate) fn retain_antecedents_until(&mut self, timestamp: Timestamp) {
+        for direction in [Direction::AToB, Direction::BToA] {
+            let (history, last_negative) = match direction {
+                Direction::AToB => (
+                    &mut self.antecedent_history_a_to_b,
+                    &mut self.last_negative_antecedent_a_to_b,
+                ),
+                Direction::BToA => (
+                    &mut self.antecedent_history_b_to_a,
+                    &mut self.last_negative_antecedent_b_to_a,
+                ),
+            };
+            let before = history.len();
+            history.retain(|entry| entry.timestamp() <= timestamp);
+            if history.len() == before {
+                continue;
+            }
+            *last_negative = history
+                .iter()
+                .filter(|entry| entry.direction() == AntecedentDirection::Negative)
+                .map(TrustAntecedent::timestamp)
+                .max();
+            let retained = history.clone();
+            self.trustworthiness_mut(direction)
+                .recompute_from_antecedents(&retained);
+        }
+    }
+
+    /// Removes the first antecedent in one direction recorded at
+    /// `timestamp` with the given type and context, recomputing that
+    /// direction's trustworthiness.
+    ///
+    /// Used to retract an event removed from the simulation. Returns true
+    /// if an antecedent was removed.
+    #[cfg(feature = "relationships")]
+    pub(crate) fn remove_antecedent(
+        &mut self,
+        direction: Direction,
+        timestamp: Timestamp,
+        antecedent_type: AntecedentType,
+        context: &str,
+    ) -> bool {
+        let (history, last_negative) = match direction {
+            Direction::AToB => (
+                &mut self.antecedent_history_a_to_b,
+                &mut self.last_negative_antecedent_a_to_b,
+            ),
+            Direction::BToA => (
+                &mut self.antecedent_history_b_to_a,
+                &mut self.last_negative_antecedent_b_to_a,
+            ),
+        };
+        let Some(index) = history.iter().position(|entry| {
+            entry.timestamp() == timestamp
+                && entry.antecedent_type() == antecedent_type
+                && entry.context() == context
+        }) else {
+            return false;
+        };
+        history.remove(index);
+        *last_negative = history
+            .iter()
+            .filter(|entry| entry.direction() == AntecedentDirection::Negative)
+            .map(TrustAntecedent::timestamp)
+            .max();
+        let retained = history.clone();
+        self.trustworthiness_mut(direction)
+            .recompute_from_antecedents(&retained);
+        true
+    }
+
     fn push_antecedent(
         history: &mut Vec<TrustAntecedent>,
         antecedent: TrustAntecedent,
@@ -638,6 +1018,26 @@ impl Relationship {
         self.compute_trust_decision_with_context(direction, trustor_propensity, stakes, 1.0)
     }
 
+    /// Computes a TrustDecision using a full TrustContext.
+    ///
+    /// Stakes come from the context and its situational dimensions supply
+    /// the context multiplier. Use `TrustContext::from_state` to derive the
+    /// stakes from the trustor's current state.
+    #[must_use]
+    pub fn compute_trust_decision_in_context(
+        &self,
+        direction: Direction,
+        trustor_propensity: f32,
+        context: &TrustContext,
+    ) -> TrustDecision {
+        self.compute_trust_decision_with_context(
+            direction,
+            trustor_propensity,
+            context.stakes(),
+            context.compute_multiplier(),
+        )
+    }
+
     /// Computes a TrustDecision for the given stakes level with a context multiplier.
     #[must_use]
     pub fn compute_trust_decision_with_context(
@@ -651,34 +1051,23 @@ impl Relationship {
         let risk = self.perceived_risk(direction);
         let context_multiplier = context_multiplier.clamp(0.0, 2.0);
 
-        // Get weights based on relationship stage
-        let prop_weight = self.stage.propensity_weight();
-        let trust_weight = self.stage.trustworthiness_weight();
         let stage_risk_mod = self.stage.risk_modifier();
-
-        // Compute risk with stage modifier
-        let perceived_risk = risk.compute_with_stage_modifier(stakes, stage_risk_mod);
-
-        // Risk weight is a constant factor
-        let risk_weight = 0.5;
-
-        // Compute each willingness domain
-        // Task willingness uses competence
-        let task_base =
-            prop_weight * trustor_propensity + trust_weight * trustworthiness.competence_effective();
-        let task_willingness = (task_base * context_multiplier) - risk_weight * perceived_risk;
-
-        // Support willingness uses benevolence
-        let support_base = prop_weight * trustor_propensity
-            + trust_weight * trustworthiness.benevolence_effective();
-        let support_willingness =
-            (support_base * context_multiplier) - risk_weight * perceived_risk;
-
-        // Disclosure willingness uses integrity
-        let disclosure_base = prop_weight * trustor_propensity
-            + trust_weight * trustworthiness.integrity_effective();
-        let disclosure_willingness =
-            (disclosure_base * context_multiplier) - risk_weight * perceived_risk;
+        let breakdown = TrustDecisionBreakdown {
+            stage: self.stage,
+            stakes,
+            propensity: trustor_propensity,
+            competence: trustworthiness.competence_effective(),
+            benevolence: trustworthiness.benevolence_effective(),
+            integrity: trustworthiness.integrity_effective(),
+            // Weights based on relationship stage
+            propensity_weight: self.stage.propensity_weight(),
+            trustworthiness_weight: self.stage.trustworthiness_weight(),
+            // Risk weight is a constant factor
+            risk_weight: 0.5,
+            context_multiplier,
+            perceived_risk: risk.compute_with_stage_modifier(stakes, stage_risk_mod),
+            low_stakes_risk: risk.compute_with_stage_modifier(StakesLevel::Low, stage_risk_mod),
+        };
 
         // Decision certainty: how confident we are in our willingness assessment
         // Lower for estranged because our willingness is conflicted
@@ -705,13 +1094,7 @@ impl Relationship {
         };
         let trustee_confidence = (history * 0.4 + stage_trustee_confidence * 0.6).clamp(0.0, 1.0);
 
-        TrustDecision::new(
-            task_willingness.clamp(0.0, 1.0),
-            support_willingness.clamp(0.0, 1.0),
-            disclosure_willingness.clamp(0.0, 1.0),
-            decision_certainty,
-            trustee_confidence,
-        )
+        TrustDecision::explained(breakdown, decision_certainty, trustee_confidence)
     }
 
     // Decay
@@ -749,13 +1132,15 @@ impl PartialEq for Relationship {
             && self.antecedent_history_b_to_a == other.antecedent_history_b_to_a
             && self.last_negative_antecedent_a_to_b == other.last_negative_antecedent_a_to_b
             && self.last_negative_antecedent_b_to_a == other.last_negative_antecedent_b_to_a
+            && self.antecedent_half_lives == other.antecedent_half_lives
+            && self.dissolved_at == other.dissolved_at
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::enums::{DirectionalPath, SharedPath, TrustPath};
+    use crate::enums::{DirectionalPath, SharedPath, TrustDomain, TrustPath};
     use crate::relationship::{AntecedentDirection, AntecedentType};
     use crate::types::Duration;
 
@@ -819,6 +1204,66 @@ mod tests {
         );
     }
 
+    #[test]
+    fn retain_antecedents_until_drops_later_entries() {
+        let mut rel = Relationship::try_between(alice(), bob()).unwrap();
+        let ts_positive = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let ts_negative = Timestamp::from_ymd_hms(2024, 2, 1, 0, 0, 0);
+        let positive = TrustAntecedent::new(
+            ts_positive,
+            AntecedentType::Benevolence,
+            AntecedentDirection::Positive,
+            0.4,
+            "support",
+        );
+        let negative = TrustAntecedent::new(
+            ts_negative,
+            AntecedentType::Integrity,
+            AntecedentDirection::Negative,
+            0.5,
+            "betrayal",
+        );
+        rel.append_antecedent(Direction::AToB, positive.clone());
+        rel.append_antecedent(Direction::AToB, negative);
+        let history = rel.antecedent_history(Direction::AToB).to_vec();
+        rel.trustworthiness_mut(Direction::AToB)
+            .recompute_from_antecedents(&history);
+        let damaged_integrity = rel.trustworthiness(Direction::AToB).integrity_effective();
+
+        let mut expected = Relationship::try_between(alice(), bob()).unwrap();
+        expected.append_antecedent(Direction::AToB, positive.clone());
+        expected
+            .trustworthiness_mut(Direction::AToB)
+            .recompute_from_antecedents(&[positive]);
+
+        rel.retain_antecedents_until(ts_positive);
+        assert_eq!(rel.antecedent_history(Direction::AToB).len(), 1);
+        assert_eq!(rel.last_negative_antecedent(Direction::AToB), None);
+        let restored = rel.trustworthiness(Direction::AToB);
+        assert!(restored.integrity_effective() > damaged_integrity);
+        assert_eq!(restored, expected.trustworthiness(Direction::AToB));
+    }
+
+    #[test]
+    #[cfg(feature = "relationships")]
+    fn remove_antecedent_drops_matching_entry() {
+        let mut rel = Relationship::try_between(alice(), bob()).unwrap();
+        let ts = Timestamp::from_ymd_hms(2024, 2, 1, 0, 0, 0);
+        let negative = TrustAntecedent::new(
+            ts,
+            AntecedentType::Integrity,
+            AntecedentDirection::Negative,
+            0.5,
+            "betrayal",
+        );
+        rel.append_antecedent(Direction::AToB, negative);
+
+        assert!(!rel.remove_antecedent(Direction::AToB, ts, AntecedentType::Integrity, "support"));
+        assert!(rel.remove_antecedent(Direction::AToB, ts, AntecedentType::Integrity, "betrayal"));
+        assert!(rel.antecedent_history(Direction::AToB).is_empty());
+        assert_eq!(rel.last_negative_antecedent(Direction::AToB), None);
+    }
+
     #[test]
     fn antecedent_history_caps_at_max_entries() {
         let mut rel = Relationship::try_between(alice(), bob()).unwrap();
@@ -903,6 +1348,18 @@ mod tests {
         assert_eq!(rel.stage(), RelationshipStage::Established);
     }
 
+    #[test]
+    fn with_dissolved_at() {
+        let rel = Relationship::try_between(alice(), bob()).unwrap();
+        assert!(!rel.is_dissolved());
+        assert_eq!(rel.dissolved_at(), None);
+
+        let at = Timestamp::from_ymd_hms(2024, 3, 1, 0, 0, 0);
+        let rel = rel.with_dissolved_at(at);
+        assert!(rel.is_dissolved());
+        assert_eq!(rel.dissolved_at(), Some(at));
+    }
+
     #[test]
     fn entities_accessor() {
         let rel = Relationship::try_between(alice(), bob()).unwrap();
@@ -1117,6 +1574,21 @@ mod tests {
         assert!(constrained.task_willingness() < baseline.task_willingness());
     }
 
+    #[test]
+    fn trust_decision_in_context_uses_context_stakes_and_multiplier() {
+        let rel = Relationship::try_between(alice(), bob()).unwrap();
+        let context = TrustContext::new().with_stakes(StakesLevel::High);
+        let in_context = rel.compute_trust_decision_in_context(Direction::AToB, 0.5, &context);
+        let explicit = rel.compute_trust_decision_with_context(
+            Direction::AToB,
+            0.5,
+            StakesLevel::High,
+            context.compute_multiplier(),
+        );
+
+        assert_eq!(in_context, explicit);
+    }
+
     #[test]
     fn propensity_weight_diminishes_with_stage() {
         let stranger = Relationship::try_between(alice(), bob()).unwrap();
@@ -1198,6 +1670,82 @@ mod tests {
         assert!(low_stakes.task_willingness() > high_stakes.task_willingness());
     }
 
+    #[test]
+    fn trust_decision_breakdown_sums_to_willingness() {
+        let mut rel = Relationship::try_between(alice(), bob()).unwrap();
+        rel.trustworthiness_mut(Direction::AToB)
+            .add_competence_delta(0.4);
+        rel.perceived_risk_mut(Direction::AToB).add_delta(0.2);
+
+        for stage in RelationshipStage::all() {
+            let rel = rel.clone().with_stage(stage);
+            for stakes in StakesLevel::all() {
+                let decision = rel.compute_trust_decision(Direction::AToB, 0.6, stakes);
+                let breakdown = decision.explanation().unwrap();
+                assert_eq!(breakdown.stage, stage);
+                assert_eq!(breakdown.stakes, stakes);
+
+                for (domain, willingness) in [
+                    (TrustDomain::Task, decision.task_willingness()),
+                    (TrustDomain::Support, decision.support_willingness()),
+                    (TrustDomain::Disclosure, decision.disclosure_willingness()),
+                ] {
+                    let total = breakdown.propensity_term()
+                        + breakdown.trustworthiness_term(domain)
+                        + breakdown.risk_term()
+                        + breakdown.stakes_adjustment()
+                        + breakdown.clamp_adjustment(domain);
+                    assert!(
+                        (total - willingness).abs() < 1e-6,
+                        "{stage} at {stakes} stakes: {total} != {willingness}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn trust_decision_breakdown_shifts_from_propensity_to_trustworthiness() {
+        let mut stranger = Relationship::try_between(alice(), bob()).unwrap();
+        stranger
+            .trustworthiness_mut(Direction::AToB)
+            .add_competence_delta(0.3);
+        let established = stranger.clone().with_stage(RelationshipStage::Established);
+
+        let stranger_breakdown = stranger
+            .compute_trust_decision(Direction::AToB, 0.5, StakesLevel::Low)
+            .explanation()
+            .unwrap();
+        let established_breakdown = established
+            .compute_trust_decision(Direction::AToB, 0.5, StakesLevel::Low)
+            .explanation()
+            .unwrap();
+
+        assert!(stranger_breakdown.is_propensity_dominated(TrustDomain::Task));
+        assert!(!established_breakdown.is_propensity_dominated(TrustDomain::Task));
+        assert!(stranger_breakdown.to_string().contains("led by propensity"));
+        assert!(established_breakdown
+            .to_string()
+            .contains("led by trustworthiness"));
+    }
+
+    #[test]
+    fn trust_decision_breakdown_has_no_stakes_adjustment_at_low_stakes() {
+        let rel = Relationship::try_between(alice(), bob()).unwrap();
+        let low = rel
+            .compute_trust_decision(Direction::AToB, 0.5, StakesLevel::Low)
+            .explanation()
+            .unwrap();
+        let high = rel
+            .compute_trust_decision(Direction::AToB, 0.5, StakesLevel::High)
+            .explanation()
+            .unwrap();
+
+        assert!(low.stakes_adjustment().abs() < f32::EPSILON);
+        assert!(high.stakes_adjustment() < 0.0);
+        assert!((low.risk_term() - high.risk_term()).abs() < f32::EPSILON);
+    }
+
     #[test]
     fn apply_decay() {
         let mut rel = Relationship::try_between(alice(), bob()).unwrap();
@@ -34,6 +34,7 @@ const INTIMACY_DECAY_HALF_LIFE: Duration = Duration::days(30);
 /// assert!(shared.affinity_effective() > 0.2);
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SharedDimensions {
     /// General liking between entities.
     /// Range: 0 (dislike) to 1 (strong liking)
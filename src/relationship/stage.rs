@@ -26,6 +26,7 @@
 /// assert_eq!(stage.propensity_weight(), 0.6);
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RelationshipStage {
     /// No significant interaction history.
     #[default]
@@ -116,6 +117,24 @@ impl RelationshipStage {
         }
     }
 
+    /// Returns how much a relationship at this stage buffers loneliness.
+    ///
+    /// - Stranger: 0.0 (no support)
+    /// - Acquaintance: 0.3 (light support)
+    /// - Established: 0.7 (reliable support)
+    /// - Intimate: 1.0 (full support)
+    /// - Estranged: 0.0 (no support)
+    #[must_use]
+    pub const fn support_weight(&self) -> f64 {
+        match self {
+            RelationshipStage::Stranger => 0.0,
+            RelationshipStage::Acquaintance => 0.3,
+            RelationshipStage::Established => 0.7,
+            RelationshipStage::Intimate => 1.0,
+            RelationshipStage::Estranged => 0.0,
+        }
+    }
+
     /// Returns a human-readable name for this stage.
     #[must_use]
     pub const fn name(&self) -> &'static str {
@@ -176,6 +195,46 @@ impl RelationshipStage {
             RelationshipStage::Established | RelationshipStage::Intimate
         )
     }
+
+    /// Returns the position of this stage on the Stranger to Intimate
+    /// ladder, or `None` for Estranged.
+    const fn ladder_index(&self) -> Option<u8> {
+        match self {
+            RelationshipStage::Stranger => Some(0),
+            RelationshipStage::Acquaintance => Some(1),
+            RelationshipStage::Established => Some(2),
+            RelationshipStage::Intimate => Some(3),
+            RelationshipStage::Estranged => None,
+        }
+    }
+
+    /// Returns true if a relationship can move from this stage to `to` in
+    /// one transition.
+    ///
+    /// Stages on the Stranger to Intimate ladder move one step up or down.
+    /// A positive stage can fall to Estranged, and an estranged
+    /// relationship can only be rebuilt as an Acquaintance. Staying at the
+    /// same stage is not a transition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::relationship::RelationshipStage;
+    ///
+    /// let stranger = RelationshipStage::Stranger;
+    /// assert!(stranger.can_transition_to(RelationshipStage::Acquaintance));
+    /// assert!(!stranger.can_transition_to(RelationshipStage::Intimate));
+    /// assert!(RelationshipStage::Intimate.can_transition_to(RelationshipStage::Estranged));
+    /// ```
+    #[must_use]
+    pub const fn can_transition_to(&self, to: RelationshipStage) -> bool {
+        match (self.ladder_index(), to.ladder_index()) {
+            (Some(from), Some(to)) => from.abs_diff(to) == 1,
+            (Some(_), None) => self.is_positive(),
+            (None, Some(_)) => matches!(to, RelationshipStage::Acquaintance),
+            (None, None) => false,
+        }
+    }
 }
 
 impl std::fmt::Display for RelationshipStage {
@@ -210,6 +269,20 @@ mod tests {
         assert!((RelationshipStage::Estranged.trustworthiness_weight() - 0.7).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn transitions_move_one_step() {
+        assert!(RelationshipStage::Acquaintance.can_transition_to(RelationshipStage::Established));
+        assert!(RelationshipStage::Intimate.can_transition_to(RelationshipStage::Established));
+        assert!(!RelationshipStage::Stranger.can_transition_to(RelationshipStage::Established));
+        assert!(!RelationshipStage::Intimate.can_transition_to(RelationshipStage::Acquaintance));
+        assert!(!RelationshipStage::Stranger.can_transition_to(RelationshipStage::Estranged));
+        assert!(RelationshipStage::Estranged.can_transition_to(RelationshipStage::Acquaintance));
+        assert!(!RelationshipStage::Estranged.can_transition_to(RelationshipStage::Intimate));
+        for stage in RelationshipStage::all() {
+            assert!(!stage.can_transition_to(stage));
+        }
+    }
+
     #[test]
     fn weights_sum_to_one() {
         for stage in RelationshipStage::all() {
@@ -228,6 +301,15 @@ mod tests {
         assert!((RelationshipStage::Estranged.risk_modifier() - 0.4).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn support_weight_values() {
+        assert!(RelationshipStage::Stranger.support_weight().abs() < f64::EPSILON);
+        assert!((RelationshipStage::Acquaintance.support_weight() - 0.3).abs() < f64::EPSILON);
+        assert!((RelationshipStage::Established.support_weight() - 0.7).abs() < f64::EPSILON);
+        assert!((RelationshipStage::Intimate.support_weight() - 1.0).abs() < f64::EPSILON);
+        assert!(RelationshipStage::Estranged.support_weight().abs() < f64::EPSILON);
+    }
+
     #[test]
     fn propensity_weight_diminishes_with_stage() {
         assert!(
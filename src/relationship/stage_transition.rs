@@ -0,0 +1,261 @@
+//! Stage transitions driven by interaction history.
+//!
+//! `Relationship::evaluate_stage_transition` moves a relationship along the
+//! Stranger -> Acquaintance -> Established -> Intimate ladder from three
+//! kinds of evidence:
+//!
+//! - **Engagement**: the relationship's accumulated interaction frequency,
+//!   with erratic contact counting half. Each evaluation blends the sampled
+//!   interaction patterns into `Relationship::pattern()` with a half-life of
+//!   `ENGAGEMENT_HALF_LIFE`, so no contact at all lets it fade
+//! - **Trust**: the lower of the two directions' overall trustworthiness
+//! - **Depth**: mean of shared intimacy and shared history
+//!
+//! Promotion to a stage needs all three at that stage's thresholds.
+//! Demotion from a stage needs any of them to fall `STAGE_HYSTERESIS` below
+//! the thresholds that promoted it, so a single bad week does not undo
+//! months of closeness. Trust collapsing below `ESTRANGEMENT_TRUST`
+//! estranges a positive relationship.
+//!
+//! A relationship moves at most one stage per evaluation.
+
+use crate::enums::Direction;
+use crate::relationship::{
+    InteractionPattern, Relationship, RelationshipStage, StageTransitionError,
+};
+use crate::types::Duration;
+
+/// How far evidence must fall below a stage's promotion thresholds before
+/// the relationship is demoted from it.
+pub const STAGE_HYSTERESIS: f32 = 0.15;
+
+/// Trust below which a positive relationship becomes Estranged.
+pub const ESTRANGEMENT_TRUST: f32 = 0.15;
+
+/// Half-life with which sampled interactions replace the accumulated
+/// interaction pattern.
+pub const ENGAGEMENT_HALF_LIFE: Duration = Duration::weeks(4);
+
+/// Engagement, trust, and depth needed to reach a stage.
+#[derive(Debug, Clone, Copy)]
+struct StageThresholds {
+    engagement: f32,
+    trust: f32,
+    depth: f32,
+}
+
+/// Returns the promotion thresholds for a stage above Stranger.
+const fn thresholds(stage: RelationshipStage) -> Option<StageThresholds> {
+    match stage {
+        RelationshipStage::Acquaintance => Some(StageThresholds {
+            engagement: 0.1,
+            trust: 0.25,
+            depth: 0.05,
+        }),
+        RelationshipStage::Established => Some(StageThresholds {
+            engagement: 0.35,
+            trust: 0.45,
+            depth: 0.25,
+        }),
+        RelationshipStage::Intimate => Some(StageThresholds {
+            engagement: 0.5,
+            trust: 0.6,
+            depth: 0.5,
+        }),
+        RelationshipStage::Stranger | RelationshipStage::Estranged => None,
+    }
+}
+
+/// The evidence a stage is evaluated against.
+#[derive(Debug, Clone, Copy)]
+struct StageEvidence {
+    engagement: f32,
+    trust: f32,
+    depth: f32,
+}
+
+impl StageEvidence {
+    fn of(relationship: &Relationship) -> Self {
+        let pattern = relationship.pattern();
+        let engagement = pattern.frequency * (0.5 + 0.5 * pattern.consistency);
+        let trust = relationship
+            .trustworthiness(Direction::AToB)
+            .overall()
+            .min(relationship.trustworthiness(Direction::BToA).overall());
+        let shared = relationship.shared();
+        let depth = (shared.intimacy_effective() + shared.history_effective()) / 2.0;
+        StageEvidence {
+            engagement,
+            trust,
+            depth,
+        }
+    }
+
+    /// Returns true if the evidence meets `thresholds` lowered by `margin`.
+    fn meets(&self, thresholds: StageThresholds, margin: f32) -> bool {
+        self.engagement >= thresholds.engagement - margin
+            && self.trust >= thresholds.trust - margin
+            && self.depth >= thresholds.depth - margin
+    }
+}
+
+/// Returns the stage one step above `stage` on the ladder, if any.
+const fn next_stage(stage: RelationshipStage) -> Option<RelationshipStage> {
+    match stage {
+        RelationshipStage::Stranger | RelationshipStage::Estranged => {
+            Some(RelationshipStage::Acquaintance)
+        }
+        RelationshipStage::Acquaintance => Some(RelationshipStage::Established),
+        RelationshipStage::Established => Some(RelationshipStage::Intimate),
+        RelationshipStage::Intimate => None,
+    }
+}
+
+/// Returns the stage one step below `stage` that demotion falls to.
+///
+/// Acquaintances never become strangers again; only estrangement ends them.
+const fn previous_stage(stage: RelationshipStage) -> Option<RelationshipStage> {
+    match stage {
+        RelationshipStage::Established => Some(RelationshipStage::Acquaintance),
+        RelationshipStage::Intimate => Some(RelationshipStage::Established),
+        _ => None,
+    }
+}
+
+/// Blends interaction samples covering `elapsed` into the accumulated
+/// pattern.
+///
+/// An empty slice means no contact: frequency fades toward zero and
+/// consistency is kept.
+fn accumulate_interactions(
+    pattern: &mut InteractionPattern,
+    interactions: &[InteractionPattern],
+    elapsed: Duration,
+) {
+    let weight =
+        1.0 - 0.5_f64.powf(elapsed.as_days_f64() / ENGAGEMENT_HALF_LIFE.as_days_f64()) as f32;
+    let count = interactions.len() as f32;
+    let (frequency, consistency) = if interactions.is_empty() {
+        (0.0, pattern.consistency)
+    } else {
+        (
+            interactions.iter().map(|p| p.frequency).sum::<f32>() / count,
+            interactions.iter().map(|p| p.consistency).sum::<f32>() / count,
+        )
+    };
+    pattern.frequency += (frequency - pattern.frequency) * weight;
+    pattern.consistency += (consistency - pattern.consistency) * weight;
+}
+
+/// Decays the relationship over `elapsed`, then applies the stage the
+/// evidence calls for.
+pub(crate) fn evaluate_stage_transition(
+    relationship: &mut Relationship,
+    interactions: &[InteractionPattern],
+    elapsed: Duration,
+) -> Result<Option<RelationshipStage>, StageTransitionError> {
+    relationship.apply_decay(elapsed);
+    accumulate_interactions(relationship.pattern_mut(), interactions, elapsed);
+    let evidence = StageEvidence::of(relationship);
+    let current = relationship.stage();
+
+    let target = if evidence.trust < ESTRANGEMENT_TRUST {
+        (current != RelationshipStage::Estranged).then_some(RelationshipStage::Estranged)
+    } else if let Some(next) =
+        next_stage(current).filter(|next| thresholds(*next).is_some_and(|t| evidence.meets(t, 0.0)))
+    {
+        Some(next)
+    } else {
+        thresholds(current)
+            .filter(|t| !evidence.meets(*t, STAGE_HYSTERESIS))
+            .and_then(|_| previous_stage(current))
+    };
+
+    let Some(target) = target else {
+        return Ok(None);
+    };
+    relationship.transition_stage(target)?;
+    Ok(Some(target))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thresholds_rise_along_the_ladder() {
+        let ladder = [
+            RelationshipStage::Acquaintance,
+            RelationshipStage::Established,
+            RelationshipStage::Intimate,
+        ];
+        for pair in ladder.windows(2) {
+            let (lower, upper) = (thresholds(pair[0]).unwrap(), thresholds(pair[1]).unwrap());
+            assert!(upper.engagement > lower.engagement);
+            assert!(upper.trust > lower.trust);
+            assert!(upper.depth > lower.depth);
+        }
+        assert!(thresholds(RelationshipStage::Acquaintance).unwrap().trust > ESTRANGEMENT_TRUST);
+    }
+
+    #[test]
+    fn silence_fades_accumulated_frequency() {
+        let mut pattern = InteractionPattern::new()
+            .with_frequency(0.8)
+            .with_consistency(0.6);
+        accumulate_interactions(&mut pattern, &[], ENGAGEMENT_HALF_LIFE);
+        assert!((pattern.frequency - 0.4).abs() < 1e-5);
+        assert!((pattern.consistency - 0.6).abs() < f32::EPSILON);
+
+        let sample = InteractionPattern::new()
+            .with_frequency(0.4)
+            .with_consistency(1.0);
+        accumulate_interactions(&mut pattern, &[sample], Duration::years(5));
+        assert!((pattern.frequency - 0.4).abs() < 1e-4);
+        assert!((pattern.consistency - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn trust_collapse_estranges_only_positive_stages() {
+        use crate::types::EntityId;
+
+        let alice = EntityId::new("alice").unwrap();
+        let bob = EntityId::new("bob").unwrap();
+        let mut stranger = Relationship::try_between(alice, bob).unwrap();
+        stranger
+            .trustworthiness_mut(Direction::AToB)
+            .integrity_mut()
+            .add_delta(-0.3);
+        stranger
+            .trustworthiness_mut(Direction::AToB)
+            .benevolence_mut()
+            .add_delta(-0.3);
+        let mut friend = stranger.clone();
+        friend.set_stage(RelationshipStage::Established).unwrap();
+
+        assert_eq!(
+            evaluate_stage_transition(&mut stranger, &[], Duration::zero()),
+            Err(StageTransitionError::new(
+                RelationshipStage::Stranger,
+                RelationshipStage::Estranged
+            ))
+        );
+        assert_eq!(stranger.stage(), RelationshipStage::Stranger);
+        assert_eq!(
+            evaluate_stage_transition(&mut friend, &[], Duration::zero()),
+            Ok(Some(RelationshipStage::Estranged))
+        );
+    }
+
+    #[test]
+    fn hysteresis_holds_a_stage_just_below_its_thresholds() {
+        let t = thresholds(RelationshipStage::Intimate).unwrap();
+        let evidence = StageEvidence {
+            engagement: t.engagement - STAGE_HYSTERESIS / 2.0,
+            trust: t.trust,
+            depth: t.depth,
+        };
+        assert!(!evidence.meets(t, 0.0));
+        assert!(evidence.meets(t, STAGE_HYSTERESIS));
+    }
+}
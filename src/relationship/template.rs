@@ -0,0 +1,484 @@
+//! Schema-driven relationship templates.
+//!
+//! A template pre-fills the bond, stage, shared dimensions, directional
+//! trust, and interaction pattern of a new relationship so that the same
+//! kind of relationship ("typical sibling", "new coworker") starts from the
+//! same configuration every time. Every value can be overridden afterwards
+//! through the relationship's usual accessors.
+//!
+//! # Built-in Templates
+//!
+//! Trust columns are competence / benevolence / integrity. Support is the
+//! `support_willingness()` of A toward B from `compute_trust_decision` at a
+//! trust propensity of 0.5 and Medium stakes.
+//!
+//! | Schema | Bond | Stage | Affinity | Respect | Tension | Intimacy | History | Trust A→B | Trust B→A | Warmth A→B / B→A | Resentment A→B / B→A | Frequency | Consistency | Support |
+//! |--------|------|-------|----------|---------|---------|----------|---------|-----------|-----------|------------------|----------------------|-----------|-------------|-------------|
+//! | Peer | Peer | Acquaintance | 0.4 | 0.4 | 0.1 | 0.2 | 0.2 | 0.5 / 0.5 / 0.5 | 0.5 / 0.5 / 0.5 | 0.4 / 0.4 | 0.0 / 0.0 | 0.4 | 0.5 | 0.10-0.25 |
+//! | Mentor | Mentor | Established | 0.5 | 0.7 | 0.1 | 0.3 | 0.4 | 0.8 / 0.7 / 0.7 | 0.6 / 0.6 / 0.6 | 0.5 / 0.5 | 0.0 / 0.0 | 0.5 | 0.7 | 0.35-0.50 |
+//! | Subordinate | Authority | Acquaintance | 0.3 | 0.5 | 0.3 | 0.1 | 0.3 | 0.5 / 0.4 / 0.5 | 0.6 / 0.4 / 0.5 | 0.3 / 0.3 | 0.0 / 0.0 | 0.7 | 0.8 | 0.05-0.20 |
+//! | Romantic | Romantic | Intimate | 0.8 | 0.7 | 0.2 | 0.8 | 0.6 | 0.6 / 0.8 / 0.8 | 0.6 / 0.8 / 0.8 | 0.8 / 0.8 | 0.0 / 0.0 | 0.9 | 0.8 | 0.50-0.65 |
+//! | Family | Family | Established | 0.6 | 0.5 | 0.2 | 0.5 | 0.8 | 0.5 / 0.7 / 0.6 | 0.5 / 0.7 / 0.6 | 0.6 / 0.6 | 0.0 / 0.0 | 0.5 | 0.6 | 0.35-0.50 |
+//! | Nuclear | Family | Intimate | 0.7 | 0.6 | 0.3 | 0.6 | 0.9 | 0.6 / 0.8 / 0.7 | 0.6 / 0.8 / 0.7 | 0.7 / 0.7 | 0.0 / 0.0 | 0.8 | 0.8 | 0.50-0.65 |
+//! | Extended | Family | Acquaintance | 0.4 | 0.4 | 0.1 | 0.2 | 0.6 | 0.5 / 0.6 / 0.6 | 0.5 / 0.6 / 0.6 | 0.4 / 0.4 | 0.0 / 0.0 | 0.2 | 0.4 | 0.15-0.30 |
+//! | Rival | Rival | Acquaintance | 0.1 | 0.4 | 0.6 | 0.0 | 0.3 | 0.6 / 0.2 / 0.3 | 0.6 / 0.2 / 0.3 | 0.1 / 0.1 | 0.3 / 0.3 | 0.4 | 0.5 | 0.00-0.10 |
+//! | Sibling | Sibling | Established | 0.6 | 0.5 | 0.3 | 0.4 | 0.9 | 0.5 / 0.7 / 0.6 | 0.5 / 0.7 / 0.6 | 0.6 / 0.6 | 0.1 / 0.1 | 0.5 | 0.6 | 0.35-0.50 |
+//! | Coworker | Colleague | Acquaintance | 0.3 | 0.4 | 0.1 | 0.1 | 0.1 | 0.5 / 0.4 / 0.4 | 0.5 / 0.4 / 0.4 | 0.3 / 0.3 | 0.0 / 0.0 | 0.8 | 0.8 | 0.05-0.20 |
+//! | EstrangedParent | Parent | Estranged | 0.1 | 0.3 | 0.7 | 0.2 | 0.9 | 0.5 / 0.2 / 0.2 | 0.4 / 0.4 / 0.3 | 0.2 / 0.4 | 0.6 / 0.2 | 0.05 | 0.1 | 0.00-0.10 |
+//!
+//! In the EstrangedParent template, A is the adult child and B the parent.
+
+use crate::enums::{BondType, Direction, RelationshipSchema};
+use crate::relationship::{Relationship, RelationshipStage, TrustworthinessFactors};
+use std::hash::{Hash, Hasher};
+
+/// One direction's starting perceptions in a relationship template.
+///
+/// All values are bases in 0-1.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DirectionalTemplate {
+    /// Perceived competence of the other entity, applied to all domains.
+    pub competence: f32,
+    /// Perceived benevolence of the other entity.
+    pub benevolence: f32,
+    /// Perceived integrity of the other entity.
+    pub integrity: f32,
+    /// Warmth toward the other entity.
+    pub warmth: f32,
+    /// Resentment toward the other entity.
+    pub resentment: f32,
+}
+
+impl DirectionalTemplate {
+    /// Creates a directional template from trust and feeling bases.
+    #[must_use]
+    pub const fn new(
+        competence: f32,
+        benevolence: f32,
+        integrity: f32,
+        warmth: f32,
+        resentment: f32,
+    ) -> Self {
+        DirectionalTemplate {
+            competence,
+            benevolence,
+            integrity,
+            warmth,
+            resentment,
+        }
+    }
+
+    fn bits(&self) -> [u32; 5] {
+        [
+            self.competence.to_bits(),
+            self.benevolence.to_bits(),
+            self.integrity.to_bits(),
+            self.warmth.to_bits(),
+            self.resentment.to_bits(),
+        ]
+    }
+}
+
+impl Default for DirectionalTemplate {
+    /// Matches the bases of a relationship created with `try_between`.
+    fn default() -> Self {
+        DirectionalTemplate::new(0.3, 0.3, 0.3, 0.2, 0.0)
+    }
+}
+
+impl PartialEq for DirectionalTemplate {
+    fn eq(&self, other: &Self) -> bool {
+        self.bits() == other.bits()
+    }
+}
+
+impl Eq for DirectionalTemplate {}
+
+impl Hash for DirectionalTemplate {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bits().hash(state);
+    }
+}
+
+/// The starting configuration of a relationship.
+///
+/// Built-in schemas have fixed templates (see the module documentation).
+/// For anything else, fill in a template and wrap it in
+/// `RelationshipSchema::Custom`.
+///
+/// Values are compared bit-for-bit, so templates can be used as part of a
+/// hashable schema.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::enums::{BondType, RelationshipSchema};
+/// use behavioral_pathways::relationship::{
+///     DirectionalTemplate, Relationship, RelationshipStage, RelationshipTemplate,
+/// };
+/// use behavioral_pathways::types::EntityId;
+///
+/// let template = RelationshipTemplate {
+///     bond: Some(BondType::Friend),
+///     stage: RelationshipStage::Intimate,
+///     affinity: 0.9,
+///     a_to_b: DirectionalTemplate::new(0.7, 0.9, 0.8, 0.9, 0.0),
+///     b_to_a: DirectionalTemplate::new(0.7, 0.9, 0.8, 0.9, 0.0),
+///     ..RelationshipTemplate::default()
+/// };
+///
+/// let alice = EntityId::new("alice").unwrap();
+/// let bob = EntityId::new("bob").unwrap();
+/// let rel = Relationship::from_schema(alice, bob, RelationshipSchema::Custom(template)).unwrap();
+/// assert!(rel.has_bond(BondType::Friend));
+/// assert!((rel.shared().affinity_effective() - 0.9).abs() < f32::EPSILON);
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RelationshipTemplate {
+    /// Bond type, if any.
+    pub bond: Option<BondType>,
+    /// Starting stage.
+    pub stage: RelationshipStage,
+    /// Shared affinity base.
+    pub affinity: f32,
+    /// Shared respect base.
+    pub respect: f32,
+    /// Shared tension base.
+    pub tension: f32,
+    /// Shared intimacy base.
+    pub intimacy: f32,
+    /// Shared history base.
+    pub history: f32,
+    /// A's perceptions of and feelings toward B.
+    pub a_to_b: DirectionalTemplate,
+    /// B's perceptions of and feelings toward A.
+    pub b_to_a: DirectionalTemplate,
+    /// Interaction frequency (0 = rarely, 1 = daily).
+    pub frequency: f32,
+    /// Interaction consistency (0 = erratic, 1 = consistent).
+    pub consistency: f32,
+}
+
+impl RelationshipTemplate {
+    /// Returns the template for a schema.
+    ///
+    /// Custom schemas return their own template.
+    #[must_use]
+    pub fn for_schema(schema: RelationshipSchema) -> Self {
+        use RelationshipSchema as S;
+        use RelationshipStage::{Acquaintance, Established, Estranged, Intimate};
+
+        let symmetric = |bond, stage, shared: [f32; 5], trust: DirectionalTemplate, pattern| {
+            Self::from_parts(bond, stage, shared, trust, trust, pattern)
+        };
+        let d = DirectionalTemplate::new;
+
+        match schema {
+            S::Peer => symmetric(
+                BondType::Peer,
+                Acquaintance,
+                [0.4, 0.4, 0.1, 0.2, 0.2],
+                d(0.5, 0.5, 0.5, 0.4, 0.0),
+                [0.4, 0.5],
+            ),
+            S::Mentor => Self::from_parts(
+                BondType::Mentor,
+                Established,
+                [0.5, 0.7, 0.1, 0.3, 0.4],
+                d(0.8, 0.7, 0.7, 0.5, 0.0),
+                d(0.6, 0.6, 0.6, 0.5, 0.0),
+                [0.5, 0.7],
+            ),
+            S::Subordinate => Self::from_parts(
+                BondType::Authority,
+                Acquaintance,
+                [0.3, 0.5, 0.3, 0.1, 0.3],
+                d(0.5, 0.4, 0.5, 0.3, 0.0),
+                d(0.6, 0.4, 0.5, 0.3, 0.0),
+                [0.7, 0.8],
+            ),
+            S::Romantic => symmetric(
+                BondType::Romantic,
+                Intimate,
+                [0.8, 0.7, 0.2, 0.8, 0.6],
+                d(0.6, 0.8, 0.8, 0.8, 0.0),
+                [0.9, 0.8],
+            ),
+            S::Family => symmetric(
+                BondType::Family,
+                Established,
+                [0.6, 0.5, 0.2, 0.5, 0.8],
+                d(0.5, 0.7, 0.6, 0.6, 0.0),
+                [0.5, 0.6],
+            ),
+            S::Nuclear => symmetric(
+                BondType::Family,
+                Intimate,
+                [0.7, 0.6, 0.3, 0.6, 0.9],
+                d(0.6, 0.8, 0.7, 0.7, 0.0),
+                [0.8, 0.8],
+            ),
+            S::Extended => symmetric(
+                BondType::Family,
+                Acquaintance,
+                [0.4, 0.4, 0.1, 0.2, 0.6],
+                d(0.5, 0.6, 0.6, 0.4, 0.0),
+                [0.2, 0.4],
+            ),
+            S::Rival => symmetric(
+                BondType::Rival,
+                Acquaintance,
+                [0.1, 0.4, 0.6, 0.0, 0.3],
+                d(0.6, 0.2, 0.3, 0.1, 0.3),
+                [0.4, 0.5],
+            ),
+            S::Sibling => symmetric(
+                BondType::Sibling,
+                Established,
+                [0.6, 0.5, 0.3, 0.4, 0.9],
+                d(0.5, 0.7, 0.6, 0.6, 0.1),
+                [0.5, 0.6],
+            ),
+            S::Coworker => symmetric(
+                BondType::Colleague,
+                Acquaintance,
+                [0.3, 0.4, 0.1, 0.1, 0.1],
+                d(0.5, 0.4, 0.4, 0.3, 0.0),
+                [0.8, 0.8],
+            ),
+            S::EstrangedParent => Self::from_parts(
+                BondType::Parent,
+                Estranged,
+                [0.1, 0.3, 0.7, 0.2, 0.9],
+                d(0.5, 0.2, 0.2, 0.2, 0.6),
+                d(0.4, 0.4, 0.3, 0.4, 0.2),
+                [0.05, 0.1],
+            ),
+            S::Custom(template) => template,
+        }
+    }
+
+    /// Builds a template from the compact table layout used above.
+    ///
+    /// `shared` is affinity, respect, tension, intimacy, history and
+    /// `pattern` is frequency, consistency.
+    fn from_parts(
+        bond: BondType,
+        stage: RelationshipStage,
+        shared: [f32; 5],
+        a_to_b: DirectionalTemplate,
+        b_to_a: DirectionalTemplate,
+        pattern: [f32; 2],
+    ) -> Self {
+        let [affinity, respect, tension, intimacy, history] = shared;
+        let [frequency, consistency] = pattern;
+        RelationshipTemplate {
+            bond: Some(bond),
+            stage,
+            affinity,
+            respect,
+            tension,
+            intimacy,
+            history,
+            a_to_b,
+            b_to_a,
+            frequency,
+            consistency,
+        }
+    }
+
+    /// Sets a relationship's dimension bases, trust, and interaction
+    /// pattern from this template. Bonds and stage are set by
+    /// `Relationship::from_schema`.
+    pub(crate) fn apply_dimensions(&self, relationship: &mut Relationship) {
+        let shared = relationship.shared_mut();
+        shared.affinity_mut().set_base(self.affinity);
+        shared.respect_mut().set_base(self.respect);
+        shared.tension_mut().set_base(self.tension);
+        shared.intimacy_mut().set_base(self.intimacy);
+        shared.history_mut().set_base(self.history);
+
+        let directions = [
+            (Direction::AToB, self.a_to_b),
+            (Direction::BToA, self.b_to_a),
+        ];
+        for (direction, template) in directions {
+            *relationship.trustworthiness_mut(direction) = TrustworthinessFactors::with_bases(
+                template.competence,
+                template.benevolence,
+                template.integrity,
+            );
+            let feelings = relationship.directional_mut(direction);
+            feelings.warmth_mut().set_base(template.warmth);
+            feelings.resentment_mut().set_base(template.resentment);
+        }
+
+        let pattern = relationship.pattern_mut();
+        pattern.frequency = self.frequency.clamp(0.0, 1.0);
+        pattern.consistency = self.consistency.clamp(0.0, 1.0);
+    }
+
+    fn bits(&self) -> [u32; 7] {
+        [
+            self.affinity.to_bits(),
+            self.respect.to_bits(),
+            self.tension.to_bits(),
+            self.intimacy.to_bits(),
+            self.history.to_bits(),
+            self.frequency.to_bits(),
+            self.consistency.to_bits(),
+        ]
+    }
+}
+
+impl Default for RelationshipTemplate {
+    /// Matches a relationship created with `try_between`: no bond, a
+    /// stranger, and neutral dimensions.
+    fn default() -> Self {
+        RelationshipTemplate {
+            bond: None,
+            stage: RelationshipStage::Stranger,
+            affinity: 0.1,
+            respect: 0.2,
+            tension: 0.0,
+            intimacy: 0.0,
+            history: 0.0,
+            a_to_b: DirectionalTemplate::default(),
+            b_to_a: DirectionalTemplate::default(),
+            frequency: 0.0,
+            consistency: 0.0,
+        }
+    }
+}
+
+impl PartialEq for RelationshipTemplate {
+    fn eq(&self, other: &Self) -> bool {
+        self.bond == other.bond
+            && self.stage == other.stage
+            && self.a_to_b == other.a_to_b
+            && self.b_to_a == other.b_to_a
+            && self.bits() == other.bits()
+    }
+}
+
+impl Eq for RelationshipTemplate {}
+
+impl Hash for RelationshipTemplate {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bond.hash(state);
+        self.stage.hash(state);
+        self.a_to_b.hash(state);
+        self.b_to_a.hash(state);
+        self.bits().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::relationship::StakesLevel;
+    use crate::types::EntityId;
+
+    fn from_schema(schema: RelationshipSchema) -> Relationship {
+        let a = EntityId::new("a").unwrap();
+        let b = EntityId::new("b").unwrap();
+        Relationship::from_schema(a, b, schema).unwrap()
+    }
+
+    #[test]
+    fn builtin_schemas_fall_in_documented_support_bands() {
+        let bands = [
+            (RelationshipSchema::Peer, 0.10, 0.25),
+            (RelationshipSchema::Mentor, 0.35, 0.50),
+            (RelationshipSchema::Subordinate, 0.05, 0.20),
+            (RelationshipSchema::Romantic, 0.50, 0.65),
+            (RelationshipSchema::Family, 0.35, 0.50),
+            (RelationshipSchema::Nuclear, 0.50, 0.65),
+            (RelationshipSchema::Extended, 0.15, 0.30),
+            (RelationshipSchema::Rival, 0.00, 0.10),
+            (RelationshipSchema::Sibling, 0.35, 0.50),
+            (RelationshipSchema::Coworker, 0.05, 0.20),
+            (RelationshipSchema::EstrangedParent, 0.00, 0.10),
+        ];
+        assert_eq!(bands.len(), RelationshipSchema::all().len());
+
+        for (schema, min, max) in bands {
+            let decision = from_schema(schema).compute_trust_decision(
+                Direction::AToB,
+                0.5,
+                StakesLevel::Medium,
+            );
+            let willingness = decision.support_willingness();
+            assert!(
+                (min..=max).contains(&willingness),
+                "{} willingness {} outside {}-{}",
+                schema,
+                willingness,
+                min,
+                max
+            );
+        }
+    }
+
+    #[test]
+    fn from_schema_prefills_and_can_be_overridden() {
+        let mut rel = from_schema(RelationshipSchema::EstrangedParent);
+
+        assert_eq!(rel.schema(), RelationshipSchema::EstrangedParent);
+        assert_eq!(rel.stage(), RelationshipStage::Estranged);
+        assert!(rel.has_bond(BondType::Parent));
+        assert!((rel.shared().tension_effective() - 0.7).abs() < f32::EPSILON);
+        assert!((rel.directional(Direction::AToB).resentment_effective() - 0.6).abs() < 1e-6);
+        assert!((rel.directional(Direction::BToA).warmth_effective() - 0.4).abs() < 1e-6);
+        assert!((rel.trustworthiness(Direction::BToA).benevolence_effective() - 0.4).abs() < 1e-6);
+        assert!((rel.pattern().frequency - 0.05).abs() < f32::EPSILON);
+
+        rel.set_stage(RelationshipStage::Acquaintance).unwrap();
+        rel.shared_mut().tension_mut().set_base(0.2);
+        assert_eq!(rel.stage(), RelationshipStage::Acquaintance);
+        assert!((rel.shared().tension_effective() - 0.2).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn default_template_matches_try_between() {
+        let a = EntityId::new("a").unwrap();
+        let b = EntityId::new("b").unwrap();
+        let plain = Relationship::try_between(a, b).unwrap();
+        let custom = from_schema(RelationshipSchema::Custom(RelationshipTemplate::default()));
+
+        assert_eq!(custom.stage(), plain.stage());
+        assert_eq!(custom.bonds(), plain.bonds());
+        assert_eq!(custom.shared(), plain.shared());
+        assert_eq!(
+            custom.directional(Direction::AToB),
+            plain.directional(Direction::AToB)
+        );
+        assert_eq!(
+            custom.trustworthiness(Direction::BToA),
+            plain.trustworthiness(Direction::BToA)
+        );
+        assert_eq!(custom.pattern(), plain.pattern());
+    }
+
+    #[test]
+    fn custom_schema_equality_and_lookup() {
+        let template = RelationshipTemplate {
+            tension: 0.9,
+            ..RelationshipTemplate::default()
+        };
+        let schema = RelationshipSchema::Custom(template);
+
+        assert_eq!(RelationshipTemplate::for_schema(schema), template);
+        assert_eq!(schema, RelationshipSchema::Custom(template));
+        assert_ne!(
+            schema,
+            RelationshipSchema::Custom(RelationshipTemplate::default())
+        );
+        assert_ne!(
+            RelationshipTemplate::for_schema(RelationshipSchema::Sibling),
+            RelationshipTemplate::for_schema(RelationshipSchema::Family)
+        );
+    }
+}
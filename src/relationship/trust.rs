@@ -35,7 +35,9 @@
 //! - **Decision certainty**: How confident we are in our willingness assessment
 //! - **Trustee confidence**: How certain we are about the trustee's attributes
 
-use crate::relationship::{RelationshipStage, StakesLevel, TrustDecision, TrustworthinessFactors};
+use crate::relationship::{
+    RelationshipStage, StakesLevel, TrustDecision, TrustDecisionBreakdown, TrustworthinessFactors,
+};
 
 /// Configuration for Trust computation weights.
 #[derive(Debug, Clone, PartialEq)]
@@ -240,9 +242,9 @@ impl Trust {
         (self.base_risk + stakes_contribution + self.stage_risk_modifier).clamp(0.0, 1.0)
     }
 
-    /// Computes willingness for a domain using the Mayer formula.
+    /// Computes the weighted terms of a decision at the given stakes.
     ///
-    /// Formula:
+    /// Formula, per domain:
     /// ```text
     /// willingness = context_multiplier * (
     ///                 propensity_weight * propensity
@@ -250,13 +252,22 @@ impl Trust {
     ///             )
     ///             - risk_weight * perceived_risk
     /// ```
-    fn compute_willingness(&self, domain_trustworthiness: f32, perceived_risk: f32) -> f32 {
-        let base = self.weights.propensity_weight * self.propensity
-            + self.weights.trustworthiness_weight * domain_trustworthiness;
-        let willingness =
-            (base * self.context_multiplier) - self.weights.risk_weight * perceived_risk;
-
-        willingness.clamp(0.0, 1.0)
+    #[must_use]
+    pub fn compute_breakdown(&self, stakes: StakesLevel) -> TrustDecisionBreakdown {
+        TrustDecisionBreakdown {
+            stage: self.stage,
+            stakes,
+            propensity: self.propensity,
+            competence: self.perceived_competence,
+            benevolence: self.perceived_benevolence,
+            integrity: self.perceived_integrity,
+            propensity_weight: self.weights.propensity_weight,
+            trustworthiness_weight: self.weights.trustworthiness_weight,
+            risk_weight: self.weights.risk_weight,
+            context_multiplier: self.context_multiplier,
+            perceived_risk: self.compute_risk(stakes),
+            low_stakes_risk: self.compute_risk(StakesLevel::Low),
+        }
     }
 
     /// Computes certainty in the trust decision (decision_certainty).
@@ -328,22 +339,10 @@ impl Trust {
     /// ```
     #[must_use]
     pub fn compute_decision(&self, stakes: StakesLevel) -> TrustDecision {
-        let perceived_risk = self.compute_risk(stakes);
-
-        let task_willingness = self.compute_willingness(self.perceived_competence, perceived_risk);
-        let support_willingness =
-            self.compute_willingness(self.perceived_benevolence, perceived_risk);
-        let disclosure_willingness =
-            self.compute_willingness(self.perceived_integrity, perceived_risk);
-        let decision_certainty = self.compute_decision_certainty();
-        let trustee_confidence = self.compute_trustee_confidence();
-
-        TrustDecision::new(
-            task_willingness,
-            support_willingness,
-            disclosure_willingness,
-            decision_certainty,
-            trustee_confidence,
+        TrustDecision::explained(
+            self.compute_breakdown(stakes),
+            self.compute_decision_certainty(),
+            self.compute_trustee_confidence(),
         )
     }
 }
@@ -351,6 +350,7 @@ impl Trust {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::enums::TrustDomain;
 
     fn default_trustworthiness() -> TrustworthinessFactors {
         TrustworthinessFactors::new()
@@ -489,6 +489,24 @@ mod tests {
         assert!(decision.confidence() <= 1.0);
     }
 
+    #[test]
+    fn compute_decision_is_explained_by_its_breakdown() {
+        let tw = default_trustworthiness();
+        let trust = Trust::new(0.6, &tw, 0.2, RelationshipStage::Established, 0.5, 1.2);
+
+        let decision = trust.compute_decision(StakesLevel::High);
+        let breakdown = decision.explanation().unwrap();
+
+        assert_eq!(breakdown, trust.compute_breakdown(StakesLevel::High));
+        assert!(
+            (breakdown.willingness(TrustDomain::Support) - decision.support_willingness()).abs()
+                < f32::EPSILON
+        );
+        assert!(
+            (breakdown.low_stakes_risk - trust.compute_risk(StakesLevel::Low)).abs() < f32::EPSILON
+        );
+    }
+
     #[test]
     fn higher_propensity_increases_willingness() {
         let tw = default_trustworthiness();
@@ -3,6 +3,14 @@
 //! Per trust theory, context includes multiple dimensions that affect willingness
 //! to be vulnerable. Rather than collapsing these to a single scalar multiplier,
 //! we track them explicitly to preserve information about context type.
+//!
+//! Stakes are part of the context too: the same disclosure feels riskier to
+//! someone who already sees themselves as a burden. `TrustContext::from_state`
+//! derives stakes and vulnerability salience from the trustor's current state.
+
+use crate::enums::{MentalHealthPath, NeedsPath, SocialCognitionPath, StatePath, TrustDomain};
+use crate::relationship::StakesLevel;
+use crate::simulation::ComputedState;
 
 /// Situational context that moderates trust decisions.
 ///
@@ -43,6 +51,13 @@ pub struct TrustContext {
     /// Cultural expectations about trust in this context (0-1).
     /// Higher values indicate culture expects/encourages trust.
     cultural_expectations: f32,
+
+    /// Stakes of the trusting action.
+    stakes: StakesLevel,
+
+    /// How salient the trustor's own vulnerability is (0-1).
+    /// Higher values mean exposure feels more threatening.
+    vulnerability_salience: f32,
 }
 
 impl TrustContext {
@@ -57,9 +72,90 @@ impl TrustContext {
             time_pressure: 0.5,
             institutional_support: 0.5,
             cultural_expectations: 0.5,
+            stakes: StakesLevel::default(),
+            vulnerability_salience: 0.0,
         }
     }
 
+    /// Derives a TrustContext from the trustor's current state.
+    ///
+    /// Situational dimensions stay neutral (0.5); only stakes and
+    /// vulnerability salience are derived. Salience is a weighted load of
+    /// the state values that make exposure feel threatening in `domain`:
+    ///
+    /// - Disclosure: 0.6 * perceived burdensomeness + 0.4 * self-hate
+    /// - Support: 0.7 * perceived burdensomeness + 0.3 * perceived liability
+    /// - Task: 0.6 * stress + 0.4 * perceived burdensomeness
+    ///
+    /// Stakes come from `base + (1 - base) * salience`, where the domain
+    /// base is 0.0 for tasks, 0.1 for support, and 0.25 for disclosure,
+    /// banded at 0.25 / 0.5 / 0.75 into Low, Medium, High, and Critical.
+    /// While an external alert puts the entity in crisis (see
+    /// `ComputedState::is_in_crisis()`), support salience is 1.0, so
+    /// support-seeking is predicted at Critical stakes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::enums::{Species, TrustDomain};
+    /// use behavioral_pathways::relationship::{StakesLevel, TrustContext};
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::types::Timestamp;
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new().id("ana").species(Species::Human).build().unwrap();
+    /// let id = sim.add_entity(entity, reference);
+    ///
+    /// let state = sim.entity(&id).unwrap().state_at(reference);
+    /// let context = TrustContext::from_state(&state, TrustDomain::Disclosure);
+    /// assert_eq!(context.stakes(), StakesLevel::Medium);
+    /// ```
+    #[must_use]
+    pub fn from_state(state: &ComputedState, domain: TrustDomain) -> Self {
+        let value = |path: StatePath| state.get_effective(path) as f32;
+        let burdensomeness = value(StatePath::MentalHealth(
+            MentalHealthPath::PerceivedBurdensomeness,
+        ));
+
+        let (base, load) = match domain {
+            TrustDomain::Disclosure => {
+                let self_hate = value(StatePath::SocialCognition(SocialCognitionPath::SelfHate));
+                (0.25, 0.6 * burdensomeness + 0.4 * self_hate)
+            }
+            TrustDomain::Support => {
+                let liability = value(StatePath::SocialCognition(
+                    SocialCognitionPath::PerceivedLiability,
+                ));
+                (0.1, 0.7 * burdensomeness + 0.3 * liability)
+            }
+            TrustDomain::Task => {
+                let stress = value(StatePath::Needs(NeedsPath::Stress));
+                (0.0, 0.6 * stress + 0.4 * burdensomeness)
+            }
+        };
+        let salience = if domain == TrustDomain::Support && state.is_in_crisis() {
+            1.0
+        } else {
+            load.clamp(0.0, 1.0)
+        };
+        let score = base + (1.0 - base) * salience;
+        let stakes = if score < 0.25 {
+            StakesLevel::Low
+        } else if score < 0.5 {
+            StakesLevel::Medium
+        } else if score < 0.75 {
+            StakesLevel::High
+        } else {
+            StakesLevel::Critical
+        };
+
+        TrustContext::new()
+            .with_stakes(stakes)
+            .with_vulnerability_salience(salience)
+    }
+
     /// Sets the social norms dimension.
     #[must_use]
     pub fn with_social_norms(mut self, value: f32) -> Self {
@@ -95,6 +191,20 @@ impl TrustContext {
         self
     }
 
+    /// Sets the stakes of the trusting action.
+    #[must_use]
+    pub fn with_stakes(mut self, stakes: StakesLevel) -> Self {
+        self.stakes = stakes;
+        self
+    }
+
+    /// Sets the vulnerability salience dimension.
+    #[must_use]
+    pub fn with_vulnerability_salience(mut self, value: f32) -> Self {
+        self.vulnerability_salience = value.clamp(0.0, 1.0);
+        self
+    }
+
     /// Returns the social norms value.
     #[must_use]
     pub fn social_norms(&self) -> f32 {
@@ -125,6 +235,18 @@ impl TrustContext {
         self.cultural_expectations
     }
 
+    /// Returns the stakes of the trusting action.
+    #[must_use]
+    pub fn stakes(&self) -> StakesLevel {
+        self.stakes
+    }
+
+    /// Returns the vulnerability salience value.
+    #[must_use]
+    pub fn vulnerability_salience(&self) -> f32 {
+        self.vulnerability_salience
+    }
+
     /// Computes the context multiplier from all dimensions.
     ///
     /// The multiplier is computed as a weighted combination of all dimensions,
@@ -181,6 +303,8 @@ impl TrustContext {
             time_pressure: 0.5,
             institutional_support: trust_encouragement,
             cultural_expectations: trust_encouragement,
+            stakes: StakesLevel::default(),
+            vulnerability_salience: 0.0,
         }
     }
 }
@@ -295,6 +419,90 @@ mod tests {
         assert!((computed - 1.0).abs() < 0.1);
     }
 
+    fn computed_state_for(social_cognition: crate::state::SocialCognition) -> ComputedState {
+        use crate::entity::EntityBuilder;
+        use crate::enums::Species;
+        use crate::simulation::Simulation;
+        use crate::types::Timestamp;
+
+        let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let mut sim = Simulation::new(reference);
+        let entity = EntityBuilder::new()
+            .id("trustor")
+            .species(Species::Human)
+            .social_cognition(social_cognition)
+            .build()
+            .unwrap();
+        let id = sim.add_entity(entity, reference);
+        sim.entity(&id).unwrap().state_at(reference)
+    }
+
+    #[test]
+    fn new_has_low_stakes_and_no_salience() {
+        let ctx = TrustContext::new();
+        assert_eq!(ctx.stakes(), StakesLevel::Low);
+        assert!(ctx.vulnerability_salience().abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn with_stakes_and_salience_set_values() {
+        let ctx = TrustContext::new()
+            .with_stakes(StakesLevel::Critical)
+            .with_vulnerability_salience(1.4);
+        assert_eq!(ctx.stakes(), StakesLevel::Critical);
+        assert!((ctx.vulnerability_salience() - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn from_state_neutral_trustor_uses_domain_base_stakes() {
+        let state = computed_state_for(crate::state::SocialCognition::new());
+
+        let disclosure = TrustContext::from_state(&state, TrustDomain::Disclosure);
+        let task = TrustContext::from_state(&state, TrustDomain::Task);
+        let support = TrustContext::from_state(&state, TrustDomain::Support);
+
+        assert_eq!(disclosure.stakes(), StakesLevel::Medium);
+        assert_eq!(task.stakes(), StakesLevel::Low);
+        assert_eq!(support.stakes(), StakesLevel::Low);
+        // Situational dimensions stay neutral
+        assert_eq!(
+            disclosure.compute_multiplier(),
+            TrustContext::new().compute_multiplier()
+        );
+    }
+
+    #[test]
+    fn from_state_burdened_trustor_raises_disclosure_stakes() {
+        let calm = computed_state_for(crate::state::SocialCognition::new());
+        let burdened = computed_state_for(
+            crate::state::SocialCognition::new()
+                .with_perceived_liability_base(0.9)
+                .with_self_hate_base(0.9),
+        );
+
+        let calm_ctx = TrustContext::from_state(&calm, TrustDomain::Disclosure);
+        let burdened_ctx = TrustContext::from_state(&burdened, TrustDomain::Disclosure);
+
+        assert!(burdened_ctx.vulnerability_salience() > calm_ctx.vulnerability_salience());
+        assert!(
+            burdened_ctx.stakes().risk_contribution() > calm_ctx.stakes().risk_contribution()
+        );
+    }
+
+    #[test]
+    fn from_state_burdened_trustor_raises_support_stakes() {
+        let burdened = computed_state_for(
+            crate::state::SocialCognition::new()
+                .with_perceived_liability_base(1.0)
+                .with_self_hate_base(1.0),
+        );
+        let ctx = TrustContext::from_state(&burdened, TrustDomain::Support);
+        assert!(matches!(
+            ctx.stakes(),
+            StakesLevel::High | StakesLevel::Critical
+        ));
+    }
+
     #[test]
     fn clone_and_equality() {
         let c1 = TrustContext::new().with_social_norms(0.7);
@@ -16,6 +16,16 @@
 //!
 //! - **Decision Certainty**: How confident we are in our willingness assessment.
 //!   Based on relationship history and stage.
+//!
+//! # Explanations
+//!
+//! A computed decision carries a `TrustDecisionBreakdown` of the weighted
+//! terms its willingness values are the sum of, so a surprising number can
+//! be traced back to propensity, trustworthiness, risk, or stakes.
+
+use crate::enums::TrustDomain;
+use crate::relationship::{RelationshipStage, StakesLevel};
+use std::fmt;
 
 /// A computed trust decision for a specific trustor-trustee relationship.
 ///
@@ -87,6 +97,10 @@ pub struct TrustDecision {
     /// Per Mayer: you can be confident about someone's attributes but still not trust
     /// them if the stakes are too high.
     trustee_confidence: f32,
+
+    /// The weighted terms behind the willingness values, for computed
+    /// decisions.
+    explanation: Option<TrustDecisionBreakdown>,
 }
 
 impl TrustDecision {
@@ -124,6 +138,27 @@ impl TrustDecision {
             disclosure_willingness: disclosure_willingness.clamp(0.0, 1.0),
             decision_certainty: decision_certainty.clamp(0.0, 1.0),
             trustee_confidence: trustee_confidence.clamp(0.0, 1.0),
+            explanation: None,
+        }
+    }
+
+    /// Creates a TrustDecision whose willingness values come from a
+    /// breakdown, which is kept as its explanation.
+    #[must_use]
+    pub(crate) fn explained(
+        breakdown: TrustDecisionBreakdown,
+        decision_certainty: f32,
+        trustee_confidence: f32,
+    ) -> Self {
+        TrustDecision {
+            explanation: Some(breakdown),
+            ..TrustDecision::new(
+                breakdown.willingness(TrustDomain::Task),
+                breakdown.willingness(TrustDomain::Support),
+                breakdown.willingness(TrustDomain::Disclosure),
+                decision_certainty,
+                trustee_confidence,
+            )
         }
     }
 
@@ -138,6 +173,7 @@ impl TrustDecision {
             disclosure_willingness: 0.0,
             decision_certainty: 0.0,
             trustee_confidence: 0.0,
+            explanation: None,
         }
     }
 
@@ -152,6 +188,7 @@ impl TrustDecision {
             disclosure_willingness: 1.0,
             decision_certainty: 1.0,
             trustee_confidence: 1.0,
+            explanation: None,
         }
     }
 
@@ -200,6 +237,38 @@ impl TrustDecision {
         self.trustee_confidence
     }
 
+    /// Returns the weighted terms the willingness values are the sum of.
+    ///
+    /// Present for decisions computed by `Relationship` or `Trust`, and
+    /// `None` for decisions built directly with `new`, `no_trust`, or
+    /// `full_trust`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::enums::{Direction, TrustDomain};
+    /// use behavioral_pathways::relationship::{Relationship, StakesLevel};
+    /// use behavioral_pathways::types::EntityId;
+    ///
+    /// let alice = EntityId::new("alice").unwrap();
+    /// let bob = EntityId::new("bob").unwrap();
+    /// let rel = Relationship::try_between(alice, bob).unwrap();
+    ///
+    /// let decision = rel.compute_trust_decision(Direction::AToB, 0.7, StakesLevel::Low);
+    /// let breakdown = decision.explanation().unwrap();
+    /// let total = breakdown.propensity_term()
+    ///     + breakdown.trustworthiness_term(TrustDomain::Task)
+    ///     + breakdown.risk_term()
+    ///     + breakdown.stakes_adjustment()
+    ///     + breakdown.clamp_adjustment(TrustDomain::Task);
+    /// assert!((total - decision.task_willingness()).abs() < 1e-6);
+    /// println!("{breakdown}");
+    /// ```
+    #[must_use]
+    pub fn explanation(&self) -> Option<TrustDecisionBreakdown> {
+        self.explanation
+    }
+
     /// Returns the confidence in this decision.
     ///
     /// Deprecated: Use `decision_certainty()` for clarity. This method
@@ -275,6 +344,136 @@ impl TrustDecision {
     }
 }
 
+/// The weighted terms behind a computed trust decision.
+///
+/// Each domain's willingness is the sum of the propensity term, the
+/// domain's trustworthiness term (competence for tasks, benevolence for
+/// support, integrity for disclosure), the risk term, the stakes
+/// adjustment, and a clamp adjustment that keeps the result in [0, 1].
+/// The propensity and trustworthiness terms are scaled by the context
+/// multiplier; the risk and stakes terms are zero or negative.
+///
+/// The `Display` output is a one-sentence summary.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrustDecisionBreakdown {
+    /// Relationship stage the weights come from.
+    pub stage: RelationshipStage,
+    /// Stakes the risk was assessed at.
+    pub stakes: StakesLevel,
+    /// The trustor's propensity (0-1).
+    pub propensity: f32,
+    /// Perceived competence of the trustee (0-1).
+    pub competence: f32,
+    /// Perceived benevolence of the trustee (0-1).
+    pub benevolence: f32,
+    /// Perceived integrity of the trustee (0-1).
+    pub integrity: f32,
+    /// Weight of propensity at this stage.
+    pub propensity_weight: f32,
+    /// Weight of trustworthiness at this stage.
+    pub trustworthiness_weight: f32,
+    /// Weight of perceived risk.
+    pub risk_weight: f32,
+    /// Situational multiplier on the propensity and trustworthiness terms
+    /// (0-2).
+    pub context_multiplier: f32,
+    /// Perceived risk at the assessed stakes (0-1).
+    pub perceived_risk: f32,
+    /// Perceived risk the same trustor would see at low stakes (0-1).
+    pub low_stakes_risk: f32,
+}
+
+impl TrustDecisionBreakdown {
+    /// Returns the propensity term, shared by every domain.
+    #[must_use]
+    pub fn propensity_term(&self) -> f32 {
+        self.propensity_weight * self.propensity * self.context_multiplier
+    }
+
+    /// Returns the perceived trustworthiness component a domain reads.
+    #[must_use]
+    pub fn trustworthiness_component(&self, domain: TrustDomain) -> f32 {
+        match domain {
+            TrustDomain::Task => self.competence,
+            TrustDomain::Support => self.benevolence,
+            TrustDomain::Disclosure => self.integrity,
+        }
+    }
+
+    /// Returns the trustworthiness term for a domain.
+    #[must_use]
+    pub fn trustworthiness_term(&self, domain: TrustDomain) -> f32 {
+        self.trustworthiness_weight
+            * self.trustworthiness_component(domain)
+            * self.context_multiplier
+    }
+
+    /// Returns the risk term at low stakes, shared by every domain.
+    #[must_use]
+    pub fn risk_term(&self) -> f32 {
+        -self.risk_weight * self.low_stakes_risk
+    }
+
+    /// Returns the further risk term the stakes add above low stakes.
+    #[must_use]
+    pub fn stakes_adjustment(&self) -> f32 {
+        -self.risk_weight * (self.perceived_risk - self.low_stakes_risk)
+    }
+
+    /// Returns what clamping the domain's willingness to [0, 1] added.
+    #[must_use]
+    pub fn clamp_adjustment(&self, domain: TrustDomain) -> f32 {
+        self.willingness(domain)
+            - (self.propensity_term()
+                + self.trustworthiness_term(domain)
+                + self.risk_term()
+                + self.stakes_adjustment())
+    }
+
+    /// Returns the willingness for a domain, the sum of its terms.
+    #[must_use]
+    pub fn willingness(&self, domain: TrustDomain) -> f32 {
+        let base = self.propensity_weight * self.propensity
+            + self.trustworthiness_weight * self.trustworthiness_component(domain);
+        (base * self.context_multiplier - self.risk_weight * self.perceived_risk).clamp(0.0, 1.0)
+    }
+
+    /// Returns true if the propensity term outweighs the domain's
+    /// trustworthiness term.
+    #[must_use]
+    pub fn is_propensity_dominated(&self, domain: TrustDomain) -> bool {
+        self.propensity_term() > self.trustworthiness_term(domain)
+    }
+}
+
+impl fmt::Display for TrustDecisionBreakdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let leading = if self.is_propensity_dominated(TrustDomain::Task) {
+            "propensity"
+        } else {
+            "trustworthiness"
+        };
+        write!(
+            f,
+            "{} at {} stakes, led by {}: propensity {:.2} plus trustworthiness \
+             {:.2}/{:.2}/{:.2} (competence/benevolence/integrity), less {:.2} for risk \
+             and {:.2} for stakes, gives task {:.2}, support {:.2}, disclosure {:.2}",
+            self.stage,
+            self.stakes,
+            leading,
+            self.propensity_term(),
+            self.trustworthiness_term(TrustDomain::Task),
+            self.trustworthiness_term(TrustDomain::Support),
+            self.trustworthiness_term(TrustDomain::Disclosure),
+            -self.risk_term(),
+            -self.stakes_adjustment(),
+            self.willingness(TrustDomain::Task),
+            self.willingness(TrustDomain::Support),
+            self.willingness(TrustDomain::Disclosure),
+        )
+    }
+}
+
 impl Default for TrustDecision {
     fn default() -> Self {
         // Moderate starting point
@@ -286,6 +485,15 @@ impl Default for TrustDecision {
 mod tests {
     use super::*;
 
+    #[test]
+    fn hand_built_decisions_have_no_explanation() {
+        assert!(TrustDecision::new(0.5, 0.5, 0.5, 0.5, 0.5)
+            .explanation()
+            .is_none());
+        assert!(TrustDecision::no_trust().explanation().is_none());
+        assert!(TrustDecision::full_trust().explanation().is_none());
+    }
+
     #[test]
     fn new_creates_with_values() {
         let decision = TrustDecision::new(0.6, 0.7, 0.5, 0.8, 0.7);
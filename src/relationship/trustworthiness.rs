@@ -39,12 +39,124 @@ const REBUILDING_POSITIVE_WEIGHT: f32 = 0.7;
 /// Rebuilding window after a negative antecedent.
 const REBUILDING_WINDOW: Duration = Duration::days(180);
 
+/// Weight of the neutral prior that recency-weighted evidence is averaged
+/// against, so a single faint antecedent cannot swing a factor fully.
+const WEIGHTED_EVIDENCE_PRIOR: f32 = 1.0;
+
 /// Half-life for antecedent temporal decay in days.
 /// Antecedent impact decays exponentially: impact = base * exp(-age_days / half_life_days).
 /// Per trust-antecedents spec, ~180 days half-life means an antecedent from
 /// 6 months ago has ~50% the impact of a recent one.
 const ANTECEDENT_DECAY_HALF_LIFE_DAYS: f64 = 180.0;
 
+/// Evidential half-lives of trust antecedents, by antecedent type.
+///
+/// Used by `Relationship::trustworthiness_at` to weight each antecedent
+/// by its age: an antecedent one half-life old counts half as much as one
+/// recorded at the query time. Integrity evidence is slowest to fade and
+/// competence evidence fastest.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::relationship::{AntecedentHalfLives, AntecedentType};
+/// use behavioral_pathways::types::Duration;
+///
+/// let half_lives = AntecedentHalfLives::default();
+/// let integrity = half_lives.half_life(AntecedentType::Integrity);
+/// assert!(integrity > half_lives.half_life(AntecedentType::Ability));
+///
+/// let weight = half_lives.weight(AntecedentType::Ability, Duration::days(180));
+/// assert!((weight - 0.5).abs() < 1e-6);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AntecedentHalfLives {
+    /// Half-life of ability (competence) evidence.
+    pub ability: Duration,
+    /// Half-life of benevolence evidence.
+    pub benevolence: Duration,
+    /// Half-life of integrity evidence.
+    pub integrity: Duration,
+}
+
+impl Default for AntecedentHalfLives {
+    fn default() -> Self {
+        AntecedentHalfLives {
+            ability: Duration::days(180),
+            benevolence: Duration::days(365),
+            integrity: Duration::days(730),
+        }
+    }
+}
+
+impl AntecedentHalfLives {
+    /// Returns the half-life for an antecedent type.
+    #[must_use]
+    pub fn half_life(&self, antecedent_type: AntecedentType) -> Duration {
+        match antecedent_type {
+            AntecedentType::Ability => self.ability,
+            AntecedentType::Benevolence => self.benevolence,
+            AntecedentType::Integrity => self.integrity,
+        }
+    }
+
+    /// Returns the recency weight (0-1] of an antecedent of the given type
+    /// and age.
+    #[must_use]
+    pub fn weight(&self, antecedent_type: AntecedentType, age: Duration) -> f32 {
+        let half_life = self
+            .half_life(antecedent_type)
+            .as_days_f64()
+            .max(1.0 / 24.0);
+        0.5_f64.powf(age.as_days_f64() / half_life) as f32
+    }
+}
+
+/// Recency-weighted antecedent evidence behind one trustworthiness factor.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct WeightedEvidence {
+    /// Number of antecedents contributing.
+    pub antecedents: usize,
+    /// Sum of the antecedents' recency weights.
+    pub total_weight: f32,
+    /// Sum of signed, asymmetry-weighted magnitudes times recency weights.
+    pub weighted_sum: f32,
+    /// Resulting delta from the factor's base:
+    /// `weighted_sum / (total_weight + 1)`, where the 1 is a neutral prior.
+    pub delta: f32,
+}
+
+impl WeightedEvidence {
+    fn add(&mut self, signed: f32, weight: f32) {
+        self.antecedents += 1;
+        self.total_weight += weight;
+        self.weighted_sum += signed * weight;
+        self.delta = self.weighted_sum / (self.total_weight + WEIGHTED_EVIDENCE_PRIOR);
+    }
+}
+
+/// Trustworthiness at a timestamp, with antecedents weighted by recency.
+///
+/// Returned by `Relationship::trustworthiness_at`. `factors` holds the
+/// declared bases with each factor's `WeightedEvidence::delta` applied, so
+/// it can be used wherever stored factors are.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeightedTrustworthiness {
+    /// The query timestamp.
+    pub timestamp: Timestamp,
+    /// Evidence per competence domain, for domains with any ability
+    /// antecedents. Ability antecedents without a life domain count toward
+    /// every domain.
+    pub competence: HashMap<LifeDomain, WeightedEvidence>,
+    /// Benevolence evidence.
+    pub benevolence: WeightedEvidence,
+    /// Integrity evidence.
+    pub integrity: WeightedEvidence,
+    /// The factors with the weighted deltas applied.
+    pub factors: TrustworthinessFactors,
+}
+
 /// Perceived trustworthiness factors of one entity toward another.
 ///
 /// These represent the trustor's perceptions of the trustee's:
@@ -78,9 +190,11 @@ const ANTECEDENT_DECAY_HALF_LIFE_DAYS: f64 = 180.0;
 /// let overall = factors.overall();
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TrustworthinessFactors {
     /// Perceived ability to perform tasks competently, per domain.
     /// Per Mayer: competence is domain-specific.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::types::sorted_serde::map"))]
     competence: HashMap<LifeDomain, StateValue>,
 
     /// Perceived caring and benevolent intentions.
@@ -458,6 +572,67 @@ impl TrustworthinessFactors {
         apply_ema_to_state_value(&mut self.integrity, integrity_ema);
     }
 
+    /// Computes factors at `timestamp` from antecedent history, weighting
+    /// each antecedent by its recency.
+    ///
+    /// Only antecedents at or before `timestamp` count. Each contributes its
+    /// signed magnitude (negatives weighted 2.5x, as in
+    /// `recompute_from_antecedents`) times its recency weight from
+    /// `half_lives`. A factor's delta is the weighted mean of its evidence
+    /// against a neutral prior of weight 1, applied to this value's bases.
+    #[must_use]
+    pub fn weighted_at(
+        &self,
+        antecedents: &[TrustAntecedent],
+        timestamp: Timestamp,
+        half_lives: &AntecedentHalfLives,
+    ) -> WeightedTrustworthiness {
+        let mut competence: HashMap<LifeDomain, WeightedEvidence> = HashMap::new();
+        let mut benevolence = WeightedEvidence::default();
+        let mut integrity = WeightedEvidence::default();
+
+        for antecedent in antecedents.iter().filter(|a| a.timestamp() <= timestamp) {
+            let antecedent_type = antecedent.antecedent_type();
+            let weight = half_lives.weight(antecedent_type, timestamp - antecedent.timestamp());
+            let signed = match antecedent.direction() {
+                AntecedentDirection::Positive => antecedent.magnitude(),
+                AntecedentDirection::Negative => {
+                    -antecedent.magnitude() * NEGATIVE_ANTECEDENT_WEIGHT
+                }
+            };
+            match antecedent_type {
+                AntecedentType::Ability => match antecedent.life_domain() {
+                    Some(domain) => competence.entry(domain).or_default().add(signed, weight),
+                    None => {
+                        for domain in self.competence.keys() {
+                            competence.entry(*domain).or_default().add(signed, weight);
+                        }
+                    }
+                },
+                AntecedentType::Benevolence => benevolence.add(signed, weight),
+                AntecedentType::Integrity => integrity.add(signed, weight),
+            }
+        }
+
+        let mut factors = self.clone();
+        factors.reset_deltas();
+        for (domain, evidence) in &competence {
+            if let Some(sv) = factors.competence.get_mut(domain) {
+                apply_ema_to_state_value(sv, evidence.delta);
+            }
+        }
+        apply_ema_to_state_value(&mut factors.benevolence, benevolence.delta);
+        apply_ema_to_state_value(&mut factors.integrity, integrity.delta);
+
+        WeightedTrustworthiness {
+            timestamp,
+            competence,
+            benevolence,
+            integrity,
+            factors,
+        }
+    }
+
     // Decay
 
     /// Applies decay to all trustworthiness factors over the specified duration.
@@ -477,6 +652,15 @@ impl TrustworthinessFactors {
         self.benevolence.reset_delta();
         self.integrity.reset_delta();
     }
+
+    /// Scales all deltas, the part of each factor built from antecedents,
+    /// by `factor`.
+    pub(crate) fn scale_deltas(&mut self, factor: f32) {
+        let factors = self.competence.values_mut();
+        for sv in factors.chain([&mut self.benevolence, &mut self.integrity]) {
+            sv.set_delta(sv.delta() * factor);
+        }
+    }
 }
 
 fn update_ema(previous: f32, value: f32) -> f32 {
@@ -794,8 +978,8 @@ mod tests {
         // Effective should be clamped to 1.0
         assert!((factors.competence_effective() - 1.0).abs() < f32::EPSILON);
 
-        // But delta is not clamped (check via one domain)
-        assert!((factors.competence(LifeDomain::Work).unwrap().delta() - 2.0).abs() < f32::EPSILON);
+        // A single addition is clamped to the bound width (check via one domain)
+        assert!((factors.competence(LifeDomain::Work).unwrap().delta() - 1.0).abs() < f32::EPSILON);
     }
 
     #[test]
@@ -1049,4 +1233,41 @@ mod tests {
         assert!(factors.competence(LifeDomain::Health).is_some());
         assert!(factors.competence(LifeDomain::Relationship).is_some());
     }
+
+    #[test]
+    fn weighted_at_ignores_later_antecedents_and_keeps_domains() {
+        let ts = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let health = TrustAntecedent::new(
+            ts,
+            AntecedentType::Ability,
+            AntecedentDirection::Positive,
+            0.5,
+            "gave_good_medical_advice",
+        )
+        .with_life_domain(LifeDomain::Health);
+        let later_lie = TrustAntecedent::new(
+            ts + Duration::days(10),
+            AntecedentType::Integrity,
+            AntecedentDirection::Negative,
+            0.9,
+            "lied",
+        );
+
+        let factors = TrustworthinessFactors::new();
+        let weighted = factors.weighted_at(
+            &[health, later_lie],
+            ts + Duration::days(5),
+            &AntecedentHalfLives::default(),
+        );
+
+        assert_eq!(weighted.competence.len(), 1);
+        let evidence = weighted.competence[&LifeDomain::Health];
+        let weight =
+            AntecedentHalfLives::default().weight(AntecedentType::Ability, Duration::days(5));
+        assert!((evidence.total_weight - weight).abs() < f32::EPSILON);
+        assert!((evidence.delta - 0.5 * weight / (weight + 1.0)).abs() < 1e-6);
+        assert_eq!(weighted.integrity, WeightedEvidence::default());
+        assert!(weighted.factors.competence_in(LifeDomain::Health) > DEFAULT_BASE);
+        assert!((weighted.factors.competence_in(LifeDomain::Work) - DEFAULT_BASE).abs() < 1e-6);
+    }
 }
@@ -0,0 +1,199 @@
+//! Adaptive sampling of one state value for visualization.
+//!
+//! Decade-long trajectories are mostly smooth exponential decay punctuated
+//! by event jumps, so a fixed step either wastes points on flat stretches or
+//! misses the jumps. `EntityQueryHandle::adaptive_series` keeps only the
+//! points needed to redraw the curve by linear interpolation within a
+//! tolerance.
+//!
+//! Every event timestamp is a breakpoint, together with the second before
+//! it, so each jump is drawn as a one-second step. A ramped event lands in
+//! daily increments, and each landing is a breakpoint in the same way, as
+//! are the landings of anticipation before an anticipated event.
+//! Between breakpoints the
+//! curve is refined by bisection: a segment is split at its midpoint while
+//! the exact value at its quarter, half, or three-quarter point differs
+//! from the chord by more than half the tolerance. The margin covers the
+//! curve between probes. Timestamps have one-second resolution, so segments
+//! shorter than two seconds have nothing left to split, and a zero tolerance
+//! degrades to per-second sampling wherever the curve is not linear.
+
+use crate::enums::StatePath;
+use crate::simulation::EntityQueryHandle;
+use crate::types::{Duration, Timestamp};
+use std::collections::BTreeSet;
+
+/// Returns the adaptive points of `path` from `start` to `end` inclusive.
+pub(crate) fn adaptive_series(
+    handle: &EntityQueryHandle<'_>,
+    path: StatePath,
+    start: Timestamp,
+    end: Timestamp,
+    tolerance: f64,
+) -> Vec<(Timestamp, f64)> {
+    if end < start {
+        return Vec::new();
+    }
+    let sampler = Sampler {
+        handle,
+        path,
+        // Half the tolerance at the probes leaves room between them
+        threshold: if tolerance > 0.0 {
+            tolerance / 2.0
+        } else {
+            0.0
+        },
+    };
+
+    let mut breakpoints = BTreeSet::from([start, end]);
+    let mut insert = |at: Timestamp| {
+        breakpoints.insert(at);
+        let before = at - Duration::seconds(1);
+        if before > start {
+            breakpoints.insert(before);
+        }
+    };
+    for te in handle.events_between(start, end) {
+        // A ramped event lands in increments after its onset
+        let landings = te
+            .event()
+            .onset_increments()
+            .into_iter()
+            .map(|(offset, _)| te.timestamp() + offset);
+        for at in std::iter::once(te.timestamp()).chain(landings) {
+            if at > end {
+                break;
+            }
+            insert(at);
+        }
+    }
+    // Anticipation lands before its event, which may lie after the range
+    for at in handle.anticipation_landings(start, end) {
+        insert(at);
+    }
+
+    let mut breakpoints = breakpoints.into_iter().map(|ts| (ts, sampler.value(ts)));
+    let Some(first) = breakpoints.next() else {
+        return Vec::new();
+    };
+    let mut points = vec![first];
+    let mut previous = first;
+    for next in breakpoints {
+        sampler.refine(previous, next, &mut points);
+        points.push(next);
+        previous = next;
+    }
+    points
+}
+
+/// Evaluates and refines one path of one entity.
+struct Sampler<'h, 'a> {
+    handle: &'h EntityQueryHandle<'a>,
+    path: StatePath,
+    /// Largest chord deviation accepted at a probe.
+    threshold: f64,
+}
+
+impl Sampler<'_, '_> {
+    fn value(&self, ts: Timestamp) -> f64 {
+        self.handle.state_at(ts).get_effective(self.path)
+    }
+
+    /// Pushes the points strictly between `from` and `to` needed to stay
+    /// within the threshold.
+    fn refine(
+        &self,
+        from: (Timestamp, f64),
+        to: (Timestamp, f64),
+        points: &mut Vec<(Timestamp, f64)>,
+    ) {
+        let span = (to.0 - from.0).as_seconds();
+        if span < 2 {
+            return;
+        }
+        let probe = |seconds: u64| {
+            let ts = from.0 + Duration::seconds(seconds);
+            let value = self.value(ts);
+            let chord = from.1 + (to.1 - from.1) * seconds as f64 / span as f64;
+            ((ts, value), (value - chord).abs())
+        };
+
+        let (middle, middle_error) = probe(span / 2);
+        let needs_split = middle_error > self.threshold
+            || [span / 4, span * 3 / 4]
+                .into_iter()
+                .filter(|seconds| *seconds > 0 && *seconds < span)
+                .any(|seconds| probe(seconds).1 > self.threshold);
+        if needs_split {
+            self.refine(from, middle, points);
+            points.push(middle);
+            self.refine(middle, to, points);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::entity::EntityBuilder;
+    use crate::enums::{EventType, MoodPath, Species, StatePath};
+    use crate::event::EventBuilder;
+    use crate::simulation::Simulation;
+    use crate::types::{Duration, EntityId, Timestamp};
+
+    const VALENCE: StatePath = StatePath::Mood(MoodPath::Valence);
+
+    fn reference() -> Timestamp {
+        Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+    }
+
+    fn setup() -> (Simulation, EntityId) {
+        let mut sim = Simulation::new(reference());
+        let entity = EntityBuilder::new()
+            .id("person_001")
+            .species(Species::Human)
+            .build()
+            .unwrap();
+        let id = sim.add_entity(entity, reference());
+        (sim, id)
+    }
+
+    #[test]
+    fn flat_trajectory_keeps_only_the_endpoints() {
+        let (sim, id) = setup();
+        let handle = sim.entity(&id).unwrap();
+        let end = reference() + Duration::days(365);
+
+        let series = handle.adaptive_series(VALENCE, reference(), end, 0.01);
+        assert_eq!(series.len(), 2);
+        assert_eq!(series[0].0, reference());
+        assert_eq!(series[1].0, end);
+        assert!(handle
+            .adaptive_series(VALENCE, end, reference(), 0.01)
+            .is_empty());
+    }
+
+    #[test]
+    fn events_are_breakpoints_with_a_step_before_them() {
+        let (mut sim, id) = setup();
+        let at = reference() + Duration::days(10);
+        let event = EventBuilder::new(EventType::Achievement)
+            .target(id.clone())
+            .severity(0.8)
+            .build()
+            .unwrap();
+        sim.add_event(event, at);
+        let handle = sim.entity(&id).unwrap();
+        let end = reference() + Duration::days(20);
+
+        let series = handle.adaptive_series(VALENCE, reference(), end, 0.01);
+        let index = series.iter().position(|(ts, _)| *ts == at).unwrap();
+        assert_eq!(series[index - 1].0, at - Duration::seconds(1));
+        assert!(series[index].1 > series[index - 1].1);
+
+        // Timestamps are strictly increasing, and a looser tolerance needs
+        // fewer points
+        assert!(series.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        let coarse = handle.adaptive_series(VALENCE, reference(), end, 0.1);
+        assert!(coarse.len() < series.len());
+    }
+}
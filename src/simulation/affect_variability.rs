@@ -0,0 +1,173 @@
+//! Affect variability over a rolling window.
+//!
+//! How much mood swings matters clinically as much as where it sits: an
+//! entity whose valence lurches between elation and despair is at risk
+//! even when its average is unremarkable.
+//! `EntityQueryHandle::affect_variability` samples valence, arousal and
+//! dominance evenly across a window ending at a given time, in one
+//! `state_range()` walk, and summarizes each dimension's spread:
+//!
+//! - the population standard deviation of the samples,
+//! - their range (largest minus smallest),
+//! - the largest change between consecutive samples, per day of spacing.
+//!
+//! The entity is flagged labile when its valence standard deviation
+//! exceeds `LABILE_VALENCE_SD`.
+
+use crate::enums::{MoodPath, StatePath};
+use crate::simulation::EntityQueryHandle;
+use crate::types::{Duration, Timestamp};
+
+/// Valence standard deviation above which affect counts as labile.
+///
+/// Valence spans -1 to 1, so this is a tenth of its range: well beyond
+/// the drift of a quiet stretch, reached when strong positive and negative
+/// events alternate.
+pub const LABILE_VALENCE_SD: f64 = 0.2;
+
+/// How much one affect dimension varied over a window.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AffectSpread {
+    /// Population standard deviation of the samples.
+    pub std_dev: f64,
+    /// Largest sample minus smallest.
+    pub range: f64,
+    /// Largest change between consecutive samples, per day of spacing.
+    pub max_daily_change: f64,
+}
+
+impl AffectSpread {
+    /// Summarizes `values` sampled `spacing_days` apart.
+    fn from_samples(values: &[f64], spacing_days: f64) -> Self {
+        if values.is_empty() {
+            return AffectSpread::default();
+        }
+        let count = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / count;
+        let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / count;
+        let (min, max) = values
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &v| {
+                (min.min(v), max.max(v))
+            });
+        let max_daily_change = if spacing_days > 0.0 {
+            values
+                .windows(2)
+                .map(|pair| (pair[1] - pair[0]).abs() / spacing_days)
+                .fold(0.0, f64::max)
+        } else {
+            0.0
+        };
+        AffectSpread {
+            std_dev: variance.sqrt(),
+            range: max - min,
+            max_daily_change,
+        }
+    }
+}
+
+/// Variability of an entity's mood over a window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AffectVariability {
+    /// Time of the first sample.
+    pub window_start: Timestamp,
+    /// Time of the last sample.
+    pub window_end: Timestamp,
+    /// Number of samples taken.
+    pub samples: usize,
+    /// Spread of valence.
+    pub valence: AffectSpread,
+    /// Spread of arousal.
+    pub arousal: AffectSpread,
+    /// Spread of dominance.
+    pub dominance: AffectSpread,
+    /// True if the valence standard deviation exceeds `LABILE_VALENCE_SD`.
+    pub labile: bool,
+}
+
+/// Samples the entity's mood `samples` times evenly across the `window`
+/// ending at `window_end`.
+///
+/// Fewer than two samples, or a window too short to space them, gives a
+/// single sample at `window_end`.
+pub(crate) fn affect_variability(
+    handle: &EntityQueryHandle<'_>,
+    window_end: Timestamp,
+    window: Duration,
+    samples: usize,
+) -> AffectVariability {
+    let intervals = samples.saturating_sub(1) as u64;
+    let step = if intervals == 0 {
+        Duration::zero()
+    } else {
+        window / intervals
+    };
+    let window_start = if step.is_zero() {
+        window_end
+    } else {
+        window_end - step * intervals
+    };
+
+    let states = handle.state_range(window_start, window_end, step);
+    let series = |path: MoodPath| -> Vec<f64> {
+        states
+            .iter()
+            .map(|(_, state)| state.get_effective(StatePath::Mood(path)))
+            .collect()
+    };
+    let spacing_days = step.as_days_f64();
+    let valence = AffectSpread::from_samples(&series(MoodPath::Valence), spacing_days);
+    AffectVariability {
+        window_start,
+        window_end,
+        samples: states.len(),
+        valence,
+        arousal: AffectSpread::from_samples(&series(MoodPath::Arousal), spacing_days),
+        dominance: AffectSpread::from_samples(&series(MoodPath::Dominance), spacing_days),
+        labile: valence.std_dev > LABILE_VALENCE_SD,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::EntityBuilder;
+    use crate::enums::Species;
+    use crate::simulation::Simulation;
+
+    #[test]
+    fn spread_of_samples() {
+        let spread = AffectSpread::from_samples(&[0.0, 0.5, -0.5, 0.0], 2.0);
+        assert!((spread.std_dev - 0.125_f64.sqrt()).abs() < 1e-12);
+        assert!((spread.range - 1.0).abs() < 1e-12);
+        assert!((spread.max_daily_change - 0.5).abs() < 1e-12);
+        assert_eq!(
+            AffectSpread::from_samples(&[], 1.0),
+            AffectSpread::default()
+        );
+    }
+
+    #[test]
+    fn samples_end_at_window_end() {
+        let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let mut sim = Simulation::new(reference);
+        let entity = EntityBuilder::new()
+            .id("quiet")
+            .species(Species::Human)
+            .build()
+            .unwrap();
+        let id = sim.add_entity(entity, reference);
+        let handle = sim.entity(&id).unwrap();
+        let end = reference + Duration::days(30);
+
+        let variability = handle.affect_variability(end, Duration::days(10), 4);
+        assert_eq!(variability.samples, 4);
+        assert_eq!(variability.window_end, end);
+        assert_eq!(variability.window_start, end - Duration::days(10));
+
+        let single = handle.affect_variability(end, Duration::days(10), 1);
+        assert_eq!(single.samples, 1);
+        assert_eq!(single.window_start, end);
+        assert_eq!(single.valence, AffectSpread::default());
+    }
+}
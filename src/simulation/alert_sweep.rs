@@ -0,0 +1,180 @@
+//! Population-wide alert sweeps.
+//!
+//! Monitoring asks "who needs attention now" across every entity at once.
+//! [`Simulation::alert_sweep`] evaluates `ComputedState::alerts()` for each
+//! entity at a timestamp and ranks the entities by composite severity:
+//!
+//! 1. the number of Critical alerts,
+//! 2. then the number of Warning alerts,
+//! 3. then the number of Info alerts,
+//!
+//! each compared highest first, with ties broken by entity ID. One Critical
+//! alert outranks any number of Warnings.
+//!
+//! A sweep can be limited to some entities and, given the timestamp of a
+//! previous sweep, report only thresholds crossed since then (see
+//! [`AlertSweepConfig`]). With the `parallel` feature, entities are
+//! evaluated on scoped threads.
+
+use crate::enums::{AlertSeverity, AlertTrigger};
+use crate::simulation::population::evaluate_each;
+use crate::simulation::Simulation;
+use crate::types::{Alert, EntityId, Timestamp};
+
+/// Options for `Simulation::alert_sweep_with`.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::simulation::AlertSweepConfig;
+/// use behavioral_pathways::types::Timestamp;
+///
+/// let config = AlertSweepConfig {
+///     since: Some(Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)),
+///     ..Default::default()
+/// };
+/// assert!(config.entities.is_none());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AlertSweepConfig {
+    /// Entities to evaluate, or every entity when `None`. Unknown IDs are
+    /// skipped.
+    pub entities: Option<Vec<EntityId>>,
+    /// Timestamp of a previous sweep. When set, an alert is reported only
+    /// if the state at `since` had no alert with the same trigger at the
+    /// same or a higher severity, so repeats are suppressed but
+    /// escalations are reported. Threshold triggers match by state path.
+    pub since: Option<Timestamp>,
+}
+
+/// Evaluates alerts for the configured entities at `at`.
+pub(crate) fn alert_sweep(
+    simulation: &Simulation,
+    at: Timestamp,
+    min_severity: AlertSeverity,
+    config: &AlertSweepConfig,
+) -> Vec<(EntityId, Vec<Alert>)> {
+    let ids: Vec<EntityId> = match &config.entities {
+        Some(ids) => ids.clone(),
+        None => simulation
+            .entities()
+            .map(|anchored| anchored.entity().id().clone())
+            .collect(),
+    };
+    let sweep = |id: &EntityId| entity_alerts(simulation, id, at, min_severity, config.since);
+
+    let mut results = evaluate_each(&ids, sweep);
+    results.sort_by(|(a, a_alerts), (b, b_alerts)| {
+        severity_rank(b_alerts)
+            .cmp(&severity_rank(a_alerts))
+            .then_with(|| a.cmp(b))
+    });
+    results
+}
+
+/// Returns one entity's reportable alerts, most severe first.
+fn entity_alerts(
+    simulation: &Simulation,
+    id: &EntityId,
+    at: Timestamp,
+    min_severity: AlertSeverity,
+    since: Option<Timestamp>,
+) -> Option<(EntityId, Vec<Alert>)> {
+    let handle = simulation.entity(id)?;
+    let mut alerts: Vec<Alert> = handle
+        .state_at(at)
+        .alerts()
+        .into_iter()
+        .filter(|alert| alert.severity() >= min_severity)
+        .collect();
+    if let Some(since) = since.filter(|_| !alerts.is_empty()) {
+        let earlier = handle.state_at(since).alerts();
+        alerts.retain(|alert| {
+            !earlier.iter().any(|previous| {
+                same_trigger(previous.trigger(), alert.trigger())
+                    && previous.severity() >= alert.severity()
+            })
+        });
+    }
+    alerts.sort_by_key(|alert| std::cmp::Reverse(alert.severity()));
+    (!alerts.is_empty()).then(|| (id.clone(), alerts))
+}
+
+/// Returns true if two triggers watch the same threshold.
+///
+/// Threshold triggers carry the value that crossed, so they match by path.
+fn same_trigger(a: &AlertTrigger, b: &AlertTrigger) -> bool {
+    match (a, b) {
+        (AlertTrigger::ThresholdExceeded(a, _), AlertTrigger::ThresholdExceeded(b, _)) => a == b,
+        _ => a == b,
+    }
+}
+
+/// Returns the composite severity: Critical, Warning, and Info counts.
+fn severity_rank(alerts: &[Alert]) -> [usize; 3] {
+    [
+        AlertSeverity::Critical,
+        AlertSeverity::Warning,
+        AlertSeverity::Info,
+    ]
+    .map(|severity| {
+        alerts
+            .iter()
+            .filter(|alert| alert.severity() == severity)
+            .count()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::{MentalHealthPath, StatePath};
+    use crate::types::Duration;
+
+    fn alert(severity: AlertSeverity, path: MentalHealthPath, value: f64) -> Alert {
+        Alert::new(
+            severity,
+            AlertTrigger::threshold(StatePath::MentalHealth(path), value),
+            Duration::zero(),
+            "test",
+        )
+    }
+
+    #[test]
+    fn one_critical_outranks_many_warnings() {
+        let critical = vec![alert(
+            AlertSeverity::Critical,
+            MentalHealthPath::AttemptRisk,
+            0.7,
+        )];
+        let warnings = vec![
+            alert(AlertSeverity::Warning, MentalHealthPath::AttemptRisk, 0.5),
+            alert(
+                AlertSeverity::Warning,
+                MentalHealthPath::SuicidalDesire,
+                0.5,
+            ),
+        ];
+        assert!(severity_rank(&critical) > severity_rank(&warnings));
+        assert_eq!(severity_rank(&warnings), [0, 2, 0]);
+    }
+
+    #[test]
+    fn thresholds_match_by_path() {
+        let desire = |value| {
+            AlertTrigger::threshold(
+                StatePath::MentalHealth(MentalHealthPath::SuicidalDesire),
+                value,
+            )
+        };
+        assert!(same_trigger(&desire(0.55), &desire(0.6)));
+        assert!(!same_trigger(
+            &desire(0.55),
+            &AlertTrigger::threshold(StatePath::MentalHealth(MentalHealthPath::AttemptRisk), 0.55)
+        ));
+        assert!(!same_trigger(
+            &AlertTrigger::custom("a"),
+            &AlertTrigger::custom("b")
+        ));
+    }
+}
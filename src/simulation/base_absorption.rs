@@ -0,0 +1,339 @@
+//! Slow absorption of sustained chronic deltas into the base.
+//!
+//! Deltas decay toward the base they were added to, so a person lonely for
+//! years recovers fully within weeks of the loneliness ending. Hedonic
+//! adaptation says otherwise: a long-sustained change partly becomes the
+//! new normal. Base absorption is the non-HEXACO counterpart of formative
+//! shifts.
+//!
+//! Absorption is opt-in through `Simulation::set_base_absorption_config()`.
+//! When enabled, each step of a projection (the time between two event
+//! increments) measures the mean chronic delta of every decaying
+//! dimension over the step. A step whose mean magnitude is at least
+//! `threshold` extends the dimension's sustained time; any other step
+//! resets it. Once sustained time passes `onset`, `monthly_rate` of the
+//! mean chronic delta moves from the delta into the base per month, until
+//! the base has moved `max_shift`. The effective value is unchanged when
+//! the delta moves; the difference shows once the rest decays.
+//!
+//! Sustained time and the moved total start from zero at each anchor,
+//! observation, and compaction checkpoint. Backward regression moves the
+//! delta back out of the base with the same rule, timed from the anchor,
+//! and flags the state `ApproximationReason::BaseAbsorption` when it does,
+//! since the sustained time before the anchor is unknown.
+//!
+//! A robotic entity's deltas never decay, so nothing is absorbed for it.
+
+use crate::enums::Species;
+use crate::processor::{advance_state, decay_processor_for, regress_state, DecayProcessor};
+use crate::state::IndividualState;
+use crate::types::Duration;
+
+/// Length of the month `BaseAbsorptionConfig::monthly_rate` is given per.
+pub const ABSORPTION_MONTH: Duration = Duration::days(30);
+
+/// Tuning for absorbing sustained chronic deltas into the base.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::simulation::BaseAbsorptionConfig;
+/// use behavioral_pathways::types::Duration;
+///
+/// let config = BaseAbsorptionConfig {
+///     onset: Duration::years(1),
+///     ..Default::default()
+/// };
+/// assert!((config.monthly_rate - 0.05).abs() < f64::EPSILON);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BaseAbsorptionConfig {
+    /// Smallest mean chronic delta magnitude over a step that counts as
+    /// sustained.
+    pub threshold: f64,
+    /// Sustained time before any delta is absorbed.
+    pub onset: Duration,
+    /// Fraction of the mean chronic delta absorbed per month past the
+    /// onset.
+    pub monthly_rate: f64,
+    /// Largest total base shift of one dimension (0-1).
+    pub max_shift: f64,
+}
+
+impl Default for BaseAbsorptionConfig {
+    fn default() -> Self {
+        BaseAbsorptionConfig {
+            threshold: 0.05,
+            onset: Duration::days(180),
+            monthly_rate: 0.05,
+            max_shift: 0.15,
+        }
+    }
+}
+
+impl BaseAbsorptionConfig {
+    /// Returns the amount absorbed over a step, given the integral of the
+    /// chronic delta over it (in delta-days) and the part of the step past
+    /// the onset (in days).
+    #[must_use]
+    pub fn absorbed(&self, exposure: f64, step_days: f64, eligible_days: f64) -> f64 {
+        if step_days <= 0.0 {
+            return 0.0;
+        }
+        let mean = exposure / step_days;
+        self.monthly_rate * mean * eligible_days / ABSORPTION_MONTH.as_days_f64()
+    }
+}
+
+/// Sustained time and moved total of each decaying dimension along one
+/// projection.
+///
+/// Dimensions are indexed in `IndividualState::decaying_values_mut()`
+/// order. Without a config, steps only decay, with the entity's decay
+/// processor.
+#[derive(Clone)]
+pub(crate) struct BaseAbsorption<'a> {
+    config: Option<&'a BaseAbsorptionConfig>,
+    processor: &'static (dyn DecayProcessor + Sync),
+    sustained: Vec<f64>,
+    moved: Vec<f64>,
+    regressed: bool,
+}
+
+impl<'a> BaseAbsorption<'a> {
+    /// Creates a tracker with nothing sustained for an entity of `species`.
+    pub(crate) fn new(config: Option<&'a BaseAbsorptionConfig>, species: &Species) -> Self {
+        BaseAbsorption {
+            config: config.filter(|_| species.decays()),
+            processor: decay_processor_for(species),
+            sustained: Vec::new(),
+            moved: Vec::new(),
+            regressed: false,
+        }
+    }
+
+    /// Returns true if a backward step moved delta back out of a base.
+    pub(crate) fn regressed(&self) -> bool {
+        self.regressed
+    }
+
+    /// Decays `state` over `elapsed` psychological time, then absorbs.
+    pub(crate) fn advance(&mut self, state: IndividualState, elapsed: Duration) -> IndividualState {
+        let Some(config) = self.config else {
+            return advance_state(state, elapsed, self.processor);
+        };
+        let mut state = state;
+        let decay: Vec<(f64, f64)> = state
+            .decaying_values_mut()
+            .iter()
+            .map(|value| chronic_decay(value.chronic_half_life(), elapsed))
+            .collect();
+        let chronic: Vec<f64> = state
+            .decaying_values_mut()
+            .iter()
+            .map(|value| f64::from(value.chronic_delta()))
+            .collect();
+        let mut state = advance_state(state, elapsed, self.processor);
+        self.fit(decay.len());
+
+        let step_days = elapsed.as_days_f64();
+        for (index, value) in state.decaying_values_mut().into_iter().enumerate() {
+            let exposure = chronic[index] * decay[index].1;
+            let eligible = self.extend(config, index, exposure, step_days);
+            let amount = self.room(
+                config,
+                index,
+                config.absorbed(exposure, step_days, eligible),
+            );
+            if amount != 0.0 {
+                self.moved[index] += f64::from(value.absorb_chronic_delta(amount as f32));
+            }
+        }
+        state
+    }
+
+    /// Moves absorbed delta back out of the base, then reverses decay over
+    /// `elapsed` psychological time.
+    ///
+    /// Inverts `advance` for a step whose sustained time, counted back
+    /// from the anchor, is past the onset.
+    pub(crate) fn regress(&mut self, state: IndividualState, elapsed: Duration) -> IndividualState {
+        let Some(config) = self.config else {
+            return regress_state(state, elapsed, self.processor);
+        };
+        let mut state = state;
+        let step_days = elapsed.as_days_f64();
+        let values = state.decaying_values_mut();
+        self.fit(values.len());
+        for (index, value) in values.into_iter().enumerate() {
+            let (retention, exposure_per_delta) = chronic_decay(value.chronic_half_life(), elapsed);
+            let later = f64::from(value.chronic_delta());
+            let earlier = later / retention;
+            let eligible = self.extend(config, index, earlier * exposure_per_delta, step_days);
+            // advance moved k * earlier, and later = (retention - k) * earlier
+            let k = config.absorbed(exposure_per_delta, step_days, eligible);
+            if k == 0.0 || retention <= k {
+                continue;
+            }
+            let amount = self.room(config, index, k * later / (retention - k));
+            if amount != 0.0 {
+                self.moved[index] -= f64::from(value.absorb_chronic_delta(-amount as f32));
+                self.regressed = true;
+            }
+        }
+        regress_state(state, elapsed, self.processor)
+    }
+
+    /// Grows the per-dimension records to `count` dimensions.
+    fn fit(&mut self, count: usize) {
+        self.sustained.resize(count, 0.0);
+        self.moved.resize(count, 0.0);
+    }
+
+    /// Adds a step to a dimension's sustained time, or resets it, and
+    /// returns how many days of the step are past the onset.
+    fn extend(
+        &mut self,
+        config: &BaseAbsorptionConfig,
+        index: usize,
+        exposure: f64,
+        step_days: f64,
+    ) -> f64 {
+        if step_days <= 0.0 {
+            return 0.0;
+        }
+        if (exposure / step_days).abs() < config.threshold {
+            self.sustained[index] = 0.0;
+            return 0.0;
+        }
+        self.sustained[index] += step_days;
+        (self.sustained[index] - config.onset.as_days_f64()).clamp(0.0, step_days)
+    }
+
+    /// Limits an absorbed amount so the dimension's moved total stays
+    /// within `max_shift`.
+    fn room(&self, config: &BaseAbsorptionConfig, index: usize, amount: f64) -> f64 {
+        let room = (config.max_shift - self.moved[index].abs()).max(0.0);
+        amount.clamp(-room, room)
+    }
+}
+
+/// Returns the fraction of a chronic delta left after `elapsed` and the
+/// integral of a unit chronic delta over it, in delta-days.
+fn chronic_decay(half_life: Option<Duration>, elapsed: Duration) -> (f64, f64) {
+    let days = elapsed.as_days_f64();
+    let Some(half_life) = half_life else {
+        return (1.0, days);
+    };
+    let half_life = half_life.as_days_f64();
+    let retention = 0.5_f64.powf(days / half_life);
+    (
+        retention,
+        half_life / std::f64::consts::LN_2 * (1.0 - retention),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::processor::StateDecayProcessor;
+
+    fn lonely(chronic: f32) -> IndividualState {
+        let mut state = IndividualState::new();
+        state
+            .social_cognition_mut()
+            .loneliness_mut()
+            .add_chronic_delta(chronic);
+        state
+    }
+
+    #[test]
+    fn chronic_decay_integrates_exponential() {
+        let (retention, exposure) = chronic_decay(Some(Duration::days(4)), Duration::days(4));
+        assert!((retention - 0.5).abs() < 1e-12);
+        assert!((exposure - 4.0 / std::f64::consts::LN_2 * 0.5).abs() < 1e-12);
+        assert_eq!(chronic_decay(None, Duration::days(3)), (1.0, 3.0));
+    }
+
+    #[test]
+    fn nothing_absorbed_before_onset() {
+        let config = BaseAbsorptionConfig::default();
+        let mut absorption = BaseAbsorption::new(Some(&config), &Species::Human);
+        let mut state = lonely(0.3);
+        let base = state.social_cognition().loneliness().base();
+        for _ in 0..20 {
+            state = absorption.advance(state, Duration::days(1));
+            state
+                .social_cognition_mut()
+                .loneliness_mut()
+                .add_chronic_delta(0.05);
+        }
+        assert_eq!(state.social_cognition().loneliness().base(), base);
+    }
+
+    #[test]
+    fn sustained_delta_moves_into_base_up_to_cap() {
+        let config = BaseAbsorptionConfig {
+            onset: Duration::days(10),
+            monthly_rate: 1.0,
+            max_shift: 0.1,
+            ..Default::default()
+        };
+        let mut absorption = BaseAbsorption::new(Some(&config), &Species::Human);
+        let mut state = lonely(0.3);
+        let base = state.social_cognition().loneliness().base();
+        for _ in 0..200 {
+            state = absorption.advance(state, Duration::days(1));
+            state
+                .social_cognition_mut()
+                .loneliness_mut()
+                .add_chronic_delta(0.05);
+        }
+        let shift = state.social_cognition().loneliness().base() - base;
+        assert!((shift - 0.1).abs() < 1e-5);
+    }
+
+    #[test]
+    fn regress_undoes_advance() {
+        let config = BaseAbsorptionConfig {
+            onset: Duration::zero(),
+            ..Default::default()
+        };
+        let original = lonely(0.4);
+        let mut forward = BaseAbsorption::new(Some(&config), &Species::Human);
+        let advanced = forward.advance(original.clone(), Duration::days(2));
+        let loneliness = advanced.social_cognition().loneliness();
+        assert!(loneliness.base() > original.social_cognition().loneliness().base());
+
+        let mut backward = BaseAbsorption::new(Some(&config), &Species::Human);
+        let regressed = backward.regress(advanced, Duration::days(2));
+        assert!(backward.regressed());
+        let before = original.social_cognition().loneliness();
+        let after = regressed.social_cognition().loneliness();
+        assert!((after.base() - before.base()).abs() < 1e-6);
+        assert!((after.delta() - before.delta()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn disabled_tracker_only_decays() {
+        let mut absorption = BaseAbsorption::new(None, &Species::Human);
+        let state = absorption.advance(lonely(0.4), Duration::days(2));
+        let decayed = advance_state(lonely(0.4), Duration::days(2), &StateDecayProcessor);
+        assert_eq!(state, decayed);
+        assert!(!absorption.regressed());
+    }
+
+    #[test]
+    fn robotic_tracker_neither_decays_nor_absorbs() {
+        let config = BaseAbsorptionConfig {
+            onset: Duration::zero(),
+            ..Default::default()
+        };
+        let mut absorption = BaseAbsorption::new(Some(&config), &Species::Robotic);
+        let state = absorption.advance(lonely(0.4), Duration::days(400));
+        assert_eq!(state, lonely(0.4));
+        assert_eq!(absorption.regress(state, Duration::days(400)), lonely(0.4));
+        assert!(!absorption.regressed());
+    }
+}
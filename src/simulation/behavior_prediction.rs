@@ -0,0 +1,368 @@
+//! Behavioral predictions from a computed state.
+//!
+//! [`ComputedState::predict`](crate::simulation::ComputedState::predict)
+//! turns the dimensions that drive a behavior into a likelihood in [0, 1].
+//! Each dimension is normalized to [0, 1] over its bounds, inverted where a
+//! low value is what drives the behavior, and weighted:
+//!
+//! | Query | Drivers (weight) | Situation |
+//! |-------|------------------|-----------|
+//! | `SeekSupport` | PRC (0.30), trust propensity (0.25), stress (0.20), extraversion (0.15), low perceived liability (0.10) | 1.0 |
+//! | `ActAggressively` | Aggression (0.30), low impulse control (0.25), grievance (0.25), low agreeableness (0.10), arousal (0.10) | `0.5 + 0.5 * provocation` |
+//! | `WithdrawSocially` | Loneliness (0.30), low PRC (0.30), low extraversion (0.15), perceived liability (0.15), low valence (0.10) | 1.0 |
+//! | `PersistOnTask` | Persistence tendency (0.30), conscientiousness (0.20), perceived competence (0.20), low fatigue (0.15), purpose (0.15) | `1 - 0.5 * difficulty` |
+//!
+//! The likelihood is the weighted sum scaled by the situation factor, and
+//! each contributor's share is its weighted, scaled term, so the shares sum
+//! to the likelihood. Predictions are deterministic.
+
+use crate::enums::{
+    DispositionPath, HexacoPath, MoodPath, NeedsPath, PersonCharacteristicsPath,
+    SocialCognitionPath, StatePath,
+};
+use crate::simulation::state_query::effective_value;
+use crate::state::IndividualState;
+
+/// Share of `ActAggressively` likelihood that needs no provocation.
+const UNPROVOKED_AGGRESSION: f64 = 0.5;
+
+/// Share of `PersistOnTask` likelihood the hardest task takes away.
+const DIFFICULTY_DETERRENCE: f64 = 0.5;
+
+/// A behavior to predict for an entity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BehaviorQuery {
+    /// Reaching out to others for help or comfort.
+    SeekSupport,
+    /// Acting aggressively under `provocation` (0-1).
+    ActAggressively {
+        /// How provoking the situation is (0 = unprovoked, 1 = maximal).
+        provocation: f64,
+    },
+    /// Pulling away from social contact.
+    WithdrawSocially,
+    /// Keeping at a task of the given `difficulty` (0-1).
+    PersistOnTask {
+        /// How hard the task is (0 = trivial, 1 = hardest).
+        difficulty: f64,
+    },
+}
+
+/// Whether a high or a low value of a dimension drives a behavior.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    High,
+    Low,
+}
+
+/// A dimension that drives a behavior, with its weight.
+type Driver = (StatePath, f64, Direction);
+
+impl BehaviorQuery {
+    /// Returns the dimensions that drive this behavior. Weights sum to 1.
+    fn drivers(&self) -> [Driver; 5] {
+        use Direction::{High, Low};
+        match self {
+            BehaviorQuery::SeekSupport => [
+                (
+                    StatePath::SocialCognition(SocialCognitionPath::PerceivedReciprocalCaring),
+                    0.3,
+                    High,
+                ),
+                (
+                    StatePath::Disposition(DispositionPath::TrustPropensity),
+                    0.25,
+                    High,
+                ),
+                (StatePath::Needs(NeedsPath::Stress), 0.2, High),
+                (StatePath::Hexaco(HexacoPath::Extraversion), 0.15, High),
+                (
+                    StatePath::SocialCognition(SocialCognitionPath::PerceivedLiability),
+                    0.1,
+                    Low,
+                ),
+            ],
+            BehaviorQuery::ActAggressively { .. } => [
+                (
+                    StatePath::Disposition(DispositionPath::Aggression),
+                    0.3,
+                    High,
+                ),
+                (
+                    StatePath::Disposition(DispositionPath::ImpulseControl),
+                    0.25,
+                    Low,
+                ),
+                (
+                    StatePath::Disposition(DispositionPath::Grievance),
+                    0.25,
+                    High,
+                ),
+                (StatePath::Hexaco(HexacoPath::Agreeableness), 0.1, Low),
+                (StatePath::Mood(MoodPath::Arousal), 0.1, High),
+            ],
+            BehaviorQuery::WithdrawSocially => [
+                (
+                    StatePath::SocialCognition(SocialCognitionPath::Loneliness),
+                    0.3,
+                    High,
+                ),
+                (
+                    StatePath::SocialCognition(SocialCognitionPath::PerceivedReciprocalCaring),
+                    0.3,
+                    Low,
+                ),
+                (StatePath::Hexaco(HexacoPath::Extraversion), 0.15, Low),
+                (
+                    StatePath::SocialCognition(SocialCognitionPath::PerceivedLiability),
+                    0.15,
+                    High,
+                ),
+                (StatePath::Mood(MoodPath::Valence), 0.1, Low),
+            ],
+            BehaviorQuery::PersistOnTask { .. } => [
+                (
+                    StatePath::PersonCharacteristics(
+                        PersonCharacteristicsPath::PersistenceTendency,
+                    ),
+                    0.3,
+                    High,
+                ),
+                (StatePath::Hexaco(HexacoPath::Conscientiousness), 0.2, High),
+                (
+                    StatePath::SocialCognition(SocialCognitionPath::PerceivedCompetence),
+                    0.2,
+                    High,
+                ),
+                (StatePath::Needs(NeedsPath::Fatigue), 0.15, Low),
+                (StatePath::Needs(NeedsPath::Purpose), 0.15, High),
+            ],
+        }
+    }
+
+    /// Returns the factor the situation scales the likelihood by.
+    fn situation_factor(&self) -> f64 {
+        match *self {
+            BehaviorQuery::ActAggressively { provocation } => {
+                UNPROVOKED_AGGRESSION + (1.0 - UNPROVOKED_AGGRESSION) * provocation.clamp(0.0, 1.0)
+            }
+            BehaviorQuery::PersistOnTask { difficulty } => {
+                1.0 - DIFFICULTY_DETERRENCE * difficulty.clamp(0.0, 1.0)
+            }
+            BehaviorQuery::SeekSupport | BehaviorQuery::WithdrawSocially => 1.0,
+        }
+    }
+}
+
+/// A dimension's share of a behavior prediction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BehaviorContributor {
+    /// The contributing dimension.
+    pub path: StatePath,
+    /// The dimension's effective value.
+    pub value: f64,
+    /// The dimension's share of the likelihood (0-1).
+    pub contribution: f64,
+}
+
+/// The predicted likelihood of a behavior and what drives it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BehaviorPrediction {
+    /// The predicted behavior.
+    pub query: BehaviorQuery,
+    /// How likely the behavior is (0-1).
+    pub likelihood: f64,
+    /// Every driving dimension, largest contribution first. Contributions
+    /// sum to `likelihood`.
+    pub contributors: Vec<BehaviorContributor>,
+}
+
+impl BehaviorPrediction {
+    /// Predicts `query` from an individual state.
+    #[must_use]
+    pub fn from_state(state: &IndividualState, query: BehaviorQuery) -> Self {
+        let situation = query.situation_factor();
+        let mut contributors: Vec<BehaviorContributor> = query
+            .drivers()
+            .into_iter()
+            .map(|(path, weight, direction)| {
+                let value = effective_value(state, path);
+                let (low, high) = path.bounds();
+                let normalized = ((value - low) / (high - low)).clamp(0.0, 1.0);
+                let drive = match direction {
+                    Direction::High => normalized,
+                    Direction::Low => 1.0 - normalized,
+                };
+                BehaviorContributor {
+                    path,
+                    value,
+                    contribution: weight * drive * situation,
+                }
+            })
+            .collect();
+        contributors.sort_by(|a, b| b.contribution.total_cmp(&a.contribution));
+        let likelihood = contributors
+            .iter()
+            .map(|contributor| contributor.contribution)
+            .sum::<f64>()
+            .clamp(0.0, 1.0);
+        BehaviorPrediction {
+            query,
+            likelihood,
+            contributors,
+        }
+    }
+
+    /// Returns the `n` largest contributors, largest first.
+    #[must_use]
+    pub fn top_contributors(&self, n: usize) -> &[BehaviorContributor] {
+        &self.contributors[..n.min(self.contributors.len())]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{Disposition, Hexaco, Mood, Needs, PersonCharacteristics, SocialCognition};
+
+    fn hostile() -> IndividualState {
+        IndividualState::new()
+            .with_disposition(
+                Disposition::new()
+                    .with_aggression_base(0.9)
+                    .with_impulse_control_base(0.1)
+                    .with_grievance_base(0.9),
+            )
+            .with_hexaco(Hexaco::new().with_agreeableness(-0.8))
+    }
+
+    fn isolated() -> IndividualState {
+        IndividualState::new()
+            .with_social_cognition(
+                SocialCognition::new()
+                    .with_loneliness_base(0.9)
+                    .with_perceived_reciprocal_caring_base(0.1)
+                    .with_perceived_liability_base(0.7),
+            )
+            .with_hexaco(Hexaco::new().with_extraversion(-0.8))
+            .with_mood(Mood::new().with_valence_base(-0.6))
+    }
+
+    fn supported() -> IndividualState {
+        IndividualState::new()
+            .with_social_cognition(
+                SocialCognition::new()
+                    .with_loneliness_base(0.1)
+                    .with_perceived_reciprocal_caring_base(0.9)
+                    .with_perceived_liability_base(0.05),
+            )
+            .with_disposition(Disposition::new().with_trust_propensity_base(0.9))
+            .with_needs(Needs::new().with_stress_base(0.7))
+            .with_hexaco(Hexaco::new().with_extraversion(0.8))
+    }
+
+    fn diligent() -> IndividualState {
+        IndividualState::new()
+            .with_person_characteristics(
+                PersonCharacteristics::new().with_persistence_tendency_base(0.9),
+            )
+            .with_hexaco(Hexaco::new().with_conscientiousness(0.8))
+            .with_social_cognition(SocialCognition::new().with_perceived_competence_base(0.9))
+            .with_needs(Needs::new().with_fatigue_base(0.05).with_purpose_base(0.9))
+    }
+
+    fn likelihood(state: &IndividualState, query: BehaviorQuery) -> f64 {
+        BehaviorPrediction::from_state(state, query).likelihood
+    }
+
+    #[test]
+    fn hostile_state_predicts_aggression() {
+        let provoked = BehaviorQuery::ActAggressively { provocation: 1.0 };
+        let prediction = BehaviorPrediction::from_state(&hostile(), provoked);
+        assert!(prediction.likelihood > 0.8, "{}", prediction.likelihood);
+        assert!(likelihood(&supported(), provoked) < 0.5);
+
+        let top: Vec<StatePath> = prediction
+            .top_contributors(3)
+            .iter()
+            .map(|contributor| contributor.path)
+            .collect();
+        assert_eq!(top[0], StatePath::Disposition(DispositionPath::Aggression));
+        assert!(top.contains(&StatePath::Disposition(DispositionPath::ImpulseControl)));
+        assert!(top.contains(&StatePath::Disposition(DispositionPath::Grievance)));
+
+        // Provocation raises the likelihood
+        let unprovoked = likelihood(
+            &hostile(),
+            BehaviorQuery::ActAggressively { provocation: 0.0 },
+        );
+        assert!(unprovoked < prediction.likelihood);
+    }
+
+    #[test]
+    fn isolated_state_predicts_withdrawal() {
+        let prediction =
+            BehaviorPrediction::from_state(&isolated(), BehaviorQuery::WithdrawSocially);
+        assert!(prediction.likelihood > 0.8, "{}", prediction.likelihood);
+        assert!(likelihood(&supported(), BehaviorQuery::WithdrawSocially) < 0.2);
+        assert!(likelihood(&isolated(), BehaviorQuery::SeekSupport) < 0.4);
+
+        let top: Vec<StatePath> = prediction
+            .top_contributors(2)
+            .iter()
+            .map(|contributor| contributor.path)
+            .collect();
+        assert!(top.contains(&StatePath::SocialCognition(SocialCognitionPath::Loneliness)));
+        assert!(top.contains(&StatePath::SocialCognition(
+            SocialCognitionPath::PerceivedReciprocalCaring
+        )));
+    }
+
+    #[test]
+    fn supported_state_predicts_seeking_support() {
+        let prediction = BehaviorPrediction::from_state(&supported(), BehaviorQuery::SeekSupport);
+        assert!(prediction.likelihood > 0.8, "{}", prediction.likelihood);
+        assert_eq!(
+            prediction.contributors[0].path,
+            StatePath::SocialCognition(SocialCognitionPath::PerceivedReciprocalCaring)
+        );
+    }
+
+    #[test]
+    fn diligent_state_persists_at_hard_tasks() {
+        let easy = BehaviorQuery::PersistOnTask { difficulty: 0.0 };
+        let hard = BehaviorQuery::PersistOnTask { difficulty: 1.0 };
+        assert!(likelihood(&diligent(), easy) > 0.8);
+        assert!(likelihood(&diligent(), hard) > likelihood(&IndividualState::new(), hard));
+        assert!(likelihood(&diligent(), hard) < likelihood(&diligent(), easy));
+        assert!(likelihood(&diligent(), hard) > 0.4);
+    }
+
+    #[test]
+    fn contributions_sum_to_likelihood() {
+        for query in [
+            BehaviorQuery::SeekSupport,
+            BehaviorQuery::ActAggressively { provocation: 0.4 },
+            BehaviorQuery::WithdrawSocially,
+            BehaviorQuery::PersistOnTask { difficulty: 0.7 },
+        ] {
+            let prediction = BehaviorPrediction::from_state(&IndividualState::new(), query);
+            let total: f64 = prediction
+                .contributors
+                .iter()
+                .map(|contributor| contributor.contribution)
+                .sum();
+            assert!((total - prediction.likelihood).abs() < 1e-12);
+            assert!((0.0..=1.0).contains(&prediction.likelihood));
+            assert_eq!(prediction.contributors.len(), 5);
+            assert!(prediction
+                .contributors
+                .windows(2)
+                .all(|pair| pair[0].contribution >= pair[1].contribution));
+            assert_eq!(
+                prediction,
+                BehaviorPrediction::from_state(&IndividualState::new(), query)
+            );
+        }
+    }
+}
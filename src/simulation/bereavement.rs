@@ -0,0 +1,221 @@
+//! Bereavement: losing someone the entity was close to.
+//!
+//! A `Loss` of a person naming the `lost_entity` ends the target's
+//! relationship with that person. When the relationship is attached to
+//! one of the target's relationship slots, the loss's loneliness is
+//! magnified by how close and attached the target was: the relationship's
+//! stage support weight scaled by dormancy closeness, times the strongest
+//! `BondType::attachment_weight` among its bonds. A relationship without
+//! bonds weighs as a peer. Losing an intimate partner therefore thwarts
+//! belonging far more than losing a colleague or an acquaintance.
+//!
+//! From the loss on, the relationship is dissolved: relationship queries
+//! report it as dissolved and it no longer buffers loneliness. Queries
+//! before the loss still see it intact.
+
+use crate::entity::Entity;
+use crate::enums::{BondType, EventPayload, LossType};
+use crate::event::Event;
+use crate::simulation::{Simulation, TimestampedEvent, TimestampedRelationship};
+use crate::types::{EntityId, RelationshipId, Timestamp};
+
+/// Returns the relationship `te` ends for `entity`, with how much losing
+/// it weighs (0-1).
+///
+/// This is the relationship in one of the entity's slots with the person
+/// `te` reports the entity lost, formed by the loss and not dissolved
+/// before it.
+pub(crate) fn lost_relationship(
+    simulation: &Simulation,
+    te: &TimestampedEvent,
+    entity: &Entity,
+) -> Option<(RelationshipId, f64)> {
+    let EventPayload::Loss {
+        loss_type: LossType::Person,
+        lost_entity: Some(lost),
+    } = te.event().payload()
+    else {
+        return None;
+    };
+    if te.event().target() != Some(entity.id()) {
+        return None;
+    }
+
+    entity
+        .relationship_slots()
+        .iter()
+        .filter_map(|slot| slot.get_attached())
+        .find_map(|id| {
+            let timestamped = simulation.get_relationship(&id)?;
+            let ends = timestamped.involves(lost)
+                && timestamped.involves(entity.id())
+                && timestamped.formed_timestamp() <= te.timestamp()
+                && dissolution_of(simulation, timestamped).is_none_or(|at| at >= te.timestamp());
+            ends.then(|| {
+                let weight = bereavement_weight(simulation, timestamped, te.timestamp());
+                (id, weight)
+            })
+        })
+}
+
+/// Returns when the relationship was dissolved, if it has been.
+///
+/// This is the earliest person loss at or after formation in which one
+/// party lost the other.
+pub(crate) fn dissolution_of(
+    simulation: &Simulation,
+    timestamped: &TimestampedRelationship,
+) -> Option<Timestamp> {
+    let (a, b) = (timestamped.entity_a(), timestamped.entity_b());
+    simulation
+        .events_for(a)
+        .into_iter()
+        .chain(simulation.events_for(b))
+        .filter(|te| {
+            te.timestamp() >= timestamped.formed_timestamp() && is_loss_of(te.event(), a, b)
+        })
+        .map(TimestampedEvent::timestamp)
+        .min()
+}
+
+/// Returns how much losing the other party of `timestamped` weighs at
+/// `timestamp`.
+fn bereavement_weight(
+    simulation: &Simulation,
+    timestamped: &TimestampedRelationship,
+    timestamp: Timestamp,
+) -> f64 {
+    let attachment = timestamped
+        .relationship()
+        .bonds()
+        .iter()
+        .map(BondType::attachment_weight)
+        .reduce(f64::max)
+        .unwrap_or(BondType::Peer.attachment_weight());
+    simulation
+        .activity_for(timestamped, timestamp)
+        .support_weight()
+        * attachment
+}
+
+/// Returns true if `event` is one of the pair losing the other.
+fn is_loss_of(event: &Event, a: &EntityId, b: &EntityId) -> bool {
+    let EventPayload::Loss {
+        loss_type: LossType::Person,
+        lost_entity: Some(lost),
+    } = event.payload()
+    else {
+        return false;
+    };
+    (event.target() == Some(a) && lost == b) || (event.target() == Some(b) && lost == a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::EntityBuilder;
+    use crate::enums::{EventType, RelationshipSchema, Species};
+    use crate::event::EventBuilder;
+    use crate::relationship::RelationshipStage;
+    use crate::types::Duration;
+
+    fn reference() -> Timestamp {
+        Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+    }
+
+    fn setup() -> (Simulation, EntityId, EntityId) {
+        let mut sim = Simulation::new(reference());
+        for id in ["alice", "bob"] {
+            let entity = EntityBuilder::new()
+                .id(id)
+                .species(Species::Human)
+                .build()
+                .unwrap();
+            sim.add_entity(entity, reference());
+        }
+        let alice = EntityId::new("alice").unwrap();
+        let bob = EntityId::new("bob").unwrap();
+        (sim, alice, bob)
+    }
+
+    fn loss(target: &EntityId, lost: &EntityId, at: Duration) -> TimestampedEvent {
+        let event = EventBuilder::new(EventType::Loss)
+            .target(target.clone())
+            .severity(0.8)
+            .payload(EventPayload::Loss {
+                loss_type: LossType::Person,
+                lost_entity: Some(lost.clone()),
+            })
+            .build()
+            .unwrap();
+        TimestampedEvent::new(event, reference() + at)
+    }
+
+    fn entity<'s>(sim: &'s Simulation, id: &EntityId) -> &'s Entity {
+        sim.get_anchored_entity(id).unwrap().entity()
+    }
+
+    #[test]
+    fn closer_bonds_weigh_more() {
+        let (mut sim, alice, bob) = setup();
+        let rel_id = sim.add_relationship(
+            alice.clone(),
+            bob.clone(),
+            RelationshipSchema::Peer,
+            reference(),
+        );
+        let te = loss(&alice, &bob, Duration::days(10));
+
+        let (found, peer) = lost_relationship(&sim, &te, entity(&sim, &alice)).unwrap();
+        assert_eq!(found, rel_id);
+
+        let relationship = sim
+            .get_relationship_mut(&rel_id)
+            .unwrap()
+            .relationship_mut();
+        relationship.add_bond(BondType::Romantic);
+        relationship.set_stage(RelationshipStage::Intimate).unwrap();
+        let (_, partner) = lost_relationship(&sim, &te, entity(&sim, &alice)).unwrap();
+        assert!(partner > peer);
+        assert!((partner - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn only_the_target_losing_a_related_person_is_bereaved() {
+        let (mut sim, alice, bob) = setup();
+        let carol = EntityId::new("carol").unwrap();
+        let te = loss(&alice, &bob, Duration::days(10));
+        assert!(lost_relationship(&sim, &te, entity(&sim, &alice)).is_none());
+
+        sim.add_relationship(
+            alice.clone(),
+            bob.clone(),
+            RelationshipSchema::Peer,
+            reference(),
+        );
+        assert!(lost_relationship(&sim, &te, entity(&sim, &bob)).is_none());
+        let stranger = loss(&alice, &carol, Duration::days(10));
+        assert!(lost_relationship(&sim, &stranger, entity(&sim, &alice)).is_none());
+    }
+
+    #[test]
+    fn earliest_loss_after_formation_dissolves() {
+        let (mut sim, alice, bob) = setup();
+        let formed = reference() + Duration::days(5);
+        let rel_id =
+            sim.add_relationship(alice.clone(), bob.clone(), RelationshipSchema::Peer, formed);
+        for (target, lost, day) in [(&alice, &bob, 1), (&bob, &alice, 30), (&alice, &bob, 20)] {
+            let te = loss(target, lost, Duration::days(day));
+            sim.add_event(te.event().clone(), te.timestamp());
+        }
+
+        let timestamped = sim.get_relationship(&rel_id).unwrap();
+        assert_eq!(
+            dissolution_of(&sim, timestamped),
+            Some(reference() + Duration::days(20))
+        );
+        // The loss that already dissolved it cannot end it again
+        let later = loss(&bob, &alice, Duration::days(30));
+        assert!(lost_relationship(&sim, &later, entity(&sim, &bob)).is_none());
+    }
+}
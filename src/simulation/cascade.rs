@@ -0,0 +1,223 @@
+//! Events that set off further events.
+//!
+//! A betrayal breeds a grievance, and the grievance a conflict. A
+//! `CascadeRule` states one such link: an event of a given type at or
+//! above a severity threshold is followed, after a delay, by a copy of a
+//! template event. `Simulation::enable_cascades` installs the rules, and
+//! each event added from then on is expanded into its cascade before it is
+//! stored, so `state_at()` applies the follow-on events like any other.
+//!
+//! Follow-on events are stored events flagged as synthetic
+//! (`TimestampedEvent::is_synthetic()`), so `events_for()` lists them with
+//! the event that set them off. A follow-on can itself trigger rules, for
+//! at most `MAX_CASCADE_DEPTH` generations, so rules that trigger each
+//! other end rather than loop.
+
+use crate::enums::EventType;
+use crate::event::{Event, MAX_CASCADE_DEPTH};
+use crate::simulation::TimestampedEvent;
+use crate::types::{Duration, EventId};
+
+/// A rule that follows a triggering event with another event.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::enums::EventType;
+/// use behavioral_pathways::event::EventBuilder;
+/// use behavioral_pathways::simulation::CascadeRule;
+/// use behavioral_pathways::types::Duration;
+///
+/// let conflict = EventBuilder::new(EventType::Conflict).severity(0.5).build().unwrap();
+/// let rule = CascadeRule::new(EventType::Betrayal, 0.6, conflict, Duration::days(3));
+///
+/// let minor = EventBuilder::new(EventType::Betrayal).severity(0.3).build().unwrap();
+/// let major = EventBuilder::new(EventType::Betrayal).severity(0.8).build().unwrap();
+/// assert!(!rule.fires_on(&minor));
+/// assert!(rule.fires_on(&major));
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CascadeRule {
+    /// Type of the events that trigger the rule.
+    trigger: EventType,
+    /// Lowest severity (0-1) that triggers the rule.
+    min_severity: f64,
+    /// The event each follow-on copies.
+    follow_on: Event,
+    /// Time from the trigger to its follow-on.
+    delay: Duration,
+}
+
+impl CascadeRule {
+    /// Creates a rule following each `trigger` event with severity at or
+    /// above `min_severity` by a copy of `follow_on`, `delay` later.
+    ///
+    /// The copy targets the triggering event's target. It keeps the
+    /// template's source, or takes the trigger's source if the template
+    /// has none.
+    #[must_use]
+    pub fn new(trigger: EventType, min_severity: f64, follow_on: Event, delay: Duration) -> Self {
+        CascadeRule {
+            trigger,
+            min_severity,
+            follow_on,
+            delay,
+        }
+    }
+
+    /// Returns the type of the events that trigger the rule.
+    #[must_use]
+    pub fn trigger(&self) -> EventType {
+        self.trigger
+    }
+
+    /// Returns the lowest severity that triggers the rule.
+    #[must_use]
+    pub fn min_severity(&self) -> f64 {
+        self.min_severity
+    }
+
+    /// Returns the event each follow-on copies.
+    #[must_use]
+    pub fn follow_on(&self) -> &Event {
+        &self.follow_on
+    }
+
+    /// Returns the time from the trigger to its follow-on.
+    #[must_use]
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    /// Returns true if `event` triggers the rule.
+    #[must_use]
+    pub fn fires_on(&self, event: &Event) -> bool {
+        event.event_type() == self.trigger && event.severity() >= self.min_severity
+    }
+
+    /// Returns the follow-on of `trigger` under the rule at `index`.
+    ///
+    /// Its ID is the trigger's ID followed by `_cascade_` and the index.
+    fn follow_on_for(&self, index: usize, trigger: &TimestampedEvent) -> TimestampedEvent {
+        let mut event = self.follow_on.clone();
+        let id = EventId::new(format!("{}_cascade_{}", trigger.event().id(), index))
+            .expect("follow-on of a valid event ID is valid");
+        event.set_id(id);
+        event.set_target(trigger.event().target().cloned());
+        if event.source().is_none() {
+            event.set_source(trigger.event().source().cloned());
+        }
+        TimestampedEvent::synthetic(
+            event,
+            trigger.timestamp() + self.delay,
+            trigger.event().id().clone(),
+        )
+    }
+}
+
+/// Returns the follow-on events `root` sets off under `rules`, generation
+/// by generation, for at most `MAX_CASCADE_DEPTH` generations.
+///
+/// Within a generation, follow-ons are ordered by trigger, then by rule.
+pub(crate) fn cascade_from(
+    rules: &[CascadeRule],
+    root: &TimestampedEvent,
+) -> Vec<TimestampedEvent> {
+    if rules.is_empty() {
+        return Vec::new();
+    }
+    let mut cascade = Vec::new();
+    let mut generation = vec![root.clone()];
+    for _ in 0..MAX_CASCADE_DEPTH {
+        let next: Vec<TimestampedEvent> = generation
+            .iter()
+            .flat_map(|trigger| {
+                rules
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, rule)| rule.fires_on(trigger.event()))
+                    .map(move |(index, rule)| rule.follow_on_for(index, trigger))
+            })
+            .collect();
+        if next.is_empty() {
+            break;
+        }
+        cascade.extend(next.iter().cloned());
+        generation = next;
+    }
+    cascade
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::EventBuilder;
+    use crate::types::{EntityId, Timestamp};
+
+    fn start() -> Timestamp {
+        Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+    }
+
+    fn template(event_type: EventType) -> Event {
+        EventBuilder::new(event_type).severity(0.7).build().unwrap()
+    }
+
+    fn betrayal(severity: f64) -> TimestampedEvent {
+        let event = EventBuilder::new(EventType::Betrayal)
+            .id(EventId::new("betrayal").unwrap())
+            .source(EntityId::new("friend").unwrap())
+            .target(EntityId::new("person").unwrap())
+            .severity(severity)
+            .build()
+            .unwrap();
+        TimestampedEvent::new(event, start())
+    }
+
+    #[test]
+    fn follow_on_inherits_target_and_source() {
+        let rules = [CascadeRule::new(
+            EventType::Betrayal,
+            0.5,
+            template(EventType::Conflict),
+            Duration::days(3),
+        )];
+        let cascade = cascade_from(&rules, &betrayal(0.8));
+        assert_eq!(cascade.len(), 1);
+
+        let conflict = &cascade[0];
+        assert_eq!(conflict.timestamp(), start() + Duration::days(3));
+        assert_eq!(conflict.event().id().as_str(), "betrayal_cascade_0");
+        assert_eq!(conflict.event().target().unwrap().as_str(), "person");
+        assert_eq!(conflict.event().source().unwrap().as_str(), "friend");
+        assert_eq!(conflict.cascade_trigger().unwrap().as_str(), "betrayal");
+        assert!(conflict.is_synthetic());
+
+        assert!(cascade_from(&rules, &betrayal(0.4)).is_empty());
+    }
+
+    #[test]
+    fn mutually_triggering_rules_stop_at_max_depth() {
+        let rules = [
+            CascadeRule::new(
+                EventType::Betrayal,
+                0.0,
+                template(EventType::Conflict),
+                Duration::days(1),
+            ),
+            CascadeRule::new(
+                EventType::Conflict,
+                0.0,
+                template(EventType::Betrayal),
+                Duration::days(1),
+            ),
+        ];
+        let cascade = cascade_from(&rules, &betrayal(0.8));
+        assert_eq!(cascade.len(), MAX_CASCADE_DEPTH);
+        let last = cascade.last().unwrap();
+        assert_eq!(
+            last.timestamp(),
+            start() + Duration::days(MAX_CASCADE_DEPTH as u64)
+        );
+    }
+}
@@ -0,0 +1,677 @@
+//! Folding old, fully decayed events into a per-entity checkpoint.
+//!
+//! Long monitoring deployments accumulate event history that no longer
+//! matters: an event many half-lives old has decayed to nothing and only
+//! ever reached the state through its deltas. `Simulation::compact` removes
+//! such events and re-anchors event processing at the cutoff. Each entity
+//! keeps a checkpoint holding its decayed state with every event increment
+//! up to the cutoff applied, and queries after the cutoff project from the
+//! checkpoint, so their results are bit-for-bit unchanged.
+//!
+//! Events that reach the state through anything other than decaying deltas
+//! are never folded: trauma (acquired capability is not reversible),
+//! formative base shifts, chronic-pattern events, role exits, health
+//! changes and caregiving, concealments and disclosures, recognition
+//! before an audience and the falls that contradict it, witnessed events,
+//! which sit in several entities' histories, and contact between related
+//! entities, which drives relationship dormancy. They stay in the event
+//! log verbatim.
+//!
+//! Queries before the cutoff no longer see the folded events, so they are
+//! flagged `RegressionQuality::Approximate` with
+//! `ApproximationReason::CompactedHistory`.
+//!
+//! An event added later that lands at or before a checkpoint is folded
+//! into the checkpoint as of its own time: each of its increments is
+//! applied, then decayed over the time from its landing to the resume
+//! point.
+
+use crate::context::{HealthCondition, RoleExit};
+use crate::entity::Entity;
+use crate::enums::{Audience, EventPayload, EventTag, EventType, StatePath, SupportType};
+use crate::event::Event;
+use crate::processor::{
+    advance_state, amplify_bereavement, amplify_fall, apply_anticipation_to_state,
+    apply_developmental_effects, apply_interpreted_event_to_state, decay_processor_for,
+    interpret_event,
+};
+use crate::relationship::is_contact_event;
+use crate::simulation::recognition::contradicted_recognition;
+use crate::simulation::sleep_loss::{amplify_after_sleep_loss, preceding_sleep_loss};
+use crate::simulation::state_query::development_age_at;
+use crate::simulation::{AnchoredEntity, Simulation, StochasticConfig, TimestampedEvent};
+use crate::state::IndividualState;
+use crate::types::{EntityId, EventId, RelationshipId, Timestamp};
+use std::fmt;
+
+/// Largest change to any state path, at the cutoff, of an event that
+/// counts as fully decayed.
+pub const COMPACTION_EPSILON: f64 = 1e-4;
+
+/// How many events `Simulation::compact` folded.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompactionReport {
+    /// Events folded per entity, in entity ID order, omitting entities
+    /// with none.
+    folded: Vec<(EntityId, usize)>,
+}
+
+impl CompactionReport {
+    /// Returns the total number of events folded.
+    #[must_use]
+    pub fn folded(&self) -> usize {
+        self.folded.iter().map(|(_, count)| count).sum()
+    }
+
+    /// Returns the number of events folded for one entity.
+    #[must_use]
+    pub fn folded_for(&self, entity_id: &EntityId) -> usize {
+        self.folded
+            .iter()
+            .find(|(id, _)| id == entity_id)
+            .map_or(0, |(_, count)| *count)
+    }
+
+    /// Returns the events folded per entity, in entity ID order.
+    #[must_use]
+    pub fn per_entity(&self) -> &[(EntityId, usize)] {
+        &self.folded
+    }
+}
+
+/// Errors from `Simulation::rebase_entity`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RebaseError {
+    /// The simulation has no entity with this ID.
+    UnknownEntity(EntityId),
+    /// The requested anchor is before the entity's current anchor or an
+    /// earlier rebase or compaction cutoff.
+    BeforeAnchor {
+        /// The entity's current anchor.
+        anchor: Timestamp,
+        /// The anchor that was requested.
+        requested: Timestamp,
+    },
+}
+
+impl fmt::Display for RebaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RebaseError::UnknownEntity(entity) => {
+                write!(f, "Entity '{}' is not in the simulation", entity.as_str())
+            }
+            RebaseError::BeforeAnchor { anchor, requested } => write!(
+                f,
+                "Cannot rebase to {}, before the current anchor {}",
+                requested, anchor
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RebaseError {}
+
+/// An entity's decayed state with every event increment up to a resume
+/// point applied, before context and other hooks.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct Checkpoint {
+    /// The known state (anchor or observation) it was projected from.
+    from: Timestamp,
+    /// When the last increment folded into `state` landed.
+    resume_at: Timestamp,
+    /// The state at `resume_at`.
+    state: IndividualState,
+}
+
+impl Checkpoint {
+    pub(crate) fn new(from: Timestamp, resume_at: Timestamp, state: IndividualState) -> Self {
+        Checkpoint {
+            from,
+            resume_at,
+            state,
+        }
+    }
+
+    pub(crate) fn from(&self) -> Timestamp {
+        self.from
+    }
+
+    pub(crate) fn resume_at(&self) -> Timestamp {
+        self.resume_at
+    }
+
+    pub(crate) fn state(&self) -> &IndividualState {
+        &self.state
+    }
+}
+
+/// What compaction has folded for one entity.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct CompactedHistory {
+    /// The latest cutoff that folded any of the entity's events.
+    cutoff: Timestamp,
+    /// Where queries after the cutoff resume, if any increments landed
+    /// between the entity's known state and the cutoff.
+    checkpoint: Option<Checkpoint>,
+}
+
+impl CompactedHistory {
+    pub(crate) fn cutoff(&self) -> Timestamp {
+        self.cutoff
+    }
+
+    pub(crate) fn checkpoint(&self) -> Option<&Checkpoint> {
+        self.checkpoint.as_ref()
+    }
+}
+
+/// Folds every entity's fully decayed events older than `cutoff`.
+pub(crate) fn compact(simulation: &mut Simulation, cutoff: Timestamp) -> CompactionReport {
+    let mut ids: Vec<EntityId> = simulation
+        .entities()
+        .map(|anchored| anchored.entity().id().clone())
+        .collect();
+    ids.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+
+    let mut report = CompactionReport::default();
+    let mut folded_positions = Vec::new();
+    let mut histories = Vec::new();
+    for id in ids {
+        let positions = foldable_positions(simulation, &id, cutoff);
+        if positions.is_empty() {
+            continue;
+        }
+        // The history is computed before anything is removed, so its
+        // checkpoint includes the folded events
+        histories.push((id.clone(), history_at(simulation, &id, cutoff)));
+        report.folded.push((id, positions.len()));
+        folded_positions.extend(positions);
+    }
+
+    simulation.remove_events(&folded_positions);
+    for (id, history) in histories {
+        if let Some(anchored) = simulation.anchored_mut(&id) {
+            anchored.set_compacted_history(history);
+        }
+    }
+    report
+}
+
+/// Re-anchors one entity's event processing at `anchor`, folding its fully
+/// decayed events before it.
+///
+/// Unlike `compact`, the checkpoint is recorded even when nothing is
+/// folded.
+pub(crate) fn rebase(
+    simulation: &mut Simulation,
+    id: &EntityId,
+    anchor: Timestamp,
+) -> Result<(), RebaseError> {
+    let Some(anchored) = simulation.get_anchored_entity(id) else {
+        return Err(RebaseError::UnknownEntity(id.clone()));
+    };
+    let current = anchored
+        .compacted_history()
+        .map_or(anchored.anchor_timestamp(), CompactedHistory::cutoff)
+        .max(anchored.anchor_timestamp());
+    if anchor < current {
+        return Err(RebaseError::BeforeAnchor {
+            anchor: current,
+            requested: anchor,
+        });
+    }
+
+    let positions = foldable_positions(simulation, id, anchor);
+    let history = history_at(simulation, id, anchor);
+    simulation.remove_events(&positions);
+    // Queries before the checkpoint change, so the entity gets a new
+    // generation. Its anchor timestamp is left as it was: the checkpoint,
+    // not the anchor state, is what later queries project from.
+    if let Some(anchored) = simulation.get_anchored_entity_mut(id) {
+        anchored.set_compacted_history(history);
+    }
+    Ok(())
+}
+
+/// Recomputes every entity's checkpoint at its cutoff from the events
+/// still in the log, after a change to how events are interpreted.
+///
+/// The folded events were fully decayed at the cutoff, so the rebuilt
+/// checkpoints differ from a replay of the whole history by no more than
+/// they did.
+pub(crate) fn rebuild_checkpoints(simulation: &mut Simulation) {
+    let compacted: Vec<(EntityId, Timestamp)> = simulation
+        .entities()
+        .filter_map(|anchored| {
+            let cutoff = anchored.compacted_history()?.cutoff();
+            Some((anchored.entity().id().clone(), cutoff))
+        })
+        .collect();
+    for (id, cutoff) in compacted {
+        if let Some(history) = simulation
+            .anchored_mut(&id)
+            .and_then(AnchoredEntity::compacted_history_mut)
+        {
+            history.checkpoint = None;
+        }
+        let checkpoint = simulation
+            .entity(&id)
+            .and_then(|handle| handle.compaction_checkpoint(cutoff));
+        if let Some(history) = simulation
+            .anchored_mut(&id)
+            .and_then(AnchoredEntity::compacted_history_mut)
+        {
+            history.checkpoint = checkpoint;
+        }
+    }
+}
+
+/// Returns the entity's compacted history with its checkpoint moved to
+/// `cutoff`.
+fn history_at(simulation: &Simulation, id: &EntityId, cutoff: Timestamp) -> CompactedHistory {
+    let handle = simulation.entity(id).expect("entity is in the simulation");
+    let checkpoint = handle.compaction_checkpoint(cutoff);
+    let previous = handle
+        .compacted_history()
+        .map(CompactedHistory::cutoff)
+        .unwrap_or(cutoff);
+    CompactedHistory {
+        cutoff: previous.max(cutoff),
+        checkpoint,
+    }
+}
+
+/// Returns the positions in the event log of the entity's events that
+/// compaction may fold.
+fn foldable_positions(simulation: &Simulation, id: &EntityId, cutoff: Timestamp) -> Vec<usize> {
+    let Some(anchored) = simulation.get_anchored_entity(id) else {
+        return Vec::new();
+    };
+    let entity = anchored.entity();
+    let mut probe = entity.clone();
+    probe.individual_state_mut().reset_all_deltas();
+    let baseline = probe.individual_state().clone();
+    let baseline_values: Vec<(StatePath, f64)> = StatePath::all()
+        .into_iter()
+        .filter_map(|path| Some((path, probe.get_effective(path)?)))
+        .collect();
+
+    simulation
+        .event_positions_for(id)
+        .filter(|&position| {
+            let te = simulation.event_at(position);
+            let Some(landed) = last_landing(te) else {
+                return false;
+            };
+            landed < cutoff
+                && !must_keep(simulation, te, entity)
+                && is_fully_decayed(
+                    &mut probe,
+                    &baseline,
+                    &baseline_values,
+                    te,
+                    cutoff,
+                    simulation.stochastic_config(),
+                )
+        })
+        .collect()
+}
+
+/// Returns when the event's last onset increment lands.
+fn last_landing(te: &TimestampedEvent) -> Option<Timestamp> {
+    let increments = te.event().onset_increments();
+    increments
+        .last()
+        .map(|(offset, _)| te.timestamp() + *offset)
+}
+
+/// Returns true if the event reaches the state through anything other
+/// than decaying deltas, or drives relationship activity or dissolution.
+fn must_keep(simulation: &Simulation, te: &TimestampedEvent, entity: &Entity) -> bool {
+    let event = te.event();
+    if event.is_trauma() || event.has_base_shifts() || event.has_tag(EventTag::ChronicPattern) {
+        return true;
+    }
+    // A witnessed event is in several histories, and folding it for one
+    // entity would remove it from the others
+    if !event.witnesses().is_empty() {
+        return true;
+    }
+    if interpret_event(event, entity).acquired_capability_delta != 0.0 {
+        return true;
+    }
+    // Later falls look recognition up in the log, and a fall's effect
+    // depends on it
+    if event.payload().audience() != Audience::Private
+        || contradicted_recognition(simulation, te, None).is_some()
+    {
+        return true;
+    }
+    // Likewise for later events felt more harshly after sleep loss
+    if event.event_type() == EventType::SleepDeprivation
+        || preceding_sleep_loss(simulation, te, None).is_some()
+    {
+        return true;
+    }
+    if RoleExit::from_event(event, te.timestamp()).is_some()
+        || HealthCondition::from_event(event, te.timestamp()).is_some()
+        || matches!(
+            event.payload(),
+            EventPayload::HealthRemission { .. }
+                | EventPayload::Concealment { ongoing: true, .. }
+                | EventPayload::Disclosure { .. }
+                | EventPayload::Loss {
+                    lost_entity: Some(_),
+                    ..
+                }
+                | EventPayload::Support {
+                    support_type: SupportType::Instrumental,
+                    ..
+                }
+        )
+    {
+        return true;
+    }
+    simulation
+        .relationships()
+        .any(|rel| is_contact_event(event, rel.entity_a(), rel.entity_b()))
+}
+
+/// Returns true if, applied alone to the entity's delta-free state, the
+/// event changes no state path by more than `COMPACTION_EPSILON` by the
+/// cutoff.
+///
+/// The whole effect is applied at onset, which overstates what is left of
+/// a ramped event.
+fn is_fully_decayed(
+    probe: &mut Entity,
+    baseline: &IndividualState,
+    baseline_values: &[(StatePath, f64)],
+    te: &TimestampedEvent,
+    cutoff: Timestamp,
+    stochastic: Option<&StochasticConfig>,
+) -> bool {
+    *probe.individual_state_mut() = baseline.clone();
+    let mut interpreted = interpret_event(te.event(), probe);
+    if let Some(stochastic) = stochastic {
+        stochastic.perturb(&mut interpreted, probe.id());
+    }
+    let age_days = development_age_at(probe, te.timestamp()).as_days();
+    let dev_factor = apply_developmental_effects(probe, te.event(), 1.0, age_days, te.timestamp());
+    let mut state =
+        apply_interpreted_event_to_state(baseline.clone(), &interpreted.scaled_by(dev_factor));
+    let elapsed = probe.species().psychological_duration(cutoff - te.timestamp());
+    state = advance_state(state, elapsed, decay_processor_for(probe.species()));
+    *probe.individual_state_mut() = state;
+
+    baseline_values.iter().all(|(path, baseline)| {
+        probe
+            .get_effective(*path)
+            .is_none_or(|value| (value - baseline).abs() <= COMPACTION_EPSILON)
+    })
+}
+
+/// Applies an event added after compaction to the target's checkpoint, for
+/// the increments that land between the checkpoint's known state and its
+/// resume point.
+///
+/// Each increment is applied at its landing and decayed to the resume
+/// point, as if it had been in the log when the checkpoint was taken.
+///
+/// `recognition` is the earlier recognition the event contradicts,
+/// `sleep_loss` the sleep deprivation it follows, and `bereavement` the
+/// relationship it ends with its weight, if any. `stochastic` is the
+/// simulation's noise on interpreted deltas.
+pub(crate) fn absorb_late_event(
+    anchored: &mut AnchoredEntity,
+    te: &TimestampedEvent,
+    recognition: Option<(EventId, Audience)>,
+    sleep_loss: Option<Event>,
+    bereavement: Option<(RelationshipId, f64)>,
+    stochastic: Option<StochasticConfig>,
+) {
+    let Some(checkpoint) = anchored
+        .compacted_history()
+        .and_then(CompactedHistory::checkpoint)
+    else {
+        return;
+    };
+    let resume_at = checkpoint.resume_at();
+    let anticipation = te
+        .event()
+        .anticipation_increments()
+        .into_iter()
+        .map(|(lead, fraction)| (te.timestamp() - lead, fraction, true));
+    let onset = te
+        .event()
+        .onset_increments()
+        .into_iter()
+        .map(|(offset, fraction)| (te.timestamp() + offset, fraction, false));
+    let mut late: Vec<(Timestamp, f64, bool)> = anticipation
+        .chain(onset)
+        .filter(|(landing, _, _)| *landing > checkpoint.from() && *landing <= resume_at)
+        .collect();
+    late.sort_by_key(|(landing, _, _)| *landing);
+    if late.is_empty() {
+        return;
+    }
+
+    let entity = match anchored
+        .observations()
+        .iter()
+        .find(|observation| observation.timestamp() == checkpoint.from())
+    {
+        Some(observation) => anchored.entity_as_observed(observation),
+        None => anchored.entity().clone(),
+    };
+    let mut interpreted = interpret_event(te.event(), &entity);
+    if let Some((recognition, audience)) = recognition {
+        amplify_fall(&mut interpreted, recognition, audience);
+    }
+    if let Some(sleep_loss) = sleep_loss {
+        amplify_after_sleep_loss(&mut interpreted, &sleep_loss, &entity);
+    }
+    if let Some((relationship, weight)) = bereavement {
+        amplify_bereavement(&mut interpreted, relationship, weight);
+    }
+    if let Some(stochastic) = stochastic {
+        stochastic.perturb(&mut interpreted, entity.id());
+    }
+    let age_days = development_age_at(&entity, te.timestamp()).as_days();
+    let dev_factor =
+        apply_developmental_effects(&entity, te.event(), 1.0, age_days, te.timestamp());
+    let processor = decay_processor_for(entity.species());
+    let mut state = checkpoint.state().clone();
+    for (landing, fraction, anticipatory) in late {
+        let apply = |state: IndividualState| {
+            if anticipatory {
+                apply_anticipation_to_state(state, &interpreted, dev_factor * fraction)
+            } else {
+                apply_interpreted_event_to_state(
+                    state,
+                    &interpreted.scaled_by(dev_factor * fraction),
+                )
+            }
+        };
+        // The increment alone, on a delta-free state, before and after
+        // decaying to the resume point
+        let mut alone = state.clone();
+        alone.reset_all_deltas();
+        let landed = apply(alone);
+        let elapsed = entity.species().psychological_duration(resume_at - landing);
+        let decayed = advance_state(landed.clone(), elapsed, processor);
+
+        state = apply(state);
+        for path in StatePath::all() {
+            let (Some(landed), Some(decayed)) =
+                (landed.state_value(path), decayed.state_value(path))
+            else {
+                continue;
+            };
+            if let Some(value) = state.state_value_mut(path) {
+                value.add_delta(decayed.delta() - landed.delta());
+                value.add_chronic_delta(decayed.chronic_delta() - landed.chronic_delta());
+            }
+        }
+    }
+    if let Some(checkpoint) = anchored
+        .compacted_history_mut()
+        .and_then(|history| history.checkpoint.as_mut())
+    {
+        checkpoint.state = state;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::EntityBuilder;
+    use crate::enums::{EventType, Species};
+    use crate::event::{Event, EventBuilder};
+    use crate::types::Duration;
+
+    fn reference() -> Timestamp {
+        Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+    }
+
+    fn event(id: &EntityId, event_type: EventType) -> Event {
+        EventBuilder::new(event_type)
+            .target(id.clone())
+            .severity(0.6)
+            .build()
+            .unwrap()
+    }
+
+    fn setup() -> (Simulation, EntityId) {
+        let mut sim = Simulation::new(reference());
+        let entity = EntityBuilder::new()
+            .id("maya")
+            .species(Species::Human)
+            .birth_date(Timestamp::from_ymd_hms(1990, 1, 1, 0, 0, 0))
+            .build()
+            .unwrap();
+        let id = sim.add_entity(entity, reference());
+        (sim, id)
+    }
+
+    #[test]
+    fn folds_only_decayed_events() {
+        let (mut sim, id) = setup();
+        sim.add_event(event(&id, EventType::SocialExclusion), reference());
+        let cutoff = reference() + Duration::years(1);
+        // Only a day old at the cutoff, so still felt
+        sim.add_event(
+            event(&id, EventType::SocialExclusion),
+            cutoff - Duration::days(1),
+        );
+
+        let report = sim.compact(cutoff);
+        assert_eq!(report.folded(), 1);
+        assert_eq!(report.per_entity(), &[(id.clone(), 1)]);
+        assert_eq!(report.folded_for(&EntityId::new("other").unwrap()), 0);
+        assert_eq!(sim.events_for(&id).len(), 1);
+    }
+
+    #[test]
+    fn keeps_trauma_regardless_of_age() {
+        let (mut sim, id) = setup();
+        sim.add_event(event(&id, EventType::Violence), reference());
+
+        let report = sim.compact(reference() + Duration::years(10));
+        assert_eq!(report.folded(), 0);
+        assert_eq!(sim.events_for(&id).len(), 1);
+        assert!(sim.entity(&id).unwrap().compacted_history().is_none());
+    }
+
+    #[test]
+    fn late_event_before_checkpoint_is_absorbed() {
+        let (mut sim, id) = setup();
+        sim.add_event(event(&id, EventType::SocialExclusion), reference());
+        let cutoff = reference() + Duration::years(1);
+        sim.add_event(
+            event(&id, EventType::SocialExclusion),
+            cutoff - Duration::days(1),
+        );
+        let mut compacted = sim.clone();
+        assert_eq!(compacted.compact(cutoff).folded(), 1);
+
+        // An event added afterwards, landing before the checkpoint's
+        // resume point, is applied to the checkpoint
+        let late = event(&id, EventType::SocialInclusion);
+        let late_at = reference() + Duration::days(100);
+        sim.add_event(late.clone(), late_at);
+        compacted.add_event(late, late_at);
+
+        let at = cutoff + Duration::days(30);
+        let uncompacted = sim.entity(&id).unwrap().state_at(at);
+        let projected = compacted.entity(&id).unwrap().state_at(at);
+        for path in StatePath::all() {
+            let diff = uncompacted.get_effective(path) - projected.get_effective(path);
+            assert!(diff.abs() < 1e-3, "{:?} differs by {}", path, diff);
+        }
+    }
+
+    #[test]
+    fn late_event_decays_from_its_own_time() {
+        let (mut sim, id) = setup();
+        sim.add_event(event(&id, EventType::SocialExclusion), reference());
+        let cutoff = reference() + Duration::years(1);
+        sim.add_event(
+            event(&id, EventType::SocialExclusion),
+            cutoff - Duration::days(1),
+        );
+        let mut compacted = sim.clone();
+        assert_eq!(compacted.compact(cutoff).folded(), 1);
+
+        // Two months before the resume point, so most of it has decayed
+        // by the time queries after the cutoff pick it up
+        let late = event(&id, EventType::Humiliation);
+        let late_at = cutoff - Duration::days(60);
+        sim.add_event(late.clone(), late_at);
+        compacted.add_event(late, late_at);
+
+        let at = cutoff + Duration::days(1);
+        let uncompacted = sim.entity(&id).unwrap().state_at(at);
+        let projected = compacted.entity(&id).unwrap().state_at(at);
+        for path in StatePath::all() {
+            let diff = uncompacted.get_effective(path) - projected.get_effective(path);
+            assert!(diff.abs() < 1e-4, "{:?} differs by {}", path, diff);
+        }
+    }
+
+    #[test]
+    fn rebase_checkpoints_without_folding() {
+        let (mut sim, id) = setup();
+        let anchor = reference() + Duration::days(10);
+        sim.add_event(
+            event(&id, EventType::SocialExclusion),
+            anchor - Duration::days(1),
+        );
+
+        sim.rebase_entity(&id, anchor).unwrap();
+        assert_eq!(sim.events_for(&id).len(), 1);
+        let history = sim.entity(&id).unwrap().compacted_history().unwrap();
+        assert_eq!(history.cutoff(), anchor);
+        assert!(history.checkpoint().is_some());
+    }
+
+    #[test]
+    fn rebase_rejects_unknown_entities_and_earlier_anchors() {
+        let (mut sim, id) = setup();
+        let unknown = EntityId::new("nobody").unwrap();
+        assert_eq!(
+            sim.rebase_entity(&unknown, reference()),
+            Err(RebaseError::UnknownEntity(unknown))
+        );
+
+        let anchor = reference() + Duration::days(10);
+        sim.rebase_entity(&id, anchor).unwrap();
+        let requested = reference() + Duration::days(5);
+        let err = sim.rebase_entity(&id, requested).unwrap_err();
+        assert_eq!(err, RebaseError::BeforeAnchor { anchor, requested });
+        assert!(err.to_string().contains("before the current anchor"));
+        assert!(sim.rebase_entity(&id, anchor).is_ok());
+    }
+}
@@ -0,0 +1,363 @@
+//! Comparison of two entities for twin and sibling designs.
+//!
+//! Behavioral-genetics designs compare two entities who share a context but
+//! differ in traits or experience. [`EntityComparison`] reports where the
+//! pair differs at a point in time (state paths, event histories, shared
+//! microsystems, HEXACO bases) and attributes each path's difference to
+//! three components:
+//!
+//! - **Traits**: the entities' HEXACO values.
+//! - **Events**: the events targeting each entity.
+//! - **Anchor**: everything else about the entity, such as its anchored
+//!   state, context, age, and species.
+//!
+//! The attribution swaps components between the pair. Each of the eight
+//! combinations of A's and B's components is projected on a copy of the
+//! simulation, with the swapped events retargeted to the entity being
+//! projected. A component's share is its average marginal effect over
+//! every order in which the three can be swapped from A's to B's (the
+//! Shapley value), so the three shares always sum to the difference and
+//! do not depend on which entity is compared to which beyond their sign.
+//!
+//! Mood baselines derived from personality at build time belong to the
+//! anchored state, not to the traits.
+
+use crate::enums::{HexacoPath, StatePath};
+use crate::simulation::{ComputedState, Simulation, TimestampedEvent, STATE_DIFF_EPSILON};
+use crate::types::{EntityId, EventId, MicrosystemId, Timestamp};
+
+/// Swap taking traits from B.
+const TRAITS: usize = 1;
+/// Swap taking events from B.
+const EVENTS: usize = 2;
+/// Swap taking the anchored entity from B.
+const ANCHOR: usize = 4;
+
+/// One state path compared between two entities.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathComparison {
+    /// The compared path.
+    pub path: StatePath,
+    /// Effective value for entity A.
+    pub a: f64,
+    /// Effective value for entity B.
+    pub b: f64,
+    /// Share of `b - a` due to HEXACO traits.
+    pub traits: f64,
+    /// Share of `b - a` due to event histories.
+    pub events: f64,
+    /// Share of `b - a` due to the rest of the anchored entity.
+    pub anchor: f64,
+}
+
+impl PathComparison {
+    /// Returns B's value minus A's.
+    #[must_use]
+    pub fn difference(&self) -> f64 {
+        self.b - self.a
+    }
+}
+
+/// Structured comparison of two entities at a timestamp.
+///
+/// Produced by [`Simulation::compare_entities`]. Differences are B minus A.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntityComparison {
+    /// Entity A.
+    pub a: EntityId,
+    /// Entity B.
+    pub b: EntityId,
+    /// When the entities are compared.
+    pub at: Timestamp,
+    /// Every state path, in `StatePath::all()` order.
+    pub paths: Vec<PathComparison>,
+    /// Events both entities experienced: pairs of an event targeting A and
+    /// one targeting B with the same type and timestamp.
+    pub shared_events: Vec<(EventId, EventId)>,
+    /// Events targeting only A, in timestamp order.
+    pub events_only_a: Vec<EventId>,
+    /// Events targeting only B, in timestamp order.
+    pub events_only_b: Vec<EventId>,
+    /// Microsystems present in both entities' contexts, sorted.
+    pub shared_microsystems: Vec<MicrosystemId>,
+    /// HEXACO bases that differ by more than `STATE_DIFF_EPSILON`.
+    pub hexaco_differences: Vec<(HexacoPath, f64)>,
+}
+
+impl EntityComparison {
+    /// Returns the comparison for one path.
+    #[must_use]
+    pub fn path(&self, path: StatePath) -> Option<&PathComparison> {
+        self.paths.iter().find(|comparison| comparison.path == path)
+    }
+}
+
+/// Compares `a` and `b` at `at`, or returns `None` if either is missing.
+pub(crate) fn compare_entities(
+    simulation: &Simulation,
+    a: &EntityId,
+    b: &EntityId,
+    at: Timestamp,
+) -> Option<EntityComparison> {
+    let entity_a = simulation.get_anchored_entity(a)?.entity();
+    let entity_b = simulation.get_anchored_entity(b)?.entity();
+
+    let worlds: Vec<ComputedState> = (0..8)
+        .map(|swaps| project(simulation, a, b, swaps, at))
+        .collect();
+    let paths = StatePath::all()
+        .into_iter()
+        .map(|path| {
+            let value = |swaps: usize| worlds[swaps].get_effective(path);
+            PathComparison {
+                path,
+                a: value(0),
+                b: value(TRAITS | EVENTS | ANCHOR),
+                traits: shapley(&value, TRAITS),
+                events: shapley(&value, EVENTS),
+                anchor: shapley(&value, ANCHOR),
+            }
+        })
+        .collect();
+
+    let (shared_events, events_only_a, events_only_b) =
+        match_events(&history(simulation, a, at), &history(simulation, b, at));
+
+    let mut shared_microsystems: Vec<MicrosystemId> = entity_a
+        .context()
+        .microsystems_iter()
+        .map(|(id, _)| id)
+        .filter(|id| entity_b.context().get_microsystem(id).is_some())
+        .cloned()
+        .collect();
+    shared_microsystems.sort();
+
+    let hexaco_differences = HexacoPath::all()
+        .into_iter()
+        .filter_map(|trait_path| {
+            let path = StatePath::Hexaco(trait_path);
+            let difference = entity_b.get_base(path)? - entity_a.get_base(path)?;
+            (difference.abs() > STATE_DIFF_EPSILON).then_some((trait_path, difference))
+        })
+        .collect();
+
+    Some(EntityComparison {
+        a: a.clone(),
+        b: b.clone(),
+        at,
+        paths,
+        shared_events,
+        events_only_a,
+        events_only_b,
+        shared_microsystems,
+        hexaco_differences,
+    })
+}
+
+/// Projects the state at `at` with the components in `swaps` taken from
+/// `b` and the rest from `a`.
+fn project(
+    simulation: &Simulation,
+    a: &EntityId,
+    b: &EntityId,
+    swaps: usize,
+    at: Timestamp,
+) -> ComputedState {
+    let from = |component: usize| if swaps & component != 0 { b } else { a };
+    let (base, traits, events) = (from(ANCHOR), from(TRAITS), from(EVENTS));
+    if traits == base && events == base {
+        return simulation
+            .entity(base)
+            .expect("compared entity")
+            .state_at(at);
+    }
+
+    let mut world = simulation.clone();
+    if traits != base {
+        let hexaco = simulation
+            .get_anchored_entity(traits)
+            .expect("compared entity")
+            .entity()
+            .individual_state()
+            .hexaco()
+            .clone();
+        *world
+            .get_anchored_entity_mut(base)
+            .expect("compared entity")
+            .entity_mut()
+            .individual_state_mut()
+            .hexaco_mut() = hexaco;
+    }
+    if events != base {
+        let own: Vec<usize> = world.event_positions_for(base).collect();
+        world.remove_events(&own);
+        for position in simulation.event_positions_for(events) {
+            let te = simulation.event_at(position);
+            let mut event = te.event().clone();
+            if event.target() == Some(events) {
+                event.set_target(Some(base.clone()));
+            } else {
+                event.set_witnesses(vec![base.clone()]);
+            }
+            world.add_event(event, te.timestamp());
+        }
+    }
+    world.entity(base).expect("compared entity").state_at(at)
+}
+
+/// Returns the Shapley value of `component` over the three swaps.
+///
+/// With three components, the marginal effect of adding `component` to a
+/// set of `k` others is weighted 1/3, 1/6, 1/3 for `k` = 0, 1, 2.
+fn shapley(value: &impl Fn(usize) -> f64, component: usize) -> f64 {
+    (0..8)
+        .filter(|swaps| swaps & component == 0)
+        .map(|swaps: usize| {
+            let weight = if swaps.count_ones() == 1 {
+                1.0 / 6.0
+            } else {
+                1.0 / 3.0
+            };
+            weight * (value(swaps | component) - value(swaps))
+        })
+        .sum()
+}
+
+/// Returns the events targeting `entity` up to `at`, in timestamp order.
+fn history<'a>(
+    simulation: &'a Simulation,
+    entity: &EntityId,
+    at: Timestamp,
+) -> Vec<&'a TimestampedEvent> {
+    let mut events: Vec<&TimestampedEvent> = simulation
+        .events_for(entity)
+        .into_iter()
+        .filter(|te| te.timestamp() <= at)
+        .collect();
+    events.sort_by_key(|te| te.timestamp());
+    events
+}
+
+/// Pairs each of A's events with the first unpaired event of B with the
+/// same type and timestamp.
+fn match_events(
+    a: &[&TimestampedEvent],
+    b: &[&TimestampedEvent],
+) -> (Vec<(EventId, EventId)>, Vec<EventId>, Vec<EventId>) {
+    let mut paired = vec![false; b.len()];
+    let mut shared = Vec::new();
+    let mut only_a = Vec::new();
+    for te in a {
+        let partner = b.iter().enumerate().position(|(index, other)| {
+            !paired[index]
+                && other.timestamp() == te.timestamp()
+                && other.event().event_type() == te.event().event_type()
+        });
+        match partner {
+            Some(index) => {
+                paired[index] = true;
+                shared.push((te.event().id().clone(), b[index].event().id().clone()));
+            }
+            None => only_a.push(te.event().id().clone()),
+        }
+    }
+    let only_b = b
+        .iter()
+        .zip(paired)
+        .filter(|(_, paired)| !paired)
+        .map(|(te, _)| te.event().id().clone())
+        .collect();
+    (shared, only_a, only_b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::EntityBuilder;
+    use crate::enums::{EventType, MoodPath, Species};
+    use crate::event::EventBuilder;
+    use crate::types::Duration;
+
+    fn reference() -> Timestamp {
+        Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+    }
+
+    fn pair() -> (Simulation, EntityId, EntityId) {
+        let mut sim = Simulation::new(reference());
+        let mut add = |id: &str| {
+            let entity = EntityBuilder::new()
+                .id(id)
+                .species(Species::Human)
+                .build()
+                .unwrap();
+            sim.add_entity(entity, reference())
+        };
+        let (a, b) = (add("twin_a"), add("twin_b"));
+        (sim, a, b)
+    }
+
+    fn add(sim: &mut Simulation, target: &EntityId, event_type: EventType, day: u64) {
+        let event = EventBuilder::new(event_type)
+            .target(target.clone())
+            .severity(0.7)
+            .build()
+            .unwrap();
+        sim.add_event(event, reference() + Duration::days(day));
+    }
+
+    #[test]
+    fn shapley_shares_sum_to_the_difference() {
+        // v(S) = 1 + 2*traits + 3*events + traits*events*anchor
+        let value = |swaps: usize| {
+            let has = |component: usize| f64::from(u8::from(swaps & component != 0));
+            1.0 + 2.0 * has(TRAITS) + 3.0 * has(EVENTS) + has(TRAITS) * has(EVENTS) * has(ANCHOR)
+        };
+        let shares = [TRAITS, EVENTS, ANCHOR].map(|component| shapley(&value, component));
+        assert!((shares.iter().sum::<f64>() - 6.0).abs() < 1e-12);
+        // The three-way interaction is split evenly
+        assert!((shares[0] - (2.0 + 1.0 / 3.0)).abs() < 1e-12);
+        assert!((shares[2] - 1.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn events_match_by_type_and_timestamp() {
+        let (mut sim, a, b) = pair();
+        add(&mut sim, &a, EventType::Conflict, 1);
+        add(&mut sim, &b, EventType::Conflict, 1);
+        add(&mut sim, &a, EventType::Conflict, 2);
+        add(&mut sim, &b, EventType::SocialExclusion, 2);
+        add(&mut sim, &b, EventType::Conflict, 20);
+
+        let comparison = sim
+            .compare_entities(&a, &b, reference() + Duration::days(10))
+            .unwrap();
+        assert_eq!(comparison.shared_events.len(), 1);
+        assert_eq!(comparison.events_only_a.len(), 1);
+        assert_eq!(comparison.events_only_b.len(), 1);
+    }
+
+    #[test]
+    fn identical_twins_do_not_differ() {
+        let (mut sim, a, b) = pair();
+        add(&mut sim, &a, EventType::Conflict, 1);
+        add(&mut sim, &b, EventType::Conflict, 1);
+
+        let comparison = sim
+            .compare_entities(&a, &b, reference() + Duration::days(2))
+            .unwrap();
+        let valence = comparison.path(StatePath::Mood(MoodPath::Valence)).unwrap();
+        assert!(valence.a < 0.0);
+        assert!(comparison.paths.iter().all(|path| path.difference() == 0.0
+            && path.traits == 0.0
+            && path.events == 0.0
+            && path.anchor == 0.0));
+        assert!(comparison.hexaco_differences.is_empty());
+    }
+
+    #[test]
+    fn missing_entity_has_no_comparison() {
+        let (sim, a, _) = pair();
+        let missing = EntityId::new("missing").unwrap();
+        assert!(sim.compare_entities(&a, &missing, reference()).is_none());
+    }
+}
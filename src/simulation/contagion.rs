@@ -0,0 +1,376 @@
+//! Emotional contagion between entities sharing a microsystem.
+//!
+//! People who share a household or workplace drift toward each other's
+//! mood. Two entities share a microsystem when both contexts hold a
+//! microsystem with the same `MicrosystemId`.
+//!
+//! Contagion is opt-in through `Simulation::set_contagion_config()`. When
+//! enabled, `state_at()` steps forward from the latest anchor in the
+//! entity's group of connected members. At each step every member's
+//! valence and arousal are pulled toward the mean of each microsystem it
+//! shares, weighted by the member's empathy and its interaction frequency
+//! in that microsystem. All pulls in a step are computed from the same
+//! snapshot, so the result does not depend on insertion or query order.
+//!
+//! The accumulated shift decays with a half-life once exposure stops and
+//! is capped, so contagion colours mood without overriding event effects.
+
+use crate::enums::{DispositionPath, MoodPath, StatePath};
+use crate::simulation::{AnchoredEntity, Simulation};
+use crate::types::{Duration, EntityId, MicrosystemId, Timestamp};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Tuning for emotional contagion between microsystem members.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::simulation::ContagionConfig;
+/// use behavioral_pathways::types::Duration;
+///
+/// let config = ContagionConfig {
+///     step: Duration::hours(12),
+///     ..Default::default()
+/// };
+/// assert!((config.max_shift - 0.15).abs() < f64::EPSILON);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContagionConfig {
+    /// Time between contagion steps.
+    pub step: Duration,
+    /// Fraction of the gap to the group mean closed per step, at full
+    /// empathy and interaction frequency.
+    pub rate: f64,
+    /// Half-life of the accumulated shift once exposure stops.
+    pub half_life: Duration,
+    /// Largest accumulated shift of valence or arousal (0-1).
+    pub max_shift: f64,
+}
+
+impl Default for ContagionConfig {
+    fn default() -> Self {
+        ContagionConfig {
+            step: Duration::days(1),
+            rate: 0.05,
+            half_life: Duration::days(14),
+            max_shift: 0.15,
+        }
+    }
+}
+
+impl ContagionConfig {
+    /// Returns one step's pull toward a group mean.
+    ///
+    /// The pull closes `rate` of the gap between `own` and `group_mean`,
+    /// scaled by empathy and interaction frequency (both 0-1).
+    #[must_use]
+    pub fn pull(&self, own: f64, group_mean: f64, empathy: f64, frequency: f64) -> f64 {
+        (group_mean - own) * self.rate * empathy.clamp(0.0, 1.0) * frequency.clamp(0.0, 1.0)
+    }
+
+    /// Returns the fraction of an accumulated shift left after `elapsed`.
+    #[must_use]
+    pub fn retention(&self, elapsed: Duration) -> f64 {
+        let half_life = self.half_life.as_days_f64();
+        if half_life <= 0.0 {
+            return 0.0;
+        }
+        0.5_f64.powf(elapsed.as_days_f64() / half_life)
+    }
+}
+
+/// The valence and arousal shift contagion adds to a member's mood.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) struct MoodShift {
+    /// Shift of valence.
+    pub(crate) valence: f64,
+    /// Shift of arousal.
+    pub(crate) arousal: f64,
+}
+
+impl MoodShift {
+    /// Returns true if the shift changes nothing.
+    pub(crate) fn is_zero(&self) -> bool {
+        self.valence == 0.0 && self.arousal == 0.0
+    }
+}
+
+/// A member's mood and empathy at one step.
+struct Snapshot {
+    valence: f64,
+    arousal: f64,
+    empathy: f64,
+}
+
+/// Computes the contagion shift for `entity_id` at `timestamp`.
+///
+/// Returns no shift if the entity shares no microsystem, or if
+/// `timestamp` is not after the latest anchor among its group.
+pub(crate) fn contagion_shift(
+    simulation: &Simulation,
+    config: &ContagionConfig,
+    entity_id: &EntityId,
+    timestamp: Timestamp,
+) -> MoodShift {
+    let members = contagion_group(simulation, entity_id);
+    if members.len() < 2 || config.step.is_zero() {
+        return MoodShift::default();
+    }
+    let Some(start) = members.iter().map(|member| member.anchor_timestamp()).max() else {
+        return MoodShift::default();
+    };
+    if timestamp <= start {
+        return MoodShift::default();
+    }
+
+    let shared = shared_microsystems(&members);
+    let handles: Vec<_> = members
+        .iter()
+        .filter_map(|member| simulation.entity(member.entity().id()))
+        .collect();
+    let retention = config.retention(config.step);
+    let mut shifts = vec![MoodShift::default(); members.len()];
+
+    let mut cursor = start;
+    while cursor + config.step <= timestamp {
+        let snapshot: Vec<Snapshot> = handles
+            .iter()
+            .zip(&shifts)
+            .map(|(handle, shift)| {
+                let state = handle.state_without_contagion(cursor);
+                Snapshot {
+                    valence: state.get_effective(StatePath::Mood(MoodPath::Valence))
+                        + shift.valence,
+                    arousal: state.get_effective(StatePath::Mood(MoodPath::Arousal))
+                        + shift.arousal,
+                    empathy: state.get_effective(StatePath::Disposition(DispositionPath::Empathy)),
+                }
+            })
+            .collect();
+
+        let mut pulls = vec![MoodShift::default(); members.len()];
+        for (id, indices) in &shared {
+            let count = indices.len() as f64;
+            let mean_valence = indices.iter().map(|&i| snapshot[i].valence).sum::<f64>() / count;
+            let mean_arousal = indices.iter().map(|&i| snapshot[i].arousal).sum::<f64>() / count;
+            for &i in indices {
+                let frequency = members[i]
+                    .entity()
+                    .context()
+                    .get_microsystem(id)
+                    .map_or(0.0, |microsystem| microsystem.interaction_frequency());
+                let own = &snapshot[i];
+                pulls[i].valence += config.pull(own.valence, mean_valence, own.empathy, frequency);
+                pulls[i].arousal += config.pull(own.arousal, mean_arousal, own.empathy, frequency);
+            }
+        }
+
+        let cap = config.max_shift.abs();
+        for (shift, pull) in shifts.iter_mut().zip(&pulls) {
+            shift.valence = (shift.valence * retention + pull.valence).clamp(-cap, cap);
+            shift.arousal = (shift.arousal * retention + pull.arousal).clamp(-cap, cap);
+        }
+        cursor = cursor + config.step;
+    }
+
+    let index = members
+        .iter()
+        .position(|member| member.entity().id() == entity_id)
+        .unwrap_or(0);
+    let remaining = config.retention(timestamp - cursor);
+    MoodShift {
+        valence: shifts[index].valence * remaining,
+        arousal: shifts[index].arousal * remaining,
+    }
+}
+
+/// Returns the entities connected to `entity_id` through shared
+/// microsystems, including the entity itself, sorted by ID.
+fn contagion_group<'a>(
+    simulation: &'a Simulation,
+    entity_id: &EntityId,
+) -> Vec<&'a AnchoredEntity> {
+    let mut by_microsystem: BTreeMap<&MicrosystemId, Vec<&EntityId>> = BTreeMap::new();
+    for anchored in simulation.entities() {
+        for (id, _) in anchored.entity().context().microsystems_iter() {
+            by_microsystem
+                .entry(id)
+                .or_default()
+                .push(anchored.entity().id());
+        }
+    }
+
+    let mut group: BTreeSet<&EntityId> = BTreeSet::new();
+    let mut frontier = vec![entity_id];
+    while let Some(current) = frontier.pop() {
+        if !group.insert(current) {
+            continue;
+        }
+        let Some(anchored) = simulation.get_anchored_entity(current) else {
+            continue;
+        };
+        for (id, _) in anchored.entity().context().microsystems_iter() {
+            for member in by_microsystem.get(id).into_iter().flatten() {
+                if !group.contains(member) {
+                    frontier.push(member);
+                }
+            }
+        }
+    }
+
+    group
+        .into_iter()
+        .filter_map(|id| simulation.get_anchored_entity(id))
+        .collect()
+}
+
+/// Returns each microsystem held by two or more members, with the indices
+/// of the members holding it, sorted by microsystem ID.
+fn shared_microsystems(members: &[&AnchoredEntity]) -> Vec<(MicrosystemId, Vec<usize>)> {
+    let mut holders: BTreeMap<MicrosystemId, Vec<usize>> = BTreeMap::new();
+    for (index, member) in members.iter().enumerate() {
+        for (id, _) in member.entity().context().microsystems_iter() {
+            holders.entry(id.clone()).or_default().push(index);
+        }
+    }
+    holders
+        .into_iter()
+        .filter(|(_, indices)| indices.len() > 1)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::{FamilyContext, Microsystem, WorkContext};
+    use crate::entity::{Entity, EntityBuilder};
+    use crate::enums::Species;
+
+    fn reference() -> Timestamp {
+        Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+    }
+
+    fn member(id: &str, valence: f32, microsystems: &[&str]) -> Entity {
+        let mut entity = EntityBuilder::new()
+            .id(id)
+            .species(Species::Human)
+            .build()
+            .unwrap();
+        entity
+            .individual_state_mut()
+            .mood_mut()
+            .valence_mut()
+            .set_base(valence);
+        for microsystem in microsystems {
+            entity.context_mut().add_microsystem(
+                MicrosystemId::new(*microsystem).unwrap(),
+                Microsystem::new_family(FamilyContext::default()),
+            );
+        }
+        entity
+    }
+
+    fn shift_for(sim: &Simulation, id: &str, timestamp: Timestamp) -> MoodShift {
+        contagion_shift(
+            sim,
+            &ContagionConfig::default(),
+            &EntityId::new(id).unwrap(),
+            timestamp,
+        )
+    }
+
+    #[test]
+    fn pull_scales_with_empathy_and_frequency() {
+        let config = ContagionConfig::default();
+        let full = config.pull(0.0, -0.4, 1.0, 1.0);
+        assert!((full + 0.02).abs() < 1e-12);
+        assert!((config.pull(0.0, -0.4, 0.5, 0.5) - full * 0.25).abs() < 1e-12);
+        assert!(config.pull(0.0, -0.4, 0.0, 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn retention_halves_over_half_life() {
+        let config = ContagionConfig::default();
+        assert!((config.retention(Duration::days(14)) - 0.5).abs() < 1e-12);
+        let none = ContagionConfig {
+            half_life: Duration::zero(),
+            ..Default::default()
+        };
+        assert!(none.retention(Duration::days(1)).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn no_shift_without_shared_microsystem() {
+        let mut sim = Simulation::new(reference());
+        sim.add_entity(member("a", -0.6, &["home_a"]), reference());
+        sim.add_entity(member("b", 0.4, &["home_b"]), reference());
+        assert!(shift_for(&sim, "b", reference() + Duration::days(60)).is_zero());
+    }
+
+    #[test]
+    fn no_shift_at_or_before_latest_anchor() {
+        let mut sim = Simulation::new(reference());
+        sim.add_entity(member("a", -0.6, &["home"]), reference());
+        sim.add_entity(member("b", 0.4, &["home"]), reference() + Duration::days(5));
+        assert!(shift_for(&sim, "a", reference() + Duration::days(5)).is_zero());
+        assert!(!shift_for(&sim, "a", reference() + Duration::days(10)).is_zero());
+    }
+
+    #[test]
+    fn shifts_are_symmetric_and_order_independent() {
+        let query = reference() + Duration::days(30);
+        let mut forward = Simulation::new(reference());
+        forward.add_entity(member("a", -0.6, &["home"]), reference());
+        forward.add_entity(member("b", 0.4, &["home"]), reference());
+        let mut reversed = Simulation::new(reference());
+        reversed.add_entity(member("b", 0.4, &["home"]), reference());
+        reversed.add_entity(member("a", -0.6, &["home"]), reference());
+
+        let a = shift_for(&forward, "a", query);
+        let b = shift_for(&forward, "b", query);
+        assert!(a.valence > 0.0 && b.valence < 0.0);
+        assert!((a.valence + b.valence).abs() < 1e-12);
+        assert_eq!(a, shift_for(&reversed, "a", query));
+        assert_eq!(b, shift_for(&reversed, "b", query));
+    }
+
+    #[test]
+    fn group_follows_transitive_membership() {
+        let mut sim = Simulation::new(reference());
+        sim.add_entity(member("a", 0.0, &["home"]), reference());
+        let mut b = member("b", 0.0, &["home"]);
+        b.context_mut().add_microsystem(
+            MicrosystemId::new("work").unwrap(),
+            Microsystem::new_work(WorkContext::default()),
+        );
+        sim.add_entity(b, reference());
+        sim.add_entity(member("c", 0.0, &["work"]), reference());
+        sim.add_entity(member("d", 0.0, &["elsewhere"]), reference());
+
+        let group = contagion_group(&sim, &EntityId::new("a").unwrap());
+        let ids: Vec<&str> = group.iter().map(|m| m.entity().id().as_str()).collect();
+        assert_eq!(ids, vec!["a", "b", "c"]);
+        assert_eq!(shared_microsystems(&group).len(), 2);
+    }
+
+    #[test]
+    fn shift_is_capped() {
+        let config = ContagionConfig {
+            rate: 1.0,
+            max_shift: 0.05,
+            ..Default::default()
+        };
+        let mut sim = Simulation::new(reference());
+        sim.add_entity(member("a", -1.0, &["home"]), reference());
+        sim.add_entity(member("b", 1.0, &["home"]), reference());
+        let shift = contagion_shift(
+            &sim,
+            &config,
+            &EntityId::new("b").unwrap(),
+            reference() + Duration::days(60),
+        );
+        assert!(shift.valence >= -0.05 - 1e-12);
+        assert!(shift.valence < -0.04);
+    }
+}
@@ -0,0 +1,199 @@
+//! Timestamped changes to one entity's ecological context.
+//!
+//! An entity's `EcologicalContext` describes its environment before any
+//! change. `Simulation::add_context_change` schedules a change, such as
+//! starting a new job, for a timestamp. `state_at()` replays every change
+//! at or before the queried timestamp, the same boundary events use, so a
+//! query before a change sees the context without it. Context effects are
+//! applied in segments between changes, each with the context held during
+//! it, and mesosystem linkages follow the microsystems present.
+
+use crate::context::{EcologicalContext, Microsystem};
+use crate::enums::ContextPath;
+use crate::types::{MicrosystemId, Timestamp};
+use std::borrow::Cow;
+
+/// A change to an entity's ecological context.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ContextChange {
+    /// Adds a microsystem, replacing any with the same ID.
+    AddMicrosystem {
+        /// The new microsystem's ID.
+        id: MicrosystemId,
+        /// The microsystem joined.
+        microsystem: Microsystem,
+    },
+    /// Removes a microsystem, if present.
+    RemoveMicrosystem(MicrosystemId),
+    /// Sets a context value, as `EcologicalContext::set` does.
+    SetValue {
+        /// The path to set.
+        path: ContextPath,
+        /// The new value.
+        value: f64,
+    },
+}
+
+impl ContextChange {
+    /// Applies the change to `context`.
+    ///
+    /// Returns false if the change had nothing to act on: a removed or
+    /// set microsystem that does not exist.
+    pub fn apply(&self, context: &mut EcologicalContext) -> bool {
+        match self {
+            ContextChange::AddMicrosystem { id, microsystem } => {
+                context.add_microsystem(id.clone(), microsystem.clone());
+                true
+            }
+            ContextChange::RemoveMicrosystem(id) => context.remove_microsystem(id).is_some(),
+            ContextChange::SetValue { path, value } => {
+                let applied = context.set(path, *value);
+                context.invalidate_mesosystem_cache();
+                applied
+            }
+        }
+    }
+}
+
+/// A context change with the timestamp it takes effect at.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimestampedContextChange {
+    /// The change instance.
+    change: ContextChange,
+    /// When the change takes effect.
+    timestamp: Timestamp,
+}
+
+impl TimestampedContextChange {
+    /// Creates a new timestamped context change.
+    #[must_use]
+    pub fn new(change: ContextChange, timestamp: Timestamp) -> Self {
+        TimestampedContextChange { change, timestamp }
+    }
+
+    /// Returns a reference to the change.
+    #[must_use]
+    pub fn change(&self) -> &ContextChange {
+        &self.change
+    }
+
+    /// Returns the timestamp when the change takes effect.
+    #[must_use]
+    pub fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+}
+
+/// Returns `context` with every change at or before `at` applied in order.
+///
+/// `changes` must be sorted by timestamp. Borrows the context unchanged
+/// when no change is that early.
+pub(crate) fn replay_context_changes<'a>(
+    context: &'a EcologicalContext,
+    changes: &[TimestampedContextChange],
+    at: Timestamp,
+) -> Cow<'a, EcologicalContext> {
+    let applied = changes.partition_point(|change| change.timestamp <= at);
+    if applied == 0 {
+        return Cow::Borrowed(context);
+    }
+    let mut context = context.clone();
+    for change in &changes[..applied] {
+        change.change.apply(&mut context);
+    }
+    Cow::Owned(context)
+}
+
+/// Returns the changes strictly between `start` and `end`.
+///
+/// `changes` must be sorted by timestamp.
+#[cfg(feature = "context")]
+pub(crate) fn context_changes_within(
+    changes: &[TimestampedContextChange],
+    start: Timestamp,
+    end: Timestamp,
+) -> &[TimestampedContextChange] {
+    let first = changes.partition_point(|change| change.timestamp <= start);
+    let last = changes.partition_point(|change| change.timestamp < end);
+    &changes[first..last.max(first)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::WorkContext;
+    use crate::enums::{MicrosystemPath, WorkPath};
+
+    fn year(year: i32) -> Timestamp {
+        Timestamp::from_ymd_hms(year, 1, 1, 0, 0, 0)
+    }
+
+    fn work() -> MicrosystemId {
+        MicrosystemId::new("work").unwrap()
+    }
+
+    fn changes() -> Vec<TimestampedContextChange> {
+        let stress =
+            ContextPath::Microsystem(work(), MicrosystemPath::Work(WorkPath::WorkloadStress));
+        vec![
+            TimestampedContextChange::new(
+                ContextChange::AddMicrosystem {
+                    id: work(),
+                    microsystem: Microsystem::new_work(WorkContext::default()),
+                },
+                year(2020),
+            ),
+            TimestampedContextChange::new(
+                ContextChange::SetValue {
+                    path: stress,
+                    value: 0.9,
+                },
+                year(2021),
+            ),
+            TimestampedContextChange::new(ContextChange::RemoveMicrosystem(work()), year(2022)),
+        ]
+    }
+
+    #[test]
+    fn replay_applies_changes_at_or_before_timestamp() {
+        let context = EcologicalContext::default();
+        let changes = changes();
+
+        assert!(matches!(
+            replay_context_changes(&context, &changes, year(2019)),
+            Cow::Borrowed(_)
+        ));
+        let joined = replay_context_changes(&context, &changes, year(2020));
+        assert_eq!(joined.microsystem_count(), 1);
+        let stressed = replay_context_changes(&context, &changes, year(2021));
+        assert!((stressed.aggregate_stress() - 0.9).abs() < f64::EPSILON);
+        let left = replay_context_changes(&context, &changes, year(2030));
+        assert!(left.is_empty());
+    }
+
+    #[test]
+    fn changes_to_missing_microsystems_report_no_effect() {
+        let mut context = EcologicalContext::default();
+        let changes = changes();
+        assert!(!changes[1].change().apply(&mut context));
+        assert!(!changes[2].change().apply(&mut context));
+        assert!(changes[0].change().apply(&mut context));
+    }
+
+    #[cfg(feature = "context")]
+    #[test]
+    fn within_excludes_span_endpoints() {
+        let changes = changes();
+        assert_eq!(
+            context_changes_within(&changes, year(2020), year(2022)).len(),
+            1
+        );
+        assert_eq!(
+            context_changes_within(&changes, year(2019), year(2030)).len(),
+            3
+        );
+        assert!(context_changes_within(&changes, year(2022), year(2020)).is_empty());
+    }
+}
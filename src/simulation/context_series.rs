@@ -0,0 +1,255 @@
+//! Time-varying context values shared by every entity.
+//!
+//! Conditions outside the entity's own microsystems, such as the
+//! unemployment rate or the cultural climate, change continuously. A context
+//! series gives one exosystem, macrosystem, or chronosystem path a value over
+//! time. Whenever `state_at()` applies context effects, it looks up each
+//! series at the timestamp the effects are computed for and uses that value
+//! in place of the entity's static context value.
+//!
+//! Before the first point a series holds its first value, and after the last
+//! point it holds its last value. Microsystem paths belong to a single
+//! entity's immediate environment and cannot be given a series.
+
+#[cfg(feature = "context")]
+use crate::context::EcologicalContext;
+use crate::enums::ContextPath;
+use crate::types::Timestamp;
+#[cfg(feature = "context")]
+use std::borrow::Cow;
+use std::fmt;
+
+/// How a context series is read between its points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Interpolation {
+    /// Each value holds until the next point.
+    #[default]
+    Step,
+    /// Values are interpolated linearly between points.
+    Linear,
+}
+
+/// Errors from `Simulation::set_context_series`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContextSeriesError {
+    /// Microsystem paths are per-entity and cannot be given a series.
+    IneligiblePath(ContextPath),
+    /// The series has no points.
+    Empty,
+    /// A point's value is NaN or infinite.
+    NonFiniteValue {
+        /// When the bad point occurs.
+        timestamp: Timestamp,
+    },
+    /// Two points share a timestamp.
+    DuplicateTimestamp(Timestamp),
+}
+
+impl fmt::Display for ContextSeriesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ContextSeriesError::IneligiblePath(path) => write!(
+                f,
+                "Context series are only supported for exosystem, macrosystem, and \
+                 chronosystem paths, not {:?}",
+                path
+            ),
+            ContextSeriesError::Empty => write!(f, "Context series needs at least one point"),
+            ContextSeriesError::NonFiniteValue { timestamp } => {
+                write!(f, "Context series value at {} is not finite", timestamp)
+            }
+            ContextSeriesError::DuplicateTimestamp(timestamp) => {
+                write!(f, "Context series has two points at {}", timestamp)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ContextSeriesError {}
+
+/// A context path's value over time.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContextSeries {
+    /// The path the series drives.
+    path: ContextPath,
+    /// Points sorted by timestamp, with no duplicate timestamps.
+    points: Vec<(Timestamp, f64)>,
+    /// How values are read between points.
+    interpolation: Interpolation,
+}
+
+impl ContextSeries {
+    /// Creates a series, sorting its points by timestamp.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for microsystem paths, empty series, non-finite
+    /// values, or two points at the same timestamp.
+    pub(crate) fn new(
+        path: ContextPath,
+        mut points: Vec<(Timestamp, f64)>,
+        interpolation: Interpolation,
+    ) -> Result<Self, ContextSeriesError> {
+        if matches!(path, ContextPath::Microsystem(..)) {
+            return Err(ContextSeriesError::IneligiblePath(path));
+        }
+        if points.is_empty() {
+            return Err(ContextSeriesError::Empty);
+        }
+        if let Some((timestamp, _)) = points.iter().find(|(_, value)| !value.is_finite()) {
+            return Err(ContextSeriesError::NonFiniteValue {
+                timestamp: *timestamp,
+            });
+        }
+        points.sort_by_key(|(timestamp, _)| *timestamp);
+        if let Some(pair) = points.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+            return Err(ContextSeriesError::DuplicateTimestamp(pair[0].0));
+        }
+        Ok(ContextSeries {
+            path,
+            points,
+            interpolation,
+        })
+    }
+
+    /// Returns the path the series drives.
+    #[must_use]
+    pub fn path(&self) -> &ContextPath {
+        &self.path
+    }
+
+    /// Returns the points, sorted by timestamp.
+    #[must_use]
+    pub fn points(&self) -> &[(Timestamp, f64)] {
+        &self.points
+    }
+
+    /// Returns how values are read between points.
+    #[must_use]
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    /// Returns the series value at a timestamp.
+    #[must_use]
+    pub fn value_at(&self, timestamp: Timestamp) -> f64 {
+        let next = self
+            .points
+            .partition_point(|(point, _)| *point <= timestamp);
+        if next == 0 {
+            return self.points[0].1;
+        }
+        let (before, before_value) = self.points[next - 1];
+        let Some(&(after, after_value)) = self.points.get(next) else {
+            return before_value;
+        };
+        match self.interpolation {
+            Interpolation::Step => before_value,
+            Interpolation::Linear => {
+                let span = (after - before).as_seconds() as f64;
+                let fraction = (timestamp - before).as_seconds() as f64 / span;
+                before_value + (after_value - before_value) * fraction
+            }
+        }
+    }
+}
+
+/// Returns `context` with every series path set to its value at `at`.
+///
+/// Borrows the context unchanged when there are no series.
+#[cfg(feature = "context")]
+pub(crate) fn context_at<'a>(
+    context: &'a EcologicalContext,
+    series: &[ContextSeries],
+    at: Timestamp,
+) -> Cow<'a, EcologicalContext> {
+    if series.is_empty() {
+        return Cow::Borrowed(context);
+    }
+    let mut context = context.clone();
+    for entry in series {
+        context.set(&entry.path, entry.value_at(at));
+    }
+    Cow::Owned(context)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::{ExosystemPath, MicrosystemPath, WorkPath};
+    use crate::types::{Duration, MicrosystemId};
+
+    const RESOURCES: ContextPath = ContextPath::Exosystem(ExosystemPath::ResourceAvailability);
+
+    fn year(year: i32) -> Timestamp {
+        Timestamp::from_ymd_hms(year, 1, 1, 0, 0, 0)
+    }
+
+    fn series(interpolation: Interpolation) -> ContextSeries {
+        // Given out of order; construction sorts them
+        let points = vec![(year(2022), 0.8), (year(2020), 0.2)];
+        ContextSeries::new(RESOURCES, points, interpolation).unwrap()
+    }
+
+    #[test]
+    fn step_holds_each_value_until_the_next_point() {
+        let series = series(Interpolation::Step);
+        assert_eq!(series.points()[0], (year(2020), 0.2));
+        assert!((series.value_at(year(2019)) - 0.2).abs() < f64::EPSILON);
+        assert!((series.value_at(year(2021)) - 0.2).abs() < f64::EPSILON);
+        assert!((series.value_at(year(2022)) - 0.8).abs() < f64::EPSILON);
+        assert!((series.value_at(year(2030)) - 0.8).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn linear_interpolates_between_points() {
+        let series = series(Interpolation::Linear);
+        // 2020 is a leap year, so the two points are 731 days apart
+        let midpoint = year(2020) + Duration::days(365) + Duration::hours(12);
+        assert!((series.value_at(midpoint) - 0.5).abs() < 1e-9);
+        assert!((series.value_at(year(2019)) - 0.2).abs() < f64::EPSILON);
+        assert!((series.value_at(year(2030)) - 0.8).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn rejects_invalid_series() {
+        let work = ContextPath::Microsystem(
+            MicrosystemId::new("work").unwrap(),
+            MicrosystemPath::Work(WorkPath::WorkloadStress),
+        );
+        let one = vec![(year(2020), 0.5)];
+        assert_eq!(
+            ContextSeries::new(work.clone(), one.clone(), Interpolation::Step).unwrap_err(),
+            ContextSeriesError::IneligiblePath(work)
+        );
+        assert_eq!(
+            ContextSeries::new(RESOURCES, Vec::new(), Interpolation::Step).unwrap_err(),
+            ContextSeriesError::Empty
+        );
+        let nan = vec![(year(2020), f64::NAN)];
+        assert!(matches!(
+            ContextSeries::new(RESOURCES, nan, Interpolation::Step),
+            Err(ContextSeriesError::NonFiniteValue { .. })
+        ));
+        let twice = vec![(year(2020), 0.1), (year(2020), 0.2)];
+        assert_eq!(
+            ContextSeries::new(RESOURCES, twice, Interpolation::Step).unwrap_err(),
+            ContextSeriesError::DuplicateTimestamp(year(2020))
+        );
+    }
+
+    #[cfg(feature = "context")]
+    #[test]
+    fn context_at_overrides_static_value() {
+        let context = EcologicalContext::default();
+        assert!(matches!(
+            context_at(&context, &[], year(2020)),
+            Cow::Borrowed(_)
+        ));
+
+        let overridden = context_at(&context, &[series(Interpolation::Step)], year(2020));
+        assert!((overridden.get(&RESOURCES).unwrap() - 0.2).abs() < f64::EPSILON);
+    }
+}
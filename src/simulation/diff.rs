@@ -0,0 +1,1792 @@
+//! Structural comparison of two simulations.
+//!
+//! [`SimulationDiff`] lists what changed between two versions of a scenario:
+//! entities whose anchor state, context, or model configuration moved,
+//! events whose fields changed, and relationships or configuration that were
+//! added, removed, or modified. Every field that can change a state query is
+//! compared, so two scenarios with an empty diff compute the same states.
+//!
+//! Entities are matched by [`EntityId`], events by [`EventId`], and
+//! relationships by their (unordered) entity pair, since relationship IDs are
+//! generated per simulation. All lists are sorted so the same pair of
+//! simulations always produces the same diff, and `a.diff(&b)` is exactly
+//! `b.diff(&a).inverse()`.
+//!
+//! [`StateDiff`] is the per-path counterpart for two computed states of one
+//! entity, such as intake and now. It lists the paths that moved and the
+//! ITS thresholds crossed, and the prose delta summary is generated from it.
+
+use crate::context::{CulturalOrientation, EcologicalContext, MacrosystemContext, Microsystem};
+use crate::enums::{Direction, MentalHealthPath, RampShape, StatePath};
+use crate::processor::AC_ELEVATED_THRESHOLD;
+use crate::relationship::Relationship;
+use crate::simulation::state_query::effective_value;
+use crate::simulation::{
+    AnchoredEntity, BaseAbsorptionConfig, ContagionConfig, Simulation, StochasticConfig,
+    TimestampedEvent, TimestampedRelationship,
+};
+use crate::state::{
+    EntityModelConfig, IndividualState, HOPELESSNESS_THRESHOLD, PB_PRESENT_THRESHOLD,
+    TB_PRESENT_THRESHOLD,
+};
+use crate::types::{EntityId, EventId};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Minimum difference in a state value reported as a change.
+pub const STATE_DIFF_EPSILON: f64 = 1e-6;
+
+/// A single field that differs between two versions.
+///
+/// Values are rendered as strings so the diff can describe heterogeneous
+/// fields (numbers, timestamps, enums, payloads) uniformly.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FieldChange {
+    /// Name of the field, such as `"severity"` or `"Mood::Valence"`.
+    pub field: String,
+    /// Value in the simulation the diff was taken from.
+    pub before: String,
+    /// Value in the simulation the diff was taken against.
+    pub after: String,
+}
+
+impl FieldChange {
+    fn new(field: impl Into<String>, before: impl Into<String>, after: impl Into<String>) -> Self {
+        FieldChange {
+            field: field.into(),
+            before: before.into(),
+            after: after.into(),
+        }
+    }
+
+    fn inverse(&self) -> Self {
+        FieldChange {
+            field: self.field.clone(),
+            before: self.after.clone(),
+            after: self.before.clone(),
+        }
+    }
+}
+
+impl fmt::Display for FieldChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} -> {}", self.field, self.before, self.after)
+    }
+}
+
+/// Field-level changes to an entity present in both simulations.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EntityDiff {
+    /// The entity that changed.
+    pub entity_id: EntityId,
+    /// Changed fields: anchor metadata, then context and model
+    /// configuration, then state paths.
+    pub changes: Vec<FieldChange>,
+}
+
+/// Field-level changes to an event present in both simulations.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventDiff {
+    /// The event that changed.
+    pub event_id: EventId,
+    /// Changed fields.
+    pub changes: Vec<FieldChange>,
+}
+
+/// Identifies a relationship by the pair of entities it connects.
+///
+/// The pair is stored in sorted order so `(a, b)` and `(b, a)` match.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RelationshipKey {
+    /// The lexically smaller entity ID.
+    pub first: EntityId,
+    /// The lexically larger entity ID.
+    pub second: EntityId,
+}
+
+impl RelationshipKey {
+    fn for_relationship(relationship: &TimestampedRelationship) -> Self {
+        let a = relationship.entity_a().clone();
+        let b = relationship.entity_b().clone();
+        if a.as_str() <= b.as_str() {
+            RelationshipKey {
+                first: a,
+                second: b,
+            }
+        } else {
+            RelationshipKey {
+                first: b,
+                second: a,
+            }
+        }
+    }
+}
+
+impl fmt::Display for RelationshipKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} <-> {}", self.first, self.second)
+    }
+}
+
+/// Field-level changes to a relationship present in both simulations.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RelationshipDiff {
+    /// The entity pair the relationship connects.
+    pub key: RelationshipKey,
+    /// Changed fields.
+    pub changes: Vec<FieldChange>,
+}
+
+/// Differences between two simulations.
+///
+/// Produced by [`Simulation::diff`]. "Added" items exist only in the other
+/// simulation, "removed" items only in this one.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::simulation::Simulation;
+/// use behavioral_pathways::types::Timestamp;
+///
+/// let a = Simulation::new(Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0));
+/// let b = Simulation::new(Timestamp::from_ymd_hms(2024, 2, 1, 0, 0, 0));
+///
+/// let diff = a.diff(&b);
+/// assert_eq!(diff.config_changes.len(), 1);
+/// assert_eq!(b.diff(&a), diff.inverse());
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SimulationDiff {
+    /// Entities present only in the other simulation.
+    pub added_entities: Vec<EntityId>,
+    /// Entities present only in this simulation.
+    pub removed_entities: Vec<EntityId>,
+    /// Entities present in both with differing anchors, contexts, or
+    /// model configurations.
+    pub modified_entities: Vec<EntityDiff>,
+    /// Events present only in the other simulation.
+    pub added_events: Vec<EventId>,
+    /// Events present only in this simulation.
+    pub removed_events: Vec<EventId>,
+    /// Events present in both with differing fields.
+    pub modified_events: Vec<EventDiff>,
+    /// Relationships present only in the other simulation.
+    pub added_relationships: Vec<RelationshipKey>,
+    /// Relationships present only in this simulation.
+    pub removed_relationships: Vec<RelationshipKey>,
+    /// Relationships present in both with differing fields.
+    pub modified_relationships: Vec<RelationshipDiff>,
+    /// Simulation-level configuration differences.
+    pub config_changes: Vec<FieldChange>,
+}
+
+impl SimulationDiff {
+    /// Computes the diff from `before` to `after`.
+    #[must_use]
+    pub(crate) fn between(before: &Simulation, after: &Simulation) -> Self {
+        let mut diff = SimulationDiff {
+            config_changes: config_changes(before, after),
+            ..Default::default()
+        };
+
+        let before_entities = index_by(before.entities(), |e| e.entity().id().clone());
+        let after_entities = index_by(after.entities(), |e| e.entity().id().clone());
+        let (added, removed, common) = partition(&before_entities, &after_entities);
+        diff.added_entities = added;
+        diff.removed_entities = removed;
+        for (id, b, a) in common {
+            let changes = entity_changes(b, a);
+            if !changes.is_empty() {
+                diff.modified_entities.push(EntityDiff {
+                    entity_id: id,
+                    changes,
+                });
+            }
+        }
+
+        let before_events = index_by(before.all_events(), |e| e.event().id().clone());
+        let after_events = index_by(after.all_events(), |e| e.event().id().clone());
+        let (added, removed, common) = partition(&before_events, &after_events);
+        diff.added_events = added;
+        diff.removed_events = removed;
+        for (id, b, a) in common {
+            let changes = event_changes(b, a);
+            if !changes.is_empty() {
+                diff.modified_events.push(EventDiff {
+                    event_id: id,
+                    changes,
+                });
+            }
+        }
+
+        let before_rels = index_by(before.relationships(), RelationshipKey::for_relationship);
+        let after_rels = index_by(after.relationships(), RelationshipKey::for_relationship);
+        let (added, removed, common) = partition(&before_rels, &after_rels);
+        diff.added_relationships = added;
+        diff.removed_relationships = removed;
+        for (key, b, a) in common {
+            let changes = relationship_changes(b, a);
+            if !changes.is_empty() {
+                diff.modified_relationships
+                    .push(RelationshipDiff { key, changes });
+            }
+        }
+
+        diff
+    }
+
+    /// Returns true if the two simulations are equivalent.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added_entities.is_empty()
+            && self.removed_entities.is_empty()
+            && self.modified_entities.is_empty()
+            && self.added_events.is_empty()
+            && self.removed_events.is_empty()
+            && self.modified_events.is_empty()
+            && self.added_relationships.is_empty()
+            && self.removed_relationships.is_empty()
+            && self.modified_relationships.is_empty()
+            && self.config_changes.is_empty()
+    }
+
+    /// Returns the diff in the opposite direction.
+    ///
+    /// Added and removed items swap, and every field change swaps its
+    /// before and after values.
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        let invert = |changes: &[FieldChange]| changes.iter().map(FieldChange::inverse).collect();
+        SimulationDiff {
+            added_entities: self.removed_entities.clone(),
+            removed_entities: self.added_entities.clone(),
+            modified_entities: self
+                .modified_entities
+                .iter()
+                .map(|d| EntityDiff {
+                    entity_id: d.entity_id.clone(),
+                    changes: invert(&d.changes),
+                })
+                .collect(),
+            added_events: self.removed_events.clone(),
+            removed_events: self.added_events.clone(),
+            modified_events: self
+                .modified_events
+                .iter()
+                .map(|d| EventDiff {
+                    event_id: d.event_id.clone(),
+                    changes: invert(&d.changes),
+                })
+                .collect(),
+            added_relationships: self.removed_relationships.clone(),
+            removed_relationships: self.added_relationships.clone(),
+            modified_relationships: self
+                .modified_relationships
+                .iter()
+                .map(|d| RelationshipDiff {
+                    key: d.key.clone(),
+                    changes: invert(&d.changes),
+                })
+                .collect(),
+            config_changes: invert(&self.config_changes),
+        }
+    }
+}
+
+impl fmt::Display for SimulationDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "no differences");
+        }
+        for change in &self.config_changes {
+            writeln!(f, "~ config {}", change)?;
+        }
+        for id in &self.added_entities {
+            writeln!(f, "+ entity {}", id)?;
+        }
+        for id in &self.removed_entities {
+            writeln!(f, "- entity {}", id)?;
+        }
+        for diff in &self.modified_entities {
+            writeln!(f, "~ entity {}", diff.entity_id)?;
+            write_changes(f, &diff.changes)?;
+        }
+        for id in &self.added_events {
+            writeln!(f, "+ event {}", id)?;
+        }
+        for id in &self.removed_events {
+            writeln!(f, "- event {}", id)?;
+        }
+        for diff in &self.modified_events {
+            writeln!(f, "~ event {}", diff.event_id)?;
+            write_changes(f, &diff.changes)?;
+        }
+        for key in &self.added_relationships {
+            writeln!(f, "+ relationship {}", key)?;
+        }
+        for key in &self.removed_relationships {
+            writeln!(f, "- relationship {}", key)?;
+        }
+        for diff in &self.modified_relationships {
+            writeln!(f, "~ relationship {}", diff.key)?;
+            write_changes(f, &diff.changes)?;
+        }
+        Ok(())
+    }
+}
+
+/// A state path whose effective value differs between two states.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PathChange {
+    /// The path that changed.
+    pub path: StatePath,
+    /// Value in the earlier state.
+    pub before: f64,
+    /// Value in the later state.
+    pub after: f64,
+}
+
+impl PathChange {
+    /// Returns `after - before`.
+    #[must_use]
+    pub fn delta(&self) -> f64 {
+        self.after - self.before
+    }
+}
+
+/// An ITS threshold crossed between two states, in either direction.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThresholdCrossing {
+    /// The path whose threshold was crossed.
+    pub path: StatePath,
+    /// The threshold, such as `PB_PRESENT_THRESHOLD`.
+    pub threshold: f64,
+    /// Value in the earlier state.
+    pub before: f64,
+    /// Value in the later state.
+    pub after: f64,
+}
+
+impl ThresholdCrossing {
+    /// Returns true if the value rose to or above the threshold, false if
+    /// it fell below it.
+    #[must_use]
+    pub fn is_upward(&self) -> bool {
+        self.after >= self.threshold
+    }
+}
+
+/// The ITS thresholds checked for crossings, matching the elevation tests
+/// in `ConvergenceStatus`.
+const ITS_THRESHOLDS: [(MentalHealthPath, f32); 4] = [
+    (
+        MentalHealthPath::ThwartedBelongingness,
+        TB_PRESENT_THRESHOLD,
+    ),
+    (
+        MentalHealthPath::PerceivedBurdensomeness,
+        PB_PRESENT_THRESHOLD,
+    ),
+    (
+        MentalHealthPath::InterpersonalHopelessness,
+        HOPELESSNESS_THRESHOLD,
+    ),
+    (MentalHealthPath::AcquiredCapability, AC_ELEVATED_THRESHOLD),
+];
+
+/// Per-path differences between two states of one entity.
+///
+/// Produced by `ComputedState::baseline_delta` (against the anchor) and
+/// `ComputedState::delta_from` (against an earlier computed state).
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::enums::{SocialCognitionPath, StatePath};
+/// use behavioral_pathways::simulation::StateDiff;
+/// use behavioral_pathways::state::IndividualState;
+///
+/// let intake = IndividualState::new();
+/// let mut now = intake.clone();
+/// now.social_cognition_mut().add_loneliness_delta(0.3);
+///
+/// let diff = StateDiff::between(&intake, &now);
+/// let loneliness = StatePath::SocialCognition(SocialCognitionPath::Loneliness);
+/// assert!((diff.change(loneliness).unwrap().delta() - 0.3).abs() < 1e-6);
+/// assert!(diff.change(StatePath::SocialCognition(SocialCognitionPath::SelfHate)).is_none());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StateDiff {
+    /// Paths that changed by more than the diff's epsilon
+    /// (`STATE_DIFF_EPSILON` unless given), in `StatePath::all()` order.
+    pub changes: Vec<PathChange>,
+    /// ITS thresholds crossed, in TB, PB, interpersonal hopelessness,
+    /// acquired capability order.
+    pub threshold_crossings: Vec<ThresholdCrossing>,
+}
+
+impl StateDiff {
+    /// Computes the differences from `before` to `after`.
+    #[must_use]
+    pub fn between(before: &IndividualState, after: &IndividualState) -> Self {
+        StateDiff::between_with_epsilon(before, after, STATE_DIFF_EPSILON)
+    }
+
+    /// Computes the differences from `before` to `after`, reporting only
+    /// paths that changed by more than `epsilon`.
+    ///
+    /// A larger epsilon suppresses small drifts such as decay between two
+    /// query times. Threshold crossings are reported regardless of size.
+    #[must_use]
+    pub fn between_with_epsilon(
+        before: &IndividualState,
+        after: &IndividualState,
+        epsilon: f64,
+    ) -> Self {
+        let changes = StatePath::all()
+            .into_iter()
+            .map(|path| PathChange {
+                path,
+                before: effective_value(before, path),
+                after: effective_value(after, path),
+            })
+            .filter(|change| change.delta().abs() > epsilon)
+            .collect();
+        let threshold_crossings = ITS_THRESHOLDS
+            .into_iter()
+            .map(|(path, threshold)| ThresholdCrossing {
+                path: StatePath::MentalHealth(path),
+                threshold: f64::from(threshold),
+                before: effective_value(before, StatePath::MentalHealth(path)),
+                after: effective_value(after, StatePath::MentalHealth(path)),
+            })
+            .filter(|crossing| {
+                (crossing.before >= crossing.threshold) != (crossing.after >= crossing.threshold)
+            })
+            .collect();
+        StateDiff {
+            changes,
+            threshold_crossings,
+        }
+    }
+
+    /// Returns the change to `path`, if it changed.
+    #[must_use]
+    pub fn change(&self, path: StatePath) -> Option<&PathChange> {
+        self.changes.iter().find(|change| change.path == path)
+    }
+
+    /// Returns true if no path changed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns the `n` largest changes by absolute delta, largest first.
+    ///
+    /// Equal magnitudes keep `StatePath::all()` order.
+    #[must_use]
+    pub fn top_changes(&self, n: usize) -> Vec<PathChange> {
+        let mut changes = self.changes.clone();
+        changes.sort_by(|a, b| b.delta().abs().total_cmp(&a.delta().abs()));
+        changes.truncate(n);
+        changes
+    }
+
+    /// Returns the changes as `FieldChange`s named by path, as in
+    /// `EntityDiff`.
+    #[must_use]
+    pub fn field_changes(&self) -> Vec<FieldChange> {
+        self.changes
+            .iter()
+            .map(|change| {
+                FieldChange::new(
+                    change.path.to_string(),
+                    change.before.to_string(),
+                    change.after.to_string(),
+                )
+            })
+            .collect()
+    }
+}
+
+fn write_changes(f: &mut fmt::Formatter<'_>, changes: &[FieldChange]) -> fmt::Result {
+    for change in changes {
+        writeln!(f, "    {}", change)?;
+    }
+    Ok(())
+}
+
+/// Indexes items by a sortable key.
+///
+/// If two items share a key, the first one wins.
+fn index_by<'a, T, K: Ord>(
+    items: impl Iterator<Item = &'a T>,
+    key: impl Fn(&T) -> K,
+) -> BTreeMap<K, &'a T>
+where
+    T: 'a,
+{
+    let mut index = BTreeMap::new();
+    for item in items {
+        index.entry(key(item)).or_insert(item);
+    }
+    index
+}
+
+/// Splits two indexes into added keys, removed keys, and common pairs.
+#[allow(clippy::type_complexity)]
+fn partition<'a, K: Ord + Clone, T>(
+    before: &BTreeMap<K, &'a T>,
+    after: &BTreeMap<K, &'a T>,
+) -> (Vec<K>, Vec<K>, Vec<(K, &'a T, &'a T)>) {
+    let added = after
+        .keys()
+        .filter(|k| !before.contains_key(*k))
+        .cloned()
+        .collect();
+    let mut removed = Vec::new();
+    let mut common = Vec::new();
+    for (key, b) in before {
+        match after.get(key) {
+            Some(a) => common.push((key.clone(), *b, *a)),
+            None => removed.push(key.clone()),
+        }
+    }
+    (added, removed, common)
+}
+
+fn push_if_changed(changes: &mut Vec<FieldChange>, field: &str, before: String, after: String) {
+    if before != after {
+        changes.push(FieldChange::new(field, before, after));
+    }
+}
+
+/// Records a change to a field compared by value and shown in `Debug` form,
+/// for composite fields such as contexts and trust factors.
+fn push_if_differs<T: PartialEq + fmt::Debug + ?Sized>(
+    changes: &mut Vec<FieldChange>,
+    field: &str,
+    before: &T,
+    after: &T,
+) {
+    if before != after {
+        changes.push(FieldChange::new(
+            field,
+            format!("{:?}", before),
+            format!("{:?}", after),
+        ));
+    }
+}
+
+fn config_changes(before: &Simulation, after: &Simulation) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    push_if_changed(
+        &mut changes,
+        "reference_date",
+        before.reference_date().to_string(),
+        after.reference_date().to_string(),
+    );
+    let b = before.dormancy_config();
+    let a = after.dormancy_config();
+    push_if_changed(
+        &mut changes,
+        "dormancy.dormancy_threshold",
+        format_days(b.dormancy_threshold.as_days_f64()),
+        format_days(a.dormancy_threshold.as_days_f64()),
+    );
+    push_if_changed(
+        &mut changes,
+        "dormancy.stage_regression_after",
+        format_days(b.stage_regression_after.as_days_f64()),
+        format_days(a.stage_regression_after.as_days_f64()),
+    );
+    push_if_changed(
+        &mut changes,
+        "dormancy.drift_half_life",
+        format_days(b.drift_half_life.as_days_f64()),
+        format_days(a.drift_half_life.as_days_f64()),
+    );
+    push_if_changed(
+        &mut changes,
+        "dormancy.reconnection_recovery",
+        b.reconnection_recovery.to_string(),
+        a.reconnection_recovery.to_string(),
+    );
+    push_if_changed(
+        &mut changes,
+        "observation_policy",
+        before.observation_policy().to_string(),
+        after.observation_policy().to_string(),
+    );
+    push_if_changed(
+        &mut changes,
+        "contagion",
+        format_contagion(before.contagion_config()),
+        format_contagion(after.contagion_config()),
+    );
+    push_if_changed(
+        &mut changes,
+        "base_absorption",
+        format_base_absorption(before.base_absorption_config()),
+        format_base_absorption(after.base_absorption_config()),
+    );
+    push_if_changed(
+        &mut changes,
+        "stochastic",
+        format_stochastic(before.stochastic_config()),
+        format_stochastic(after.stochastic_config()),
+    );
+    push_if_changed(
+        &mut changes,
+        "cascade_rules",
+        format_list(before.cascade_rules()),
+        format_list(after.cascade_rules()),
+    );
+    push_if_changed(
+        &mut changes,
+        "recurring_events",
+        format_list(before.recurring_events()),
+        format_list(after.recurring_events()),
+    );
+    push_if_changed(
+        &mut changes,
+        "context_series",
+        format_list(before.context_series()),
+        format_list(after.context_series()),
+    );
+    push_if_changed(
+        &mut changes,
+        "external_entities",
+        format_external_entities(before),
+        format_external_entities(after),
+    );
+    push_if_changed(
+        &mut changes,
+        "external_relationship_weight",
+        before.external_relationship_weight().to_string(),
+        after.external_relationship_weight().to_string(),
+    );
+    push_if_changed(
+        &mut changes,
+        "groups",
+        format_groups(before),
+        format_groups(after),
+    );
+    push_if_changed(
+        &mut changes,
+        "memory_formation",
+        before.memory_formation().to_string(),
+        after.memory_formation().to_string(),
+    );
+    push_if_changed(
+        &mut changes,
+        "emotion_model",
+        format!("{:?}", before.emotion_model()),
+        format!("{:?}", after.emotion_model()),
+    );
+    push_if_changed(
+        &mut changes,
+        "its_invariant_mode",
+        format!("{:?}", before.its_invariant_mode()),
+        format!("{:?}", after.its_invariant_mode()),
+    );
+    push_if_changed(
+        &mut changes,
+        "validation",
+        format!("{:?}", before.validation_config()),
+        format!("{:?}", after.validation_config()),
+    );
+    changes
+}
+
+fn format_contagion(config: Option<&ContagionConfig>) -> String {
+    config.map_or_else(
+        || "off".to_string(),
+        |c| {
+            format!(
+                "step {}, rate {}, half-life {}, max shift {}",
+                format_days(c.step.as_days_f64()),
+                c.rate,
+                format_days(c.half_life.as_days_f64()),
+                c.max_shift
+            )
+        },
+    )
+}
+
+fn format_base_absorption(config: Option<&BaseAbsorptionConfig>) -> String {
+    config.map_or_else(
+        || "off".to_string(),
+        |c| {
+            format!(
+                "threshold {}, onset {}, monthly rate {}, max shift {}",
+                c.threshold,
+                format_days(c.onset.as_days_f64()),
+                c.monthly_rate,
+                c.max_shift
+            )
+        },
+    )
+}
+
+fn format_stochastic(config: Option<&StochasticConfig>) -> String {
+    config.map_or_else(
+        || "off".to_string(),
+        |c| format!("seed {}, magnitude {}", c.seed, c.magnitude),
+    )
+}
+
+fn format_list<T: fmt::Debug>(items: &[T]) -> String {
+    if items.is_empty() {
+        return "none".to_string();
+    }
+    let items: Vec<String> = items.iter().map(|item| format!("{:?}", item)).collect();
+    items.join("; ")
+}
+
+fn format_external_entities(simulation: &Simulation) -> String {
+    let mut ids: Vec<&str> = simulation
+        .external_entities()
+        .map(EntityId::as_str)
+        .collect();
+    if ids.is_empty() {
+        return "none".to_string();
+    }
+    ids.sort_unstable();
+    ids.join(", ")
+}
+
+fn format_groups(simulation: &Simulation) -> String {
+    let mut groups: Vec<String> = simulation
+        .groups()
+        .map(|(group, members)| {
+            let members: Vec<&str> = members.iter().map(EntityId::as_str).collect();
+            format!("{}: {}", group.as_str(), members.join(", "))
+        })
+        .collect();
+    if groups.is_empty() {
+        return "none".to_string();
+    }
+    groups.sort_unstable();
+    groups.join("; ")
+}
+
+fn format_days(days: f64) -> String {
+    format!("{}d", days)
+}
+
+fn format_option<T: fmt::Display>(value: Option<T>) -> String {
+    value.map_or_else(|| "none".to_string(), |v| v.to_string())
+}
+
+fn format_observations(anchored: &AnchoredEntity) -> String {
+    let timestamps: Vec<String> = anchored
+        .observations()
+        .iter()
+        .map(|observation| observation.timestamp().to_string())
+        .collect();
+    if timestamps.is_empty() {
+        "none".to_string()
+    } else {
+        timestamps.join(", ")
+    }
+}
+
+fn entity_changes(before: &AnchoredEntity, after: &AnchoredEntity) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    push_if_changed(
+        &mut changes,
+        "anchor_timestamp",
+        before.anchor_timestamp().to_string(),
+        after.anchor_timestamp().to_string(),
+    );
+    push_if_changed(
+        &mut changes,
+        "observations",
+        format_observations(before),
+        format_observations(after),
+    );
+    let b = before.entity();
+    let a = after.entity();
+    push_if_changed(
+        &mut changes,
+        "species",
+        format!("{:?}", b.species()),
+        format!("{:?}", a.species()),
+    );
+    push_if_changed(
+        &mut changes,
+        "birth_date",
+        format_option(b.birth_date()),
+        format_option(a.birth_date()),
+    );
+    push_if_changed(
+        &mut changes,
+        "age",
+        format_days(b.age().as_days_f64()),
+        format_days(a.age().as_days_f64()),
+    );
+    push_if_changed(
+        &mut changes,
+        "life_stage",
+        format!("{:?}", b.life_stage()),
+        format!("{:?}", a.life_stage()),
+    );
+    changes.extend(context_changes(b.context(), a.context()));
+    push_if_differs(
+        &mut changes,
+        "context_changes",
+        before.context_changes(),
+        after.context_changes(),
+    );
+    changes.extend(model_config_changes(b.config(), a.config()));
+    for path in StatePath::all() {
+        let before_value = b.get_effective(path);
+        let after_value = a.get_effective(path);
+        let differs = match (before_value, after_value) {
+            (Some(x), Some(y)) => (x - y).abs() > STATE_DIFF_EPSILON,
+            (x, y) => x.is_some() != y.is_some(),
+        };
+        if differs {
+            changes.push(FieldChange::new(
+                path.to_string(),
+                format_option(before_value),
+                format_option(after_value),
+            ));
+        }
+    }
+    changes
+}
+
+fn event_changes(before: &TimestampedEvent, after: &TimestampedEvent) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    push_if_changed(
+        &mut changes,
+        "timestamp",
+        before.timestamp().to_string(),
+        after.timestamp().to_string(),
+    );
+    let b = before.event();
+    let a = after.event();
+    push_if_changed(
+        &mut changes,
+        "event_type",
+        format!("{:?}", b.event_type()),
+        format!("{:?}", a.event_type()),
+    );
+    push_if_changed(
+        &mut changes,
+        "severity",
+        b.severity().to_string(),
+        a.severity().to_string(),
+    );
+    push_if_changed(
+        &mut changes,
+        "objective_severity",
+        format_option(b.objective_severity()),
+        format_option(a.objective_severity()),
+    );
+    push_if_changed(
+        &mut changes,
+        "source",
+        format_option(b.source()),
+        format_option(a.source()),
+    );
+    push_if_changed(
+        &mut changes,
+        "target",
+        format_option(b.target()),
+        format_option(a.target()),
+    );
+    push_if_changed(
+        &mut changes,
+        "payload",
+        format!("{:?}", b.payload()),
+        format!("{:?}", a.payload()),
+    );
+    push_if_changed(
+        &mut changes,
+        "provenance",
+        format_option(b.provenance()),
+        format_option(a.provenance()),
+    );
+    push_if_changed(
+        &mut changes,
+        "severity_appraised",
+        b.is_severity_appraised().to_string(),
+        a.is_severity_appraised().to_string(),
+    );
+    push_if_changed(
+        &mut changes,
+        "witnesses",
+        format_ids(b.witnesses()),
+        format_ids(a.witnesses()),
+    );
+    push_if_differs(&mut changes, "tags", b.tags(), a.tags());
+    push_if_changed(
+        &mut changes,
+        "group",
+        format_option(b.group()),
+        format_option(a.group()),
+    );
+    push_if_changed(
+        &mut changes,
+        "microsystem_context",
+        format_option(b.microsystem_context()),
+        format_option(a.microsystem_context()),
+    );
+    push_if_differs(
+        &mut changes,
+        "base_shifts",
+        b.base_shifts(),
+        a.base_shifts(),
+    );
+    push_if_changed(
+        &mut changes,
+        "ramp",
+        format_ramp(b.ramp_shape(), b.ramp_duration().as_days_f64()),
+        format_ramp(a.ramp_shape(), a.ramp_duration().as_days_f64()),
+    );
+    push_if_changed(
+        &mut changes,
+        "anticipation_window",
+        format_days(b.anticipation_window().as_days_f64()),
+        format_days(a.anticipation_window().as_days_f64()),
+    );
+    changes
+}
+
+fn format_ids(ids: &[EntityId]) -> String {
+    if ids.is_empty() {
+        return "none".to_string();
+    }
+    let ids: Vec<&str> = ids.iter().map(EntityId::as_str).collect();
+    ids.join(", ")
+}
+
+fn format_ramp(shape: RampShape, days: f64) -> String {
+    format!("{} over {}", shape, format_days(days))
+}
+
+fn relationship_changes(
+    before: &TimestampedRelationship,
+    after: &TimestampedRelationship,
+) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    push_if_changed(
+        &mut changes,
+        "formed_timestamp",
+        before.formed_timestamp().to_string(),
+        after.formed_timestamp().to_string(),
+    );
+    let b = before.relationship();
+    let a = after.relationship();
+    push_if_changed(
+        &mut changes,
+        "schema",
+        b.schema().to_string(),
+        a.schema().to_string(),
+    );
+    push_if_changed(
+        &mut changes,
+        "stage",
+        b.stage().to_string(),
+        a.stage().to_string(),
+    );
+    push_if_differs(&mut changes, "bonds", b.bonds(), a.bonds());
+    push_if_changed(
+        &mut changes,
+        "dissolved_at",
+        format_option(b.dissolved_at()),
+        format_option(a.dissolved_at()),
+    );
+    push_if_differs(&mut changes, "shared", b.shared(), a.shared());
+    push_if_differs(&mut changes, "pattern", b.pattern(), a.pattern());
+    push_if_differs(
+        &mut changes,
+        "antecedent_half_lives",
+        b.antecedent_half_lives(),
+        a.antecedent_half_lives(),
+    );
+    // Directions are named by entity, since the two relationships may list
+    // the pair in opposite orders
+    let key = RelationshipKey::for_relationship(before);
+    for (from, to) in [(&key.first, &key.second), (&key.second, &key.first)] {
+        let b_direction = direction_from(b, from);
+        let a_direction = direction_from(a, from);
+        let field = |name: &str| format!("{} ({} -> {})", name, from, to);
+        push_if_differs(
+            &mut changes,
+            &field("trustworthiness"),
+            b.trustworthiness(b_direction),
+            a.trustworthiness(a_direction),
+        );
+        push_if_differs(
+            &mut changes,
+            &field("perceived_risk"),
+            b.perceived_risk(b_direction),
+            a.perceived_risk(a_direction),
+        );
+        push_if_differs(
+            &mut changes,
+            &field("directional"),
+            b.directional(b_direction),
+            a.directional(a_direction),
+        );
+        push_if_differs(
+            &mut changes,
+            &field("antecedent_history"),
+            b.antecedent_history(b_direction),
+            a.antecedent_history(a_direction),
+        );
+        push_if_changed(
+            &mut changes,
+            &field("last_negative_antecedent"),
+            format_option(b.last_negative_antecedent(b_direction)),
+            format_option(a.last_negative_antecedent(a_direction)),
+        );
+    }
+    changes
+}
+
+/// Returns the direction of `relationship` whose perspective is `from`'s.
+fn direction_from(relationship: &Relationship, from: &EntityId) -> Direction {
+    if relationship.entity_a() == from {
+        Direction::AToB
+    } else {
+        Direction::BToA
+    }
+}
+
+/// Changes to an entity's ecological context, microsystems by ID and the
+/// other layers whole, with cultural orientation apart from the rest of
+/// the macrosystem.
+fn context_changes(before: &EcologicalContext, after: &EcologicalContext) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    let before_micro: BTreeMap<&str, &Microsystem> = before
+        .microsystems_iter()
+        .map(|(id, microsystem)| (id.as_str(), microsystem))
+        .collect();
+    let after_micro: BTreeMap<&str, &Microsystem> = after
+        .microsystems_iter()
+        .map(|(id, microsystem)| (id.as_str(), microsystem))
+        .collect();
+    let mut ids: Vec<&str> = before_micro
+        .keys()
+        .chain(after_micro.keys())
+        .copied()
+        .collect();
+    ids.sort_unstable();
+    ids.dedup();
+    for id in ids {
+        push_if_differs(
+            &mut changes,
+            &format!("context.microsystems.{}", id),
+            &before_micro.get(id),
+            &after_micro.get(id),
+        );
+    }
+    push_if_differs(
+        &mut changes,
+        "context.exosystem",
+        before.exosystem(),
+        after.exosystem(),
+    );
+    let b = before.macrosystem();
+    let a = after.macrosystem();
+    push_if_differs(
+        &mut changes,
+        "context.macrosystem.cultural_orientation",
+        &b.cultural_orientation,
+        &a.cultural_orientation,
+    );
+    let without_orientation = |macrosystem: &MacrosystemContext| MacrosystemContext {
+        cultural_orientation: CulturalOrientation::default(),
+        ..macrosystem.clone()
+    };
+    push_if_differs(
+        &mut changes,
+        "context.macrosystem",
+        &without_orientation(b),
+        &without_orientation(a),
+    );
+    push_if_differs(
+        &mut changes,
+        "context.chronosystem",
+        before.chronosystem(),
+        after.chronosystem(),
+    );
+    changes
+}
+
+/// Changes to an entity's model configuration.
+fn model_config_changes(before: &EntityModelConfig, after: &EntityModelConfig) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    let subsystems = |config: &EntityModelConfig| {
+        let mut subsystems: Vec<String> = config
+            .active_subsystems()
+            .iter()
+            .map(|subsystem| format!("{:?}", subsystem))
+            .collect();
+        subsystems.sort_unstable();
+        subsystems.join(", ")
+    };
+    push_if_changed(
+        &mut changes,
+        "config.active_subsystems",
+        subsystems(before),
+        subsystems(after),
+    );
+    push_if_changed(
+        &mut changes,
+        "config.personality_enabled",
+        before.personality_enabled().to_string(),
+        after.personality_enabled().to_string(),
+    );
+    push_if_changed(
+        &mut changes,
+        "config.mental_health_enabled",
+        before.mental_health_enabled().to_string(),
+        after.mental_health_enabled().to_string(),
+    );
+    push_if_changed(
+        &mut changes,
+        "config.time_scale",
+        before.time_scale().to_string(),
+        after.time_scale().to_string(),
+    );
+    push_if_changed(
+        &mut changes,
+        "config.proximal_frequency_threshold",
+        before.proximal_frequency_threshold().to_string(),
+        after.proximal_frequency_threshold().to_string(),
+    );
+    push_if_changed(
+        &mut changes,
+        "config.proximal_complexity_threshold",
+        before.proximal_complexity_threshold().to_string(),
+        after.proximal_complexity_threshold().to_string(),
+    );
+    push_if_differs(
+        &mut changes,
+        "config.decay_profile",
+        &before.decay_profile(),
+        &after.decay_profile(),
+    );
+    push_if_differs(
+        &mut changes,
+        "config.life_stage_table",
+        &before.life_stage_table(),
+        &after.life_stage_table(),
+    );
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::WorkContext;
+    use crate::entity::{Entity, EntityBuilder};
+    use crate::enums::{
+        ContextPath, EventTag, EventType, ExosystemPath, HexacoPath, LifeStage, MoodPath,
+        NeedsPath, RelationshipSchema, Species,
+    };
+    use crate::event::{EventBuilder, Provenance};
+    use crate::relationship::{AntecedentDirection, AntecedentType, TrustAntecedent};
+    use crate::simulation::{
+        CascadeRule, ContextChange, EmotionModel, Interpolation, ItsInvariantMode,
+        ObservationPolicy, ValidationConfig,
+    };
+    use crate::state::{DecayProfile, IndividualState, LifeStageTable};
+    use crate::types::{Duration, GroupId, MicrosystemId, Timestamp};
+
+    fn reference() -> Timestamp {
+        Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+    }
+
+    fn human(id: &str) -> Entity {
+        EntityBuilder::new()
+            .id(id)
+            .species(Species::Human)
+            .build()
+            .unwrap()
+    }
+
+    fn fixture(valence_delta: f32, severity: f64, with_relationship: bool) -> Simulation {
+        let mut sim = Simulation::new(reference());
+        let mut alice = human("alice");
+        alice
+            .individual_state_mut()
+            .mood_mut()
+            .add_valence_delta(valence_delta);
+        sim.add_entity(alice, reference());
+        sim.add_entity(human("bob"), reference());
+
+        let event = EventBuilder::new(EventType::Conflict)
+            .id(EventId::new("evt_conflict").unwrap())
+            .target(EntityId::new("alice").unwrap())
+            .severity(severity)
+            .build()
+            .unwrap();
+        sim.add_event(event, reference() + Duration::days(10));
+
+        if with_relationship {
+            sim.add_relationship(
+                EntityId::new("bob").unwrap(),
+                EntityId::new("alice").unwrap(),
+                RelationshipSchema::Peer,
+                reference(),
+            );
+        }
+        sim
+    }
+
+    #[test]
+    fn identical_simulations_have_empty_diff() {
+        let a = fixture(0.0, 0.5, false);
+        let b = fixture(0.0, 0.5, false);
+        let diff = a.diff(&b);
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_string(), "no differences\n");
+    }
+
+    #[test]
+    fn diff_reports_anchor_event_and_relationship_changes() {
+        let a = fixture(0.0, 0.5, false);
+        let b = fixture(0.2, 0.8, true);
+        let diff = a.diff(&b);
+
+        assert_eq!(diff.modified_entities.len(), 1);
+        let entity = &diff.modified_entities[0];
+        assert_eq!(entity.entity_id.as_str(), "alice");
+        assert_eq!(entity.changes.len(), 1);
+        assert_eq!(
+            entity.changes[0].field,
+            StatePath::Mood(MoodPath::Valence).to_string()
+        );
+
+        assert_eq!(diff.modified_events.len(), 1);
+        let event = &diff.modified_events[0];
+        assert_eq!(event.event_id.as_str(), "evt_conflict");
+        assert_eq!(
+            event.changes,
+            vec![FieldChange::new("severity", "0.5", "0.8")]
+        );
+
+        assert_eq!(diff.added_relationships.len(), 1);
+        let key = &diff.added_relationships[0];
+        assert_eq!(key.first.as_str(), "alice");
+        assert_eq!(key.second.as_str(), "bob");
+
+        assert!(diff.added_entities.is_empty());
+        assert!(diff.removed_entities.is_empty());
+        assert!(diff.removed_relationships.is_empty());
+        assert!(diff.config_changes.is_empty());
+    }
+
+    #[test]
+    fn diff_is_symmetric_under_inverse() {
+        let a = fixture(0.0, 0.5, false);
+        let b = fixture(0.2, 0.8, true);
+        let forward = a.diff(&b);
+        let backward = b.diff(&a);
+        assert_eq!(backward, forward.inverse());
+        assert_eq!(forward.inverse().inverse(), forward);
+        assert_eq!(backward.removed_relationships, forward.added_relationships);
+    }
+
+    #[test]
+    fn diff_tracks_added_and_removed_entities_and_events() {
+        let a = fixture(0.0, 0.5, false);
+        let mut b = fixture(0.0, 0.5, false);
+        b.add_entity(human("carol"), reference());
+        let event = EventBuilder::new(EventType::Support)
+            .id(EventId::new("evt_support").unwrap())
+            .target(EntityId::new("bob").unwrap())
+            .build()
+            .unwrap();
+        b.add_event(event, reference());
+
+        let diff = a.diff(&b);
+        assert_eq!(diff.added_entities, vec![EntityId::new("carol").unwrap()]);
+        assert_eq!(
+            diff.added_events,
+            vec![EventId::new("evt_support").unwrap()]
+        );
+
+        let reverse = b.diff(&a);
+        assert_eq!(reverse.removed_entities, diff.added_entities);
+        assert_eq!(reverse.removed_events, diff.added_events);
+    }
+
+    #[test]
+    fn diff_reports_ramp_changes() {
+        let a = fixture(0.0, 0.5, false);
+        let mut b = Simulation::new(reference());
+        b.add_entity(human("alice"), reference());
+        b.add_entity(human("bob"), reference());
+        let event = EventBuilder::new(EventType::Conflict)
+            .id(EventId::new("evt_conflict").unwrap())
+            .target(EntityId::new("alice").unwrap())
+            .severity(0.5)
+            .ramp(Duration::days(30), RampShape::Linear)
+            .build()
+            .unwrap();
+        b.add_event(event, reference() + Duration::days(10));
+
+        let diff = a.diff(&b);
+        assert_eq!(
+            diff.modified_events[0].changes,
+            vec![FieldChange::new("ramp", "Step over 0d", "Linear over 30d")]
+        );
+    }
+
+    #[test]
+    fn diff_reports_provenance_changes() {
+        let a = fixture(0.0, 0.5, false);
+        let mut b = Simulation::new(reference());
+        b.add_entity(human("alice"), reference());
+        b.add_entity(human("bob"), reference());
+        let event = EventBuilder::new(EventType::Conflict)
+            .id(EventId::new("evt_conflict").unwrap())
+            .target(EntityId::new("alice").unwrap())
+            .severity(0.5)
+            .provenance(Provenance {
+                system: "ehr".to_string(),
+                external_id: "enc-7".to_string(),
+                ingested_at: reference(),
+            })
+            .build()
+            .unwrap();
+        b.add_event(event, reference() + Duration::days(10));
+
+        let diff = a.diff(&b);
+        assert_eq!(
+            diff.modified_events[0].changes,
+            vec![FieldChange::new(
+                "provenance",
+                "none",
+                "ehr:enc-7 (ingested 2024-01-01 00:00:00)"
+            )]
+        );
+    }
+
+    #[test]
+    fn diff_reports_config_changes() {
+        let a = fixture(0.0, 0.5, false);
+        let mut b = fixture(0.0, 0.5, false);
+        let mut config = *b.dormancy_config();
+        config.reconnection_recovery = 0.5;
+        b.set_dormancy_config(config);
+
+        let diff = a.diff(&b);
+        assert_eq!(
+            diff.config_changes,
+            vec![FieldChange::new(
+                "dormancy.reconnection_recovery",
+                "0.75",
+                "0.5"
+            )]
+        );
+    }
+
+    #[test]
+    fn diff_reports_every_simulation_setting() {
+        let a = fixture(0.0, 0.5, false);
+        let mut b = fixture(0.0, 0.5, false);
+        let alice = EntityId::new("alice").unwrap();
+        let event = EventBuilder::new(EventType::Conflict)
+            .target(alice.clone())
+            .build()
+            .unwrap();
+        b.set_stochastic_config(Some(StochasticConfig::new(7)));
+        b.enable_cascades(vec![CascadeRule::new(
+            EventType::Conflict,
+            0.5,
+            event.clone(),
+            Duration::days(1),
+        )]);
+        b.add_recurring_event(event, reference(), Duration::days(7), 4);
+        b.set_context_series(
+            ContextPath::Exosystem(ExosystemPath::ResourceAvailability),
+            vec![(reference(), 0.6)],
+            Interpolation::Step,
+        )
+        .unwrap();
+        b.declare_external_entity(EntityId::new("pen_pal").unwrap());
+        b.set_external_relationship_weight(0.2);
+        b.add_group_member(GroupId::new("team").unwrap(), alice);
+        b.set_memory_formation(true);
+        b.set_emotion_model(EmotionModel {
+            attribution_emotions: Vec::new(),
+            ..EmotionModel::default()
+        });
+        b.set_its_invariant_mode(ItsInvariantMode::Strict);
+        b.set_validation_config(ValidationConfig {
+            assert_on_query: true,
+            ..ValidationConfig::default()
+        });
+
+        let diff = a.diff(&b);
+        let fields: Vec<&str> = diff
+            .config_changes
+            .iter()
+            .map(|change| change.field.as_str())
+            .collect();
+        assert_eq!(
+            fields,
+            [
+                "stochastic",
+                "cascade_rules",
+                "recurring_events",
+                "context_series",
+                "external_entities",
+                "external_relationship_weight",
+                "groups",
+                "memory_formation",
+                "emotion_model",
+                "its_invariant_mode",
+                "validation",
+            ]
+        );
+        assert_eq!(diff.config_changes[4].after, "pen_pal");
+        assert_eq!(diff.config_changes[6].after, "team: alice");
+        assert_eq!(b.diff(&a), diff.inverse());
+    }
+
+    /// Adds alice and bob and the conflict `shape` builds.
+    fn with_conflict(shape: fn(EventBuilder) -> EventBuilder) -> Simulation {
+        let mut sim = Simulation::new(reference());
+        sim.add_entity(human("alice"), reference());
+        sim.add_entity(human("bob"), reference());
+        let builder = EventBuilder::new(EventType::Conflict)
+            .id(EventId::new("evt_conflict").unwrap())
+            .target(EntityId::new("alice").unwrap());
+        sim.add_event(
+            shape(builder).build().unwrap(),
+            reference() + Duration::days(10),
+        );
+        sim
+    }
+
+    fn event_fields(diff: &SimulationDiff) -> Vec<&str> {
+        diff.modified_events
+            .iter()
+            .flat_map(|event| event.changes.iter().map(|change| change.field.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn diff_reports_every_event_field() {
+        type Shape = fn(EventBuilder) -> EventBuilder;
+        let unchanged: Shape = |e| e.severity(0.5);
+        let cases: [(&str, Shape, Shape); 7] = [
+            ("witnesses", unchanged, |e| {
+                e.severity(0.5).witness(EntityId::new("bob").unwrap())
+            }),
+            ("tags", unchanged, |e| e.severity(0.5).tag(EventTag::Work)),
+            ("group", unchanged, |e| {
+                e.severity(0.5).group(GroupId::new("team").unwrap())
+            }),
+            ("microsystem_context", unchanged, |e| {
+                e.severity(0.5).context(MicrosystemId::new("work").unwrap())
+            }),
+            ("base_shifts", unchanged, |e| {
+                e.severity(0.5)
+                    .with_base_shift(HexacoPath::Neuroticism, 0.1)
+            }),
+            ("anticipation_window", unchanged, |e| {
+                e.severity(0.5).anticipation_window(Duration::days(14))
+            }),
+            (
+                "severity_appraised",
+                |e| e.severity(0.5).objective_severity(0.5),
+                |e| e.objective_severity(0.5),
+            ),
+        ];
+        for (field, before, after) in cases {
+            let a = with_conflict(before);
+            let b = with_conflict(after);
+            let diff = a.diff(&b);
+            assert_eq!(event_fields(&diff), [field]);
+            assert_eq!(b.diff(&a), diff.inverse());
+        }
+    }
+
+    /// Changes the alice-bob relationship of an otherwise unchanged fixture.
+    fn with_relationship(change: fn(&mut Relationship)) -> Simulation {
+        let mut sim = fixture(0.0, 0.5, false);
+        let id = sim.add_relationship(
+            EntityId::new("bob").unwrap(),
+            EntityId::new("alice").unwrap(),
+            RelationshipSchema::Peer,
+            reference(),
+        );
+        change(sim.get_relationship_mut(&id).unwrap().relationship_mut());
+        sim
+    }
+
+    #[test]
+    fn diff_reports_every_relationship_field() {
+        type Change = fn(&mut Relationship);
+        let cases: [(&str, Change); 6] = [
+            ("trustworthiness (bob -> alice)", |r| {
+                r.trustworthiness_mut(Direction::AToB)
+                    .add_integrity_delta(0.2);
+            }),
+            ("perceived_risk (alice -> bob)", |r| {
+                r.perceived_risk_mut(Direction::BToA).add_delta(0.2);
+            }),
+            ("directional (bob -> alice)", |r| {
+                r.directional_mut(Direction::AToB).add_warmth_delta(0.2);
+            }),
+            ("antecedent_history (alice -> bob)", |r| {
+                r.append_antecedent(
+                    Direction::BToA,
+                    TrustAntecedent::new(
+                        reference(),
+                        AntecedentType::Integrity,
+                        AntecedentDirection::Positive,
+                        0.5,
+                        "kept a promise",
+                    ),
+                );
+            }),
+            ("pattern", |r| {
+                r.pattern_mut().record_interaction(reference(), None, 30);
+            }),
+            ("dissolved_at", |r| {
+                *r = r
+                    .clone()
+                    .with_dissolved_at(reference() + Duration::days(30));
+            }),
+        ];
+        let a = fixture(0.0, 0.5, true);
+        for (field, change) in cases {
+            let b = with_relationship(change);
+            let diff = a.diff(&b);
+            let fields: Vec<&str> = diff
+                .modified_relationships
+                .iter()
+                .flat_map(|rel| rel.changes.iter().map(|change| change.field.as_str()))
+                .collect();
+            assert_eq!(fields, [field]);
+            assert_eq!(b.diff(&a), diff.inverse());
+        }
+    }
+
+    /// Replaces alice with the entity `build` makes from her builder.
+    fn with_alice(build: fn(EntityBuilder) -> EntityBuilder) -> Simulation {
+        let mut sim = Simulation::new(reference());
+        let builder = EntityBuilder::new().id("alice").species(Species::Human);
+        sim.add_entity(build(builder).build().unwrap(), reference());
+        sim
+    }
+
+    #[test]
+    fn diff_reports_every_entity_setting() {
+        type Build = fn(EntityBuilder) -> EntityBuilder;
+        let cases: [(&str, Build); 4] = [
+            ("context.microsystems.work", |e| {
+                let mut context = EcologicalContext::default();
+                context.add_microsystem(
+                    MicrosystemId::new("work").unwrap(),
+                    Microsystem::new_work(WorkContext::default()),
+                );
+                e.with_context(context)
+            }),
+            ("context.macrosystem.cultural_orientation", |e| {
+                let mut context = EcologicalContext::default();
+                context
+                    .macrosystem_mut()
+                    .cultural_orientation
+                    .individualism_collectivism = -0.8;
+                e.with_context(context)
+            }),
+            ("config.decay_profile", |e| {
+                e.decay_profile(
+                    DecayProfile::new()
+                        .with_half_life(StatePath::Mood(MoodPath::Valence), Duration::days(3)),
+                )
+            }),
+            ("config.life_stage_table", |e| {
+                e.life_stage_table(
+                    LifeStageTable::for_species(&Species::Human)
+                        .with_stage_entry(LifeStage::Adult, 21.0),
+                )
+            }),
+        ];
+        let a = with_alice(|e| e);
+        for (field, build) in cases {
+            let b = with_alice(build);
+            let diff = a.diff(&b);
+            let fields: Vec<&str> = diff.modified_entities[0]
+                .changes
+                .iter()
+                .map(|change| change.field.as_str())
+                .collect();
+            assert_eq!(fields, [field]);
+            assert_eq!(b.diff(&a), diff.inverse());
+        }
+
+        let mut b = with_alice(|e| e);
+        b.add_context_change(
+            &EntityId::new("alice").unwrap(),
+            ContextChange::SetValue {
+                path: ContextPath::Exosystem(ExosystemPath::ResourceAvailability),
+                value: 0.2,
+            },
+            reference() + Duration::days(30),
+        );
+        let diff = a.diff(&b);
+        assert_eq!(diff.modified_entities[0].changes.len(), 1);
+        assert_eq!(
+            diff.modified_entities[0].changes[0].field,
+            "context_changes"
+        );
+    }
+
+    #[test]
+    fn diff_reports_contagion_changes() {
+        let a = fixture(0.0, 0.5, false);
+        let mut b = fixture(0.0, 0.5, false);
+        b.set_contagion_config(Some(ContagionConfig::default()));
+
+        let diff = a.diff(&b);
+        assert_eq!(
+            diff.config_changes,
+            vec![FieldChange::new(
+                "contagion",
+                "off",
+                "step 1d, rate 0.05, half-life 14d, max shift 0.15"
+            )]
+        );
+    }
+
+    #[test]
+    fn diff_reports_observation_changes() {
+        let a = fixture(0.0, 0.5, false);
+        let mut b = fixture(0.0, 0.5, false);
+        b.set_observation_policy(ObservationPolicy::Blend {
+            window: Duration::days(30),
+        });
+        let alice = EntityId::new("alice").unwrap();
+        let observed_at = reference() + Duration::days(90);
+        b.add_observation(&alice, IndividualState::new(), observed_at);
+
+        let diff = a.diff(&b);
+        assert_eq!(
+            diff.config_changes,
+            vec![FieldChange::new("observation_policy", "snap", "blend(30d)")]
+        );
+        assert_eq!(diff.modified_entities.len(), 1);
+        assert_eq!(
+            diff.modified_entities[0].changes,
+            vec![FieldChange::new(
+                "observations",
+                "none",
+                observed_at.to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn relationship_stage_change_is_modification() {
+        let a = fixture(0.0, 0.5, true);
+        let mut b = fixture(0.0, 0.5, false);
+        let id = b.add_relationship(
+            EntityId::new("alice").unwrap(),
+            EntityId::new("bob").unwrap(),
+            RelationshipSchema::Peer,
+            reference(),
+        );
+        b.get_relationship_mut(&id)
+            .unwrap()
+            .relationship_mut()
+            .set_stage(crate::relationship::RelationshipStage::Acquaintance)
+            .unwrap();
+
+        let diff = a.diff(&b);
+        assert_eq!(diff.modified_relationships.len(), 1);
+        assert_eq!(diff.modified_relationships[0].changes[0].field, "stage");
+    }
+
+    #[test]
+    fn display_lists_each_change() {
+        let a = fixture(0.0, 0.5, false);
+        let b = fixture(0.2, 0.8, true);
+        let text = a.diff(&b).to_string();
+        assert!(text.contains("~ entity alice"));
+        assert!(text.contains("Mood::Valence"));
+        assert!(text.contains("~ event evt_conflict"));
+        assert!(text.contains("severity: 0.5 -> 0.8"));
+        assert!(text.contains("+ relationship alice <-> bob"));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn diff_round_trips_through_serde() {
+        let a = fixture(0.0, 0.5, false);
+        let b = fixture(0.2, 0.8, true);
+        let diff = a.diff(&b);
+        let json = serde_json::to_string(&diff).unwrap();
+        let restored: SimulationDiff = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, diff);
+    }
+
+    #[test]
+    fn format_option_renders_missing_values() {
+        assert_eq!(format_option::<f64>(None), "none");
+        assert_eq!(format_option(Some(0.5)), "0.5");
+    }
+
+    #[test]
+    fn state_diff_reports_downward_crossings() {
+        let mut before = IndividualState::new();
+        before.mental_health_mut().add_acquired_capability_delta(0.5);
+        let mut after = before.clone();
+        after.mood_mut().add_valence_delta(0.2);
+
+        let diff = StateDiff::between(&before, &after);
+        assert_eq!(diff.changes.len(), 1);
+        assert!(diff.threshold_crossings.is_empty());
+        assert_eq!(diff.field_changes()[0].field, "Mood::Valence");
+
+        let inverse = StateDiff::between(&after, &IndividualState::new());
+        let crossing = inverse.threshold_crossings[0];
+        assert_eq!(
+            crossing.path,
+            StatePath::MentalHealth(MentalHealthPath::AcquiredCapability)
+        );
+        assert!(!crossing.is_upward());
+    }
+
+    #[test]
+    fn state_diff_epsilon_and_top_changes() {
+        let before = IndividualState::new();
+        let mut after = before.clone();
+        after.mood_mut().add_valence_delta(-0.05);
+        after.mood_mut().add_arousal_delta(0.4);
+        after.needs_mut().add_stress_delta(0.005);
+
+        let diff = StateDiff::between(&before, &after);
+        assert_eq!(diff.changes.len(), 3);
+        let top = diff.top_changes(2);
+        assert_eq!(top.len(), 2);
+        assert_eq!(top[0].path, StatePath::Mood(MoodPath::Arousal));
+        assert_eq!(top[1].path, StatePath::Mood(MoodPath::Valence));
+        assert!(top[1].delta() < 0.0);
+        assert_eq!(diff.top_changes(10).len(), 3);
+
+        let coarse = StateDiff::between_with_epsilon(&before, &after, 0.01);
+        assert_eq!(coarse.changes.len(), 2);
+        assert!(coarse.change(StatePath::Needs(NeedsPath::Stress)).is_none());
+    }
+}
@@ -0,0 +1,550 @@
+//! Configurable emotion derivation.
+//!
+//! `ComputedState::derived_emotions()` uses the fixed PAD octant
+//! memberships in `EmotionIntensities::from_pad`. An [`EmotionModel`], set
+//! with `Simulation::set_emotion_model`, makes those memberships tunable and
+//! adds attribution-gated emotions, and is read by
+//! `ComputedState::derived_emotions_extended()`.
+//!
+//! # Octant boundaries
+//!
+//! Each octant emotion has an [`OctantBoundaries`] with a center and width
+//! per PAD dimension. Membership in the high half of a dimension is
+//! `(value - center) / width` and in the low half `(center - value) /
+//! width`, each clamped to 0-1, and an octant's membership is the minimum
+//! over its three dimensions. The default (center 0.0, width 1.0) reproduces
+//! `EmotionIntensities::from_pad` exactly. Disgust uses the hostile octant.
+//!
+//! # Attribution-gated emotions
+//!
+//! An [`AttributionEmotion`] takes the strongest membership among some
+//! octants and scales it by how recently the entity attributed a matching
+//! event, so pride needs a positive, dominant mood and a recent achievement
+//! the entity credits to itself. The gate falls linearly from 1.0 at the
+//! event to 0.0 at the end of its window. Attributions are recomputed from
+//! the entity's anchor personality, as during interpretation.
+
+use crate::entity::Entity;
+use crate::enums::{Attribution, AttributionStability, Emotion, EventType};
+use crate::processor::{attribute_event, EmotionIntensities};
+use crate::simulation::TimestampedEvent;
+use crate::state::IndividualState;
+use crate::types::{Duration, Timestamp};
+
+/// Default window over which an attribution gates an emotion.
+pub const DEFAULT_ATTRIBUTION_WINDOW: Duration = Duration::days(14);
+
+/// Center and width of one PAD dimension's split into high and low halves.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OctantBoundary {
+    /// Value (-1.0 to 1.0) where high and low membership are both 0.0.
+    pub center: f32,
+    /// Distance from the center at which membership reaches 1.0.
+    pub width: f32,
+}
+
+impl Default for OctantBoundary {
+    fn default() -> Self {
+        OctantBoundary {
+            center: 0.0,
+            width: 1.0,
+        }
+    }
+}
+
+impl OctantBoundary {
+    /// Returns membership in the high half of the dimension.
+    fn high(&self, value: f32) -> f32 {
+        let (norm, center, width) = self.normalized(value);
+        clamp01((norm - center) / width)
+    }
+
+    /// Returns membership in the low half of the dimension.
+    fn low(&self, value: f32) -> f32 {
+        let (norm, center, width) = self.normalized(value);
+        clamp01((center - norm) / width)
+    }
+
+    /// Maps the value, center, and width onto the 0-1 scale the fixed
+    /// derivation uses, so the default boundary matches it bit for bit.
+    fn normalized(&self, value: f32) -> (f32, f32, f32) {
+        let width = self.width / 2.0;
+        debug_assert!(width > 0.0, "octant width must be positive");
+        (
+            clamp01((value + 1.0) / 2.0),
+            (self.center + 1.0) / 2.0,
+            width.max(f32::EPSILON),
+        )
+    }
+}
+
+/// Boundaries of one octant emotion across the three PAD dimensions.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OctantBoundaries {
+    /// Valence (pleasure) boundary.
+    pub valence: OctantBoundary,
+    /// Arousal boundary.
+    pub arousal: OctantBoundary,
+    /// Dominance boundary.
+    pub dominance: OctantBoundary,
+}
+
+/// Which side of an octant's boundaries an emotion sits on.
+#[derive(Debug, Clone, Copy)]
+struct Octant {
+    valence_high: bool,
+    arousal_high: bool,
+    dominance_high: bool,
+}
+
+impl OctantBoundaries {
+    /// Returns the membership of a PAD point in `octant`.
+    fn membership(&self, octant: Octant, valence: f32, arousal: f32, dominance: f32) -> f32 {
+        let side = |boundary: &OctantBoundary, high: bool, value: f32| {
+            if high {
+                boundary.high(value)
+            } else {
+                boundary.low(value)
+            }
+        };
+        side(&self.valence, octant.valence_high, valence)
+            .min(side(&self.arousal, octant.arousal_high, arousal))
+            .min(side(&self.dominance, octant.dominance_high, dominance))
+    }
+}
+
+/// Locus of causality an attribution gate matches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AttributionLocus {
+    /// The entity credits or blames itself.
+    SelfCaused,
+    /// The entity credits or blames another entity.
+    Other,
+    /// The entity credits or blames circumstances.
+    Situational,
+}
+
+impl AttributionLocus {
+    /// Returns true if `attribution` has this locus.
+    #[must_use]
+    pub fn matches(&self, attribution: &Attribution) -> bool {
+        match self {
+            AttributionLocus::SelfCaused => attribution.is_self_caused(),
+            AttributionLocus::Other => attribution.is_other(),
+            AttributionLocus::Situational => attribution.is_situational(),
+        }
+    }
+}
+
+/// The recent attributions that open an attribution-gated emotion.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AttributionGate {
+    /// Locus the attribution must have.
+    pub locus: AttributionLocus,
+    /// Stability the attribution must have, or any when `None`.
+    pub stability: Option<AttributionStability>,
+    /// Event types that count, or every type when empty.
+    pub event_types: Vec<EventType>,
+    /// How long after the event the attribution still counts.
+    pub window: Duration,
+}
+
+impl AttributionGate {
+    /// Returns true if an event of `event_type` attributed as
+    /// `attribution` opens this gate.
+    #[must_use]
+    pub fn matches(&self, event_type: EventType, attribution: &Attribution) -> bool {
+        (self.event_types.is_empty() || self.event_types.contains(&event_type))
+            && self.locus.matches(attribution)
+            && self
+                .stability
+                .is_none_or(|stability| attribution.stability() == Some(stability))
+    }
+}
+
+/// An emotion derived from octant memberships and recent attributions.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::enums::Emotion;
+/// use behavioral_pathways::simulation::AttributionEmotion;
+///
+/// let pride = AttributionEmotion::pride();
+/// assert_eq!(pride.name, "pride");
+/// assert!(pride.octants.contains(&Emotion::Exuberant));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AttributionEmotion {
+    /// Name reported in `ExtendedEmotions`.
+    pub name: String,
+    /// Octant emotions whose strongest membership sets the intensity.
+    pub octants: Vec<Emotion>,
+    /// Attributions that gate the emotion.
+    pub gate: AttributionGate,
+}
+
+impl AttributionEmotion {
+    /// Pride: positive valence and high dominance after an achievement the
+    /// entity credits to itself.
+    #[must_use]
+    pub fn pride() -> Self {
+        AttributionEmotion {
+            name: "pride".to_string(),
+            octants: vec![Emotion::Exuberant, Emotion::Relaxed],
+            gate: AttributionGate {
+                locus: AttributionLocus::SelfCaused,
+                stability: None,
+                event_types: vec![EventType::Achievement],
+                window: DEFAULT_ATTRIBUTION_WINDOW,
+            },
+        }
+    }
+
+    /// Shame: negative valence and low dominance after any event the entity
+    /// blames on a stable fault of its own.
+    #[must_use]
+    pub fn shame() -> Self {
+        AttributionEmotion {
+            name: "shame".to_string(),
+            octants: vec![Emotion::Anxious, Emotion::Depressed],
+            gate: AttributionGate {
+                locus: AttributionLocus::SelfCaused,
+                stability: Some(AttributionStability::Stable),
+                event_types: Vec::new(),
+                window: DEFAULT_ATTRIBUTION_WINDOW,
+            },
+        }
+    }
+
+    /// Returns the gate strength (0-1) from the attributions made at or
+    /// before `at`: 1.0 at a matching event, falling to 0.0 at the end of
+    /// the window.
+    fn gate_strength(&self, attributions: &[RecentAttribution], at: Timestamp) -> f32 {
+        let window = self.gate.window.as_days_f64();
+        attributions
+            .iter()
+            .filter(|recent| recent.timestamp <= at)
+            .filter(|recent| self.gate.matches(recent.event_type, &recent.attribution))
+            .map(|recent| {
+                if window <= 0.0 {
+                    return 0.0;
+                }
+                let elapsed = (at - recent.timestamp).as_days_f64();
+                (1.0 - elapsed / window).clamp(0.0, 1.0) as f32
+            })
+            .fold(0.0, f32::max)
+    }
+}
+
+/// Tunable emotion derivation for `ComputedState::derived_emotions_extended`.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::simulation::EmotionModel;
+/// use behavioral_pathways::EmotionIntensities;
+///
+/// let model = EmotionModel::default();
+/// assert_eq!(
+///     model.derive(-0.4, 0.6, -0.2, 0.0),
+///     EmotionIntensities::from_pad(-0.4, 0.6, -0.2, 0.0)
+/// );
+///
+/// // Split anxious from hostile at a higher dominance
+/// let mut shifted = EmotionModel::default();
+/// shifted.anxious.dominance.center = 0.3;
+/// assert!(shifted.derive(-0.4, 0.6, 0.1, 0.0).anxious > 0.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmotionModel {
+    /// Boundaries of the exuberant octant (V+ A+ D+).
+    pub exuberant: OctantBoundaries,
+    /// Boundaries of the dependent octant (V+ A+ D-).
+    pub dependent: OctantBoundaries,
+    /// Boundaries of the relaxed octant (V+ A- D+).
+    pub relaxed: OctantBoundaries,
+    /// Boundaries of the docile octant (V+ A- D-).
+    pub docile: OctantBoundaries,
+    /// Boundaries of the hostile octant (V- A+ D+), also used for disgust.
+    pub hostile: OctantBoundaries,
+    /// Boundaries of the anxious octant (V- A+ D-).
+    pub anxious: OctantBoundaries,
+    /// Boundaries of the bored octant (V- A- D+).
+    pub bored: OctantBoundaries,
+    /// Boundaries of the depressed octant (V- A- D-).
+    pub depressed: OctantBoundaries,
+    /// Attribution-gated emotions, reported in this order. Pride and shame
+    /// by default.
+    pub attribution_emotions: Vec<AttributionEmotion>,
+}
+
+impl Default for EmotionModel {
+    fn default() -> Self {
+        EmotionModel {
+            exuberant: OctantBoundaries::default(),
+            dependent: OctantBoundaries::default(),
+            relaxed: OctantBoundaries::default(),
+            docile: OctantBoundaries::default(),
+            hostile: OctantBoundaries::default(),
+            anxious: OctantBoundaries::default(),
+            bored: OctantBoundaries::default(),
+            depressed: OctantBoundaries::default(),
+            attribution_emotions: vec![AttributionEmotion::pride(), AttributionEmotion::shame()],
+        }
+    }
+}
+
+impl EmotionModel {
+    /// Derives graded octant emotions from PAD values with this model's
+    /// boundaries.
+    ///
+    /// Values outside -1 to 1 are clamped. Disgust is the hostile
+    /// membership scaled by `moral_violation_flag` (0 to 1).
+    #[must_use]
+    pub fn derive(
+        &self,
+        valence: f32,
+        arousal: f32,
+        dominance: f32,
+        moral_violation_flag: f32,
+    ) -> EmotionIntensities {
+        let octant = |boundaries: &OctantBoundaries, v: bool, a: bool, d: bool| {
+            let octant = Octant {
+                valence_high: v,
+                arousal_high: a,
+                dominance_high: d,
+            };
+            boundaries.membership(octant, valence, arousal, dominance)
+        };
+        let hostile = octant(&self.hostile, false, true, true);
+
+        EmotionIntensities {
+            exuberant: octant(&self.exuberant, true, true, true),
+            dependent: octant(&self.dependent, true, true, false),
+            relaxed: octant(&self.relaxed, true, false, true),
+            docile: octant(&self.docile, true, false, false),
+            hostile,
+            disgust: hostile * clamp01(moral_violation_flag),
+            anxious: octant(&self.anxious, false, true, false),
+            bored: octant(&self.bored, false, false, true),
+            depressed: octant(&self.depressed, false, false, false),
+        }
+    }
+
+    /// Derives graded octant emotions from a state's effective PAD values
+    /// and moral violation flag.
+    #[must_use]
+    pub fn derive_from_state(&self, state: &IndividualState) -> EmotionIntensities {
+        let mood = state.mood();
+        self.derive(
+            mood.valence_effective(),
+            mood.arousal_effective(),
+            mood.dominance_effective(),
+            state.recent_moral_violation_flag(),
+        )
+    }
+
+    /// Returns the longest attribution window, which bounds how far back
+    /// attributions are collected.
+    pub(crate) fn longest_window(&self) -> Option<Duration> {
+        self.attribution_emotions
+            .iter()
+            .map(|emotion| emotion.gate.window)
+            .max()
+    }
+
+    /// Derives the octant and attribution-gated emotions at `at`.
+    pub(crate) fn derive_extended(
+        &self,
+        state: &IndividualState,
+        attributions: &[RecentAttribution],
+        at: Timestamp,
+    ) -> ExtendedEmotions {
+        let octants = self.derive_from_state(state);
+        let gated = self
+            .attribution_emotions
+            .iter()
+            .map(|emotion| {
+                let membership = emotion
+                    .octants
+                    .iter()
+                    .map(|octant| octants.intensity(*octant))
+                    .fold(0.0, f32::max);
+                let intensity = membership * emotion.gate_strength(attributions, at);
+                (emotion.name.clone(), intensity)
+            })
+            .collect();
+        ExtendedEmotions { octants, gated }
+    }
+}
+
+/// An attribution the entity made for an event.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct RecentAttribution {
+    /// When the event occurred.
+    pub timestamp: Timestamp,
+    /// The event's type.
+    pub event_type: EventType,
+    /// The attribution made.
+    pub attribution: Attribution,
+}
+
+impl RecentAttribution {
+    /// Collects the attributions `entity` made for `events` in the window
+    /// ending at `at`.
+    pub(crate) fn collect<'a>(
+        entity: &Entity,
+        events: impl IntoIterator<Item = &'a TimestampedEvent>,
+        at: Timestamp,
+        window: Duration,
+    ) -> Vec<RecentAttribution> {
+        let start = at - window;
+        events
+            .into_iter()
+            .filter(|te| te.timestamp() <= at && te.timestamp() >= start)
+            .map(|te| RecentAttribution {
+                timestamp: te.timestamp(),
+                event_type: te.event().event_type(),
+                attribution: attribute_event(te.event(), entity),
+            })
+            .collect()
+    }
+}
+
+/// Octant and attribution-gated emotions at a timestamp.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::entity::EntityBuilder;
+/// use behavioral_pathways::enums::Species;
+/// use behavioral_pathways::simulation::Simulation;
+/// use behavioral_pathways::types::Timestamp;
+///
+/// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+/// let mut sim = Simulation::new(reference);
+/// let entity = EntityBuilder::new().species(Species::Human).build().unwrap();
+/// let id = sim.add_entity(entity, reference);
+///
+/// let emotions = sim.entity(&id).unwrap().state_at(reference).derived_emotions_extended();
+/// assert_eq!(emotions.get("pride"), Some(0.0));
+/// assert_eq!(emotions.get("awe"), None);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtendedEmotions {
+    /// Octant emotions under the model's boundaries.
+    pub octants: EmotionIntensities,
+    /// Attribution-gated emotions by name, in model order.
+    pub gated: Vec<(String, f32)>,
+}
+
+impl ExtendedEmotions {
+    /// Returns the intensity of the attribution-gated emotion `name`.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<f32> {
+        self.gated
+            .iter()
+            .find(|(gated, _)| gated == name)
+            .map(|(_, intensity)| *intensity)
+    }
+}
+
+fn clamp01(value: f32) -> f32 {
+    value.clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::EntityId;
+
+    fn self_caused(stability: AttributionStability) -> Attribution {
+        Attribution::SelfCaused(stability)
+    }
+
+    #[test]
+    fn default_model_matches_fixed_derivation() {
+        let model = EmotionModel::default();
+        let steps = [-1.2, -1.0, -0.73, -0.5, -0.1, 0.0, 0.2, 0.5, 0.91, 1.0, 1.3];
+        for v in steps {
+            for a in steps {
+                for d in steps {
+                    let fixed = EmotionIntensities::from_pad(v, a, d, 0.6);
+                    let configured = model.derive(v, a, d, 0.6);
+                    for emotion in Emotion::all() {
+                        assert_eq!(
+                            fixed.intensity(emotion).to_bits(),
+                            configured.intensity(emotion).to_bits(),
+                            "{emotion:?} at ({v}, {a}, {d})"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn narrower_width_saturates_sooner() {
+        let mut model = EmotionModel::default();
+        model.relaxed.valence.width = 0.5;
+        assert_eq!(model.derive(0.5, -1.0, 1.0, 0.0).relaxed, 1.0);
+        assert_eq!(
+            EmotionModel::default().derive(0.5, -1.0, 1.0, 0.0).relaxed,
+            0.5
+        );
+    }
+
+    #[test]
+    fn gate_matches_locus_stability_and_type() {
+        let shame = AttributionEmotion::shame().gate;
+        assert!(shame.matches(
+            EventType::Failure,
+            &self_caused(AttributionStability::Stable)
+        ));
+        assert!(!shame.matches(
+            EventType::Failure,
+            &self_caused(AttributionStability::Unstable)
+        ));
+        let other = Attribution::Other(
+            EntityId::new("critic").unwrap(),
+            AttributionStability::Stable,
+        );
+        assert!(!shame.matches(EventType::Failure, &other));
+
+        let pride = AttributionEmotion::pride().gate;
+        assert!(!pride.matches(
+            EventType::Failure,
+            &self_caused(AttributionStability::Stable)
+        ));
+    }
+
+    #[test]
+    fn gate_strength_falls_over_the_window() {
+        let pride = AttributionEmotion::pride();
+        let event_at = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let attributions = [RecentAttribution {
+            timestamp: event_at,
+            event_type: EventType::Achievement,
+            attribution: self_caused(AttributionStability::Unstable),
+        }];
+
+        assert_eq!(pride.gate_strength(&attributions, event_at), 1.0);
+        let halfway = pride.gate_strength(&attributions, event_at + Duration::days(7));
+        assert!((halfway - 0.5).abs() < 1e-6);
+        assert_eq!(
+            pride.gate_strength(&attributions, event_at + Duration::days(20)),
+            0.0
+        );
+        assert_eq!(
+            pride.gate_strength(&attributions, event_at - Duration::days(1)),
+            0.0
+        );
+    }
+}
@@ -0,0 +1,323 @@
+//! Owned entity references that outlive simulation borrows.
+//!
+//! `EntityQueryHandle` borrows the `Simulation`, so it must be dropped
+//! before the simulation can be mutated. `EntityRef` holds only the entity
+//! ID and the entity's generation, and takes the simulation as an argument
+//! on each query, so it can be stored in application state across
+//! mutations.
+//!
+//! The generation changes whenever the entity's anchor data may have
+//! changed: the entity is replaced, borrowed mutably, or given an
+//! observation. Adding events, relationships, or other entities does not
+//! change it, since queries already recompute from the current events.
+
+use crate::simulation::{ComputedState, EntityQueryHandle, Simulation};
+use crate::types::{EntityId, Timestamp};
+use std::fmt;
+
+/// Errors from querying through an `EntityRef`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EntityRefError {
+    /// The entity is not in the simulation.
+    UnknownEntity(EntityId),
+    /// The entity changed since the reference was taken, and the
+    /// reference is strict.
+    StaleGeneration {
+        /// The entity that changed.
+        entity_id: EntityId,
+        /// The generation the reference was taken at.
+        expected: u64,
+        /// The entity's current generation.
+        current: u64,
+    },
+}
+
+impl fmt::Display for EntityRefError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EntityRefError::UnknownEntity(id) => {
+                write!(f, "Unknown entity: {}", id.as_str())
+            }
+            EntityRefError::StaleGeneration {
+                entity_id,
+                expected,
+                current,
+            } => write!(
+                f,
+                "Entity '{}' changed since reference was taken (generation {} is now {})",
+                entity_id.as_str(),
+                expected,
+                current
+            ),
+        }
+    }
+}
+
+impl std::error::Error for EntityRefError {}
+
+/// An owned reference to an entity, validated against its generation.
+///
+/// By default a reference is lenient: queries recompute from the entity's
+/// current data even if it changed since the reference was taken. A strict
+/// reference instead returns `EntityRefError::StaleGeneration`, so callers
+/// can notice the change and `refresh()` deliberately.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::simulation::{EntityRefError, Simulation};
+/// use behavioral_pathways::entity::EntityBuilder;
+/// use behavioral_pathways::enums::Species;
+/// use behavioral_pathways::types::Timestamp;
+///
+/// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+/// let mut sim = Simulation::new(reference);
+/// let entity = EntityBuilder::new().id("person_001").species(Species::Human).build().unwrap();
+/// let id = sim.add_entity(entity.clone(), reference);
+///
+/// let mut person = sim.entity_ref(&id).unwrap().strict();
+///
+/// // The simulation can be mutated while the reference is held
+/// sim.add_entity(entity, reference);
+/// assert!(matches!(
+///     person.state_at(&sim, reference),
+///     Err(EntityRefError::StaleGeneration { .. })
+/// ));
+///
+/// person.refresh(&sim).unwrap();
+/// assert!(person.state_at(&sim, reference).is_ok());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntityRef {
+    /// The referenced entity.
+    entity_id: EntityId,
+    /// The entity's generation when the reference was taken.
+    generation: u64,
+    /// Whether a changed generation is an error.
+    strict: bool,
+}
+
+impl EntityRef {
+    /// Creates a lenient reference at the given generation.
+    pub(crate) fn new(entity_id: EntityId, generation: u64) -> Self {
+        EntityRef {
+            entity_id,
+            generation,
+            strict: false,
+        }
+    }
+
+    /// Returns a strict copy of this reference.
+    #[must_use]
+    pub fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+
+    /// Returns the referenced entity's ID.
+    #[must_use]
+    pub fn entity_id(&self) -> &EntityId {
+        &self.entity_id
+    }
+
+    /// Returns the generation the reference was taken at.
+    #[must_use]
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Returns true if the reference is strict.
+    #[must_use]
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Returns true if the entity exists and has not changed since the
+    /// reference was taken.
+    #[must_use]
+    pub fn is_current(&self, simulation: &Simulation) -> bool {
+        simulation
+            .get_anchored_entity(&self.entity_id)
+            .is_some_and(|anchored| anchored.generation() == self.generation)
+    }
+
+    /// Updates the reference to the entity's current generation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EntityRefError::UnknownEntity` if the entity is not in the
+    /// simulation.
+    pub fn refresh(&mut self, simulation: &Simulation) -> Result<(), EntityRefError> {
+        let anchored = simulation
+            .get_anchored_entity(&self.entity_id)
+            .ok_or_else(|| EntityRefError::UnknownEntity(self.entity_id.clone()))?;
+        self.generation = anchored.generation();
+        Ok(())
+    }
+
+    /// Returns a borrowed query handle for the entity.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EntityRefError::UnknownEntity` if the entity is not in the
+    /// simulation, or `EntityRefError::StaleGeneration` if the reference
+    /// is strict and the entity has changed.
+    pub fn handle<'a>(
+        &self,
+        simulation: &'a Simulation,
+    ) -> Result<EntityQueryHandle<'a>, EntityRefError> {
+        let anchored = simulation
+            .get_anchored_entity(&self.entity_id)
+            .ok_or_else(|| EntityRefError::UnknownEntity(self.entity_id.clone()))?;
+        if self.strict && anchored.generation() != self.generation {
+            return Err(EntityRefError::StaleGeneration {
+                entity_id: self.entity_id.clone(),
+                expected: self.generation,
+                current: anchored.generation(),
+            });
+        }
+        Ok(EntityQueryHandle::new(simulation, self.entity_id.clone()))
+    }
+
+    /// Computes the entity's state at the given timestamp.
+    ///
+    /// Equivalent to `handle(simulation)?.state_at(timestamp)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as `handle()`.
+    pub fn state_at(
+        &self,
+        simulation: &Simulation,
+        timestamp: Timestamp,
+    ) -> Result<ComputedState, EntityRefError> {
+        Ok(self.handle(simulation)?.state_at(timestamp))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::{Entity, EntityBuilder};
+    use crate::enums::{EventType, RelationshipSchema, Species};
+    use crate::event::EventBuilder;
+    use crate::state::IndividualState;
+    use crate::types::Duration;
+
+    fn reference() -> Timestamp {
+        Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+    }
+
+    fn human(id: &str) -> Entity {
+        EntityBuilder::new()
+            .id(id)
+            .species(Species::Human)
+            .build()
+            .unwrap()
+    }
+
+    fn sim_with(ids: &[&str]) -> Simulation {
+        let mut sim = Simulation::new(reference());
+        for id in ids {
+            sim.add_entity(human(id), reference());
+        }
+        sim
+    }
+
+    #[test]
+    fn replacing_entity_bumps_generation() {
+        let mut sim = sim_with(&["alice"]);
+        let alice = EntityId::new("alice").unwrap();
+        let lenient = sim.entity_ref(&alice).unwrap();
+        let strict = lenient.clone().strict();
+
+        sim.add_entity(human("alice"), reference() + Duration::days(1));
+
+        assert!(!lenient.is_current(&sim));
+        assert!(lenient.state_at(&sim, reference()).is_ok());
+        let err = strict.state_at(&sim, reference()).unwrap_err();
+        assert_eq!(
+            err,
+            EntityRefError::StaleGeneration {
+                entity_id: alice.clone(),
+                expected: strict.generation(),
+                current: sim.get_anchored_entity(&alice).unwrap().generation(),
+            }
+        );
+        assert!(err.to_string().contains("alice"));
+    }
+
+    #[test]
+    fn mutable_access_and_observations_bump_generation() {
+        let mut sim = sim_with(&["alice"]);
+        let alice = EntityId::new("alice").unwrap();
+
+        let before = sim.entity_ref(&alice).unwrap();
+        let _ = sim.get_anchored_entity_mut(&alice);
+        assert!(!before.is_current(&sim));
+
+        let before = sim.entity_ref(&alice).unwrap();
+        sim.add_observation(
+            &alice,
+            IndividualState::new(),
+            reference() + Duration::days(30),
+        );
+        assert!(!before.is_current(&sim));
+    }
+
+    #[test]
+    fn unrelated_mutations_keep_generation() {
+        let mut sim = sim_with(&["alice", "bob"]);
+        let alice = EntityId::new("alice").unwrap();
+        let bob = EntityId::new("bob").unwrap();
+        let person = sim.entity_ref(&alice).unwrap().strict();
+
+        let event = EventBuilder::new(EventType::Achievement)
+            .target(alice.clone())
+            .build()
+            .unwrap();
+        sim.add_event(event, reference() + Duration::days(2));
+        sim.add_relationship(
+            alice.clone(),
+            bob.clone(),
+            RelationshipSchema::Peer,
+            reference(),
+        );
+        sim.add_entity(human("carol"), reference());
+        let _ = sim.get_anchored_entity_mut(&bob);
+        sim.set_dormancy_config(Default::default());
+
+        assert!(person.is_current(&sim));
+        let computed = person
+            .state_at(&sim, reference() + Duration::days(3))
+            .unwrap();
+        let direct = sim
+            .entity(&alice)
+            .unwrap()
+            .state_at(reference() + Duration::days(3));
+        assert_eq!(computed.individual_state(), direct.individual_state());
+    }
+
+    #[test]
+    fn unknown_entity_and_refresh() {
+        let mut sim = sim_with(&["alice"]);
+        assert!(sim.entity_ref(&EntityId::new("nobody").unwrap()).is_none());
+
+        let mut person = sim
+            .entity_ref(&EntityId::new("alice").unwrap())
+            .unwrap()
+            .strict();
+        assert!(person.is_strict());
+        sim.add_entity(human("alice"), reference());
+        assert!(person.handle(&sim).is_err());
+        person.refresh(&sim).unwrap();
+        assert!(person.handle(&sim).is_ok());
+
+        let other = Simulation::new(reference());
+        assert_eq!(
+            person.state_at(&other, reference()).unwrap_err(),
+            EntityRefError::UnknownEntity(EntityId::new("alice").unwrap())
+        );
+        assert!(person.refresh(&other).is_err());
+    }
+}
@@ -0,0 +1,128 @@
+//! Memories formed from high-salience events.
+//!
+//! `EntityQueryHandle::memories_at()` consolidates the entity's stored
+//! memories together with one memory for each event targeting or witnessed
+//! by the entity whose salience reaches `EVENT_MEMORY_SALIENCE_THRESHOLD`.
+//! Salience is the arousal-modulated salience `state_at()` computes when it
+//! interprets the event.
+//!
+//! Event memories are derived on every query and never stored, so they
+//! are deterministic and do not prime later states the way stored
+//! memories do. Each holds:
+//!
+//! - the entity's age at the event, and the event's ID
+//! - the entity's PAD mood at the event, as computed after it
+//! - the event's source and target as participants
+//! - a `MemorySource::Witness` source for an event the entity witnessed
+//! - tags inferred from the event's category and the sign of its valence
+//!
+//! An event that already has a stored memory (as `record_interaction`
+//! forms) does not form a second one.
+
+use crate::enums::EventCategory;
+use crate::memory::{EmotionalSnapshot, MemoryEntry, MemoryTag};
+use crate::processor::interpret_event;
+use crate::simulation::state_query::development_age_at;
+use crate::simulation::EntityQueryHandle;
+use crate::types::{EventId, MemoryId, Timestamp};
+use std::collections::HashSet;
+
+/// Salience at or above which an event forms a memory in `memories_at()`.
+///
+/// Matches the boundary between the short-term and long-term layers of
+/// `Entity::create_memory`, so only significant events are remembered.
+pub const EVENT_MEMORY_SALIENCE_THRESHOLD: f32 = 0.6;
+
+/// Returns the memories formed from events targeting the entity at or
+/// before `timestamp`, in chronological order.
+pub(crate) fn event_memories(
+    handle: &EntityQueryHandle<'_>,
+    timestamp: Timestamp,
+) -> Vec<MemoryEntry> {
+    let entity = handle.anchor_entity();
+    let remembered: HashSet<&EventId> = entity
+        .memories()
+        .all_memories()
+        .filter_map(MemoryEntry::event_id)
+        .collect();
+
+    let mut memories = Vec::new();
+    for te in handle.events_through(timestamp) {
+        let event = te.event();
+        if remembered.contains(event.id()) {
+            continue;
+        }
+        let interpreted = interpret_event(event, entity);
+        if interpreted.salience < EVENT_MEMORY_SALIENCE_THRESHOLD {
+            continue;
+        }
+
+        let state = handle.state_at(te.timestamp());
+        let id = MemoryId::new(format!("mem_{}", event.id()))
+            .expect("memory ID from a valid event ID is valid");
+        let participants = event.source().into_iter().chain(event.target()).cloned();
+        let mut entry = MemoryEntry::with_id(
+            id,
+            development_age_at(entity, te.timestamp()),
+            event.event_type().name(),
+        )
+        .with_event_id(event.id().clone())
+        .with_participants(participants.collect())
+        .with_tags(tags_for(event.category(), interpreted.valence_delta))
+        .with_salience(interpreted.salience)
+        .with_source(interpreted.role.memory_source())
+        .with_emotional_snapshot(EmotionalSnapshot::from_mood(
+            state.individual_state().mood(),
+        ));
+        if let Some(context) = event.microsystem_context() {
+            entry = entry.with_microsystem_context(context.clone());
+        }
+        memories.push(entry);
+    }
+    memories
+}
+
+/// Returns the memory tags for an event of `category` with the given
+/// valence change.
+fn tags_for(category: EventCategory, valence_delta: f32) -> Vec<MemoryTag> {
+    let negative = valence_delta < 0.0;
+    match category {
+        EventCategory::Trauma => vec![MemoryTag::Violence, MemoryTag::Crisis],
+        EventCategory::SocialBelonging if negative => vec![MemoryTag::RelationshipBreakdown],
+        EventCategory::SocialBelonging => vec![MemoryTag::Support],
+        EventCategory::BurdenPerception => vec![MemoryTag::Personal, MemoryTag::Scarcity],
+        EventCategory::Control if negative => vec![MemoryTag::Injustice],
+        EventCategory::Achievement if negative => vec![MemoryTag::Loss],
+        EventCategory::Control | EventCategory::Achievement => vec![MemoryTag::Achievement],
+        EventCategory::Social if negative => vec![MemoryTag::Conflict],
+        EventCategory::Social => vec![MemoryTag::Cooperation],
+        EventCategory::Contextual => vec![MemoryTag::Personal],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trauma_tags_are_trauma() {
+        let tags = tags_for(EventCategory::Trauma, -0.5);
+        assert!(tags.iter().all(MemoryTag::is_trauma));
+    }
+
+    #[test]
+    fn valence_sign_selects_tag() {
+        assert_eq!(
+            tags_for(EventCategory::Social, -0.2),
+            vec![MemoryTag::Conflict]
+        );
+        assert_eq!(
+            tags_for(EventCategory::Social, 0.2),
+            vec![MemoryTag::Cooperation]
+        );
+        assert_eq!(
+            tags_for(EventCategory::Achievement, -0.2),
+            vec![MemoryTag::Loss]
+        );
+    }
+}
@@ -0,0 +1,363 @@
+//! Affective forecasting under uncertain future events.
+//!
+//! A forecast describes the future as a set of scenario branches, each a
+//! probability and the events that happen if that branch comes true. Every
+//! branch is simulated as a counterfactual (the simulation itself is never
+//! modified) and the resulting states are summarised per path as a
+//! probability-weighted distribution.
+//!
+//! Branches are independent. With the `parallel` feature they are
+//! computed on scoped threads, in chunks like population queries; without
+//! it they are computed in turn. Results do not depend on scheduling:
+//! branches keep the order they were given in.
+
+use crate::enums::StatePath;
+use crate::event::Event;
+use crate::simulation::population::evaluate_each;
+use crate::simulation::{ComputedState, EntityQueryHandle};
+use crate::types::Timestamp;
+use std::fmt;
+
+/// How far branch probabilities may sum from 1.0 before `forecast` errors.
+pub const FORECAST_PROBABILITY_TOLERANCE: f64 = 1e-3;
+
+/// One scenario branch: its probability and the events that occur in it.
+pub type ForecastScenario = (f64, Vec<(Event, Timestamp)>);
+
+/// Errors from `EntityQueryHandle::forecast`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ForecastError {
+    /// No scenario branches were given.
+    NoScenarios,
+    /// A branch probability is negative or not finite.
+    InvalidProbability {
+        /// Index of the branch.
+        branch: usize,
+        /// The probability given.
+        probability: f64,
+    },
+    /// Branch probabilities do not sum to 1 within
+    /// `FORECAST_PROBABILITY_TOLERANCE`.
+    ProbabilitiesDoNotSumToOne {
+        /// The sum of all branch probabilities.
+        total: f64,
+    },
+}
+
+impl fmt::Display for ForecastError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ForecastError::NoScenarios => write!(f, "Forecast needs at least one scenario"),
+            ForecastError::InvalidProbability {
+                branch,
+                probability,
+            } => write!(
+                f,
+                "Scenario {} has invalid probability {}",
+                branch, probability
+            ),
+            ForecastError::ProbabilitiesDoNotSumToOne { total } => write!(
+                f,
+                "Scenario probabilities sum to {}, expected 1 (tolerance {})",
+                total, FORECAST_PROBABILITY_TOLERANCE
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ForecastError {}
+
+/// The state reached in one scenario branch.
+#[derive(Debug, Clone)]
+pub struct ForecastBranch {
+    /// Probability of this branch.
+    probability: f64,
+    /// State at the forecast timestamp if this branch comes true.
+    state: ComputedState,
+}
+
+impl ForecastBranch {
+    /// Returns the branch probability.
+    #[must_use]
+    pub fn probability(&self) -> f64 {
+        self.probability
+    }
+
+    /// Returns the state at the forecast timestamp in this branch.
+    #[must_use]
+    pub fn state(&self) -> &ComputedState {
+        &self.state
+    }
+}
+
+/// Probability-weighted distribution of an entity's state across
+/// scenario branches.
+///
+/// Summaries are computed per path on request, so any `StatePath` can be
+/// queried. Probabilities are normalised to sum to exactly 1.
+#[derive(Debug, Clone)]
+pub struct ForecastDistribution {
+    /// The forecast timestamp.
+    at: Timestamp,
+    /// Branches in the order their scenarios were given.
+    branches: Vec<ForecastBranch>,
+}
+
+impl ForecastDistribution {
+    /// Returns the forecast timestamp.
+    #[must_use]
+    pub fn at(&self) -> Timestamp {
+        self.at
+    }
+
+    /// Returns the per-branch states, in the order scenarios were given.
+    #[must_use]
+    pub fn branches(&self) -> &[ForecastBranch] {
+        &self.branches
+    }
+
+    /// Returns the probability-weighted mean of a path across branches.
+    #[must_use]
+    pub fn mean(&self, path: StatePath) -> f64 {
+        self.branches
+            .iter()
+            .map(|branch| branch.probability * branch.state.get_effective(path))
+            .sum()
+    }
+
+    /// Returns the lowest value of a path in any branch.
+    #[must_use]
+    pub fn min(&self, path: StatePath) -> f64 {
+        self.values(path).fold(f64::INFINITY, f64::min)
+    }
+
+    /// Returns the highest value of a path in any branch.
+    #[must_use]
+    pub fn max(&self, path: StatePath) -> f64 {
+        self.values(path).fold(f64::NEG_INFINITY, f64::max)
+    }
+
+    /// Returns the weighted quantile of a path across branches.
+    ///
+    /// This is the lowest branch value whose cumulative probability
+    /// reaches `q` (clamped to 0-1), so `quantile(path, 0.5)` is the
+    /// weighted median.
+    #[must_use]
+    pub fn quantile(&self, path: StatePath, q: f64) -> f64 {
+        let q = q.clamp(0.0, 1.0);
+        let mut weighted: Vec<(f64, f64)> = self
+            .branches
+            .iter()
+            .map(|branch| (branch.state.get_effective(path), branch.probability))
+            .collect();
+        weighted.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut cumulative = 0.0;
+        for (value, probability) in &weighted {
+            cumulative += probability;
+            if cumulative >= q - f64::EPSILON {
+                return *value;
+            }
+        }
+        weighted[weighted.len() - 1].0
+    }
+
+    fn values(&self, path: StatePath) -> impl Iterator<Item = f64> + '_ {
+        self.branches
+            .iter()
+            .map(move |branch| branch.state.get_effective(path))
+    }
+}
+
+/// Validates scenario probabilities and returns their sum.
+fn check_probabilities(scenarios: &[ForecastScenario]) -> Result<f64, ForecastError> {
+    if scenarios.is_empty() {
+        return Err(ForecastError::NoScenarios);
+    }
+    for (branch, (probability, _)) in scenarios.iter().enumerate() {
+        if !probability.is_finite() || *probability < 0.0 {
+            return Err(ForecastError::InvalidProbability {
+                branch,
+                probability: *probability,
+            });
+        }
+    }
+    let total: f64 = scenarios.iter().map(|(probability, _)| probability).sum();
+    if (total - 1.0).abs() > FORECAST_PROBABILITY_TOLERANCE {
+        return Err(ForecastError::ProbabilitiesDoNotSumToOne { total });
+    }
+    Ok(total)
+}
+
+/// Simulates every scenario branch and collects the distribution.
+pub(crate) fn forecast(
+    handle: &EntityQueryHandle<'_>,
+    at: Timestamp,
+    scenarios: Vec<ForecastScenario>,
+) -> Result<ForecastDistribution, ForecastError> {
+    let total = check_probabilities(&scenarios)?;
+
+    let states: Vec<ComputedState> = evaluate_each(&scenarios, |(_, events)| {
+        Some(handle.preview_events(events, at))
+    });
+
+    let branches = scenarios
+        .iter()
+        .zip(states)
+        .map(|((probability, _), state)| ForecastBranch {
+            probability: probability / total,
+            state,
+        })
+        .collect();
+    Ok(ForecastDistribution { at, branches })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::EntityBuilder;
+    use crate::enums::{EventType, SocialCognitionPath, Species};
+    use crate::event::EventBuilder;
+    use crate::simulation::Simulation;
+    use crate::types::{Duration, EntityId};
+
+    const LONELINESS: StatePath = StatePath::SocialCognition(SocialCognitionPath::Loneliness);
+
+    fn reference() -> Timestamp {
+        Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+    }
+
+    fn setup() -> (Simulation, EntityId) {
+        let mut sim = Simulation::new(reference());
+        let entity = EntityBuilder::new()
+            .id("maya")
+            .species(Species::Human)
+            .birth_date(Timestamp::from_ymd_hms(1990, 1, 1, 0, 0, 0))
+            .build()
+            .unwrap();
+        let id = sim.add_entity(entity, reference());
+        (sim, id)
+    }
+
+    fn monthly(id: &EntityId, event_type: EventType, months: u64) -> Vec<(Event, Timestamp)> {
+        (1..=months)
+            .map(|month| {
+                let event = EventBuilder::new(event_type)
+                    .target(id.clone())
+                    .severity(0.8)
+                    .build()
+                    .unwrap();
+                (event, reference() + Duration::days(30 * month))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn intervention_branch_dominates_weighted_mean() {
+        let (sim, id) = setup();
+        let at = reference() + Duration::days(181);
+        let handle = sim.entity(&id).unwrap();
+
+        // Monthly support groups, nothing, or monthly exclusion
+        let inclusion = monthly(&id, EventType::SocialInclusion, 6);
+        let exclusion = monthly(&id, EventType::SocialExclusion, 6);
+        let forecast = handle
+            .forecast(
+                at,
+                vec![
+                    (0.7, inclusion),
+                    (0.2, Vec::new()),
+                    (0.1, exclusion.clone()),
+                ],
+            )
+            .unwrap();
+
+        assert_eq!(forecast.at(), at);
+        assert_eq!(forecast.branches().len(), 3);
+        let values: Vec<f64> = forecast
+            .branches()
+            .iter()
+            .map(|branch| branch.state().get_effective(LONELINESS))
+            .collect();
+        let [intervention, baseline, decline] = [values[0], values[1], values[2]];
+        assert!(intervention < baseline && baseline < decline);
+
+        // The 70% intervention branch pulls the mean toward its outcome
+        let mean = forecast.mean(LONELINESS);
+        assert!((mean - intervention).abs() < (mean - decline).abs());
+        assert!((forecast.min(LONELINESS) - intervention).abs() < f64::EPSILON);
+        assert!((forecast.max(LONELINESS) - decline).abs() < f64::EPSILON);
+        assert!((forecast.quantile(LONELINESS, 0.5) - intervention).abs() < f64::EPSILON);
+        assert!((forecast.quantile(LONELINESS, 0.95) - decline).abs() < f64::EPSILON);
+
+        // Branches match committing their events, and the simulation is untouched
+        let mut committed = sim.clone();
+        for (event, ts) in exclusion {
+            committed.add_event(event, ts);
+        }
+        let direct = committed.entity(&id).unwrap().state_at(at);
+        assert!((direct.get_effective(LONELINESS) - decline).abs() < 1e-12);
+        assert_eq!(handle.event_count(), 0);
+    }
+
+    #[test]
+    fn forecast_is_deterministic() {
+        let (sim, id) = setup();
+        let at = reference() + Duration::days(90);
+        let handle = sim.entity(&id).unwrap();
+        let scenarios = || {
+            vec![
+                (0.5, monthly(&id, EventType::SocialExclusion, 2)),
+                (0.5, monthly(&id, EventType::SocialInclusion, 2)),
+            ]
+        };
+
+        let first = handle.forecast(at, scenarios()).unwrap();
+        let second = handle.forecast(at, scenarios()).unwrap();
+        assert_eq!(
+            first.mean(LONELINESS).to_bits(),
+            second.mean(LONELINESS).to_bits()
+        );
+        assert!((first.branches()[0].probability() - 0.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn rejects_invalid_probabilities() {
+        let (sim, id) = setup();
+        let handle = sim.entity(&id).unwrap();
+        let at = reference();
+
+        assert_eq!(
+            handle.forecast(at, Vec::new()).unwrap_err(),
+            ForecastError::NoScenarios
+        );
+        assert_eq!(
+            handle
+                .forecast(at, vec![(1.5, Vec::new()), (-0.5, Vec::new())])
+                .unwrap_err(),
+            ForecastError::InvalidProbability {
+                branch: 1,
+                probability: -0.5
+            }
+        );
+        let err = handle
+            .forecast(at, vec![(0.5, Vec::new()), (0.3, Vec::new())])
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ForecastError::ProbabilitiesDoNotSumToOne { .. }
+        ));
+        assert!(err.to_string().contains("0.8"));
+
+        // Small rounding error is accepted and normalised away
+        let forecast = handle
+            .forecast(at, vec![(0.3334, Vec::new()), (0.6667, Vec::new())])
+            .unwrap();
+        let total: f64 = forecast
+            .branches()
+            .iter()
+            .map(ForecastBranch::probability)
+            .sum();
+        assert!((total - 1.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,276 @@
+//! Narrative identity coherence from memory and formative history.
+//!
+//! Narrative identity research asks how continuous a person's life story
+//! is: whether defining memories hang together, whether personality has
+//! moved in a consistent direction or been thrown about, and whether a
+//! sense of purpose has held steady. [`IdentityProfile`] answers each
+//! question with a component score in [0, 1] and combines them into one
+//! coherence score:
+//!
+//! | Component | Score | Weight |
+//! |-----------|-------|--------|
+//! | Memory | `0.5 + 0.5 * valence_balance` over legacy and milestone memories; 0.5 without any | 0.25 |
+//! | Formative | Half direction consistency (`sum |net| / sum |shift|` per trait), half `1 - min(upheaval / FORMATIVE_UPHEAVAL_SCALE, 1)`; 1.0 without shifts | 0.35 |
+//! | Purpose | `1 - min(std_dev / PURPOSE_STD_SCALE, 1)` over monthly purpose samples in the trailing window | 0.40 |
+//!
+//! Formative shifts are those made by events after the entity's anchor;
+//! shifts before it are already part of the anchored personality. Purpose
+//! is sampled with `series()` from the later of the anchor and
+//! `timestamp - IDENTITY_PURPOSE_WINDOW`. The profile is deterministic.
+
+use crate::enums::{HexacoPath, NeedsPath, StatePath};
+use crate::memory::{MemoryLayer, MemoryTag};
+use crate::simulation::EntityQueryHandle;
+use crate::types::{Duration, Timestamp};
+
+/// Trailing window over which purpose stability is measured.
+pub const IDENTITY_PURPOSE_WINDOW: Duration = Duration::years(3);
+
+/// Step between purpose samples.
+const PURPOSE_SAMPLE_STEP: Duration = Duration::days(30);
+
+/// Purpose standard deviation at which purpose stability reaches zero.
+pub const PURPOSE_STD_SCALE: f64 = 0.1;
+
+/// Total absolute formative shift at which upheaval is complete.
+pub const FORMATIVE_UPHEAVAL_SCALE: f64 = 0.5;
+
+/// Weight of the memory component in the coherence score.
+const MEMORY_WEIGHT: f64 = 0.25;
+/// Weight of the formative component in the coherence score.
+const FORMATIVE_WEIGHT: f64 = 0.35;
+/// Weight of the purpose component in the coherence score.
+const PURPOSE_WEIGHT: f64 = 0.4;
+
+/// Net formative movement of one HEXACO trait.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TraitShift {
+    /// The shifted trait.
+    pub trait_path: HexacoPath,
+    /// Sum of settled shifts; its sign is the net direction.
+    pub net: f64,
+    /// Sum of absolute settled shifts.
+    pub total: f64,
+    /// Number of shifts.
+    pub count: usize,
+}
+
+/// Component scores behind the coherence score, each 0.0 to 1.0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CoherenceComponents {
+    /// Emotional balance of legacy and milestone memories.
+    pub memory: f64,
+    /// Consistency and restraint of formative personality shifts.
+    pub formative: f64,
+    /// Stability of purpose over the trailing window.
+    pub purpose: f64,
+}
+
+impl CoherenceComponents {
+    /// Returns the weighted composite of the components.
+    #[must_use]
+    pub fn composite(&self) -> f64 {
+        (MEMORY_WEIGHT * self.memory
+            + FORMATIVE_WEIGHT * self.formative
+            + PURPOSE_WEIGHT * self.purpose)
+            .clamp(0.0, 1.0)
+    }
+}
+
+/// Narrative identity profile of an entity at a point in time.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::entity::EntityBuilder;
+/// use behavioral_pathways::enums::Species;
+/// use behavioral_pathways::simulation::Simulation;
+/// use behavioral_pathways::types::{Duration, Timestamp};
+///
+/// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+/// let mut sim = Simulation::new(reference);
+/// let entity = EntityBuilder::new().species(Species::Human).build().unwrap();
+/// let id = sim.add_entity(entity, reference);
+///
+/// let profile = sim
+///     .entity(&id)
+///     .unwrap()
+///     .identity_profile_at(reference + Duration::years(1));
+/// assert_eq!(profile.identity_memories, 0);
+/// assert!(profile.trait_shifts.is_empty());
+/// assert_eq!(profile.coherence, profile.components.composite());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdentityProfile {
+    /// When the profile was computed.
+    pub timestamp: Timestamp,
+    /// Legacy-layer or milestone-tagged memories formed by `timestamp`.
+    pub identity_memories: usize,
+    /// Identity memories with positive valence.
+    pub positive_memories: usize,
+    /// Identity memories with negative valence.
+    pub negative_memories: usize,
+    /// Mean valence of identity memories (-1.0 to 1.0); 0.0 without any.
+    pub valence_balance: f64,
+    /// Net formative movement per shifted trait, in HEXACO order.
+    pub trait_shifts: Vec<TraitShift>,
+    /// Mean purpose over the trailing window.
+    pub purpose_mean: f64,
+    /// Variance of purpose over the trailing window.
+    pub purpose_variance: f64,
+    /// Component scores behind `coherence`.
+    pub components: CoherenceComponents,
+    /// Composite coherence score (0.0 to 1.0).
+    pub coherence: f64,
+}
+
+/// Computes the identity profile of `handle`'s entity at `timestamp`.
+pub(crate) fn identity_profile(
+    handle: &EntityQueryHandle<'_>,
+    timestamp: Timestamp,
+) -> IdentityProfile {
+    let entity = handle.anchor_entity();
+    let valences: Vec<f64> = handle
+        .memories_at(timestamp)
+        .iter()
+        .filter(|memory| {
+            memory.has_tag(MemoryTag::Milestone)
+                || entity.memories().find_layer(memory.id()) == Some(MemoryLayer::Legacy)
+        })
+        .map(|memory| f64::from(memory.emotional_snapshot().valence()))
+        .collect();
+    let valence_balance = mean(&valences);
+
+    let records = handle.formative_shifts_at(timestamp);
+    let trait_shifts: Vec<TraitShift> = HexacoPath::all()
+        .into_iter()
+        .filter_map(|trait_path| {
+            let settled: Vec<f64> = records
+                .iter()
+                .filter(|record| record.trait_path() == trait_path)
+                .map(|record| f64::from(record.settled()))
+                .collect();
+            (!settled.is_empty()).then(|| TraitShift {
+                trait_path,
+                net: settled.iter().sum(),
+                total: settled.iter().map(|shift| shift.abs()).sum(),
+                count: settled.len(),
+            })
+        })
+        .collect();
+
+    let start = handle
+        .anchor_timestamp()
+        .map_or(timestamp, |anchor| anchor.min(timestamp))
+        .max(timestamp - IDENTITY_PURPOSE_WINDOW);
+    let purpose: Vec<f64> = handle
+        .series(
+            StatePath::Needs(NeedsPath::Purpose),
+            start,
+            timestamp,
+            PURPOSE_SAMPLE_STEP,
+        )
+        .into_iter()
+        .map(|(_, value)| value)
+        .collect();
+    let purpose_mean = mean(&purpose);
+    let purpose_variance = variance(&purpose, purpose_mean);
+
+    let components = CoherenceComponents {
+        memory: if valences.is_empty() {
+            0.5
+        } else {
+            0.5 + 0.5 * valence_balance
+        },
+        formative: formative_coherence(&trait_shifts),
+        purpose: 1.0 - (purpose_variance.sqrt() / PURPOSE_STD_SCALE).min(1.0),
+    };
+    IdentityProfile {
+        timestamp,
+        identity_memories: valences.len(),
+        positive_memories: valences.iter().filter(|v| **v > 0.0).count(),
+        negative_memories: valences.iter().filter(|v| **v < 0.0).count(),
+        valence_balance,
+        trait_shifts,
+        purpose_mean,
+        purpose_variance,
+        coherence: components.composite(),
+        components,
+    }
+}
+
+/// Scores formative shifts: half direction consistency, half restraint.
+fn formative_coherence(shifts: &[TraitShift]) -> f64 {
+    let upheaval: f64 = shifts.iter().map(|shift| shift.total).sum();
+    if upheaval < f64::EPSILON {
+        return 1.0;
+    }
+    let consistency = shifts.iter().map(|shift| shift.net.abs()).sum::<f64>() / upheaval;
+    0.5 * consistency + 0.5 * (1.0 - (upheaval / FORMATIVE_UPHEAVAL_SCALE).min(1.0))
+}
+
+/// Returns the mean of `values`, or 0.0 if there are none.
+fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+/// Returns the population variance of `values` about `mean`.
+fn variance(values: &[f64], mean: f64) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / values.len() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shift(trait_path: HexacoPath, net: f64, total: f64) -> TraitShift {
+        TraitShift {
+            trait_path,
+            net,
+            total,
+            count: 2,
+        }
+    }
+
+    #[test]
+    fn formative_coherence_rewards_consistent_small_shifts() {
+        assert_eq!(formative_coherence(&[]), 1.0);
+
+        let consistent = formative_coherence(&[shift(HexacoPath::Conscientiousness, 0.1, 0.1)]);
+        let reversing = formative_coherence(&[shift(HexacoPath::Neuroticism, 0.0, 0.1)]);
+        let upheaval = formative_coherence(&[shift(HexacoPath::Neuroticism, 0.0, 0.8)]);
+        assert!((consistent - 0.9).abs() < 1e-12);
+        assert!((reversing - 0.4).abs() < 1e-12);
+        assert_eq!(upheaval, 0.0);
+    }
+
+    #[test]
+    fn composite_weights_sum_to_one() {
+        let all = CoherenceComponents {
+            memory: 1.0,
+            formative: 1.0,
+            purpose: 1.0,
+        };
+        assert!((all.composite() - 1.0).abs() < 1e-12);
+
+        let purpose_only = CoherenceComponents {
+            memory: 0.0,
+            formative: 0.0,
+            purpose: 1.0,
+        };
+        assert!((purpose_only.composite() - PURPOSE_WEIGHT).abs() < 1e-12);
+    }
+
+    #[test]
+    fn variance_of_constant_series_is_zero() {
+        let values = [0.4, 0.4, 0.4];
+        assert!(variance(&values, mean(&values)) < 1e-12);
+        assert!((variance(&[0.0, 1.0], 0.5) - 0.25).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,149 @@
+//! Leave-one-event-out influence of an entity's events on its state.
+//!
+//! Sensitivity analysis asks how an outcome responds to an input; influence
+//! ranking asks which events the current state owes the most to, without
+//! naming an outcome. Each event is left out in turn and the state at the
+//! query time is projected again; the distance between that counterfactual
+//! and the actual state, over every state path, is the event's influence.
+//!
+//! Influences are not additive. Effects that reinforce each other make the
+//! sum of influences track the total change since the anchor, while
+//! effects that cancel or saturate make the two drift apart.
+
+use crate::enums::StatePath;
+use crate::simulation::{ComputedState, EntityQueryHandle};
+use crate::types::{EventId, Timestamp};
+
+/// Returns each event's influence on the state at `at`, most influential
+/// first.
+pub(crate) fn influence_ranking(
+    handle: &EntityQueryHandle<'_>,
+    at: Timestamp,
+) -> Vec<(EventId, f64)> {
+    let (actual, counterfactuals) = handle.leave_one_out_states(at);
+    let mut ranking: Vec<(EventId, f64)> = counterfactuals
+        .into_iter()
+        .map(|(te, counterfactual)| {
+            let influence = counterfactual.map_or(0.0, |state| distance(&actual, &state));
+            (te.event().id().clone(), influence)
+        })
+        .collect();
+    ranking.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ranking
+}
+
+/// Returns the Euclidean distance between two states over every path.
+fn distance(a: &ComputedState, b: &ComputedState) -> f64 {
+    StatePath::all()
+        .into_iter()
+        .map(|path| (a.get_effective(path) - b.get_effective(path)).powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::EntityBuilder;
+    use crate::enums::{Audience, EventPayload, EventType, LifeDomain, Species};
+    use crate::event::{Event, EventBuilder};
+    use crate::simulation::Simulation;
+    use crate::types::{Duration, EntityId};
+
+    fn reference() -> Timestamp {
+        Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+    }
+
+    fn simulation(events: &[(Event, Timestamp)]) -> (Simulation, EntityId) {
+        let mut sim = Simulation::new(reference());
+        let entity = EntityBuilder::new()
+            .id("person_001")
+            .species(Species::Human)
+            .age(Duration::years(30))
+            .build()
+            .unwrap();
+        let id = sim.add_entity(entity, reference());
+        for (event, timestamp) in events {
+            sim.add_event(event.clone(), *timestamp);
+        }
+        (sim, id)
+    }
+
+    fn event(event_type: EventType, payload: EventPayload) -> Event {
+        EventBuilder::new(event_type)
+            .target(EntityId::new("person_001").unwrap())
+            .severity(0.7)
+            .payload(payload)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn influence_matches_rebuilding_without_each_event() {
+        let award = event(
+            EventType::Achievement,
+            EventPayload::Achievement {
+                domain: LifeDomain::Work,
+                magnitude: 0.9,
+                audience: Audience::Public,
+            },
+        );
+        let failure = event(
+            EventType::Failure,
+            EventPayload::Failure {
+                domain: LifeDomain::Work,
+                public: true,
+            },
+        );
+        let exclusion = event(EventType::SocialExclusion, EventPayload::Empty);
+        let events = vec![
+            (award, reference() + Duration::days(2)),
+            (failure, reference() + Duration::days(4)),
+            (exclusion, reference() + Duration::days(5)),
+        ];
+        let at = reference() + Duration::days(6);
+        let (sim, id) = simulation(&events);
+        let actual = sim.entity(&id).unwrap().state_at(at);
+
+        let ranking = sim.entity(&id).unwrap().influence_ranking(at);
+        assert_eq!(ranking.len(), events.len());
+        for (index, (left_out, _)) in events.iter().enumerate() {
+            let rest: Vec<_> = events
+                .iter()
+                .enumerate()
+                .filter(|(other, _)| *other != index)
+                .map(|(_, te)| te.clone())
+                .collect();
+            let (rebuilt, rebuilt_id) = simulation(&rest);
+            let expected = distance(&actual, &rebuilt.entity(&rebuilt_id).unwrap().state_at(at));
+            let (_, influence) = ranking
+                .iter()
+                .find(|(event_id, _)| event_id == left_out.id())
+                .unwrap();
+            assert!((influence - expected).abs() < 1e-9, "{:?}", left_out.id());
+        }
+    }
+
+    #[test]
+    fn events_outside_the_projection_have_no_influence() {
+        let before = event(EventType::SocialExclusion, EventPayload::Empty);
+        let after = event(EventType::SocialExclusion, EventPayload::Empty);
+        let events = vec![
+            (before, reference() - Duration::days(3)),
+            (after, reference() + Duration::days(3)),
+        ];
+        let (sim, id) = simulation(&events);
+        let handle = sim.entity(&id).unwrap();
+
+        // Before the anchor is already in the anchored state; after the
+        // query has not happened yet
+        assert!(handle
+            .influence_ranking(reference() + Duration::days(1))
+            .iter()
+            .all(|(_, influence)| *influence == 0.0));
+        assert!(handle
+            .influence_ranking(reference())
+            .iter()
+            .all(|(_, influence)| *influence == 0.0));
+    }
+}
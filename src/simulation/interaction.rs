@@ -0,0 +1,274 @@
+//! Recording a two-way interaction in one call.
+//!
+//! A conversation between two entities is one occurrence that both of them
+//! experience. `Simulation::record_interaction` adds it as a pair of
+//! `Interaction` events, one targeting each party with the other as its
+//! source, so each party interprets it through their own personality and
+//! each direction of the relationship updates once.
+//!
+//! When memory formation is enabled, each simulated party also remembers the
+//! interaction. Both memories link the other party and their own event, and
+//! hold the party's mood at the interaction as computed after it.
+
+use crate::entity::Entity;
+use crate::enums::{EventPayload, EventType, InteractionTopic};
+use crate::event::{Event, EventBuilder};
+use crate::memory::{EmotionalSnapshot, MemoryEntry, MemoryTag};
+use crate::processor::interpret_event;
+use crate::simulation::state_query::development_age_at;
+use crate::simulation::Simulation;
+use crate::types::{EntityId, EventId, MemoryId, Timestamp};
+
+/// IDs of what `Simulation::record_interaction` created, for auditing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedInteraction {
+    /// The event targeting `a`, then the event targeting `b`.
+    event_ids: [EventId; 2],
+    /// Memories formed, with the entity that holds each.
+    memories: Vec<(EntityId, MemoryId)>,
+}
+
+impl RecordedInteraction {
+    /// Returns the IDs of the event targeting `a` and the event targeting
+    /// `b`, in that order.
+    #[must_use]
+    pub fn event_ids(&self) -> &[EventId; 2] {
+        &self.event_ids
+    }
+
+    /// Returns the memories formed, with the entity that holds each.
+    ///
+    /// Empty when memory formation is disabled.
+    #[must_use]
+    pub fn memories(&self) -> &[(EntityId, MemoryId)] {
+        &self.memories
+    }
+}
+
+/// Adds the pair of events for an interaction and forms the memories.
+pub(crate) fn record_interaction(
+    simulation: &mut Simulation,
+    a: &EntityId,
+    b: &EntityId,
+    at: Timestamp,
+    topic: InteractionTopic,
+    duration_minutes: u32,
+    quality: f64,
+) -> RecordedInteraction {
+    let toward_a = interaction_event(b, a, topic, duration_minutes, quality);
+    let toward_b = interaction_event(a, b, topic, duration_minutes, quality);
+    let event_ids = [toward_a.id().clone(), toward_b.id().clone()];
+    simulation.add_event(toward_a.clone(), at);
+    simulation.add_event(toward_b.clone(), at);
+
+    let mut memories = Vec::new();
+    if cfg!(feature = "memory") && simulation.memory_formation() {
+        // Snapshots are taken for both parties before either memory is
+        // stored, since stored memories prime later states
+        let snapshots: Vec<Option<EmotionalSnapshot>> = [a, b]
+            .into_iter()
+            .map(|id| {
+                let state = simulation.entity(id)?.state_at(at);
+                Some(EmotionalSnapshot::from_mood(
+                    state.individual_state().mood(),
+                ))
+            })
+            .collect();
+        let parties = [(a, b, &toward_a), (b, a, &toward_b)];
+        for ((holder, other, event), snapshot) in parties.into_iter().zip(snapshots) {
+            let Some(snapshot) = snapshot else {
+                continue;
+            };
+            let Some(anchored) = simulation.get_anchored_entity_mut(holder) else {
+                continue;
+            };
+            let entity = anchored.entity_mut();
+            let entry = interaction_memory(entity, other, event, at, topic, snapshot);
+            memories.push((holder.clone(), entry.id().clone()));
+            let layer = Entity::layer_for_salience(entry.salience());
+            entity.memories_mut().add(layer, entry);
+        }
+    }
+
+    RecordedInteraction {
+        event_ids,
+        memories,
+    }
+}
+
+/// Builds the interaction event `target` experiences from `source`.
+fn interaction_event(
+    source: &EntityId,
+    target: &EntityId,
+    topic: InteractionTopic,
+    duration_minutes: u32,
+    quality: f64,
+) -> Event {
+    EventBuilder::new(EventType::Interaction)
+        .source(source.clone())
+        .target(target.clone())
+        .severity(quality)
+        .payload(EventPayload::Interaction {
+            topic: Some(topic),
+            duration_minutes,
+        })
+        .build()
+        .expect("interaction event has a valid payload")
+}
+
+/// Builds the memory `entity` forms of an interaction with `other`.
+fn interaction_memory(
+    entity: &Entity,
+    other: &EntityId,
+    event: &Event,
+    at: Timestamp,
+    topic: InteractionTopic,
+    snapshot: EmotionalSnapshot,
+) -> MemoryEntry {
+    let tag = match topic {
+        InteractionTopic::Work => MemoryTag::Cooperation,
+        InteractionTopic::Conflict => MemoryTag::Conflict,
+        InteractionTopic::Support => MemoryTag::Support,
+        InteractionTopic::Personal
+        | InteractionTopic::Casual
+        | InteractionTopic::DeepConversation => MemoryTag::Personal,
+    };
+    MemoryEntry::new(
+        development_age_at(entity, at),
+        format!("{} with {}", topic.name(), other),
+    )
+    .with_event_id(event.id().clone())
+    .with_participants(vec![other.clone()])
+    .with_tags(vec![tag])
+    .with_salience(interpret_event(event, entity).salience)
+    .with_emotional_snapshot(snapshot)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::EntityBuilder;
+    #[cfg(feature = "relationships")]
+    use crate::enums::Direction;
+    use crate::enums::{RelationshipSchema, SocialCognitionPath, Species, StatePath};
+    use crate::types::Duration;
+
+    const LONELINESS: StatePath = StatePath::SocialCognition(SocialCognitionPath::Loneliness);
+
+    fn reference() -> Timestamp {
+        Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+    }
+
+    fn setup() -> (Simulation, EntityId, EntityId) {
+        let mut sim = Simulation::new(reference());
+        let mut ids = Vec::new();
+        for id in ["alice", "bob"] {
+            let entity = EntityBuilder::new()
+                .id(id)
+                .species(Species::Human)
+                .birth_date(Timestamp::from_ymd_hms(1990, 1, 1, 0, 0, 0))
+                .build()
+                .unwrap();
+            ids.push(sim.add_entity(entity, reference()));
+        }
+        let bob = ids.pop().unwrap();
+        let alice = ids.pop().unwrap();
+        (sim, alice, bob)
+    }
+
+    #[test]
+    fn both_parties_feel_the_interaction() {
+        let (mut sim, alice, bob) = setup();
+        let at = reference() + Duration::days(62);
+        let after = at + Duration::hours(2);
+        let rel_id = sim.add_relationship(
+            alice.clone(),
+            bob.clone(),
+            RelationshipSchema::Peer,
+            reference(),
+        );
+        let before: Vec<f64> = [&alice, &bob]
+            .iter()
+            .map(|id| {
+                sim.entity(id)
+                    .unwrap()
+                    .state_at(after)
+                    .get_effective(LONELINESS)
+            })
+            .collect();
+        let recorded = sim.record_interaction(
+            &alice,
+            &bob,
+            at,
+            InteractionTopic::DeepConversation,
+            90,
+            0.8,
+        );
+
+        for (id, before) in [&alice, &bob].into_iter().zip(before) {
+            let loneliness = sim
+                .entity(id)
+                .unwrap()
+                .state_at(after)
+                .get_effective(LONELINESS);
+            assert!(loneliness < before, "{} not less lonely", id);
+        }
+
+        // One event per direction, each sourced from the other party
+        let toward_alice = sim.events_for(&alice);
+        assert_eq!(toward_alice.len(), 1);
+        assert_eq!(toward_alice[0].event().id(), &recorded.event_ids()[0]);
+        assert_eq!(toward_alice[0].event().source(), Some(&bob));
+        assert_eq!(
+            sim.events_for(&bob)[0].event().id(),
+            &recorded.event_ids()[1]
+        );
+
+        // The relationship registers the contact once per direction
+        let rel = sim.get_relationship(&rel_id).unwrap().relationship();
+        assert_eq!(rel.pattern().last_interaction, Some(at));
+        #[cfg(feature = "relationships")]
+        {
+            let a_to_b = rel.antecedent_history(Direction::AToB).len();
+            let b_to_a = rel.antecedent_history(Direction::BToA).len();
+            assert!(a_to_b > 0);
+            assert_eq!(a_to_b, b_to_a);
+        }
+
+        // Memory formation is off by default
+        assert!(recorded.memories().is_empty());
+        assert!(sim
+            .get_anchored_entity(&alice)
+            .unwrap()
+            .entity()
+            .memories()
+            .is_empty());
+    }
+
+    #[cfg(feature = "memory")]
+    #[test]
+    fn forms_linked_memories_when_enabled() {
+        let (mut sim, alice, bob) = setup();
+        sim.set_memory_formation(true);
+        let at = reference() + Duration::days(10);
+        let recorded = sim.record_interaction(&alice, &bob, at, InteractionTopic::Support, 45, 0.6);
+
+        assert_eq!(recorded.memories().len(), 2);
+        for (index, (holder, other)) in [(&alice, &bob), (&bob, &alice)].into_iter().enumerate() {
+            let (memory_holder, memory_id) = &recorded.memories()[index];
+            assert_eq!(memory_holder, holder);
+            let memories = sim.get_anchored_entity(holder).unwrap().entity().memories();
+            let memory = memories.get_by_id(memory_id).unwrap();
+            assert!(memory.involves_participant(other));
+            assert!(memory.has_tag(MemoryTag::Support));
+            assert_eq!(memory.event_id(), Some(&recorded.event_ids()[index]));
+        }
+
+        // An external party has no memories to form
+        let external = EntityId::new("pen_pal").unwrap();
+        let recorded =
+            sim.record_interaction(&alice, &external, at, InteractionTopic::Casual, 10, 0.3);
+        assert_eq!(recorded.memories().len(), 1);
+        assert_eq!(recorded.memories()[0].0, alice);
+    }
+}
@@ -0,0 +1,263 @@
+//! Interpersonal Theory of Suicide invariants on computed states.
+//!
+//! Joiner's model constrains how its constructs relate, whatever the inputs:
+//!
+//! 1. Suicidal desire is zero unless thwarted belongingness and perceived
+//!    burdensomeness are both present and interpersonal hopelessness is
+//!    above its threshold.
+//! 2. Attempt risk is zero unless desire is present and acquired
+//!    capability is at or above `AC_ELEVATED_THRESHOLD`.
+//! 3. Acquired capability never decreases along a forward trajectory: the
+//!    value at any time is at least every value known (anchored or
+//!    observed) at or before it.
+//!
+//! `ItsInvariantMode` decides what happens when a computed state breaks
+//! one: nothing (the default), a logged warning, or, in strict mode, the
+//! first violation returned as an error from `try_state_at()`. `state_at()`
+//! never fails: in strict mode it logs, like warn mode.
+//!
+//! Rule 2 is stricter than the risk formula, which multiplies desire by
+//! acquired capability without a threshold. With desire present and
+//! capability between zero and the elevated threshold, states carry a
+//! small nonzero risk that the rule reports.
+
+use crate::enums::{MentalHealthPath, StatePath};
+use crate::processor::AC_ELEVATED_THRESHOLD;
+use crate::simulation::ComputedState;
+use crate::state::{HOPELESSNESS_THRESHOLD, PB_PRESENT_THRESHOLD, TB_PRESENT_THRESHOLD};
+use crate::types::{EntityId, Timestamp};
+use std::fmt;
+
+/// Tolerance below which desire, risk, and capability decreases count as
+/// zero.
+const INVARIANT_EPSILON: f64 = 1e-6;
+
+/// What a query does when a computed state breaks an ITS invariant.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ItsInvariantMode {
+    /// Invariants are not checked.
+    #[default]
+    Off,
+    /// Violations are logged as `tracing` warnings.
+    Warn,
+    /// `try_state_at()` returns the first violation as an error, with the
+    /// violated rule and its values; `state_at()` logs it as in `Warn`.
+    Strict,
+}
+
+/// A computed state that breaks an ITS invariant.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InvariantViolation {
+    /// Desire is present without all three of its preconditions.
+    DesireWithoutPreconditions {
+        /// The entity whose state broke the rule.
+        entity_id: EntityId,
+        /// When the state was computed.
+        timestamp: Timestamp,
+        /// Suicidal desire.
+        desire: f64,
+        /// Thwarted belongingness.
+        thwarted_belongingness: f64,
+        /// Perceived burdensomeness.
+        perceived_burdensomeness: f64,
+        /// Interpersonal hopelessness.
+        interpersonal_hopelessness: f64,
+    },
+    /// Attempt risk is present without desire and elevated capability.
+    RiskWithoutPreconditions {
+        /// The entity whose state broke the rule.
+        entity_id: EntityId,
+        /// When the state was computed.
+        timestamp: Timestamp,
+        /// Attempt risk.
+        attempt_risk: f64,
+        /// Suicidal desire.
+        desire: f64,
+        /// Acquired capability.
+        acquired_capability: f64,
+    },
+    /// Acquired capability is below a value known earlier.
+    AcquiredCapabilityDecreased {
+        /// The entity whose state broke the rule.
+        entity_id: EntityId,
+        /// When the state was computed.
+        timestamp: Timestamp,
+        /// Acquired capability at `timestamp`.
+        acquired_capability: f64,
+        /// When the higher value was known.
+        earlier_timestamp: Timestamp,
+        /// The higher value known earlier.
+        earlier: f64,
+    },
+}
+
+impl InvariantViolation {
+    /// Returns a short name for the violated rule.
+    #[must_use]
+    pub const fn rule(&self) -> &'static str {
+        match self {
+            InvariantViolation::DesireWithoutPreconditions { .. } => {
+                "desire requires TB, PB, and hopelessness"
+            }
+            InvariantViolation::RiskWithoutPreconditions { .. } => {
+                "attempt risk requires desire and elevated AC"
+            }
+            InvariantViolation::AcquiredCapabilityDecreased { .. } => "AC is non-decreasing",
+        }
+    }
+}
+
+impl fmt::Display for InvariantViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvariantViolation::DesireWithoutPreconditions {
+                entity_id,
+                timestamp,
+                desire,
+                thwarted_belongingness,
+                perceived_burdensomeness,
+                interpersonal_hopelessness,
+            } => write!(
+                f,
+                "ITS invariant '{}' violated for entity '{}' at {}: desire {:.4} with TB {:.4} \
+                 (threshold {}), PB {:.4} (threshold {}), hopelessness {:.4} (threshold {})",
+                self.rule(),
+                entity_id,
+                timestamp,
+                desire,
+                thwarted_belongingness,
+                TB_PRESENT_THRESHOLD,
+                perceived_burdensomeness,
+                PB_PRESENT_THRESHOLD,
+                interpersonal_hopelessness,
+                HOPELESSNESS_THRESHOLD
+            ),
+            InvariantViolation::RiskWithoutPreconditions {
+                entity_id,
+                timestamp,
+                attempt_risk,
+                desire,
+                acquired_capability,
+            } => write!(
+                f,
+                "ITS invariant '{}' violated for entity '{}' at {}: risk {:.4} with desire {:.4}, \
+                 AC {:.4} (threshold {})",
+                self.rule(),
+                entity_id,
+                timestamp,
+                attempt_risk,
+                desire,
+                acquired_capability,
+                AC_ELEVATED_THRESHOLD
+            ),
+            InvariantViolation::AcquiredCapabilityDecreased {
+                entity_id,
+                timestamp,
+                acquired_capability,
+                earlier_timestamp,
+                earlier,
+            } => write!(
+                f,
+                "ITS invariant '{}' violated for entity '{}' at {}: AC {:.4} is below {:.4} \
+                 known at {}",
+                self.rule(),
+                entity_id,
+                timestamp,
+                acquired_capability,
+                earlier,
+                earlier_timestamp
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InvariantViolation {}
+
+/// Returns every ITS invariant `state` breaks.
+///
+/// `earlier` is the highest acquired capability known at or before
+/// `timestamp`, and when it was known.
+pub(crate) fn invariant_violations(
+    entity_id: &EntityId,
+    timestamp: Timestamp,
+    state: &ComputedState,
+    earlier: Option<(Timestamp, f64)>,
+) -> Vec<InvariantViolation> {
+    let value = |path: MentalHealthPath| state.get_effective(StatePath::MentalHealth(path));
+    let desire = value(MentalHealthPath::SuicidalDesire);
+    let attempt_risk = value(MentalHealthPath::AttemptRisk);
+    let acquired_capability = value(MentalHealthPath::AcquiredCapability);
+    let mut violations = Vec::new();
+
+    if desire > INVARIANT_EPSILON {
+        let thwarted_belongingness = value(MentalHealthPath::ThwartedBelongingness);
+        let perceived_burdensomeness = value(MentalHealthPath::PerceivedBurdensomeness);
+        let interpersonal_hopelessness = value(MentalHealthPath::InterpersonalHopelessness);
+        if thwarted_belongingness < f64::from(TB_PRESENT_THRESHOLD)
+            || perceived_burdensomeness < f64::from(PB_PRESENT_THRESHOLD)
+            || interpersonal_hopelessness < f64::from(HOPELESSNESS_THRESHOLD)
+        {
+            violations.push(InvariantViolation::DesireWithoutPreconditions {
+                entity_id: entity_id.clone(),
+                timestamp,
+                desire,
+                thwarted_belongingness,
+                perceived_burdensomeness,
+                interpersonal_hopelessness,
+            });
+        }
+    }
+
+    if attempt_risk > INVARIANT_EPSILON
+        && (desire <= INVARIANT_EPSILON
+            || acquired_capability < f64::from(AC_ELEVATED_THRESHOLD))
+    {
+        violations.push(InvariantViolation::RiskWithoutPreconditions {
+            entity_id: entity_id.clone(),
+            timestamp,
+            attempt_risk,
+            desire,
+            acquired_capability,
+        });
+    }
+
+    if let Some((earlier_timestamp, earlier)) = earlier {
+        if acquired_capability < earlier - INVARIANT_EPSILON {
+            violations.push(InvariantViolation::AcquiredCapabilityDecreased {
+                entity_id: entity_id.clone(),
+                timestamp,
+                acquired_capability,
+                earlier_timestamp,
+                earlier,
+            });
+        }
+    }
+
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_mode_is_off() {
+        assert_eq!(ItsInvariantMode::default(), ItsInvariantMode::Off);
+    }
+
+    #[test]
+    fn display_names_rule_and_values() {
+        let violation = InvariantViolation::AcquiredCapabilityDecreased {
+            entity_id: EntityId::new("person").unwrap(),
+            timestamp: Timestamp::from_ymd_hms(2024, 2, 1, 0, 0, 0),
+            acquired_capability: 0.2,
+            earlier_timestamp: Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0),
+            earlier: 0.5,
+        };
+        let text = violation.to_string();
+        assert!(text.contains("'AC is non-decreasing'"));
+        assert!(text.contains("'person'"));
+        assert!(text.contains("AC 0.2000 is below 0.5000"));
+    }
+}
@@ -45,13 +45,95 @@
 //! - **Birth Date**: When an entity was born (for age calculations)
 //! - **state_at()**: The core API for computing state at any timestamp
 
+mod adaptive_series;
+mod affect_variability;
+mod alert_sweep;
+mod base_absorption;
+mod behavior_prediction;
+mod bereavement;
+mod cascade;
+mod compaction;
+mod comparison;
+mod contagion;
+mod context_change;
+mod context_series;
+mod diff;
+mod emotion_model;
+mod entity_ref;
+mod event_memory;
+mod forecast;
+mod identity;
+mod influence;
+mod interaction;
+mod its_invariants;
+mod observation;
+mod population;
+mod profiling;
+mod recognition;
+mod recurrence;
+mod relationship_query;
+mod removal;
+mod scope;
 #[allow(clippy::module_inception)]
 mod simulation;
 mod simulation_builder;
+mod sleep_loss;
 mod state_query;
+mod stochastic;
+mod threshold;
+mod trust_calibration;
+mod turning_points;
+mod validation;
 
+pub use affect_variability::{AffectSpread, AffectVariability, LABILE_VALENCE_SD};
+pub use alert_sweep::AlertSweepConfig;
+pub use base_absorption::{BaseAbsorptionConfig, ABSORPTION_MONTH};
+pub use behavior_prediction::{BehaviorContributor, BehaviorPrediction, BehaviorQuery};
+pub use cascade::CascadeRule;
+pub use compaction::{CompactionReport, RebaseError, COMPACTION_EPSILON};
+pub use comparison::{EntityComparison, PathComparison};
+pub use contagion::ContagionConfig;
+pub use context_change::{ContextChange, TimestampedContextChange};
+pub use context_series::{ContextSeries, ContextSeriesError, Interpolation};
+pub use diff::{
+    EntityDiff, EventDiff, FieldChange, PathChange, RelationshipDiff, RelationshipKey,
+    SimulationDiff, StateDiff, ThresholdCrossing, STATE_DIFF_EPSILON,
+};
+pub use emotion_model::{
+    AttributionEmotion, AttributionGate, AttributionLocus, EmotionModel, ExtendedEmotions,
+    OctantBoundaries, OctantBoundary, DEFAULT_ATTRIBUTION_WINDOW,
+};
+pub use entity_ref::{EntityRef, EntityRefError};
+pub use event_memory::EVENT_MEMORY_SALIENCE_THRESHOLD;
+pub use forecast::{
+    ForecastBranch, ForecastDistribution, ForecastError, ForecastScenario,
+    FORECAST_PROBABILITY_TOLERANCE,
+};
+pub use identity::{
+    CoherenceComponents, IdentityProfile, TraitShift, FORMATIVE_UPHEAVAL_SCALE,
+    IDENTITY_PURPOSE_WINDOW, PURPOSE_STD_SCALE,
+};
+pub use interaction::RecordedInteraction;
+pub use its_invariants::{InvariantViolation, ItsInvariantMode};
+pub use observation::{Observation, ObservationPolicy, ObservationResidual};
+pub use profiling::{PhaseStats, ProfilePhase, ProfileReport};
+pub use recognition::RECOGNITION_FALL_WINDOW;
+pub use recurrence::RecurringEvent;
+pub use relationship_query::{ComputedRelationship, RelationshipQueryHandle};
+pub use removal::RemoveEntityError;
+pub use scope::ScopedEventError;
 pub use simulation::{
-    AnchoredEntity, RegressionQuality, Simulation, TimestampedEvent, TimestampedRelationship,
+    AnchoredEntity, ApproximationReason, RegressionQuality, Simulation, TimestampedAlert,
+    TimestampedEvent, TimestampedRelationship, DEFAULT_EXTERNAL_RELATIONSHIP_WEIGHT,
 };
 pub use simulation_builder::{SimulationBuildError, SimulationBuilder};
+pub use sleep_loss::SLEEP_LOSS_WINDOW;
 pub use state_query::{ComputedState, EntityQueryHandle};
+pub use stochastic::{StochasticConfig, DEFAULT_NOISE_MAGNITUDE};
+#[cfg(any(test, feature = "contract-tests"))]
+pub(crate) use state_query::effective_value;
+pub use trust_calibration::{
+    TrustCalibrationRecord, TrustCalibrationReport, TrustOutcome, TRUST_CALIBRATION_THRESHOLD,
+};
+pub use turning_points::TURNING_POINT_SHIFT_THRESHOLD;
+pub use validation::{ValidationConfig, ValidationIssue};
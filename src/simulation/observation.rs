@@ -0,0 +1,362 @@
+//! Later observations of an entity and how they reconcile with projections.
+//!
+//! An entity is added with one anchor state. Observations record further
+//! known states at later (or earlier) timestamps, so `state_at()` projects
+//! from the nearest known state instead of carrying one anchor forward
+//! across years of events.
+//!
+//! When an observation disagrees with the projection from the anchor
+//! before it, the difference is the observation's residual. The
+//! simulation's `ObservationPolicy` decides how queried trajectories
+//! handle it:
+//!
+//! - **Snap** (default): trajectories follow the earlier anchor right up
+//!   to the observation, then jump to the observed state.
+//! - **Blend**: the residual is spread linearly across a window before the
+//!   observation, so trajectories arrive at the observed state without a
+//!   discontinuity.
+//!
+//! Under both policies, querying exactly at an anchor or observation
+//! returns the known state.
+
+use crate::enums::{DispositionPath, PersonCharacteristicsPath, SocialCognitionPath};
+use crate::enums::{HexacoPath, MentalHealthPath, MoodPath, NeedsPath, StatePath};
+use crate::simulation::ComputedState;
+use crate::state::IndividualState;
+use crate::types::{Duration, Timestamp};
+use std::fmt;
+
+/// How `state_at()` reconciles an observation with the projection before it.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::simulation::ObservationPolicy;
+/// use behavioral_pathways::types::Duration;
+///
+/// assert_eq!(ObservationPolicy::default(), ObservationPolicy::Snap);
+/// let blend = ObservationPolicy::Blend { window: Duration::days(30) };
+/// assert_eq!(blend.to_string(), "blend(30d)");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ObservationPolicy {
+    /// Follow the earlier anchor until the observation, then jump to it.
+    #[default]
+    Snap,
+    /// Spread the residual linearly across `window` before the observation.
+    ///
+    /// The window is shortened when the previous anchor is closer than
+    /// `window`, so the previous anchor stays exact.
+    Blend {
+        /// How long before the observation blending starts.
+        window: Duration,
+    },
+}
+
+impl fmt::Display for ObservationPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ObservationPolicy::Snap => write!(f, "snap"),
+            ObservationPolicy::Blend { window } => write!(f, "blend({}d)", window.as_days_f64()),
+        }
+    }
+}
+
+/// A known state of an entity at a timestamp other than its anchor.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Observation {
+    /// When the state was observed.
+    timestamp: Timestamp,
+    /// The observed state.
+    state: IndividualState,
+}
+
+impl Observation {
+    /// Creates a new observation.
+    #[must_use]
+    pub fn new(state: IndividualState, timestamp: Timestamp) -> Self {
+        Observation { timestamp, state }
+    }
+
+    /// Returns when the state was observed.
+    #[must_use]
+    pub fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+
+    /// Returns the observed state.
+    #[must_use]
+    pub fn state(&self) -> &IndividualState {
+        &self.state
+    }
+}
+
+/// The gap between an observation and the projection that preceded it.
+///
+/// Each value is observed minus projected for one stored state path, so a
+/// positive residual means the model under-predicted. Computed ITS paths
+/// and composite person characteristics are derived from stored paths and
+/// are not included.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObservationResidual {
+    /// When the observation was made.
+    timestamp: Timestamp,
+    /// Observed minus projected, per stored path.
+    values: Vec<(StatePath, f64)>,
+}
+
+impl ObservationResidual {
+    /// Computes the residual between an observed and a projected state.
+    pub(crate) fn between(
+        observed: &ComputedState,
+        projected: &ComputedState,
+        timestamp: Timestamp,
+    ) -> Self {
+        let values = StatePath::all()
+            .into_iter()
+            .filter(|path| is_reconciled(*path))
+            .map(|path| {
+                (
+                    path,
+                    observed.get_effective(path) - projected.get_effective(path),
+                )
+            })
+            .collect();
+        ObservationResidual { timestamp, values }
+    }
+
+    /// Returns when the observation was made.
+    #[must_use]
+    pub fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+
+    /// Returns observed minus projected for every reconciled path.
+    #[must_use]
+    pub fn values(&self) -> &[(StatePath, f64)] {
+        &self.values
+    }
+
+    /// Returns the residual for one path, or 0.0 for paths not reconciled.
+    #[must_use]
+    pub fn get(&self, path: StatePath) -> f64 {
+        self.values
+            .iter()
+            .find(|(p, _)| *p == path)
+            .map_or(0.0, |(_, value)| *value)
+    }
+
+    /// Returns the largest absolute residual across all paths.
+    #[must_use]
+    pub fn max_abs(&self) -> f64 {
+        self.values
+            .iter()
+            .map(|(_, value)| value.abs())
+            .fold(0.0, f64::max)
+    }
+
+    /// Adds `weight` times the residual to the state.
+    pub(crate) fn apply_weighted(&self, state: &mut IndividualState, weight: f64) {
+        for (path, value) in &self.values {
+            shift_path(state, *path, (value * weight) as f32);
+        }
+    }
+}
+
+/// Returns true if the path is stored rather than derived from other paths.
+fn is_reconciled(path: StatePath) -> bool {
+    match path {
+        StatePath::MentalHealth(p) => !p.is_computed(),
+        StatePath::PersonCharacteristics(p) => !p.is_composite(),
+        _ => true,
+    }
+}
+
+/// Shifts a stored path's effective value by `amount`.
+///
+/// HEXACO traits have no delta, so their value is shifted directly; every
+/// other path takes the shift as a delta.
+fn shift_path(state: &mut IndividualState, path: StatePath, amount: f32) {
+    match path {
+        StatePath::Hexaco(p) => {
+            let hexaco = state.hexaco_mut();
+            match p {
+                HexacoPath::Openness => hexaco.set_openness(hexaco.openness() + amount),
+                HexacoPath::Conscientiousness => {
+                    hexaco.set_conscientiousness(hexaco.conscientiousness() + amount)
+                }
+                HexacoPath::Extraversion => hexaco.set_extraversion(hexaco.extraversion() + amount),
+                HexacoPath::Agreeableness => {
+                    hexaco.set_agreeableness(hexaco.agreeableness() + amount)
+                }
+                HexacoPath::Neuroticism => hexaco.set_neuroticism(hexaco.neuroticism() + amount),
+                HexacoPath::HonestyHumility => {
+                    hexaco.set_honesty_humility(hexaco.honesty_humility() + amount)
+                }
+            }
+        }
+        StatePath::Mood(p) => {
+            let mood = state.mood_mut();
+            match p {
+                MoodPath::Valence => mood.add_valence_delta(amount),
+                MoodPath::Arousal => mood.add_arousal_delta(amount),
+                MoodPath::Dominance => mood.add_dominance_delta(amount),
+            }
+        }
+        StatePath::Needs(p) => {
+            let needs = state.needs_mut();
+            match p {
+                NeedsPath::Stress => needs.add_stress_delta(amount),
+                NeedsPath::Fatigue => needs.add_fatigue_delta(amount),
+                NeedsPath::Purpose => needs.add_purpose_delta(amount),
+                NeedsPath::Satiation => needs.add_satiation_delta(amount),
+                NeedsPath::Safety => needs.add_safety_delta(amount),
+            }
+        }
+        StatePath::SocialCognition(p) => {
+            let social = state.social_cognition_mut();
+            match p {
+                SocialCognitionPath::Loneliness => social.add_loneliness_delta(amount),
+                SocialCognitionPath::PerceivedReciprocalCaring => {
+                    social.add_perceived_reciprocal_caring_delta(amount)
+                }
+                SocialCognitionPath::PerceivedLiability => {
+                    social.add_perceived_liability_delta(amount)
+                }
+                SocialCognitionPath::SelfHate => social.add_self_hate_delta(amount),
+                SocialCognitionPath::PerceivedCompetence => {
+                    social.add_perceived_competence_delta(amount)
+                }
+            }
+        }
+        StatePath::MentalHealth(p) => {
+            let mental_health = state.mental_health_mut();
+            match p {
+                MentalHealthPath::Depression => mental_health.add_depression_delta(amount),
+                MentalHealthPath::SelfWorth => mental_health.add_self_worth_delta(amount),
+                MentalHealthPath::Hopelessness => mental_health.add_hopelessness_delta(amount),
+                MentalHealthPath::InterpersonalHopelessness => {
+                    mental_health.add_interpersonal_hopelessness_delta(amount)
+                }
+                MentalHealthPath::AcquiredCapability => {
+                    mental_health.add_acquired_capability_delta(amount)
+                }
+                // Computed from other paths
+                _ => {}
+            }
+        }
+        StatePath::Disposition(p) => {
+            let disposition = state.disposition_mut();
+            match p {
+                DispositionPath::Empathy => disposition.add_empathy_delta(amount),
+                DispositionPath::Aggression => disposition.add_aggression_delta(amount),
+                DispositionPath::Grievance => disposition.add_grievance_delta(amount),
+                DispositionPath::ImpulseControl => disposition.add_impulse_control_delta(amount),
+                DispositionPath::Reactance => disposition.add_reactance_delta(amount),
+                DispositionPath::TrustPropensity => disposition.add_trust_propensity_delta(amount),
+            }
+        }
+        StatePath::PersonCharacteristics(p) => {
+            let pc = state.person_characteristics_mut();
+            let value = match p {
+                PersonCharacteristicsPath::SocialCapital => pc.social_capital_mut(),
+                PersonCharacteristicsPath::CognitiveAbility => pc.cognitive_ability_mut(),
+                PersonCharacteristicsPath::EmotionalRegulationAssets => {
+                    pc.emotional_regulation_assets_mut()
+                }
+                PersonCharacteristicsPath::MaterialSecurity => pc.material_security_mut(),
+                PersonCharacteristicsPath::ExperienceDiversity => pc.experience_diversity_mut(),
+                PersonCharacteristicsPath::BaselineMotivation => pc.baseline_motivation_mut(),
+                PersonCharacteristicsPath::PersistenceTendency => pc.persistence_tendency_mut(),
+                PersonCharacteristicsPath::CuriosityTendency => pc.curiosity_tendency_mut(),
+                // Composites of the paths above
+                PersonCharacteristicsPath::Resource | PersonCharacteristicsPath::Force => return,
+            };
+            value.add_delta(amount);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::EntityBuilder;
+    use crate::enums::Species;
+    use crate::simulation::Simulation;
+
+    fn computed(state: IndividualState) -> ComputedState {
+        let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let mut entity = EntityBuilder::new()
+            .id("observed")
+            .species(Species::Human)
+            .build()
+            .unwrap();
+        *entity.individual_state_mut() = state;
+        let mut sim = Simulation::new(reference);
+        let id = sim.add_entity(entity, reference);
+        sim.entity(&id).unwrap().state_at(reference)
+    }
+
+    #[test]
+    fn policy_defaults_to_snap() {
+        assert_eq!(ObservationPolicy::default(), ObservationPolicy::Snap);
+        assert_eq!(ObservationPolicy::Snap.to_string(), "snap");
+    }
+
+    #[test]
+    fn computed_and_composite_paths_are_not_reconciled() {
+        assert!(!is_reconciled(StatePath::MentalHealth(
+            MentalHealthPath::ThwartedBelongingness
+        )));
+        assert!(!is_reconciled(StatePath::PersonCharacteristics(
+            PersonCharacteristicsPath::Resource
+        )));
+        assert!(is_reconciled(StatePath::MentalHealth(
+            MentalHealthPath::Depression
+        )));
+        assert!(is_reconciled(StatePath::Hexaco(HexacoPath::Openness)));
+    }
+
+    #[test]
+    fn shift_path_moves_every_reconciled_path() {
+        let before = IndividualState::new();
+        let mut after = before.clone();
+        let paths: Vec<StatePath> = StatePath::all()
+            .into_iter()
+            .filter(|path| is_reconciled(*path))
+            .collect();
+        for path in &paths {
+            shift_path(&mut after, *path, 0.05);
+        }
+        let before = computed(before);
+        let after = computed(after);
+        for path in paths {
+            let moved = after.get_effective(path) - before.get_effective(path);
+            assert!((moved - 0.05).abs() < 1e-5, "{path} moved by {moved}");
+        }
+    }
+
+    #[test]
+    fn residual_get_and_max_abs() {
+        let ts = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let projected = IndividualState::new();
+        let mut observed = projected.clone();
+        observed.mood_mut().add_valence_delta(-0.3);
+        observed.needs_mut().add_stress_delta(0.1);
+        let residual =
+            ObservationResidual::between(&computed(observed), &computed(projected.clone()), ts);
+
+        let valence = StatePath::Mood(MoodPath::Valence);
+        assert_eq!(residual.timestamp(), ts);
+        assert!((residual.get(valence) + 0.3).abs() < 1e-6);
+        assert!((residual.max_abs() - 0.3).abs() < 1e-6);
+        let tb = StatePath::MentalHealth(MentalHealthPath::ThwartedBelongingness);
+        assert!(residual.get(tb).abs() < f64::EPSILON);
+
+        let mut half = projected;
+        residual.apply_weighted(&mut half, 0.5);
+        assert!((half.mood().valence_effective() + 0.15).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,61 @@
+//! Population-wide state queries.
+//!
+//! [`Simulation::states_at`] computes every entity's state at one
+//! timestamp. Each entity is queried through its own handle exactly as
+//! `EntityQueryHandle::state_at` would, so the results match sequential
+//! per-handle queries. With the `parallel` feature, entities are evaluated
+//! on scoped threads: a query only reads the simulation, and the profiler
+//! and range cursors it touches are behind mutexes.
+
+use crate::simulation::{ComputedState, Simulation};
+use crate::types::{EntityId, Timestamp};
+use std::collections::HashMap;
+
+/// Computes the state at `timestamp` of each entity in `ids`.
+///
+/// Unknown IDs are skipped.
+pub(crate) fn states_at(
+    simulation: &Simulation,
+    ids: &[EntityId],
+    timestamp: Timestamp,
+) -> HashMap<EntityId, ComputedState> {
+    evaluate_each(ids, |id| {
+        let handle = simulation.entity(id)?;
+        Some((id.clone(), handle.state_at(timestamp)))
+    })
+    .into_iter()
+    .collect()
+}
+
+/// Evaluates `query` for each item on scoped threads, one chunk per
+/// available core, keeping the order of `items`.
+#[cfg(feature = "parallel")]
+pub(crate) fn evaluate_each<I, T, F>(items: &[I], query: F) -> Vec<T>
+where
+    I: Sync,
+    T: Send,
+    F: Fn(&I) -> Option<T> + Sync,
+{
+    let threads = std::thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get);
+    let chunk_size = items.len().div_ceil(threads).max(1);
+    let query = &query;
+    std::thread::scope(|scope| {
+        let workers: Vec<_> = items
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || chunk.iter().filter_map(query).collect::<Vec<_>>()))
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("population query worker panicked"))
+            .collect()
+    })
+}
+
+/// Evaluates `query` for each item, keeping the order of `items`.
+#[cfg(not(feature = "parallel"))]
+pub(crate) fn evaluate_each<I, T, F>(items: &[I], query: F) -> Vec<T>
+where
+    F: Fn(&I) -> Option<T>,
+{
+    items.iter().filter_map(query).collect()
+}
@@ -0,0 +1,281 @@
+//! Opt-in timing of state queries by phase.
+//!
+//! When a workload slows down, the question is which phase dominates:
+//! decay over elapsed time, event interpretation, applying event deltas,
+//! context effects, memory consolidation, personality base shifts, or
+//! building the text interpretation. With profiling enabled
+//! (`Simulation::set_profiling(true)`), every `state_at()` query adds each
+//! phase's wall-clock time and call count to a [`ProfileReport`], read with
+//! `Simulation::profile_report()` and cleared with
+//! `Simulation::reset_profile()`.
+//!
+//! Profiling is off by default. Disabled, each phase costs one untaken
+//! branch and no clock reads or locks.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// A phase of state computation timed by the profiler.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ProfilePhase {
+    /// Decaying or regressing state over elapsed time.
+    Advance,
+    /// Interpreting events for the entity.
+    Interpret,
+    /// Applying or reversing interpreted event deltas.
+    Apply,
+    /// Ecological context, role exit, health, and concealment effects.
+    Context,
+    /// Memory consolidation.
+    Memory,
+    /// Collecting and applying formative personality base shifts.
+    BaseShifts,
+    /// Building the text interpretation and delta summary.
+    Interpreter,
+}
+
+impl ProfilePhase {
+    /// Returns every phase, in computation order.
+    #[must_use]
+    pub const fn all() -> [ProfilePhase; 7] {
+        [
+            ProfilePhase::Advance,
+            ProfilePhase::Interpret,
+            ProfilePhase::Apply,
+            ProfilePhase::Context,
+            ProfilePhase::Memory,
+            ProfilePhase::BaseShifts,
+            ProfilePhase::Interpreter,
+        ]
+    }
+
+    /// Returns a human-readable name for this phase.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            ProfilePhase::Advance => "Advance",
+            ProfilePhase::Interpret => "Interpret",
+            ProfilePhase::Apply => "Apply",
+            ProfilePhase::Context => "Context",
+            ProfilePhase::Memory => "Memory",
+            ProfilePhase::BaseShifts => "Base Shifts",
+            ProfilePhase::Interpreter => "Interpreter",
+        }
+    }
+}
+
+/// Accumulated time and calls for one phase.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PhaseStats {
+    /// Number of times the phase ran.
+    pub calls: u64,
+    /// Total wall-clock time in the phase, in nanoseconds.
+    pub total_nanos: u64,
+}
+
+impl PhaseStats {
+    /// Returns the total time in the phase.
+    #[must_use]
+    pub fn total(&self) -> std::time::Duration {
+        std::time::Duration::from_nanos(self.total_nanos)
+    }
+}
+
+/// Per-phase timings accumulated across state queries.
+///
+/// With the `serde` feature, serializes to JSON for attaching to support
+/// tickets.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::entity::EntityBuilder;
+/// use behavioral_pathways::enums::Species;
+/// use behavioral_pathways::simulation::{ProfilePhase, Simulation};
+/// use behavioral_pathways::types::{Duration, Timestamp};
+///
+/// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+/// let mut sim = Simulation::new(reference);
+/// let entity = EntityBuilder::new().species(Species::Human).build().unwrap();
+/// let id = sim.add_entity(entity, reference);
+/// sim.set_profiling(true);
+///
+/// let _ = sim.entity(&id).unwrap().state_at(reference + Duration::days(30));
+/// let report = sim.profile_report();
+/// assert_eq!(report.queries, 1);
+/// assert_eq!(report.phase(ProfilePhase::Interpreter).calls, 1);
+///
+/// # #[cfg(feature = "serde")]
+/// # {
+/// let json = serde_json::to_string(&report).unwrap();
+/// assert!(json.contains("Interpreter"));
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProfileReport {
+    /// Number of `state_at()` queries profiled.
+    pub queries: u64,
+    /// Stats for each phase that ran at least once.
+    pub phases: BTreeMap<ProfilePhase, PhaseStats>,
+}
+
+impl ProfileReport {
+    /// Returns the stats for `phase`, zero if it never ran.
+    #[must_use]
+    pub fn phase(&self, phase: ProfilePhase) -> PhaseStats {
+        self.phases.get(&phase).copied().unwrap_or_default()
+    }
+
+    /// Returns the phase with the most total time, if any ran.
+    #[must_use]
+    pub fn dominant_phase(&self) -> Option<ProfilePhase> {
+        self.phases
+            .iter()
+            .max_by_key(|(_, stats)| stats.total_nanos)
+            .map(|(phase, _)| *phase)
+    }
+}
+
+impl fmt::Display for ProfileReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} queries", self.queries)?;
+        for (phase, stats) in &self.phases {
+            writeln!(
+                f,
+                "  {}: {} calls, {:?}",
+                phase.name(),
+                stats.calls,
+                stats.total()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Accumulates a `ProfileReport` when enabled.
+///
+/// Queries run through `&Simulation`, possibly on several threads with the
+/// `parallel` feature, so the report sits behind a mutex that is only
+/// locked while profiling.
+#[derive(Debug, Default)]
+pub(crate) struct Profiler {
+    enabled: bool,
+    report: Mutex<ProfileReport>,
+}
+
+impl Clone for Profiler {
+    fn clone(&self) -> Self {
+        Profiler {
+            enabled: self.enabled,
+            report: Mutex::new(self.report()),
+        }
+    }
+}
+
+impl Profiler {
+    /// Returns true if profiling is enabled.
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Enables or disables profiling, keeping what was accumulated.
+    pub(crate) fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// Runs `f`, adding its time to `phase` when profiling is enabled.
+    #[inline]
+    pub(crate) fn time<T>(&self, phase: ProfilePhase, f: impl FnOnce() -> T) -> T {
+        if !self.enabled {
+            return f();
+        }
+        let start = Instant::now();
+        let result = f();
+        let elapsed = u64::try_from(start.elapsed().as_nanos()).unwrap_or(u64::MAX);
+        let mut report = self.lock();
+        let stats = report.phases.entry(phase).or_default();
+        stats.calls += 1;
+        stats.total_nanos = stats.total_nanos.saturating_add(elapsed);
+        result
+    }
+
+    /// Counts a query when profiling is enabled.
+    #[inline]
+    pub(crate) fn record_query(&self) {
+        if self.enabled {
+            self.lock().queries += 1;
+        }
+    }
+
+    /// Returns a copy of the accumulated report.
+    pub(crate) fn report(&self) -> ProfileReport {
+        self.lock().clone()
+    }
+
+    /// Clears the accumulated report.
+    pub(crate) fn reset(&mut self) {
+        *self.report.get_mut().unwrap_or_else(|err| err.into_inner()) = ProfileReport::default();
+    }
+
+    /// Locks the report, recovering it if a profiled query panicked.
+    fn lock(&self) -> std::sync::MutexGuard<'_, ProfileReport> {
+        self.report.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabled_profiler_records_nothing() {
+        let profiler = Profiler::default();
+        assert_eq!(profiler.time(ProfilePhase::Apply, || 7), 7);
+        profiler.record_query();
+        assert_eq!(profiler.report(), ProfileReport::default());
+    }
+
+    #[test]
+    fn enabled_profiler_counts_calls_and_resets() {
+        let mut profiler = Profiler::default();
+        profiler.set_enabled(true);
+        profiler.record_query();
+        profiler.time(ProfilePhase::Advance, || ());
+        profiler.time(ProfilePhase::Advance, || ());
+
+        let report = profiler.clone().report();
+        assert_eq!(report.queries, 1);
+        assert_eq!(report.phase(ProfilePhase::Advance).calls, 2);
+        assert_eq!(report.phase(ProfilePhase::Memory).calls, 0);
+        assert_eq!(report.dominant_phase(), Some(ProfilePhase::Advance));
+
+        profiler.reset();
+        assert_eq!(profiler.report(), ProfileReport::default());
+        assert!(profiler.is_enabled());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn report_serde_round_trip() {
+        let mut report = ProfileReport {
+            queries: 3,
+            ..Default::default()
+        };
+        report.phases.insert(
+            ProfilePhase::BaseShifts,
+            PhaseStats {
+                calls: 3,
+                total_nanos: 1_500,
+            },
+        );
+        let json = serde_json::to_string(&report).unwrap();
+        let back: ProfileReport = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, report);
+        assert!(report.to_string().contains("Base Shifts: 3 calls"));
+    }
+}
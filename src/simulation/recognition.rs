@@ -0,0 +1,196 @@
+//! Public recognition and the falls that contradict it.
+//!
+//! Recognition before an audience raises the stakes of what follows. A
+//! failure or humiliation soon after public praise hurts more than the same
+//! event after private praise, because the entity falls in front of the
+//! people who watched it rise. A fall contradicts the most prominent
+//! non-private Achievement or Support payload targeting the same entity in
+//! the `RECOGNITION_FALL_WINDOW` before it, and its losses are amplified by
+//! that recognition's `Audience::fall_multiplier`. A failure only
+//! contradicts an achievement in its own life domain; a humiliation
+//! contradicts any recognition.
+//!
+//! Amplification depends on the event history, so the simulation applies
+//! it wherever it interprets events for queries, validation, and
+//! compaction.
+
+use crate::entity::Entity;
+use crate::enums::{Audience, EventPayload, EventType};
+use crate::processor::{amplify_bereavement, amplify_fall, interpret_event, InterpretedEvent};
+use crate::simulation::bereavement::lost_relationship;
+use crate::simulation::sleep_loss::{amplify_after_sleep_loss, preceding_sleep_loss};
+use crate::simulation::{Simulation, TimestampedEvent};
+use crate::types::{Duration, EventId};
+
+/// How long after non-private recognition a failure or humiliation counts
+/// as contradicting it.
+pub const RECOGNITION_FALL_WINDOW: Duration = Duration::days(30);
+
+/// Returns the recognition `fall` contradicts and its audience.
+///
+/// Among several contradicted recognitions, the one with the largest
+/// audience wins, then the most recent. An `excluded` event is treated as
+/// missing from the history.
+pub(crate) fn contradicted_recognition(
+    simulation: &Simulation,
+    fall: &TimestampedEvent,
+    excluded: Option<&TimestampedEvent>,
+) -> Option<(EventId, Audience)> {
+    let event = fall.event();
+    let fall_domain = match (event.event_type(), event.payload()) {
+        (EventType::Failure, EventPayload::Failure { domain, .. }) => Some(*domain),
+        (EventType::Failure | EventType::Humiliation, _) => None,
+        _ => return None,
+    };
+    let target = event.target()?;
+
+    simulation
+        .events_for(target)
+        .into_iter()
+        .filter(|te| {
+            let payload = te.event().payload();
+            let contradicts = match payload {
+                EventPayload::Achievement { domain, .. } => {
+                    fall_domain.is_none_or(|fall_domain| fall_domain == *domain)
+                }
+                EventPayload::Support { .. } => true,
+                _ => false,
+            };
+            contradicts
+                && te.event().target() == Some(target)
+                && !excluded.is_some_and(|excluded| std::ptr::eq(*te, excluded))
+                && payload.audience() != Audience::Private
+                && te.timestamp() < fall.timestamp()
+                && fall.timestamp() - te.timestamp() <= RECOGNITION_FALL_WINDOW
+        })
+        .max_by_key(|te| (te.event().payload().audience(), te.timestamp()))
+        .map(|te| (te.event().id().clone(), te.event().payload().audience()))
+}
+
+/// Interprets `te` for `entity`, amplifying it if it is a fall that
+/// contradicts earlier recognition other than `excluded`, follows a sleep
+/// loss other than `excluded`, or ends a relationship with someone the
+/// entity was close to.
+///
+/// Only the target's interpretation is amplified; a witness did not live
+/// through the recognition, the sleep loss, or the bond. Either way the deltas then
+/// take the simulation's stochastic noise, if any.
+pub(crate) fn interpret_in_history(
+    simulation: &Simulation,
+    te: &TimestampedEvent,
+    entity: &Entity,
+    excluded: Option<&TimestampedEvent>,
+) -> InterpretedEvent {
+    let mut interpreted = interpret_event(te.event(), entity);
+    if !interpreted.role.is_observer() {
+        if let Some((recognition, audience)) = contradicted_recognition(simulation, te, excluded) {
+            amplify_fall(&mut interpreted, recognition, audience);
+        }
+        if let Some(sleep_loss) = preceding_sleep_loss(simulation, te, excluded) {
+            amplify_after_sleep_loss(&mut interpreted, sleep_loss.event(), entity);
+        }
+        if let Some((relationship, weight)) = lost_relationship(simulation, te, entity) {
+            amplify_bereavement(&mut interpreted, relationship, weight);
+        }
+    }
+    if let Some(stochastic) = simulation.stochastic_config() {
+        stochastic.perturb(&mut interpreted, entity.id());
+    }
+    interpreted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::EntityBuilder;
+    use crate::enums::{LifeDomain, Species};
+    use crate::event::EventBuilder;
+    use crate::types::{EntityId, Timestamp};
+
+    fn reference() -> Timestamp {
+        Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+    }
+
+    fn setup() -> (Simulation, EntityId) {
+        let mut sim = Simulation::new(reference());
+        let entity = EntityBuilder::new()
+            .id("person_001")
+            .species(Species::Human)
+            .build()
+            .unwrap();
+        let id = sim.add_entity(entity, reference());
+        (sim, id)
+    }
+
+    fn add_achievement(
+        sim: &mut Simulation,
+        id: &EntityId,
+        domain: LifeDomain,
+        audience: Audience,
+        day: u64,
+    ) -> EventId {
+        let event = EventBuilder::new(EventType::Achievement)
+            .target(id.clone())
+            .payload(EventPayload::Achievement {
+                domain,
+                magnitude: 0.8,
+                audience,
+            })
+            .build()
+            .unwrap();
+        let event_id = event.id().clone();
+        sim.add_event(event, reference() + Duration::days(day));
+        event_id
+    }
+
+    fn fall(
+        id: &EntityId,
+        event_type: EventType,
+        payload: EventPayload,
+        day: u64,
+    ) -> TimestampedEvent {
+        let event = EventBuilder::new(event_type)
+            .target(id.clone())
+            .payload(payload)
+            .build()
+            .unwrap();
+        TimestampedEvent::new(event, reference() + Duration::days(day))
+    }
+
+    fn work_failure(id: &EntityId, day: u64) -> TimestampedEvent {
+        let payload = EventPayload::Failure {
+            domain: LifeDomain::Work,
+            public: false,
+        };
+        fall(id, EventType::Failure, payload, day)
+    }
+
+    #[test]
+    fn largest_audience_in_window_is_contradicted() {
+        let (mut sim, id) = setup();
+        add_achievement(&mut sim, &id, LifeDomain::Work, Audience::SmallGroup, 5);
+        let public = add_achievement(&mut sim, &id, LifeDomain::Work, Audience::Public, 2);
+        add_achievement(&mut sim, &id, LifeDomain::Work, Audience::Private, 8);
+
+        assert_eq!(
+            contradicted_recognition(&sim, &work_failure(&id, 10), None),
+            Some((public, Audience::Public))
+        );
+    }
+
+    #[test]
+    fn private_distant_and_other_domain_recognition_is_ignored() {
+        let (mut sim, id) = setup();
+        add_achievement(&mut sim, &id, LifeDomain::Work, Audience::Private, 5);
+        add_achievement(&mut sim, &id, LifeDomain::Creative, Audience::Public, 5);
+        add_achievement(&mut sim, &id, LifeDomain::Work, Audience::Public, 40);
+
+        assert_eq!(contradicted_recognition(&sim, &work_failure(&id, 10), None), None);
+        assert_eq!(contradicted_recognition(&sim, &work_failure(&id, 71), None), None);
+        assert!(contradicted_recognition(&sim, &work_failure(&id, 70), None).is_some());
+
+        // A humiliation contradicts recognition in any domain
+        let humiliation = fall(&id, EventType::Humiliation, EventPayload::Empty, 10);
+        assert!(contradicted_recognition(&sim, &humiliation, None).is_some());
+    }
+}
@@ -0,0 +1,256 @@
+//! Events that repeat at a fixed interval.
+//!
+//! A weekly therapy session over two years is one event repeated 104
+//! times. `Simulation::add_recurring_event` stores it as a single
+//! `RecurringEvent` rule, and each state query expands only the
+//! occurrences it needs, so a rule costs the same however many times it
+//! repeats.
+//!
+//! Occurrence `n` (counting from 0) happens at `start + n * interval`.
+//! Each is a copy of the event identified as `"{event}_{n}"`, and applies
+//! to the event's target exactly as an event added with `add_event` at
+//! that time would, including the boundary rules of state queries and
+//! their regression quality. At a shared timestamp, events added with
+//! `add_event` apply before occurrences, and rules apply in the order they
+//! were added.
+//!
+//! Occurrences affect the target's state only. They do not record trust
+//! antecedents or contact on relationships, and the event accessors of
+//! `Simulation` and `EntityQueryHandle` list stored events only.
+
+use crate::event::Event;
+use crate::simulation::TimestampedEvent;
+use crate::types::{Duration, EntityId, EventId, Timestamp};
+use std::ops::Range;
+
+/// An event repeated at a fixed interval.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::enums::EventType;
+/// use behavioral_pathways::event::EventBuilder;
+/// use behavioral_pathways::simulation::RecurringEvent;
+/// use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+///
+/// let start = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+/// let session = EventBuilder::new(EventType::Support)
+///     .target(EntityId::new("person").unwrap())
+///     .build()
+///     .unwrap();
+/// let weekly = RecurringEvent::new(session, start, Duration::weeks(1), 104);
+///
+/// assert_eq!(weekly.last_timestamp(), Some(start + Duration::weeks(103)));
+/// assert_eq!(weekly.occurrences_between(start, start + Duration::weeks(2)).count(), 2);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecurringEvent {
+    /// The event each occurrence copies.
+    event: Event,
+    /// When the first occurrence happens.
+    start: Timestamp,
+    /// Time between consecutive occurrences.
+    interval: Duration,
+    /// Number of occurrences.
+    count: u32,
+}
+
+impl RecurringEvent {
+    /// Creates a rule for `count` occurrences of `event`, the first at
+    /// `start` and each later one `interval` after the last.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is zero.
+    #[must_use]
+    pub fn new(event: Event, start: Timestamp, interval: Duration, count: u32) -> Self {
+        assert!(!interval.is_zero(), "Recurrence interval must be positive");
+        RecurringEvent {
+            event,
+            start,
+            interval,
+            count,
+        }
+    }
+
+    /// Creates a rule for occurrences of `event` from `start` through
+    /// `until`, `interval` apart.
+    ///
+    /// There are none if `until` is before `start`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is zero.
+    #[must_use]
+    pub fn until(event: Event, start: Timestamp, interval: Duration, until: Timestamp) -> Self {
+        assert!(!interval.is_zero(), "Recurrence interval must be positive");
+        let count = if until < start {
+            0
+        } else {
+            let steps = (until - start).as_seconds() / interval.as_seconds();
+            u32::try_from(steps + 1).unwrap_or(u32::MAX)
+        };
+        RecurringEvent::new(event, start, interval, count)
+    }
+
+    /// Returns the event each occurrence copies.
+    #[must_use]
+    pub fn event(&self) -> &Event {
+        &self.event
+    }
+
+    /// Returns when the first occurrence happens.
+    #[must_use]
+    pub fn start(&self) -> Timestamp {
+        self.start
+    }
+
+    /// Returns the time between consecutive occurrences.
+    #[must_use]
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Returns the number of occurrences.
+    #[must_use]
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Returns when the last occurrence happens, or `None` if there are
+    /// none.
+    #[must_use]
+    pub fn last_timestamp(&self) -> Option<Timestamp> {
+        let last = self.count.checked_sub(1)?;
+        Some(self.timestamp_of(last))
+    }
+
+    /// Returns the entity the occurrences target, if any.
+    #[must_use]
+    pub fn target(&self) -> Option<&EntityId> {
+        self.event.target()
+    }
+
+    /// Returns the occurrences after `after`, up to and including
+    /// `through`, in order.
+    pub fn occurrences_between(
+        &self,
+        after: Timestamp,
+        through: Timestamp,
+    ) -> impl Iterator<Item = TimestampedEvent> + '_ {
+        self.occurrences_in(Some(after), through)
+    }
+
+    /// Returns the occurrences after `after`, or from the first if `None`,
+    /// up to and including `through`, in order.
+    pub(crate) fn occurrences_in(
+        &self,
+        after: Option<Timestamp>,
+        through: Timestamp,
+    ) -> impl Iterator<Item = TimestampedEvent> + '_ {
+        self.indices_in(after, through)
+            .map(|index| self.occurrence(index))
+    }
+
+    /// Returns the occurrence with the given index.
+    fn occurrence(&self, index: u32) -> TimestampedEvent {
+        let mut event = self.event.clone();
+        let id = EventId::new(format!("{}_{}", self.event.id(), index))
+            .expect("occurrence of a valid event ID is valid");
+        event.set_id(id);
+        TimestampedEvent::new(event, self.timestamp_of(index))
+    }
+
+    /// Returns when the occurrence with the given index happens.
+    fn timestamp_of(&self, index: u32) -> Timestamp {
+        self.start + self.interval * u64::from(index)
+    }
+
+    /// Returns the indices of the occurrences in (`after`, `through`].
+    fn indices_in(&self, after: Option<Timestamp>, through: Timestamp) -> Range<u32> {
+        // Number of occurrences at or before a time
+        let through_count = |ts: Timestamp| {
+            if ts < self.start {
+                0
+            } else {
+                let steps = (ts - self.start).as_seconds() / self.interval.as_seconds();
+                u32::try_from(steps + 1).unwrap_or(u32::MAX).min(self.count)
+            }
+        };
+        let first = after.map_or(0, through_count);
+        first..through_count(through).max(first)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::EventType;
+    use crate::event::EventBuilder;
+
+    fn start() -> Timestamp {
+        Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+    }
+
+    fn session() -> Event {
+        EventBuilder::new(EventType::Support)
+            .id(EventId::new("session").unwrap())
+            .target(EntityId::new("person").unwrap())
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn occurrences_follow_boundary_rules() {
+        let rule = RecurringEvent::new(session(), start(), Duration::days(7), 4);
+        let timestamps = |after: Timestamp, through: Timestamp| {
+            rule.occurrences_between(after, through)
+                .map(|te| te.timestamp())
+                .collect::<Vec<_>>()
+        };
+
+        let all = timestamps(start() - Duration::days(1), start() + Duration::days(100));
+        assert_eq!(all.len(), 4);
+        assert_eq!(all[3], start() + Duration::days(21));
+        assert_eq!(
+            timestamps(start(), start() + Duration::days(14)),
+            vec![start() + Duration::days(7), start() + Duration::days(14)]
+        );
+        assert!(timestamps(start() + Duration::days(8), start() + Duration::days(13)).is_empty());
+        assert!(timestamps(start() + Duration::days(14), start()).is_empty());
+    }
+
+    #[test]
+    fn occurrences_have_distinct_ids() {
+        let rule = RecurringEvent::new(session(), start(), Duration::days(1), 3);
+        let ids: Vec<String> = rule
+            .occurrences_between(start() - Duration::days(1), start() + Duration::days(3))
+            .map(|te| te.event().id().to_string())
+            .collect();
+        assert_eq!(ids, ["session_0", "session_1", "session_2"]);
+    }
+
+    #[test]
+    fn until_includes_last_occurrence() {
+        let until = start() + Duration::days(14);
+        let rule = RecurringEvent::until(session(), start(), Duration::days(7), until);
+        assert_eq!(rule.count(), 3);
+        assert_eq!(rule.last_timestamp(), Some(until));
+
+        let empty = RecurringEvent::until(
+            session(),
+            start(),
+            Duration::days(7),
+            start() - Duration::days(1),
+        );
+        assert_eq!(empty.count(), 0);
+        assert_eq!(empty.last_timestamp(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "interval must be positive")]
+    fn zero_interval_panics() {
+        let _ = RecurringEvent::new(session(), start(), Duration::zero(), 3);
+    }
+}
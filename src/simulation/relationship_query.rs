@@ -0,0 +1,316 @@
+//! Relationship query API for timestamp-based relationship access.
+//!
+//! This module provides `RelationshipQueryHandle` for querying a
+//! relationship at any timestamp, and `ComputedRelationship` as the result
+//! type.
+//!
+//! Events between the pair record trust antecedents on the relationship as
+//! they are added, each dated by its event. A query keeps the antecedents
+//! of events up to the timestamp, recomputes trustworthiness from them, and
+//! derives the trust decision each party would make about the other.
+
+use crate::enums::{Direction, DispositionPath, StatePath};
+use crate::relationship::{Relationship, TrustContext, TrustDecision};
+use crate::simulation::{Simulation, TimestampedRelationship};
+use crate::state::IndividualState;
+use crate::types::{EntityId, RelationshipId, Timestamp};
+
+/// Handle for querying a relationship's state at any timestamp.
+///
+/// Obtained from `Simulation::relationship()`.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::simulation::Simulation;
+/// use behavioral_pathways::enums::RelationshipSchema;
+/// use behavioral_pathways::types::{EntityId, Timestamp};
+///
+/// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+/// let mut sim = Simulation::new(reference);
+/// let alice = EntityId::new("alice").unwrap();
+/// let bob = EntityId::new("bob").unwrap();
+/// sim.add_relationship(alice.clone(), bob.clone(), RelationshipSchema::Peer, reference);
+///
+/// let handle = sim.relationship(&alice, &bob).unwrap();
+/// assert_eq!(handle.entity_b(), &bob);
+/// ```
+pub struct RelationshipQueryHandle<'a> {
+    simulation: &'a Simulation,
+    id: RelationshipId,
+}
+
+impl<'a> RelationshipQueryHandle<'a> {
+    /// Creates a new query handle.
+    pub(crate) fn new(simulation: &'a Simulation, id: RelationshipId) -> Self {
+        RelationshipQueryHandle { simulation, id }
+    }
+
+    /// Returns the relationship's ID.
+    #[must_use]
+    pub fn id(&self) -> &RelationshipId {
+        &self.id
+    }
+
+    /// Returns the relationship's entity A.
+    #[must_use]
+    pub fn entity_a(&self) -> &'a EntityId {
+        self.timestamped().entity_a()
+    }
+
+    /// Returns the relationship's entity B.
+    #[must_use]
+    pub fn entity_b(&self) -> &'a EntityId {
+        self.timestamped().entity_b()
+    }
+
+    /// Computes the relationship at the given timestamp.
+    ///
+    /// Starts from the relationship as declared, keeps the trust
+    /// antecedents of events up to `timestamp`, and recomputes
+    /// trustworthiness from them. The stage reflects dormancy regression,
+    /// as in `Simulation::relationship_at()`.
+    ///
+    /// Each trust decision uses the trustor's trust propensity at
+    /// `timestamp` in a neutral `TrustContext`. A trustor who is not
+    /// simulated has the propensity of a neutral state, and an external
+    /// trustee keeps their declared trustworthiness regardless of events.
+    ///
+    /// Before the relationship formed, the result holds the declared
+    /// relationship and `is_formed()` is false.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::enums::{EventType, RelationshipSchema, Species};
+    /// use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// for id in ["alice", "bob"] {
+    ///     let entity = EntityBuilder::new().id(id).species(Species::Human).build().unwrap();
+    ///     sim.add_entity(entity, reference);
+    /// }
+    /// let alice = EntityId::new("alice").unwrap();
+    /// let bob = EntityId::new("bob").unwrap();
+    /// sim.add_relationship(alice.clone(), bob.clone(), RelationshipSchema::Peer, reference);
+    ///
+    /// let betrayal = EventBuilder::new(EventType::Betrayal)
+    ///     .source(bob.clone())
+    ///     .target(alice.clone())
+    ///     .severity(0.8)
+    ///     .build()
+    ///     .unwrap();
+    /// sim.add_event(betrayal, reference + Duration::days(10));
+    ///
+    /// let handle = sim.relationship(&alice, &bob).unwrap();
+    /// let before = handle.state_at(reference + Duration::days(5));
+    /// let after = handle.state_at(reference + Duration::days(15));
+    /// # #[cfg(feature = "relationships")]
+    /// assert!(
+    ///     after.a_to_b.disclosure_willingness() < before.a_to_b.disclosure_willingness()
+    /// );
+    /// ```
+    #[must_use]
+    pub fn state_at(&self, timestamp: Timestamp) -> ComputedRelationship {
+        let timestamped = self.timestamped();
+        let formed = timestamp >= timestamped.formed_timestamp();
+        let relationship = if formed {
+            self.simulation.relationship_as_of(timestamped, timestamp)
+        } else {
+            timestamped.relationship().clone()
+        };
+        let freshness = if formed {
+            self.simulation
+                .activity_for(timestamped, timestamp)
+                .antecedent_freshness
+        } else {
+            1.0
+        };
+        let context = TrustContext::new();
+        let decision = |direction: Direction, trustor: &EntityId, trustee: &EntityId| {
+            self.simulation.trust_decision_in(
+                relationship.clone(),
+                direction,
+                trustee,
+                self.propensity_at(trustor, timestamp),
+                freshness,
+                &context,
+            )
+        };
+        let (a, b) = (timestamped.entity_a(), timestamped.entity_b());
+        let a_to_b = decision(Direction::AToB, a, b);
+        let b_to_a = decision(Direction::BToA, b, a);
+
+        ComputedRelationship {
+            relationship,
+            a_to_b,
+            b_to_a,
+            timestamp,
+            formed,
+        }
+    }
+
+    /// Returns the queried relationship.
+    fn timestamped(&self) -> &'a TimestampedRelationship {
+        self.simulation
+            .get_relationship(&self.id)
+            .expect("query handle refers to a relationship in the simulation")
+    }
+
+    /// Returns a trustor's trust propensity at a timestamp.
+    fn propensity_at(&self, trustor: &EntityId, timestamp: Timestamp) -> f32 {
+        match self.simulation.entity(trustor) {
+            Some(handle) => handle
+                .state_at(timestamp)
+                .get_effective(StatePath::Disposition(DispositionPath::TrustPropensity))
+                as f32,
+            None => IndividualState::new()
+                .disposition()
+                .trust_propensity_effective(),
+        }
+    }
+}
+
+/// A relationship as computed at a specific timestamp.
+///
+/// Returned by `RelationshipQueryHandle::state_at()`.
+#[derive(Debug, Clone)]
+pub struct ComputedRelationship {
+    /// The relationship with the trust antecedents of events so far.
+    pub relationship: Relationship,
+    /// Entity A's trust decision about entity B.
+    pub a_to_b: TrustDecision,
+    /// Entity B's trust decision about entity A.
+    pub b_to_a: TrustDecision,
+    /// The queried timestamp.
+    timestamp: Timestamp,
+    /// Whether the relationship had formed by the timestamp.
+    formed: bool,
+}
+
+impl ComputedRelationship {
+    /// Returns the queried timestamp.
+    #[must_use]
+    pub fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+
+    /// Returns true if the relationship had formed by the timestamp.
+    #[must_use]
+    pub fn is_formed(&self) -> bool {
+        self.formed
+    }
+
+    /// Returns true if one party had lost the other by the timestamp,
+    /// dissolving the relationship.
+    #[must_use]
+    pub fn is_dissolved(&self) -> bool {
+        self.relationship.is_dissolved()
+    }
+
+    /// Returns the trust decision in the given direction.
+    #[must_use]
+    pub fn decision(&self, direction: Direction) -> &TrustDecision {
+        match direction {
+            Direction::AToB => &self.a_to_b,
+            Direction::BToA => &self.b_to_a,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::EntityBuilder;
+    use crate::enums::{RelationshipSchema, Species};
+    use crate::types::Duration;
+
+    fn reference() -> Timestamp {
+        Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+    }
+
+    fn pair() -> (Simulation, EntityId, EntityId) {
+        let mut sim = Simulation::new(reference());
+        for id in ["alice", "bob"] {
+            let entity = EntityBuilder::new()
+                .id(id)
+                .species(Species::Human)
+                .build()
+                .unwrap();
+            sim.add_entity(entity, reference());
+        }
+        let alice = EntityId::new("alice").unwrap();
+        let bob = EntityId::new("bob").unwrap();
+        (sim, alice, bob)
+    }
+
+    #[test]
+    fn relationship_found_in_either_order() {
+        let (mut sim, alice, bob) = pair();
+        assert!(sim.relationship(&alice, &bob).is_none());
+        sim.add_relationship(
+            alice.clone(),
+            bob.clone(),
+            RelationshipSchema::Peer,
+            reference(),
+        );
+
+        let handle = sim.relationship(&bob, &alice).unwrap();
+        assert_eq!(handle.entity_a(), &alice);
+        assert_eq!(handle.entity_b(), &bob);
+        assert!(sim.relationship(&alice, &alice).is_none());
+    }
+
+    #[test]
+    fn state_before_formation_is_declared_relationship() {
+        let (mut sim, alice, bob) = pair();
+        let formed = reference() + Duration::days(10);
+        sim.add_relationship(alice.clone(), bob.clone(), RelationshipSchema::Peer, formed);
+
+        let handle = sim.relationship(&alice, &bob).unwrap();
+        let early = handle.state_at(reference());
+        assert!(!early.is_formed());
+        assert_eq!(early.timestamp(), reference());
+        assert!(handle.state_at(formed).is_formed());
+    }
+
+    #[test]
+    #[cfg(feature = "relationships")]
+    fn events_added_before_relationship_still_count() {
+        use crate::enums::EventType;
+        use crate::event::EventBuilder;
+
+        let (mut sim, alice, bob) = pair();
+        let betrayal = EventBuilder::new(EventType::Betrayal)
+            .source(bob.clone())
+            .target(alice.clone())
+            .severity(0.8)
+            .build()
+            .unwrap();
+        sim.add_event(betrayal, reference() + Duration::days(5));
+        sim.add_relationship(
+            alice.clone(),
+            bob.clone(),
+            RelationshipSchema::Peer,
+            reference(),
+        );
+
+        let state = sim
+            .relationship(&alice, &bob)
+            .unwrap()
+            .state_at(reference() + Duration::days(6));
+        assert!(!state
+            .relationship
+            .antecedent_history(Direction::AToB)
+            .is_empty());
+        assert!(state
+            .relationship
+            .antecedent_history(Direction::BToA)
+            .is_empty());
+        assert_eq!(state.decision(Direction::BToA), &state.b_to_a);
+    }
+}
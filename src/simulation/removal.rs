@@ -0,0 +1,93 @@
+//! Removing entities from a simulation.
+//!
+//! `Simulation::remove_entity` refuses to remove an entity that events or
+//! relationships still refer to, rather than silently cascading, so a
+//! scenario edit never drops history the caller did not mean to drop.
+//! Remove the listed events and relationships first with
+//! `Simulation::remove_event` and `Simulation::remove_relationship`.
+//!
+//! Events the entity is only the source of are not references: their
+//! targets still experienced them.
+
+use crate::simulation::{AnchoredEntity, Simulation};
+use crate::types::{EntityId, EventId, RelationshipId};
+use std::fmt;
+
+/// Errors from `Simulation::remove_entity`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoveEntityError {
+    /// The simulation has no entity with this ID.
+    UnknownEntity(EntityId),
+    /// Events or relationships still refer to the entity.
+    Referenced {
+        /// The entity that could not be removed.
+        entity: EntityId,
+        /// Stored events and recurring rules targeting the entity, in
+        /// chronological and then rule order.
+        events: Vec<EventId>,
+        /// Relationships involving the entity, sorted by ID.
+        relationships: Vec<RelationshipId>,
+    },
+}
+
+impl fmt::Display for RemoveEntityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RemoveEntityError::UnknownEntity(entity) => {
+                write!(f, "Entity '{}' is not in the simulation", entity.as_str())
+            }
+            RemoveEntityError::Referenced {
+                entity,
+                events,
+                relationships,
+            } => write!(
+                f,
+                "Entity '{}' is referenced by {} events and {} relationships",
+                entity.as_str(),
+                events.len(),
+                relationships.len()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RemoveEntityError {}
+
+/// Removes `entity` if nothing refers to it.
+pub(crate) fn remove_entity(
+    simulation: &mut Simulation,
+    entity: &EntityId,
+) -> Result<AnchoredEntity, RemoveEntityError> {
+    if simulation.get_anchored_entity(entity).is_none() {
+        return Err(RemoveEntityError::UnknownEntity(entity.clone()));
+    }
+
+    let events: Vec<EventId> = simulation
+        .events_for(entity)
+        .into_iter()
+        .map(|te| te.event().id())
+        .chain(
+            simulation
+                .recurring_events_for(entity)
+                .map(|rule| rule.event().id()),
+        )
+        .cloned()
+        .collect();
+    let mut relationships: Vec<RelationshipId> = simulation
+        .relationship_entries()
+        .filter(|(_, relationship)| relationship.involves(entity))
+        .map(|(id, _)| id.clone())
+        .collect();
+    relationships.sort();
+    if !events.is_empty() || !relationships.is_empty() {
+        return Err(RemoveEntityError::Referenced {
+            entity: entity.clone(),
+            events,
+            relationships,
+        });
+    }
+
+    Ok(simulation
+        .take_entity(entity)
+        .expect("entity was checked to be present"))
+}
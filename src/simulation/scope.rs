@@ -0,0 +1,304 @@
+//! Scoped events that fan out to several entities.
+//!
+//! `Simulation::add_event` applies an event to its single target. A scoped
+//! event is one occurrence experienced by everyone in its `EventScope`:
+//!
+//! - **Individual**: the named entity, exactly like `add_event`.
+//! - **Group**: every member added with `Simulation::add_group_member` or
+//!   `Simulation::add_group_event`. Each copy records the group.
+//! - **Microsystem**: every entity whose context holds the microsystem.
+//! - **Global**: every entity.
+//!
+//! A broadcast is fanned out when it is added: each recipient gets its own
+//! copy targeting it, so each interprets the event through its own
+//! personality and developmental stage. Copies are identified as
+//! `"{event}_{entity}"`. A broadcast reaches the entities in the simulation
+//! whose anchor timestamp is at or before the event's timestamp, other than
+//! the event's source. An entity anchored later was observed after the
+//! event, so its anchored state already includes it, and an entity added
+//! to the simulation after the event does not receive it.
+
+use crate::enums::EventScope;
+use crate::event::Event;
+use crate::simulation::Simulation;
+use crate::types::{EntityId, EventId, GroupId, MicrosystemId, Timestamp};
+use std::fmt;
+
+/// Errors from `Simulation::add_scoped_event`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScopedEventError {
+    /// The event names a target its scope does not allow: another entity
+    /// for an individual scope, or any target for a broadcast.
+    TargetMismatch {
+        /// The event's target.
+        target: EntityId,
+        /// The scope the event was added with.
+        scope: EventScope,
+    },
+    /// The event's microsystem context differs from its microsystem scope.
+    MicrosystemMismatch {
+        /// The event's microsystem context.
+        context: MicrosystemId,
+        /// The microsystem in the scope.
+        scope: MicrosystemId,
+    },
+    /// The event's group differs from its group scope.
+    GroupMismatch {
+        /// The event's group.
+        group: GroupId,
+        /// The group in the scope.
+        scope: GroupId,
+    },
+    /// The group has no members.
+    UnknownGroup(GroupId),
+}
+
+impl fmt::Display for ScopedEventError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScopedEventError::TargetMismatch { target, scope } => write!(
+                f,
+                "Event targets '{}' but was added with {} scope",
+                target.as_str(),
+                scope.type_name()
+            ),
+            ScopedEventError::MicrosystemMismatch { context, scope } => write!(
+                f,
+                "Event occurs in microsystem '{}' but was scoped to '{}'",
+                context.as_str(),
+                scope.as_str()
+            ),
+            ScopedEventError::GroupMismatch { group, scope } => write!(
+                f,
+                "Event was delivered to group '{}' but was scoped to '{}'",
+                group.as_str(),
+                scope.as_str()
+            ),
+            ScopedEventError::UnknownGroup(group) => {
+                write!(f, "Group '{}' has no members", group.as_str())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScopedEventError {}
+
+/// Validates `event` against `scope` and adds a copy for each recipient.
+pub(crate) fn add_scoped_event(
+    simulation: &mut Simulation,
+    mut event: Event,
+    scope: EventScope,
+    timestamp: Timestamp,
+) -> Result<Vec<EventId>, ScopedEventError> {
+    if let EventScope::Individual(entity) = &scope {
+        if let Some(target) = event.target().filter(|target| *target != entity) {
+            return Err(ScopedEventError::TargetMismatch {
+                target: target.clone(),
+                scope: scope.clone(),
+            });
+        }
+        event.set_target(Some(entity.clone()));
+        let id = event.id().clone();
+        simulation.add_event(event, timestamp);
+        return Ok(vec![id]);
+    }
+
+    if let Some(target) = event.target() {
+        return Err(ScopedEventError::TargetMismatch {
+            target: target.clone(),
+            scope,
+        });
+    }
+    if let EventScope::Microsystem(microsystem) = &scope {
+        if let Some(context) = event
+            .microsystem_context()
+            .filter(|context| *context != microsystem)
+        {
+            return Err(ScopedEventError::MicrosystemMismatch {
+                context: context.clone(),
+                scope: microsystem.clone(),
+            });
+        }
+        event.set_microsystem_context(Some(microsystem.clone()));
+    }
+
+    if let EventScope::Group(group) = &scope {
+        if let Some(other) = event.group().filter(|other| *other != group) {
+            return Err(ScopedEventError::GroupMismatch {
+                group: other.clone(),
+                scope: group.clone(),
+            });
+        }
+        if simulation.group_members(group).is_empty() {
+            return Err(ScopedEventError::UnknownGroup(group.clone()));
+        }
+        event.set_group(Some(group.clone()));
+    }
+
+    let in_scope = |id: &EntityId| match &scope {
+        EventScope::Group(group) => simulation.group_members(group).contains(id),
+        EventScope::Microsystem(microsystem) => {
+            simulation.get_anchored_entity(id).is_some_and(|anchored| {
+                anchored
+                    .entity()
+                    .context()
+                    .get_microsystem(microsystem)
+                    .is_some()
+            })
+        }
+        EventScope::Global | EventScope::Individual(_) => true,
+    };
+    let mut recipients: Vec<EntityId> = simulation
+        .entities()
+        .filter(|anchored| anchored.anchor_timestamp() <= timestamp)
+        .map(|anchored| anchored.entity().id().clone())
+        .filter(|id| in_scope(id) && event.source() != Some(id))
+        .collect();
+    recipients.sort();
+
+    let mut ids = Vec::with_capacity(recipients.len());
+    for recipient in recipients {
+        let mut copy = event.clone();
+        let id = EventId::new(format!("{}_{}", event.id(), recipient))
+            .expect("copy of a valid event ID is valid");
+        copy.set_id(id.clone());
+        copy.set_target(Some(recipient));
+        simulation.add_event(copy, timestamp);
+        ids.push(id);
+    }
+    Ok(ids)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::{EcologicalContext, Microsystem, WorkContext};
+    use crate::entity::EntityBuilder;
+    use crate::enums::{EventType, Species};
+    use crate::event::EventBuilder;
+    use crate::types::Duration;
+
+    fn reference() -> Timestamp {
+        Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+    }
+
+    fn id(id: &str) -> EntityId {
+        EntityId::new(id).unwrap()
+    }
+
+    fn simulation() -> Simulation {
+        let mut sim = Simulation::new(reference());
+        for name in ["ana", "ben", "cas"] {
+            let mut builder = EntityBuilder::new().id(name).species(Species::Human);
+            if name != "cas" {
+                let mut context = EcologicalContext::default();
+                context.add_microsystem(
+                    MicrosystemId::new("office").unwrap(),
+                    Microsystem::new_work(WorkContext::default()),
+                );
+                builder = builder.with_context(context);
+            }
+            sim.add_entity(builder.build().unwrap(), reference());
+        }
+        sim
+    }
+
+    fn layoff() -> Event {
+        EventBuilder::new(EventType::JobLoss)
+            .id(EventId::new("layoff").unwrap())
+            .severity(0.6)
+            .build()
+            .unwrap()
+    }
+
+    fn targets(sim: &Simulation) -> Vec<EntityId> {
+        let mut targets: Vec<EntityId> = sim
+            .all_events()
+            .filter_map(|te| te.event().target().cloned())
+            .collect();
+        targets.sort();
+        targets
+    }
+
+    #[test]
+    fn individual_scope_targets_its_entity() {
+        let mut sim = simulation();
+        let ids = sim
+            .add_scoped_event(layoff(), EventScope::Individual(id("ben")), reference())
+            .unwrap();
+        assert_eq!(ids, vec![EventId::new("layoff").unwrap()]);
+        assert_eq!(targets(&sim), vec![id("ben")]);
+    }
+
+    #[test]
+    fn broadcasts_fan_out_to_their_scope() {
+        let at = reference() + Duration::days(1);
+        let mut sim = simulation();
+        let ids = sim
+            .add_scoped_event(layoff(), EventScope::Global, at)
+            .unwrap();
+        assert_eq!(ids[0], EventId::new("layoff_ana").unwrap());
+        assert_eq!(targets(&sim), vec![id("ana"), id("ben"), id("cas")]);
+
+        let mut sim = simulation();
+        let office = MicrosystemId::new("office").unwrap();
+        sim.add_scoped_event(layoff(), EventScope::Microsystem(office.clone()), at)
+            .unwrap();
+        assert_eq!(targets(&sim), vec![id("ana"), id("ben")]);
+        assert!(sim
+            .all_events()
+            .all(|te| te.event().microsystem_context() == Some(&office)));
+
+        let mut sim = simulation();
+        let team = GroupId::new("team").unwrap();
+        sim.add_group_member(team.clone(), id("ana"));
+        sim.add_group_member(team.clone(), id("cas"));
+        sim.add_scoped_event(layoff(), EventScope::Group(team.clone()), at)
+            .unwrap();
+        assert_eq!(targets(&sim), vec![id("ana"), id("cas")]);
+        assert!(sim.all_events().all(|te| te.event().group() == Some(&team)));
+    }
+
+    #[test]
+    fn mismatched_fields_are_rejected() {
+        let mut sim = simulation();
+        let targeted = EventBuilder::new(EventType::JobLoss)
+            .target(id("ana"))
+            .build()
+            .unwrap();
+        assert!(matches!(
+            sim.add_scoped_event(targeted.clone(), EventScope::Global, reference()),
+            Err(ScopedEventError::TargetMismatch { .. })
+        ));
+        assert!(matches!(
+            sim.add_scoped_event(targeted, EventScope::Individual(id("ben")), reference()),
+            Err(ScopedEventError::TargetMismatch { .. })
+        ));
+
+        let elsewhere = EventBuilder::new(EventType::JobLoss)
+            .context(MicrosystemId::new("school").unwrap())
+            .build()
+            .unwrap();
+        let office = EventScope::Microsystem(MicrosystemId::new("office").unwrap());
+        assert!(matches!(
+            sim.add_scoped_event(elsewhere, office, reference()),
+            Err(ScopedEventError::MicrosystemMismatch { .. })
+        ));
+
+        let team = GroupId::new("team").unwrap();
+        assert_eq!(
+            sim.add_scoped_event(layoff(), EventScope::Group(team.clone()), reference()),
+            Err(ScopedEventError::UnknownGroup(team.clone()))
+        );
+        sim.add_group_member(team.clone(), id("ana"));
+        let elsewhere = EventBuilder::new(EventType::JobLoss)
+            .group(GroupId::new("club").unwrap())
+            .build()
+            .unwrap();
+        assert!(matches!(
+            sim.add_scoped_event(elsewhere, EventScope::Group(team), reference()),
+            Err(ScopedEventError::GroupMismatch { .. })
+        ));
+        assert_eq!(sim.all_events().count(), 0);
+    }
+}
@@ -4,25 +4,78 @@
 //! It holds entities, events, and relationships with their timestamps,
 //! enabling state queries at any point in time.
 
-use crate::entity::Entity;
-use crate::enums::RelationshipSchema;
-use crate::event::Event;
-use crate::processor::process_event_to_relationships;
-use crate::relationship::Relationship;
-use crate::simulation::state_query::EntityQueryHandle;
-use crate::types::{EntityId, RelationshipId, Timestamp};
-use std::collections::HashMap;
+use crate::entity::{Entity, EntityBuildError, EntityTemplate};
+use crate::enums::{
+    AlertProvenance, AlertSeverity, ContextPath, Direction, DispositionPath, EventPayload,
+    EventScope, EventType, InteractionTopic, RelationshipSchema, SpiralType, StatePath,
+    TrustDomain,
+};
+use crate::event::{Event, EventFilter, Provenance};
+#[cfg(feature = "relationships")]
+use crate::processor::{process_event_to_relationships, retract_event_from_relationships};
+use crate::relationship::{
+    is_contact_event, DormancyConfig, Relationship, RelationshipActivity, TrustContext,
+    TrustDecision,
+};
+use crate::simulation::alert_sweep::{self, AlertSweepConfig};
+use crate::simulation::base_absorption::BaseAbsorptionConfig;
+use crate::simulation::bereavement;
+use crate::simulation::cascade::{self, CascadeRule};
+use crate::simulation::compaction::{self, CompactedHistory, CompactionReport, RebaseError};
+use crate::simulation::comparison::{self, EntityComparison};
+use crate::simulation::contagion::ContagionConfig;
+use crate::simulation::context_change::{ContextChange, TimestampedContextChange};
+use crate::simulation::context_series::{ContextSeries, ContextSeriesError, Interpolation};
+use crate::simulation::diff::SimulationDiff;
+use crate::simulation::emotion_model::EmotionModel;
+use crate::simulation::profiling::{ProfileReport, Profiler};
+use crate::simulation::simulation_builder::{event_errors, SimulationBuildError};
+use crate::simulation::interaction::{self, RecordedInteraction};
+use crate::simulation::its_invariants::ItsInvariantMode;
+use crate::simulation::entity_ref::EntityRef;
+use crate::simulation::observation::{Observation, ObservationPolicy};
+use crate::simulation::population;
+use crate::simulation::recognition;
+use crate::simulation::recurrence::RecurringEvent;
+use crate::simulation::relationship_query::RelationshipQueryHandle;
+use crate::simulation::removal::{self, RemoveEntityError};
+use crate::simulation::scope::{self, ScopedEventError};
+use crate::simulation::sleep_loss;
+use crate::simulation::state_query::{ComputedState, EntityQueryHandle};
+use crate::simulation::stochastic::StochasticConfig;
+use crate::simulation::trust_calibration::{self, TrustCalibrationReport};
+use crate::simulation::validation::{self, ValidationConfig, ValidationIssue};
+use crate::state::IndividualState;
+use crate::types::{Alert, Duration, EntityId, EventId, GroupId, RelationshipId, Timestamp};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// Default weight of a relationship with an external entity in the
+/// relationship-quality estimate, relative to a simulated one.
+pub const DEFAULT_EXTERNAL_RELATIONSHIP_WEIGHT: f64 = 0.5;
 
 /// An entity with its anchor timestamp.
 ///
 /// The anchor timestamp represents when this entity's state was observed.
-/// All state queries compute relative to this anchor point.
+/// State queries compute relative to this anchor point, or to the nearest
+/// later observation of the entity's state.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AnchoredEntity {
     /// The entity instance.
     entity: Entity,
     /// When this state snapshot was captured.
     anchor_timestamp: Timestamp,
+    /// Further observed states, sorted by timestamp.
+    observations: Vec<Observation>,
+    /// Alerts supplied by outside systems, sorted by timestamp.
+    external_alerts: Vec<TimestampedAlert>,
+    /// Scheduled changes to the entity's context, sorted by timestamp.
+    context_changes: Vec<TimestampedContextChange>,
+    /// Changes whenever this entity's anchor data may have changed.
+    generation: u64,
+    /// What `Simulation::compact` has folded, boxed as it is rarely set.
+    compacted_history: Option<Box<CompactedHistory>>,
 }
 
 impl AnchoredEntity {
@@ -32,6 +85,11 @@ impl AnchoredEntity {
         AnchoredEntity {
             entity,
             anchor_timestamp,
+            observations: Vec::new(),
+            external_alerts: Vec::new(),
+            context_changes: Vec::new(),
+            generation: 0,
+            compacted_history: None,
         }
     }
 
@@ -51,22 +109,119 @@ impl AnchoredEntity {
     pub fn anchor_timestamp(&self) -> Timestamp {
         self.anchor_timestamp
     }
+
+    /// Returns the generation of this entity's anchor data.
+    ///
+    /// The simulation assigns a new generation when the entity is replaced,
+    /// borrowed mutably, or given an observation or context change. See
+    /// `EntityRef`.
+    #[must_use]
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Returns the observations of this entity, sorted by timestamp.
+    #[must_use]
+    pub fn observations(&self) -> &[Observation] {
+        &self.observations
+    }
+
+    /// Returns the external alerts for this entity, sorted by timestamp.
+    #[must_use]
+    pub fn external_alerts(&self) -> &[TimestampedAlert] {
+        &self.external_alerts
+    }
+
+    /// Adds an external alert after any others at the same timestamp.
+    pub(crate) fn insert_external_alert(&mut self, alert: TimestampedAlert) {
+        let index = self
+            .external_alerts
+            .partition_point(|existing| existing.timestamp <= alert.timestamp);
+        self.external_alerts.insert(index, alert);
+    }
+
+    /// Returns the scheduled context changes for this entity, sorted by
+    /// timestamp.
+    #[must_use]
+    pub fn context_changes(&self) -> &[TimestampedContextChange] {
+        &self.context_changes
+    }
+
+    /// Adds a context change after any others at the same timestamp.
+    pub(crate) fn insert_context_change(&mut self, change: TimestampedContextChange) {
+        let index = self
+            .context_changes
+            .partition_point(|existing| existing.timestamp() <= change.timestamp());
+        self.context_changes.insert(index, change);
+    }
+
+    /// Adds an observation, replacing any existing one at the same timestamp.
+    pub(crate) fn insert_observation(&mut self, observation: Observation) {
+        let timestamp = observation.timestamp();
+        match self
+            .observations
+            .binary_search_by_key(&timestamp, Observation::timestamp)
+        {
+            Ok(index) => self.observations[index] = observation,
+            Err(index) => self.observations.insert(index, observation),
+        }
+    }
+
+    /// Returns the entity with its state replaced by an observation's.
+    pub(crate) fn entity_as_observed(&self, observation: &Observation) -> Entity {
+        let mut entity = self.entity.clone();
+        *entity.individual_state_mut() = observation.state().clone();
+        entity
+    }
+
+    /// Returns what compaction has folded for this entity, if anything.
+    pub(crate) fn compacted_history(&self) -> Option<&CompactedHistory> {
+        self.compacted_history.as_deref()
+    }
+
+    /// Returns a mutable reference to the compacted history, if any.
+    pub(crate) fn compacted_history_mut(&mut self) -> Option<&mut CompactedHistory> {
+        self.compacted_history.as_deref_mut()
+    }
+
+    /// Records what compaction has folded for this entity.
+    pub(crate) fn set_compacted_history(&mut self, history: CompactedHistory) {
+        self.compacted_history = Some(Box::new(history));
+    }
 }
 
 /// An event with its absolute timestamp.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TimestampedEvent {
     /// The event instance.
     event: Event,
     /// When this event occurred.
     timestamp: Timestamp,
+    /// The event whose cascade generated this one, if it is synthetic
+    /// (boxed because few events are).
+    #[cfg_attr(feature = "serde", serde(default))]
+    cascade_trigger: Option<Box<EventId>>,
 }
 
 impl TimestampedEvent {
     /// Creates a new timestamped event.
     #[must_use]
     pub fn new(event: Event, timestamp: Timestamp) -> Self {
-        TimestampedEvent { event, timestamp }
+        TimestampedEvent {
+            event,
+            timestamp,
+            cascade_trigger: None,
+        }
+    }
+
+    /// Creates an event generated by the cascade of `trigger`.
+    pub(crate) fn synthetic(event: Event, timestamp: Timestamp, trigger: EventId) -> Self {
+        TimestampedEvent {
+            event,
+            timestamp,
+            cascade_trigger: Some(Box::new(trigger)),
+        }
     }
 
     /// Returns a reference to the event.
@@ -80,10 +235,68 @@ impl TimestampedEvent {
     pub fn timestamp(&self) -> Timestamp {
         self.timestamp
     }
+
+    /// Returns where the event was ingested from, if recorded.
+    #[must_use]
+    pub fn provenance(&self) -> Option<&Provenance> {
+        self.event.provenance()
+    }
+
+    /// Returns true if a cascade rule generated this event.
+    #[must_use]
+    pub fn is_synthetic(&self) -> bool {
+        self.cascade_trigger.is_some()
+    }
+
+    /// Returns the ID of the event that set this one off, if a cascade
+    /// rule generated it.
+    #[must_use]
+    pub fn cascade_trigger(&self) -> Option<&EventId> {
+        self.cascade_trigger.as_deref()
+    }
+}
+
+/// An alert with its absolute timestamp.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimestampedAlert {
+    /// The alert instance.
+    alert: Alert,
+    /// When this alert was raised.
+    timestamp: Timestamp,
+}
+
+impl TimestampedAlert {
+    /// Creates a new timestamped alert.
+    #[must_use]
+    pub fn new(alert: Alert, timestamp: Timestamp) -> Self {
+        TimestampedAlert { alert, timestamp }
+    }
+
+    /// Returns a reference to the alert.
+    #[must_use]
+    pub fn alert(&self) -> &Alert {
+        &self.alert
+    }
+
+    /// Returns the timestamp when this alert was raised.
+    #[must_use]
+    pub fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+
+    /// Returns true if the alert's crisis window covers `timestamp`.
+    #[must_use]
+    pub fn is_crisis_at(&self, timestamp: Timestamp) -> bool {
+        self.alert.crisis_window().is_some_and(|window| {
+            self.timestamp <= timestamp && timestamp < self.timestamp + window
+        })
+    }
 }
 
 /// A relationship with its formation timestamp.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TimestampedRelationship {
     /// The relationship instance.
     relationship: Relationship,
@@ -151,11 +364,11 @@ impl TimestampedRelationship {
 /// Quality indicator for backward regression.
 ///
 /// Some state computations cannot be exactly reversed:
-/// - Events that triggered feedback loops (spirals)
+/// - Events crossed while a feedback loop (spiral) was active
 /// - Events that modified non-reversible dimensions
 ///
 /// This enum indicates whether the regression was mathematically exact
-/// or an approximation.
+/// or an approximation; `ComputedState::approximate_reasons` says why.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum RegressionQuality {
     /// Regression is mathematically exact.
@@ -165,6 +378,27 @@ pub enum RegressionQuality {
     Approximate,
 }
 
+/// Why a computed state is approximate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ApproximationReason {
+    /// The regression crossed trauma, whose acquired capability increase
+    /// cannot be reversed.
+    IrreversibleEvents,
+    /// The timestamp is before a compaction cutoff or a rebased anchor, so
+    /// the events folded by `Simulation::compact` or
+    /// `Simulation::rebase_entity` are missing.
+    CompactedHistory {
+        /// The cutoff the events were folded at.
+        cutoff: Timestamp,
+    },
+    /// The regression crossed an event while a feedback spiral was active,
+    /// and spirals compound in ways reversing the event does not undo.
+    FeedbackSpiral(SpiralType),
+    /// The regression moved absorbed delta back out of a base, timing how
+    /// long it was sustained from the anchor rather than from its start.
+    BaseAbsorption,
+}
+
 impl RegressionQuality {
     /// Returns true if the regression was exact.
     #[must_use]
@@ -184,17 +418,75 @@ impl RegressionQuality {
 /// Holds entities, events, and relationships with their timestamps.
 /// Provides the `state_at()` API for querying entity state at any timestamp.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Simulation {
     /// The simulation's reference date.
     reference_date: Timestamp,
     /// Entities indexed by their ID.
-    entities: HashMap<EntityId, AnchoredEntity>,
-    /// Events in the simulation.
+    /// Shared behind `Arc` so the table's spare capacity costs a pointer,
+    /// not an entity, and branches share anchors until one modifies them.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::types::sorted_serde::map"))]
+    entities: HashMap<EntityId, Arc<AnchoredEntity>>,
+    /// Events in the simulation, in the order they were added.
     events: Vec<TimestampedEvent>,
+    /// Positions in `events` of the events targeting or witnessed by each
+    /// entity, sorted by timestamp with ties in the order added. Positions
+    /// are stored as `u32`, as an index entry is kept per event and entity.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::types::sorted_serde::map"))]
+    events_by_target: HashMap<EntityId, Vec<u32>>,
+    /// Longest ramp among the events added, bounding how long after an
+    /// event its onset increments land. Kept when events are removed.
+    longest_ramp: Duration,
+    /// Longest anticipation window among the events added, bounding how
+    /// long before an event its anticipation builds. Kept when events are
+    /// removed.
+    longest_anticipation: Duration,
+    /// Recurring event rules, in the order they were added.
+    recurring_events: Vec<RecurringEvent>,
+    /// Rules expanding each added event into its cascade.
+    cascade_rules: Vec<CascadeRule>,
     /// Relationships indexed by their ID.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::types::sorted_serde::map"))]
     relationships: HashMap<RelationshipId, TimestampedRelationship>,
     /// Counter for generating relationship IDs.
     relationship_counter: u64,
+    /// Tuning for relationship drift when the pair stops interacting.
+    dormancy_config: DormancyConfig,
+    /// How observations reconcile with the projections before them.
+    observation_policy: ObservationPolicy,
+    /// Tuning for emotional contagion, or `None` when disabled.
+    contagion_config: Option<ContagionConfig>,
+    /// Base absorption tuning, or `None` when deltas always decay to the
+    /// original base.
+    base_absorption_config: Option<BaseAbsorptionConfig>,
+    /// Seeded noise on interpreted deltas, or `None` when interpretation
+    /// is deterministic.
+    stochastic_config: Option<StochasticConfig>,
+    /// Tuning for saturation checks in `validate()`.
+    validation_config: ValidationConfig,
+    /// Octant boundaries and attribution-gated emotions for
+    /// `derived_emotions_extended()`.
+    emotion_model: EmotionModel,
+    /// Per-phase query timings, accumulated when profiling is enabled.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    profiler: Profiler,
+    /// What `state_at()` does when a state breaks an ITS invariant.
+    its_invariant_mode: ItsInvariantMode,
+    /// Time-varying context values shared by every entity.
+    context_series: Vec<ContextSeries>,
+    /// Relationship partners declared as deliberately not simulated.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::types::sorted_serde::set"))]
+    external_entities: HashSet<EntityId>,
+    /// Weight (0-1) of a relationship with an external entity in the
+    /// relationship-quality estimate.
+    external_relationship_weight: f64,
+    /// Whether `record_interaction` forms memories for the parties.
+    memory_formation: bool,
+    /// Members of each group, for group-scoped events.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::types::sorted_serde::map"))]
+    groups: HashMap<GroupId, Vec<EntityId>>,
+    /// Last generation assigned to an entity's anchor data.
+    generation_counter: u64,
 }
 
 impl Simulation {
@@ -219,8 +511,28 @@ impl Simulation {
             reference_date,
             entities: HashMap::new(),
             events: Vec::new(),
+            events_by_target: HashMap::new(),
+            longest_ramp: Duration::zero(),
+            longest_anticipation: Duration::zero(),
+            recurring_events: Vec::new(),
+            cascade_rules: Vec::new(),
             relationships: HashMap::new(),
             relationship_counter: 0,
+            dormancy_config: DormancyConfig::default(),
+            observation_policy: ObservationPolicy::default(),
+            contagion_config: None,
+            base_absorption_config: None,
+            stochastic_config: None,
+            validation_config: ValidationConfig::default(),
+            emotion_model: EmotionModel::default(),
+            profiler: Profiler::default(),
+            its_invariant_mode: ItsInvariantMode::default(),
+            context_series: Vec::new(),
+            external_entities: HashSet::new(),
+            external_relationship_weight: DEFAULT_EXTERNAL_RELATIONSHIP_WEIGHT,
+            memory_formation: false,
+            groups: HashMap::new(),
+            generation_counter: 0,
         }
     }
 
@@ -230,12 +542,294 @@ impl Simulation {
         self.reference_date
     }
 
+    /// Returns the dormancy tuning used for relationship drift.
+    #[must_use]
+    pub fn dormancy_config(&self) -> &DormancyConfig {
+        &self.dormancy_config
+    }
+
+    /// Sets the dormancy tuning used for relationship drift.
+    pub fn set_dormancy_config(&mut self, config: DormancyConfig) {
+        self.dormancy_config = config;
+    }
+
+    /// Returns how observations reconcile with the projections before them.
+    #[must_use]
+    pub fn observation_policy(&self) -> ObservationPolicy {
+        self.observation_policy
+    }
+
+    /// Sets how observations reconcile with the projections before them.
+    pub fn set_observation_policy(&mut self, policy: ObservationPolicy) {
+        self.observation_policy = policy;
+    }
+
+    /// Returns the emotional contagion tuning, or `None` when disabled.
+    #[must_use]
+    pub fn contagion_config(&self) -> Option<&ContagionConfig> {
+        self.contagion_config.as_ref()
+    }
+
+    /// Enables emotional contagion between members of shared microsystems,
+    /// or disables it with `None`.
+    ///
+    /// Contagion is off by default. When on, each `state_at()` query steps
+    /// through every member of the entity's group, so query cost grows
+    /// with group size and the time since the latest anchor.
+    pub fn set_contagion_config(&mut self, config: Option<ContagionConfig>) {
+        self.contagion_config = config;
+    }
+
+    /// Returns the base absorption tuning, or `None` when disabled.
+    #[must_use]
+    pub fn base_absorption_config(&self) -> Option<&BaseAbsorptionConfig> {
+        self.base_absorption_config.as_ref()
+    }
+
+    /// Enables absorbing long-sustained chronic deltas into the base, or
+    /// disables it with `None`.
+    ///
+    /// Off by default, so deltas decay to the original base however long
+    /// they lasted. When on, part of a chronic delta sustained past the
+    /// onset stays after the delta itself has decayed.
+    pub fn set_base_absorption_config(&mut self, config: Option<BaseAbsorptionConfig>) {
+        self.base_absorption_config = config;
+    }
+
+    /// Returns the seeded noise on interpreted deltas, or `None` when
+    /// disabled.
+    #[must_use]
+    pub fn stochastic_config(&self) -> Option<&StochasticConfig> {
+        self.stochastic_config.as_ref()
+    }
+
+    /// Enables seeded noise on each entity's interpreted deltas, or
+    /// disables it with `None`.
+    ///
+    /// Off by default, so entities with the same profile react to the same
+    /// event identically. When on, each delta is scaled by a factor within
+    /// `1 ± magnitude` drawn from the seed, the entity, the event, and the
+    /// state path, so a seed reproduces every state whatever order events
+    /// were added in. Seed 0 adds no noise.
+    ///
+    /// Compaction checkpoints hold states computed under the previous
+    /// config, so a change rebuilds them from the events still in the log.
+    pub fn set_stochastic_config(&mut self, config: Option<StochasticConfig>) {
+        if self.stochastic_config == config {
+            return;
+        }
+        self.stochastic_config = config;
+        compaction::rebuild_checkpoints(self);
+    }
+
+    /// Returns true if `record_interaction` forms memories for the parties.
+    #[must_use]
+    pub fn memory_formation(&self) -> bool {
+        self.memory_formation
+    }
+
+    /// Sets whether `record_interaction` forms memories for the parties.
+    ///
+    /// Off by default. Stored memories prime mood during consolidation, so
+    /// enabling this changes later states as well as memory contents.
+    /// Without the `memory` feature no memories are formed.
+    pub fn set_memory_formation(&mut self, enabled: bool) {
+        self.memory_formation = enabled;
+    }
+
+    /// Returns the tuning for saturation checks in `validate()`.
+    #[must_use]
+    pub fn validation_config(&self) -> &ValidationConfig {
+        &self.validation_config
+    }
+
+    /// Sets the tuning for saturation checks in `validate()`, including
+    /// whether every query asserts on saturated anchors.
+    pub fn set_validation_config(&mut self, config: ValidationConfig) {
+        self.validation_config = config;
+    }
+
+    /// Returns the emotion model used by `derived_emotions_extended()`.
+    #[must_use]
+    pub fn emotion_model(&self) -> &EmotionModel {
+        &self.emotion_model
+    }
+
+    /// Sets the octant boundaries and attribution-gated emotions used by
+    /// `derived_emotions_extended()`. `derived_emotions()` is unaffected.
+    pub fn set_emotion_model(&mut self, model: EmotionModel) {
+        self.emotion_model = model;
+    }
+
+    /// Returns true if state queries are profiled.
+    #[must_use]
+    pub fn profiling(&self) -> bool {
+        self.profiler.is_enabled()
+    }
+
+    /// Sets whether state queries are profiled.
+    ///
+    /// Off by default. When on, each `state_at()` query adds the time and
+    /// call count of each computation phase to `profile_report()`. When
+    /// off, queries take no clock readings.
+    pub fn set_profiling(&mut self, enabled: bool) {
+        self.profiler.set_enabled(enabled);
+    }
+
+    /// Returns the per-phase timings accumulated since profiling was
+    /// enabled or last reset.
+    #[must_use]
+    pub fn profile_report(&self) -> ProfileReport {
+        self.profiler.report()
+    }
+
+    /// Clears the accumulated per-phase timings.
+    pub fn reset_profile(&mut self) {
+        self.profiler.reset();
+    }
+
+    /// Returns what a query does when a state breaks an ITS invariant.
+    #[must_use]
+    pub fn its_invariant_mode(&self) -> ItsInvariantMode {
+        self.its_invariant_mode
+    }
+
+    /// Sets what a query does when a computed state breaks an ITS
+    /// invariant: nothing, log a warning, or, in strict mode, have
+    /// `try_state_at()` return it as an error.
+    ///
+    /// Off by default. `state_at()` never fails on a violation; outside
+    /// `Off` it logs one.
+    pub fn set_its_invariant_mode(&mut self, mode: ItsInvariantMode) {
+        self.its_invariant_mode = mode;
+    }
+
+    /// Returns the profiler that times state queries.
+    pub(crate) fn profiler(&self) -> &Profiler {
+        &self.profiler
+    }
+
+    /// Returns the context series, in the order their paths were first set.
+    #[must_use]
+    pub fn context_series(&self) -> &[ContextSeries] {
+        &self.context_series
+    }
+
+    /// Drives a context path from a time series instead of each entity's
+    /// static value, replacing any earlier series for the same path.
+    ///
+    /// Only exosystem, macrosystem, and chronosystem paths are eligible.
+    /// Context effects in `state_at()` read the series at the timestamp
+    /// they are computed for.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for microsystem paths, empty series, non-finite
+    /// values, or two points at the same timestamp.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::enums::{ContextPath, ExosystemPath};
+    /// use behavioral_pathways::simulation::{Interpolation, Simulation};
+    /// use behavioral_pathways::types::Timestamp;
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2020, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let resources = ContextPath::Exosystem(ExosystemPath::ResourceAvailability);
+    /// let series = vec![
+    ///     (reference, 0.6),
+    ///     (Timestamp::from_ymd_hms(2020, 4, 1, 0, 0, 0), 0.2),
+    /// ];
+    ///
+    /// sim.set_context_series(resources, series, Interpolation::Linear).unwrap();
+    /// assert_eq!(sim.context_series().len(), 1);
+    /// ```
+    pub fn set_context_series(
+        &mut self,
+        path: ContextPath,
+        series: Vec<(Timestamp, f64)>,
+        interpolation: Interpolation,
+    ) -> Result<(), ContextSeriesError> {
+        let series = ContextSeries::new(path, series, interpolation)?;
+        match self
+            .context_series
+            .iter_mut()
+            .find(|existing| existing.path() == series.path())
+        {
+            Some(existing) => *existing = series,
+            None => self.context_series.push(series),
+        }
+        Ok(())
+    }
+
+    // --- External Entities ---
+
+    /// Declares an entity ID as an external relationship partner.
+    ///
+    /// External entities are people not worth simulating, such as a
+    /// deceased parent or a distant pen pal. Relationships and events may
+    /// reference them without `validate()` reporting a dangling
+    /// relationship. Their state is never looked up: trust in them uses the
+    /// declared trustworthiness statically, and their relationships count
+    /// toward relationship quality at `external_relationship_weight`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::types::{EntityId, Timestamp};
+    ///
+    /// let mut sim = Simulation::new(Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0));
+    /// let grandmother = EntityId::new("grandmother").unwrap();
+    /// sim.declare_external_entity(grandmother.clone());
+    ///
+    /// assert!(sim.is_external_entity(&grandmother));
+    /// ```
+    pub fn declare_external_entity(&mut self, id: EntityId) {
+        self.external_entities.insert(id);
+    }
+
+    /// Returns true if the ID was declared as an external entity.
+    #[must_use]
+    pub fn is_external_entity(&self, id: &EntityId) -> bool {
+        self.external_entities.contains(id)
+    }
+
+    /// Returns the declared external entities, in no particular order.
+    pub fn external_entities(&self) -> impl Iterator<Item = &EntityId> {
+        self.external_entities.iter()
+    }
+
+    /// Returns the weight of a relationship with an external entity in the
+    /// relationship-quality estimate.
+    #[must_use]
+    pub fn external_relationship_weight(&self) -> f64 {
+        self.external_relationship_weight
+    }
+
+    /// Sets the weight (clamped to 0-1) of a relationship with an external
+    /// entity in the relationship-quality estimate, relative to one with a
+    /// simulated entity.
+    pub fn set_external_relationship_weight(&mut self, weight: f64) {
+        self.external_relationship_weight = weight.clamp(0.0, 1.0);
+    }
+
+    /// Returns true if either side of the relationship is external.
+    #[cfg(all(feature = "context", feature = "relationships"))]
+    pub(crate) fn has_external_party(&self, relationship: &TimestampedRelationship) -> bool {
+        self.is_external_entity(relationship.entity_a())
+            || self.is_external_entity(relationship.entity_b())
+    }
+
     // --- Entity Management ---
 
     /// Adds an entity to the simulation with its anchor timestamp.
     ///
     /// The anchor timestamp represents when the entity's state was observed.
-    /// All state queries compute relative to this anchor point.
+    /// All state queries compute relative to this anchor point. Relationships
+    /// already in the simulation that involve the entity are attached to its
+    /// free relationship slots.
     ///
     /// # Returns
     ///
@@ -263,143 +857,1441 @@ impl Simulation {
     ///
     /// assert_eq!(sim.entity_count(), 1);
     /// ```
-    pub fn add_entity(&mut self, entity: Entity, anchor_timestamp: Timestamp) -> EntityId {
+    pub fn add_entity(&mut self, mut entity: Entity, anchor_timestamp: Timestamp) -> EntityId {
         let id = entity.id().clone();
-        let anchored = AnchoredEntity::new(entity, anchor_timestamp);
-        self.entities.insert(id.clone(), anchored);
+        let mut related: Vec<&RelationshipId> = self
+            .relationships
+            .iter()
+            .filter(|(_, relationship)| relationship.involves(&id))
+            .map(|(rel_id, _)| rel_id)
+            .collect();
+        related.sort();
+        for rel_id in related {
+            entity.attach_relationship(rel_id);
+        }
+        let mut anchored = AnchoredEntity::new(entity, anchor_timestamp);
+        anchored.generation = self.next_generation();
+        self.entities.insert(id.clone(), Arc::new(anchored));
         id
     }
 
-    /// Returns a query handle for the given entity ID.
+    /// Spawns one entity from `template` per identifier, all anchored at
+    /// `anchor_timestamp`.
     ///
-    /// The handle provides the `state_at()` method for querying state.
+    /// Identifiers are validated before any entity is added, so on error
+    /// the simulation is unchanged. An identifier already in the
+    /// simulation replaces that entity, as with `add_entity`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EntityBuildError::InvalidId` if any identifier is empty.
     ///
     /// # Examples
     ///
     /// ```
     /// use behavioral_pathways::simulation::Simulation;
-    /// use behavioral_pathways::entity::EntityBuilder;
-    /// use behavioral_pathways::types::{Timestamp, EntityId};
+    /// use behavioral_pathways::entity::{EntityBuilder, EntityTemplate};
+    /// use behavioral_pathways::types::Timestamp;
     /// use behavioral_pathways::enums::Species;
     ///
     /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
     /// let mut sim = Simulation::new(reference);
+    /// let template =
+    ///     EntityTemplate::compile(EntityBuilder::new().species(Species::Human)).unwrap();
     ///
-    /// let entity = EntityBuilder::new()
-    ///     .id("person_001")
-    ///     .species(Species::Human)
-    ///     .build()
+    /// let ids = sim
+    ///     .add_from_template(&template, &["npc_001", "npc_002"], reference)
     ///     .unwrap();
-    ///
-    /// sim.add_entity(entity, reference);
-    ///
-    /// let handle = sim.entity(&EntityId::new("person_001").unwrap());
-    /// assert!(handle.is_some());
-    ///
-    /// let unknown = sim.entity(&EntityId::new("unknown").unwrap());
-    /// assert!(unknown.is_none());
+    /// assert_eq!(ids.len(), 2);
+    /// assert_eq!(sim.entity_count(), 2);
     /// ```
-    #[must_use]
-    pub fn entity(&self, id: &EntityId) -> Option<EntityQueryHandle<'_>> {
-        if self.entities.contains_key(id) {
-            Some(EntityQueryHandle::new(self, id.clone()))
-        } else {
-            None
+    pub fn add_from_template(
+        &mut self,
+        template: &EntityTemplate,
+        ids: &[&str],
+        anchor_timestamp: Timestamp,
+    ) -> Result<Vec<EntityId>, EntityBuildError> {
+        let ids = ids
+            .iter()
+            .map(|id| EntityId::new(*id).map_err(|err| EntityBuildError::InvalidId(err.reason)))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.entities.reserve(ids.len());
+        for id in &ids {
+            let entity = template.spawn_with_id(id.clone());
+            self.add_entity(entity, anchor_timestamp);
         }
+        Ok(ids)
     }
 
-    /// Returns an iterator over all anchored entities.
-    pub fn entities(&self) -> impl Iterator<Item = &AnchoredEntity> {
-        self.entities.values()
-    }
-
-    /// Returns the number of entities in the simulation.
-    #[must_use]
-    pub fn entity_count(&self) -> usize {
-        self.entities.len()
+    /// Removes an entity, returning it with its anchor data.
+    ///
+    /// Group memberships are dropped with the entity. Removal is refused
+    /// while events or recurring rules target the entity or relationships
+    /// involve it; events it is only the source of do not block removal.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RemoveEntityError::UnknownEntity` if the entity is not in
+    /// the simulation, or `RemoveEntityError::Referenced` listing the
+    /// events and relationships to remove first. The simulation is
+    /// unchanged on error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::{RemoveEntityError, Simulation};
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::enums::{EventType, Species};
+    /// use behavioral_pathways::types::{EventId, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new().id("person").species(Species::Human).build().unwrap();
+    /// let id = sim.add_entity(entity, reference);
+    /// let loss = EventBuilder::new(EventType::Loss)
+    ///     .id(EventId::new("loss").unwrap())
+    ///     .target(id.clone())
+    ///     .build()
+    ///     .unwrap();
+    /// sim.add_event(loss, reference);
+    ///
+    /// assert!(matches!(
+    ///     sim.remove_entity(&id),
+    ///     Err(RemoveEntityError::Referenced { .. })
+    /// ));
+    /// sim.remove_event(&EventId::new("loss").unwrap());
+    /// assert!(sim.remove_entity(&id).is_ok());
+    /// assert_eq!(sim.entity_count(), 0);
+    /// ```
+    pub fn remove_entity(&mut self, id: &EntityId) -> Result<AnchoredEntity, RemoveEntityError> {
+        removal::remove_entity(self, id)
     }
 
-    /// Returns the anchored entity for the given ID.
-    #[must_use]
-    pub fn get_anchored_entity(&self, id: &EntityId) -> Option<&AnchoredEntity> {
-        self.entities.get(id)
+    /// Removes an entity and its group memberships without checking for
+    /// references to it.
+    pub(crate) fn take_entity(&mut self, id: &EntityId) -> Option<AnchoredEntity> {
+        let anchored = self.entities.remove(id)?;
+        self.events_by_target.remove(id);
+        self.groups.retain(|_, members| {
+            members.retain(|member| member != id);
+            !members.is_empty()
+        });
+        Some(Arc::unwrap_or_clone(anchored))
     }
 
-    /// Returns a mutable reference to the anchored entity.
-    pub fn get_anchored_entity_mut(&mut self, id: &EntityId) -> Option<&mut AnchoredEntity> {
-        self.entities.get_mut(id)
+    /// Returns a fresh generation for an entity's anchor data.
+    fn next_generation(&mut self) -> u64 {
+        self.generation_counter += 1;
+        self.generation_counter
     }
 
-    // --- Event Management ---
-
-    /// Adds an event to the simulation with its timestamp.
+    /// Records an observed state of an entity at a timestamp.
     ///
-    /// Events are applied during state computation when their timestamp
-    /// falls within the query range.
+    /// Later `state_at()` queries project from the nearest anchor or
+    /// observation at or before the queried timestamp, so an observation
+    /// years after the anchor is not contradicted by a long projection.
+    /// An observation replaces any earlier one at the same timestamp.
+    ///
+    /// Returns `false` without recording anything if the entity is unknown
+    /// or `timestamp` is the entity's anchor timestamp.
     ///
     /// # Examples
     ///
     /// ```
     /// use behavioral_pathways::simulation::Simulation;
-    /// use behavioral_pathways::event::EventBuilder;
-    /// use behavioral_pathways::types::{Timestamp, EntityId};
-    /// use behavioral_pathways::enums::EventType;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::enums::{MoodPath, Species, StatePath};
+    /// use behavioral_pathways::state::IndividualState;
+    /// use behavioral_pathways::types::{Duration, Timestamp};
     ///
     /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
     /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new().id("person_001").species(Species::Human).build().unwrap();
+    /// let id = sim.add_entity(entity, reference);
     ///
-    /// let target = EntityId::new("person_001").unwrap();
-    /// let event = EventBuilder::new(EventType::SocialExclusion)
-    ///     .target(target.clone())
-    ///     .severity(0.7)
-    ///     .build()
-    ///     .unwrap();
+    /// let mut observed = IndividualState::new();
+    /// observed.mood_mut().add_valence_delta(-0.4);
+    /// let later = reference + Duration::years(2);
+    /// assert!(sim.add_observation(&id, observed, later));
     ///
-    /// let event_time = Timestamp::from_ymd_hms(2024, 1, 15, 14, 0, 0);
-    /// sim.add_event(event, event_time);
+    /// let state = sim.entity(&id).unwrap().state_at(later);
+    /// let valence = state.get_effective(StatePath::Mood(MoodPath::Valence));
+    /// assert!((valence + 0.4).abs() < 1e-6);
     /// ```
-    pub fn add_event(&mut self, event: Event, timestamp: Timestamp) {
-        self.events.push(TimestampedEvent::new(event, timestamp));
-
-        let last_event = self
-            .events
-            .last()
-            .expect("event just pushed should be present");
-        for relationship in self.relationships.values_mut() {
-            if last_event.timestamp() < relationship.formed_timestamp() {
-                continue;
+    pub fn add_observation(
+        &mut self,
+        entity_id: &EntityId,
+        state: IndividualState,
+        timestamp: Timestamp,
+    ) -> bool {
+        let generation = self.generation_counter + 1;
+        match self.entities.get_mut(entity_id).map(Arc::make_mut) {
+            Some(anchored) if anchored.anchor_timestamp() != timestamp => {
+                anchored.insert_observation(Observation::new(state, timestamp));
+                anchored.generation = generation;
+                self.generation_counter = generation;
+                true
             }
-            let rel_slice = std::slice::from_mut(relationship.relationship_mut());
-            process_event_to_relationships(last_event.event(), last_event.timestamp(), rel_slice);
+            _ => false,
         }
     }
 
-    /// Returns all events that target the given entity.
+    /// Adds an alert from an outside system for the given entity.
     ///
-    /// Events are returned in no particular order. Use `events_between`
-    /// for time-range queries.
-    #[must_use]
-    pub fn events_for(&self, entity_id: &EntityId) -> Vec<&TimestampedEvent> {
+    /// The alert is marked `AlertProvenance::External` and is merged with
+    /// model-generated alerts by `ComputedState::alerts()` and
+    /// `EntityQueryHandle::alerts_between()`. An alert with a crisis window
+    /// also gates model behavior: see `ComputedState::is_in_crisis()`.
+    ///
+    /// Returns false if the entity is not in the simulation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::enums::{AlertProvenance, AlertSeverity, AlertTrigger, Species};
+    /// use behavioral_pathways::types::{Alert, Duration, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new().id("person_001").species(Species::Human).build().unwrap();
+    /// let id = sim.add_entity(entity, reference);
+    ///
+    /// let flag = Alert::builder()
+    ///     .severity(AlertSeverity::Critical)
+    ///     .trigger(AlertTrigger::custom("clinician_flag"))
+    ///     .note("Acute risk reported at intake")
+    ///     .crisis_window(Duration::days(14))
+    ///     .build()
+    ///     .unwrap();
+    /// let flagged_at = reference + Duration::days(10);
+    /// assert!(sim.add_external_alert(&id, flag, flagged_at));
+    ///
+    /// let state = sim.entity(&id).unwrap().state_at(flagged_at + Duration::days(1));
+    /// assert_eq!(state.alerts()[0].provenance(), AlertProvenance::External);
+    /// assert!(state.is_in_crisis());
+    /// ```
+    pub fn add_external_alert(
+        &mut self,
+        entity_id: &EntityId,
+        mut alert: Alert,
+        at: Timestamp,
+    ) -> bool {
+        let Some(anchored) = self.entities.get_mut(entity_id).map(Arc::make_mut) else {
+            return false;
+        };
+        alert.set_provenance(AlertProvenance::External);
+        anchored.insert_external_alert(TimestampedAlert::new(alert, at));
+        true
+    }
+
+    /// Schedules a change to an entity's ecological context.
+    ///
+    /// The context the entity was added with holds until its first change.
+    /// `state_at()` replays every change at or before the queried
+    /// timestamp, so earlier queries see the context without it, and
+    /// context effects accrue under whichever context held at each point
+    /// of the projection. Changes at the same timestamp apply in the order
+    /// added.
+    ///
+    /// Returns false if the entity is not in the simulation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::context::{Microsystem, WorkContext};
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::enums::Species;
+    /// use behavioral_pathways::simulation::{ContextChange, Simulation};
+    /// use behavioral_pathways::types::{Duration, MicrosystemId, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2020, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new().id("person_001").species(Species::Human).build().unwrap();
+    /// let id = sim.add_entity(entity, reference);
+    ///
+    /// let new_job = ContextChange::AddMicrosystem {
+    ///     id: MicrosystemId::new("work_acme").unwrap(),
+    ///     microsystem: Microsystem::new_work(WorkContext::default()),
+    /// };
+    /// let hired = reference + Duration::days(90);
+    /// assert!(sim.add_context_change(&id, new_job, hired));
+    ///
+    /// let handle = sim.entity(&id).unwrap();
+    /// assert_eq!(handle.state_at(reference).context().microsystem_count(), 0);
+    /// assert_eq!(handle.state_at(hired).context().microsystem_count(), 1);
+    /// ```
+    pub fn add_context_change(
+        &mut self,
+        entity_id: &EntityId,
+        change: ContextChange,
+        timestamp: Timestamp,
+    ) -> bool {
+        let generation = self.generation_counter + 1;
+        let Some(anchored) = self.entities.get_mut(entity_id).map(Arc::make_mut) else {
+            return false;
+        };
+        anchored.insert_context_change(TimestampedContextChange::new(change, timestamp));
+        anchored.generation = generation;
+        self.generation_counter = generation;
+        true
+    }
+
+    /// Returns every entity's alerts at `at`, riskiest entities first.
+    ///
+    /// Only alerts at or above `min_severity` are reported, and entities
+    /// without any are left out. Entities are ranked by their number of
+    /// Critical, then Warning, then Info alerts, with ties broken by ID;
+    /// each entity's alerts are listed most severe first. Same as
+    /// `alert_sweep_with` and a default `AlertSweepConfig`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::enums::{AlertSeverity, AlertTrigger, Species};
+    /// use behavioral_pathways::types::{Alert, Duration, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// for id in ["ana", "ben", "cas"] {
+    ///     let entity = EntityBuilder::new().id(id).species(Species::Human).build().unwrap();
+    ///     sim.add_entity(entity, reference);
+    /// }
+    /// let flag = |severity| {
+    ///     Alert::builder()
+    ///         .severity(severity)
+    ///         .trigger(AlertTrigger::custom("clinician_flag"))
+    ///         .build()
+    ///         .unwrap()
+    /// };
+    /// let ids: Vec<_> = sim.entities().map(|a| a.entity().id().clone()).collect();
+    /// sim.add_external_alert(&ids[0], flag(AlertSeverity::Warning), reference);
+    /// sim.add_external_alert(&ids[1], flag(AlertSeverity::Critical), reference);
+    ///
+    /// let sweep = sim.alert_sweep(reference + Duration::days(1), AlertSeverity::Warning);
+    /// assert_eq!(sweep.len(), 2);
+    /// assert!(sweep[0].1[0].is_critical());
+    /// ```
+    #[must_use]
+    pub fn alert_sweep(
+        &self,
+        at: Timestamp,
+        min_severity: AlertSeverity,
+    ) -> Vec<(EntityId, Vec<Alert>)> {
+        alert_sweep::alert_sweep(self, at, min_severity, &AlertSweepConfig::default())
+    }
+
+    /// Like `alert_sweep`, limited to some entities or to thresholds
+    /// crossed since a previous sweep. See `AlertSweepConfig`.
+    #[must_use]
+    pub fn alert_sweep_with(
+        &self,
+        at: Timestamp,
+        min_severity: AlertSeverity,
+        config: &AlertSweepConfig,
+    ) -> Vec<(EntityId, Vec<Alert>)> {
+        alert_sweep::alert_sweep(self, at, min_severity, config)
+    }
+
+    /// Returns every entity's state at `timestamp`, keyed by entity ID.
+    ///
+    /// Each state matches `self.entity(id).state_at(timestamp)`. With the
+    /// `parallel` feature, entities are evaluated on scoped threads.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::enums::Species;
+    /// use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// for id in ["ana", "ben"] {
+    ///     let entity = EntityBuilder::new().id(id).species(Species::Human).build().unwrap();
+    ///     sim.add_entity(entity, reference);
+    /// }
+    ///
+    /// let states = sim.states_at(reference + Duration::days(1));
+    /// assert_eq!(states.len(), 2);
+    /// assert!(states.contains_key(&EntityId::new("ben").unwrap()));
+    /// ```
+    #[must_use]
+    pub fn states_at(&self, timestamp: Timestamp) -> HashMap<EntityId, ComputedState> {
+        let ids: Vec<EntityId> = self.entities.keys().cloned().collect();
+        population::states_at(self, &ids, timestamp)
+    }
+
+    /// Like `states_at`, for the given entities only. Unknown IDs are
+    /// skipped.
+    #[must_use]
+    pub fn states_at_for(
+        &self,
+        entities: &[EntityId],
+        timestamp: Timestamp,
+    ) -> HashMap<EntityId, ComputedState> {
+        population::states_at(self, entities, timestamp)
+    }
+
+    /// Returns an owned reference to the given entity.
+    ///
+    /// Unlike `entity()`, the reference does not borrow the simulation, so
+    /// it can be held across mutations. See `EntityRef`.
+    #[must_use]
+    pub fn entity_ref(&self, id: &EntityId) -> Option<EntityRef> {
+        self.entities
+            .get(id)
+            .map(|anchored| EntityRef::new(id.clone(), anchored.generation()))
+    }
+
+    /// Returns a query handle for the given entity ID.
+    ///
+    /// The handle provides the `state_at()` method for querying state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::types::{Timestamp, EntityId};
+    /// use behavioral_pathways::enums::Species;
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    ///
+    /// let entity = EntityBuilder::new()
+    ///     .id("person_001")
+    ///     .species(Species::Human)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// sim.add_entity(entity, reference);
+    ///
+    /// let handle = sim.entity(&EntityId::new("person_001").unwrap());
+    /// assert!(handle.is_some());
+    ///
+    /// let unknown = sim.entity(&EntityId::new("unknown").unwrap());
+    /// assert!(unknown.is_none());
+    /// ```
+    #[must_use]
+    pub fn entity(&self, id: &EntityId) -> Option<EntityQueryHandle<'_>> {
+        if self.entities.contains_key(id) {
+            Some(EntityQueryHandle::new(self, id.clone()))
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over all anchored entities.
+    pub fn entities(&self) -> impl Iterator<Item = &AnchoredEntity> {
+        self.entities.values().map(|anchored| &**anchored)
+    }
+
+    /// Returns the number of entities in the simulation.
+    #[must_use]
+    pub fn entity_count(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Returns the anchored entity for the given ID.
+    #[must_use]
+    pub fn get_anchored_entity(&self, id: &EntityId) -> Option<&AnchoredEntity> {
+        self.entities.get(id).map(|anchored| &**anchored)
+    }
+
+    /// Returns a mutable reference to the anchored entity.
+    ///
+    /// The entity is assigned a new generation, since it may be changed
+    /// through the returned reference.
+    pub fn get_anchored_entity_mut(&mut self, id: &EntityId) -> Option<&mut AnchoredEntity> {
+        let generation = self.generation_counter + 1;
+        let anchored = Arc::make_mut(self.entities.get_mut(id)?);
+        anchored.generation = generation;
+        self.generation_counter = generation;
+        Some(anchored)
+    }
+
+    /// Returns a mutable reference to the anchored entity without
+    /// assigning a new generation, for changes that leave every state
+    /// query unchanged.
+    pub(crate) fn anchored_mut(&mut self, id: &EntityId) -> Option<&mut AnchoredEntity> {
+        self.entities.get_mut(id).map(Arc::make_mut)
+    }
+
+    // --- Event Management ---
+
+    /// Adds an event to the simulation with its timestamp.
+    ///
+    /// Events are applied during state computation when their timestamp
+    /// falls within the query range. The event's witnesses take a share of
+    /// its impact scaled by their empathy; see `InterpretationRole`. With
+    /// cascades enabled, the follow-on events the event sets off are added
+    /// after it; see `enable_cascades`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::types::{Timestamp, EntityId};
+    /// use behavioral_pathways::enums::EventType;
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    ///
+    /// let target = EntityId::new("person_001").unwrap();
+    /// let event = EventBuilder::new(EventType::SocialExclusion)
+    ///     .target(target.clone())
+    ///     .severity(0.7)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let event_time = Timestamp::from_ymd_hms(2024, 1, 15, 14, 0, 0);
+    /// sim.add_event(event, event_time);
+    /// ```
+    pub fn add_event(&mut self, event: Event, timestamp: Timestamp) {
+        let te = TimestampedEvent::new(event, timestamp);
+        let cascade = cascade::cascade_from(&self.cascade_rules, &te);
+        self.insert_event(te);
+        for follow_on in cascade {
+            self.insert_event(follow_on);
+        }
+    }
+
+    /// Adds an event at a specific timestamp, as `add_event`, after checking
+    /// that its source, target, and witnesses are all entities in the
+    /// simulation or declared external entities.
+    ///
+    /// `add_event` accepts events for entities not added yet, leaving
+    /// `validate` to report them; use this to reject them up front instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SimulationBuildError::EventReferencesUnknownEntity`, adding
+    /// nothing, for the first unknown source, target, or witness.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::{Simulation, SimulationBuildError};
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::enums::{EventType, Species};
+    /// use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new().id("person").species(Species::Human).build().unwrap();
+    /// let id = sim.add_entity(entity, reference);
+    ///
+    /// let stranger = EntityId::new("stranger").unwrap();
+    /// let event = EventBuilder::new(EventType::Conflict)
+    ///     .source(stranger.clone())
+    ///     .target(id.clone())
+    ///     .build()
+    ///     .unwrap();
+    /// let result = sim.try_add_event(event, reference + Duration::days(1));
+    /// assert!(matches!(
+    ///     result,
+    ///     Err(SimulationBuildError::EventReferencesUnknownEntity(_, ref unknown)) if *unknown == stranger
+    /// ));
+    /// assert!(sim.events_for(&id).is_empty());
+    /// ```
+    pub fn try_add_event(
+        &mut self,
+        event: Event,
+        timestamp: Timestamp,
+    ) -> Result<(), SimulationBuildError> {
+        self.check_event_entities(&event)?;
+        self.add_event(event, timestamp);
+        Ok(())
+    }
+
+    /// Returns an error for the first source, target, or witness of `event`
+    /// that is not a known entity; shared by `try_add_event` and
+    /// `add_events`.
+    fn check_event_entities(&self, event: &Event) -> Result<(), SimulationBuildError> {
+        event_errors(event, |id| self.is_known_entity(id))
+            .into_iter()
+            .next()
+            .map_or(Ok(()), Err)
+    }
+
+    /// Returns true if `id` is an entity in the simulation or a declared
+    /// external entity.
+    pub(crate) fn is_known_entity(&self, id: &EntityId) -> bool {
+        self.entities.contains_key(id) || self.external_entities.contains(id)
+    }
+
+    /// Stores an event, indexing it by target and witnesses and recording
+    /// it on their compacted histories and on relationships.
+    fn insert_event(&mut self, te: TimestampedEvent) {
+        for entity_id in experiencing_entities(te.event()) {
+            let positions = self.events_by_target.entry(entity_id.clone()).or_default();
+            let index = positions.partition_point(|&position| {
+                self.events[position as usize].timestamp <= te.timestamp
+            });
+            positions.insert(index, event_position(self.events.len()));
+        }
+        self.longest_ramp = self.longest_ramp.max(te.event().ramp_duration());
+        if te.event().is_anticipated() {
+            self.longest_anticipation = self
+                .longest_anticipation
+                .max(te.event().anticipation_window());
+        }
+        self.events.push(te);
+
+        let last_event = self
+            .events
+            .last()
+            .expect("event just pushed should be present");
+        let recognition = recognition::contradicted_recognition(self, last_event, None);
+        let sleep_loss = sleep_loss::preceding_sleep_loss(self, last_event, None)
+            .map(|loss| loss.event().clone());
+        let bereavement = last_event
+            .event()
+            .target()
+            .and_then(|target| self.get_anchored_entity(target))
+            .and_then(|anchored| {
+                bereavement::lost_relationship(self, last_event, anchored.entity())
+            });
+        let stochastic = self.stochastic_config;
+        if let Some(anchored) = last_event
+            .event()
+            .target()
+            .and_then(|target| self.entities.get_mut(target))
+            .map(Arc::make_mut)
+        {
+            compaction::absorb_late_event(
+                anchored,
+                last_event,
+                recognition,
+                sleep_loss,
+                bereavement,
+                stochastic,
+            );
+        }
+        // Recognition, sleep loss, and bereavement amplify only what the
+        // target lives through
+        for witness in last_event.event().witnesses() {
+            if last_event.event().target() == Some(witness) {
+                continue;
+            }
+            if let Some(anchored) = self.entities.get_mut(witness).map(Arc::make_mut) {
+                compaction::absorb_late_event(anchored, last_event, None, None, None, stochastic);
+            }
+        }
+        for relationship in self.relationships.values_mut() {
+            record_event_on_relationship(relationship, last_event);
+        }
+    }
+
+    /// Removes the first stored event with the given ID, returning it.
+    ///
+    /// Later `state_at()` queries no longer apply the event, and the trust
+    /// antecedents it recorded on relationships are withdrawn. The synthetic
+    /// events its cascade generated are removed with it. The per-entity
+    /// event index is rebuilt. An event folded into a compaction checkpoint
+    /// cannot be removed, and one added after compaction at or before the
+    /// cutoff stays in the target's checkpoint. Occurrences of recurring
+    /// events are not stored events and are not matched.
+    ///
+    /// Returns `None` if no stored event has the ID.
+    pub fn remove_event(&mut self, id: &EventId) -> Option<TimestampedEvent> {
+        let position = self.events.iter().position(|te| te.event().id() == id)?;
+        let removed = self.events[position].clone();
+
+        // Follow-ons are stored after their trigger, so one pass finds them
+        let mut triggers: HashSet<&EventId> = HashSet::from([id]);
+        let mut positions = vec![position];
+        for (later, te) in self.events.iter().enumerate().skip(position + 1) {
+            if te
+                .cascade_trigger()
+                .is_some_and(|trigger| triggers.contains(trigger))
+            {
+                triggers.insert(te.event().id());
+                positions.push(later);
+            }
+        }
+        self.remove_positions(&positions);
+        Some(removed)
+    }
+
+    /// Removes the events at the given positions and withdraws what they
+    /// recorded on relationships.
+    fn remove_positions(&mut self, positions: &[usize]) {
+        let removed: Vec<TimestampedEvent> = positions
+            .iter()
+            .map(|&position| self.events[position].clone())
+            .collect();
+        self.remove_events(positions);
+        for relationship in self.relationships.values_mut() {
+            for te in &removed {
+                unrecord_event_on_relationship(relationship, te, &self.events);
+            }
+        }
+    }
+
+    /// Expands events into cascades: each rule follows a triggering event
+    /// with a copy of its template event, a fixed delay later.
+    ///
+    /// Replaces any earlier rules. Synthetic events generated under them
+    /// are removed, and every stored event is expanded under the new
+    /// rules, in the order it was added, as are events added later. A
+    /// follow-on can trigger rules in turn, for at most
+    /// `MAX_CASCADE_DEPTH` generations. Pass no rules to disable cascades.
+    ///
+    /// Follow-on events are stored like events added with `add_event`, so
+    /// `state_at()` applies them and `events_for()` lists them, flagged by
+    /// `TimestampedEvent::is_synthetic()`. Occurrences of recurring events
+    /// do not trigger rules.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::{CascadeRule, Simulation};
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::enums::{EventType, Species};
+    /// use behavioral_pathways::types::{Duration, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new().id("person").species(Species::Human).build().unwrap();
+    /// let id = sim.add_entity(entity, reference);
+    ///
+    /// let conflict = EventBuilder::new(EventType::Conflict).severity(0.5).build().unwrap();
+    /// sim.enable_cascades(vec![CascadeRule::new(
+    ///     EventType::Betrayal,
+    ///     0.6,
+    ///     conflict,
+    ///     Duration::days(3),
+    /// )]);
+    ///
+    /// let betrayal = EventBuilder::new(EventType::Betrayal)
+    ///     .target(id.clone())
+    ///     .severity(0.8)
+    ///     .build()
+    ///     .unwrap();
+    /// sim.add_event(betrayal, reference + Duration::days(1));
+    ///
+    /// let events = sim.events_for(&id);
+    /// assert_eq!(events.len(), 2);
+    /// assert!(events[1].is_synthetic());
+    /// assert_eq!(events[1].timestamp(), reference + Duration::days(4));
+    /// ```
+    pub fn enable_cascades(&mut self, rules: Vec<CascadeRule>) {
+        let synthetic: Vec<usize> = self
+            .events
+            .iter()
+            .enumerate()
+            .filter(|(_, te)| te.is_synthetic())
+            .map(|(position, _)| position)
+            .collect();
+        self.remove_positions(&synthetic);
+
+        self.cascade_rules = rules;
+        let roots = self.events.clone();
+        for root in &roots {
+            for follow_on in cascade::cascade_from(&self.cascade_rules, root) {
+                self.insert_event(follow_on);
+            }
+        }
+    }
+
+    /// Returns the cascade rules, in the order they were given.
+    #[must_use]
+    pub fn cascade_rules(&self) -> &[CascadeRule] {
+        &self.cascade_rules
+    }
+
+    /// Adds an event that repeats `count` times, `interval` apart, the
+    /// first at `start`.
+    ///
+    /// The rule is stored once and its occurrences are expanded only when
+    /// a state query needs them. Each occurrence applies to the event's
+    /// target as if it had been added with `add_event`; see
+    /// `RecurringEvent` for how occurrences differ from stored events.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::enums::{EventType, Species};
+    /// use behavioral_pathways::types::{Duration, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new().id("person").species(Species::Human).build().unwrap();
+    /// let id = sim.add_entity(entity, reference);
+    ///
+    /// let therapy = EventBuilder::new(EventType::Support).target(id.clone()).build().unwrap();
+    /// sim.add_recurring_event(therapy, reference + Duration::weeks(1), Duration::weeks(1), 104);
+    ///
+    /// assert_eq!(sim.recurring_events().len(), 1);
+    /// let handle = sim.entity(&id).unwrap();
+    /// let after = handle.state_at(reference + Duration::years(1));
+    /// assert_ne!(after.individual_state(), handle.state_at(reference).individual_state());
+    /// ```
+    pub fn add_recurring_event(
+        &mut self,
+        event: Event,
+        start: Timestamp,
+        interval: Duration,
+        count: u32,
+    ) {
+        self.insert_recurring_event(RecurringEvent::new(event, start, interval, count));
+    }
+
+    /// Adds an event that repeats `interval` apart from `start` through
+    /// `until`.
+    ///
+    /// Otherwise the same as `add_recurring_event`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is zero.
+    pub fn add_recurring_event_until(
+        &mut self,
+        event: Event,
+        start: Timestamp,
+        interval: Duration,
+        until: Timestamp,
+    ) {
+        self.insert_recurring_event(RecurringEvent::until(event, start, interval, until));
+    }
+
+    /// Stores a recurring event rule.
+    ///
+    /// An entity compacted past the first occurrences, or their
+    /// anticipation, applies them to its checkpoint, as `add_event` does
+    /// for a late event.
+    fn insert_recurring_event(&mut self, rule: RecurringEvent) {
+        let stochastic = self.stochastic_config;
+        if let Some(anchored) = rule
+            .target()
+            .and_then(|target| self.entities.get_mut(target))
+            .map(Arc::make_mut)
+        {
+            let resume_at = anchored
+                .compacted_history()
+                .and_then(CompactedHistory::checkpoint)
+                .map(|checkpoint| checkpoint.resume_at());
+            if let Some(resume_at) = resume_at {
+                let lookahead = rule.event().anticipation_window();
+                for occurrence in rule.occurrences_in(None, resume_at + lookahead) {
+                    compaction::absorb_late_event(
+                        anchored,
+                        &occurrence,
+                        None,
+                        None,
+                        None,
+                        stochastic,
+                    );
+                }
+            }
+        }
+        self.recurring_events.push(rule);
+    }
+
+    /// Returns the recurring event rules, in the order they were added.
+    #[must_use]
+    pub fn recurring_events(&self) -> &[RecurringEvent] {
+        &self.recurring_events
+    }
+
+    /// Returns the recurring event rules targeting an entity, in the order
+    /// they were added.
+    pub(crate) fn recurring_events_for<'s>(
+        &'s self,
+        entity_id: &'s EntityId,
+    ) -> impl Iterator<Item = &'s RecurringEvent> {
+        self.recurring_events
+            .iter()
+            .filter(move |rule| rule.target() == Some(entity_id))
+    }
+
+    /// Adds a batch of events, such as a life history.
+    ///
+    /// Every source, target, and witness is checked, as by
+    /// `try_add_event`, before anything is added: each must be an entity in
+    /// the simulation or a declared external entity.
+    /// The events are then added in timestamp order (ties keep their order
+    /// in `events`), each exactly as `add_event` would add it, so building
+    /// a history this way or event by event gives the same states.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SimulationBuildError::EventReferencesUnknownEntity`, adding
+    /// nothing, for the first event whose source, target, or witness is
+    /// unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::enums::{EventType, Species};
+    /// use behavioral_pathways::types::{Duration, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new().id("person").species(Species::Human).build().unwrap();
+    /// let id = sim.add_entity(entity, reference);
+    ///
+    /// let events = (1..=3)
+    ///     .map(|year| {
+    ///         let event = EventBuilder::new(EventType::Achievement)
+    ///             .target(id.clone())
+    ///             .severity(0.5)
+    ///             .build()
+    ///             .unwrap();
+    ///         (event, reference + Duration::years(year))
+    ///     })
+    ///     .collect();
+    /// sim.add_events(events).unwrap();
+    /// assert_eq!(sim.events_for(&id).len(), 3);
+    /// ```
+    pub fn add_events(
+        &mut self,
+        mut events: Vec<(Event, Timestamp)>,
+    ) -> Result<(), SimulationBuildError> {
+        for (event, _) in &events {
+            self.check_event_entities(event)?;
+        }
+        events.sort_by_key(|(_, timestamp)| *timestamp);
+        self.events.reserve(events.len());
+        for (event, timestamp) in events {
+            self.add_event(event, timestamp);
+        }
+        Ok(())
+    }
+
+    /// Adds an event experienced by every entity in `scope`.
+    ///
+    /// An `Individual` scope targets the named entity, like `add_event`.
+    /// A `Group`, `Microsystem`, or `Global` scope fans the event out as one
+    /// copy per recipient, each interpreted through that recipient's own
+    /// personality and developmental stage. A broadcast reaches entities
+    /// already in the simulation and anchored at or before `timestamp`,
+    /// other than the event's source; see `add_group_member` for groups.
+    ///
+    /// Returns the IDs of the events added, ordered by recipient. A copy's
+    /// ID is the event's ID followed by `_` and the recipient's ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ScopedEventError`, adding nothing, if the event targets an
+    /// entity other than an individual scope's or has any target under a
+    /// broadcast scope, if its microsystem context differs from a
+    /// microsystem scope, or if a group scope names a group with no
+    /// members.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::enums::{EventScope, EventType, Species};
+    /// use behavioral_pathways::types::{Duration, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// for id in ["ana", "ben"] {
+    ///     let entity = EntityBuilder::new().id(id).species(Species::Human).build().unwrap();
+    ///     sim.add_entity(entity, reference);
+    /// }
+    ///
+    /// let lockdown = EventBuilder::new(EventType::PolicyChange).severity(0.7).build().unwrap();
+    /// let added = sim
+    ///     .add_scoped_event(lockdown, EventScope::Global, reference + Duration::days(30))
+    ///     .unwrap();
+    /// assert_eq!(added.len(), 2);
+    /// ```
+    pub fn add_scoped_event(
+        &mut self,
+        event: Event,
+        scope: EventScope,
+        timestamp: Timestamp,
+    ) -> Result<Vec<EventId>, ScopedEventError> {
+        scope::add_scoped_event(self, event, scope, timestamp)
+    }
+
+    /// Adds an entity to a group, for group-scoped events.
+    ///
+    /// Adding a member twice has no effect. Membership applies to scoped
+    /// events added afterwards.
+    pub fn add_group_member(&mut self, group: GroupId, entity: EntityId) {
+        let members = self.groups.entry(group).or_default();
+        if !members.contains(&entity) {
+            members.push(entity);
+        }
+    }
+
+    /// Adds `members` to `group` and fans `event` out to the group.
+    ///
+    /// Shorthand for `add_group_member` for each member followed by
+    /// `add_scoped_event` with `EventScope::Group`: every member anchored
+    /// at or before `timestamp`, other than the event's source, gets its
+    /// own copy, interpreted through its own personality. Each copy
+    /// records the group, and membership persists for later group events.
+    ///
+    /// Returns the IDs of the events added, ordered by recipient.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ScopedEventError`, adding no events, if the event has a
+    /// target or a different group, or if the group has no members.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::enums::{EventType, Species};
+    /// use behavioral_pathways::types::{Duration, GroupId, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let household: Vec<_> = ["parent", "child"]
+    ///     .into_iter()
+    ///     .map(|id| {
+    ///         let entity = EntityBuilder::new().id(id).species(Species::Human).build().unwrap();
+    ///         sim.add_entity(entity, reference)
+    ///     })
+    ///     .collect();
+    ///
+    /// let eviction = EventBuilder::new(EventType::Loss).severity(0.8).build().unwrap();
+    /// let family = GroupId::new("family").unwrap();
+    /// let added = sim
+    ///     .add_group_event(eviction, family.clone(), &household, reference + Duration::days(7))
+    ///     .unwrap();
+    /// assert_eq!(added.len(), 2);
+    /// assert_eq!(sim.group_members(&family), household.as_slice());
+    /// ```
+    pub fn add_group_event(
+        &mut self,
+        event: Event,
+        group: GroupId,
+        members: &[EntityId],
+        timestamp: Timestamp,
+    ) -> Result<Vec<EventId>, ScopedEventError> {
+        for member in members {
+            self.add_group_member(group.clone(), member.clone());
+        }
+        self.add_scoped_event(event, EventScope::Group(group), timestamp)
+    }
+
+    /// Returns the members of a group, in the order they were added.
+    #[must_use]
+    pub fn group_members(&self, group: &GroupId) -> &[EntityId] {
+        self.groups.get(group).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns every group with its members, in no particular order.
+    pub(crate) fn groups(&self) -> impl Iterator<Item = (&GroupId, &[EntityId])> {
+        self.groups
+            .iter()
+            .map(|(group, members)| (group, members.as_slice()))
+    }
+
+    /// Records an interaction between two entities at `at`.
+    ///
+    /// Adds an `Interaction` event targeting each party with the other as
+    /// its source, so each interprets it individually and each direction of
+    /// their relationship registers the contact once. `quality` (clamped to
+    /// 0-1) is the events' severity. When `memory_formation()` is enabled,
+    /// each simulated party also remembers the interaction, linked to the
+    /// other party and to their own event.
+    ///
+    /// Either party may be external; it gets no state or memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::enums::{InteractionTopic, Species};
+    /// use behavioral_pathways::types::{Duration, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let alice = EntityBuilder::new().id("alice").species(Species::Human).build().unwrap();
+    /// let bob = EntityBuilder::new().id("bob").species(Species::Human).build().unwrap();
+    /// let alice = sim.add_entity(alice, reference);
+    /// let bob = sim.add_entity(bob, reference);
+    /// sim.set_memory_formation(true);
+    ///
+    /// let at = reference + Duration::days(62);
+    /// let recorded =
+    ///     sim.record_interaction(&alice, &bob, at, InteractionTopic::DeepConversation, 90, 0.8);
+    ///
+    /// assert_eq!(sim.events_for(&alice).len(), 1);
+    /// # #[cfg(feature = "memory")]
+    /// assert_eq!(recorded.memories().len(), 2);
+    /// ```
+    pub fn record_interaction(
+        &mut self,
+        a: &EntityId,
+        b: &EntityId,
+        at: Timestamp,
+        topic: InteractionTopic,
+        duration_minutes: u32,
+        quality: f64,
+    ) -> RecordedInteraction {
+        interaction::record_interaction(self, a, b, at, topic, duration_minutes, quality)
+    }
+
+    /// Returns all events that target or are witnessed by the given entity.
+    ///
+    /// Events are sorted by timestamp, with ties in the order they were
+    /// added. Synthetic events generated by cascades are included. Use
+    /// `events_between` for time-range queries.
+    #[must_use]
+    pub fn events_for(&self, entity_id: &EntityId) -> Vec<&TimestampedEvent> {
+        self.events_by_target
+            .get(entity_id)
+            .map_or_else(Vec::new, |positions| {
+                positions
+                    .iter()
+                    .map(|&index| &self.events[index as usize])
+                    .collect()
+            })
+    }
+
+    /// Returns all stored events matching `filter`, the same filter an
+    /// `EventBus` subscription takes.
+    ///
+    /// Events are sorted by timestamp, with ties in the order they were
+    /// added. Synthetic events generated by cascades are included;
+    /// occurrences of recurring events are not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::enums::{EventCategory, EventType};
+    /// use behavioral_pathways::event::{EventBuilder, EventFilter};
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let target = EntityId::new("person").unwrap();
+    /// for (event_type, severity) in [(EventType::Violence, 0.9), (EventType::Support, 0.9)] {
+    ///     let event = EventBuilder::new(event_type)
+    ///         .target(target.clone())
+    ///         .severity(severity)
+    ///         .build()
+    ///         .unwrap();
+    ///     sim.add_event(event, reference + Duration::days(1));
+    /// }
+    ///
+    /// let trauma = sim.events_matching(&EventFilter::category(EventCategory::Trauma));
+    /// assert_eq!(trauma.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn events_matching(&self, filter: &EventFilter) -> Vec<&TimestampedEvent> {
+        let mut events: Vec<&TimestampedEvent> = self
+            .events
+            .iter()
+            .filter(|te| filter.matches(te.event()))
+            .collect();
+        events.sort_by_key(|te| te.timestamp);
+        events
+    }
+
+    /// Returns all events between the start and end timestamps (inclusive).
+    ///
+    /// Events are returned in no particular order.
+    #[must_use]
+    pub fn events_between(&self, start: Timestamp, end: Timestamp) -> Vec<&TimestampedEvent> {
         self.events
             .iter()
-            .filter(|te| te.event.target() == Some(entity_id))
+            .filter(|te| te.timestamp >= start && te.timestamp <= end)
             .collect()
     }
 
-    /// Returns all events between the start and end timestamps (inclusive).
+    /// Returns all events in the simulation.
+    pub fn all_events(&self) -> impl Iterator<Item = &TimestampedEvent> {
+        self.events.iter()
+    }
+
+    /// Folds every entity's fully decayed events from before `before`.
+    ///
+    /// An event is folded when all of its increments landed before the
+    /// cutoff and, applied alone, it changes no state path by more than
+    /// `COMPACTION_EPSILON` by the cutoff. Trauma, formative base shifts,
+    /// chronic-pattern events, role exits, health changes, caregiving,
+    /// witnessed events, and contact between related entities are always
+    /// kept.
+    ///
+    /// Folded events are removed from the event log. Each entity keeps a
+    /// checkpoint of its decayed state at the cutoff, so `state_at()` at or
+    /// after the cutoff returns exactly what it did before. Earlier queries
+    /// are `RegressionQuality::Approximate`, with
+    /// `ApproximationReason::CompactedHistory`.
+    ///
+    /// Compaction assumes the history before the cutoff is final: an
+    /// anchor or observation changed afterwards no longer sees the folded
+    /// events.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::enums::{EventType, Species};
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::types::{Duration, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new().id("person_001").species(Species::Human).build().unwrap();
+    /// let id = sim.add_entity(entity, reference);
+    ///
+    /// let event = EventBuilder::new(EventType::SocialExclusion)
+    ///     .target(id.clone())
+    ///     .severity(0.5)
+    ///     .build()
+    ///     .unwrap();
+    /// sim.add_event(event, reference + Duration::days(1));
+    ///
+    /// let cutoff = reference + Duration::years(2);
+    /// let later = cutoff + Duration::days(30);
+    /// let before = sim.entity(&id).unwrap().state_at(later);
+    ///
+    /// let report = sim.compact(cutoff);
+    /// assert_eq!(report.folded(), 1);
+    /// let after = sim.entity(&id).unwrap().state_at(later);
+    /// assert_eq!(after.individual_state(), before.individual_state());
+    /// ```
+    pub fn compact(&mut self, before: Timestamp) -> CompactionReport {
+        compaction::compact(self, before)
+    }
+
+    /// Moves an entity's anchor forward to `new_anchor`.
+    ///
+    /// The entity's state at `new_anchor` becomes a checkpoint that later
+    /// queries project from, so the event increments before it are not
+    /// replayed again, and its fully decayed events before it are folded
+    /// as `compact` does. Queries from `new_anchor` on return the same
+    /// states as before rebasing. Queries before it are flagged
+    /// `RegressionQuality::Approximate` with
+    /// `ApproximationReason::CompactedHistory`.
+    ///
+    /// The entity's `anchor_timestamp()` is left unchanged, since the
+    /// rebase installs a checkpoint rather than replacing the anchor
+    /// state. The entity gets a new generation, so a strict `EntityRef`
+    /// taken before the rebase reports it as stale.
+    ///
+    /// Rebasing to the current anchor records a checkpoint at it and
+    /// changes nothing else.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RebaseError::UnknownEntity` if the entity is not in the
+    /// simulation, and `RebaseError::BeforeAnchor` if `new_anchor` is
+    /// before its anchor or an earlier rebase or compaction cutoff.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::enums::{EventType, Species};
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::simulation::{RegressionQuality, Simulation};
+    /// use behavioral_pathways::types::{Duration, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new()
+    ///     .id("person_001")
+    ///     .species(Species::Human)
+    ///     .build()
+    ///     .unwrap();
+    /// let id = sim.add_entity(entity, reference);
+    ///
+    /// let event = EventBuilder::new(EventType::SocialExclusion)
+    ///     .target(id.clone())
+    ///     .severity(0.5)
+    ///     .build()
+    ///     .unwrap();
+    /// sim.add_event(event, reference + Duration::days(1));
+    ///
+    /// let new_anchor = reference + Duration::days(30);
+    /// let later = new_anchor + Duration::days(10);
+    /// let before = sim.entity(&id).unwrap().state_at(later);
+    ///
+    /// sim.rebase_entity(&id, new_anchor).unwrap();
+    /// let after = sim.entity(&id).unwrap().state_at(later);
+    /// assert_eq!(after.individual_state(), before.individual_state());
+    /// let earlier = sim.entity(&id).unwrap().state_at(reference + Duration::days(5));
+    /// assert_eq!(earlier.regression_quality(), RegressionQuality::Approximate);
+    /// ```
+    pub fn rebase_entity(
+        &mut self,
+        id: &EntityId,
+        new_anchor: Timestamp,
+    ) -> Result<(), RebaseError> {
+        compaction::rebase(self, id, new_anchor)
+    }
+
+    /// Returns the positions in the event log of the events targeting or
+    /// witnessed by an entity, sorted by timestamp with ties in the order
+    /// they were added.
+    pub(crate) fn event_positions_for(
+        &self,
+        entity_id: &EntityId,
+    ) -> impl Iterator<Item = usize> + '_ {
+        self.events_by_target
+            .get(entity_id)
+            .map_or(&[][..], Vec::as_slice)
+            .iter()
+            .map(|&position| position as usize)
+    }
+
+    /// Returns the events targeting an entity with timestamps in
+    /// `(after, through]`, sorted by timestamp.
+    ///
+    /// Binary-searches the entity's sorted positions, so the cost grows
+    /// with the events in range rather than the entity's whole history.
+    pub(crate) fn events_for_range(
+        &self,
+        entity_id: &EntityId,
+        after: Timestamp,
+        through: Timestamp,
+    ) -> impl Iterator<Item = &TimestampedEvent> {
+        let positions = self
+            .events_by_target
+            .get(entity_id)
+            .map_or(&[][..], Vec::as_slice);
+        let landed_by = |at: Timestamp| {
+            positions.partition_point(|&position| self.events[position as usize].timestamp <= at)
+        };
+        let start = landed_by(after);
+        let end = landed_by(through);
+        positions[start..end.max(start)]
+            .iter()
+            .map(|&position| &self.events[position as usize])
+    }
+
+    /// Returns how far an added event's effect can land from its
+    /// timestamp: the longest ramp after it and the longest anticipation
+    /// window before it.
+    pub(crate) fn event_reach(&self) -> (Duration, Duration) {
+        (self.longest_ramp, self.longest_anticipation)
+    }
+
+    /// Returns the event at a position in the event log.
+    pub(crate) fn event_at(&self, position: usize) -> &TimestampedEvent {
+        &self.events[position]
+    }
+
+    /// Removes the events at the given positions, keeping the order of the
+    /// rest.
+    pub(crate) fn remove_events(&mut self, positions: &[usize]) {
+        if positions.is_empty() {
+            return;
+        }
+        let removed: HashSet<usize> = positions.iter().copied().collect();
+        let mut position = 0;
+        self.events.retain(|_| {
+            let keep = !removed.contains(&position);
+            position += 1;
+            keep
+        });
+        self.events_by_target.clear();
+        for (position, te) in self.events.iter().enumerate() {
+            for entity_id in experiencing_entities(te.event()) {
+                self.events_by_target
+                    .entry(entity_id.clone())
+                    .or_default()
+                    .push(event_position(position));
+            }
+        }
+        for positions in self.events_by_target.values_mut() {
+            positions.sort_by_key(|&position| self.events[position as usize].timestamp);
+        }
+    }
+
+    // --- Branching ---
+
+    /// Returns an independent copy of the simulation for exploring a
+    /// counterfactual.
     ///
-    /// Events are returned in no particular order.
+    /// The branch starts with the same entities, events, relationships and
+    /// configuration, and changes to either side leave the other untouched.
+    /// Entity anchors are shared until one side modifies them, so a branch
+    /// copies the event list and relationships but not entity state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::enums::{EventType, Species};
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::types::{Duration, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let person = EntityBuilder::new()
+    ///     .id("person")
+    ///     .species(Species::Human)
+    ///     .build()
+    ///     .unwrap();
+    /// let id = sim.add_entity(person, reference);
+    ///
+    /// let mut branch = sim.branch();
+    /// let exclusion = EventBuilder::new(EventType::SocialExclusion)
+    ///     .target(id.clone())
+    ///     .severity(0.8)
+    ///     .build()
+    ///     .unwrap();
+    /// branch.add_event(exclusion, reference + Duration::days(1));
+    ///
+    /// assert!(sim.events_for(&id).is_empty());
+    /// assert_eq!(branch.events_for(&id).len(), 1);
+    /// ```
     #[must_use]
-    pub fn events_between(&self, start: Timestamp, end: Timestamp) -> Vec<&TimestampedEvent> {
-        self.events
-            .iter()
-            .filter(|te| te.timestamp >= start && te.timestamp <= end)
-            .collect()
+    pub fn branch(&self) -> Simulation {
+        self.clone()
     }
 
-    /// Returns all events in the simulation.
-    pub fn all_events(&self) -> impl Iterator<Item = &TimestampedEvent> {
-        self.events.iter()
+    /// Returns a branch of the simulation without the event with the
+    /// given ID, as if it had never happened.
+    ///
+    /// The event is removed as `remove_event` would, taking its cascade
+    /// follow-ons with it. If no stored event has the ID, the branch is an
+    /// unchanged copy.
+    #[must_use]
+    pub fn with_event_excluded(&self, event_id: &EventId) -> Simulation {
+        let mut branch = self.branch();
+        branch.remove_event(event_id);
+        branch
+    }
+
+    /// Returns a branch of the simulation with one more event, as
+    /// `add_event` would add it.
+    #[must_use]
+    pub fn with_event_added(&self, event: Event, timestamp: Timestamp) -> Simulation {
+        let mut branch = self.branch();
+        branch.add_event(event, timestamp);
+        branch
     }
 
     // --- Relationship Management ---
@@ -433,74 +2325,645 @@ impl Simulation {
 
     /// Adds a relationship between two entities.
     ///
-    /// # Returns
+    /// The relationship is attached to a free relationship slot of each
+    /// party in the simulation. A party whose slots are all taken still has
+    /// the relationship, but a loss of the other party is not magnified by
+    /// it; see `EventPayload::Loss`.
+    ///
+    /// # Returns
+    ///
+    /// The relationship's ID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a relationship between an entity and itself is attempted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::types::{Timestamp, EntityId};
+    /// use behavioral_pathways::enums::RelationshipSchema;
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    ///
+    /// let alice = EntityId::new("alice").unwrap();
+    /// let bob = EntityId::new("bob").unwrap();
+    ///
+    /// let formed = Timestamp::from_ymd_hms(2024, 1, 5, 0, 0, 0);
+    /// let rel_id = sim.add_relationship(alice, bob, RelationshipSchema::Peer, formed);
+    /// ```
+    pub fn add_relationship(
+        &mut self,
+        entity_a: EntityId,
+        entity_b: EntityId,
+        schema: RelationshipSchema,
+        formed_timestamp: Timestamp,
+    ) -> RelationshipId {
+        assert!(
+            entity_a != entity_b,
+            "Cannot create relationship between an entity and itself"
+        );
+
+        let schema = self.resolve_schema_constraints(&entity_a, &entity_b, schema);
+
+        // Create the relationship
+        let relationship = Relationship::try_between(entity_a.clone(), entity_b.clone())
+            .expect("Failed to create relationship")
+            .with_schema(schema);
+
+        // Generate ID
+        self.relationship_counter += 1;
+        let rel_id =
+            RelationshipId::new(format!("rel_{:016x}", self.relationship_counter)).unwrap();
+
+        let mut timestamped =
+            TimestampedRelationship::new(relationship, entity_a, entity_b, formed_timestamp);
+        // Events added before the relationship still inform it
+        let mut earlier: Vec<&TimestampedEvent> = self.events.iter().collect();
+        earlier.sort_by_key(|te| te.timestamp());
+        for event in earlier {
+            record_event_on_relationship(&mut timestamped, event);
+        }
+
+        let parties = [
+            timestamped.entity_a().clone(),
+            timestamped.entity_b().clone(),
+        ];
+        self.relationships.insert(rel_id.clone(), timestamped);
+        // State queries read simulation relationships from the simulation,
+        // so attaching them leaves the generation alone
+        for id in &parties {
+            if let Some(anchored) = self.anchored_mut(id) {
+                anchored.entity_mut().attach_relationship(&rel_id);
+            }
+        }
+        rel_id
+    }
+
+    /// Returns all relationships involving the given entity.
+    #[must_use]
+    pub fn relationships_for(&self, entity_id: &EntityId) -> Vec<&TimestampedRelationship> {
+        self.relationships
+            .values()
+            .filter(|tr| tr.involves(entity_id))
+            .collect()
+    }
+
+    /// Returns all relationships in the simulation.
+    pub fn relationships(&self) -> impl Iterator<Item = &TimestampedRelationship> {
+        self.relationships.values()
+    }
+
+    /// Returns all relationships with their IDs, in no particular order.
+    pub(crate) fn relationship_entries(
+        &self,
+    ) -> impl Iterator<Item = (&RelationshipId, &TimestampedRelationship)> {
+        self.relationships.iter()
+    }
+
+    /// Returns the relationship with the given ID.
+    #[must_use]
+    pub fn get_relationship(&self, id: &RelationshipId) -> Option<&TimestampedRelationship> {
+        self.relationships.get(id)
+    }
+
+    /// Returns a mutable reference to the relationship with the given ID.
+    ///
+    /// Use this to declare a relationship's stage or dimensions after
+    /// adding it.
+    pub fn get_relationship_mut(
+        &mut self,
+        id: &RelationshipId,
+    ) -> Option<&mut TimestampedRelationship> {
+        self.relationships.get_mut(id)
+    }
+
+    /// Removes the relationship with the given ID, returning it, and
+    /// detaches it from its parties' relationship slots.
+    ///
+    /// Returns `None` if there is no such relationship.
+    pub fn remove_relationship(&mut self, id: &RelationshipId) -> Option<TimestampedRelationship> {
+        let removed = self.relationships.remove(id)?;
+        for party in [removed.entity_a(), removed.entity_b()] {
+            if let Some(anchored) = self.anchored_mut(party) {
+                anchored.entity_mut().detach_relationship(id);
+            }
+        }
+        Some(removed)
+    }
+
+    /// Returns a query handle for the relationship between two entities.
+    ///
+    /// The entities may be given in either order; the handle's directions
+    /// follow the relationship's own entity A and entity B.
+    ///
+    /// Returns `None` if the two entities have no relationship.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::enums::{Direction, EventType, RelationshipSchema, Species};
+    /// use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// for id in ["alice", "bob"] {
+    ///     let entity = EntityBuilder::new().id(id).species(Species::Human).build().unwrap();
+    ///     sim.add_entity(entity, reference);
+    /// }
+    /// let alice = EntityId::new("alice").unwrap();
+    /// let bob = EntityId::new("bob").unwrap();
+    /// sim.add_relationship(alice.clone(), bob.clone(), RelationshipSchema::Peer, reference);
+    ///
+    /// let help = EventBuilder::new(EventType::Support)
+    ///     .source(bob.clone())
+    ///     .target(alice.clone())
+    ///     .build()
+    ///     .unwrap();
+    /// sim.add_event(help, reference + Duration::days(10));
+    ///
+    /// let handle = sim.relationship(&bob, &alice).unwrap();
+    /// let later = handle.state_at(reference + Duration::days(15));
+    /// assert_eq!(later.relationship.entity_a(), &alice);
+    /// # #[cfg(feature = "relationships")]
+    /// assert_eq!(later.relationship.antecedent_history(Direction::AToB).len(), 1);
+    /// ```
+    #[must_use]
+    pub fn relationship(&self, a: &EntityId, b: &EntityId) -> Option<RelationshipQueryHandle<'_>> {
+        if a == b {
+            return None;
+        }
+        let (id, _) = self
+            .relationships
+            .iter()
+            .find(|(_, rel)| rel.involves(a) && rel.involves(b))?;
+        Some(RelationshipQueryHandle::new(self, id.clone()))
+    }
+
+    /// Returns the relationship as it stood at the given timestamp.
+    ///
+    /// Trust antecedents recorded after `timestamp` are dropped and
+    /// trustworthiness is recomputed from the rest, so trust reflects only
+    /// the events that had happened by then. Interaction events between the
+    /// pair up to `timestamp` are recorded with
+    /// `Relationship::record_interaction`, building its interaction pattern
+    /// and shared history. The stage reflects dormancy regression from
+    /// `relationship_activity_at`. Once one party has a `Loss` event naming
+    /// the other as `lost_entity`, the relationship is dissolved from that
+    /// time on.
+    ///
+    /// Returns `None` if the relationship does not exist or had not yet
+    /// formed at `timestamp`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::enums::{Direction, EventType, RelationshipSchema, Species};
+    /// use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// for id in ["alice", "bob"] {
+    ///     let entity = EntityBuilder::new().id(id).species(Species::Human).build().unwrap();
+    ///     sim.add_entity(entity, reference);
+    /// }
+    /// let alice = EntityId::new("alice").unwrap();
+    /// let bob = EntityId::new("bob").unwrap();
+    /// let peer = RelationshipSchema::Peer;
+    /// let rel_id = sim.add_relationship(alice.clone(), bob.clone(), peer, reference);
+    ///
+    /// let help = EventBuilder::new(EventType::Support).source(bob).target(alice).build().unwrap();
+    /// sim.add_event(help, reference + Duration::days(10));
+    ///
+    /// let before = sim.relationship_at(&rel_id, reference + Duration::days(5)).unwrap();
+    /// let after = sim.relationship_at(&rel_id, reference + Duration::days(15)).unwrap();
+    /// assert!(before.antecedent_history(Direction::AToB).is_empty());
+    /// # #[cfg(feature = "relationships")]
+    /// assert_eq!(after.antecedent_history(Direction::AToB).len(), 1);
+    /// ```
+    #[must_use]
+    pub fn relationship_at(
+        &self,
+        id: &RelationshipId,
+        timestamp: Timestamp,
+    ) -> Option<Relationship> {
+        let timestamped = self.relationships.get(id)?;
+        if timestamp < timestamped.formed_timestamp() {
+            return None;
+        }
+        Some(self.relationship_as_of(timestamped, timestamp))
+    }
+
+    /// Reconstructs a relationship at a timestamp, without checking that it
+    /// had formed.
+    pub(crate) fn relationship_as_of(
+        &self,
+        timestamped: &TimestampedRelationship,
+        timestamp: Timestamp,
+    ) -> Relationship {
+        let activity = self.activity_for(timestamped, timestamp);
+        let mut relationship = timestamped.relationship().clone().with_stage(activity.stage);
+        relationship.retain_antecedents_until(timestamp);
+        self.record_interactions_on(&mut relationship, timestamped, timestamp);
+        match bereavement::dissolution_of(self, timestamped).filter(|at| *at <= timestamp) {
+            Some(at) => relationship.with_dissolved_at(at),
+            None => relationship,
+        }
+    }
+
+    /// Records the pair's `Interaction` events from formation through
+    /// `timestamp` on `relationship`.
+    ///
+    /// `record_interaction` adds one event per party, so interactions
+    /// between the pair at the same instant count once.
+    fn record_interactions_on(
+        &self,
+        relationship: &mut Relationship,
+        timestamped: &TimestampedRelationship,
+        timestamp: Timestamp,
+    ) {
+        let (a, b) = (timestamped.entity_a(), timestamped.entity_b());
+        let mut interactions: Vec<&TimestampedEvent> = self
+            .events
+            .iter()
+            .filter(|te| {
+                te.event().event_type() == EventType::Interaction
+                    && (timestamped.formed_timestamp()..=timestamp).contains(&te.timestamp())
+                    && is_contact_event(te.event(), a, b)
+            })
+            .collect();
+        interactions.sort_by_key(|te| te.timestamp());
+        interactions.dedup_by_key(|te| te.timestamp());
+        for te in interactions {
+            let (topic, minutes) = match te.event().payload() {
+                EventPayload::Interaction {
+                    topic,
+                    duration_minutes,
+                } => (*topic, *duration_minutes),
+                _ => (None, 0),
+            };
+            relationship.record_interaction(te.timestamp(), topic, minutes);
+        }
+    }
+
+    /// Returns the relationship's activity at the given timestamp.
+    ///
+    /// Contact is inferred from `Interaction` and `Support` events between
+    /// the pair. Long gaps without contact reduce closeness, regress an
+    /// Intimate relationship to Established, and lower antecedent freshness,
+    /// according to the simulation's `DormancyConfig`.
+    ///
+    /// Returns `None` if the relationship does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::enums::{RelationshipSchema, Species};
+    /// use behavioral_pathways::relationship::RelationshipStage;
+    /// use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let alice = EntityId::new("alice").unwrap();
+    /// let bob = EntityId::new("bob").unwrap();
+    /// for id in ["alice", "bob"] {
+    ///     let entity = EntityBuilder::new().id(id).species(Species::Human).build().unwrap();
+    ///     sim.add_entity(entity, reference);
+    /// }
+    /// let rel_id = sim.add_relationship(alice, bob, RelationshipSchema::Peer, reference);
+    /// sim.get_relationship_mut(&rel_id)
+    ///     .unwrap()
+    ///     .relationship_mut()
+    ///     .set_stage(RelationshipStage::Intimate)
+    ///     .unwrap();
+    ///
+    /// let later = reference + Duration::years(2);
+    /// let activity = sim.relationship_activity_at(&rel_id, later).unwrap();
+    /// assert_eq!(activity.stage, RelationshipStage::Established);
+    /// assert!(activity.closeness < 0.5);
+    /// ```
+    #[must_use]
+    pub fn relationship_activity_at(
+        &self,
+        id: &RelationshipId,
+        timestamp: Timestamp,
+    ) -> Option<RelationshipActivity> {
+        let relationship = self.relationships.get(id)?;
+        Some(self.activity_for(relationship, timestamp))
+    }
+
+    /// Computes activity for a relationship from the event log.
+    pub(crate) fn activity_for(
+        &self,
+        relationship: &TimestampedRelationship,
+        timestamp: Timestamp,
+    ) -> RelationshipActivity {
+        let (a, b) = (relationship.entity_a(), relationship.entity_b());
+        let formed = relationship.formed_timestamp();
+        // A contact targets one of the pair, so it is in that one's index
+        let contacts: Vec<Timestamp> = [a, b]
+            .into_iter()
+            .flat_map(|member| {
+                self.events_for_range(member, formed, timestamp)
+                    .filter(move |te| te.event().target() == Some(member))
+            })
+            .filter(|te| is_contact_event(te.event(), a, b))
+            .map(TimestampedEvent::timestamp)
+            .collect();
+        RelationshipActivity::compute(
+            relationship.relationship().stage(),
+            relationship.formed_timestamp(),
+            &contacts,
+            timestamp,
+            &self.dormancy_config,
+        )
+    }
+
+    /// Predicts how willing `trustor` is to trust `trustee` at a timestamp.
+    ///
+    /// The stakes are derived from the trustor's state at `timestamp` via
+    /// `TrustContext::from_state` for the given domain, so a trustor who
+    /// feels like a burden sees disclosure as higher-stakes. Use
+    /// `predict_interaction_with_context` to supply the context instead.
+    /// Trustworthiness comes from `relationship_at(timestamp)`, so only
+    /// events up to `timestamp` inform the decision. An external trustee
+    /// keeps their declared trustworthiness regardless of events.
+    ///
+    /// Returns `None` if the trustor is not in the simulation or the two
+    /// entities have no relationship formed by `timestamp`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::enums::{RelationshipSchema, Species, TrustDomain};
+    /// use behavioral_pathways::types::{EntityId, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// for id in ["ana", "ben"] {
+    ///     let entity = EntityBuilder::new().id(id).species(Species::Human).build().unwrap();
+    ///     sim.add_entity(entity, reference);
+    /// }
+    /// let ana = EntityId::new("ana").unwrap();
+    /// let ben = EntityId::new("ben").unwrap();
+    /// sim.add_relationship(ana.clone(), ben.clone(), RelationshipSchema::Peer, reference);
+    ///
+    /// let decision = sim
+    ///     .predict_interaction(&ana, &ben, TrustDomain::Disclosure, reference)
+    ///     .unwrap();
+    /// assert!(decision.disclosure_willingness() >= 0.0);
+    /// ```
+    #[must_use]
+    pub fn predict_interaction(
+        &self,
+        trustor: &EntityId,
+        trustee: &EntityId,
+        domain: TrustDomain,
+        timestamp: Timestamp,
+    ) -> Option<TrustDecision> {
+        let state = self.entity(trustor)?.state_at(timestamp);
+        let context = TrustContext::from_state(&state, domain);
+        self.predict_with_state(trustor, trustee, timestamp, &state, &context)
+    }
+
+    /// Predicts trust willingness using a caller-supplied TrustContext.
+    ///
+    /// The trustor's propensity still comes from their state at `timestamp`;
+    /// only the context (and therefore the stakes) is overridden.
+    #[must_use]
+    pub fn predict_interaction_with_context(
+        &self,
+        trustor: &EntityId,
+        trustee: &EntityId,
+        timestamp: Timestamp,
+        context: &TrustContext,
+    ) -> Option<TrustDecision> {
+        let state = self.entity(trustor)?.state_at(timestamp);
+        self.predict_with_state(trustor, trustee, timestamp, &state, context)
+    }
+
+    fn predict_with_state(
+        &self,
+        trustor: &EntityId,
+        trustee: &EntityId,
+        timestamp: Timestamp,
+        state: &ComputedState,
+        context: &TrustContext,
+    ) -> Option<TrustDecision> {
+        let (id, timestamped) = self
+            .relationships
+            .iter()
+            .find(|(_, rel)| rel.involves(trustor) && rel.involves(trustee))?;
+        let relationship = self.relationship_at(id, timestamp)?;
+        let direction = if timestamped.entity_a() == trustor {
+            Direction::AToB
+        } else {
+            Direction::BToA
+        };
+        let propensity =
+            state.get_effective(StatePath::Disposition(DispositionPath::TrustPropensity)) as f32;
+        let freshness = self
+            .activity_for(timestamped, timestamp)
+            .antecedent_freshness;
+        Some(self.trust_decision_in(
+            relationship,
+            direction,
+            trustee,
+            propensity,
+            freshness,
+            context,
+        ))
+    }
+
+    /// Computes the trust decision in one direction of a relationship.
+    ///
+    /// The trustworthiness built from antecedents is scaled by
+    /// `antecedent_freshness`, so evidence from before a long silence
+    /// counts for less.
+    pub(crate) fn trust_decision_in(
+        &self,
+        mut relationship: Relationship,
+        direction: Direction,
+        trustee: &EntityId,
+        propensity: f32,
+        antecedent_freshness: f64,
+        context: &TrustContext,
+    ) -> TrustDecision {
+        if self.is_external_entity(trustee) {
+            // An external trustee's behaviour is not simulated, so trust
+            // rests on what was declared about them
+            relationship.clear_antecedents(direction);
+        } else {
+            relationship
+                .trustworthiness_mut(direction)
+                .scale_deltas(antecedent_freshness as f32);
+        }
+        relationship.compute_trust_decision_in_context(direction, propensity, context)
+    }
+
+    /// Compares trust predictions with interaction outcomes in `[start, end]`.
+    ///
+    /// Every `Interaction`, `Support`, or `Betrayal` event between two
+    /// related entities (restricted to the pair in `between`, in either
+    /// direction, when given) is scored: the target's willingness to trust
+    /// the source, predicted by `predict_interaction` one second before the
+    /// event, against whether the event honored or betrayed that trust. The
+    /// report has hit and miss counts and a Brier score for tuning
+    /// antecedent weights.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::enums::{EventType, RelationshipSchema, Species};
+    /// use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// for id in ["ana", "ben"] {
+    ///     let entity = EntityBuilder::new().id(id).species(Species::Human).build().unwrap();
+    ///     sim.add_entity(entity, reference);
+    /// }
+    /// let ana = EntityId::new("ana").unwrap();
+    /// let ben = EntityId::new("ben").unwrap();
+    /// sim.add_relationship(ana.clone(), ben.clone(), RelationshipSchema::Peer, reference);
+    /// let help = EventBuilder::new(EventType::Support).source(ben).target(ana).build().unwrap();
+    /// sim.add_event(help, reference + Duration::days(3));
+    ///
+    /// let report = sim.trust_calibration(None, reference, reference + Duration::days(7));
+    /// assert_eq!(report.hits() + report.misses(), 1);
+    /// assert!(report.brier_score().is_some());
+    /// ```
+    #[must_use]
+    pub fn trust_calibration(
+        &self,
+        between: Option<(EntityId, EntityId)>,
+        start: Timestamp,
+        end: Timestamp,
+    ) -> TrustCalibrationReport {
+        trust_calibration::trust_calibration(self, between.as_ref(), start, end)
+    }
+
+    /// Compares this simulation with another.
+    ///
+    /// Entities are matched by ID and compared on their anchor state, events
+    /// are matched by ID, and relationships by entity pair. `other` is
+    /// treated as the newer version, so items only it contains are "added".
+    ///
+    /// # Examples
     ///
-    /// The relationship's ID.
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::types::Timestamp;
     ///
-    /// # Panics
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let a = Simulation::new(reference);
+    /// let b = Simulation::new(reference);
     ///
-    /// Panics if a relationship between an entity and itself is attempted.
+    /// assert!(a.diff(&b).is_empty());
+    /// ```
+    #[must_use]
+    pub fn diff(&self, other: &Simulation) -> SimulationDiff {
+        SimulationDiff::between(self, other)
+    }
+
+    /// Compares two entities at `at`, as in a twin or sibling study.
+    ///
+    /// Reports per-path differences (B minus A), shared and divergent event
+    /// histories, shared microsystems, and differing HEXACO bases. Each
+    /// path's difference is attributed to traits, events, and the rest of
+    /// the anchored entity by swapping them between the pair; the three
+    /// shares sum to the difference. Returns `None` if either entity is
+    /// not in the simulation.
     ///
     /// # Examples
     ///
     /// ```
     /// use behavioral_pathways::simulation::Simulation;
-    /// use behavioral_pathways::types::{Timestamp, EntityId};
-    /// use behavioral_pathways::enums::RelationshipSchema;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::enums::{EventType, MoodPath, Species, StatePath};
+    /// use behavioral_pathways::types::{Duration, Timestamp};
     ///
     /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
     /// let mut sim = Simulation::new(reference);
+    /// let twin_a = EntityBuilder::new().id("twin_a").species(Species::Human).build().unwrap();
+    /// let twin_b = EntityBuilder::new().id("twin_b").species(Species::Human).build().unwrap();
+    /// let a = sim.add_entity(twin_a, reference);
+    /// let b = sim.add_entity(twin_b, reference);
     ///
-    /// let alice = EntityId::new("alice").unwrap();
-    /// let bob = EntityId::new("bob").unwrap();
+    /// let conflict = EventBuilder::new(EventType::Conflict).target(b.clone()).build().unwrap();
+    /// sim.add_event(conflict, reference + Duration::days(1));
     ///
-    /// let formed = Timestamp::from_ymd_hms(2024, 1, 5, 0, 0, 0);
-    /// let rel_id = sim.add_relationship(alice, bob, RelationshipSchema::Peer, formed);
+    /// let comparison = sim.compare_entities(&a, &b, reference + Duration::days(2)).unwrap();
+    /// let valence = comparison.path(StatePath::Mood(MoodPath::Valence)).unwrap();
+    /// assert!(valence.difference() < 0.0);
+    /// assert!((valence.events - valence.difference()).abs() < 1e-9);
+    /// assert_eq!(comparison.events_only_b.len(), 1);
     /// ```
-    pub fn add_relationship(
-        &mut self,
-        entity_a: EntityId,
-        entity_b: EntityId,
-        schema: RelationshipSchema,
-        formed_timestamp: Timestamp,
-    ) -> RelationshipId {
-        assert!(
-            entity_a != entity_b,
-            "Cannot create relationship between an entity and itself"
-        );
-
-        let schema = self.resolve_schema_constraints(&entity_a, &entity_b, schema);
-
-        // Create the relationship
-        let relationship = Relationship::try_between(entity_a.clone(), entity_b.clone())
-            .expect("Failed to create relationship")
-            .with_schema(schema);
-
-        // Generate ID
-        self.relationship_counter += 1;
-        let rel_id =
-            RelationshipId::new(format!("rel_{:016x}", self.relationship_counter)).unwrap();
-
-        let timestamped =
-            TimestampedRelationship::new(relationship, entity_a, entity_b, formed_timestamp);
-
-        self.relationships.insert(rel_id.clone(), timestamped);
-        rel_id
-    }
-
-    /// Returns all relationships involving the given entity.
     #[must_use]
-    pub fn relationships_for(&self, entity_id: &EntityId) -> Vec<&TimestampedRelationship> {
-        self.relationships
-            .values()
-            .filter(|tr| tr.involves(entity_id))
-            .collect()
+    pub fn compare_entities(
+        &self,
+        a: &EntityId,
+        b: &EntityId,
+        at: Timestamp,
+    ) -> Option<EntityComparison> {
+        comparison::compare_entities(self, a, b, at)
     }
 
-    /// Returns the relationship with the given ID.
+    /// Checks the simulation's inputs for values that are probably wrong.
+    ///
+    /// Unlike build errors, issues do not prevent simulation. This flags
+    /// events whose explicit subjective and objective severity differ by
+    /// more than `IMPLAUSIBLE_APPRAISAL_GAP`, and dimensions pinned at a
+    /// bound: saturated anchors, events clamping would absorb, and
+    /// trajectories that stay saturated (see `ValidationConfig`). Each
+    /// issue names the entity or event, the path, and the timestamps.
+    /// Relationships with a partner that is neither simulated nor declared
+    /// external are reported as dangling. Entities built with
+    /// `EntityBuilder::build_partial` are reported with their imputed inputs.
+    /// Inputs added with `add_entity` or `add_event` that
+    /// `SimulationBuilder::build` would reject, such as an anchor before
+    /// birth or an event for an unknown entity, are reported first as
+    /// `ValidationIssue::BuildError`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::{Simulation, ValidationIssue};
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::enums::EventType;
+    /// use behavioral_pathways::types::Timestamp;
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let event = EventBuilder::new(EventType::Loss)
+    ///     .objective_severity(0.1)
+    ///     .severity(0.95)
+    ///     .build()
+    ///     .unwrap();
+    /// sim.add_event(event, reference);
+    ///
+    /// let issues = sim.validate();
+    /// assert!(matches!(issues[0], ValidationIssue::ImplausibleAppraisalGap { .. }));
+    /// ```
     #[must_use]
-    pub fn get_relationship(&self, id: &RelationshipId) -> Option<&TimestampedRelationship> {
-        self.relationships.get(id)
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        validation::validate(self)
     }
 
     /// Returns the number of relationships.
@@ -510,12 +2973,96 @@ impl Simulation {
     }
 }
 
+/// Converts a position in the event log to its stored form.
+fn event_position(position: usize) -> u32 {
+    u32::try_from(position).expect("event log holds at most u32::MAX events")
+}
+
+/// Returns the entities whose histories include an event: its target, then
+/// its witnesses other than the target.
+fn experiencing_entities(event: &Event) -> impl Iterator<Item = &EntityId> {
+    let target = event.target();
+    target.into_iter().chain(
+        event
+            .witnesses()
+            .iter()
+            .filter(move |witness| Some(*witness) != target),
+    )
+}
+
+/// Records an event's contact and trust antecedents on a relationship
+/// formed by the event's timestamp.
+fn record_event_on_relationship(
+    relationship: &mut TimestampedRelationship,
+    event: &TimestampedEvent,
+) {
+    if event.timestamp() < relationship.formed_timestamp() {
+        return;
+    }
+    if is_contact_event(
+        event.event(),
+        relationship.entity_a(),
+        relationship.entity_b(),
+    ) {
+        let pattern = relationship.relationship_mut().pattern_mut();
+        if pattern
+            .last_interaction
+            .is_none_or(|last| last < event.timestamp())
+        {
+            pattern.last_interaction = Some(event.timestamp());
+        }
+    }
+    #[cfg(feature = "relationships")]
+    process_event_to_relationships(
+        event.event(),
+        event.timestamp(),
+        std::slice::from_mut(relationship.relationship_mut()),
+    );
+}
+
+/// Withdraws what `record_event_on_relationship` recorded for a removed
+/// event, given the events that remain.
+///
+/// The last interaction falls back to the latest remaining contact event
+/// between the pair if the removed event set it.
+fn unrecord_event_on_relationship(
+    relationship: &mut TimestampedRelationship,
+    removed: &TimestampedEvent,
+    remaining: &[TimestampedEvent],
+) {
+    if removed.timestamp() < relationship.formed_timestamp() {
+        return;
+    }
+    let (a, b) = (relationship.entity_a(), relationship.entity_b());
+    if is_contact_event(removed.event(), a, b)
+        && relationship.relationship().pattern().last_interaction == Some(removed.timestamp())
+    {
+        let formed = relationship.formed_timestamp();
+        let latest = remaining
+            .iter()
+            .filter(|te| te.timestamp() >= formed && is_contact_event(te.event(), a, b))
+            .map(TimestampedEvent::timestamp)
+            .max();
+        relationship
+            .relationship_mut()
+            .pattern_mut()
+            .last_interaction = latest;
+    }
+    #[cfg(feature = "relationships")]
+    retract_event_from_relationships(
+        removed.event(),
+        removed.timestamp(),
+        std::slice::from_mut(relationship.relationship_mut()),
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::entity::EntityBuilder;
-    use crate::enums::{Direction, EventType, Species};
+    use crate::enums::{AlertSeverity, AlertTrigger, Direction, EventType, Species};
     use crate::event::EventBuilder;
+    use crate::relationship::{RelationshipStage, StakesLevel};
     use crate::types::Duration;
 
     fn create_simulation() -> Simulation {
@@ -590,6 +3137,7 @@ mod tests {
         assert_eq!(events[0].timestamp(), event_time);
     }
 
+    #[cfg(feature = "relationships")]
     #[test]
     fn simulation_add_event_updates_relationships() {
         let mut sim = create_simulation();
@@ -615,6 +3163,371 @@ mod tests {
         assert!(!history.is_empty());
     }
 
+    #[test]
+    fn simulation_add_event_records_last_interaction_for_pair() {
+        let mut sim = create_simulation();
+        let alice = EntityId::new("alice").unwrap();
+        let bob = EntityId::new("bob").unwrap();
+        let formed = sim.reference_date();
+        let rel_id =
+            sim.add_relationship(alice.clone(), bob.clone(), RelationshipSchema::Peer, formed);
+
+        let chat = EventBuilder::new(EventType::Interaction)
+            .source(bob.clone())
+            .target(alice.clone())
+            .build()
+            .unwrap();
+        let later = formed + Duration::days(10);
+        sim.add_event(chat.clone(), later);
+        // An out-of-order older contact does not move last_interaction back
+        sim.add_event(chat, formed + Duration::days(5));
+
+        let pattern = sim.get_relationship(&rel_id).unwrap().relationship().pattern();
+        assert_eq!(pattern.last_interaction, Some(later));
+    }
+
+    #[test]
+    fn simulation_relationship_activity_reflects_dormancy() {
+        let mut sim = create_simulation();
+        let alice = EntityId::new("alice").unwrap();
+        let bob = EntityId::new("bob").unwrap();
+        let formed = sim.reference_date();
+        let rel_id =
+            sim.add_relationship(alice.clone(), bob.clone(), RelationshipSchema::Peer, formed);
+        sim.get_relationship_mut(&rel_id)
+            .unwrap()
+            .relationship_mut()
+            .set_stage(RelationshipStage::Intimate)
+            .unwrap();
+
+        let later = formed + Duration::years(2);
+        let dormant = sim.relationship_activity_at(&rel_id, later).unwrap();
+        assert_eq!(dormant.stage, RelationshipStage::Established);
+
+        let reconnect = EventBuilder::new(EventType::Support)
+            .source(alice)
+            .target(bob)
+            .build()
+            .unwrap();
+        sim.add_event(reconnect, later - Duration::days(3));
+        let reconnected = sim.relationship_activity_at(&rel_id, later).unwrap();
+        assert_eq!(reconnected.stage, RelationshipStage::Intimate);
+        assert!(reconnected.closeness > dormant.closeness);
+    }
+
+    #[cfg(feature = "relationships")]
+    #[test]
+    fn relationship_at_reflects_only_events_so_far() {
+        let mut sim = create_simulation();
+        let alice = EntityId::new("alice").unwrap();
+        let bob = EntityId::new("bob").unwrap();
+        let formed = sim.reference_date();
+        let rel_id =
+            sim.add_relationship(alice.clone(), bob.clone(), RelationshipSchema::Peer, formed);
+        let betrayal = EventBuilder::new(EventType::Betrayal)
+            .source(bob)
+            .target(alice)
+            .severity(0.8)
+            .build()
+            .unwrap();
+        let betrayed_at = formed + Duration::days(30);
+        sim.add_event(betrayal, betrayed_at);
+
+        assert!(sim
+            .relationship_at(&rel_id, formed - Duration::days(1))
+            .is_none());
+        let before = sim
+            .relationship_at(&rel_id, betrayed_at - Duration::days(1))
+            .unwrap();
+        let after = sim.relationship_at(&rel_id, betrayed_at).unwrap();
+        assert!(before.antecedent_history(Direction::AToB).is_empty());
+        assert!(!after.antecedent_history(Direction::AToB).is_empty());
+        assert!(
+            after.trustworthiness(Direction::AToB).integrity_effective()
+                < before.trustworthiness(Direction::AToB).integrity_effective()
+        );
+    }
+
+    #[test]
+    fn relationship_at_applies_dormancy_stage() {
+        let mut sim = create_simulation();
+        let alice = EntityId::new("alice").unwrap();
+        let bob = EntityId::new("bob").unwrap();
+        let formed = sim.reference_date();
+        let rel_id = sim.add_relationship(alice, bob, RelationshipSchema::Peer, formed);
+        sim.get_relationship_mut(&rel_id)
+            .unwrap()
+            .relationship_mut()
+            .set_stage(RelationshipStage::Intimate)
+            .unwrap();
+
+        let dormant = sim
+            .relationship_at(&rel_id, formed + Duration::years(2))
+            .unwrap();
+        assert_eq!(dormant.stage(), RelationshipStage::Established);
+        let missing = RelationshipId::new("missing").unwrap();
+        assert!(sim.relationship_at(&missing, formed).is_none());
+    }
+
+    #[test]
+    fn simulation_relationship_activity_unknown_returns_none() {
+        let sim = create_simulation();
+        let missing = RelationshipId::new("missing").unwrap();
+        assert!(sim
+            .relationship_activity_at(&missing, sim.reference_date())
+            .is_none());
+    }
+
+    #[test]
+    fn predict_interaction_lower_disclosure_when_burdened() {
+        use crate::state::SocialCognition;
+
+        let build = |social_cognition: SocialCognition| {
+            let mut sim = create_simulation();
+            let reference = sim.reference_date();
+            let discloser = EntityBuilder::new()
+                .id("discloser")
+                .species(Species::Human)
+                .social_cognition(social_cognition)
+                .build()
+                .unwrap();
+            let discloser = sim.add_entity(discloser, reference);
+            let confidant = sim.add_entity(create_human("confidant"), reference);
+            let rel_id = sim.add_relationship(
+                discloser.clone(),
+                confidant.clone(),
+                RelationshipSchema::Peer,
+                reference,
+            );
+            sim.get_relationship_mut(&rel_id)
+                .unwrap()
+                .relationship_mut()
+                .set_stage(RelationshipStage::Established)
+                .unwrap();
+            sim.predict_interaction(&discloser, &confidant, TrustDomain::Disclosure, reference)
+                .unwrap()
+        };
+
+        let calm = build(SocialCognition::new());
+        let burdened = build(
+            SocialCognition::new()
+                .with_perceived_liability_base(0.9)
+                .with_self_hate_base(0.9),
+        );
+
+        assert!(
+            burdened.disclosure_willingness() < calm.disclosure_willingness(),
+            "Burdened: {}, Calm: {}",
+            burdened.disclosure_willingness(),
+            calm.disclosure_willingness()
+        );
+    }
+
+    #[test]
+    fn predict_interaction_with_context_overrides_stakes() {
+        let mut sim = create_simulation();
+        let reference = sim.reference_date();
+        let ana = sim.add_entity(create_human("ana"), reference);
+        let ben = sim.add_entity(create_human("ben"), reference);
+        sim.add_relationship(ben.clone(), ana.clone(), RelationshipSchema::Peer, reference);
+
+        let low = TrustContext::new().with_stakes(StakesLevel::Low);
+        let critical = TrustContext::new().with_stakes(StakesLevel::Critical);
+        let low_decision = sim
+            .predict_interaction_with_context(&ana, &ben, reference, &low)
+            .unwrap();
+        let critical_decision = sim
+            .predict_interaction_with_context(&ana, &ben, reference, &critical)
+            .unwrap();
+
+        assert!(critical_decision.disclosure_willingness() < low_decision.disclosure_willingness());
+    }
+
+    #[test]
+    fn predict_interaction_none_without_relationship_or_entity() {
+        let mut sim = create_simulation();
+        let reference = sim.reference_date();
+        let ana = sim.add_entity(create_human("ana"), reference);
+        let ben = sim.add_entity(create_human("ben"), reference);
+        let ghost = EntityId::new("ghost").unwrap();
+
+        assert!(sim
+            .predict_interaction(&ana, &ben, TrustDomain::Support, reference)
+            .is_none());
+        assert!(sim
+            .predict_interaction(&ghost, &ben, TrustDomain::Support, reference)
+            .is_none());
+    }
+
+    #[cfg(feature = "relationships")]
+    #[test]
+    fn external_trustee_keeps_declared_trustworthiness() {
+        let mut sim = create_simulation();
+        let reference = sim.reference_date();
+        let ana = sim.add_entity(create_human("ana"), reference);
+        let aunt = EntityId::new("aunt").unwrap();
+        sim.add_relationship(ana.clone(), aunt.clone(), RelationshipSchema::Peer, reference);
+        let before = sim
+            .predict_interaction(&ana, &aunt, TrustDomain::Support, reference)
+            .unwrap();
+
+        for day in 1..=3 {
+            let help = EventBuilder::new(EventType::Support)
+                .source(aunt.clone())
+                .target(ana.clone())
+                .severity(0.8)
+                .build()
+                .unwrap();
+            sim.add_event(help, reference + Duration::days(day));
+        }
+        let later = reference + Duration::days(5);
+        let undeclared = sim
+            .predict_interaction(&ana, &aunt, TrustDomain::Support, later)
+            .unwrap();
+        assert!(undeclared.support_willingness() > before.support_willingness());
+
+        // Once declared external, the support no longer moves trust
+        sim.declare_external_entity(aunt.clone());
+        let declared = sim
+            .predict_interaction(&ana, &aunt, TrustDomain::Support, later)
+            .unwrap();
+        assert!((declared.support_willingness() - before.support_willingness()).abs() < 1e-6);
+    }
+
+    fn crisis_flag(window: Option<Duration>) -> Alert {
+        let builder = Alert::builder()
+            .severity(AlertSeverity::Critical)
+            .trigger(AlertTrigger::custom("clinician_flag"))
+            .note("Acute risk reported");
+        match window {
+            Some(window) => builder.crisis_window(window).build().unwrap(),
+            None => builder.build().unwrap(),
+        }
+    }
+
+    #[cfg(feature = "its")]
+    fn high_risk_human(sim: &mut Simulation, id: &str) -> EntityId {
+        let reference = sim.reference_date();
+        let id = sim.add_entity(create_human(id), reference);
+        let state = sim
+            .get_anchored_entity_mut(&id)
+            .unwrap()
+            .entity_mut()
+            .individual_state_mut();
+        state.social_cognition_mut().loneliness_mut().set_base(0.9);
+        state
+            .social_cognition_mut()
+            .perceived_reciprocal_caring_mut()
+            .set_base(0.1);
+        state.social_cognition_mut().perceived_liability_mut().set_base(0.9);
+        state.social_cognition_mut().self_hate_mut().set_base(0.9);
+        state
+            .mental_health_mut()
+            .interpersonal_hopelessness_mut()
+            .set_base(0.7);
+        state
+            .mental_health_mut()
+            .acquired_capability_mut()
+            .set_base(0.8);
+        id
+    }
+
+    #[cfg(feature = "its")]
+    #[test]
+    fn external_alerts_merge_with_model_alerts_in_order() {
+        let mut sim = create_simulation();
+        let reference = sim.reference_date();
+        let id = high_risk_human(&mut sim, "ana");
+
+        let later = reference + Duration::days(5);
+        let earlier = reference + Duration::days(2);
+        assert!(sim.add_external_alert(&id, crisis_flag(None), later));
+        assert!(sim.add_external_alert(&id, crisis_flag(None), reference));
+        assert!(sim.add_external_alert(&id, crisis_flag(None), earlier));
+        let ghost = EntityId::new("ghost").unwrap();
+        assert!(!sim.add_external_alert(&ghost, crisis_flag(None), later));
+
+        let handle = sim.entity(&id).unwrap();
+        let end = reference + Duration::days(7);
+        let alerts = handle.alerts_between(reference, end, Duration::days(1));
+        let timestamps: Vec<Timestamp> = alerts.iter().map(TimestampedAlert::timestamp).collect();
+        let mut sorted = timestamps.clone();
+        sorted.sort();
+        assert_eq!(timestamps, sorted);
+
+        // Persistent model alerts are reported once, after the external
+        // alert raised at the same time
+        assert!(alerts[0].alert().is_external());
+        let model: Vec<&TimestampedAlert> =
+            alerts.iter().filter(|alert| !alert.alert().is_external()).collect();
+        assert!(!model.is_empty());
+        assert!(model.iter().all(|alert| alert.timestamp() == reference));
+        assert!(model
+            .iter()
+            .all(|alert| alert.alert().provenance() == AlertProvenance::Model));
+        assert_eq!(alerts.len(), model.len() + 3);
+
+        // Point-in-time alerts include external alerts raised so far
+        let state_alerts = handle.state_at(earlier).alerts();
+        assert_eq!(state_alerts.iter().filter(|alert| alert.is_external()).count(), 2);
+        assert!(state_alerts[0].is_external() && state_alerts[1].is_external());
+        assert!(!state_alerts.last().unwrap().is_external());
+    }
+
+    #[test]
+    fn external_crisis_alert_gates_support_prediction() {
+        let mut sim = create_simulation();
+        let reference = sim.reference_date();
+        let ana = sim.add_entity(create_human("ana"), reference);
+        let ben = sim.add_entity(create_human("ben"), reference);
+        sim.add_relationship(ana.clone(), ben.clone(), RelationshipSchema::Peer, reference);
+
+        let flagged = reference + Duration::days(10);
+        let during = flagged + Duration::days(1);
+        let after = flagged + Duration::days(20);
+        let baseline = sim
+            .predict_interaction(&ana, &ben, TrustDomain::Support, during)
+            .unwrap();
+
+        // Alerts without a crisis window do not gate behavior
+        sim.add_external_alert(&ana, crisis_flag(None), flagged);
+        let ungated = sim
+            .predict_interaction(&ana, &ben, TrustDomain::Support, during)
+            .unwrap();
+        assert_eq!(ungated.support_willingness(), baseline.support_willingness());
+
+        sim.add_external_alert(&ana, crisis_flag(Some(Duration::days(14))), flagged);
+        let handle = sim.entity(&ana).unwrap();
+        assert!(!handle.state_at(reference).is_in_crisis());
+        assert!(handle.state_at(during).is_in_crisis());
+        assert!(!handle.state_at(after).is_in_crisis());
+
+        let in_crisis = sim
+            .predict_interaction(&ana, &ben, TrustDomain::Support, during)
+            .unwrap();
+        let context = TrustContext::from_state(&handle.state_at(during), TrustDomain::Support);
+        assert_eq!(context.stakes(), StakesLevel::Critical);
+        assert!(in_crisis.support_willingness() < baseline.support_willingness());
+
+        let recovered = sim
+            .predict_interaction(&ana, &ben, TrustDomain::Support, after)
+            .unwrap();
+        assert_eq!(recovered.support_willingness(), baseline.support_willingness());
+    }
+
+    #[test]
+    fn simulation_dormancy_config_is_settable() {
+        let mut sim = create_simulation();
+        assert_eq!(*sim.dormancy_config(), DormancyConfig::default());
+
+        let config = DormancyConfig {
+            reconnection_recovery: 0.5,
+            ..Default::default()
+        };
+        sim.set_dormancy_config(config);
+        assert_eq!(*sim.dormancy_config(), config);
+    }
+
     #[test]
     fn simulation_add_event_before_relationship_formed_is_ignored() {
         let mut sim = create_simulation();
@@ -745,6 +3658,154 @@ mod tests {
         assert_eq!(events.len(), 3); // days 10, 15, 20
     }
 
+    #[test]
+    fn events_for_range_matches_full_scan() {
+        let mut sim = create_simulation();
+        let target = EntityId::new("person_001").unwrap();
+        let reference = sim.reference_date();
+        for i in 0..500 {
+            let event = EventBuilder::new(EventType::Interaction)
+                .target(target.clone())
+                .build()
+                .unwrap();
+            sim.add_event(event, reference + Duration::days(5 + (i * 37) % 100));
+        }
+        let removed: Vec<usize> = (0..500).step_by(7).collect();
+        let full_scan = |sim: &Simulation, after: Timestamp, through: Timestamp| {
+            let mut events: Vec<&TimestampedEvent> = sim
+                .all_events()
+                .filter(|te| te.timestamp > after && te.timestamp <= through)
+                .collect();
+            events.sort_by_key(|te| te.timestamp);
+            events.iter().map(|te| te.event().id().clone()).collect::<Vec<_>>()
+        };
+
+        for pass in 0..2 {
+            if pass == 1 {
+                sim.remove_events(&removed);
+            }
+            let ranges = [(0, 104), (15, 15), (15, 16), (45, 65), (0, 300), (65, 45)];
+            for (after, through) in ranges {
+                let after = reference + Duration::days(after);
+                let through = reference + Duration::days(through);
+                let indexed: Vec<_> = sim
+                    .events_for_range(&target, after, through)
+                    .map(|te| te.event().id().clone())
+                    .collect();
+                assert_eq!(indexed, full_scan(&sim, after, through));
+            }
+        }
+    }
+
+    #[test]
+    fn remove_event_withdraws_relationship_effects() {
+        let mut sim = create_simulation();
+        let reference = sim.reference_date();
+        let alice = sim.add_entity(create_human("alice"), reference);
+        let bob = sim.add_entity(create_human("bob"), reference);
+        let rel_id = sim.add_relationship(
+            alice.clone(),
+            bob.clone(),
+            RelationshipSchema::Peer,
+            reference,
+        );
+        let support = |id: &str| {
+            EventBuilder::new(EventType::Support)
+                .id(EventId::new(id).unwrap())
+                .source(bob.clone())
+                .target(alice.clone())
+                .build()
+                .unwrap()
+        };
+        sim.add_event(support("first"), reference + Duration::days(1));
+        sim.add_event(support("second"), reference + Duration::days(2));
+        let pattern = |sim: &Simulation| {
+            sim.get_relationship(&rel_id)
+                .unwrap()
+                .relationship()
+                .pattern()
+                .last_interaction
+        };
+        assert_eq!(pattern(&sim), Some(reference + Duration::days(2)));
+
+        let removed = sim.remove_event(&EventId::new("second").unwrap()).unwrap();
+        assert_eq!(removed.timestamp(), reference + Duration::days(2));
+        assert_eq!(pattern(&sim), Some(reference + Duration::days(1)));
+        assert_eq!(sim.events_for(&alice).len(), 1);
+        #[cfg(feature = "relationships")]
+        {
+            let history_len = |sim: &Simulation| {
+                sim.get_relationship(&rel_id)
+                    .unwrap()
+                    .relationship()
+                    .antecedent_history(Direction::AToB)
+                    .len()
+                    + sim
+                        .get_relationship(&rel_id)
+                        .unwrap()
+                        .relationship()
+                        .antecedent_history(Direction::BToA)
+                        .len()
+            };
+            let before = history_len(&sim);
+            sim.remove_event(&EventId::new("first").unwrap());
+            assert!(history_len(&sim) < before);
+        }
+        #[cfg(not(feature = "relationships"))]
+        sim.remove_event(&EventId::new("first").unwrap());
+        assert_eq!(pattern(&sim), None);
+        assert!(sim.remove_event(&EventId::new("first").unwrap()).is_none());
+    }
+
+    #[test]
+    fn remove_entity_refuses_referenced_entity() {
+        let mut sim = create_simulation();
+        let reference = sim.reference_date();
+        let alice = sim.add_entity(create_human("alice"), reference);
+        let bob = sim.add_entity(create_human("bob"), reference);
+        let group = GroupId::new("team").unwrap();
+        sim.add_group_member(group.clone(), alice.clone());
+        sim.add_group_member(group.clone(), bob.clone());
+        let rel_id = sim.add_relationship(
+            alice.clone(),
+            bob.clone(),
+            RelationshipSchema::Peer,
+            reference,
+        );
+        // Bob only sources this event, so it does not block his removal.
+        let event = EventBuilder::new(EventType::Conflict)
+            .id(EventId::new("argument").unwrap())
+            .source(bob.clone())
+            .target(alice.clone())
+            .build()
+            .unwrap();
+        sim.add_event(event, reference + Duration::days(1));
+
+        assert_eq!(
+            sim.remove_entity(&bob).unwrap_err(),
+            RemoveEntityError::Referenced {
+                entity: bob.clone(),
+                events: Vec::new(),
+                relationships: vec![rel_id.clone()],
+            }
+        );
+        assert!(matches!(
+            sim.remove_entity(&alice),
+            Err(RemoveEntityError::Referenced { ref events, .. }) if events.len() == 1
+        ));
+
+        assert!(sim.remove_relationship(&rel_id).is_some());
+        assert!(sim.remove_relationship(&rel_id).is_none());
+        let removed = sim.remove_entity(&bob).unwrap();
+        assert_eq!(removed.entity().id(), &bob);
+        assert_eq!(sim.group_members(&group), std::slice::from_ref(&alice));
+        assert_eq!(
+            sim.remove_entity(&bob).unwrap_err(),
+            RemoveEntityError::UnknownEntity(bob.clone())
+        );
+        assert!(sim.entity(&alice).is_some());
+    }
+
     #[test]
     fn simulation_add_relationship_with_timestamp() {
         let mut sim = create_simulation();
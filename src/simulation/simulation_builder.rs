@@ -6,7 +6,7 @@
 use crate::entity::Entity;
 use crate::enums::RelationshipSchema;
 use crate::event::Event;
-use crate::simulation::Simulation;
+use crate::simulation::{Simulation, StochasticConfig, DEFAULT_NOISE_MAGNITUDE};
 use crate::types::{EntityId, EventId, RelationshipId, Timestamp};
 use std::fmt;
 
@@ -23,6 +23,12 @@ pub enum SimulationBuildError {
     RelationshipReferencesUnknownEntity(RelationshipId, EntityId),
     /// A relationship between an entity and itself was attempted.
     SelfRelationship(EntityId),
+    /// An entity is anchored before its birth date.
+    /// Contains the entity ID, its birth date, and the anchor timestamp.
+    AnchorBeforeBirth(EntityId, Timestamp, Timestamp),
+    /// More than one of the errors above, in the order `validate` found
+    /// them.
+    Multiple(Vec<SimulationBuildError>),
 }
 
 impl fmt::Display for SimulationBuildError {
@@ -54,6 +60,25 @@ impl fmt::Display for SimulationBuildError {
                     id.as_str()
                 )
             }
+            SimulationBuildError::AnchorBeforeBirth(id, birth, anchor) => {
+                write!(
+                    f,
+                    "Entity '{}' is anchored at {}, before its birth at {}",
+                    id.as_str(),
+                    anchor,
+                    birth
+                )
+            }
+            SimulationBuildError::Multiple(errors) => {
+                write!(f, "{} errors: ", errors.len())?;
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "; ")?;
+                    }
+                    write!(f, "{}", error)?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -123,6 +148,9 @@ pub struct SimulationBuilder {
     entities: Vec<PendingEntity>,
     events: Vec<PendingEvent>,
     relationships: Vec<PendingRelationship>,
+    external_entities: Vec<EntityId>,
+    stochastic_seed: Option<u64>,
+    noise_magnitude: f64,
 }
 
 impl SimulationBuilder {
@@ -140,6 +168,9 @@ impl SimulationBuilder {
             entities: Vec::new(),
             events: Vec::new(),
             relationships: Vec::new(),
+            external_entities: Vec::new(),
+            stochastic_seed: None,
+            noise_magnitude: DEFAULT_NOISE_MAGNITUDE,
         }
     }
 
@@ -181,78 +212,152 @@ impl SimulationBuilder {
         self
     }
 
-    /// Builds the simulation.
+    /// Declares an entity ID as an external relationship partner.
     ///
-    /// # Errors
+    /// Events and relationships may reference external entities without
+    /// failing the build. See `Simulation::declare_external_entity`.
+    #[must_use]
+    pub fn declare_external_entity(mut self, id: EntityId) -> Self {
+        self.external_entities.push(id);
+        self
+    }
+
+    /// Perturbs each interpreted delta with noise drawn from `seed`.
     ///
-    /// Returns an error if:
-    /// - A duplicate entity ID was added
-    /// - An event references an entity that doesn't exist
-    /// - A relationship references an entity that doesn't exist
-    /// - A relationship between an entity and itself was attempted
-    pub fn build(self) -> Result<Simulation, SimulationBuildError> {
-        let mut simulation = Simulation::new(self.reference_date);
+    /// The noise is reproducible for a given seed and independent of the
+    /// order events are added in. Seed 0 adds none. See
+    /// `Simulation::set_stochastic_config`.
+    #[must_use]
+    pub fn stochastic(mut self, seed: u64) -> Self {
+        self.stochastic_seed = Some(seed);
+        self
+    }
+
+    /// Sets the largest relative change (0-1) stochastic noise makes to a
+    /// delta, `DEFAULT_NOISE_MAGNITUDE` if not set.
+    ///
+    /// Has no effect unless `stochastic()` is also called.
+    #[must_use]
+    pub fn noise_magnitude(mut self, magnitude: f64) -> Self {
+        self.noise_magnitude = magnitude;
+        self
+    }
+
+    /// Checks the pending inputs and returns every inconsistency found.
+    ///
+    /// Reports duplicate entity IDs, entities anchored before their birth
+    /// dates, events whose source, target, or witness is unknown, and
+    /// relationships with an unknown partner or between an entity and
+    /// itself. Entities declared external count as known. Errors are
+    /// grouped in that order, each group in the order inputs were added.
+    /// `build` runs the same checks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::{SimulationBuildError, SimulationBuilder};
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::types::Timestamp;
+    /// use behavioral_pathways::enums::Species;
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let entity = EntityBuilder::new()
+    ///     .id("person")
+    ///     .species(Species::Human)
+    ///     .birth_date(Timestamp::from_ymd_hms(1990, 6, 15, 0, 0, 0))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let builder = SimulationBuilder::new(reference)
+    ///     .add_entity(entity.clone(), Timestamp::from_ymd_hms(1980, 1, 1, 0, 0, 0))
+    ///     .add_entity(entity, reference);
+    /// let errors = builder.validate();
+    /// assert_eq!(errors.len(), 2);
+    /// assert!(matches!(errors[0], SimulationBuildError::DuplicateEntityId(_)));
+    /// assert!(matches!(errors[1], SimulationBuildError::AnchorBeforeBirth(..)));
+    /// ```
+    #[must_use]
+    pub fn validate(&self) -> Vec<SimulationBuildError> {
+        let mut errors = Vec::new();
 
         // Track entity IDs for duplicate detection and reference validation
         let mut seen_ids = std::collections::HashSet::new();
-
-        // Add entities
         for pending in &self.entities {
             let id = pending.entity.id().clone();
             if !seen_ids.insert(id.clone()) {
-                return Err(SimulationBuildError::DuplicateEntityId(id));
+                errors.push(SimulationBuildError::DuplicateEntityId(id));
             }
         }
 
-        // Validate event source and target references
+        errors.extend(
+            self.entities
+                .iter()
+                .filter_map(|pending| anchor_error(&pending.entity, pending.anchor_timestamp)),
+        );
+
+        // External entities may be referenced without being added
+        let known = |id: &EntityId| seen_ids.contains(id) || self.external_entities.contains(id);
+
         for pending in &self.events {
-            // Validate source if present
-            if let Some(source) = pending.event.source() {
-                if !seen_ids.contains(source) {
-                    return Err(SimulationBuildError::EventReferencesUnknownEntity(
-                        pending.event.id().clone(),
-                        source.clone(),
-                    ));
-                }
-            }
-            // Validate target if present
-            if let Some(target) = pending.event.target() {
-                if !seen_ids.contains(target) {
-                    return Err(SimulationBuildError::EventReferencesUnknownEntity(
-                        pending.event.id().clone(),
-                        target.clone(),
-                    ));
-                }
-            }
+            errors.extend(event_errors(&pending.event, known));
         }
 
-        // Validate relationship references
         for pending in &self.relationships {
-            // Check for self-relationship
             if pending.entity_a == pending.entity_b {
-                return Err(SimulationBuildError::SelfRelationship(
+                errors.push(SimulationBuildError::SelfRelationship(
                     pending.entity_a.clone(),
                 ));
             }
-
-            // Check entity_a exists
-            if !seen_ids.contains(&pending.entity_a) {
-                return Err(SimulationBuildError::RelationshipReferencesUnknownEntity(
-                    pending.id.clone(),
-                    pending.entity_a.clone(),
-                ));
+            let mut partners = vec![&pending.entity_a];
+            if pending.entity_b != pending.entity_a {
+                partners.push(&pending.entity_b);
             }
-
-            // Check entity_b exists
-            if !seen_ids.contains(&pending.entity_b) {
-                return Err(SimulationBuildError::RelationshipReferencesUnknownEntity(
-                    pending.id.clone(),
-                    pending.entity_b.clone(),
-                ));
+            for partner in partners {
+                if !known(partner) {
+                    errors.push(SimulationBuildError::RelationshipReferencesUnknownEntity(
+                        pending.id.clone(),
+                        partner.clone(),
+                    ));
+                }
             }
         }
 
+        errors
+    }
+
+    /// Builds the simulation.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error `validate` finds if there is one, or
+    /// `SimulationBuildError::Multiple` holding all of them if there are
+    /// several. Errors are:
+    /// - A duplicate entity ID was added
+    /// - An entity is anchored before its birth date
+    /// - An event references an entity that doesn't exist and was not
+    ///   declared external
+    /// - A relationship references an entity that doesn't exist and was not
+    ///   declared external
+    /// - A relationship between an entity and itself was attempted
+    pub fn build(self) -> Result<Simulation, SimulationBuildError> {
+        let mut errors = self.validate();
+        match errors.len() {
+            0 => {}
+            1 => return Err(errors.remove(0)),
+            _ => return Err(SimulationBuildError::Multiple(errors)),
+        }
+
+        let mut simulation = Simulation::new(self.reference_date);
+        simulation.set_stochastic_config(self.stochastic_seed.map(|seed| StochasticConfig {
+            seed,
+            magnitude: self.noise_magnitude,
+        }));
+
         // All validations passed - now add everything to the simulation
+        for id in self.external_entities {
+            simulation.declare_external_entity(id);
+        }
+
         for pending in self.entities {
             simulation.add_entity(pending.entity, pending.anchor_timestamp);
         }
@@ -274,12 +379,38 @@ impl SimulationBuilder {
     }
 }
 
+/// Returns an error if `entity` is anchored before its birth date.
+pub(crate) fn anchor_error(entity: &Entity, anchor: Timestamp) -> Option<SimulationBuildError> {
+    let birth = entity.birth_date()?;
+    (anchor < birth)
+        .then(|| SimulationBuildError::AnchorBeforeBirth(entity.id().clone(), birth, anchor))
+}
+
+/// Returns an error for each source, target, and witness of `event` that
+/// `known` rejects.
+pub(crate) fn event_errors(
+    event: &Event,
+    known: impl Fn(&EntityId) -> bool,
+) -> Vec<SimulationBuildError> {
+    event
+        .source()
+        .into_iter()
+        .chain(event.target())
+        .chain(event.witnesses())
+        .filter(|id| !known(id))
+        .map(|id| {
+            SimulationBuildError::EventReferencesUnknownEntity(event.id().clone(), id.clone())
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::entity::EntityBuilder;
     use crate::enums::{EventType, Species};
     use crate::event::EventBuilder;
+    use crate::types::Duration;
 
     fn create_human(id: &str) -> Entity {
         EntityBuilder::new()
@@ -428,6 +559,35 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn builder_accepts_declared_external_entities() {
+        let pen_pal = EntityId::new("pen_pal").unwrap();
+        let target = EntityId::new("person_001").unwrap();
+        let letter = EventBuilder::new(EventType::Interaction)
+            .source(pen_pal.clone())
+            .target(target.clone())
+            .build()
+            .unwrap();
+
+        let sim = SimulationBuilder::new(reference_date())
+            .add_entity(create_human("person_001"), reference_date())
+            .declare_external_entity(pen_pal.clone())
+            .add_event(letter, Timestamp::from_ymd_hms(2024, 1, 15, 0, 0, 0))
+            .add_relationship(
+                target.clone(),
+                pen_pal.clone(),
+                RelationshipSchema::Peer,
+                reference_date(),
+            )
+            .build()
+            .unwrap();
+
+        assert!(sim.is_external_entity(&pen_pal));
+        assert_eq!(sim.events_for(&target).len(), 1);
+        assert_eq!(sim.relationships_for(&pen_pal).len(), 1);
+        assert!(sim.validate().is_empty());
+    }
+
     #[test]
     fn builder_add_relationship() {
         let alice_id = EntityId::new("alice").unwrap();
@@ -520,6 +680,105 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn builder_anchor_before_birth_fails() {
+        let birth = Timestamp::from_ymd_hms(2000, 1, 1, 0, 0, 0);
+        let anchor = Timestamp::from_ymd_hms(1999, 6, 1, 0, 0, 0);
+        let entity = EntityBuilder::new()
+            .id("alice")
+            .species(Species::Human)
+            .birth_date(birth)
+            .build()
+            .unwrap();
+
+        let err = SimulationBuilder::new(reference_date())
+            .add_entity(entity.clone(), anchor)
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            SimulationBuildError::AnchorBeforeBirth(entity.id().clone(), birth, anchor)
+        );
+
+        // Anchoring at birth is allowed
+        assert!(SimulationBuilder::new(reference_date())
+            .add_entity(entity, birth)
+            .build()
+            .is_ok());
+    }
+
+    #[test]
+    fn builder_reports_all_errors_together() {
+        let alice_id = EntityId::new("alice").unwrap();
+        let ghost = EntityId::new("ghost").unwrap();
+        let birth = Timestamp::from_ymd_hms(2000, 1, 1, 0, 0, 0);
+        let unborn = EntityBuilder::new()
+            .id("unborn")
+            .species(Species::Human)
+            .birth_date(birth)
+            .build()
+            .unwrap();
+        let event = EventBuilder::new(EventType::Conflict)
+            .source(ghost.clone())
+            .target(alice_id.clone())
+            .build()
+            .unwrap();
+        let event_id = event.id().clone();
+
+        let builder = SimulationBuilder::new(reference_date())
+            .add_entity(create_human("alice"), reference_date())
+            .add_entity(create_human("alice"), reference_date())
+            .add_entity(unborn, reference_date() - Duration::years(30))
+            .add_event(event, reference_date())
+            .add_relationship(
+                alice_id.clone(),
+                alice_id.clone(),
+                RelationshipSchema::Peer,
+                reference_date(),
+            )
+            .add_relationship(
+                alice_id.clone(),
+                ghost.clone(),
+                RelationshipSchema::Peer,
+                reference_date(),
+            );
+
+        let errors = builder.validate();
+        assert_eq!(errors.len(), 5);
+        assert_eq!(
+            errors[0],
+            SimulationBuildError::DuplicateEntityId(alice_id.clone())
+        );
+        assert!(matches!(
+            errors[1],
+            SimulationBuildError::AnchorBeforeBirth(ref id, at, _)
+                if id.as_str() == "unborn" && at == birth
+        ));
+        assert_eq!(
+            errors[2],
+            SimulationBuildError::EventReferencesUnknownEntity(event_id, ghost.clone())
+        );
+        assert_eq!(errors[3], SimulationBuildError::SelfRelationship(alice_id));
+        assert!(matches!(
+            errors[4],
+            SimulationBuildError::RelationshipReferencesUnknownEntity(_, ref id) if *id == ghost
+        ));
+
+        let err = builder.build().unwrap_err();
+        assert_eq!(err, SimulationBuildError::Multiple(errors));
+        assert!(err
+            .to_string()
+            .starts_with("5 errors: Duplicate entity ID: alice; "));
+    }
+
+    #[test]
+    fn builder_validate_is_empty_for_consistent_inputs() {
+        let builder = SimulationBuilder::new(reference_date())
+            .add_entity(create_human("alice"), reference_date())
+            .add_entity(create_human("bob"), reference_date());
+        assert!(builder.validate().is_empty());
+    }
+
     #[test]
     fn builder_fluent_chain() {
         let alice = create_human("alice");
@@ -550,6 +809,33 @@ mod tests {
         assert_eq!(sim.events_for(&alice_id).len(), 1);
     }
 
+    #[test]
+    fn builder_stochastic_sets_seed_and_magnitude() {
+        let plain = SimulationBuilder::new(reference_date()).build().unwrap();
+        assert!(plain.stochastic_config().is_none());
+
+        let seeded = SimulationBuilder::new(reference_date())
+            .stochastic(42)
+            .build()
+            .unwrap();
+        assert_eq!(seeded.stochastic_config(), Some(&StochasticConfig::new(42)));
+
+        let wide = SimulationBuilder::new(reference_date())
+            .noise_magnitude(0.3)
+            .stochastic(42)
+            .build()
+            .unwrap();
+        let config = wide.stochastic_config().unwrap();
+        assert_eq!(config.seed, 42);
+        assert!((config.magnitude - 0.3).abs() < f64::EPSILON);
+
+        let unseeded = SimulationBuilder::new(reference_date())
+            .noise_magnitude(0.3)
+            .build()
+            .unwrap();
+        assert!(unseeded.stochastic_config().is_none());
+    }
+
     #[test]
     fn simulation_build_error_display() {
         let alice_id = EntityId::new("alice").unwrap();
@@ -558,7 +844,7 @@ mod tests {
         let event_id = EventId::new("test_event").unwrap();
         let rel_id = RelationshipId::new("test_rel").unwrap();
 
-        let err1 = SimulationBuildError::DuplicateEntityId(alice_id);
+        let err1 = SimulationBuildError::DuplicateEntityId(alice_id.clone());
         assert!(format!("{}", err1).contains("alice"));
         assert!(format!("{}", err1).contains("Duplicate"));
 
@@ -575,6 +861,11 @@ mod tests {
         let err4 = SimulationBuildError::SelfRelationship(carol_id);
         assert!(format!("{}", err4).contains("carol"));
         assert!(format!("{}", err4).contains("itself"));
+
+        let birth = Timestamp::from_ymd_hms(2000, 1, 1, 0, 0, 0);
+        let err5 = SimulationBuildError::AnchorBeforeBirth(alice_id, birth, reference_date());
+        assert!(format!("{}", err5).contains("alice"));
+        assert!(format!("{}", err5).contains("before its birth"));
     }
 
     #[test]
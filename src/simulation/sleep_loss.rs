@@ -0,0 +1,159 @@
+//! Sleep loss and the setbacks that follow it.
+//!
+//! A tired entity takes bad news harder. An event in the
+//! `SLEEP_LOSS_WINDOW` after a `SleepDeprivation` targeting the same entity
+//! has its negative valence amplified in proportion to the fatigue that
+//! sleep loss added, so a night with almost no sleep makes the next day's
+//! setbacks hurt more than a slightly short one. Only the most recent
+//! sleep loss counts.
+//!
+//! Amplification depends on the event history, so the simulation applies
+//! it wherever it interprets events for queries, validation, and
+//! compaction.
+
+use crate::entity::Entity;
+use crate::enums::{EventType, NeedsPath, StatePath};
+use crate::event::Event;
+use crate::processor::{amplify_when_tired, interpret_event, InterpretedEvent};
+use crate::simulation::{Simulation, TimestampedEvent};
+use crate::types::Duration;
+
+/// How long after a sleep deprivation later events are felt more harshly.
+pub const SLEEP_LOSS_WINDOW: Duration = Duration::hours(24);
+
+/// Returns the sleep deprivation `te` follows, if any.
+///
+/// This is the most recent `SleepDeprivation` targeting the same entity
+/// strictly before `te` and within `SLEEP_LOSS_WINDOW` of it. An `excluded`
+/// event is treated as missing from the history.
+pub(crate) fn preceding_sleep_loss<'s>(
+    simulation: &'s Simulation,
+    te: &TimestampedEvent,
+    excluded: Option<&TimestampedEvent>,
+) -> Option<&'s TimestampedEvent> {
+    let event = te.event();
+    if event.event_type() == EventType::SleepDeprivation {
+        return None;
+    }
+    let target = event.target()?;
+
+    simulation
+        .events_for(target)
+        .into_iter()
+        .filter(|loss| {
+            loss.event().event_type() == EventType::SleepDeprivation
+                && loss.event().target() == Some(target)
+                && !excluded.is_some_and(|excluded| std::ptr::eq(*loss, excluded))
+                && loss.timestamp() < te.timestamp()
+                && te.timestamp() - loss.timestamp() <= SLEEP_LOSS_WINDOW
+        })
+        .max_by_key(|loss| loss.timestamp())
+}
+
+/// Amplifies `interpreted` for `entity` if it carries negative valence,
+/// using the fatigue `sleep_loss` adds to the same entity.
+pub(crate) fn amplify_after_sleep_loss(
+    interpreted: &mut InterpretedEvent,
+    sleep_loss: &Event,
+    entity: &Entity,
+) {
+    if interpreted.valence_delta >= 0.0 {
+        return;
+    }
+    let fatigue = interpret_event(sleep_loss, entity)
+        .state_deltas
+        .iter()
+        .find(|(path, _)| *path == StatePath::Needs(NeedsPath::Fatigue))
+        .map_or(0.0, |(_, delta)| *delta as f32);
+    amplify_when_tired(interpreted, sleep_loss.id().clone(), fatigue);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::EntityBuilder;
+    use crate::enums::{EventPayload, Species};
+    use crate::event::EventBuilder;
+    use crate::types::{EntityId, EventId, Timestamp};
+
+    fn reference() -> Timestamp {
+        Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+    }
+
+    fn setup() -> (Simulation, EntityId) {
+        let mut sim = Simulation::new(reference());
+        let entity = EntityBuilder::new()
+            .id("person_001")
+            .species(Species::Human)
+            .build()
+            .unwrap();
+        let id = sim.add_entity(entity, reference());
+        (sim, id)
+    }
+
+    fn add_sleep_loss(sim: &mut Simulation, id: &EntityId, hours: f64, at: Duration) -> EventId {
+        let event = EventBuilder::new(EventType::SleepDeprivation)
+            .target(id.clone())
+            .payload(EventPayload::Sleep {
+                hours,
+                quality: 0.5,
+            })
+            .build()
+            .unwrap();
+        let event_id = event.id().clone();
+        sim.add_event(event, reference() + at);
+        event_id
+    }
+
+    fn failure(id: &EntityId, at: Duration) -> TimestampedEvent {
+        let event = EventBuilder::new(EventType::Failure)
+            .target(id.clone())
+            .severity(0.6)
+            .build()
+            .unwrap();
+        TimestampedEvent::new(event, reference() + at)
+    }
+
+    #[test]
+    fn most_recent_sleep_loss_in_window_is_found() {
+        let (mut sim, id) = setup();
+        add_sleep_loss(&mut sim, &id, 5.0, Duration::hours(2));
+        let recent = add_sleep_loss(&mut sim, &id, 3.0, Duration::hours(10));
+
+        let found = preceding_sleep_loss(&sim, &failure(&id, Duration::hours(20)), None);
+        assert_eq!(found.map(|te| te.event().id()), Some(&recent));
+
+        // Outside the window, or before the sleep loss, nothing is found
+        let late = failure(&id, Duration::hours(35));
+        assert!(preceding_sleep_loss(&sim, &late, None).is_none());
+        let early = failure(&id, Duration::hours(1));
+        assert!(preceding_sleep_loss(&sim, &early, None).is_none());
+    }
+
+    #[test]
+    fn worse_sleep_amplifies_negative_valence_more() {
+        let entity = EntityBuilder::new()
+            .species(Species::Human)
+            .build()
+            .unwrap();
+        let fail = failure(&EntityId::new("person_001").unwrap(), Duration::hours(12));
+        let plain = interpret_event(fail.event(), &entity);
+
+        let mut amplified = Vec::new();
+        for hours in [6.0, 0.0] {
+            let loss = EventBuilder::new(EventType::SleepDeprivation)
+                .payload(EventPayload::Sleep {
+                    hours,
+                    quality: 0.5,
+                })
+                .build()
+                .unwrap();
+            let mut interpreted = plain.clone();
+            amplify_after_sleep_loss(&mut interpreted, &loss, &entity);
+            assert_eq!(interpreted.preceding_sleep_loss.as_ref(), Some(loss.id()));
+            amplified.push(interpreted.valence_delta);
+        }
+        assert!(amplified[0] < plain.valence_delta);
+        assert!(amplified[1] < amplified[0]);
+    }
+}
@@ -3,21 +3,79 @@
 //! This module provides `EntityQueryHandle` for querying entity state at
 //! any timestamp, and `ComputedState` as the result type.
 
-use crate::context::apply_context_effects;
-use crate::entity::Entity;
-use crate::enums::{HexacoPath, LifeStage, StatePath};
-use crate::memory::{apply_memory_consolidation, MemoryEntry};
+#[cfg(all(feature = "context", feature = "relationships"))]
+use crate::context::CONCEALMENT_SUPPORT_SUPPRESSION;
+#[cfg(feature = "context")]
+use crate::context::{
+    apply_concealment_effects, apply_context_effects, apply_health_condition_effects,
+    apply_role_exit_effects, shape_context_over, Concealment, HealthCondition, RoleExit,
+};
+use crate::context::{shape_context, EcologicalContext, TurningPoint};
+use crate::entity::{AffectiveState, Entity, PhysiologicalState};
+use crate::event::{appraise_severity, Event, SeverityAppraisal};
+use crate::enums::{
+    AlertTrigger, Emotion, EventType, HexacoPath, LifeStage, QueryPath, Species, SpiralType,
+    StatePath,
+};
+#[cfg(feature = "context")]
+use crate::enums::{EventPayload, SupportType};
+#[cfg(feature = "memory")]
+use crate::memory::apply_memory_consolidation;
+use crate::memory::maintenance::consolidate_at;
+use crate::memory::MemoryEntry;
+#[cfg(feature = "its")]
+use crate::processor::{check_factor_thresholds, check_its_thresholds, check_spiral_alerts};
+#[cfg(any(feature = "context", feature = "memory"))]
+use crate::processor::apply_span_hooks;
 use crate::processor::{
-    advance_state, apply_developmental_effects, apply_interpreted_event_to_state,
-    get_derived_emotion, interpret_event, regress_state, reverse_interpreted_event_from_state,
-    EmotionIntensities, InterpretedEvent,
+    active_spirals, apply_anticipation_to_state, apply_developmental_effects,
+    apply_interpreted_event_to_state, compute_its_factors, get_derived_emotion,
+    reverse_anticipation_from_state, reverse_interpreted_event_from_state, EmotionIntensities,
+    InterpretedEvent, ItsContributor, ItsContributors, ItsFactors,
+};
+use crate::simulation::adaptive_series::adaptive_series;
+use crate::simulation::affect_variability::{affect_variability, AffectVariability};
+use crate::simulation::base_absorption::BaseAbsorption;
+use crate::simulation::behavior_prediction::{BehaviorPrediction, BehaviorQuery};
+#[cfg(all(feature = "context", feature = "relationships"))]
+use crate::simulation::bereavement::dissolution_of;
+use crate::simulation::compaction::{Checkpoint, CompactedHistory};
+use crate::simulation::contagion::{contagion_shift, MoodShift};
+use crate::simulation::context_change::replay_context_changes;
+#[cfg(feature = "context")]
+use crate::simulation::context_change::{context_changes_within, ContextChange};
+use crate::simulation::emotion_model::{ExtendedEmotions, RecentAttribution};
+use crate::simulation::event_memory::event_memories;
+#[cfg(feature = "context")]
+use crate::simulation::context_series::context_at;
+use crate::simulation::forecast::{
+    self, ForecastDistribution, ForecastError, ForecastScenario,
+};
+use crate::simulation::identity::{identity_profile, IdentityProfile};
+use crate::simulation::influence::influence_ranking;
+use crate::simulation::its_invariants::{
+    invariant_violations, InvariantViolation, ItsInvariantMode,
+};
+use crate::simulation::profiling::{ProfilePhase, Profiler};
+use crate::simulation::recognition::interpret_in_history;
+use crate::simulation::threshold::time_to_value;
+use crate::simulation::turning_points::turning_points_at;
+use crate::simulation::validation::anchor_issues;
+use crate::simulation::{
+    AnchoredEntity, ApproximationReason, Observation, ObservationPolicy, ObservationResidual,
+    RegressionQuality, Simulation, StateDiff, TimestampedAlert, TimestampedEvent,
+    STATE_DIFF_EPSILON,
 };
-use crate::simulation::{RegressionQuality, Simulation, TimestampedEvent};
 use crate::state::{
-    apply_formative_modifiers, effective_base_at, BaseShiftRecord, IndividualState, StateInterpreter,
+    audit_formative_modifiers, effective_base_at, BaseShiftRecord, IndividualState, LifeStageTable,
+    StateInterpreter,
 };
-use crate::types::{Alert, Duration, EntityId, Timestamp};
+use crate::types::{Alert, Duration, EntityId, EventId, Timestamp};
+use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::Mutex;
 
 /// A handle for querying entity state at different timestamps.
 ///
@@ -49,6 +107,8 @@ use std::collections::HashMap;
 pub struct EntityQueryHandle<'a> {
     simulation: &'a Simulation,
     entity_id: EntityId,
+    /// The walk `state_range()` continues between samples.
+    range: Mutex<Option<RangeCursor<'a>>>,
 }
 
 impl<'a> EntityQueryHandle<'a> {
@@ -57,6 +117,7 @@ impl<'a> EntityQueryHandle<'a> {
         EntityQueryHandle {
             simulation,
             entity_id,
+            range: Mutex::new(None),
         }
     }
 
@@ -74,13 +135,98 @@ impl<'a> EntityQueryHandle<'a> {
             .map(|a| a.anchor_timestamp())
     }
 
+    /// Returns the events `state_at()` would apply moving from `start` to `end`.
+    ///
+    /// Events are returned in the order `state_at()` applies them:
+    /// chronologically, with same-timestamp events in insertion order.
+    ///
+    /// # Boundary Rules
+    ///
+    /// - `start <= end`: events in `(start, end]`, matching forward projection
+    /// - `start > end`: events in `(end, start]`, matching backward regression
+    ///
+    /// So `events_between(anchor, t)` is exactly the set of events that
+    /// `state_at(t)` applies (or reverses) from the anchor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::enums::{EventType, Species};
+    /// use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new().id("person_001").species(Species::Human).build().unwrap();
+    /// let id = sim.add_entity(entity, reference);
+    ///
+    /// let event = EventBuilder::new(EventType::Achievement).target(id.clone()).build().unwrap();
+    /// sim.add_event(event, reference + Duration::days(5));
+    ///
+    /// let handle = sim.entity(&id).unwrap();
+    /// assert_eq!(handle.events_between(reference, reference + Duration::days(5)).len(), 1);
+    /// let later = reference + Duration::days(9);
+    /// assert!(handle.events_between(reference + Duration::days(5), later).is_empty());
+    /// ```
+    #[must_use]
+    pub fn events_between(&self, start: Timestamp, end: Timestamp) -> Vec<&'a TimestampedEvent> {
+        let (after, through) = if start <= end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+        self.simulation
+            .events_for_range(&self.entity_id, after, through)
+            .collect()
+    }
+
+    /// Returns the number of events targeting this entity.
+    #[must_use]
+    pub fn event_count(&self) -> usize {
+        self.simulation.events_for(&self.entity_id).len()
+    }
+
+    /// Returns the earliest event targeting this entity.
+    ///
+    /// Among events sharing the earliest timestamp, returns the one added first.
+    #[must_use]
+    pub fn first_event(&self) -> Option<&'a TimestampedEvent> {
+        self.stored_events().first().copied()
+    }
+
+    /// Returns the latest event targeting this entity.
+    ///
+    /// Among events sharing the latest timestamp, returns the one added last.
+    #[must_use]
+    pub fn last_event(&self) -> Option<&'a TimestampedEvent> {
+        self.stored_events().last().copied()
+    }
+
+    /// Returns all events of the given type targeting this entity, in
+    /// application order.
+    #[must_use]
+    pub fn events_of_type(&self, event_type: EventType) -> Vec<&'a TimestampedEvent> {
+        self.stored_events()
+            .into_iter()
+            .filter(|te| te.event().event_type() == event_type)
+            .collect()
+    }
+
     /// Computes the entity's state at the given timestamp.
     ///
     /// This is the primary consumer API. It computes state by:
-    /// 1. Starting from the anchor state
+    /// 1. Starting from the latest anchor or observation at or before the
+    ///    timestamp (or the earliest one, if all are later)
     /// 2. Applying decay forward or reversing backward
     /// 3. Applying events in the time range
     ///
+    /// Under `ObservationPolicy::Blend`, part of the next observation's
+    /// residual is added when the timestamp falls in its blend window.
+    /// When contagion is enabled, the mood shift from members of shared
+    /// microsystems is added to every state except a known one.
+    ///
     /// # Arguments
     ///
     /// * `timestamp` - The time at which to compute state
@@ -119,35 +265,413 @@ impl<'a> EntityQueryHandle<'a> {
     /// Panics if the entity does not exist in the simulation. This should never
     /// happen when using the public API (`Simulation::entity()`), which returns
     /// `None` for unknown entities. Use `Simulation::entity()` to check existence.
+    ///
+    /// In debug builds with `ValidationConfig::assert_on_query` set, also
+    /// panics if the entity's anchor or events are saturated. A state that
+    /// breaks an ITS invariant never panics: outside `ItsInvariantMode::Off`
+    /// it is logged, and `try_state_at()` returns it as an error in
+    /// `ItsInvariantMode::Strict`.
     #[must_use]
     pub fn state_at(&self, timestamp: Timestamp) -> ComputedState {
-        let anchored = self
-            .simulation
+        self.checked_state(timestamp, &History::default())
+    }
+
+    /// Computes the state at `timestamp` for a public query over `history`,
+    /// logging the ITS invariants it breaks unless checking is off.
+    fn checked_state(&self, timestamp: Timestamp, history: &History<'_>) -> ComputedState {
+        let state = self.query_state(timestamp, history);
+        if self.simulation.its_invariant_mode() != ItsInvariantMode::Off {
+            self.warn_violations(timestamp, &state);
+        }
+        state
+    }
+
+    /// Logs each ITS invariant `state`, computed at `timestamp`, breaks as a
+    /// `tracing` warning.
+    fn warn_violations(&self, timestamp: Timestamp, state: &ComputedState) {
+        for violation in self.invariant_violations(timestamp, state) {
+            tracing::warn!(entity = %self.entity_id, "{violation}");
+        }
+    }
+
+    /// Computes the state at `timestamp` for a public query, with its
+    /// birth-date warning, saturation assertion, and contagion shift.
+    fn query_state(&self, timestamp: Timestamp, history: &History<'_>) -> ComputedState {
+        let anchored = self.anchored();
+        if anchored.entity().birth_date().is_none() && timestamp != anchored.anchor_timestamp() {
+            tracing::warn!(
+                entity = %self.entity_id,
+                "entity has no birth date; age and life stage stay at their anchor values"
+            );
+        }
+        if cfg!(debug_assertions) && self.simulation.validation_config().assert_on_query {
+            let issues = anchor_issues(self.simulation, anchored);
+            assert!(
+                issues.is_empty(),
+                "saturated inputs for entity '{}':\n{}",
+                self.entity_id,
+                issues
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            );
+        }
+        self.simulation.profiler().record_query();
+        let shift = match self.simulation.contagion_config() {
+            Some(config) => contagion_shift(self.simulation, config, &self.entity_id, timestamp),
+            None => MoodShift::default(),
+        };
+        self.state_with_shift(timestamp, shift, history)
+    }
+
+    /// Computes the entity's state at the given timestamp, checking it
+    /// against the ITS invariants as the `ItsInvariantMode` asks.
+    ///
+    /// With `ItsInvariantMode::Strict`, a state that breaks an invariant is
+    /// returned as an error. With `Warn` the violations are logged, as by
+    /// `state_at()`, and the state is returned; with `Off` nothing is
+    /// checked.
+    ///
+    /// # Errors
+    ///
+    /// In strict mode, returns the first invariant the state breaks: desire
+    /// without TB, PB, and interpersonal hopelessness above their
+    /// thresholds; attempt risk without desire and elevated acquired
+    /// capability; or acquired capability below a value anchored or
+    /// observed at or before `timestamp`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::enums::Species;
+    /// use behavioral_pathways::simulation::{ItsInvariantMode, Simulation};
+    /// use behavioral_pathways::types::{Duration, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// sim.set_its_invariant_mode(ItsInvariantMode::Strict);
+    /// let entity = EntityBuilder::new().species(Species::Human).build().unwrap();
+    /// let id = sim.add_entity(entity, reference);
+    ///
+    /// let state = sim.entity(&id).unwrap().try_state_at(reference + Duration::days(7));
+    /// assert!(state.is_ok());
+    /// ```
+    pub fn try_state_at(&self, timestamp: Timestamp) -> Result<ComputedState, InvariantViolation> {
+        let state = self.query_state(timestamp, &History::default());
+        match self.simulation.its_invariant_mode() {
+            ItsInvariantMode::Off => Ok(state),
+            ItsInvariantMode::Warn => {
+                self.warn_violations(timestamp, &state);
+                Ok(state)
+            }
+            ItsInvariantMode::Strict => {
+                match self.invariant_violations(timestamp, &state).into_iter().next() {
+                    Some(violation) => Err(violation),
+                    None => Ok(state),
+                }
+            }
+        }
+    }
+
+    /// Returns every ITS invariant `state`, computed at `timestamp`, breaks.
+    fn invariant_violations(
+        &self,
+        timestamp: Timestamp,
+        state: &ComputedState,
+    ) -> Vec<InvariantViolation> {
+        let anchored = self.anchored();
+        let earlier = anchor_points(anchored)
+            .iter()
+            .filter(|point| point.timestamp <= timestamp)
+            .map(|point| {
+                let capability = point
+                    .entity(anchored)
+                    .individual_state()
+                    .mental_health()
+                    .acquired_capability_effective();
+                (point.timestamp, f64::from(capability))
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        invariant_violations(&self.entity_id, timestamp, state, earlier)
+    }
+
+    /// Computes the entity's state at the given timestamp, ignoring
+    /// contagion from members of shared microsystems.
+    pub(crate) fn state_without_contagion(&self, timestamp: Timestamp) -> ComputedState {
+        self.state_with_shift(timestamp, MoodShift::default(), &History::default())
+    }
+
+    /// Computes the entity's state over `history` with a contagion shift
+    /// added to its mood.
+    fn state_with_shift(
+        &self,
+        timestamp: Timestamp,
+        shift: MoodShift,
+        history: &History<'_>,
+    ) -> ComputedState {
+        let anchored = self.anchored();
+        let points = anchor_points(anchored);
+        let prev_index = points
+            .iter()
+            .rposition(|point| point.timestamp <= timestamp)
+            .unwrap_or(0);
+        let prev = &points[prev_index];
+
+        // Blend only between two known states, once inside the window
+        // before the later one. The window never reaches back past the
+        // earlier state, so that state stays exact
+        let blend = match self.simulation.observation_policy() {
+            ObservationPolicy::Blend { window } if prev.timestamp <= timestamp => points
+                .get(prev_index + 1)
+                .and_then(|next| {
+                    let start = (next.timestamp - window).max(prev.timestamp);
+                    if timestamp < start {
+                        return None;
+                    }
+                    let weight = (timestamp - start).as_millis() as f64
+                        / (next.timestamp - start).as_millis() as f64;
+                    Some((self.residual_between(anchored, prev, next), weight))
+                }),
+            _ => None,
+        };
+
+        let entity = prev.entity(anchored);
+        let mut computed = self.state_from(
+            &entity,
+            prev.timestamp,
+            timestamp,
+            blend.as_ref().map(|(residual, weight)| (residual, *weight)),
+            shift,
+            history,
+        );
+        let raised = anchored
+            .external_alerts()
+            .partition_point(|alert| alert.timestamp() <= timestamp);
+        computed.external_alerts = anchored.external_alerts()[..raised].to_vec();
+        computed.in_crisis = computed
+            .external_alerts
+            .iter()
+            .any(|alert| alert.is_crisis_at(timestamp));
+        #[cfg(feature = "its")]
+        {
+            computed.contributor_activations = self.contributor_activations(timestamp);
+        }
+        computed
+    }
+
+    /// Returns the ITS contributors activated by events targeting this
+    /// entity at or before `timestamp`, each at its event's severity.
+    #[cfg(feature = "its")]
+    fn contributor_activations(&self, timestamp: Timestamp) -> ItsContributors {
+        let mut contributors = ItsContributors::new();
+        for te in self.events_through(timestamp) {
+            let event = te.event();
+            for &contributor in ItsContributor::for_event_type(event.event_type()) {
+                contributors.activate(contributor, te.timestamp(), event.severity() as f32);
+            }
+        }
+        contributors
+    }
+
+    /// Returns the residual of each observation of this entity.
+    ///
+    /// Each residual is the observed state minus the state projected from
+    /// the anchor or observation before it (or, for an observation before
+    /// the anchor, regressed from the known state after it). Residuals are
+    /// sorted by timestamp and are the same under either observation
+    /// policy, which makes them suitable for checking where the model
+    /// systematically over- or under-predicts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::enums::{MoodPath, Species, StatePath};
+    /// use behavioral_pathways::state::IndividualState;
+    /// use behavioral_pathways::types::{Duration, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new().id("person_001").species(Species::Human).build().unwrap();
+    /// let id = sim.add_entity(entity, reference);
+    ///
+    /// let mut observed = IndividualState::new();
+    /// observed.mood_mut().add_valence_delta(-0.4);
+    /// sim.add_observation(&id, observed, reference + Duration::years(1));
+    ///
+    /// let residuals = sim.entity(&id).unwrap().observation_residuals();
+    /// assert_eq!(residuals.len(), 1);
+    /// assert!(residuals[0].get(StatePath::Mood(MoodPath::Valence)) < -0.3);
+    /// ```
+    #[must_use]
+    pub fn observation_residuals(&self) -> Vec<ObservationResidual> {
+        let anchored = self.anchored();
+        let points = anchor_points(anchored);
+        points
+            .iter()
+            .enumerate()
+            .filter(|(_, point)| point.observation.is_some())
+            .map(|(index, point)| {
+                let reference = if index == 0 { 1 } else { index - 1 };
+                self.residual_between(anchored, &points[reference], point)
+            })
+            .collect()
+    }
+
+    /// Returns the anchored entity this handle queries.
+    fn anchored(&self) -> &'a AnchoredEntity {
+        self.simulation
             .get_anchored_entity(&self.entity_id)
-            .expect("EntityQueryHandle created for non-existent entity - use Simulation::entity() to check existence");
-        let anchor_timestamp = anchored.anchor_timestamp();
-        let entity = anchored.entity();
+            .expect("EntityQueryHandle created for non-existent entity - use Simulation::entity() to check existence")
+    }
+
+    /// Returns what compaction has folded for this entity, if anything.
+    pub(crate) fn compacted_history(&self) -> Option<&'a CompactedHistory> {
+        self.anchored().compacted_history()
+    }
+
+    /// Returns the compaction checkpoint a forward projection from `from`
+    /// to `timestamp` can resume at.
+    fn checkpoint_for(&self, from: Timestamp, timestamp: Timestamp) -> Option<&'a Checkpoint> {
+        self.compacted_history()
+            .and_then(CompactedHistory::checkpoint)
+            .filter(|checkpoint| checkpoint.from() == from && checkpoint.resume_at() <= timestamp)
+    }
+
+    /// Projects the entity's latest known state at or before `cutoff` to
+    /// the last increment landing at or before `cutoff`.
+    ///
+    /// Returns `None` if no increments land between the known state and
+    /// the cutoff.
+    pub(crate) fn compaction_checkpoint(&self, cutoff: Timestamp) -> Option<Checkpoint> {
+        let anchored = self.anchored();
+        let points = anchor_points(anchored);
+        let point = points.iter().rev().find(|point| point.timestamp <= cutoff)?;
+        let entity = point.entity(anchored);
+
+        let existing = self.checkpoint_for(point.timestamp, cutoff);
+        let (start, resume) = match existing {
+            Some(checkpoint) => (checkpoint.state().clone(), checkpoint.resume_at()),
+            None => (entity.individual_state().clone(), point.timestamp),
+        };
+        let (applied_events, increments) =
+            self.get_onset_increments_for_range(point.timestamp, cutoff, true);
+        let pending = increments.partition_point(|increment| increment.landing <= resume);
+        if pending == increments.len() {
+            return existing.cloned();
+        }
+        let profiler = self.simulation.profiler();
+        let interpreted_events: Vec<Cow<'_, InterpretedEvent>> = applied_events
+            .iter()
+            .map(|te| {
+                profiler.time(ProfilePhase::Interpret, || {
+                    Cow::Owned(interpret_in_history(self.simulation, te, &entity, None))
+                })
+            })
+            .collect();
+        let (state, resume_at) = apply_increments(
+            profiler,
+            &entity,
+            &mut BaseAbsorption::new(self.simulation.base_absorption_config(), entity.species()),
+            (start, resume),
+            &applied_events,
+            &interpreted_events,
+            &increments[pending..],
+        );
+        Some(Checkpoint::new(point.timestamp, resume_at, state))
+    }
+
+    /// Returns the known state at `observed` minus the projection to it
+    /// from `reference`.
+    fn residual_between(
+        &self,
+        anchored: &AnchoredEntity,
+        reference: &AnchorPoint<'_>,
+        observed: &AnchorPoint<'_>,
+    ) -> ObservationResidual {
+        let known = self.state_from(
+            &observed.entity(anchored),
+            observed.timestamp,
+            observed.timestamp,
+            None,
+            MoodShift::default(),
+            &History::default(),
+        );
+        let projected = self.state_from(
+            &reference.entity(anchored),
+            reference.timestamp,
+            observed.timestamp,
+            None,
+            MoodShift::default(),
+            &History::default(),
+        );
+        ObservationResidual::between(&known, &projected, observed.timestamp)
+    }
 
-        // Clone the individual state as our starting point
-        let mut state = entity.individual_state().clone();
+    /// Projects `entity`, known at `anchor_timestamp`, to `timestamp`.
+    ///
+    /// `blend` adds a weighted residual, and `shift` a contagion mood
+    /// shift, to the projected state before it is interpreted. `history`
+    /// may leave an event out and supply interpretations to reuse.
+    fn state_from(
+        &self,
+        entity: &Entity,
+        anchor_timestamp: Timestamp,
+        timestamp: Timestamp,
+        blend: Option<(&ObservationResidual, f64)>,
+        shift: MoodShift,
+        history: &History<'_>,
+    ) -> ComputedState {
+        let profiler = self.simulation.profiler();
         let species = entity.species().clone();
 
         // Short-circuit: if querying at anchor timestamp, return anchor state
         if timestamp == anchor_timestamp {
+            let state = entity.individual_state().clone();
             let age_at_timestamp = self.compute_age_at_timestamp(entity, timestamp);
-            let life_stage =
-                LifeStage::from_age_years_for_species(&species, age_at_timestamp.as_years() as f64);
-
-            let interpreter = StateInterpreter::from_state(&state);
+            let life_stage = entity
+                .config()
+                .life_stage_at(&species, age_at_timestamp.as_years_f64());
+
+            let interpreter =
+                profiler.time(ProfilePhase::Interpreter, || StateInterpreter::from_state(&state));
+            let extended_emotions = self.extended_emotions(entity, &state, timestamp, history);
+            let context = self.context_as_of(entity, timestamp).into_owned();
+            let turning_points = turning_points_at(
+                context.chronosystem().turning_points(),
+                &[],
+                &[],
+                timestamp,
+                false,
+            );
             return ComputedState {
                 individual_state: state,
+                context,
                 age_at_timestamp,
                 life_stage,
+                species,
+                life_stage_table: entity.config().life_stage_table().cloned(),
+                age_is_static: entity.birth_date().is_none(),
                 regression_quality: RegressionQuality::Exact,
+                approximate_reasons: Vec::new(),
+                confidence: entity.completeness().confidence(),
+                extended_emotions,
                 alerts: std::cell::OnceCell::new(),
+                external_alerts: Vec::new(),
+                in_crisis: false,
+                timestamp,
+                contributor_activations: ItsContributors::new(),
+                its_factors: std::cell::OnceCell::new(),
+                its_contributors: std::cell::OnceCell::new(),
                 interpretations: interpreter.interpretations().clone(),
                 summary: interpreter.summary().to_string(),
+                baseline_delta: StateDiff::default(),
                 delta_summary: None,
+                base_shift_history: Vec::new(),
+                turning_points,
             };
         }
 
@@ -157,109 +681,47 @@ impl<'a> EntityQueryHandle<'a> {
         // Get events targeting this entity in the relevant time range
         // Forward: (anchor, target] - exclude anchor, include target
         // Backward: [target, anchor) - include target, exclude anchor
-        let events = self.get_sorted_events_for_range(anchor_timestamp, timestamp, is_forward);
-
-        // Compute regression quality based on events
-        let regression_quality = if is_forward {
-            RegressionQuality::Exact
-        } else {
-            self.determine_regression_quality(&events)
-        };
+        let mut events = self.get_sorted_events_for_range(anchor_timestamp, timestamp, is_forward);
+        events.retain(|te| !history.excludes(te));
+
+        // Compute regression quality based on events. Compaction removed
+        // events before its cutoff, so earlier states are approximate
+        let compacted_cutoff = self.compacted_history().map(CompactedHistory::cutoff);
+        let mut approximate_reasons = Vec::new();
+        if let Some(cutoff) = compacted_cutoff.filter(|cutoff| timestamp < *cutoff) {
+            approximate_reasons.push(ApproximationReason::CompactedHistory { cutoff });
+        }
+        if !is_forward && self.determine_regression_quality(&events).is_approximate() {
+            approximate_reasons.push(ApproximationReason::IrreversibleEvents);
+        }
 
-        // Interpret events once using the anchor entity's personality
-        // Personality (HEXACO) is stable, so using anchor state is appropriate
-        let interpreted_events: Vec<InterpretedEvent> = events
-            .iter()
-            .map(|te| interpret_event(te.event(), entity))
-            .collect();
+        // Formative shifts and context effects come only from what happened
+        // to the entity itself, not from what it witnessed
+        events.retain(|te| te.event().target() == Some(&self.entity_id));
 
         // Collect base shift records from events that have formative shifts
         // These represent permanent personality changes from significant life events
-        let base_shift_records: Vec<BaseShiftRecord> = collect_base_shift_records(
-            &events,
-            entity,
-            timestamp,
-            is_forward,
-        );
-
-        // Helper to compute age at a given timestamp for developmental effects
-        let compute_age_at = |ts: Timestamp| -> Duration {
-            if let Some(birth_date) = entity.birth_date() {
-                if ts >= birth_date {
-                    ts - birth_date
-                } else {
-                    Duration::zero()
-                }
-            } else {
-                // Without birth_date, age is constant at anchor age
-                entity.age()
-            }
+        let event_shifts = profiler.time(ProfilePhase::BaseShifts, || {
+            collect_event_base_shifts(&events, entity, timestamp, is_forward)
+        });
+        let base_shift_records: Vec<BaseShiftRecord> =
+            event_shifts.iter().flatten().cloned().collect();
+
+        let walked = if history.walk {
+            self.walked_state(entity, anchor_timestamp, timestamp)
+        } else {
+            None
         };
-
-        if is_forward {
-            // Forward: use cursor pattern to track current time position
-            // This avoids compounding decay by advancing in deltas between events
-            let mut cursor = anchor_timestamp;
-
-            for (te, interpreted) in events.iter().zip(interpreted_events.iter()) {
-                // Advance from cursor to this event's timestamp
-                let delta = te.timestamp() - cursor;
-                state = advance_state(state, delta);
-
-                // Apply developmental effects to scale event impact
-                // Compute entity's age at the time of this event
-                let age_at_event = compute_age_at(te.timestamp());
-                let age_days = age_at_event.as_days();
-                let dev_factor =
-                    apply_developmental_effects(entity, te.event(), 1.0, age_days, te.timestamp());
-
-                // Scale the interpreted event by the developmental factor
-                let scaled_interpreted = interpreted.scaled_by(dev_factor);
-
-                // Apply the scaled interpreted event deltas
-                state = apply_interpreted_event_to_state(state, &scaled_interpreted);
-                // Move cursor forward
-                cursor = te.timestamp();
-            }
-
-            // Advance remaining time from cursor to target timestamp
-            let remaining = timestamp - cursor;
-            state = advance_state(state, remaining);
+        let (mut state, projection_reasons) = match walked {
+            Some(walked) => walked,
+            None => self.project(entity, anchor_timestamp, timestamp, history),
+        };
+        approximate_reasons.extend(projection_reasons);
+        let regression_quality = if approximate_reasons.is_empty() {
+            RegressionQuality::Exact
         } else {
-            // Backward: use cursor pattern in reverse
-            // Start at anchor and work backward through events in reverse order
-            let mut cursor = anchor_timestamp;
-
-            // Events are sorted chronologically, so iterate in reverse
-            // Use indices to access both events and interpreted events in sync
-            for i in (0..events.len()).rev() {
-                let te = &events[i];
-                let interpreted = &interpreted_events[i];
-
-                // Regress from cursor to this event's timestamp
-                let delta = cursor - te.timestamp();
-                state = regress_state(state, delta);
-
-                // Apply developmental effects to scale event impact for reversal
-                // Compute entity's age at the time of this event
-                let age_at_event = compute_age_at(te.timestamp());
-                let age_days = age_at_event.as_days();
-                let dev_factor =
-                    apply_developmental_effects(entity, te.event(), 1.0, age_days, te.timestamp());
-
-                // Scale the interpreted event by the developmental factor
-                let scaled_interpreted = interpreted.scaled_by(dev_factor);
-
-                // Reverse the scaled interpreted event using its actual deltas
-                state = reverse_interpreted_event_from_state(state, &scaled_interpreted);
-                // Move cursor backward
-                cursor = te.timestamp();
-            }
-
-            // Regress remaining time from cursor to target timestamp
-            let remaining = cursor - timestamp;
-            state = regress_state(state, remaining);
-        }
+            RegressionQuality::Approximate
+        };
 
         // Apply hook points AFTER decay and events, in order:
         // 1. Context effects (ecological systems)
@@ -267,99 +729,1386 @@ impl<'a> EntityQueryHandle<'a> {
         //
         // Developmental effects (plasticity, sensitive periods) are applied above
         // during event processing via apply_developmental_effects().
-        let total_duration = if is_forward {
-            timestamp - anchor_timestamp
-        } else {
-            anchor_timestamp - timestamp
-        };
-        let relationship_quality = estimate_relationship_quality(entity);
         let age_at_timestamp = self.compute_age_at_timestamp(entity, timestamp);
-        let life_stage =
-            LifeStage::from_age_years_for_species(&species, age_at_timestamp.as_years() as f64);
-        state = apply_context_effects(
-            state,
-            entity.context(),
-            relationship_quality,
-            total_duration,
-            life_stage,
-            timestamp,
-        );
-        state = apply_memory_consolidation(state, entity.memories(), total_duration);
+        let life_stage = entity
+            .config()
+            .life_stage_at(&species, age_at_timestamp.as_years_f64());
+        // The hooks accrue over the span, so a backward span removes what
+        // the forward one adds
+        #[cfg(any(feature = "context", feature = "memory"))]
+        {
+            state = apply_span_hooks(state, is_forward, |state| {
+                #[cfg(feature = "context")]
+                let state = profiler.time(ProfilePhase::Context, || {
+                    self.apply_context_hooks(
+                        state,
+                        entity,
+                        &events,
+                        anchor_timestamp,
+                        timestamp,
+                        life_stage,
+                    )
+                });
+                #[cfg(feature = "memory")]
+                let state = profiler.time(ProfilePhase::Memory, || {
+                    let total_duration = if is_forward {
+                        timestamp - anchor_timestamp
+                    } else {
+                        anchor_timestamp - timestamp
+                    };
+                    apply_memory_consolidation(
+                        state,
+                        entity.memories(),
+                        species.psychological_duration(total_duration),
+                    )
+                });
+                state
+            });
+        }
 
         // Apply formative base shifts to HEXACO personality traits
         // This computes effective base values for each trait based on accumulated shifts
-        state = apply_base_shifts_to_state(state, &base_shift_records, timestamp);
+        state = profiler.time(ProfilePhase::BaseShifts, || {
+            apply_base_shifts_to_state(state, &base_shift_records, timestamp)
+        });
+
+        if let Some((residual, weight)) = blend {
+            residual.apply_weighted(&mut state, weight);
+        }
+        if !shift.is_zero() {
+            state.mood_mut().add_valence_delta(shift.valence as f32);
+            state.mood_mut().add_arousal_delta(shift.arousal as f32);
+        }
 
-        let baseline_state = entity.individual_state();
-        let interpreter = StateInterpreter::from_state_with_baseline(&state, baseline_state);
+        // The person shapes a copy of the context over the time since the
+        // anchor. Before the anchor, the stored context already holds it.
+        let context = self.context_as_of(entity, timestamp);
+        let context = if is_forward {
+            shape_context(
+                &context,
+                entity.individual_state(),
+                timestamp - anchor_timestamp,
+            )
+        } else {
+            context.into_owned()
+        };
+        let turning_points = turning_points_at(
+            context.chronosystem().turning_points(),
+            &events,
+            &event_shifts,
+            timestamp,
+            is_forward,
+        );
+
+        let (baseline_delta, interpreter) = profiler.time(ProfilePhase::Interpreter, || {
+            let baseline_delta = StateDiff::between(entity.individual_state(), &state);
+            let interpreter = StateInterpreter::from_state_with_diff(&state, &baseline_delta);
+            (baseline_delta, interpreter)
+        });
+        let extended_emotions = self.extended_emotions(entity, &state, timestamp, history);
         ComputedState {
             individual_state: state,
+            context,
             age_at_timestamp,
             life_stage,
+            species,
+            life_stage_table: entity.config().life_stage_table().cloned(),
+            age_is_static: entity.birth_date().is_none(),
             regression_quality,
+            approximate_reasons,
+            confidence: entity.completeness().confidence(),
+            extended_emotions,
             alerts: std::cell::OnceCell::new(),
+            external_alerts: Vec::new(),
+            in_crisis: false,
+            timestamp,
+            contributor_activations: ItsContributors::new(),
+            its_factors: std::cell::OnceCell::new(),
+            its_contributors: std::cell::OnceCell::new(),
             interpretations: interpreter.interpretations().clone(),
             summary: interpreter.summary().to_string(),
+            baseline_delta,
             delta_summary: interpreter.delta_summary().map(|s| s.to_string()),
+            base_shift_history: base_shift_records,
+            turning_points,
         }
     }
 
-    /// Gets events in the time range, sorted chronologically.
-    ///
-    /// # Boundary Rules
-    ///
-    /// - Forward projection: (anchor, target] - excludes anchor, includes target
-    /// - Backward regression: (target, anchor] - excludes target, includes anchor
+    /// Projects the individual state of `entity`, known at
+    /// `anchor_timestamp`, through decay and the onset increments landing
+    /// between it and `timestamp`.
     ///
-    /// The anchor state already reflects events that occurred at anchor time,
-    /// so for forward projection we exclude anchor. For backward regression,
-    /// we include anchor events (which need to be reversed) but exclude target
-    /// events (which should not exist in the pre-event state).
-    fn get_sorted_events_for_range(
+    /// Returns the state and, for a regression, every reason it is
+    /// approximate: absorbed delta moved back out of a base, and each
+    /// feedback spiral active at an event it crossed.
+    fn project(
         &self,
-        anchor: Timestamp,
-        target: Timestamp,
-        is_forward: bool,
-    ) -> Vec<&'a TimestampedEvent> {
-        let mut events: Vec<_> = self
-            .simulation
-            .events_for(&self.entity_id)
-            .into_iter()
-            .filter(|te| {
-                let ts = te.timestamp();
-                if is_forward {
-                    // Forward: (anchor, target] - after anchor, up to and including target
-                    ts > anchor && ts <= target
-                } else {
-                    // Backward: (target, anchor] - after target, up to and including anchor
-                    ts > target && ts <= anchor
-                }
+        entity: &Entity,
+        anchor_timestamp: Timestamp,
+        timestamp: Timestamp,
+        history: &History<'_>,
+    ) -> (IndividualState, Vec<ApproximationReason>) {
+        let profiler = self.simulation.profiler();
+        let is_forward = timestamp > anchor_timestamp;
+
+        // Split events into onset increments landing in the range. Instant
+        // events contribute one increment at their timestamp; ramped events
+        // contribute one per increment of their ramp window
+        let (mut applied_events, mut increments) =
+            self.get_onset_increments_for_range(anchor_timestamp, timestamp, is_forward);
+
+        // Interpret events once using the anchor entity's personality
+        // Personality (HEXACO) is stable, so using anchor state is appropriate
+        let interpreted_events: Vec<Cow<'_, InterpretedEvent>> = applied_events
+            .iter()
+            .enumerate()
+            .filter(|(_, te)| !history.excludes(te))
+            .map(|(index, te)| match history.reusable(index) {
+                Some(interpreted) => Cow::Borrowed(interpreted),
+                None => profiler.time(ProfilePhase::Interpret, || {
+                    Cow::Owned(interpret_in_history(
+                        self.simulation,
+                        te,
+                        entity,
+                        history.excluded,
+                    ))
+                }),
             })
             .collect();
+        if let Some(excluded) = applied_events.iter().position(|te| history.excludes(te)) {
+            applied_events.remove(excluded);
+            increments.retain(|increment| increment.event_index != excluded);
+            for increment in &mut increments {
+                if increment.event_index > excluded {
+                    increment.event_index -= 1;
+                }
+            }
+        }
 
-        events.sort_by_key(|te| te.timestamp());
-        events
-    }
-
-    /// Determines regression quality based on events.
-    ///
-    /// Regression is approximate when:
-    /// - Trauma events are present (AC increases are not reversible)
-    /// - Events triggered feedback loops (spirals) - Phase 10+
-    fn determine_regression_quality(&self, events: &[&TimestampedEvent]) -> RegressionQuality {
-        use crate::enums::EventCategory;
+        // Sustained chronic deltas are timed from the anchor
+        let mut absorption =
+            BaseAbsorption::new(self.simulation.base_absorption_config(), entity.species());
+        if is_forward {
+            // Forward: use cursor pattern to track current time position
+            // This avoids compounding decay by advancing in deltas between events
+            let (start, resume) = self.projection_start(entity, anchor_timestamp, timestamp);
+            let pending = increments.partition_point(|increment| increment.landing <= resume);
+            let (state, cursor) = apply_increments(
+                profiler,
+                entity,
+                &mut absorption,
+                (start, resume),
+                &applied_events,
+                &interpreted_events,
+                &increments[pending..],
+            );
 
-        for te in events {
-            let event = te.event();
+            // Advance remaining time from cursor to target timestamp
+            let remaining = timestamp - cursor;
+            let state = profiler.time(ProfilePhase::Advance, || {
+                absorption.advance(state, entity.species().psychological_duration(remaining))
+            });
+            (state, Vec::new())
+        } else {
+            // Backward: use cursor pattern in reverse, starting at the anchor
+            let (state, cursor) = reverse_increments(
+                profiler,
+                entity,
+                &mut absorption,
+                (entity.individual_state().clone(), anchor_timestamp),
+                &applied_events,
+                &interpreted_events,
+                &increments,
+            );
+
+            // Regress remaining time from cursor to target timestamp
+            let remaining = cursor - timestamp;
+            let state = profiler.time(ProfilePhase::Advance, || {
+                absorption.regress(state, entity.species().psychological_duration(remaining))
+            });
+            let spirals = spirals_crossed(
+                profiler,
+                entity,
+                BaseAbsorption::new(self.simulation.base_absorption_config(), entity.species()),
+                (state.clone(), timestamp),
+                &applied_events,
+                &interpreted_events,
+                &increments,
+            );
+            (state, regression_reasons(spirals, &absorption))
+        }
+    }
+
+    /// Returns the state a forward projection from `entity`, known at
+    /// `from`, starts at and when it holds.
+    ///
+    /// A compaction checkpoint already holds every increment up to its
+    /// resume point, so projection continues from there.
+    fn projection_start(
+        &self,
+        entity: &Entity,
+        from: Timestamp,
+        timestamp: Timestamp,
+    ) -> (IndividualState, Timestamp) {
+        match self.checkpoint_for(from, timestamp) {
+            Some(checkpoint) => (checkpoint.state().clone(), checkpoint.resume_at()),
+            None => (entity.individual_state().clone(), from),
+        }
+    }
+
+    /// Continues the `state_range()` walk from `entity`, known at `from`, to
+    /// `timestamp`.
+    ///
+    /// Starts a new walk when the sample needs a different known state,
+    /// direction, or checkpoint, or lies behind the walk. Returns what
+    /// `project()` would, or `None` outside a range.
+    fn walked_state(
+        &self,
+        entity: &Entity,
+        from: Timestamp,
+        timestamp: Timestamp,
+    ) -> Option<(IndividualState, Vec<ApproximationReason>)> {
+        let mut range = self.range.lock().unwrap_or_else(|err| err.into_inner());
+        let range = range.as_mut()?;
+        let (first, last) = range.window;
+        if timestamp < first || timestamp > last {
+            return None;
+        }
+        let forward = timestamp > from;
+        let resume = match self.checkpoint_for(from, timestamp) {
+            Some(checkpoint) if forward => checkpoint.resume_at(),
+            _ => from,
+        };
+        let continues = range.walk.as_ref().is_some_and(|walk| {
+            walk.from == from
+                && walk.resume == resume
+                && walk.forward == forward
+                && walk.reaches(timestamp)
+        });
+        if !continues {
+            let end = if forward { last } else { first };
+            range.walk = Some(self.start_walk(entity, from, timestamp, end));
+        }
+        let walk = range.walk.as_mut()?;
+        Some(walk.sample(self.simulation.profiler(), entity, timestamp))
+    }
+
+    /// Starts a walk from `entity`, known at `from`, for samples from
+    /// `timestamp` to `end`.
+    fn start_walk(
+        &self,
+        entity: &Entity,
+        from: Timestamp,
+        timestamp: Timestamp,
+        end: Timestamp,
+    ) -> RangeWalk<'a> {
+        let profiler = self.simulation.profiler();
+        let forward = timestamp > from;
+        let (applied_events, increments) = self.get_onset_increments_for_range(from, end, forward);
+        let interpreted_events = applied_events
+            .iter()
+            .map(|te| {
+                profiler.time(ProfilePhase::Interpret, || {
+                    Cow::Owned(interpret_in_history(self.simulation, te, entity, None))
+                })
+            })
+            .collect();
+        let (state, resume) = if forward {
+            self.projection_start(entity, from, timestamp)
+        } else {
+            (entity.individual_state().clone(), from)
+        };
+        let pending = if forward {
+            increments.partition_point(|increment| increment.landing <= resume)..increments.len()
+        } else {
+            0..increments.len()
+        };
+        let absorption =
+            BaseAbsorption::new(self.simulation.base_absorption_config(), entity.species());
+        RangeWalk {
+            from,
+            resume,
+            forward,
+            applied_events,
+            interpreted_events,
+            increments,
+            pending,
+            state,
+            cursor: resume,
+            absorption: absorption.clone(),
+            replay: absorption,
+        }
+    }
+
+    /// Derives the emotions in `state` at `timestamp` under the
+    /// simulation's emotion model, gated by the attributions the entity
+    /// made for its events.
+    fn extended_emotions(
+        &self,
+        entity: &Entity,
+        state: &IndividualState,
+        timestamp: Timestamp,
+        history: &History<'_>,
+    ) -> ExtendedEmotions {
+        let model = self.simulation.emotion_model();
+        let attributions = match model.longest_window() {
+            Some(window) => {
+                // One second wider than the window, which includes its start
+                let after = timestamp - window - Duration::seconds(1);
+                let stored = self
+                    .simulation
+                    .events_for_range(&self.entity_id, after, timestamp);
+                RecentAttribution::collect(
+                    entity,
+                    self.with_occurrences(stored, Some(after), timestamp)
+                        .iter()
+                        .map(|te| &**te)
+                        .filter(|te| !history.excludes(te)),
+                    timestamp,
+                    window,
+                )
+            }
+            None => Vec::new(),
+        };
+        model.derive_extended(state, &attributions, timestamp)
+    }
+
+    /// Returns the entity's context with its scheduled changes at or
+    /// before `timestamp` applied.
+    fn context_as_of<'e>(
+        &self,
+        entity: &'e Entity,
+        timestamp: Timestamp,
+    ) -> Cow<'e, EcologicalContext> {
+        replay_context_changes(
+            entity.context(),
+            self.anchored().context_changes(),
+            timestamp,
+        )
+    }
+
+    /// Applies ecological context, role exit, health condition, and
+    /// concealment effects for the range from `anchor_timestamp` to
+    /// `timestamp`.
+    #[cfg(feature = "context")]
+    fn apply_context_hooks(
+        &self,
+        mut state: IndividualState,
+        entity: &Entity,
+        events: &[Cow<'_, TimestampedEvent>],
+        anchor_timestamp: Timestamp,
+        timestamp: Timestamp,
+        life_stage: LifeStage,
+    ) -> IndividualState {
+        let is_forward = timestamp > anchor_timestamp;
+        let total_duration = if is_forward {
+            timestamp - anchor_timestamp
+        } else {
+            anchor_timestamp - timestamp
+        };
+
+        // Collect role exits (retirement, job exits) that end microsystem memberships
+        let role_exits =
+            collect_role_exits(events, entity, anchor_timestamp, timestamp, is_forward);
+
+        // Collect chronic health conditions and the caregiving received
+        // while ill, which add sustained load on top of event effects
+        let (health_conditions, caregiving) =
+            collect_health_conditions(events, anchor_timestamp, timestamp, is_forward);
+
+        // Collect concealments, which add a maintenance load and weaken the
+        // buffering of relationships the secret is kept in
+        let concealments = collect_concealments(events, anchor_timestamp, timestamp, is_forward);
+
+        // Without relationship support, only attached slots buffer loneliness
+        #[cfg(feature = "relationships")]
+        let simulated_support = self.simulated_relationship_support(timestamp, &concealments);
+        #[cfg(not(feature = "relationships"))]
+        let simulated_support = 0.0;
+        let relationship_quality =
+            estimate_relationship_quality(entity, self.simulation, simulated_support);
+        // The span starts under the context held at its earlier end, and
+        // scheduled context changes inside it take over from there
+        let (start, end) = if is_forward {
+            (anchor_timestamp, timestamp)
+        } else {
+            (timestamp, anchor_timestamp)
+        };
+        let initial = self.context_as_of(entity, start);
+        let changes = context_changes_within(self.anchored().context_changes(), start, end);
+        // Forward effects accrue while the person shapes the context
+        let shaped = if is_forward {
+            shape_context_over(&initial, entity.individual_state(), total_duration)
+        } else {
+            initial.into_owned()
+        };
+        // Context series replace static values at each effect's timestamp
+        let series = self.simulation.context_series();
+        if role_exits.is_empty() && changes.is_empty() {
+            state = apply_context_effects(
+                state,
+                &context_at(&shaped, series, timestamp),
+                relationship_quality,
+                total_duration,
+                life_stage,
+                timestamp,
+            );
+        } else {
+            // Apply context in segments so an exited role or a changed
+            // context only contributes effects for the time it was held
+            let exits = role_exits
+                .iter()
+                .map(|exit| (exit.timestamp, ContextBoundary::RoleExit(exit)));
+            let scheduled = changes
+                .iter()
+                .map(|change| (change.timestamp(), ContextBoundary::Change(change.change())));
+            let mut boundaries: Vec<_> = exits.chain(scheduled).collect();
+            boundaries.sort_by_key(|(at, _)| *at);
+            let mut context = shaped.clone();
+            let mut segment_start = start;
+            for (at, boundary) in boundaries {
+                state = apply_context_effects(
+                    state,
+                    &context_at(&context, series, at),
+                    relationship_quality,
+                    at - segment_start,
+                    life_stage,
+                    at,
+                );
+                match boundary {
+                    ContextBoundary::RoleExit(exit) => {
+                        context.end_role(exit);
+                    }
+                    ContextBoundary::Change(change) => {
+                        change.apply(&mut context);
+                    }
+                }
+                segment_start = at;
+            }
+            state = apply_context_effects(
+                state,
+                &context_at(&context, series, end),
+                relationship_quality,
+                end - segment_start,
+                life_stage,
+                end,
+            );
+            if !role_exits.is_empty() {
+                state = apply_role_exit_effects(state, &context, &role_exits, timestamp);
+            }
+        }
+        state = apply_health_condition_effects(
+            state,
+            &shaped,
+            &health_conditions,
+            &caregiving,
+            timestamp,
+        );
+        apply_concealment_effects(state, &concealments, timestamp)
+    }
+
+    /// Samples the entity's state from `start` to `end` at a fixed step.
+    ///
+    /// Samples are taken at `start`, `start + step`, and so on up to and
+    /// including `end`. Each sample equals the `state_at()` query at its
+    /// time, computed in one pass as `state_range()` does.
+    /// Returns only the `start` sample when `step` is zero, and nothing
+    /// when `end` is before `start`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::enums::Species;
+    /// use behavioral_pathways::types::{Duration, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new().id("person_001").species(Species::Human).build().unwrap();
+    /// let id = sim.add_entity(entity, reference);
+    ///
+    /// let handle = sim.entity(&id).unwrap();
+    /// let end = reference + Duration::days(30);
+    /// let series = handle.state_series(reference, end, Duration::days(7));
+    /// assert_eq!(series.len(), 5);
+    /// ```
+    #[must_use]
+    pub fn state_series(
+        &self,
+        start: Timestamp,
+        end: Timestamp,
+        step: Duration,
+    ) -> Vec<(Timestamp, ComputedState)> {
+        self.state_range(start, end, step)
+    }
+
+    /// Samples the entity's state from `start` to `end` at a fixed step,
+    /// walking through the window once.
+    ///
+    /// Uses the same sampling rules as `state_series()`, and each sample
+    /// equals the `state_at()` query at its time. Rather than projecting
+    /// from the anchor for every sample, the walk applies each event
+    /// increment once and decays a copy of its state to each sample. It
+    /// restarts only where a sample projects from a different known state,
+    /// such as an observation. Samples before the anchor are walked
+    /// backward from it, latest first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::enums::{EventType, MoodPath, Species, StatePath};
+    /// use behavioral_pathways::types::{Duration, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new().id("person_001").species(Species::Human).build().unwrap();
+    /// let id = sim.add_entity(entity, reference);
+    /// let loss = EventBuilder::new(EventType::Loss).target(id.clone()).build().unwrap();
+    /// sim.add_event(loss, reference + Duration::days(10));
+    ///
+    /// let valence = StatePath::Mood(MoodPath::Valence);
+    /// let handle = sim.entity(&id).unwrap();
+    /// let end = reference + Duration::days(30);
+    /// let range = handle.state_range(reference, end, Duration::days(7));
+    /// assert_eq!(range.len(), 5);
+    /// for (ts, state) in &range {
+    ///     assert_eq!(state.get_effective(valence), handle.state_at(*ts).get_effective(valence));
+    /// }
+    /// ```
+    #[must_use]
+    pub fn state_range(
+        &self,
+        start: Timestamp,
+        end: Timestamp,
+        step: Duration,
+    ) -> Vec<(Timestamp, ComputedState)> {
+        let times = sample_times(start, end, step);
+        let (Some(&first), Some(&last)) = (times.first(), times.last()) else {
+            return Vec::new();
+        };
+        *self.range.lock().unwrap_or_else(|err| err.into_inner()) = Some(RangeCursor {
+            window: (first, last),
+            walk: None,
+        });
+
+        // Samples before the earliest known state regress from it, so they
+        // are walked latest first
+        let earliest = anchor_points(self.anchored())[0].timestamp;
+        let before = times.partition_point(|ts| *ts < earliest);
+        let history = History {
+            walk: true,
+            ..History::default()
+        };
+        let mut states: Vec<(Timestamp, ComputedState)> = times[..before]
+            .iter()
+            .rev()
+            .chain(&times[before..])
+            .map(|&ts| (ts, self.checked_state(ts, &history)))
+            .collect();
+        states[..before].reverse();
+
+        *self.range.lock().unwrap_or_else(|err| err.into_inner()) = None;
+        states
+    }
+
+    /// Samples one effective state value from `start` to `end` at a fixed step.
+    ///
+    /// Uses the same sampling rules as `state_series()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::enums::{MoodPath, Species, StatePath};
+    /// use behavioral_pathways::types::{Duration, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new().id("person_001").species(Species::Human).build().unwrap();
+    /// let id = sim.add_entity(entity, reference);
+    ///
+    /// let valence = StatePath::Mood(MoodPath::Valence);
+    /// let handle = sim.entity(&id).unwrap();
+    /// let end = reference + Duration::days(2);
+    /// let series = handle.series(valence, reference, end, Duration::days(1));
+    /// assert_eq!(series.len(), 3);
+    /// assert_eq!(series[0].0, reference);
+    /// ```
+    #[must_use]
+    pub fn series(
+        &self,
+        path: StatePath,
+        start: Timestamp,
+        end: Timestamp,
+        step: Duration,
+    ) -> Vec<(Timestamp, f64)> {
+        self.state_range(start, end, step)
+            .into_iter()
+            .map(|(ts, state)| (ts, state.get_effective(path)))
+            .collect()
+    }
+
+    /// Returns what changed in the entity's state from `from` to `to`.
+    ///
+    /// Equivalent to `state_at(to).delta_from(&state_at(from))`: every path
+    /// whose effective value moved by more than `STATE_DIFF_EPSILON`, with
+    /// its old value, new value, and signed delta, plus the ITS thresholds
+    /// crossed. `from` may be later than `to`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::enums::{EventType, SocialCognitionPath, Species, StatePath};
+    /// use behavioral_pathways::types::{Duration, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new().id("person_001").species(Species::Human).build().unwrap();
+    /// let id = sim.add_entity(entity, reference);
+    /// let exclusion = EventBuilder::new(EventType::SocialExclusion)
+    ///     .target(id.clone())
+    ///     .severity(0.8)
+    ///     .build()
+    ///     .unwrap();
+    /// sim.add_event(exclusion, reference + Duration::days(5));
+    ///
+    /// let handle = sim.entity(&id).unwrap();
+    /// let diff = handle.diff(reference, reference + Duration::days(6));
+    /// let loneliness = StatePath::SocialCognition(SocialCognitionPath::Loneliness);
+    /// assert!(diff.change(loneliness).unwrap().delta() > 0.0);
+    /// ```
+    #[must_use]
+    pub fn diff(&self, from: Timestamp, to: Timestamp) -> StateDiff {
+        self.diff_with_epsilon(from, to, STATE_DIFF_EPSILON)
+    }
+
+    /// Returns what changed from `from` to `to`, reporting only paths that
+    /// moved by more than `epsilon`.
+    ///
+    /// See `StateDiff::between_with_epsilon`.
+    #[must_use]
+    pub fn diff_with_epsilon(&self, from: Timestamp, to: Timestamp, epsilon: f64) -> StateDiff {
+        StateDiff::between_with_epsilon(
+            self.state_at(from).individual_state(),
+            self.state_at(to).individual_state(),
+            epsilon,
+        )
+    }
+
+    /// Samples one effective state value from `start` to `end` with only as
+    /// many points as linear interpolation needs to stay within `tolerance`.
+    ///
+    /// Points are chronological and always include `start`, `end`, every
+    /// event timestamp in between and the second before each event, so
+    /// event jumps are kept as steps. Each increment of a ramped event is
+    /// kept the same way. Between them, points are added where the exact
+    /// trajectory bends away from a straight line. Returns nothing when
+    /// `end` is before `start`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::enums::{EventType, MoodPath, Species, StatePath};
+    /// use behavioral_pathways::types::{Duration, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new().id("person_001").species(Species::Human).build().unwrap();
+    /// let id = sim.add_entity(entity, reference);
+    /// let loss = EventBuilder::new(EventType::Loss).target(id.clone()).build().unwrap();
+    /// sim.add_event(loss, reference + Duration::days(100));
+    ///
+    /// let valence = StatePath::Mood(MoodPath::Valence);
+    /// let handle = sim.entity(&id).unwrap();
+    /// let end = reference + Duration::years(1);
+    /// let points = handle.adaptive_series(valence, reference, end, 0.01);
+    /// assert!(points.len() < handle.series(valence, reference, end, Duration::days(1)).len());
+    /// ```
+    #[must_use]
+    pub fn adaptive_series(
+        &self,
+        path: StatePath,
+        start: Timestamp,
+        end: Timestamp,
+        tolerance: f64,
+    ) -> Vec<(Timestamp, f64)> {
+        adaptive_series(self, path, start, end, tolerance)
+    }
+
+    /// Ranks this entity's events by how much leaving each one out changes
+    /// the state at `at`.
+    ///
+    /// Each event's influence is the Euclidean distance, over every state
+    /// path, between the state at `at` and the state at `at` had that one
+    /// event never happened. Both are projected from the anchor or
+    /// observation `state_at()` projects from, without contagion or
+    /// observation blending. Events are returned from most to least
+    /// influential; ties keep application order.
+    ///
+    /// Events are interpreted once and the interpretations are shared by
+    /// every leave-one-out projection, so ranking costs about one query per
+    /// event. Events that cannot reach the projection, such as events
+    /// before the anchor, have no influence and are not re-projected.
+    /// Events removed by compaction are not ranked, and increments a
+    /// compaction checkpoint already holds stay applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::enums::{EventType, Species};
+    /// use behavioral_pathways::types::{Duration, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new().id("person_001").species(Species::Human).build().unwrap();
+    /// let id = sim.add_entity(entity, reference);
+    ///
+    /// let snub = EventBuilder::new(EventType::SocialExclusion)
+    ///     .target(id.clone())
+    ///     .severity(0.2)
+    ///     .build()
+    ///     .unwrap();
+    /// let assault = EventBuilder::new(EventType::Violence)
+    ///     .target(id.clone())
+    ///     .severity(0.9)
+    ///     .build()
+    ///     .unwrap();
+    /// let assault_id = assault.id().clone();
+    /// sim.add_event(snub, reference + Duration::days(1));
+    /// sim.add_event(assault, reference + Duration::days(2));
+    ///
+    /// let ranking = sim.entity(&id).unwrap().influence_ranking(reference + Duration::days(3));
+    /// assert_eq!(ranking[0].0, assault_id);
+    /// assert!(ranking[0].1 > ranking[1].1);
+    /// ```
+    #[must_use]
+    pub fn influence_ranking(&self, at: Timestamp) -> Vec<(EventId, f64)> {
+        influence_ranking(self, at)
+    }
+
+    /// Returns when `path` first satisfies `comparator` against `value`,
+    /// searching from `from` up to `from + horizon`.
+    ///
+    /// The comparator is applied as `effective.cmp(&value)`: `Less` waits
+    /// for the path to fall below `value`, `Greater` for it to rise above,
+    /// and `Equal` for it to reach `value` from either side. Returns `from`
+    /// if the condition already holds, and `None` if it does not hold by
+    /// the end of the horizon.
+    ///
+    /// Events already in the log are part of the projection. Between event
+    /// landings the path's exponential decay, plus any steady accumulation
+    /// from sustained context loads, is solved directly rather than
+    /// sampled; an event whose landing carries the path across `value`
+    /// answers with its landing time. Paths or segments that do not follow
+    /// that form, such as computed ITS factors, are bisected on
+    /// `state_at()` to within a second.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::enums::{EventType, MoodPath, Species, StatePath};
+    /// use behavioral_pathways::types::{Duration, Timestamp};
+    /// use std::cmp::Ordering;
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new().id("person_001").species(Species::Human).build().unwrap();
+    /// let id = sim.add_entity(entity, reference);
+    ///
+    /// let conflict = EventBuilder::new(EventType::Conflict)
+    ///     .target(id.clone())
+    ///     .severity(0.9)
+    ///     .build()
+    ///     .unwrap();
+    /// let landing = reference + Duration::hours(1);
+    /// sim.add_event(conflict, landing);
+    ///
+    /// // When does dominance recover to within 0.05 of its base?
+    /// let dominance = StatePath::Mood(MoodPath::Dominance);
+    /// let handle = sim.entity(&id).unwrap();
+    /// let recovered = handle
+    ///     .time_to_value(dominance, Ordering::Greater, -0.05, landing, Duration::days(30))
+    ///     .unwrap();
+    /// assert!(recovered > landing);
+    /// assert!(handle.state_at(recovered).get_effective(dominance) > -0.05);
+    /// ```
+    #[must_use]
+    pub fn time_to_value(
+        &self,
+        path: StatePath,
+        comparator: Ordering,
+        value: f64,
+        from: Timestamp,
+        horizon: Duration,
+    ) -> Option<Timestamp> {
+        time_to_value(self, path, comparator, value, from, horizon)
+    }
+
+    /// Summarizes how much the entity's mood varied over the `window`
+    /// ending at `window_end`.
+    ///
+    /// Takes `samples` evenly spaced samples, the last at `window_end`, in
+    /// one `state_range()` walk, and reports the standard deviation, range
+    /// and largest day-over-day change of valence, arousal and dominance.
+    /// The result is flagged labile when the valence standard deviation
+    /// exceeds [`LABILE_VALENCE_SD`](crate::simulation::LABILE_VALENCE_SD).
+    /// Fewer than two samples give a single sample with no spread.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::enums::{EventType, Species};
+    /// use behavioral_pathways::types::{Duration, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new().id("person_001").species(Species::Human).build().unwrap();
+    /// let id = sim.add_entity(entity, reference);
+    /// let loss = EventBuilder::new(EventType::Loss).target(id.clone()).build().unwrap();
+    /// sim.add_event(loss, reference + Duration::days(10));
+    ///
+    /// let handle = sim.entity(&id).unwrap();
+    /// let end = reference + Duration::days(20);
+    /// let variability = handle.affect_variability(end, Duration::days(20), 21);
+    /// assert_eq!(variability.samples, 21);
+    /// assert!(variability.valence.range > 0.0);
+    /// ```
+    #[must_use]
+    pub fn affect_variability(
+        &self,
+        window_end: Timestamp,
+        window: Duration,
+        samples: usize,
+    ) -> AffectVariability {
+        affect_variability(self, window_end, window, samples)
+    }
+
+    /// Returns the times in (`start`, `end`) where the projection jumps:
+    /// event increment landings and anchor points, sorted and distinct.
+    pub(crate) fn projection_breaks(&self, start: Timestamp, end: Timestamp) -> Vec<Timestamp> {
+        let (_, increments) = self.get_onset_increments_for_range(start, end, true);
+        let mut breaks: Vec<Timestamp> = increments
+            .iter()
+            .map(|increment| increment.landing)
+            .chain(
+                anchor_points(self.anchored())
+                    .iter()
+                    .map(|point| point.timestamp),
+            )
+            .filter(|ts| *ts > start && *ts < end)
+            .collect();
+        breaks.sort();
+        breaks.dedup();
+        breaks
+    }
+
+    /// Returns when anticipation increments land in (`start`, `end`].
+    pub(crate) fn anticipation_landings(&self, start: Timestamp, end: Timestamp) -> Vec<Timestamp> {
+        let (_, increments) = self.get_onset_increments_for_range(start, end, true);
+        increments
+            .iter()
+            .filter(|increment| increment.anticipatory)
+            .map(|increment| increment.landing)
+            .collect()
+    }
+
+    /// Returns the species whose time scale drives this entity's decay.
+    pub(crate) fn species(&self) -> &'a Species {
+        self.anchored().entity().species()
+    }
+
+    /// Returns the entity as anchored.
+    pub(crate) fn anchor_entity(&self) -> &'a Entity {
+        self.anchored().entity()
+    }
+
+    /// Returns the formative shifts made by the entity's events after its
+    /// anchor, up to `timestamp`.
+    pub(crate) fn formative_shifts_at(&self, timestamp: Timestamp) -> Vec<BaseShiftRecord> {
+        let anchored = self.anchored();
+        let anchor_timestamp = anchored.anchor_timestamp();
+        if timestamp <= anchor_timestamp {
+            return Vec::new();
+        }
+        let mut events = self.get_sorted_events_for_range(anchor_timestamp, timestamp, true);
+        events.retain(|te| te.event().target() == Some(&self.entity_id));
+        collect_base_shift_records(&events, anchored.entity(), timestamp, true)
+    }
+
+    /// Projects the state at `timestamp` as `influence_ranking()` does,
+    /// then again with each event left out in turn.
+    ///
+    /// Events that cannot reach the projection are paired with `None`.
+    pub(crate) fn leave_one_out_states(
+        &self,
+        timestamp: Timestamp,
+    ) -> (
+        ComputedState,
+        Vec<(&'a TimestampedEvent, Option<ComputedState>)>,
+    ) {
+        let anchored = self.anchored();
+        let points = anchor_points(anchored);
+        let prev = points
+            .iter()
+            .rfind(|point| point.timestamp <= timestamp)
+            .unwrap_or(&points[0]);
+        let entity = prev.entity(anchored);
+        let is_forward = timestamp > prev.timestamp;
+        let project = |history: &History<'_>| {
+            self.state_from(
+                &entity,
+                prev.timestamp,
+                timestamp,
+                None,
+                MoodShift::default(),
+                history,
+            )
+        };
+
+        let (applied_events, _) =
+            self.get_onset_increments_for_range(prev.timestamp, timestamp, is_forward);
+        let interpreted: Vec<InterpretedEvent> = applied_events
+            .iter()
+            .map(|te| interpret_in_history(self.simulation, te, &entity, None))
+            .collect();
+        let full = History {
+            interpreted: Some(&interpreted),
+            ..History::default()
+        };
+        let baseline = project(&full);
+
+        // An event reaches the projection through its increments, its
+        // onset (base shifts and context), or a later event it amplified as
+        // recognition or sleep loss
+        let in_range = self.get_sorted_events_for_range(prev.timestamp, timestamp, is_forward);
+        let reaches = |te: &TimestampedEvent| {
+            let id = te.event().id();
+            applied_events
+                .iter()
+                .chain(&in_range)
+                .any(|other| std::ptr::eq(&**other, te))
+                || interpreted.iter().any(|later| {
+                    later.contradicted_recognition.as_ref() == Some(id)
+                        || later.preceding_sleep_loss.as_ref() == Some(id)
+                })
+        };
+        let counterfactuals = self
+            .stored_events()
+            .into_iter()
+            .map(|te| {
+                let state = reaches(te).then(|| {
+                    project(&History {
+                        excluded: Some(te),
+                        ..full
+                    })
+                });
+                (te, state)
+            })
+            .collect();
+        (baseline, counterfactuals)
+    }
+
+    /// Computes the state at `query_timestamp` as if `event` also occurred
+    /// at `event_timestamp`.
+    ///
+    /// The simulation is not modified, so this can compare candidate
+    /// interventions side by side. The event should target this entity;
+    /// an event for anyone else leaves the result equal to `state_at()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::enums::{EventType, MoodPath, Species, StatePath};
+    /// use behavioral_pathways::types::{Duration, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new().id("person_001").species(Species::Human).build().unwrap();
+    /// let id = sim.add_entity(entity, reference);
+    ///
+    /// let praise = EventBuilder::new(EventType::Achievement).target(id.clone()).build().unwrap();
+    /// let at = reference + Duration::days(1);
+    /// let handle = sim.entity(&id).unwrap();
+    /// let valence = StatePath::Mood(MoodPath::Valence);
+    /// let previewed = handle.preview_event(&praise, at, at).get_effective(valence);
+    /// assert!(previewed > handle.state_at(at).get_effective(valence));
+    /// assert_eq!(handle.event_count(), 0);
+    /// ```
+    #[must_use]
+    pub fn preview_event(
+        &self,
+        event: &Event,
+        event_timestamp: Timestamp,
+        query_timestamp: Timestamp,
+    ) -> ComputedState {
+        self.preview_events(&[(event.clone(), event_timestamp)], query_timestamp)
+    }
+
+    /// Computes the state at `query_timestamp` as if all `events` also
+    /// occurred, without modifying the simulation.
+    pub(crate) fn preview_events(
+        &self,
+        events: &[(Event, Timestamp)],
+        query_timestamp: Timestamp,
+    ) -> ComputedState {
+        let mut preview = self.simulation.clone();
+        for (event, timestamp) in events {
+            preview.add_event(event.clone(), *timestamp);
+        }
+        EntityQueryHandle::new(&preview, self.entity_id.clone()).state_at(query_timestamp)
+    }
+
+    /// Forecasts the state at `at` across uncertain future scenarios.
+    ///
+    /// Each scenario is a probability and the events that happen if it
+    /// comes true. Every branch is simulated like `preview_event()`, so the
+    /// simulation is not modified, and the results are summarised as a
+    /// probability-weighted distribution with per-branch states for
+    /// drill-down.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ForecastError` if there are no scenarios, a probability
+    /// is negative or not finite, or the probabilities do not sum to 1
+    /// within `FORECAST_PROBABILITY_TOLERANCE`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::enums::{EventType, SocialCognitionPath, Species, StatePath};
+    /// use behavioral_pathways::types::{Duration, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new().id("person_001").species(Species::Human).build().unwrap();
+    /// let id = sim.add_entity(entity, reference);
+    ///
+    /// let in_six_months = reference + Duration::days(180);
+    /// let exclusion = EventBuilder::new(EventType::SocialExclusion)
+    ///     .target(id.clone())
+    ///     .severity(0.9)
+    ///     .build()
+    ///     .unwrap();
+    /// let excluded = vec![(exclusion, in_six_months - Duration::days(1))];
+    ///
+    /// let handle = sim.entity(&id).unwrap();
+    /// let forecast = handle
+    ///     .forecast(in_six_months, vec![(0.8, Vec::new()), (0.2, excluded)])
+    ///     .unwrap();
+    ///
+    /// let loneliness = StatePath::SocialCognition(SocialCognitionPath::Loneliness);
+    /// assert!(forecast.min(loneliness) < forecast.mean(loneliness));
+    /// assert!(forecast.mean(loneliness) < forecast.max(loneliness));
+    /// assert_eq!(forecast.quantile(loneliness, 0.5), forecast.min(loneliness));
+    /// ```
+    pub fn forecast(
+        &self,
+        at: Timestamp,
+        scenarios: Vec<ForecastScenario>,
+    ) -> Result<ForecastDistribution, ForecastError> {
+        forecast::forecast(self, at, scenarios)
+    }
+
+    /// Returns how this entity appraises an event's severity.
+    ///
+    /// This is the appraisal interpretation uses: the subjective severity
+    /// drives the event's effects and memory salience, while the
+    /// objective severity and the gap are reported for explanation.
+    #[must_use]
+    pub fn severity_appraisal(&self, event: &Event) -> SeverityAppraisal {
+        appraise_severity(event, self.anchored().entity())
+    }
+
+    /// Returns alerts raised from `start` to `end`, in chronological order.
+    ///
+    /// External alerts appear at the timestamps they were added with.
+    /// Model alerts are detected by sampling the state at a fixed step, as
+    /// in `state_series()`, and appear at the first sample where each
+    /// condition holds; a condition that persists across samples is
+    /// reported once. At equal timestamps, external alerts come first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::enums::{AlertTrigger, Species};
+    /// use behavioral_pathways::types::{Alert, Duration, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new().id("person_001").species(Species::Human).build().unwrap();
+    /// let id = sim.add_entity(entity, reference);
+    ///
+    /// let flag = Alert::builder().trigger(AlertTrigger::custom("intake")).build().unwrap();
+    /// sim.add_external_alert(&id, flag, reference + Duration::days(3));
+    ///
+    /// let handle = sim.entity(&id).unwrap();
+    /// let end = reference + Duration::days(7);
+    /// let alerts = handle.alerts_between(reference, end, Duration::days(1));
+    /// assert_eq!(alerts.len(), 1);
+    /// assert!(alerts[0].alert().is_external());
+    /// ```
+    #[must_use]
+    pub fn alerts_between(
+        &self,
+        start: Timestamp,
+        end: Timestamp,
+        step: Duration,
+    ) -> Vec<TimestampedAlert> {
+        let mut alerts: Vec<TimestampedAlert> = self
+            .anchored()
+            .external_alerts()
+            .iter()
+            .filter(|alert| alert.timestamp() >= start && alert.timestamp() <= end)
+            .cloned()
+            .collect();
+
+        let mut previous: Vec<Alert> = Vec::new();
+        for (timestamp, state) in self.state_series(start, end, step) {
+            let current = state.model_alerts();
+            for alert in &current {
+                if !previous.iter().any(|earlier| same_condition(earlier, alert)) {
+                    alerts.push(TimestampedAlert::new(alert.clone(), timestamp));
+                }
+            }
+            previous = current;
+        }
+
+        // Stable, so external alerts stay ahead of model alerts at ties
+        alerts.sort_by_key(TimestampedAlert::timestamp);
+        alerts
+    }
+
+    /// Sums support from simulation relationships at the given timestamp.
+    ///
+    /// Each relationship counts by its stage's support weight scaled by
+    /// closeness at the query time, so dormant relationships count less.
+    /// Relationships with an external entity are further scaled by the
+    /// simulation's external relationship weight. A secret kept in a
+    /// relationship suppresses its weight by `CONCEALMENT_SUPPORT_SUPPRESSION`
+    /// at full intensity. A relationship dissolved by bereavement no longer
+    /// counts.
+    #[cfg(all(feature = "context", feature = "relationships"))]
+    fn simulated_relationship_support(
+        &self,
+        timestamp: Timestamp,
+        concealments: &[Concealment],
+    ) -> f64 {
+        self.simulation
+            .relationship_entries()
+            .filter(|(_, rel)| {
+                rel.involves(&self.entity_id)
+                    && rel.formed_timestamp() <= timestamp
+                    && dissolution_of(self.simulation, rel).is_none_or(|at| at > timestamp)
+            })
+            .map(|(id, rel)| {
+                let openness: f64 = concealments
+                    .iter()
+                    .filter(|c| c.conceals_from(id, timestamp))
+                    .map(|c| 1.0 - CONCEALMENT_SUPPORT_SUPPRESSION * f64::from(c.intensity))
+                    .product();
+                let weight = self
+                    .simulation
+                    .activity_for(rel, timestamp)
+                    .support_weight()
+                    * openness;
+                if self.simulation.has_external_party(rel) {
+                    weight * self.simulation.external_relationship_weight()
+                } else {
+                    weight
+                }
+            })
+            .sum()
+    }
+
+    /// Gets events in the time range, sorted chronologically.
+    ///
+    /// # Boundary Rules
+    ///
+    /// - Forward projection: (anchor, target] - excludes anchor, includes target
+    /// - Backward regression: (target, anchor] - excludes target, includes anchor
+    ///
+    /// The anchor state already reflects events that occurred at anchor time,
+    /// so for forward projection we exclude anchor. For backward regression,
+    /// we include anchor events (which need to be reversed) but exclude target
+    /// events (which should not exist in the pre-event state).
+    fn get_sorted_events_for_range(
+        &self,
+        anchor: Timestamp,
+        target: Timestamp,
+        is_forward: bool,
+    ) -> Vec<Cow<'a, TimestampedEvent>> {
+        let (after, through) = if is_forward {
+            // Forward: (anchor, target] - after anchor, up to and including target
+            (anchor, target)
+        } else {
+            // Backward: (target, anchor] - after target, up to and including anchor
+            (target, anchor)
+        };
+        self.with_occurrences(
+            self.simulation.events_for_range(&self.entity_id, after, through),
+            Some(after),
+            through,
+        )
+    }
+
+    /// Gets the onset increments landing in the time range.
+    ///
+    /// Uses the same boundary rules as `get_sorted_events_for_range`, applied
+    /// to when each increment lands rather than to event onset. A ramped
+    /// event whose window straddles the anchor or target contributes only
+    /// the increments inside the range. An anticipated event also
+    /// contributes the anticipation increments landing in the range, even
+    /// if the event itself happens after it.
+    ///
+    /// Returns the events with at least one increment in range, and the
+    /// increments (indexing into those events) sorted by landing time.
+    fn get_onset_increments_for_range(
+        &self,
+        anchor: Timestamp,
+        target: Timestamp,
+        is_forward: bool,
+    ) -> (Vec<Cow<'a, TimestampedEvent>>, Vec<OnsetIncrement>) {
+        let (start, end) = if is_forward {
+            (anchor, target)
+        } else {
+            (target, anchor)
+        };
+        // Ramps starting before the range can land in it, and anticipated
+        // events after it can reach back into it
+        let (mut lookbehind, mut lookahead) = self.simulation.event_reach();
+        for rule in self.simulation.recurring_events_for(&self.entity_id) {
+            lookbehind = lookbehind.max(rule.event().ramp_duration());
+            lookahead = lookahead.max(rule.event().anticipation_window());
+        }
+        let after = start - lookbehind;
+        let through = end + lookahead;
+        let stored = self
+            .simulation
+            .events_for_range(&self.entity_id, after, through);
+        let mut applied_events = Vec::new();
+        let mut increments = Vec::new();
+        for te in self.with_occurrences(stored, Some(after), through) {
+            let event = te.event();
+            if te.timestamp() > end && !event.is_anticipated() {
+                continue;
+            }
+            let event_index = applied_events.len();
+            let before = increments.len();
+            let anticipation = event
+                .anticipation_increments()
+                .into_iter()
+                .map(|(lead, fraction)| (te.timestamp() - lead, fraction, true));
+            let onset = event
+                .onset_increments()
+                .into_iter()
+                .map(|(offset, fraction)| (te.timestamp() + offset, fraction, false));
+            for (landing, fraction, anticipatory) in anticipation.chain(onset) {
+                if landing > start && landing <= end {
+                    increments.push(OnsetIncrement {
+                        event_index,
+                        landing,
+                        fraction,
+                        anticipatory,
+                    });
+                }
+            }
+            if increments.len() > before {
+                applied_events.push(te);
+            }
+        }
+        increments.sort_by_key(|increment| increment.landing);
+        (applied_events, increments)
+    }
+
+    /// Returns the stored events targeting this entity in application
+    /// order.
+    ///
+    /// Events are sorted chronologically; events sharing a timestamp keep
+    /// the order they were added to the simulation.
+    fn stored_events(&self) -> Vec<&'a TimestampedEvent> {
+        self.simulation.events_for(&self.entity_id)
+    }
+
+    /// Returns the events targeting this entity at or before `timestamp`,
+    /// stored and recurring, in application order.
+    pub(crate) fn events_through(&self, timestamp: Timestamp) -> Vec<Cow<'a, TimestampedEvent>> {
+        let stored = self
+            .stored_events()
+            .into_iter()
+            .filter(|te| te.timestamp() <= timestamp);
+        self.with_occurrences(stored, None, timestamp)
+    }
+
+    /// Merges `stored` events with the occurrences of recurring events
+    /// targeting this entity after `after` (if given) and up to and
+    /// including `through`, in application order.
+    ///
+    /// At a shared timestamp, stored events come first.
+    fn with_occurrences(
+        &self,
+        stored: impl IntoIterator<Item = &'a TimestampedEvent>,
+        after: Option<Timestamp>,
+        through: Timestamp,
+    ) -> Vec<Cow<'a, TimestampedEvent>> {
+        let mut events: Vec<Cow<'a, TimestampedEvent>> =
+            stored.into_iter().map(Cow::Borrowed).collect();
+        let stored_count = events.len();
+        for rule in self.simulation.recurring_events_for(&self.entity_id) {
+            events.extend(rule.occurrences_in(after, through).map(Cow::Owned));
+        }
+        if events.len() > stored_count {
+            events.sort_by_key(|te| te.timestamp());
+        }
+        events
+    }
+
+    /// Determines regression quality based on events.
+    ///
+    /// Regression is approximate when trauma events are present (AC
+    /// increases are not reversible). Feedback spirals are found after
+    /// regressing, by `spirals_crossed()`.
+    fn determine_regression_quality(
+        &self,
+        events: &[Cow<'_, TimestampedEvent>],
+    ) -> RegressionQuality {
+        use crate::enums::EventCategory;
+
+        for te in events {
+            let event = te.event();
             let category = event.category();
 
             // Trauma events have non-reversible Acquired Capability increases
             if matches!(category, EventCategory::Trauma) {
                 return RegressionQuality::Approximate;
             }
-
-            // Phase 10+: Check for spiral-triggering events
-            // This will involve checking if the event triggered a stress/depression spiral
         }
 
         RegressionQuality::Exact
@@ -394,9 +2143,22 @@ impl<'a> EntityQueryHandle<'a> {
 
     /// Returns memories that exist at the given timestamp.
     ///
-    /// A memory "exists" at a timestamp if it was formed before or at that time.
-    /// This method returns all memories from the entity's memory layers that
-    /// were formed at or before the specified timestamp.
+    /// A memory "exists" at a timestamp if it was formed before or at that
+    /// time and has not been forgotten since. The candidates are the
+    /// memories in the entity's memory layers, plus a memory for each
+    /// event at or before the timestamp whose salience reaches
+    /// `EVENT_MEMORY_SALIENCE_THRESHOLD`. Event memories are derived on
+    /// each call and never stored; without the `memory` feature there are
+    /// none.
+    ///
+    /// The candidates are consolidated to the entity's age at the
+    /// timestamp with `maintenance::consolidate_at`: each is promoted to
+    /// the layer it would occupy by then, reported by `layer()`, with its
+    /// salience decayed, and memories that have faded below
+    /// `FORGETTING_THRESHOLD` are dropped unless they are legacy or
+    /// trauma memories. They are returned by layer from Immediate to
+    /// Legacy, oldest first within a layer. The stored layers are not
+    /// modified.
     ///
     /// # Arguments
     ///
@@ -452,30 +2214,404 @@ impl<'a> EntityQueryHandle<'a> {
             } else {
                 Duration::zero()
             }
-        };
+        };
+
+        // MemoryEntry.timestamp() returns the entity's age when the memory was formed
+        let mut memories: Vec<MemoryEntry> = entity.memories().all_memories().cloned().collect();
+        if cfg!(feature = "memory") {
+            memories.extend(event_memories(self, timestamp));
+        }
+        consolidate_at(memories, age_at_timestamp, entity.species())
+            .all_memories()
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the entity's narrative identity profile at `timestamp`.
+    ///
+    /// Combines the valence balance of legacy and milestone memories
+    /// (weight 0.25), the consistency and size of formative personality
+    /// shifts since the anchor (0.35), and purpose stability over
+    /// `IDENTITY_PURPOSE_WINDOW` (0.4) into a coherence score in [0, 1].
+    /// `IdentityProfile::components` holds each component's score.
+    ///
+    /// Purpose is sampled monthly with `series()`, so the cost is about 36
+    /// `state_at()` queries.
+    #[must_use]
+    pub fn identity_profile_at(&self, timestamp: Timestamp) -> IdentityProfile {
+        identity_profile(self, timestamp)
+    }
+}
+
+
+/// Returns true if two alerts report the same condition at the same
+/// severity, ignoring the exact threshold value.
+fn same_condition(a: &Alert, b: &Alert) -> bool {
+    a.severity() == b.severity()
+        && match (a.trigger(), b.trigger()) {
+            (
+                AlertTrigger::ThresholdExceeded(a_path, _),
+                AlertTrigger::ThresholdExceeded(b_path, _),
+            ) => a_path == b_path,
+            (a_trigger, b_trigger) => a_trigger == b_trigger,
+        }
+}
+
+/// The event history a projection runs over.
+///
+/// The default is the full history, interpreted from scratch.
+#[derive(Debug, Clone, Copy, Default)]
+struct History<'h> {
+    /// An event left out of the history.
+    excluded: Option<&'h TimestampedEvent>,
+    /// Interpretations of the full history's applied events over the same
+    /// range, in application order.
+    interpreted: Option<&'h [InterpretedEvent]>,
+    /// Whether the projection may continue the handle's `state_range()`
+    /// walk instead of starting from the known state.
+    walk: bool,
+}
+
+impl History<'_> {
+    /// Returns true if `te` is the event left out.
+    fn excludes(&self, te: &TimestampedEvent) -> bool {
+        self.excluded
+            .is_some_and(|excluded| std::ptr::eq(te, excluded))
+    }
+
+    /// Returns the interpretation of the applied event at `index` if it
+    /// does not depend on the event left out.
+    ///
+    /// Only a fall that contradicted the left-out recognition, or an event
+    /// amplified by the left-out sleep loss, changes.
+    fn reusable(&self, index: usize) -> Option<&InterpretedEvent> {
+        let interpreted = &self.interpreted?[index];
+        let depends_on_excluded = self.excluded.is_some_and(|excluded| {
+            let id = Some(excluded.event().id());
+            interpreted.contradicted_recognition.as_ref() == id
+                || interpreted.preceding_sleep_loss.as_ref() == id
+        });
+        (!depends_on_excluded).then_some(interpreted)
+    }
+}
+
+/// One delivery of an event's effect during state traversal.
+///
+/// Instant events have a single increment at their timestamp carrying the
+/// full effect; ramped events have one per increment of their window.
+#[derive(Debug, Clone, Copy)]
+struct OnsetIncrement {
+    /// Index of the event in the applied-events list.
+    event_index: usize,
+    /// When this increment lands.
+    landing: Timestamp,
+    /// Fraction of the event's full effect delivered by this increment, or
+    /// of its anticipatory effect if `anticipatory`.
+    fraction: f64,
+    /// Whether this increment builds anticipation before the event rather
+    /// than delivering its effect.
+    anticipatory: bool,
+}
+
+/// Sampling progress `state_range()` keeps on its handle.
+struct RangeCursor<'a> {
+    /// The first and last sample times.
+    window: (Timestamp, Timestamp),
+    /// The walk the latest sample continued.
+    walk: Option<RangeWalk<'a>>,
+}
+
+/// A projection from one known state, continued from sample to sample.
+///
+/// Holds every increment landing between the known state and the end of
+/// the range, interpreted once, and the state once those up to the latest
+/// sample are applied (or, walking backward, reversed). A sample decays a
+/// copy of that state the rest of the way, as `project()` does from the
+/// last increment, so it matches a separate query.
+struct RangeWalk<'a> {
+    /// When the state walked from is known.
+    from: Timestamp,
+    /// When the walk began: `from`, or a compaction checkpoint's resume
+    /// point.
+    resume: Timestamp,
+    /// Whether samples lie after `from`.
+    forward: bool,
+    applied_events: Vec<Cow<'a, TimestampedEvent>>,
+    interpreted_events: Vec<Cow<'a, InterpretedEvent>>,
+    increments: Vec<OnsetIncrement>,
+    /// Increments not yet applied or reversed.
+    pending: Range<usize>,
+    /// The state at `cursor`.
+    state: IndividualState,
+    cursor: Timestamp,
+    absorption: BaseAbsorption<'a>,
+    /// A fresh tracker for replaying increments forward from a sample.
+    replay: BaseAbsorption<'a>,
+}
+
+impl RangeWalk<'_> {
+    /// Returns true if the walk has not passed `timestamp`.
+    fn reaches(&self, timestamp: Timestamp) -> bool {
+        if self.forward {
+            timestamp >= self.cursor
+        } else {
+            timestamp <= self.cursor
+        }
+    }
+
+    /// Continues the walk to `timestamp` and returns the state there, with
+    /// why a regression is approximate, as `project()` does.
+    fn sample(
+        &mut self,
+        profiler: &Profiler,
+        entity: &Entity,
+        timestamp: Timestamp,
+    ) -> (IndividualState, Vec<ApproximationReason>) {
+        let pending = &self.increments[self.pending.clone()];
+        let landed = self.pending.start
+            + pending.partition_point(|increment| increment.landing <= timestamp);
+        let start = (std::mem::take(&mut self.state), self.cursor);
+        let mut absorption = if self.forward {
+            (self.state, self.cursor) = apply_increments(
+                profiler,
+                entity,
+                &mut self.absorption,
+                start,
+                &self.applied_events,
+                &self.interpreted_events,
+                &self.increments[self.pending.start..landed],
+            );
+            self.pending.start = landed;
+            self.absorption.clone()
+        } else {
+            (self.state, self.cursor) = reverse_increments(
+                profiler,
+                entity,
+                &mut self.absorption,
+                start,
+                &self.applied_events,
+                &self.interpreted_events,
+                &self.increments[landed..self.pending.end],
+            );
+            self.pending.end = landed;
+            self.absorption.clone()
+        };
+
+        let state = self.state.clone();
+        let species = entity.species();
+        profiler.time(ProfilePhase::Advance, || {
+            if self.forward {
+                let remaining = species.psychological_duration(timestamp - self.cursor);
+                (absorption.advance(state, remaining), Vec::new())
+            } else {
+                let remaining = species.psychological_duration(self.cursor - timestamp);
+                let state = absorption.regress(state, remaining);
+                let spirals = spirals_crossed(
+                    profiler,
+                    entity,
+                    self.replay.clone(),
+                    (state.clone(), timestamp),
+                    &self.applied_events,
+                    &self.interpreted_events,
+                    &self.increments[landed..],
+                );
+                (state, regression_reasons(spirals, &absorption))
+            }
+        })
+    }
+}
+
+/// Applies decay and each onset increment in order, starting from a state
+/// and the time it holds at.
+///
+/// Decay runs on the entity's psychological time, scaled by species, and
+/// `absorption` absorbs sustained chronic deltas into the base. Returns
+/// the state once the last increment has landed, and when that was (the
+/// starting time if there are no increments). Each advance and application
+/// is timed by `profiler`.
+fn apply_increments(
+    profiler: &Profiler,
+    entity: &Entity,
+    absorption: &mut BaseAbsorption<'_>,
+    (mut state, mut cursor): (IndividualState, Timestamp),
+    applied_events: &[Cow<'_, TimestampedEvent>],
+    interpreted_events: &[Cow<'_, InterpretedEvent>],
+    increments: &[OnsetIncrement],
+) -> (IndividualState, Timestamp) {
+    for increment in increments {
+        let te = &applied_events[increment.event_index];
+        let interpreted = &interpreted_events[increment.event_index];
+
+        // Advance from cursor to when this increment lands
+        let delta = increment.landing - cursor;
+        state = profiler.time(ProfilePhase::Advance, || {
+            absorption.advance(state, entity.species().psychological_duration(delta))
+        });
+
+        state = profiler.time(ProfilePhase::Apply, || {
+            // Apply developmental effects to scale event impact
+            // Compute entity's age at the onset of this event
+            let age_days = development_age_at(entity, te.timestamp()).as_days();
+            let dev_factor =
+                apply_developmental_effects(entity, te.event(), 1.0, age_days, te.timestamp());
+
+            // Scale the interpreted event by the developmental factor and
+            // the share of the effect this increment delivers
+            let factor = dev_factor * increment.fraction;
+            if increment.anticipatory {
+                return apply_anticipation_to_state(state, interpreted, factor);
+            }
+            let scaled_interpreted = interpreted.scaled_by(factor);
+
+            // Apply the scaled interpreted event deltas
+            apply_interpreted_event_to_state(state, &scaled_interpreted)
+        });
+        // Move cursor forward
+        cursor = increment.landing;
+    }
+    (state, cursor)
+}
+
+/// Reverses each onset increment, latest first, starting from a state and
+/// the time it holds at.
+///
+/// The inverse of `apply_increments()`: decay is regressed back to each
+/// increment before it is unwound. Returns the state once the earliest
+/// increment is unwound, and when it landed (the starting time if there
+/// are no increments).
+fn reverse_increments(
+    profiler: &Profiler,
+    entity: &Entity,
+    absorption: &mut BaseAbsorption<'_>,
+    (mut state, mut cursor): (IndividualState, Timestamp),
+    applied_events: &[Cow<'_, TimestampedEvent>],
+    interpreted_events: &[Cow<'_, InterpretedEvent>],
+    increments: &[OnsetIncrement],
+) -> (IndividualState, Timestamp) {
+    // Only increments that landed in the range are unwound, so a ramp
+    // straddling the target keeps its earlier increments
+    for increment in increments.iter().rev() {
+        let te = &applied_events[increment.event_index];
+        let interpreted = &interpreted_events[increment.event_index];
+
+        // Regress from cursor to when this increment landed
+        let delta = cursor - increment.landing;
+        state = profiler.time(ProfilePhase::Advance, || {
+            absorption.regress(state, entity.species().psychological_duration(delta))
+        });
+
+        state = profiler.time(ProfilePhase::Apply, || {
+            // Apply developmental effects to scale event impact for reversal
+            // Compute entity's age at the onset of this event
+            let age_days = development_age_at(entity, te.timestamp()).as_days();
+            let dev_factor =
+                apply_developmental_effects(entity, te.event(), 1.0, age_days, te.timestamp());
+
+            // Scale the interpreted event by the developmental factor and
+            // the share of the effect this increment delivered
+            let factor = dev_factor * increment.fraction;
+            if increment.anticipatory {
+                return reverse_anticipation_from_state(state, interpreted, factor);
+            }
+            let scaled_interpreted = interpreted.scaled_by(factor);
+
+            // Reverse the scaled interpreted event using its actual deltas
+            reverse_interpreted_event_from_state(state, &scaled_interpreted)
+        });
+        // Move cursor backward
+        cursor = increment.landing;
+    }
+    (state, cursor)
+}
+
+/// Replays each onset increment forward from a regressed state and the
+/// time it holds at, and returns each feedback spiral active once an
+/// increment has landed, in the order they first became active.
+///
+/// Regression grows deltas back exponentially, so the states it passes
+/// through can read as spirals that never ran; the replayed states are
+/// the ones the regressed state leads to. A spiral among them compounds
+/// in ways unwinding the increments does not undo.
+fn spirals_crossed(
+    profiler: &Profiler,
+    entity: &Entity,
+    mut absorption: BaseAbsorption<'_>,
+    (mut state, mut cursor): (IndividualState, Timestamp),
+    applied_events: &[Cow<'_, TimestampedEvent>],
+    interpreted_events: &[Cow<'_, InterpretedEvent>],
+    increments: &[OnsetIncrement],
+) -> Vec<SpiralType> {
+    let mut spirals = Vec::new();
+    for increment in increments {
+        (state, cursor) = apply_increments(
+            profiler,
+            entity,
+            &mut absorption,
+            (state, cursor),
+            applied_events,
+            interpreted_events,
+            std::slice::from_ref(increment),
+        );
+        for spiral in active_spirals(&state, entity.species()) {
+            if !spirals.contains(&spiral) {
+                spirals.push(spiral);
+            }
+        }
+    }
+    spirals
+}
 
-        // Get all memories and filter by those formed at or before the computed age
-        // MemoryEntry.timestamp() returns the entity's age when the memory was formed
-        entity
-            .memories()
-            .all_memories()
-            .filter(|memory: &&MemoryEntry| memory.timestamp() <= age_at_timestamp)
-            .cloned()
-            .collect()
+/// Returns every reason a regression is approximate: absorbed delta moved
+/// back out of a base, then each feedback spiral active on replay.
+fn regression_reasons(
+    spirals: Vec<SpiralType>,
+    absorption: &BaseAbsorption<'_>,
+) -> Vec<ApproximationReason> {
+    absorption
+        .regressed()
+        .then_some(ApproximationReason::BaseAbsorption)
+        .into_iter()
+        .chain(spirals.into_iter().map(ApproximationReason::FeedbackSpiral))
+        .collect()
+}
+
+/// Returns the entity's age at `ts` for developmental effects.
+///
+/// Without a birth date, age is constant at the anchor age.
+pub(crate) fn development_age_at(entity: &Entity, ts: Timestamp) -> Duration {
+    match entity.birth_date() {
+        Some(birth_date) if ts >= birth_date => ts - birth_date,
+        Some(_) => Duration::zero(),
+        None => entity.age(),
     }
 }
 
-fn estimate_relationship_quality(entity: &Entity) -> f64 {
+/// Estimates average relationship quality for loneliness buffering.
+///
+/// Attached relationship slots count fully; `simulated_support` adds the
+/// closeness-weighted support of simulation relationships. Slots holding a
+/// simulation relationship are left to `simulated_support`, so it is not
+/// counted twice.
+#[cfg(feature = "context")]
+fn estimate_relationship_quality(
+    entity: &Entity,
+    simulation: &Simulation,
+    simulated_support: f64,
+) -> f64 {
     let attached_count = entity
         .relationship_slots()
         .iter()
-        .filter(|slot| slot.is_attached())
+        .filter_map(|slot| slot.get_attached())
+        .filter(|id| simulation.get_relationship(id).is_none())
         .count();
 
-    if attached_count > 0 {
-        0.5 + 0.1 * (attached_count as f64).min(5.0)
+    // Partial support ramps from the isolated baseline (0.3) so a
+    // drifting relationship fades out rather than dropping off a cliff
+    let support = attached_count as f64 + simulated_support;
+    if support >= 1.0 {
+        0.5 + 0.1 * support.min(5.0)
     } else {
-        0.3
+        0.3 + 0.3 * support
     }
 }
 
@@ -515,20 +2651,53 @@ fn estimate_relationship_quality(entity: &Entity) -> f64 {
 pub struct ComputedState {
     /// The computed individual state.
     pub individual_state: IndividualState,
+    /// The entity's context as shaped by the person up to the timestamp.
+    context: EcologicalContext,
     /// The entity's age at the queried timestamp.
     pub age_at_timestamp: Duration,
     /// The entity's life stage at the queried timestamp.
     pub life_stage: LifeStage,
+    /// The entity's species, which scales life stage boundaries.
+    species: Species,
+    /// The entity's life stage boundary overrides, if any.
+    life_stage_table: Option<LifeStageTable>,
+    /// Whether age is fixed at the anchor because there is no birth date.
+    age_is_static: bool,
     /// Quality indicator for backward regression.
     regression_quality: RegressionQuality,
+    /// Every reason the state is approximate; empty if it is exact.
+    approximate_reasons: Vec<ApproximationReason>,
+    /// Confidence from the completeness of the entity's inputs.
+    confidence: f64,
+    /// Emotions under the simulation's emotion model.
+    extended_emotions: ExtendedEmotions,
     /// Cached alerts (lazy computed with interior mutability).
     alerts: std::cell::OnceCell<Vec<Alert>>,
+    /// External alerts raised at or before the queried timestamp.
+    external_alerts: Vec<TimestampedAlert>,
+    /// Whether an external alert's crisis window covers the timestamp.
+    in_crisis: bool,
+    /// The queried timestamp, at which contributor intensities are read.
+    timestamp: Timestamp,
+    /// ITS contributor activations from events at or before the timestamp.
+    contributor_activations: ItsContributors,
+    /// Cached ITS factors (lazy computed with interior mutability).
+    its_factors: std::cell::OnceCell<ItsFactors>,
+    /// Cached active ITS contributors (lazy computed with interior mutability).
+    its_contributors: std::cell::OnceCell<Vec<ItsContributor>>,
     /// Human-readable interpretations of psychological dimensions.
     pub interpretations: HashMap<String, String>,
     /// Condensed plain-English summary paragraph.
     pub summary: String,
-    /// Delta emphasis summary showing changes from baseline.
+    /// Structured changes from the anchor state.
+    baseline_delta: StateDiff,
+    /// Delta emphasis summary showing changes from baseline, generated
+    /// from `baseline_delta`.
     pub delta_summary: Option<String>,
+    /// Formative shifts applied to the personality since the anchor.
+    base_shift_history: Vec<BaseShiftRecord>,
+    /// Turning points at or before the timestamp.
+    turning_points: Vec<TurningPoint>,
 }
 
 impl ComputedState {
@@ -538,6 +2707,32 @@ impl ComputedState {
         &self.individual_state
     }
 
+    /// Returns the entity's context at the queried timestamp.
+    ///
+    /// After the anchor, this is a copy of the entity's context shaped by
+    /// the person over the elapsed time (see `person_shaping_fraction`).
+    /// The context stored on the entity is not changed.
+    #[must_use]
+    pub fn context(&self) -> &EcologicalContext {
+        &self.context
+    }
+
+    /// Returns the PAD affective state at the queried timestamp.
+    ///
+    /// Same as `AffectiveState::from_computed(self)`.
+    #[must_use]
+    pub fn affective_state(&self) -> AffectiveState {
+        AffectiveState::from_computed(self)
+    }
+
+    /// Returns fatigue and stress at the queried timestamp.
+    ///
+    /// Same as `PhysiologicalState::snapshot(self)`.
+    #[must_use]
+    pub fn physiological_state(&self) -> PhysiologicalState {
+        PhysiologicalState::snapshot(self)
+    }
+
     /// Returns the age at the queried timestamp.
     #[must_use]
     pub fn age_at_timestamp(&self) -> Duration {
@@ -550,20 +2745,189 @@ impl ComputedState {
         self.life_stage
     }
 
+    /// Returns how far through the current life stage the entity is.
+    ///
+    /// 0.0 at stage entry, approaching 1.0 at the next stage, using the
+    /// species' stage boundaries or the entity's `LifeStageTable`. See
+    /// `LifeStage::progress_for_species()`. When `age_is_static()` is
+    /// true, progress does not change over time.
+    #[must_use]
+    pub fn life_stage_progress(&self) -> f64 {
+        let age_years = self.age_at_timestamp.as_years_f64();
+        match &self.life_stage_table {
+            Some(table) => table.progress(age_years),
+            None => LifeStage::progress_for_species(&self.species, age_years),
+        }
+    }
+
+    /// Returns the time until the entity enters its next life stage.
+    ///
+    /// Returns `None` for Elders. When `age_is_static()` is true, the
+    /// entity never actually reaches the next stage.
+    #[must_use]
+    pub fn time_until_next_stage(&self) -> Option<Duration> {
+        let age_years = self.age_at_timestamp.as_years_f64();
+        match &self.life_stage_table {
+            Some(table) => table.time_until_next_stage(age_years),
+            None => LifeStage::time_until_next_stage_for_species(&self.species, age_years),
+        }
+    }
+
+    /// Returns true if age is fixed at its anchor value.
+    ///
+    /// Entities without a birth date cannot age, so their age, life stage,
+    /// and stage progress stay at the anchor's values at every timestamp.
+    /// UIs can use this to flag that development is not being modeled.
+    #[must_use]
+    pub fn age_is_static(&self) -> bool {
+        self.age_is_static
+    }
+
     /// Returns the regression quality indicator.
     ///
     /// This indicates whether the state was computed exactly or approximately.
-    /// Forward projections are always Exact. Backward regressions may be
-    /// Approximate if the time range contains spiral-triggering events.
+    /// Forward projections are Exact unless they start before a
+    /// compaction cutoff. Backward regressions are Approximate if they
+    /// cross trauma, or an event while a feedback spiral was active.
     #[must_use]
     pub fn regression_quality(&self) -> RegressionQuality {
         self.regression_quality
     }
 
-    /// Returns alerts generated during state computation.
+    /// Returns every reason the state is approximate, or an empty slice
+    /// if it is exact.
+    ///
+    /// Several can apply at once: a regression that moves absorbed delta
+    /// back out of a base while crossing a stress spiral lists both.
+    #[must_use]
+    pub fn approximate_reasons(&self) -> &[ApproximationReason] {
+        &self.approximate_reasons
+    }
+
+    /// Returns confidence in this state from 0.0 to 1.0.
+    ///
+    /// Confidence reflects how completely the entity's key inputs were
+    /// specified: 1.0 unless it was built with
+    /// `EntityBuilder::build_partial` and some inputs were imputed, in
+    /// which case each imputed input lowers it by
+    /// `ImputedField::confidence_weight`. Missing personality lowers it
+    /// more than a missing birth date. See `regression_quality` for the
+    /// quality of backward projections.
+    #[must_use]
+    pub fn confidence(&self) -> f64 {
+        self.confidence
+    }
+
+    /// Returns what changed since the entity's anchor state, such as
+    /// intake, and which ITS thresholds were crossed.
+    ///
+    /// `delta_summary` is generated from this diff, so the prose names
+    /// exactly the narrated paths that changed here by at least 0.01. The
+    /// diff is empty at the anchor timestamp.
+    #[must_use]
+    pub fn baseline_delta(&self) -> &StateDiff {
+        &self.baseline_delta
+    }
+
+    /// Returns the formative personality shifts applied between the anchor
+    /// and the queried timestamp, in event order.
+    ///
+    /// Each record keeps its `FormativeShiftAudit`: the raw request, the
+    /// age and modifiers applied, and any cap that clipped it. Record
+    /// timestamps are durations since birth, or since 1970 without a birth
+    /// date. Empty at or before the anchor, where formative events are
+    /// already in the anchor state.
+    #[must_use]
+    pub fn base_shift_history(&self) -> &[BaseShiftRecord] {
+        &self.base_shift_history
+    }
+
+    /// Returns the turning points in the entity's life at or before the
+    /// queried timestamp, in timestamp order.
+    ///
+    /// These are the turning points recorded in the chronosystem, plus one
+    /// for each event after the anchor whose applied formative shifts add
+    /// up to `TURNING_POINT_SHIFT_THRESHOLD` or that is tagged
+    /// `EventTag::TurningPoint`. A derived turning point takes its domain
+    /// from `TurningPointDomain::for_event` and its magnitude from the
+    /// event's severity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::context::TurningPointDomain;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::enums::{EventTag, EventType, Species};
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::types::{Duration, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new().id("person").species(Species::Human).build().unwrap();
+    /// let id = sim.add_entity(entity, reference);
+    /// let move_abroad = EventBuilder::new(EventType::ContextTransition)
+    ///     .target(id.clone())
+    ///     .severity(0.6)
+    ///     .tag(EventTag::TurningPoint)
+    ///     .build()
+    ///     .unwrap();
+    /// sim.add_event(move_abroad, reference + Duration::days(30));
+    ///
+    /// let handle = sim.entity(&id).unwrap();
+    /// assert!(handle.state_at(reference + Duration::days(29)).turning_points().is_empty());
+    /// let later = handle.state_at(reference + Duration::days(60));
+    /// assert_eq!(later.turning_points()[0].domain, TurningPointDomain::Location);
+    /// ```
+    #[must_use]
+    pub fn turning_points(&self) -> &[TurningPoint] {
+        &self.turning_points
+    }
+
+    /// Returns what changed since `earlier`, a state computed by the
+    /// caller, such as the one shown at the previous review.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::enums::{EventType, Species};
+    /// use behavioral_pathways::event::EventBuilder;
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::types::{Duration, Timestamp};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new().species(Species::Human).build().unwrap();
+    /// let id = sim.add_entity(entity, reference);
+    /// let exclusion = EventBuilder::new(EventType::SocialExclusion)
+    ///     .target(id.clone())
+    ///     .severity(0.7)
+    ///     .build()
+    ///     .unwrap();
+    /// sim.add_event(exclusion, reference + Duration::days(20));
+    ///
+    /// let handle = sim.entity(&id).unwrap();
+    /// let last_review = handle.state_at(reference + Duration::days(10));
+    /// let now = handle.state_at(reference + Duration::days(21));
+    /// assert!(!now.delta_from(&last_review).is_empty());
+    /// ```
+    #[must_use]
+    pub fn delta_from(&self, earlier: &ComputedState) -> StateDiff {
+        StateDiff::between(&earlier.individual_state, &self.individual_state)
+    }
+
+    /// Returns alerts in effect at the queried timestamp.
     ///
-    /// This is lazily computed on first access. Alerts include threshold
-    /// violations and feedback loop detections.
+    /// This is lazily computed on first access. Alerts are in
+    /// chronological order: external alerts raised at or before the
+    /// timestamp, then model alerts for this state. Model alerts are an
+    /// info alert per ITS factor (TB, PB, interpersonal hopelessness) at
+    /// or above its presence threshold, a warning per active stress or
+    /// depression spiral, and a warning or critical alert for elevated
+    /// suicidal desire and attempt risk. Use `Alert::provenance()` to tell
+    /// external and model alerts apart. Without the `its` feature only
+    /// external alerts are returned.
     ///
     /// Returns a cloned vector of alerts per the spec API.
     #[must_use]
@@ -571,10 +2935,85 @@ impl ComputedState {
         self.alerts.get_or_init(|| self.compute_alerts()).clone()
     }
 
+    /// Returns the ITS factors at the queried timestamp.
+    ///
+    /// This is lazily computed on first access from the computed state:
+    /// thwarted belongingness, perceived burdensomeness, acquired
+    /// capability, interpersonal hopelessness, suicidal desire, attempt
+    /// risk, and the `ConvergenceStatus` of the three proximal factors.
+    #[must_use]
+    pub fn its_factors(&self) -> ItsFactors {
+        *self
+            .its_factors
+            .get_or_init(|| compute_its_factors(&self.individual_state))
+    }
+
+    /// Returns the ITS contributors active at the queried timestamp.
+    ///
+    /// This is lazily computed on first access. Each event at or before
+    /// the timestamp activates the contributors for its type (see
+    /// `ItsContributor::for_event_type`) at the event's severity. Chronic
+    /// contributors stay active; acute ones decay with
+    /// `ACUTE_CONTRIBUTOR_DECAY_HALF_LIFE` and count while at or above
+    /// `CONTRIBUTOR_ACTIVATION_THRESHOLD`. Each contributor is listed
+    /// once, in order of first activation. Without the `its` feature this
+    /// is empty.
+    #[must_use]
+    pub fn its_contributors(&self) -> Vec<ItsContributor> {
+        self.its_contributors
+            .get_or_init(|| {
+                let mut active = Vec::new();
+                for activation in self.contributor_activations.active_at(self.timestamp) {
+                    if !active.contains(&activation.contributor) {
+                        active.push(activation.contributor);
+                    }
+                }
+                active
+            })
+            .clone()
+    }
+
+    /// Returns true if an external alert's crisis window covers the
+    /// queried timestamp.
+    ///
+    /// While in crisis, support-seeking predictions use crisis-level
+    /// stakes regardless of the computed state.
+    #[must_use]
+    pub fn is_in_crisis(&self) -> bool {
+        self.in_crisis
+    }
+
     /// Computes alerts for this state.
     fn compute_alerts(&self) -> Vec<Alert> {
-        // Placeholder for Phase 10+ implementation
-        // Will check thresholds on mental health dimensions
+        let mut alerts: Vec<Alert> = self
+            .external_alerts
+            .iter()
+            .map(|timestamped| timestamped.alert().clone())
+            .collect();
+        alerts.extend(self.model_alerts());
+        alerts
+    }
+
+    /// Computes model alerts from this state's ITS factors and feedback
+    /// spirals, least severe first.
+    #[cfg(feature = "its")]
+    fn model_alerts(&self) -> Vec<Alert> {
+        let state = &self.individual_state;
+        let factors = self.its_factors();
+        let hopelessness = factors.interpersonal_hopelessness;
+        let mut alerts = check_factor_thresholds(&factors, hopelessness, self.age_at_timestamp);
+        alerts.extend(check_spiral_alerts(
+            state,
+            self.species == Species::Human,
+            self.age_at_timestamp,
+        ));
+        alerts.extend(check_its_thresholds(&factors, self.age_at_timestamp));
+        alerts
+    }
+
+    /// Model alerts need the `its` feature.
+    #[cfg(not(feature = "its"))]
+    fn model_alerts(&self) -> Vec<Alert> {
         Vec::new()
     }
 
@@ -628,6 +3067,16 @@ impl ComputedState {
         get_derived_emotion(&self.individual_state)
     }
 
+    /// Returns the octant emotions under the simulation's `EmotionModel`
+    /// and its attribution-gated emotions, such as pride and shame.
+    ///
+    /// `derived_emotions()` keeps the fixed octant boundaries, so it is
+    /// unaffected by `Simulation::set_emotion_model`.
+    #[must_use]
+    pub fn derived_emotions_extended(&self) -> ExtendedEmotions {
+        self.extended_emotions.clone()
+    }
+
     /// Gets the effective value for a state path.
     ///
     /// This is a convenience method that delegates to the individual state.
@@ -669,114 +3118,203 @@ impl ComputedState {
     /// ```
     #[must_use]
     pub fn get_effective(&self, path: StatePath) -> f64 {
-        use crate::enums::{
-            DispositionPath, HexacoPath, MentalHealthPath, MoodPath, NeedsPath,
-            PersonCharacteristicsPath, SocialCognitionPath,
-        };
-
-        let state = &self.individual_state;
+        effective_value(&self.individual_state, path)
+    }
 
-        let value: f32 = match path {
-            StatePath::Hexaco(p) => match p {
-                HexacoPath::HonestyHumility => state.hexaco().honesty_humility(),
-                HexacoPath::Neuroticism => state.hexaco().neuroticism(),
-                HexacoPath::Extraversion => state.hexaco().extraversion(),
-                HexacoPath::Agreeableness => state.hexaco().agreeableness(),
-                HexacoPath::Conscientiousness => state.hexaco().conscientiousness(),
-                HexacoPath::Openness => state.hexaco().openness(),
-            },
-            StatePath::Mood(p) => match p {
-                MoodPath::Valence => state.mood().valence_effective(),
-                MoodPath::Arousal => state.mood().arousal_effective(),
-                MoodPath::Dominance => state.mood().dominance_effective(),
-            },
-            StatePath::Needs(p) => match p {
-                NeedsPath::Stress => state.needs().stress_effective(),
-                NeedsPath::Fatigue => state.needs().fatigue_effective(),
-                NeedsPath::Purpose => state.needs().purpose_effective(),
-            },
-            StatePath::SocialCognition(p) => match p {
-                SocialCognitionPath::Loneliness => state.social_cognition().loneliness_effective(),
-                SocialCognitionPath::PerceivedReciprocalCaring => state
-                    .social_cognition()
-                    .perceived_reciprocal_caring_effective(),
-                SocialCognitionPath::PerceivedLiability => {
-                    state.social_cognition().perceived_liability_effective()
-                }
-                SocialCognitionPath::SelfHate => state.social_cognition().self_hate_effective(),
-                SocialCognitionPath::PerceivedCompetence => state
-                    .social_cognition()
-                    .perceived_competence_effective(),
-            },
-            StatePath::MentalHealth(p) => match p {
-                MentalHealthPath::Depression => state.mental_health().depression_effective(),
-                MentalHealthPath::AcquiredCapability => {
-                    state.mental_health().acquired_capability_effective()
-                }
-                MentalHealthPath::InterpersonalHopelessness => {
-                    state.mental_health().interpersonal_hopelessness_effective()
-                }
-                MentalHealthPath::ThwartedBelongingness => state.compute_thwarted_belongingness(),
-                MentalHealthPath::PerceivedBurdensomeness => {
-                    state.compute_perceived_burdensomeness()
-                }
-                MentalHealthPath::SuicidalDesire => state.compute_suicidal_desire(),
-                MentalHealthPath::AttemptRisk => state.compute_attempt_risk(),
-                MentalHealthPath::SelfWorth => state.mental_health().self_worth_effective(),
-                MentalHealthPath::Hopelessness => state.mental_health().hopelessness_effective(),
-            },
-            StatePath::Disposition(p) => match p {
-                DispositionPath::Empathy => state.disposition().empathy_effective(),
-                DispositionPath::Aggression => state.disposition().aggression_effective(),
-                DispositionPath::Grievance => state.disposition().grievance_effective(),
-                DispositionPath::ImpulseControl => state.disposition().impulse_control_effective(),
-                DispositionPath::Reactance => state.disposition().reactance_effective(),
-                DispositionPath::TrustPropensity => {
-                    state.disposition().trust_propensity_effective()
-                }
-            },
-            StatePath::PersonCharacteristics(p) => match p {
-                PersonCharacteristicsPath::SocialCapital => {
-                    state.person_characteristics().social_capital_effective()
-                }
-                PersonCharacteristicsPath::CognitiveAbility => {
-                    state.person_characteristics().cognitive_ability_effective()
-                }
-                PersonCharacteristicsPath::EmotionalRegulationAssets => state
-                    .person_characteristics()
-                    .emotional_regulation_assets_effective(),
-                PersonCharacteristicsPath::MaterialSecurity => {
-                    state.person_characteristics().material_security_effective()
-                }
-                PersonCharacteristicsPath::ExperienceDiversity => state
-                    .person_characteristics()
-                    .experience_diversity_effective(),
-                PersonCharacteristicsPath::BaselineMotivation => state
-                    .person_characteristics()
-                    .baseline_motivation_effective(),
-                PersonCharacteristicsPath::PersistenceTendency => state
-                    .person_characteristics()
-                    .persistence_tendency_effective(),
-                PersonCharacteristicsPath::CuriosityTendency => state
-                    .person_characteristics()
-                    .curiosity_tendency_effective(),
-                // Composite values
-                PersonCharacteristicsPath::Resource => state.person_characteristics().resource(),
-                PersonCharacteristicsPath::Force => state.person_characteristics().force(),
-            },
-        };
+    /// Predicts how likely the entity is to behave as `query` asks, from
+    /// the dimensions that drive the behavior.
+    ///
+    /// See [`BehaviorPrediction`] for the drivers of each query.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::{BehaviorQuery, Simulation};
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::types::{Timestamp, EntityId};
+    /// use behavioral_pathways::enums::Species;
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new()
+    ///     .id("person_001")
+    ///     .species(Species::Human)
+    ///     .build()
+    ///     .unwrap();
+    /// sim.add_entity(entity, reference);
+    ///
+    /// let handle = sim.entity(&EntityId::new("person_001").unwrap()).unwrap();
+    /// let computed = handle.state_at(reference);
+    ///
+    /// let prediction = computed.predict(BehaviorQuery::ActAggressively { provocation: 0.8 });
+    /// assert!((0.0..=1.0).contains(&prediction.likelihood));
+    /// assert_eq!(prediction.top_contributors(3).len(), 3);
+    /// ```
+    #[must_use]
+    pub fn predict(&self, query: BehaviorQuery) -> BehaviorPrediction {
+        BehaviorPrediction::from_state(&self.individual_state, query)
+    }
 
-        f64::from(value)
+    /// Gets the value of any queryable dimension.
+    ///
+    /// State paths return `get_effective`, and emotions their intensity in
+    /// `derived_emotions()`. Relationship paths and `Emotion::Neutral`
+    /// return `None`: a relationship value needs a relationship handle,
+    /// and Neutral has no intensity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::simulation::Simulation;
+    /// use behavioral_pathways::entity::EntityBuilder;
+    /// use behavioral_pathways::types::{Timestamp, EntityId};
+    /// use behavioral_pathways::enums::{Emotion, QueryPath, Species};
+    ///
+    /// let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let mut sim = Simulation::new(reference);
+    /// let entity = EntityBuilder::new()
+    ///     .id("person_001")
+    ///     .species(Species::Human)
+    ///     .build()
+    ///     .unwrap();
+    /// sim.add_entity(entity, reference);
+    ///
+    /// let handle = sim.entity(&EntityId::new("person_001").unwrap()).unwrap();
+    /// let computed = handle.state_at(reference);
+    ///
+    /// let anxious = computed.query(QueryPath::Emotion(Emotion::Anxious));
+    /// assert_eq!(anxious, Some(f64::from(computed.derived_emotions().anxious)));
+    /// ```
+    #[must_use]
+    pub fn query(&self, path: QueryPath) -> Option<f64> {
+        match path {
+            QueryPath::State(path) => Some(self.get_effective(path)),
+            QueryPath::Emotion(Emotion::Neutral) => None,
+            QueryPath::Emotion(emotion) => {
+                Some(f64::from(self.derived_emotions().intensity(emotion)))
+            }
+            QueryPath::Relationship(_) => None,
+        }
     }
 }
 
+/// Returns the effective value of `path` in `state`, or the computed
+/// value for derived paths.
+pub(crate) fn effective_value(state: &IndividualState, path: StatePath) -> f64 {
+    use crate::enums::{
+        DispositionPath, HexacoPath, MentalHealthPath, MoodPath, NeedsPath,
+        PersonCharacteristicsPath, SocialCognitionPath,
+    };
+
+    let value: f32 = match path {
+        StatePath::Hexaco(p) => match p {
+            HexacoPath::HonestyHumility => state.hexaco().honesty_humility(),
+            HexacoPath::Neuroticism => state.hexaco().neuroticism(),
+            HexacoPath::Extraversion => state.hexaco().extraversion(),
+            HexacoPath::Agreeableness => state.hexaco().agreeableness(),
+            HexacoPath::Conscientiousness => state.hexaco().conscientiousness(),
+            HexacoPath::Openness => state.hexaco().openness(),
+        },
+        StatePath::Mood(p) => match p {
+            MoodPath::Valence => state.mood().valence_effective(),
+            MoodPath::Arousal => state.mood().arousal_effective(),
+            MoodPath::Dominance => state.mood().dominance_effective(),
+        },
+        StatePath::Needs(p) => match p {
+            NeedsPath::Stress => state.needs().stress_effective(),
+            NeedsPath::Fatigue => state.needs().fatigue_effective(),
+            NeedsPath::Purpose => state.needs().purpose_effective(),
+            NeedsPath::Satiation => state.needs().satiation_effective(),
+            NeedsPath::Safety => state.needs().safety_effective(),
+        },
+        StatePath::SocialCognition(p) => match p {
+            SocialCognitionPath::Loneliness => state.social_cognition().loneliness_effective(),
+            SocialCognitionPath::PerceivedReciprocalCaring => state
+                .social_cognition()
+                .perceived_reciprocal_caring_effective(),
+            SocialCognitionPath::PerceivedLiability => {
+                state.social_cognition().perceived_liability_effective()
+            }
+            SocialCognitionPath::SelfHate => state.social_cognition().self_hate_effective(),
+            SocialCognitionPath::PerceivedCompetence => state
+                .social_cognition()
+                .perceived_competence_effective(),
+        },
+        StatePath::MentalHealth(p) => match p {
+            MentalHealthPath::Depression => state.mental_health().depression_effective(),
+            MentalHealthPath::AcquiredCapability => {
+                state.mental_health().acquired_capability_effective()
+            }
+            MentalHealthPath::InterpersonalHopelessness => {
+                state.mental_health().interpersonal_hopelessness_effective()
+            }
+            MentalHealthPath::ThwartedBelongingness => state.compute_thwarted_belongingness(),
+            MentalHealthPath::PerceivedBurdensomeness => {
+                state.compute_perceived_burdensomeness()
+            }
+            MentalHealthPath::SuicidalDesire => state.compute_suicidal_desire(),
+            MentalHealthPath::AttemptRisk => state.compute_attempt_risk(),
+            MentalHealthPath::SelfWorth => state.mental_health().self_worth_effective(),
+            MentalHealthPath::Hopelessness => state.mental_health().hopelessness_effective(),
+        },
+        StatePath::Disposition(p) => match p {
+            DispositionPath::Empathy => state.disposition().empathy_effective(),
+            DispositionPath::Aggression => state.disposition().aggression_effective(),
+            DispositionPath::Grievance => state.disposition().grievance_effective(),
+            DispositionPath::ImpulseControl => state.disposition().impulse_control_effective(),
+            DispositionPath::Reactance => state.disposition().reactance_effective(),
+            DispositionPath::TrustPropensity => {
+                state.disposition().trust_propensity_effective()
+            }
+        },
+        StatePath::PersonCharacteristics(p) => match p {
+            PersonCharacteristicsPath::SocialCapital => {
+                state.person_characteristics().social_capital_effective()
+            }
+            PersonCharacteristicsPath::CognitiveAbility => {
+                state.person_characteristics().cognitive_ability_effective()
+            }
+            PersonCharacteristicsPath::EmotionalRegulationAssets => state
+                .person_characteristics()
+                .emotional_regulation_assets_effective(),
+            PersonCharacteristicsPath::MaterialSecurity => {
+                state.person_characteristics().material_security_effective()
+            }
+            PersonCharacteristicsPath::ExperienceDiversity => state
+                .person_characteristics()
+                .experience_diversity_effective(),
+            PersonCharacteristicsPath::BaselineMotivation => state
+                .person_characteristics()
+                .baseline_motivation_effective(),
+            PersonCharacteristicsPath::PersistenceTendency => state
+                .person_characteristics()
+                .persistence_tendency_effective(),
+            PersonCharacteristicsPath::CuriosityTendency => state
+                .person_characteristics()
+                .curiosity_tendency_effective(),
+            // Composite values
+            PersonCharacteristicsPath::Resource => state.person_characteristics().resource(),
+            PersonCharacteristicsPath::Force => state.person_characteristics().force(),
+        },
+    };
+
+    f64::from(value)
+}
+
 impl Clone for ComputedState {
     fn clone(&self) -> Self {
         ComputedState {
             individual_state: self.individual_state.clone(),
+            context: self.context.clone(),
             age_at_timestamp: self.age_at_timestamp,
             life_stage: self.life_stage,
+            species: self.species.clone(),
+            life_stage_table: self.life_stage_table.clone(),
+            age_is_static: self.age_is_static,
             regression_quality: self.regression_quality,
+            approximate_reasons: self.approximate_reasons.clone(),
+            confidence: self.confidence,
+            extended_emotions: self.extended_emotions.clone(),
             alerts: match self.alerts.get() {
                 Some(v) => {
                     let cell = std::cell::OnceCell::new();
@@ -785,9 +3323,18 @@ impl Clone for ComputedState {
                 }
                 None => std::cell::OnceCell::new(),
             },
+            external_alerts: self.external_alerts.clone(),
+            in_crisis: self.in_crisis,
+            timestamp: self.timestamp,
+            contributor_activations: self.contributor_activations.clone(),
+            its_factors: self.its_factors.clone(),
+            its_contributors: self.its_contributors.clone(),
             interpretations: self.interpretations.clone(),
             summary: self.summary.clone(),
+            baseline_delta: self.baseline_delta.clone(),
             delta_summary: self.delta_summary.clone(),
+            base_shift_history: self.base_shift_history.clone(),
+            turning_points: self.turning_points.clone(),
         }
     }
 }
@@ -797,47 +3344,54 @@ impl Clone for ComputedState {
 /// For forward queries, collects shifts from events before the query timestamp.
 /// For backward queries, we don't collect shifts (they don't exist yet in the past).
 fn collect_base_shift_records(
-    events: &[&TimestampedEvent],
+    events: &[Cow<'_, TimestampedEvent>],
     entity: &Entity,
     query_timestamp: Timestamp,
     is_forward: bool,
 ) -> Vec<BaseShiftRecord> {
+    collect_event_base_shifts(events, entity, query_timestamp, is_forward)
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// Collects the base shift records of each event, as
+/// `collect_base_shift_records` does, with one entry per event.
+fn collect_event_base_shifts(
+    events: &[Cow<'_, TimestampedEvent>],
+    entity: &Entity,
+    query_timestamp: Timestamp,
+    is_forward: bool,
+) -> Vec<Vec<BaseShiftRecord>> {
     // Backward queries don't include formative events (they haven't happened yet)
     if !is_forward {
-        return Vec::new();
+        return vec![Vec::new(); events.len()];
     }
 
     let reference_timestamp = entity
         .birth_date()
         .unwrap_or_else(|| Timestamp::from_ymd_hms(1970, 1, 1, 0, 0, 0));
 
-    let mut records = Vec::new();
+    let mut event_records = Vec::with_capacity(events.len());
     let mut cumulative_positive: HashMap<HexacoPath, f32> = HashMap::new();
     let mut cumulative_negative: HashMap<HexacoPath, f32> = HashMap::new();
 
     for te in events {
         let event = te.event();
+        let mut records = Vec::new();
 
-        // Skip events without base shifts
-        if !event.has_base_shifts() {
-            continue;
-        }
-
-        // Skip events after query timestamp
-        if te.timestamp() > query_timestamp {
+        // Skip events without base shifts and events after query timestamp
+        if !event.has_base_shifts() || te.timestamp() > query_timestamp {
+            event_records.push(records);
             continue;
         }
 
-        // Compute entity's age at event time for plasticity modifiers
-        let age_at_event = if let Some(birth_date) = entity.birth_date() {
-            if te.timestamp() >= birth_date {
-                (te.timestamp() - birth_date).as_years() as u16
-            } else {
-                0
-            }
-        } else {
-            entity.age().as_years() as u16
-        };
+        // Plasticity modifiers are defined on human ages, so convert the
+        // entity's age at event time to human-equivalent years
+        let age_at_event = entity.config().human_equivalent_age(
+            entity.species(),
+            development_age_at(entity, te.timestamp()).as_years_f64(),
+        ) as u16;
 
         // Convert event timestamp to Duration from reference
         let event_duration = if te.timestamp() >= reference_timestamp {
@@ -856,34 +3410,211 @@ fn collect_base_shift_records(
             };
 
             // Apply all modifiers: plasticity, trait stability, saturation, caps
-            let modified = apply_formative_modifiers(
+            let audit = audit_formative_modifiers(
                 *raw_amount,
                 *trait_path,
                 age_at_event,
                 existing,
                 entity.species(),
             );
+            let modified = audit.applied;
 
             // Skip zero shifts
             if modified.abs() < f32::EPSILON {
                 continue;
             }
 
-            // Create the base shift record
-            let record = BaseShiftRecord::new(event_duration, *trait_path, modified);
+            // Create the base shift record, keeping the audit
+            let record = BaseShiftRecord::from_audit(event_duration, *trait_path, audit)
+                .scaled_for_species(entity.species());
+
+            // Update cumulative tracking
+            if modified > 0.0 {
+                *cumulative_positive.entry(*trait_path).or_insert(0.0) += modified.abs();
+            } else {
+                *cumulative_negative.entry(*trait_path).or_insert(0.0) += modified.abs();
+            }
+
+            records.push(record);
+        }
+        event_records.push(records);
+    }
+
+    event_records
+}
+
+/// A point within a context hook span where the context changes.
+#[cfg(feature = "context")]
+enum ContextBoundary<'a> {
+    /// A role exit ends a microsystem membership.
+    RoleExit(&'a RoleExit),
+    /// A scheduled context change takes effect.
+    Change(&'a ContextChange),
+}
+
+/// Collects role exits that take effect within a forward query range.
+///
+/// Exits come from events carrying `EventPayload::RoleExit` and from a
+/// completed "retirement" normative transition in the chronosystem. Only
+/// exits in (anchor, query] are returned, sorted chronologically; exits at
+/// or before the anchor are assumed to be reflected in the anchor context.
+/// Backward queries return no exits.
+#[cfg(feature = "context")]
+fn collect_role_exits(
+    events: &[Cow<'_, TimestampedEvent>],
+    entity: &Entity,
+    anchor_timestamp: Timestamp,
+    query_timestamp: Timestamp,
+    is_forward: bool,
+) -> Vec<RoleExit> {
+    if !is_forward {
+        return Vec::new();
+    }
+
+    let transition_exits = entity
+        .context()
+        .chronosystem()
+        .normative_transitions()
+        .iter()
+        .filter_map(|t| RoleExit::from_retirement_transition(t, entity.birth_date()));
+    let event_exits = events
+        .iter()
+        .filter_map(|te| RoleExit::from_event(te.event(), te.timestamp()));
+
+    let mut exits: Vec<RoleExit> = event_exits
+        .chain(transition_exits)
+        .filter(|exit| exit.timestamp > anchor_timestamp && exit.timestamp <= query_timestamp)
+        .collect();
+    exits.sort_by_key(|exit| exit.timestamp);
+    exits
+}
+
+/// Collects chronic health conditions and caregiving times for a forward query.
+///
+/// Conditions come from events carrying a chronic
+/// `EventPayload::HealthCondition` with onset in (anchor, query]. Each is
+/// ended by the first later `EventPayload::HealthRemission` for the same
+/// condition kind. Caregiving is every instrumental `EventPayload::Support`
+/// event in the range. Backward queries return nothing.
+#[cfg(feature = "context")]
+fn collect_health_conditions(
+    events: &[Cow<'_, TimestampedEvent>],
+    anchor_timestamp: Timestamp,
+    query_timestamp: Timestamp,
+    is_forward: bool,
+) -> (Vec<HealthCondition>, Vec<Timestamp>) {
+    if !is_forward {
+        return (Vec::new(), Vec::new());
+    }
+
+    let in_range = |ts: Timestamp| ts > anchor_timestamp && ts <= query_timestamp;
+    let mut conditions: Vec<HealthCondition> = Vec::new();
+    let mut caregiving = Vec::new();
+    for te in events.iter().filter(|te| in_range(te.timestamp())) {
+        match te.event().payload() {
+            EventPayload::HealthRemission { condition } => {
+                if let Some(active) = conditions
+                    .iter_mut()
+                    .find(|c| c.kind == *condition && c.remission.is_none())
+                {
+                    active.remission = Some(te.timestamp());
+                }
+            }
+            EventPayload::Support {
+                support_type: SupportType::Instrumental,
+                ..
+            } => caregiving.push(te.timestamp()),
+            _ => {
+                if let Some(condition) = HealthCondition::from_event(te.event(), te.timestamp()) {
+                    conditions.push(condition);
+                }
+            }
+        }
+    }
+    (conditions, caregiving)
+}
+
+/// Collects concealments for a forward query.
+///
+/// Concealments come from events carrying an ongoing
+/// `EventPayload::Concealment` with onset in (anchor, query]. Each
+/// `EventPayload::Disclosure` in the range is recorded on every earlier
+/// concealment in the same life domain. Backward queries return nothing.
+#[cfg(feature = "context")]
+fn collect_concealments(
+    events: &[Cow<'_, TimestampedEvent>],
+    anchor_timestamp: Timestamp,
+    query_timestamp: Timestamp,
+    is_forward: bool,
+) -> Vec<Concealment> {
+    if !is_forward {
+        return Vec::new();
+    }
 
-            // Update cumulative tracking
-            if modified > 0.0 {
-                *cumulative_positive.entry(*trait_path).or_insert(0.0) += modified.abs();
-            } else {
-                *cumulative_negative.entry(*trait_path).or_insert(0.0) += modified.abs();
+    let in_range = |ts: Timestamp| ts > anchor_timestamp && ts <= query_timestamp;
+    let mut concealments: Vec<Concealment> = Vec::new();
+    for te in events.iter().filter(|te| in_range(te.timestamp())) {
+        if let EventPayload::Disclosure {
+            domain,
+            relationship,
+            ..
+        } = te.event().payload()
+        {
+            for concealment in concealments.iter_mut().filter(|c| c.domain == *domain) {
+                concealment
+                    .disclosures
+                    .push((relationship.clone(), te.timestamp()));
             }
+        } else if let Some(concealment) = Concealment::from_event(te.event(), te.timestamp()) {
+            concealments.push(concealment);
+        }
+    }
+    concealments
+}
 
-            records.push(record);
+/// Returns sample timestamps from `start` to `end` (inclusive) at `step`.
+fn sample_times(start: Timestamp, end: Timestamp, step: Duration) -> Vec<Timestamp> {
+    start.iter_step(end, step).collect()
+}
+
+/// A known state of an entity: its anchor or one of its observations.
+struct AnchorPoint<'e> {
+    /// When the state is known.
+    timestamp: Timestamp,
+    /// The observation, or `None` for the anchor itself.
+    observation: Option<&'e Observation>,
+}
+
+impl AnchorPoint<'_> {
+    /// Returns the entity as known at this point.
+    fn entity<'a>(&self, anchored: &'a AnchoredEntity) -> Cow<'a, Entity> {
+        match self.observation {
+            Some(observation) => Cow::Owned(anchored.entity_as_observed(observation)),
+            None => Cow::Borrowed(anchored.entity()),
         }
     }
+}
 
-    records
+/// Returns the anchor and every observation of an entity, sorted by time.
+fn anchor_points(anchored: &AnchoredEntity) -> Vec<AnchorPoint<'_>> {
+    let mut points: Vec<AnchorPoint<'_>> = anchored
+        .observations()
+        .iter()
+        .map(|observation| AnchorPoint {
+            timestamp: observation.timestamp(),
+            observation: Some(observation),
+        })
+        .collect();
+    let anchor_timestamp = anchored.anchor_timestamp();
+    let anchor_index = points.partition_point(|point| point.timestamp < anchor_timestamp);
+    points.insert(
+        anchor_index,
+        AnchorPoint {
+            timestamp: anchor_timestamp,
+            observation: None,
+        },
+    );
+    points
 }
 
 /// Applies accumulated base shifts to HEXACO personality traits in the state.
@@ -954,7 +3685,9 @@ fn apply_base_shifts_to_state(
 mod tests {
     use super::*;
     use crate::entity::EntityBuilder;
-    use crate::enums::{EventType, Species};
+    #[cfg(feature = "context")]
+    use crate::enums::{Audience, ConditionKind, CourseShape, LifeDomain};
+    use crate::enums::{EventType, RampShape, SocialCognitionPath, Species};
     use crate::event::EventBuilder;
 
     fn create_simulation() -> Simulation {
@@ -977,6 +3710,204 @@ mod tests {
         assert_eq!(handle.entity_id().as_str(), "test");
     }
 
+    fn sim_with_event_history() -> (Simulation, EntityId, Timestamp) {
+        let mut sim = create_simulation();
+        let anchor = sim.reference_date();
+        let id = sim.add_entity(create_human("person_001"), anchor);
+        let other = EntityId::new("other").unwrap();
+
+        // Added out of order; the two day-10 events tie on timestamp
+        let plan = [
+            (EventType::Failure, 10),
+            (EventType::Achievement, 0),
+            (EventType::Support, 20),
+            (EventType::Achievement, 10),
+        ];
+        for (event_type, day) in plan {
+            let event = EventBuilder::new(event_type)
+                .target(id.clone())
+                .build()
+                .unwrap();
+            sim.add_event(event, anchor + Duration::days(day));
+        }
+        let unrelated = EventBuilder::new(EventType::Failure)
+            .target(other)
+            .build()
+            .unwrap();
+        sim.add_event(unrelated, anchor + Duration::days(15));
+        (sim, id, anchor)
+    }
+
+    #[test]
+    fn handle_events_between_forward_excludes_start_includes_end() {
+        let (sim, id, anchor) = sim_with_event_history();
+        let handle = sim.entity(&id).unwrap();
+
+        let events = handle.events_between(anchor, anchor + Duration::days(10));
+        let types: Vec<EventType> = events.iter().map(|te| te.event().event_type()).collect();
+        assert_eq!(types, vec![EventType::Failure, EventType::Achievement]);
+    }
+
+    #[test]
+    fn handle_events_between_backward_matches_regression_range() {
+        let (sim, id, anchor) = sim_with_event_history();
+        let handle = sim.entity(&id).unwrap();
+
+        // Backward (end, start]: excludes day 10, includes day 20
+        let events =
+            handle.events_between(anchor + Duration::days(20), anchor + Duration::days(10));
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event().event_type(), EventType::Support);
+    }
+
+    #[test]
+    fn handle_events_between_matches_state_at_range() {
+        let (sim, id, anchor) = sim_with_event_history();
+        let handle = sim.entity(&id).unwrap();
+        let target = anchor + Duration::days(30);
+
+        let from_handle = handle.events_between(anchor, target);
+        let applied = handle.get_sorted_events_for_range(anchor, target, true);
+        assert_eq!(from_handle.len(), applied.len());
+        for (a, b) in from_handle.iter().zip(&applied) {
+            assert!(std::ptr::eq(*a, &**b));
+        }
+    }
+
+    #[test]
+    fn onset_increments_match_full_history_scan() {
+        use crate::enums::EventTag;
+
+        let mut sim = create_simulation();
+        let anchor = sim.reference_date();
+        let id = sim.add_entity(create_human("person_001"), anchor);
+        for day in 0..120u64 {
+            let mut builder = EventBuilder::new(EventType::Interaction).target(id.clone());
+            if day % 3 == 0 {
+                builder = builder.ramp(Duration::days(1 + day % 40), RampShape::Linear);
+            }
+            if day % 5 == 0 {
+                builder = builder
+                    .tag(EventTag::Anticipated)
+                    .anticipation_window(Duration::days(1 + day % 30));
+            }
+            sim.add_event(builder.build().unwrap(), anchor + Duration::days(day * 3));
+        }
+        let session = EventBuilder::new(EventType::Support)
+            .target(id.clone())
+            .ramp(Duration::days(20), RampShape::Linear)
+            .tag(EventTag::Anticipated)
+            .anticipation_window(Duration::days(10))
+            .build()
+            .unwrap();
+        sim.add_recurring_event(session, anchor, Duration::days(45), 8);
+        let handle = sim.entity(&id).unwrap();
+
+        // The previous implementation, scanning the whole history
+        let full_scan = |start: Timestamp, end: Timestamp| {
+            let mut found = Vec::new();
+            let horizon = end + Duration::days(400);
+            for te in handle.with_occurrences(handle.stored_events(), None, horizon) {
+                let event = te.event();
+                if te.timestamp() > end && !event.is_anticipated() {
+                    continue;
+                }
+                let anticipation = event
+                    .anticipation_increments()
+                    .into_iter()
+                    .map(|(lead, fraction)| (te.timestamp() - lead, fraction, true));
+                let onset = event
+                    .onset_increments()
+                    .into_iter()
+                    .map(|(offset, fraction)| (te.timestamp() + offset, fraction, false));
+                for (landing, fraction, anticipatory) in anticipation.chain(onset) {
+                    if landing > start && landing <= end {
+                        found.push((
+                            event.id().clone(),
+                            landing,
+                            fraction.to_bits(),
+                            anticipatory,
+                        ));
+                    }
+                }
+            }
+            found.sort_by(|a, b| (a.1, &a.0, a.3).cmp(&(b.1, &b.0, b.3)));
+            found
+        };
+
+        let ranges = [(0, 360), (50, 51), (100, 140), (200, 420), (355, 365)];
+        for (from, to) in ranges {
+            let start = anchor + Duration::days(from);
+            let end = anchor + Duration::days(to);
+            for is_forward in [true, false] {
+                let (query_anchor, target) = if is_forward {
+                    (start, end)
+                } else {
+                    (end, start)
+                };
+                let (events, increments) =
+                    handle.get_onset_increments_for_range(query_anchor, target, is_forward);
+                let mut bounded: Vec<_> = increments
+                    .iter()
+                    .map(|increment| {
+                        (
+                            events[increment.event_index].event().id().clone(),
+                            increment.landing,
+                            increment.fraction.to_bits(),
+                            increment.anticipatory,
+                        )
+                    })
+                    .collect();
+                bounded.sort_by(|a, b| (a.1, &a.0, a.3).cmp(&(b.1, &b.0, b.3)));
+                assert_eq!(bounded, full_scan(start, end), "range {from}..{to}");
+            }
+        }
+    }
+
+    #[test]
+    fn handle_event_count_first_and_last() {
+        let (sim, id, anchor) = sim_with_event_history();
+        let handle = sim.entity(&id).unwrap();
+
+        assert_eq!(handle.event_count(), 4);
+        let first = handle.first_event().unwrap();
+        assert_eq!(first.timestamp(), anchor);
+        let last = handle.last_event().unwrap();
+        assert_eq!(last.event().event_type(), EventType::Support);
+    }
+
+    #[test]
+    fn handle_events_of_type_in_application_order() {
+        let (sim, id, anchor) = sim_with_event_history();
+        let handle = sim.entity(&id).unwrap();
+
+        let achievements = handle.events_of_type(EventType::Achievement);
+        let days: Vec<Timestamp> = achievements.iter().map(|te| te.timestamp()).collect();
+        assert_eq!(days, vec![anchor, anchor + Duration::days(10)]);
+        assert!(handle.events_of_type(EventType::Violence).is_empty());
+    }
+
+    #[test]
+    fn handle_event_history_empty_for_entity_without_events() {
+        let mut sim = create_simulation();
+        let id = sim.add_entity(create_human("person_001"), sim.reference_date());
+        let handle = sim.entity(&id).unwrap();
+
+        assert_eq!(handle.event_count(), 0);
+        assert!(handle.first_event().is_none());
+        assert!(handle.last_event().is_none());
+    }
+
+    #[test]
+    fn handle_event_references_outlive_handle() {
+        let (sim, id, _) = sim_with_event_history();
+        let last = {
+            let handle = sim.entity(&id).unwrap();
+            handle.last_event()
+        };
+        assert!(last.is_some());
+    }
+
     #[test]
     fn entity_query_handle_anchor_timestamp() {
         let mut sim = create_simulation();
@@ -1429,6 +4360,80 @@ mod tests {
         assert_eq!(state2.life_stage(), LifeStage::Child);
     }
 
+    #[test]
+    fn life_stage_progress_near_human_boundary() {
+        let mut sim = create_simulation();
+        let anchor = sim.reference_date();
+        let entity = EntityBuilder::new()
+            .id("person_001")
+            .species(Species::Human)
+            .birth_date(anchor - Duration::years(12))
+            .build()
+            .unwrap();
+        sim.add_entity(entity, anchor);
+        let handle = sim.entity(&EntityId::new("person_001").unwrap()).unwrap();
+
+        // Age 12.9: nearly through childhood
+        let late_child = handle.state_at(anchor + Duration::days(330));
+        assert_eq!(late_child.life_stage(), LifeStage::Child);
+        assert!(late_child.life_stage_progress() > 0.98);
+        let remaining = late_child.time_until_next_stage().unwrap();
+        assert_eq!(remaining, Duration::days(35));
+        assert!(!late_child.age_is_static());
+
+        // Age 13: adolescence just begun
+        let adolescent = handle.state_at(anchor + Duration::years(1));
+        assert_eq!(adolescent.life_stage(), LifeStage::Adolescent);
+        assert!(adolescent.life_stage_progress() < 1e-9);
+        assert_eq!(adolescent.time_until_next_stage(), Some(Duration::years(5)));
+    }
+
+    #[test]
+    fn life_stage_progress_near_short_lifespan_boundary() {
+        let mut sim = create_simulation();
+        let anchor = sim.reference_date();
+        let entity = EntityBuilder::new()
+            .id("dog_001")
+            .species(Species::Dog)
+            .birth_date(anchor - Duration::years(10))
+            .build()
+            .unwrap();
+        sim.add_entity(entity, anchor);
+        let handle = sim.entity(&EntityId::new("dog_001").unwrap()).unwrap();
+
+        // Dog Elder begins at about 10.36 years
+        let mature = handle.state_at(anchor + Duration::days(110));
+        assert_eq!(mature.life_stage(), LifeStage::MatureAdult);
+        assert!(mature.life_stage_progress() > 0.9);
+        assert!(mature.time_until_next_stage().unwrap() < Duration::days(30));
+
+        let elder = handle.state_at(anchor + Duration::days(146));
+        assert_eq!(elder.life_stage(), LifeStage::Elder);
+        assert!(elder.life_stage_progress() < 0.05);
+        assert_eq!(elder.time_until_next_stage(), None);
+    }
+
+    #[test]
+    fn age_is_static_without_birth_date() {
+        let mut sim = create_simulation();
+        let entity = EntityBuilder::new()
+            .id("person_001")
+            .species(Species::Human)
+            .age(Duration::days(4700))
+            .build()
+            .unwrap();
+        let anchor = sim.reference_date();
+        sim.add_entity(entity, anchor);
+        let handle = sim.entity(&EntityId::new("person_001").unwrap()).unwrap();
+
+        let now = handle.state_at(anchor);
+        let later = handle.state_at(anchor + Duration::years(3));
+        assert!(now.age_is_static() && later.age_is_static());
+        assert_eq!(later.life_stage(), LifeStage::Child);
+        assert!((now.life_stage_progress() - later.life_stage_progress()).abs() < 1e-12);
+        assert_eq!(now.time_until_next_stage(), later.time_until_next_stage());
+    }
+
     #[test]
     fn regression_quality_forward_is_exact() {
         let mut sim = create_simulation();
@@ -1701,14 +4706,15 @@ mod tests {
         let anchor = sim.reference_date();
         sim.add_entity(entity, anchor);
 
-        // Query 10 years in the future - entity is now 35
-        // Memory formed at age 25, so it should still exist
-        let future = anchor + Duration::years(10);
+        // Query a month in the future - the memory has faded but is still held
+        let future = anchor + Duration::days(30);
         let handle = sim.entity(&EntityId::new("person_001").unwrap()).unwrap();
         let memories_future = handle.memories_at(future);
-
-        // Memory should still exist (formed at age 25, now age 35)
         assert_eq!(memories_future.len(), 1);
+        assert!(memories_future[0].salience() < 0.5);
+
+        // Ten years on, a memory of middling salience has been forgotten
+        assert!(handle.memories_at(anchor + Duration::years(10)).is_empty());
     }
 
     #[test]
@@ -1828,18 +4834,20 @@ mod tests {
         assert!(state.regression_quality().is_exact());
     }
 
+    #[cfg(feature = "context")]
     #[test]
     fn estimate_relationship_quality_accounts_for_attached_slots() {
         use crate::types::RelationshipId;
 
+        let sim = create_simulation();
         let mut entity = create_human("person_001");
-        let baseline = estimate_relationship_quality(&entity);
+        let baseline = estimate_relationship_quality(&entity, &sim, 0.0);
         assert!((baseline - 0.3).abs() < f64::EPSILON);
 
         let rel_id = RelationshipId::new("rel_attached").unwrap();
         entity.relationship_slots_mut()[0].attach(rel_id);
 
-        let attached = estimate_relationship_quality(&entity);
+        let attached = estimate_relationship_quality(&entity, &sim, 0.0);
         assert!(attached > baseline);
     }
 
@@ -2327,6 +5335,444 @@ mod tests {
         assert!((result.hexaco().openness() - original_openness).abs() < f32::EPSILON);
     }
 
+    #[cfg(feature = "context")]
+    #[test]
+    fn chronosystem_retirement_ends_work_and_lowers_purpose() {
+        use crate::context::{EcologicalContext, Microsystem, NormativeTransition, WorkContext};
+        use crate::enums::NeedsPath;
+        use crate::types::MicrosystemId;
+
+        let mut sim = create_simulation();
+        let reference = sim.reference_date();
+        let birth_date = reference - Duration::years(64);
+
+        let mut context = EcologicalContext::default();
+        context.add_microsystem(
+            MicrosystemId::new("office").unwrap(),
+            Microsystem::new_work(WorkContext::default()),
+        );
+        let mut retirement = NormativeTransition::new("retirement", 65.0, 3.0, false);
+        retirement.complete(64.5);
+        context.chronosystem_mut().add_normative_transition(retirement);
+
+        let entity = EntityBuilder::new()
+            .id("person_001")
+            .species(Species::Human)
+            .birth_date(birth_date)
+            .with_context(context)
+            .build()
+            .unwrap();
+        let entity_id = entity.id().clone();
+        sim.add_entity(entity, reference);
+
+        let exits = collect_role_exits(
+            &[],
+            sim.get_anchored_entity(&entity_id).unwrap().entity(),
+            reference,
+            reference + Duration::years(1),
+            true,
+        );
+        assert_eq!(exits.len(), 1);
+        assert!(exits[0].voluntary);
+
+        let handle = sim.entity(&entity_id).unwrap();
+        let before = handle.state_at(reference + Duration::days(60));
+        let after = handle.state_at(reference + Duration::days(200));
+        let purpose = StatePath::Needs(NeedsPath::Purpose);
+        assert!(after.get_effective(purpose) < before.get_effective(purpose));
+    }
+
+    #[cfg(all(feature = "context", feature = "relationships"))]
+    #[test]
+    fn dormant_relationship_contributes_less_relationship_quality() {
+        use crate::enums::RelationshipSchema;
+        use crate::relationship::RelationshipStage;
+
+        let mut sim = create_simulation();
+        let reference = sim.reference_date();
+        let alice = sim.add_entity(create_human("alice"), reference);
+        let bob = sim.add_entity(create_human("bob"), reference);
+        let rel_id = sim.add_relationship(alice.clone(), bob, RelationshipSchema::Peer, reference);
+        sim.get_relationship_mut(&rel_id)
+            .unwrap()
+            .relationship_mut()
+            .set_stage(RelationshipStage::Intimate)
+            .unwrap();
+
+        let handle = sim.entity(&alice).unwrap();
+        let entity = sim.get_anchored_entity(&alice).unwrap().entity();
+        let fresh = estimate_relationship_quality(
+            entity,
+            &sim,
+            handle.simulated_relationship_support(reference, &[]),
+        );
+        let dormant = estimate_relationship_quality(
+            entity,
+            &sim,
+            handle.simulated_relationship_support(reference + Duration::years(2), &[]),
+        );
+
+        assert!((fresh - 0.6).abs() < 1e-9);
+        assert!(dormant < fresh);
+        assert!(dormant > 0.3);
+    }
+
+    fn create_simulation_with_entity(anchor: Timestamp) -> Simulation {
+        let mut sim = create_simulation();
+        sim.add_entity(create_human("person_001"), anchor);
+        sim
+    }
+
+    fn sim_with_exclusion(anchor: Timestamp, onset: Timestamp, ramp: RampShape) -> Simulation {
+        let mut sim = create_simulation();
+        sim.add_entity(create_human("person_001"), anchor);
+        let event = EventBuilder::new(EventType::SocialExclusion)
+            .target(EntityId::new("person_001").unwrap())
+            .severity(0.8)
+            .ramp(Duration::days(4), ramp)
+            .build()
+            .unwrap();
+        sim.add_event(event, onset);
+        sim
+    }
+
+    #[test]
+    fn ramped_event_is_partially_applied_mid_ramp() {
+        let anchor = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let onset = anchor + Duration::days(1);
+        let id = EntityId::new("person_001").unwrap();
+        let prc = StatePath::SocialCognition(SocialCognitionPath::PerceivedReciprocalCaring);
+
+        let instant = sim_with_exclusion(anchor, onset, RampShape::Step);
+        let ramped = sim_with_exclusion(anchor, onset, RampShape::Linear);
+        let mid = onset + Duration::days(2);
+
+        let instant_prc = instant.entity(&id).unwrap().state_at(mid).get_effective(prc);
+        let ramped_prc = ramped.entity(&id).unwrap().state_at(mid).get_effective(prc);
+        let baseline = create_simulation_with_entity(anchor)
+            .entity(&id)
+            .unwrap()
+            .state_at(mid)
+            .get_effective(prc);
+
+        // Ramp increments are fresher but smaller than the instant hit
+        assert!(ramped_prc < baseline);
+        assert!(instant_prc < baseline);
+        assert!((ramped_prc - instant_prc).abs() > 1e-4);
+    }
+
+    #[test]
+    fn backward_regression_unwinds_only_landed_increments() {
+        let onset = Timestamp::from_ymd_hms(2024, 1, 10, 0, 0, 0);
+        let anchor = onset + Duration::days(4);
+        let id = EntityId::new("person_001").unwrap();
+        let prc = StatePath::SocialCognition(SocialCognitionPath::PerceivedReciprocalCaring);
+        let mid = onset + Duration::days(2);
+
+        let instant = sim_with_exclusion(anchor, onset, RampShape::Step);
+        let ramped = sim_with_exclusion(anchor, onset, RampShape::Linear);
+        let baseline = create_simulation_with_entity(anchor);
+
+        let baseline_mid = baseline.entity(&id).unwrap().state_at(mid).get_effective(prc);
+        let instant_mid = instant.entity(&id).unwrap().state_at(mid).get_effective(prc);
+        let ramped_mid = ramped.entity(&id).unwrap().state_at(mid).get_effective(prc);
+        let ramped_onset = ramped.entity(&id).unwrap().state_at(onset).get_effective(prc);
+        let instant_onset = instant.entity(&id).unwrap().state_at(onset).get_effective(prc);
+
+        // An instant event at onset lies outside (mid, anchor]
+        assert!((instant_mid - baseline_mid).abs() < 1e-9);
+        // The ramp's later increments landed after mid and are unwound
+        assert!(ramped_mid > baseline_mid);
+        // Regressing to onset unwinds every increment, more than mid-ramp
+        assert!(ramped_onset - ramped_mid > 0.0);
+        // The instant event is unwound in full when regressing before onset
+        let before_onset = onset - Duration::hours(1);
+        let instant_before = instant
+            .entity(&id)
+            .unwrap()
+            .state_at(before_onset)
+            .get_effective(prc);
+        assert!(instant_before > instant_onset);
+    }
+
+    #[test]
+    fn sample_times_include_end_and_handle_edges() {
+        let start = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let end = start + Duration::days(3);
+        assert_eq!(sample_times(start, end, Duration::days(1)).len(), 4);
+        assert_eq!(sample_times(start, end, Duration::days(2)).len(), 2);
+        assert_eq!(sample_times(start, end, Duration::zero()), vec![start]);
+        assert!(sample_times(end, start, Duration::days(1)).is_empty());
+    }
+
+    #[test]
+    fn series_matches_state_at_samples() {
+        let anchor = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let onset = anchor + Duration::days(1);
+        let sim = sim_with_exclusion(anchor, onset, RampShape::Step);
+        let handle = sim.entity(&EntityId::new("person_001").unwrap()).unwrap();
+        let prc = StatePath::SocialCognition(SocialCognitionPath::PerceivedReciprocalCaring);
+        let end = anchor + Duration::days(4);
+
+        let series = handle.series(prc, anchor, end, Duration::days(1));
+        let states = handle.state_series(anchor, end, Duration::days(1));
+        assert_eq!(series.len(), 5);
+        assert_eq!(states.len(), 5);
+        for ((ts, value), (state_ts, state)) in series.iter().zip(&states) {
+            assert_eq!(ts, state_ts);
+            assert!((handle.state_at(*ts).get_effective(prc) - value).abs() < 1e-12);
+            assert!((state.get_effective(prc) - value).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn preview_event_matches_adding_event_without_mutation() {
+        let anchor = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let onset = anchor + Duration::days(1);
+        let id = EntityId::new("person_001").unwrap();
+        let prc = StatePath::SocialCognition(SocialCognitionPath::PerceivedReciprocalCaring);
+        let event = EventBuilder::new(EventType::SocialExclusion)
+            .target(id.clone())
+            .severity(0.8)
+            .build()
+            .unwrap();
+        let query = onset + Duration::hours(12);
+
+        let sim = create_simulation_with_entity(anchor);
+        let handle = sim.entity(&id).unwrap();
+        let previewed = handle.preview_event(&event, onset, query).get_effective(prc);
+        let committed = sim_with_exclusion(anchor, onset, RampShape::Step)
+            .entity(&id)
+            .unwrap()
+            .state_at(query)
+            .get_effective(prc);
+
+        assert!((previewed - committed).abs() < 1e-12);
+        assert_eq!(handle.event_count(), 0);
+        assert!(handle.state_at(query).get_effective(prc) > previewed);
+    }
+
+    fn sim_with_observation(policy: ObservationPolicy) -> (Simulation, Timestamp) {
+        let anchor = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let observed_at = anchor + Duration::years(2);
+        let mut sim = create_simulation_with_entity(anchor);
+        sim.set_observation_policy(policy);
+        let mut observed = IndividualState::new();
+        observed.mood_mut().add_valence_delta(-0.4);
+        let id = EntityId::new("person_001").unwrap();
+        assert!(sim.add_observation(&id, observed, observed_at));
+        (sim, observed_at)
+    }
+
+    fn valence_at(sim: &Simulation, timestamp: Timestamp) -> f64 {
+        sim.entity(&EntityId::new("person_001").unwrap())
+            .unwrap()
+            .state_at(timestamp)
+            .get_effective(StatePath::Mood(crate::enums::MoodPath::Valence))
+    }
+
+    #[test]
+    fn anchor_and_observation_are_exact_under_both_policies() {
+        let blend = ObservationPolicy::Blend {
+            window: Duration::days(30),
+        };
+        for policy in [ObservationPolicy::Snap, blend] {
+            let (sim, observed_at) = sim_with_observation(policy);
+            let anchor = sim.reference_date();
+            let baseline = IndividualState::new().mood().valence_effective() as f64;
+            assert!((valence_at(&sim, anchor) - baseline).abs() < 1e-9);
+            assert!((valence_at(&sim, observed_at) - (baseline - 0.4)).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn blend_is_continuous_into_observation_and_snap_is_not() {
+        let window = Duration::days(30);
+        let (snap, observed_at) = sim_with_observation(ObservationPolicy::Snap);
+        let (blend, _) = sim_with_observation(ObservationPolicy::Blend { window });
+        let observed = valence_at(&blend, observed_at);
+        let just_before = observed_at - Duration::hours(1);
+
+        assert!((valence_at(&blend, just_before) - observed).abs() < 0.01);
+        assert!((valence_at(&snap, just_before) - observed).abs() > 0.3);
+
+        // Halfway through the window, half the residual is applied
+        let halfway = observed_at - Duration::days(15);
+        let gap = valence_at(&blend, halfway) - valence_at(&snap, halfway);
+        assert!((gap + 0.2).abs() < 1e-3);
+
+        // Before the window, both policies follow the anchor
+        let before_window = observed_at - Duration::days(60);
+        let gap = valence_at(&blend, before_window) - valence_at(&snap, before_window);
+        assert!(gap.abs() < 1e-9);
+    }
+
+    #[test]
+    fn state_after_observation_projects_from_it() {
+        let (sim, observed_at) = sim_with_observation(ObservationPolicy::Snap);
+        let mut reanchored = create_simulation();
+        let mut entity = create_human("person_001");
+        entity.individual_state_mut().mood_mut().add_valence_delta(-0.4);
+        reanchored.add_entity(entity, observed_at);
+
+        let later = observed_at + Duration::days(3);
+        assert!((valence_at(&sim, later) - valence_at(&reanchored, later)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn observation_residuals_compare_against_preceding_state() {
+        let (mut sim, observed_at) = sim_with_observation(ObservationPolicy::Snap);
+        let id = EntityId::new("person_001").unwrap();
+        let earlier = sim.reference_date() - Duration::days(10);
+        let mut before_anchor = IndividualState::new();
+        before_anchor.needs_mut().add_stress_delta(0.2);
+        assert!(sim.add_observation(&id, before_anchor, earlier));
+        assert!(!sim.add_observation(&id, IndividualState::new(), sim.reference_date()));
+
+        let residuals = sim.entity(&id).unwrap().observation_residuals();
+        assert_eq!(residuals.len(), 2);
+        assert_eq!(residuals[0].timestamp(), earlier);
+        assert_eq!(residuals[1].timestamp(), observed_at);
+
+        let valence = StatePath::Mood(crate::enums::MoodPath::Valence);
+        let unobserved = create_simulation_with_entity(sim.reference_date());
+        let projected = valence_at(&unobserved, observed_at);
+        let expected = valence_at(&sim, observed_at) - projected;
+        assert!((residuals[1].get(valence) - expected).abs() < 1e-9);
+        assert!(residuals[1].get(valence) < -0.3);
+        assert!(residuals[0].get(StatePath::Needs(crate::enums::NeedsPath::Stress)) > 0.0);
+    }
+
+    #[cfg(feature = "context")]
+    #[test]
+    fn collect_role_exits_empty_for_backward_query() {
+        let entity = create_human("person_001");
+        let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let exits = collect_role_exits(&[], &entity, reference, reference, false);
+        assert!(exits.is_empty());
+    }
+
+    #[cfg(feature = "context")]
+    #[test]
+    fn physiological_state_responds_to_health_condition() {
+        let mut sim = create_simulation();
+        let anchor = sim.reference_date();
+        let id = sim.add_entity(create_human("person_001"), anchor);
+        let before = sim.entity(&id).unwrap().state_at(anchor + Duration::days(3));
+
+        let onset = EventBuilder::new(EventType::HealthChange)
+            .target(id.clone())
+            .severity(0.8)
+            .payload(EventPayload::HealthCondition {
+                condition: ConditionKind::Autoimmune,
+                chronic: false,
+                severity_course: CourseShape::Sudden,
+            })
+            .build()
+            .unwrap();
+        sim.add_event(onset, anchor + Duration::days(2));
+        let after = sim.entity(&id).unwrap().state_at(anchor + Duration::days(3));
+
+        let physiological = after.physiological_state();
+        assert!(physiological.fatigue > before.physiological_state().fatigue);
+        assert_eq!(physiological, PhysiologicalState::snapshot(&after));
+        let fatigue = after.get_effective(StatePath::Needs(crate::enums::NeedsPath::Fatigue));
+        assert!((f64::from(physiological.fatigue) - fatigue).abs() < 1e-6);
+
+        let affective = after.affective_state();
+        assert_eq!(affective, AffectiveState::from_computed(&after));
+        let valence = after.get_effective(StatePath::Mood(crate::enums::MoodPath::Valence));
+        assert!((f64::from(affective.valence) - valence).abs() < 1e-6);
+    }
+
+    #[cfg(feature = "context")]
+    #[test]
+    fn collect_health_conditions_matches_remission_and_caregiving() {
+        let anchor = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let onset = EventBuilder::new(EventType::ChronicIllnessOnset)
+            .payload(EventPayload::HealthCondition {
+                condition: ConditionKind::Autoimmune,
+                chronic: true,
+                severity_course: CourseShape::Gradual,
+            })
+            .build()
+            .unwrap();
+        let care = EventBuilder::new(EventType::Support)
+            .payload(EventPayload::Support {
+                support_type: SupportType::Instrumental,
+                effectiveness: 0.8,
+                audience: Audience::Private,
+            })
+            .build()
+            .unwrap();
+        let remission = EventBuilder::new(EventType::HealthChange)
+            .payload(EventPayload::HealthRemission {
+                condition: ConditionKind::Autoimmune,
+            })
+            .build()
+            .unwrap();
+        let events = [
+            TimestampedEvent::new(onset, anchor + Duration::days(1)),
+            TimestampedEvent::new(care, anchor + Duration::days(5)),
+            TimestampedEvent::new(remission, anchor + Duration::days(90)),
+        ];
+        let refs: Vec<Cow<'_, TimestampedEvent>> = events.iter().map(Cow::Borrowed).collect();
+        let query = anchor + Duration::days(120);
+
+        let (conditions, caregiving) = collect_health_conditions(&refs, anchor, query, true);
+        assert_eq!(conditions.len(), 1);
+        assert_eq!(conditions[0].remission, Some(anchor + Duration::days(90)));
+        assert_eq!(caregiving, vec![anchor + Duration::days(5)]);
+
+        let (conditions, caregiving) = collect_health_conditions(&refs, query, anchor, false);
+        assert!(conditions.is_empty() && caregiving.is_empty());
+    }
+
+    #[cfg(feature = "context")]
+    #[test]
+    fn collect_concealments_records_disclosures_in_the_same_domain() {
+        use crate::types::RelationshipId;
+
+        let anchor = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let partner = RelationshipId::new("rel_partner").unwrap();
+        let concealment = |domain| {
+            EventBuilder::new(EventType::Interaction)
+                .payload(EventPayload::Concealment {
+                    domain,
+                    intensity: 0.7,
+                    ongoing: true,
+                    kept_from: vec![partner.clone()],
+                    until: None,
+                })
+                .build()
+                .unwrap()
+        };
+        let disclosure = EventBuilder::new(EventType::Interaction)
+            .payload(EventPayload::Disclosure {
+                domain: LifeDomain::Health,
+                relationship: partner.clone(),
+                reaction: 0.5,
+            })
+            .build()
+            .unwrap();
+        let events = [
+            TimestampedEvent::new(concealment(LifeDomain::Health), anchor + Duration::days(1)),
+            TimestampedEvent::new(concealment(LifeDomain::Work), anchor + Duration::days(2)),
+            TimestampedEvent::new(disclosure, anchor + Duration::days(30)),
+        ];
+        let refs: Vec<Cow<'_, TimestampedEvent>> = events.iter().map(Cow::Borrowed).collect();
+        let query = anchor + Duration::days(60);
+
+        let concealments = collect_concealments(&refs, anchor, query, true);
+        assert_eq!(concealments.len(), 2);
+        assert_eq!(concealments[0].ends_at(), Some(anchor + Duration::days(30)));
+        assert!(!concealments[0].conceals_from(&partner, query));
+        assert!(concealments[1].is_active_at(query));
+        assert!(concealments[1].conceals_from(&partner, query));
+
+        assert!(collect_concealments(&refs, query, anchor, false).is_empty());
+    }
+
     #[test]
     fn formative_event_all_hexaco_traits() {
         // Test all six HEXACO traits get shifted
@@ -2590,7 +6036,7 @@ mod tests {
 
         // Query timestamp is BEFORE event timestamp
         let query_ts = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
-        let records = collect_base_shift_records(&[&te], &entity, query_ts, true);
+        let records = collect_base_shift_records(&[Cow::Borrowed(&te)], &entity, query_ts, true);
 
         // Event after query should be skipped
         assert!(records.is_empty());
@@ -2618,7 +6064,7 @@ mod tests {
 
         // Query in 2020 (forward query)
         let query_ts = Timestamp::from_ymd_hms(2020, 1, 1, 0, 0, 0);
-        let records = collect_base_shift_records(&[&te], &entity, query_ts, true);
+        let records = collect_base_shift_records(&[Cow::Borrowed(&te)], &entity, query_ts, true);
 
         // Event should still be processed with age 0 (high plasticity)
         assert_eq!(records.len(), 1);
@@ -2646,7 +6092,7 @@ mod tests {
 
         // Query after event
         let query_ts = Timestamp::from_ymd_hms(2024, 6, 1, 0, 0, 0);
-        let records = collect_base_shift_records(&[&te], &entity, query_ts, true);
+        let records = collect_base_shift_records(&[Cow::Borrowed(&te)], &entity, query_ts, true);
 
         // Should have processed the event using entity.age() (35 years)
         assert_eq!(records.len(), 1);
@@ -2674,7 +6120,7 @@ mod tests {
 
         // Query in 2000
         let query_ts = Timestamp::from_ymd_hms(2000, 1, 1, 0, 0, 0);
-        let records = collect_base_shift_records(&[&te], &entity, query_ts, true);
+        let records = collect_base_shift_records(&[Cow::Borrowed(&te)], &entity, query_ts, true);
 
         // Event before 1970 should still be processed (uses Duration::zero)
         assert_eq!(records.len(), 1);
@@ -2705,7 +6151,7 @@ mod tests {
 
         // Query after event
         let query_ts = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
-        let records = collect_base_shift_records(&[&te], &entity, query_ts, true);
+        let records = collect_base_shift_records(&[Cow::Borrowed(&te)], &entity, query_ts, true);
 
         // The modified shift should be near zero and skipped
         assert!(records.is_empty());
@@ -2732,7 +6178,7 @@ mod tests {
         let te = TimestampedEvent::new(event, Timestamp::from_ymd_hms(2024, 1, 15, 0, 0, 0));
 
         let query_ts = Timestamp::from_ymd_hms(2024, 6, 1, 0, 0, 0);
-        let records = collect_base_shift_records(&[&te], &entity, query_ts, true);
+        let records = collect_base_shift_records(&[Cow::Borrowed(&te)], &entity, query_ts, true);
 
         // Should have one positive shift record
         assert_eq!(records.len(), 1);
@@ -2766,7 +6212,7 @@ mod tests {
         let te2 = TimestampedEvent::new(event2, Timestamp::from_ymd_hms(2024, 2, 15, 0, 0, 0));
 
         let query_ts = Timestamp::from_ymd_hms(2024, 6, 1, 0, 0, 0);
-        let records = collect_base_shift_records(&[&te1, &te2], &entity, query_ts, true);
+        let records = collect_base_shift_records(&[Cow::Borrowed(&te1), Cow::Borrowed(&te2)], &entity, query_ts, true);
 
         // Both shifts should be recorded
         assert_eq!(records.len(), 2);
@@ -0,0 +1,189 @@
+//! Reproducible variation between entities' reactions to events.
+//!
+//! Interpretation is deterministic: two entities with the same profile
+//! react to the same event identically, which suits tests but leaves a
+//! Monte Carlo population study with nothing to vary. With a
+//! `StochasticConfig` installed, each interpreted delta is scaled by a
+//! factor drawn uniformly from `1 ± magnitude`.
+//!
+//! The factor comes from a counter-based generator keyed on the seed, the
+//! entity ID, the event ID, and the state path, not from a stream of draws.
+//! The same seed therefore reproduces every state exactly, whatever order
+//! events were added or queried in, and changing one event leaves the
+//! noise on the others alone. Seed 0 adds no noise, so a simulation seeded
+//! with it matches one without stochastic mode.
+
+use crate::enums::{MentalHealthPath, MoodPath, SocialCognitionPath, StatePath};
+use crate::processor::InterpretedEvent;
+use crate::types::{EntityId, EventId};
+
+/// Default largest relative change noise makes to a delta.
+pub const DEFAULT_NOISE_MAGNITUDE: f64 = 0.1;
+
+/// Seed and size of the noise added to interpreted deltas.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::enums::{MoodPath, StatePath};
+/// use behavioral_pathways::simulation::StochasticConfig;
+/// use behavioral_pathways::types::{EntityId, EventId};
+///
+/// let config = StochasticConfig::new(42);
+/// let entity = EntityId::new("person").unwrap();
+/// let event = EventId::new("loss").unwrap();
+/// let path = StatePath::Mood(MoodPath::Valence);
+///
+/// let factor = config.factor(&entity, &event, path);
+/// assert!((0.9..=1.1).contains(&factor));
+/// assert_eq!(factor, config.factor(&entity, &event, path));
+/// assert_eq!(StochasticConfig::new(0).factor(&entity, &event, path), 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StochasticConfig {
+    /// Seed for the noise; 0 adds none.
+    pub seed: u64,
+    /// Largest relative change to a delta (0-1), so 0.1 scales each delta
+    /// by between 0.9 and 1.1.
+    pub magnitude: f64,
+}
+
+impl StochasticConfig {
+    /// Creates a config with the given seed and the default magnitude.
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        StochasticConfig {
+            seed,
+            magnitude: DEFAULT_NOISE_MAGNITUDE,
+        }
+    }
+
+    /// Returns the factor scaling the entity's delta on `path` from the
+    /// event.
+    #[must_use]
+    pub fn factor(&self, entity: &EntityId, event: &EventId, path: StatePath) -> f64 {
+        if self.seed == 0 {
+            return 1.0;
+        }
+        let mut key = mix(self.seed);
+        for part in [entity.as_str(), event.as_str(), path.key().as_str()] {
+            key = mix(key ^ hash(part));
+        }
+        // The top 53 bits give a uniform draw in [0, 1)
+        let uniform = (key >> 11) as f64 / (1u64 << 53) as f64;
+        1.0 + self.magnitude.clamp(0.0, 1.0) * (2.0 * uniform - 1.0)
+    }
+
+    /// Scales each of the entity's deltas in `interpreted` by its factor.
+    pub(crate) fn perturb(&self, interpreted: &mut InterpretedEvent, entity: &EntityId) {
+        if self.seed == 0 {
+            return;
+        }
+        let event = interpreted.original_event.clone();
+        let factor = |path| self.factor(entity, &event, path);
+        for (path, delta) in &mut interpreted.state_deltas {
+            *delta *= factor(*path);
+        }
+        for (path, delta) in [
+            (
+                StatePath::Mood(MoodPath::Valence),
+                &mut interpreted.valence_delta,
+            ),
+            (
+                StatePath::Mood(MoodPath::Arousal),
+                &mut interpreted.arousal_delta,
+            ),
+            (
+                StatePath::Mood(MoodPath::Dominance),
+                &mut interpreted.dominance_delta,
+            ),
+            (
+                StatePath::SocialCognition(SocialCognitionPath::Loneliness),
+                &mut interpreted.loneliness_delta,
+            ),
+            (
+                StatePath::SocialCognition(SocialCognitionPath::PerceivedReciprocalCaring),
+                &mut interpreted.prc_delta,
+            ),
+            (
+                StatePath::SocialCognition(SocialCognitionPath::PerceivedLiability),
+                &mut interpreted.perceived_liability_delta,
+            ),
+            (
+                StatePath::SocialCognition(SocialCognitionPath::SelfHate),
+                &mut interpreted.self_hate_delta,
+            ),
+            (
+                StatePath::MentalHealth(MentalHealthPath::AcquiredCapability),
+                &mut interpreted.acquired_capability_delta,
+            ),
+            (
+                StatePath::MentalHealth(MentalHealthPath::InterpersonalHopelessness),
+                &mut interpreted.interpersonal_hopelessness_delta,
+            ),
+        ] {
+            *delta = (f64::from(*delta) * factor(path)) as f32;
+        }
+    }
+}
+
+/// The SplitMix64 finalizer, which spreads every input bit over the output.
+fn mix(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
+
+/// FNV-1a hash of a string, stable across platforms and releases.
+fn hash(s: &str) -> u64 {
+    s.bytes().fold(0xCBF2_9CE4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01B3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids() -> (EntityId, EventId) {
+        (
+            EntityId::new("person").unwrap(),
+            EventId::new("loss").unwrap(),
+        )
+    }
+
+    #[test]
+    fn factors_are_spread_across_the_magnitude() {
+        let (entity, event) = ids();
+        let path = StatePath::Mood(MoodPath::Valence);
+        let factors: Vec<f64> = (1..=200)
+            .map(|seed| StochasticConfig::new(seed).factor(&entity, &event, path))
+            .collect();
+        assert!(factors.iter().all(|factor| (0.9..=1.1).contains(factor)));
+        assert!(factors.iter().any(|factor| *factor < 0.95));
+        assert!(factors.iter().any(|factor| *factor > 1.05));
+        let mean = factors.iter().sum::<f64>() / factors.len() as f64;
+        assert!((mean - 1.0).abs() < 0.02);
+    }
+
+    #[test]
+    fn factors_differ_by_entity_event_and_path() {
+        let (entity, event) = ids();
+        let config = StochasticConfig {
+            seed: 7,
+            magnitude: 0.5,
+        };
+        let valence = StatePath::Mood(MoodPath::Valence);
+        let base = config.factor(&entity, &event, valence);
+        let other_entity = EntityId::new("other").unwrap();
+        let other_event = EventId::new("gain").unwrap();
+        assert_ne!(base, config.factor(&other_entity, &event, valence));
+        assert_ne!(base, config.factor(&entity, &other_event, valence));
+        assert_ne!(
+            base,
+            config.factor(&entity, &event, StatePath::Mood(MoodPath::Arousal))
+        );
+    }
+}
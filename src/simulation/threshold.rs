@@ -0,0 +1,385 @@
+//! Time-to-threshold projections.
+//!
+//! Risk monitoring asks when, assuming nothing new happens, a state path
+//! will cross a threshold: when stress will fall back below 0.4, or when
+//! hopelessness will cross a risk level. Between the landings of logged
+//! events a stored path has a known form. Its acute and chronic deltas
+//! decay exponentially toward the base, and sustained context loads move
+//! the base at a steady rate. Each segment between landings is fitted to
+//! that form from the queried state at its start and end, and the fit is
+//! checked at its midpoint:
+//!
+//! - A single decaying delta with no accumulation is solved with a
+//!   logarithm.
+//! - Two deltas or an accumulating base are bisected on the fitted
+//!   trajectory, which costs no further queries.
+//! - Segments the form does not fit, such as computed ITS factors or loads
+//!   that build up over time, are bisected on `state_at()` itself.
+//!
+//! Crossings found on a fit are confirmed against `state_at()`, so the
+//! returned time always meets the condition. An event whose landing
+//! carries the path across the threshold answers with its landing time.
+
+use crate::enums::StatePath;
+use crate::simulation::EntityQueryHandle;
+use crate::state::StateValue;
+use crate::types::{Duration, Timestamp};
+use std::cmp::Ordering;
+
+/// Largest gap between the fitted trajectory and `state_at()` at a
+/// segment's midpoint for the fit to be used.
+const FIT_TOLERANCE: f64 = 1e-4;
+
+/// Precision of bisected crossing times.
+const RESOLUTION: Duration = Duration::seconds(1);
+
+/// Points scanned for a first crossing when bisecting `state_at()`.
+const QUERY_SCAN_POINTS: u64 = 32;
+
+/// Points scanned for a first crossing when bisecting a fitted trajectory.
+const FIT_SCAN_POINTS: u64 = 256;
+
+/// The condition a projection waits for.
+struct Target {
+    comparator: Ordering,
+    value: f64,
+    /// Which side of `value` the path starts on, for `Equal`.
+    side: f64,
+}
+
+impl Target {
+    /// Returns true if `effective` satisfies the condition.
+    fn is_met(&self, effective: f64) -> bool {
+        match self.comparator {
+            Ordering::Less => effective < self.value,
+            Ordering::Greater => effective > self.value,
+            Ordering::Equal => (effective - self.value) * self.side <= 0.0,
+        }
+    }
+}
+
+/// A stored path's trajectory over a segment without event landings.
+struct Trajectory {
+    /// The path's value at the start of the segment.
+    value: StateValue,
+    start: Timestamp,
+    /// Base change per real second from sustained loads.
+    drift: f64,
+}
+
+impl Trajectory {
+    /// Returns the path's effective value at `at`.
+    fn effective_at(&self, handle: &EntityQueryHandle<'_>, at: Timestamp) -> f64 {
+        let elapsed = at - self.start;
+        let mut value = self.value.clone();
+        if handle.species().decays() {
+            value.apply_decay(handle.species().psychological_duration(elapsed));
+        }
+        f64::from(value.effective()) + self.drift * elapsed.as_millis() as f64 / 1000.0
+    }
+
+    /// Returns when a lone decaying delta reaches the target, if it does.
+    ///
+    /// `None` means the trajectory needs bisecting, not that the target is
+    /// unreachable; unreachable targets are caught by the caller.
+    fn solve(&self, handle: &EntityQueryHandle<'_>, target: &Target) -> Option<Timestamp> {
+        if !handle.species().decays() {
+            return None;
+        }
+        let half_life = self.value.decay_half_life()?;
+        let delta = f64::from(self.value.delta());
+        if self.drift != 0.0 || self.value.chronic_delta() != 0.0 || delta == 0.0 {
+            return None;
+        }
+        let remaining = (target.value - f64::from(self.value.base())) / delta;
+        if !(remaining > 0.0 && remaining < 1.0) {
+            return None;
+        }
+        let psychological = half_life.as_millis() as f64 * -remaining.log2();
+        let real = psychological / f64::from(handle.species().time_scale());
+        Some(self.start + Duration::from_millis(real.ceil() as u64))
+    }
+}
+
+/// Returns when `path` first satisfies `comparator` against `value` within
+/// `horizon` of `from`.
+pub(crate) fn time_to_value(
+    handle: &EntityQueryHandle<'_>,
+    path: StatePath,
+    comparator: Ordering,
+    value: f64,
+    from: Timestamp,
+    horizon: Duration,
+) -> Option<Timestamp> {
+    let current = handle.state_at(from).get_effective(path);
+    let target = Target {
+        comparator,
+        value,
+        side: (current - value).signum(),
+    };
+    if target.is_met(current) {
+        return Some(from);
+    }
+
+    let end = from + horizon;
+    let mut start = from;
+    for boundary in handle
+        .projection_breaks(from, end)
+        .into_iter()
+        .chain(std::iter::once(end))
+    {
+        if let Some(crossing) = solve_segment(handle, path, &target, start, boundary) {
+            return Some(crossing);
+        }
+        // An event landing at the boundary may carry the path across
+        if target.is_met(handle.state_at(boundary).get_effective(path)) {
+            return Some(boundary);
+        }
+        start = boundary;
+    }
+    None
+}
+
+/// Returns the first crossing in (`start`, `end`), excluding `end` itself.
+fn solve_segment(
+    handle: &EntityQueryHandle<'_>,
+    path: StatePath,
+    target: &Target,
+    start: Timestamp,
+    end: Timestamp,
+) -> Option<Timestamp> {
+    let last = end - Duration::from_millis(1);
+    if last <= start {
+        return None;
+    }
+    let queried = |at: Timestamp| handle.state_at(at).get_effective(path);
+
+    match fit(handle, path, start, last) {
+        Some(trajectory) => {
+            let crossing = trajectory.solve(handle, target).or_else(|| {
+                first_crossing(
+                    |at| trajectory.effective_at(handle, at),
+                    target,
+                    start,
+                    last,
+                    FIT_SCAN_POINTS,
+                )
+            })?;
+            settle(queried, target, crossing, last)
+        }
+        None => first_crossing(queried, target, start, last, QUERY_SCAN_POINTS),
+    }
+}
+
+/// Moves a crossing found on a fitted trajectory forward until the queried
+/// state meets `target`.
+///
+/// The fit and `state_at()` round differently, so a crossing solved on
+/// the fit can fall just short of the threshold. Steps double from
+/// `RESOLUTION` until the target is met, then the last step is bisected.
+fn settle(
+    effective_at: impl Fn(Timestamp) -> f64,
+    target: &Target,
+    crossing: Timestamp,
+    last: Timestamp,
+) -> Option<Timestamp> {
+    if crossing > last {
+        return None;
+    }
+    if target.is_met(effective_at(crossing)) {
+        return Some(crossing);
+    }
+    let (mut low, mut step) = (crossing, RESOLUTION);
+    loop {
+        let high = (low + step).min(last);
+        if target.is_met(effective_at(high)) {
+            return Some(bisect(&effective_at, target, low, high));
+        }
+        if high == last {
+            return None;
+        }
+        low = high;
+        step = step + step;
+    }
+}
+
+/// Fits the decay-plus-drift form to `path` between `start` and `last`.
+///
+/// Returns `None` for computed paths and when the fit misses the queried
+/// midpoint by more than `FIT_TOLERANCE`.
+fn fit(
+    handle: &EntityQueryHandle<'_>,
+    path: StatePath,
+    start: Timestamp,
+    last: Timestamp,
+) -> Option<Trajectory> {
+    let state = handle.state_at(start);
+    let mut trajectory = Trajectory {
+        value: state.individual_state().state_value(path)?.clone(),
+        start,
+        drift: 0.0,
+    };
+
+    let span = last - start;
+    let residual =
+        handle.state_at(last).get_effective(path) - trajectory.effective_at(handle, last);
+    if residual.abs() > FIT_TOLERANCE {
+        trajectory.drift = residual / (span.as_millis() as f64 / 1000.0);
+    }
+
+    let midpoint = start + Duration::from_millis(span.as_millis() / 2);
+    let miss =
+        handle.state_at(midpoint).get_effective(path) - trajectory.effective_at(handle, midpoint);
+    (miss.abs() <= FIT_TOLERANCE).then_some(trajectory)
+}
+
+/// Scans `points` evenly spaced times in (`start`, `last`] for the first
+/// that meets `target`, then bisects the step before it to `RESOLUTION`.
+fn first_crossing(
+    effective_at: impl Fn(Timestamp) -> f64,
+    target: &Target,
+    start: Timestamp,
+    last: Timestamp,
+    points: u64,
+) -> Option<Timestamp> {
+    let span = (last - start).as_millis();
+    let at = |point: u64| start + Duration::from_millis(span * point / points);
+    let point = (1..=points).find(|point| target.is_met(effective_at(at(*point))))?;
+
+    Some(bisect(&effective_at, target, at(point - 1), at(point)))
+}
+
+/// Bisects between an unmet `low` and a met `high` to `RESOLUTION`.
+fn bisect(
+    effective_at: impl Fn(Timestamp) -> f64,
+    target: &Target,
+    mut low: Timestamp,
+    mut high: Timestamp,
+) -> Timestamp {
+    while high - low > RESOLUTION {
+        let middle = low + Duration::from_millis((high - low).as_millis() / 2);
+        if target.is_met(effective_at(middle)) {
+            high = middle;
+        } else {
+            low = middle;
+        }
+    }
+    high
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::EntityBuilder;
+    use crate::enums::{EventType, MentalHealthPath, MoodPath, Species};
+    use crate::event::EventBuilder;
+    use crate::simulation::Simulation;
+    use crate::types::EntityId;
+
+    const DOMINANCE: StatePath = StatePath::Mood(MoodPath::Dominance);
+
+    fn reference() -> Timestamp {
+        Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+    }
+
+    fn simulation(species: Species) -> (Simulation, EntityId) {
+        let mut sim = Simulation::new(reference());
+        let entity = EntityBuilder::new()
+            .id("subject")
+            .species(species)
+            .build()
+            .unwrap();
+        let id = sim.add_entity(entity, reference());
+        let conflict = EventBuilder::new(EventType::Conflict)
+            .target(id.clone())
+            .severity(0.8)
+            .build()
+            .unwrap();
+        sim.add_event(conflict, reference() + Duration::days(1));
+        (sim, id)
+    }
+
+    #[test]
+    fn target_sides() {
+        let below = Target {
+            comparator: Ordering::Less,
+            value: 0.4,
+            side: 1.0,
+        };
+        assert!(below.is_met(0.39) && !below.is_met(0.4));
+
+        let reach_from_above = Target {
+            comparator: Ordering::Equal,
+            value: 0.4,
+            side: 1.0,
+        };
+        assert!(reach_from_above.is_met(0.4) && reach_from_above.is_met(0.3));
+        assert!(!reach_from_above.is_met(0.41));
+    }
+
+    #[test]
+    fn species_time_scale_shortens_the_solved_time() {
+        let threshold = |species: Species| {
+            let (sim, id) = simulation(species.clone());
+            let handle = sim.entity(&id).unwrap();
+            let landing = reference() + Duration::days(1);
+            let state = handle.state_at(landing);
+            let value = state.individual_state().state_value(DOMINANCE).unwrap();
+            // One half-life after the landing
+            let level = f64::from(value.base()) + f64::from(value.delta()) / 2.0;
+            let crossing = handle
+                .time_to_value(
+                    DOMINANCE,
+                    Ordering::Equal,
+                    level,
+                    landing,
+                    Duration::days(60),
+                )
+                .unwrap();
+            let half_life = value.decay_half_life().unwrap().as_millis() as f64;
+            let expected = half_life / f64::from(species.time_scale());
+            let elapsed = (crossing - landing).as_millis() as f64;
+            assert!(
+                (elapsed - expected).abs() <= 1000.0,
+                "{} vs {}",
+                elapsed,
+                expected
+            );
+            crossing - landing
+        };
+
+        let human = threshold(Species::Human);
+        let dog = threshold(Species::Dog);
+        assert!(dog < human);
+        let ratio = human.as_millis() as f64 / dog.as_millis() as f64;
+        assert!((ratio - f64::from(Species::Dog.time_scale())).abs() < 0.01);
+    }
+
+    #[test]
+    fn computed_paths_are_bisected_on_queries() {
+        let (sim, id) = simulation(Species::Human);
+        let handle = sim.entity(&id).unwrap();
+        let path = StatePath::MentalHealth(MentalHealthPath::ThwartedBelongingness);
+        let landing = reference() + Duration::days(1);
+        let start = handle.state_at(landing).get_effective(path);
+        let settled = handle
+            .state_at(landing + Duration::days(60))
+            .get_effective(path);
+        let level = (start + settled) / 2.0;
+        let comparator = if settled < start {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        };
+
+        let crossing = handle
+            .time_to_value(path, comparator, level, landing, Duration::days(60))
+            .unwrap();
+        let at = handle.state_at(crossing).get_effective(path);
+        let before = handle
+            .state_at(crossing - Duration::seconds(2))
+            .get_effective(path);
+        assert_eq!(at.partial_cmp(&level), Some(comparator));
+        assert_ne!(before.partial_cmp(&level), Some(comparator));
+    }
+}
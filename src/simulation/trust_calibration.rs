@@ -0,0 +1,298 @@
+//! Checking trust predictions against what actually happened.
+//!
+//! Each `Interaction`, `Support`, or `Betrayal` event between two related
+//! entities is a test of the trust model: just before the event, the target
+//! (as trustor) was predicted to be some amount willing to trust the source
+//! (as trustee), and the event then either honored or betrayed that trust.
+//! Predictions are recomputed retrospectively with `predict_interaction` one
+//! second before each event, so neither the event's state effects nor its
+//! trust antecedents inform its own prediction.
+//!
+//! The willingness compared is the one for the event's domain: support
+//! willingness for `Support`, and disclosure willingness for `Interaction`
+//! and `Betrayal`.
+
+use crate::enums::{EventType, TrustDomain};
+use crate::simulation::Simulation;
+use crate::types::{Duration, EntityId, EventId, Timestamp};
+
+/// Predicted willingness at or above which trust counts as predicted.
+pub const TRUST_CALIBRATION_THRESHOLD: f64 = 0.5;
+
+/// Whether an event honored or betrayed the trust placed in its source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TrustOutcome {
+    /// The source interacted with or supported the target.
+    Honored,
+    /// The source betrayed the target.
+    Betrayed,
+}
+
+impl TrustOutcome {
+    /// Returns the outcome an event type realizes, if it tests trust.
+    #[must_use]
+    pub fn for_event_type(event_type: EventType) -> Option<TrustOutcome> {
+        match event_type {
+            EventType::Interaction | EventType::Support => Some(TrustOutcome::Honored),
+            EventType::Betrayal => Some(TrustOutcome::Betrayed),
+            _ => None,
+        }
+    }
+
+    /// Returns 1.0 for an honored outcome and 0.0 for a betrayal.
+    #[must_use]
+    pub fn value(self) -> f64 {
+        match self {
+            TrustOutcome::Honored => 1.0,
+            TrustOutcome::Betrayed => 0.0,
+        }
+    }
+}
+
+/// One prediction compared with its realized outcome.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrustCalibrationRecord {
+    /// The event that realized the outcome.
+    pub event_id: EventId,
+    /// When the event occurred.
+    pub timestamp: Timestamp,
+    /// The event target, whose trust was tested.
+    pub trustor: EntityId,
+    /// The event source, who honored or betrayed the trust.
+    pub trustee: EntityId,
+    /// Willingness predicted just before the event (0-1).
+    pub predicted: f64,
+    /// What the event realized.
+    pub outcome: TrustOutcome,
+}
+
+impl TrustCalibrationRecord {
+    /// Returns true if the prediction and outcome agree.
+    ///
+    /// Predicted willingness at or above `TRUST_CALIBRATION_THRESHOLD`
+    /// agrees with an honored outcome; below it agrees with a betrayal.
+    #[must_use]
+    pub fn is_hit(&self) -> bool {
+        let predicted_trust = self.predicted >= TRUST_CALIBRATION_THRESHOLD;
+        predicted_trust == (self.outcome == TrustOutcome::Honored)
+    }
+
+    /// Returns the squared error between prediction and outcome.
+    #[must_use]
+    pub fn squared_error(&self) -> f64 {
+        (self.predicted - self.outcome.value()).powi(2)
+    }
+}
+
+/// How well trust predictions matched interaction outcomes.
+///
+/// Returned by `Simulation::trust_calibration`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TrustCalibrationReport {
+    /// Records in chronological order.
+    records: Vec<TrustCalibrationRecord>,
+}
+
+impl TrustCalibrationReport {
+    /// Returns every compared prediction, in chronological order.
+    #[must_use]
+    pub fn records(&self) -> &[TrustCalibrationRecord] {
+        &self.records
+    }
+
+    /// Returns the number of predictions that matched their outcome.
+    #[must_use]
+    pub fn hits(&self) -> usize {
+        self.records.iter().filter(|record| record.is_hit()).count()
+    }
+
+    /// Returns the number of predictions that did not match their outcome.
+    #[must_use]
+    pub fn misses(&self) -> usize {
+        self.records.len() - self.hits()
+    }
+
+    /// Returns the mean squared error of the predictions (0 is perfect,
+    /// 1 is always confidently wrong).
+    ///
+    /// Returns `None` when there are no records.
+    #[must_use]
+    pub fn brier_score(&self) -> Option<f64> {
+        if self.records.is_empty() {
+            return None;
+        }
+        let total: f64 = self
+            .records
+            .iter()
+            .map(TrustCalibrationRecord::squared_error)
+            .sum();
+        Some(total / self.records.len() as f64)
+    }
+
+    /// Returns true if no events were compared.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+}
+
+/// Compares trust predictions with outcomes for events in `[start, end]`.
+pub(crate) fn trust_calibration(
+    simulation: &Simulation,
+    between: Option<&(EntityId, EntityId)>,
+    start: Timestamp,
+    end: Timestamp,
+) -> TrustCalibrationReport {
+    let mut events = simulation.events_between(start, end);
+    events.sort_by_key(|te| te.timestamp());
+
+    let mut records = Vec::new();
+    for te in events {
+        let event = te.event();
+        let Some(outcome) = TrustOutcome::for_event_type(event.event_type()) else {
+            continue;
+        };
+        let (Some(trustee), Some(trustor)) = (event.source(), event.target()) else {
+            continue;
+        };
+        if trustee == trustor {
+            continue;
+        }
+        if let Some((a, b)) = between {
+            // Either may be the trustor
+            let pair = (trustor == a && trustee == b) || (trustor == b && trustee == a);
+            if !pair {
+                continue;
+            }
+        }
+
+        let domain = match event.event_type() {
+            EventType::Support => TrustDomain::Support,
+            _ => TrustDomain::Disclosure,
+        };
+        let before = te.timestamp() - Duration::seconds(1);
+        let Some(decision) = simulation.predict_interaction(trustor, trustee, domain, before)
+        else {
+            continue;
+        };
+        let willingness = match domain {
+            TrustDomain::Support => decision.support_willingness(),
+            _ => decision.disclosure_willingness(),
+        };
+        records.push(TrustCalibrationRecord {
+            event_id: event.id().clone(),
+            timestamp: te.timestamp(),
+            trustor: trustor.clone(),
+            trustee: trustee.clone(),
+            predicted: f64::from(willingness),
+            outcome,
+        });
+    }
+    TrustCalibrationReport { records }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::EntityBuilder;
+    use crate::enums::{RelationshipSchema, Species};
+    use crate::event::EventBuilder;
+
+    fn reference() -> Timestamp {
+        Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+    }
+
+    fn record(predicted: f64, outcome: TrustOutcome) -> TrustCalibrationRecord {
+        TrustCalibrationRecord {
+            event_id: EventId::new("evt").unwrap(),
+            timestamp: reference(),
+            trustor: EntityId::new("ana").unwrap(),
+            trustee: EntityId::new("ben").unwrap(),
+            predicted,
+            outcome,
+        }
+    }
+
+    #[test]
+    fn scores_known_predictions() {
+        let report = TrustCalibrationReport {
+            records: vec![
+                record(0.8, TrustOutcome::Honored),
+                record(0.6, TrustOutcome::Betrayed),
+                record(0.2, TrustOutcome::Betrayed),
+                record(0.4, TrustOutcome::Honored),
+            ],
+        };
+
+        assert_eq!(report.hits(), 2);
+        assert_eq!(report.misses(), 2);
+        // (0.04 + 0.36 + 0.04 + 0.36) / 4
+        assert!((report.brier_score().unwrap() - 0.2).abs() < 1e-12);
+        assert!(TrustCalibrationReport::default().brier_score().is_none());
+    }
+
+    #[test]
+    fn compares_events_between_related_entities() {
+        let mut sim = Simulation::new(reference());
+        for id in ["ana", "ben", "cal"] {
+            let entity = EntityBuilder::new()
+                .id(id)
+                .species(Species::Human)
+                .build()
+                .unwrap();
+            sim.add_entity(entity, reference());
+        }
+        let [ana, ben, cal] = ["ana", "ben", "cal"].map(|id| EntityId::new(id).unwrap());
+        sim.add_relationship(
+            ana.clone(),
+            ben.clone(),
+            RelationshipSchema::Peer,
+            reference(),
+        );
+        let event = |event_type, source: &EntityId, target: &EntityId| {
+            EventBuilder::new(event_type)
+                .source(source.clone())
+                .target(target.clone())
+                .severity(0.6)
+                .build()
+                .unwrap()
+        };
+        sim.add_event(
+            event(EventType::Support, &ben, &ana),
+            reference() + Duration::days(1),
+        );
+        sim.add_event(
+            event(EventType::Betrayal, &ben, &ana),
+            reference() + Duration::days(2),
+        );
+        // Unrelated entities and non-trust events are skipped
+        sim.add_event(
+            event(EventType::Support, &cal, &ana),
+            reference() + Duration::days(3),
+        );
+        sim.add_event(
+            event(EventType::Conflict, &ben, &ana),
+            reference() + Duration::days(4),
+        );
+
+        let end = reference() + Duration::days(10);
+        let report = sim.trust_calibration(None, reference(), end);
+        assert_eq!(report.records().len(), 2);
+        let support = &report.records()[0];
+        assert_eq!(support.trustor, ana);
+        assert_eq!(support.outcome, TrustOutcome::Honored);
+
+        // Each prediction matches predicting directly just before the event
+        let before = support.timestamp - Duration::seconds(1);
+        let expected = sim
+            .predict_interaction(&ana, &ben, TrustDomain::Support, before)
+            .unwrap()
+            .support_willingness();
+        assert!((support.predicted - f64::from(expected)).abs() < f64::EPSILON);
+
+        let only_cal = sim.trust_calibration(Some((cal, ana)), reference(), end);
+        assert!(only_cal.is_empty());
+        let window = sim.trust_calibration(None, reference(), reference() + Duration::days(1));
+        assert_eq!(window.records().len(), 1);
+    }
+}
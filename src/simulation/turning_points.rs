@@ -0,0 +1,166 @@
+//! Turning points derived from formative events.
+//!
+//! An event that reshapes personality is what a turning point records, so
+//! a state query derives a `TurningPoint` for each event after the anchor
+//! whose formative shifts, as applied, add up to at least
+//! `TURNING_POINT_SHIFT_THRESHOLD`, and for each event tagged
+//! `EventTag::TurningPoint`. The domain is inferred with
+//! `TurningPointDomain::for_event` and the magnitude is the event's
+//! severity.
+//!
+//! As with formative shifts, turning points at or before the anchor are
+//! taken to be recorded in the anchor's chronosystem already, and a query
+//! before an event never includes it.
+
+use crate::context::{TurningPoint, TurningPointDomain};
+use crate::enums::EventTag;
+use crate::simulation::TimestampedEvent;
+use crate::state::BaseShiftRecord;
+use crate::types::Timestamp;
+use std::borrow::Cow;
+
+/// Combined applied formative shift, summed over the traits an event
+/// shifts, at which the event becomes a turning point.
+///
+/// An adult's 0.3 request on a single trait applies about 0.12, while
+/// mild requests of 0.1 on two traits apply about 0.08 together.
+pub const TURNING_POINT_SHIFT_THRESHOLD: f32 = 0.1;
+
+/// Returns the turning points at `timestamp`: those `recorded` at or
+/// before it, and those derived from the entity's `events` since the
+/// anchor, in timestamp order.
+///
+/// `event_shifts` holds the formative shifts applied for each of `events`.
+/// Backward queries derive none.
+pub(crate) fn turning_points_at(
+    recorded: &[TurningPoint],
+    events: &[Cow<'_, TimestampedEvent>],
+    event_shifts: &[Vec<BaseShiftRecord>],
+    timestamp: Timestamp,
+    is_forward: bool,
+) -> Vec<TurningPoint> {
+    let mut turning_points: Vec<TurningPoint> = recorded
+        .iter()
+        .filter(|tp| tp.timestamp <= timestamp)
+        .cloned()
+        .collect();
+    if is_forward {
+        turning_points.extend(
+            events
+                .iter()
+                .zip(event_shifts)
+                .filter(|(te, shifts)| te.timestamp() <= timestamp && is_turning_point(te, shifts))
+                .map(|(te, _)| {
+                    let event = te.event();
+                    TurningPoint {
+                        event_id: event.id().clone(),
+                        timestamp: te.timestamp(),
+                        domain: TurningPointDomain::for_event(event.event_type(), event.payload()),
+                        magnitude: event.severity(),
+                    }
+                }),
+        );
+    }
+    turning_points.sort_by_key(|tp| tp.timestamp);
+    turning_points
+}
+
+/// Returns true if `te` is tagged as a turning point or its applied
+/// `shifts` reach `TURNING_POINT_SHIFT_THRESHOLD`.
+fn is_turning_point(te: &TimestampedEvent, shifts: &[BaseShiftRecord]) -> bool {
+    let combined: f32 = shifts.iter().map(|record| record.immediate().abs()).sum();
+    te.event().has_tag(EventTag::TurningPoint) || combined >= TURNING_POINT_SHIFT_THRESHOLD
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::{EventType, HexacoPath};
+    use crate::event::EventBuilder;
+    use crate::types::{Duration, EventId};
+
+    fn reference() -> Timestamp {
+        Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+    }
+
+    fn event(id: &str, builder: EventBuilder, days: u64) -> TimestampedEvent {
+        let event = builder.id(EventId::new(id).unwrap()).build().unwrap();
+        TimestampedEvent::new(event, reference() + Duration::days(days))
+    }
+
+    fn shift(amount: f32) -> Vec<BaseShiftRecord> {
+        vec![BaseShiftRecord::new(
+            Duration::zero(),
+            HexacoPath::Neuroticism,
+            amount,
+        )]
+    }
+
+    #[test]
+    fn derives_from_large_shifts_and_tags_up_to_the_timestamp() {
+        let events = [
+            event("small", EventBuilder::new(EventType::Loss), 1),
+            event("large", EventBuilder::new(EventType::Loss), 2),
+            event(
+                "tagged",
+                EventBuilder::new(EventType::ContextTransition).tag(EventTag::TurningPoint),
+                3,
+            ),
+            event("late", EventBuilder::new(EventType::Loss), 10),
+        ];
+        let events: Vec<Cow<'_, TimestampedEvent>> = events.iter().map(Cow::Borrowed).collect();
+        let shifts = [shift(0.05), shift(-0.2), Vec::new(), shift(0.3)];
+
+        let turning_points =
+            turning_points_at(&[], &events, &shifts, reference() + Duration::days(5), true);
+        let ids: Vec<&str> = turning_points
+            .iter()
+            .map(|tp| tp.event_id.as_str())
+            .collect();
+        assert_eq!(ids, ["large", "tagged"]);
+        assert_eq!(turning_points[0].domain, TurningPointDomain::Loss);
+        assert_eq!(turning_points[1].domain, TurningPointDomain::Location);
+
+        let backward = turning_points_at(
+            &[],
+            &events,
+            &shifts,
+            reference() + Duration::days(5),
+            false,
+        );
+        assert!(backward.is_empty());
+    }
+
+    #[test]
+    fn recorded_turning_points_are_merged_in_order() {
+        let recorded = [
+            TurningPoint {
+                event_id: EventId::new("marriage").unwrap(),
+                timestamp: reference() + Duration::days(4),
+                domain: TurningPointDomain::Relationship,
+                magnitude: 0.7,
+            },
+            TurningPoint {
+                event_id: EventId::new("future").unwrap(),
+                timestamp: reference() + Duration::days(40),
+                domain: TurningPointDomain::Career,
+                magnitude: 0.5,
+            },
+        ];
+        let events = [event("large", EventBuilder::new(EventType::Loss), 2)];
+        let events: Vec<Cow<'_, TimestampedEvent>> = events.iter().map(Cow::Borrowed).collect();
+
+        let turning_points = turning_points_at(
+            &recorded,
+            &events,
+            &[shift(0.2)],
+            reference() + Duration::days(5),
+            true,
+        );
+        let ids: Vec<&str> = turning_points
+            .iter()
+            .map(|tp| tp.event_id.as_str())
+            .collect();
+        assert_eq!(ids, ["large", "marriage"]);
+    }
+}
@@ -0,0 +1,746 @@
+//! Plausibility checks over simulation inputs.
+//!
+//! Building a simulation rejects inputs that cannot be simulated at all,
+//! such as events for unknown entities. Validation instead reports inputs
+//! that can be simulated but are probably wrong, so callers can review
+//! them before trusting the results.
+//!
+//! # Saturation
+//!
+//! A dimension pinned at a bound silently swallows every event that pushes
+//! it further, so an authoring mistake like a loneliness base of 0.95 plus
+//! chronic deltas turns later exclusion events into no-ops. Validation
+//! flags three symptoms: anchors already at a bound, events whose deltas
+//! clamping would absorb entirely at the anchor, and dimensions that stay
+//! at a bound for too much of a sampled trajectory.
+//!
+//! Saturation means within `ValidationConfig::saturation_epsilon` of the
+//! ceiling, or of the floor for bipolar dimensions (mood). The floor of a
+//! unipolar dimension is treated as absence rather than saturation: a
+//! person with no acquired capability is not a modelling error. HEXACO
+//! factors are traits without deltas and are not checked.
+//!
+//! # Dangling relationships
+//!
+//! A relationship may name a partner who is not simulated on purpose, such
+//! as a deceased parent, once that partner is declared with
+//! `Simulation::declare_external_entity`. A partner that is neither
+//! simulated nor declared is usually a typo or a forgotten `add_entity`,
+//! and is reported.
+//!
+//! # Imputed inputs
+//!
+//! An entity built with `EntityBuilder::build_partial` records the key
+//! inputs it filled with population defaults, and each such entity is
+//! reported once. Defaults applied by `EntityBuilder::build` are taken as
+//! deliberate and are not reported.
+//!
+//! # Build errors
+//!
+//! Entities and events added one at a time with `add_entity` and
+//! `add_event` skip the checks `SimulationBuilder::build` makes. Entities
+//! anchored before their birth dates and events naming an unknown source,
+//! target, or witness are reported as the build error they would have
+//! raised. Unknown relationship partners are reported as dangling instead.
+
+use crate::entity::{Entity, ImputedField};
+use crate::enums::StatePath;
+use crate::event::IMPLAUSIBLE_APPRAISAL_GAP;
+use crate::simulation::recognition::interpret_in_history;
+use crate::simulation::simulation_builder::{anchor_error, event_errors};
+use crate::simulation::{AnchoredEntity, Simulation, SimulationBuildError};
+use crate::types::{Duration, EntityId, EventId, RelationshipId, Timestamp};
+use std::fmt;
+
+/// Tuning for the saturation checks in `Simulation::validate`.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::simulation::ValidationConfig;
+/// use behavioral_pathways::types::Duration;
+///
+/// let config = ValidationConfig {
+///     sample_step: Duration::days(7),
+///     ..Default::default()
+/// };
+/// assert!(!config.assert_on_query);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ValidationConfig {
+    /// Distance from a bound within which a value counts as saturated.
+    pub saturation_epsilon: f64,
+    /// Fraction of trajectory samples (0-1) a dimension may spend
+    /// saturated before it is flagged.
+    pub max_saturated_fraction: f64,
+    /// Step between trajectory samples.
+    pub sample_step: Duration,
+    /// When true, every `state_at()` query checks the entity's anchor and
+    /// events and panics in debug builds if either is saturated.
+    pub assert_on_query: bool,
+}
+
+impl Default for ValidationConfig {
+    fn default() -> Self {
+        ValidationConfig {
+            saturation_epsilon: 1e-3,
+            max_saturated_fraction: 0.5,
+            sample_step: Duration::days(30),
+            assert_on_query: false,
+        }
+    }
+}
+
+impl ValidationConfig {
+    /// Returns the bound `value` is saturated at on `path`, if any.
+    fn saturated_bound(&self, path: StatePath, value: f64) -> Option<f64> {
+        let (min, max) = path.bounds();
+        if value >= max - self.saturation_epsilon {
+            Some(max)
+        } else if min < 0.0 && value <= min + self.saturation_epsilon {
+            Some(min)
+        } else {
+            None
+        }
+    }
+}
+
+/// A suspicious input found by `Simulation::validate`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationIssue {
+    /// An event's subjective and objective severity are further apart
+    /// than `IMPLAUSIBLE_APPRAISAL_GAP`.
+    ImplausibleAppraisalGap {
+        /// The event with both severities given.
+        event_id: EventId,
+        /// The objective severity.
+        objective: f64,
+        /// The subjective severity.
+        subjective: f64,
+    },
+    /// An anchor's base plus delta already sits at a bound.
+    SaturatedAnchor {
+        /// The anchored entity.
+        entity_id: EntityId,
+        /// The saturated dimension.
+        path: StatePath,
+        /// The anchor's base plus delta, before clamping.
+        value: f64,
+        /// The bound the value sits at.
+        bound: f64,
+        /// The anchor timestamp.
+        anchored_at: Timestamp,
+    },
+    /// An event pushes a dimension toward a bound the anchor already sits
+    /// at, so clamping absorbs the whole delta.
+    AbsorbedEvent {
+        /// The entity the event targets.
+        entity_id: EntityId,
+        /// The absorbed event.
+        event_id: EventId,
+        /// The dimension the delta applies to.
+        path: StatePath,
+        /// The interpreted delta.
+        delta: f64,
+        /// The bound that absorbs it.
+        bound: f64,
+        /// When the event occurs.
+        timestamp: Timestamp,
+    },
+    /// A dimension stays at a bound for more than
+    /// `ValidationConfig::max_saturated_fraction` of the sampled trajectory.
+    PersistentSaturation {
+        /// The entity whose trajectory was sampled.
+        entity_id: EntityId,
+        /// The saturated dimension.
+        path: StatePath,
+        /// The bound of the first saturated sample.
+        bound: f64,
+        /// Fraction of samples that were saturated.
+        fraction: f64,
+        /// The first saturated sample.
+        first: Timestamp,
+        /// The last saturated sample.
+        last: Timestamp,
+    },
+    /// A relationship names an entity that is neither in the simulation
+    /// nor declared external.
+    DanglingRelationship {
+        /// The relationship's ID.
+        relationship_id: RelationshipId,
+        /// The missing entity.
+        entity_id: EntityId,
+    },
+    /// An entity's key inputs were missing and filled with population
+    /// defaults by `EntityBuilder::build_partial`.
+    ImputedInputs {
+        /// The partially specified entity.
+        entity_id: EntityId,
+        /// The imputed inputs.
+        fields: Vec<ImputedField>,
+        /// Confidence of the entity's computed states.
+        confidence: f64,
+    },
+    /// An input added after building that `SimulationBuilder::build`
+    /// would have rejected: an entity anchored before its birth date, or
+    /// an event naming an unknown entity.
+    BuildError(SimulationBuildError),
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationIssue::ImplausibleAppraisalGap {
+                event_id,
+                objective,
+                subjective,
+            } => write!(
+                f,
+                "Event '{}' has subjective severity {} but objective severity {} \
+                 (gap exceeds {})",
+                event_id.as_str(),
+                subjective,
+                objective,
+                IMPLAUSIBLE_APPRAISAL_GAP
+            ),
+            ValidationIssue::SaturatedAnchor {
+                entity_id,
+                path,
+                value,
+                bound,
+                anchored_at,
+            } => write!(
+                f,
+                "Entity '{}' is anchored at {} with {} = {} (saturated at {})",
+                entity_id.as_str(),
+                anchored_at,
+                path,
+                value,
+                bound
+            ),
+            ValidationIssue::AbsorbedEvent {
+                entity_id,
+                event_id,
+                path,
+                delta,
+                bound,
+                timestamp,
+            } => write!(
+                f,
+                "Event '{}' at {} changes {} of entity '{}' by {}, but the anchor is \
+                 already at {}",
+                event_id.as_str(),
+                timestamp,
+                path,
+                entity_id.as_str(),
+                delta,
+                bound
+            ),
+            ValidationIssue::PersistentSaturation {
+                entity_id,
+                path,
+                bound,
+                fraction,
+                first,
+                last,
+            } => write!(
+                f,
+                "Entity '{}' has {} saturated at {} for {:.0}% of samples ({} to {})",
+                entity_id.as_str(),
+                path,
+                bound,
+                fraction * 100.0,
+                first,
+                last
+            ),
+            ValidationIssue::DanglingRelationship {
+                relationship_id,
+                entity_id,
+            } => write!(
+                f,
+                "Relationship '{}' references entity '{}', which is neither simulated nor \
+                 declared external",
+                relationship_id.as_str(),
+                entity_id.as_str()
+            ),
+            ValidationIssue::ImputedInputs {
+                entity_id,
+                fields,
+                confidence,
+            } => {
+                let names: Vec<&str> = fields.iter().map(ImputedField::name).collect();
+                write!(
+                    f,
+                    "Entity '{}' has imputed {} (confidence {:.2})",
+                    entity_id.as_str(),
+                    names.join(", "),
+                    confidence
+                )
+            }
+            ValidationIssue::BuildError(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+/// Returns every issue found in the simulation's inputs.
+///
+/// Entities are checked in ID order.
+pub(crate) fn validate(simulation: &Simulation) -> Vec<ValidationIssue> {
+    let mut issues = build_error_issues(simulation);
+    issues.extend(appraisal_issues(simulation));
+    issues.extend(dangling_relationship_issues(simulation));
+    let mut entities: Vec<&AnchoredEntity> = simulation.entities().collect();
+    entities.sort_by(|a, b| a.entity().id().as_str().cmp(b.entity().id().as_str()));
+    for anchored in entities {
+        issues.extend(imputed_issue(anchored.entity()));
+        issues.extend(anchor_issues(simulation, anchored));
+        issues.extend(trajectory_issues(simulation, anchored));
+    }
+    issues
+}
+
+/// Returns the build errors among entities, in ID order, then among
+/// events, in the order they were added.
+fn build_error_issues(simulation: &Simulation) -> Vec<ValidationIssue> {
+    let mut entities: Vec<&AnchoredEntity> = simulation.entities().collect();
+    entities.sort_by(|a, b| a.entity().id().as_str().cmp(b.entity().id().as_str()));
+    let anchors = entities
+        .into_iter()
+        .filter_map(|anchored| anchor_error(anchored.entity(), anchored.anchor_timestamp()));
+    let events = simulation
+        .all_events()
+        .flat_map(|te| event_errors(te.event(), |id| simulation.is_known_entity(id)));
+    anchors
+        .chain(events)
+        .map(ValidationIssue::BuildError)
+        .collect()
+}
+
+fn appraisal_issues(simulation: &Simulation) -> Vec<ValidationIssue> {
+    simulation
+        .all_events()
+        .filter_map(|te| {
+            let event = te.event();
+            let objective = event.objective_severity()?;
+            let subjective = event.severity();
+            let implausible = !event.is_severity_appraised()
+                && (subjective - objective).abs() > IMPLAUSIBLE_APPRAISAL_GAP;
+            implausible.then(|| ValidationIssue::ImplausibleAppraisalGap {
+                event_id: event.id().clone(),
+                objective,
+                subjective,
+            })
+        })
+        .collect()
+}
+
+/// Returns an issue for each relationship side that is neither simulated
+/// nor declared external, in relationship ID order.
+fn dangling_relationship_issues(simulation: &Simulation) -> Vec<ValidationIssue> {
+    let mut entries: Vec<_> = simulation.relationship_entries().collect();
+    entries.sort_by(|a, b| a.0.as_str().cmp(b.0.as_str()));
+    entries
+        .into_iter()
+        .flat_map(|(id, relationship)| {
+            [relationship.entity_a(), relationship.entity_b()]
+                .into_iter()
+                .filter(|entity_id| {
+                    simulation.get_anchored_entity(entity_id).is_none()
+                        && !simulation.is_external_entity(entity_id)
+                })
+                .map(move |entity_id| ValidationIssue::DanglingRelationship {
+                    relationship_id: id.clone(),
+                    entity_id: entity_id.clone(),
+                })
+        })
+        .collect()
+}
+
+/// Returns an issue if any of the entity's key inputs were imputed.
+fn imputed_issue(entity: &Entity) -> Option<ValidationIssue> {
+    let completeness = entity.completeness();
+    (!completeness.is_complete()).then(|| ValidationIssue::ImputedInputs {
+        entity_id: entity.id().clone(),
+        fields: completeness.imputed().to_vec(),
+        confidence: completeness.confidence(),
+    })
+}
+
+/// Dimensions the saturation checks cover.
+fn checked_paths(entity: &Entity) -> impl Iterator<Item = StatePath> + '_ {
+    StatePath::all()
+        .into_iter()
+        .filter(|path| !matches!(path, StatePath::Hexaco(_)))
+        .filter(|path| entity.get_base(*path).is_some())
+}
+
+/// Returns saturated-anchor and absorbed-event issues for one entity.
+///
+/// These only look at the anchor, so they are cheap enough to run on
+/// every query.
+pub(crate) fn anchor_issues(
+    simulation: &Simulation,
+    anchored: &AnchoredEntity,
+) -> Vec<ValidationIssue> {
+    let config = simulation.validation_config();
+    let entity = anchored.entity();
+    let entity_id = entity.id();
+    let mut issues = Vec::new();
+
+    for path in checked_paths(entity) {
+        let value = entity.get_base(path).unwrap_or(0.0) + entity.get_delta(path).unwrap_or(0.0);
+        if let Some(bound) = config.saturated_bound(path, value) {
+            issues.push(ValidationIssue::SaturatedAnchor {
+                entity_id: entity_id.clone(),
+                path,
+                value,
+                bound,
+                anchored_at: anchored.anchor_timestamp(),
+            });
+        }
+    }
+
+    let mut events = simulation.events_for(entity_id);
+    events.sort_by_key(|te| te.timestamp());
+    for te in events {
+        let interpreted = interpret_in_history(simulation, te, entity, None);
+        for (path, delta) in interpreted.state_deltas {
+            if delta.abs() <= config.saturation_epsilon {
+                continue;
+            }
+            let Some(value) = entity.get_effective(path) else {
+                continue;
+            };
+            let absorbed = config
+                .saturated_bound(path, value)
+                .filter(|bound| (*bound > 0.0) == (delta > 0.0));
+            if let Some(bound) = absorbed {
+                issues.push(ValidationIssue::AbsorbedEvent {
+                    entity_id: entity_id.clone(),
+                    event_id: te.event().id().clone(),
+                    path,
+                    delta,
+                    bound,
+                    timestamp: te.timestamp(),
+                });
+            }
+        }
+    }
+    issues
+}
+
+/// Returns persistent-saturation issues from sampling one entity's
+/// trajectory across the span of its anchor and events.
+fn trajectory_issues(simulation: &Simulation, anchored: &AnchoredEntity) -> Vec<ValidationIssue> {
+    let config = simulation.validation_config();
+    let entity_id = anchored.entity().id();
+    let anchor = anchored.anchor_timestamp();
+    let events = simulation.events_for(entity_id);
+    let start = events.iter().map(|te| te.timestamp()).fold(anchor, Timestamp::min);
+    let end = events.iter().map(|te| te.timestamp()).fold(anchor, Timestamp::max);
+
+    let Some(handle) = simulation.entity(entity_id) else {
+        return Vec::new();
+    };
+    let samples = handle.state_series(start, end, config.sample_step);
+    if samples.len() < 2 {
+        return Vec::new();
+    }
+
+    checked_paths(anchored.entity())
+        .filter_map(|path| {
+            let saturated: Vec<(Timestamp, f64)> = samples
+                .iter()
+                .filter_map(|(ts, state)| {
+                    let bound = config.saturated_bound(path, state.get_effective(path))?;
+                    Some((*ts, bound))
+                })
+                .collect();
+            let fraction = saturated.len() as f64 / samples.len() as f64;
+            if fraction <= config.max_saturated_fraction {
+                return None;
+            }
+            Some(ValidationIssue::PersistentSaturation {
+                entity_id: entity_id.clone(),
+                path,
+                bound: saturated[0].1,
+                fraction,
+                first: saturated[0].0,
+                last: saturated[saturated.len() - 1].0,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::EntityBuilder;
+    use crate::enums::{EventType, RelationshipSchema, SocialCognitionPath, Species};
+    use crate::event::EventBuilder;
+
+    fn reference() -> Timestamp {
+        Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+    }
+
+    fn sim_with(builders: Vec<EventBuilder>) -> Simulation {
+        let mut sim = Simulation::new(reference());
+        for builder in builders {
+            sim.add_event(builder.build().unwrap(), reference());
+        }
+        sim
+    }
+
+    #[test]
+    fn flags_only_explicit_implausible_gaps() {
+        let flagged = EventId::new("flagged").unwrap();
+        let sim = sim_with(vec![
+            EventBuilder::new(EventType::Loss).severity(0.9),
+            EventBuilder::new(EventType::Loss).objective_severity(1.0),
+            EventBuilder::new(EventType::Loss)
+                .objective_severity(0.5)
+                .severity(0.8),
+            EventBuilder::new(EventType::Loss)
+                .id(flagged.clone())
+                .objective_severity(0.1)
+                .severity(0.9),
+        ]);
+
+        let issues = sim.validate();
+        assert_eq!(issues.len(), 1);
+        let ValidationIssue::ImplausibleAppraisalGap {
+            event_id,
+            objective,
+            subjective,
+        } = &issues[0]
+        else {
+            panic!("expected an appraisal gap, got {:?}", issues[0]);
+        };
+        assert_eq!(event_id, &flagged);
+        assert!((objective - 0.1).abs() < f64::EPSILON);
+        assert!((subjective - 0.9).abs() < f64::EPSILON);
+        assert!(issues[0].to_string().contains("flagged"));
+    }
+
+    #[test]
+    fn empty_simulation_is_valid() {
+        assert!(sim_with(Vec::new()).validate().is_empty());
+    }
+
+    #[test]
+    fn reports_imputed_entities_only() {
+        let mut sim = Simulation::new(reference());
+        let (partial, _) = EntityBuilder::new().id("partial").build_partial();
+        sim.add_entity(partial, reference());
+        let deliberate = EntityBuilder::new()
+            .id("deliberate")
+            .species(Species::Human)
+            .build()
+            .unwrap();
+        sim.add_entity(deliberate, reference());
+
+        let issues = sim.validate();
+        assert_eq!(issues.len(), 1);
+        let ValidationIssue::ImputedInputs {
+            entity_id, fields, ..
+        } = &issues[0]
+        else {
+            panic!("expected imputed inputs, got {:?}", issues[0]);
+        };
+        assert_eq!(entity_id.as_str(), "partial");
+        assert!(fields.contains(&ImputedField::Species));
+        assert!(issues[0].to_string().contains("Personality"));
+    }
+
+    #[test]
+    fn distinguishes_external_partners_from_dangling_ones() {
+        let mut sim = Simulation::new(reference());
+        let entity = EntityBuilder::new()
+            .id("maya")
+            .species(Species::Human)
+            .build()
+            .unwrap();
+        let maya = sim.add_entity(entity, reference());
+        let mother = EntityId::new("late_mother").unwrap();
+        let typo = EntityId::new("jo_typo").unwrap();
+        sim.declare_external_entity(mother.clone());
+        let peer = RelationshipSchema::Peer;
+        sim.add_relationship(maya.clone(), mother, peer, reference());
+        let dangling = sim.add_relationship(maya, typo.clone(), peer, reference());
+
+        let issues = sim.validate();
+        assert_eq!(
+            issues,
+            vec![ValidationIssue::DanglingRelationship {
+                relationship_id: dangling,
+                entity_id: typo,
+            }]
+        );
+        assert!(issues[0].to_string().contains("jo_typo"));
+    }
+
+    #[test]
+    fn reports_build_errors_from_incremental_additions() {
+        let mut sim = Simulation::new(reference());
+        let birth = Timestamp::from_ymd_hms(2030, 1, 1, 0, 0, 0);
+        let entity = EntityBuilder::new()
+            .id("future")
+            .species(Species::Human)
+            .birth_date(birth)
+            .build()
+            .unwrap();
+        let future = sim.add_entity(entity, reference());
+        let stranger = EntityId::new("stranger").unwrap();
+        let event = EventBuilder::new(EventType::Conflict)
+            .source(stranger.clone())
+            .target(future.clone())
+            .build()
+            .unwrap();
+        let event_id = event.id().clone();
+        sim.add_event(event, reference());
+
+        let issues = sim.validate();
+        assert_eq!(
+            issues,
+            vec![
+                ValidationIssue::BuildError(SimulationBuildError::AnchorBeforeBirth(
+                    future,
+                    birth,
+                    reference()
+                )),
+                ValidationIssue::BuildError(SimulationBuildError::EventReferencesUnknownEntity(
+                    event_id, stranger
+                )),
+            ]
+        );
+        assert!(issues[1].to_string().contains("stranger"));
+    }
+
+    const LONELINESS: StatePath = StatePath::SocialCognition(SocialCognitionPath::Loneliness);
+
+    /// A person anchored with loneliness pinned at the ceiling, excluded
+    /// monthly for a year.
+    fn pinned_loneliness() -> (Simulation, EntityId) {
+        let mut entity = EntityBuilder::new()
+            .id("pinned")
+            .species(Species::Human)
+            .build()
+            .unwrap();
+        let loneliness = entity
+            .individual_state_mut()
+            .social_cognition_mut()
+            .loneliness_mut();
+        loneliness.set_base(0.95);
+        loneliness.add_chronic_delta(0.1);
+
+        let mut sim = Simulation::new(reference());
+        let id = sim.add_entity(entity, reference());
+        for month in 1..=12 {
+            let event = EventBuilder::new(EventType::SocialExclusion)
+                .id(EventId::new(format!("exclusion_{}", month)).unwrap())
+                .target(id.clone())
+                .severity(0.6)
+                .build()
+                .unwrap();
+            sim.add_event(event, reference() + Duration::days(30 * month));
+        }
+        (sim, id)
+    }
+
+    #[test]
+    fn flags_saturated_anchor_absorbed_events_and_trajectory() {
+        let (sim, id) = pinned_loneliness();
+        let issues = sim.validate();
+
+        assert!(issues.contains(&ValidationIssue::SaturatedAnchor {
+            entity_id: id.clone(),
+            path: LONELINESS,
+            value: f64::from(0.95_f32) + f64::from(0.1_f32),
+            bound: 1.0,
+            anchored_at: reference(),
+        }));
+
+        let absorbed: Vec<&ValidationIssue> = issues
+            .iter()
+            .filter(|issue| {
+                matches!(issue, ValidationIssue::AbsorbedEvent { path, .. } if *path == LONELINESS)
+            })
+            .collect();
+        assert_eq!(absorbed.len(), 12);
+        let message = absorbed[0].to_string();
+        assert!(message.contains("exclusion_1"));
+        assert!(message.contains("pinned"));
+        assert!(message.contains(&LONELINESS.to_string()));
+
+        let persistent = issues
+            .iter()
+            .find_map(|issue| match issue {
+                ValidationIssue::PersistentSaturation {
+                    path,
+                    fraction,
+                    first,
+                    last,
+                    ..
+                } if *path == LONELINESS => Some((*fraction, *first, *last)),
+                _ => None,
+            })
+            .unwrap();
+        assert!((persistent.0 - 1.0).abs() < f64::EPSILON);
+        assert_eq!(persistent.1, reference());
+        assert_eq!(persistent.2, reference() + Duration::days(360));
+    }
+
+    #[test]
+    fn saturated_fraction_threshold_is_configurable() {
+        let (mut sim, _) = pinned_loneliness();
+        sim.set_validation_config(ValidationConfig {
+            max_saturated_fraction: 1.0,
+            ..Default::default()
+        });
+
+        let issues = sim.validate();
+        assert!(!issues.is_empty());
+        assert!(!issues
+            .iter()
+            .any(|issue| matches!(issue, ValidationIssue::PersistentSaturation { .. })));
+    }
+
+    #[test]
+    fn ordinary_scenario_is_not_saturated() {
+        let mut sim = Simulation::new(reference());
+        let entity = EntityBuilder::new()
+            .id("ordinary")
+            .species(Species::Human)
+            .build()
+            .unwrap();
+        let id = sim.add_entity(entity, reference());
+        let events = [
+            EventType::SocialExclusion,
+            EventType::Achievement,
+            EventType::Support,
+            EventType::Loss,
+        ];
+        for (index, event_type) in events.into_iter().enumerate() {
+            let event = EventBuilder::new(event_type)
+                .target(id.clone())
+                .build()
+                .unwrap();
+            sim.add_event(event, reference() + Duration::days(20 * (index as u64 + 1)));
+        }
+
+        assert!(sim.validate().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "saturated inputs for entity 'pinned'")]
+    fn assert_on_query_panics_for_saturated_anchor() {
+        let (mut sim, id) = pinned_loneliness();
+        sim.set_validation_config(ValidationConfig {
+            assert_on_query: true,
+            ..Default::default()
+        });
+
+        let _ = sim.entity(&id).unwrap().state_at(reference());
+    }
+}
@@ -0,0 +1,112 @@
+//! Per-dimension decay half-life overrides.
+//!
+//! Every decaying state dimension has a default half-life (6 hours for
+//! mood, 1 day for loneliness, 2 weeks for depression, and so on). A
+//! `DecayProfile` replaces some of them for one entity, for modeling
+//! animals or running sensitivity analyses.
+
+use crate::enums::StatePath;
+use crate::types::Duration;
+
+/// Half-life overrides for decaying state dimensions.
+///
+/// Dimensions without an override keep their default half-life. Paths
+/// that never decay (HEXACO traits, Acquired Capability) and computed
+/// paths (such as Thwarted Belongingness) are ignored.
+///
+/// Forward decay and backward regression both read the profile from the
+/// state's `EntityModelConfig`, so advancing and then regressing by the
+/// same duration round-trips with the overridden half-lives.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::enums::{MoodPath, StatePath};
+/// use behavioral_pathways::state::DecayProfile;
+/// use behavioral_pathways::types::Duration;
+///
+/// let profile = DecayProfile::new()
+///     .with_half_life(StatePath::Mood(MoodPath::Valence), Duration::hours(2));
+///
+/// assert_eq!(
+///     profile.half_life(StatePath::Mood(MoodPath::Valence)),
+///     Some(Duration::hours(2))
+/// );
+/// assert_eq!(profile.half_life(StatePath::Mood(MoodPath::Arousal)), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DecayProfile {
+    /// Overrides in the order they were first set.
+    half_lives: Vec<(StatePath, Duration)>,
+}
+
+impl DecayProfile {
+    /// Creates a profile with no overrides.
+    #[must_use]
+    pub fn new() -> Self {
+        DecayProfile::default()
+    }
+
+    /// Overrides the half-life of `path`, replacing any earlier override.
+    #[must_use]
+    pub fn with_half_life(mut self, path: StatePath, half_life: Duration) -> Self {
+        self.set_half_life(path, half_life);
+        self
+    }
+
+    /// Overrides the half-life of `path`, replacing any earlier override.
+    pub fn set_half_life(&mut self, path: StatePath, half_life: Duration) {
+        match self.half_lives.iter_mut().find(|(p, _)| *p == path) {
+            Some(entry) => entry.1 = half_life,
+            None => self.half_lives.push((path, half_life)),
+        }
+    }
+
+    /// Returns the overridden half-life of `path`, if any.
+    #[must_use]
+    pub fn half_life(&self, path: StatePath) -> Option<Duration> {
+        self.half_lives
+            .iter()
+            .find(|(p, _)| *p == path)
+            .map(|(_, half_life)| *half_life)
+    }
+
+    /// Returns the overrides in the order they were first set.
+    pub fn iter(&self) -> impl Iterator<Item = (StatePath, Duration)> + '_ {
+        self.half_lives.iter().copied()
+    }
+
+    /// Returns true if the profile overrides nothing.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.half_lives.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enums::{MentalHealthPath, SocialCognitionPath};
+
+    #[test]
+    fn later_override_replaces_earlier() {
+        let path = StatePath::SocialCognition(SocialCognitionPath::Loneliness);
+        let profile = DecayProfile::new()
+            .with_half_life(path, Duration::days(1))
+            .with_half_life(path, Duration::days(3));
+
+        assert_eq!(profile.half_life(path), Some(Duration::days(3)));
+        assert_eq!(profile.iter().count(), 1);
+    }
+
+    #[test]
+    fn empty_profile_overrides_nothing() {
+        let profile = DecayProfile::new();
+        assert!(profile.is_empty());
+        assert_eq!(
+            profile.half_life(StatePath::MentalHealth(MentalHealthPath::Depression)),
+            None
+        );
+    }
+}
@@ -3,10 +3,9 @@
 //! These are observable social signals (gender, ethnicity, appearance)
 //! that shape how others respond to the person in immediate interactions.
 
-use serde::{Deserialize, Serialize};
-
 /// Demand characteristics for observable social signals.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DemandCharacteristics {
     /// Gender identity or presentation.
     pub gender: String,
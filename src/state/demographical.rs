@@ -4,10 +4,10 @@
 //! used for ecological bias and discrimination effects.
 
 use crate::types::{Duration, Timestamp};
-use serde::{Deserialize, Serialize};
 
 /// Demographical information for an individual.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Demographical {
     /// Display name or preferred name.
     pub name: String,
@@ -4,9 +4,9 @@
 //! an entity responds to events and interacts with others. They have
 //! very slow decay rates (monthly) as they represent semi-stable traits.
 
+use crate::enums::PersonalityProfile;
 use crate::state::StateValue;
 use crate::types::Duration;
-use serde::{Deserialize, Serialize};
 
 /// Behavioral disposition state.
 ///
@@ -24,7 +24,8 @@ use serde::{Deserialize, Serialize};
 /// // High trust propensity means more willing to trust others
 /// assert!(disposition.trust_propensity_effective() > 0.4);
 /// ```
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Disposition {
     /// Self-regulation capacity.
     /// Range: 0 (impulsive) to 1 (highly regulated)
@@ -118,6 +119,28 @@ impl Disposition {
         }
     }
 
+    /// Creates a Disposition with the empathy, aggression, and trust
+    /// propensity baselines of a personality profile.
+    ///
+    /// Other dispositions keep their defaults.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::enums::PersonalityProfile;
+    /// use behavioral_pathways::state::Disposition;
+    ///
+    /// let disposition = Disposition::from_profile(PersonalityProfile::Agreeable);
+    /// assert!(disposition.trust_propensity_effective() > Disposition::new().trust_propensity_effective());
+    /// ```
+    #[must_use]
+    pub fn from_profile(profile: PersonalityProfile) -> Self {
+        Disposition::new()
+            .with_empathy_base(profile.empathy())
+            .with_aggression_base(profile.aggression())
+            .with_trust_propensity_base(profile.trust_propensity())
+    }
+
     // Builder methods
 
     /// Sets the base impulse control.
@@ -304,12 +327,21 @@ impl Disposition {
 
     /// Applies decay to all disposition dimensions over the specified duration.
     pub fn apply_decay(&mut self, elapsed: Duration) {
-        self.impulse_control.apply_decay(elapsed);
-        self.empathy.apply_decay(elapsed);
-        self.aggression.apply_decay(elapsed);
-        self.grievance.apply_decay(elapsed);
-        self.reactance.apply_decay(elapsed);
-        self.trust_propensity.apply_decay(elapsed);
+        for value in self.decaying_values_mut() {
+            value.apply_decay(elapsed);
+        }
+    }
+
+    /// Returns every dimension that decays, for decay and its reversal.
+    pub(crate) fn decaying_values_mut(&mut self) -> [&mut StateValue; 6] {
+        [
+            &mut self.impulse_control,
+            &mut self.empathy,
+            &mut self.aggression,
+            &mut self.grievance,
+            &mut self.reactance,
+            &mut self.trust_propensity,
+        ]
     }
 
     /// Resets all deltas to zero.
@@ -436,6 +468,18 @@ mod tests {
         assert!(disposition.empathy_effective() > 0.5);
     }
 
+    #[test]
+    fn from_profile_sets_profile_baselines() {
+        let disposition = Disposition::from_profile(PersonalityProfile::Rebel);
+        assert!((disposition.aggression_effective() - 0.4).abs() < f32::EPSILON);
+        assert!((disposition.trust_propensity_effective() - 0.3).abs() < f32::EPSILON);
+        assert!((disposition.empathy_effective() - 0.5).abs() < f32::EPSILON);
+        assert_eq!(
+            Disposition::from_profile(PersonalityProfile::Balanced),
+            Disposition::new()
+        );
+    }
+
     #[test]
     fn clone_and_equality() {
         let d1 = Disposition::new().with_aggression_base(0.5);
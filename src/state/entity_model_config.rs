@@ -4,8 +4,8 @@
 //! and other entity-type-specific configuration. Different entity types
 //! (Human, Animal) have different subsystem requirements.
 
-use crate::enums::{Species, SubsystemId};
-use serde::{Deserialize, Serialize};
+use crate::enums::{human_equivalent_age, LifeStage, Species, SubsystemId};
+use crate::state::{DecayProfile, LifeStageTable};
 use std::collections::HashSet;
 
 /// Configuration for an entity model.
@@ -28,9 +28,11 @@ use std::collections::HashSet;
 ///     .with_subsystem(SubsystemId::State)
 ///     .with_subsystem(SubsystemId::Memory);
 /// ```
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EntityModelConfig {
     /// Active subsystems for this entity.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::types::sorted_serde::set"))]
     active_subsystems: HashSet<SubsystemId>,
 
     /// Whether personality modeling is enabled.
@@ -52,6 +54,14 @@ pub struct EntityModelConfig {
     /// Effects are blocked when complexity is below this threshold.
     /// Default: 0.3
     proximal_process_complexity_threshold: f64,
+
+    /// Half-life overrides for decaying dimensions.
+    /// Default: None (every dimension keeps its default half-life)
+    decay_profile: Option<DecayProfile>,
+
+    /// Life stage boundary overrides.
+    /// Default: None (stages scale the human ones by species)
+    life_stage_table: Option<LifeStageTable>,
 }
 
 /// Default proximal process frequency threshold.
@@ -83,6 +93,8 @@ impl EntityModelConfig {
             time_scale: 1.0,
             proximal_process_frequency_threshold: DEFAULT_PROXIMAL_FREQUENCY_THRESHOLD,
             proximal_process_complexity_threshold: DEFAULT_PROXIMAL_COMPLEXITY_THRESHOLD,
+            decay_profile: None,
+            life_stage_table: None,
         }
     }
 
@@ -115,6 +127,8 @@ impl EntityModelConfig {
             time_scale: 1.0,
             proximal_process_frequency_threshold: DEFAULT_PROXIMAL_FREQUENCY_THRESHOLD,
             proximal_process_complexity_threshold: DEFAULT_PROXIMAL_COMPLEXITY_THRESHOLD,
+            decay_profile: None,
+            life_stage_table: None,
         }
     }
 
@@ -150,6 +164,7 @@ impl EntityModelConfig {
             | Species::Chimpanzee
             | Species::Crow
             | Species::Mouse
+            | Species::Robotic
             | Species::Custom { .. } => EntityModelConfig::animal_simple(),
         }
     }
@@ -186,6 +201,8 @@ impl EntityModelConfig {
             time_scale: 1.0,
             proximal_process_frequency_threshold: DEFAULT_PROXIMAL_FREQUENCY_THRESHOLD,
             proximal_process_complexity_threshold: DEFAULT_PROXIMAL_COMPLEXITY_THRESHOLD,
+            decay_profile: None,
+            life_stage_table: None,
         }
     }
 
@@ -206,6 +223,8 @@ impl EntityModelConfig {
             time_scale: 1.0,
             proximal_process_frequency_threshold: DEFAULT_PROXIMAL_FREQUENCY_THRESHOLD,
             proximal_process_complexity_threshold: DEFAULT_PROXIMAL_COMPLEXITY_THRESHOLD,
+            decay_profile: None,
+            life_stage_table: None,
         }
     }
 
@@ -260,6 +279,20 @@ impl EntityModelConfig {
         self
     }
 
+    /// Sets half-life overrides for decaying dimensions.
+    #[must_use]
+    pub fn with_decay_profile(mut self, profile: DecayProfile) -> Self {
+        self.decay_profile = Some(profile);
+        self
+    }
+
+    /// Sets life stage boundary overrides.
+    #[must_use]
+    pub fn with_life_stage_table(mut self, table: LifeStageTable) -> Self {
+        self.life_stage_table = Some(table);
+        self
+    }
+
     // Accessors
 
     /// Checks if a subsystem is active.
@@ -319,6 +352,59 @@ impl EntityModelConfig {
         self.proximal_process_complexity_threshold
     }
 
+    /// Returns the half-life overrides for decaying dimensions, if any.
+    #[must_use]
+    pub fn decay_profile(&self) -> Option<&DecayProfile> {
+        self.decay_profile.as_ref()
+    }
+
+    /// Returns the life stage boundary overrides, if any.
+    #[must_use]
+    pub fn life_stage_table(&self) -> Option<&LifeStageTable> {
+        self.life_stage_table.as_ref()
+    }
+
+    /// Returns the life stage of a `species` entity at `age_years`.
+    ///
+    /// Uses the life stage table if one is set, and
+    /// `LifeStage::from_age_years_for_species` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::enums::{LifeStage, Species};
+    /// use behavioral_pathways::state::{EntityModelConfig, LifeStageTable};
+    ///
+    /// let table = LifeStageTable::for_species(&Species::Dog)
+    ///     .with_stage_entry(LifeStage::YoungAdult, 3.0);
+    /// let config = EntityModelConfig::animal_simple().with_life_stage_table(table);
+    ///
+    /// assert_eq!(config.life_stage_at(&Species::Dog, 2.0), LifeStage::Adolescent);
+    /// assert_eq!(
+    ///     EntityModelConfig::animal_simple().life_stage_at(&Species::Dog, 2.0),
+    ///     LifeStage::YoungAdult
+    /// );
+    /// ```
+    #[must_use]
+    pub fn life_stage_at(&self, species: &Species, age_years: f64) -> LifeStage {
+        match &self.life_stage_table {
+            Some(table) => table.stage_at(age_years),
+            None => LifeStage::from_age_years_for_species(species, age_years),
+        }
+    }
+
+    /// Converts the age of a `species` entity to human-equivalent years.
+    ///
+    /// Formative plasticity and sensitive periods are defined on human
+    /// ages and read this. Uses the life stage table if one is set.
+    #[must_use]
+    pub fn human_equivalent_age(&self, species: &Species, age_years: f64) -> f64 {
+        match &self.life_stage_table {
+            Some(table) => table.human_equivalent_age(age_years),
+            None => human_equivalent_age(species, age_years),
+        }
+    }
+
     /// Checks whether proximal process criteria are met.
     ///
     /// Returns true if both frequency and complexity meet or exceed thresholds.
@@ -364,6 +450,16 @@ impl EntityModelConfig {
     pub fn set_proximal_complexity_threshold(&mut self, threshold: f64) {
         self.proximal_process_complexity_threshold = threshold.clamp(0.0, 1.0);
     }
+
+    /// Sets or clears the half-life overrides for decaying dimensions.
+    pub fn set_decay_profile(&mut self, profile: Option<DecayProfile>) {
+        self.decay_profile = profile;
+    }
+
+    /// Sets or clears the life stage boundary overrides.
+    pub fn set_life_stage_table(&mut self, table: Option<LifeStageTable>) {
+        self.life_stage_table = table;
+    }
 }
 
 impl Default for EntityModelConfig {
@@ -19,10 +19,16 @@
 //! - **Sensitive Periods**: Trait-specific windows of heightened plasticity
 //! - **Diminishing Returns**: Repeated shifts approach asymptotic limits
 //! - **Partial Recovery**: Severe shifts partially settle over time
+//!
+//! # Auditing
+//!
+//! [`audit_formative_modifiers`] records each modifier applied to a raw
+//! shift request as a [`FormativeShiftAudit`]. Records collected by a state
+//! query carry their audit, so `ComputedState::base_shift_history()` shows
+//! why a large requested shift moved a trait only a little.
 
 use crate::enums::{HexacoPath, Species};
 use crate::types::Duration;
-use serde::{Deserialize, Serialize};
 
 /// Maximum magnitude for a single formative event's base shift.
 pub const MAX_SINGLE_EVENT_SHIFT: f32 = 0.30;
@@ -52,7 +58,8 @@ pub const CUMULATIVE_CAP: f32 = 1.0;
 ///
 /// Shifts exceeding [`SEVERE_SHIFT_THRESHOLD`] have an immediate impact
 /// that gradually settles to a lower permanent value over [`SETTLING_DAYS`].
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BaseShiftRecord {
     /// When the shift occurred.
     timestamp: Duration,
@@ -68,6 +75,10 @@ pub struct BaseShiftRecord {
 
     /// Days to settle from immediate to settled (0 if no settling).
     settling_days: u32,
+
+    /// How the modifiers produced the immediate shift, when recorded.
+    #[cfg_attr(feature = "serde", serde(default))]
+    audit: Option<FormativeShiftAudit>,
 }
 
 impl BaseShiftRecord {
@@ -97,7 +108,40 @@ impl BaseShiftRecord {
             immediate: shift_amount,
             settled,
             settling_days,
+            audit: None,
+        }
+    }
+
+    /// Creates a base shift record of an audited shift.
+    ///
+    /// The immediate shift is the audit's applied amount; settling behaves
+    /// as in [`BaseShiftRecord::new`].
+    #[must_use]
+    pub fn from_audit(
+        timestamp: Duration,
+        trait_path: HexacoPath,
+        audit: FormativeShiftAudit,
+    ) -> Self {
+        BaseShiftRecord {
+            audit: Some(audit),
+            ..BaseShiftRecord::new(timestamp, trait_path, audit.applied)
+        }
+    }
+
+    /// Returns the record with its settling period shortened to the same
+    /// psychological time for `species`.
+    ///
+    /// The settling period is divided by `Species::time_scale()`, rounded
+    /// up to whole days, so a severe shift settles over 180 days for a
+    /// human but 5 days for a mouse. Records without settling are
+    /// unchanged.
+    #[must_use]
+    pub fn scaled_for_species(mut self, species: &Species) -> Self {
+        if self.settling_days > 0 {
+            let days = f64::from(self.settling_days) / f64::from(species.time_scale());
+            self.settling_days = (days.ceil() as u32).max(1);
         }
+        self
     }
 
     /// Returns the timestamp when this shift occurred.
@@ -130,6 +174,33 @@ impl BaseShiftRecord {
         self.settling_days
     }
 
+    /// Returns how the modifiers produced the immediate shift, if recorded.
+    #[must_use]
+    pub fn audit(&self) -> Option<&FormativeShiftAudit> {
+        self.audit.as_ref()
+    }
+
+    /// Returns the shift the event requested before any modifier.
+    ///
+    /// Without an audit, this is the immediate shift.
+    #[must_use]
+    pub fn raw_amount(&self) -> f32 {
+        self.audit.map_or(self.immediate, |audit| audit.raw)
+    }
+
+    /// Returns the human-equivalent age in years the modifiers used, if
+    /// recorded.
+    #[must_use]
+    pub fn age_at_event(&self) -> Option<u16> {
+        self.audit.map(|audit| audit.age_years)
+    }
+
+    /// Returns the cap that clipped the shift, if recorded and clipped.
+    #[must_use]
+    pub fn clipped_by(&self) -> Option<ShiftCap> {
+        self.audit.and_then(|audit| audit.clipped_by())
+    }
+
     /// Returns true if this is a severe shift with settling behavior.
     #[must_use]
     pub fn is_severe(&self) -> bool {
@@ -205,6 +276,9 @@ pub fn trait_modifier(trait_path: HexacoPath) -> f32 {
 
 /// Returns the age plasticity modifier for a given age.
 ///
+/// Ages are human-equivalent years; see
+/// `EntityModelConfig::human_equivalent_age` for other species.
+///
 /// | Age Range | Modifier |
 /// |-----------|----------|
 /// | < 18 | 1.3 |
@@ -280,13 +354,89 @@ pub fn saturation_factor(existing_cumulative: f32) -> f32 {
     1.0 / (1.0 + existing_cumulative / SATURATION_CONSTANT)
 }
 
+/// A cap that clipped a formative shift.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ShiftCap {
+    /// The shift exceeded [`MAX_SINGLE_EVENT_SHIFT`].
+    SingleEvent,
+    /// The shift would have taken the trait's cumulative shift in its
+    /// direction past [`CUMULATIVE_CAP`].
+    Cumulative,
+}
+
+/// Each modifier applied to a raw formative shift request.
+///
+/// The shift before caps is the product of `raw` and the four
+/// multiplicative modifiers. It is then clamped to
+/// [`MAX_SINGLE_EVENT_SHIFT`] and limited by [`CUMULATIVE_CAP`] to give
+/// `applied`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FormativeShiftAudit {
+    /// The requested shift.
+    pub raw: f32,
+    /// Human-equivalent age in years at the event.
+    pub age_years: u16,
+    /// Existing cumulative shift in the same direction before this one.
+    pub existing_cumulative: f32,
+    /// Species base plasticity.
+    pub species_plasticity: f32,
+    /// Age and sensitive period plasticity.
+    pub plasticity: f32,
+    /// Trait modifier (inverse of stability).
+    pub trait_modifier: f32,
+    /// Saturation factor from the existing cumulative shift.
+    pub saturation: f32,
+    /// The shift after every modifier and cap.
+    pub applied: f32,
+}
+
+impl FormativeShiftAudit {
+    /// Returns the shift after the multiplicative modifiers, before caps.
+    #[must_use]
+    pub fn modified(&self) -> f32 {
+        self.raw * self.species_plasticity * self.plasticity * self.trait_modifier * self.saturation
+    }
+
+    /// Returns the cap that limited the applied shift, if any.
+    ///
+    /// The cumulative cap is reported when both apply, since it is
+    /// enforced last.
+    #[must_use]
+    pub fn clipped_by(&self) -> Option<ShiftCap> {
+        let capped = self
+            .modified()
+            .clamp(-MAX_SINGLE_EVENT_SHIFT, MAX_SINGLE_EVENT_SHIFT);
+        if self.applied.abs() < capped.abs() - f32::EPSILON {
+            Some(ShiftCap::Cumulative)
+        } else if self.modified().abs() > MAX_SINGLE_EVENT_SHIFT {
+            Some(ShiftCap::SingleEvent)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the fraction of the raw shift that was applied.
+    ///
+    /// Returns 0.0 for a zero request.
+    #[must_use]
+    pub fn retained_fraction(&self) -> f32 {
+        if self.raw == 0.0 {
+            0.0
+        } else {
+            self.applied / self.raw
+        }
+    }
+}
+
 /// Applies all formative modifiers to a raw shift request.
 ///
 /// # Arguments
 ///
 /// * `shift_request` - The raw shift amount requested
 /// * `trait_path` - Which trait is being shifted
-/// * `age_years` - Entity's age at the time of the shift
+/// * `age_years` - Entity's human-equivalent age at the time of the shift
 /// * `existing_cumulative` - Sum of existing shifts in the same direction
 /// * `species` - Entity's species (affects base plasticity)
 ///
@@ -301,26 +451,51 @@ pub fn apply_formative_modifiers(
     existing_cumulative: f32,
     species: &Species,
 ) -> f32 {
-    // 1. Base plasticity from species
-    let species_plasticity = species_plasticity_modifier(species);
-
-    // 2. Combined age/sensitive period plasticity
-    let plasticity = combined_plasticity(trait_path, age_years);
-
-    // 3. Trait modifier (inverse of stability)
-    let trait_mod = trait_modifier(trait_path);
-
-    // 4. Saturation from existing shifts
-    let saturation = saturation_factor(existing_cumulative);
-
-    // 5. Apply all modifiers
-    let modified = shift_request * species_plasticity * plasticity * trait_mod * saturation;
-
-    // 6. Single-event cap
-    let capped = modified.clamp(-MAX_SINGLE_EVENT_SHIFT, MAX_SINGLE_EVENT_SHIFT);
+    audit_formative_modifiers(
+        shift_request,
+        trait_path,
+        age_years,
+        existing_cumulative,
+        species,
+    )
+    .applied
+}
 
-    // 7. Cumulative cap enforcement
-    enforce_cumulative_cap(capped, existing_cumulative)
+/// Applies all formative modifiers to a raw shift request, recording each.
+///
+/// Takes the same arguments as [`apply_formative_modifiers`], whose result
+/// is the audit's `applied` amount.
+#[must_use]
+pub fn audit_formative_modifiers(
+    shift_request: f32,
+    trait_path: HexacoPath,
+    age_years: u16,
+    existing_cumulative: f32,
+    species: &Species,
+) -> FormativeShiftAudit {
+    let mut audit = FormativeShiftAudit {
+        raw: shift_request,
+        age_years,
+        existing_cumulative,
+        // 1. Base plasticity from species
+        species_plasticity: species_plasticity_modifier(species),
+        // 2. Combined age/sensitive period plasticity
+        plasticity: combined_plasticity(trait_path, age_years),
+        // 3. Trait modifier (inverse of stability)
+        trait_modifier: trait_modifier(trait_path),
+        // 4. Saturation from existing shifts
+        saturation: saturation_factor(existing_cumulative),
+        applied: 0.0,
+    };
+
+    // 5. Single-event cap
+    let capped = audit
+        .modified()
+        .clamp(-MAX_SINGLE_EVENT_SHIFT, MAX_SINGLE_EVENT_SHIFT);
+
+    // 6. Cumulative cap enforcement
+    audit.applied = enforce_cumulative_cap(capped, existing_cumulative);
+    audit
 }
 
 /// Returns the base plasticity modifier for a species.
@@ -329,11 +504,13 @@ pub fn apply_formative_modifiers(
 /// |--------------|----------|
 /// | Human | 1.0 (reference) |
 /// | Animal | 1.2 (higher base plasticity) |
+/// | Robotic | 0.0 (personality never shifts) |
 /// | Custom | Based on social complexity |
 #[must_use]
 pub fn species_plasticity_modifier(species: &Species) -> f32 {
     match species {
         Species::Human => 1.0,
+        Species::Robotic => 0.0,
         Species::Custom { social_complexity, .. } => {
             // Scale based on social complexity
             0.8 + (*social_complexity * 0.4)
@@ -454,6 +631,21 @@ mod tests {
         assert!(!shift.is_severe());
     }
 
+    #[test]
+    fn scaled_for_species_shortens_settling() {
+        let severe = BaseShiftRecord::new(Duration::seconds(0), HexacoPath::Neuroticism, 0.25);
+
+        let human = severe.clone().scaled_for_species(&Species::Human);
+        assert_eq!(human.settling_days(), SETTLING_DAYS);
+        let mouse = severe.scaled_for_species(&Species::Mouse);
+        assert_eq!(mouse.settling_days(), 5); // 180 / 40, rounded up
+        assert!((mouse.settled() - 0.175).abs() < f32::EPSILON);
+
+        let mild = BaseShiftRecord::new(Duration::seconds(0), HexacoPath::Neuroticism, 0.1)
+            .scaled_for_species(&Species::Mouse);
+        assert_eq!(mild.settling_days(), 0);
+    }
+
     #[test]
     fn base_shift_record_accessors() {
         let ts = Duration::seconds(1000);
@@ -691,6 +883,11 @@ mod tests {
         assert!((species_plasticity_modifier(&Species::Elephant) - 1.2).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn species_plasticity_robotic() {
+        assert!(species_plasticity_modifier(&Species::Robotic).abs() < f32::EPSILON);
+    }
+
     #[test]
     fn species_plasticity_custom() {
         let custom = Species::custom("Test", 50, 5, 0.5);
@@ -772,6 +969,59 @@ mod tests {
         assert!(result.abs() <= CUMULATIVE_CAP - 0.95 + f32::EPSILON);
     }
 
+    // Audit tests
+
+    #[test]
+    fn audit_records_each_modifier() {
+        let audit =
+            audit_formative_modifiers(-0.3, HexacoPath::Agreeableness, 45, 0.2, &Species::Human);
+
+        assert!((audit.raw - -0.3).abs() < f32::EPSILON);
+        assert_eq!(audit.age_years, 45);
+        assert!((audit.species_plasticity - 1.0).abs() < f32::EPSILON);
+        // max(age 0.8, 1.0 outside the sensitive period)
+        assert!((audit.plasticity - 1.0).abs() < f32::EPSILON);
+        assert!((audit.trait_modifier - 0.35).abs() < 0.001);
+        assert!((audit.saturation - saturation_factor(0.2)).abs() < f32::EPSILON);
+        assert!((audit.applied - audit.modified()).abs() < f32::EPSILON);
+        assert_eq!(audit.clipped_by(), None);
+        assert_eq!(
+            audit.applied,
+            apply_formative_modifiers(-0.3, HexacoPath::Agreeableness, 45, 0.2, &Species::Human)
+        );
+    }
+
+    #[test]
+    fn audit_reports_clipping_cap() {
+        let single =
+            audit_formative_modifiers(1.0, HexacoPath::Neuroticism, 15, 0.0, &Species::Human);
+        assert_eq!(single.clipped_by(), Some(ShiftCap::SingleEvent));
+        assert!((single.applied - MAX_SINGLE_EVENT_SHIFT).abs() < f32::EPSILON);
+
+        let cumulative =
+            audit_formative_modifiers(1.0, HexacoPath::Neuroticism, 15, 0.95, &Species::Human);
+        assert_eq!(cumulative.clipped_by(), Some(ShiftCap::Cumulative));
+        assert!(cumulative.retained_fraction() < 0.1);
+    }
+
+    #[test]
+    fn record_from_audit_keeps_breakdown() {
+        let audit =
+            audit_formative_modifiers(-0.3, HexacoPath::Agreeableness, 45, 0.0, &Species::Human);
+        let record =
+            BaseShiftRecord::from_audit(Duration::days(10), HexacoPath::Agreeableness, audit);
+
+        assert_eq!(record.audit(), Some(&audit));
+        assert!((record.immediate() - audit.applied).abs() < f32::EPSILON);
+        assert!((record.raw_amount() - -0.3).abs() < f32::EPSILON);
+        assert_eq!(record.age_at_event(), Some(45));
+
+        let plain = BaseShiftRecord::new(Duration::days(10), HexacoPath::Agreeableness, -0.1);
+        assert!(plain.audit().is_none());
+        assert!((plain.raw_amount() - -0.1).abs() < f32::EPSILON);
+        assert_eq!(plain.age_at_event(), None);
+    }
+
     // Effective base tests
 
     #[test]
@@ -14,7 +14,12 @@
 //! - Positive values indicate high trait presence
 
 use crate::enums::PersonalityProfile;
-use serde::{Deserialize, Serialize};
+use crate::types::SignedUnit;
+
+/// Clamps a trait level to -1.0 to 1.0 under the runtime mutator policy.
+fn trait_level(value: f32) -> f32 {
+    SignedUnit::clamped(f64::from(value)).into()
+}
 
 /// HEXACO personality factors.
 ///
@@ -38,7 +43,8 @@ use serde::{Deserialize, Serialize};
 ///     .with_openness(0.7)
 ///     .with_conscientiousness(0.5);
 /// ```
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Hexaco {
     /// Openness to experience: curiosity, creativity, preference for novelty.
     /// Range: -1.0 (closed) to 1.0 (open)
@@ -118,6 +124,49 @@ impl Hexaco {
         }
     }
 
+    /// Returns the preset profile whose HEXACO values are closest to these.
+    ///
+    /// Distance is Euclidean across the six factors. Ties go to the profile
+    /// listed first in `PersonalityProfile::all()`, so an exact preset
+    /// always maps back to itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::state::Hexaco;
+    /// use behavioral_pathways::enums::PersonalityProfile;
+    ///
+    /// let hexaco = Hexaco::from_profile(PersonalityProfile::Rebel).with_openness(0.3);
+    /// assert_eq!(hexaco.closest_profile(), PersonalityProfile::Rebel);
+    /// ```
+    #[must_use]
+    pub fn closest_profile(&self) -> PersonalityProfile {
+        let distance = |profile: &PersonalityProfile| {
+            let preset = Hexaco::from_profile(*profile);
+            [
+                self.openness - preset.openness,
+                self.conscientiousness - preset.conscientiousness,
+                self.extraversion - preset.extraversion,
+                self.agreeableness - preset.agreeableness,
+                self.neuroticism - preset.neuroticism,
+                self.honesty_humility - preset.honesty_humility,
+            ]
+            .iter()
+            .map(|d| d * d)
+            .sum::<f32>()
+        };
+        PersonalityProfile::all()
+            .into_iter()
+            .reduce(|best, profile| {
+                if distance(&profile) < distance(&best) {
+                    profile
+                } else {
+                    best
+                }
+            })
+            .unwrap_or_default()
+    }
+
     /// Creates a Hexaco with all factors set to the specified value.
     ///
     /// The value is clamped to the valid range -1.0 to 1.0.
@@ -137,7 +186,7 @@ impl Hexaco {
     /// ```
     #[must_use]
     pub fn uniform(value: f32) -> Self {
-        let clamped = value.clamp(-1.0, 1.0);
+        let clamped = trait_level(value);
         Hexaco {
             openness: clamped,
             conscientiousness: clamped,
@@ -153,42 +202,42 @@ impl Hexaco {
     /// Sets the openness factor.
     #[must_use]
     pub fn with_openness(mut self, value: f32) -> Self {
-        self.openness = value.clamp(-1.0, 1.0);
+        self.openness = trait_level(value);
         self
     }
 
     /// Sets the conscientiousness factor.
     #[must_use]
     pub fn with_conscientiousness(mut self, value: f32) -> Self {
-        self.conscientiousness = value.clamp(-1.0, 1.0);
+        self.conscientiousness = trait_level(value);
         self
     }
 
     /// Sets the extraversion factor.
     #[must_use]
     pub fn with_extraversion(mut self, value: f32) -> Self {
-        self.extraversion = value.clamp(-1.0, 1.0);
+        self.extraversion = trait_level(value);
         self
     }
 
     /// Sets the agreeableness factor.
     #[must_use]
     pub fn with_agreeableness(mut self, value: f32) -> Self {
-        self.agreeableness = value.clamp(-1.0, 1.0);
+        self.agreeableness = trait_level(value);
         self
     }
 
     /// Sets the neuroticism factor.
     #[must_use]
     pub fn with_neuroticism(mut self, value: f32) -> Self {
-        self.neuroticism = value.clamp(-1.0, 1.0);
+        self.neuroticism = trait_level(value);
         self
     }
 
     /// Sets the honesty-humility factor.
     #[must_use]
     pub fn with_honesty_humility(mut self, value: f32) -> Self {
-        self.honesty_humility = value.clamp(-1.0, 1.0);
+        self.honesty_humility = trait_level(value);
         self
     }
 
@@ -246,32 +295,32 @@ impl Hexaco {
 
     /// Sets the openness factor directly.
     pub fn set_openness(&mut self, value: f32) {
-        self.openness = value.clamp(-1.0, 1.0);
+        self.openness = trait_level(value);
     }
 
     /// Sets the conscientiousness factor directly.
     pub fn set_conscientiousness(&mut self, value: f32) {
-        self.conscientiousness = value.clamp(-1.0, 1.0);
+        self.conscientiousness = trait_level(value);
     }
 
     /// Sets the extraversion factor directly.
     pub fn set_extraversion(&mut self, value: f32) {
-        self.extraversion = value.clamp(-1.0, 1.0);
+        self.extraversion = trait_level(value);
     }
 
     /// Sets the agreeableness factor directly.
     pub fn set_agreeableness(&mut self, value: f32) {
-        self.agreeableness = value.clamp(-1.0, 1.0);
+        self.agreeableness = trait_level(value);
     }
 
     /// Sets the neuroticism factor directly.
     pub fn set_neuroticism(&mut self, value: f32) {
-        self.neuroticism = value.clamp(-1.0, 1.0);
+        self.neuroticism = trait_level(value);
     }
 
     /// Sets the honesty-humility factor directly.
     pub fn set_honesty_humility(&mut self, value: f32) {
-        self.honesty_humility = value.clamp(-1.0, 1.0);
+        self.honesty_humility = trait_level(value);
     }
 }
 
@@ -315,6 +364,21 @@ mod tests {
         assert!(hexaco.neuroticism() > 0.5);
     }
 
+    #[test]
+    fn closest_profile_round_trips_every_preset() {
+        for profile in PersonalityProfile::all() {
+            assert_eq!(Hexaco::from_profile(profile).closest_profile(), profile);
+        }
+    }
+
+    #[test]
+    fn closest_profile_of_neutral_is_balanced() {
+        assert_eq!(
+            Hexaco::new().closest_profile(),
+            PersonalityProfile::Balanced
+        );
+    }
+
     #[test]
     fn from_profile_leader_characteristics() {
         let hexaco = Hexaco::from_profile(PersonalityProfile::Leader);
@@ -403,6 +467,13 @@ mod tests {
         assert!((hexaco.neuroticism() - (-1.0)).abs() < f32::EPSILON);
     }
 
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "non-finite input")]
+    fn setters_assert_finite_in_debug_builds() {
+        Hexaco::new().set_openness(f32::NAN);
+    }
+
     #[test]
     fn default_is_neutral() {
         let hexaco = Hexaco::default();
@@ -14,12 +14,18 @@
 //!
 //! This is the primary container for an entity's internal state.
 
+use crate::enums::{
+    DispositionPath, MentalHealthPath, MoodPath, NeedsPath, PersonCharacteristicsPath,
+    SocialCognitionPath, Species, StatePath,
+};
+use crate::processor::decay_processor_for;
+#[cfg(feature = "serde")]
+use crate::state::{snapshot, SnapshotCompatReport, SnapshotError};
 use crate::state::{
     DemandCharacteristics, Demographical, Disposition, EntityModelConfig, Hexaco, MentalHealth,
     Mood, Needs, PersonCharacteristics, SocialCognition, StateValue,
 };
 use crate::types::Duration;
-use serde::{Deserialize, Serialize};
 
 /// Aggregate container for all individual psychological state.
 ///
@@ -42,7 +48,8 @@ use serde::{Deserialize, Serialize};
 /// // Apply decay to all components
 /// state.apply_decay(Duration::hours(6));
 /// ```
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IndividualState {
     /// HEXACO personality factors.
     hexaco: Hexaco,
@@ -179,6 +186,7 @@ impl IndividualState {
     #[must_use]
     pub fn with_config(mut self, config: EntityModelConfig) -> Self {
         self.config = config;
+        self.apply_decay_profile();
         self
     }
 
@@ -314,7 +322,10 @@ impl IndividualState {
     ///
     /// Note: Hexaco (personality) is stable and does not decay.
     /// Note: Acquired Capability in MentalHealth does not decay.
+    /// Half-lives overridden by the config's `DecayProfile` take effect
+    /// first.
     pub fn apply_decay(&mut self, elapsed: Duration) {
+        self.apply_decay_profile();
         // Hexaco is stable - no decay
         self.mood.apply_decay(elapsed);
         self.recent_moral_violation_flag.apply_decay(elapsed);
@@ -325,6 +336,235 @@ impl IndividualState {
         self.person_characteristics.apply_decay(elapsed);
     }
 
+    /// Returns every dimension that decays, for decay and its reversal.
+    ///
+    /// Regression reverses exactly these values with their own half-lives,
+    /// so forward and backward traversal always agree on what decays.
+    pub(crate) fn decaying_values_mut(&mut self) -> Vec<&mut StateValue> {
+        let mut values = vec![&mut self.recent_moral_violation_flag];
+        values.extend(self.mood.decaying_values_mut());
+        values.extend(self.needs.decaying_values_mut());
+        values.extend(self.social_cognition.decaying_values_mut());
+        values.extend(self.mental_health.decaying_values_mut());
+        values.extend(self.disposition.decaying_values_mut());
+        values.extend(self.person_characteristics.decaying_values_mut());
+        values
+    }
+
+    /// Sets each decaying value's half-life from the config's
+    /// `DecayProfile`.
+    ///
+    /// Decay and its reversal both call this first, so they always use
+    /// the same overridden half-lives. Paths that never decay are skipped.
+    pub(crate) fn apply_decay_profile(&mut self) {
+        let Some(profile) = self.config.decay_profile() else {
+            return;
+        };
+        let overrides: Vec<_> = profile.iter().collect();
+        for (path, half_life) in overrides {
+            if let Some(value) = self.state_value_mut(path).filter(|value| value.decays()) {
+                value.set_decay_half_life(half_life);
+            }
+        }
+    }
+
+    /// Returns the stored value behind a state path.
+    ///
+    /// Returns `None` for HEXACO traits, which have no delta, and for
+    /// paths computed from other values.
+    pub(crate) fn state_value(&self, path: StatePath) -> Option<&StateValue> {
+        let value = match path {
+            StatePath::Hexaco(_) => return None,
+            StatePath::Mood(p) => match p {
+                MoodPath::Valence => self.mood.valence(),
+                MoodPath::Arousal => self.mood.arousal(),
+                MoodPath::Dominance => self.mood.dominance(),
+            },
+            StatePath::Needs(p) => match p {
+                NeedsPath::Stress => self.needs.stress(),
+                NeedsPath::Fatigue => self.needs.fatigue(),
+                NeedsPath::Purpose => self.needs.purpose(),
+                NeedsPath::Satiation => self.needs.satiation(),
+                NeedsPath::Safety => self.needs.safety(),
+            },
+            StatePath::SocialCognition(p) => match p {
+                SocialCognitionPath::Loneliness => self.social_cognition.loneliness(),
+                SocialCognitionPath::PerceivedReciprocalCaring => {
+                    self.social_cognition.perceived_reciprocal_caring()
+                }
+                SocialCognitionPath::PerceivedLiability => {
+                    self.social_cognition.perceived_liability()
+                }
+                SocialCognitionPath::SelfHate => self.social_cognition.self_hate(),
+                SocialCognitionPath::PerceivedCompetence => {
+                    self.social_cognition.perceived_competence()
+                }
+            },
+            StatePath::MentalHealth(p) => match p {
+                MentalHealthPath::Depression => self.mental_health.depression(),
+                MentalHealthPath::SelfWorth => self.mental_health.self_worth(),
+                MentalHealthPath::Hopelessness => self.mental_health.hopelessness(),
+                MentalHealthPath::InterpersonalHopelessness => {
+                    self.mental_health.interpersonal_hopelessness()
+                }
+                MentalHealthPath::AcquiredCapability => self.mental_health.acquired_capability(),
+                MentalHealthPath::ThwartedBelongingness
+                | MentalHealthPath::PerceivedBurdensomeness
+                | MentalHealthPath::SuicidalDesire
+                | MentalHealthPath::AttemptRisk => return None,
+            },
+            StatePath::Disposition(p) => match p {
+                DispositionPath::Empathy => self.disposition.empathy(),
+                DispositionPath::Aggression => self.disposition.aggression(),
+                DispositionPath::Grievance => self.disposition.grievance(),
+                DispositionPath::ImpulseControl => self.disposition.impulse_control(),
+                DispositionPath::Reactance => self.disposition.reactance(),
+                DispositionPath::TrustPropensity => self.disposition.trust_propensity(),
+            },
+            StatePath::PersonCharacteristics(p) => match p {
+                PersonCharacteristicsPath::SocialCapital => {
+                    self.person_characteristics.social_capital()
+                }
+                PersonCharacteristicsPath::CognitiveAbility => {
+                    self.person_characteristics.cognitive_ability()
+                }
+                PersonCharacteristicsPath::EmotionalRegulationAssets => {
+                    self.person_characteristics.emotional_regulation_assets()
+                }
+                PersonCharacteristicsPath::MaterialSecurity => {
+                    self.person_characteristics.material_security()
+                }
+                PersonCharacteristicsPath::ExperienceDiversity => {
+                    self.person_characteristics.experience_diversity()
+                }
+                PersonCharacteristicsPath::BaselineMotivation => {
+                    self.person_characteristics.baseline_motivation()
+                }
+                PersonCharacteristicsPath::PersistenceTendency => {
+                    self.person_characteristics.persistence_tendency()
+                }
+                PersonCharacteristicsPath::CuriosityTendency => {
+                    self.person_characteristics.curiosity_tendency()
+                }
+                PersonCharacteristicsPath::Resource | PersonCharacteristicsPath::Force => {
+                    return None
+                }
+            },
+        };
+        Some(value)
+    }
+
+    /// Returns the stored value behind a state path, mutably.
+    ///
+    /// Returns `None` for the same paths as `state_value`.
+    pub(crate) fn state_value_mut(&mut self, path: StatePath) -> Option<&mut StateValue> {
+        let value = match path {
+            StatePath::Hexaco(_) => return None,
+            StatePath::Mood(p) => match p {
+                MoodPath::Valence => self.mood.valence_mut(),
+                MoodPath::Arousal => self.mood.arousal_mut(),
+                MoodPath::Dominance => self.mood.dominance_mut(),
+            },
+            StatePath::Needs(p) => match p {
+                NeedsPath::Stress => self.needs.stress_mut(),
+                NeedsPath::Fatigue => self.needs.fatigue_mut(),
+                NeedsPath::Purpose => self.needs.purpose_mut(),
+                NeedsPath::Satiation => self.needs.satiation_mut(),
+                NeedsPath::Safety => self.needs.safety_mut(),
+            },
+            StatePath::SocialCognition(p) => match p {
+                SocialCognitionPath::Loneliness => self.social_cognition.loneliness_mut(),
+                SocialCognitionPath::PerceivedReciprocalCaring => {
+                    self.social_cognition.perceived_reciprocal_caring_mut()
+                }
+                SocialCognitionPath::PerceivedLiability => {
+                    self.social_cognition.perceived_liability_mut()
+                }
+                SocialCognitionPath::SelfHate => self.social_cognition.self_hate_mut(),
+                SocialCognitionPath::PerceivedCompetence => {
+                    self.social_cognition.perceived_competence_mut()
+                }
+            },
+            StatePath::MentalHealth(p) => match p {
+                MentalHealthPath::Depression => self.mental_health.depression_mut(),
+                MentalHealthPath::SelfWorth => self.mental_health.self_worth_mut(),
+                MentalHealthPath::Hopelessness => self.mental_health.hopelessness_mut(),
+                MentalHealthPath::InterpersonalHopelessness => {
+                    self.mental_health.interpersonal_hopelessness_mut()
+                }
+                MentalHealthPath::AcquiredCapability => self.mental_health.acquired_capability_mut(),
+                MentalHealthPath::ThwartedBelongingness
+                | MentalHealthPath::PerceivedBurdensomeness
+                | MentalHealthPath::SuicidalDesire
+                | MentalHealthPath::AttemptRisk => return None,
+            },
+            StatePath::Disposition(p) => match p {
+                DispositionPath::Empathy => self.disposition.empathy_mut(),
+                DispositionPath::Aggression => self.disposition.aggression_mut(),
+                DispositionPath::Grievance => self.disposition.grievance_mut(),
+                DispositionPath::ImpulseControl => self.disposition.impulse_control_mut(),
+                DispositionPath::Reactance => self.disposition.reactance_mut(),
+                DispositionPath::TrustPropensity => self.disposition.trust_propensity_mut(),
+            },
+            StatePath::PersonCharacteristics(p) => match p {
+                PersonCharacteristicsPath::SocialCapital => {
+                    self.person_characteristics.social_capital_mut()
+                }
+                PersonCharacteristicsPath::CognitiveAbility => {
+                    self.person_characteristics.cognitive_ability_mut()
+                }
+                PersonCharacteristicsPath::EmotionalRegulationAssets => {
+                    self.person_characteristics.emotional_regulation_assets_mut()
+                }
+                PersonCharacteristicsPath::MaterialSecurity => {
+                    self.person_characteristics.material_security_mut()
+                }
+                PersonCharacteristicsPath::ExperienceDiversity => {
+                    self.person_characteristics.experience_diversity_mut()
+                }
+                PersonCharacteristicsPath::BaselineMotivation => {
+                    self.person_characteristics.baseline_motivation_mut()
+                }
+                PersonCharacteristicsPath::PersistenceTendency => {
+                    self.person_characteristics.persistence_tendency_mut()
+                }
+                PersonCharacteristicsPath::CuriosityTendency => {
+                    self.person_characteristics.curiosity_tendency_mut()
+                }
+                PersonCharacteristicsPath::Resource | PersonCharacteristicsPath::Force => {
+                    return None
+                }
+            },
+        };
+        Some(value)
+    }
+
+    /// Applies decay over real elapsed time for an entity of `species`.
+    ///
+    /// Shorter-lived species experience time faster, so `elapsed` is scaled
+    /// by `Species::time_scale()` before decaying, exactly as `state_at()`
+    /// does between events. A robotic entity's state does not decay. Use
+    /// `apply_decay()` for unscaled time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::enums::Species;
+    /// use behavioral_pathways::state::IndividualState;
+    /// use behavioral_pathways::types::Duration;
+    ///
+    /// let mut human = IndividualState::new();
+    /// human.needs_mut().add_stress_delta(0.8);
+    /// let mut dog = human.clone();
+    ///
+    /// human.apply_species_decay(Duration::hours(12), &Species::Human);
+    /// dog.apply_species_decay(Duration::hours(12), &Species::Dog);
+    /// assert!(dog.needs().stress().delta() < human.needs().stress().delta());
+    /// ```
+    pub fn apply_species_decay(&mut self, elapsed: Duration, species: &Species) {
+        decay_processor_for(species).apply_decay(self, elapsed, f64::from(species.time_scale()));
+    }
+
     /// Resets all deltas across all components.
     ///
     /// Note: Acquired Capability delta is not reset (permanent accumulation).
@@ -366,6 +606,47 @@ impl IndividualState {
     pub fn compute_attempt_risk(&self) -> f32 {
         self.mental_health.compute_attempt_risk(&self.social_cognition)
     }
+
+    // Snapshots
+
+    /// Serializes this state into a versioned JSON snapshot.
+    ///
+    /// Snapshots load in later crate versions via `from_snapshot`. Needs the
+    /// `serde` feature.
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub fn to_snapshot(&self) -> String {
+        snapshot::to_snapshot(self)
+    }
+
+    /// Loads a state from a snapshot written by this or another crate
+    /// version.
+    ///
+    /// Fields the snapshot lacks take the values `IndividualState::new()`
+    /// gives them, and fields this version does not know are dropped. The
+    /// report lists both. Needs the `serde` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the snapshot is not JSON, lacks its
+    /// `schema_version` or `state`, or holds a value of the wrong type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::state::IndividualState;
+    ///
+    /// let mut state = IndividualState::new();
+    /// state.mood_mut().add_valence_delta(0.3);
+    ///
+    /// let (loaded, report) = IndividualState::from_snapshot(&state.to_snapshot()).unwrap();
+    /// assert_eq!(loaded, state);
+    /// assert!(report.is_exact());
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn from_snapshot(json: &str) -> Result<(Self, SnapshotCompatReport), SnapshotError> {
+        snapshot::from_snapshot(json)
+    }
 }
 
 impl Default for IndividualState {
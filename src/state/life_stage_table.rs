@@ -0,0 +1,213 @@
+//! Per-entity life stage boundary overrides.
+//!
+//! By default, life stage boundaries scale the human ones by the species'
+//! maturity age and lifespan (see `LifeStage::from_age_years_for_species`).
+//! A `LifeStageTable` replaces those boundaries for one entity, for species
+//! whose development the default scaling fits poorly. Life stage, stage
+//! progress, and the age that formative plasticity reads all follow the
+//! table.
+
+use crate::enums::{species_age, LifeStage, Species, HUMAN_LIFESPAN};
+use crate::types::Duration;
+
+/// Species ages at which each life stage begins.
+///
+/// Start from `LifeStageTable::for_species`, which reproduces the default
+/// scaled boundaries, and override the stages that differ. Child always
+/// begins at age 0. An age falls in the latest stage whose entry age it
+/// has reached, so entry ages should rise along the stages.
+///
+/// Ages map to human-equivalent years linearly within each stage, from
+/// the human entry age of the stage to that of the next (or the human
+/// lifespan for Elder). Formative plasticity and sensitive periods read
+/// this human-equivalent age.
+///
+/// # Examples
+///
+/// ```
+/// use behavioral_pathways::enums::{LifeStage, Species};
+/// use behavioral_pathways::state::LifeStageTable;
+///
+/// // A working breed that stays adolescent until 3
+/// let table = LifeStageTable::for_species(&Species::Dog)
+///     .with_stage_entry(LifeStage::YoungAdult, 3.0);
+///
+/// assert_eq!(table.stage_at(2.0), LifeStage::Adolescent);
+/// assert_eq!(table.stage_at(3.0), LifeStage::YoungAdult);
+/// assert!((table.human_equivalent_age(3.0) - 18.0).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LifeStageTable {
+    /// Entry ages in years for Adolescent through Elder.
+    entries: [f64; 5],
+    /// Lifespan in years, where Elder progress ends.
+    lifespan_years: f64,
+}
+
+impl LifeStageTable {
+    /// Creates the table of default stage boundaries for `species`.
+    #[must_use]
+    pub fn for_species(species: &Species) -> Self {
+        let stages = LifeStage::all();
+        let mut entries = [0.0; 5];
+        for (entry, stage) in entries.iter_mut().zip(&stages[1..]) {
+            *entry = species_age(species, f64::from(stage.age_range().0));
+        }
+        LifeStageTable {
+            entries,
+            lifespan_years: f64::from(species.lifespan_years()),
+        }
+    }
+
+    /// Sets the age in years at which `stage` begins.
+    ///
+    /// Child always begins at 0, so overriding it has no effect.
+    #[must_use]
+    pub fn with_stage_entry(mut self, stage: LifeStage, age_years: f64) -> Self {
+        self.set_stage_entry(stage, age_years);
+        self
+    }
+
+    /// Sets the age in years at which `stage` begins.
+    ///
+    /// Child always begins at 0, so overriding it has no effect.
+    pub fn set_stage_entry(&mut self, stage: LifeStage, age_years: f64) {
+        if let Some(index) = entry_index(stage) {
+            self.entries[index] = age_years.max(0.0);
+        }
+    }
+
+    /// Returns the age in years at which `stage` begins.
+    #[must_use]
+    pub fn stage_entry(&self, stage: LifeStage) -> f64 {
+        entry_index(stage).map_or(0.0, |index| self.entries[index])
+    }
+
+    /// Returns the lifespan in years, where Elder progress ends.
+    #[must_use]
+    pub fn lifespan_years(&self) -> f64 {
+        self.lifespan_years
+    }
+
+    /// Returns the life stage at `age_years`.
+    #[must_use]
+    pub fn stage_at(&self, age_years: f64) -> LifeStage {
+        LifeStage::all()
+            .into_iter()
+            .rev()
+            .find(|stage| age_years >= self.stage_entry(*stage))
+            .unwrap_or(LifeStage::Child)
+    }
+
+    /// Returns the age in years at which the stage after `stage` begins,
+    /// or the lifespan for Elder.
+    fn stage_exit(&self, stage: LifeStage) -> f64 {
+        stage
+            .next()
+            .map_or(self.lifespan_years, |next| self.stage_entry(next))
+    }
+
+    /// Converts `age_years` to human-equivalent years.
+    #[must_use]
+    pub fn human_equivalent_age(&self, age_years: f64) -> f64 {
+        let stage = self.stage_at(age_years);
+        let (entry, exit) = (self.stage_entry(stage), self.stage_exit(stage));
+        let human_entry = f64::from(stage.age_range().0);
+        let human_exit = stage
+            .next()
+            .map_or(HUMAN_LIFESPAN, |next| f64::from(next.age_range().0));
+        if exit <= entry {
+            return human_entry;
+        }
+        human_entry + (age_years - entry) * (human_exit - human_entry) / (exit - entry)
+    }
+
+    /// Returns how far through its life stage an entity of this age is.
+    ///
+    /// Same as `LifeStage::progress_for_species`, with this table's
+    /// boundaries.
+    #[must_use]
+    pub fn progress(&self, age_years: f64) -> f64 {
+        let stage = self.stage_at(age_years);
+        let (entry, exit) = (self.stage_entry(stage), self.stage_exit(stage));
+        if exit <= entry {
+            return 1.0;
+        }
+        ((age_years - entry) / (exit - entry)).clamp(0.0, 1.0)
+    }
+
+    /// Returns the time until an entity of this age enters its next stage.
+    ///
+    /// Same as `LifeStage::time_until_next_stage_for_species`, with this
+    /// table's boundaries.
+    #[must_use]
+    pub fn time_until_next_stage(&self, age_years: f64) -> Option<Duration> {
+        let next = self.stage_at(age_years).next()?;
+        let remaining_years = (self.stage_entry(next) - age_years).max(0.0);
+        let seconds_per_year = Duration::years(1).as_seconds() as f64;
+        Some(Duration::seconds(
+            (remaining_years * seconds_per_year).round() as u64,
+        ))
+    }
+}
+
+/// Returns the index of `stage` in the entry ages, or `None` for Child.
+const fn entry_index(stage: LifeStage) -> Option<usize> {
+    match stage {
+        LifeStage::Child => None,
+        LifeStage::Adolescent => Some(0),
+        LifeStage::YoungAdult => Some(1),
+        LifeStage::Adult => Some(2),
+        LifeStage::MatureAdult => Some(3),
+        LifeStage::Elder => Some(4),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn species_table_matches_default_stages() {
+        for species in [Species::Human, Species::Dog, Species::Cat, Species::Mouse] {
+            let table = LifeStageTable::for_species(&species);
+            let lifespan = f64::from(species.lifespan_years());
+            for step in 0..200 {
+                // Offset from the boundaries, where rounding could differ
+                let age = lifespan * (f64::from(step) + 0.5) / 150.0;
+                assert_eq!(
+                    table.stage_at(age),
+                    LifeStage::from_age_years_for_species(&species, age),
+                    "{species:?} at {age}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn human_table_maps_ages_to_themselves() {
+        let table = LifeStageTable::for_species(&Species::Human);
+        for age in [0.0, 2.0, 13.0, 25.0, 40.5, 71.0, 90.0] {
+            assert!((table.human_equivalent_age(age) - age).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn child_entry_cannot_move() {
+        let table =
+            LifeStageTable::for_species(&Species::Dog).with_stage_entry(LifeStage::Child, 1.0);
+        assert_eq!(table.stage_entry(LifeStage::Child), 0.0);
+        assert_eq!(table.stage_at(0.5), LifeStage::Child);
+    }
+
+    #[test]
+    fn collapsed_stage_is_skipped() {
+        let table = LifeStageTable::for_species(&Species::Human)
+            .with_stage_entry(LifeStage::Adolescent, 18.0);
+        assert_eq!(table.stage_at(17.0), LifeStage::Child);
+        assert_eq!(table.stage_at(18.0), LifeStage::YoungAdult);
+        assert_eq!(table.progress(18.0), 0.0);
+        assert_eq!(table.time_until_next_stage(17.0), Some(Duration::years(1)));
+    }
+}
@@ -14,7 +14,6 @@
 
 use crate::state::{SocialCognition, StateValue};
 use crate::types::Duration;
-use serde::{Deserialize, Serialize};
 
 /// Threshold above which Thwarted Belongingness is considered present.
 /// Per ITS, TB must be significantly elevated to contribute to suicidal desire.
@@ -52,7 +51,8 @@ pub const HOPELESSNESS_THRESHOLD: f32 = 0.5;
 /// let tb = mental_health.compute_thwarted_belongingness(&social);
 /// assert!(tb < 0.5); // Low loneliness + high caring = low TB
 /// ```
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MentalHealth {
     /// Depression severity.
     /// Range: 0 (not depressed) to 1 (severe depression)
@@ -417,11 +417,21 @@ impl MentalHealth {
     ///
     /// Note: Acquired Capability NEVER decays.
     pub fn apply_decay(&mut self, elapsed: Duration) {
-        self.depression.apply_decay(elapsed);
-        self.self_worth.apply_decay(elapsed);
-        self.hopelessness.apply_decay(elapsed);
-        self.interpersonal_hopelessness.apply_decay(elapsed);
-        // acquired_capability intentionally not decayed
+        for value in self.decaying_values_mut() {
+            value.apply_decay(elapsed);
+        }
+    }
+
+    /// Returns every dimension that decays, for decay and its reversal.
+    ///
+    /// Acquired Capability never decays, so it is not included.
+    pub(crate) fn decaying_values_mut(&mut self) -> [&mut StateValue; 4] {
+        [
+            &mut self.depression,
+            &mut self.self_worth,
+            &mut self.hopelessness,
+            &mut self.interpersonal_hopelessness,
+        ]
     }
 
     /// Resets deltas for decaying dimensions only.
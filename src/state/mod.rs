@@ -16,8 +16,12 @@
 //! - [`Demographical`] - Demographical metadata
 //! - [`DemandCharacteristics`] - Observable social signals
 //! - [`EntityModelConfig`] - Subsystem activation flags
+//! - [`DecayProfile`] - Per-dimension decay half-life overrides
+//! - [`LifeStageTable`] - Per-entity life stage boundary overrides
 //! - [`IndividualState`] - Aggregate container for all state
+//! - [`SnapshotCompatReport`] - What loading a versioned state snapshot defaulted or dropped
 
+mod decay_profile;
 mod disposition;
 mod demand_characteristics;
 mod demographical;
@@ -25,20 +29,25 @@ mod entity_model_config;
 mod formative;
 mod hexaco;
 mod individual_state;
+mod life_stage_table;
 mod mental_health;
 mod mood;
 mod needs;
 mod social_cognition;
 mod person_characteristics;
+#[cfg(feature = "serde")]
+mod snapshot;
 mod state_value;
 mod state_interpreter;
 
+pub use decay_profile::DecayProfile;
 pub use demand_characteristics::DemandCharacteristics;
 pub use demographical::Demographical;
 pub use disposition::Disposition;
 pub use entity_model_config::EntityModelConfig;
 pub use hexaco::Hexaco;
 pub use individual_state::IndividualState;
+pub use life_stage_table::LifeStageTable;
 pub use mental_health::{
     MentalHealth, HOPELESSNESS_THRESHOLD, PB_PRESENT_THRESHOLD, TB_PRESENT_THRESHOLD,
 };
@@ -46,11 +55,14 @@ pub use mood::Mood;
 pub use needs::Needs;
 pub use social_cognition::SocialCognition;
 pub use person_characteristics::PersonCharacteristics;
-pub use state_value::StateValue;
+#[cfg(feature = "serde")]
+pub use snapshot::{SnapshotCompatReport, SnapshotError, SNAPSHOT_SCHEMA_VERSION};
+pub use state_value::{DeltaBreakdown, StateValue};
 pub use state_interpreter::StateInterpreter;
 pub use formative::{
-    age_plasticity, apply_formative_modifiers, combined_plasticity, cumulative_in_direction,
-    effective_base_at, saturation_factor, sensitive_period_modifier, species_plasticity_modifier,
-    stability_coefficient, trait_modifier, BaseShiftRecord, CUMULATIVE_CAP, MAX_SINGLE_EVENT_SHIFT,
-    SATURATION_CONSTANT, SETTLING_DAYS, SEVERE_SHIFT_RETENTION, SEVERE_SHIFT_THRESHOLD,
+    age_plasticity, apply_formative_modifiers, audit_formative_modifiers, combined_plasticity,
+    cumulative_in_direction, effective_base_at, saturation_factor, sensitive_period_modifier,
+    species_plasticity_modifier, stability_coefficient, trait_modifier, BaseShiftRecord,
+    FormativeShiftAudit, ShiftCap, CUMULATIVE_CAP, MAX_SINGLE_EVENT_SHIFT, SATURATION_CONSTANT,
+    SETTLING_DAYS, SEVERE_SHIFT_RETENTION, SEVERE_SHIFT_THRESHOLD,
 };
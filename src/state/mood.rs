@@ -14,7 +14,6 @@
 
 use crate::state::{Hexaco, StateValue};
 use crate::types::Duration;
-use serde::{Deserialize, Serialize};
 
 /// Mood state containing PAD (Pleasure-Arousal-Dominance) dimensions.
 ///
@@ -38,7 +37,8 @@ use serde::{Deserialize, Serialize};
 /// // Mood decays over time
 /// mood.apply_decay(Duration::hours(6));
 /// ```
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Mood {
     /// Valence: pleasantness of emotional experience.
     /// Range: -1 (displeasure) to +1 (pleasure)
@@ -291,9 +291,18 @@ impl Mood {
     ///
     /// Each dimension decays according to its own half-life.
     pub fn apply_decay(&mut self, elapsed: Duration) {
-        self.valence.apply_decay(elapsed);
-        self.arousal.apply_decay(elapsed);
-        self.dominance.apply_decay(elapsed);
+        for value in self.decaying_values_mut() {
+            value.apply_decay(elapsed);
+        }
+    }
+
+    /// Returns every dimension that decays, for decay and its reversal.
+    pub(crate) fn decaying_values_mut(&mut self) -> [&mut StateValue; 3] {
+        [
+            &mut self.valence,
+            &mut self.arousal,
+            &mut self.dominance,
+        ]
     }
 
     /// Resets all deltas to zero.
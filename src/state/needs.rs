@@ -2,11 +2,11 @@
 //!
 //! This module contains needs that drive behavior and represent
 //! physiological or motivational states. Fatigue and stress are
-//! distinct from PAD mood dimensions.
+//! distinct from PAD mood dimensions. Satiation and safety are the basic
+//! drives behind much non-human behavior.
 
 use crate::state::StateValue;
 use crate::types::Duration;
-use serde::{Deserialize, Serialize};
 
 /// Psychological and physiological needs.
 ///
@@ -28,7 +28,8 @@ use serde::{Deserialize, Serialize};
 /// // Needs decay over time
 /// needs.apply_decay(Duration::days(1));
 /// ```
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Needs {
     /// Physical and mental tiredness.
     /// Range: 0 (fresh) to 1 (exhausted)
@@ -44,6 +45,20 @@ pub struct Needs {
     /// Range: 0 (purposeless) to 1 (strong sense of purpose)
     /// Default decay half-life: 3 days
     purpose: StateValue,
+
+    /// Fullness after eating.
+    /// Range: 0 (starving) to 1 (sated)
+    /// The base is the hungry level satiation falls back to between meals.
+    /// Default decay half-life: 12 hours
+    #[cfg_attr(feature = "serde", serde(default = "Needs::default_satiation"))]
+    satiation: StateValue,
+
+    /// Perceived safety from threat.
+    /// Range: 0 (under threat) to 1 (secure)
+    /// Default decay half-life: 3 days, so safety recovers slowly after a
+    /// threat
+    #[cfg_attr(feature = "serde", serde(default = "Needs::default_safety"))]
+    safety: StateValue,
 }
 
 impl Needs {
@@ -56,6 +71,12 @@ impl Needs {
     /// Default decay half-life for purpose (3 days).
     const PURPOSE_DECAY_HALF_LIFE: Duration = Duration::days(3);
 
+    /// Default decay half-life for satiation (12 hours).
+    const SATIATION_DECAY_HALF_LIFE: Duration = Duration::hours(12);
+
+    /// Default decay half-life for safety (3 days).
+    const SAFETY_DECAY_HALF_LIFE: Duration = Duration::days(3);
+
     /// Creates a new Needs with default base values.
     ///
     /// Default bases are neutral (0.2-0.3 range) representing a
@@ -81,9 +102,27 @@ impl Needs {
             purpose: StateValue::new(0.7)
                 .with_bounds(0.0, 1.0)
                 .with_decay_half_life(Self::PURPOSE_DECAY_HALF_LIFE),
+            satiation: Self::default_satiation(),
+            safety: Self::default_safety(),
         }
     }
 
+    /// Satiation at its default hungry base, also used for snapshots
+    /// saved before satiation existed.
+    fn default_satiation() -> StateValue {
+        StateValue::new(0.3)
+            .with_bounds(0.0, 1.0)
+            .with_decay_half_life(Self::SATIATION_DECAY_HALF_LIFE)
+    }
+
+    /// Safety at its default secure base, also used for snapshots saved
+    /// before safety existed.
+    fn default_safety() -> StateValue {
+        StateValue::new(0.7)
+            .with_bounds(0.0, 1.0)
+            .with_decay_half_life(Self::SAFETY_DECAY_HALF_LIFE)
+    }
+
     // Builder methods for base values
 
     /// Sets the base fatigue.
@@ -107,6 +146,20 @@ impl Needs {
         self
     }
 
+    /// Sets the base satiation.
+    #[must_use]
+    pub fn with_satiation_base(mut self, value: f32) -> Self {
+        self.satiation.set_base(value);
+        self
+    }
+
+    /// Sets the base safety.
+    #[must_use]
+    pub fn with_safety_base(mut self, value: f32) -> Self {
+        self.safety.set_base(value);
+        self
+    }
+
     // Effective value accessors (base + delta)
 
     /// Returns the effective fatigue (base + delta).
@@ -127,6 +180,18 @@ impl Needs {
         self.purpose.effective()
     }
 
+    /// Returns the effective satiation (base + delta).
+    #[must_use]
+    pub fn satiation_effective(&self) -> f32 {
+        self.satiation.effective()
+    }
+
+    /// Returns the effective safety (base + delta).
+    #[must_use]
+    pub fn safety_effective(&self) -> f32 {
+        self.safety.effective()
+    }
+
     // Base value accessors
 
     /// Returns the base fatigue.
@@ -147,6 +212,18 @@ impl Needs {
         self.purpose.base()
     }
 
+    /// Returns the base satiation.
+    #[must_use]
+    pub fn satiation_base(&self) -> f32 {
+        self.satiation.base()
+    }
+
+    /// Returns the base safety.
+    #[must_use]
+    pub fn safety_base(&self) -> f32 {
+        self.safety.base()
+    }
+
     // StateValue references
 
     /// Returns a reference to the fatigue StateValue.
@@ -167,6 +244,18 @@ impl Needs {
         &self.purpose
     }
 
+    /// Returns a reference to the satiation StateValue.
+    #[must_use]
+    pub fn satiation(&self) -> &StateValue {
+        &self.satiation
+    }
+
+    /// Returns a reference to the safety StateValue.
+    #[must_use]
+    pub fn safety(&self) -> &StateValue {
+        &self.safety
+    }
+
     /// Returns a mutable reference to the fatigue StateValue.
     pub fn fatigue_mut(&mut self) -> &mut StateValue {
         &mut self.fatigue
@@ -182,6 +271,16 @@ impl Needs {
         &mut self.purpose
     }
 
+    /// Returns a mutable reference to the satiation StateValue.
+    pub fn satiation_mut(&mut self) -> &mut StateValue {
+        &mut self.satiation
+    }
+
+    /// Returns a mutable reference to the safety StateValue.
+    pub fn safety_mut(&mut self) -> &mut StateValue {
+        &mut self.safety
+    }
+
     // Delta modifiers
 
     /// Adds to the fatigue delta.
@@ -199,11 +298,32 @@ impl Needs {
         self.purpose.add_delta(amount);
     }
 
+    /// Adds to the satiation delta.
+    pub fn add_satiation_delta(&mut self, amount: f32) {
+        self.satiation.add_delta(amount);
+    }
+
+    /// Adds to the safety delta.
+    pub fn add_safety_delta(&mut self, amount: f32) {
+        self.safety.add_delta(amount);
+    }
+
     /// Applies decay to all needs based on elapsed time.
     pub fn apply_decay(&mut self, elapsed: Duration) {
-        self.fatigue.apply_decay(elapsed);
-        self.stress.apply_decay(elapsed);
-        self.purpose.apply_decay(elapsed);
+        for value in self.decaying_values_mut() {
+            value.apply_decay(elapsed);
+        }
+    }
+
+    /// Returns every dimension that decays, for decay and its reversal.
+    pub(crate) fn decaying_values_mut(&mut self) -> [&mut StateValue; 5] {
+        [
+            &mut self.fatigue,
+            &mut self.stress,
+            &mut self.purpose,
+            &mut self.satiation,
+            &mut self.safety,
+        ]
     }
 
     /// Resets all deltas to zero.
@@ -211,6 +331,8 @@ impl Needs {
         self.fatigue.reset_delta();
         self.stress.reset_delta();
         self.purpose.reset_delta();
+        self.satiation.reset_delta();
+        self.safety.reset_delta();
     }
 }
 
@@ -302,6 +424,44 @@ mod tests {
         assert!((needs.purpose().delta() - (-0.2)).abs() < 0.01);
     }
 
+    #[test]
+    fn satiation_falls_back_to_hungry_base() {
+        let mut needs = Needs::new();
+        let hungry = needs.satiation_effective();
+        needs.add_satiation_delta(0.6);
+
+        // After 12 hours (one half-life), half the meal is left
+        needs.apply_decay(Duration::hours(12));
+        assert!((needs.satiation().delta() - 0.3).abs() < 0.01);
+        assert!(needs.satiation_effective() > hungry);
+        needs.apply_decay(Duration::days(7));
+        assert!((needs.satiation_effective() - hungry).abs() < 0.01);
+    }
+
+    #[test]
+    fn safety_recovers_slowly_after_threat() {
+        let mut needs = Needs::new();
+        let secure = needs.safety_effective();
+        needs.add_safety_delta(-0.4);
+
+        // After 3 days (one half-life), half the threat is left
+        needs.apply_decay(Duration::days(3));
+        assert!((needs.safety().delta() - (-0.2)).abs() < 0.01);
+        assert!(needs.safety_effective() < secure);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn snapshots_without_satiation_or_safety_take_defaults() {
+        let mut json = serde_json::to_value(Needs::new().with_stress_base(0.4)).unwrap();
+        let fields = json.as_object_mut().unwrap();
+        fields.remove("satiation");
+        fields.remove("safety");
+
+        let needs: Needs = serde_json::from_value(json).unwrap();
+        assert_eq!(needs, Needs::new().with_stress_base(0.4));
+    }
+
     #[test]
     fn reset_deltas_clears_all() {
         let mut needs = Needs::new();
@@ -355,6 +515,12 @@ mod tests {
 
         n.add_purpose_delta(0.2);
         assert!((n.purpose().delta() - 0.2).abs() < f32::EPSILON);
+
+        n.add_satiation_delta(0.3);
+        assert!((n.satiation().delta() - 0.3).abs() < f32::EPSILON);
+
+        n.add_safety_delta(-0.2);
+        assert!((n.safety().delta() - (-0.2)).abs() < f32::EPSILON);
     }
 
     #[test]
@@ -378,5 +544,7 @@ mod tests {
         let _ = n.fatigue_base();
         let _ = n.stress_base();
         let _ = n.purpose_base();
+        let _ = n.satiation_base();
+        let _ = n.safety_base();
     }
 }
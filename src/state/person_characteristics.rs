@@ -14,9 +14,9 @@
 //!   temperament that set proximal processes in motion (curiosity, drive,
 //!   self-efficacy)
 
+use crate::enums::PersonalityProfile;
 use crate::state::StateValue;
 use crate::types::Duration;
-use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Person characteristics per the PPCT model.
@@ -36,7 +36,8 @@ use std::collections::HashMap;
 /// // Resource characteristics affect capability
 /// assert!(pc.cognitive_ability_effective() > 0.4);
 /// ```
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PersonCharacteristics {
     // --- Resource Characteristics ---
     /// Reasoning and problem-solving ability.
@@ -81,6 +82,7 @@ pub struct PersonCharacteristics {
     curiosity_tendency: StateValue,
 
     /// Domain-specific self-efficacy beliefs (0-1).
+    #[cfg_attr(feature = "serde", serde(serialize_with = "crate::types::sorted_serde::map"))]
     self_efficacy_by_domain: HashMap<String, f32>,
 }
 
@@ -152,6 +154,32 @@ impl PersonCharacteristics {
         }
     }
 
+    /// Creates PersonCharacteristics shaped by a personality profile.
+    ///
+    /// The force characteristics persistence and curiosity follow the
+    /// profile's conscientiousness and openness, social capital follows
+    /// extraversion, and emotional regulation assets follow low
+    /// neuroticism. Other characteristics keep their defaults, so the
+    /// Balanced profile gives the same values as `new()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::enums::PersonalityProfile;
+    /// use behavioral_pathways::state::PersonCharacteristics;
+    ///
+    /// let pc = PersonCharacteristics::from_profile(PersonalityProfile::Conscientious);
+    /// assert!(pc.persistence_tendency_effective() > 0.7);
+    /// ```
+    #[must_use]
+    pub fn from_profile(profile: PersonalityProfile) -> Self {
+        PersonCharacteristics::new()
+            .with_emotional_regulation_assets_base(1.0 - profile.neuroticism())
+            .with_social_capital_base(profile.extraversion())
+            .with_persistence_tendency_base(profile.conscientiousness())
+            .with_curiosity_tendency_base(profile.openness())
+    }
+
     // --- Composite Accessors ---
 
     /// Returns the overall resource characteristic level.
@@ -413,14 +441,23 @@ impl PersonCharacteristics {
 
     /// Applies decay to all person characteristics over the specified duration.
     pub fn apply_decay(&mut self, elapsed: Duration) {
-        self.cognitive_ability.apply_decay(elapsed);
-        self.emotional_regulation_assets.apply_decay(elapsed);
-        self.social_capital.apply_decay(elapsed);
-        self.material_security.apply_decay(elapsed);
-        self.experience_diversity.apply_decay(elapsed);
-        self.baseline_motivation.apply_decay(elapsed);
-        self.persistence_tendency.apply_decay(elapsed);
-        self.curiosity_tendency.apply_decay(elapsed);
+        for value in self.decaying_values_mut() {
+            value.apply_decay(elapsed);
+        }
+    }
+
+    /// Returns every dimension that decays, for decay and its reversal.
+    pub(crate) fn decaying_values_mut(&mut self) -> [&mut StateValue; 8] {
+        [
+            &mut self.cognitive_ability,
+            &mut self.emotional_regulation_assets,
+            &mut self.social_capital,
+            &mut self.material_security,
+            &mut self.experience_diversity,
+            &mut self.baseline_motivation,
+            &mut self.persistence_tendency,
+            &mut self.curiosity_tendency,
+        ]
     }
 
     /// Resets all deltas to zero.
@@ -551,6 +588,17 @@ mod tests {
         assert!(pc.resource() >= 0.3);
     }
 
+    #[test]
+    fn from_profile_follows_profile_traits() {
+        let leader = PersonCharacteristics::from_profile(PersonalityProfile::Leader);
+        assert!((leader.persistence_tendency_effective() - 0.8).abs() < f32::EPSILON);
+        assert!((leader.emotional_regulation_assets_effective() - 0.8).abs() < 1e-6);
+        assert_eq!(
+            PersonCharacteristics::from_profile(PersonalityProfile::Balanced),
+            PersonCharacteristics::new()
+        );
+    }
+
     #[test]
     fn clone_and_equality() {
         let pc1 = PersonCharacteristics::new().with_cognitive_ability_base(0.8);
@@ -0,0 +1,244 @@
+//! Versioned, forward-compatible snapshots of individual state.
+//!
+//! A snapshot is a JSON envelope holding a schema version and the serialized
+//! `IndividualState`:
+//!
+//! ```json
+//! { "schema_version": 1, "state": { "hexaco": { ... }, ... } }
+//! ```
+//!
+//! Snapshots written by one crate version load in later versions under
+//! these rules:
+//!
+//! - A field the snapshot lacks takes the value `IndividualState::new()`
+//!   gives it, and its path is listed in `SnapshotCompatReport::defaulted`.
+//! - A field this version does not know is dropped, and its path is listed
+//!   in `SnapshotCompatReport::ignored`.
+//! - Maps that are empty in the defaults, such as per-domain self-efficacy,
+//!   and values whose JSON type differs from the default (such as an
+//!   optional half-life that is `null` by default) are taken as written.
+//!
+//! Any field added to a serialized state type must therefore be one that
+//! `IndividualState::new()` can supply. Renaming or removing a field drops
+//! its old value, so old fixtures report it as ignored.
+
+use crate::state::IndividualState;
+use serde_json::{Map, Value};
+use std::fmt;
+
+/// Schema version written by `IndividualState::to_snapshot`.
+///
+/// Raised whenever the serialized shape changes in a way the defaulting
+/// rules alone do not cover.
+pub const SNAPSHOT_SCHEMA_VERSION: u32 = 1;
+
+/// Errors from `IndividualState::from_snapshot`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SnapshotError {
+    /// The snapshot is not valid JSON.
+    Parse(String),
+    /// The envelope has no numeric `schema_version` or no `state` object.
+    MissingEnvelope,
+    /// A field holds a value of the wrong type.
+    Invalid(String),
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotError::Parse(message) => write!(f, "Snapshot is not valid JSON: {}", message),
+            SnapshotError::MissingEnvelope => write!(
+                f,
+                "Snapshot needs a numeric schema_version and a state object"
+            ),
+            SnapshotError::Invalid(message) => write!(f, "Snapshot state is invalid: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+/// What loading a snapshot had to fill in or drop.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SnapshotCompatReport {
+    /// Schema version the snapshot was written with.
+    schema_version: u32,
+    /// Dotted paths of fields filled from defaults.
+    defaulted: Vec<String>,
+    /// Dotted paths of unknown fields that were dropped.
+    ignored: Vec<String>,
+}
+
+impl SnapshotCompatReport {
+    /// Returns the schema version the snapshot was written with.
+    #[must_use]
+    pub fn schema_version(&self) -> u32 {
+        self.schema_version
+    }
+
+    /// Returns the dotted paths of fields filled from defaults.
+    #[must_use]
+    pub fn defaulted(&self) -> &[String] {
+        &self.defaulted
+    }
+
+    /// Returns the dotted paths of unknown fields that were dropped.
+    #[must_use]
+    pub fn ignored(&self) -> &[String] {
+        &self.ignored
+    }
+
+    /// Returns true if the snapshot matched this version's schema exactly.
+    #[must_use]
+    pub fn is_exact(&self) -> bool {
+        self.defaulted.is_empty() && self.ignored.is_empty()
+    }
+}
+
+/// Serializes a state into a versioned snapshot.
+pub(crate) fn to_snapshot(state: &IndividualState) -> String {
+    let state = serde_json::to_value(state).expect("individual state serializes");
+    let mut envelope = Map::new();
+    envelope.insert("schema_version".into(), SNAPSHOT_SCHEMA_VERSION.into());
+    envelope.insert("state".into(), state);
+    Value::Object(envelope).to_string()
+}
+
+/// Loads a state from a snapshot, applying the defaulting rules.
+pub(crate) fn from_snapshot(
+    json: &str,
+) -> Result<(IndividualState, SnapshotCompatReport), SnapshotError> {
+    let envelope: Value =
+        serde_json::from_str(json).map_err(|err| SnapshotError::Parse(err.to_string()))?;
+    let schema_version = envelope
+        .get("schema_version")
+        .and_then(Value::as_u64)
+        .and_then(|version| u32::try_from(version).ok())
+        .ok_or(SnapshotError::MissingEnvelope)?;
+    let Some(Value::Object(written)) = envelope.get("state") else {
+        return Err(SnapshotError::MissingEnvelope);
+    };
+
+    let mut report = SnapshotCompatReport {
+        schema_version,
+        ..SnapshotCompatReport::default()
+    };
+    let Value::Object(defaults) =
+        serde_json::to_value(IndividualState::new()).expect("individual state serializes")
+    else {
+        unreachable!("individual state serializes as an object");
+    };
+    let merged = merge(defaults, written, "", &mut report);
+    let state = serde_json::from_value(Value::Object(merged))
+        .map_err(|err| SnapshotError::Invalid(err.to_string()))?;
+    Ok((state, report))
+}
+
+/// Returns `defaults` overlaid with the known fields of `written`.
+fn merge(
+    defaults: Map<String, Value>,
+    written: &Map<String, Value>,
+    prefix: &str,
+    report: &mut SnapshotCompatReport,
+) -> Map<String, Value> {
+    let path = |key: &str| {
+        if prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", prefix, key)
+        }
+    };
+    for key in written.keys().filter(|key| !defaults.contains_key(*key)) {
+        report.ignored.push(path(key));
+    }
+
+    let mut merged = Map::new();
+    for (key, default) in defaults {
+        let value = match (default, written.get(&key)) {
+            (default, None) => {
+                report.defaulted.push(path(&key));
+                default
+            }
+            (Value::Object(default), Some(Value::Object(value))) if !default.is_empty() => {
+                Value::Object(merge(default, value, &path(&key), report))
+            }
+            (_, Some(value)) => value.clone(),
+        };
+        merged.insert(key, value);
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state_json(state: &IndividualState) -> Map<String, Value> {
+        match serde_json::to_value(state).unwrap() {
+            Value::Object(map) => map,
+            _ => unreachable!(),
+        }
+    }
+
+    fn envelope(state: Map<String, Value>) -> String {
+        serde_json::json!({ "schema_version": 1, "state": state }).to_string()
+    }
+
+    #[test]
+    fn round_trips_exactly() {
+        let mut state = IndividualState::new();
+        state.mood_mut().add_valence_delta(-0.4);
+        state.hexaco_mut().set_openness(0.7);
+
+        let (loaded, report) = from_snapshot(&to_snapshot(&state)).unwrap();
+        assert_eq!(loaded, state);
+        assert_eq!(report.schema_version(), SNAPSHOT_SCHEMA_VERSION);
+        assert!(report.is_exact());
+    }
+
+    #[test]
+    fn fills_missing_fields_and_drops_unknown_ones() {
+        let mut state = IndividualState::new();
+        state.needs_mut().add_stress_delta(0.5);
+        let mut json = state_json(&state);
+        json.remove("mood");
+        json["needs"]
+            .as_object_mut()
+            .unwrap()
+            .insert("hydration".into(), Value::from(0.3));
+        json.insert("future_module".into(), Value::Bool(true));
+
+        let (loaded, report) = from_snapshot(&envelope(json)).unwrap();
+        assert_eq!(loaded.mood(), IndividualState::new().mood());
+        assert!((loaded.needs().stress().delta() - 0.5).abs() < f32::EPSILON);
+        assert_eq!(report.defaulted(), ["mood"]);
+        assert_eq!(report.ignored(), ["future_module", "needs.hydration"]);
+    }
+
+    #[test]
+    fn empty_default_maps_keep_written_entries() {
+        let mut json = state_json(&IndividualState::new());
+        json["person_characteristics"]["self_efficacy_by_domain"] =
+            serde_json::json!({ "work": 0.8 });
+        let (loaded, report) = from_snapshot(&envelope(json)).unwrap();
+        assert!(report.is_exact());
+        let efficacy = loaded.person_characteristics().self_efficacy("work");
+        assert!((efficacy.unwrap() - 0.8).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn rejects_malformed_snapshots() {
+        assert!(matches!(
+            from_snapshot("{not json"),
+            Err(SnapshotError::Parse(_))
+        ));
+        assert_eq!(
+            from_snapshot(r#"{"state": {}}"#).unwrap_err(),
+            SnapshotError::MissingEnvelope
+        );
+        assert!(matches!(
+            from_snapshot(r#"{"schema_version": 1, "state": {"hexaco": {"openness": "high"}}}"#),
+            Err(SnapshotError::Invalid(_))
+        ));
+    }
+}
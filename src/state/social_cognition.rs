@@ -3,15 +3,15 @@
 //! This module captures interpersonal beliefs and perceptions that feed
 //! ITS computations, distinct from physiological needs.
 
-use crate::state::StateValue;
+use crate::state::{DeltaBreakdown, StateValue};
 use crate::types::Duration;
-use serde::{Deserialize, Serialize};
 
 /// Social cognition dimensions.
 ///
 /// These represent beliefs about social belonging, burdensomeness,
 /// and self-perception that influence mental health.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SocialCognition {
     /// Social isolation - feeling disconnected from others.
     /// Range: 0 (connected) to 1 (isolated)
@@ -205,6 +205,59 @@ impl SocialCognition {
         &self.perceived_competence
     }
 
+    // Delta breakdowns
+
+    /// Returns the loneliness delta split into acute and chronic parts.
+    ///
+    /// A high chronicity ratio means the loneliness is an entrenched
+    /// condition rather than a passing mood.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::state::SocialCognition;
+    ///
+    /// let mut social = SocialCognition::new();
+    /// social.add_loneliness_delta(0.1);
+    /// social.loneliness_mut().add_chronic_delta(0.3);
+    ///
+    /// let breakdown = social.loneliness_breakdown();
+    /// assert!((breakdown.acute - 0.1).abs() < 1e-6);
+    /// assert!((breakdown.chronic - 0.3).abs() < 1e-6);
+    /// assert!(breakdown.chronicity_ratio() > 0.5);
+    /// ```
+    #[must_use]
+    pub fn loneliness_breakdown(&self) -> DeltaBreakdown {
+        self.loneliness.breakdown()
+    }
+
+    /// Returns the perceived reciprocal caring delta split into acute and
+    /// chronic parts.
+    #[must_use]
+    pub fn perceived_reciprocal_caring_breakdown(&self) -> DeltaBreakdown {
+        self.perceived_reciprocal_caring.breakdown()
+    }
+
+    /// Returns the perceived liability delta split into acute and chronic
+    /// parts.
+    #[must_use]
+    pub fn perceived_liability_breakdown(&self) -> DeltaBreakdown {
+        self.perceived_liability.breakdown()
+    }
+
+    /// Returns the self-hate delta split into acute and chronic parts.
+    #[must_use]
+    pub fn self_hate_breakdown(&self) -> DeltaBreakdown {
+        self.self_hate.breakdown()
+    }
+
+    /// Returns the perceived competence delta split into acute and chronic
+    /// parts.
+    #[must_use]
+    pub fn perceived_competence_breakdown(&self) -> DeltaBreakdown {
+        self.perceived_competence.breakdown()
+    }
+
     /// Returns a mutable reference to the loneliness StateValue.
     pub fn loneliness_mut(&mut self) -> &mut StateValue {
         &mut self.loneliness
@@ -261,11 +314,20 @@ impl SocialCognition {
 
     /// Applies decay to all social cognition dimensions.
     pub fn apply_decay(&mut self, elapsed: Duration) {
-        self.loneliness.apply_decay(elapsed);
-        self.perceived_reciprocal_caring.apply_decay(elapsed);
-        self.perceived_liability.apply_decay(elapsed);
-        self.self_hate.apply_decay(elapsed);
-        self.perceived_competence.apply_decay(elapsed);
+        for value in self.decaying_values_mut() {
+            value.apply_decay(elapsed);
+        }
+    }
+
+    /// Returns every dimension that decays, for decay and its reversal.
+    pub(crate) fn decaying_values_mut(&mut self) -> [&mut StateValue; 5] {
+        [
+            &mut self.loneliness,
+            &mut self.perceived_reciprocal_caring,
+            &mut self.perceived_liability,
+            &mut self.self_hate,
+            &mut self.perceived_competence,
+        ]
     }
 
     /// Resets all deltas to zero.
@@ -370,6 +432,23 @@ mod tests {
         assert!(social.perceived_competence().delta().abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn breakdowns_split_each_dimension() {
+        let mut social = SocialCognition::new();
+        social
+            .perceived_reciprocal_caring_mut()
+            .add_chronic_delta(-0.2);
+        social.add_perceived_liability_delta(0.1);
+        social.self_hate_mut().add_chronic_delta(0.1);
+        social.add_perceived_competence_delta(0.05);
+
+        assert!(social.loneliness_breakdown().chronicity_ratio().abs() < f32::EPSILON);
+        assert!((social.perceived_reciprocal_caring_breakdown().chronic + 0.2).abs() < 1e-6);
+        assert!((social.perceived_liability_breakdown().acute - 0.1).abs() < 1e-6);
+        assert!((social.self_hate_breakdown().chronicity_ratio() - 1.0).abs() < 1e-6);
+        assert!((social.perceived_competence_breakdown().total() - 0.05).abs() < 1e-6);
+    }
+
     #[test]
     fn default_equals_new() {
         let d = SocialCognition::default();
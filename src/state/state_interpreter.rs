@@ -1,8 +1,13 @@
 //! State interpretation - converts psychological dimensions to human-readable text.
 
+use crate::enums::{MentalHealthPath, MoodPath, NeedsPath, SocialCognitionPath, StatePath};
+use crate::simulation::StateDiff;
 use crate::state::IndividualState;
 use std::collections::HashMap;
 
+/// Describes the change from a baseline value to a current one.
+type DeltaDescriber = fn(f32, f32) -> Option<String>;
+
 /// Interprets psychological state as human-readable text.
 pub struct StateInterpreter {
     interpretations: HashMap<String, String>,
@@ -74,7 +79,11 @@ impl StateInterpreter {
         );
 
         // Build summary
-        let summary = Self::build_summary(&interpretations);
+        let mut summary = Self::build_summary(&interpretations);
+        let chronic = Self::chronic_notes(state);
+        if !chronic.is_empty() {
+            summary = format!("{} {}", summary, Self::build_sentences(&chronic));
+        }
 
         StateInterpreter {
             interpretations,
@@ -85,64 +94,26 @@ impl StateInterpreter {
 
     /// Creates a new interpreter with delta from baseline.
     pub fn from_state_with_baseline(state: &IndividualState, baseline: &IndividualState) -> Self {
-        let mut interpretations = HashMap::new();
-        let mut deltas = Vec::new();
-
-        let valence = state.mood().valence_effective();
-        let baseline_valence = baseline.mood().valence_effective();
-        interpretations.insert("valence".to_string(), Self::interpret_valence(valence));
-        if let Some(delta) = Self::delta_valence(valence, baseline_valence) { deltas.push(delta); }
-
-        let arousal = state.mood().arousal_effective();
-        let baseline_arousal = baseline.mood().arousal_effective();
-        interpretations.insert("arousal".to_string(), Self::interpret_arousal(arousal));
-        if let Some(delta) = Self::delta_arousal(arousal, baseline_arousal) { deltas.push(delta); }
-
-        let dominance = state.mood().dominance_effective();
-        let baseline_dominance = baseline.mood().dominance_effective();
-        interpretations.insert("dominance".to_string(), Self::interpret_dominance(dominance));
-        if let Some(delta) = Self::delta_dominance(dominance, baseline_dominance) { deltas.push(delta); }
-
-        let stress = state.needs().stress_effective();
-        let baseline_stress = baseline.needs().stress_effective();
-        interpretations.insert("stress".to_string(), Self::interpret_stress(stress));
-        if let Some(delta) = Self::delta_stress(stress, baseline_stress) { deltas.push(delta); }
-
-        let fatigue = state.needs().fatigue_effective();
-        let baseline_fatigue = baseline.needs().fatigue_effective();
-        interpretations.insert("fatigue".to_string(), Self::interpret_fatigue(fatigue));
-        if let Some(delta) = Self::delta_fatigue(fatigue, baseline_fatigue) { deltas.push(delta); }
-
-        let purpose = state.needs().purpose_effective();
-        let baseline_purpose = baseline.needs().purpose_effective();
-        interpretations.insert("purpose".to_string(), Self::interpret_purpose(purpose));
-        if let Some(delta) = Self::delta_purpose(purpose, baseline_purpose) { deltas.push(delta); }
-
-        let loneliness = state.social_cognition().loneliness_effective();
-        let baseline_loneliness = baseline.social_cognition().loneliness_effective();
-        interpretations.insert("loneliness".to_string(), Self::interpret_loneliness(loneliness));
-        if let Some(delta) = Self::delta_loneliness(loneliness, baseline_loneliness) { deltas.push(delta); }
-        let prc = state.social_cognition().perceived_reciprocal_caring_effective();
-        let baseline_prc = baseline.social_cognition().perceived_reciprocal_caring_effective();
-        interpretations.insert("perceived_reciprocal_caring".to_string(), Self::interpret_perceived_reciprocal_caring(prc));
-        if let Some(delta) = Self::delta_perceived_reciprocal_caring(prc, baseline_prc) { deltas.push(delta); }
-        let depression = state.mental_health().depression_effective();
-        let baseline_depression = baseline.mental_health().depression_effective();
-        interpretations.insert("depression".to_string(), Self::interpret_depression(depression));
-        if let Some(delta) = Self::delta_depression(depression, baseline_depression) { deltas.push(delta); }
-
-        let summary = Self::build_summary(&interpretations);
-        let delta_summary = if deltas.is_empty() {
-            None
-        } else {
-            Some(deltas.join(". ") + ".")
-        };
+        Self::from_state_with_diff(state, &StateDiff::between(baseline, state))
+    }
 
-        StateInterpreter {
-            interpretations,
-            summary,
-            delta_summary,
+    /// Creates a new interpreter whose delta summary narrates `diff`.
+    ///
+    /// The summary is generated from the values in `diff`, so the prose and
+    /// the numbers cannot disagree.
+    pub fn from_state_with_diff(state: &IndividualState, diff: &StateDiff) -> Self {
+        let mut interpreter = Self::from_state(state);
+        let deltas: Vec<String> = Self::narrated_paths()
+            .into_iter()
+            .filter_map(|(path, describe)| {
+                let change = diff.change(path)?;
+                describe(change.after as f32, change.before as f32)
+            })
+            .collect();
+        if !deltas.is_empty() {
+            interpreter.delta_summary = Some(deltas.join(". ") + ".");
         }
+        interpreter
     }
 
     /// Returns all interpretations.
@@ -294,6 +265,62 @@ impl StateInterpreter {
     fn delta_perceived_reciprocal_caring(current: f32, baseline: f32) -> Option<String> { Self::build_delta_description(current, baseline, "more cared for", "less cared for") }
     fn delta_depression(current: f32, baseline: f32) -> Option<String> { Self::build_delta_description(current, baseline, "more depressed", "less depressed") }
 
+    /// Paths narrated in the delta summary, in order, with their
+    /// descriptions.
+    fn narrated_paths() -> [(StatePath, DeltaDescriber); 9] {
+        [
+            (StatePath::Mood(MoodPath::Valence), Self::delta_valence),
+            (StatePath::Mood(MoodPath::Arousal), Self::delta_arousal),
+            (StatePath::Mood(MoodPath::Dominance), Self::delta_dominance),
+            (StatePath::Needs(NeedsPath::Stress), Self::delta_stress),
+            (StatePath::Needs(NeedsPath::Fatigue), Self::delta_fatigue),
+            (StatePath::Needs(NeedsPath::Purpose), Self::delta_purpose),
+            (StatePath::SocialCognition(SocialCognitionPath::Loneliness), Self::delta_loneliness),
+            (
+                StatePath::SocialCognition(SocialCognitionPath::PerceivedReciprocalCaring),
+                Self::delta_perceived_reciprocal_caring,
+            ),
+            (StatePath::MentalHealth(MentalHealthPath::Depression), Self::delta_depression),
+        ]
+    }
+
+    /// Chronicity ratio above which a dimension is largely chronic.
+    const LARGELY_CHRONIC_RATIO: f32 = 0.6;
+
+    /// Notes for the social cognition dimensions whose deviation is
+    /// largely chronic rather than a passing reaction.
+    fn chronic_notes(state: &IndividualState) -> Vec<String> {
+        let social = state.social_cognition();
+        [
+            ("loneliness", social.loneliness_breakdown()),
+            ("perceived caring", social.perceived_reciprocal_caring_breakdown()),
+            ("feeling like a burden", social.perceived_liability_breakdown()),
+            ("self-hate", social.self_hate_breakdown()),
+            ("perceived competence", social.perceived_competence_breakdown()),
+        ]
+        .into_iter()
+        .filter(|(_, breakdown)| {
+            breakdown.total().abs() >= Self::MIN_DELTA_THRESHOLD
+                && breakdown.chronicity_ratio() > Self::LARGELY_CHRONIC_RATIO
+        })
+        .map(|(name, _)| format!("{} is largely chronic", name))
+        .collect()
+    }
+
+    /// Joins phrases into capitalized sentences.
+    fn build_sentences(phrases: &[String]) -> String {
+        let sentences: Vec<String> = phrases
+            .iter()
+            .map(|s| {
+                let mut chars = s.chars();
+                chars.next().map_or_else(String::new, |first| {
+                    first.to_uppercase().chain(chars).collect()
+                })
+            })
+            .collect();
+        sentences.join(". ") + "."
+    }
+
     fn build_summary(interpretations: &HashMap<String, String>) -> String {
         // Build in a consistent order
         let order = vec![
@@ -308,12 +335,11 @@ impl StateInterpreter {
             "depression",
         ];
 
-        let sentences: Vec<String> = order
+        let phrases: Vec<String> = order
             .iter()
-            .filter_map(|key| interpretations.get(*key))
-            .map(|s| { let mut chars = s.chars(); chars.next().map_or_else(String::new, |first| first.to_uppercase().chain(chars).collect()) })
+            .filter_map(|key| interpretations.get(*key).cloned())
             .collect();
-        sentences.join(". ") + "."
+        Self::build_sentences(&phrases)
     }
 }
 
@@ -330,6 +356,22 @@ mod tests {
         assert_eq!(summary, ".");
     }
 
+    #[test]
+    fn summary_notes_largely_chronic_dimensions() {
+        let mut state = IndividualState::new();
+        let social = state.social_cognition_mut();
+        social.add_loneliness_delta(0.05);
+        social.loneliness_mut().add_chronic_delta(0.3);
+        social.add_self_hate_delta(0.3);
+        social.self_hate_mut().add_chronic_delta(0.1);
+        social.perceived_liability_mut().add_chronic_delta(0.005);
+
+        let summary = StateInterpreter::from_state(&state).summary().to_string();
+        assert!(summary.ends_with(". Loneliness is largely chronic."));
+        assert!(!summary.contains("Self-hate"));
+        assert!(!summary.contains("burden"));
+    }
+
     #[test]
     fn build_summary_capitalizes_first_letter() {
         let mut interpretations = HashMap::new();
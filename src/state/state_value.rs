@@ -7,11 +7,42 @@
 //! - Optional bounds (min/max for the effective value)
 
 use crate::types::Duration;
-use serde::{Deserialize, Serialize};
 
 /// Chronic deltas decay more slowly than acute deltas.
 const CHRONIC_HALF_LIFE_MULTIPLIER: u64 = 4;
 
+/// The delta of a `StateValue` split into its acute and chronic parts.
+///
+/// Acute deltas come from passing events and decay at the value's
+/// half-life; chronic deltas come from events tagged as chronic patterns
+/// and decay `CHRONIC_HALF_LIFE_MULTIPLIER` times slower.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DeltaBreakdown {
+    /// Deviation from passing events.
+    pub acute: f32,
+    /// Deviation from chronic patterns.
+    pub chronic: f32,
+}
+
+impl DeltaBreakdown {
+    /// Returns the whole delta (acute + chronic).
+    #[must_use]
+    pub fn total(&self) -> f32 {
+        self.acute + self.chronic
+    }
+
+    /// Returns the share of the delta's magnitude that is chronic (0-1),
+    /// or 0 if there is no delta.
+    #[must_use]
+    pub fn chronicity_ratio(&self) -> f32 {
+        let magnitude = self.acute.abs() + self.chronic.abs();
+        if magnitude <= f32::EPSILON {
+            return 0.0;
+        }
+        self.chronic.abs() / magnitude
+    }
+}
+
 /// A psychological state value with base, delta, and decay behavior.
 ///
 /// The effective value is `base + delta`, clamped to bounds if set.
@@ -42,7 +73,8 @@ const CHRONIC_HALF_LIFE_MULTIPLIER: u64 = 4;
 /// stress.apply_decay(Duration::days(3));
 /// assert!((stress.effective() - 0.5).abs() < 0.01); // Half of delta decayed
 /// ```
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StateValue {
     /// The stable baseline value (personality/trait).
     base: f32,
@@ -240,12 +272,74 @@ impl StateValue {
         self.delta + self.chronic_delta
     }
 
+    /// Returns the acute part of the delta, added with `add_delta`.
+    #[must_use]
+    pub fn acute_delta(&self) -> f32 {
+        self.delta
+    }
+
+    /// Returns the chronic part of the delta, added with
+    /// `add_chronic_delta`.
+    #[must_use]
+    pub fn chronic_delta(&self) -> f32 {
+        self.chronic_delta
+    }
+
+    /// Returns the share of the delta's magnitude that is chronic (0-1),
+    /// or 0 if there is no delta.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::state::StateValue;
+    ///
+    /// let mut loneliness = StateValue::new(0.2);
+    /// loneliness.add_delta(0.1);
+    /// loneliness.add_chronic_delta(0.3);
+    /// assert!((loneliness.delta() - 0.4).abs() < 1e-6);
+    /// assert!((loneliness.chronicity_ratio() - 0.75).abs() < 1e-6);
+    /// ```
+    #[must_use]
+    pub fn chronicity_ratio(&self) -> f32 {
+        self.breakdown().chronicity_ratio()
+    }
+
+    /// Returns the delta split into its acute and chronic parts.
+    #[must_use]
+    pub fn breakdown(&self) -> DeltaBreakdown {
+        DeltaBreakdown {
+            acute: self.delta,
+            chronic: self.chronic_delta,
+        }
+    }
+
     /// Returns the decay half-life, or None if this value never decays.
     #[must_use]
     pub fn decay_half_life(&self) -> Option<Duration> {
         self.decay_half_life
     }
 
+    /// Returns the half-life of the chronic delta, or None if it never
+    /// decays.
+    #[must_use]
+    pub(crate) fn chronic_half_life(&self) -> Option<Duration> {
+        self.decay_half_life
+            .filter(|half_life| !half_life.is_zero())
+            .map(|half_life| half_life * CHRONIC_HALF_LIFE_MULTIPLIER)
+    }
+
+    /// Moves up to `amount` of the chronic delta into the base.
+    ///
+    /// The effective value is unchanged. The base stays within the bounds,
+    /// so less may move; returns the amount moved.
+    pub(crate) fn absorb_chronic_delta(&mut self, amount: f32) -> f32 {
+        let base = (self.base + amount).clamp(self.min_bound, self.max_bound);
+        let moved = base - self.base;
+        self.base = base;
+        self.chronic_delta -= moved;
+        moved
+    }
+
     /// Returns true if this value decays over time.
     #[must_use]
     pub fn decays(&self) -> bool {
@@ -307,16 +401,19 @@ impl StateValue {
         self.base + self.delta + self.chronic_delta
     }
 
-    /// Sets the base value.
+    /// Sets the base value, clamped to the bounds.
     ///
-    /// This is typically only done at entity creation.
+    /// This is typically only done at entity creation. A non-finite base
+    /// fails a debug assertion.
     pub fn set_base(&mut self, base: f32) {
-        self.base = base;
+        self.base = self.clamp_input(base, self.min_bound, self.max_bound);
     }
 
     /// Adds to the delta value.
     ///
-    /// Delta accumulates from acute events.
+    /// Delta accumulates from acute events. A single amount is clamped to
+    /// the width of the bounds, since no larger change can be observed, and
+    /// a non-finite amount fails a debug assertion.
     ///
     /// # Arguments
     ///
@@ -333,14 +430,32 @@ impl StateValue {
     /// assert!((value.delta() - 0.3).abs() < f32::EPSILON);
     /// ```
     pub fn add_delta(&mut self, amount: f32) {
-        self.delta += amount;
+        self.delta += self.clamp_amount(amount);
     }
 
     /// Adds to the chronic delta value.
     ///
-    /// Chronic deltas decay more slowly than acute deltas.
+    /// Chronic deltas decay more slowly than acute deltas. The amount is
+    /// clamped as in `add_delta`.
     pub fn add_chronic_delta(&mut self, amount: f32) {
-        self.chronic_delta += amount;
+        self.chronic_delta += self.clamp_amount(amount);
+    }
+
+    /// Clamps a delta amount to the width of the bounds.
+    fn clamp_amount(&self, amount: f32) -> f32 {
+        let width = self.max_bound - self.min_bound;
+        self.clamp_input(amount, -width, width)
+    }
+
+    /// Clamps a runtime input to `min..=max`, asserting it is finite.
+    ///
+    /// In release builds NaN becomes `min`.
+    fn clamp_input(&self, value: f32, min: f32, max: f32) -> f32 {
+        debug_assert!(value.is_finite(), "non-finite input: {value}");
+        if value.is_nan() {
+            return min;
+        }
+        value.clamp(min, max)
     }
 
     /// Sets the delta value directly.
@@ -405,6 +520,27 @@ impl StateValue {
         self.chronic_delta *= chronic_decay_factor as f32;
     }
 
+    /// Reverses `apply_decay` over the specified duration.
+    ///
+    /// The acute and chronic deltas grow back by the factors decay shrank
+    /// them by, using the same half-lives. Values that never decay are
+    /// unchanged. Reversals too large to represent are skipped, and results
+    /// are clamped to +/-100.
+    pub(crate) fn reverse_decay(&mut self, elapsed: Duration) {
+        let half_life = match self.decay_half_life {
+            Some(hl) => hl,
+            None => return,
+        };
+
+        if half_life.is_zero() || elapsed.is_zero() {
+            return;
+        }
+
+        self.delta = reverse_decayed(self.delta, elapsed, half_life);
+        let chronic_half_life = half_life * CHRONIC_HALF_LIFE_MULTIPLIER;
+        self.chronic_delta = reverse_decayed(self.chronic_delta, elapsed, chronic_half_life);
+    }
+
     /// Resets delta to zero.
     pub fn reset_delta(&mut self) {
         self.delta = 0.0;
@@ -412,6 +548,25 @@ impl StateValue {
     }
 }
 
+/// Returns `delta` before `elapsed` of decay at `half_life`.
+fn reverse_decayed(delta: f32, elapsed: Duration, half_life: Duration) -> f32 {
+    // Tiny deltas are still reversed, since they grow back
+    if delta == 0.0 {
+        return delta;
+    }
+
+    // factor = 2^(elapsed / half_life), the inverse of apply_decay's factor
+    let exponent =
+        std::f64::consts::LN_2 * elapsed.as_seconds() as f64 / half_life.as_seconds() as f64;
+
+    // Guard against overflow
+    if exponent > 700.0 {
+        return delta;
+    }
+
+    (f64::from(delta) * exponent.exp()).clamp(-100.0, 100.0) as f32
+}
+
 impl Default for StateValue {
     fn default() -> Self {
         StateValue::new(0.5)
@@ -470,6 +625,24 @@ mod tests {
         assert!((value.delta() - 0.5).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn breakdown_separates_acute_and_chronic() {
+        let mut value = StateValue::new(0.5);
+        assert!(value.chronicity_ratio().abs() < f32::EPSILON);
+
+        value.add_delta(-0.1);
+        value.add_chronic_delta(0.3);
+        let breakdown = value.breakdown();
+        assert!((value.acute_delta() + 0.1).abs() < f32::EPSILON);
+        assert!((value.chronic_delta() - 0.3).abs() < f32::EPSILON);
+        assert!((breakdown.total() - value.delta()).abs() < f32::EPSILON);
+        // Opposite signs still count by magnitude
+        assert!((breakdown.chronicity_ratio() - 0.75).abs() < 1e-6);
+
+        value.set_delta(0.2);
+        assert!(value.chronicity_ratio().abs() < f32::EPSILON);
+    }
+
     #[test]
     fn chronic_delta_decays_slower_than_acute() {
         let mut value = StateValue::new(0.5)
@@ -569,6 +742,37 @@ mod tests {
         assert!((value.base() - 0.7).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn set_base_clamps_to_bounds() {
+        let mut value = StateValue::new(0.5).with_bounds(-1.0, 1.0);
+        value.set_base(1.5);
+        assert!((value.base() - 1.0).abs() < f32::EPSILON);
+        value.set_base(-0.25);
+        assert!((value.base() - (-0.25)).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn add_delta_clamps_amount_to_bound_width() {
+        let mut value = StateValue::new(0.5);
+        value.add_delta(5.0);
+        assert!((value.delta() - 1.0).abs() < f32::EPSILON);
+
+        // Accumulated delta may still exceed the width
+        value.add_delta(0.5);
+        assert!((value.delta() - 1.5).abs() < f32::EPSILON);
+
+        let mut signed = StateValue::new(0.0).with_bounds(-1.0, 1.0);
+        signed.add_chronic_delta(-3.0);
+        assert!((signed.effective_raw() - (-2.0)).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "non-finite input")]
+    fn add_delta_asserts_finite_in_debug_builds() {
+        StateValue::new(0.5).add_delta(f32::NAN);
+    }
+
     #[test]
     fn custom_bounds() {
         let value = StateValue::new(0.0).with_bounds(-1.0, 1.0).with_delta(0.5);
@@ -731,4 +935,183 @@ mod tests {
         assert_eq!(value.decay_half_life().unwrap().as_days(), 5);
         assert!(value.decays());
     }
+
+    #[test]
+    fn reverse_decay_handles_zero_half_life() {
+        // A zero half-life returns early without modification
+        let mut state_value = StateValue::new(0.5).with_delta(0.3);
+        let original_delta = state_value.delta();
+        let duration = Duration::days(1);
+        let half_life = Duration::zero(); // Zero half-life triggers early return
+
+        state_value.set_decay_half_life(half_life);
+        state_value.reverse_decay(duration);
+
+        // Delta should be unchanged because function returned early
+        assert!((state_value.delta() - original_delta).abs() < 0.001);
+    }
+
+    #[test]
+    fn reverse_decay_handles_extremely_long_duration() {
+        // Test the exponent > 700 early return
+        // This guards against exp() overflow with very long durations.
+        // exponent = ln(2) * elapsed / half_life
+        // For exponent > 700, we need elapsed / half_life > 1010
+        // With 1 day half-life, 1010+ days elapsed triggers this.
+        let mut state_value = StateValue::new(0.5).with_delta(0.3);
+        let original_delta = state_value.delta();
+        let duration = Duration::years(10); // Very long duration
+        let half_life = Duration::days(1); // 1 day half-life
+
+        // exponent = ln(2) * (10*365*24*60*60) / (24*60*60)
+        // exponent = ln(2) * 3650 = 2530 which is > 700
+        state_value.set_decay_half_life(half_life);
+        state_value.reverse_decay(duration);
+
+        // Delta should be unchanged because function returned early due to overflow guard
+        assert!((state_value.delta() - original_delta).abs() < 0.001);
+    }
+
+    #[test]
+    fn reverse_decay_with_negative_delta() {
+        let mut state_value = StateValue::new(0.5).with_delta(-0.3);
+        let original_delta = state_value.delta();
+        let duration = Duration::hours(6);
+        let half_life = Duration::hours(6);
+
+        state_value.set_decay_half_life(half_life);
+        state_value.reverse_decay(duration);
+
+        // Negative delta should double in magnitude (reversal)
+        assert!(state_value.delta() < original_delta);
+        assert!(state_value.delta().abs() > original_delta.abs() * 0.9);
+    }
+
+    #[test]
+    fn reverse_decay_with_extremely_small_delta() {
+        let mut state_value = StateValue::new(0.5).with_delta(1e-10);
+        let original_delta = state_value.delta();
+        let duration = Duration::hours(6);
+        let half_life = Duration::hours(6);
+
+        state_value.set_decay_half_life(half_life);
+        state_value.reverse_decay(duration);
+
+        // Tiny deltas double over one half-life like any other
+        assert!((state_value.delta() - original_delta * 2.0).abs() < 1e-15);
+    }
+
+    #[test]
+    fn reverse_decay_clamping_large_reversal() {
+        let mut state_value = StateValue::new(0.5).with_delta(10.0);
+        let duration = Duration::years(1);
+        let half_life = Duration::hours(6);
+
+        state_value.set_decay_half_life(half_life);
+        state_value.reverse_decay(duration);
+
+        // Should be clamped to 100.0 due to clamp(-100.0, 100.0)
+        assert!(state_value.delta() <= 100.0);
+        assert!(state_value.delta() >= -100.0);
+    }
+
+    #[test]
+    fn reverse_decay_exponent_overflow_guard() {
+        let mut state_value = StateValue::new(0.5).with_delta(10.0);
+        let duration = Duration::years(100);
+        let half_life = Duration::hours(1);
+
+        state_value.set_decay_half_life(half_life);
+        state_value.reverse_decay(duration);
+
+        assert!(state_value.delta().is_finite());
+    }
+
+    #[test]
+    fn reverse_decay_very_long_half_life() {
+        // Test edge case: very long half_life with short duration should barely change delta
+        let mut state_value = StateValue::new(0.5).with_delta(0.3);
+        let duration = Duration::seconds(1);
+        let half_life = Duration::days(1000); // Very long half-life relative to duration
+
+        // This test primarily verifies the function handles extreme values gracefully
+        state_value.set_decay_half_life(half_life);
+        state_value.reverse_decay(duration);
+        assert!(state_value.delta().is_finite());
+        // Delta should be nearly the same since only 1 second elapsed vs 1000 day half-life
+        assert!(state_value.delta() > 0.29);
+    }
+
+    #[test]
+    fn reverse_decay_very_small_delta() {
+        // Test that very small deltas are handled correctly
+        let mut state_value = StateValue::new(0.5).with_delta(1e-10);
+        let duration = Duration::hours(1);
+        let half_life = Duration::hours(6);
+
+        state_value.set_decay_half_life(half_life);
+        state_value.reverse_decay(duration);
+
+        // Should not cause underflow or NaN
+        assert!(state_value.delta().is_finite());
+    }
+
+    #[test]
+    fn reverse_decay_overflow_guard() {
+        // Test that extremely large duration doesn't cause overflow
+        // This tests the guard: if exponent > 700.0
+        let mut state_value = StateValue::new(0.5).with_delta(0.1);
+        let duration = Duration::days(365 * 1000); // 1000 years - extreme case
+        let half_life = Duration::hours(6);
+
+        state_value.set_decay_half_life(half_life);
+        state_value.reverse_decay(duration);
+
+        // Should not cause overflow - delta should remain unchanged due to guard
+        assert!((state_value.delta() - 0.1).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn reverse_decay_undoes_apply_decay() {
+        let mut value = StateValue::new(0.5)
+            .with_delta(0.4)
+            .with_decay_half_life(Duration::hours(6));
+        value.add_chronic_delta(0.2);
+        let original = value.clone();
+
+        value.apply_decay(Duration::days(1));
+        value.reverse_decay(Duration::days(1));
+        assert!((value.delta() - original.delta()).abs() < 1e-5);
+        // The chronic part comes back on its own, slower half-life
+        value.reset_delta();
+        value.add_chronic_delta(0.2);
+        value.apply_decay(Duration::days(1));
+        value.reverse_decay(Duration::days(1));
+        assert!((value.delta() - 0.2).abs() < 1e-5);
+    }
+
+    #[test]
+    fn absorb_chronic_delta_keeps_effective_value() {
+        let mut value = StateValue::new(0.3).with_decay_half_life(Duration::days(1));
+        value.add_chronic_delta(0.2);
+        let effective = value.effective();
+
+        let moved = value.absorb_chronic_delta(0.05);
+        assert!((moved - 0.05).abs() < f32::EPSILON);
+        assert!((value.base() - 0.35).abs() < 1e-6);
+        assert!((value.chronic_delta() - 0.15).abs() < 1e-6);
+        assert!((value.effective() - effective).abs() < 1e-6);
+        assert_eq!(value.chronic_half_life(), Some(Duration::days(4)));
+    }
+
+    #[test]
+    fn absorb_chronic_delta_stops_at_bounds() {
+        let mut value = StateValue::new(0.95);
+        value.add_chronic_delta(0.2);
+
+        let moved = value.absorb_chronic_delta(0.1);
+        assert!((moved - 0.05).abs() < 1e-6);
+        assert!((value.base() - 1.0).abs() < f32::EPSILON);
+        assert!(StateValue::new_no_decay(0.0).chronic_half_life().is_none());
+    }
 }
@@ -0,0 +1,568 @@
+//! Canonical scenarios for regression testing.
+//!
+//! Each scenario is a fixed simulation whose monthly trajectory over
+//! `GOLDEN_PATHS` is checked in as a golden CSV under `tests/golden/`.
+//! The crate's own golden tests compare against those files; downstream
+//! users can run the same scenarios against their forks or configurations
+//! and compare with `Trajectory::max_deviations()`.
+//!
+//! Scenarios use fixed dates and event IDs so their trajectories are
+//! deterministic.
+
+use crate::context::{
+    EcologicalContext, FamilyContext, InteractionProfile, Microsystem, WorkContext,
+};
+use crate::entity::{Entity, EntityBuilder};
+use crate::enums::{
+    Audience, EventPayload, EventType, HexacoPath, MentalHealthPath, MoodPath, NeedsPath,
+    SocialCognitionPath, Species, StatePath, SupportType,
+};
+use crate::event::EventBuilder;
+use crate::simulation::Simulation;
+use crate::types::{Duration, EntityId, EventId, MicrosystemId, Timestamp};
+use std::fmt;
+
+/// State paths recorded in golden trajectories.
+pub const GOLDEN_PATHS: [StatePath; 10] = [
+    StatePath::Mood(MoodPath::Valence),
+    StatePath::Mood(MoodPath::Arousal),
+    StatePath::Needs(NeedsPath::Stress),
+    StatePath::Needs(NeedsPath::Purpose),
+    StatePath::SocialCognition(SocialCognitionPath::Loneliness),
+    StatePath::SocialCognition(SocialCognitionPath::PerceivedLiability),
+    StatePath::MentalHealth(MentalHealthPath::Depression),
+    StatePath::MentalHealth(MentalHealthPath::AcquiredCapability),
+    StatePath::MentalHealth(MentalHealthPath::ThwartedBelongingness),
+    StatePath::Hexaco(HexacoPath::Neuroticism),
+];
+
+/// Largest deviation from a golden value treated as unchanged behavior.
+pub const GOLDEN_TOLERANCE: f64 = 1e-6;
+
+/// A canonical simulation with a fixed observation window.
+#[derive(Debug, Clone)]
+pub struct Scenario {
+    /// Stable name, used as the golden file name.
+    name: &'static str,
+    /// What the scenario exercises.
+    description: &'static str,
+    /// The simulation to sample.
+    simulation: Simulation,
+    /// The entity whose trajectory is recorded.
+    entity_id: EntityId,
+    /// Number of months sampled after the reference date.
+    months: u64,
+}
+
+impl Scenario {
+    /// Returns the scenario's stable name.
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns a one-line description of what the scenario exercises.
+    #[must_use]
+    pub fn description(&self) -> &'static str {
+        self.description
+    }
+
+    /// Returns the scenario's simulation.
+    #[must_use]
+    pub fn simulation(&self) -> &Simulation {
+        &self.simulation
+    }
+
+    /// Returns the entity whose trajectory is recorded.
+    #[must_use]
+    pub fn entity_id(&self) -> &EntityId {
+        &self.entity_id
+    }
+
+    /// Returns the number of months sampled after the reference date.
+    #[must_use]
+    pub fn months(&self) -> u64 {
+        self.months
+    }
+
+    /// Samples `GOLDEN_PATHS` monthly from the reference date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::test_scenarios::{self, GOLDEN_PATHS};
+    ///
+    /// let scenario = test_scenarios::chronic_exclusion();
+    /// let trajectory = scenario.trajectory();
+    /// assert_eq!(trajectory.len() as u64, scenario.months() + 1);
+    /// assert_eq!(trajectory.paths(), &GOLDEN_PATHS[..]);
+    /// ```
+    #[must_use]
+    pub fn trajectory(&self) -> Trajectory {
+        let handle = self
+            .simulation
+            .entity(&self.entity_id)
+            .expect("scenario entity is in its simulation");
+        let start = self.simulation.reference_date();
+        let rows = (0..=self.months)
+            .map(|month| {
+                let state = handle.state_at(start + Duration::months(month));
+                GOLDEN_PATHS
+                    .iter()
+                    .map(|path| state.get_effective(*path))
+                    .collect()
+            })
+            .collect();
+
+        Trajectory {
+            paths: GOLDEN_PATHS.to_vec(),
+            rows,
+        }
+    }
+}
+
+/// Monthly samples of several state paths.
+///
+/// Row `i` holds the values `i` months after the reference date, in the
+/// order of `paths()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trajectory {
+    /// Column order.
+    paths: Vec<StatePath>,
+    /// One row per month.
+    rows: Vec<Vec<f64>>,
+}
+
+impl Trajectory {
+    /// Returns the recorded paths, in column order.
+    #[must_use]
+    pub fn paths(&self) -> &[StatePath] {
+        &self.paths
+    }
+
+    /// Returns the number of monthly rows.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns true if the trajectory has no rows.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Returns the values for the given month, in column order.
+    #[must_use]
+    pub fn row(&self, month: usize) -> Option<&[f64]> {
+        self.rows.get(month).map(Vec::as_slice)
+    }
+
+    /// Renders the trajectory as CSV with a `month` column and one column
+    /// per path, named as `StatePath` displays.
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("month");
+        for path in &self.paths {
+            csv.push(',');
+            csv.push_str(&path.to_string());
+        }
+        csv.push('\n');
+        for (month, row) in self.rows.iter().enumerate() {
+            csv.push_str(&month.to_string());
+            for value in row {
+                csv.push_str(&format!(",{value:.9}"));
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// Parses a trajectory written by `to_csv()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TrajectoryParseError` if the header names an unknown
+    /// path or a row is malformed.
+    pub fn from_csv(csv: &str) -> Result<Trajectory, TrajectoryParseError> {
+        let mut lines = csv.lines().filter(|line| !line.trim().is_empty());
+        let header = lines.next().ok_or(TrajectoryParseError::MissingHeader)?;
+        let paths = header
+            .split(',')
+            .skip(1)
+            .map(|name| {
+                name.parse::<StatePath>()
+                    .map_err(|_| TrajectoryParseError::UnknownPath(name.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut rows = Vec::new();
+        for (index, line) in lines.enumerate() {
+            let line_number = index + 2;
+            let values = line
+                .split(',')
+                .skip(1)
+                .map(|value| {
+                    value
+                        .parse::<f64>()
+                        .map_err(|_| TrajectoryParseError::InvalidValue {
+                            line: line_number,
+                            value: value.to_string(),
+                        })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            if values.len() != paths.len() {
+                return Err(TrajectoryParseError::ColumnCount {
+                    line: line_number,
+                    expected: paths.len(),
+                    found: values.len(),
+                });
+            }
+            rows.push(values);
+        }
+
+        Ok(Trajectory { paths, rows })
+    }
+
+    /// Returns the largest absolute deviation from `golden` for each path.
+    ///
+    /// Paths missing from `golden`, and months present in only one of the
+    /// trajectories, count as an infinite deviation.
+    #[must_use]
+    pub fn max_deviations(&self, golden: &Trajectory) -> Vec<(StatePath, f64)> {
+        self.paths
+            .iter()
+            .enumerate()
+            .map(|(column, path)| {
+                let Some(golden_column) = golden.paths.iter().position(|p| p == path) else {
+                    return (*path, f64::INFINITY);
+                };
+                if self.rows.len() != golden.rows.len() {
+                    return (*path, f64::INFINITY);
+                }
+                let deviation = self
+                    .rows
+                    .iter()
+                    .zip(&golden.rows)
+                    .map(|(row, golden_row)| (row[column] - golden_row[golden_column]).abs())
+                    .fold(0.0, f64::max);
+                (*path, deviation)
+            })
+            .collect()
+    }
+}
+
+/// Errors from parsing a golden trajectory CSV.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrajectoryParseError {
+    /// The CSV has no header line.
+    MissingHeader,
+    /// The header names a column that is not a state path.
+    UnknownPath(String),
+    /// A value is not a number.
+    InvalidValue {
+        /// One-based line number.
+        line: usize,
+        /// The unparseable value.
+        value: String,
+    },
+    /// A row has the wrong number of values.
+    ColumnCount {
+        /// One-based line number.
+        line: usize,
+        /// Values expected from the header.
+        expected: usize,
+        /// Values found on the line.
+        found: usize,
+    },
+}
+
+impl fmt::Display for TrajectoryParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TrajectoryParseError::MissingHeader => write!(f, "Trajectory CSV has no header"),
+            TrajectoryParseError::UnknownPath(name) => {
+                write!(f, "Unknown state path in header: {}", name)
+            }
+            TrajectoryParseError::InvalidValue { line, value } => {
+                write!(f, "Invalid value on line {}: {}", line, value)
+            }
+            TrajectoryParseError::ColumnCount {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Line {} has {} values, expected {}",
+                line, found, expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TrajectoryParseError {}
+
+/// Returns every canonical scenario.
+#[must_use]
+pub fn all() -> Vec<Scenario> {
+    vec![
+        chronic_exclusion(),
+        trauma_and_recovery(),
+        formative_shifts(),
+        work_family_conflict(),
+    ]
+}
+
+/// Monthly exclusion and burden feedback building ITS risk over a year,
+/// followed by a year without further events.
+#[must_use]
+pub fn chronic_exclusion() -> Scenario {
+    let mut simulation = Simulation::new(reference());
+    let entity_id =
+        simulation.add_entity(adult("excluded", EcologicalContext::default()), reference());
+
+    for month in 1..=12 {
+        let at = reference() + Duration::months(month);
+        add_event(
+            &mut simulation,
+            EventBuilder::new(EventType::SocialExclusion).severity(0.6),
+            &entity_id,
+            ("exclusion", month),
+            at,
+        );
+        if month % 2 == 0 {
+            add_event(
+                &mut simulation,
+                EventBuilder::new(EventType::BurdenFeedback).severity(0.5),
+                &entity_id,
+                ("burden", month),
+                at + Duration::days(7),
+            );
+        }
+    }
+
+    Scenario {
+        name: "chronic_exclusion",
+        description: "Twelve months of exclusion and burden feedback, then a year of recovery",
+        simulation,
+        entity_id,
+        months: 24,
+    }
+}
+
+/// A violent trauma followed by nine months of emotional support.
+#[must_use]
+pub fn trauma_and_recovery() -> Scenario {
+    let mut simulation = Simulation::new(reference());
+    let entity_id =
+        simulation.add_entity(adult("survivor", EcologicalContext::default()), reference());
+
+    add_event(
+        &mut simulation,
+        EventBuilder::new(EventType::Violence).severity(0.9),
+        &entity_id,
+        ("violence", 1),
+        reference() + Duration::months(1),
+    );
+    for month in 3..=11 {
+        add_event(
+            &mut simulation,
+            EventBuilder::new(EventType::Support)
+                .severity(0.6)
+                .payload(EventPayload::Support {
+                    support_type: SupportType::Emotional,
+                    effectiveness: 0.8,
+                    audience: Audience::Private,
+                }),
+            &entity_id,
+            ("support", month),
+            reference() + Duration::months(month),
+        );
+    }
+
+    Scenario {
+        name: "trauma_and_recovery",
+        description: "Violent trauma in month one, emotional support in months three to eleven",
+        simulation,
+        entity_id,
+        months: 36,
+    }
+}
+
+/// Formative events that shift personality base values.
+#[must_use]
+pub fn formative_shifts() -> Scenario {
+    let mut simulation = Simulation::new(reference());
+    let entity_id =
+        simulation.add_entity(adult("formed", EcologicalContext::default()), reference());
+
+    add_event(
+        &mut simulation,
+        EventBuilder::new(EventType::TraumaticExposure)
+            .severity(0.8)
+            .with_base_shift(HexacoPath::Neuroticism, 0.2),
+        &entity_id,
+        ("exposure", 2),
+        reference() + Duration::months(2),
+    );
+    add_event(
+        &mut simulation,
+        EventBuilder::new(EventType::Achievement)
+            .severity(0.7)
+            .with_base_shift(HexacoPath::Neuroticism, -0.1),
+        &entity_id,
+        ("achievement", 12),
+        reference() + Duration::months(12),
+    );
+
+    Scenario {
+        name: "formative_shifts",
+        description: "A traumatic exposure raising neuroticism, later partly offset by achievement",
+        simulation,
+        entity_id,
+        months: 24,
+    }
+}
+
+/// A stressful job alongside a hostile, demanding family, with recurring
+/// family discord.
+#[must_use]
+pub fn work_family_conflict() -> Scenario {
+    let mut context = EcologicalContext::default();
+    context.add_microsystem(
+        MicrosystemId::new("office").expect("valid microsystem id"),
+        Microsystem::new_work(WorkContext {
+            workload_stress: 0.8,
+            interaction_profile: InteractionProfile::with_values(0.8, 0.6),
+            ..Default::default()
+        }),
+    );
+    context.add_microsystem(
+        MicrosystemId::new("home").expect("valid microsystem id"),
+        Microsystem::new_family(FamilyContext {
+            caregiving_burden: 0.7,
+            hostility: 0.5,
+            warmth: 0.4,
+            ..Default::default()
+        }),
+    );
+
+    let mut simulation = Simulation::new(reference());
+    let entity_id = simulation.add_entity(adult("juggler", context), reference());
+
+    for month in (2..=22).step_by(2) {
+        add_event(
+            &mut simulation,
+            EventBuilder::new(EventType::FamilyDiscord).severity(0.5),
+            &entity_id,
+            ("discord", month),
+            reference() + Duration::months(month),
+        );
+    }
+
+    Scenario {
+        name: "work_family_conflict",
+        description: "High work stress and a hostile family, with discord every other month",
+        simulation,
+        entity_id,
+        months: 24,
+    }
+}
+
+/// The reference date shared by every scenario.
+fn reference() -> Timestamp {
+    Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+}
+
+/// A 34-year-old human with default state.
+fn adult(id: &str, context: EcologicalContext) -> Entity {
+    EntityBuilder::new()
+        .id(id)
+        .species(Species::Human)
+        .birth_date(Timestamp::from_ymd_hms(1990, 1, 1, 0, 0, 0))
+        .with_context(context)
+        .build()
+        .expect("scenario entity is valid")
+}
+
+/// Adds an event with a deterministic ID derived from `label`.
+fn add_event(
+    simulation: &mut Simulation,
+    builder: EventBuilder,
+    target: &EntityId,
+    label: (&str, u64),
+    at: Timestamp,
+) {
+    let id = EventId::new(format!("{}_{}", label.0, label.1)).expect("valid event id");
+    let event = builder
+        .id(id)
+        .target(target.clone())
+        .build()
+        .expect("scenario event is valid");
+    simulation.add_event(event, at);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_round_trips() {
+        let trajectory = chronic_exclusion().trajectory();
+        let parsed = Trajectory::from_csv(&trajectory.to_csv()).unwrap();
+
+        assert_eq!(parsed.paths(), trajectory.paths());
+        assert_eq!(parsed.len(), trajectory.len());
+        assert!(trajectory
+            .max_deviations(&parsed)
+            .iter()
+            .all(|(_, deviation)| *deviation < 1e-8));
+    }
+
+    #[test]
+    fn scenarios_are_deterministic() {
+        for (first, second) in all().iter().zip(all().iter()) {
+            assert_eq!(first.name(), second.name());
+            assert_eq!(first.trajectory(), second.trajectory());
+        }
+    }
+
+    #[test]
+    fn max_deviations_reports_mismatches() {
+        let trajectory = trauma_and_recovery().trajectory();
+        let mut shifted = trajectory.clone();
+        shifted.rows[5][0] += 0.25;
+        let deviations = trajectory.max_deviations(&shifted);
+        assert!((deviations[0].1 - 0.25).abs() < 1e-9);
+        assert!(deviations[1].1 < f64::EPSILON);
+
+        let mut truncated = trajectory.clone();
+        truncated.rows.pop();
+        assert!(trajectory
+            .max_deviations(&truncated)
+            .iter()
+            .all(|(_, deviation)| deviation.is_infinite()));
+
+        let mut narrowed = trajectory.clone();
+        narrowed.paths.truncate(1);
+        assert!(trajectory.max_deviations(&narrowed)[1].1.is_infinite());
+    }
+
+    #[test]
+    fn from_csv_rejects_malformed_input() {
+        assert_eq!(
+            Trajectory::from_csv(""),
+            Err(TrajectoryParseError::MissingHeader)
+        );
+        assert_eq!(
+            Trajectory::from_csv("month,Mood::Nope\n"),
+            Err(TrajectoryParseError::UnknownPath("Mood::Nope".to_string()))
+        );
+        assert!(matches!(
+            Trajectory::from_csv("month,Mood::Valence\n0,abc\n"),
+            Err(TrajectoryParseError::InvalidValue { line: 2, .. })
+        ));
+        let err = Trajectory::from_csv("month,Mood::Valence\n0,0.1,0.2\n").unwrap_err();
+        assert!(err.to_string().contains("expected 1"));
+    }
+}
@@ -1,10 +1,12 @@
 //! Alert type for threshold notifications.
 //!
 //! Alerts are generated when entity state crosses dangerous thresholds
-//! or when feedback loops are detected.
+//! or when feedback loops are detected. External systems can also supply
+//! alerts, built with `Alert::builder()`, for things the model cannot see.
 
-use crate::enums::{AlertSeverity, AlertTrigger};
+use crate::enums::{AlertProvenance, AlertSeverity, AlertTrigger};
 use crate::types::Duration;
+use std::fmt;
 
 /// An alert generated when entity state crosses a threshold.
 ///
@@ -30,6 +32,7 @@ use crate::types::Duration;
 /// assert_eq!(alert.severity(), AlertSeverity::Warning);
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Alert {
     /// The severity level of this alert.
     severity: AlertSeverity,
@@ -42,6 +45,12 @@ pub struct Alert {
 
     /// Human-readable message describing the alert.
     message: String,
+
+    /// Whether the model or an outside system raised this alert.
+    provenance: AlertProvenance,
+
+    /// How long the entity is treated as in crisis after this alert.
+    crisis_window: Option<Duration>,
 }
 
 impl Alert {
@@ -65,9 +74,35 @@ impl Alert {
             trigger,
             timestamp,
             message: message.into(),
+            provenance: AlertProvenance::Model,
+            crisis_window: None,
         }
     }
 
+    /// Creates a builder for an alert, typically from an external system.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::types::{Alert, Duration};
+    /// use behavioral_pathways::enums::{AlertSeverity, AlertTrigger};
+    ///
+    /// let alert = Alert::builder()
+    ///     .severity(AlertSeverity::Critical)
+    ///     .trigger(AlertTrigger::custom("clinician_flag"))
+    ///     .note("Acute risk reported at intake")
+    ///     .crisis_window(Duration::days(14))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert!(alert.is_critical());
+    /// assert_eq!(alert.message(), "Acute risk reported at intake");
+    /// ```
+    #[must_use]
+    pub fn builder() -> AlertBuilder {
+        AlertBuilder::default()
+    }
+
     /// Creates an info-level alert.
     #[must_use]
     pub fn info(trigger: AlertTrigger, timestamp: Duration, message: impl Into<String>) -> Self {
@@ -114,6 +149,29 @@ impl Alert {
         &self.message
     }
 
+    /// Returns whether the model or an outside system raised this alert.
+    #[must_use]
+    pub fn provenance(&self) -> AlertProvenance {
+        self.provenance
+    }
+
+    /// Returns true if an outside system raised this alert.
+    #[must_use]
+    pub fn is_external(&self) -> bool {
+        self.provenance == AlertProvenance::External
+    }
+
+    /// Returns how long the entity is treated as in crisis after this
+    /// alert, if it gates model behavior at all.
+    #[must_use]
+    pub fn crisis_window(&self) -> Option<Duration> {
+        self.crisis_window
+    }
+
+    pub(crate) fn set_provenance(&mut self, provenance: AlertProvenance) {
+        self.provenance = provenance;
+    }
+
     /// Returns true if this alert is critical.
     #[must_use]
     pub fn is_critical(&self) -> bool {
@@ -133,6 +191,94 @@ impl std::fmt::Display for Alert {
     }
 }
 
+/// Error returned when an alert cannot be built.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertBuildError {
+    /// Description of what went wrong.
+    pub reason: String,
+}
+
+impl fmt::Display for AlertBuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Alert build error: {}", self.reason)
+    }
+}
+
+impl std::error::Error for AlertBuildError {}
+
+/// Fluent builder for alerts.
+///
+/// A trigger is required. Severity defaults to Warning, the timestamp to
+/// zero, and the note to empty. Alerts only gate model behavior when a
+/// crisis window is set.
+#[derive(Debug, Clone, Default)]
+pub struct AlertBuilder {
+    severity: Option<AlertSeverity>,
+    trigger: Option<AlertTrigger>,
+    timestamp: Duration,
+    note: String,
+    crisis_window: Option<Duration>,
+}
+
+impl AlertBuilder {
+    /// Sets the severity.
+    #[must_use]
+    pub fn severity(mut self, severity: AlertSeverity) -> Self {
+        self.severity = Some(severity);
+        self
+    }
+
+    /// Sets what triggered the alert.
+    #[must_use]
+    pub fn trigger(mut self, trigger: AlertTrigger) -> Self {
+        self.trigger = Some(trigger);
+        self
+    }
+
+    /// Sets the simulation time of the alert.
+    #[must_use]
+    pub fn timestamp(mut self, timestamp: Duration) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    /// Sets a free-text note, used as the alert message.
+    #[must_use]
+    pub fn note(mut self, note: impl Into<String>) -> Self {
+        self.note = note.into();
+        self
+    }
+
+    /// Treats the entity as in crisis for this long after the alert.
+    ///
+    /// While the window is open, `ComputedState::is_in_crisis()` is true
+    /// and support-seeking predictions use crisis-level stakes.
+    #[must_use]
+    pub fn crisis_window(mut self, window: Duration) -> Self {
+        self.crisis_window = Some(window);
+        self
+    }
+
+    /// Builds the alert.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no trigger was set.
+    pub fn build(self) -> Result<Alert, AlertBuildError> {
+        let trigger = self.trigger.ok_or_else(|| AlertBuildError {
+            reason: "trigger is required".to_string(),
+        })?;
+        let mut alert = Alert::new(
+            self.severity.unwrap_or(AlertSeverity::Warning),
+            trigger,
+            self.timestamp,
+            self.note,
+        );
+        alert.crisis_window = self.crisis_window;
+        Ok(alert)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -198,6 +344,38 @@ mod tests {
         assert!(alert.is_warning_or_higher());
     }
 
+    #[test]
+    fn alert_builder_defaults_and_required_trigger() {
+        let alert = Alert::builder()
+            .trigger(AlertTrigger::custom("clinician_flag"))
+            .build()
+            .unwrap();
+        assert_eq!(alert.severity(), AlertSeverity::Warning);
+        assert_eq!(alert.provenance(), AlertProvenance::Model);
+        assert!(!alert.is_external());
+        assert_eq!(alert.crisis_window(), None);
+        assert!(alert.message().is_empty());
+
+        let err = Alert::builder().note("missing trigger").build().unwrap_err();
+        assert!(err.to_string().contains("trigger"));
+    }
+
+    #[test]
+    fn alert_builder_sets_all_fields() {
+        let alert = Alert::builder()
+            .severity(AlertSeverity::Critical)
+            .trigger(AlertTrigger::custom("clinician_flag"))
+            .timestamp(Duration::days(3))
+            .note("Acute risk")
+            .crisis_window(Duration::days(14))
+            .build()
+            .unwrap();
+        assert!(alert.is_critical());
+        assert_eq!(alert.timestamp().as_days(), 3);
+        assert_eq!(alert.message(), "Acute risk");
+        assert_eq!(alert.crisis_window(), Some(Duration::days(14)));
+    }
+
     #[test]
     fn alert_display() {
         let alert = Alert::new(
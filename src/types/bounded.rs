@@ -0,0 +1,272 @@
+//! Range-checked values for psychological inputs.
+//!
+//! Most psychological dimensions live on one of two scales: the unit
+//! interval (0.0 to 1.0) for intensities such as stress or severity, and
+//! the signed unit interval (-1.0 to 1.0) for bipolar dimensions such as
+//! HEXACO traits or valence. [`UnitInterval`] and [`SignedUnit`] carry a
+//! value already known to be on its scale.
+//!
+//! # Policy
+//!
+//! Inputs are handled the same way at every public intake point:
+//!
+//! - **Builders error.** `EntityBuilder` and `EventBuilder` reject an
+//!   out-of-range or non-finite input from `build()`. Their setters accept
+//!   either a raw float, validated at build, or one of these types.
+//! - **Runtime mutators clamp.** Setters and delta-adding methods on state
+//!   and context clamp into range, since deltas computed during simulation
+//!   legitimately overshoot. A non-finite value is a caller bug and fails a
+//!   debug assertion.
+
+use std::fmt;
+
+/// Error returned when a value is outside its scale or not finite.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundedValueError {
+    /// The rejected value.
+    pub value: f64,
+    /// Lower end of the scale.
+    pub min: f64,
+    /// Upper end of the scale.
+    pub max: f64,
+}
+
+impl fmt::Display for BoundedValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Value {} is outside [{}, {}]",
+            self.value, self.min, self.max
+        )
+    }
+}
+
+impl std::error::Error for BoundedValueError {}
+
+/// A value known to lie on a fixed scale.
+pub trait BoundedValue: Copy {
+    /// Lower end of the scale.
+    const MIN: f64;
+    /// Upper end of the scale.
+    const MAX: f64;
+
+    /// Returns the value.
+    fn get(self) -> f64;
+}
+
+/// Conversion into a bounded value, accepted by builder setters.
+///
+/// Raw floats are validated; bounded values pass through unchanged.
+pub trait IntoBounded<B: BoundedValue> {
+    /// Converts `self`, or returns the reason it is out of range.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BoundedValueError` for a value outside the scale of `B` or
+    /// a non-finite value.
+    fn into_bounded(self) -> Result<B, BoundedValueError>;
+}
+
+/// Macro to generate bounded value newtypes with common functionality.
+macro_rules! define_bounded {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $min:literal, $max:literal
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+        pub struct $name(f64);
+
+        impl $name {
+            /// Creates a value, or returns an error if it is out of range.
+            ///
+            /// # Errors
+            ///
+            /// Returns `BoundedValueError` if `value` is outside the scale
+            /// or not finite.
+            pub fn new(value: f64) -> Result<Self, BoundedValueError> {
+                if value.is_finite() && ($min..=$max).contains(&value) {
+                    Ok(Self(value))
+                } else {
+                    Err(BoundedValueError {
+                        value,
+                        min: $min,
+                        max: $max,
+                    })
+                }
+            }
+
+            /// Creates a value, clamping it into range.
+            ///
+            /// This is the runtime mutator policy. A non-finite value fails
+            /// a debug assertion; in release builds NaN becomes the lower
+            /// end of the scale.
+            #[must_use]
+            pub fn clamped(value: f64) -> Self {
+                debug_assert!(value.is_finite(), "non-finite input: {value}");
+                if value.is_nan() {
+                    return Self($min);
+                }
+                Self(value.clamp($min, $max))
+            }
+
+            /// Returns the value.
+            #[must_use]
+            pub const fn get(self) -> f64 {
+                self.0
+            }
+        }
+
+        impl BoundedValue for $name {
+            const MIN: f64 = $min;
+            const MAX: f64 = $max;
+
+            fn get(self) -> f64 {
+                self.0
+            }
+        }
+
+        impl IntoBounded<$name> for $name {
+            fn into_bounded(self) -> Result<$name, BoundedValueError> {
+                Ok(self)
+            }
+        }
+
+        impl IntoBounded<$name> for f64 {
+            fn into_bounded(self) -> Result<$name, BoundedValueError> {
+                $name::new(self)
+            }
+        }
+
+        impl IntoBounded<$name> for f32 {
+            fn into_bounded(self) -> Result<$name, BoundedValueError> {
+                $name::new(f64::from(self))
+            }
+        }
+
+        impl TryFrom<f64> for $name {
+            type Error = BoundedValueError;
+
+            fn try_from(value: f64) -> Result<Self, Self::Error> {
+                Self::new(value)
+            }
+        }
+
+        impl TryFrom<f32> for $name {
+            type Error = BoundedValueError;
+
+            fn try_from(value: f32) -> Result<Self, Self::Error> {
+                Self::new(f64::from(value))
+            }
+        }
+
+        impl From<$name> for f64 {
+            fn from(value: $name) -> f64 {
+                value.0
+            }
+        }
+
+        impl From<$name> for f32 {
+            fn from(value: $name) -> f32 {
+                value.0 as f32
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+    };
+}
+
+define_bounded!(
+    /// A value from 0.0 to 1.0, such as an intensity or a severity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::types::UnitInterval;
+    ///
+    /// let severity = UnitInterval::try_from(0.7).unwrap();
+    /// assert_eq!(severity.get(), 0.7);
+    /// assert!(UnitInterval::try_from(1.5).is_err());
+    /// assert_eq!(UnitInterval::clamped(1.5).get(), 1.0);
+    /// ```
+    UnitInterval,
+    0.0,
+    1.0
+);
+
+define_bounded!(
+    /// A value from -1.0 to 1.0, such as a HEXACO trait or valence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::types::SignedUnit;
+    ///
+    /// let extraversion = SignedUnit::try_from(-0.4).unwrap();
+    /// assert_eq!(extraversion.get(), -0.4);
+    /// assert!(SignedUnit::try_from(7.0).is_err());
+    /// ```
+    SignedUnit,
+    -1.0,
+    1.0
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_accepts_the_closed_scale() {
+        assert_eq!(UnitInterval::new(0.0).unwrap().get(), 0.0);
+        assert_eq!(UnitInterval::new(1.0).unwrap().get(), 1.0);
+        assert_eq!(SignedUnit::new(-1.0).unwrap().get(), -1.0);
+        assert_eq!(
+            SignedUnit::new(1.5),
+            Err(BoundedValueError {
+                value: 1.5,
+                min: -1.0,
+                max: 1.0
+            })
+        );
+        assert!(UnitInterval::new(-0.1).is_err());
+    }
+
+    #[test]
+    fn non_finite_values_are_rejected() {
+        assert!(UnitInterval::new(f64::NAN).is_err());
+        assert!(SignedUnit::new(f64::INFINITY).is_err());
+        assert!(UnitInterval::try_from(f32::NEG_INFINITY).is_err());
+    }
+
+    #[test]
+    fn into_bounded_validates_raw_floats_only() {
+        let bounded: UnitInterval = 0.25_f32.into_bounded().unwrap();
+        assert_eq!(bounded.get(), 0.25);
+        assert_eq!(bounded.into_bounded(), Ok(bounded));
+        let raw: Result<SignedUnit, _> = 7.0_f64.into_bounded();
+        assert!(raw.is_err());
+    }
+
+    #[test]
+    fn clamped_pins_to_the_scale() {
+        assert_eq!(SignedUnit::clamped(-3.0).get(), -1.0);
+        assert_eq!(f32::from(UnitInterval::clamped(0.5)), 0.5);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "non-finite input")]
+    fn clamped_asserts_finite_in_debug_builds() {
+        let _ = UnitInterval::clamped(f64::NAN);
+    }
+
+    #[test]
+    fn error_display_names_the_scale() {
+        let error = UnitInterval::new(1.5).unwrap_err();
+        assert_eq!(error.to_string(), "Value 1.5 is outside [0, 1]");
+    }
+}
@@ -4,7 +4,6 @@
 //! common units (seconds, minutes, hours, days, weeks, months, years)
 //! and arithmetic operations.
 
-use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::ops::{Add, Div, Mul, Sub};
 
@@ -29,7 +28,8 @@ use std::ops::{Add, Div, Mul, Sub};
 /// // Comparison
 /// assert!(one_week > one_day);
 /// ```
-#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Duration {
     /// Total seconds in this duration.
     seconds: u64,
@@ -173,6 +173,27 @@ impl Duration {
         }
     }
 
+    /// Creates a duration from a fractional number of days, rounded to the
+    /// nearest second.
+    ///
+    /// Negative or NaN days give a zero duration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::types::Duration;
+    ///
+    /// let duration = Duration::from_days_f64(1.5);
+    /// assert_eq!(duration.as_hours(), 36);
+    /// assert!(Duration::from_days_f64(-2.0).is_zero());
+    /// ```
+    #[must_use]
+    pub fn from_days_f64(days: f64) -> Self {
+        // Float-to-int casts saturate, sending negatives and NaN to zero
+        let seconds = (days * Self::SECONDS_PER_DAY as f64).round() as u64;
+        Duration { seconds }
+    }
+
     /// Creates a duration from the specified number of weeks.
     ///
     /// # Examples
@@ -783,4 +804,19 @@ mod tests {
         let d3 = Duration::from_hours_f32(0.5);
         assert_eq!(d3.as_minutes(), 30);
     }
+
+    #[test]
+    fn from_days_f64() {
+        assert_eq!(Duration::from_days_f64(2.0), Duration::days(2));
+        assert_eq!(Duration::from_days_f64(0.25).as_hours(), 6);
+        // Rounded to the nearest second
+        assert_eq!(
+            Duration::from_days_f64(1.0 / 86_400.0 * 0.6).as_seconds(),
+            1
+        );
+        assert!(Duration::from_days_f64(-1.0).is_zero());
+        assert!(Duration::from_days_f64(f64::NAN).is_zero());
+        let days = Duration::days(3) + Duration::hours(7);
+        assert_eq!(Duration::from_days_f64(days.as_days_f64()), days);
+    }
 }
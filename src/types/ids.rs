@@ -1,12 +1,13 @@
 //! Type-safe identifier wrappers for domain entities.
 //!
-//! Each ID type is a newtype wrapper around String, providing type safety
-//! to prevent mixing different kinds of identifiers.
+//! Each ID type is a newtype wrapper around a shared `Arc<str>`, providing
+//! type safety to prevent mixing different kinds of identifiers. Cloning an
+//! ID shares the string instead of copying it, so the many copies of an
+//! entity's ID held by its events cost one allocation between them.
 
 use std::fmt;
 use std::hash::Hash;
-
-use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
 /// Error returned when an ID cannot be created from invalid input.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -32,8 +33,9 @@ macro_rules! define_id {
         $name:ident, $type_name:literal
     ) => {
         $(#[$meta])*
-        #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-        pub struct $name(String);
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct $name(Arc<str>);
 
         impl $name {
             /// Creates a new ID from a string.
@@ -49,7 +51,7 @@ macro_rules! define_id {
                         reason: "ID cannot be empty".to_string(),
                     });
                 }
-                Ok(Self(id))
+                Ok(Self(Arc::from(id)))
             }
 
             /// Returns the ID as a string slice.
@@ -58,10 +60,10 @@ macro_rules! define_id {
                 &self.0
             }
 
-            /// Consumes the ID and returns the inner String.
+            /// Consumes the ID and returns it as a String.
             #[must_use]
             pub fn into_string(self) -> String {
-                self.0
+                self.0.to_string()
             }
         }
 
@@ -370,6 +372,22 @@ mod tests {
         assert_eq!(id1, id2);
     }
 
+    #[test]
+    fn clone_shares_storage() {
+        let id1 = EntityId::new("person_001").unwrap();
+        let id2 = id1.clone();
+        assert!(std::ptr::eq(id1.as_str(), id2.as_str()));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip_is_a_plain_string() {
+        let id = EntityId::new("person_001").unwrap();
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, "\"person_001\"");
+        assert_eq!(serde_json::from_str::<EntityId>(&json).unwrap(), id);
+    }
+
     #[test]
     fn debug_format() {
         let id = EntityId::new("person_001").unwrap();
@@ -3,12 +3,16 @@
 //! This module contains core types used throughout the library.
 
 mod alert;
+mod bounded;
 mod duration;
 mod ids;
 mod relationship_slot;
+#[cfg(feature = "serde")]
+pub(crate) mod sorted_serde;
 mod timestamp;
 
-pub use alert::Alert;
+pub use alert::{Alert, AlertBuildError, AlertBuilder};
+pub use bounded::{BoundedValue, BoundedValueError, IntoBounded, SignedUnit, UnitInterval};
 pub use duration::Duration;
 pub use ids::{
     EntityId, EventId, GroupId, IdError, MemoryId, MicrosystemId, RelationshipId, SubscriptionId,
@@ -33,6 +33,7 @@ use crate::types::RelationshipId;
 /// assert!(slot.get_attached().is_none());
 /// ```
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RelationshipSlot {
     /// The attached relationship ID, if any.
     attached: Option<RelationshipId>,
@@ -112,7 +113,6 @@ impl RelationshipSlot {
     /// in Phase 5. External code should not call this directly.
     ///
     /// If a relationship is already attached, it is replaced.
-    #[allow(dead_code)]
     pub(crate) fn attach(&mut self, id: RelationshipId) {
         self.attached = Some(id);
     }
@@ -123,7 +123,6 @@ impl RelationshipSlot {
     /// in Phase 5. External code should not call this directly.
     ///
     /// Returns the previously attached ID, if any.
-    #[allow(dead_code)]
     pub(crate) fn detach(&mut self) -> Option<RelationshipId> {
         self.attached.take()
     }
@@ -0,0 +1,31 @@
+//! Serializers that write hash maps and sets in key order.
+//!
+//! `HashMap` iteration order varies between runs, so serializing one
+//! directly gives different output for the same data. These helpers are
+//! used with `#[serde(serialize_with = "...")]`; deserialization is the
+//! default for the field's type.
+
+use serde::{Serialize, Serializer};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::hash::BuildHasher;
+
+/// Serializes a map with its entries sorted by key.
+pub(crate) fn map<K, V, H, S>(map: &HashMap<K, V, H>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    K: Ord + Serialize,
+    V: Serialize,
+    H: BuildHasher,
+    S: Serializer,
+{
+    map.iter().collect::<BTreeMap<_, _>>().serialize(serializer)
+}
+
+/// Serializes a set with its items sorted.
+pub(crate) fn set<T, H, S>(set: &HashSet<T, H>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Ord + Serialize,
+    H: BuildHasher,
+    S: Serializer,
+{
+    set.iter().collect::<BTreeSet<_>>().serialize(serializer)
+}
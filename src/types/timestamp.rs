@@ -19,16 +19,24 @@
 //! // Create from string
 //! let ts = Timestamp::from_str("2024-01-15 14:30:00").unwrap();
 //! ```
+//!
+//! # Time Zones
+//!
+//! Timestamps carry no time zone and are read as UTC. There are no
+//! daylight saving transitions: every day is exactly 86,400 seconds, so
+//! adding `Duration::days(1)` always keeps the wall-clock time, and
+//! calendar helpers such as `add_calendar_months` only ever change the
+//! date.
 
 use crate::types::Duration;
-use chrono::NaiveDateTime;
-use serde::{Deserialize, Serialize};
+use chrono::{Months, NaiveDateTime, NaiveTime};
 use std::cmp::Ordering;
 use std::fmt;
 use std::ops::{Add, Sub};
 
 /// Error type for timestamp parsing failures.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TimestampParseError {
     /// The string format was not recognized.
     InvalidFormat(String),
@@ -78,7 +86,8 @@ impl std::error::Error for TimestampParseError {}
 /// // Add duration to timestamp
 /// let future = now + Duration::years(10);
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Timestamp {
     inner: NaiveDateTime,
 }
@@ -221,6 +230,125 @@ impl Timestamp {
     pub fn from_naive_datetime(dt: NaiveDateTime) -> Self {
         Timestamp { inner: dt }
     }
+
+    /// Returns midnight at the start of this timestamp's day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::types::Timestamp;
+    ///
+    /// let ts = Timestamp::from_ymd_hms(2024, 1, 15, 14, 30, 45);
+    /// assert_eq!(ts.truncate_to_day(), Timestamp::from_ymd_hms(2024, 1, 15, 0, 0, 0));
+    /// ```
+    #[must_use]
+    pub fn truncate_to_day(&self) -> Self {
+        Timestamp {
+            inner: self.inner.date().and_time(NaiveTime::MIN),
+        }
+    }
+
+    /// Returns the start of this timestamp's hour.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::types::Timestamp;
+    ///
+    /// let ts = Timestamp::from_ymd_hms(2024, 1, 15, 14, 30, 45);
+    /// assert_eq!(ts.truncate_to_hour(), Timestamp::from_ymd_hms(2024, 1, 15, 14, 0, 0));
+    /// ```
+    #[must_use]
+    pub fn truncate_to_hour(&self) -> Self {
+        let time = NaiveTime::from_hms_opt(self.hour(), 0, 0).expect("hour of a valid time");
+        Timestamp {
+            inner: self.inner.date().and_time(time),
+        }
+    }
+
+    /// Returns the same time of day `months` calendar months later, or
+    /// earlier when negative.
+    ///
+    /// A day past the end of the target month is clamped to its last day,
+    /// so January 31 plus one month is February 29 in a leap year and
+    /// February 28 otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is outside the representable date range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::types::Timestamp;
+    ///
+    /// let ts = Timestamp::from_ymd_hms(2024, 1, 31, 9, 0, 0);
+    /// assert_eq!(ts.add_calendar_months(1), Timestamp::from_ymd_hms(2024, 2, 29, 9, 0, 0));
+    /// assert_eq!(ts.add_calendar_months(-2), Timestamp::from_ymd_hms(2023, 11, 30, 9, 0, 0));
+    /// ```
+    #[must_use]
+    pub fn add_calendar_months(&self, months: i32) -> Self {
+        let shift = Months::new(months.unsigned_abs());
+        let inner = if months >= 0 {
+            self.inner.checked_add_months(shift)
+        } else {
+            self.inner.checked_sub_months(shift)
+        };
+        Timestamp {
+            inner: inner.expect("calendar month arithmetic out of range"),
+        }
+    }
+
+    /// Returns the same date and time `years` calendar years later, or
+    /// earlier when negative.
+    ///
+    /// February 29 becomes February 28 in a year that is not a leap year.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result is outside the representable date range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::types::Timestamp;
+    ///
+    /// let leap_day = Timestamp::from_ymd_hms(2024, 2, 29, 0, 0, 0);
+    /// assert_eq!(leap_day.add_calendar_years(1), Timestamp::from_ymd_hms(2025, 2, 28, 0, 0, 0));
+    /// assert_eq!(leap_day.add_calendar_years(4), Timestamp::from_ymd_hms(2028, 2, 29, 0, 0, 0));
+    /// ```
+    #[must_use]
+    pub fn add_calendar_years(&self, years: i32) -> Self {
+        let months = years
+            .checked_mul(12)
+            .expect("calendar year arithmetic out of range");
+        self.add_calendar_months(months)
+    }
+
+    /// Iterates from this timestamp to `end` at a fixed step.
+    ///
+    /// Yields this timestamp, then this plus `step`, and so on up to and
+    /// including `end`. Yields only this timestamp when `step` is zero,
+    /// and nothing when `end` is before it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use behavioral_pathways::types::{Duration, Timestamp};
+    ///
+    /// let start = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    /// let end = start + Duration::days(3);
+    /// let days: Vec<Timestamp> = start.iter_step(end, Duration::days(1)).collect();
+    /// assert_eq!(days.len(), 4);
+    /// assert_eq!(days.last(), Some(&end));
+    /// ```
+    pub fn iter_step(self, end: Timestamp, step: Duration) -> impl Iterator<Item = Timestamp> {
+        let first = (self <= end).then_some(self);
+        std::iter::successors(first, move |&ts| {
+            let next = ts + step;
+            (!step.is_zero() && next <= end).then_some(next)
+        })
+    }
 }
 
 impl fmt::Display for Timestamp {
@@ -626,4 +754,148 @@ mod tests {
         assert!(ts2.partial_cmp(&ts1) == Some(Ordering::Greater));
         assert!(ts1.partial_cmp(&ts1) == Some(Ordering::Equal));
     }
+
+    #[test]
+    fn truncation_keeps_the_date() {
+        let ts = Timestamp::from_ymd_hms(2024, 12, 31, 23, 59, 59);
+        let day = ts.truncate_to_day();
+        let hour = ts.truncate_to_hour();
+        assert_eq!(day, Timestamp::from_ymd_hms(2024, 12, 31, 0, 0, 0));
+        assert_eq!(hour, Timestamp::from_ymd_hms(2024, 12, 31, 23, 0, 0));
+        assert_eq!(day.truncate_to_day(), day);
+        assert_eq!(hour.truncate_to_hour(), hour);
+
+        // The truncated part is exactly what duration arithmetic recovers
+        let into_day = timestamp_to_duration(&day, &ts);
+        assert_eq!(
+            into_day,
+            Duration::hours(23) + Duration::seconds(59 * 60 + 59)
+        );
+        assert_eq!(duration_to_timestamp(&day, into_day), ts);
+        assert_eq!(
+            timestamp_to_duration(&hour, &ts),
+            Duration::seconds(59 * 60 + 59)
+        );
+    }
+
+    #[test]
+    fn calendar_months_clamp_to_month_end() {
+        let jan_31 = Timestamp::from_ymd_hms(2023, 1, 31, 8, 15, 0);
+        assert_eq!(
+            jan_31.add_calendar_months(1),
+            Timestamp::from_ymd_hms(2023, 2, 28, 8, 15, 0)
+        );
+        assert_eq!(
+            jan_31.add_calendar_months(3),
+            Timestamp::from_ymd_hms(2023, 4, 30, 8, 15, 0)
+        );
+        assert_eq!(
+            jan_31.add_calendar_months(13),
+            Timestamp::from_ymd_hms(2024, 2, 29, 8, 15, 0)
+        );
+        assert_eq!(
+            jan_31.add_calendar_months(-2),
+            Timestamp::from_ymd_hms(2022, 11, 30, 8, 15, 0)
+        );
+        assert_eq!(jan_31.add_calendar_months(0), jan_31);
+
+        // Clamping is not undone by moving back
+        let feb_28 = jan_31.add_calendar_months(1);
+        assert_eq!(
+            feb_28.add_calendar_months(-1),
+            Timestamp::from_ymd_hms(2023, 1, 28, 8, 15, 0)
+        );
+    }
+
+    #[test]
+    fn calendar_years_handle_leap_days() {
+        let leap_day = Timestamp::from_ymd_hms(2024, 2, 29, 12, 0, 0);
+        assert_eq!(
+            leap_day.add_calendar_years(1),
+            Timestamp::from_ymd_hms(2025, 2, 28, 12, 0, 0)
+        );
+        assert_eq!(
+            leap_day.add_calendar_years(-4),
+            Timestamp::from_ymd_hms(2020, 2, 29, 12, 0, 0)
+        );
+        // 2100 is not a leap year
+        assert_eq!(
+            leap_day.add_calendar_years(76),
+            Timestamp::from_ymd_hms(2100, 2, 28, 12, 0, 0)
+        );
+        assert_eq!(
+            leap_day.add_calendar_years(376),
+            Timestamp::from_ymd_hms(2400, 2, 29, 12, 0, 0)
+        );
+
+        // A calendar year spans 366 days across a leap day, unlike
+        // `Duration::years`
+        let start = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let year = timestamp_to_duration(&start, &start.add_calendar_years(1));
+        assert_eq!(year, Duration::days(366));
+        assert_ne!(year, Duration::years(1));
+    }
+
+    #[test]
+    fn calendar_results_round_trip_through_strings() {
+        let ts = Timestamp::from_ymd_hms(2024, 1, 31, 23, 45, 10);
+        for shifted in [
+            ts.truncate_to_day(),
+            ts.truncate_to_hour(),
+            ts.add_calendar_months(1),
+            ts.add_calendar_years(-1),
+        ] {
+            assert_eq!(Timestamp::from_str(&shifted.to_string()), Ok(shifted));
+        }
+        // Dates the calendar helpers never produce are still rejected
+        assert_eq!(
+            Timestamp::try_from_ymd_hms(2023, 2, 29, 0, 0, 0),
+            Err(TimestampParseError::InvalidDate {
+                year: 2023,
+                month: 2,
+                day: 29
+            })
+        );
+    }
+
+    #[test]
+    fn days_are_dst_free() {
+        // Daylight saving transitions in the US and EU during 2024
+        for (month, day) in [(3, 10), (3, 31), (10, 27), (11, 3)] {
+            let before = Timestamp::from_ymd_hms(2024, month, day, 1, 30, 0);
+            let after = before + Duration::days(1);
+            assert_eq!(after.hour(), 1);
+            assert_eq!(after.minute(), 30);
+            assert_eq!(after - before, Duration::hours(24));
+            let next_month = before.add_calendar_months(1);
+            assert_eq!((next_month.hour(), next_month.minute()), (1, 30));
+        }
+        let midnights: Vec<Timestamp> = Timestamp::from_ymd_hms(2024, 3, 1, 0, 0, 0)
+            .iter_step(
+                Timestamp::from_ymd_hms(2024, 11, 30, 0, 0, 0),
+                Duration::days(1),
+            )
+            .collect();
+        assert!(midnights.iter().all(|ts| *ts == ts.truncate_to_day()));
+        assert_eq!(midnights.len(), 275);
+    }
+
+    #[test]
+    fn iter_step_includes_end_and_handles_edges() {
+        let start = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+        let end = start + Duration::days(3);
+        assert_eq!(start.iter_step(end, Duration::days(1)).count(), 4);
+        assert_eq!(start.iter_step(end, Duration::days(2)).count(), 2);
+        assert_eq!(
+            start.iter_step(end, Duration::zero()).collect::<Vec<_>>(),
+            vec![start]
+        );
+        assert_eq!(end.iter_step(start, Duration::days(1)).count(), 0);
+        assert_eq!(
+            start
+                .iter_step(start, Duration::days(1))
+                .collect::<Vec<_>>(),
+            vec![start]
+        );
+    }
 }
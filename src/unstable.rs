@@ -0,0 +1,67 @@
+//! Unstable processor internals kept for existing callers.
+//!
+//! These functions expose the event and emotion pipeline below the
+//! `Simulation` API. They are hidden from the docs, deprecated, and may
+//! change or disappear in any release. Each one names its stable
+//! replacement.
+//!
+//! # Stability Tiers
+//!
+//! | Tier | Surface | Guarantee |
+//! |------|---------|-----------|
+//! | Stable | Everything documented on docs.rs | Semver |
+//! | Unstable | This module | None; deprecated on arrival |
+
+use crate::entity::Entity;
+use crate::event::Event;
+use crate::processor::{self, EmotionIntensities, InterpretedEvent};
+use crate::state::IndividualState;
+
+/// Interprets an event for an entity without applying it.
+#[deprecated(since = "1.0.0", note = "Use `Entity::interpret_event` instead.")]
+#[must_use]
+pub fn interpret_event(event: &Event, entity: &Entity) -> InterpretedEvent {
+    processor::interpret_event(event, entity)
+}
+
+/// Applies an interpreted event directly to an entity's current state.
+///
+/// Bypasses timestamps, developmental scaling, and decay.
+#[deprecated(
+    since = "1.0.0",
+    note = "Add the event with `Simulation::add_event` and query `state_at`."
+)]
+pub fn apply_interpreted_event(interpreted: &InterpretedEvent, entity: &mut Entity) {
+    processor::apply_interpreted_event(interpreted, entity);
+}
+
+/// Interprets an event and applies it directly to an entity.
+///
+/// Bypasses timestamps, developmental scaling, and decay.
+#[deprecated(
+    since = "1.0.0",
+    note = "Add the event with `Simulation::add_event` and query `state_at`, \
+            or use `EntityQueryHandle::preview_event`."
+)]
+pub fn process_event(event: &Event, entity: &mut Entity) -> InterpretedEvent {
+    processor::process_event(event, entity)
+}
+
+/// Derives graded emotions from raw PAD values.
+#[deprecated(since = "1.0.0", note = "Use `EmotionIntensities::from_pad` instead.")]
+#[must_use]
+pub fn derive_emotion(
+    valence: f32,
+    arousal: f32,
+    dominance: f32,
+    moral_violation_flag: f32,
+) -> EmotionIntensities {
+    EmotionIntensities::from_pad(valence, arousal, dominance, moral_violation_flag)
+}
+
+/// Derives graded emotions from a state's effective PAD values.
+#[deprecated(since = "1.0.0", note = "Use `EmotionIntensities::from_state` instead.")]
+#[must_use]
+pub fn get_derived_emotion(state: &IndividualState) -> EmotionIntensities {
+    EmotionIntensities::from_state(state)
+}
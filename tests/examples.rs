@@ -0,0 +1,35 @@
+//! Runs the programs in `examples/` as tests.
+//!
+//! Each example asserts the outcomes it demonstrates, so running it here
+//! catches public API regressions that unit tests miss.
+
+#[cfg(feature = "relationships")]
+#[path = "../examples/dyad_trust_evolution.rs"]
+mod dyad_trust_evolution;
+#[path = "../examples/intervention_comparison.rs"]
+mod intervention_comparison;
+#[path = "../examples/lifetime_projection.rs"]
+mod lifetime_projection;
+#[path = "../examples/population_cohort.rs"]
+mod population_cohort;
+
+#[cfg(feature = "relationships")]
+#[test]
+fn dyad_trust_evolution_example() {
+    dyad_trust_evolution::main();
+}
+
+#[test]
+fn intervention_comparison_example() {
+    intervention_comparison::main();
+}
+
+#[test]
+fn lifetime_projection_example() {
+    lifetime_projection::main();
+}
+
+#[test]
+fn population_cohort_example() {
+    population_cohort::main();
+}
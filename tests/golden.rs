@@ -0,0 +1,107 @@
+//! Golden trajectory regression tests.
+//!
+//! Each canonical scenario in `behavioral_pathways::test_scenarios` has a
+//! checked-in monthly trajectory in `tests/golden/<scenario>.csv`. These
+//! tests fail when the current output drifts from it by more than
+//! `GOLDEN_TOLERANCE`, printing the maximum deviation per path.
+//!
+//! When a behavior change is intended, regenerate the files and review
+//! the CSV diff alongside the code change:
+//!
+//! ```text
+//! UPDATE_GOLDENS=1 cargo test --test golden
+//! ```
+//!
+//! The goldens record the full model, so they only run with every default
+//! feature enabled.
+
+#![cfg(all(
+    feature = "context",
+    feature = "memory",
+    feature = "relationships",
+    feature = "its"
+))]
+
+use behavioral_pathways::test_scenarios::{self, Scenario, Trajectory, GOLDEN_TOLERANCE};
+use std::fs;
+use std::path::PathBuf;
+
+fn golden_file(scenario: &Scenario) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join(format!("{}.csv", scenario.name()))
+}
+
+fn check_golden(scenario: Scenario) {
+    let current = scenario.trajectory();
+    let file = golden_file(&scenario);
+
+    if std::env::var("UPDATE_GOLDENS").is_ok_and(|value| value == "1") {
+        fs::create_dir_all(file.parent().unwrap()).unwrap();
+        fs::write(&file, current.to_csv()).unwrap();
+        return;
+    }
+
+    let contents = fs::read_to_string(&file).unwrap_or_else(|err| {
+        panic!(
+            "Cannot read {} ({}); run with UPDATE_GOLDENS=1 to create it",
+            file.display(),
+            err
+        )
+    });
+    let golden = Trajectory::from_csv(&contents).unwrap();
+    let deviations = current.max_deviations(&golden);
+
+    if deviations.iter().any(|(_, deviation)| *deviation > GOLDEN_TOLERANCE) {
+        let report: Vec<String> = deviations
+            .iter()
+            .map(|(path, deviation)| {
+                let marker = if *deviation > GOLDEN_TOLERANCE { "  <-- drift" } else { "" };
+                format!("  {:<45} {:.9}{}", path.to_string(), deviation, marker)
+            })
+            .collect();
+        panic!(
+            "Scenario '{}' drifted from {} (tolerance {}).\nMaximum deviation per path:\n{}\n\
+             If the change is intended, rerun with UPDATE_GOLDENS=1.",
+            scenario.name(),
+            file.display(),
+            GOLDEN_TOLERANCE,
+            report.join("\n")
+        );
+    }
+}
+
+#[test]
+fn chronic_exclusion_matches_golden() {
+    check_golden(test_scenarios::chronic_exclusion());
+}
+
+#[test]
+fn trauma_and_recovery_matches_golden() {
+    check_golden(test_scenarios::trauma_and_recovery());
+}
+
+#[test]
+fn formative_shifts_matches_golden() {
+    check_golden(test_scenarios::formative_shifts());
+}
+
+#[test]
+fn work_family_conflict_matches_golden() {
+    check_golden(test_scenarios::work_family_conflict());
+}
+
+#[test]
+fn every_scenario_has_a_golden_test() {
+    let names: Vec<&str> = test_scenarios::all().iter().map(Scenario::name).collect();
+    assert_eq!(
+        names,
+        [
+            "chronic_exclusion",
+            "trauma_and_recovery",
+            "formative_shifts",
+            "work_family_conflict"
+        ]
+    );
+}
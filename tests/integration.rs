@@ -5,7 +5,9 @@
 
 mod integration {
     pub mod context;
+    pub mod fixtures;
     pub mod memory;
+    pub mod processor;
     pub mod relationship;
     pub mod simulation;
     pub mod state;
@@ -5,3 +5,4 @@
 mod entity_builder_with_context;
 mod entity_get_context_returns_value;
 mod entity_set_context_modifies_value;
+mod person_to_context_shaping;
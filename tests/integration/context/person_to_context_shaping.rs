@@ -0,0 +1,150 @@
+//! Integration test: person-to-context shaping builds up over queried time.
+//!
+//! Validates that state queries shape a copy of the entity's context by the
+//! elapsed time, saturating, composable, and without changing the entity.
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::context::{
+    person_shaping_fraction, EcologicalContext, Microsystem, SocialContext, WorkContext,
+    PERSON_SHAPING_HALF_LIFE_DAYS,
+};
+use behavioral_pathways::entity::{Entity, EntityBuilder};
+use behavioral_pathways::enums::Species;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::state::Hexaco;
+use behavioral_pathways::types::{Duration, MicrosystemId};
+
+fn friends() -> MicrosystemId {
+    MicrosystemId::new("friends").unwrap()
+}
+
+fn office() -> MicrosystemId {
+    MicrosystemId::new("office").unwrap()
+}
+
+fn person(context: EcologicalContext) -> Entity {
+    EntityBuilder::new()
+        .id("extravert")
+        .species(Species::Human)
+        .hexaco(
+            Hexaco::new()
+                .with_extraversion(0.9)
+                .with_conscientiousness(0.8),
+        )
+        .with_context(context)
+        .build()
+        .unwrap()
+}
+
+fn context() -> EcologicalContext {
+    let mut context = EcologicalContext::default();
+    context.add_microsystem(
+        friends(),
+        Microsystem::new_social(SocialContext {
+            warmth: 0.4,
+            ..Default::default()
+        }),
+    );
+    context.add_microsystem(
+        office(),
+        Microsystem::new_work(WorkContext {
+            role_clarity: 0.5,
+            ..Default::default()
+        }),
+    );
+    context
+}
+
+fn warmth(context: &EcologicalContext) -> f64 {
+    context
+        .get_microsystem(&friends())
+        .unwrap()
+        .social()
+        .unwrap()
+        .warmth
+}
+
+fn role_clarity(context: &EcologicalContext) -> f64 {
+    context
+        .get_microsystem(&office())
+        .unwrap()
+        .work()
+        .unwrap()
+        .role_clarity
+}
+
+/// Tests that a long query shapes the context more than a short one.
+///
+/// Validates: extraversion 0.9 shifts warmth by (0.9 - 0.3) * 0.1 = 0.06 at
+/// saturation, scaled by `person_shaping_fraction` of the elapsed time.
+#[test]
+fn shaping_scales_with_elapsed_time_up_to_an_asymptote() {
+    let mut sim = Simulation::new(reference());
+    let id = sim.add_entity(person(context()), reference());
+    let handle = sim.entity(&id).unwrap();
+
+    let day = handle.state_at(reference() + Duration::days(1));
+    let half_life =
+        handle.state_at(reference() + Duration::days(PERSON_SHAPING_HALF_LIFE_DAYS as u64));
+    let decades = handle.state_at(reference() + Duration::years(20));
+
+    let expected = |elapsed: Duration| 0.4 + 0.06 * person_shaping_fraction(elapsed);
+    assert!((warmth(day.context()) - expected(Duration::days(1))).abs() < 1e-6);
+    assert!((warmth(half_life.context()) - 0.43).abs() < 1e-6);
+    assert!((warmth(decades.context()) - 0.46).abs() < 1e-6);
+    assert!(warmth(day.context()) < warmth(half_life.context()));
+
+    // Queries never change the stored context
+    assert_eq!(
+        warmth(sim.get_anchored_entity(&id).unwrap().entity().context()),
+        0.4
+    );
+    assert_eq!(handle.state_at(reference()).context(), &context());
+}
+
+/// Tests that shaping over consecutive spans matches a single span.
+///
+/// Validates: for many split points, the shift up to the split plus the
+/// shift from the split to the end equals the shift over the whole span,
+/// and each shift is the span's share of the fully developed shaping.
+#[test]
+fn consecutive_spans_compose_to_a_single_span() {
+    let mut sim = Simulation::new(reference());
+    let id = sim.add_entity(person(context()), reference());
+    let handle = sim.entity(&id).unwrap();
+    let shaped = |days: u64| {
+        let state = handle.state_at(reference() + Duration::days(days));
+        (warmth(state.context()), role_clarity(state.context()))
+    };
+    let share = |from: u64, to: u64| {
+        person_shaping_fraction(Duration::days(to)) - person_shaping_fraction(Duration::days(from))
+    };
+
+    for total_days in [10_u64, 90, 365, 1000, 5000] {
+        let whole = shaped(total_days);
+        for split_days in [1, total_days / 4, total_days / 2, total_days - 1] {
+            let midway = shaped(split_days);
+            let first = (midway.0 - 0.4, midway.1 - 0.5);
+            let second = (whole.0 - midway.0, whole.1 - midway.1);
+            assert!((first.0 + second.0 - (whole.0 - 0.4)).abs() < 1e-12);
+            assert!((second.0 - 0.06 * share(split_days, total_days)).abs() < 1e-6);
+            assert!((second.1 - 0.05 * share(split_days, total_days)).abs() < 1e-6);
+            assert!((first.1 - 0.05 * share(0, split_days)).abs() < 1e-6);
+        }
+    }
+}
+
+/// Tests that backward queries leave the context as stored.
+///
+/// Validates: the stored context already holds shaping before the anchor.
+#[test]
+fn backward_queries_are_not_shaped() {
+    let mut sim = Simulation::new(reference());
+    let id = sim.add_entity(person(context()), reference());
+
+    let earlier = sim
+        .entity(&id)
+        .unwrap()
+        .state_at(reference() - Duration::days(30));
+    assert_eq!(earlier.context(), &context());
+}
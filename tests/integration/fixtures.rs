@@ -0,0 +1,33 @@
+//! Shared fixtures for integration tests.
+//!
+//! Most tests anchor a simulation at one reference date and query a single
+//! human, "person". Tests that need more than that build it themselves.
+
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::Species;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+/// The date integration tests anchor their simulations at.
+pub fn reference() -> Timestamp {
+    Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+}
+
+/// The ID of the entity `simulation_with_person` adds.
+pub fn person() -> EntityId {
+    EntityId::new("person").unwrap()
+}
+
+/// Builds a simulation at `reference()` with one human, `person()`, of the
+/// given age.
+pub fn simulation_with_person(age: Duration) -> Simulation {
+    let mut sim = Simulation::new(reference());
+    let entity = EntityBuilder::new()
+        .id("person")
+        .species(Species::Human)
+        .age(age)
+        .build()
+        .unwrap();
+    sim.add_entity(entity, reference());
+    sim
+}
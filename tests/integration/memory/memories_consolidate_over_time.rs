@@ -0,0 +1,82 @@
+//! Integration test: `memories_at` consolidates memories over time.
+//!
+//! Validates that memories move up through the layers as time passes since
+//! the anchor, that a passing low-salience memory is forgotten within two
+//! years, and that trauma memories, stored or formed from events, survive
+//! into the long-term layer.
+
+#![cfg(feature = "memory")]
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{EventType, Species};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::memory::{MemoryLayer, MemoryTag};
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EventId};
+
+/// Tests that a low-salience memory fades while a trauma memory survives.
+#[test]
+fn trauma_outlasts_passing_memory() {
+    let mut entity = EntityBuilder::new()
+        .id("person")
+        .species(Species::Human)
+        .age(Duration::years(30))
+        .build()
+        .unwrap();
+    entity.create_memory_in_layer(
+        MemoryLayer::Immediate,
+        "Small talk at the bus stop",
+        vec![],
+        vec![MemoryTag::Personal],
+        0.2,
+        None,
+    );
+    entity.create_memory_in_layer(
+        MemoryLayer::ShortTerm,
+        "Mugged on the way home",
+        vec![],
+        vec![MemoryTag::Violence],
+        0.9,
+        None,
+    );
+    let mut sim = Simulation::new(reference());
+    let id = sim.add_entity(entity, reference());
+    let assault = EventBuilder::new(EventType::Violence)
+        .id(EventId::new("assault").unwrap())
+        .target(id.clone())
+        .severity(0.9)
+        .build()
+        .unwrap();
+    sim.add_event(assault, reference() + Duration::days(1));
+    let handle = sim.entity(&id).unwrap();
+
+    // At the anchor both stored memories sit where they were stored
+    let now = handle.memories_at(reference());
+    let layers: Vec<Option<MemoryLayer>> = now.iter().map(|m| m.layer()).collect();
+    assert_eq!(
+        layers,
+        [Some(MemoryLayer::Immediate), Some(MemoryLayer::ShortTerm)]
+    );
+
+    // Two years on the small talk is gone; both trauma memories are
+    // long-term, with faded salience
+    let later = handle.memories_at(reference() + Duration::years(2));
+    assert!(later
+        .iter()
+        .all(|m| m.summary() != "Small talk at the bus stop"));
+    assert_eq!(later.len(), 2);
+    for memory in &later {
+        assert_eq!(memory.layer(), Some(MemoryLayer::LongTerm));
+        assert!(memory.tags().iter().any(MemoryTag::is_trauma));
+        assert!(memory.salience() < 0.9);
+    }
+    assert!(later
+        .iter()
+        .any(|m| m.event_id() == Some(&EventId::new("assault").unwrap())));
+
+    // The stored layers are left as they were
+    let stored = sim.get_anchored_entity(&id).unwrap().entity().memories();
+    assert_eq!(stored.immediate_count(), 1);
+    assert_eq!(stored.short_term_count(), 1);
+}
@@ -4,5 +4,7 @@
 
 mod entity_create_memory_captures_mood;
 mod entity_mood_snapshot_freezes_current_state;
+mod memories_consolidate_over_time;
 mod memory_layers_respect_capacity;
 mod mood_congruent_recall_uses_entity_mood;
+mod salient_events_form_memories_in_state_queries;
@@ -0,0 +1,96 @@
+//! Integration test: high-salience events form memories in `memories_at`.
+//!
+//! Validates that a severe Violence event yields a trauma-tagged memory at
+//! and after the event but not before it, holding the mood at the event
+//! and consolidating into the long-term layer, and that a trivial
+//! interaction yields none.
+
+#![cfg(feature = "memory")]
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{EventType, Species};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::memory::{EmotionalSnapshot, MemoryLayer, MemoryTag};
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EntityId, EventId};
+
+fn setup() -> (Simulation, EntityId, EntityId) {
+    let mut sim = Simulation::new(reference());
+    let mut ids = Vec::new();
+    for id in ["victim", "attacker"] {
+        let entity = EntityBuilder::new()
+            .id(id)
+            .species(Species::Human)
+            .age(Duration::years(30))
+            .build()
+            .unwrap();
+        ids.push(sim.add_entity(entity, reference()));
+    }
+    let attacker = ids.pop().unwrap();
+    let victim = ids.pop().unwrap();
+    (sim, victim, attacker)
+}
+
+/// Tests that a severe assault forms a trauma memory from its onset on.
+#[test]
+fn severe_violence_forms_trauma_memory() {
+    let (mut sim, victim, attacker) = setup();
+    let assault_at = reference() + Duration::days(10);
+    let assault = EventBuilder::new(EventType::Violence)
+        .id(EventId::new("assault").unwrap())
+        .source(attacker.clone())
+        .target(victim.clone())
+        .severity(0.9)
+        .build()
+        .unwrap();
+    sim.add_event(assault, assault_at);
+    let handle = sim.entity(&victim).unwrap();
+
+    assert!(handle
+        .memories_at(assault_at - Duration::hours(1))
+        .is_empty());
+
+    for at in [assault_at, assault_at + Duration::days(365)] {
+        let memories = handle.memories_at(at);
+        assert_eq!(memories.len(), 1);
+        let memory = &memories[0];
+        assert_eq!(memory.event_id(), Some(&EventId::new("assault").unwrap()));
+        assert!(memory.tags().iter().any(MemoryTag::is_trauma));
+        assert_eq!(memory.participants(), [attacker.clone(), victim.clone()]);
+
+        let state = handle.state_at(assault_at);
+        let snapshot = EmotionalSnapshot::from_mood(state.individual_state().mood());
+        assert_eq!(memory.emotional_snapshot(), &snapshot);
+    }
+
+    // Salient when formed, fading but consolidated into long-term a year on
+    assert!(handle.memories_at(assault_at)[0].salience() >= 0.6);
+    let year_on = &handle.memories_at(assault_at + Duration::days(365))[0];
+    assert!(year_on.salience() < 0.6);
+    assert_eq!(year_on.layer(), Some(MemoryLayer::LongTerm));
+
+    // Derived on each query, so repeated queries agree
+    assert_eq!(
+        handle.memories_at(assault_at),
+        handle.memories_at(assault_at)
+    );
+}
+
+/// Tests that a trivial interaction forms no memory.
+#[test]
+fn trivial_interaction_forms_no_memory() {
+    let (mut sim, victim, attacker) = setup();
+    let chat = EventBuilder::new(EventType::Interaction)
+        .source(attacker)
+        .target(victim.clone())
+        .severity(0.1)
+        .build()
+        .unwrap();
+    sim.add_event(chat, reference() + Duration::days(1));
+
+    let handle = sim.entity(&victim).unwrap();
+    assert!(handle
+        .memories_at(reference() + Duration::days(30))
+        .is_empty());
+}
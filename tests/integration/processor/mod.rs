@@ -4,19 +4,25 @@
 //! - processor/event.rs: 7 missed regions, 2 missed lines
 //! - processor/emotions.rs: 1 missed region
 //! - processor/state_evolution.rs: 1 missed region, 1 missed line
+//!
+//! The processor is reached through the deprecated `unstable` shims, so
+//! these tests also pin that the shims keep working.
+
+#![allow(deprecated)]
 
 use behavioral_pathways::entity::EntityBuilder;
 use behavioral_pathways::enums::{
-    Attribution, AttributionStability, DispositionPath, EventCategory, EventPayload, EventType,
-    LifeDomain, MentalHealthPath, MoodPath, NeedsPath, SocialCognitionPath, Species, StatePath,
-    SupportType, RealizationType,
+    Audience, DispositionPath, EventCategory, EventPayload, EventType, LifeDomain,
+    MentalHealthPath, MoodPath, NeedsPath, SocialCognitionPath, Species, StatePath, SupportType,
+    RealizationType,
 };
 use behavioral_pathways::event::EventBuilder;
-use behavioral_pathways::processor::{
-    interpret_event, apply_interpreted_event, derive_emotion, process_event,
+use behavioral_pathways::unstable::{
+    apply_interpreted_event, derive_emotion, get_derived_emotion, interpret_event, process_event,
 };
-use behavioral_pathways::state::{IndividualState, Hexaco};
-use behavioral_pathways::types::EntityId;
+use behavioral_pathways::state::IndividualState;
+use behavioral_pathways::types::{Duration, EntityId};
+use behavioral_pathways::EmotionIntensities;
 
 // ============================================================================
 // processor/emotions.rs coverage
@@ -43,11 +49,11 @@ fn emotions_hostile_octant() {
     assert!(emotions.hostile > 0.0);
 }
 
-/// Test disdainful octant
+/// Test bored octant
 #[test]
-fn emotions_disdainful_octant() {
+fn emotions_bored_octant() {
     let emotions = derive_emotion(-0.8, -0.8, 0.8, 0.0);
-    assert!(emotions.disdainful > 0.0);
+    assert!(emotions.bored > 0.0);
 }
 
 /// Test intensity method with neutral emotion
@@ -168,13 +174,13 @@ fn event_social_with_empty_payload_no_effect() {
         .build()
         .unwrap();
 
-    let mut event = EventBuilder::new(EventType::Interaction)
+    let event = EventBuilder::new(EventType::Interaction)
         .severity(0.5)
         .payload(EventPayload::Empty)
         .build()
         .unwrap();
 
-    event.set_category_for_test(EventCategory::Social);
+    assert_eq!(event.category(), EventCategory::Social);
     let interpreted = interpret_event(&event, &entity);
 
     // Empty payload for social event should not change state
@@ -280,6 +286,7 @@ fn event_achievement_creative_domain_adds_purpose() {
         .payload(EventPayload::Achievement {
             domain: LifeDomain::Creative,
             magnitude: 0.8,
+            audience: Audience::Private,
         })
         .build()
         .unwrap();
@@ -300,19 +307,21 @@ fn event_social_inclusion_with_group_id_extra_loneliness_reduction() {
         .build()
         .unwrap();
 
+    let inclusion = |group_id| {
+        EventBuilder::new(EventType::SocialInclusion)
+            .severity(0.6)
+            .payload(EventPayload::SocialInclusion { group_id })
+            .build()
+            .unwrap()
+    };
     let group_id = behavioral_pathways::types::GroupId::new("group1").unwrap();
-    let event = EventBuilder::new(EventType::SocialInclusion)
-        .severity(0.6)
-        .payload(EventPayload::SocialInclusion {
-            group_id: Some(group_id),
-        })
-        .build()
-        .unwrap();
 
-    let interpreted = interpret_event(&event, &entity);
+    let with_group = interpret_event(&inclusion(Some(group_id)), &entity);
+    let without_group = interpret_event(&inclusion(None), &entity);
 
-    // Has extra -0.08 loneliness reduction on top of base -0.20 (total -0.28)
-    assert!(interpreted.loneliness_delta < -0.2);
+    // Group membership adds loneliness reduction on top of the base effect
+    assert!(without_group.loneliness_delta < 0.0);
+    assert!(with_group.loneliness_delta < without_group.loneliness_delta);
 }
 
 /// Test Support event with effectiveness reduces liability and increases worth
@@ -328,6 +337,7 @@ fn event_support_emotional_reduces_liability_and_worth() {
         .payload(EventPayload::Support {
             support_type: SupportType::Emotional,
             effectiveness: 0.85,
+            audience: Audience::Private,
         })
         .build()
         .unwrap();
@@ -406,15 +416,16 @@ fn event_support_non_emotional_type_no_self_worth_boost() {
     let event = EventBuilder::new(EventType::Support)
         .severity(0.8)
         .payload(EventPayload::Support {
-            support_type: SupportType::Financial,
+            support_type: SupportType::Instrumental,
             effectiveness: 0.9,
+            audience: Audience::Private,
         })
         .build()
         .unwrap();
 
     let interpreted = interpret_event(&event, &entity);
 
-    // Financial support shouldn't add self_worth delta
+    // Instrumental support shouldn't add self_worth delta
     let has_self_worth = interpreted.state_deltas.iter().any(|(path, _)| {
         matches!(path, StatePath::MentalHealth(MentalHealthPath::SelfWorth))
     });
@@ -458,14 +469,14 @@ fn event_apply_non_chronic_loneliness_uses_normal_delta() {
         .build()
         .unwrap();
 
+    let path = StatePath::SocialCognition(SocialCognitionPath::Loneliness);
+    let before = entity.get_effective(path).unwrap_or(0.0);
     let interpreted = interpret_event(&event, &entity);
     apply_interpreted_event(&interpreted, &mut entity);
 
     // Should have decreased loneliness
-    let loneliness = entity
-        .get_effective(StatePath::SocialCognition(SocialCognitionPath::Loneliness))
-        .unwrap_or(0.0);
-    assert!(loneliness < 0.0);
+    let loneliness = entity.get_effective(path).unwrap_or(0.0);
+    assert!(loneliness < before);
 }
 
 // ============================================================================
@@ -490,3 +501,56 @@ fn state_evolution_tiny_delta_not_applied() {
     // Should have minimal state deltas due to epsilon check
     assert!(interpreted.state_deltas.is_empty() || interpreted.state_deltas.len() < 2);
 }
+
+// ============================================================================
+// unstable shims match their stable replacements
+// ============================================================================
+
+/// Test that the emotion shims match EmotionIntensities constructors
+#[test]
+fn unstable_emotion_shims_match_stable_wrappers() {
+    assert_eq!(
+        derive_emotion(-0.8, 0.9, 0.7, 1.0),
+        EmotionIntensities::from_pad(-0.8, 0.9, 0.7, 1.0)
+    );
+
+    let mut state = IndividualState::new();
+    state.mood_mut().add_valence_delta(0.6);
+    assert_eq!(get_derived_emotion(&state), EmotionIntensities::from_state(&state));
+}
+
+/// Test that interpret_event matches Entity::interpret_event and process_event applies it
+#[test]
+fn unstable_event_shims_match_stable_wrappers() {
+    let mut entity = EntityBuilder::new()
+        .species(Species::Human)
+        .build()
+        .unwrap();
+
+    let event = EventBuilder::new(EventType::SocialExclusion)
+        .severity(0.7)
+        .build()
+        .unwrap();
+
+    let interpreted = interpret_event(&event, &entity);
+    let stable = entity.interpret_event(&event);
+    assert_eq!(interpreted.state_deltas, stable.state_deltas);
+
+    let valence = StatePath::Mood(MoodPath::Valence);
+    let before = entity.get_effective(valence).unwrap();
+    let processed = process_event(&event, &mut entity);
+    assert_eq!(processed.state_deltas, stable.state_deltas);
+    assert!(entity.get_effective(valence).unwrap() < before);
+}
+
+/// Test species decay scales elapsed time by the species time scale
+#[test]
+fn apply_species_decay_matches_scaled_apply_decay() {
+    let mut state = IndividualState::new();
+    state.needs_mut().add_stress_delta(0.8);
+    let mut scaled = state.clone();
+
+    state.apply_species_decay(Duration::hours(12), &Species::Human);
+    scaled.apply_decay(Duration::hours(12));
+    assert!((state.needs().stress().delta() - scaled.needs().stress().delta()).abs() < 1e-6);
+}
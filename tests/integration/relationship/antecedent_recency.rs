@@ -0,0 +1,148 @@
+//! Antecedent recency weighting integration tests.
+//!
+//! Tests that `Relationship::trustworthiness_at` weights trust antecedents
+//! by their age, so recent evidence outweighs old evidence and the same
+//! history spread over years reads differently than compressed into months.
+
+use behavioral_pathways::enums::Direction;
+use behavioral_pathways::relationship::{
+    AntecedentDirection, AntecedentHalfLives, AntecedentType, Relationship, RelationshipStage,
+    StakesLevel, TrustAntecedent,
+};
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+const RELIABILITY_COUNT: u64 = 8;
+const BETRAYAL_MAGNITUDE: f32 = 0.8;
+const RELIABILITY_MAGNITUDE: f32 = 0.6;
+
+fn start() -> Timestamp {
+    Timestamp::from_ymd_hms(2020, 1, 1, 0, 0, 0)
+}
+
+/// A botched job at the start, then reliable work spread evenly over `span`.
+///
+/// Returns the relationship and the time of the last antecedent.
+fn history(span: Duration) -> (Relationship, Timestamp) {
+    let alice = EntityId::new("alice").unwrap();
+    let bob = EntityId::new("bob").unwrap();
+    let mut rel = Relationship::try_between(alice, bob)
+        .unwrap()
+        .with_stage(RelationshipStage::Established);
+    rel.append_antecedent(
+        Direction::AToB,
+        TrustAntecedent::new(
+            start(),
+            AntecedentType::Ability,
+            AntecedentDirection::Negative,
+            BETRAYAL_MAGNITUDE,
+            "botched the job",
+        ),
+    );
+    let step = span.as_seconds() / RELIABILITY_COUNT;
+    let mut last = start();
+    for i in 1..=RELIABILITY_COUNT {
+        last = start() + Duration::seconds(step * i);
+        rel.append_antecedent(
+            Direction::AToB,
+            TrustAntecedent::new(
+                last,
+                AntecedentType::Ability,
+                AntecedentDirection::Positive,
+                RELIABILITY_MAGNITUDE,
+                "delivered on time",
+            ),
+        );
+    }
+    (rel, last)
+}
+
+/// Task willingness with the recency-weighted factors at `at`.
+fn task_willingness_at(rel: &Relationship, at: Timestamp) -> f32 {
+    let weighted = rel.trustworthiness_at(Direction::AToB, at);
+    let mut view = rel.clone();
+    *view.trustworthiness_mut(Direction::AToB) = weighted.factors;
+    view.compute_trust_decision(Direction::AToB, 0.5, StakesLevel::Medium)
+        .task_willingness()
+}
+
+/// Expected competence delta from the recency weighting formula.
+fn expected_delta(rel: &Relationship, at: Timestamp) -> f32 {
+    let half_life = rel.antecedent_half_lives().ability.as_days_f64();
+    let (mut sum, mut weights) = (0.0_f64, 0.0_f64);
+    for antecedent in rel.antecedent_history(Direction::AToB) {
+        let age = (at - antecedent.timestamp()).as_days_f64();
+        let weight = 0.5_f64.powf(age / half_life);
+        let signed = match antecedent.direction() {
+            AntecedentDirection::Positive => f64::from(antecedent.magnitude()),
+            AntecedentDirection::Negative => -2.5 * f64::from(antecedent.magnitude()),
+        };
+        sum += signed * weight;
+        weights += weight;
+    }
+    (sum / (weights + 1.0)) as f32
+}
+
+/// Tests that an old betrayal followed by two years of reliability yields
+/// higher task willingness than the same history compressed into two
+/// months.
+///
+/// Validates: the betrayal's weight fades with its age, and the weighted
+/// competence delta matches `weighted_sum / (total_weight + 1)`.
+#[test]
+fn spread_out_recovery_outweighs_compressed_recovery() {
+    let (spread, spread_end) = history(Duration::years(2));
+    let (compressed, compressed_end) = history(Duration::days(60));
+
+    let spread_evidence = spread.trustworthiness_at(Direction::AToB, spread_end);
+    let compressed_evidence = compressed.trustworthiness_at(Direction::AToB, compressed_end);
+    let spread_delta = spread_evidence.competence.values().next().unwrap().delta;
+    let compressed_delta = compressed_evidence
+        .competence
+        .values()
+        .next()
+        .unwrap()
+        .delta;
+    assert!((spread_delta - expected_delta(&spread, spread_end)).abs() < 1e-5);
+    assert!((compressed_delta - expected_delta(&compressed, compressed_end)).abs() < 1e-5);
+    assert!(spread_delta > compressed_delta);
+
+    assert!(
+        task_willingness_at(&spread, spread_end) > task_willingness_at(&compressed, compressed_end)
+    );
+}
+
+/// Tests that integrity evidence outlasts competence evidence by default.
+///
+/// Validates: per-type half-lives, configurable per relationship.
+#[test]
+fn integrity_evidence_decays_slower_than_competence_evidence() {
+    let alice = EntityId::new("alice").unwrap();
+    let bob = EntityId::new("bob").unwrap();
+    let mut rel = Relationship::try_between(alice, bob).unwrap();
+    for antecedent_type in [AntecedentType::Ability, AntecedentType::Integrity] {
+        rel.append_antecedent(
+            Direction::AToB,
+            TrustAntecedent::new(
+                start(),
+                antecedent_type,
+                AntecedentDirection::Positive,
+                0.6,
+                "followed through",
+            ),
+        );
+    }
+    let later = start() + Duration::years(1);
+
+    let weighted = rel.trustworthiness_at(Direction::AToB, later);
+    let competence = weighted.competence.values().next().unwrap();
+    assert!(weighted.integrity.total_weight > competence.total_weight);
+    assert!(weighted.integrity.delta > competence.delta);
+
+    rel.set_antecedent_half_lives(AntecedentHalfLives {
+        ability: Duration::days(730),
+        ..AntecedentHalfLives::default()
+    });
+    let reweighted = rel.trustworthiness_at(Direction::AToB, later);
+    let competence = reweighted.competence.values().next().unwrap();
+    assert_eq!(competence.total_weight, reweighted.integrity.total_weight);
+}
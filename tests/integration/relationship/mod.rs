@@ -3,6 +3,8 @@
 //! Tests for validating the integration between Entity disposition
 //! and Relationship trust computation.
 
+mod antecedent_recency;
 mod bond_management;
 mod entity_relationship_trust_decision_integration;
+mod stage_transitions;
 mod trust_propensity_flows_to_trust_decision;
@@ -0,0 +1,170 @@
+//! Stage transition integration tests.
+//!
+//! Tests that `Relationship::evaluate_stage_transition` promotes a
+//! relationship one stage at a time under sustained positive contact, holds
+//! its stage through a single bad week, and demotes it after a betrayal
+//! followed by prolonged silence.
+
+use behavioral_pathways::enums::Direction;
+use behavioral_pathways::relationship::{
+    AntecedentDirection, AntecedentType, InteractionPattern, Relationship, RelationshipStage,
+    TrustAntecedent,
+};
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+fn start() -> Timestamp {
+    Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+}
+
+fn relationship() -> Relationship {
+    Relationship::try_between(
+        EntityId::new("alice").unwrap(),
+        EntityId::new("bob").unwrap(),
+    )
+    .unwrap()
+}
+
+fn weekly_contact() -> InteractionPattern {
+    InteractionPattern::new()
+        .with_frequency(0.8)
+        .with_consistency(0.9)
+}
+
+/// Records a week of positive time together: trust evidence in both
+/// directions and a little more shared closeness.
+fn positive_week(rel: &mut Relationship, at: Timestamp) {
+    for direction in [Direction::AToB, Direction::BToA] {
+        for antecedent_type in [
+            AntecedentType::Ability,
+            AntecedentType::Benevolence,
+            AntecedentType::Integrity,
+        ] {
+            rel.append_antecedent(
+                direction,
+                TrustAntecedent::new(
+                    at,
+                    antecedent_type,
+                    AntecedentDirection::Positive,
+                    0.5,
+                    "good week together",
+                ),
+            );
+        }
+        let history = rel.antecedent_history(direction).to_vec();
+        rel.trustworthiness_mut(direction)
+            .recompute_from_antecedents(&history);
+    }
+    rel.shared_mut().add_intimacy_delta(0.05);
+    rel.shared_mut().add_history_delta(0.03);
+}
+
+/// Evaluates weekly positive contact until `stage` is reached, returning
+/// every stage change in order.
+fn walk_to(rel: &mut Relationship, stage: RelationshipStage, weeks: u64) -> Vec<RelationshipStage> {
+    let mut changes = Vec::new();
+    for week in 0..weeks {
+        positive_week(rel, start() + Duration::weeks(week));
+        if let Some(next) = rel
+            .evaluate_stage_transition(&[weekly_contact()], Duration::weeks(1))
+            .unwrap()
+        {
+            changes.push(next);
+        }
+        if rel.stage() == stage {
+            break;
+        }
+    }
+    changes
+}
+
+/// Tests that sustained positive interactions walk a relationship from
+/// Stranger to Established.
+///
+/// Validates: the stage passes through every stage in order, never
+/// skipping, and reaches Established.
+#[test]
+fn sustained_positive_contact_walks_stranger_to_friend() {
+    let mut rel = relationship();
+    assert_eq!(rel.stage(), RelationshipStage::Stranger);
+
+    let changes = walk_to(&mut rel, RelationshipStage::Established, 52);
+
+    assert_eq!(
+        changes,
+        vec![
+            RelationshipStage::Acquaintance,
+            RelationshipStage::Established
+        ]
+    );
+}
+
+/// Tests that a betrayal followed by months of silence demotes a close
+/// relationship further than the betrayal alone.
+///
+/// Validates: an Intimate relationship survives one week without contact;
+/// a broken confidence demotes it one stage, to Established; where contact
+/// resumes it stays there, and where it stops it falls on to Acquaintance,
+/// one stage at a time and never estranged.
+#[test]
+fn betrayal_and_silence_demote_intimate_relationship() {
+    let mut rel = relationship();
+    walk_to(&mut rel, RelationshipStage::Intimate, 104);
+    assert_eq!(rel.stage(), RelationshipStage::Intimate);
+
+    let bad_week = rel
+        .evaluate_stage_transition(&[], Duration::weeks(1))
+        .unwrap();
+    assert_eq!(bad_week, None);
+
+    let betrayal = start() + Duration::weeks(60);
+    rel.append_antecedent(
+        Direction::AToB,
+        TrustAntecedent::new(
+            betrayal,
+            AntecedentType::Integrity,
+            AntecedentDirection::Negative,
+            0.4,
+            "broke a confidence",
+        ),
+    );
+    let history = rel.antecedent_history(Direction::AToB).to_vec();
+    rel.trustworthiness_mut(Direction::AToB)
+        .recompute_from_antecedents(&history);
+
+    let mut reconciled = rel.clone();
+    let mut silent = rel;
+    let mut reconciled_stages = Vec::new();
+    let mut silent_stages = Vec::new();
+    for week in 0..26 {
+        if week > 0 {
+            positive_week(&mut reconciled, betrayal + Duration::weeks(week));
+        }
+        let before = reconciled.stage();
+        if let Some(stage) = reconciled
+            .evaluate_stage_transition(&[weekly_contact()], Duration::weeks(1))
+            .unwrap()
+        {
+            assert!(before.can_transition_to(stage));
+            reconciled_stages.push(stage);
+        }
+
+        let before = silent.stage();
+        if let Some(stage) = silent
+            .evaluate_stage_transition(&[], Duration::weeks(1))
+            .unwrap()
+        {
+            assert!(before.can_transition_to(stage));
+            silent_stages.push(stage);
+        }
+    }
+
+    assert_eq!(reconciled_stages[0], RelationshipStage::Established);
+    assert!(reconciled.stage().is_developed());
+    assert_eq!(
+        silent_stages,
+        vec![
+            RelationshipStage::Established,
+            RelationshipStage::Acquaintance
+        ]
+    );
+}
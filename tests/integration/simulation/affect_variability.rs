@@ -0,0 +1,86 @@
+//! Integration tests for affect variability over a rolling window.
+//!
+//! Tests that alternating strong positive and negative events read as
+//! labile mood, while an entity left alone barely varies.
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{EventType, MoodPath, Species, StatePath};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::{Simulation, LABILE_VALENCE_SD};
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+fn simulation() -> (Simulation, EntityId) {
+    let mut sim = Simulation::new(reference());
+    let entity = EntityBuilder::new()
+        .id("person")
+        .species(Species::Human)
+        .birth_date(Timestamp::from_ymd_hms(1990, 1, 1, 0, 0, 0))
+        .build()
+        .unwrap();
+    let id = sim.add_entity(entity, reference());
+    (sim, id)
+}
+
+/// Tests that alternating highs and lows make mood labile.
+///
+/// Validates: strong achievements and humiliations on alternating days
+/// give a valence standard deviation above the labile threshold, large
+/// day-over-day swings, and a range matching the sampled extremes.
+#[test]
+fn alternating_events_make_mood_labile() {
+    let (mut sim, id) = simulation();
+    for day in 1..=14 {
+        let event_type = if day % 2 == 0 {
+            EventType::Achievement
+        } else {
+            EventType::Humiliation
+        };
+        let event = EventBuilder::new(event_type)
+            .target(id.clone())
+            .severity(0.9)
+            .build()
+            .unwrap();
+        sim.add_event(event, reference() + Duration::days(day));
+    }
+
+    let handle = sim.entity(&id).unwrap();
+    let end = reference() + Duration::days(14);
+    let variability = handle.affect_variability(end, Duration::days(14), 15);
+
+    assert!(variability.labile);
+    assert!(variability.valence.std_dev > LABILE_VALENCE_SD);
+    assert!(variability.valence.max_daily_change > 0.3);
+
+    let valence: Vec<f64> = handle
+        .state_range(variability.window_start, end, Duration::days(1))
+        .iter()
+        .map(|(_, state)| state.get_effective(StatePath::Mood(MoodPath::Valence)))
+        .collect();
+    let max = valence.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let min = valence.iter().copied().fold(f64::INFINITY, f64::min);
+    assert!((variability.valence.range - (max - min)).abs() < 1e-12);
+}
+
+/// Tests that an entity without events barely varies.
+///
+/// Validates: every dimension's spread is near zero and the entity is not
+/// flagged labile.
+#[test]
+fn quiet_entity_shows_near_zero_variability() {
+    let (sim, id) = simulation();
+    let handle = sim.entity(&id).unwrap();
+    let end = reference() + Duration::days(60);
+    let variability = handle.affect_variability(end, Duration::days(30), 31);
+
+    assert!(!variability.labile);
+    for spread in [
+        variability.valence,
+        variability.arousal,
+        variability.dominance,
+    ] {
+        assert!(spread.std_dev < 1e-6);
+        assert!(spread.range < 1e-6);
+        assert!(spread.max_daily_change < 1e-6);
+    }
+}
@@ -0,0 +1,212 @@
+//! Integration tests for population-wide alert sweeps.
+//!
+//! Tests a 20-entity population in which three entities are scripted to
+//! cross different ITS thresholds between two weekly sweeps.
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{AlertSeverity, AlertTrigger, EventType, Species};
+#[cfg(feature = "its")]
+use behavioral_pathways::enums::{MentalHealthPath, StatePath};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::{AlertSweepConfig, Simulation};
+use behavioral_pathways::types::{Alert, Duration, EntityId, Timestamp};
+
+#[cfg(feature = "its")]
+const DESIRE: StatePath = StatePath::MentalHealth(MentalHealthPath::SuicidalDesire);
+#[cfg(feature = "its")]
+const RISK: StatePath = StatePath::MentalHealth(MentalHealthPath::AttemptRisk);
+/// The info alerts every at-risk entity carries for its present ITS
+/// factors.
+#[cfg(feature = "its")]
+const FACTORS: [(StatePath, AlertSeverity); 3] = [
+    (
+        StatePath::MentalHealth(MentalHealthPath::ThwartedBelongingness),
+        AlertSeverity::Info,
+    ),
+    (
+        StatePath::MentalHealth(MentalHealthPath::PerceivedBurdensomeness),
+        AlertSeverity::Info,
+    ),
+    (
+        StatePath::MentalHealth(MentalHealthPath::InterpersonalHopelessness),
+        AlertSeverity::Info,
+    ),
+];
+
+fn last_week() -> Timestamp {
+    reference() + Duration::days(1)
+}
+
+fn this_week() -> Timestamp {
+    reference() + Duration::days(8)
+}
+
+/// ITS components at the anchor: loneliness, perceived caring, perceived
+/// liability, self-hate, and acquired capability.
+type Risk = (f32, f32, f32, f32, f32);
+
+fn add(sim: &mut Simulation, id: &str, risk: Option<Risk>) -> EntityId {
+    let mut entity = EntityBuilder::new()
+        .id(id)
+        .species(Species::Human)
+        .age(Duration::years(30))
+        .build()
+        .unwrap();
+    if let Some((loneliness, caring, liability, self_hate, capability)) = risk {
+        let state = entity.individual_state_mut();
+        let social = state.social_cognition_mut();
+        social.loneliness_mut().set_base(loneliness);
+        social.perceived_reciprocal_caring_mut().set_base(caring);
+        social.perceived_liability_mut().set_base(liability);
+        social.self_hate_mut().set_base(self_hate);
+        let mental_health = state.mental_health_mut();
+        mental_health.interpersonal_hopelessness_mut().set_base(0.7);
+        mental_health.acquired_capability_mut().set_base(capability);
+    }
+    sim.add_entity(entity, reference())
+}
+
+fn script(sim: &mut Simulation, id: &EntityId, event_type: EventType) {
+    let event = EventBuilder::new(event_type)
+        .target(id.clone())
+        .severity(0.9)
+        .build()
+        .unwrap();
+    sim.add_event(event, this_week() - Duration::days(1));
+}
+
+/// Builds the population: 16 healthy entities, one with a steady desire
+/// warning, and three scripted to cross thresholds between the sweeps.
+fn population() -> Simulation {
+    let mut sim = Simulation::new(reference());
+    for index in 0..16 {
+        add(&mut sim, &format!("healthy_{index:02}"), None);
+    }
+    add(&mut sim, "steady", Some((0.9, 0.1, 0.8, 0.75, 0.3)));
+
+    // Shaming pushes burdensomeness over the desire warning threshold
+    let shamed = add(&mut sim, "shamed", Some((0.8, 0.2, 0.7, 0.7, 0.5)));
+    script(&mut sim, &shamed, EventType::ShamingEvent);
+    // Already warned for desire; shaming escalates it to critical
+    let escalating = add(&mut sim, "escalating", Some((0.95, 0.05, 0.85, 0.8, 0.55)));
+    script(&mut sim, &escalating, EventType::ShamingEvent);
+    // Already warned for desire; a prior attempt raises capability enough
+    // for attempt risk to cross its warning threshold
+    let capable = add(&mut sim, "capable", Some((0.95, 0.05, 0.85, 0.765, 0.5)));
+    script(&mut sim, &capable, EventType::PriorSuicideAttempt);
+    sim
+}
+
+/// Returns `alerts` followed by the present-factor info alerts.
+#[cfg(feature = "its")]
+fn with_factors(alerts: &[(StatePath, AlertSeverity)]) -> Vec<(StatePath, AlertSeverity)> {
+    alerts.iter().chain(&FACTORS).copied().collect()
+}
+
+#[cfg(feature = "its")]
+fn thresholds(alerts: &[Alert]) -> Vec<(StatePath, AlertSeverity)> {
+    alerts
+        .iter()
+        .map(|alert| match alert.trigger() {
+            AlertTrigger::ThresholdExceeded(path, _) => (*path, alert.severity()),
+            other => panic!("unexpected trigger {other:?}"),
+        })
+        .collect()
+}
+
+fn ids(sweep: &[(EntityId, Vec<Alert>)]) -> Vec<&str> {
+    sweep.iter().map(|(id, _)| id.as_str()).collect()
+}
+
+/// Tests that a sweep reports at-risk entities ranked by composite severity.
+///
+/// Validates: healthy entities are left out, the entity with a critical
+/// alert comes first, and ties in composite severity are ordered by ID.
+#[test]
+#[cfg(feature = "its")]
+fn sweep_ranks_entities_by_composite_severity() {
+    let sim = population();
+    let sweep = sim.alert_sweep(this_week(), AlertSeverity::Info);
+    assert_eq!(sim.entity_count(), 20);
+    assert_eq!(ids(&sweep), ["escalating", "capable", "shamed", "steady"]);
+    assert_eq!(
+        thresholds(&sweep[0].1),
+        with_factors(&[
+            (DESIRE, AlertSeverity::Critical),
+            (RISK, AlertSeverity::Warning)
+        ])
+    );
+    assert_eq!(
+        thresholds(&sweep[1].1),
+        with_factors(&[
+            (DESIRE, AlertSeverity::Warning),
+            (RISK, AlertSeverity::Warning)
+        ])
+    );
+    assert_eq!(
+        thresholds(&sweep[2].1),
+        with_factors(&[(DESIRE, AlertSeverity::Warning)])
+    );
+
+    let critical = sim.alert_sweep(this_week(), AlertSeverity::Critical);
+    assert_eq!(ids(&critical), ["escalating"]);
+    assert_eq!(critical[0].1.len(), 1);
+}
+
+/// Tests that a sweep since a previous one reports only new crossings.
+///
+/// Validates: the steady warning is suppressed, an escalation from warning
+/// to critical is reported, and each scripted entity reports only the
+/// threshold it crossed.
+#[test]
+#[cfg(feature = "its")]
+fn sweep_since_reports_newly_crossed_thresholds() {
+    let sim = population();
+    let previous = sim.alert_sweep(last_week(), AlertSeverity::Warning);
+    assert_eq!(ids(&previous), ["capable", "escalating", "steady"]);
+
+    let config = AlertSweepConfig {
+        since: Some(last_week()),
+        ..Default::default()
+    };
+    let sweep = sim.alert_sweep_with(this_week(), AlertSeverity::Warning, &config);
+    assert_eq!(ids(&sweep), ["escalating", "capable", "shamed"]);
+    assert_eq!(
+        thresholds(&sweep[0].1),
+        [
+            (DESIRE, AlertSeverity::Critical),
+            (RISK, AlertSeverity::Warning)
+        ]
+    );
+    assert_eq!(thresholds(&sweep[1].1), [(RISK, AlertSeverity::Warning)]);
+    assert_eq!(thresholds(&sweep[2].1), [(DESIRE, AlertSeverity::Warning)]);
+}
+
+/// Tests that a sweep can be limited to selected entities.
+///
+/// Validates: only the selected entities are evaluated, unknown IDs are
+/// skipped, and external alerts are ranked with model alerts.
+#[test]
+fn sweep_evaluates_only_selected_entities() {
+    let mut sim = population();
+    let healthy = EntityId::new("healthy_03").unwrap();
+    let flag = Alert::builder()
+        .severity(AlertSeverity::Critical)
+        .trigger(AlertTrigger::custom("clinician_flag"))
+        .build()
+        .unwrap();
+    sim.add_external_alert(&healthy, flag, last_week());
+
+    let config = AlertSweepConfig {
+        entities: Some(vec![
+            healthy.clone(),
+            EntityId::new("healthy_04").unwrap(),
+            EntityId::new("missing").unwrap(),
+        ]),
+        ..Default::default()
+    };
+    let sweep = sim.alert_sweep_with(this_week(), AlertSeverity::Info, &config);
+    assert_eq!(ids(&sweep), ["healthy_03"]);
+    assert!(sweep[0].1[0].is_critical());
+}
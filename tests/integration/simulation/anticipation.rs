@@ -0,0 +1,92 @@
+//! Integration tests for anticipated events.
+//!
+//! Tests that an event tagged `Anticipated` builds arousal and stress across
+//! its anticipation window before it happens, and that backward queries
+//! reverse that build-up like any other increment.
+
+use crate::integration::fixtures::{person, reference, simulation_with_person};
+use behavioral_pathways::enums::{EventTag, EventType, MoodPath, NeedsPath, StatePath};
+use behavioral_pathways::event::{Event, EventBuilder};
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::Duration;
+
+fn job_loss(anticipated: bool) -> Event {
+    let mut builder = EventBuilder::new(EventType::JobLoss)
+        .target(person())
+        .severity(0.9)
+        .anticipation_window(Duration::weeks(1));
+    if anticipated {
+        builder = builder.tag(EventTag::Anticipated);
+    }
+    builder.build().unwrap()
+}
+
+fn stress() -> StatePath {
+    StatePath::Needs(NeedsPath::Stress)
+}
+
+/// Tests that stress rises in the week before an anticipated job loss.
+///
+/// Validates: stress matches the untagged event before the window opens,
+/// then climbs toward the event inside the window.
+#[test]
+fn stress_builds_before_anticipated_event() {
+    let happens = reference() + Duration::days(30);
+    let mut anticipated = simulation_with_person(Duration::years(30));
+    anticipated.add_event(job_loss(true), happens);
+    let mut untagged = simulation_with_person(Duration::years(30));
+    untagged.add_event(job_loss(false), happens);
+
+    let stress_at = |sim: &Simulation, days: u64| {
+        sim.entity(&person())
+            .unwrap()
+            .state_at(reference() + Duration::days(days))
+            .get_effective(stress())
+    };
+
+    // The window opens at day 23
+    assert!((stress_at(&anticipated, 22) - stress_at(&untagged, 22)).abs() < 1e-9);
+    assert!(stress_at(&anticipated, 26) > stress_at(&untagged, 26));
+    assert!(stress_at(&anticipated, 29) > stress_at(&anticipated, 26));
+}
+
+/// Tests that the window alone does not make an event anticipated.
+///
+/// Validates: without the `Anticipated` tag, state before the event stays
+/// at the entity's baseline.
+#[test]
+fn untagged_event_leaves_state_before_it_untouched() {
+    let happens = reference() + Duration::days(30);
+    let mut sim = simulation_with_person(Duration::years(30));
+    sim.add_event(job_loss(false), happens);
+    let baseline = simulation_with_person(Duration::years(30));
+
+    let before = happens - Duration::days(1);
+    let with_event = sim.entity(&person()).unwrap().state_at(before);
+    let without = baseline.entity(&person()).unwrap().state_at(before);
+    assert_eq!(with_event.individual_state(), without.individual_state());
+    assert!(!job_loss(false).is_anticipated());
+    assert!(job_loss(true).is_anticipated());
+}
+
+/// Tests that backward queries reverse anticipation landing in range.
+///
+/// Validates: with the anchor inside the anticipation window, the anchor
+/// state is taken to carry the build-up so far, and regressing past the
+/// window removes it, leaving less stress and arousal than the untagged
+/// event.
+#[test]
+fn backward_query_reverses_anticipation() {
+    let happens = reference() + Duration::days(2);
+    let mut anticipated = simulation_with_person(Duration::years(30));
+    anticipated.add_event(job_loss(true), happens);
+    let mut untagged = simulation_with_person(Duration::years(30));
+    untagged.add_event(job_loss(false), happens);
+
+    let query = reference() - Duration::days(10);
+    let a = anticipated.entity(&person()).unwrap().state_at(query);
+    let b = untagged.entity(&person()).unwrap().state_at(query);
+    for path in [stress(), StatePath::Mood(MoodPath::Arousal)] {
+        assert!(a.get_effective(path) < b.get_effective(path));
+    }
+}
@@ -0,0 +1,154 @@
+//! Integration tests for base absorption of sustained chronic deltas.
+//!
+//! Tests that years of chronic loneliness leave a raised baseline once the
+//! loneliness ends, that the current model recovers fully, and that
+//! regression back through the absorbed years is flagged.
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::EntityBuilder;
+#[cfg(feature = "context")]
+use behavioral_pathways::enums::SpiralType;
+use behavioral_pathways::enums::{EventTag, EventType, SocialCognitionPath, Species, StatePath};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::{ApproximationReason, BaseAbsorptionConfig, Simulation};
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+const LONELINESS: StatePath = StatePath::SocialCognition(SocialCognitionPath::Loneliness);
+
+/// Builds a person excluded every week for five years, with base
+/// absorption set to `config`.
+fn chronically_lonely(config: Option<BaseAbsorptionConfig>) -> (Simulation, EntityId) {
+    let mut sim = Simulation::new(reference());
+    sim.set_base_absorption_config(config);
+    let person = EntityBuilder::new()
+        .id("person")
+        .species(Species::Human)
+        .age(Duration::years(40))
+        .build()
+        .unwrap();
+    let id = sim.add_entity(person, reference());
+    for week in 1..=260 {
+        let exclusion = EventBuilder::new(EventType::SocialExclusion)
+            .target(id.clone())
+            .severity(0.6)
+            .tag(EventTag::ChronicPattern)
+            .build()
+            .unwrap();
+        sim.add_event(exclusion, reference() + Duration::weeks(week));
+    }
+    (sim, id)
+}
+
+fn loneliness_at(sim: &Simulation, id: &EntityId, at: Timestamp) -> f64 {
+    sim.entity(id)
+        .unwrap()
+        .state_at(at)
+        .get_effective(LONELINESS)
+}
+
+/// Tests that five years of chronic loneliness leave an elevated baseline
+/// after a quiet year, where the current model recovers fully.
+///
+/// Validates: with absorption the loneliness a year after the last
+/// exclusion stays above the starting level, and without it the level
+/// returns to the start.
+#[test]
+fn sustained_loneliness_raises_baseline_after_quiet_year() {
+    let quiet_year = reference() + Duration::weeks(260) + Duration::years(1);
+
+    let (current, id) = chronically_lonely(None);
+    let start = loneliness_at(&current, &id, reference());
+    let recovered = loneliness_at(&current, &id, quiet_year);
+    assert!((recovered - start).abs() < 1e-3);
+
+    let (adapted, id) = chronically_lonely(Some(BaseAbsorptionConfig::default()));
+    let during = loneliness_at(&adapted, &id, reference() + Duration::years(3));
+    let after = loneliness_at(&adapted, &id, quiet_year);
+    assert!(during > start);
+    assert!(after - start > 0.05);
+    assert!(after < during);
+    assert!(after - start <= BaseAbsorptionConfig::default().max_shift + 1e-6);
+}
+
+/// Tests that nothing is absorbed when the loneliness lasts less than the
+/// onset.
+///
+/// Validates: a short chronic pattern recovers fully with absorption on.
+#[test]
+fn short_pattern_is_not_absorbed() {
+    let config = BaseAbsorptionConfig {
+        onset: Duration::years(10),
+        ..Default::default()
+    };
+    let (sim, id) = chronically_lonely(Some(config));
+    let start = loneliness_at(&sim, &id, reference());
+    let after = loneliness_at(&sim, &id, reference() + Duration::years(7));
+    assert!((after - start).abs() < 1e-3);
+}
+
+/// Tests that regressing from an anchor after the sustained years moves
+/// the absorbed delta back out of the base and flags the state.
+///
+/// Validates: the regressed state is approximate with reason
+/// `BaseAbsorption`. With context effects, the stress spiral five years
+/// of exclusion built up is listed alongside it, and without absorption
+/// the state is still approximate from the spiral alone.
+#[test]
+fn regression_through_absorbed_years_is_approximate() {
+    let anchor = reference() + Duration::weeks(260) + Duration::days(1);
+    let (current, id) = chronically_lonely(None);
+    let anchored = current.entity(&id).unwrap().state_at(anchor);
+
+    let mut sim = Simulation::new(anchor);
+    sim.set_base_absorption_config(Some(BaseAbsorptionConfig::default()));
+    let mut person = EntityBuilder::new()
+        .id("person")
+        .species(Species::Human)
+        .age(Duration::years(45))
+        .build()
+        .unwrap();
+    *person.individual_state_mut() = anchored.individual_state().clone();
+    let id = sim.add_entity(person, anchor);
+    for week in 1..=260 {
+        let exclusion = EventBuilder::new(EventType::SocialExclusion)
+            .target(id.clone())
+            .severity(0.6)
+            .tag(EventTag::ChronicPattern)
+            .build()
+            .unwrap();
+        sim.add_event(exclusion, reference() + Duration::weeks(week));
+    }
+
+    let earlier = sim
+        .entity(&id)
+        .unwrap()
+        .state_at(reference() + Duration::days(1));
+    assert!(earlier.regression_quality().is_approximate());
+    assert_eq!(
+        earlier.approximate_reasons().first(),
+        Some(&ApproximationReason::BaseAbsorption)
+    );
+    // The stress that builds to a spiral comes from context effects
+    #[cfg(feature = "context")]
+    assert_eq!(
+        earlier.approximate_reasons(),
+        [
+            ApproximationReason::BaseAbsorption,
+            ApproximationReason::FeedbackSpiral(SpiralType::Stress),
+        ]
+    );
+
+    sim.set_base_absorption_config(None);
+    let earlier = sim
+        .entity(&id)
+        .unwrap()
+        .state_at(reference() + Duration::days(1));
+    assert!(!earlier
+        .approximate_reasons()
+        .contains(&ApproximationReason::BaseAbsorption));
+    #[cfg(feature = "context")]
+    assert_eq!(
+        earlier.approximate_reasons(),
+        [ApproximationReason::FeedbackSpiral(SpiralType::Stress)]
+    );
+}
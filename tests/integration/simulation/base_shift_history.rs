@@ -0,0 +1,97 @@
+//! Integration tests for the formative shift audit trail.
+//!
+//! Tests that `ComputedState::base_shift_history()` reports each formative
+//! shift applied since the anchor with the raw request, the age and
+//! modifiers used, and the applied amount.
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{EventType, HexacoPath, Species, StatePath};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::state::CUMULATIVE_CAP;
+use behavioral_pathways::types::{Duration, EntityId};
+
+fn subject() -> EntityId {
+    EntityId::new("subject").unwrap()
+}
+
+/// A 45-year-old who goes through three betrayals a month apart, each
+/// requesting a -0.3 agreeableness shift.
+fn simulation() -> Simulation {
+    let entity = EntityBuilder::new()
+        .id("subject")
+        .species(Species::Human)
+        .birth_date(reference() - Duration::years(45))
+        .build()
+        .unwrap();
+    let mut sim = Simulation::new(reference());
+    sim.add_entity(entity, reference());
+    for month in 1..=3 {
+        let betrayal = EventBuilder::new(EventType::Conflict)
+            .target(subject())
+            .severity(0.9)
+            .with_base_shift(HexacoPath::Agreeableness, -0.3)
+            .build()
+            .unwrap();
+        sim.add_event(betrayal, reference() + Duration::days(30 * month));
+    }
+    sim
+}
+
+/// Tests that stacked same-direction shifts show diminishing returns.
+///
+/// Validates: each of the three -0.3 requests is recorded with its raw
+/// amount and age, applied amounts shrink in magnitude as saturation
+/// grows, each audit sees the cumulative shift of the ones before it, and
+/// the cumulative shift stays within `CUMULATIVE_CAP`.
+#[test]
+fn stacked_shifts_show_diminishing_applied_amounts() {
+    let sim = simulation();
+    let handle = sim.entity(&subject()).unwrap();
+    let state = handle.state_at(reference() + Duration::days(120));
+
+    let history = state.base_shift_history();
+    assert_eq!(history.len(), 3);
+
+    let mut cumulative = 0.0;
+    for record in history {
+        let audit = record.audit().unwrap();
+        assert_eq!(record.trait_path(), HexacoPath::Agreeableness);
+        assert!((record.raw_amount() - -0.3).abs() < f32::EPSILON);
+        assert_eq!(record.age_at_event(), Some(45));
+        assert!(record.clipped_by().is_none());
+        assert!((audit.existing_cumulative - cumulative).abs() < 1e-6);
+        assert!((audit.applied - record.immediate()).abs() < f32::EPSILON);
+        assert!(audit.retained_fraction() < 0.5);
+        cumulative += record.immediate().abs();
+    }
+    assert!(cumulative <= CUMULATIVE_CAP);
+
+    let applied: Vec<f32> = history.iter().map(|record| record.immediate()).collect();
+    assert!(applied.iter().all(|amount| *amount < 0.0));
+    assert!(applied[1].abs() < applied[0].abs() - 0.01);
+    assert!(applied[2].abs() < applied[1].abs() - 0.01);
+}
+
+/// Tests that the history covers only shifts up to the queried timestamp.
+///
+/// Validates: the history is empty at the anchor, holds one record after
+/// the first event, and the trait moved by about what that record says.
+#[test]
+fn history_covers_shifts_up_to_timestamp() {
+    let sim = simulation();
+    let handle = sim.entity(&subject()).unwrap();
+    let agreeableness = StatePath::Hexaco(HexacoPath::Agreeableness);
+
+    let anchor = handle.state_at(reference());
+    assert!(anchor.base_shift_history().is_empty());
+
+    let after_first = handle.state_at(reference() + Duration::days(45));
+    let history = after_first.base_shift_history();
+    assert_eq!(history.len(), 1);
+
+    let moved = after_first.get_effective(agreeableness) - anchor.get_effective(agreeableness);
+    assert!(moved < 0.0);
+    assert!(f64::from(history[0].immediate()).abs() + 0.05 > moved.abs());
+}
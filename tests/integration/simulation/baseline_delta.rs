@@ -0,0 +1,202 @@
+//! Integration tests for structured deltas against a baseline.
+//!
+//! Tests the "what changed since intake" view: the diff against the anchor,
+//! the diff against an earlier computed state or timestamp, and the prose
+//! generated from them.
+
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{
+    EventType, MentalHealthPath, MoodPath, NeedsPath, SocialCognitionPath, Species, StatePath,
+};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::state::SocialCognition;
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+use behavioral_pathways::PB_PRESENT_THRESHOLD;
+
+fn intake() -> Timestamp {
+    Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0)
+}
+
+/// Builds a client whose perceived burdensomeness is below threshold at
+/// intake and rises above it after repeated burden feedback.
+fn scenario() -> (Simulation, EntityId) {
+    let mut sim = Simulation::new(intake());
+    let client = EntityBuilder::new()
+        .id("client")
+        .species(Species::Human)
+        .age(Duration::years(35))
+        .social_cognition(
+            SocialCognition::new()
+                .with_perceived_liability_base(0.65)
+                .with_self_hate_base(0.65),
+        )
+        .build()
+        .unwrap();
+    let id = sim.add_entity(client, intake());
+    for day in [5, 10, 15] {
+        let feedback = EventBuilder::new(EventType::BurdenFeedback)
+            .target(id.clone())
+            .severity(0.8)
+            .build()
+            .unwrap();
+        sim.add_event(feedback, intake() + Duration::days(day));
+    }
+    (sim, id)
+}
+
+/// Tests that PB crossing its threshold between intake and the query is
+/// reported in the baseline delta.
+///
+/// Validates: the golden crossing list (PB only, upward from 0.4225 to
+/// 0.719), the PB change matching the queried values, and an empty delta
+/// at intake.
+#[test]
+fn pb_threshold_crossing_since_intake() {
+    let (sim, id) = scenario();
+    let handle = sim.entity(&id).unwrap();
+    let pb = StatePath::MentalHealth(MentalHealthPath::PerceivedBurdensomeness);
+
+    let at_intake = handle.state_at(intake());
+    assert!(at_intake.baseline_delta().is_empty());
+    assert!(at_intake.baseline_delta().threshold_crossings.is_empty());
+
+    let now = handle.state_at(intake() + Duration::days(16));
+    let delta = now.baseline_delta();
+    assert_eq!(delta.threshold_crossings.len(), 1);
+    let crossing = delta.threshold_crossings[0];
+    assert_eq!(crossing.path, pb);
+    assert!(crossing.is_upward());
+    assert_eq!(crossing.threshold, f64::from(PB_PRESENT_THRESHOLD));
+    assert!((crossing.before - 0.4225).abs() < 1e-3);
+    assert!((crossing.after - 0.719).abs() < 1e-3);
+
+    let change = delta.change(pb).unwrap();
+    assert_eq!(change.before, at_intake.get_effective(pb));
+    assert_eq!(change.after, now.get_effective(pb));
+}
+
+/// Tests that the prose delta summary is generated from the structure.
+///
+/// Validates: each narrated path appears in the prose exactly when the
+/// diff holds a change of at least 0.01 for it.
+#[test]
+fn prose_follows_structured_delta() {
+    let (sim, id) = scenario();
+    let now = sim
+        .entity(&id)
+        .unwrap()
+        .state_at(intake() + Duration::days(16));
+    let delta = now.baseline_delta();
+    let prose = now.delta_summary.clone().unwrap_or_default();
+
+    let narrated = [
+        (StatePath::Mood(MoodPath::Valence), "happier", "sadder"),
+        (
+            StatePath::Needs(NeedsPath::Stress),
+            "more stressed",
+            "less stressed",
+        ),
+        (
+            StatePath::SocialCognition(SocialCognitionPath::Loneliness),
+            "lonelier",
+            "less lonely",
+        ),
+    ];
+    for (path, more, less) in narrated {
+        match delta
+            .change(path)
+            .filter(|change| change.delta().abs() >= 0.01)
+        {
+            Some(change) if change.delta() > 0.0 => assert!(prose.contains(more), "{path:?}"),
+            Some(_) => assert!(prose.contains(less), "{path:?}"),
+            None => {
+                assert!(!prose.contains(more) && !prose.contains(less), "{path:?}");
+            }
+        }
+    }
+    assert!(prose.contains("sadder"));
+}
+
+/// Tests the comparison against a caller-supplied earlier state.
+///
+/// Validates: against the intake state it equals the baseline delta,
+/// against itself it is empty, and against a later review it reports a
+/// smaller PB change.
+#[test]
+fn delta_from_earlier_state() {
+    let (sim, id) = scenario();
+    let handle = sim.entity(&id).unwrap();
+    let pb = StatePath::MentalHealth(MentalHealthPath::PerceivedBurdensomeness);
+    let at_intake = handle.state_at(intake());
+    let last_review = handle.state_at(intake() + Duration::days(11));
+    let now = handle.state_at(intake() + Duration::days(16));
+
+    assert_eq!(&now.delta_from(&at_intake), now.baseline_delta());
+    assert!(now.delta_from(&now).is_empty());
+
+    let since_review = now.delta_from(&last_review).change(pb).unwrap().delta();
+    let since_intake = now.baseline_delta().change(pb).unwrap().delta();
+    assert!(since_review > 0.0);
+    assert!(since_review < since_intake);
+}
+
+/// Tests that a dominant change is ranked first in the handle's diff.
+///
+/// Validates: a day after a severe exclusion, when the mood drop has
+/// mostly decayed, loneliness is the largest change, and the diff matches
+/// `delta_from` on the two states.
+#[test]
+fn diff_ranks_loneliness_spike_first() {
+    let mut sim = Simulation::new(intake());
+    let id = sim.add_entity(
+        EntityBuilder::new()
+            .id("person")
+            .species(Species::Human)
+            .age(Duration::years(30))
+            .build()
+            .unwrap(),
+        intake(),
+    );
+    let exclusion = EventBuilder::new(EventType::SocialExclusion)
+        .target(id.clone())
+        .severity(1.0)
+        .build()
+        .unwrap();
+    let landing = intake() + Duration::days(5);
+    sim.add_event(exclusion, landing);
+
+    let handle = sim.entity(&id).unwrap();
+    let from = landing - Duration::hours(1);
+    let to = landing + Duration::days(1);
+    let diff = handle.diff(from, to);
+    let loneliness = StatePath::SocialCognition(SocialCognitionPath::Loneliness);
+    let top = diff.top_changes(3);
+    assert_eq!(top[0].path, loneliness);
+    assert!(top[0].delta() > 0.0);
+    assert!(top[0].delta().abs() >= top[1].delta().abs());
+    assert_eq!(diff, handle.state_at(to).delta_from(&handle.state_at(from)));
+}
+
+/// Tests that a coarser epsilon suppresses decay noise.
+///
+/// Validates: a month after the last burden feedback, a day of decay
+/// still moves perceived liability by more than `STATE_DIFF_EPSILON`, but
+/// no path by more than 0.01.
+#[test]
+fn diff_epsilon_suppresses_decay_noise() {
+    let (sim, id) = scenario();
+    let handle = sim.entity(&id).unwrap();
+    let from = intake() + Duration::days(45);
+    let to = from + Duration::days(1);
+
+    let liability = StatePath::SocialCognition(SocialCognitionPath::PerceivedLiability);
+    assert!(handle
+        .diff(from, to)
+        .changes
+        .iter()
+        .any(|change| change.path == liability));
+    let coarse = handle.diff_with_epsilon(from, to, 0.01);
+    assert!(coarse.is_empty(), "{:?}", coarse.changes);
+    assert!(coarse.top_changes(5).is_empty());
+}
@@ -0,0 +1,79 @@
+//! Integration tests for behavioral predictions on computed states.
+//!
+//! Tests that `ComputedState::predict()` follows the state events leave
+//! behind: exclusion makes withdrawal more likely and seeking support less
+//! likely, and conflict makes aggression more likely.
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{EventType, SocialCognitionPath, Species, StatePath};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::{BehaviorPrediction, BehaviorQuery, Simulation};
+use behavioral_pathways::types::{Duration, EntityId};
+
+/// Builds a person who experiences `event_type` daily for two weeks.
+fn repeated(event_type: EventType) -> (Simulation, EntityId) {
+    let entity = EntityBuilder::new()
+        .id("person")
+        .species(Species::Human)
+        .age(Duration::years(30))
+        .build()
+        .unwrap();
+    let mut sim = Simulation::new(reference());
+    let id = sim.add_entity(entity, reference());
+    for day in 1..=14 {
+        let event = EventBuilder::new(event_type)
+            .target(id.clone())
+            .severity(0.8)
+            .build()
+            .unwrap();
+        sim.add_event(event, reference() + Duration::days(day));
+    }
+    (sim, id)
+}
+
+/// Tests that repeated exclusion shifts social predictions.
+///
+/// Validates: after two weeks of exclusion, withdrawal is more likely and
+/// seeking support less likely than at the anchor, and loneliness is among
+/// the top contributors to withdrawal.
+#[test]
+fn exclusion_raises_withdrawal_and_lowers_support_seeking() {
+    let (sim, id) = repeated(EventType::SocialExclusion);
+    let handle = sim.entity(&id).unwrap();
+    let before = handle.state_at(reference());
+    let after = handle.state_at(reference() + Duration::days(14));
+
+    let withdrawal = after.predict(BehaviorQuery::WithdrawSocially);
+    assert!(withdrawal.likelihood > before.predict(BehaviorQuery::WithdrawSocially).likelihood);
+    assert!(
+        after.predict(BehaviorQuery::SeekSupport).likelihood
+            < before.predict(BehaviorQuery::SeekSupport).likelihood
+    );
+    assert!(withdrawal
+        .top_contributors(3)
+        .iter()
+        .any(|contributor| contributor.path
+            == StatePath::SocialCognition(SocialCognitionPath::Loneliness)));
+}
+
+/// Tests that repeated conflict makes aggression more likely.
+///
+/// Validates: after two weeks of conflict, aggression under the same
+/// provocation is more likely than at the anchor, and the prediction
+/// matches one made from the computed individual state.
+#[test]
+fn conflict_raises_aggression() {
+    let (sim, id) = repeated(EventType::Conflict);
+    let handle = sim.entity(&id).unwrap();
+    let query = BehaviorQuery::ActAggressively { provocation: 0.6 };
+    let before = handle.state_at(reference()).predict(query);
+    let after_state = handle.state_at(reference() + Duration::days(14));
+    let after = after_state.predict(query);
+
+    assert!(after.likelihood > before.likelihood);
+    assert_eq!(
+        after,
+        BehaviorPrediction::from_state(after_state.individual_state(), query)
+    );
+}
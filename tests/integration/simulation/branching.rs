@@ -0,0 +1,166 @@
+//! Integration tests for counterfactual branching.
+//!
+//! Tests that `Simulation::branch()`, `with_event_excluded()` and
+//! `with_event_added()` give independent simulations whose futures
+//! diverge only where their event lists differ.
+
+use crate::integration::fixtures::{person, reference};
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{EventType, MentalHealthPath, Species, StatePath};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EventId, Timestamp};
+
+const ACQUIRED_CAPABILITY: StatePath =
+    StatePath::MentalHealth(MentalHealthPath::AcquiredCapability);
+
+fn query_time() -> Timestamp {
+    reference() + Duration::weeks(8)
+}
+
+/// Builds a person excluded weekly, with an assault in week 4 if
+/// `assaulted`, returning the simulation and the assault's ID.
+fn history(assaulted: bool) -> (Simulation, EventId) {
+    let entity = EntityBuilder::new()
+        .id("person")
+        .species(Species::Human)
+        .age(Duration::years(30))
+        .build()
+        .unwrap();
+    let mut sim = Simulation::new(reference());
+    let id = sim.add_entity(entity, reference());
+    for week in 1..=6 {
+        let exclusion = EventBuilder::new(EventType::SocialExclusion)
+            .target(id.clone())
+            .severity(0.5)
+            .build()
+            .unwrap();
+        sim.add_event(exclusion, reference() + Duration::weeks(week));
+    }
+    let assault = EventBuilder::new(EventType::Violence)
+        .target(id.clone())
+        .severity(0.9)
+        .build()
+        .unwrap();
+    let assault_id = assault.id().clone();
+    if assaulted {
+        sim.add_event(
+            assault,
+            reference() + Duration::weeks(4) + Duration::days(2),
+        );
+    }
+    (sim, assault_id)
+}
+
+fn effective(sim: &Simulation, path: StatePath) -> f64 {
+    sim.entity(&person())
+        .unwrap()
+        .state_at(query_time())
+        .get_effective(path)
+}
+
+/// Tests that removing an event in a branch changes only what it touched.
+///
+/// Validates: without the assault, acquired capability at the query time
+/// is lower in the branch than in the original, every dimension the
+/// assault alone does not move matches exactly, and the branch matches a
+/// simulation built without the assault.
+#[test]
+fn excluding_violence_changes_only_acquired_capability() {
+    let (original, assault_id) = history(true);
+    let before = effective(&original, ACQUIRED_CAPABILITY);
+    let branch = original.with_event_excluded(&assault_id);
+
+    assert!(effective(&branch, ACQUIRED_CAPABILITY) < effective(&original, ACQUIRED_CAPABILITY));
+    assert_eq!(
+        effective(&original, ACQUIRED_CAPABILITY).to_bits(),
+        before.to_bits()
+    );
+
+    // The dimensions an assault moves on its own, in a quiet history
+    let (quiet, _) = history(false);
+    let alone = quiet.with_event_added(
+        EventBuilder::new(EventType::Violence)
+            .target(person())
+            .severity(0.9)
+            .build()
+            .unwrap(),
+        reference() + Duration::weeks(4) + Duration::days(2),
+    );
+    let moved: Vec<StatePath> = StatePath::all()
+        .into_iter()
+        .filter(|&path| effective(&alone, path) != effective(&quiet, path))
+        .collect();
+    assert!(moved.contains(&ACQUIRED_CAPABILITY));
+
+    for path in StatePath::all() {
+        if !moved.contains(&path) {
+            assert_eq!(
+                effective(&branch, path).to_bits(),
+                effective(&original, path).to_bits(),
+                "{:?}",
+                path
+            );
+        }
+        assert_eq!(
+            effective(&branch, path).to_bits(),
+            effective(&quiet, path).to_bits(),
+            "{:?}",
+            path
+        );
+    }
+}
+
+/// Tests that branches are independent of the original and each other.
+///
+/// Validates: events added to one of many branches appear only in that
+/// branch, and the original keeps its own event list.
+#[test]
+fn branches_diverge_independently() {
+    let (original, _) = history(false);
+    let branches: Vec<Simulation> = (0..200)
+        .map(|index| {
+            let assault = EventBuilder::new(EventType::Violence)
+                .target(person())
+                .severity(0.9)
+                .build()
+                .unwrap();
+            if index % 2 == 0 {
+                original.with_event_added(assault, reference() + Duration::weeks(2))
+            } else {
+                original.branch()
+            }
+        })
+        .collect();
+
+    let unchanged = effective(&original, ACQUIRED_CAPABILITY);
+    assert_eq!(original.events_for(&person()).len(), 6);
+    for (index, branch) in branches.iter().enumerate() {
+        let capability = effective(branch, ACQUIRED_CAPABILITY);
+        if index % 2 == 0 {
+            assert_eq!(branch.events_for(&person()).len(), 7);
+            assert!(capability > unchanged);
+        } else {
+            assert_eq!(branch.events_for(&person()).len(), 6);
+            assert_eq!(capability.to_bits(), unchanged.to_bits());
+        }
+    }
+}
+
+/// Tests that excluding an unknown event leaves an unchanged copy.
+#[test]
+fn excluding_unknown_event_copies_unchanged() {
+    let (original, _) = history(true);
+    let missing = EventId::new("missing").unwrap();
+    let branch = original.with_event_excluded(&missing);
+
+    assert_eq!(branch.events_for(&person()).len(), 7);
+    for path in StatePath::all() {
+        assert_eq!(
+            effective(&branch, path).to_bits(),
+            effective(&original, path).to_bits(),
+            "{:?}",
+            path
+        );
+    }
+}
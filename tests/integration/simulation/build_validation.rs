@@ -0,0 +1,122 @@
+//! Integration tests for build-time validation.
+//!
+//! Tests that `SimulationBuilder::validate()` and `build()` report every
+//! inconsistent input at once, and that `Simulation::validate()` reports
+//! the same inconsistencies when inputs are added one at a time.
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::{Entity, EntityBuilder};
+use behavioral_pathways::enums::{EventType, RelationshipSchema, Species};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::{
+    Simulation, SimulationBuildError, SimulationBuilder, ValidationIssue,
+};
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+fn birth() -> Timestamp {
+    Timestamp::from_ymd_hms(1990, 6, 15, 0, 0, 0)
+}
+
+fn person(id: &str) -> Entity {
+    EntityBuilder::new()
+        .id(id)
+        .species(Species::Human)
+        .birth_date(birth())
+        .build()
+        .unwrap()
+}
+
+/// Tests that build reports every error, not just the first.
+///
+/// Validates: a builder with a duplicate ID, an anchor before birth, an
+/// event for an unknown target, and a relationship with an unknown
+/// partner fails with `Multiple` holding all four, in that order, and
+/// `validate()` returns the same list.
+#[test]
+fn build_reports_every_inconsistency_together() {
+    let maya = EntityId::new("maya").unwrap();
+    let nobody = EntityId::new("nobody").unwrap();
+    let event = EventBuilder::new(EventType::Support)
+        .target(nobody.clone())
+        .build()
+        .unwrap();
+
+    let builder = SimulationBuilder::new(reference())
+        .add_entity(person("maya"), reference())
+        .add_entity(person("maya"), reference())
+        .add_entity(person("early"), birth() - Duration::days(1))
+        .add_event(event, reference() + Duration::days(1))
+        .add_relationship(maya, nobody.clone(), RelationshipSchema::Peer, reference());
+
+    let errors = builder.validate();
+    assert!(matches!(
+        errors.as_slice(),
+        [
+            SimulationBuildError::DuplicateEntityId(_),
+            SimulationBuildError::AnchorBeforeBirth(..),
+            SimulationBuildError::EventReferencesUnknownEntity(_, _),
+            SimulationBuildError::RelationshipReferencesUnknownEntity(_, _),
+        ]
+    ));
+    match builder.build() {
+        Err(SimulationBuildError::Multiple(all)) => assert_eq!(all, errors),
+        other => panic!("expected every error, got {:?}", other.err()),
+    }
+}
+
+/// Tests that a single error is returned as itself.
+///
+/// Validates: an entity anchored a day before birth fails the build with
+/// `AnchorBeforeBirth` naming the entity, birth, and anchor.
+#[test]
+fn single_error_is_not_wrapped() {
+    let anchor = birth() - Duration::days(1);
+    let err = SimulationBuilder::new(reference())
+        .add_entity(person("early"), anchor)
+        .build()
+        .err();
+    assert_eq!(
+        err,
+        Some(SimulationBuildError::AnchorBeforeBirth(
+            EntityId::new("early").unwrap(),
+            birth(),
+            anchor
+        ))
+    );
+}
+
+/// Tests that incremental additions are checked after the fact.
+///
+/// Validates: the same mistakes added with `add_entity` and `add_event`
+/// are reported by `Simulation::validate()` as build errors, and a
+/// consistent simulation reports none.
+#[test]
+fn simulation_validate_reports_build_errors() {
+    let mut sim = Simulation::new(reference());
+    sim.add_entity(person("early"), birth() - Duration::days(1));
+    let event = EventBuilder::new(EventType::Support)
+        .target(EntityId::new("nobody").unwrap())
+        .build()
+        .unwrap();
+    sim.add_event(event, reference());
+
+    let build_errors: Vec<_> = sim
+        .validate()
+        .into_iter()
+        .filter_map(|issue| match issue {
+            ValidationIssue::BuildError(error) => Some(error),
+            _ => None,
+        })
+        .collect();
+    assert!(matches!(
+        build_errors.as_slice(),
+        [
+            SimulationBuildError::AnchorBeforeBirth(..),
+            SimulationBuildError::EventReferencesUnknownEntity(_, _),
+        ]
+    ));
+
+    let mut consistent = Simulation::new(reference());
+    consistent.add_entity(person("maya"), reference());
+    assert!(consistent.validate().is_empty());
+}
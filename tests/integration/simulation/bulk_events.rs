@@ -0,0 +1,143 @@
+//! Integration tests for bulk event insertion.
+//!
+//! Tests that a life history added in one batch gives the same events and
+//! states as adding it event by event, and that a batch or a checked
+//! single event with an unknown entity adds nothing.
+
+use crate::integration::fixtures::{person, reference, simulation_with_person};
+use behavioral_pathways::enums::EventType;
+use behavioral_pathways::event::{Event, EventBuilder};
+use behavioral_pathways::simulation::SimulationBuildError;
+use behavioral_pathways::types::{Duration, EntityId, EventId, Timestamp};
+
+const BIOGRAPHY_EVENTS: usize = 3_000;
+const BIOGRAPHY_DAYS: u64 = 40 * 365;
+
+/// A 40-year biography of several thousand events, one per day, listed
+/// out of chronological order.
+fn biography() -> Vec<(Event, Timestamp)> {
+    let kinds = [
+        EventType::Conflict,
+        EventType::Support,
+        EventType::Achievement,
+        EventType::SocialExclusion,
+        EventType::Loss,
+    ];
+    (0..BIOGRAPHY_EVENTS)
+        .map(|i| {
+            let event = EventBuilder::new(kinds[i % kinds.len()])
+                .id(EventId::new(format!("event_{i:04}")).unwrap())
+                .target(person())
+                .severity(0.2 + 0.1 * (i % 5) as f64)
+                .build()
+                .unwrap();
+            let day = 1 + (i as u64 * 7_919) % BIOGRAPHY_DAYS;
+            (event, reference() + Duration::days(day))
+        })
+        .collect()
+}
+
+/// Tests that a biography added in one batch matches one added event by
+/// event in reverse order.
+///
+/// Validates: both keep the entity's events sorted by timestamp, and
+/// `state_at` agrees across the 40 years.
+#[test]
+fn bulk_history_matches_event_by_event_history() {
+    let mut bulk = simulation_with_person(Duration::years(20));
+    bulk.add_events(biography()).unwrap();
+
+    let mut single = simulation_with_person(Duration::years(20));
+    for (event, timestamp) in biography().into_iter().rev() {
+        single.add_event(event, timestamp);
+    }
+
+    let bulk_events = bulk.events_for(&person());
+    let single_events = single.events_for(&person());
+    assert_eq!(bulk_events.len(), BIOGRAPHY_EVENTS);
+    assert!(bulk_events
+        .windows(2)
+        .all(|pair| pair[0].timestamp() <= pair[1].timestamp()));
+    let bulk_ids: Vec<_> = bulk_events.iter().map(|te| te.event().id()).collect();
+    let single_ids: Vec<_> = single_events.iter().map(|te| te.event().id()).collect();
+    assert_eq!(bulk_ids, single_ids);
+
+    for years in [1, 10, 25, 40] {
+        let at = reference() + Duration::years(years);
+        let from_bulk = bulk.entity(&person()).unwrap().state_at(at);
+        let from_single = single.entity(&person()).unwrap().state_at(at);
+        assert_eq!(
+            from_bulk.individual_state(),
+            from_single.individual_state(),
+            "states differ after {years} years"
+        );
+    }
+}
+
+/// Tests that a batch naming an unknown entity is rejected whole.
+///
+/// Validates: targets are checked before anything is added.
+#[test]
+fn bulk_add_with_unknown_target_adds_nothing() {
+    let mut sim = simulation_with_person(Duration::years(20));
+    let mut events = biography();
+    events.truncate(10);
+    let stranger = EntityId::new("stranger").unwrap();
+    let unknown = EventBuilder::new(EventType::Conflict)
+        .target(stranger.clone())
+        .build()
+        .unwrap();
+    let unknown_id = unknown.id().clone();
+    events.push((unknown, reference() + Duration::days(5)));
+
+    let result = sim.add_events(events);
+    assert_eq!(
+        result,
+        Err(SimulationBuildError::EventReferencesUnknownEntity(
+            unknown_id, stranger
+        ))
+    );
+    assert_eq!(sim.all_events().count(), 0);
+}
+
+/// Tests that a checked single event applies the same entity check as a
+/// batch.
+///
+/// Validates: an unknown witness is rejected and nothing is added, while
+/// a declared external source is accepted.
+#[test]
+fn checked_single_event_rejects_unknown_witness() {
+    let mut sim = simulation_with_person(Duration::years(20));
+    let stranger = EntityId::new("stranger").unwrap();
+    let witnessed = EventBuilder::new(EventType::Conflict)
+        .target(person())
+        .witness(stranger.clone())
+        .build()
+        .unwrap();
+    let witnessed_id = witnessed.id().clone();
+
+    let result = sim.try_add_event(witnessed.clone(), reference() + Duration::days(5));
+    assert_eq!(
+        result,
+        Err(SimulationBuildError::EventReferencesUnknownEntity(
+            witnessed_id.clone(),
+            stranger.clone()
+        ))
+    );
+    assert_eq!(
+        sim.add_events(vec![(witnessed, reference() + Duration::days(5))]),
+        result
+    );
+    assert_eq!(sim.all_events().count(), 0);
+
+    let employer = EntityId::new("employer").unwrap();
+    sim.declare_external_entity(employer.clone());
+    let dismissal = EventBuilder::new(EventType::Conflict)
+        .source(employer)
+        .target(person())
+        .build()
+        .unwrap();
+    sim.try_add_event(dismissal, reference() + Duration::days(6))
+        .unwrap();
+    assert_eq!(sim.events_for(&person()).len(), 1);
+}
@@ -0,0 +1,82 @@
+//! Integration tests for per-entity decay profiles.
+//!
+//! Tests that half-lives overridden with `EntityBuilder::decay_profile()`
+//! drive both forward and backward state queries.
+
+use crate::integration::fixtures::{person, reference};
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{EventType, SocialCognitionPath, Species, StatePath};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::state::DecayProfile;
+use behavioral_pathways::types::Duration;
+
+fn loneliness() -> StatePath {
+    StatePath::SocialCognition(SocialCognitionPath::Loneliness)
+}
+
+fn simulation(profile: Option<DecayProfile>) -> Simulation {
+    let mut builder = EntityBuilder::new()
+        .id("person")
+        .species(Species::Human)
+        .age(Duration::years(30));
+    if let Some(profile) = profile {
+        builder = builder.decay_profile(profile);
+    }
+    let mut sim = Simulation::new(reference());
+    sim.add_entity(builder.build().unwrap(), reference());
+    let exclusion = EventBuilder::new(EventType::SocialExclusion)
+        .target(person())
+        .severity(0.8)
+        .build()
+        .unwrap();
+    sim.add_event(exclusion, reference() + Duration::days(1));
+    sim
+}
+
+/// Tests that a longer loneliness half-life keeps an exclusion's effect.
+///
+/// Validates: a week after the event, loneliness stays higher with a
+/// 2-week half-life than with the 1-day default.
+#[test]
+fn longer_half_life_slows_forward_decay() {
+    let slow = simulation(Some(
+        DecayProfile::new().with_half_life(loneliness(), Duration::weeks(2)),
+    ));
+    let default = simulation(None);
+
+    let query = reference() + Duration::days(8);
+    let slow_value = slow.entity(&person()).unwrap().state_at(query);
+    let default_value = default.entity(&person()).unwrap().state_at(query);
+    assert!(slow_value.get_effective(loneliness()) > default_value.get_effective(loneliness()));
+}
+
+/// Tests that backward queries reverse decay with the overridden half-life.
+///
+/// Validates: regressing from a later anchor recovers the loneliness the
+/// forward query produced.
+#[test]
+fn backward_query_matches_forward_with_custom_half_life() {
+    let profile = DecayProfile::new().with_half_life(loneliness(), Duration::days(3));
+    let sim = simulation(Some(profile.clone()));
+    let later = reference() + Duration::days(10);
+    let forward = sim.entity(&person()).unwrap().state_at(later);
+
+    // Anchor the same person, carrying the forward state, at `later`
+    let entity = EntityBuilder::new()
+        .id("person")
+        .species(Species::Human)
+        .age(Duration::years(30))
+        .social_cognition(forward.individual_state().social_cognition().clone())
+        .decay_profile(profile)
+        .build()
+        .unwrap();
+    let mut backward = Simulation::new(later);
+    backward.add_entity(entity, later);
+
+    let query = reference() + Duration::days(4);
+    let expected = sim.entity(&person()).unwrap().state_at(query);
+    let regressed = backward.entity(&person()).unwrap().state_at(query);
+    let diff = regressed.get_effective(loneliness()) - expected.get_effective(loneliness());
+    assert!(diff.abs() < 1e-4, "diff {diff}");
+}
@@ -0,0 +1,158 @@
+//! Integration tests for the configurable emotion model.
+//!
+//! Tests that the default model reproduces the fixed octant derivation, and
+//! that attribution-gated emotions follow the entity's recent attributions.
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{Emotion, EventType, HexacoPath, Species};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::{EmotionModel, Simulation};
+use behavioral_pathways::state::Mood;
+use behavioral_pathways::types::{Duration, EntityId};
+
+/// Builds a simulation with one entity and an event on day 10.
+///
+/// `honesty_humility` drives self-attribution; `mood` sets the PAD bases.
+fn scenario(
+    honesty_humility: f32,
+    mood: Mood,
+    event_type: EventType,
+    severity: f64,
+) -> (Simulation, EntityId) {
+    let mut sim = Simulation::new(reference());
+    let entity = EntityBuilder::new()
+        .id("person")
+        .species(Species::Human)
+        .age(Duration::years(30))
+        .trait_value(HexacoPath::HonestyHumility, honesty_humility)
+        .mood(mood)
+        .build()
+        .unwrap();
+    let id = sim.add_entity(entity, reference());
+    let event = EventBuilder::new(event_type)
+        .target(id.clone())
+        .severity(severity)
+        .build()
+        .unwrap();
+    sim.add_event(event, reference() + Duration::days(10));
+    (sim, id)
+}
+
+fn positive_mood() -> Mood {
+    Mood::new()
+        .with_valence_base(0.4)
+        .with_arousal_base(0.3)
+        .with_dominance_base(0.4)
+}
+
+fn negative_mood() -> Mood {
+    Mood::new()
+        .with_valence_base(-0.4)
+        .with_arousal_base(0.3)
+        .with_dominance_base(-0.4)
+}
+
+/// Tests that the default model is a golden match for `derived_emotions`.
+///
+/// Validates: every octant intensity is bit-identical before, at, and
+/// after events, forward and backward of the anchor.
+#[test]
+fn default_model_reproduces_derived_emotions() {
+    let (sim, id) = scenario(0.8, negative_mood(), EventType::Failure, 0.9);
+    let handle = sim.entity(&id).unwrap();
+
+    for day in [-30_i64, 0, 10, 11, 45, 400] {
+        let at = if day < 0 {
+            reference() - Duration::days(day.unsigned_abs())
+        } else {
+            reference() + Duration::days(day as u64)
+        };
+        let state = handle.state_at(at);
+        let fixed = state.derived_emotions();
+        let configured = state.derived_emotions_extended().octants;
+        for emotion in Emotion::all() {
+            assert_eq!(
+                fixed.intensity(emotion).to_bits(),
+                configured.intensity(emotion).to_bits(),
+                "{emotion:?} on day {day}"
+            );
+        }
+    }
+}
+
+/// Tests that pride needs a recent achievement the entity credits to
+/// itself.
+///
+/// Validates: pride is zero before the achievement, positive after it for
+/// a self-attributing entity, fades after the window, and stays zero for
+/// an entity that attributes the achievement to circumstances.
+#[test]
+fn pride_follows_self_attributed_achievement() {
+    let (sim, id) = scenario(0.8, positive_mood(), EventType::Achievement, 0.9);
+    let handle = sim.entity(&id).unwrap();
+    let pride = |day: u64| {
+        handle
+            .state_at(reference() + Duration::days(day))
+            .derived_emotions_extended()
+            .get("pride")
+            .unwrap()
+    };
+
+    assert_eq!(pride(5), 0.0);
+    assert!(pride(11) > 0.0);
+    assert!(pride(18) < pride(11));
+    assert_eq!(pride(30), 0.0);
+
+    let (modest, id) = scenario(-0.8, positive_mood(), EventType::Achievement, 0.9);
+    let state = modest
+        .entity(&id)
+        .unwrap()
+        .state_at(reference() + Duration::days(11));
+    assert_eq!(state.derived_emotions_extended().get("pride"), Some(0.0));
+}
+
+/// Tests that shame needs a stable self-blame.
+///
+/// Validates: shame is positive after a severe failure blamed on the self
+/// and zero after a mild one, whose attribution is unstable.
+#[test]
+fn shame_follows_stable_self_blame() {
+    let at = reference() + Duration::days(12);
+    let shame = |severity: f64| {
+        let (sim, id) = scenario(0.8, negative_mood(), EventType::Failure, severity);
+        sim.entity(&id)
+            .unwrap()
+            .state_at(at)
+            .derived_emotions_extended()
+            .get("shame")
+            .unwrap()
+    };
+
+    assert!(shame(0.9) > 0.0);
+    assert_eq!(shame(0.4), 0.0);
+}
+
+/// Tests that custom boundaries change the extended octants only.
+///
+/// Validates: moving the anxious dominance split up makes a mildly
+/// dominant negative state anxious under the model, while
+/// `derived_emotions` is unchanged.
+#[test]
+fn custom_boundaries_leave_derived_emotions_untouched() {
+    let mood = Mood::new()
+        .with_valence_base(-0.5)
+        .with_arousal_base(0.5)
+        .with_dominance_base(0.1);
+    let (mut sim, id) = scenario(0.0, mood, EventType::Interaction, 0.1);
+    let before = sim.entity(&id).unwrap().state_at(reference());
+
+    let mut model = EmotionModel::default();
+    model.anxious.dominance.center = 0.3;
+    sim.set_emotion_model(model);
+    let after = sim.entity(&id).unwrap().state_at(reference());
+
+    assert_eq!(before.derived_emotions_extended().octants.anxious, 0.0);
+    assert!(after.derived_emotions_extended().octants.anxious > 0.0);
+    assert_eq!(before.derived_emotions(), after.derived_emotions());
+}
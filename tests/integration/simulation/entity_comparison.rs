@@ -0,0 +1,164 @@
+//! Integration tests for twin and sibling entity comparisons.
+//!
+//! Tests that a constructed twin pair's outcome differences are attributed
+//! to the component the pair was built to differ in.
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{
+    EventType, HexacoPath, MoodPath, NeedsPath, SocialCognitionPath, Species, StatePath,
+};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::{EntityComparison, Simulation};
+use behavioral_pathways::state::{Hexaco, Mood, Needs};
+use behavioral_pathways::types::{Duration, EntityId};
+
+const VALENCE: StatePath = StatePath::Mood(MoodPath::Valence);
+const LONELINESS: StatePath = StatePath::SocialCognition(SocialCognitionPath::Loneliness);
+
+/// A twin builder with a fixed mood, so HEXACO does not leak into the
+/// anchored mood baseline.
+fn twin(id: &str) -> EntityBuilder {
+    EntityBuilder::new()
+        .id(id)
+        .species(Species::Human)
+        .age(Duration::years(25))
+        .mood(Mood::new())
+}
+
+fn add(sim: &mut Simulation, target: &EntityId, event_type: EventType, day: u64) {
+    let event = EventBuilder::new(event_type)
+        .target(target.clone())
+        .severity(0.8)
+        .build()
+        .unwrap();
+    sim.add_event(event, reference() + Duration::days(day));
+}
+
+fn compare(sim: &Simulation, a: &EntityId, b: &EntityId) -> EntityComparison {
+    sim.compare_entities(a, b, reference() + Duration::days(3))
+        .unwrap()
+}
+
+/// Asserts every path's shares sum to its difference.
+fn assert_additive(comparison: &EntityComparison) {
+    for path in &comparison.paths {
+        let shares = path.traits + path.events + path.anchor;
+        assert!(
+            (shares - path.difference()).abs() < 1e-9,
+            "{:?} shares {} difference {}",
+            path.path,
+            shares,
+            path.difference()
+        );
+    }
+}
+
+/// Tests that twins differing only in neuroticism, sharing every event,
+/// have their differences attributed to traits.
+///
+/// Validates: shared events are paired, HEXACO differences are reported,
+/// and events and anchor shares are zero.
+#[test]
+fn trait_twins_attribute_differences_to_traits() {
+    let mut sim = Simulation::new(reference());
+    let calm = twin("twin_a").hexaco(Hexaco::new().with_neuroticism(-0.6));
+    let anxious = twin("twin_b").hexaco(Hexaco::new().with_neuroticism(0.8));
+    let a = sim.add_entity(calm.build().unwrap(), reference());
+    let b = sim.add_entity(anxious.build().unwrap(), reference());
+    for id in [&a, &b] {
+        add(&mut sim, id, EventType::Conflict, 1);
+        add(&mut sim, id, EventType::SocialExclusion, 2);
+    }
+
+    let comparison = compare(&sim, &a, &b);
+    assert_eq!(comparison.shared_events.len(), 2);
+    assert!(comparison.events_only_a.is_empty() && comparison.events_only_b.is_empty());
+    assert_eq!(comparison.hexaco_differences.len(), 1);
+    assert_eq!(comparison.hexaco_differences[0].0, HexacoPath::Neuroticism);
+
+    let valence = comparison.path(VALENCE).unwrap();
+    assert!(valence.difference() < 0.0, "{:?}", valence);
+    assert_additive(&comparison);
+    for path in &comparison.paths {
+        assert!(
+            path.events.abs() < 1e-9 && path.anchor.abs() < 1e-9,
+            "{:?}",
+            path
+        );
+    }
+}
+
+/// Tests that identical twins differing only in an exclusion have their
+/// differences attributed to events.
+///
+/// Validates: the divergent event is listed for its twin and the traits
+/// and anchor shares are zero.
+#[test]
+fn event_twins_attribute_differences_to_events() {
+    let mut sim = Simulation::new(reference());
+    let a = sim.add_entity(twin("twin_a").build().unwrap(), reference());
+    let b = sim.add_entity(twin("twin_b").build().unwrap(), reference());
+    add(&mut sim, &a, EventType::Conflict, 1);
+    add(&mut sim, &b, EventType::Conflict, 1);
+    add(&mut sim, &b, EventType::SocialExclusion, 2);
+
+    let comparison = compare(&sim, &a, &b);
+    assert_eq!(comparison.shared_events.len(), 1);
+    assert_eq!(comparison.events_only_b.len(), 1);
+    assert!(comparison.hexaco_differences.is_empty());
+
+    let loneliness = comparison.path(LONELINESS).unwrap();
+    assert!(loneliness.difference() > 0.0);
+    assert_additive(&comparison);
+    for path in &comparison.paths {
+        assert!(
+            path.traits.abs() < 1e-9 && path.anchor.abs() < 1e-9,
+            "{:?}",
+            path
+        );
+    }
+}
+
+/// Tests that a pair differing in traits, events, and anchored stress
+/// splits the difference between all three, and that swapping the pair
+/// flips every share.
+///
+/// Validates: shares are additive and antisymmetric, and the anchored
+/// stress difference is attributed to the anchor.
+#[test]
+fn mixed_pair_splits_and_swapping_flips_shares() {
+    let mut sim = Simulation::new(reference());
+    let a = twin("twin_a").hexaco(Hexaco::new().with_neuroticism(-0.4));
+    let b = twin("twin_b")
+        .hexaco(Hexaco::new().with_neuroticism(0.6))
+        .needs(Needs::new().with_stress_base(0.6));
+    let a = sim.add_entity(a.build().unwrap(), reference());
+    let b = sim.add_entity(b.build().unwrap(), reference());
+    add(&mut sim, &b, EventType::Conflict, 1);
+
+    let forward = compare(&sim, &a, &b);
+    let backward = compare(&sim, &b, &a);
+    assert_additive(&forward);
+    assert_additive(&backward);
+
+    let stress = forward.path(StatePath::Needs(NeedsPath::Stress)).unwrap();
+    assert!(stress.difference() > 0.3);
+    assert!(
+        (stress.anchor - stress.difference()).abs() < 0.05,
+        "{:?}",
+        stress
+    );
+
+    let valence = forward.path(VALENCE).unwrap();
+    assert!(
+        valence.traits < 0.0 && valence.events < 0.0,
+        "{:?}",
+        valence
+    );
+    for (there, back) in forward.paths.iter().zip(&backward.paths) {
+        assert!((there.traits + back.traits).abs() < 1e-9);
+        assert!((there.events + back.events).abs() < 1e-9);
+        assert!((there.anchor + back.anchor).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,136 @@
+//! Integration tests for event cascades.
+//!
+//! Tests that rules installed with `Simulation::enable_cascades()` follow
+//! triggering events with synthetic events that state queries apply and
+//! `events_for()` lists, and that cascades end at `MAX_CASCADE_DEPTH`.
+
+use crate::integration::fixtures::{person, reference, simulation_with_person};
+use behavioral_pathways::enums::{EventType, MoodPath, StatePath};
+use behavioral_pathways::event::{Event, EventBuilder, MAX_CASCADE_DEPTH};
+use behavioral_pathways::simulation::{CascadeRule, Simulation};
+use behavioral_pathways::types::{Duration, EventId, Timestamp};
+
+fn event(event_type: EventType, id: &str, severity: f64) -> Event {
+    EventBuilder::new(event_type)
+        .id(EventId::new(id).unwrap())
+        .target(person())
+        .severity(severity)
+        .build()
+        .unwrap()
+}
+
+/// A betrayal at severity 0.6 or more leads to a conflict three days later.
+fn betrayal_to_conflict() -> CascadeRule {
+    let conflict = EventBuilder::new(EventType::Conflict)
+        .severity(0.7)
+        .build()
+        .unwrap();
+    CascadeRule::new(EventType::Betrayal, 0.6, conflict, Duration::days(3))
+}
+
+/// Tests that a severe betrayal is followed by a synthetic conflict.
+///
+/// Validates: the conflict is listed by `events_for()` three days after the
+/// betrayal and flagged as synthetic, the valence trajectory matches a
+/// simulation without cascades until the conflict and is lower after it,
+/// and a mild betrayal sets off nothing.
+#[test]
+fn betrayal_above_threshold_generates_conflict() {
+    let betrayal_at = reference() + Duration::days(1);
+    let mut plain = simulation_with_person(Duration::years(30));
+    plain.add_event(event(EventType::Betrayal, "betrayal", 0.8), betrayal_at);
+    let mut cascading = simulation_with_person(Duration::years(30));
+    cascading.enable_cascades(vec![betrayal_to_conflict()]);
+    cascading.add_event(event(EventType::Betrayal, "betrayal", 0.8), betrayal_at);
+
+    let events = cascading.events_for(&person());
+    assert_eq!(events.len(), 2);
+    assert!(!events[0].is_synthetic());
+    let conflict = events[1];
+    assert!(conflict.is_synthetic());
+    assert_eq!(conflict.event().event_type(), EventType::Conflict);
+    assert_eq!(conflict.timestamp(), betrayal_at + Duration::days(3));
+    assert_eq!(conflict.cascade_trigger().unwrap().as_str(), "betrayal");
+
+    let valence = StatePath::Mood(MoodPath::Valence);
+    let value_at = |sim: &Simulation, at: Timestamp| {
+        sim.entity(&person())
+            .unwrap()
+            .state_at(at)
+            .get_effective(valence)
+    };
+    let before_conflict = betrayal_at + Duration::days(2);
+    assert_eq!(
+        value_at(&cascading, before_conflict),
+        value_at(&plain, before_conflict)
+    );
+    let after_conflict = betrayal_at + Duration::days(3);
+    assert!(
+        value_at(&cascading, after_conflict) < value_at(&plain, after_conflict) - 0.01,
+        "The conflict should lower valence further"
+    );
+
+    let mut mild = simulation_with_person(Duration::years(30));
+    mild.enable_cascades(vec![betrayal_to_conflict()]);
+    mild.add_event(event(EventType::Betrayal, "betrayal", 0.4), betrayal_at);
+    assert_eq!(mild.events_for(&person()).len(), 1);
+}
+
+/// Tests that rules triggering each other stop at the depth limit.
+///
+/// Validates: a betrayal-conflict loop adds exactly `MAX_CASCADE_DEPTH`
+/// follow-ons, a day apart, and state queries across them complete.
+#[test]
+fn mutually_triggering_rules_are_depth_limited() {
+    let mut sim = simulation_with_person(Duration::years(30));
+    let conflict = EventBuilder::new(EventType::Conflict)
+        .severity(0.7)
+        .build()
+        .unwrap();
+    let betrayal = EventBuilder::new(EventType::Betrayal)
+        .severity(0.7)
+        .build()
+        .unwrap();
+    sim.enable_cascades(vec![
+        CascadeRule::new(EventType::Betrayal, 0.5, conflict, Duration::days(1)),
+        CascadeRule::new(EventType::Conflict, 0.5, betrayal, Duration::days(1)),
+    ]);
+    sim.add_event(event(EventType::Betrayal, "betrayal", 0.8), reference());
+
+    let events = sim.events_for(&person());
+    assert_eq!(events.len(), 1 + MAX_CASCADE_DEPTH);
+    assert_eq!(
+        events.last().unwrap().timestamp(),
+        reference() + Duration::days(MAX_CASCADE_DEPTH as u64)
+    );
+    let state = sim
+        .entity(&person())
+        .unwrap()
+        .state_at(reference() + Duration::days(30));
+    assert!(state.get_effective(StatePath::Mood(MoodPath::Valence)) < 0.0);
+}
+
+/// Tests that the cascade follows its trigger's lifecycle and the rules.
+///
+/// Validates: enabling cascades expands events already stored, replacing
+/// the rules removes the old follow-ons, and removing a trigger removes
+/// its follow-ons.
+#[test]
+fn cascades_follow_rules_and_triggers() {
+    let mut sim = simulation_with_person(Duration::years(30));
+    sim.add_event(
+        event(EventType::Betrayal, "betrayal", 0.8),
+        reference() + Duration::days(1),
+    );
+    sim.enable_cascades(vec![betrayal_to_conflict()]);
+    assert_eq!(sim.events_for(&person()).len(), 2);
+    assert_eq!(sim.cascade_rules().len(), 1);
+
+    sim.enable_cascades(Vec::new());
+    assert_eq!(sim.events_for(&person()).len(), 1);
+
+    sim.enable_cascades(vec![betrayal_to_conflict()]);
+    let removed = sim.remove_event(&EventId::new("betrayal").unwrap());
+    assert!(removed.is_some_and(|te| !te.is_synthetic()));
+    assert!(sim.events_for(&person()).is_empty());
+}
@@ -0,0 +1,122 @@
+//! Integration tests for composable event filters.
+//!
+//! Tests that one `EventFilter` tree selects the same events from a live
+//! `EventBus` subscription and from `Simulation::events_matching()` over
+//! the stored history.
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{EventCategory, EventScope, EventTag, EventType, Species};
+use behavioral_pathways::event::{Event, EventBuilder, EventBus, EventFilter};
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EntityId, EventId, GroupId};
+
+fn event(id: &str, event_type: EventType, severity: f64, target: &str) -> Event {
+    EventBuilder::new(event_type)
+        .id(EventId::new(id).unwrap())
+        .target(EntityId::new(target).unwrap())
+        .severity(severity)
+        .build()
+        .unwrap()
+}
+
+/// Builds a simulation with a two-person crew and a bystander, and the
+/// events that happen to them.
+fn scenario() -> (Simulation, Vec<Event>) {
+    let mut sim = Simulation::new(reference());
+    for id in ["ana", "ben", "cal"] {
+        let entity = EntityBuilder::new()
+            .id(id)
+            .species(Species::Human)
+            .build()
+            .unwrap();
+        sim.add_entity(entity, reference());
+    }
+    let crew = GroupId::new("crew").unwrap();
+    sim.add_group_member(crew.clone(), EntityId::new("ana").unwrap());
+    sim.add_group_member(crew, EntityId::new("ben").unwrap());
+
+    let cover_up = EventBuilder::new(EventType::Betrayal)
+        .id(EventId::new("cover_up").unwrap())
+        .target(EntityId::new("cal").unwrap())
+        .severity(0.2)
+        .tag(EventTag::MoralViolation)
+        .build()
+        .unwrap();
+    let events = vec![
+        event("ambush", EventType::CombatExposure, 0.9, "ben"),
+        event("scuffle", EventType::Violence, 0.3, "ana"),
+        event("mugging", EventType::Violence, 0.8, "cal"),
+        event("praise", EventType::Support, 0.9, "ana"),
+        cover_up,
+        event("assault", EventType::Violence, 0.7, "ana"),
+    ];
+    for (day, event) in (1..).zip(events.iter().rev()) {
+        sim.add_event(event.clone(), reference() + Duration::days(day));
+    }
+    (sim, events)
+}
+
+/// Severe trauma to the crew, or any moral violation.
+fn watch_filter(sim: &Simulation) -> EventFilter {
+    let crew = sim.group_members(&GroupId::new("crew").unwrap());
+    EventFilter::category(EventCategory::Trauma)
+        .and(EventFilter::severity_range(0.6, 1.0))
+        .and(EventFilter::target_in(crew))
+        .or(EventFilter::tag(EventTag::MoralViolation))
+}
+
+fn ids<'a>(events: impl IntoIterator<Item = &'a Event>) -> Vec<&'a str> {
+    events
+        .into_iter()
+        .map(|event| event.id().as_str())
+        .collect()
+}
+
+/// Tests that a retrospective query returns the matching events in time
+/// order.
+///
+/// Validates: severe crew trauma and the moral violation match; mild
+/// crew trauma, trauma outside the crew and support do not; and the
+/// results are sorted by timestamp.
+#[test]
+fn events_matching_selects_stored_events() {
+    let (sim, _) = scenario();
+    let matched = sim.events_matching(&watch_filter(&sim));
+
+    assert_eq!(
+        ids(matched.iter().map(|te| te.event())),
+        ["assault", "cover_up", "ambush"]
+    );
+    assert!(matched
+        .windows(2)
+        .all(|pair| pair[0].timestamp() <= pair[1].timestamp()));
+}
+
+/// Tests that a subscription and a retrospective query agree.
+///
+/// Validates: dispatching the same events through an `EventBus` delivers
+/// exactly the events `events_matching` returns, and the negated filter
+/// selects the rest.
+#[test]
+fn subscription_and_query_agree() {
+    let (sim, events) = scenario();
+    let filter = watch_filter(&sim);
+    let mut bus = EventBus::new();
+    let watched = bus.subscribe(filter.clone());
+    let unwatched = bus.subscribe(filter.clone().not());
+    for event in events.iter().rev() {
+        bus.dispatch(event.clone(), EventScope::Global);
+    }
+
+    let delivered = bus.poll(&watched);
+    let queried = sim.events_matching(&filter);
+    assert_eq!(ids(&delivered), ids(queried.iter().map(|te| te.event())));
+
+    let rest = sim.events_matching(&filter.not());
+    assert_eq!(rest.len() + queried.len(), events.len());
+    assert_eq!(
+        ids(&bus.poll(&unwatched)),
+        ids(rest.iter().map(|te| te.event()))
+    );
+}
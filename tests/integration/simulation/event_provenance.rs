@@ -0,0 +1,119 @@
+//! Integration tests for event provenance.
+//!
+//! Tests that source-system metadata travels with ingested events and has
+//! no effect on how they are interpreted.
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{EventType, PersonalityProfile, Species, StatePath};
+use behavioral_pathways::event::{EventBuilder, EventFilter, Provenance};
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EntityId, EventId};
+
+fn provenance(system: &str, external_id: &str) -> Provenance {
+    Provenance {
+        system: system.to_string(),
+        external_id: external_id.to_string(),
+        ingested_at: reference() + Duration::days(40),
+    }
+}
+
+/// Builds a simulation with a loss and a conflict, optionally recording
+/// where each was ingested from.
+fn scenario(with_provenance: bool) -> Simulation {
+    let mut sim = Simulation::new(reference());
+    let person = EntityBuilder::new()
+        .id("patient")
+        .species(Species::Human)
+        .age(Duration::years(42))
+        .personality(PersonalityProfile::Anxious)
+        .build()
+        .unwrap();
+    let id = sim.add_entity(person, reference());
+
+    let events = [
+        ("evt_loss", EventType::Loss, 0.7, 10, ("ehr", "enc-101")),
+        (
+            "evt_conflict",
+            EventType::Conflict,
+            0.5,
+            25,
+            ("survey", "resp-9"),
+        ),
+    ];
+    for (event_id, event_type, severity, day, (system, external_id)) in events {
+        let mut builder = EventBuilder::new(event_type)
+            .id(EventId::new(event_id).unwrap())
+            .target(id.clone())
+            .severity(severity);
+        if with_provenance {
+            builder = builder.provenance(provenance(system, external_id));
+        }
+        sim.add_event(builder.build().unwrap(), reference() + Duration::days(day));
+    }
+    sim
+}
+
+/// Tests that provenance has zero effect on interpretation.
+///
+/// Validates: every state path is identical with and without provenance at
+/// several timestamps, before, between, and after the events.
+#[test]
+fn provenance_does_not_affect_state() {
+    let plain = scenario(false);
+    let tagged = scenario(true);
+    let id = EntityId::new("patient").unwrap();
+
+    for day in [5, 10, 11, 25, 60, 400] {
+        let at = reference() + Duration::days(day);
+        let a = plain.entity(&id).unwrap().state_at(at);
+        let b = tagged.entity(&id).unwrap().state_at(at);
+        for path in StatePath::all() {
+            assert_eq!(
+                a.get_effective(path).to_bits(),
+                b.get_effective(path).to_bits(),
+                "{path:?} on day {day}"
+            );
+        }
+    }
+}
+
+/// Tests that provenance is exposed on stored events, filterable, and
+/// reported by the diff tool.
+///
+/// Validates: `TimestampedEvent::provenance`, `EventFilter::with_source_system`,
+/// and `Simulation::diff` listing provenance as the only changed field.
+#[test]
+fn provenance_is_queryable_and_diffed() {
+    let plain = scenario(false);
+    let tagged = scenario(true);
+
+    let loss = tagged
+        .all_events()
+        .find(|te| te.event().event_type() == EventType::Loss)
+        .unwrap();
+    assert_eq!(loss.provenance(), Some(&provenance("ehr", "enc-101")));
+
+    let from_ehr = EventFilter::new().with_source_system("ehr");
+    let matched: Vec<_> = tagged
+        .all_events()
+        .filter(|te| from_ehr.matches(te.event()))
+        .map(|te| te.event().id().as_str().to_string())
+        .collect();
+    assert_eq!(matched, vec!["evt_loss".to_string()]);
+    assert_eq!(
+        plain
+            .all_events()
+            .filter(|te| from_ehr.matches(te.event()))
+            .count(),
+        0
+    );
+
+    let diff = plain.diff(&tagged);
+    assert_eq!(diff.modified_events.len(), 2);
+    for event in &diff.modified_events {
+        assert_eq!(event.changes.len(), 1);
+        assert_eq!(event.changes[0].field, "provenance");
+        assert_eq!(event.changes[0].before, "none");
+    }
+}
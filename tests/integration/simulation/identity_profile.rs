@@ -0,0 +1,167 @@
+//! Integration tests for narrative identity profiles.
+//!
+//! Tests two scripted lives: a stable, purposeful one and a chaotic one
+//! full of upheaval, which should sit at opposite ends of the coherence
+//! score.
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{
+    Audience, EventPayload, EventType, HexacoPath, LifeDomain, RealizationType, Species,
+};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::memory::{EmotionalSnapshot, MemoryEntry, MemoryLayer, MemoryTag};
+use behavioral_pathways::simulation::{IdentityProfile, Simulation};
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+fn query() -> Timestamp {
+    reference() + Duration::years(4)
+}
+
+fn achievement(id: &EntityId, magnitude: f64) -> EventBuilder {
+    EventBuilder::new(EventType::Achievement)
+        .target(id.clone())
+        .payload(EventPayload::Achievement {
+            domain: LifeDomain::Work,
+            magnitude,
+            audience: Audience::Private,
+        })
+}
+
+/// Builds a 34-year-old anchored at the reference date with milestone
+/// memories of the given valences, formed at ages 20, 25, and 30.
+fn simulation(memory_valences: [f32; 3]) -> (Simulation, EntityId) {
+    let mut entity = EntityBuilder::new()
+        .id("person")
+        .species(Species::Human)
+        .age(Duration::years(34))
+        .birth_date(reference() - Duration::years(34))
+        .build()
+        .unwrap();
+    for (age, valence) in [20, 25, 30].into_iter().zip(memory_valences) {
+        let memory = MemoryEntry::new(Duration::years(age), format!("Turning point at {age}"))
+            .with_tags(vec![MemoryTag::Milestone])
+            .with_emotional_snapshot(EmotionalSnapshot::new(valence, 0.3, 0.0))
+            .with_salience(0.9);
+        entity.memories_mut().add(MemoryLayer::Legacy, memory);
+    }
+    let mut sim = Simulation::new(reference());
+    let id = sim.add_entity(entity, reference());
+    (sim, id)
+}
+
+/// A life of steady weekly work achievements, warm defining memories, and
+/// a slow conscientiousness gain each year.
+fn stable_life() -> (Simulation, EntityId) {
+    let (mut sim, id) = simulation([0.7, 0.6, 0.8]);
+    for week in 0..(4 * 52) {
+        let mut builder = achievement(&id, 0.5).severity(0.4);
+        if week % 52 == 26 {
+            builder = builder.with_base_shift(HexacoPath::Conscientiousness, 0.05);
+        }
+        sim.add_event(
+            builder.build().unwrap(),
+            reference() + Duration::weeks(week + 1),
+        );
+    }
+    (sim, id)
+}
+
+/// A life of bursts of insight and achievement broken by losses and
+/// violence, painful defining memories, and personality shifts that keep
+/// reversing.
+fn chaotic_life() -> (Simulation, EntityId) {
+    let (mut sim, id) = simulation([-0.8, 0.6, -0.7]);
+    for cycle in 0..8u64 {
+        let start = reference() + Duration::days(cycle * 180 + 1);
+        let direction = if cycle % 2 == 0 { 1.0 } else { -1.0 };
+        let insight = EventBuilder::new(EventType::Realization)
+            .target(id.clone())
+            .severity(0.9)
+            .payload(EventPayload::Realization {
+                realization_type: RealizationType::ExistentialInsight,
+            })
+            .build()
+            .unwrap();
+        sim.add_event(insight, start);
+        for day in [3, 6, 9] {
+            let burst = achievement(&id, 1.0).severity(0.9).build().unwrap();
+            sim.add_event(burst, start + Duration::days(day));
+        }
+        let upheaval = EventBuilder::new(if cycle % 2 == 0 {
+            EventType::Loss
+        } else {
+            EventType::Violence
+        })
+        .target(id.clone())
+        .severity(0.9)
+        .with_base_shift(HexacoPath::Neuroticism, 0.3 * direction)
+        .with_base_shift(HexacoPath::Agreeableness, -0.3 * direction)
+        .build()
+        .unwrap();
+        sim.add_event(upheaval, start + Duration::days(90));
+    }
+    (sim, id)
+}
+
+fn profile((sim, id): (Simulation, EntityId)) -> IdentityProfile {
+    sim.entity(&id).unwrap().identity_profile_at(query())
+}
+
+/// Tests that a stable, purposeful life scores high on coherence.
+///
+/// Validates: positive memory balance, a single consistent trait shift,
+/// low purpose variance, and a composite above 0.75.
+#[test]
+fn stable_life_is_coherent() {
+    let profile = profile(stable_life());
+
+    assert_eq!(profile.identity_memories, 3);
+    assert_eq!(profile.positive_memories, 3);
+    assert!(profile.valence_balance > 0.6);
+    assert_eq!(profile.trait_shifts.len(), 1);
+    let shift = profile.trait_shifts[0];
+    assert_eq!(shift.trait_path, HexacoPath::Conscientiousness);
+    assert_eq!(shift.count, 4);
+    assert!((shift.net - shift.total).abs() < 1e-9);
+    assert!(profile.coherence > 0.75, "coherence {}", profile.coherence);
+}
+
+/// Tests that a chaotic, high-upheaval life scores low on coherence.
+///
+/// Validates: negative memory balance, reversing trait shifts with near
+/// zero net, high purpose variance, and a composite below 0.4.
+#[test]
+fn chaotic_life_is_incoherent() {
+    let profile = profile(chaotic_life());
+
+    assert_eq!(profile.identity_memories, 3);
+    assert_eq!(profile.negative_memories, 2);
+    assert!(profile.valence_balance < 0.0);
+    assert_eq!(profile.trait_shifts.len(), 2);
+    for shift in &profile.trait_shifts {
+        assert!(shift.net.abs() < shift.total * 0.5);
+    }
+    assert!(profile.coherence < 0.4, "coherence {}", profile.coherence);
+}
+
+/// Tests that the profile is deterministic and explains its score.
+///
+/// Validates: repeated queries return identical profiles, and the
+/// coherence equals the weighted composite of its components.
+#[test]
+fn profile_is_deterministic_and_explained() {
+    let (sim, id) = chaotic_life();
+    let handle = sim.entity(&id).unwrap();
+    let first = handle.identity_profile_at(query());
+    let second = handle.identity_profile_at(query());
+    assert_eq!(first, second);
+    assert_eq!(first.coherence, first.components.composite());
+    for component in [
+        first.components.memory,
+        first.components.formative,
+        first.components.purpose,
+    ] {
+        assert!((0.0..=1.0).contains(&component));
+    }
+}
@@ -0,0 +1,101 @@
+//! Integration tests for leave-one-event-out influence ranking.
+//!
+//! Tests that the events a state owes the most to rank first, and that
+//! influences add up to the total change when event effects reinforce
+//! each other.
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{EventType, HexacoPath, Species, StatePath};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EntityId};
+
+fn setup() -> (Simulation, EntityId) {
+    let mut sim = Simulation::new(reference());
+    let entity = EntityBuilder::new()
+        .id("person_001")
+        .species(Species::Human)
+        .age(Duration::years(30))
+        .build()
+        .unwrap();
+    let id = sim.add_entity(entity, reference());
+    (sim, id)
+}
+
+/// Adds an event of the given type and severity at `day` after the anchor.
+fn add(sim: &mut Simulation, id: &EntityId, event_type: EventType, severity: f64, day: u64) {
+    let event = EventBuilder::new(event_type)
+        .target(id.clone())
+        .severity(severity)
+        .build()
+        .unwrap();
+    sim.add_event(event, reference() + Duration::days(day));
+}
+
+/// Tests that a formative trauma outranks everyday events.
+///
+/// Validates: the event with the largest leave-one-out state difference
+/// ranks first, and every event is ranked.
+#[test]
+fn formative_trauma_ranks_first() {
+    let (mut sim, id) = setup();
+    add(&mut sim, &id, EventType::SocialExclusion, 0.3, 1);
+    add(&mut sim, &id, EventType::Conflict, 0.4, 3);
+    let assault = EventBuilder::new(EventType::Violence)
+        .target(id.clone())
+        .severity(0.9)
+        .with_base_shift(HexacoPath::Neuroticism, 0.2)
+        .build()
+        .unwrap();
+    let assault_id = assault.id().clone();
+    sim.add_event(assault, reference() + Duration::days(5));
+    add(&mut sim, &id, EventType::Achievement, 0.3, 8);
+    add(&mut sim, &id, EventType::SocialInclusion, 0.3, 9);
+
+    let ranking = sim
+        .entity(&id)
+        .unwrap()
+        .influence_ranking(reference() + Duration::days(10));
+    assert_eq!(ranking.len(), 5);
+    assert_eq!(ranking[0].0, assault_id);
+    assert!(ranking.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+    assert!(ranking[0].1 > 2.0 * ranking[1].1);
+}
+
+/// Tests that influences sum to roughly the total change when events push
+/// the state the same way.
+///
+/// Validates: for reinforcing events, the sum of leave-one-out influences
+/// tracks the distance between the anchor state and the queried state.
+#[test]
+fn influences_track_total_change_for_reinforcing_events() {
+    let (mut sim, id) = setup();
+    for day in 1..=4 {
+        add(&mut sim, &id, EventType::SocialExclusion, 0.4, day);
+    }
+    let at = reference() + Duration::days(5);
+    let handle = sim.entity(&id).unwrap();
+
+    let total: f64 = handle
+        .influence_ranking(at)
+        .iter()
+        .map(|(_, influence)| influence)
+        .sum();
+    let anchor = handle.state_at(reference());
+    let queried = handle.state_at(at);
+    let change = StatePath::all()
+        .into_iter()
+        .map(|path| (queried.get_effective(path) - anchor.get_effective(path)).powi(2))
+        .sum::<f64>()
+        .sqrt();
+
+    assert!(change > 0.0);
+    let ratio = total / change;
+    assert!(
+        (0.8..1.25).contains(&ratio),
+        "sum {} change {}",
+        total,
+        change
+    );
+}
@@ -0,0 +1,149 @@
+//! Integration tests for interaction history on relationships.
+//!
+//! Tests that `Interaction` events between a related pair build up the
+//! interaction pattern of the relationship returned by
+//! `Simulation::relationship_at()`: how often and how long they meet,
+//! which topics they cover, and the shared history that grows from it,
+//! counting only the interactions that had happened by the query time.
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::context::INTERACTION_FREQUENCY_THRESHOLD;
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{
+    EventPayload, EventType, InteractionTopic, RelationshipSchema, Species,
+};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EntityId, RelationshipId, Timestamp};
+
+fn six_months() -> Timestamp {
+    reference() + Duration::days(182)
+}
+
+/// Builds alice and bob with a peer relationship formed at the reference.
+fn friends() -> (Simulation, EntityId, EntityId, RelationshipId) {
+    let mut sim = Simulation::new(reference());
+    for id in ["alice", "bob"] {
+        let entity = EntityBuilder::new()
+            .id(id)
+            .species(Species::Human)
+            .age(Duration::years(30))
+            .build()
+            .unwrap();
+        sim.add_entity(entity, reference());
+    }
+    let alice = EntityId::new("alice").unwrap();
+    let bob = EntityId::new("bob").unwrap();
+    let rel_id = sim.add_relationship(
+        alice.clone(),
+        bob.clone(),
+        RelationshipSchema::Peer,
+        reference(),
+    );
+    (sim, alice, bob, rel_id)
+}
+
+/// Records `count` casual 45-minute chats spread evenly over six months.
+fn chats(count: u64) -> (Simulation, RelationshipId) {
+    let (mut sim, alice, bob, rel_id) = friends();
+    let spacing = Duration::days(180 / count);
+    for index in 0..count {
+        let at = reference() + Duration::days(1) + Duration::days(spacing.as_days() * index);
+        sim.record_interaction(&alice, &bob, at, InteractionTopic::Casual, 45, 0.6);
+    }
+    (sim, rel_id)
+}
+
+/// Tests that regular contact passes the proximal-process gate and rare
+/// contact does not.
+///
+/// Validates: after six months, 50 recorded chats give a frequency at or
+/// above `INTERACTION_FREQUENCY_THRESHOLD` and pass the gate, while 2
+/// give a frequency below it and fail.
+#[test]
+fn regular_interactions_pass_the_proximal_process_gate() {
+    let (regular, regular_id) = chats(50);
+    let (rare, rare_id) = chats(2);
+
+    let regular = regular.relationship_at(&regular_id, six_months()).unwrap();
+    let pattern = regular.pattern();
+    assert_eq!(pattern.interaction_count, 50);
+    assert!((pattern.mean_duration_minutes() - 45.0).abs() < 1e-9);
+    assert_eq!(pattern.topics, vec![InteractionTopic::Casual]);
+    assert!(f64::from(pattern.frequency) >= INTERACTION_FREQUENCY_THRESHOLD);
+    assert!(pattern.proximal_process_gate().is_ok());
+
+    let rare = rare.relationship_at(&rare_id, six_months()).unwrap();
+    assert_eq!(rare.pattern().interaction_count, 2);
+    assert!(f64::from(rare.pattern().frequency) < INTERACTION_FREQUENCY_THRESHOLD);
+    assert!(rare.pattern().proximal_process_gate().is_err());
+}
+
+/// Tests that the history only counts interactions up to the query time.
+///
+/// Validates: a month in, fewer chats are counted and shared history is
+/// lower than at six months, and before any chat the relationship has no
+/// recorded history.
+#[test]
+fn history_accumulates_over_time() {
+    let (sim, rel_id) = chats(50);
+    let before = sim.relationship_at(&rel_id, reference()).unwrap();
+    let month = sim
+        .relationship_at(&rel_id, reference() + Duration::days(30))
+        .unwrap();
+    let later = sim.relationship_at(&rel_id, six_months()).unwrap();
+
+    assert_eq!(before.pattern().interaction_count, 0);
+    assert!(month.pattern().interaction_count > 0);
+    assert!(month.pattern().interaction_count < 50);
+    assert!(before.shared().history_effective() < month.shared().history_effective());
+    assert!(month.shared().history_effective() < later.shared().history_effective());
+}
+
+/// Tests that interaction events added directly are recorded with their
+/// payload.
+///
+/// Validates: events in either direction count once each, their topics
+/// widen topic diversity, and an event without a payload counts toward
+/// frequency but not the mean duration.
+#[test]
+fn added_interaction_events_are_recorded() {
+    let (mut sim, alice, bob, rel_id) = friends();
+    let interaction = |source: &EntityId, target: &EntityId, topic| {
+        EventBuilder::new(EventType::Interaction)
+            .source(source.clone())
+            .target(target.clone())
+            .payload(EventPayload::Interaction {
+                topic: Some(topic),
+                duration_minutes: 90,
+            })
+            .build()
+            .unwrap()
+    };
+    sim.add_event(
+        interaction(&alice, &bob, InteractionTopic::Work),
+        reference() + Duration::days(1),
+    );
+    sim.add_event(
+        interaction(&bob, &alice, InteractionTopic::DeepConversation),
+        reference() + Duration::days(2),
+    );
+    let untimed = EventBuilder::new(EventType::Interaction)
+        .source(alice.clone())
+        .target(bob.clone())
+        .build()
+        .unwrap();
+    sim.add_event(untimed, reference() + Duration::days(3));
+
+    let rel = sim
+        .relationship_at(&rel_id, reference() + Duration::days(4))
+        .unwrap();
+    let pattern = rel.pattern();
+    assert_eq!(pattern.interaction_count, 3);
+    assert!((pattern.mean_duration_minutes() - 90.0).abs() < 1e-9);
+    assert!((pattern.topic_diversity() - 2.0 / 6.0).abs() < 1e-9);
+    assert_eq!(
+        pattern.last_interaction,
+        Some(reference() + Duration::days(3))
+    );
+}
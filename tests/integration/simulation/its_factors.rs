@@ -0,0 +1,98 @@
+//! Integration tests for `ComputedState::its_factors()` and
+//! `ComputedState::its_contributors()`.
+//!
+//! Tests that stacked exclusion, burden, and trauma converge all three ITS
+//! factors and report the contributors behind them, while a baseline
+//! person shows neither.
+
+#![cfg(feature = "its")]
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{EventTag, EventType, Species};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::state::Hexaco;
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+use behavioral_pathways::{AcContributor, ItsContributor, PbContributor, TbContributor};
+
+/// Adds a 30-year-old who blames themself for negative events.
+fn add_person(sim: &mut Simulation) -> EntityId {
+    let person = EntityBuilder::new()
+        .id("person")
+        .species(Species::Human)
+        .age(Duration::years(30))
+        .hexaco(Hexaco::new().with_honesty_humility(0.8))
+        .build()
+        .unwrap();
+    sim.add_entity(person, reference())
+}
+
+fn add_event(sim: &mut Simulation, id: &EntityId, event_type: EventType, at: Timestamp) {
+    let event = EventBuilder::new(event_type)
+        .target(id.clone())
+        .severity(0.9)
+        .tag(EventTag::ChronicPattern)
+        .build()
+        .unwrap();
+    sim.add_event(event, at);
+}
+
+/// Tests that a baseline person has no elevated factors or contributors.
+///
+/// Validates: no factor is elevated and `its_contributors()` is empty at
+/// the anchor and a year later.
+#[test]
+fn baseline_person_has_no_factors_or_contributors() {
+    let mut sim = Simulation::new(reference());
+    let id = add_person(&mut sim);
+    let handle = sim.entity(&id).unwrap();
+
+    for at in [reference(), reference() + Duration::years(1)] {
+        let state = handle.state_at(at);
+        let factors = state.its_factors();
+        assert_eq!(factors.convergence_status.elevated_factor_count, 0);
+        assert!(!factors.convergence_status.is_three_factor_convergent);
+        assert!(state.its_contributors().is_empty());
+    }
+}
+
+/// Tests that stacked exclusion, burden, and trauma converge the factors.
+///
+/// Validates: after weekly exclusion and burden joined by violence and
+/// combat exposure, all three factors are elevated, and the contributors
+/// are listed once each in order of first activation.
+#[test]
+fn stacked_events_converge_factors_with_contributors() {
+    let mut sim = Simulation::new(reference());
+    let id = add_person(&mut sim);
+    for week in 1..=12 {
+        let at = reference() + Duration::weeks(week);
+        add_event(&mut sim, &id, EventType::SocialExclusion, at);
+        add_event(&mut sim, &id, EventType::BurdenFeedback, at);
+    }
+    for week in 9..=12 {
+        let at = reference() + Duration::weeks(week) + Duration::hours(1);
+        add_event(&mut sim, &id, EventType::Violence, at);
+        add_event(&mut sim, &id, EventType::CombatExposure, at);
+    }
+
+    let handle = sim.entity(&id).unwrap();
+    let state = handle.state_at(reference() + Duration::weeks(12) + Duration::hours(2));
+    let factors = state.its_factors();
+    assert!(factors.convergence_status.is_three_factor_convergent);
+    assert_eq!(factors.convergence_status.elevated_factor_count, 3);
+    assert_eq!(
+        state.its_contributors(),
+        [
+            ItsContributor::Tb(TbContributor::SocialRejection),
+            ItsContributor::Pb(PbContributor::DirectBurdenFeedback),
+            ItsContributor::Ac(AcContributor::PhysicalAbuseExposure),
+            ItsContributor::Ac(AcContributor::CombatExposure),
+        ]
+    );
+
+    // Before the first event nothing has contributed yet
+    let before = handle.state_at(reference() + Duration::days(1));
+    assert!(before.its_contributors().is_empty());
+}
@@ -0,0 +1,177 @@
+//! Integration tests for ITS invariant checking.
+//!
+//! Tests that states near the ITS thresholds pass, that states breaking an
+//! invariant are reported by `try_state_at()` in strict mode, and that
+//! `state_at()` returns the state in every `ItsInvariantMode`.
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{MentalHealthPath, Species, StatePath};
+use behavioral_pathways::simulation::{
+    ComputedState, InvariantViolation, ItsInvariantMode, Simulation,
+};
+use behavioral_pathways::state::{IndividualState, MentalHealth, SocialCognition};
+use behavioral_pathways::types::{Duration, EntityId};
+
+fn value(state: &ComputedState, path: MentalHealthPath) -> f64 {
+    state.get_effective(StatePath::MentalHealth(path))
+}
+
+/// Social cognition with TB 0.8 and PB 0.64, both above threshold.
+fn isolated_and_burdened() -> SocialCognition {
+    SocialCognition::new()
+        .with_loneliness_base(0.8)
+        .with_perceived_reciprocal_caring_base(0.2)
+        .with_perceived_liability_base(0.8)
+        .with_self_hate_base(0.8)
+}
+
+/// Builds a simulation with one person anchored at the reference time.
+fn scenario(hopelessness: f32, capability: f32) -> (Simulation, EntityId) {
+    let mut sim = Simulation::new(reference());
+    let person = EntityBuilder::new()
+        .id("person")
+        .species(Species::Human)
+        .age(Duration::years(30))
+        .social_cognition(isolated_and_burdened())
+        .mental_health(
+            MentalHealth::new()
+                .with_interpersonal_hopelessness_base(hopelessness)
+                .with_acquired_capability_base(capability),
+        )
+        .build()
+        .unwrap();
+    let id = sim.add_entity(person, reference());
+    (sim, id)
+}
+
+/// Builds the same simulation, checking invariants strictly.
+fn strict_scenario(hopelessness: f32, capability: f32) -> (Simulation, EntityId) {
+    let (mut sim, id) = scenario(hopelessness, capability);
+    sim.set_its_invariant_mode(ItsInvariantMode::Strict);
+    (sim, id)
+}
+
+/// Tests that states just inside each threshold pass the invariants.
+///
+/// Validates: hopelessness just below threshold zeroes desire and risk,
+/// and capability at the elevated threshold permits risk.
+#[test]
+fn near_threshold_states_pass() {
+    let (sim, id) = strict_scenario(0.49, 0.9);
+    let state = sim
+        .entity(&id)
+        .unwrap()
+        .try_state_at(reference() + Duration::days(10))
+        .unwrap();
+    assert_eq!(value(&state, MentalHealthPath::SuicidalDesire), 0.0);
+
+    let (sim, id) = strict_scenario(0.6, 0.3);
+    let state = sim
+        .entity(&id)
+        .unwrap()
+        .try_state_at(reference() + Duration::days(10))
+        .unwrap();
+    assert!(value(&state, MentalHealthPath::AttemptRisk) > 0.0);
+}
+
+/// Tests that desire with capability just below the elevated threshold is
+/// reported as risk without preconditions.
+///
+/// Validates: the risk formula's small nonzero risk below the threshold is
+/// caught, with the offending values in the error.
+#[test]
+fn risk_below_capability_threshold_is_reported() {
+    let (sim, id) = strict_scenario(0.6, 0.29);
+    let result = sim
+        .entity(&id)
+        .unwrap()
+        .try_state_at(reference() + Duration::days(10));
+
+    match result {
+        Err(InvariantViolation::RiskWithoutPreconditions {
+            entity_id,
+            desire,
+            acquired_capability,
+            ..
+        }) => {
+            assert_eq!(entity_id, id);
+            assert!(desire > 0.0);
+            assert!(acquired_capability < 0.3);
+        }
+        other => panic!("expected a risk violation, got {other:?}"),
+    }
+}
+
+/// Tests that an observation with lower acquired capability than the
+/// anchor is reported as a decrease.
+///
+/// Validates: the check compares against the highest capability known at
+/// or before the query, and ignores observations after it.
+#[test]
+fn capability_decrease_from_observation_is_reported() {
+    let (mut sim, id) = strict_scenario(0.0, 0.6);
+    let observed = IndividualState::new()
+        .with_mental_health(MentalHealth::new().with_acquired_capability_base(0.2));
+    let observed_at = reference() + Duration::days(60);
+    assert!(sim.add_observation(&id, observed, observed_at));
+
+    let handle = sim.entity(&id).unwrap();
+    assert!(handle
+        .try_state_at(reference() + Duration::days(30))
+        .is_ok());
+    match handle.try_state_at(observed_at) {
+        Err(InvariantViolation::AcquiredCapabilityDecreased {
+            earlier_timestamp,
+            earlier,
+            acquired_capability,
+            ..
+        }) => {
+            assert_eq!(earlier_timestamp, reference());
+            assert!((earlier - 0.6).abs() < 1e-6);
+            assert!(acquired_capability < earlier);
+        }
+        other => panic!("expected a capability violation, got {other:?}"),
+    }
+}
+
+/// Tests that `state_at()` returns the state in every mode.
+///
+/// Validates: Off, Warn, and Strict return the same state for a violating
+/// query, without panicking.
+#[test]
+fn state_at_returns_state_in_every_mode() {
+    let (mut sim, id) = scenario(0.6, 0.29);
+    let at = reference() + Duration::days(10);
+    assert_eq!(sim.its_invariant_mode(), ItsInvariantMode::Off);
+    let off = sim.entity(&id).unwrap().state_at(at);
+
+    for mode in [ItsInvariantMode::Warn, ItsInvariantMode::Strict] {
+        sim.set_its_invariant_mode(mode);
+        let state = sim.entity(&id).unwrap().state_at(at);
+        assert_eq!(
+            value(&off, MentalHealthPath::AttemptRisk),
+            value(&state, MentalHealthPath::AttemptRisk)
+        );
+    }
+}
+
+/// Tests that only strict mode turns a violation into an error.
+///
+/// Validates: Off and Warn return the violating state from
+/// `try_state_at()`, and Strict returns an error naming the violated rule.
+#[test]
+fn only_strict_mode_returns_violations() {
+    let (mut sim, id) = scenario(0.6, 0.29);
+    let at = reference() + Duration::days(10);
+    for mode in [ItsInvariantMode::Off, ItsInvariantMode::Warn] {
+        sim.set_its_invariant_mode(mode);
+        assert!(sim.entity(&id).unwrap().try_state_at(at).is_ok());
+    }
+
+    sim.set_its_invariant_mode(ItsInvariantMode::Strict);
+    let violation = sim.entity(&id).unwrap().try_state_at(at).unwrap_err();
+    assert!(violation
+        .to_string()
+        .contains("attempt risk requires desire and elevated AC"));
+}
@@ -0,0 +1,96 @@
+//! Integration tests for species life stages and formative plasticity.
+//!
+//! Tests that state queries report species-scaled life stages, that
+//! formative shifts read the human-equivalent age of the entity, and that
+//! a `LifeStageTable` set with `EntityBuilder::life_stage_table()`
+//! overrides both.
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{EventType, HexacoPath, LifeStage, Species, StatePath};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::state::LifeStageTable;
+use behavioral_pathways::types::{Duration, EntityId};
+
+fn subject() -> EntityId {
+    EntityId::new("subject").unwrap()
+}
+
+fn extraversion() -> StatePath {
+    StatePath::Hexaco(HexacoPath::Extraversion)
+}
+
+/// A two-year-old of `species` who goes through one formative event that
+/// shifts extraversion a week after the reference.
+fn simulation(species: Species, table: Option<LifeStageTable>) -> Simulation {
+    let mut builder = EntityBuilder::new()
+        .id("subject")
+        .species(species)
+        .birth_date(reference() - Duration::years(2));
+    if let Some(table) = table {
+        builder = builder.life_stage_table(table);
+    }
+    let mut sim = Simulation::new(reference());
+    sim.add_entity(builder.build().unwrap(), reference());
+    let rescue = EventBuilder::new(EventType::Support)
+        .target(subject())
+        .severity(0.8)
+        .with_base_shift(HexacoPath::Extraversion, 0.2)
+        .build()
+        .unwrap();
+    sim.add_event(rescue, reference() + Duration::weeks(1));
+    sim
+}
+
+/// Returns the life stage after the event and how far the event moved
+/// extraversion.
+fn stage_and_shift(sim: &Simulation) -> (LifeStage, f64) {
+    let handle = sim.entity(&subject()).unwrap();
+    let before = handle.state_at(reference());
+    let after = handle.state_at(reference() + Duration::weeks(2));
+    let shift = after.get_effective(extraversion()) - before.get_effective(extraversion());
+    (after.life_stage(), shift)
+}
+
+/// Tests that a two-year-old dog is treated as an adult.
+///
+/// Validates: the dog reports an adult-equivalent stage while the human
+/// toddler is a child, and the same formative event shifts the dog's
+/// extraversion less. Extraversion's sensitive period (13-22) covers
+/// neither, so only age plasticity and species plasticity differ.
+#[test]
+fn two_year_old_dog_is_less_plastic_than_two_year_old_human() {
+    let (dog_stage, dog_shift) = stage_and_shift(&simulation(Species::Dog, None));
+    let (human_stage, human_shift) = stage_and_shift(&simulation(Species::Human, None));
+
+    assert_eq!(dog_stage, LifeStage::YoungAdult);
+    assert_eq!(human_stage, LifeStage::Child);
+    assert!(dog_shift > 0.0);
+    assert!(
+        dog_shift < human_shift,
+        "dog shift {dog_shift} should be below human shift {human_shift}"
+    );
+}
+
+/// Tests that a life stage table overrides the species scaling.
+///
+/// Validates: a dog table that delays adulthood to three years reports
+/// the two-year-old as an adolescent, with more plasticity than the
+/// default dog and progress measured against the table.
+#[test]
+fn life_stage_table_delays_dog_adulthood() {
+    let table = LifeStageTable::for_species(&Species::Dog)
+        .with_stage_entry(LifeStage::Adolescent, 1.0)
+        .with_stage_entry(LifeStage::YoungAdult, 3.0);
+    let late = simulation(Species::Dog, Some(table));
+    let (late_stage, late_shift) = stage_and_shift(&late);
+    let (_, default_shift) = stage_and_shift(&simulation(Species::Dog, None));
+
+    assert_eq!(late_stage, LifeStage::Adolescent);
+    assert!(late_shift > default_shift);
+
+    let state = late.entity(&subject()).unwrap().state_at(reference());
+    assert!((state.life_stage_progress() - 0.5).abs() < 1e-3);
+    assert_eq!(state.time_until_next_stage(), Some(Duration::years(1)));
+}
@@ -2,6 +2,46 @@
 //!
 //! Tests for the Simulation container and timestamp-based state queries.
 
+mod affect_variability;
+mod alert_sweep;
+mod anticipation;
+mod base_absorption;
+mod base_shift_history;
+mod baseline_delta;
+mod behavior_prediction;
+mod branching;
+mod build_validation;
+mod bulk_events;
+mod decay_profile;
+mod emotion_model;
+mod entity_comparison;
+mod event_cascades;
+mod event_filters;
+mod event_provenance;
+mod identity_profile;
+mod influence_ranking;
+mod interaction_history;
+mod its_factors;
+mod its_invariants;
+mod life_stage_table;
+mod model_alerts;
+mod partial_entities;
+mod path_independence;
+mod population_states;
+mod profiling;
+mod query_paths;
+mod rebase;
+mod recognition_fall;
+mod recurring_events;
+mod relationship_query;
+mod removal;
+mod scoped_events;
+mod state_range;
+mod stochastic_variation;
+mod template_spawning;
+mod time_to_value;
 mod timestamp_entity_lifecycle;
 mod timestamp_event_processing;
 mod timestamp_regression;
+mod trajectory_export;
+mod witnessed_events;
@@ -0,0 +1,169 @@
+//! Integration tests for model alerts from `ComputedState::alerts()`.
+//!
+//! Tests that a healthy person raises no alerts, that chronic exclusion and
+//! burden raise ITS factor alerts and then a desire warning as the state
+//! worsens, and that feedback spirals raise spiral alerts.
+
+#![cfg(feature = "its")]
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{
+    AlertSeverity, AlertTrigger, EventTag, EventType, MentalHealthPath, Species, SpiralType,
+    StatePath,
+};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::state::Hexaco;
+use behavioral_pathways::types::{Alert, Duration, EntityId, Timestamp};
+
+const TB: StatePath = StatePath::MentalHealth(MentalHealthPath::ThwartedBelongingness);
+const PB: StatePath = StatePath::MentalHealth(MentalHealthPath::PerceivedBurdensomeness);
+const HOPELESSNESS: StatePath =
+    StatePath::MentalHealth(MentalHealthPath::InterpersonalHopelessness);
+const DESIRE: StatePath = StatePath::MentalHealth(MentalHealthPath::SuicidalDesire);
+
+/// Adds a 30-year-old who blames themself for negative events.
+fn add_person(sim: &mut Simulation) -> EntityId {
+    let person = EntityBuilder::new()
+        .id("person")
+        .species(Species::Human)
+        .age(Duration::years(30))
+        .hexaco(Hexaco::new().with_honesty_humility(0.8))
+        .build()
+        .unwrap();
+    sim.add_entity(person, reference())
+}
+
+fn add_event(sim: &mut Simulation, id: &EntityId, event_type: EventType, at: Timestamp) {
+    let event = EventBuilder::new(event_type)
+        .target(id.clone())
+        .severity(0.9)
+        .tag(EventTag::ChronicPattern)
+        .build()
+        .unwrap();
+    sim.add_event(event, at);
+}
+
+/// Returns the state paths of the threshold alerts in `alerts`.
+fn thresholds(alerts: &[Alert]) -> Vec<StatePath> {
+    alerts
+        .iter()
+        .filter_map(|alert| match alert.trigger() {
+            AlertTrigger::ThresholdExceeded(path, _) => Some(*path),
+            _ => None,
+        })
+        .collect()
+}
+
+fn highest_severity(alerts: &[Alert]) -> Option<AlertSeverity> {
+    alerts.iter().map(Alert::severity).max()
+}
+
+/// Tests that a healthy person raises no alerts.
+///
+/// Validates: `alerts()` is empty at the anchor and a year later.
+#[test]
+fn healthy_person_has_no_alerts() {
+    let mut sim = Simulation::new(reference());
+    let id = add_person(&mut sim);
+    let handle = sim.entity(&id).unwrap();
+    assert!(handle.state_at(reference()).alerts().is_empty());
+    assert!(handle
+        .state_at(reference() + Duration::years(1))
+        .alerts()
+        .is_empty());
+}
+
+/// Tests that weekly exclusion and burden, joined by combat exposure and
+/// self-injury in the last weeks, raise alerts of increasing severity as
+/// the state worsens.
+///
+/// Validates: thwarted belongingness is flagged first, then perceived
+/// burdensomeness, and once interpersonal hopelessness is present too a
+/// suicidal desire warning follows. The highest severity never drops.
+#[test]
+fn chronic_exclusion_and_burden_escalate_alerts() {
+    let mut sim = Simulation::new(reference());
+    let id = add_person(&mut sim);
+    for week in 1..=12 {
+        let at = reference() + Duration::weeks(week);
+        add_event(&mut sim, &id, EventType::SocialExclusion, at);
+        add_event(&mut sim, &id, EventType::BurdenFeedback, at);
+    }
+    for week in 9..=12 {
+        let at = reference() + Duration::weeks(week);
+        add_event(&mut sim, &id, EventType::CombatExposure, at);
+        add_event(&mut sim, &id, EventType::NonSuicidalSelfInjury, at);
+    }
+
+    let handle = sim.entity(&id).unwrap();
+    let alerts_at = |week: u64| {
+        handle
+            .state_at(reference() + Duration::weeks(week) + Duration::hours(1))
+            .alerts()
+    };
+    let stages = [alerts_at(1), alerts_at(2), alerts_at(4), alerts_at(10)];
+
+    assert!(stages[0].is_empty());
+    assert_eq!(thresholds(&stages[1]), [TB]);
+    assert_eq!(thresholds(&stages[2]), [TB, PB]);
+    assert_eq!(thresholds(&stages[3]), [TB, PB, HOPELESSNESS, DESIRE]);
+
+    let severities: Vec<Option<AlertSeverity>> = stages
+        .iter()
+        .map(|alerts| highest_severity(alerts))
+        .collect();
+    assert_eq!(
+        severities,
+        [
+            None,
+            Some(AlertSeverity::Info),
+            Some(AlertSeverity::Info),
+            Some(AlertSeverity::Warning)
+        ]
+    );
+}
+
+/// Tests that sustained stress and depression raise spiral alerts.
+///
+/// Validates: a person anchored with high stress and depression has a
+/// warning for each spiral, and neither spiral is flagged once both are
+/// back to normal.
+#[test]
+fn active_spirals_raise_spiral_alerts() {
+    let mut sim = Simulation::new(reference());
+    let mut person = EntityBuilder::new()
+        .id("person")
+        .species(Species::Human)
+        .age(Duration::years(30))
+        .build()
+        .unwrap();
+    let state = person.individual_state_mut();
+    state.needs_mut().stress_mut().set_base(0.8);
+    state.mental_health_mut().depression_mut().set_base(0.6);
+    let id = sim.add_entity(person, reference());
+
+    let alerts = sim.entity(&id).unwrap().state_at(reference()).alerts();
+    let spirals: Vec<(SpiralType, AlertSeverity)> = alerts
+        .iter()
+        .filter_map(|alert| match alert.trigger() {
+            AlertTrigger::SpiralDetected(spiral) => Some((*spiral, alert.severity())),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        spirals,
+        [
+            (SpiralType::Stress, AlertSeverity::Warning),
+            (SpiralType::Depression, AlertSeverity::Warning)
+        ]
+    );
+
+    let mut calm = Simulation::new(reference());
+    let id = add_person(&mut calm);
+    let alerts = calm.entity(&id).unwrap().state_at(reference()).alerts();
+    assert!(!alerts
+        .iter()
+        .any(|alert| matches!(alert.trigger(), AlertTrigger::SpiralDetected(_))));
+}
@@ -0,0 +1,119 @@
+//! Integration tests for partially specified entities.
+//!
+//! Tests that entities with missing registry inputs still simulate, and
+//! that confidence and validation reflect what was imputed.
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::{EntityBuilder, ImputedField};
+use behavioral_pathways::enums::{EventType, PersonalityProfile, Species, StatePath};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::{Simulation, ValidationIssue};
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+fn birth_date() -> Timestamp {
+    Timestamp::from_ymd_hms(1984, 6, 1, 0, 0, 0)
+}
+
+/// Builds three registry records with progressively more inputs and adds a
+/// shared loss event to each.
+fn scenario() -> Simulation {
+    let mut sim = Simulation::new(reference());
+    let (no_personality, _) = EntityBuilder::new()
+        .id("no_personality")
+        .species(Species::Human)
+        .birth_date(birth_date())
+        .build_partial();
+    let (no_birth_date, _) = EntityBuilder::new()
+        .id("no_birth_date")
+        .species(Species::Human)
+        .age(Duration::years(40))
+        .personality(PersonalityProfile::Anxious)
+        .build_partial();
+    let (full, report) = EntityBuilder::new()
+        .id("full")
+        .species(Species::Human)
+        .birth_date(birth_date())
+        .personality(PersonalityProfile::Anxious)
+        .person_characteristics(Default::default())
+        .with_context(Default::default())
+        .build_partial();
+    assert!(report.is_complete());
+
+    for entity in [no_personality, no_birth_date, full] {
+        let id = sim.add_entity(entity, reference());
+        let loss = EventBuilder::new(EventType::Loss)
+            .target(id)
+            .severity(0.6)
+            .build()
+            .unwrap();
+        sim.add_event(loss, reference() + Duration::days(10));
+    }
+    sim
+}
+
+/// Tests that confidence falls with the weight of the missing input.
+///
+/// Validates: missing personality < missing birth date < fully specified,
+/// with every state still finite.
+#[test]
+fn confidence_orders_by_missing_input() {
+    let sim = scenario();
+    let at = reference() + Duration::days(30);
+    let confidence = |id: &str| {
+        let state = sim
+            .entity(&EntityId::new(id).unwrap())
+            .unwrap()
+            .state_at(at);
+        for path in StatePath::all() {
+            assert!(state.get_effective(path).is_finite(), "{id} {path:?}");
+        }
+        state.confidence()
+    };
+
+    let no_personality = confidence("no_personality");
+    let no_birth_date = confidence("no_birth_date");
+    let full = confidence("full");
+    assert!(no_personality < no_birth_date);
+    assert!(no_birth_date < full);
+    assert_eq!(full, 1.0);
+}
+
+/// Tests that validation distinguishes imputed inputs from defaults the
+/// caller chose.
+///
+/// Validates: only the two partial records are reported, each with its
+/// missing input.
+#[test]
+fn validation_reports_imputed_entities() {
+    let mut sim = scenario();
+    let deliberate = EntityBuilder::new()
+        .id("deliberate")
+        .species(Species::Human)
+        .build()
+        .unwrap();
+    sim.add_entity(deliberate, reference());
+
+    let imputed: Vec<(String, Vec<ImputedField>)> = sim
+        .validate()
+        .into_iter()
+        .filter_map(|issue| match issue {
+            ValidationIssue::ImputedInputs {
+                entity_id, fields, ..
+            } => Some((entity_id.as_str().to_string(), fields)),
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(imputed.len(), 2);
+    let fields_for = |id: &str| {
+        imputed
+            .iter()
+            .find(|(entity_id, _)| entity_id == id)
+            .map(|(_, fields)| fields.clone())
+            .unwrap()
+    };
+    assert!(fields_for("no_personality").contains(&ImputedField::Personality));
+    assert!(!fields_for("no_personality").contains(&ImputedField::BirthDate));
+    assert!(fields_for("no_birth_date").contains(&ImputedField::BirthDate));
+    assert!(!fields_for("no_birth_date").contains(&ImputedField::Age));
+}
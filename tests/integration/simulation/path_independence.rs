@@ -0,0 +1,107 @@
+//! Integration tests for path independence of state queries.
+//!
+//! Projecting forward from an anchor to T1 should match projecting to a
+//! later T2, re-anchoring there, and regressing back to T1. The residual
+//! comes from f32 rounding in decay and its reversal. Regressing through an
+//! event multiplies the rounding left after unwinding it by 2^(span /
+//! half-life), so with events between T1 and T2 the bound holds while the
+//! span covers a few half-lives of the fastest dimension (mood, 6 hours).
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{EventType, Species, StatePath};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::{RegressionQuality, Simulation};
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+/// Largest accepted difference on any state path between the direct and
+/// the re-anchored state.
+const RESIDUAL_BOUND: f64 = 1e-4;
+
+/// Builds a simulation anchored at the reference with elevated deltas and
+/// events at the given hours.
+fn build(events: &[(u64, EventType)]) -> (Simulation, EntityId) {
+    let mut sim = Simulation::new(reference());
+    let mut entity = EntityBuilder::new()
+        .id("person_001")
+        .species(Species::Human)
+        .age(Duration::years(30))
+        .build()
+        .unwrap();
+    let state = entity.individual_state_mut();
+    state.mood_mut().add_valence_delta(-0.5);
+    state.mood_mut().add_dominance_delta(0.3);
+    state.needs_mut().add_stress_delta(0.4);
+    state.mental_health_mut().add_depression_delta(0.3);
+    state.social_cognition_mut().add_loneliness_delta(0.2);
+    let id = sim.add_entity(entity, reference());
+
+    for (hour, event_type) in events {
+        let event = EventBuilder::new(*event_type)
+            .target(id.clone())
+            .severity(0.6)
+            .build()
+            .unwrap();
+        sim.add_event(event, reference() + Duration::hours(*hour));
+    }
+    (sim, id)
+}
+
+/// Returns the largest difference over every path between the state at
+/// `t1` projected directly and regressed from a re-anchor at `t2`.
+fn residual(sim: &Simulation, id: &EntityId, t1: Timestamp, t2: Timestamp) -> f64 {
+    let handle = sim.entity(id).unwrap();
+    let direct = handle.state_at(t1);
+
+    let mut entity = sim.get_anchored_entity(id).unwrap().entity().clone();
+    *entity.individual_state_mut() = handle.state_at(t2).individual_state().clone();
+    let mut reanchored = Simulation::new(t2);
+    let reanchored_id = reanchored.add_entity(entity, t2);
+    for te in sim.events_for(id) {
+        reanchored.add_event(te.event().clone(), te.timestamp());
+    }
+    let regressed = reanchored.entity(&reanchored_id).unwrap().state_at(t1);
+    assert_eq!(regressed.regression_quality(), RegressionQuality::Exact);
+
+    StatePath::all()
+        .into_iter()
+        .map(|path| (direct.get_effective(path) - regressed.get_effective(path)).abs())
+        .fold(0.0, f64::max)
+}
+
+/// Tests that an event-free history regresses back onto the direct path.
+///
+/// Validates: decay and its reversal share half-lives, and context hooks
+/// accrue in both directions without being decayed twice.
+#[test]
+fn event_free_history_is_path_independent() {
+    let (sim, id) = build(&[]);
+
+    for (t1, t2) in [(1, 2), (4, 10), (10, 60), (30, 365)] {
+        let t1 = reference() + Duration::days(t1);
+        let t2 = reference() + Duration::days(t2);
+        let residual = residual(&sim, &id, t1, t2);
+        assert!(residual < RESIDUAL_BOUND, "residual {} at {}", residual, t1);
+    }
+}
+
+/// Tests that reversible events between T1 and T2 unwind onto the direct path.
+///
+/// Validates: events regressed through are reversed with the deltas they
+/// applied going forward.
+#[test]
+fn reversible_event_history_is_path_independent() {
+    let events = [
+        (10, EventType::Achievement),
+        (16, EventType::SocialExclusion),
+        (22, EventType::SocialInclusion),
+    ];
+    let (sim, id) = build(&events);
+
+    for (t1, t2) in [(6, 24), (12, 20), (18, 30), (2, 12)] {
+        let t1 = reference() + Duration::hours(t1);
+        let t2 = reference() + Duration::hours(t2);
+        let residual = residual(&sim, &id, t1, t2);
+        assert!(residual < RESIDUAL_BOUND, "residual {} at {}", residual, t1);
+    }
+}
@@ -0,0 +1,121 @@
+//! Integration tests for population-wide state queries.
+//!
+//! Tests that `Simulation::states_at()` matches per-handle `state_at()`
+//! queries exactly over a 200-entity scenario with events, relationships,
+//! and entities of different ages. Run with `--features parallel` to
+//! compare the threaded path.
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{
+    EventType, HexacoPath, PersonalityProfile, RelationshipSchema, Species,
+};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EntityId};
+
+const POPULATION: usize = 200;
+
+fn id(index: usize) -> EntityId {
+    EntityId::new(format!("person_{index:03}")).unwrap()
+}
+
+/// Builds 200 entities with varied ages and personalities, a ring of
+/// peer relationships, and a spread of events before and after the
+/// reference.
+fn population() -> Simulation {
+    let profiles = [
+        PersonalityProfile::Balanced,
+        PersonalityProfile::Leader,
+        PersonalityProfile::Anxious,
+    ];
+    let events = [
+        EventType::SocialExclusion,
+        EventType::Support,
+        EventType::Achievement,
+        EventType::Conflict,
+    ];
+
+    let mut sim = Simulation::new(reference());
+    for index in 0..POPULATION {
+        let age = Duration::years(8 + (index as u64 * 7) % 70);
+        let entity = EntityBuilder::new()
+            .id(id(index).as_str())
+            .species(Species::Human)
+            .birth_date(reference() - age)
+            .personality(profiles[index % profiles.len()])
+            .build()
+            .unwrap();
+        sim.add_entity(entity, reference());
+    }
+    for index in 0..POPULATION {
+        let other = id((index + 1) % POPULATION);
+        sim.add_relationship(
+            id(index),
+            other.clone(),
+            RelationshipSchema::Peer,
+            reference() - Duration::days(30),
+        );
+        for step in 0..3 {
+            let offset = Duration::days((index as u64 * 3 + step * 11) % 40);
+            let mut builder = EventBuilder::new(events[(index + step as usize) % events.len()])
+                .target(id(index))
+                .source(other.clone())
+                .severity(0.3 + 0.2 * step as f64);
+            if index % 25 == 0 && step == 0 {
+                builder = builder.with_base_shift(HexacoPath::Neuroticism, 0.1);
+            }
+            let at = if step == 0 {
+                reference() - offset
+            } else {
+                reference() + offset
+            };
+            sim.add_event(builder.build().unwrap(), at);
+        }
+    }
+    sim
+}
+
+/// Tests that the population query matches per-handle queries.
+///
+/// Validates: at timestamps before, at, and after the anchors, every
+/// entity's state from `states_at()` equals its handle's `state_at()`,
+/// including life stage, regression quality, and alerts.
+#[test]
+fn states_at_matches_per_handle_queries() {
+    let sim = population();
+    for at in [
+        reference() - Duration::days(20),
+        reference(),
+        reference() + Duration::days(45),
+    ] {
+        let states = sim.states_at(at);
+        assert_eq!(states.len(), POPULATION);
+        for index in 0..POPULATION {
+            let expected = sim.entity(&id(index)).unwrap().state_at(at);
+            let actual = &states[&id(index)];
+            assert_eq!(actual.individual_state(), expected.individual_state());
+            assert_eq!(actual.life_stage(), expected.life_stage());
+            assert_eq!(actual.regression_quality(), expected.regression_quality());
+            assert_eq!(actual.alerts(), expected.alerts());
+        }
+    }
+}
+
+/// Tests that the filtered variant evaluates only the given entities.
+///
+/// Validates: unknown IDs are skipped, and the states match the full
+/// population query.
+#[test]
+fn states_at_for_skips_unknown_entities() {
+    let sim = population();
+    let at = reference() + Duration::days(10);
+    let wanted = [id(3), id(150), EntityId::new("nobody").unwrap()];
+
+    let states = sim.states_at_for(&wanted, at);
+    let all = sim.states_at(at);
+    assert_eq!(states.len(), 2);
+    for id in &wanted[..2] {
+        assert_eq!(states[id].individual_state(), all[id].individual_state());
+    }
+}
@@ -0,0 +1,112 @@
+//! Integration tests for query profiling.
+//!
+//! Tests that an opt-in profile accounts for every computation phase of a
+//! state query, and that disabled profiling records nothing.
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{EventType, Species};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::{ProfilePhase, ProfileReport, Simulation};
+use behavioral_pathways::types::{Duration, EntityId};
+
+/// Builds a simulation with one person and three events after the anchor.
+fn scenario() -> (Simulation, EntityId) {
+    let mut sim = Simulation::new(reference());
+    let person = EntityBuilder::new()
+        .id("person")
+        .species(Species::Human)
+        .age(Duration::years(30))
+        .build()
+        .unwrap();
+    let id = sim.add_entity(person, reference());
+    let events = [
+        (EventType::Conflict, 5),
+        (EventType::Support, 10),
+        (EventType::Loss, 20),
+    ];
+    for (event_type, day) in events {
+        let event = EventBuilder::new(event_type)
+            .target(id.clone())
+            .severity(0.6)
+            .build()
+            .unwrap();
+        sim.add_event(event, reference() + Duration::days(day));
+    }
+    (sim, id)
+}
+
+/// Tests that a profiled forward query reports every phase with call
+/// counts matching the scenario.
+///
+/// Validates: one interpretation and one application per event, one
+/// advance per event plus the final stretch, and one call each for the
+/// hooks and the interpreter, and, with serde, a JSON round trip.
+#[test]
+fn profile_covers_every_phase() {
+    let (mut sim, id) = scenario();
+    sim.set_profiling(true);
+    assert!(sim.profiling());
+
+    let _ = sim
+        .entity(&id)
+        .unwrap()
+        .state_at(reference() + Duration::days(30));
+    let report = sim.profile_report();
+    let event_count = sim.events_for(&id).len() as u64;
+
+    assert_eq!(report.queries, 1);
+    assert_eq!(report.phase(ProfilePhase::Interpret).calls, event_count);
+    assert_eq!(report.phase(ProfilePhase::Apply).calls, event_count);
+    assert_eq!(report.phase(ProfilePhase::Advance).calls, event_count + 1);
+    assert_eq!(report.phase(ProfilePhase::BaseShifts).calls, 2);
+    assert_eq!(report.phase(ProfilePhase::Interpreter).calls, 1);
+
+    let mut expected = vec![
+        ProfilePhase::Advance,
+        ProfilePhase::Interpret,
+        ProfilePhase::Apply,
+        ProfilePhase::BaseShifts,
+        ProfilePhase::Interpreter,
+    ];
+    #[cfg(feature = "context")]
+    expected.push(ProfilePhase::Context);
+    #[cfg(feature = "memory")]
+    expected.push(ProfilePhase::Memory);
+    expected.sort();
+    let phases: Vec<ProfilePhase> = report.phases.keys().copied().collect();
+    assert_eq!(phases, expected);
+
+    #[cfg(feature = "serde")]
+    {
+        let json = serde_json::to_string(&report).unwrap();
+        let back: ProfileReport = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, report);
+    }
+}
+
+/// Tests that profiling is off by default, accumulates across queries,
+/// and resets.
+///
+/// Validates: an empty report when disabled, doubled counts after two
+/// queries, and an empty report after `reset_profile()`.
+#[test]
+fn profile_accumulates_and_resets() {
+    let (mut sim, id) = scenario();
+    let at = reference() + Duration::days(30);
+
+    let _ = sim.entity(&id).unwrap().state_at(at);
+    assert!(!sim.profiling());
+    assert_eq!(sim.profile_report(), ProfileReport::default());
+
+    sim.set_profiling(true);
+    let _ = sim.entity(&id).unwrap().state_at(at);
+    let _ = sim.entity(&id).unwrap().state_at(at);
+    let report = sim.profile_report();
+    assert_eq!(report.queries, 2);
+    assert_eq!(report.phase(ProfilePhase::Interpret).calls, 6);
+
+    sim.reset_profile();
+    assert_eq!(sim.profile_report(), ProfileReport::default());
+    assert!(sim.profiling());
+}
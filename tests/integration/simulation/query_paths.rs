@@ -0,0 +1,113 @@
+//! Integration tests for querying state by `QueryPath`.
+//!
+//! Tests that `ComputedState::query()` returns the same values as
+//! `get_effective()` and `derived_emotions()`, and `None` for relationship
+//! paths.
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{
+    Direction, DirectionalPath, Emotion, MoodPath, QueryPath, RelPath, SharedPath, Species,
+    StatePath,
+};
+use behavioral_pathways::simulation::{ComputedState, Simulation};
+use behavioral_pathways::state::Mood;
+use behavioral_pathways::types::{Duration, EntityId};
+
+/// Returns the state of an entity whose mood bases are set to the given
+/// pleasure, arousal, and dominance.
+fn state_with_pad(valence: f32, arousal: f32, dominance: f32) -> ComputedState {
+    let mood = Mood::new()
+        .with_valence_base(valence)
+        .with_arousal_base(arousal)
+        .with_dominance_base(dominance);
+    let entity = EntityBuilder::new()
+        .id("person")
+        .species(Species::Human)
+        .age(Duration::years(30))
+        .mood(mood)
+        .build()
+        .unwrap();
+    let mut sim = Simulation::new(reference());
+    sim.add_entity(entity, reference());
+    sim.entity(&EntityId::new("person").unwrap())
+        .unwrap()
+        .state_at(reference())
+}
+
+/// Tests that emotion queries match the derived emotions.
+///
+/// Validates: for anxious, exuberant, and mixed PAD states, querying each
+/// octant emotion gives the matching `derived_emotions()` field, and the
+/// octant the state sits in is the one with a nonzero intensity.
+#[test]
+fn emotion_queries_match_derived_emotions() {
+    let anxious = state_with_pad(-0.6, 0.7, -0.5);
+    let exuberant = state_with_pad(0.7, 0.6, 0.5);
+    let mixed = state_with_pad(0.2, -0.3, 0.1);
+
+    for state in [&anxious, &exuberant, &mixed] {
+        let emotions = state.derived_emotions();
+        assert_eq!(
+            state.query(QueryPath::Emotion(Emotion::Anxious)),
+            Some(f64::from(emotions.anxious))
+        );
+        assert_eq!(
+            state.query(QueryPath::Emotion(Emotion::Exuberant)),
+            Some(f64::from(emotions.exuberant))
+        );
+        assert_eq!(
+            state.query(Emotion::Relaxed.into()),
+            Some(f64::from(emotions.relaxed))
+        );
+    }
+
+    assert!(anxious.query(Emotion::Anxious.into()).unwrap() > 0.4);
+    assert_eq!(anxious.query(Emotion::Exuberant.into()), Some(0.0));
+    assert!(exuberant.query(Emotion::Exuberant.into()).unwrap() > 0.4);
+    assert_eq!(exuberant.query(Emotion::Anxious.into()), Some(0.0));
+}
+
+/// Tests that every queryable dimension except relationships has a value.
+///
+/// Validates: each state path in `QueryPath::all()` matches
+/// `get_effective()`, each emotion is within 0-1, and each relationship
+/// path returns `None`.
+#[test]
+fn all_paths_query_like_their_accessors() {
+    let state = state_with_pad(-0.2, 0.4, 0.3);
+
+    for path in QueryPath::all() {
+        let value = state.query(path);
+        match path {
+            QueryPath::State(state_path) => {
+                assert_eq!(value, Some(state.get_effective(state_path)), "{path}");
+            }
+            QueryPath::Emotion(_) => {
+                let intensity = value.unwrap();
+                assert!((0.0..=1.0).contains(&intensity), "{path}: {intensity}");
+            }
+            QueryPath::Relationship(_) => assert_eq!(value, None, "{path}"),
+        }
+    }
+}
+
+/// Tests that paths convert into `QueryPath` from each wrapped type.
+///
+/// Validates: state, emotion, and directional relationship paths convert
+/// with `into()`, and Neutral has no value.
+#[test]
+fn paths_convert_into_query_paths() {
+    let state = state_with_pad(0.0, 0.0, 0.0);
+    let warmth = RelPath::Directional(Direction::AToB, DirectionalPath::Warmth);
+
+    assert!(state
+        .query(StatePath::Mood(MoodPath::Valence).into())
+        .is_some());
+    assert_eq!(state.query(warmth.into()), None);
+    assert_eq!(
+        state.query(RelPath::Shared(SharedPath::Affinity).into()),
+        None
+    );
+    assert_eq!(state.query(Emotion::Neutral.into()), None);
+}
@@ -0,0 +1,122 @@
+//! Integration tests for moving an entity's anchor forward.
+//!
+//! Tests that queries from the new anchor on are unchanged by rebasing,
+//! while queries before it are flagged as approximate and references taken
+//! before it go stale.
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{EventType, Species, StatePath};
+use behavioral_pathways::event::{Event, EventBuilder};
+use behavioral_pathways::simulation::{
+    ApproximationReason, EntityRefError, RegressionQuality, Simulation,
+};
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+fn event(id: &EntityId, event_type: EventType, severity: f64) -> Event {
+    EventBuilder::new(event_type)
+        .target(id.clone())
+        .severity(severity)
+        .build()
+        .unwrap()
+}
+
+/// Builds a simulation with events on both sides of day 60.
+fn simulation() -> (Simulation, EntityId) {
+    let mut sim = Simulation::new(reference());
+    let entity = EntityBuilder::new()
+        .id("person")
+        .species(Species::Human)
+        .birth_date(Timestamp::from_ymd_hms(1990, 1, 1, 0, 0, 0))
+        .build()
+        .unwrap();
+    let id = sim.add_entity(entity, reference());
+
+    for (day, event_type, severity) in [
+        (2, EventType::SocialExclusion, 0.7),
+        (20, EventType::Achievement, 0.5),
+        (45, EventType::Loss, 0.6),
+        (58, EventType::Conflict, 0.4),
+        (75, EventType::Support, 0.5),
+        (90, EventType::Humiliation, 0.6),
+    ] {
+        sim.add_event(
+            event(&id, event_type, severity),
+            reference() + Duration::days(day),
+        );
+    }
+    (sim, id)
+}
+
+/// Tests that states from the new anchor on match those before rebasing.
+///
+/// Validates: every state path agrees within float epsilon at the new
+/// anchor and at several later timestamps, across events both before and
+/// after it.
+#[test]
+fn rebased_queries_match_the_original_anchor() {
+    let (original, id) = simulation();
+    let mut rebased = original.clone();
+    let new_anchor = reference() + Duration::days(60);
+    rebased.rebase_entity(&id, new_anchor).unwrap();
+
+    for day in [60, 61, 75, 80, 100, 365] {
+        let at = reference() + Duration::days(day);
+        let expected = original.entity(&id).unwrap().state_at(at);
+        let actual = rebased.entity(&id).unwrap().state_at(at);
+        for path in StatePath::all() {
+            let diff = expected.get_effective(path) - actual.get_effective(path);
+            assert!(
+                diff.abs() < 1e-9,
+                "{:?} differs by {} on day {}",
+                path,
+                diff,
+                day
+            );
+        }
+        assert_eq!(actual.regression_quality(), expected.regression_quality());
+    }
+}
+
+/// Tests that states before the new anchor report reduced fidelity.
+///
+/// Validates: a query before the new anchor that was exact is now
+/// approximate, naming the new anchor as the cutoff.
+#[test]
+fn queries_before_the_new_anchor_are_approximate() {
+    let (original, id) = simulation();
+    let mut rebased = original.clone();
+    let new_anchor = reference() + Duration::days(60);
+    rebased.rebase_entity(&id, new_anchor).unwrap();
+
+    let before = reference() + Duration::days(30);
+    let exact = original.entity(&id).unwrap().state_at(before);
+    assert_eq!(exact.regression_quality(), RegressionQuality::Exact);
+
+    let state = rebased.entity(&id).unwrap().state_at(before);
+    assert_eq!(state.regression_quality(), RegressionQuality::Approximate);
+    assert_eq!(
+        state.approximate_reasons(),
+        [ApproximationReason::CompactedHistory { cutoff: new_anchor }]
+    );
+}
+
+/// Tests that rebasing invalidates references to the entity.
+///
+/// Validates: a strict `EntityRef` taken before the rebase reports a stale
+/// generation, and the anchor timestamp itself is unchanged.
+#[test]
+fn rebase_invalidates_entity_refs() {
+    let (mut sim, id) = simulation();
+    let person = sim.entity_ref(&id).unwrap().strict();
+    let new_anchor = reference() + Duration::days(60);
+
+    sim.rebase_entity(&id, new_anchor).unwrap();
+
+    assert!(matches!(
+        person.state_at(&sim, new_anchor),
+        Err(EntityRefError::StaleGeneration { .. })
+    ));
+    let anchored = sim.get_anchored_entity(&id).unwrap();
+    assert_eq!(anchored.anchor_timestamp(), reference());
+}
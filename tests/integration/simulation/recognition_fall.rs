@@ -0,0 +1,140 @@
+//! Integration tests for falls after public recognition.
+//!
+//! Tests that a failure or humiliation soon after recognition before an
+//! audience costs more than the same event after private praise.
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{
+    Audience, EventPayload, EventType, LifeDomain, MoodPath, Species, StatePath,
+};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::{Simulation, RECOGNITION_FALL_WINDOW};
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+const DOMINANCE: StatePath = StatePath::Mood(MoodPath::Dominance);
+const VALENCE: StatePath = StatePath::Mood(MoodPath::Valence);
+
+/// When the award is given, a day after the anchor.
+fn award_at() -> Timestamp {
+    reference() + Duration::days(1)
+}
+
+/// Builds a simulation with a work award before `audience` and a failure
+/// at work `fall_after` later.
+fn award_then_failure(audience: Audience, fall_after: Duration) -> (Simulation, EntityId) {
+    let mut sim = Simulation::new(reference());
+    let entity = EntityBuilder::new()
+        .id("person_001")
+        .species(Species::Human)
+        .age(Duration::years(35))
+        .build()
+        .unwrap();
+    let id = sim.add_entity(entity, reference());
+
+    let award = EventBuilder::new(EventType::Achievement)
+        .target(id.clone())
+        .severity(0.8)
+        .payload(EventPayload::Achievement {
+            domain: LifeDomain::Work,
+            magnitude: 0.9,
+            audience,
+        })
+        .build()
+        .unwrap();
+    sim.add_event(award, award_at());
+
+    let failure = EventBuilder::new(EventType::Failure)
+        .target(id.clone())
+        .severity(0.7)
+        .payload(EventPayload::Failure {
+            domain: LifeDomain::Work,
+            public: false,
+        })
+        .build()
+        .unwrap();
+    sim.add_event(failure, award_at() + fall_after);
+    (sim, id)
+}
+
+/// Returns the change on `path` across the failure.
+fn drop_across_failure(audience: Audience, fall_after: Duration, path: StatePath) -> f64 {
+    let (sim, id) = award_then_failure(audience, fall_after);
+    let handle = sim.entity(&id).unwrap();
+    let at = award_at() + fall_after;
+    handle.state_at(at).get_effective(path)
+        - handle
+            .state_at(at - Duration::seconds(1))
+            .get_effective(path)
+}
+
+/// Tests that a failure after a public award falls further than after
+/// private praise.
+///
+/// Validates: the fall's dominance and valence losses are amplified by the
+/// public fall multiplier against the private baseline.
+#[test]
+fn failure_after_public_award_falls_further_than_private_baseline() {
+    let fall_after = Duration::days(5);
+    let factor = Audience::Public.fall_multiplier();
+
+    for path in [DOMINANCE, VALENCE] {
+        let private = drop_across_failure(Audience::Private, fall_after, path);
+        let public = drop_across_failure(Audience::Public, fall_after, path);
+        assert!(private < 0.0, "{:?} private drop {}", path, private);
+        assert!(
+            public < private,
+            "{:?} public {} private {}",
+            path,
+            public,
+            private
+        );
+        // Bounded by the public multiplier, up to f32 rounding
+        assert!(
+            (public - private * factor).abs() < 1e-5,
+            "{:?} public {} private {}",
+            path,
+            public,
+            private
+        );
+    }
+
+    let small_group = drop_across_failure(Audience::SmallGroup, fall_after, DOMINANCE);
+    let public = drop_across_failure(Audience::Public, fall_after, DOMINANCE);
+    let private = drop_across_failure(Audience::Private, fall_after, DOMINANCE);
+    assert!(public < small_group && small_group < private);
+}
+
+/// Tests that a public award lifts dominance above private praise.
+///
+/// Validates: recognition deltas scale with the audience.
+#[test]
+fn public_award_lifts_more_than_private_praise() {
+    let fall_after = Duration::days(5);
+    let gain = |audience| {
+        let (sim, id) = award_then_failure(audience, fall_after);
+        let handle = sim.entity(&id).unwrap();
+        handle.state_at(award_at()).get_effective(DOMINANCE)
+            - handle
+                .state_at(award_at() - Duration::seconds(1))
+                .get_effective(DOMINANCE)
+    };
+
+    let private = gain(Audience::Private);
+    let public = gain(Audience::Public);
+    assert!(private > 0.0);
+    let ratio = Audience::Public.recognition_multiplier();
+    assert!((public - private * ratio).abs() < 1e-5);
+}
+
+/// Tests that a failure outside the window is not amplified.
+///
+/// Validates: only recognition within `RECOGNITION_FALL_WINDOW` is
+/// contradicted.
+#[test]
+fn failure_after_the_window_is_not_amplified() {
+    let fall_after = RECOGNITION_FALL_WINDOW + Duration::days(1);
+    let private = drop_across_failure(Audience::Private, fall_after, DOMINANCE);
+    let public = drop_across_failure(Audience::Public, fall_after, DOMINANCE);
+    assert!((public - private).abs() < 1e-9);
+}
@@ -0,0 +1,133 @@
+//! Integration tests for recurring events.
+//!
+//! Tests that a rule added with `Simulation::add_recurring_event()` changes
+//! state exactly as its manually expanded occurrences would, within the
+//! boundary rules of state queries.
+
+use crate::integration::fixtures::{person, reference, simulation_with_person};
+use behavioral_pathways::enums::{EventType, MoodPath, StatePath};
+use behavioral_pathways::event::{Event, EventBuilder};
+use behavioral_pathways::simulation::RegressionQuality;
+use behavioral_pathways::types::{Duration, EventId};
+
+fn event(event_type: EventType, id: &str) -> Event {
+    EventBuilder::new(event_type)
+        .id(EventId::new(id).unwrap())
+        .target(person())
+        .severity(0.6)
+        .build()
+        .unwrap()
+}
+
+/// Tests that a weekly Support rule matches the same events added one by
+/// one.
+///
+/// Validates: state after eight weekly sessions is identical whether the
+/// sessions come from a rule or from `add_event`.
+#[test]
+fn recurring_support_matches_manual_expansion() {
+    let start = reference() + Duration::days(3);
+    let interval = Duration::weeks(1);
+
+    let mut recurring = simulation_with_person(Duration::years(30));
+    recurring.add_recurring_event(event(EventType::Support, "session"), start, interval, 8);
+
+    let mut manual = simulation_with_person(Duration::years(30));
+    for n in 0..8u64 {
+        manual.add_event(
+            event(EventType::Support, &format!("session_{n}")),
+            start + interval * n,
+        );
+    }
+
+    let query = reference() + Duration::days(90);
+    let from_rule = recurring.entity(&person()).unwrap().state_at(query);
+    let from_events = manual.entity(&person()).unwrap().state_at(query);
+    assert_eq!(from_rule.individual_state(), from_events.individual_state());
+    assert_eq!(recurring.recurring_events().len(), 1);
+    assert_eq!(recurring.all_events().count(), 0);
+}
+
+/// Tests that only occurrences inside the query range apply.
+///
+/// Validates: a query landing mid-rule matches a manual expansion of the
+/// occurrences up to it, ignoring the later occurrences.
+#[test]
+fn rule_straddling_query_applies_only_occurrences_in_range() {
+    let start = reference() + Duration::days(1);
+    let interval = Duration::weeks(1);
+
+    let mut recurring = simulation_with_person(Duration::years(30));
+    recurring.add_recurring_event(event(EventType::Support, "session"), start, interval, 20);
+
+    // Occurrences 0..=4 land on or before the query
+    let query = start + Duration::weeks(4);
+    let mut manual = simulation_with_person(Duration::years(30));
+    for n in 0..5u64 {
+        manual.add_event(
+            event(EventType::Support, &format!("session_{n}")),
+            start + interval * n,
+        );
+    }
+
+    let from_rule = recurring.entity(&person()).unwrap().state_at(query);
+    let from_events = manual.entity(&person()).unwrap().state_at(query);
+    assert_eq!(from_rule.individual_state(), from_events.individual_state());
+
+    let rule = &recurring.recurring_events()[0];
+    assert_eq!(rule.occurrences_between(reference(), query).count(), 5);
+}
+
+/// Tests that occurrences before the anchor regress backward queries.
+///
+/// Validates: a recurring trauma event between the query and the anchor
+/// makes regression approximate, while one outside the range does not.
+#[test]
+fn recurring_trauma_makes_regression_approximate() {
+    let mut sim = simulation_with_person(Duration::years(30));
+    let start = reference() - Duration::days(60);
+    sim.add_recurring_event(
+        event(EventType::Violence, "assault"),
+        start,
+        Duration::weeks(1),
+        3,
+    );
+    let handle = sim.entity(&person()).unwrap();
+
+    let through_rule = handle.state_at(reference() - Duration::days(90));
+    assert_eq!(
+        through_rule.regression_quality(),
+        RegressionQuality::Approximate
+    );
+
+    let after_rule = handle.state_at(reference() - Duration::days(10));
+    assert_eq!(after_rule.regression_quality(), RegressionQuality::Exact);
+}
+
+/// Tests that the `until` variant stops at its end time.
+///
+/// Validates: a rule until a time applies the same occurrences as the
+/// equivalent counted rule.
+#[test]
+fn until_variant_matches_counted_rule() {
+    let start = reference() + Duration::days(2);
+    let interval = Duration::days(3);
+
+    let mut counted = simulation_with_person(Duration::years(30));
+    counted.add_recurring_event(event(EventType::Support, "visit"), start, interval, 4);
+    let mut until = simulation_with_person(Duration::years(30));
+    until.add_recurring_event_until(
+        event(EventType::Support, "visit"),
+        start,
+        interval,
+        start + Duration::days(10),
+    );
+
+    let query = reference() + Duration::days(30);
+    let path = StatePath::Mood(MoodPath::Valence);
+    let a = counted.entity(&person()).unwrap().state_at(query);
+    let b = until.entity(&person()).unwrap().state_at(query);
+    assert_eq!(a.individual_state(), b.individual_state());
+    assert!(a.get_effective(path).is_finite());
+    assert_eq!(until.recurring_events()[0].count(), 4);
+}
@@ -0,0 +1,150 @@
+//! Integration tests for querying relationships at a timestamp.
+//!
+//! Tests that events between a pair move the directional trust of the
+//! relationship returned by `Simulation::relationship()`, only from the
+//! time they happen, and only in the direction of the party they targeted.
+
+#![cfg(feature = "relationships")]
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{Direction, EventType, RelationshipSchema, Species};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EntityId};
+
+/// Builds alice and bob with a peer relationship formed at the reference.
+fn friends() -> (Simulation, EntityId, EntityId) {
+    let mut sim = Simulation::new(reference());
+    for id in ["alice", "bob"] {
+        let entity = EntityBuilder::new()
+            .id(id)
+            .species(Species::Human)
+            .age(Duration::years(30))
+            .build()
+            .unwrap();
+        sim.add_entity(entity, reference());
+    }
+    let alice = EntityId::new("alice").unwrap();
+    let bob = EntityId::new("bob").unwrap();
+    sim.add_relationship(
+        alice.clone(),
+        bob.clone(),
+        RelationshipSchema::Peer,
+        reference(),
+    );
+    (sim, alice, bob)
+}
+
+fn event(
+    event_type: EventType,
+    source: &EntityId,
+    target: &EntityId,
+) -> behavioral_pathways::event::Event {
+    EventBuilder::new(event_type)
+        .source(source.clone())
+        .target(target.clone())
+        .severity(0.8)
+        .build()
+        .unwrap()
+}
+
+/// Tests that a betrayal of alice by bob lowers alice's willingness to
+/// disclose to bob once it has happened.
+///
+/// Validates: A to B disclosure willingness is lower after the betrayal
+/// than before it, while B to A willingness barely moves.
+#[test]
+fn betrayal_lowers_disclosure_in_one_direction() {
+    let (mut sim, alice, bob) = friends();
+    let betrayed_at = reference() + Duration::days(10);
+    sim.add_event(event(EventType::Betrayal, &bob, &alice), betrayed_at);
+
+    let handle = sim.relationship(&alice, &bob).unwrap();
+    let before = handle.state_at(betrayed_at - Duration::days(1));
+    let after = handle.state_at(betrayed_at + Duration::days(1));
+
+    let a_before = before.a_to_b.disclosure_willingness();
+    let a_after = after.a_to_b.disclosure_willingness();
+    assert!(a_after < a_before - 0.02, "{a_before} -> {a_after}");
+
+    let b_before = before.b_to_a.disclosure_willingness();
+    let b_after = after.b_to_a.disclosure_willingness();
+    assert!((b_after - b_before).abs() < 0.01, "{b_before} -> {b_after}");
+    assert!(after
+        .relationship
+        .antecedent_history(Direction::BToA)
+        .is_empty());
+}
+
+/// Tests that support and conflict move trust in opposite directions.
+///
+/// Validates: support from bob raises alice's trust in bob, and a conflict
+/// started by bob lowers it.
+#[test]
+fn support_and_conflict_move_trust_opposite_ways() {
+    let at = reference() + Duration::days(10);
+    let later = at + Duration::days(1);
+
+    let (baseline, alice, bob) = friends();
+    let neutral = baseline.relationship(&alice, &bob).unwrap().state_at(later);
+
+    let (mut supported, alice, bob) = friends();
+    supported.add_event(event(EventType::Support, &bob, &alice), at);
+    let helped = supported
+        .relationship(&alice, &bob)
+        .unwrap()
+        .state_at(later);
+    assert!(helped.a_to_b.support_willingness() > neutral.a_to_b.support_willingness());
+
+    let (mut conflicted, alice, bob) = friends();
+    conflicted.add_event(event(EventType::Conflict, &bob, &alice), at);
+    let fought = conflicted
+        .relationship(&alice, &bob)
+        .unwrap()
+        .state_at(later);
+    let trust = |state: &behavioral_pathways::simulation::ComputedRelationship| {
+        state
+            .relationship
+            .trustworthiness(Direction::AToB)
+            .benevolence_effective()
+    };
+    assert!(trust(&fought) < trust(&neutral));
+}
+
+/// Tests that the handle agrees with `relationship_at` and is found from
+/// either party.
+///
+/// Validates: the computed relationship matches `relationship_at` at the
+/// same timestamp, whichever order the pair is given in.
+#[test]
+fn handle_matches_relationship_at() {
+    let (mut sim, alice, bob) = friends();
+    sim.add_event(
+        event(EventType::Betrayal, &bob, &alice),
+        reference() + Duration::days(3),
+    );
+    sim.add_event(
+        event(EventType::Support, &alice, &bob),
+        reference() + Duration::days(6),
+    );
+    let query = reference() + Duration::days(4);
+
+    let handle = sim.relationship(&bob, &alice).unwrap();
+    let computed = handle.state_at(query);
+    let expected = sim.relationship_at(handle.id(), query).unwrap();
+    for direction in [Direction::AToB, Direction::BToA] {
+        assert_eq!(
+            computed.relationship.antecedent_history(direction),
+            expected.antecedent_history(direction)
+        );
+    }
+    assert!(!computed
+        .relationship
+        .antecedent_history(Direction::AToB)
+        .is_empty());
+    assert!(computed
+        .relationship
+        .antecedent_history(Direction::BToA)
+        .is_empty());
+}
@@ -0,0 +1,142 @@
+//! Integration tests for removing entities, events, and relationships.
+//!
+//! Tests that state queries after a removal behave as if the removed item
+//! had never been added.
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{EventType, RelationshipSchema, Species};
+use behavioral_pathways::event::{Event, EventBuilder};
+use behavioral_pathways::simulation::{RemoveEntityError, Simulation};
+use behavioral_pathways::types::{Duration, EntityId, EventId};
+
+fn simulation(ids: &[&str]) -> Simulation {
+    let mut sim = Simulation::new(reference());
+    for id in ids {
+        let entity = EntityBuilder::new()
+            .id(*id)
+            .species(Species::Human)
+            .age(Duration::years(30))
+            .build()
+            .unwrap();
+        sim.add_entity(entity, reference());
+    }
+    sim
+}
+
+fn id(id: &str) -> EntityId {
+    EntityId::new(id).unwrap()
+}
+
+fn event(event_type: EventType, event_id: &str, target: &str) -> Event {
+    EventBuilder::new(event_type)
+        .id(EventId::new(event_id).unwrap())
+        .target(id(target))
+        .severity(0.7)
+        .build()
+        .unwrap()
+}
+
+/// Tests that a removed event no longer influences `state_at()`.
+///
+/// Validates: state after removing a loss matches a simulation that only
+/// ever had the remaining events, before and after the removed timestamp.
+#[test]
+fn removed_event_no_longer_influences_state() {
+    let mut edited = simulation(&["person"]);
+    edited.add_event(
+        event(EventType::Support, "support", "person"),
+        reference() + Duration::days(2),
+    );
+    edited.add_event(
+        event(EventType::Loss, "loss", "person"),
+        reference() + Duration::days(5),
+    );
+
+    let mut untouched = simulation(&["person"]);
+    untouched.add_event(
+        event(EventType::Support, "support", "person"),
+        reference() + Duration::days(2),
+    );
+
+    let query = reference() + Duration::days(6);
+    let before = edited.entity(&id("person")).unwrap().state_at(query);
+    let removed = edited.remove_event(&EventId::new("loss").unwrap()).unwrap();
+    assert_eq!(removed.event().event_type(), EventType::Loss);
+
+    let handle = edited.entity(&id("person")).unwrap();
+    let expected = untouched.entity(&id("person")).unwrap();
+    assert_ne!(
+        handle.state_at(query).individual_state(),
+        before.individual_state()
+    );
+    for offset in [1, 3, 6, 30] {
+        let at = reference() + Duration::days(offset);
+        assert_eq!(
+            handle.state_at(at).individual_state(),
+            expected.state_at(at).individual_state()
+        );
+    }
+    assert_eq!(edited.events_for(&id("person")).len(), 1);
+}
+
+/// Tests that the other entities stay queryable after an entity is
+/// removed.
+///
+/// Validates: removal is refused while an event targets the entity, then
+/// succeeds once it is gone, leaving the remaining entity's state and
+/// events unchanged.
+#[test]
+fn querying_remaining_entities_after_entity_removal() {
+    let mut sim = simulation(&["alice", "bob"]);
+    sim.add_event(
+        event(EventType::Loss, "alice_loss", "alice"),
+        reference() + Duration::days(1),
+    );
+    sim.add_event(
+        event(EventType::Loss, "bob_loss", "bob"),
+        reference() + Duration::days(3),
+    );
+    let query = reference() + Duration::days(10);
+    let alice_before = sim.entity(&id("alice")).unwrap().state_at(query);
+
+    match sim.remove_entity(&id("bob")) {
+        Err(RemoveEntityError::Referenced { events, .. }) => {
+            assert_eq!(events, vec![EventId::new("bob_loss").unwrap()]);
+        }
+        other => panic!("expected a Referenced error, got {other:?}"),
+    }
+    sim.remove_event(&EventId::new("bob_loss").unwrap());
+    sim.remove_entity(&id("bob")).unwrap();
+
+    assert!(sim.entity(&id("bob")).is_none());
+    assert_eq!(sim.entity_count(), 1);
+    let alice = sim.entity(&id("alice")).unwrap();
+    assert_eq!(
+        alice.state_at(query).individual_state(),
+        alice_before.individual_state()
+    );
+    assert_eq!(sim.events_for(&id("alice")).len(), 1);
+}
+
+/// Tests that a removed relationship can no longer be queried.
+///
+/// Validates: the relationship lookup is gone and the entities can then be
+/// removed.
+#[test]
+fn removed_relationship_is_no_longer_queryable() {
+    let mut sim = simulation(&["alice", "bob"]);
+    let rel_id = sim.add_relationship(
+        id("alice"),
+        id("bob"),
+        RelationshipSchema::Peer,
+        reference(),
+    );
+    assert!(sim.relationship(&id("alice"), &id("bob")).is_some());
+
+    assert!(sim.remove_relationship(&rel_id).is_some());
+    assert!(sim.relationship(&id("alice"), &id("bob")).is_none());
+    assert!(sim.get_relationship(&rel_id).is_none());
+    assert!(sim.remove_entity(&id("alice")).is_ok());
+    assert!(sim.remove_entity(&id("bob")).is_ok());
+}
@@ -0,0 +1,202 @@
+//! Integration tests for scoped events.
+//!
+//! Tests that group, microsystem, and global events reach the entities in
+//! their scope, each interpreting the event through its own personality.
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::context::{EcologicalContext, Microsystem, WorkContext};
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{
+    EventScope, EventType, MoodPath, PersonalityProfile, Species, StatePath,
+};
+use behavioral_pathways::event::{Event, EventBuilder};
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::state::Hexaco;
+use behavioral_pathways::types::{Duration, EntityId, GroupId, MicrosystemId, Timestamp};
+
+const VALENCE: StatePath = StatePath::Mood(MoodPath::Valence);
+
+fn event_day() -> Timestamp {
+    reference() + Duration::days(10)
+}
+
+fn person(id: &str, personality: PersonalityProfile) -> EntityBuilder {
+    EntityBuilder::new()
+        .id(id)
+        .species(Species::Human)
+        .age(Duration::years(30))
+        .personality(personality)
+}
+
+fn loss() -> Event {
+    EventBuilder::new(EventType::Loss)
+        .severity(0.8)
+        .build()
+        .unwrap()
+}
+
+/// Returns the change in valence across the event day.
+fn valence_drop(sim: &Simulation, id: &EntityId) -> f64 {
+    let handle = sim.entity(id).unwrap();
+    handle.state_at(event_day()).get_effective(VALENCE)
+        - handle
+            .state_at(event_day() - Duration::seconds(1))
+            .get_effective(VALENCE)
+}
+
+/// Tests that a global event reaches every entity anchored before it, each
+/// responding through its own personality, and no entity anchored or added
+/// after it.
+///
+/// Validates: global fan-out, per-entity interpretation, and the anchor
+/// rule for broadcasts.
+#[test]
+fn global_event_reaches_entities_anchored_before_it() {
+    let mut sim = Simulation::new(reference());
+    let anxious = sim.add_entity(
+        person("anxious", PersonalityProfile::Anxious)
+            .build()
+            .unwrap(),
+        reference(),
+    );
+    let steady = sim.add_entity(
+        person("steady", PersonalityProfile::Balanced)
+            .build()
+            .unwrap(),
+        reference(),
+    );
+    let newcomer = sim.add_entity(
+        person("newcomer", PersonalityProfile::Balanced)
+            .build()
+            .unwrap(),
+        event_day() + Duration::days(5),
+    );
+
+    let added = sim
+        .add_scoped_event(loss(), EventScope::Global, event_day())
+        .unwrap();
+    let late = sim.add_entity(
+        person("late", PersonalityProfile::Balanced)
+            .build()
+            .unwrap(),
+        reference(),
+    );
+
+    assert_eq!(added.len(), 2);
+    assert_eq!(sim.events_for(&anxious).len(), 1);
+    assert_eq!(sim.events_for(&steady).len(), 1);
+    assert!(sim.events_for(&newcomer).is_empty());
+    assert!(sim.events_for(&late).is_empty());
+
+    let anxious_drop = valence_drop(&sim, &anxious);
+    let steady_drop = valence_drop(&sim, &steady);
+    assert!(steady_drop < 0.0);
+    assert!(
+        anxious_drop < steady_drop,
+        "anxious {} steady {}",
+        anxious_drop,
+        steady_drop
+    );
+    assert!(valence_drop(&sim, &late).abs() < 1e-9);
+}
+
+/// Tests that a group event reaches only the group's members.
+///
+/// Validates: group fan-out to members, and no effect on others.
+#[test]
+fn group_event_reaches_members_only() {
+    let mut sim = Simulation::new(reference());
+    let ids: Vec<EntityId> = ["ana", "ben", "cas"]
+        .into_iter()
+        .map(|id| {
+            let entity = person(id, PersonalityProfile::Balanced).build().unwrap();
+            sim.add_entity(entity, reference())
+        })
+        .collect();
+    let choir = GroupId::new("choir").unwrap();
+    sim.add_group_member(choir.clone(), ids[0].clone());
+    sim.add_group_member(choir.clone(), ids[2].clone());
+
+    sim.add_scoped_event(loss(), EventScope::Group(choir), event_day())
+        .unwrap();
+    assert!(valence_drop(&sim, &ids[0]) < 0.0);
+    assert!(valence_drop(&sim, &ids[1]).abs() < 1e-9);
+    assert!(valence_drop(&sim, &ids[2]) < 0.0);
+}
+
+/// Tests that a group event added for a household reaches each member
+/// through that member's own personality.
+///
+/// Validates: `add_group_event` registers the members, each copy records
+/// the group, and a high-neuroticism member's valence drops further than
+/// a low-neuroticism member's.
+#[test]
+fn group_event_interpreted_per_member() {
+    let mut sim = Simulation::new(reference());
+    let household: Vec<EntityId> = [("parent", 0.1), ("teen", 0.9)]
+        .into_iter()
+        .map(|(id, neuroticism)| {
+            let entity = person(id, PersonalityProfile::Balanced)
+                .hexaco(Hexaco::new().with_neuroticism(neuroticism))
+                .build()
+                .unwrap();
+            sim.add_entity(entity, reference())
+        })
+        .collect();
+    let family = GroupId::new("family").unwrap();
+
+    let added = sim
+        .add_group_event(loss(), family.clone(), &household, event_day())
+        .unwrap();
+    assert_eq!(added.len(), 2);
+    assert_eq!(sim.group_members(&family), household.as_slice());
+    assert!(household
+        .iter()
+        .all(|id| sim.events_for(id)[0].event().group() == Some(&family)));
+
+    let calm_drop = valence_drop(&sim, &household[0]);
+    let anxious_drop = valence_drop(&sim, &household[1]);
+    assert!(calm_drop < 0.0);
+    assert!(
+        anxious_drop < calm_drop,
+        "anxious {} calm {}",
+        anxious_drop,
+        calm_drop
+    );
+}
+
+/// Tests that a microsystem event reaches every entity holding the
+/// microsystem and no one else.
+///
+/// Validates: microsystem fan-out and the copies' microsystem context.
+#[test]
+fn microsystem_event_reaches_its_members() {
+    let office = MicrosystemId::new("office").unwrap();
+    let mut context = EcologicalContext::default();
+    context.add_microsystem(
+        office.clone(),
+        Microsystem::new_work(WorkContext::default()),
+    );
+
+    let mut sim = Simulation::new(reference());
+    let worker = sim.add_entity(
+        person("worker", PersonalityProfile::Balanced)
+            .with_context(context)
+            .build()
+            .unwrap(),
+        reference(),
+    );
+    let retiree = sim.add_entity(
+        person("retiree", PersonalityProfile::Balanced)
+            .build()
+            .unwrap(),
+        reference(),
+    );
+
+    sim.add_scoped_event(loss(), EventScope::Microsystem(office.clone()), event_day())
+        .unwrap();
+    let events = sim.events_for(&worker);
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event().microsystem_context(), Some(&office));
+    assert!(sim.events_for(&retiree).is_empty());
+}
@@ -0,0 +1,122 @@
+//! Integration tests for sampling state over a range in one pass.
+//!
+//! Tests that every sample of `state_range()` equals the `state_at()` query
+//! at its time, with events inside the window, samples before the anchor,
+//! and an observation the walk restarts at.
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{EventTag, EventType, RampShape, Species};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::{
+    BaseAbsorptionConfig, EntityQueryHandle, ObservationPolicy, Simulation,
+};
+use behavioral_pathways::state::IndividualState;
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+/// Builds a person anchored at the reference with events before and after
+/// it, one of them ramped.
+fn person_with_events() -> (Simulation, EntityId) {
+    let mut sim = Simulation::new(reference());
+    let person = EntityBuilder::new()
+        .id("person")
+        .species(Species::Human)
+        .age(Duration::years(30))
+        .build()
+        .unwrap();
+    let id = sim.add_entity(person, reference());
+    let events = [
+        (EventType::Loss, reference() - Duration::days(20)),
+        (EventType::SocialExclusion, reference() - Duration::days(6)),
+        (EventType::Achievement, reference() + Duration::days(3)),
+        (EventType::SocialExclusion, reference() + Duration::days(9)),
+        (EventType::Conflict, reference() + Duration::days(17)),
+    ];
+    for (event_type, at) in events {
+        let event = EventBuilder::new(event_type)
+            .target(id.clone())
+            .severity(0.7)
+            .build()
+            .unwrap();
+        sim.add_event(event, at);
+    }
+    let ramped = EventBuilder::new(EventType::SocialExclusion)
+        .target(id.clone())
+        .severity(0.6)
+        .tag(EventTag::ChronicPattern)
+        .ramp(Duration::days(8), RampShape::Linear)
+        .build()
+        .unwrap();
+    sim.add_event(ramped, reference() + Duration::days(11));
+    (sim, id)
+}
+
+/// Asserts each sample of `state_range()` equals `state_at()` at its time.
+fn assert_matches_state_at(handle: &EntityQueryHandle<'_>, start: Timestamp, end: Timestamp) {
+    let range = handle.state_range(start, end, Duration::hours(36));
+    assert!(!range.is_empty());
+    for (ts, sampled) in &range {
+        let queried = handle.state_at(*ts);
+        assert_eq!(sampled.individual_state(), queried.individual_state());
+        assert_eq!(sampled.approximate_reasons(), queried.approximate_reasons());
+        assert_eq!(sampled.summary, queried.summary);
+    }
+}
+
+/// Tests that samples match separate queries with several events inside
+/// the window, starting before the anchor.
+///
+/// Validates: every sample, before and after the anchor, has the same
+/// individual state, approximation reason, and summary as `state_at()`.
+#[test]
+fn samples_match_state_at_across_anchor() {
+    let (sim, id) = person_with_events();
+    let handle = sim.entity(&id).unwrap();
+    assert_matches_state_at(
+        &handle,
+        reference() - Duration::days(30),
+        reference() + Duration::days(40),
+    );
+}
+
+/// Tests that samples match separate queries when the walk restarts at an
+/// observation, blends toward it, and absorbs chronic deltas.
+///
+/// Validates: every sample has the same individual state, approximation
+/// reason, and summary as `state_at()`.
+#[test]
+fn samples_match_state_at_through_observation() {
+    let (mut sim, id) = person_with_events();
+    sim.set_observation_policy(ObservationPolicy::Blend {
+        window: Duration::days(5),
+    });
+    sim.set_base_absorption_config(Some(BaseAbsorptionConfig {
+        onset: Duration::days(1),
+        ..Default::default()
+    }));
+    let mut observed = IndividualState::new();
+    observed.mood_mut().add_valence_delta(-0.3);
+    sim.add_observation(&id, observed, reference() + Duration::days(14));
+
+    let handle = sim.entity(&id).unwrap();
+    assert_matches_state_at(
+        &handle,
+        reference() - Duration::days(10),
+        reference() + Duration::days(30),
+    );
+}
+
+/// Tests that an empty window yields no samples.
+///
+/// Validates: `state_range()` returns nothing when `end` is before `start`.
+#[test]
+fn empty_window_has_no_samples() {
+    let (sim, id) = person_with_events();
+    let handle = sim.entity(&id).unwrap();
+    let range = handle.state_range(
+        reference(),
+        reference() - Duration::days(1),
+        Duration::days(1),
+    );
+    assert!(range.is_empty());
+}
@@ -0,0 +1,173 @@
+//! Integration tests for stochastic variation.
+//!
+//! Tests that `SimulationBuilder::stochastic()` perturbs interpreted deltas
+//! reproducibly for a seed, whatever order events are added in, and that
+//! without it, or with seed 0, state queries match deterministic mode.
+
+use crate::integration::fixtures::{person, reference};
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{EventType, MoodPath, Species, StatePath};
+use behavioral_pathways::event::{Event, EventBuilder};
+use behavioral_pathways::simulation::{Simulation, SimulationBuilder, StochasticConfig};
+use behavioral_pathways::state::IndividualState;
+use behavioral_pathways::types::{Duration, EventId, Timestamp};
+
+fn event(event_type: EventType, id: &str, severity: f64) -> Event {
+    EventBuilder::new(event_type)
+        .id(EventId::new(id).unwrap())
+        .target(person())
+        .severity(severity)
+        .build()
+        .unwrap()
+}
+
+/// A setback, a conflict, and a support event over the first week.
+fn history() -> Vec<(Event, Timestamp)> {
+    vec![
+        (
+            event(EventType::Failure, "setback", 0.7),
+            reference() + Duration::days(1),
+        ),
+        (
+            event(EventType::Conflict, "argument", 0.6),
+            reference() + Duration::days(3),
+        ),
+        (
+            event(EventType::Support, "help", 0.5),
+            reference() + Duration::days(5),
+        ),
+    ]
+}
+
+/// Builds a simulation with the history, configured by `configure`.
+fn simulation(
+    events: Vec<(Event, Timestamp)>,
+    configure: impl FnOnce(SimulationBuilder) -> SimulationBuilder,
+) -> Simulation {
+    let entity = EntityBuilder::new()
+        .id("person")
+        .species(Species::Human)
+        .age(Duration::years(30))
+        .build()
+        .unwrap();
+    let builder = SimulationBuilder::new(reference()).add_entity(entity, reference());
+    events
+        .into_iter()
+        .fold(configure(builder), |builder, (event, at)| {
+            builder.add_event(event, at)
+        })
+        .build()
+        .unwrap()
+}
+
+fn state_at(sim: &Simulation, days: u64) -> IndividualState {
+    sim.entity(&person())
+        .unwrap()
+        .state_at(reference() + Duration::days(days))
+        .individual_state()
+        .clone()
+}
+
+/// Tests that a seed reproduces every state exactly.
+///
+/// Validates: two simulations built with the same seed give identical
+/// states throughout the history, and a third built with the events in
+/// reverse order gives the same states too.
+#[test]
+fn same_seed_reproduces_states_in_any_event_order() {
+    let first = simulation(history(), |builder| builder.stochastic(42));
+    let second = simulation(history(), |builder| builder.stochastic(42));
+    let mut reversed_history = history();
+    reversed_history.reverse();
+    let reversed = simulation(reversed_history, |builder| builder.stochastic(42));
+
+    for days in [2, 4, 6, 10] {
+        assert_eq!(state_at(&first, days), state_at(&second, days));
+        assert_eq!(state_at(&first, days), state_at(&reversed, days));
+    }
+}
+
+/// Tests that seeds vary the outcome within the noise magnitude.
+///
+/// Validates: different seeds give different valence after the setback,
+/// each within 10% of the deterministic shift, and a larger magnitude
+/// spreads them further.
+#[test]
+fn different_seeds_diverge_within_the_magnitude() {
+    let valence = StatePath::Mood(MoodPath::Valence);
+    let shift = |sim: &Simulation| {
+        let handle = sim.entity(&person()).unwrap();
+        handle
+            .state_at(reference() + Duration::days(1))
+            .get_effective(valence)
+            - handle.state_at(reference()).get_effective(valence)
+    };
+    let deterministic = shift(&simulation(history(), |builder| builder));
+    assert!(deterministic < -0.05);
+
+    let shifts: Vec<f64> = (1..=20)
+        .map(|seed| shift(&simulation(history(), |builder| builder.stochastic(seed))))
+        .collect();
+    assert!(shifts.windows(2).any(|pair| pair[0] != pair[1]));
+    for shift in &shifts {
+        let ratio = shift / deterministic;
+        assert!((0.9 - 1e-6..=1.1 + 1e-6).contains(&ratio), "Ratio {ratio}");
+    }
+
+    let wide: Vec<f64> = (1..=20)
+        .map(|seed| {
+            shift(&simulation(history(), |builder| {
+                builder.stochastic(seed).noise_magnitude(0.5)
+            }))
+        })
+        .collect();
+    let spread = |shifts: &[f64]| {
+        shifts.iter().cloned().fold(f64::MIN, f64::max)
+            - shifts.iter().cloned().fold(f64::MAX, f64::min)
+    };
+    assert!(spread(&wide) > spread(&shifts));
+}
+
+/// Tests that seed 0 and no stochastic mode match deterministic queries.
+///
+/// Validates: a simulation without stochastic mode has no stochastic
+/// config, and one seeded with 0 gives exactly its states.
+#[test]
+fn seed_zero_matches_deterministic_mode() {
+    let plain = simulation(history(), |builder| builder);
+    let zero = simulation(history(), |builder| builder.stochastic(0));
+    assert!(plain.stochastic_config().is_none());
+    assert_eq!(zero.stochastic_config().map(|config| config.seed), Some(0));
+
+    for days in [2, 4, 6, 10] {
+        assert_eq!(state_at(&plain, days), state_at(&zero, days));
+    }
+}
+
+/// Tests that changing the seed after compaction re-derives the checkpoint.
+///
+/// Validates: a simulation compacted under one seed and then reseeded
+/// matches, after the cutoff, an uncompacted simulation built with the new
+/// seed.
+#[test]
+fn reseeding_after_compaction_rebuilds_checkpoints() {
+    let cutoff = reference() + Duration::days(400);
+    let mut events = history();
+    events.push((
+        event(EventType::SocialExclusion, "exclusion", 0.9),
+        cutoff - Duration::days(1),
+    ));
+    let mut compacted = simulation(events.clone(), |builder| builder.stochastic(42));
+    assert_eq!(compacted.compact(cutoff).folded(), 3);
+
+    compacted.set_stochastic_config(Some(StochasticConfig::new(7)));
+    let reseeded = simulation(events, |builder| builder.stochastic(7));
+
+    let at = cutoff + Duration::days(1);
+    let projected = compacted.entity(&person()).unwrap().state_at(at);
+    let replayed = reseeded.entity(&person()).unwrap().state_at(at);
+    for path in StatePath::all() {
+        let diff = projected.get_effective(path) - replayed.get_effective(path);
+        assert!(diff.abs() < 1e-3, "{:?} differs by {}", path, diff);
+    }
+}
@@ -0,0 +1,117 @@
+//! Integration tests for spawning entities from templates.
+//!
+//! Tests that template spawns match builder output, are anchored by the
+//! simulation that adds them, and are cheap enough for populations of
+//! short-lived NPCs.
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::context::{EcologicalContext, Microsystem, WorkContext};
+use behavioral_pathways::entity::{EntityBuildError, EntityBuilder, EntityTemplate};
+use behavioral_pathways::enums::{PersonalityProfile, Species};
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, MicrosystemId};
+use std::time::{Duration as WallTime, Instant};
+
+const POPULATION: usize = 10_000;
+
+/// The builder for a working adult NPC archetype.
+fn archetype() -> EntityBuilder {
+    let mut context = EcologicalContext::default();
+    let work = WorkContext {
+        workload_stress: 0.6,
+        ..Default::default()
+    };
+    context.add_microsystem(
+        MicrosystemId::new("work").unwrap(),
+        Microsystem::new_work(work),
+    );
+    EntityBuilder::new()
+        .species(Species::Human)
+        .age(Duration::years(35))
+        .personality(PersonalityProfile::Conscientious)
+        .with_context(context)
+}
+
+/// Returns the fastest of three timed runs of `run`.
+fn fastest(mut run: impl FnMut()) -> WallTime {
+    (0..3)
+        .map(|_| {
+            let start = Instant::now();
+            run();
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+/// Tests that a spawned entity equals the builder-built one.
+///
+/// Validates: templates keep the derived mood, life stage, context, and
+/// model configuration of the builder.
+#[test]
+fn spawned_entity_equals_built_entity() {
+    let template = EntityTemplate::compile(archetype()).unwrap();
+
+    let built = archetype().id("npc_001").build().unwrap();
+    assert_eq!(template.spawn("npc_001").unwrap(), built);
+}
+
+/// Tests that a simulation adds every spawned entity at the anchor.
+///
+/// Validates: add_from_template anchors each entity and rejects the whole
+/// batch on an invalid identifier.
+#[test]
+fn add_from_template_anchors_every_entity() {
+    let template = EntityTemplate::compile(archetype()).unwrap();
+    let mut sim = Simulation::new(reference());
+
+    let ids = sim
+        .add_from_template(&template, &["npc_001", "npc_002", "npc_003"], reference())
+        .unwrap();
+    assert_eq!(sim.entity_count(), 3);
+    for id in &ids {
+        let anchored = sim.get_anchored_entity(id).unwrap();
+        assert_eq!(anchored.anchor_timestamp(), reference());
+        assert_eq!(anchored.entity(), &template.spawn(id.as_str()).unwrap());
+    }
+
+    let result = sim.add_from_template(&template, &["npc_004", ""], reference());
+    assert!(matches!(result, Err(EntityBuildError::InvalidId(_))));
+    assert_eq!(sim.entity_count(), 3);
+}
+
+/// Tests that spawning a population from a template is at least five times
+/// faster than building it.
+///
+/// Validates: the builder's validation and derivation run once per
+/// template rather than once per entity.
+///
+/// This test measures wall-clock time, so it is marked #[ignore] and must
+/// be run explicitly, ideally in release mode:
+/// `cargo test --release --test integration spawning_ten_thousand -- --ignored`
+#[test]
+#[ignore = "Wall-clock benchmark - run explicitly with --ignored flag"]
+fn spawning_ten_thousand_is_five_times_faster_than_building() {
+    let ids: Vec<String> = (0..POPULATION).map(|i| format!("npc_{:05}", i)).collect();
+
+    let built = fastest(|| {
+        let entities: Vec<_> = ids
+            .iter()
+            .map(|id| archetype().id(id.as_str()).build().unwrap())
+            .collect();
+        assert_eq!(entities.len(), POPULATION);
+    });
+    let spawned = fastest(|| {
+        let template = EntityTemplate::compile(archetype()).unwrap();
+        let entities: Vec<_> = ids.iter().map(|id| template.spawn(id).unwrap()).collect();
+        assert_eq!(entities.len(), POPULATION);
+    });
+
+    println!("building took {:?}, spawning took {:?}", built, spawned);
+    assert!(
+        spawned * 5 <= built,
+        "spawning took {:?}, building took {:?}",
+        spawned,
+        built
+    );
+}
@@ -0,0 +1,207 @@
+//! Integration tests for time-to-threshold projections.
+//!
+//! Tests that projected crossing times match hand-computed decay, follow
+//! accumulating context loads, and land on the events that cause them.
+
+use crate::integration::fixtures::reference;
+#[cfg(feature = "context")]
+use behavioral_pathways::context::{EcologicalContext, Microsystem, WorkContext};
+use behavioral_pathways::entity::EntityBuilder;
+#[cfg(feature = "context")]
+use behavioral_pathways::enums::NeedsPath;
+use behavioral_pathways::enums::{EventType, MoodPath, Species, StatePath};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+#[cfg(feature = "context")]
+use behavioral_pathways::types::MicrosystemId;
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+use std::cmp::Ordering;
+
+const DOMINANCE: StatePath = StatePath::Mood(MoodPath::Dominance);
+#[cfg(feature = "context")]
+const STRESS: StatePath = StatePath::Needs(NeedsPath::Stress);
+
+fn setup(builder: EntityBuilder) -> (Simulation, EntityId) {
+    let mut sim = Simulation::new(reference());
+    let entity = builder
+        .id("person_001")
+        .species(Species::Human)
+        .age(Duration::years(30))
+        .build()
+        .unwrap();
+    let id = sim.add_entity(entity, reference());
+    (sim, id)
+}
+
+/// Adds a conflict of the given severity at `at`.
+fn add_conflict(sim: &mut Simulation, id: &EntityId, severity: f64, at: Timestamp) {
+    let event = EventBuilder::new(EventType::Conflict)
+        .target(id.clone())
+        .severity(severity)
+        .build()
+        .unwrap();
+    sim.add_event(event, at);
+}
+
+/// Tests that a decaying delta reaches a quarter of its size after two
+/// half-lives.
+///
+/// Validates: the solved crossing matches `base + delta * 0.5^(t/h)`
+/// solved by hand, for each of the two half-lives.
+#[test]
+fn decay_crossing_matches_hand_computed_half_lives() {
+    let (mut sim, id) = setup(EntityBuilder::new());
+    let landing = reference() + Duration::days(1);
+    add_conflict(&mut sim, &id, 0.8, landing);
+    let handle = sim.entity(&id).unwrap();
+
+    let state = handle.state_at(landing);
+    let dominance = state.individual_state().mood().dominance();
+    let base = f64::from(dominance.base());
+    let delta = f64::from(dominance.delta());
+    let half_life = dominance.decay_half_life().unwrap();
+    assert!(delta < 0.0);
+
+    for (half_lives, remaining) in [(1, 0.5), (2, 0.25)] {
+        let crossing = handle
+            .time_to_value(
+                DOMINANCE,
+                Ordering::Greater,
+                base + delta * remaining,
+                landing,
+                Duration::days(30),
+            )
+            .unwrap();
+        let expected = landing + half_life * half_lives;
+        let error = (crossing - expected)
+            .as_millis()
+            .max((expected - crossing).as_millis());
+        assert!(
+            error <= 1000,
+            "{} half-lives off by {}ms",
+            half_lives,
+            error
+        );
+    }
+}
+
+/// Tests that stress under a sustained workload overload is projected to
+/// cross a threshold it is not yet near.
+///
+/// Validates: the crossing of an accumulating load matches the linear
+/// climb observed from state queries, and is the first second above it.
+#[cfg(feature = "context")]
+#[test]
+fn accumulating_workload_stress_crossing_follows_the_climb() {
+    let mut context = EcologicalContext::default();
+    let work = WorkContext {
+        workload_stress: 0.95,
+        ..Default::default()
+    };
+    context.add_microsystem(
+        MicrosystemId::new("work").unwrap(),
+        Microsystem::new_work(work),
+    );
+    let (sim, id) = setup(EntityBuilder::new().with_context(context));
+    let handle = sim.entity(&id).unwrap();
+
+    let early = handle
+        .state_at(reference() + Duration::days(10))
+        .get_effective(STRESS);
+    let later = handle
+        .state_at(reference() + Duration::days(30))
+        .get_effective(STRESS);
+    let per_day = (later - early) / 20.0;
+    assert!(per_day > 0.0);
+    let threshold = 0.5;
+    let expected_days = 10.0 + (threshold - early) / per_day;
+
+    let crossing = handle
+        .time_to_value(
+            STRESS,
+            Ordering::Greater,
+            threshold,
+            reference(),
+            Duration::days(365),
+        )
+        .unwrap();
+    let days = (crossing - reference()).as_days_f64();
+    assert!(
+        (days - expected_days).abs() < 0.01,
+        "{} vs {}",
+        days,
+        expected_days
+    );
+    assert!(handle.state_at(crossing).get_effective(STRESS) > threshold);
+    assert!(
+        handle
+            .state_at(crossing - Duration::seconds(2))
+            .get_effective(STRESS)
+            <= threshold
+    );
+}
+
+/// Tests that an event whose landing carries the path across the threshold
+/// answers with its landing time.
+///
+/// Validates: a crossing caused by a logged event lands exactly on the
+/// event's timestamp, even when the search starts between events.
+#[test]
+fn crossing_caused_by_an_event_lands_on_its_timestamp() {
+    let (mut sim, id) = setup(EntityBuilder::new());
+    add_conflict(&mut sim, &id, 0.3, reference() + Duration::days(1));
+    let second = reference() + Duration::days(3);
+    add_conflict(&mut sim, &id, 0.9, second);
+    let handle = sim.entity(&id).unwrap();
+
+    let first_peak = handle
+        .state_at(reference() + Duration::days(1))
+        .get_effective(DOMINANCE);
+    let threshold = first_peak - 0.01;
+    assert!(handle.state_at(second).get_effective(DOMINANCE) < threshold);
+
+    let from = reference() + Duration::hours(30);
+    let crossing = handle
+        .time_to_value(
+            DOMINANCE,
+            Ordering::Less,
+            threshold,
+            from,
+            Duration::days(10),
+        )
+        .unwrap();
+    assert_eq!(crossing, second);
+}
+
+/// Tests that unreachable thresholds and thresholds already met are
+/// reported as such.
+///
+/// Validates: `None` when the condition does not hold by the end of the
+/// horizon, and `from` when it already holds.
+#[test]
+fn unreachable_and_already_met_thresholds() {
+    let (mut sim, id) = setup(EntityBuilder::new());
+    let landing = reference() + Duration::days(1);
+    add_conflict(&mut sim, &id, 0.8, landing);
+    let handle = sim.entity(&id).unwrap();
+
+    assert_eq!(
+        handle.time_to_value(
+            DOMINANCE,
+            Ordering::Less,
+            -0.9,
+            reference(),
+            Duration::days(30)
+        ),
+        None
+    );
+    // Recovery takes longer than an hour
+    assert_eq!(
+        handle.time_to_value(DOMINANCE, Ordering::Equal, 0.0, landing, Duration::hours(1)),
+        None
+    );
+    assert_eq!(
+        handle.time_to_value(DOMINANCE, Ordering::Less, 0.5, landing, Duration::days(30)),
+        Some(landing)
+    );
+}
@@ -3,9 +3,10 @@
 //! Tests that backward regression (querying state before anchor) works correctly.
 
 use behavioral_pathways::entity::EntityBuilder;
-use behavioral_pathways::enums::{EventType, MoodPath, Species, StatePath};
+use behavioral_pathways::enums::{EventTag, EventType, MoodPath, Species, SpiralType, StatePath};
 use behavioral_pathways::event::EventBuilder;
-use behavioral_pathways::simulation::{RegressionQuality, Simulation};
+use behavioral_pathways::simulation::{ApproximationReason, RegressionQuality, Simulation};
+use behavioral_pathways::state::Needs;
 use behavioral_pathways::types::{Duration, EntityId, Timestamp};
 
 /// Backward regression returns state before anchor.
@@ -145,6 +146,51 @@ fn backward_regression_through_trauma_is_approximate() {
     assert_eq!(state.regression_quality(), RegressionQuality::Approximate);
 }
 
+/// Backward regression lists every reason it is approximate.
+#[test]
+fn backward_regression_lists_every_approximation_reason() {
+    let reference = Timestamp::from_ymd_hms(2024, 6, 1, 0, 0, 0);
+    let mut sim = Simulation::new(reference);
+
+    let entity = EntityBuilder::new()
+        .id("person_001")
+        .species(Species::Human)
+        .age(Duration::years(30))
+        .build()
+        .unwrap();
+    let entity_id = sim.add_entity(entity, reference);
+
+    // Trauma before the anchor, and history after it compacted away
+    let trauma_event = EventBuilder::new(EventType::Violence)
+        .target(entity_id.clone())
+        .severity(0.8)
+        .build()
+        .unwrap();
+    let event_time = reference - Duration::days(30);
+    sim.add_event(trauma_event, event_time);
+    let exclusion = EventBuilder::new(EventType::SocialExclusion)
+        .target(entity_id.clone())
+        .severity(0.5)
+        .build()
+        .unwrap();
+    sim.add_event(exclusion, reference + Duration::days(1));
+    let cutoff = reference + Duration::days(60);
+    assert_eq!(sim.compact(cutoff).folded(), 1);
+
+    // Query before the trauma: both the compaction and the trauma apply
+    let handle = sim.entity(&entity_id).unwrap();
+    let state = handle.state_at(event_time - Duration::days(1));
+
+    assert_eq!(state.regression_quality(), RegressionQuality::Approximate);
+    assert_eq!(
+        state.approximate_reasons(),
+        [
+            ApproximationReason::CompactedHistory { cutoff },
+            ApproximationReason::IrreversibleEvents,
+        ]
+    );
+}
+
 /// Forward projection is always exact.
 #[test]
 fn forward_projection_is_always_exact() {
@@ -203,3 +249,83 @@ fn regression_without_events_is_exact() {
     // Pure time regression is exact
     assert_eq!(state.regression_quality(), RegressionQuality::Exact);
 }
+
+/// Builds a simulation anchored at `reference` with a person at `stress`
+/// and twelve weeks of chronic financial burden before the anchor.
+fn under_mounting_pressure(reference: Timestamp, stress: f32) -> (Simulation, EntityId) {
+    let mut sim = Simulation::new(reference);
+    let entity = EntityBuilder::new()
+        .id("person_001")
+        .species(Species::Human)
+        .age(Duration::years(30))
+        .needs(Needs::new().with_stress_base(stress))
+        .build()
+        .unwrap();
+    let entity_id = sim.add_entity(entity, reference);
+
+    for week in 1..=12 {
+        let burden = EventBuilder::new(EventType::FinancialBurden)
+            .target(entity_id.clone())
+            .severity(0.7)
+            .tag(EventTag::ChronicPattern)
+            .build()
+            .unwrap();
+        sim.add_event(burden, reference - Duration::weeks(week));
+    }
+    (sim, entity_id)
+}
+
+/// Backward regression across events during a stress spiral is approximate.
+#[test]
+fn backward_regression_through_stress_spiral_is_approximate() {
+    let reference = Timestamp::from_ymd_hms(2024, 6, 1, 0, 0, 0);
+    let (sim, entity_id) = under_mounting_pressure(reference, 0.75);
+
+    // Query before the first burden, crossing all twelve
+    let handle = sim.entity(&entity_id).unwrap();
+    let state = handle.state_at(reference - Duration::weeks(13));
+
+    // The replayed stress stays above the spiral threshold at each crossing
+    assert_eq!(state.regression_quality(), RegressionQuality::Approximate);
+    assert_eq!(
+        state.approximate_reasons(),
+        [ApproximationReason::FeedbackSpiral(SpiralType::Stress)]
+    );
+
+    // A range walks back through the same crossings and agrees
+    let start = reference - Duration::weeks(13);
+    for (at, sample) in handle.state_range(start, reference, Duration::days(4)) {
+        assert_eq!(
+            sample.approximate_reasons(),
+            handle.state_at(at).approximate_reasons(),
+            "{}",
+            at
+        );
+    }
+}
+
+/// Backward regression that crosses no event stays exact despite a spiral.
+#[test]
+fn backward_regression_through_quiet_interval_is_exact() {
+    let reference = Timestamp::from_ymd_hms(2024, 6, 1, 0, 0, 0);
+    let (sim, entity_id) = under_mounting_pressure(reference, 0.75);
+
+    // Query between the last burden and the anchor
+    let handle = sim.entity(&entity_id).unwrap();
+    let state = handle.state_at(reference - Duration::days(3));
+
+    assert_eq!(state.regression_quality(), RegressionQuality::Exact);
+    assert_eq!(state.approximate_reasons(), []);
+}
+
+/// Backward regression across the same events without a spiral is exact.
+#[test]
+fn backward_regression_below_spiral_threshold_is_exact() {
+    let reference = Timestamp::from_ymd_hms(2024, 6, 1, 0, 0, 0);
+    let (sim, entity_id) = under_mounting_pressure(reference, 0.3);
+
+    let handle = sim.entity(&entity_id).unwrap();
+    let state = handle.state_at(reference - Duration::weeks(13));
+
+    assert_eq!(state.regression_quality(), RegressionQuality::Exact);
+}
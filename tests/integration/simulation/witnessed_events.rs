@@ -0,0 +1,168 @@
+//! Integration tests for witnessed events.
+//!
+//! Tests that entities listed with `EventBuilder::witness()` take a share
+//! of an event's impact scaled by their empathy, attribute it to someone
+//! else, and remember it as witnesses, while the target is unaffected by
+//! who watched.
+
+use crate::integration::fixtures::reference;
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{EventType, InterpretationRole, MoodPath, Species, StatePath};
+use behavioral_pathways::event::{Event, EventBuilder};
+#[cfg(feature = "memory")]
+use behavioral_pathways::memory::MemorySource;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::state::Disposition;
+use behavioral_pathways::types::{Duration, EntityId, EventId, Timestamp};
+
+fn id(name: &str) -> EntityId {
+    EntityId::new(name).unwrap()
+}
+
+/// A victim and two bystanders, one highly empathic and one barely.
+fn simulation() -> Simulation {
+    let mut sim = Simulation::new(reference());
+    for (name, empathy) in [("victim", 0.7), ("empathic", 0.95), ("callous", 0.05)] {
+        let entity = EntityBuilder::new()
+            .id(name)
+            .species(Species::Human)
+            .age(Duration::years(30))
+            .disposition(Disposition::new().with_empathy_base(empathy))
+            .build()
+            .unwrap();
+        sim.add_entity(entity, reference());
+    }
+    sim
+}
+
+fn assault(witnesses: &[&str]) -> Event {
+    witnesses
+        .iter()
+        .fold(
+            EventBuilder::new(EventType::Violence)
+                .id(EventId::new("assault").unwrap())
+                .source(id("attacker"))
+                .target(id("victim"))
+                .severity(0.8),
+            |builder, witness| builder.witness(id(witness)),
+        )
+        .build()
+        .unwrap()
+}
+
+fn assaulted_at() -> Timestamp {
+    reference() + Duration::days(1)
+}
+
+/// The change in a mood dimension from the reference to the assault.
+fn mood_shift(sim: &Simulation, name: &str, path: MoodPath) -> f64 {
+    let handle = sim.entity(&id(name)).unwrap();
+    let path = StatePath::Mood(path);
+    handle.state_at(assaulted_at()).get_effective(path)
+        - handle.state_at(reference()).get_effective(path)
+}
+
+/// Tests that a witness's reaction grows with empathy.
+///
+/// Validates: a highly empathic witness to violence takes a clear valence
+/// and arousal hit, smaller than the victim's, while a callous witness
+/// barely moves.
+#[test]
+fn empathic_witness_feels_violence_callous_witness_barely_moves() {
+    let mut sim = simulation();
+    sim.add_event(assault(&["empathic", "callous"]), assaulted_at());
+
+    let victim_valence = mood_shift(&sim, "victim", MoodPath::Valence);
+    let victim_arousal = mood_shift(&sim, "victim", MoodPath::Arousal);
+    let empathic_valence = mood_shift(&sim, "empathic", MoodPath::Valence);
+    let empathic_arousal = mood_shift(&sim, "empathic", MoodPath::Arousal);
+    let callous_valence = mood_shift(&sim, "callous", MoodPath::Valence);
+    let callous_arousal = mood_shift(&sim, "callous", MoodPath::Arousal);
+
+    assert!(victim_valence < -0.1 && victim_arousal > 0.1);
+    // Nearly half the victim's hit for a highly empathic witness
+    assert!(
+        empathic_valence < victim_valence * 0.4 && empathic_valence > victim_valence * 0.6,
+        "Empathic witness valence shift {empathic_valence} vs {victim_valence}"
+    );
+    assert!(empathic_arousal > victim_arousal * 0.4 && empathic_arousal < victim_arousal * 0.6);
+    assert!(callous_valence.abs() < victim_valence.abs() * 0.05);
+    assert!(callous_arousal.abs() < victim_arousal.abs() * 0.05);
+}
+
+/// Tests that the witness list leaves the target's experience alone.
+///
+/// Validates: the victim's state is identical with and without witnesses,
+/// and only witnesses see the event in their histories.
+#[test]
+fn target_is_unaffected_by_witnesses() {
+    let mut alone = simulation();
+    alone.add_event(assault(&[]), assaulted_at());
+    let mut watched = simulation();
+    watched.add_event(assault(&["empathic", "callous"]), assaulted_at());
+
+    let at = reference() + Duration::days(5);
+    let state = |sim: &Simulation| sim.entity(&id("victim")).unwrap().state_at(at);
+    assert_eq!(
+        state(&alone).individual_state(),
+        state(&watched).individual_state()
+    );
+    assert!(alone.events_for(&id("empathic")).is_empty());
+    assert_eq!(watched.events_for(&id("empathic")).len(), 1);
+    assert_eq!(watched.events_for(&id("victim")).len(), 1);
+}
+
+/// Tests that witnesses interpret the event as observers.
+///
+/// Validates: the witness's role is derived from the event, and it
+/// attributes the event to the attacker rather than itself.
+#[test]
+fn witnesses_attribute_outward() {
+    let mut sim = simulation();
+    let event = assault(&["empathic"]);
+    sim.add_event(event.clone(), assaulted_at());
+
+    let interpreted = sim
+        .get_anchored_entity(&id("empathic"))
+        .unwrap()
+        .entity()
+        .interpret_event(&event);
+    assert_eq!(interpreted.role, InterpretationRole::Witness);
+    assert!(!interpreted.attribution.is_self_caused());
+    assert_eq!(
+        interpreted.attribution.other_entity().map(EntityId::as_str),
+        Some("attacker")
+    );
+}
+
+/// Tests that witnesses remember the event as witnesses.
+///
+/// Validates: the memory the witness forms carries a
+/// `MemorySource::Witness` source, while the victim's memories are its
+/// own.
+#[test]
+#[cfg(feature = "memory")]
+fn witnesses_remember_as_witnesses() {
+    let mut sim = simulation();
+    sim.add_event(assault(&["empathic"]), assaulted_at());
+
+    let witness = sim.entity(&id("empathic")).unwrap();
+    let memories = witness.memories_at(reference() + Duration::days(2));
+    let memory = memories
+        .iter()
+        .find(|memory| {
+            memory
+                .event_id()
+                .is_some_and(|event| event.as_str() == "assault")
+        })
+        .expect("witnessing violence should be memorable");
+    assert_eq!(memory.source(), MemorySource::Witness);
+
+    let victim_memories = sim
+        .entity(&id("victim"))
+        .unwrap()
+        .memories_at(reference() + Duration::days(2));
+    assert!(victim_memories
+        .iter()
+        .all(|memory| memory.source() == MemorySource::Self_));
+}
@@ -4,3 +4,5 @@
 
 mod individual_state;
 mod mental_health_needs;
+#[cfg(feature = "serde")]
+mod snapshot_compat;
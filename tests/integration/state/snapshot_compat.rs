@@ -0,0 +1,126 @@
+//! Integration test: IndividualState snapshot compatibility.
+//!
+//! Validates that snapshots written by earlier crate versions keep loading:
+//! - Checked-in fixtures load with every value intact and nothing dropped
+//! - Every serialized field can be filled from defaults when missing
+//! - Fields written by newer versions are ignored and reported
+//!
+//! Fixtures in `tests/fixtures/snapshots` are never regenerated. When the
+//! schema version is raised, add a fixture for the new version alongside
+//! the old ones.
+
+use behavioral_pathways::state::{IndividualState, SNAPSHOT_SCHEMA_VERSION};
+use serde_json::Value;
+
+const V1_FIXTURE: &str = include_str!("../../fixtures/snapshots/individual_state_v1.json");
+
+/// Returns the dotted path of every field in a serialized state.
+fn field_paths(value: &Value, prefix: &str, paths: &mut Vec<String>) {
+    let Value::Object(map) = value else {
+        return;
+    };
+    for (key, child) in map {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        paths.push(path.clone());
+        // Map entries are data, not schema fields
+        if key != "self_efficacy_by_domain" {
+            field_paths(child, &path, paths);
+        }
+    }
+}
+
+/// Removes the field at a dotted path.
+fn remove_path(value: &mut Value, path: &str) {
+    let (parent, key) = match path.rsplit_once('.') {
+        Some((parent, key)) => (parent.split('.').fold(value, |v, k| &mut v[k]), key),
+        None => (value, path),
+    };
+    parent.as_object_mut().unwrap().remove(key);
+}
+
+/// Tests that the version 1 fixture still loads with its values intact.
+///
+/// Validates:
+/// - No field written by version 1 is dropped, so none was renamed or removed
+/// - Values set in the fixture survive loading
+#[test]
+fn v1_fixture_loads_without_losing_fields() {
+    let (state, report) = IndividualState::from_snapshot(V1_FIXTURE).unwrap();
+
+    assert_eq!(report.schema_version(), 1);
+    assert!(
+        report.ignored().is_empty(),
+        "dropped {:?}",
+        report.ignored()
+    );
+
+    assert!((state.hexaco().openness() - 0.8).abs() < 1e-6);
+    assert!((state.mood().valence_delta() + 0.3).abs() < 1e-6);
+    assert!((state.needs().stress().delta() - 0.4).abs() < 1e-6);
+    assert!((state.social_cognition().loneliness().delta() - 0.25).abs() < 1e-6);
+    let efficacy = state
+        .person_characteristics()
+        .self_efficacy("work")
+        .unwrap();
+    assert!((efficacy - 0.6).abs() < 1e-6);
+    assert_eq!(state.demographical().name, "Avery");
+}
+
+/// Policy test: every serialized field must be defaultable.
+///
+/// Validates:
+/// - An empty state loads entirely from defaults
+/// - Removing any single field from a current snapshot still loads, with
+///   that field reported as defaulted
+///
+/// A new field that fails here cannot be filled in for snapshots written
+/// before it existed, and needs a default in `IndividualState::new()`.
+#[test]
+fn every_serialized_field_is_defaultable() {
+    let empty = format!(
+        r#"{{"schema_version": {}, "state": {{}}}}"#,
+        SNAPSHOT_SCHEMA_VERSION
+    );
+    let (state, _) = IndividualState::from_snapshot(&empty).unwrap();
+    assert_eq!(state, IndividualState::new());
+
+    let current: Value = serde_json::from_str(&IndividualState::new().to_snapshot()).unwrap();
+    let mut paths = Vec::new();
+    field_paths(&current["state"], "", &mut paths);
+    assert!(paths.len() > 100);
+
+    for path in paths {
+        let mut snapshot = current.clone();
+        remove_path(&mut snapshot["state"], &path);
+        let (state, report) = IndividualState::from_snapshot(&snapshot.to_string())
+            .unwrap_or_else(|err| panic!("{} is not defaultable: {}", path, err));
+        assert_eq!(report.defaulted(), std::slice::from_ref(&path));
+        assert_eq!(state, IndividualState::new(), "{}", path);
+    }
+}
+
+/// Tests that a snapshot from a newer version loads in this one.
+///
+/// Validates:
+/// - Unknown fields at any depth are dropped and reported
+/// - The newer schema version is reported, and known values are kept
+/// - Nothing is defaulted beyond the fields added since the fixture
+#[test]
+fn newer_snapshot_fields_are_ignored_and_reported() {
+    let (_, v1_report) = IndividualState::from_snapshot(V1_FIXTURE).unwrap();
+    let mut snapshot: Value = serde_json::from_str(V1_FIXTURE).unwrap();
+    snapshot["schema_version"] = Value::from(SNAPSHOT_SCHEMA_VERSION + 1);
+    snapshot["state"]["mood"]["surprise"] = serde_json::json!({ "base": 0.2 });
+    snapshot["state"]["circadian"] = Value::from(0.5);
+
+    let (state, report) = IndividualState::from_snapshot(&snapshot.to_string()).unwrap();
+
+    assert_eq!(report.schema_version(), SNAPSHOT_SCHEMA_VERSION + 1);
+    assert_eq!(report.ignored(), ["circadian", "mood.surprise"]);
+    assert_eq!(report.defaulted(), v1_report.defaulted());
+    assert!((state.hexaco().openness() - 0.8).abs() < 1e-6);
+}
@@ -5,10 +5,12 @@
 mod simulation {
     // Domain tests (theoretical frameworks)
     mod affect;
+    mod ecology;
     mod mental_health;
 
     // System tests
     mod entity;
+    mod relationships;
     mod time;
 
     // Invariant tests
@@ -7,6 +7,8 @@
 mod job_promotion_elevates_valence_and_dominance;
 mod public_humiliation_creates_negative_valence_low_dominance_high_arousal;
 mod sleep_deprivation_lowers_arousal_without_valence_change;
+mod sleep_deprivation_payload_lowers_arousal_without_valence_change;
+mod sleep_loss_amplifies_next_day_setbacks;
 mod winning_lottery_high_valence_high_arousal_moderate_dominance;
 mod chronic_powerlessness_from_repeated_failures;
 mod meditation_lowers_arousal_increases_dominance_neutral_valence;
@@ -0,0 +1,124 @@
+//! Test: A night of lost sleep lowers arousal without changing valence.
+//!
+//! Tests that a `SleepDeprivation` event carrying a `Sleep` payload raises
+//! fatigue and lowers arousal while leaving valence and dominance alone,
+//! and that a later `Rest` event restores the lost energy.
+
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{
+    EventPayload, EventType, MoodPath, NeedsPath, Species, StatePath,
+};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+#[test]
+fn sleep_deprivation_payload_lowers_arousal_without_valence_change() {
+    // ========================================================================
+    // SETUP
+    // What we're doing: Creating a human entity who sleeps only two hours
+    // on the first night and a full eight hours on the second.
+    // ========================================================================
+
+    let reference = Timestamp::from_ymd_hms(2024, 1, 1, 8, 0, 0);
+    let mut sim = Simulation::new(reference);
+
+    let entity = EntityBuilder::new()
+        .id("sleepy_person")
+        .species(Species::Human)
+        .build()
+        .unwrap();
+
+    let entity_id = EntityId::new("sleepy_person").unwrap();
+    let anchor = reference;
+    sim.add_entity(entity, anchor);
+
+    let short_night = EventBuilder::new(EventType::SleepDeprivation)
+        .target(entity_id.clone())
+        .payload(EventPayload::Sleep {
+            hours: 2.0,
+            quality: 0.5,
+        })
+        .build()
+        .unwrap();
+    sim.add_event(short_night, anchor + Duration::hours(1));
+
+    let full_night = EventBuilder::new(EventType::Rest)
+        .target(entity_id.clone())
+        .payload(EventPayload::Sleep {
+            hours: 8.0,
+            quality: 1.0,
+        })
+        .build()
+        .unwrap();
+    sim.add_event(full_night, anchor + Duration::hours(24));
+
+    let valence = StatePath::Mood(MoodPath::Valence);
+    let arousal = StatePath::Mood(MoodPath::Arousal);
+    let dominance = StatePath::Mood(MoodPath::Dominance);
+    let fatigue = StatePath::Needs(NeedsPath::Fatigue);
+
+    // ========================================================================
+    // STAGE 1: Establish baseline morning state
+    // What we're testing: Entity starts with neutral PAD dimensions.
+    // ========================================================================
+
+    let handle = sim.entity(&entity_id).unwrap();
+    let morning_state = handle.state_at(anchor);
+
+    assert!(
+        morning_state.get_effective(valence).abs() < 0.1,
+        "Morning valence should be near neutral"
+    );
+
+    // ========================================================================
+    // STAGE 2: After the short night
+    // What we're testing: Fatigue rises and arousal falls.
+    // ========================================================================
+
+    let tired_state = handle.state_at(anchor + Duration::hours(2));
+
+    assert!(
+        tired_state.get_effective(fatigue) > morning_state.get_effective(fatigue) + 0.1,
+        "Fatigue should rise after two hours of poor sleep. Morning: {}, Tired: {}",
+        morning_state.get_effective(fatigue),
+        tired_state.get_effective(fatigue)
+    );
+    assert!(
+        tired_state.get_effective(arousal) < morning_state.get_effective(arousal) - 0.05,
+        "Arousal should fall after lost sleep. Morning: {}, Tired: {}",
+        morning_state.get_effective(arousal),
+        tired_state.get_effective(arousal)
+    );
+
+    // ========================================================================
+    // STAGE 3: Verify valence and dominance unaffected
+    // What we're testing: Lost sleep on its own changes arousal without a
+    // corresponding valence or dominance change (dimension independence).
+    // ========================================================================
+
+    assert!(
+        (tired_state.get_effective(valence) - morning_state.get_effective(valence)).abs() < 1e-6,
+        "Valence should not change from sleep loss alone"
+    );
+    assert!(
+        (tired_state.get_effective(dominance) - morning_state.get_effective(dominance)).abs()
+            < 1e-6,
+        "Dominance should not change from sleep loss alone"
+    );
+
+    // ========================================================================
+    // STAGE 4: After a full night of rest
+    // What we're testing: Rest brings fatigue back down.
+    // ========================================================================
+
+    let before_rest = handle.state_at(anchor + Duration::hours(23));
+    let rested_state = handle.state_at(anchor + Duration::hours(25));
+
+    assert!(
+        rested_state.get_effective(fatigue) < before_rest.get_effective(fatigue) - 0.1,
+        "Fatigue should fall after a full night. Before: {}, After: {}",
+        before_rest.get_effective(fatigue),
+        rested_state.get_effective(fatigue)
+    );
+}
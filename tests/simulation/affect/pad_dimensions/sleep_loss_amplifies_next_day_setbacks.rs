@@ -0,0 +1,92 @@
+//! Test: Setbacks the day after lost sleep hurt more.
+//!
+//! Tests that a negative event within `SLEEP_LOSS_WINDOW` of a
+//! `SleepDeprivation` lowers valence further than the same event for a
+//! rested twin, that a worse night amplifies more, and that the effect
+//! ends once the window has passed.
+
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{EventPayload, EventType, MoodPath, Species, StatePath};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::{Simulation, SLEEP_LOSS_WINDOW};
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+/// Valence change from a setback `setback_after` hours after a night of
+/// `hours` sleep, or after no recorded night at all.
+fn setback_valence_drop(hours: Option<f64>, setback_after: Duration) -> f64 {
+    let reference = Timestamp::from_ymd_hms(2024, 1, 1, 8, 0, 0);
+    let mut sim = Simulation::new(reference);
+    let entity = EntityBuilder::new()
+        .id("worker")
+        .species(Species::Human)
+        .build()
+        .unwrap();
+    let id = EntityId::new("worker").unwrap();
+    sim.add_entity(entity, reference);
+
+    if let Some(hours) = hours {
+        let night = EventBuilder::new(EventType::SleepDeprivation)
+            .target(id.clone())
+            .payload(EventPayload::Sleep {
+                hours,
+                quality: 0.5,
+            })
+            .build()
+            .unwrap();
+        sim.add_event(night, reference);
+    }
+
+    let setback_at = reference + setback_after;
+    let setback = EventBuilder::new(EventType::Failure)
+        .target(id.clone())
+        .severity(0.6)
+        .build()
+        .unwrap();
+    sim.add_event(setback, setback_at);
+
+    let handle = sim.entity(&id).unwrap();
+    let valence = StatePath::Mood(MoodPath::Valence);
+    let before = handle.state_at(setback_at - Duration::minutes(1));
+    let after = handle.state_at(setback_at);
+    after.get_effective(valence) - before.get_effective(valence)
+}
+
+#[test]
+fn sleep_loss_amplifies_next_day_setbacks() {
+    // ========================================================================
+    // STAGE 1: Setback the morning after
+    // What we're testing: A failure eight hours after a bad night lowers
+    // valence more than the same failure for a rested twin, and less
+    // sleep makes it worse.
+    // ========================================================================
+
+    let next_morning = Duration::hours(8);
+    let rested = setback_valence_drop(None, next_morning);
+    let short_night = setback_valence_drop(Some(6.0), next_morning);
+    let sleepless = setback_valence_drop(Some(0.0), next_morning);
+
+    assert!(rested < 0.0, "A failure should lower valence, got {rested}");
+    assert!(
+        short_night < rested,
+        "A short night should amplify the setback. Rested: {rested}, Short: {short_night}"
+    );
+    assert!(
+        sleepless < short_night,
+        "A sleepless night should amplify more. Short: {short_night}, None: {sleepless}"
+    );
+
+    // ========================================================================
+    // STAGE 2: Setback after the window
+    // What we're testing: Once SLEEP_LOSS_WINDOW has passed, the same
+    // failure hurts no more than it does for a rested twin.
+    // ========================================================================
+
+    let later = SLEEP_LOSS_WINDOW + Duration::hours(1);
+    let rested_later = setback_valence_drop(None, later);
+    let sleepless_later = setback_valence_drop(Some(0.0), later);
+    assert!(
+        (sleepless_later - rested_later).abs() < 1e-6,
+        "Amplification should end after the window. Rested: {rested_later}, \
+         Sleepless: {sleepless_later}"
+    );
+}
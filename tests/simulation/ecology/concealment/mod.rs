@@ -0,0 +1,6 @@
+//! Concealment burden tests.
+//!
+//! Tests for how keeping a secret, and disclosing it, shape loneliness and
+//! self-hate over time.
+
+mod year_of_concealment_diverges_from_early_disclosure;
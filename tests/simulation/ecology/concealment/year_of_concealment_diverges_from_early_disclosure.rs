@@ -0,0 +1,162 @@
+//! Test: A year of concealment diverges from early disclosure.
+//!
+//! Sam keeps a secret from their partner Alex. In one simulation the secret
+//! is kept for the whole year; in the other Sam discloses it a month in and
+//! Alex responds supportively. Keeping the secret adds a stress and
+//! self-hate maintenance load and weakens how much the relationship buffers
+//! loneliness, so a year on Sam is lonelier and more self-critical than
+//! after the early disclosure.
+
+use behavioral_pathways::context::{
+    EcologicalContext, InteractionProfile, Microsystem, SocialContext,
+};
+use behavioral_pathways::entity::{Entity, EntityBuilder};
+use behavioral_pathways::enums::{
+    EventPayload, EventType, LifeDomain, MoodPath, RelationshipSchema, SocialCognitionPath,
+    Species, StatePath,
+};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::relationship::RelationshipStage;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EntityId, MicrosystemId, RelationshipId, Timestamp};
+
+const LONELINESS: StatePath = StatePath::SocialCognition(SocialCognitionPath::Loneliness);
+const SELF_HATE: StatePath = StatePath::SocialCognition(SocialCognitionPath::SelfHate);
+
+fn build_person(id: &str) -> Entity {
+    let mut context = EcologicalContext::default();
+    let social = SocialContext {
+        warmth: 0.7,
+        interaction_profile: InteractionProfile::with_values(0.5, 0.5),
+        ..Default::default()
+    };
+    context.add_microsystem(
+        MicrosystemId::new("friends").unwrap(),
+        Microsystem::new_social(social),
+    );
+
+    EntityBuilder::new()
+        .id(id)
+        .species(Species::Human)
+        .birth_date(Timestamp::from_ymd_hms(1995, 9, 1, 0, 0, 0))
+        .with_context(context)
+        .build()
+        .unwrap()
+}
+
+/// Builds Sam and Alex as partners in touch every month, with Sam keeping a
+/// secret from Alex from the first day.
+fn build_couple(reference: Timestamp) -> (Simulation, EntityId, RelationshipId) {
+    let mut sim = Simulation::new(reference);
+    let sam = sim.add_entity(build_person("sam"), reference);
+    let alex = sim.add_entity(build_person("alex"), reference);
+    let rel_id = sim.add_relationship(
+        sam.clone(),
+        alex.clone(),
+        RelationshipSchema::Romantic,
+        reference,
+    );
+    sim.get_relationship_mut(&rel_id)
+        .unwrap()
+        .relationship_mut()
+        .set_stage(RelationshipStage::Intimate)
+        .unwrap();
+
+    for month in 1..=12 {
+        let call = EventBuilder::new(EventType::Interaction)
+            .source(alex.clone())
+            .target(sam.clone())
+            .severity(0.2)
+            .build()
+            .unwrap();
+        sim.add_event(call, reference + Duration::days(30 * month));
+    }
+
+    let concealment = EventBuilder::new(EventType::Interaction)
+        .target(sam.clone())
+        .payload(EventPayload::Concealment {
+            domain: LifeDomain::Relationship,
+            intensity: 0.8,
+            ongoing: true,
+            kept_from: vec![rel_id.clone()],
+            until: None,
+        })
+        .build()
+        .unwrap();
+    sim.add_event(concealment, reference + Duration::days(1));
+    (sim, sam, rel_id)
+}
+
+/// Concealing for a year leaves more loneliness and self-hate than an early,
+/// supportively received disclosure.
+#[test]
+fn year_of_concealment_diverges_from_early_disclosure() {
+    // ========================================================================
+    // SETUP
+    // What we're doing: Sam conceals a secret from Alex in both
+    // simulations. In the disclosure simulation Sam tells Alex after a
+    // month and Alex responds with near-full acceptance.
+    // ========================================================================
+
+    let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let (concealed_sim, sam, _) = build_couple(reference);
+    let (mut disclosed_sim, _, rel_id) = build_couple(reference);
+    let disclosed_at = reference + Duration::days(31);
+    let disclosure = EventBuilder::new(EventType::Interaction)
+        .target(sam.clone())
+        .severity(0.6)
+        .payload(EventPayload::Disclosure {
+            domain: LifeDomain::Relationship,
+            relationship: rel_id,
+            reaction: 0.9,
+        })
+        .build()
+        .unwrap();
+    disclosed_sim.add_event(disclosure, disclosed_at);
+
+    // ========================================================================
+    // STAGE 1: The disclosure itself
+    // What we're testing: A supportive reaction lifts valence at once.
+    // ========================================================================
+
+    let valence = StatePath::Mood(MoodPath::Valence);
+    let handle = disclosed_sim.entity(&sam).unwrap();
+    let before = handle
+        .state_at(disclosed_at - Duration::seconds(1))
+        .get_effective(valence);
+    let after = handle.state_at(disclosed_at).get_effective(valence);
+    assert!(
+        after > before,
+        "Supportive disclosure should lift valence. Before: {}, After: {}",
+        before,
+        after
+    );
+
+    // ========================================================================
+    // STAGE 2: One year on
+    // What we're testing: Concealment kept for the year leaves Sam lonelier
+    // and more self-critical than the early disclosure.
+    // ========================================================================
+
+    let horizon = reference + Duration::years(1);
+    let concealed = concealed_sim.entity(&sam).unwrap().state_at(horizon);
+    let disclosed = disclosed_sim.entity(&sam).unwrap().state_at(horizon);
+
+    let concealed_loneliness = concealed.get_effective(LONELINESS);
+    let disclosed_loneliness = disclosed.get_effective(LONELINESS);
+    assert!(
+        concealed_loneliness > disclosed_loneliness + 0.03,
+        "Concealment should leave Sam lonelier. Concealed: {}, Disclosed: {}",
+        concealed_loneliness,
+        disclosed_loneliness
+    );
+
+    let concealed_self_hate = concealed.get_effective(SELF_HATE);
+    let disclosed_self_hate = disclosed.get_effective(SELF_HATE);
+    assert!(
+        concealed_self_hate > disclosed_self_hate + 0.05,
+        "Concealment should leave Sam more self-critical. Concealed: {}, Disclosed: {}",
+        concealed_self_hate,
+        disclosed_self_hate
+    );
+}
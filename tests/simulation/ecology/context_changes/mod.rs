@@ -0,0 +1,7 @@
+//! Context change tests.
+//!
+//! Tests for changes to one person's microsystems partway through a
+//! simulation, such as starting a new job, scheduled as timestamped
+//! context changes.
+
+mod new_high_stress_job_raises_stress_after_hire;
@@ -0,0 +1,138 @@
+//! Test: A new high-stress job raises stress only after the hire.
+//!
+//! A parent at home takes a demanding job halfway through the year. The
+//! job is scheduled as a context change, so queries before the hire see
+//! the home alone and queries after it see both microsystems, linked
+//! through the mesosystem. Stress tracks a control parent who never takes
+//! the job until the hire, then rises above it. A second simulation
+//! anchored after the hire regresses to a date before it and sees the
+//! pre-hire context.
+
+use behavioral_pathways::context::{
+    EcologicalContext, FamilyContext, FamilyRole, InteractionProfile, Microsystem, WorkContext,
+};
+use behavioral_pathways::entity::{Entity, EntityBuilder};
+use behavioral_pathways::enums::{NeedsPath, Species, StatePath};
+use behavioral_pathways::simulation::{ContextChange, Simulation};
+use behavioral_pathways::types::{EntityId, MicrosystemId, Timestamp};
+
+fn home() -> MicrosystemId {
+    MicrosystemId::new("home").unwrap()
+}
+
+fn work() -> MicrosystemId {
+    MicrosystemId::new("work_logistics").unwrap()
+}
+
+fn build_parent() -> Entity {
+    let mut context = EcologicalContext::default();
+    let family = FamilyContext {
+        family_role: FamilyRole::Parent,
+        caregiving_burden: 0.3,
+        interaction_profile: InteractionProfile::with_values(0.8, 0.6),
+        ..Default::default()
+    };
+    context.add_microsystem(home(), Microsystem::new_family(family));
+
+    EntityBuilder::new()
+        .id("parent")
+        .species(Species::Human)
+        .birth_date(Timestamp::from_ymd_hms(1988, 5, 1, 0, 0, 0))
+        .with_context(context)
+        .build()
+        .unwrap()
+}
+
+fn new_job() -> ContextChange {
+    let job = WorkContext {
+        workload_stress: 0.9,
+        hostility: 0.4,
+        interaction_profile: InteractionProfile::with_values(0.7, 0.6),
+        ..Default::default()
+    };
+    ContextChange::AddMicrosystem {
+        id: work(),
+        microsystem: Microsystem::new_work(job),
+    }
+}
+
+/// A high-stress job added mid-simulation raises stress from the hire on.
+#[test]
+fn new_high_stress_job_raises_stress_after_hire() {
+    // ========================================================================
+    // SETUP
+    // What we're doing: Two identical parents anchored on New Year's Day.
+    // One takes a high-stress job on July 1st; the other stays home.
+    // ========================================================================
+
+    let reference = Timestamp::from_ymd_hms(2020, 1, 1, 0, 0, 0);
+    let hired = Timestamp::from_ymd_hms(2020, 7, 1, 0, 0, 0);
+    let entity_id = EntityId::new("parent").unwrap();
+    let stress = StatePath::Needs(NeedsPath::Stress);
+
+    let mut job_sim = Simulation::new(reference);
+    job_sim.add_entity(build_parent(), reference);
+    assert!(job_sim.add_context_change(&entity_id, new_job(), hired));
+
+    let mut home_sim = Simulation::new(reference);
+    home_sim.add_entity(build_parent(), reference);
+
+    let working = job_sim.entity(&entity_id).unwrap();
+    let control = home_sim.entity(&entity_id).unwrap();
+
+    // ========================================================================
+    // STAGE 1: Spring, before the hire
+    // What we're testing: The job is not part of the context yet, so the
+    // two parents are indistinguishable.
+    // ========================================================================
+
+    let spring = Timestamp::from_ymd_hms(2020, 4, 1, 0, 0, 0);
+    let before = working.state_at(spring);
+    assert_eq!(before.context().microsystem_count(), 1);
+    assert!(before.context().list_linkages().is_empty());
+    assert_eq!(
+        before.get_effective(stress),
+        control.state_at(spring).get_effective(stress)
+    );
+
+    // ========================================================================
+    // STAGE 2: Autumn, after the hire
+    // What we're testing: The job raises aggregate context stress, links
+    // to the home through the mesosystem, and adds stress over the control.
+    // ========================================================================
+
+    let autumn = Timestamp::from_ymd_hms(2020, 10, 1, 0, 0, 0);
+    let after = working.state_at(autumn);
+    assert_eq!(after.context().microsystem_count(), 2);
+    assert!(
+        after.context().aggregate_stress() > before.context().aggregate_stress() + 0.2,
+        "The job should raise aggregate stress from {} to well above it, got {}",
+        before.context().aggregate_stress(),
+        after.context().aggregate_stress()
+    );
+    let linkages = after.context().list_linkages();
+    assert_eq!(linkages.len(), 1);
+    assert!(linkages[0] == (home(), work()) || linkages[0] == (work(), home()));
+    assert!(after.context().get_spillover(&work(), &home()) > 0.0);
+
+    let added = after.get_effective(stress) - control.state_at(autumn).get_effective(stress);
+    assert!(added > 0.01, "The job should add stress, got {}", added);
+
+    // ========================================================================
+    // STAGE 3: Regressing from an anchor after the hire
+    // What we're testing: A parent observed in the new year, with the same
+    // scheduled job, sees the home-only context when queried in spring.
+    // ========================================================================
+
+    let new_year = Timestamp::from_ymd_hms(2021, 1, 1, 0, 0, 0);
+    let mut late_sim = Simulation::new(new_year);
+    late_sim.add_entity(build_parent(), new_year);
+    late_sim.add_context_change(&entity_id, new_job(), hired);
+    let late = late_sim.entity(&entity_id).unwrap();
+
+    assert_eq!(late.state_at(new_year).context().microsystem_count(), 2);
+    let regressed = late.state_at(spring);
+    assert_eq!(regressed.context().microsystem_count(), 1);
+    assert!(regressed.context().get_microsystem(&work()).is_none());
+    assert!(regressed.context().list_linkages().is_empty());
+}
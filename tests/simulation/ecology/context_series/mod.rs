@@ -0,0 +1,6 @@
+//! Context series tests.
+//!
+//! Tests for exosystem conditions that change over time for everyone at
+//! once, such as economic indicators, supplied as time series.
+
+mod unemployment_series_drives_resource_stress;
@@ -0,0 +1,106 @@
+//! Test: An unemployment series drives resource stress without any events.
+//!
+//! The unemployment rate spikes in spring 2020 and recovers by 2023. Fed in
+//! as a resource availability series, it should raise stress for a worker
+//! queried during the spike and leave a worker queried after the recovery
+//! no more stressed than one living with the static default context, even
+//! though neither simulation contains a single event.
+
+use behavioral_pathways::entity::{Entity, EntityBuilder};
+use behavioral_pathways::enums::{ContextPath, ExosystemPath, NeedsPath, Species, StatePath};
+use behavioral_pathways::simulation::{Interpolation, Simulation};
+use behavioral_pathways::types::{EntityId, Timestamp};
+
+fn build_worker() -> Entity {
+    EntityBuilder::new()
+        .id("worker")
+        .species(Species::Human)
+        .birth_date(Timestamp::from_ymd_hms(1985, 3, 1, 0, 0, 0))
+        .build()
+        .unwrap()
+}
+
+fn month(year: i32, month: u32) -> Timestamp {
+    Timestamp::from_ymd_hms(year, month, 1, 0, 0, 0)
+}
+
+/// Monthly-sampled unemployment rate, as a fraction of the labor force.
+fn unemployment() -> Vec<(Timestamp, f64)> {
+    vec![
+        (month(2019, 1), 0.040),
+        (month(2020, 2), 0.035),
+        (month(2020, 4), 0.147),
+        (month(2020, 12), 0.067),
+        (month(2021, 12), 0.039),
+        (month(2023, 1), 0.034),
+    ]
+}
+
+/// Maps unemployment to resource availability: full employment is ample,
+/// and each point of unemployment removes four points of availability.
+fn resource_availability(rate: f64) -> f64 {
+    (1.0 - 4.0 * rate).clamp(0.0, 1.0)
+}
+
+/// An unemployment spike raises stress in 2020 but not in 2023.
+#[test]
+fn unemployment_series_drives_resource_stress() {
+    // ========================================================================
+    // SETUP
+    // What we're doing: Two identical workers anchored in 2019 with no
+    // events. One simulation drives resource availability from the
+    // unemployment series; the other keeps the static default.
+    // ========================================================================
+
+    let reference = month(2019, 1);
+    let entity_id = EntityId::new("worker").unwrap();
+    let stress = StatePath::Needs(NeedsPath::Stress);
+    let resources = ContextPath::Exosystem(ExosystemPath::ResourceAvailability);
+
+    let series: Vec<(Timestamp, f64)> = unemployment()
+        .into_iter()
+        .map(|(timestamp, rate)| (timestamp, resource_availability(rate)))
+        .collect();
+    let mut economy_sim = Simulation::new(reference);
+    economy_sim.add_entity(build_worker(), reference);
+    economy_sim
+        .set_context_series(resources, series, Interpolation::Linear)
+        .unwrap();
+
+    let mut static_sim = Simulation::new(reference);
+    static_sim.add_entity(build_worker(), reference);
+
+    let economy = economy_sim.entity(&entity_id).unwrap();
+    let baseline = static_sim.entity(&entity_id).unwrap();
+    assert_eq!(economy.event_count(), 0);
+
+    // ========================================================================
+    // STAGE 1: Spring 2020, at the unemployment peak
+    // What we're testing: Scarce resources add stress over the static context.
+    // ========================================================================
+
+    let peak = month(2020, 5);
+    let adjustment_2020 = economy.state_at(peak).get_effective(stress)
+        - baseline.state_at(peak).get_effective(stress);
+    assert!(
+        adjustment_2020 > 0.05,
+        "Peak unemployment should add stress, got {}",
+        adjustment_2020
+    );
+
+    // ========================================================================
+    // STAGE 2: Spring 2023, after the recovery
+    // What we're testing: With unemployment back down there is no deficit,
+    // so the worker matches the static context again.
+    // ========================================================================
+
+    let recovered = month(2023, 5);
+    let adjustment_2023 = economy.state_at(recovered).get_effective(stress)
+        - baseline.state_at(recovered).get_effective(stress);
+    assert!(
+        adjustment_2023.abs() < 1e-6,
+        "Recovered economy should add no stress, got {}",
+        adjustment_2023
+    );
+    assert!(adjustment_2020 > adjustment_2023);
+}
@@ -0,0 +1,130 @@
+//! Test: Exclusion isolates more in a collectivist culture.
+//!
+//! Two identical people are shut out of their group on the same day. They
+//! differ only in the culture they live in: one collectivist, one
+//! individualist. Being excluded leaves the collectivist lonelier, while
+//! a work achievement does less to lift their self-worth.
+
+use behavioral_pathways::context::EcologicalContext;
+use behavioral_pathways::entity::{Entity, EntityBuilder};
+use behavioral_pathways::enums::{
+    Audience, EventPayload, EventType, LifeDomain, MentalHealthPath, SocialCognitionPath, Species,
+    StatePath,
+};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::{EntityQueryHandle, Simulation};
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+fn build_person(individualism: f64) -> Entity {
+    let mut context = EcologicalContext::default();
+    context
+        .macrosystem_mut()
+        .cultural_orientation
+        .individualism_collectivism = individualism;
+
+    EntityBuilder::new()
+        .id("lin")
+        .species(Species::Human)
+        .birth_date(Timestamp::from_ymd_hms(1994, 5, 20, 0, 0, 0))
+        .with_context(context)
+        .build()
+        .unwrap()
+}
+
+/// Builds a simulation where Lin is excluded on day 10 and promoted on
+/// day 40.
+fn build_simulation(reference: Timestamp, individualism: f64) -> Simulation {
+    let lin = EntityId::new("lin").unwrap();
+    let mut sim = Simulation::new(reference);
+    sim.add_entity(build_person(individualism), reference);
+
+    let exclusion = EventBuilder::new(EventType::SocialExclusion)
+        .target(lin.clone())
+        .severity(0.7)
+        .build()
+        .unwrap();
+    sim.add_event(exclusion, reference + Duration::days(10));
+
+    let promotion = EventBuilder::new(EventType::Achievement)
+        .target(lin)
+        .severity(0.7)
+        .payload(EventPayload::Achievement {
+            domain: LifeDomain::Work,
+            magnitude: 0.8,
+            audience: Audience::Private,
+        })
+        .build()
+        .unwrap();
+    sim.add_event(promotion, reference + Duration::days(40));
+    sim
+}
+
+/// Exclusion isolates a collectivist more and achievement protects them less.
+#[test]
+fn exclusion_isolates_more_in_a_collectivist_culture() {
+    // ========================================================================
+    // SETUP
+    // What we're doing: Lin lives in a strongly collectivist culture in one
+    // simulation and a strongly individualist one in the other. Everything
+    // else, including the events, is identical.
+    // ========================================================================
+
+    let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let lin = EntityId::new("lin").unwrap();
+    let collectivist_sim = build_simulation(reference, -0.8);
+    let individualist_sim = build_simulation(reference, 0.8);
+    let collectivist = collectivist_sim.entity(&lin).unwrap();
+    let individualist = individualist_sim.entity(&lin).unwrap();
+
+    // ========================================================================
+    // STAGE 1: The exclusion
+    // What we're testing: The rise in loneliness from just before to just
+    // after the exclusion is larger in the collectivist culture.
+    // ========================================================================
+
+    let loneliness = StatePath::SocialCognition(SocialCognitionPath::Loneliness);
+    let rise = |handle: &EntityQueryHandle<'_>| {
+        handle
+            .state_at(reference + Duration::days(10) + Duration::hours(1))
+            .get_effective(loneliness)
+            - handle
+                .state_at(reference + Duration::days(10) - Duration::hours(1))
+                .get_effective(loneliness)
+    };
+    let collectivist_rise = rise(&collectivist);
+    let individualist_rise = rise(&individualist);
+
+    assert!(individualist_rise > 0.0);
+    assert!(
+        collectivist_rise > individualist_rise * 1.3,
+        "Exclusion should isolate the collectivist more. Collectivist: {}, Individualist: {}",
+        collectivist_rise,
+        individualist_rise
+    );
+
+    // ========================================================================
+    // STAGE 2: The promotion
+    // What we're testing: The lift in self-worth from the promotion is
+    // smaller in the collectivist culture.
+    // ========================================================================
+
+    let self_worth = StatePath::MentalHealth(MentalHealthPath::SelfWorth);
+    let lift = |handle: &EntityQueryHandle<'_>| {
+        handle
+            .state_at(reference + Duration::days(40) + Duration::hours(1))
+            .get_effective(self_worth)
+            - handle
+                .state_at(reference + Duration::days(40) - Duration::hours(1))
+                .get_effective(self_worth)
+    };
+    let collectivist_lift = lift(&collectivist);
+    let individualist_lift = lift(&individualist);
+
+    assert!(collectivist_lift > 0.0);
+    assert!(
+        collectivist_lift < individualist_lift,
+        "Achievement should protect the collectivist less. Collectivist: {}, Individualist: {}",
+        collectivist_lift,
+        individualist_lift
+    );
+}
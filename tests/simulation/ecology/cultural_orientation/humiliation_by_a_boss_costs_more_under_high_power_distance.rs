@@ -0,0 +1,96 @@
+//! Test: Humiliation by a boss costs more under high power distance.
+//!
+//! Two identical employees are publicly dressed down by their manager. One
+//! lives in a culture that accepts steep hierarchy, the other in an
+//! egalitarian one. The humiliation, tagged as coming from an authority,
+//! costs the first far more sense of control. A humiliation by a peer
+//! costs both the same.
+
+use behavioral_pathways::context::EcologicalContext;
+use behavioral_pathways::entity::{Entity, EntityBuilder};
+use behavioral_pathways::enums::{EventTag, EventType, MoodPath, Species, StatePath};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+fn build_employee(power_distance: f64) -> Entity {
+    let mut context = EcologicalContext::default();
+    context
+        .macrosystem_mut()
+        .cultural_orientation
+        .power_distance = power_distance;
+
+    EntityBuilder::new()
+        .id("ravi")
+        .species(Species::Human)
+        .birth_date(Timestamp::from_ymd_hms(1990, 9, 2, 0, 0, 0))
+        .with_context(context)
+        .build()
+        .unwrap()
+}
+
+/// Builds a simulation where Ravi is humiliated on day 10, by a manager
+/// if `by_authority`.
+fn build_simulation(reference: Timestamp, power_distance: f64, by_authority: bool) -> Simulation {
+    let mut sim = Simulation::new(reference);
+    sim.add_entity(build_employee(power_distance), reference);
+
+    let mut humiliation = EventBuilder::new(EventType::Humiliation)
+        .target(EntityId::new("ravi").unwrap())
+        .severity(0.7);
+    if by_authority {
+        humiliation = humiliation.tag(EventTag::Authority);
+    }
+    sim.add_event(humiliation.build().unwrap(), reference + Duration::days(10));
+    sim
+}
+
+/// Humiliation by an authority costs more dominance under high power distance.
+#[test]
+fn humiliation_by_a_boss_costs_more_under_high_power_distance() {
+    // ========================================================================
+    // SETUP
+    // What we're doing: Ravi lives in a high power-distance culture (0.9) or
+    // an egalitarian one (0.1), and is humiliated on day 10 either by his
+    // manager or by a peer.
+    // ========================================================================
+
+    let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let ravi = EntityId::new("ravi").unwrap();
+    let dominance = StatePath::Mood(MoodPath::Dominance);
+    let drop = |power_distance: f64, by_authority: bool| -> f64 {
+        let sim = build_simulation(reference, power_distance, by_authority);
+        let handle = sim.entity(&ravi).unwrap();
+        handle
+            .state_at(reference + Duration::days(10) - Duration::hours(1))
+            .get_effective(dominance)
+            - handle
+                .state_at(reference + Duration::days(10) + Duration::hours(1))
+                .get_effective(dominance)
+    };
+
+    // ========================================================================
+    // STAGE 1: Humiliated by the manager
+    // What we're testing: The loss of dominance is larger in the
+    // hierarchical culture.
+    // ========================================================================
+
+    let hierarchical = drop(0.9, true);
+    let egalitarian = drop(0.1, true);
+    assert!(egalitarian > 0.0);
+    assert!(
+        hierarchical > egalitarian * 1.5,
+        "Humiliation by a boss should cost more under high power distance. \
+         Hierarchical: {}, Egalitarian: {}",
+        hierarchical,
+        egalitarian
+    );
+
+    // ========================================================================
+    // STAGE 2: Humiliated by a peer
+    // What we're testing: Without an authority source, power distance makes
+    // no difference.
+    // ========================================================================
+
+    assert!((drop(0.9, false) - drop(0.1, false)).abs() < 1e-9);
+}
@@ -0,0 +1,8 @@
+//! Cultural orientation tests.
+//!
+//! Tests for how the macrosystem's cultural orientation shapes the way an
+//! entity interprets events: collectivism and exclusion, power distance and
+//! humiliation by an authority.
+
+mod exclusion_isolates_more_in_a_collectivist_culture;
+mod humiliation_by_a_boss_costs_more_under_high_power_distance;
@@ -0,0 +1,130 @@
+//! Test: A depressed household member lowers their partner's valence.
+//!
+//! Two partners share a Family microsystem with frequent interaction. One is
+//! persistently depressed, with a low valence baseline. With contagion
+//! enabled, the partner's valence is pulled toward the household mean, so
+//! months later it sits clearly below that of an identical partner whose
+//! depressed counterpart lives in a separate household.
+
+use behavioral_pathways::context::{
+    EcologicalContext, FamilyContext, InteractionProfile, Microsystem,
+};
+use behavioral_pathways::entity::{Entity, EntityBuilder};
+use behavioral_pathways::enums::{MoodPath, Species, StatePath};
+use behavioral_pathways::simulation::{ContagionConfig, Simulation};
+use behavioral_pathways::state::{MentalHealth, Mood};
+use behavioral_pathways::types::{Duration, EntityId, MicrosystemId, Timestamp};
+
+fn household(id: &str) -> EcologicalContext {
+    let mut context = EcologicalContext::default();
+    let family = FamilyContext {
+        interaction_profile: InteractionProfile::with_values(0.9, 0.6),
+        ..Default::default()
+    };
+    context.add_microsystem(
+        MicrosystemId::new(id).unwrap(),
+        Microsystem::new_family(family),
+    );
+    context
+}
+
+fn build_depressed(id: &str, home: &str) -> Entity {
+    EntityBuilder::new()
+        .id(id)
+        .species(Species::Human)
+        .birth_date(Timestamp::from_ymd_hms(1988, 5, 1, 0, 0, 0))
+        .mood(Mood::new().with_valence_base(-0.6))
+        .mental_health(MentalHealth::new().with_depression_base(0.7))
+        .with_context(household(home))
+        .build()
+        .unwrap()
+}
+
+fn build_partner(id: &str, home: &str) -> Entity {
+    EntityBuilder::new()
+        .id(id)
+        .species(Species::Human)
+        .birth_date(Timestamp::from_ymd_hms(1987, 9, 1, 0, 0, 0))
+        .with_context(household(home))
+        .build()
+        .unwrap()
+}
+
+/// Sharing a household with a depressed partner lowers valence over months.
+#[test]
+fn depressed_household_member_lowers_partner_valence() {
+    // ========================================================================
+    // SETUP
+    // What we're doing: One simulation with contagion enabled holds two
+    // pairs. The first pair shares a household; the control pair lives in
+    // separate households. Partners and depressed members are identical
+    // across pairs.
+    // ========================================================================
+
+    let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let mut sim = Simulation::new(reference);
+    sim.set_contagion_config(Some(ContagionConfig::default()));
+
+    sim.add_entity(build_depressed("depressed", "home_shared"), reference);
+    sim.add_entity(build_partner("partner", "home_shared"), reference);
+    sim.add_entity(build_depressed("depressed_control", "home_a"), reference);
+    sim.add_entity(build_partner("partner_control", "home_b"), reference);
+
+    let partner = EntityId::new("partner").unwrap();
+    let control = EntityId::new("partner_control").unwrap();
+    let depressed = EntityId::new("depressed").unwrap();
+    let valence = StatePath::Mood(MoodPath::Valence);
+
+    // ========================================================================
+    // STAGE 1: Shared household drags the partner down
+    // What we're testing: Three months on, the partner in the shared
+    // household has lower valence than the control partner.
+    // ========================================================================
+
+    let three_months = reference + Duration::days(90);
+    let shared_valence = sim
+        .entity(&partner)
+        .unwrap()
+        .state_at(three_months)
+        .get_effective(valence);
+    let control_valence = sim
+        .entity(&control)
+        .unwrap()
+        .state_at(three_months)
+        .get_effective(valence);
+
+    assert!(
+        shared_valence < control_valence - 0.03,
+        "Partner of a depressed household member should have lower valence. \
+         Shared: {}, Control: {}",
+        shared_valence,
+        control_valence
+    );
+
+    // ========================================================================
+    // STAGE 2: Contagion runs both ways but stays bounded
+    // What we're testing: The depressed member is lifted slightly, and
+    // neither shift exceeds the configured cap.
+    // ========================================================================
+
+    let depressed_valence = sim
+        .entity(&depressed)
+        .unwrap()
+        .state_at(three_months)
+        .get_effective(valence);
+    let depressed_control_valence = sim
+        .entity(&EntityId::new("depressed_control").unwrap())
+        .unwrap()
+        .state_at(three_months)
+        .get_effective(valence);
+    let max_shift = ContagionConfig::default().max_shift;
+
+    assert!(
+        depressed_valence > depressed_control_valence,
+        "Depressed member should be lifted by the partner. Shared: {}, Control: {}",
+        depressed_valence,
+        depressed_control_valence
+    );
+    assert!(control_valence - shared_valence <= max_shift + 1e-6);
+    assert!(depressed_valence - depressed_control_valence <= max_shift + 1e-6);
+}
@@ -0,0 +1,6 @@
+//! Emotional contagion tests.
+//!
+//! Tests for how members of a shared microsystem drift toward each other's
+//! mood when contagion is enabled.
+
+mod depressed_household_member_lowers_partner_valence;
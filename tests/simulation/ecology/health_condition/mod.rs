@@ -0,0 +1,6 @@
+//! Chronic health condition tests.
+//!
+//! Tests for how Healthcare microsystem quality shapes the sustained
+//! burden of a chronic illness.
+
+mod poor_healthcare_access_worsens_chronic_illness_depression;
@@ -0,0 +1,147 @@
+//! Test: Poor healthcare access worsens depression from a chronic illness.
+//!
+//! A relapsing-remitting autoimmune condition adds a persistent fatigue and
+//! depression load plus periodic flares. How much of that load reaches the
+//! person depends on how well the condition is managed. With a rarely
+//! accessible, unresponsive clinic the condition stays largely unmanaged, so
+//! two years after onset depression is clearly worse than for the same
+//! condition under frequent, responsive care.
+
+use behavioral_pathways::context::{EcologicalContext, HealthcareContext, Microsystem};
+use behavioral_pathways::entity::{Entity, EntityBuilder};
+use behavioral_pathways::enums::{
+    ConditionKind, CourseShape, EventPayload, EventType, MentalHealthPath, NeedsPath, Species,
+    StatePath,
+};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EntityId, MicrosystemId, Timestamp};
+
+fn build_patient(care: HealthcareContext) -> Entity {
+    let mut context = EcologicalContext::default();
+    context.add_microsystem(
+        MicrosystemId::new("clinic").unwrap(),
+        Microsystem::new_healthcare(care),
+    );
+
+    EntityBuilder::new()
+        .id("patient")
+        .species(Species::Human)
+        .birth_date(Timestamp::from_ymd_hms(1984, 3, 1, 0, 0, 0))
+        .with_context(context)
+        .build()
+        .unwrap()
+}
+
+fn build_sim(care: HealthcareContext, with_condition: bool) -> Simulation {
+    let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let mut sim = Simulation::new(reference);
+    sim.add_entity(build_patient(care), reference);
+    if with_condition {
+        let diagnosis = EventBuilder::new(EventType::ChronicIllnessOnset)
+            .target(EntityId::new("patient").unwrap())
+            .severity(0.7)
+            .payload(EventPayload::HealthCondition {
+                condition: ConditionKind::Autoimmune,
+                chronic: true,
+                severity_course: CourseShape::RelapsingRemitting,
+            })
+            .build()
+            .unwrap();
+        sim.add_event(diagnosis, reference + Duration::days(14));
+    }
+    sim
+}
+
+fn poor_access() -> HealthcareContext {
+    HealthcareContext {
+        access_frequency: 0.1,
+        responsiveness: 0.2,
+        warmth: 0.3,
+        hostility: 0.4,
+        ..Default::default()
+    }
+}
+
+fn good_access() -> HealthcareContext {
+    HealthcareContext {
+        access_frequency: 0.9,
+        responsiveness: 0.9,
+        warmth: 0.8,
+        hostility: 0.0,
+        ..Default::default()
+    }
+}
+
+/// Poor healthcare access leaves a chronic illness unmanaged and depressing.
+#[test]
+fn poor_healthcare_access_worsens_chronic_illness_depression() {
+    // ========================================================================
+    // SETUP
+    // What we're doing: The same person diagnosed with the same autoimmune
+    // condition two weeks in, once with poor healthcare access and once with
+    // good access. Healthy controls with each clinic isolate the condition.
+    // ========================================================================
+
+    let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let entity_id = EntityId::new("patient").unwrap();
+    let two_years = reference + Duration::days(730);
+    let depression = StatePath::MentalHealth(MentalHealthPath::Depression);
+    let fatigue = StatePath::Needs(NeedsPath::Fatigue);
+
+    let poor_sim = build_sim(poor_access(), true);
+    let good_sim = build_sim(good_access(), true);
+    let poor_control_sim = build_sim(poor_access(), false);
+    let good_control_sim = build_sim(good_access(), false);
+
+    let poor = poor_sim.entity(&entity_id).unwrap().state_at(two_years);
+    let good = good_sim.entity(&entity_id).unwrap().state_at(two_years);
+    let poor_control = poor_control_sim
+        .entity(&entity_id)
+        .unwrap()
+        .state_at(two_years);
+    let good_control = good_control_sim
+        .entity(&entity_id)
+        .unwrap()
+        .state_at(two_years);
+
+    // ========================================================================
+    // STAGE 1: Chronic burden persists
+    // What we're testing: Two years on, the condition still weighs on both
+    // patients compared with their healthy controls.
+    // ========================================================================
+
+    let poor_burden = poor.get_effective(depression) - poor_control.get_effective(depression);
+    let good_burden = good.get_effective(depression) - good_control.get_effective(depression);
+
+    assert!(
+        poor_burden > 0.0 && good_burden > 0.0,
+        "Chronic illness should raise depression. Poor: {}, Good: {}",
+        poor_burden,
+        good_burden
+    );
+    assert!(
+        poor.get_effective(fatigue) > poor_control.get_effective(fatigue),
+        "Chronic illness should leave persistent fatigue"
+    );
+
+    // ========================================================================
+    // STAGE 2: Management effectiveness
+    // What we're testing: Poor access leaves more of the burden unmanaged,
+    // so 2-year depression is worse than under good access.
+    // ========================================================================
+
+    assert!(
+        poor.get_effective(depression) > good.get_effective(depression),
+        "Poor access should worsen depression. Poor: {}, Good: {}",
+        poor.get_effective(depression),
+        good.get_effective(depression)
+    );
+    assert!(
+        poor_burden > good_burden * 1.5,
+        "Illness burden under poor access should clearly exceed good access. \
+         Poor: {}, Good: {}",
+        poor_burden,
+        good_burden
+    );
+}
@@ -0,0 +1,18 @@
+//! Ecology (Bronfenbrenner) simulation tests.
+//!
+//! Tests for microsystem through chronosystem influences on development.
+
+#[cfg(all(feature = "context", feature = "relationships"))]
+mod concealment;
+#[cfg(feature = "context")]
+mod context_changes;
+#[cfg(feature = "context")]
+mod context_series;
+#[cfg(feature = "context")]
+mod cultural_orientation;
+mod emotional_contagion;
+#[cfg(feature = "context")]
+mod health_condition;
+#[cfg(feature = "context")]
+mod role_exit;
+mod turning_points;
@@ -0,0 +1,125 @@
+//! Test: Involuntary role exit without alternatives leaves purpose depressed.
+//!
+//! A forced exit ends the Work microsystem and its stress spillover, but with
+//! no volunteering or strong family role to fall back on, purpose stays low
+//! for the full two-year horizon. The exit also leaves a grievance and a
+//! self-worth hit that are still present, though fading, two years later.
+
+use behavioral_pathways::context::{EcologicalContext, InteractionProfile, Microsystem, WorkContext};
+use behavioral_pathways::entity::{Entity, EntityBuilder};
+use behavioral_pathways::enums::{
+    DispositionPath, EventPayload, EventType, LifeDomain, MentalHealthPath, NeedsPath, Species,
+    StatePath,
+};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EntityId, MicrosystemId, Timestamp};
+
+fn build_worker() -> Entity {
+    let mut context = EcologicalContext::default();
+    let work = WorkContext {
+        workload_stress: 0.85,
+        interaction_profile: InteractionProfile::with_values(0.8, 0.6),
+        ..Default::default()
+    };
+    context.add_microsystem(
+        MicrosystemId::new("plant").unwrap(),
+        Microsystem::new_work(work),
+    );
+
+    EntityBuilder::new()
+        .id("worker")
+        .species(Species::Human)
+        .birth_date(Timestamp::from_ymd_hms(1962, 7, 1, 0, 0, 0))
+        .with_context(context)
+        .build()
+        .unwrap()
+}
+
+/// Involuntary exit without alternative purpose sources depresses purpose.
+#[test]
+fn involuntary_role_exit_without_alternatives_depresses_purpose() {
+    // ========================================================================
+    // SETUP
+    // What we're doing: Two identical factory workers whose job is their
+    // only microsystem. One is pushed into early retirement after a month;
+    // the other keeps working as a control.
+    // ========================================================================
+
+    let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let entity_id = EntityId::new("worker").unwrap();
+    let plant_id = MicrosystemId::new("plant").unwrap();
+    let forced_out = reference + Duration::days(30);
+
+    let mut exited_sim = Simulation::new(reference);
+    exited_sim.add_entity(build_worker(), reference);
+    let exit = EventBuilder::new(EventType::ContextTransition)
+        .target(entity_id.clone())
+        .severity(0.8)
+        .context(plant_id)
+        .payload(EventPayload::RoleExit {
+            domain: LifeDomain::Work,
+            voluntary: false,
+        })
+        .build()
+        .unwrap();
+    exited_sim.add_event(exit, forced_out);
+
+    let mut working_sim = Simulation::new(reference);
+    working_sim.add_entity(build_worker(), reference);
+
+    let exited = exited_sim.entity(&entity_id).unwrap();
+    let working = working_sim.entity(&entity_id).unwrap();
+
+    // ========================================================================
+    // STAGE 1: Two years later
+    // What we're testing: Purpose has not recovered without alternatives.
+    // ========================================================================
+
+    let horizon = reference + Duration::years(2);
+    let exited_state = exited.state_at(horizon);
+    let working_state = working.state_at(horizon);
+
+    let purpose = StatePath::Needs(NeedsPath::Purpose);
+    assert!(
+        exited_state.get_effective(purpose) < working_state.get_effective(purpose) - 0.2,
+        "Purpose should stay depressed without alternatives. Exited: {}, Working: {}",
+        exited_state.get_effective(purpose),
+        working_state.get_effective(purpose)
+    );
+
+    // ========================================================================
+    // STAGE 2: Grievance and self-worth
+    // What we're testing: The imposed exit leaves a lasting grievance and
+    // self-worth hit.
+    // ========================================================================
+
+    let grievance = StatePath::Disposition(DispositionPath::Grievance);
+    assert!(
+        exited_state.get_effective(grievance) > working_state.get_effective(grievance) + 0.05,
+        "Involuntary exit should add grievance. Exited: {}, Working: {}",
+        exited_state.get_effective(grievance),
+        working_state.get_effective(grievance)
+    );
+
+    let self_worth = StatePath::MentalHealth(MentalHealthPath::SelfWorth);
+    assert!(
+        exited_state.get_effective(self_worth) < working_state.get_effective(self_worth) - 0.04,
+        "Involuntary exit should reduce self-worth. Exited: {}, Working: {}",
+        exited_state.get_effective(self_worth),
+        working_state.get_effective(self_worth)
+    );
+
+    // ========================================================================
+    // STAGE 3: Stress spillover
+    // What we're testing: Even an unwanted exit removes the job's stress.
+    // ========================================================================
+
+    let stress = StatePath::Needs(NeedsPath::Stress);
+    assert!(
+        exited_state.get_effective(stress) < working_state.get_effective(stress) - 0.1,
+        "Leaving the job should remove its stress spillover. Exited: {}, Working: {}",
+        exited_state.get_effective(stress),
+        working_state.get_effective(stress)
+    );
+}
@@ -0,0 +1,8 @@
+//! Role exit tests.
+//!
+//! Tests for retirement and other departures from a life role, crossing
+//! voluntary vs. involuntary exits with the presence or absence of
+//! alternative purpose sources.
+
+mod involuntary_role_exit_without_alternatives_depresses_purpose;
+mod voluntary_retirement_with_alternatives_recovers_purpose;
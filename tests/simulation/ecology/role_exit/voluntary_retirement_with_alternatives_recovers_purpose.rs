@@ -0,0 +1,148 @@
+//! Test: Voluntary retirement with alternative purpose sources recovers purpose.
+//!
+//! A planned retirement ends the Work microsystem, removing its stress
+//! spillover. Purpose dips when the role ends, but a warm spouse role and
+//! regular volunteering let it recover almost fully within two years.
+//! No grievance or self-worth hit follows a chosen exit.
+
+use behavioral_pathways::context::{
+    EcologicalContext, FamilyContext, FamilyRole, InteractionProfile, Microsystem, SocialContext,
+    WorkContext,
+};
+use behavioral_pathways::entity::{Entity, EntityBuilder};
+use behavioral_pathways::enums::{
+    DispositionPath, EventPayload, EventType, LifeDomain, MentalHealthPath, NeedsPath, Species,
+    StatePath,
+};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EntityId, MicrosystemId, Timestamp};
+
+fn build_retiree() -> Entity {
+    let mut context = EcologicalContext::default();
+    let work = WorkContext {
+        workload_stress: 0.85,
+        interaction_profile: InteractionProfile::with_values(0.8, 0.6),
+        ..Default::default()
+    };
+    context.add_microsystem(
+        MicrosystemId::new("hospital").unwrap(),
+        Microsystem::new_work(work),
+    );
+    let family = FamilyContext {
+        family_role: FamilyRole::Spouse,
+        warmth: 0.8,
+        ..Default::default()
+    };
+    context.add_microsystem(
+        MicrosystemId::new("home").unwrap(),
+        Microsystem::new_family(family),
+    );
+    let volunteering = SocialContext {
+        interaction_profile: InteractionProfile::with_values(0.7, 0.6),
+        ..Default::default()
+    };
+    context.add_microsystem(
+        MicrosystemId::new("literacy_program").unwrap(),
+        Microsystem::new_social(volunteering),
+    );
+
+    EntityBuilder::new()
+        .id("retiree")
+        .species(Species::Human)
+        .birth_date(Timestamp::from_ymd_hms(1959, 3, 1, 0, 0, 0))
+        .with_context(context)
+        .build()
+        .unwrap()
+}
+
+/// Voluntary retirement with a spouse role and volunteering recovers purpose.
+#[test]
+fn voluntary_retirement_with_alternatives_recovers_purpose() {
+    // ========================================================================
+    // SETUP
+    // What we're doing: Two identical nurses with a stressful job, a warm
+    // marriage, and a volunteering commitment. One retires by choice after
+    // a month; the other keeps working as a control.
+    // ========================================================================
+
+    let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let entity_id = EntityId::new("retiree").unwrap();
+    let retirement = reference + Duration::days(30);
+
+    let mut retired_sim = Simulation::new(reference);
+    retired_sim.add_entity(build_retiree(), reference);
+    let exit = EventBuilder::new(EventType::ContextTransition)
+        .target(entity_id.clone())
+        .severity(0.6)
+        .payload(EventPayload::RoleExit {
+            domain: LifeDomain::Work,
+            voluntary: true,
+        })
+        .build()
+        .unwrap();
+    retired_sim.add_event(exit, retirement);
+
+    let mut working_sim = Simulation::new(reference);
+    working_sim.add_entity(build_retiree(), reference);
+
+    let retired = retired_sim.entity(&entity_id).unwrap();
+    let working = working_sim.entity(&entity_id).unwrap();
+    let purpose = StatePath::Needs(NeedsPath::Purpose);
+
+    // ========================================================================
+    // STAGE 1: Shortly after retirement
+    // What we're testing: Leaving the role removes its purpose contribution.
+    // ========================================================================
+
+    let early = retirement + Duration::days(7);
+    let early_retired = retired.state_at(early).get_effective(purpose);
+    let early_working = working.state_at(early).get_effective(purpose);
+    assert!(
+        early_retired < early_working - 0.15,
+        "Purpose should dip after retirement. Retired: {}, Working: {}",
+        early_retired,
+        early_working
+    );
+
+    // ========================================================================
+    // STAGE 2: Two years later
+    // What we're testing: Alternatives let purpose recover, work stress
+    // spillover is gone, and no grievance or self-worth hit remains.
+    // ========================================================================
+
+    let horizon = reference + Duration::years(2);
+    let retired_state = retired.state_at(horizon);
+    let working_state = working.state_at(horizon);
+
+    let late_retired = retired_state.get_effective(purpose);
+    let late_working = working_state.get_effective(purpose);
+    assert!(
+        late_retired > late_working - 0.05,
+        "Purpose should recover with alternatives. Retired: {}, Working: {}",
+        late_retired,
+        late_working
+    );
+
+    let stress = StatePath::Needs(NeedsPath::Stress);
+    assert!(
+        retired_state.get_effective(stress) < working_state.get_effective(stress) - 0.05,
+        "Retirement should remove work stress spillover. Retired: {}, Working: {}",
+        retired_state.get_effective(stress),
+        working_state.get_effective(stress)
+    );
+
+    let grievance = StatePath::Disposition(DispositionPath::Grievance);
+    assert!(
+        (retired_state.get_effective(grievance) - working_state.get_effective(grievance)).abs()
+            < 0.01,
+        "Voluntary retirement should not add grievance"
+    );
+
+    let self_worth = StatePath::MentalHealth(MentalHealthPath::SelfWorth);
+    assert!(
+        (retired_state.get_effective(self_worth) - working_state.get_effective(self_worth)).abs()
+            < 0.01,
+        "Voluntary retirement should not reduce self-worth"
+    );
+}
@@ -0,0 +1,101 @@
+//! Test: A severe assault becomes a turning point; a mild scuffle does not.
+//!
+//! An assault that shifts neuroticism up and agreeableness down reshapes
+//! personality, which is what a turning point records, so later queries
+//! list it in the health domain. A scuffle with small shifts changes
+//! little and is not a turning point. Queries before the assault never
+//! list it.
+
+use behavioral_pathways::context::TurningPointDomain;
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{EventType, HexacoPath, Species};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EntityId, EventId, Timestamp};
+
+/// A simulation with an adult assaulted at `at`, with formative shifts of
+/// `shift` toward neuroticism and away from agreeableness.
+fn build_sim(reference: Timestamp, at: Timestamp, severity: f64, shift: f32) -> Simulation {
+    let person = EntityBuilder::new()
+        .id("person")
+        .species(Species::Human)
+        .birth_date(Timestamp::from_ymd_hms(1990, 3, 1, 0, 0, 0))
+        .build()
+        .unwrap();
+    let mut sim = Simulation::new(reference);
+    let id = sim.add_entity(person, reference);
+    let assault = EventBuilder::new(EventType::Violence)
+        .id(EventId::new("assault").unwrap())
+        .target(id)
+        .severity(severity)
+        .with_base_shift(HexacoPath::Neuroticism, shift)
+        .with_base_shift(HexacoPath::Agreeableness, -shift)
+        .build()
+        .unwrap();
+    sim.add_event(assault, at);
+    sim
+}
+
+/// A severe assault is listed as one turning point from then on.
+#[test]
+fn assault_becomes_a_turning_point() {
+    // ========================================================================
+    // SETUP
+    // What we're doing: Two adults anchored at the start of the year, one
+    // severely assaulted in March and one in a mild scuffle on the same day.
+    // ========================================================================
+
+    let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let assaulted_at = Timestamp::from_ymd_hms(2024, 3, 1, 0, 0, 0);
+    let severe = build_sim(reference, assaulted_at, 0.9, 0.3);
+    let mild = build_sim(reference, assaulted_at, 0.3, 0.05);
+    let id = EntityId::new("person").unwrap();
+
+    // ========================================================================
+    // STAGE 1: Before the assault
+    // What we're testing: No turning point is listed yet.
+    // ========================================================================
+
+    let handle = severe.entity(&id).unwrap();
+    assert!(handle.state_at(reference).turning_points().is_empty());
+    assert!(handle
+        .state_at(assaulted_at - Duration::days(1))
+        .turning_points()
+        .is_empty());
+
+    // ========================================================================
+    // STAGE 2: After the assault
+    // What we're testing: Every later query lists the assault once, as a
+    // health turning point as significant as the assault was severe.
+    // ========================================================================
+
+    for later in [
+        assaulted_at,
+        assaulted_at + Duration::days(30),
+        assaulted_at + Duration::years(2),
+    ] {
+        let state = handle.state_at(later);
+        let turning_points = state.turning_points();
+        assert_eq!(turning_points.len(), 1, "at {}", later);
+        assert_eq!(turning_points[0].event_id.as_str(), "assault");
+        assert_eq!(turning_points[0].timestamp, assaulted_at);
+        assert!(matches!(
+            turning_points[0].domain,
+            TurningPointDomain::Health | TurningPointDomain::Relationship
+        ));
+        assert!((turning_points[0].magnitude - 0.9).abs() < 1e-9);
+    }
+
+    // ========================================================================
+    // STAGE 3: The mild scuffle
+    // What we're testing: Its small shifts still apply, but never make it a
+    // turning point.
+    // ========================================================================
+
+    let mild_later = mild
+        .entity(&id)
+        .unwrap()
+        .state_at(assaulted_at + Duration::days(30));
+    assert!(!mild_later.base_shift_history().is_empty());
+    assert!(mild_later.turning_points().is_empty());
+}
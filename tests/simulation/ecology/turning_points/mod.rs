@@ -0,0 +1,6 @@
+//! Turning point tests.
+//!
+//! Tests for turning points derived from formative events in the
+//! chronosystem.
+
+mod assault_becomes_a_turning_point;
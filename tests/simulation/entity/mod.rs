@@ -1,5 +1,6 @@
 //! Entity lifecycle simulation tests.
 //!
-//! Tests for entity creation, aging, and state management.
+//! Tests for entity creation, aging, state management, and needs.
 
 mod creation;
+mod needs;
@@ -0,0 +1,88 @@
+//! Test: A fed dog is hungry again within a day.
+//!
+//! A meal raises satiation, which then falls back toward the hungry base.
+//! Satiation decays in psychological time, so a dog, living about seven
+//! psychological days per real day, is hungry again within hours, while a
+//! human fed the same meal is still full.
+
+use behavioral_pathways::entity::{Entity, EntityBuilder};
+use behavioral_pathways::enums::{EventType, NeedsPath, Species, StatePath};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+const SATIATION: StatePath = StatePath::Needs(NeedsPath::Satiation);
+
+fn build(species: Species) -> Entity {
+    EntityBuilder::new()
+        .id("subject")
+        .species(species)
+        .age(Duration::years(3))
+        .build()
+        .unwrap()
+}
+
+/// A simulation with `entity` anchored at `anchor` and fed at `meal`.
+fn build_sim(entity: Entity, anchor: Timestamp, meal: Timestamp) -> Simulation {
+    let mut sim = Simulation::new(anchor);
+    let id = sim.add_entity(entity, anchor);
+    let feeding = EventBuilder::new(EventType::Feeding)
+        .target(id)
+        .severity(0.8)
+        .build()
+        .unwrap();
+    sim.add_event(feeding, meal);
+    sim
+}
+
+fn satiation(sim: &Simulation, at: Timestamp) -> f64 {
+    let id = EntityId::new("subject").unwrap();
+    sim.entity(&id)
+        .unwrap()
+        .state_at(at)
+        .get_effective(SATIATION)
+}
+
+/// Satiation falls back to hungry over hours for a dog.
+#[test]
+fn dog_grows_hungry_within_a_day() {
+    // ========================================================================
+    // SETUP
+    // What we're doing: A dog and a human, each fed the same morning meal
+    // an hour after being anchored.
+    // ========================================================================
+
+    let anchor = Timestamp::from_ymd_hms(2024, 1, 1, 6, 0, 0);
+    let meal = anchor + Duration::hours(1);
+    let dog = build_sim(build(Species::Dog), anchor, meal);
+    let human = build_sim(build(Species::Human), anchor, meal);
+    let hungry = satiation(&dog, anchor);
+
+    // ========================================================================
+    // STAGE 1: At the meal
+    // What we're testing: Eating fills both.
+    // ========================================================================
+
+    let fed = satiation(&dog, meal);
+    assert!(fed > hungry + 0.3, "dog {} fed {}", hungry, fed);
+    assert!(satiation(&human, meal) > hungry + 0.3);
+
+    // ========================================================================
+    // STAGE 2: Over the day
+    // What we're testing: The dog grows steadily hungrier and is back at its
+    // hungry base by the next morning, while the human is still fuller
+    // than the dog at midday.
+    // ========================================================================
+
+    let midday = meal + Duration::hours(5);
+    let evening = meal + Duration::hours(12);
+    let next_morning = meal + Duration::days(1);
+    assert!(satiation(&dog, midday) < fed);
+    assert!(satiation(&dog, evening) < satiation(&dog, midday));
+    assert!(
+        (satiation(&dog, next_morning) - hungry).abs() < 0.01,
+        "dog still {} above hungry",
+        satiation(&dog, next_morning) - hungry
+    );
+    assert!(satiation(&human, midday) > satiation(&dog, midday) + 0.1);
+}
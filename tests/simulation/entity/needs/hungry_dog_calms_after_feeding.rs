@@ -0,0 +1,96 @@
+//! Test: Feeding calms a hungry dog, and barely calms a full one.
+//!
+//! A meal settles arousal in proportion to how hungry the animal is. Two
+//! dogs get the same meal: one observed at its hungry base calms clearly,
+//! one observed just after eating is already full and calms far less.
+
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{EventType, MoodPath, NeedsPath, Species, StatePath};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::state::Needs;
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+const AROUSAL: StatePath = StatePath::Mood(MoodPath::Arousal);
+const SATIATION: StatePath = StatePath::Needs(NeedsPath::Satiation);
+
+/// A simulation with a dog observed at `anchor` with `needs` and fed a
+/// minute later.
+fn build_sim(needs: Needs, anchor: Timestamp) -> Simulation {
+    let dog = EntityBuilder::new()
+        .id("rex")
+        .species(Species::Dog)
+        .age(Duration::years(3))
+        .needs(needs)
+        .build()
+        .unwrap();
+    let mut sim = Simulation::new(anchor);
+    let id = sim.add_entity(dog, anchor);
+    let meal = EventBuilder::new(EventType::Feeding)
+        .target(id)
+        .severity(0.8)
+        .build()
+        .unwrap();
+    sim.add_event(meal, anchor + Duration::minutes(1));
+    sim
+}
+
+/// Returns how much the meal lowered arousal, and the satiation before it.
+fn calm_from_meal(sim: &Simulation, anchor: Timestamp) -> (f64, f64) {
+    let handle = sim.entity(&EntityId::new("rex").unwrap()).unwrap();
+    let before = handle.state_at(anchor);
+    let after = handle.state_at(anchor + Duration::minutes(1));
+    (
+        before.get_effective(AROUSAL) - after.get_effective(AROUSAL),
+        before.get_effective(SATIATION),
+    )
+}
+
+/// Feeding lowers arousal in proportion to hunger.
+#[test]
+fn hungry_dog_calms_after_feeding() {
+    // ========================================================================
+    // SETUP
+    // What we're doing: Two dogs observed at noon, one at its hungry base and
+    // one that has just eaten, each fed the same meal a minute later.
+    // ========================================================================
+
+    let noon = Timestamp::from_ymd_hms(2024, 1, 1, 12, 0, 0);
+    let hungry = build_sim(Needs::new(), noon);
+    let mut just_ate = Needs::new();
+    just_ate.add_satiation_delta(0.6);
+    let full = build_sim(just_ate, noon);
+
+    // ========================================================================
+    // STAGE 1: The hungry dog
+    // What we're testing: It fills up and calms.
+    // ========================================================================
+
+    let (hungry_calm, hungry_satiation) = calm_from_meal(&hungry, noon);
+    let hungry_handle = hungry.entity(&EntityId::new("rex").unwrap()).unwrap();
+    let fed = hungry_handle
+        .state_at(noon + Duration::minutes(1))
+        .get_effective(SATIATION);
+    assert!(
+        fed > hungry_satiation + 0.3,
+        "satiation {} to {}",
+        hungry_satiation,
+        fed
+    );
+    assert!(hungry_calm > 0.05, "hungry dog calmed by {}", hungry_calm);
+
+    // ========================================================================
+    // STAGE 2: The full dog
+    // What we're testing: Already full, it calms far less from the same meal.
+    // ========================================================================
+
+    let (full_calm, full_satiation) = calm_from_meal(&full, noon);
+    assert!(full_satiation > hungry_satiation + 0.5);
+    assert!(full_calm >= 0.0);
+    assert!(
+        full_calm < hungry_calm * 0.5,
+        "full dog calmed by {} vs hungry {}",
+        full_calm,
+        hungry_calm
+    );
+}
@@ -0,0 +1,4 @@
+//! Tests for physiological needs driving non-human behavior.
+
+mod dog_grows_hungry_within_a_day;
+mod hungry_dog_calms_after_feeding;
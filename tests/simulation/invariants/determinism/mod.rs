@@ -3,3 +3,5 @@
 //! Determinism is a core invariant: same inputs must always produce same outputs.
 
 mod same_inputs_same_state_at_result;
+#[cfg(feature = "serde")]
+mod serde_round_trip_same_state_at_result;
@@ -0,0 +1,141 @@
+//! Test: A simulation reloaded from JSON produces the same state_at results
+//!
+//! Scenario: Build a simulation with contexts, events, a relationship, an
+//! interaction that forms memories, an observation, and a context series,
+//! then round-trip it through JSON
+//! Expected: Every state path is bit-identical at every query, and the
+//! JSON is the same on every serialization
+
+use behavioral_pathways::context::{EcologicalContext, Microsystem, WorkContext};
+use behavioral_pathways::entity::{Entity, EntityBuilder};
+use behavioral_pathways::enums::{
+    ContextPath, EventType, ExosystemPath, InteractionTopic, MentalHealthPath, RelationshipSchema,
+    Species, StatePath,
+};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::{Interpolation, Simulation};
+use behavioral_pathways::state::{IndividualState, MentalHealth};
+use behavioral_pathways::types::{Duration, EntityId, MicrosystemId, Timestamp};
+
+fn build_person(id: &str, work: &str) -> Entity {
+    let mut context = EcologicalContext::default();
+    context.add_microsystem(
+        MicrosystemId::new(work).unwrap(),
+        Microsystem::new_work(WorkContext::default()),
+    );
+    EntityBuilder::new()
+        .id(id)
+        .species(Species::Human)
+        .age(Duration::years(35))
+        .with_context(context)
+        .build()
+        .unwrap()
+}
+
+fn build_simulation(reference: Timestamp) -> Simulation {
+    let mut sim = Simulation::new(reference);
+    sim.set_memory_formation(true);
+    let alice = sim.add_entity(build_person("alice", "work_acme"), reference);
+    let bob = sim.add_entity(build_person("bob", "work_globex"), reference);
+    sim.add_relationship(
+        alice.clone(),
+        bob.clone(),
+        RelationshipSchema::Peer,
+        reference,
+    );
+
+    let exclusion = EventBuilder::new(EventType::SocialExclusion)
+        .target(alice.clone())
+        .source(bob.clone())
+        .severity(0.7)
+        .build()
+        .unwrap();
+    sim.add_event(exclusion, reference + Duration::days(10));
+    let job_loss = EventBuilder::new(EventType::JobLoss)
+        .target(bob.clone())
+        .severity(0.8)
+        .build()
+        .unwrap();
+    sim.add_event(job_loss, reference + Duration::days(40));
+    sim.record_interaction(
+        &alice,
+        &bob,
+        reference + Duration::days(60),
+        InteractionTopic::DeepConversation,
+        90,
+        0.8,
+    );
+
+    let observed = IndividualState::new()
+        .with_mental_health(MentalHealth::new().with_acquired_capability_base(0.3));
+    sim.add_observation(&bob, observed, reference + Duration::days(120));
+    sim.set_context_series(
+        ContextPath::Exosystem(ExosystemPath::ResourceAvailability),
+        vec![
+            (reference, 0.8),
+            (reference + Duration::days(90), 0.4),
+            (reference + Duration::days(365), 0.7),
+        ],
+        Interpolation::Linear,
+    )
+    .unwrap();
+    sim
+}
+
+/// A simulation deserialized from its JSON answers every query exactly as
+/// the original does.
+///
+/// Stage 1: Build the simulation and serialize it to JSON
+/// Stage 2: Deserialize it and serialize the copy again
+/// Stage 3: Query both at several timestamps and compare bit for bit
+#[test]
+fn serde_round_trip_same_state_at_result() {
+    // Stage 1: Build and serialize
+    let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let original = build_simulation(reference);
+    let json = serde_json::to_string(&original).unwrap();
+    assert_eq!(
+        json,
+        serde_json::to_string(&original).unwrap(),
+        "Serialization should be stable"
+    );
+
+    // Stage 2: Deserialize and re-serialize
+    let reloaded: Simulation = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        json,
+        serde_json::to_string(&reloaded).unwrap(),
+        "A reloaded simulation should serialize to the same JSON"
+    );
+    assert!(original.diff(&reloaded).is_empty());
+
+    // Stage 3: Compare every path at every query
+    let depression = StatePath::MentalHealth(MentalHealthPath::Depression);
+    for id in ["alice", "bob"] {
+        let id = EntityId::new(id).unwrap();
+        let before = original.entity(&id).unwrap();
+        let after = reloaded.entity(&id).unwrap();
+        assert!(
+            before
+                .state_at(reference + Duration::days(61))
+                .get_effective(depression)
+                > 0.0
+        );
+        for days in [0, 15, 61, 121, 200, 365, 730] {
+            let at = reference + Duration::days(days);
+            let expected = before.state_at(at);
+            let actual = after.state_at(at);
+            assert_eq!(expected.individual_state(), actual.individual_state());
+            for path in StatePath::all() {
+                assert_eq!(
+                    expected.get_effective(path).to_bits(),
+                    actual.get_effective(path).to_bits(),
+                    "{} differs for {} after {} days",
+                    path,
+                    id,
+                    days
+                );
+            }
+        }
+    }
+}
@@ -12,6 +12,8 @@ mod chronic_burden_belief_creates_perceived_burdensomeness;
 mod desire_requires_hopelessness_threshold_despite_high_tb_pb;
 mod healthcare_worker_dormant_capability_with_low_desire;
 mod high_risk_convergence_all_three_factors_present;
+mod months_of_exclusion_leave_chronic_loneliness;
 mod repeated_violence_exposure_builds_acquired_capability;
+mod same_failure_diverges_by_attributional_style;
 mod social_intervention_reduces_tb_but_not_ac;
 mod social_isolation_increases_thwarted_belongingness;
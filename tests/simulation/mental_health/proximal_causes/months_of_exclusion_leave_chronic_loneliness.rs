@@ -0,0 +1,125 @@
+//! Test: Months of exclusion leave loneliness that is largely chronic.
+//!
+//! Exclusion tagged as a chronic pattern adds to the chronic part of
+//! loneliness, which decays four times slower than the acute part. After
+//! one passing exclusion and three months of weekly chronic exclusion,
+//! two quiet weeks clear the acute loneliness but not the chronic: the
+//! breakdown shows the chronic part dominating and the interpreter says
+//! so. The same exclusions without the tag leave nothing chronic.
+
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{EventTag, EventType, Species};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::state::{DeltaBreakdown, StateInterpreter};
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+/// Returns the simulation after an acute exclusion and twelve weekly
+/// exclusions, tagged as a chronic pattern if `chronic`.
+fn excluded_for_months(anchor: Timestamp, chronic: bool) -> Simulation {
+    let person = EntityBuilder::new()
+        .id("person")
+        .species(Species::Human)
+        .age(Duration::years(30))
+        .build()
+        .unwrap();
+    let mut sim = Simulation::new(anchor);
+    let id = sim.add_entity(person, anchor);
+
+    let passing = EventBuilder::new(EventType::SocialExclusion)
+        .target(id.clone())
+        .severity(0.7)
+        .build()
+        .unwrap();
+    sim.add_event(passing, anchor + Duration::days(1));
+
+    for week in 1..=12 {
+        let mut builder = EventBuilder::new(EventType::SocialExclusion)
+            .target(id.clone())
+            .severity(0.6);
+        if chronic {
+            builder = builder.tag(EventTag::ChronicPattern);
+        }
+        sim.add_event(builder.build().unwrap(), anchor + Duration::weeks(week));
+    }
+    sim
+}
+
+/// Returns the loneliness breakdown and the interpreter summary at `at`.
+fn loneliness_at(sim: &Simulation, at: Timestamp) -> (DeltaBreakdown, String) {
+    let handle = sim.entity(&EntityId::new("person").unwrap()).unwrap();
+    let state = handle.state_at(at);
+    let individual = state.individual_state();
+    (
+        individual.social_cognition().loneliness_breakdown(),
+        StateInterpreter::from_state(individual)
+            .summary()
+            .to_string(),
+    )
+}
+
+/// Chronic exclusion leaves loneliness that outlasts the acute part.
+#[test]
+fn months_of_exclusion_leave_chronic_loneliness() {
+    // ========================================================================
+    // SETUP
+    // What we're doing: Two people, each excluded once in passing and then
+    // weekly for three months; only the first person's weekly exclusion is
+    // tagged as a chronic pattern.
+    // ========================================================================
+
+    let anchor = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let last_exclusion = anchor + Duration::weeks(12);
+    let two_weeks_later = last_exclusion + Duration::weeks(2);
+    let chronic = excluded_for_months(anchor, true);
+    let acute = excluded_for_months(anchor, false);
+
+    // ========================================================================
+    // STAGE 1: Right after the passing exclusion
+    // What we're testing: Before any chronic exclusion, loneliness is all
+    // acute.
+    // ========================================================================
+
+    let (early, early_summary) = loneliness_at(&chronic, anchor + Duration::days(1));
+    assert!(early.acute > 0.0);
+    assert!(early.chronic.abs() < f32::EPSILON);
+    assert!(!early_summary.contains("largely chronic"));
+
+    // ========================================================================
+    // STAGE 2: Two weeks after the last exclusion
+    // What we're testing: The acute part has decayed away and the chronic
+    // part dominates, which the interpreter summary reports.
+    // ========================================================================
+
+    let (at_last, _) = loneliness_at(&chronic, last_exclusion);
+    let (settled, summary) = loneliness_at(&chronic, two_weeks_later);
+    assert!(at_last.chronic > settled.chronic);
+    assert!(
+        settled.chronic > 0.01,
+        "chronic loneliness {}",
+        settled.chronic
+    );
+    assert!(settled.chronic > settled.acute * 10.0);
+    assert!(
+        settled.chronicity_ratio() > 0.9,
+        "chronicity {}",
+        settled.chronicity_ratio()
+    );
+    assert!(
+        summary.contains("Loneliness is largely chronic."),
+        "{}",
+        summary
+    );
+
+    // ========================================================================
+    // STAGE 3: The same exclusions, untagged
+    // What we're testing: Without the chronic tag, nothing chronic builds
+    // up and the summary says nothing about chronicity.
+    // ========================================================================
+
+    let (passing, acute_summary) = loneliness_at(&acute, two_weeks_later);
+    assert!(passing.chronic.abs() < f32::EPSILON);
+    assert!(passing.total() < settled.total());
+    assert!(passing.chronicity_ratio().abs() < f32::EPSILON);
+    assert!(!acute_summary.contains("largely chronic"));
+}
@@ -0,0 +1,101 @@
+//! Test: The same failure diverges by attributional style.
+//!
+//! How a person explains a setback depends on who they are. Someone
+//! neurotic with low self-worth blames themselves and expects it to last,
+//! which feeds self-hate and interpersonal hopelessness. Someone calm and
+//! secure puts the same failure down to passing circumstance and takes
+//! none of that from it.
+
+use behavioral_pathways::entity::{Entity, EntityBuilder};
+use behavioral_pathways::enums::{
+    EventType, MentalHealthPath, SocialCognitionPath, Species, StatePath,
+};
+use behavioral_pathways::event::{Event, EventBuilder};
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::state::Hexaco;
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+const SELF_HATE: StatePath = StatePath::SocialCognition(SocialCognitionPath::SelfHate);
+const HOPELESSNESS: StatePath =
+    StatePath::MentalHealth(MentalHealthPath::InterpersonalHopelessness);
+
+/// Builds a person with the given neuroticism and self-worth.
+fn person(id: &str, neuroticism: f32, self_worth: f32) -> Entity {
+    let mut entity = EntityBuilder::new()
+        .id(id)
+        .species(Species::Human)
+        .age(Duration::years(30))
+        .hexaco(Hexaco::new().with_neuroticism(neuroticism))
+        .build()
+        .unwrap();
+    entity
+        .individual_state_mut()
+        .mental_health_mut()
+        .self_worth_mut()
+        .set_base(self_worth);
+    entity
+}
+
+fn failure(target: &EntityId) -> Event {
+    EventBuilder::new(EventType::Failure)
+        .target(target.clone())
+        .severity(0.6)
+        .build()
+        .unwrap()
+}
+
+/// Opposite personalities attribute an identical failure differently.
+#[test]
+fn same_failure_diverges_by_attributional_style() {
+    // ========================================================================
+    // SETUP
+    // What we're doing: A self-critical person (high neuroticism, low
+    // self-worth) and a secure one (low neuroticism, high self-worth) in
+    // one simulation.
+    // ========================================================================
+
+    let critical_person = person("critical", 0.8, 0.2);
+    let secure_person = person("secure", -0.8, 0.9);
+
+    // ========================================================================
+    // STAGE 1: Interpreting the failure
+    // What we're testing: The self-critical person blames themselves and
+    // sees the cause as lasting; the secure person blames circumstance.
+    // Only the self-critical interpretation adds self-hate and
+    // hopelessness.
+    // ========================================================================
+
+    let critical_view = critical_person.interpret_event(&failure(critical_person.id()));
+    let secure_view = secure_person.interpret_event(&failure(secure_person.id()));
+
+    assert!(critical_view.attribution.is_self_caused());
+    assert!(critical_view.attribution.is_stable());
+    assert!(secure_view.attribution.is_situational());
+    assert!(!secure_view.attribution.is_stable());
+    assert!(critical_view.self_hate_delta > secure_view.self_hate_delta);
+    assert!(critical_view.interpersonal_hopelessness_delta > 0.0);
+    assert!(secure_view.interpersonal_hopelessness_delta.abs() < f32::EPSILON);
+
+    // ========================================================================
+    // STAGE 2: The day after
+    // What we're testing: With the failure added to a simulation for both,
+    // the self-critical person's self-hate and hopelessness rise further
+    // above where they started than the secure person's.
+    // ========================================================================
+
+    let anchor = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let at = anchor + Duration::days(1);
+    let mut sim = Simulation::new(anchor);
+    let critical = sim.add_entity(critical_person, anchor);
+    let secure = sim.add_entity(secure_person, anchor);
+    sim.add_event(failure(&critical), at);
+    sim.add_event(failure(&secure), at);
+
+    let rise = |id: &EntityId, path: StatePath| {
+        let handle = sim.entity(id).unwrap();
+        handle.state_at(at).get_effective(path) - handle.state_at(anchor).get_effective(path)
+    };
+    assert!(rise(&critical, SELF_HATE) > rise(&secure, SELF_HATE));
+    assert!(rise(&critical, HOPELESSNESS) > 0.0);
+    assert!(rise(&critical, HOPELESSNESS) > rise(&secure, HOPELESSNESS));
+}
@@ -0,0 +1,136 @@
+//! Test: Losing a partner thwarts belonging far more than losing a colleague.
+//!
+//! Maya loses Sam in two simulations that differ only in who Sam was to
+//! her: an intimate romantic partner, or a colleague she knew in passing.
+//! The Loss events are identical, yet losing the partner leaves her far
+//! lonelier, and the relationship reads as dissolved from the loss on while
+//! queries before it still see it intact.
+
+use behavioral_pathways::entity::{Entity, EntityBuilder};
+use behavioral_pathways::enums::{
+    BondType, EventPayload, EventType, LossType, MentalHealthPath, RelationshipSchema, Species,
+    StatePath,
+};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::relationship::RelationshipStage;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+fn build_person(id: &str) -> Entity {
+    EntityBuilder::new()
+        .id(id)
+        .species(Species::Human)
+        .birth_date(Timestamp::from_ymd_hms(1985, 3, 12, 0, 0, 0))
+        .build()
+        .unwrap()
+}
+
+/// Builds Maya's simulation with Sam bonded to her as `bond` at `stage`,
+/// and Maya losing Sam a month in.
+fn build_bereavement(reference: Timestamp, bond: BondType, stage: RelationshipStage) -> Simulation {
+    let mut sim = Simulation::new(reference);
+    let maya = sim.add_entity(build_person("maya"), reference);
+    let sam = sim.add_entity(build_person("sam"), reference);
+    let rel_id = sim.add_relationship(
+        maya.clone(),
+        sam.clone(),
+        RelationshipSchema::Peer,
+        reference,
+    );
+    let relationship = sim
+        .get_relationship_mut(&rel_id)
+        .unwrap()
+        .relationship_mut();
+    relationship.add_bond(bond);
+    relationship.set_stage(stage).unwrap();
+
+    let death = EventBuilder::new(EventType::Loss)
+        .target(maya)
+        .severity(0.8)
+        .payload(EventPayload::Loss {
+            loss_type: LossType::Person,
+            lost_entity: Some(sam),
+        })
+        .build()
+        .unwrap();
+    sim.add_event(death, reference + Duration::days(30));
+    sim
+}
+
+/// An identical loss thwarts belonging far more for an intimate partner.
+#[test]
+fn losing_a_partner_thwarts_belonging_more_than_losing_a_colleague() {
+    // ========================================================================
+    // SETUP
+    // What we're doing: In one simulation Sam is Maya's intimate romantic
+    // partner; in the other, a colleague she is acquainted with. On day 30
+    // Maya loses Sam, through the same Loss event in both.
+    // ========================================================================
+
+    let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let maya = EntityId::new("maya").unwrap();
+    let sam = EntityId::new("sam").unwrap();
+    let loss_at = reference + Duration::days(30);
+
+    let partner_sim = build_bereavement(reference, BondType::Romantic, RelationshipStage::Intimate);
+    let colleague_sim = build_bereavement(
+        reference,
+        BondType::Colleague,
+        RelationshipStage::Acquaintance,
+    );
+
+    // ========================================================================
+    // STAGE 1: Thwarted belongingness before and after the loss
+    // What we're testing: Before the loss Maya feels the same in both
+    // simulations. After it, losing the partner thwarts belonging far more,
+    // and stays above the colleague's loss for weeks as both decay.
+    // ========================================================================
+
+    let tb = StatePath::MentalHealth(MentalHealthPath::ThwartedBelongingness);
+    let trajectory = |sim: &Simulation| -> Vec<f64> {
+        let handle = sim.entity(&maya).unwrap();
+        [29, 31, 35, 40, 45]
+            .into_iter()
+            .map(|day| {
+                handle
+                    .state_at(reference + Duration::days(day))
+                    .get_effective(tb)
+            })
+            .collect()
+    };
+    let partner = trajectory(&partner_sim);
+    let colleague = trajectory(&colleague_sim);
+
+    assert!(
+        (partner[0] - colleague[0]).abs() < 1e-9,
+        "Before the loss TB should match. Partner: {}, Colleague: {}",
+        partner[0],
+        colleague[0]
+    );
+    assert!(
+        partner[1] > colleague[1] + 0.05,
+        "Losing a partner should thwart belonging more. Partner: {}, Colleague: {}",
+        partner[1],
+        colleague[1]
+    );
+    assert!(partner[1] > partner[0]);
+    for (partner, colleague) in partner.iter().zip(&colleague).skip(2) {
+        assert!(partner > colleague);
+    }
+
+    // ========================================================================
+    // STAGE 2: The relationship before and after the loss
+    // What we're testing: Relationship queries report the relationship
+    // dissolved from the loss on, and intact before it.
+    // ========================================================================
+
+    for sim in [&partner_sim, &colleague_sim] {
+        let handle = sim.relationship(&maya, &sam).unwrap();
+        let before = handle.state_at(loss_at - Duration::days(1));
+        let after = handle.state_at(loss_at + Duration::days(1));
+
+        assert!(!before.is_dissolved());
+        assert!(after.is_dissolved());
+        assert_eq!(after.relationship.dissolved_at(), Some(loss_at));
+    }
+}
@@ -0,0 +1,6 @@
+//! Bereavement tests.
+//!
+//! Tests for losses that end a relationship, weighed by how close the
+//! bereaved was to the person lost.
+
+mod losing_a_partner_thwarts_belonging_more_than_losing_a_colleague;
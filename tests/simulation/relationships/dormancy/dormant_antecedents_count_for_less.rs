@@ -0,0 +1,75 @@
+//! Test: Trust evidence from before a long silence counts for less.
+//!
+//! Bob helps Alice three times in their first month and then the two stop
+//! talking. Two months in, the help is recent and lifts Alice's view of
+//! Bob's benevolence. Two years in, the relationship has been dormant for
+//! well past the threshold and the old help has lost most of its weight.
+
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{EventType, RelationshipSchema, Species, TrustDomain};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+/// Antecedents in a dormant relationship weigh less in trust decisions.
+#[test]
+fn dormant_antecedents_count_for_less() {
+    // ========================================================================
+    // SETUP
+    // What we're doing: Alice and Bob are peers. Bob supports Alice on days
+    // 10, 20, and 30, with no contact afterwards.
+    // ========================================================================
+
+    let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let mut sim = Simulation::new(reference);
+    for id in ["alice", "bob"] {
+        let entity = EntityBuilder::new()
+            .id(id)
+            .species(Species::Human)
+            .build()
+            .unwrap();
+        sim.add_entity(entity, reference);
+    }
+    let alice = EntityId::new("alice").unwrap();
+    let bob = EntityId::new("bob").unwrap();
+    sim.add_relationship(
+        alice.clone(),
+        bob.clone(),
+        RelationshipSchema::Peer,
+        reference,
+    );
+    for day in [10, 20, 30] {
+        let help = EventBuilder::new(EventType::Support)
+            .source(bob.clone())
+            .target(alice.clone())
+            .severity(0.6)
+            .build()
+            .unwrap();
+        sim.add_event(help, reference + Duration::days(day));
+    }
+
+    // ========================================================================
+    // STAGE 1: Benevolence while the help is fresh and after two years apart
+    // What we're testing: The lift over the declared benevolence shrinks
+    // once the relationship has gone dormant.
+    // ========================================================================
+
+    let benevolence = |at: Timestamp| {
+        sim.predict_interaction(&alice, &bob, TrustDomain::Support, at)
+            .unwrap()
+            .explanation()
+            .unwrap()
+            .benevolence
+    };
+    let declared = benevolence(reference);
+    let fresh_lift = benevolence(reference + Duration::days(60)) - declared;
+    let dormant_lift = benevolence(reference + Duration::years(2)) - declared;
+
+    assert!(fresh_lift > 0.0, "Recent help should raise benevolence");
+    assert!(
+        dormant_lift < fresh_lift * 0.5,
+        "Old help should count for less after dormancy. Fresh: {}, Dormant: {}",
+        fresh_lift,
+        dormant_lift
+    );
+}
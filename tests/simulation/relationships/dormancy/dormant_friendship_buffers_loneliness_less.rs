@@ -0,0 +1,123 @@
+//! Test: A dormant friendship buffers loneliness less than an active one.
+//!
+//! Two people declare a close friendship. In one simulation they keep in
+//! touch monthly; in the other they never interact again. Two years on, the
+//! dormant friendship has drifted to a lower stage and contributes less to
+//! relationship quality, so loneliness is higher.
+
+use behavioral_pathways::context::{
+    EcologicalContext, InteractionProfile, Microsystem, SocialContext,
+};
+use behavioral_pathways::entity::{Entity, EntityBuilder};
+use behavioral_pathways::enums::{
+    EventType, RelationshipSchema, SocialCognitionPath, Species, StatePath,
+};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::relationship::RelationshipStage;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EntityId, MicrosystemId, RelationshipId, Timestamp};
+
+fn build_person(id: &str) -> Entity {
+    let mut context = EcologicalContext::default();
+    let social = SocialContext {
+        warmth: 0.7,
+        interaction_profile: InteractionProfile::with_values(0.5, 0.5),
+        ..Default::default()
+    };
+    context.add_microsystem(
+        MicrosystemId::new("neighborhood_club").unwrap(),
+        Microsystem::new_social(social),
+    );
+
+    EntityBuilder::new()
+        .id(id)
+        .species(Species::Human)
+        .birth_date(Timestamp::from_ymd_hms(1990, 5, 1, 0, 0, 0))
+        .with_context(context)
+        .build()
+        .unwrap()
+}
+
+fn build_friendship(reference: Timestamp) -> (Simulation, RelationshipId) {
+    let mut sim = Simulation::new(reference);
+    let maya = sim.add_entity(build_person("maya"), reference);
+    let jo = sim.add_entity(build_person("jo"), reference);
+    let rel_id = sim.add_relationship(maya, jo, RelationshipSchema::Peer, reference);
+    sim.get_relationship_mut(&rel_id)
+        .unwrap()
+        .relationship_mut()
+        .set_stage(RelationshipStage::Intimate)
+        .unwrap();
+    (sim, rel_id)
+}
+
+/// A friendship without contact for two years buffers loneliness less.
+#[test]
+fn dormant_friendship_buffers_loneliness_less() {
+    // ========================================================================
+    // SETUP
+    // What we're doing: Maya and Jo are close friends. In the active
+    // simulation Jo calls Maya every month; in the dormant one they never
+    // speak again after the friendship is declared.
+    // ========================================================================
+
+    let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let maya = EntityId::new("maya").unwrap();
+    let jo = EntityId::new("jo").unwrap();
+
+    let (mut active_sim, active_rel) = build_friendship(reference);
+    for month in 1..=24 {
+        let call = EventBuilder::new(EventType::Interaction)
+            .source(jo.clone())
+            .target(maya.clone())
+            .severity(0.2)
+            .build()
+            .unwrap();
+        active_sim.add_event(call, reference + Duration::days(30 * month));
+    }
+
+    let (dormant_sim, dormant_rel) = build_friendship(reference);
+    let horizon = reference + Duration::years(2);
+
+    // ========================================================================
+    // STAGE 1: Relationship activity at two years
+    // What we're testing: The dormant friendship has drifted and regressed
+    // from Intimate to Established; the active one has not.
+    // ========================================================================
+
+    let active = active_sim
+        .relationship_activity_at(&active_rel, horizon)
+        .unwrap();
+    let dormant = dormant_sim
+        .relationship_activity_at(&dormant_rel, horizon)
+        .unwrap();
+
+    assert_eq!(active.stage, RelationshipStage::Intimate);
+    assert_eq!(dormant.stage, RelationshipStage::Established);
+    assert!(dormant.closeness < active.closeness - 0.5);
+    assert!(dormant.antecedent_freshness < active.antecedent_freshness);
+
+    // ========================================================================
+    // STAGE 2: Loneliness at two years
+    // What we're testing: The dormant friendship buffers loneliness less.
+    // ========================================================================
+
+    let loneliness = StatePath::SocialCognition(SocialCognitionPath::Loneliness);
+    let active_loneliness = active_sim
+        .entity(&maya)
+        .unwrap()
+        .state_at(horizon)
+        .get_effective(loneliness);
+    let dormant_loneliness = dormant_sim
+        .entity(&maya)
+        .unwrap()
+        .state_at(horizon)
+        .get_effective(loneliness);
+
+    assert!(
+        dormant_loneliness > active_loneliness + 0.05,
+        "Dormant friendship should buffer loneliness less. Dormant: {}, Active: {}",
+        dormant_loneliness,
+        active_loneliness
+    );
+}
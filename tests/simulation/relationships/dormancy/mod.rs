@@ -0,0 +1,8 @@
+//! Relationship dormancy tests.
+//!
+//! Tests for close relationships drifting apart without contact and
+//! recovering after a reconnection.
+
+mod dormant_antecedents_count_for_less;
+mod dormant_friendship_buffers_loneliness_less;
+mod reconnection_restores_loneliness_buffering;
@@ -0,0 +1,135 @@
+//! Test: One reconnection restores most of a dormant friendship's buffering.
+//!
+//! A close friendship goes two years without contact and drifts apart. A
+//! single reconnection shortly before the query restores most of the lost
+//! closeness, because rebuilding a relationship is faster than forming it,
+//! so loneliness falls most of the way back to the always-in-touch level.
+
+use behavioral_pathways::context::{
+    EcologicalContext, InteractionProfile, Microsystem, SocialContext,
+};
+use behavioral_pathways::entity::{Entity, EntityBuilder};
+use behavioral_pathways::enums::{
+    EventType, RelationshipSchema, SocialCognitionPath, Species, StatePath,
+};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::relationship::RelationshipStage;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EntityId, MicrosystemId, RelationshipId, Timestamp};
+
+fn build_person(id: &str) -> Entity {
+    let mut context = EcologicalContext::default();
+    let social = SocialContext {
+        warmth: 0.7,
+        interaction_profile: InteractionProfile::with_values(0.5, 0.5),
+        ..Default::default()
+    };
+    context.add_microsystem(
+        MicrosystemId::new("neighborhood_club").unwrap(),
+        Microsystem::new_social(social),
+    );
+
+    EntityBuilder::new()
+        .id(id)
+        .species(Species::Human)
+        .birth_date(Timestamp::from_ymd_hms(1990, 5, 1, 0, 0, 0))
+        .with_context(context)
+        .build()
+        .unwrap()
+}
+
+fn build_friendship(reference: Timestamp) -> (Simulation, RelationshipId) {
+    let mut sim = Simulation::new(reference);
+    let maya = sim.add_entity(build_person("maya"), reference);
+    let jo = sim.add_entity(build_person("jo"), reference);
+    let rel_id = sim.add_relationship(maya, jo, RelationshipSchema::Peer, reference);
+    sim.get_relationship_mut(&rel_id)
+        .unwrap()
+        .relationship_mut()
+        .set_stage(RelationshipStage::Intimate)
+        .unwrap();
+    (sim, rel_id)
+}
+
+fn call(from: &EntityId, to: &EntityId) -> behavioral_pathways::event::Event {
+    EventBuilder::new(EventType::Interaction)
+        .source(from.clone())
+        .target(to.clone())
+        .severity(0.2)
+        .build()
+        .unwrap()
+}
+
+/// A single reconnection recovers most of the loneliness buffering.
+#[test]
+fn reconnection_restores_loneliness_buffering() {
+    // ========================================================================
+    // SETUP
+    // What we're doing: Three versions of Maya and Jo's friendship: one
+    // with monthly calls, one with no contact at all, and one with no
+    // contact until a single call two weeks before the two-year mark.
+    // ========================================================================
+
+    let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let maya = EntityId::new("maya").unwrap();
+    let jo = EntityId::new("jo").unwrap();
+    let horizon = reference + Duration::years(2);
+
+    let (mut active_sim, _) = build_friendship(reference);
+    for month in 1..=24 {
+        active_sim.add_event(call(&jo, &maya), reference + Duration::days(30 * month));
+    }
+
+    let (dormant_sim, dormant_rel) = build_friendship(reference);
+
+    let (mut reconnected_sim, reconnected_rel) = build_friendship(reference);
+    reconnected_sim.add_event(call(&jo, &maya), horizon - Duration::days(14));
+
+    // ========================================================================
+    // STAGE 1: Relationship activity after reconnecting
+    // What we're testing: One call restores the Intimate stage and most
+    // of the lost closeness.
+    // ========================================================================
+
+    let dormant = dormant_sim
+        .relationship_activity_at(&dormant_rel, horizon)
+        .unwrap();
+    let reconnected = reconnected_sim
+        .relationship_activity_at(&reconnected_rel, horizon)
+        .unwrap();
+
+    assert_eq!(reconnected.stage, RelationshipStage::Intimate);
+    assert!(
+        reconnected.closeness > 0.75,
+        "Reconnection should restore most closeness, got {}",
+        reconnected.closeness
+    );
+    assert!(reconnected.closeness > dormant.closeness);
+
+    // ========================================================================
+    // STAGE 2: Loneliness after reconnecting
+    // What we're testing: Most of the gap between the dormant and active
+    // friendship's loneliness is closed.
+    // ========================================================================
+
+    let loneliness = StatePath::SocialCognition(SocialCognitionPath::Loneliness);
+    let at_horizon = |sim: &Simulation| {
+        sim.entity(&maya)
+            .unwrap()
+            .state_at(horizon)
+            .get_effective(loneliness)
+    };
+    let active_loneliness = at_horizon(&active_sim);
+    let dormant_loneliness = at_horizon(&dormant_sim);
+    let reconnected_loneliness = at_horizon(&reconnected_sim);
+
+    let gap = dormant_loneliness - active_loneliness;
+    let recovered = dormant_loneliness - reconnected_loneliness;
+    assert!(
+        recovered > gap * 0.6,
+        "Reconnection should recover most buffering. Dormant: {}, Reconnected: {}, Active: {}",
+        dormant_loneliness,
+        reconnected_loneliness,
+        active_loneliness
+    );
+}
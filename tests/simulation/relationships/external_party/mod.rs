@@ -0,0 +1,5 @@
+//! External relationship partner tests.
+//!
+//! Tests for relationships with people who are declared but not simulated.
+
+mod pen_pal_counts_toward_relationship_quality;
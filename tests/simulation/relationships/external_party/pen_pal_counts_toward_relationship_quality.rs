@@ -0,0 +1,141 @@
+//! Test: A declared external pen pal counts toward relationship quality.
+//!
+//! Maya writes to a pen pal abroad who is not worth simulating. The pen pal
+//! is declared external, so the relationship is not flagged as dangling,
+//! their letters interpret like any other contact, and the relationship
+//! buffers loneliness at the simulation's external relationship weight.
+
+use behavioral_pathways::context::{
+    EcologicalContext, InteractionProfile, Microsystem, SocialContext,
+};
+use behavioral_pathways::entity::{Entity, EntityBuilder};
+use behavioral_pathways::enums::{
+    EventType, RelationshipSchema, SocialCognitionPath, Species, StatePath,
+};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::relationship::RelationshipStage;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EntityId, MicrosystemId, Timestamp};
+
+fn build_person(id: &str) -> Entity {
+    let mut context = EcologicalContext::default();
+    let social = SocialContext {
+        warmth: 0.7,
+        interaction_profile: InteractionProfile::with_values(0.5, 0.5),
+        ..Default::default()
+    };
+    context.add_microsystem(
+        MicrosystemId::new("neighborhood_club").unwrap(),
+        Microsystem::new_social(social),
+    );
+
+    EntityBuilder::new()
+        .id(id)
+        .species(Species::Human)
+        .birth_date(Timestamp::from_ymd_hms(1990, 5, 1, 0, 0, 0))
+        .with_context(context)
+        .build()
+        .unwrap()
+}
+
+/// Maya with a close pen pal who writes monthly for a year. The pen pal is
+/// external unless `simulate_pen_pal` adds them as an entity, and the
+/// relationship is omitted when `befriend` is false.
+fn build_sim(reference: Timestamp, befriend: bool, simulate_pen_pal: bool) -> Simulation {
+    let mut sim = Simulation::new(reference);
+    let maya = sim.add_entity(build_person("maya"), reference);
+    let pen_pal = EntityId::new("pen_pal").unwrap();
+    if simulate_pen_pal {
+        sim.add_entity(build_person("pen_pal"), reference);
+    } else {
+        sim.declare_external_entity(pen_pal.clone());
+    }
+
+    if befriend {
+        let peer = RelationshipSchema::Peer;
+        let rel_id = sim.add_relationship(maya.clone(), pen_pal.clone(), peer, reference);
+        sim.get_relationship_mut(&rel_id)
+            .unwrap()
+            .relationship_mut()
+            .set_stage(RelationshipStage::Intimate)
+            .unwrap();
+    }
+
+    for month in 1..=12 {
+        let letter = EventBuilder::new(EventType::Interaction)
+            .source(pen_pal.clone())
+            .target(maya.clone())
+            .severity(0.2)
+            .build()
+            .unwrap();
+        sim.add_event(letter, reference + Duration::days(30 * month));
+    }
+    sim
+}
+
+fn loneliness_at(sim: &Simulation, at: Timestamp) -> f64 {
+    let maya = EntityId::new("maya").unwrap();
+    sim.entity(&maya)
+        .unwrap()
+        .state_at(at)
+        .get_effective(StatePath::SocialCognition(SocialCognitionPath::Loneliness))
+}
+
+/// An external pen pal buffers loneliness at the configured weight.
+#[test]
+fn pen_pal_counts_toward_relationship_quality() {
+    // ========================================================================
+    // SETUP
+    // What we're doing: Maya receives monthly letters from a pen pal for a
+    // year. We compare no friendship, an external friendship at the default
+    // weight, an external friendship at full weight, and a simulated pen pal.
+    // ========================================================================
+
+    let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let horizon = reference + Duration::days(365);
+
+    let stranger = build_sim(reference, false, false);
+    let external = build_sim(reference, true, false);
+    let mut full_weight = build_sim(reference, true, false);
+    full_weight.set_external_relationship_weight(1.0);
+    let simulated = build_sim(reference, true, true);
+
+    // ========================================================================
+    // STAGE 1: Validation
+    // What we're testing: The declared pen pal is not a dangling partner.
+    // ========================================================================
+
+    assert!(external.validate().is_empty());
+
+    // ========================================================================
+    // STAGE 2: Relationship quality
+    // What we're testing: The external friendship buffers loneliness, but
+    // less than at full weight.
+    // ========================================================================
+
+    let stranger_loneliness = loneliness_at(&stranger, horizon);
+    let external_loneliness = loneliness_at(&external, horizon);
+    let full_loneliness = loneliness_at(&full_weight, horizon);
+
+    assert!(
+        external_loneliness < stranger_loneliness,
+        "External friendship should buffer loneliness. External: {}, None: {}",
+        external_loneliness,
+        stranger_loneliness
+    );
+    assert!(
+        full_loneliness < external_loneliness,
+        "Full weight should buffer more. Full: {}, Default: {}",
+        full_loneliness,
+        external_loneliness
+    );
+
+    // ========================================================================
+    // STAGE 3: Letters interpret normally
+    // What we're testing: At full weight, an external pen pal affects Maya
+    // exactly as a simulated one does.
+    // ========================================================================
+
+    let simulated_loneliness = loneliness_at(&simulated, horizon);
+    assert!((full_loneliness - simulated_loneliness).abs() < 1e-12);
+}
@@ -0,0 +1,108 @@
+//! Test: A heartfelt conversation reaches both parties coherently.
+//!
+//! Alice and Bob have a long, heartfelt conversation on March 3rd. Recorded
+//! with one call, it eases both friends' loneliness, refreshes their
+//! relationship's last contact once, and leaves each of them a memory of
+//! the other that links back to the event they experienced.
+
+use behavioral_pathways::entity::{Entity, EntityBuilder};
+use behavioral_pathways::enums::{
+    InteractionTopic, RelationshipSchema, SocialCognitionPath, Species, StatePath,
+};
+use behavioral_pathways::memory::MemoryTag;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+fn build_person(id: &str) -> Entity {
+    EntityBuilder::new()
+        .id(id)
+        .species(Species::Human)
+        .birth_date(Timestamp::from_ymd_hms(1992, 8, 1, 0, 0, 0))
+        .build()
+        .unwrap()
+}
+
+/// One call records the conversation for both friends.
+#[test]
+fn heartfelt_conversation_reaches_both_parties() {
+    // ========================================================================
+    // SETUP
+    // What we're doing: Two friends with memory formation enabled, and a
+    // 90-minute deep conversation on March 3rd.
+    // ========================================================================
+
+    let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let conversation = Timestamp::from_ymd_hms(2024, 3, 3, 19, 0, 0);
+    let evening = conversation + Duration::hours(3);
+    let loneliness = StatePath::SocialCognition(SocialCognitionPath::Loneliness);
+
+    let mut sim = Simulation::new(reference);
+    sim.set_memory_formation(true);
+    let alice = sim.add_entity(build_person("alice"), reference);
+    let bob = sim.add_entity(build_person("bob"), reference);
+    let rel_id = sim.add_relationship(
+        alice.clone(),
+        bob.clone(),
+        RelationshipSchema::Peer,
+        reference,
+    );
+    let evening_loneliness = |sim: &Simulation, id: &EntityId| {
+        sim.entity(id)
+            .unwrap()
+            .state_at(evening)
+            .get_effective(loneliness)
+    };
+    let alice_before = evening_loneliness(&sim, &alice);
+    let bob_before = evening_loneliness(&sim, &bob);
+
+    // ========================================================================
+    // STAGE 1: Record the conversation
+    // What we're testing: Each friend gets one event from the other, and the
+    // relationship's last contact is the conversation.
+    // ========================================================================
+
+    let recorded = sim.record_interaction(
+        &alice,
+        &bob,
+        conversation,
+        InteractionTopic::DeepConversation,
+        90,
+        0.8,
+    );
+    assert_eq!(sim.events_for(&alice).len(), 1);
+    assert_eq!(sim.events_for(&bob).len(), 1);
+    assert_eq!(sim.events_for(&alice)[0].event().source(), Some(&bob));
+    let pattern = sim
+        .get_relationship(&rel_id)
+        .unwrap()
+        .relationship()
+        .pattern()
+        .clone();
+    assert_eq!(pattern.last_interaction, Some(conversation));
+
+    // ========================================================================
+    // STAGE 2: Both feel it
+    // What we're testing: That evening, both are less lonely than they
+    // would have been.
+    // ========================================================================
+
+    assert!(evening_loneliness(&sim, &alice) < alice_before);
+    assert!(evening_loneliness(&sim, &bob) < bob_before);
+
+    // ========================================================================
+    // STAGE 3: Both remember it
+    // What we're testing: Each memory names the other friend and links the
+    // event its holder experienced.
+    // ========================================================================
+
+    assert_eq!(recorded.memories().len(), 2);
+    for (index, (holder, other)) in [(&alice, &bob), (&bob, &alice)].into_iter().enumerate() {
+        let (memory_holder, memory_id) = &recorded.memories()[index];
+        assert_eq!(memory_holder, holder);
+        let memories = sim.get_anchored_entity(holder).unwrap().entity().memories();
+        let memory = memories.get_by_id(memory_id).unwrap();
+        assert!(memory.involves_participant(other));
+        assert!(memory.has_tag(MemoryTag::Personal));
+        assert_eq!(memory.event_id(), Some(&recorded.event_ids()[index]));
+    }
+}
@@ -0,0 +1,3 @@
+//! Tests for recording two-way interactions with `record_interaction`.
+
+mod heartfelt_conversation_reaches_both_parties;
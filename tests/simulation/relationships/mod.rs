@@ -0,0 +1,14 @@
+//! Relationship system simulation tests.
+//!
+//! Tests for relationship formation, dynamics, and influence on state.
+
+#[cfg(feature = "relationships")]
+mod bereavement;
+#[cfg(all(feature = "context", feature = "relationships"))]
+mod dormancy;
+#[cfg(all(feature = "context", feature = "relationships"))]
+mod external_party;
+#[cfg(feature = "memory")]
+mod interaction;
+#[cfg(feature = "relationships")]
+mod trust_calibration;
@@ -0,0 +1,161 @@
+//! Test: Calibration scores a scripted history of support and betrayal.
+//!
+//! Nora has two close friends. Iris supports her every week for three
+//! months. Theo supports her for two months, then betrays her four times.
+//! Calibration replays the trust model's prediction before each event.
+//! Nora's willingness to lean on Iris grows with every visit, and her trust
+//! in Theo collapses after his first betrayal, so the betrayals are
+//! predicted and Theo's dyad scores better than the under-predicted support
+//! from Iris.
+
+use behavioral_pathways::entity::{Entity, EntityBuilder};
+use behavioral_pathways::enums::{EventType, RelationshipSchema, Species, TrustDomain};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::relationship::RelationshipStage;
+use behavioral_pathways::simulation::{Simulation, TrustOutcome};
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+fn build_person(id: &str) -> Entity {
+    EntityBuilder::new()
+        .id(id)
+        .species(Species::Human)
+        .birth_date(Timestamp::from_ymd_hms(1992, 3, 1, 0, 0, 0))
+        .build()
+        .unwrap()
+}
+
+/// Adds one event a week from `from` to `to`, starting at `first`.
+fn weekly(
+    sim: &mut Simulation,
+    event_type: EventType,
+    (from, to): (&EntityId, &EntityId),
+    first: Timestamp,
+    weeks: u64,
+) {
+    for week in 0..weeks {
+        let event = EventBuilder::new(event_type)
+            .source(from.clone())
+            .target(to.clone())
+            .severity(0.7)
+            .build()
+            .unwrap();
+        sim.add_event(event, first + Duration::weeks(week));
+    }
+}
+
+#[test]
+fn calibration_tracks_support_and_betrayal() {
+    // ========================================================================
+    // SETUP
+    // ========================================================================
+    let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let mut sim = Simulation::new(reference);
+    let nora = sim.add_entity(build_person("nora"), reference);
+    let iris = sim.add_entity(build_person("iris"), reference);
+    let theo = sim.add_entity(build_person("theo"), reference);
+    for friend in [&iris, &theo] {
+        let peer = RelationshipSchema::Peer;
+        let rel_id = sim.add_relationship(nora.clone(), friend.clone(), peer, reference);
+        sim.get_relationship_mut(&rel_id)
+            .unwrap()
+            .relationship_mut()
+            .set_stage(RelationshipStage::Intimate)
+            .unwrap();
+    }
+
+    let start = reference + Duration::days(1);
+    weekly(&mut sim, EventType::Support, (&iris, &nora), start, 12);
+    weekly(&mut sim, EventType::Support, (&theo, &nora), start, 8);
+    let betrayals_start = start + Duration::weeks(8);
+    weekly(
+        &mut sim,
+        EventType::Betrayal,
+        (&theo, &nora),
+        betrayals_start,
+        4,
+    );
+    let end = start + Duration::weeks(12);
+
+    // ========================================================================
+    // STAGE 1: One record per scored event
+    // What we're testing: Every support and betrayal between the friends is
+    // scored, and a pair filter keeps one dyad in either order.
+    // ========================================================================
+
+    let everyone = sim.trust_calibration(None, reference, end);
+    assert_eq!(everyone.records().len(), 24);
+    let iris_report = sim.trust_calibration(Some((nora.clone(), iris.clone())), reference, end);
+    let theo_report = sim.trust_calibration(Some((theo.clone(), nora.clone())), reference, end);
+    assert_eq!(iris_report.records().len(), 12);
+    assert_eq!(theo_report.records().len(), 12);
+
+    // ========================================================================
+    // STAGE 2: Predictions are replayed from just before each event
+    // What we're testing: Each record holds the willingness
+    // predict_interaction gives one second before its event.
+    // ========================================================================
+
+    for record in everyone.records() {
+        let domain = match record.outcome {
+            TrustOutcome::Honored => TrustDomain::Support,
+            TrustOutcome::Betrayed => TrustDomain::Disclosure,
+        };
+        let before = record.timestamp - Duration::seconds(1);
+        let decision = sim
+            .predict_interaction(&record.trustor, &record.trustee, domain, before)
+            .unwrap();
+        let expected = match domain {
+            TrustDomain::Support => decision.support_willingness(),
+            _ => decision.disclosure_willingness(),
+        };
+        assert!((record.predicted - f64::from(expected)).abs() < 1e-12);
+    }
+
+    // ========================================================================
+    // STAGE 3: Trust follows the history
+    // What we're testing: Willingness toward Iris rises with every visit,
+    // and Theo's later betrayals are predicted with less trust than his
+    // first.
+    // ========================================================================
+
+    let iris_predictions: Vec<f64> = iris_report.records().iter().map(|r| r.predicted).collect();
+    assert!(iris_predictions.windows(2).all(|pair| pair[1] > pair[0]));
+
+    let betrayals: Vec<f64> = theo_report
+        .records()
+        .iter()
+        .filter(|record| record.outcome == TrustOutcome::Betrayed)
+        .map(|record| record.predicted)
+        .collect();
+    assert_eq!(betrayals.len(), 4);
+    assert!(betrayals[1..].iter().all(|later| *later < betrayals[0]));
+
+    // ========================================================================
+    // STAGE 4: Scores
+    // What we're testing: Nora's willingness stays below the threshold, so
+    // every betrayal is a hit and every support a miss. The Brier score is
+    // the mean squared error of the records, and Theo's predicted betrayals
+    // score better than Iris's under-predicted support.
+    // ========================================================================
+
+    assert_eq!(iris_report.hits(), 0);
+    assert_eq!(theo_report.hits(), 4);
+    assert_eq!(theo_report.misses(), 8);
+
+    let mean_squared_error = theo_report
+        .records()
+        .iter()
+        .map(|record| {
+            let outcome = if record.outcome == TrustOutcome::Honored {
+                1.0
+            } else {
+                0.0
+            };
+            (record.predicted - outcome).powi(2)
+        })
+        .sum::<f64>()
+        / 12.0;
+    let theo_brier = theo_report.brier_score().unwrap();
+    assert!((theo_brier - mean_squared_error).abs() < 1e-12);
+    assert!(theo_brier < iris_report.brier_score().unwrap());
+}
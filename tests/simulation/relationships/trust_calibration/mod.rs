@@ -0,0 +1,5 @@
+//! Trust calibration tests.
+//!
+//! Tests for scoring trust predictions against interaction outcomes.
+
+mod calibration_tracks_support_and_betrayal;
@@ -0,0 +1,146 @@
+//! Test: A decade of mood redraws within tolerance from a few hundred points.
+//!
+//! Priya's decade holds a loss, a run of exclusions, achievements, and a
+//! slow-building trauma. A dashboard asks for her valence and loneliness as
+//! adaptive series instead of fixed-step samples. Interpolating the
+//! adaptive points anywhere in the decade must land within the tolerance of
+//! the exact state, every event must be kept as a breakpoint, and the
+//! payload must be far smaller than sampling finely enough to draw the
+//! mood swings.
+
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{
+    EventType, MoodPath, RampShape, SocialCognitionPath, Species, StatePath,
+};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, Timestamp};
+
+const TOLERANCE: f64 = 0.01;
+
+/// Linearly interpolates the adaptive points at `ts`.
+fn interpolate(points: &[(Timestamp, f64)], ts: Timestamp) -> f64 {
+    let next = points.partition_point(|(point, _)| *point <= ts);
+    let (before, before_value) = points[next - 1];
+    let Some(&(after, after_value)) = points.get(next) else {
+        return before_value;
+    };
+    let fraction = (ts - before).as_seconds() as f64 / (after - before).as_seconds() as f64;
+    before_value + (after_value - before_value) * fraction
+}
+
+#[test]
+fn decade_trajectory_redraws_within_tolerance() {
+    // ========================================================================
+    // SETUP
+    // ========================================================================
+    let reference = Timestamp::from_ymd_hms(2020, 1, 1, 0, 0, 0);
+    let mut sim = Simulation::new(reference);
+    let priya = EntityBuilder::new()
+        .id("priya")
+        .species(Species::Human)
+        .age(Duration::years(30))
+        .build()
+        .unwrap();
+    let id = sim.add_entity(priya, reference);
+
+    let mut schedule = vec![(EventType::Loss, 0.8, Duration::days(90))];
+    for month in 0..6 {
+        let at = Duration::days(400 + month * 30);
+        schedule.push((EventType::SocialExclusion, 0.6, at));
+    }
+    for year in 2..10 {
+        schedule.push((EventType::Achievement, 0.5, Duration::days(year * 365 + 45)));
+    }
+    for (event_type, severity, offset) in schedule {
+        let event = EventBuilder::new(event_type)
+            .target(id.clone())
+            .severity(severity)
+            .build()
+            .unwrap();
+        sim.add_event(event, reference + offset);
+    }
+    let trauma = EventBuilder::new(EventType::Violence)
+        .target(id.clone())
+        .severity(0.7)
+        .ramp(Duration::days(14), RampShape::Linear)
+        .build()
+        .unwrap();
+    sim.add_event(trauma, reference + Duration::days(2000));
+
+    let handle = sim.entity(&id).unwrap();
+    let end = reference + Duration::years(10);
+    let paths = [
+        StatePath::Mood(MoodPath::Valence),
+        StatePath::SocialCognition(SocialCognitionPath::Loneliness),
+    ];
+
+    for path in paths {
+        let points = handle.adaptive_series(path, reference, end, TOLERANCE);
+
+        // ====================================================================
+        // STAGE 1: Shape of the output
+        // What we're testing: Points are chronological, span the window, and
+        // include every event with the second before it.
+        // ====================================================================
+
+        assert_eq!(points.first().unwrap().0, reference);
+        assert_eq!(points.last().unwrap().0, end);
+        assert!(points.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        for te in handle.events_between(reference, end) {
+            let at = te.timestamp();
+            assert!(points.iter().any(|(ts, _)| *ts == at));
+            assert!(points
+                .iter()
+                .any(|(ts, _)| *ts == at - Duration::seconds(1)));
+        }
+
+        // ====================================================================
+        // STAGE 2: Reconstruction stays within tolerance
+        // What we're testing: Interpolating the points matches the exact
+        // state every six hours, every minute of each event's first six hours,
+        // and at every point emitted.
+        // ====================================================================
+
+        let mut checks: Vec<Timestamp> = (0..=3652 * 4)
+            .map(|quarter| reference + Duration::hours(quarter * 6))
+            .filter(|ts| *ts <= end)
+            .collect();
+        for te in handle.events_between(reference, end) {
+            checks.extend((0..6 * 60).map(|minute| te.timestamp() + Duration::minutes(minute)));
+        }
+        for ts in checks {
+            let exact = handle.state_at(ts).get_effective(path);
+            let drawn = interpolate(&points, ts);
+            assert!(
+                (exact - drawn).abs() <= TOLERANCE,
+                "{:?} at {}: exact {} drawn {}",
+                path,
+                ts,
+                exact,
+                drawn
+            );
+        }
+        for (ts, value) in &points {
+            assert_eq!(*value, handle.state_at(*ts).get_effective(path));
+        }
+
+        // ====================================================================
+        // STAGE 3: Payload size
+        // What we're testing: The adaptive series is smaller than daily
+        // samples, which miss most of each six-hour mood swing, and at least
+        // 20 times smaller than hourly samples.
+        // ====================================================================
+
+        let daily = handle.series(path, reference, end, Duration::days(1));
+        let hourly = (end - reference).as_hours() as usize + 1;
+        assert!(points.len() < daily.len());
+        assert!(
+            points.len() * 20 <= hourly,
+            "{:?}: {} adaptive points vs {} hourly",
+            path,
+            points.len(),
+            hourly
+        );
+    }
+}
@@ -0,0 +1,3 @@
+//! Tests for adaptive state series export.
+
+mod decade_trajectory_redraws_within_tolerance;
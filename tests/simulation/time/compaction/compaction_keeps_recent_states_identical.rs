@@ -0,0 +1,127 @@
+//! Test: Compaction folds decayed history without changing recent states.
+//!
+//! A monitoring deployment records weekly everyday events for Maya over
+//! five years, plus one assault in the first year. Compacting at four years
+//! folds the old, fully decayed everyday events into a checkpoint. Every
+//! state from the cutoff onwards is bit-for-bit what it was before, the
+//! assault stays in the event log because acquired capability never decays,
+//! and states before the cutoff are flagged approximate.
+
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{EventType, Species, StatePath};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::{ApproximationReason, Simulation};
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+fn build_sim(reference: Timestamp) -> Simulation {
+    let mut sim = Simulation::new(reference);
+    let entity = EntityBuilder::new()
+        .id("maya")
+        .species(Species::Human)
+        .birth_date(Timestamp::from_ymd_hms(1990, 5, 1, 0, 0, 0))
+        .build()
+        .unwrap();
+    let maya = sim.add_entity(entity, reference);
+
+    let everyday = [
+        EventType::SocialExclusion,
+        EventType::SocialInclusion,
+        EventType::Achievement,
+        EventType::Failure,
+    ];
+    for week in 1..=260u64 {
+        let event = EventBuilder::new(everyday[(week % 4) as usize])
+            .target(maya.clone())
+            .severity(0.3)
+            .build()
+            .unwrap();
+        sim.add_event(event, reference + Duration::weeks(week));
+    }
+
+    let assault = EventBuilder::new(EventType::Violence)
+        .target(maya)
+        .severity(0.7)
+        .build()
+        .unwrap();
+    sim.add_event(assault, reference + Duration::days(200));
+    sim
+}
+
+/// Every path, as bits, at monthly steps from `start` for two years.
+fn fingerprint(sim: &Simulation, start: Timestamp) -> Vec<u64> {
+    let maya = EntityId::new("maya").unwrap();
+    let handle = sim.entity(&maya).unwrap();
+    (0..24u64)
+        .flat_map(|month| {
+            let state = handle.state_at(start + Duration::days(30 * month));
+            StatePath::all()
+                .into_iter()
+                .map(move |path| state.get_effective(path).to_bits())
+        })
+        .collect()
+}
+
+/// Compaction folds old events and keeps later states identical.
+#[test]
+fn compaction_keeps_recent_states_identical() {
+    // ========================================================================
+    // SETUP
+    // What we're doing: Five years of weekly events and one assault, and a
+    // compaction cutoff four years in.
+    // ========================================================================
+
+    let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let cutoff = reference + Duration::years(4);
+    let maya = EntityId::new("maya").unwrap();
+    let mut sim = build_sim(reference);
+    let events_before = sim.events_for(&maya).len();
+    let expected = fingerprint(&sim, cutoff);
+
+    // ========================================================================
+    // STAGE 1: Compact
+    // What we're testing: Old everyday events are folded, the assault and
+    // recent events are kept, and the report counts what was folded.
+    // ========================================================================
+
+    let report = sim.compact(cutoff);
+    assert!(report.folded() > 100, "folded {}", report.folded());
+    assert_eq!(report.folded_for(&maya), report.folded());
+    assert_eq!(sim.events_for(&maya).len(), events_before - report.folded());
+    assert!(sim
+        .events_for(&maya)
+        .iter()
+        .any(|te| te.event().event_type() == EventType::Violence));
+    assert!(sim
+        .events_for(&maya)
+        .iter()
+        .any(|te| te.timestamp() > cutoff - Duration::weeks(2)));
+
+    // ========================================================================
+    // STAGE 2: States from the cutoff on
+    // What we're testing: Monthly states for two years from the cutoff are
+    // bit-for-bit unchanged, and exact.
+    // ========================================================================
+
+    assert_eq!(fingerprint(&sim, cutoff), expected);
+    let handle = sim.entity(&maya).unwrap();
+    let recent = handle.state_at(cutoff + Duration::days(10));
+    assert!(recent.regression_quality().is_exact());
+    assert_eq!(recent.approximate_reasons(), []);
+
+    // ========================================================================
+    // STAGE 3: States before the cutoff
+    // What we're testing: Earlier states no longer see the folded events,
+    // so they are approximate and say why.
+    // ========================================================================
+
+    let early = handle.state_at(reference + Duration::years(2));
+    assert!(early.regression_quality().is_approximate());
+    assert_eq!(
+        early.approximate_reasons(),
+        [ApproximationReason::CompactedHistory { cutoff }]
+    );
+
+    // Compacting again at the same cutoff folds nothing more
+    assert_eq!(sim.compact(cutoff).folded(), 0);
+    assert_eq!(fingerprint(&sim, cutoff), expected);
+}
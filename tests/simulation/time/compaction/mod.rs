@@ -0,0 +1,3 @@
+//! Tests for folding decayed event history with `Simulation::compact`.
+
+mod compaction_keeps_recent_states_identical;
@@ -2,4 +2,8 @@
 //!
 //! Tests for forward simulation and backward regression.
 
+mod adaptive_series;
+mod compaction;
+mod ramp;
+mod species_scaling;
 mod state_at;
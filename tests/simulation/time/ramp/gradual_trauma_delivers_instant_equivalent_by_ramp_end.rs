@@ -0,0 +1,107 @@
+//! Test: A ramped event delivers its effect progressively.
+//!
+//! A progressive stressor represented as one ramped event should build up
+//! across its window instead of landing at once. Acquired Capability never
+//! decays, so it measures the delivered total directly: partway through the
+//! ramp only part of the effect has landed, and by the end of the window the
+//! total equals the same event applied instantly.
+
+use behavioral_pathways::entity::EntityBuilder;
+use behavioral_pathways::enums::{EventType, MentalHealthPath, RampShape, Species, StatePath};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+fn build_sim(ramp: Option<RampShape>) -> Simulation {
+    let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let mut sim = Simulation::new(reference);
+    let entity = EntityBuilder::new()
+        .id("person")
+        .species(Species::Human)
+        .build()
+        .unwrap();
+    sim.add_entity(entity, reference);
+
+    let mut builder = EventBuilder::new(EventType::Violence)
+        .target(EntityId::new("person").unwrap())
+        .severity(0.8);
+    if let Some(shape) = ramp {
+        builder = builder.ramp(Duration::days(60), shape);
+    }
+    sim.add_event(builder.build().unwrap(), reference + Duration::days(10));
+    sim
+}
+
+/// A ramped trauma builds across its window and totals the instant effect.
+#[test]
+fn gradual_trauma_delivers_instant_equivalent_by_ramp_end() {
+    // ========================================================================
+    // SETUP
+    // What we're doing: The same violent event three ways: instant, ramped
+    // linearly over 60 days, and ramped with an ease-in profile.
+    // ========================================================================
+
+    let reference = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let entity_id = EntityId::new("person").unwrap();
+    let onset = reference + Duration::days(10);
+    let ac = StatePath::MentalHealth(MentalHealthPath::AcquiredCapability);
+
+    let instant_sim = build_sim(None);
+    let linear_sim = build_sim(Some(RampShape::Linear));
+    let ease_in_sim = build_sim(Some(RampShape::EaseIn));
+
+    let instant = instant_sim.entity(&entity_id).unwrap();
+    let linear = linear_sim.entity(&entity_id).unwrap();
+    let ease_in = ease_in_sim.entity(&entity_id).unwrap();
+
+    let baseline = instant.state_at(reference).get_effective(ac);
+
+    // ========================================================================
+    // STAGE 1: Mid-ramp
+    // What we're testing: Only part of the effect has landed halfway through
+    // the window, and ease-in has delivered less than linear.
+    // ========================================================================
+
+    let mid = onset + Duration::days(30);
+    let instant_gain = instant.state_at(mid).get_effective(ac) - baseline;
+    let linear_gain = linear.state_at(mid).get_effective(ac) - baseline;
+    let ease_in_gain = ease_in.state_at(mid).get_effective(ac) - baseline;
+
+    assert!(instant_gain > 0.0, "Violence should raise AC");
+    assert!(
+        (linear_gain - instant_gain * 0.5).abs() < instant_gain * 0.05,
+        "Linear ramp should have delivered about half by mid-ramp. Linear: {}, Instant: {}",
+        linear_gain,
+        instant_gain
+    );
+    assert!(
+        ease_in_gain < linear_gain,
+        "Ease-in should lag linear mid-ramp. Ease-in: {}, Linear: {}",
+        ease_in_gain,
+        linear_gain
+    );
+
+    // ========================================================================
+    // STAGE 2: Ramp end
+    // What we're testing: By the end of the window the total delivered
+    // effect equals the instant-event equivalent.
+    // ========================================================================
+
+    let end = onset + Duration::days(60);
+    let instant_total = instant.state_at(end).get_effective(ac) - baseline;
+    let linear_total = linear.state_at(end).get_effective(ac) - baseline;
+    let ease_in_total = ease_in.state_at(end).get_effective(ac) - baseline;
+
+    assert!(
+        (linear_total - instant_total).abs() < 1e-4,
+        "Linear ramp total should equal instant. Linear: {}, Instant: {}",
+        linear_total,
+        instant_total
+    );
+    assert!(
+        (ease_in_total - instant_total).abs() < 1e-4,
+        "Ease-in ramp total should equal instant. Ease-in: {}, Instant: {}",
+        ease_in_total,
+        instant_total
+    );
+}
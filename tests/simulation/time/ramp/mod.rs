@@ -0,0 +1,3 @@
+//! Tests for gradual-onset events delivered across a ramp window.
+
+mod gradual_trauma_delivers_instant_equivalent_by_ramp_end;
@@ -0,0 +1,4 @@
+//! Tests for species time scaling of psychological processes.
+
+mod mouse_forgets_within_a_week;
+mod robot_mood_holds_for_a_year;
@@ -0,0 +1,118 @@
+//! Test: The same exclusion is ancient to a mouse after a week.
+//!
+//! A mouse lives about two years, so it experiences 40 psychological days
+//! for every real day. The same social exclusion shakes a human's and a
+//! mouse's sense of being cared for; a week later the mouse has long
+//! recovered while the human still feels it. Scaling changes only how fast time passes, so queries
+//! stay deterministic and a forward projection regresses back to where it
+//! started.
+
+use behavioral_pathways::entity::{Entity, EntityBuilder};
+use behavioral_pathways::enums::{EventType, SocialCognitionPath, Species, StatePath};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+const CARING: StatePath =
+    StatePath::SocialCognition(SocialCognitionPath::PerceivedReciprocalCaring);
+
+fn build(species: Species) -> Entity {
+    EntityBuilder::new()
+        .id("subject")
+        .species(species)
+        .age(Duration::days(200))
+        .build()
+        .unwrap()
+}
+
+/// A simulation with `entity` anchored at `anchor` and excluded at `onset`.
+fn build_sim(entity: Entity, anchor: Timestamp, onset: Timestamp) -> Simulation {
+    let mut sim = Simulation::new(anchor);
+    let id = sim.add_entity(entity, anchor);
+    let exclusion = EventBuilder::new(EventType::SocialExclusion)
+        .target(id)
+        .severity(0.8)
+        .build()
+        .unwrap();
+    sim.add_event(exclusion, onset);
+    sim
+}
+
+/// How far perceived caring is below the entity's anchor value at `at`.
+fn lost_caring(sim: &Simulation, at: Timestamp) -> f64 {
+    let id = EntityId::new("subject").unwrap();
+    let baseline = sim
+        .get_anchored_entity(&id)
+        .unwrap()
+        .entity()
+        .get_effective(CARING)
+        .unwrap();
+    baseline - sim.entity(&id).unwrap().state_at(at).get_effective(CARING)
+}
+
+/// Species time scaling makes a week ancient history for a mouse.
+#[test]
+fn mouse_forgets_within_a_week() {
+    // ========================================================================
+    // SETUP
+    // What we're doing: The same exclusion for a human and a mouse, a day
+    // after each is anchored.
+    // ========================================================================
+
+    let anchor = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let onset = anchor + Duration::days(1);
+    let human = build_sim(build(Species::Human), anchor, onset);
+    let mouse = build_sim(build(Species::Mouse), anchor, onset);
+
+    // ========================================================================
+    // STAGE 1: At onset
+    // What we're testing: The exclusion lands on both.
+    // ========================================================================
+
+    let human_hit = lost_caring(&human, onset);
+    let mouse_hit = lost_caring(&mouse, onset);
+    assert!(human_hit > 0.05);
+    assert!(mouse_hit > 0.05, "human {} mouse {}", human_hit, mouse_hit);
+
+    // ========================================================================
+    // STAGE 2: A week later
+    // What we're testing: The mouse has recovered; the human has not.
+    // ========================================================================
+
+    let week_later = onset + Duration::weeks(1);
+    let human_left = lost_caring(&human, week_later);
+    let mouse_left = lost_caring(&mouse, week_later);
+    assert!(human_left > 0.05 * human_hit, "human {}", human_left);
+    assert!(mouse_left < 0.001 * mouse_hit, "mouse {}", mouse_left);
+
+    // ========================================================================
+    // STAGE 3: Invariants under scaling
+    // What we're testing: Identical mouse simulations agree bit for bit, and
+    // re-anchoring the mouse a few hours after onset regresses back to its
+    // original state.
+    // ========================================================================
+
+    let twin = build_sim(build(Species::Mouse), anchor, onset);
+    let id = EntityId::new("subject").unwrap();
+    let at = onset + Duration::hours(5);
+    let state = mouse.entity(&id).unwrap().state_at(at);
+    let twin_state = twin.entity(&id).unwrap().state_at(at);
+    for path in StatePath::all() {
+        assert_eq!(
+            state.get_effective(path).to_bits(),
+            twin_state.get_effective(path).to_bits(),
+            "{:?}",
+            path
+        );
+    }
+
+    let mut later = build(Species::Mouse);
+    *later.individual_state_mut() = state.individual_state().clone();
+    let reanchored = build_sim(later, at, onset);
+    let regressed = reanchored.entity(&id).unwrap().state_at(anchor);
+    let original = build(Species::Mouse);
+    for path in [CARING, StatePath::SocialCognition(SocialCognitionPath::Loneliness)] {
+        let drift = regressed.get_effective(path) - original.get_effective(path).unwrap();
+        assert!(drift.abs() < 1e-3, "{:?} drifted by {}", path, drift);
+    }
+}
@@ -0,0 +1,118 @@
+//! Test: A robot's mood holds for a year while a human's fades.
+//!
+//! Robotic entities have no decay: their state stays exactly what events
+//! made it until another event changes it. A robot and a human start with
+//! the same lift in mood; a year later the human's has faded and the
+//! robot's has not. With nothing decaying, regressing a robot's state
+//! back through time loses nothing.
+
+use behavioral_pathways::entity::{Entity, EntityBuilder};
+use behavioral_pathways::enums::{EventType, MoodPath, SocialCognitionPath, Species, StatePath};
+use behavioral_pathways::event::EventBuilder;
+use behavioral_pathways::simulation::Simulation;
+use behavioral_pathways::types::{Duration, EntityId, Timestamp};
+
+const VALENCE: StatePath = StatePath::Mood(MoodPath::Valence);
+
+fn build(species: Species) -> Entity {
+    EntityBuilder::new()
+        .id("subject")
+        .species(species)
+        .age(Duration::years(5))
+        .build()
+        .unwrap()
+}
+
+/// `entity` with its mood lifted by `delta`.
+fn lifted(mut entity: Entity, delta: f32) -> Entity {
+    entity
+        .individual_state_mut()
+        .mood_mut()
+        .add_valence_delta(delta);
+    entity
+}
+
+fn valence(sim: &Simulation, at: Timestamp) -> f64 {
+    let id = EntityId::new("subject").unwrap();
+    sim.entity(&id).unwrap().state_at(at).get_effective(VALENCE)
+}
+
+/// Robotic state neither decays forward nor drifts backward.
+#[test]
+fn robot_mood_holds_for_a_year() {
+    // ========================================================================
+    // SETUP
+    // What we're doing: A robot and a human, each anchored with the same
+    // lift in mood.
+    // ========================================================================
+
+    let anchor = Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0);
+    let year_later = anchor + Duration::years(1);
+    let mut robot = Simulation::new(anchor);
+    robot.add_entity(lifted(build(Species::Robotic), 0.5), anchor);
+    let mut human = Simulation::new(anchor);
+    human.add_entity(lifted(build(Species::Human), 0.5), anchor);
+
+    // ========================================================================
+    // STAGE 1: A year on
+    // What we're testing: The human's lift has decayed away; the robot's is
+    // exactly where it was.
+    // ========================================================================
+
+    let human_lift = valence(&human, year_later) - valence(&human, anchor);
+    assert!(human_lift < -0.4, "human lift changed by {}", human_lift);
+    assert_eq!(
+        valence(&robot, year_later).to_bits(),
+        valence(&robot, anchor).to_bits()
+    );
+
+    // ========================================================================
+    // STAGE 2: A year back
+    // What we're testing: Regressing the robot a year before its anchor
+    // returns its anchored mood bit for bit.
+    // ========================================================================
+
+    let id = EntityId::new("subject").unwrap();
+    let year_before = anchor - Duration::years(1);
+    let anchored = robot.entity(&id).unwrap().state_at(anchor);
+    let regressed = robot.entity(&id).unwrap().state_at(year_before);
+    for path in MoodPath::all() {
+        assert_eq!(
+            regressed.get_effective(StatePath::Mood(path)).to_bits(),
+            anchored.get_effective(StatePath::Mood(path)).to_bits(),
+            "{:?}",
+            path
+        );
+    }
+
+    // ========================================================================
+    // STAGE 3: Back through an event
+    // What we're testing: A robot excluded a month after its anchor and
+    // re-anchored a year later regresses to its original state.
+    // ========================================================================
+
+    let onset = anchor + Duration::days(30);
+    let mut excluded = Simulation::new(anchor);
+    excluded.add_entity(build(Species::Robotic), anchor);
+    let exclusion = EventBuilder::new(EventType::SocialExclusion)
+        .target(id.clone())
+        .severity(0.8)
+        .build()
+        .unwrap();
+    excluded.add_event(exclusion.clone(), onset);
+    let after = excluded.entity(&id).unwrap().state_at(year_later);
+    let loneliness = StatePath::SocialCognition(SocialCognitionPath::Loneliness);
+    let original = build(Species::Robotic);
+    assert!(after.get_effective(loneliness) > original.get_effective(loneliness).unwrap());
+
+    let mut later = build(Species::Robotic);
+    *later.individual_state_mut() = after.individual_state().clone();
+    let mut reanchored = Simulation::new(year_later);
+    reanchored.add_entity(later, year_later);
+    reanchored.add_event(exclusion, onset);
+    let regressed = reanchored.entity(&id).unwrap().state_at(anchor);
+    for path in StatePath::all() {
+        let drift = regressed.get_effective(path) - original.get_effective(path).unwrap_or(0.0);
+        assert!(drift.abs() < 1e-6, "{:?} drifted by {}", path, drift);
+    }
+}